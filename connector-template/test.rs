@@ -23,8 +23,9 @@ impl utils::Connector for {{project-name | downcase | pascal_case}}Test {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .{{project-name | downcase}}
+                .clone()
                 .expect("Missing connector authentication configuration").into(),
         )
     }
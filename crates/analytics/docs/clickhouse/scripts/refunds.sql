@@ -18,6 +18,7 @@ CREATE TABLE refund_queue (
     `attempt_id` String,
     `description` Nullable(String),
     `refund_reason` Nullable(String),
+    `refund_reason_code` LowCardinality(Nullable(String)),
     `refund_error_code` Nullable(String),
     `created_at` DateTime,
     `modified_at` DateTime,
@@ -48,6 +49,7 @@ CREATE TABLE refunds (
     `attempt_id` String,
     `description` Nullable(String),
     `refund_reason` Nullable(String),
+    `refund_reason_code` LowCardinality(Nullable(String)),
     `refund_error_code` Nullable(String),
     `created_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `modified_at` DateTime DEFAULT now() CODEC(T64, LZ4),
@@ -81,6 +83,7 @@ CREATE MATERIALIZED VIEW refund_mv TO refunds (
     `attempt_id` String,
     `description` Nullable(String),
     `refund_reason` Nullable(String),
+    `refund_reason_code` LowCardinality(Nullable(String)),
     `refund_error_code` Nullable(String),
     `created_at` DateTime64(3),
     `modified_at` DateTime64(3),
@@ -107,6 +110,7 @@ SELECT
     attempt_id,
     description,
     refund_reason,
+    refund_reason_code,
     refund_error_code,
     created_at,
     modified_at,
@@ -12,7 +12,7 @@ use api_models::{
     },
     enums::{
         AttemptStatus, AuthenticationType, Connector, Currency, DisputeStage, PaymentMethod,
-        PaymentMethodType,
+        PaymentMethodType, RefundReasonCode,
     },
     refunds::RefundStatus,
 };
@@ -371,6 +371,7 @@ impl_to_sql_for_to_string!(
     storage_enums::RefundStatus,
     Currency,
     RefundType,
+    RefundReasonCode,
     Flow,
     &String,
     &bool,
@@ -191,6 +191,9 @@ pub async fn get_filters(
             RefundDimensions::RefundStatus => fil.refund_status.map(|i| i.as_ref().to_string()),
             RefundDimensions::Connector => fil.connector,
             RefundDimensions::RefundType => fil.refund_type.map(|i| i.as_ref().to_string()),
+            RefundDimensions::RefundReasonCode => {
+                fil.refund_reason_code.map(|i| i.as_ref().to_string())
+            }
         })
         .collect::<Vec<String>>();
         res.query_data.push(RefundFilterValue {
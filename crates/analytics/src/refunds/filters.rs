@@ -3,7 +3,7 @@ use api_models::analytics::{
     Granularity, TimeRange,
 };
 use common_utils::errors::ReportSwitchExt;
-use diesel_models::enums::{Currency, RefundStatus};
+use diesel_models::enums::{Currency, RefundReasonCode, RefundStatus};
 use error_stack::ResultExt;
 use time::PrimitiveDateTime;
 
@@ -56,4 +56,5 @@ pub struct RefundFilterRow {
     pub refund_status: Option<DBEnumWrapper<RefundStatus>>,
     pub connector: Option<String>,
     pub refund_type: Option<DBEnumWrapper<RefundType>>,
+    pub refund_reason_code: Option<DBEnumWrapper<RefundReasonCode>>,
 }
@@ -6,7 +6,7 @@ use api_models::{
 };
 use common_utils::errors::{CustomResult, ParsingError};
 use diesel_models::enums::{
-    AttemptStatus, AuthenticationType, Currency, PaymentMethod, RefundStatus,
+    AttemptStatus, AuthenticationType, Currency, PaymentMethod, RefundReasonCode, RefundStatus,
 };
 use error_stack::ResultExt;
 use masking::PeekInterface;
@@ -92,6 +92,7 @@ db_type!(AttemptStatus);
 db_type!(PaymentMethod, TEXT);
 db_type!(RefundStatus);
 db_type!(RefundType);
+db_type!(RefundReasonCode, TEXT);
 db_type!(DisputeStage);
 db_type!(DisputeStatus);
 
@@ -201,6 +202,11 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::metrics::RefundMetricRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let refund_reason_code: Option<DBEnumWrapper<RefundReasonCode>> =
+            row.try_get("refund_reason_code").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -221,6 +227,7 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::metrics::RefundMetricRow {
             refund_status,
             connector,
             refund_type,
+            refund_reason_code,
             total,
             count,
             start_bucket,
@@ -419,11 +426,17 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::filters::RefundFilterRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let refund_reason_code: Option<DBEnumWrapper<RefundReasonCode>> =
+            row.try_get("refund_reason_code").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         Ok(Self {
             currency,
             refund_status,
             connector,
             refund_type,
+            refund_reason_code,
         })
     }
 }
@@ -0,0 +1,35 @@
+#[path = "../common_enums/connector_manifest.rs"]
+mod connector_manifest;
+
+fn main() {
+    let manifest =
+        connector_manifest::ConnectorManifest::load("../common_enums/connectors.toml".as_ref());
+
+    connector_manifest::write_generated_file(
+        "connector.rs",
+        &format!(
+            r#"/// A connector is an integration to fulfill payments
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::VariantNames,
+    strum::EnumIter,
+    strum::Display,
+    strum::EnumString,
+    Hash,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum Connector {{
+{}}}
+"#,
+            manifest.connector_variants()
+        ),
+    );
+}
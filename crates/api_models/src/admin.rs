@@ -14,7 +14,7 @@ use super::payments::AddressDetails;
 use crate::{
     enums,
     enums::{self as api_enums},
-    payment_methods,
+    payment_methods, payments,
 };
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -95,6 +95,9 @@ pub struct MerchantAccountCreate {
 
     /// The id of the organization to which the merchant belongs to
     pub organization_id: Option<String>,
+
+    /// The features which the merchant is allowed to use, all features are allowed if not provided
+    pub feature_entitlements: Option<MerchantFeatureEntitlements>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
@@ -113,6 +116,78 @@ pub struct MerchantAccountMetadata {
     #[serde(flatten)]
     pub data: Option<pii::SecretSerdeValue>,
 }
+
+/// Features of the platform which can be enabled or disabled for a merchant account
+#[derive(Clone, Copy, Debug, Eq, PartialEq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum MerchantFeature {
+    Payouts,
+    Frm,
+    PaymentLinks,
+    Analytics,
+    RawCardData,
+    Moto,
+}
+
+/// The features which a merchant account is entitled to use
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct MerchantFeatureEntitlements {
+    /// Whether the merchant can create and manage payouts
+    #[schema(default = true, example = true)]
+    pub payouts: bool,
+
+    /// Whether payments of the merchant can be screened by fraud and risk management connectors
+    #[schema(default = true, example = true)]
+    pub frm: bool,
+
+    /// Whether the merchant can create payment links
+    #[schema(default = true, example = false)]
+    pub payment_links: bool,
+
+    /// Whether the merchant can access analytics
+    #[schema(default = true, example = true)]
+    pub analytics: bool,
+
+    /// Whether the merchant is PCI compliant, and can send raw card data when confirming payments
+    /// from their server, if the deployment requires it
+    #[schema(default = false, example = false)]
+    pub raw_card_data: bool,
+
+    /// Whether the merchant can create mail order / telephone order payments, which are not
+    /// authenticated with 3DS
+    #[schema(default = false, example = false)]
+    pub moto: bool,
+}
+
+impl Default for MerchantFeatureEntitlements {
+    /// Merchant accounts are entitled to all the features unless restricted, except for raw card
+    /// data which requires the merchant to be marked as PCI compliant, and for MOTO payments which
+    /// skip the authentication of the customer
+    fn default() -> Self {
+        Self {
+            payouts: true,
+            frm: true,
+            payment_links: true,
+            analytics: true,
+            raw_card_data: false,
+            moto: false,
+        }
+    }
+}
+
+impl MerchantFeatureEntitlements {
+    pub fn is_entitled_to(&self, feature: MerchantFeature) -> bool {
+        match feature {
+            MerchantFeature::Payouts => self.payouts,
+            MerchantFeature::Frm => self.frm,
+            MerchantFeature::PaymentLinks => self.payment_links,
+            MerchantFeature::Analytics => self.analytics,
+            MerchantFeature::RawCardData => self.raw_card_data,
+            MerchantFeature::Moto => self.moto,
+        }
+    }
+}
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct MerchantAccountUpdate {
@@ -277,6 +352,9 @@ pub struct MerchantAccountResponse {
     /// Used to indicate the status of the recon module for a merchant account
     #[schema(value_type = ReconStatus, example = "not_requested")]
     pub recon_status: enums::ReconStatus,
+
+    /// The features which the merchant is allowed to use
+    pub feature_entitlements: MerchantFeatureEntitlements,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -444,6 +522,8 @@ pub struct MerchantConnectorCreate {
     pub connector_webhook_details: Option<MerchantConnectorWebhookDetails>,
 
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    ///
+    /// The payment metadata keys specified in `metadata_mapping` of the metadata are propagated to the connector metadata fields they are mapped to, for the connectors supporting it like Stripe, Adyen and Checkout.
     #[schema(value_type = Option<Object>,max_length = 255,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
 
@@ -690,6 +770,8 @@ pub struct MerchantConnectorUpdate {
     pub connector_webhook_details: Option<MerchantConnectorWebhookDetails>,
 
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    ///
+    /// The payment metadata keys specified in `metadata_mapping` of the metadata are propagated to the connector metadata fields they are mapped to, for the connectors supporting it like Stripe, Adyen and Checkout.
     #[schema(value_type = Option<Object>,max_length = 255,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
 
@@ -826,6 +908,28 @@ pub struct ToggleKVRequest {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantKeyCustodyRequest {
+    #[serde(skip_deserializing)]
+    pub merchant_id: String,
+    /// URI of the key provided by the merchant, used to wrap the key its data is encrypted with.
+    /// For AWS KMS keys this is the key ARN, and the key must be shared with a grant.
+    /// The key is managed by Hyperswitch if this is not provided.
+    #[schema(
+        example = "arn:aws:kms:us-east-1:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab"
+    )]
+    pub external_key_uri: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantKeyCustodyResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// URI of the key provided by the merchant, if the merchant holds custody of its key
+    pub external_key_uri: Option<String>,
+}
+
 #[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct MerchantConnectorDetailsWrap {
     /// Creds Identifier is to uniquely identify the credentials. Do not send any sensitive info in this field. And do not send the string "null".
@@ -913,6 +1017,45 @@ pub struct BusinessProfileCreate {
 
     /// External 3DS authentication details
     pub authentication_connector_details: Option<AuthenticationConnectorDetails>,
+
+    /// Retries of off-session payments which failed with soft declines
+    pub dunning_config: Option<DunningConfig>,
+
+    /// Whether network tokens are provisioned with the card networks for the cards saved by the
+    /// customers of the business profile, which are used instead of the cards with the connectors
+    /// supporting them
+    #[schema(default = false, example = false)]
+    pub is_network_tokenization_enabled: Option<bool>,
+
+    /// Whether the tax on the payments of the business profile is calculated by the tax
+    /// calculation service, and added to their amount, when the shipping address of the payment is
+    /// provided before confirmation
+    #[schema(default = false, example = false)]
+    pub is_tax_calculation_enabled: Option<bool>,
+
+    /// Whether the CVC of the saved cards of the customers must be collected and sent in
+    /// `card_cvc` with their payment token when confirming their customer initiated payments. The
+    /// `{merchant_id}_requires_cvv` config of the merchant is used if not set
+    #[schema(example = true)]
+    pub requires_cvc_for_saved_cards: Option<bool>,
+
+    /// Hours after the authorization of a manual capture payment after which it is voided
+    /// automatically if it has not been captured, releasing the funds held on the payment method of
+    /// the customer. Uncaptured payments are not voided if not set
+    #[schema(minimum = 1, maximum = 720, example = 168)]
+    pub auto_void_after_in_hours: Option<u16>,
+
+    /// Notifications of the payments waiting for a payment method or for their confirmation beyond
+    /// a period, which are sent as `payment_abandoned` webhooks for cart recovery campaigns. The
+    /// payments are not notified if not set
+    pub abandoned_payment_config: Option<AbandonedPaymentConfig>,
+
+    /// Maximum number of times a payment declined with a soft decline, as classified by the
+    /// gateway status map of its connector, is retried right away on the next connectors of its
+    /// routing fallback list. Takes precedence over the `max_auto_retries_enabled_{merchant_id}`
+    /// config of the merchant, and `0` disables the retries for the business profile
+    #[schema(maximum = 5, example = 2)]
+    pub max_auto_retries: Option<u8>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -982,6 +1125,44 @@ pub struct BusinessProfileResponse {
 
     /// External 3DS authentication details
     pub authentication_connector_details: Option<AuthenticationConnectorDetails>,
+
+    /// Retries of off-session payments which failed with soft declines
+    pub dunning_config: Option<DunningConfig>,
+
+    /// Whether delivery of outgoing webhooks to the webhook endpoint of the business profile has
+    /// been paused, after consecutive delivery failures
+    pub is_webhook_endpoint_disabled: bool,
+
+    /// Whether network tokens are provisioned with the card networks for the cards saved by the
+    /// customers of the business profile, which are used instead of the cards with the connectors
+    /// supporting them
+    pub is_network_tokenization_enabled: bool,
+
+    /// Whether the tax on the payments of the business profile is calculated by the tax
+    /// calculation service, and added to their amount, when the shipping address of the payment is
+    /// provided before confirmation
+    pub is_tax_calculation_enabled: bool,
+
+    /// Whether the CVC of the saved cards of the customers must be collected and sent in
+    /// `card_cvc` with their payment token when confirming their customer initiated payments. The
+    /// `{merchant_id}_requires_cvv` config of the merchant is used if not set
+    pub requires_cvc_for_saved_cards: Option<bool>,
+
+    /// Hours after the authorization of a manual capture payment after which it is voided
+    /// automatically if it has not been captured, releasing the funds held on the payment method of
+    /// the customer. Uncaptured payments are not voided if not set
+    pub auto_void_after_in_hours: Option<u16>,
+
+    /// Notifications of the payments waiting for a payment method or for their confirmation beyond
+    /// a period, which are sent as `payment_abandoned` webhooks for cart recovery campaigns. The
+    /// payments are not notified if not set
+    pub abandoned_payment_config: Option<AbandonedPaymentConfig>,
+
+    /// Maximum number of times a payment declined with a soft decline, as classified by the
+    /// gateway status map of its connector, is retried right away on the next connectors of its
+    /// routing fallback list. Takes precedence over the `max_auto_retries_enabled_{merchant_id}`
+    /// config of the merchant, and `0` disables the retries for the business profile
+    pub max_auto_retries: Option<u8>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -1046,6 +1227,96 @@ pub struct BusinessProfileUpdate {
 
     /// Merchant's config to support extended card info feature
     pub extended_card_info_config: Option<ExtendedCardInfoConfig>,
+
+    /// Retries of off-session payments which failed with soft declines
+    pub dunning_config: Option<DunningConfig>,
+
+    /// Whether network tokens are provisioned with the card networks for the cards saved by the
+    /// customers of the business profile, which are used instead of the cards with the connectors
+    /// supporting them
+    #[schema(example = false)]
+    pub is_network_tokenization_enabled: Option<bool>,
+
+    /// Whether the tax on the payments of the business profile is calculated by the tax
+    /// calculation service, and added to their amount, when the shipping address of the payment is
+    /// provided before confirmation
+    #[schema(example = false)]
+    pub is_tax_calculation_enabled: Option<bool>,
+
+    /// Whether the CVC of the saved cards of the customers must be collected and sent in
+    /// `card_cvc` with their payment token when confirming their customer initiated payments. The
+    /// `{merchant_id}_requires_cvv` config of the merchant is used if not set
+    #[schema(example = true)]
+    pub requires_cvc_for_saved_cards: Option<bool>,
+
+    /// Hours after the authorization of a manual capture payment after which it is voided
+    /// automatically if it has not been captured, releasing the funds held on the payment method of
+    /// the customer. Uncaptured payments are not voided if not set
+    #[schema(minimum = 1, maximum = 720, example = 168)]
+    pub auto_void_after_in_hours: Option<u16>,
+
+    /// Notifications of the payments waiting for a payment method or for their confirmation beyond
+    /// a period, which are sent as `payment_abandoned` webhooks for cart recovery campaigns. The
+    /// payments are not notified if not set
+    pub abandoned_payment_config: Option<AbandonedPaymentConfig>,
+
+    /// Maximum number of times a payment declined with a soft decline, as classified by the
+    /// gateway status map of its connector, is retried right away on the next connectors of its
+    /// routing fallback list. Takes precedence over the `max_auto_retries_enabled_{merchant_id}`
+    /// config of the merchant, and `0` disables the retries for the business profile
+    #[schema(maximum = 5, example = 2)]
+    pub max_auto_retries: Option<u8>,
+}
+
+/// Notifications of the payments abandoned by the customers, which have been waiting for a payment
+/// method or for their confirmation since their creation for longer than the configured period. A
+/// `payment_abandoned` webhook is sent once for each of them, so that the merchant can reach out
+/// to the customer.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
+pub struct AbandonedPaymentConfig {
+    /// Minutes after the creation of a payment after which it is notified as abandoned, if it is
+    /// still waiting for a payment method or for its confirmation
+    #[schema(minimum = 5, maximum = 10080, example = 60)]
+    pub notify_after_in_minutes: u16,
+
+    /// Whether the customer of the abandoned payment is also sent an email with the payment link
+    /// of the payment, to complete it. The email is sent only if the email of the customer is known
+    /// and a payment link has been created for the payment
+    #[serde(default)]
+    #[schema(default = false, example = false)]
+    pub email_customer: bool,
+}
+
+/// Retries of off-session (merchant initiated) payments which failed with soft declines, such as
+/// insufficient funds. They are scheduled at times the issuers are more likely to approve them,
+/// and `payment_retry_scheduled` and `payment_retries_exhausted` webhooks are sent so that the
+/// merchant can reach out to the customer.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
+pub struct DunningConfig {
+    /// Maximum number of retries of a failed payment
+    #[schema(minimum = 1, maximum = 10, example = 4)]
+    pub max_retries: u8,
+
+    /// Minimum number of days between a failure and the next retry
+    #[schema(minimum = 1, example = 3)]
+    pub min_days_between_retries: u8,
+
+    /// Hour of the day (0 to 23, in the timezone of `utc_offset`) at which the payments are
+    /// retried, as issuers approve more payments during business hours
+    #[serde(alias = "retry_hour_utc")]
+    #[schema(maximum = 23, example = 10)]
+    pub retry_hour: u8,
+
+    /// UTC offset of the timezone of the merchant, such as `+05:30`, which the days and the hour of
+    /// the retries are in. The retries are scheduled in UTC if not set
+    #[schema(example = "+05:30")]
+    pub utc_offset: Option<String>,
+
+    /// Days of the month (1 to 31) on which the balances of the customers are typically refreshed,
+    /// such as paydays. A retry is moved to the next of these days if it falls within a week of it.
+    #[serde(default)]
+    #[schema(example = json!([1, 15]))]
+    pub balance_refresh_days: Vec<u8>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
@@ -1144,3 +1415,70 @@ impl std::ops::Deref for TtlForExtendedCardInfo {
         &self.0
     }
 }
+
+/// Certificates and keys of the wallets of a business profile, with which the Apple Pay sessions
+/// are created and the wallet payment tokens are decrypted for all the connectors of the business
+/// profile. They take precedence over the ones in the metadata of the merchant connector accounts.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WalletCertificates {
+    /// Certificates of Apple Pay
+    pub apple_pay: Option<ApplePayCertificates>,
+    /// Private key and recipient of the Google Pay payment tokens
+    #[schema(value_type = Option<GooglePayPaymentProcessingDetails>)]
+    pub google_pay: Option<payments::GooglePayPaymentProcessingDetails>,
+    /// Private key of the Samsung Pay payment tokens
+    #[schema(value_type = Option<SamsungPayPaymentProcessingDetails>)]
+    pub samsung_pay: Option<payments::SamsungPayPaymentProcessingDetails>,
+}
+
+impl common_utils::events::ApiEventMetric for WalletCertificates {}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ApplePayCertificates {
+    /// The Apple Pay merchant identifier the merchant identity certificate is issued for
+    pub merchant_identifier: String,
+    /// Base64 encoded merchant identity certificate, in PEM format, to create the Apple Pay
+    /// sessions with
+    #[schema(value_type = String)]
+    pub merchant_certificate: Secret<String>,
+    /// Base64 encoded private key of the merchant identity certificate, in PEM format
+    #[schema(value_type = String)]
+    pub merchant_certificate_key: Secret<String>,
+    /// Payment processing certificate to decrypt the Apple Pay payment tokens with, the payment
+    /// tokens are forwarded to the connectors as they are if it is not given
+    #[schema(value_type = Option<PaymentProcessingDetails>)]
+    pub payment_processing_details: Option<payments::PaymentProcessingDetails>,
+}
+
+/// The wallets a business profile has certificates for, without their certificates and keys
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WalletCertificatesResponse {
+    /// The identifier of the business profile
+    pub profile_id: String,
+    pub apple_pay: Option<ApplePayCertificatesResponse>,
+    pub google_pay: Option<GooglePayCertificatesResponse>,
+    /// Whether the private key of the Samsung Pay payment tokens is set
+    pub is_samsung_pay_private_key_set: bool,
+}
+
+impl common_utils::events::ApiEventMetric for WalletCertificatesResponse {}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ApplePayCertificatesResponse {
+    /// The Apple Pay merchant identifier the merchant identity certificate is issued for
+    pub merchant_identifier: String,
+    /// The time the merchant identity certificate expires at
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub merchant_certificate_expires_at: time::PrimitiveDateTime,
+    /// The time the payment processing certificate expires at, if one is set
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub payment_processing_certificate_expires_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct GooglePayCertificatesResponse {
+    /// The recipient of the Google Pay payment tokens
+    pub recipient_id: String,
+}
@@ -3,7 +3,10 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use crate::{enums::Currency, refunds::RefundStatus};
+use crate::{
+    enums::{Currency, RefundReasonCode},
+    refunds::RefundStatus,
+};
 
 #[derive(
     Clone,
@@ -38,6 +41,8 @@ pub struct RefundFilters {
     pub connector: Vec<String>,
     #[serde(default)]
     pub refund_type: Vec<RefundType>,
+    #[serde(default)]
+    pub refund_reason_code: Vec<RefundReasonCode>,
 }
 
 #[derive(
@@ -61,6 +66,7 @@ pub enum RefundDimensions {
     RefundStatus,
     Connector,
     RefundType,
+    RefundReasonCode,
 }
 
 #[derive(
@@ -116,6 +122,7 @@ pub struct RefundMetricsBucketIdentifier {
     pub connector: Option<String>,
 
     pub refund_type: Option<String>,
+    pub refund_reason_code: Option<String>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     #[serde(rename = "time_bucket")]
@@ -129,6 +136,7 @@ impl Hash for RefundMetricsBucketIdentifier {
         self.refund_status.hash(state);
         self.connector.hash(state);
         self.refund_type.hash(state);
+        self.refund_reason_code.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -148,6 +156,7 @@ impl RefundMetricsBucketIdentifier {
         refund_status: Option<String>,
         connector: Option<String>,
         refund_type: Option<String>,
+        refund_reason_code: Option<String>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
@@ -155,6 +164,7 @@ impl RefundMetricsBucketIdentifier {
             refund_status,
             connector,
             refund_type,
+            refund_reason_code,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
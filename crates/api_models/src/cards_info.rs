@@ -1,5 +1,6 @@
 use std::fmt::Debug;
 
+use masking::MaskedDebug;
 use utoipa::ToSchema;
 
 #[derive(serde::Deserialize, ToSchema)]
@@ -8,8 +9,9 @@ pub struct CardsInfoRequestParams {
     pub client_secret: Option<String>,
 }
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, MaskedDebug, serde::Serialize)]
 pub struct CardsInfoRequest {
+    #[masked]
     pub client_secret: Option<String>,
     pub card_iin: String,
 }
@@ -28,4 +30,8 @@ pub struct CardInfoResponse {
     pub card_sub_type: Option<String>,
     #[schema(example = "INDIA")]
     pub card_issuing_country: Option<String>,
+    /// The networks of the card if it is co-badged, any of which can be chosen as the
+    /// `card_network` of the payment
+    #[schema(example = json!(["Visa", "CartesBancaires"]))]
+    pub co_badged_card_networks: Option<Vec<String>>,
 }
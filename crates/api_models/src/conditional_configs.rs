@@ -58,6 +58,13 @@ impl EuclidAnalysable for AuthenticationType {
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ConditionalConfigs {
     pub override_3ds: Option<AuthenticationType>,
+    /// Whether a 3DS payment declined by the connector as 3DS being unavailable can be retried
+    /// with the same connector without 3DS
+    pub allow_3ds_step_down: Option<bool>,
+    /// Whether a non-3DS payment soft declined by the connector as requiring strong customer
+    /// authentication can be retried with the same connector with 3DS. Falls back to the
+    /// connectors step-up is enabled for by the merchant when not decided.
+    pub allow_3ds_step_up: Option<bool>,
 }
 impl EuclidDirFilter for ConditionalConfigs {
     const ALLOWED: &'static [DirKeyKind] = &[
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorMigrationRequest {
+    /// The merchant connector account the payment methods and mandates are migrated from
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub source_merchant_connector_id: String,
+
+    /// The merchant connector account the payment methods and mandates are migrated to
+    #[schema(example = "mca_Dw3Hl6xW8pKcGz0aFQe1")]
+    pub target_merchant_connector_id: String,
+
+    /// Tokens of the source connector mapped to the tokens they have been imported as by the
+    /// target connector, as provided by the connectors when exporting and importing the tokens.
+    /// Payment methods and mandates whose tokens are not mapped fall back to their network
+    /// transaction ID, if the target connector supports it.
+    #[serde(default)]
+    pub token_mapping: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorMigrationStatus {
+    /// The migration has been scheduled and has not been completed yet
+    Pending,
+    /// All the payment methods and mandates of the source connector have been processed
+    Completed,
+    /// The migration could not be completed
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorMigrationOutcome {
+    /// The token of the source connector has been replaced by the one imported by the target
+    /// connector
+    TokenImported,
+    /// Subsequent payments are made using the network transaction ID, as the token is not
+    /// available with the target connector
+    NetworkTransactionIdFallback,
+    /// Neither an imported token nor a network transaction ID is available for the target
+    /// connector, or the migration of the item failed
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConnectorMigrationItem {
+    /// The payment method which has been migrated, or the payment method of the migrated mandate
+    #[schema(example = "pm_y3oqhf46pyzlyrb8xn3")]
+    pub payment_method_id: String,
+
+    /// The mandate which has been migrated, if the item is a mandate
+    #[schema(example = "man_Ia2dwUH8gB3oy7ZIdBx3")]
+    pub mandate_id: Option<String>,
+
+    /// The outcome of the migration of the item
+    pub outcome: ConnectorMigrationOutcome,
+
+    /// The reason the migration of the item failed
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ConnectorMigrationResponse {
+    /// The identifier of the migration
+    #[schema(example = "cm_Hv9F3UwTqQuXjwUsjt3T")]
+    pub migration_id: String,
+
+    /// The merchant whose payment methods and mandates are migrated
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    /// The merchant connector account the payment methods and mandates are migrated from
+    pub source_merchant_connector_id: String,
+
+    /// The merchant connector account the payment methods and mandates are migrated to
+    pub target_merchant_connector_id: String,
+
+    /// The status of the migration
+    pub status: ConnectorMigrationStatus,
+
+    /// The outcome of the migration of every payment method and mandate of the source connector,
+    /// available once the migration has been completed
+    pub items: Vec<ConnectorMigrationItem>,
+}
+
+impl ApiEventMetric for ConnectorMigrationRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for ConnectorMigrationResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -1,5 +1,7 @@
 use common_utils::events::ApiEventMetric;
 
+use crate::enums;
+
 /// QueryParams to be send to convert the amount -> from_currency -> to_currency
 #[derive(Debug, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -17,5 +19,45 @@ pub struct CurrencyConversionResponse {
     pub currency: String,
 }
 
+/// QueryParams to be send to localize the amount for the shoppers of a country
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AmountLocalizationParams {
+    /// The amount to be localized, in the lowest denomination of its currency
+    pub amount: i64,
+    pub currency: enums::Currency,
+    /// The country of the shopper the amount is presented to
+    pub country: enums::CountryAlpha2,
+}
+
+/// Response to be send for localize amount route
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AmountLocalizationResponse {
+    pub amount: i64,
+    pub currency: enums::Currency,
+    pub country: enums::CountryAlpha2,
+    /// The localized amounts which can be presented to the shopper, in each of the currencies
+    /// configured for their country
+    pub presentment_options: Vec<LocalizedAmount>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct LocalizedAmount {
+    pub currency: enums::Currency,
+    /// The converted amount, with the markup and the rounding applied, in the lowest denomination
+    /// of the currency
+    pub amount: i64,
+    /// The converted amount in the base unit of the currency, as it can be displayed
+    pub display_amount: String,
+    /// Markup added to the converted amount, in basis points
+    pub markup_in_basis_points: u16,
+    /// Whether the amount has been rounded up to a charm price, such as 12.99
+    pub charm_priced: bool,
+}
+
 impl ApiEventMetric for CurrencyConversionResponse {}
 impl ApiEventMetric for CurrencyConversionParams {}
+impl ApiEventMetric for AmountLocalizationResponse {}
+impl ApiEventMetric for AmountLocalizationParams {}
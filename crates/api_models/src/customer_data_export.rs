@@ -0,0 +1,127 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::{
+    customers::CustomerResponse, disputes::DisputeResponse, enums as api_enums,
+    payments::PaymentsResponse, webhook_events::EventListItemResponse,
+};
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerDataExportRequest {
+    /// The customer whose data is exported
+    #[schema(max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerDataExportStatus {
+    /// The export has been scheduled and has not been produced yet
+    Pending,
+    /// The data held about the customer has been exported
+    Completed,
+    /// The export could not be produced
+    Failed,
+}
+
+/// A payment method saved for the customer. The details of the payment method stored in the
+/// locker, such as the card number, are not exported.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CustomerDataExportPaymentMethod {
+    /// The identifier for the payment method
+    #[schema(example = "pm_y3oqhf46pyzlyrb8xn3")]
+    pub payment_method_id: String,
+
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// The issuer of the payment method
+    #[schema(example = "Chase")]
+    pub payment_method_issuer: Option<String>,
+
+    /// The card network of the payment method
+    #[schema(example = "Visa")]
+    pub scheme: Option<String>,
+
+    /// The country the payment method has been issued in
+    #[schema(example = "US")]
+    pub issuer_country: Option<String>,
+
+    #[schema(value_type = PaymentMethodStatus)]
+    pub status: api_enums::PaymentMethodStatus,
+
+    /// Time at which the payment method was saved
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time at which the payment method was last used
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_used_at: PrimitiveDateTime,
+}
+
+/// All the data held about a customer by the merchant
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CustomerDataExport {
+    /// The details of the customer
+    pub customer: CustomerResponse,
+
+    /// The payments made by the customer
+    pub payments: Vec<PaymentsResponse>,
+
+    /// The payment methods saved for the customer
+    pub payment_methods: Vec<CustomerDataExportPaymentMethod>,
+
+    /// The disputes raised on the payments of the customer
+    pub disputes: Vec<DisputeResponse>,
+
+    /// The webhook events sent to the merchant for the payments and disputes of the customer
+    pub webhook_events: Vec<EventListItemResponse>,
+
+    /// Time at which the data was exported
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub exported_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDataExportResponse {
+    /// The identifier of the export
+    #[schema(example = "cde_Hv9F3UwTqQuXjwUsjt3T")]
+    pub export_id: String,
+
+    /// The merchant holding the data of the customer
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    /// The customer whose data is exported
+    #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+
+    /// The status of the export
+    pub status: CustomerDataExportStatus,
+
+    /// The data held about the customer, available once the export has been completed
+    pub data: Option<CustomerDataExport>,
+}
+
+impl ApiEventMetric for CustomerDataExportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for CustomerDataExportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
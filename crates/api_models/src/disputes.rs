@@ -5,7 +5,7 @@ use utoipa::ToSchema;
 use super::enums::{DisputeStage, DisputeStatus};
 use crate::files;
 
-#[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema, Eq, PartialEq)]
 pub struct DisputeResponse {
     /// The identifier for dispute
     pub dispute_id: String,
@@ -49,7 +49,7 @@ pub struct DisputeResponse {
     pub merchant_connector_id: Option<String>,
 }
 
-#[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema, Eq, PartialEq)]
 pub struct DisputeResponsePaymentsRetrieve {
     /// The identifier for dispute
     pub dispute_id: String,
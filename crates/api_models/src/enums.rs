@@ -25,121 +25,16 @@ pub enum RoutingAlgorithm {
     Custom,
 }
 
-/// A connector is an integration to fulfill payments
-#[derive(
-    Clone,
-    Copy,
-    Debug,
-    Eq,
-    PartialEq,
-    ToSchema,
-    serde::Deserialize,
-    serde::Serialize,
-    strum::VariantNames,
-    strum::EnumIter,
-    strum::Display,
-    strum::EnumString,
-    Hash,
-)]
-#[serde(rename_all = "snake_case")]
-#[strum(serialize_all = "snake_case")]
-pub enum Connector {
-    #[cfg(feature = "dummy_connector")]
-    #[serde(rename = "phonypay")]
-    #[strum(serialize = "phonypay")]
-    DummyConnector1,
-    #[cfg(feature = "dummy_connector")]
-    #[serde(rename = "fauxpay")]
-    #[strum(serialize = "fauxpay")]
-    DummyConnector2,
-    #[cfg(feature = "dummy_connector")]
-    #[serde(rename = "pretendpay")]
-    #[strum(serialize = "pretendpay")]
-    DummyConnector3,
-    #[cfg(feature = "dummy_connector")]
-    #[serde(rename = "stripe_test")]
-    #[strum(serialize = "stripe_test")]
-    DummyConnector4,
-    #[cfg(feature = "dummy_connector")]
-    #[serde(rename = "adyen_test")]
-    #[strum(serialize = "adyen_test")]
-    DummyConnector5,
-    #[cfg(feature = "dummy_connector")]
-    #[serde(rename = "checkout_test")]
-    #[strum(serialize = "checkout_test")]
-    DummyConnector6,
-    #[cfg(feature = "dummy_connector")]
-    #[serde(rename = "paypal_test")]
-    #[strum(serialize = "paypal_test")]
-    DummyConnector7,
-    Aci,
-    Adyen,
-    Airwallex,
-    Authorizedotnet,
-    Bambora,
-    Bankofamerica,
-    Billwerk,
-    Bitpay,
-    Bluesnap,
-    Boku,
-    Braintree,
-    Cashtocode,
-    Checkout,
-    Coinbase,
-    Cryptopay,
-    Cybersource,
-    Dlocal,
-    Ebanx,
-    Fiserv,
-    Forte,
-    Globalpay,
-    Globepay,
-    Gocardless,
-    Helcim,
-    Iatapay,
-    Klarna,
-    Mollie,
-    Multisafepay,
-    Netcetera,
-    Nexinets,
-    Nmi,
-    Noon,
-    Nuvei,
-    // Opayo, added as template code for future usage
-    Opennode,
-    // Payeezy, As psync and rsync are not supported by this connector, it is added as template code for future usage
-    Payme,
-    Paypal,
-    Payu,
-    Placetopay,
-    Powertranz,
-    Prophetpay,
-    Rapyd,
-    Shift4,
-    Square,
-    Stax,
-    Stripe,
-    Threedsecureio,
-    Trustpay,
-    // Tsys,
-    Tsys,
-    Volt,
-    Wise,
-    Worldline,
-    Worldpay,
-    Signifyd,
-    Plaid,
-    Riskified,
-    Zen,
-    Zsl,
-}
+include!(concat!(env!("OUT_DIR"), "/connector.rs"));
 
 impl Connector {
     #[cfg(feature = "payouts")]
     pub fn supports_instant_payout(&self, payout_method: PayoutType) -> bool {
         matches!(
             (self, payout_method),
-            (Self::Paypal, PayoutType::Wallet) | (_, PayoutType::Card)
+            (Self::Paypal, PayoutType::Wallet)
+                | (Self::Payoneer, PayoutType::Wallet)
+                | (_, PayoutType::Card)
         )
     }
     #[cfg(feature = "payouts")]
@@ -148,11 +43,14 @@ impl Connector {
     }
     #[cfg(feature = "payouts")]
     pub fn supports_payout_eligibility(&self, payout_method: PayoutType) -> bool {
-        matches!((self, payout_method), (_, PayoutType::Card))
+        matches!(
+            (self, payout_method),
+            (Self::Payoneer, PayoutType::Wallet) | (_, PayoutType::Card)
+        )
     }
     #[cfg(feature = "payouts")]
     pub fn supports_access_token_for_payout(&self, payout_method: PayoutType) -> bool {
-        matches!((self, payout_method), (Self::Paypal, _))
+        matches!((self, payout_method), (Self::Paypal, _) | (Self::Payoneer, _))
     }
     #[cfg(feature = "payouts")]
     pub fn supports_vendor_disburse_account_create_for_payout(&self) -> bool {
@@ -188,6 +86,8 @@ impl Connector {
             | Self::DummyConnector7 => false,
             Self::Aci
             | Self::Adyen
+            | Self::Affirm
+            | Self::Afterpay
             | Self::Airwallex
             | Self::Authorizedotnet
             | Self::Bambora
@@ -216,6 +116,7 @@ impl Connector {
             | Self::Nuvei
             | Self::Opennode
             | Self::Payme
+            | Self::Payoneer
             | Self::Paypal
             | Self::Payu
             | Self::Placetopay
@@ -238,15 +139,16 @@ impl Connector {
             | Self::Riskified
             | Self::Threedsecureio
             | Self::Netcetera
-            | Self::Cybersource
             | Self::Noon
             | Self::Stripe => false,
-            Self::Checkout | Self::Nmi => true,
+            Self::Checkout | Self::Cybersource | Self::Nmi => true,
         }
         #[cfg(not(feature = "dummy_connector"))]
         match self {
             Self::Aci
             | Self::Adyen
+            | Self::Affirm
+            | Self::Afterpay
             | Self::Airwallex
             | Self::Authorizedotnet
             | Self::Bambora
@@ -276,6 +178,7 @@ impl Connector {
             | Self::Nuvei
             | Self::Opennode
             | Self::Payme
+            | Self::Payoneer
             | Self::Paypal
             | Self::Payu
             | Self::Placetopay
@@ -297,11 +200,10 @@ impl Connector {
             | Self::Plaid
             | Self::Riskified
             | Self::Threedsecureio
-            | Self::Cybersource
             | Self::Noon
             | Self::Netcetera
             | Self::Stripe => false,
-            Self::Checkout => true,
+            Self::Checkout | Self::Cybersource => true,
         }
     }
 }
@@ -348,6 +250,8 @@ pub enum PayoutConnectors {
     Wise,
     Paypal,
     Ebanx,
+    Nuvei,
+    Payoneer,
 }
 
 #[cfg(feature = "payouts")]
@@ -359,6 +263,8 @@ impl From<PayoutConnectors> for RoutableConnectors {
             PayoutConnectors::Wise => Self::Wise,
             PayoutConnectors::Paypal => Self::Paypal,
             PayoutConnectors::Ebanx => Self::Ebanx,
+            PayoutConnectors::Nuvei => Self::Nuvei,
+            PayoutConnectors::Payoneer => Self::Payoneer,
         }
     }
 }
@@ -372,6 +278,8 @@ impl From<PayoutConnectors> for Connector {
             PayoutConnectors::Wise => Self::Wise,
             PayoutConnectors::Paypal => Self::Paypal,
             PayoutConnectors::Ebanx => Self::Ebanx,
+            PayoutConnectors::Nuvei => Self::Nuvei,
+            PayoutConnectors::Payoneer => Self::Payoneer,
         }
     }
 }
@@ -386,6 +294,8 @@ impl TryFrom<Connector> for PayoutConnectors {
             Connector::Wise => Ok(Self::Wise),
             Connector::Paypal => Ok(Self::Paypal),
             Connector::Ebanx => Ok(Self::Ebanx),
+            Connector::Nuvei => Ok(Self::Nuvei),
+            Connector::Payoneer => Ok(Self::Payoneer),
             _ => Err(format!("Invalid payout connector {}", value)),
         }
     }
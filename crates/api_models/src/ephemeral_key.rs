@@ -1,7 +1,8 @@
+use masking::MaskedDebug;
 use serde;
 use utoipa::ToSchema;
 
-#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, Eq, PartialEq, ToSchema)]
+#[derive(MaskedDebug, serde::Serialize, serde::Deserialize, Clone, Eq, PartialEq, ToSchema)]
 pub struct EphemeralKeyCreateResponse {
     /// customer_id to which this ephemeral key belongs to
     pub customer_id: String,
@@ -10,5 +11,6 @@ pub struct EphemeralKeyCreateResponse {
     /// time at which this ephemeral key would expire
     pub expires: i64,
     /// ephemeral key
+    #[masked]
     pub secret: String,
 }
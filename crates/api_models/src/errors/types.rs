@@ -94,6 +94,7 @@ pub enum ApiErrorResponse {
     NotFound(ApiError),
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
+    PayloadTooLarge(ApiError),
     DomainError(ApiError),
 }
 
@@ -123,6 +124,7 @@ impl ApiErrorResponse {
             | Self::NotFound(i)
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
+            | Self::PayloadTooLarge(i)
             | Self::DomainError(i)
             | Self::ConnectorError(i, _) => i,
         }
@@ -141,6 +143,7 @@ impl ApiErrorResponse {
             | Self::NotFound(i)
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
+            | Self::PayloadTooLarge(i)
             | Self::DomainError(i)
             | Self::ConnectorError(i, _) => i,
         }
@@ -157,7 +160,8 @@ impl ApiErrorResponse {
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
             | Self::NotFound(_)
-            | Self::BadRequest(_) => "invalid_request",
+            | Self::BadRequest(_)
+            | Self::PayloadTooLarge(_) => "invalid_request",
             Self::InternalServerError(_) => "api",
             Self::DomainError(_) => "blocked",
             Self::ConnectorError(_, _) => "connector",
@@ -42,6 +42,8 @@ impl_misc_api_event_type!(
     PaymentsSessionResponse,
     PaymentMethodCreate,
     PaymentLinkInitiateRequest,
+    HostedCheckoutInitiateRequest,
+    PaymentLinkCreateRequest,
     RetrievePaymentLinkResponse,
     MandateListConstraints,
     CreateFileResponse,
@@ -60,6 +62,8 @@ impl_misc_api_event_type!(
     RevokeApiKeyResponse,
     ToggleKVResponse,
     ToggleKVRequest,
+    MerchantKeyCustodyRequest,
+    MerchantKeyCustodyResponse,
     MerchantAccountDeleteResponse,
     MerchantAccountUpdate,
     CardInfoResponse,
@@ -73,10 +77,13 @@ impl_misc_api_event_type!(
     MerchantAccountResponse,
     MerchantAccountListRequest,
     MerchantAccountCreate,
+    MerchantFeatureEntitlements,
     PaymentsSessionRequest,
     ApplepayMerchantVerificationRequest,
     ApplepayMerchantResponse,
     ApplepayVerifiedDomainsResponse,
+    ApplepayProfileDomainRegistrationRequest,
+    ApplepayProfileDomainRegistrationResponse,
     UpdateApiKeyRequest,
     GetApiEventFiltersRequest,
     ApiEventFiltersResponse,
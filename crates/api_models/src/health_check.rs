@@ -13,6 +13,17 @@ pub struct RouterHealthCheckResponse {
 
 impl common_utils::events::ApiEventMetric for RouterHealthCheckResponse {}
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DeploymentRoleHealthCheckResponse {
+    pub role: String,
+    pub database: bool,
+    pub redis: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outgoing_request: Option<bool>,
+}
+
+impl common_utils::events::ApiEventMetric for DeploymentRoleHealthCheckResponse {}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SchedulerHealthCheckResponse {
     pub database: bool,
@@ -5,8 +5,10 @@ pub mod api_keys;
 pub mod blocklist;
 pub mod cards_info;
 pub mod conditional_configs;
+pub mod connector_migration;
 pub mod connector_onboarding;
 pub mod currency;
+pub mod customer_data_export;
 pub mod customers;
 pub mod disputes;
 pub mod enums;
@@ -31,6 +33,7 @@ pub mod recon;
 pub mod refunds;
 pub mod routing;
 pub mod surcharge_decision_configs;
+pub mod usage;
 pub mod user;
 pub mod user_role;
 pub mod verifications;
@@ -43,6 +43,8 @@ pub struct MandateResponse {
     /// Details about the customer’s acceptance
     #[schema(value_type = Option<CustomerAcceptance>)]
     pub customer_acceptance: Option<payments::CustomerAcceptance>,
+    /// The number of days in advance that the customer is notified of each debit
+    pub pre_notification_days: Option<u8>,
 }
 
 #[derive(Default, Debug, Deserialize, Serialize, ToSchema, Clone)]
@@ -7,6 +7,7 @@ use common_utils::{
     pii,
     types::{Percentage, Surcharge},
 };
+use masking::MaskedDebug;
 use serde::de;
 use utoipa::{schema, ToSchema};
 
@@ -17,7 +18,7 @@ use crate::{
     payments::{self, BankCodeResponse},
 };
 
-#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[derive(MaskedDebug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PaymentMethodCreate {
     /// The type of payment method use for the payment.
@@ -70,6 +71,7 @@ pub struct PaymentMethodCreate {
     /// in order to call /payment_methods
     /// Client secret will be generated whenever a new
     /// payment method is created
+    #[masked]
     pub client_secret: Option<String>,
 
     /// Payment method data to be passed in case of client
@@ -77,7 +79,7 @@ pub struct PaymentMethodCreate {
     pub payment_method_data: Option<PaymentMethodCreateData>,
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[derive(MaskedDebug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PaymentMethodUpdate {
     /// Card Details
@@ -108,6 +110,7 @@ pub struct PaymentMethodUpdate {
 
     /// This is a 15 minute expiry token which shall be used from the client to authenticate and perform sessions from the SDK
     #[schema(max_length = 30, min_length = 30, example = "secret_k2uj3he2893eiu2d")]
+    #[masked]
     pub client_secret: Option<String>,
 }
 
@@ -205,7 +208,7 @@ impl CardDetailUpdate {
     }
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(MaskedDebug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentMethodResponse {
     /// Unique identifier for a merchant
     #[schema(example = "merchant_1671528864")]
@@ -263,6 +266,7 @@ pub struct PaymentMethodResponse {
     pub last_used_at: Option<time::PrimitiveDateTime>,
 
     /// For Client based calls
+    #[masked]
     pub client_secret: Option<String>,
 }
 
@@ -295,6 +299,20 @@ pub struct PaymentMethodDataBankCreds {
     pub account_name: Option<String>,
     pub payment_method_type: api_enums::PaymentMethodType,
     pub connector_details: Vec<BankAccountConnectorDetails>,
+    #[serde(default)]
+    pub verification_status: Option<BankAccountVerificationStatus>,
+}
+
+/// Outcome of the checks run against a bank account through the payment method auth connector
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BankAccountVerificationStatus {
+    /// Account and routing details were retrieved and the balance covered the last debit
+    Verified,
+    /// Balance of the account was lower than the amount of the debit
+    InsufficientBalance,
+    /// Balance of the account could not be checked against the amount of the debit
+    Failed,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -302,6 +320,8 @@ pub struct BankAccountTokenData {
     pub payment_method_type: api_enums::PaymentMethodType,
     pub payment_method: api_enums::PaymentMethod,
     pub connector_details: BankAccountConnectorDetails,
+    #[serde(default)]
+    pub payment_method_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -487,6 +507,10 @@ pub struct ResponsePaymentMethodTypes {
 
     /// auth service connector label for this payment method type, if exists
     pub pm_auth_connector: Option<String>,
+
+    /// The installment plans the payment can be paid in with the payment method type, if
+    /// applicable for a payment method type
+    pub installment_options: Option<Vec<InstallmentOption>>,
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
@@ -576,6 +600,7 @@ pub struct ResponsePaymentMethodIntermediate {
     pub card_networks: Option<Vec<api_enums::CardNetwork>>,
     pub payment_method: api_enums::PaymentMethod,
     pub connector: String,
+    pub installment_plans: Option<Vec<InstallmentPlan>>,
 }
 
 impl ResponsePaymentMethodIntermediate {
@@ -588,6 +613,7 @@ impl ResponsePaymentMethodIntermediate {
             payment_method_type: pm_type.payment_method_type,
             payment_experience: pm_type.payment_experience,
             card_networks: pm_type.card_networks,
+            installment_plans: pm_type.installment_plans,
             payment_method: pm,
             connector,
         }
@@ -636,14 +662,204 @@ pub struct RequestPaymentMethodTypes {
     /// Boolean to enable installment / EMI / BNPL payments. Default is true.
     #[schema(default = true, example = false)]
     pub installment_payment_enabled: bool,
+
+    /// The installment plans the connector offers for the payments with the payment method type,
+    /// which are listed as the installment options of the payment methods list
+    pub installment_plans: Option<Vec<InstallmentPlan>>,
+}
+
+/// An installment / EMI plan the connector offers, in which the amount of the payment is paid in
+/// a number of installments
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq, Hash)]
+#[serde(deny_unknown_fields)]
+pub struct InstallmentPlan {
+    /// The number of installments the amount of the payment is paid in
+    #[schema(example = 6)]
+    pub tenure: u8,
+
+    /// The interest charged on the amount of the payment over the tenure, in basis points
+    #[serde(default)]
+    #[schema(default = 0, example = 150)]
+    pub interest_rate_in_basis_points: u16,
+
+    /// The BINs of the cards the plan is offered for, such as the BINs of the issuers running the
+    /// plan. The plan is offered for all the cards if no BINs are listed.
+    #[schema(example = json!(["424242", "51234567"]))]
+    pub card_bins: Option<Vec<String>>,
+
+    /// Minimum amount of the payments the plan is offered for, in the lowest denomination of
+    /// their currency
+    #[schema(example = 10000)]
+    pub minimum_amount: Option<i64>,
+}
+
+impl InstallmentPlan {
+    /// The plans restricted to BINs are offered only when the card of the customer is known to
+    /// start with one of them
+    pub fn is_offered_for(&self, amount: i64, card_bin: Option<&str>) -> bool {
+        let is_amount_allowed = self
+            .minimum_amount
+            .map_or(true, |minimum_amount| amount >= minimum_amount);
+        let is_card_bin_allowed = self.card_bins.as_ref().map_or(true, |card_bins| {
+            card_bin.is_some_and(|card_bin| {
+                card_bins
+                    .iter()
+                    .any(|plan_card_bin| card_bin.starts_with(plan_card_bin.as_str()))
+            })
+        });
+
+        amount > 0 && is_amount_allowed && is_card_bin_allowed
+    }
+
+    /// The installments of the plan for the amount, the interest and the installments being
+    /// rounded up to the lowest denomination of the currency
+    pub fn get_installment_option(&self, connector: String, amount: i64) -> InstallmentOption {
+        let interest_amount = amount
+            .saturating_mul(i64::from(self.interest_rate_in_basis_points))
+            .saturating_add(9999)
+            / 10000;
+        let total_amount = amount.saturating_add(interest_amount);
+        let tenure = i64::from(self.tenure.max(1));
+
+        InstallmentOption {
+            connector,
+            tenure: self.tenure,
+            interest_rate_in_basis_points: self.interest_rate_in_basis_points,
+            installment_amount: total_amount.saturating_add(tenure - 1) / tenure,
+            total_amount,
+        }
+    }
+}
+
+/// An installment plan the payment can be paid in, which is chosen by passing its tenure as the
+/// `installment_tenure` of the payment
+#[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
+pub struct InstallmentOption {
+    /// The connector offering the plan
+    #[schema(example = "adyen")]
+    pub connector: String,
+
+    /// The number of installments the amount of the payment is paid in
+    #[schema(example = 6)]
+    pub tenure: u8,
+
+    /// The interest charged on the amount of the payment over the tenure, in basis points
+    #[schema(example = 150)]
+    pub interest_rate_in_basis_points: u16,
+
+    /// The amount of each installment, in the lowest denomination of the currency
+    #[schema(example = 1692)]
+    pub installment_amount: i64,
+
+    /// The amount paid over all the installments, including the interest
+    #[schema(example = 10150)]
+    pub total_amount: i64,
+}
+
+/// Rules configured by the merchant which the order must satisfy for a payment method type to be
+/// listed for it, whichever connector the payment method type is enabled for
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodEligibilityRules {
+    /// The payment method types are listed if the order satisfies any of their rules, the
+    /// payment method types without rules are always listed
+    pub rules: Vec<PaymentMethodEligibilityRule>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodEligibilityRule {
+    #[schema(value_type = PaymentMethodType, example = "klarna")]
+    pub payment_method_type: api_enums::PaymentMethodType,
+
+    /// Minimum amount of the order, in the lowest denomination of its currency
+    #[schema(example = 1000)]
+    pub minimum_amount: Option<i64>,
+
+    /// Maximum amount of the order, in the lowest denomination of its currency
+    #[schema(example = 100000)]
+    pub maximum_amount: Option<i64>,
+
+    /// Currencies the order can be in
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["EUR", "GBP"]))]
+    pub currencies: Option<Vec<api_enums::Currency>>,
+
+    /// Countries of the billing address of the order, or of its shipping address if it has no
+    /// billing address
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["DE", "GB"]))]
+    pub countries: Option<Vec<api_enums::CountryAlpha2>>,
+
+    /// Type of the customer the order is placed by
+    #[schema(value_type = Option<EligibilityCustomerType>)]
+    pub customer_type: Option<EligibilityCustomerType>,
+}
+
+/// Whether the order is placed by a customer known to the merchant or by a guest
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EligibilityCustomerType {
+    Guest,
+    Registered,
+}
+
+/// Details of the order the eligibility rules are evaluated against
+#[derive(Debug, Clone, Copy)]
+pub struct EligibilityContext {
+    pub amount: i64,
+    pub currency: Option<api_enums::Currency>,
+    pub country: Option<api_enums::CountryAlpha2>,
+    pub customer_type: EligibilityCustomerType,
+}
+
+impl PaymentMethodEligibilityRule {
+    /// The constraints on details of the order which are not known are not satisfied
+    pub fn is_satisfied_by(&self, context: &EligibilityContext) -> bool {
+        let is_amount_in_range = self
+            .minimum_amount
+            .map_or(true, |minimum_amount| context.amount >= minimum_amount)
+            && self
+                .maximum_amount
+                .map_or(true, |maximum_amount| context.amount <= maximum_amount);
+        let is_currency_allowed = self.currencies.as_ref().map_or(true, |currencies| {
+            context
+                .currency
+                .is_some_and(|currency| currencies.contains(&currency))
+        });
+        let is_country_allowed = self.countries.as_ref().map_or(true, |countries| {
+            context
+                .country
+                .is_some_and(|country| countries.contains(&country))
+        });
+        let is_customer_type_allowed = self
+            .customer_type
+            .map_or(true, |customer_type| customer_type == context.customer_type);
+
+        is_amount_in_range && is_currency_allowed && is_country_allowed && is_customer_type_allowed
+    }
+}
+
+impl PaymentMethodEligibilityRules {
+    pub fn is_eligible(
+        &self,
+        payment_method_type: api_enums::PaymentMethodType,
+        context: &EligibilityContext,
+    ) -> bool {
+        let mut rules = self
+            .rules
+            .iter()
+            .filter(|rule| rule.payment_method_type == payment_method_type)
+            .peekable();
+        rules.peek().is_none() || rules.any(|rule| rule.is_satisfied_by(context))
+    }
 }
 
 //List Payment Method
-#[derive(Debug, Clone, serde::Serialize, Default, ToSchema)]
+#[derive(MaskedDebug, Clone, serde::Serialize, Default, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PaymentMethodListRequest {
     /// This is a 15 minute expiry token which shall be used from the client to authenticate and perform sessions from the SDK
     #[schema(max_length = 30, min_length = 30, example = "secret_k2uj3he2893eiu2d")]
+    #[masked]
     pub client_secret: Option<String>,
 
     /// The two-letter ISO currency code
@@ -673,6 +889,11 @@ pub struct PaymentMethodListRequest {
     /// Indicates the limit of last used payment methods
     #[schema(example = 1)]
     pub limit: Option<i64>,
+
+    /// The first 6 to 8 digits of the card of the customer, for which the installment plans
+    /// restricted to the BINs of the card are listed
+    #[schema(example = "424242")]
+    pub card_bin: Option<String>,
 }
 
 impl<'de> serde::Deserialize<'de> for PaymentMethodListRequest {
@@ -744,6 +965,13 @@ impl<'de> serde::Deserialize<'de> for PaymentMethodListRequest {
                         "limit" => {
                             set_or_reject_duplicate(&mut output.limit, "limit", map.next_value()?)?;
                         }
+                        "card_bin" => {
+                            set_or_reject_duplicate(
+                                &mut output.card_bin,
+                                "card_bin",
+                                map.next_value()?,
+                            )?;
+                        }
                         _ => {}
                     }
                 }
@@ -944,6 +1172,11 @@ pub struct CustomerPaymentMethod {
     #[schema(example = true)]
     pub requires_cvv: bool,
 
+    /// Whether the CVC of this saved card must be collected and sent in `card_cvc` with the
+    /// payment token to confirm the payment, as required by the business profile of the payment
+    #[schema(example = false)]
+    pub requires_cvc: bool,
+
     ///  A timestamp (ISO 8601 code) that determines when the payment method was last used
     #[schema(value_type = Option<PrimitiveDateTime>,example = "2024-02-24T11:04:09.922Z")]
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
@@ -956,6 +1189,8 @@ pub struct CustomerPaymentMethod {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct MaskedBankDetails {
     pub mask: String,
+    /// Outcome of the last verification run against the bank account
+    pub verification_status: Option<BankAccountVerificationStatus>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -1080,3 +1315,117 @@ pub struct TokenizedBankRedirectValue1 {
 pub struct TokenizedBankRedirectValue2 {
     pub customer_id: Option<String>,
 }
+
+#[cfg(test)]
+mod eligibility_rules_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_is_eligible() {
+        let rules = serde_json::from_value::<PaymentMethodEligibilityRules>(serde_json::json!({
+            "rules": [
+                {
+                    "payment_method_type": "klarna",
+                    "minimum_amount": 1000,
+                    "maximum_amount": 100000,
+                    "currencies": ["EUR"],
+                    "countries": ["DE"],
+                    "customer_type": "registered"
+                },
+                {
+                    "payment_method_type": "klarna",
+                    "currencies": ["GBP"]
+                }
+            ]
+        }))
+        .unwrap();
+        let context = EligibilityContext {
+            amount: 5000,
+            currency: Some(api_enums::Currency::EUR),
+            country: Some(api_enums::CountryAlpha2::DE),
+            customer_type: EligibilityCustomerType::Registered,
+        };
+
+        assert!(rules.is_eligible(api_enums::PaymentMethodType::Klarna, &context));
+        assert!(rules.is_eligible(api_enums::PaymentMethodType::Credit, &context));
+        assert!(!rules.is_eligible(
+            api_enums::PaymentMethodType::Klarna,
+            &EligibilityContext {
+                amount: 500,
+                ..context
+            }
+        ));
+        assert!(!rules.is_eligible(
+            api_enums::PaymentMethodType::Klarna,
+            &EligibilityContext {
+                customer_type: EligibilityCustomerType::Guest,
+                country: None,
+                ..context
+            }
+        ));
+        assert!(rules.is_eligible(
+            api_enums::PaymentMethodType::Klarna,
+            &EligibilityContext {
+                amount: 500,
+                currency: Some(api_enums::Currency::GBP),
+                ..context
+            }
+        ));
+    }
+}
+
+#[cfg(test)]
+mod installment_plans_tests {
+    use super::*;
+
+    #[test]
+    fn test_is_offered_for() {
+        let plan = InstallmentPlan {
+            tenure: 6,
+            interest_rate_in_basis_points: 0,
+            card_bins: Some(vec!["424242".to_string(), "51234567".to_string()]),
+            minimum_amount: Some(10000),
+        };
+
+        assert!(plan.is_offered_for(10000, Some("42424242")));
+        assert!(plan.is_offered_for(20000, Some("51234567")));
+        assert!(!plan.is_offered_for(9999, Some("424242")));
+        assert!(!plan.is_offered_for(10000, Some("512345")));
+        assert!(!plan.is_offered_for(10000, None));
+        assert!(InstallmentPlan {
+            card_bins: None,
+            ..plan.clone()
+        }
+        .is_offered_for(10000, None));
+    }
+
+    #[test]
+    fn test_get_installment_option() {
+        let plan = InstallmentPlan {
+            tenure: 6,
+            interest_rate_in_basis_points: 150,
+            card_bins: None,
+            minimum_amount: None,
+        };
+
+        assert_eq!(
+            plan.get_installment_option("adyen".to_string(), 10000),
+            InstallmentOption {
+                connector: "adyen".to_string(),
+                tenure: 6,
+                interest_rate_in_basis_points: 150,
+                installment_amount: 1692,
+                total_amount: 10150,
+            }
+        );
+        let option = InstallmentPlan {
+            tenure: 3,
+            interest_rate_in_basis_points: 0,
+            ..plan
+        }
+        .get_installment_option("nuvei".to_string(), 1000);
+        assert_eq!(option.total_amount, 1000);
+        assert_eq!(option.installment_amount, 334);
+    }
+}
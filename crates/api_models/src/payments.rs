@@ -10,8 +10,9 @@ use common_utils::{
     crypto,
     ext_traits::{ConfigExt, Encode},
     pii::{self, Email},
+    types::ExpiresAt,
 };
-use masking::{PeekInterface, Secret};
+use masking::{MaskedDebug, PeekInterface, Secret};
 use router_derive::Setter;
 use serde::{
     de::{self, Unexpected, Visitor},
@@ -62,9 +63,10 @@ pub struct BankCodeResponse {
     pub eligible_connectors: Vec<String>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(MaskedDebug, PartialEq)]
 pub struct ClientSecret {
     pub payment_id: String,
+    #[masked]
     pub secret: String,
 }
 
@@ -200,7 +202,7 @@ pub struct CustomerDetails {
 
 #[derive(
     Default,
-    Debug,
+    MaskedDebug,
     serde::Deserialize,
     serde::Serialize,
     Clone,
@@ -325,6 +327,7 @@ pub struct PaymentsRequest {
 
     /// Provide a reference to a stored payment method
     #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
+    #[masked]
     pub payment_token: Option<String>,
 
     /// This is used along with the payment_token field while collecting during saved card payments. This field will be deprecated soon, use the payment_method_data.card_token object instead
@@ -354,6 +357,7 @@ pub struct PaymentsRequest {
     /// It's a token used for client side verification.
     #[schema(example = "pay_U42c409qyHwOkWo3vK60_secret_el9ksDkiB8hi6j9N78yo")]
     #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest)]
+    #[masked]
     pub client_secret: Option<String>,
 
     /// Passing this object during payments creates a mandate. The mandate_type sub object is passed by the server.
@@ -446,13 +450,57 @@ pub struct PaymentsRequest {
     #[schema(value_type = Option<RequestSurchargeDetails>)]
     pub surcharge_details: Option<RequestSurchargeDetails>,
 
-    /// The type of the payment that differentiates between normal and various types of mandate payments
+    /// The type of the payment that differentiates between normal and various types of mandate payments.
+    /// Payments of zero amount which are not paid with a mandate verify the payment method with the connector and save it if `setup_future_usage` is set, instead of authorizing a zero amount
     #[schema(value_type = Option<PaymentType>)]
     pub payment_type: Option<api_enums::PaymentType>,
 
     ///Request for an incremental authorization
     pub request_incremental_authorization: Option<bool>,
 
+    /// Request for capturing more than the authorized amount, if supported by the connector
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    #[schema(example = true)]
+    pub request_overcapture: Option<bool>,
+
+    /// Request for extending the validity of the authorization beyond the default of the card
+    /// network, such as the 30 days of visa for lodging and travel merchants, if supported by the
+    /// connector. The extension is applied only when the card network and the merchant category of
+    /// the payment are eligible for it
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    #[schema(example = true)]
+    pub request_extended_authorization: Option<bool>,
+
+    /// Split of the amount of the payment between the platform and its sub-merchants, if supported
+    /// by the connector
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub split_payments: Option<SplitPayments>,
+
+    /// The channel through which the customer has provided their payment details. Mail order /
+    /// telephone order payments are not authenticated with 3DS, and require the merchant to be
+    /// entitled to them.
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    #[schema(value_type = Option<PaymentChannel>, example = "moto")]
+    pub payment_channel: Option<api_enums::PaymentChannel>,
+
+    /// Level 2 / level 3 data of a card payment, such as the tax amount, the purchase order number
+    /// and the line items, which is forwarded to the connectors supporting it so that the payment
+    /// qualifies for lower interchange rates
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub l2_l3_data: Option<L2L3Data>,
+
+    /// Free-form tags of the payment, such as the campaign or the sales channel it belongs to, which
+    /// the payments can be listed by. The tags can be replaced later through the tags update API
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    #[schema(max_items = 20, example = json!(["black_friday", "web"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// The number of installments the card payment is paid in, which chooses one of the
+    /// installment options of the payment methods list. The payment is paid at once if no tenure
+    /// is chosen.
+    #[schema(minimum = 2, maximum = 60, example = 6)]
+    pub installment_tenure: Option<u8>,
+
     ///Will be used to expire client secret after certain amount of time to be supplied in seconds
     ///(900) for 15 mins
     #[schema(example = 900)]
@@ -465,6 +513,11 @@ pub struct PaymentsRequest {
     #[schema(example = true)]
     pub request_external_three_ds_authentication: Option<bool>,
 
+    /// The result of the 3DS authentication of the card payment which the merchant has run with
+    /// their own 3DS provider, which is passed to the connector so that the payment is only
+    /// authorized. It can only be given when the payment is confirmed.
+    pub external_three_ds_authentication_data: Option<ExternalThreeDsAuthenticationData>,
+
     /// Details required for recurring payment
     pub recurring_details: Option<RecurringDetails>,
 }
@@ -479,6 +532,152 @@ impl PaymentsRequest {
             .map(|amount| i64::from(amount) + surcharge_amount)
     }
 }
+
+/// Split of the amount of a payment of a marketplace between the fee kept by the platform and the
+/// transfers to the connected accounts of its sub-merchants
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SplitPayments {
+    /// The fee kept by the platform, in the lowest denomination of the currency. Any amount added
+    /// to the payment after its creation, such as a surcharge, is also kept by the platform
+    #[schema(example = 500)]
+    pub platform_fee: i64,
+
+    /// The transfers to the sub-merchants, which add up to the amount of the payment along with
+    /// the platform fee
+    pub transfers: Vec<SplitPaymentTransfer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SplitPaymentTransfer {
+    /// The account of the sub-merchant with the connector, such as the connected account with
+    /// Stripe or the balance account with Adyen
+    #[schema(example = "acct_1NcUqnDBtvAbbJWv")]
+    pub account_id: String,
+
+    /// The amount transferred to the sub-merchant, in the lowest denomination of the currency
+    #[schema(example = 6040)]
+    pub amount: i64,
+
+    /// Reference of the transfer in the reports of the connector
+    #[schema(example = "order_1234_seller_42")]
+    pub reference: Option<String>,
+}
+
+impl SplitPayments {
+    /// The total amount transferred to the sub-merchants
+    pub fn get_transferred_amount(&self) -> i64 {
+        self.transfers.iter().map(|transfer| transfer.amount).sum()
+    }
+}
+
+/// Level 2 / level 3 data of a card payment. The amounts are in the lowest denomination of the
+/// currency and are included in the amount of the payment
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct L2L3Data {
+    /// The purchase order number the customer has provided for the order
+    #[schema(max_length = 25, example = "PO-2024-00042")]
+    pub purchase_order_number: Option<String>,
+
+    /// The tax on the order. The order tax amount of the payment is used if it is not provided
+    #[schema(example = 350)]
+    pub tax_amount: Option<i64>,
+
+    /// The discount applied to the order
+    #[schema(example = 100)]
+    pub discount_amount: Option<i64>,
+
+    /// The shipping cost of the order
+    #[schema(example = 500)]
+    pub shipping_amount: Option<i64>,
+
+    /// The duty on the order
+    #[schema(example = 0)]
+    pub duty_amount: Option<i64>,
+
+    /// The postal code of the location the order is shipped from
+    #[schema(max_length = 10, example = "94105")]
+    pub ship_from_postal_code: Option<String>,
+
+    /// The items of the order
+    pub line_items: Option<Vec<L3LineItem>>,
+}
+
+/// An item of the order of a card payment, for level 3 data
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct L3LineItem {
+    /// The name of the product
+    #[schema(max_length = 35, example = "Printer paper")]
+    pub product_name: String,
+
+    /// The number of units of the product
+    #[schema(example = 2)]
+    pub quantity: u16,
+
+    /// The price of a unit of the product
+    #[schema(example = 1250)]
+    pub unit_amount: i64,
+
+    /// The commodity code of the product, which classifies the goods or services
+    #[schema(max_length = 12, example = "44121618")]
+    pub commodity_code: Option<String>,
+
+    /// The code of the product in the catalog of the merchant
+    #[schema(max_length = 12, example = "SKU-1029")]
+    pub product_code: Option<String>,
+
+    /// The unit the quantity of the product is measured in
+    #[schema(max_length = 12, example = "EA")]
+    pub unit_of_measure: Option<String>,
+
+    /// The tax on the item
+    #[schema(example = 175)]
+    pub tax_amount: Option<i64>,
+
+    /// The discount applied to the item
+    #[schema(example = 0)]
+    pub discount_amount: Option<i64>,
+}
+
+impl L3LineItem {
+    /// The total amount of the item, including its tax and excluding its discount
+    pub fn get_total_amount(&self) -> i64 {
+        self.unit_amount * i64::from(self.quantity) + self.tax_amount.unwrap_or(0)
+            - self.discount_amount.unwrap_or(0)
+    }
+}
+
+/// The result of a 3DS authentication run outside of Hyperswitch, as returned by the 3DS server
+/// of the merchant
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExternalThreeDsAuthenticationData {
+    /// The cardholder authentication verification value (CAVV), or the accountholder
+    /// authentication value (AAV) for Mastercard, returned by the issuer
+    #[schema(value_type = String, example = "AAABBZV3cGEAAAAAJXdwYQAAAAA=")]
+    pub cavv: Secret<String>,
+
+    /// The electronic commerce indicator (ECI) of the authentication, which tells whether the
+    /// cardholder was authenticated or the authentication was only attempted
+    #[schema(max_length = 2, example = "05")]
+    pub eci: Option<String>,
+
+    /// The transaction ID the directory server has assigned to the authentication
+    #[schema(example = "f25084f0-5b16-4c0a-ae5d-b24808a95e4b")]
+    pub ds_transaction_id: String,
+
+    /// The transaction ID the 3DS server has assigned to the authentication
+    #[schema(example = "b5c3fcbd-4a41-4c47-9a4e-0b8c1e6b3b4c")]
+    pub three_ds_server_transaction_id: Option<String>,
+
+    /// The version of the 3DS protocol the authentication was run with
+    #[schema(value_type = String, example = "2.2.0")]
+    pub message_version: common_utils::types::SemanticVersion,
+}
+
 #[derive(
     Default, Debug, Clone, serde::Serialize, serde::Deserialize, Copy, ToSchema, PartialEq,
 )]
@@ -550,7 +749,14 @@ impl HeaderPayload {
 }
 
 #[derive(
-    Default, Debug, serde::Serialize, Clone, PartialEq, ToSchema, router_derive::PolymorphicSchema,
+    Default,
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    PartialEq,
+    ToSchema,
+    router_derive::PolymorphicSchema,
 )]
 pub struct PaymentAttemptResponse {
     /// Unique identifier for the attempt
@@ -601,10 +807,22 @@ pub struct PaymentAttemptResponse {
     pub unified_code: Option<String>,
     /// error message unified across the connectors is received here if there was an error while calling connector
     pub unified_message: Option<String>,
+    /// The identifier the connector assigned to its last request for the attempt, including when the request failed. Can be used to look up the request with the connector
+    #[schema(example = "req_LmT1hIjbyNnC9p")]
+    pub connector_request_id: Option<String>,
+    /// The surcharge the attempt was confirmed with, which is included in the amount charged
+    pub surcharge_details: Option<RequestSurchargeDetails>,
 }
 
 #[derive(
-    Default, Debug, serde::Serialize, Clone, PartialEq, ToSchema, router_derive::PolymorphicSchema,
+    Default,
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    PartialEq,
+    ToSchema,
+    router_derive::PolymorphicSchema,
 )]
 pub struct CaptureResponse {
     /// unique identifier for the capture
@@ -660,6 +878,30 @@ impl PaymentsRequest {
             .transpose()
     }
 
+    pub fn get_split_payments_as_value(
+        &self,
+    ) -> common_utils::errors::CustomResult<
+        Option<serde_json::Value>,
+        common_utils::errors::ParsingError,
+    > {
+        self.split_payments
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+    }
+
+    pub fn get_l2_l3_data_as_value(
+        &self,
+    ) -> common_utils::errors::CustomResult<
+        Option<serde_json::Value>,
+        common_utils::errors::ParsingError,
+    > {
+        self.l2_l3_data
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+    }
+
     pub fn get_allowed_payment_method_types_as_value(
         &self,
     ) -> common_utils::errors::CustomResult<
@@ -720,7 +962,7 @@ pub struct PaymentsRedirectRequest {
     pub param: String,
 }
 
-#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone)]
+#[derive(Default, MaskedDebug, serde::Deserialize, serde::Serialize, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct VerifyRequest {
     // The merchant_id is generated through api key
@@ -733,10 +975,12 @@ pub struct VerifyRequest {
     pub phone_country_code: Option<String>,
     pub payment_method: Option<api_enums::PaymentMethod>,
     pub payment_method_data: Option<PaymentMethodData>,
+    #[masked]
     pub payment_token: Option<String>,
     pub mandate_data: Option<MandateData>,
     pub setup_future_usage: Option<api_enums::FutureUsage>,
     pub off_session: Option<bool>,
+    #[masked]
     pub client_secret: Option<String>,
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
 }
@@ -794,6 +1038,10 @@ pub struct MandateData {
     pub customer_acceptance: Option<CustomerAcceptance>,
     /// A way to select the type of mandate used
     pub mandate_type: Option<MandateType>,
+    /// The number of days in advance that the customer is notified of each debit made using the
+    /// mandate. Defaults to 14 days for SEPA Direct Debit mandates
+    #[schema(example = 14)]
+    pub pre_notification_days: Option<u8>,
 }
 
 #[derive(Clone, Eq, PartialEq, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -899,7 +1147,8 @@ pub struct Card {
     #[schema(example = "chase")]
     pub card_issuer: Option<String>,
 
-    /// The card network for the card
+    /// The card network for the card. For co-badged cards, this is the network the payment is
+    /// authorized on, which defaults to the network the card is branded with.
     #[schema(value_type = Option<CardNetwork>, example = "Visa")]
     pub card_network: Option<api_enums::CardNetwork>,
 
@@ -1373,6 +1622,7 @@ mod payment_method_data_serde {
                     | PaymentMethodData::CardToken(_)
                     | PaymentMethodData::Crypto(_)
                     | PaymentMethodData::GiftCard(_)
+                    | PaymentMethodData::RealTimePayment(_)
                     | PaymentMethodData::PayLater(_)
                     | PaymentMethodData::Upi(_)
                     | PaymentMethodData::Voucher(_)
@@ -1430,6 +1680,8 @@ pub enum PaymentMethodData {
     Voucher(VoucherData),
     #[schema(title = "GiftCard")]
     GiftCard(Box<GiftCardData>),
+    #[schema(title = "RealTimePayment")]
+    RealTimePayment(Box<RealTimePaymentData>),
     #[schema(title = "CardToken")]
     CardToken(CardToken),
 }
@@ -1453,6 +1705,7 @@ impl GetAddressFromPaymentMethodData for PaymentMethodData {
             | Self::Reward
             | Self::Upi(_)
             | Self::GiftCard(_)
+            | Self::RealTimePayment(_)
             | Self::CardToken(_)
             | Self::MandatePayment => None,
         }
@@ -1490,6 +1743,7 @@ impl PaymentMethodData {
             Self::Upi(_) => Some(api_enums::PaymentMethod::Upi),
             Self::Voucher(_) => Some(api_enums::PaymentMethod::Voucher),
             Self::GiftCard(_) => Some(api_enums::PaymentMethod::GiftCard),
+            Self::RealTimePayment(_) => Some(api_enums::PaymentMethod::RealTimePayment),
             Self::CardToken(_) | Self::MandatePayment => None,
         }
     }
@@ -1530,6 +1784,7 @@ impl GetPaymentMethodType for WalletData {
             Self::MobilePayRedirect(_) => api_enums::PaymentMethodType::MobilePay,
             Self::PaypalRedirect(_) | Self::PaypalSdk(_) => api_enums::PaymentMethodType::Paypal,
             Self::SamsungPay(_) => api_enums::PaymentMethodType::SamsungPay,
+            Self::SkrillRedirect {} => api_enums::PaymentMethodType::Skrill,
             Self::TwintRedirect {} => api_enums::PaymentMethodType::Twint,
             Self::VippsRedirect {} => api_enums::PaymentMethodType::Vipps,
             Self::TouchNGoRedirect(_) => api_enums::PaymentMethodType::TouchNGo,
@@ -1538,6 +1793,7 @@ impl GetPaymentMethodType for WalletData {
             }
             Self::CashappQr(_) => api_enums::PaymentMethodType::Cashapp,
             Self::SwishQr(_) => api_enums::PaymentMethodType::Swish,
+            Self::ClickToPay(_) => api_enums::PaymentMethodType::ClickToPay,
         }
     }
 }
@@ -1677,6 +1933,29 @@ pub struct GiftCardDetails {
     pub cvc: Secret<String>,
 }
 
+impl GetPaymentMethodType for RealTimePaymentData {
+    fn get_payment_method_type(&self) -> api_enums::PaymentMethodType {
+        match self {
+            Self::PayNow {} => api_enums::PaymentMethodType::PayNow,
+            Self::PromptPay {} => api_enums::PaymentMethodType::PromptPay,
+            Self::UpiQr {} => api_enums::PaymentMethodType::UpiQr,
+        }
+    }
+}
+
+/// Real time payments are paid by the customer scanning the QR code returned in the
+/// `next_action` of the payment with their banking or UPI app
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RealTimePaymentData {
+    /// PayNow of Singapore
+    PayNow {},
+    /// PromptPay of Thailand
+    PromptPay {},
+    /// UPI of India, paid with a dynamic QR code
+    UpiQr {},
+}
+
 #[derive(Default, Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct AdditionalCardInfo {
@@ -1737,6 +2016,7 @@ pub enum AdditionalPaymentData {
     Voucher {},
     CardRedirect {},
     CardToken {},
+    RealTimePayment {},
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -2313,6 +2593,8 @@ pub enum WalletData {
     PaypalSdk(PayPalWalletData),
     /// The wallet data for Samsung Pay
     SamsungPay(Box<SamsungPayWalletData>),
+    /// Wallet data for Skrill Redirection
+    SkrillRedirect {},
     /// Wallet data for Twint Redirection
     TwintRedirect {},
     /// Wallet data for Vipps Redirection
@@ -2327,6 +2609,8 @@ pub enum WalletData {
     CashappQr(Box<CashappQr>),
     // The wallet data for Swish
     SwishQr(SwishQrData),
+    /// The wallet data for Click to Pay
+    ClickToPay(Box<ClickToPayWalletData>),
 }
 
 impl GetAddressFromPaymentMethodData for WalletData {
@@ -2369,23 +2653,83 @@ impl GetAddressFromPaymentMethodData for WalletData {
             | Self::GooglePayThirdPartySdk(_)
             | Self::PaypalSdk(_)
             | Self::SamsungPay(_)
+            | Self::SkrillRedirect {}
             | Self::TwintRedirect {}
             | Self::VippsRedirect {}
             | Self::TouchNGoRedirect(_)
             | Self::WeChatPayRedirect(_)
             | Self::WeChatPayQr(_)
             | Self::CashappQr(_)
-            | Self::SwishQr(_) => None,
+            | Self::SwishQr(_)
+            | Self::ClickToPay(_) => None,
         }
     }
 }
 
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ClickToPayWalletData {
+    /// The card network of the card the customer checked out with
+    #[schema(value_type = CardNetwork, example = "Visa")]
+    pub card_network: api_enums::CardNetwork,
+    /// Identifier of the Click to Pay checkout, returned by the card network on checkout
+    pub src_correlation_id: String,
+    /// Identifier of the card the customer checked out with, in their Click to Pay profile
+    pub src_digital_card_id: String,
+    /// Reference to the network token of the card, returned by the card network on checkout
+    pub src_token_reference: Option<String>,
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct SamsungPayWalletData {
-    /// The encrypted payment token from Samsung
+    /// The payment credential returned by the Samsung Pay SDK
+    pub payment_credential: SamsungPayWalletCredentials,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SamsungPayWalletCredentials {
+    /// The method with which the payment was authenticated
+    #[schema(example = "3DS")]
+    pub method: Option<String>,
+    /// Whether the payment is a recurring payment
+    pub recurring_payment: Option<bool>,
+    /// The brand of the card the customer paid with
+    pub card_brand: SamsungPayCardBrand,
+    /// The last four digits of the device specific card number
+    #[serde(rename = "card_last4digits")]
+    #[schema(example = "1234")]
+    pub card_last_four_digits: String,
+    /// The encrypted payment token
+    #[serde(rename = "3_d_s")]
+    pub token_data: SamsungPayTokenData,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SamsungPayTokenData {
+    /// The type of the payment token
+    #[serde(rename = "type")]
+    #[schema(example = "S")]
+    pub three_ds_type: Option<String>,
+    /// The version of the payment token
+    #[schema(example = "100")]
+    pub version: String,
+    /// The payment token, a JWE encrypted with the public key of the merchant
     #[schema(value_type = String)]
-    pub token: Secret<String>,
+    pub data: Secret<String>,
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SamsungPayCardBrand {
+    Visa,
+    MasterCard,
+    Amex,
+    Discover,
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -2473,9 +2817,10 @@ pub struct GooglePayPaymentMethodInfo {
     pub card_details: String,
 }
 
-#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Eq, PartialEq, Clone, MaskedDebug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PayPalWalletData {
     /// Token generated for the Apple pay
+    #[masked]
     pub token: String,
 }
 
@@ -2485,12 +2830,13 @@ pub struct TouchNGoRedirection {}
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct SwishQrData {}
 
-#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Eq, PartialEq, Clone, MaskedDebug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct GpayTokenizationData {
     /// The type of the token
     #[serde(rename = "type")]
     pub token_type: String,
     /// Token generated for the wallet
+    #[masked]
     pub token: String,
 }
 
@@ -2631,6 +2977,7 @@ where
             | PaymentMethodDataResponse::Crypto {}
             | PaymentMethodDataResponse::MandatePayment {}
             | PaymentMethodDataResponse::GiftCard {}
+            | PaymentMethodDataResponse::RealTimePayment {}
             | PaymentMethodDataResponse::PayLater {}
             | PaymentMethodDataResponse::Paypal {}
             | PaymentMethodDataResponse::Upi {}
@@ -2664,6 +3011,7 @@ pub enum PaymentMethodDataResponse {
     GiftCard {},
     CardRedirect {},
     CardToken {},
+    RealTimePayment {},
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, ToSchema, serde::Serialize)]
@@ -2861,6 +3209,29 @@ pub struct PaymentsCaptureRequest {
     /// Merchant connector details used to make payments.
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>)]
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
+    /// The reason the payment is captured for less than the amount authorized. It is required when the order details are adjusted, and requires the amount to capture to be less than the amount capturable.
+    #[schema(value_type = Option<CaptureAdjustmentReason>, example = "out_of_stock")]
+    pub capture_adjustment_reason: Option<api_enums::CaptureAdjustmentReason>,
+    /// The order details after the adjustment of the amount, which replace the order details of the payment and are sent to the connectors supporting them. They should not add up to more than the amount to capture.
+    pub order_details: Option<Vec<OrderDetailsWithAmount>>,
+}
+
+impl PaymentsCaptureRequest {
+    pub fn get_order_details_as_value(
+        &self,
+    ) -> common_utils::errors::CustomResult<
+        Option<Vec<pii::SecretSerdeValue>>,
+        common_utils::errors::ParsingError,
+    > {
+        self.order_details
+            .as_ref()
+            .map(|od| {
+                od.iter()
+                    .map(|order| order.encode_to_value().map(masking::Secret::new))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()
+    }
 }
 
 #[derive(Default, Clone, Debug, Eq, PartialEq, serde::Serialize)]
@@ -2881,9 +3252,10 @@ pub enum NextActionType {
     TriggerApi,
     DisplayBankTransferInformation,
     DisplayWaitScreen,
+    VerifyWithMicroDeposits,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum NextActionData {
     /// Contains the url for redirection flow
@@ -2899,7 +3271,9 @@ pub enum NextActionData {
         #[schema(value_type = String)]
         /// Hyperswitch generated image data source url
         image_data_url: Option<Url>,
-        display_to_timestamp: Option<i64>,
+        #[schema(value_type = Option<i64>, example = 1707091200000_i64)]
+        /// The time until which the QR code can be scanned, in milliseconds since the UNIX epoch
+        display_to_timestamp: Option<ExpiresAt>,
         #[schema(value_type = String)]
         /// The url for Qr code given by the connector
         qr_code_url: Option<Url>,
@@ -2916,9 +3290,13 @@ pub enum NextActionData {
     },
     /// Contains the information regarding three_ds_method_data submission, three_ds authentication, and authorization flows
     ThreeDsInvoke { three_ds_data: ThreeDsData },
+    /// Contains the details of the micro-deposits which have to be verified before the bank debit can be processed
+    VerifyWithMicroDeposits {
+        micro_deposits_details: MicroDepositsVerificationDetails,
+    },
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct ThreeDsData {
     /// ThreeDS authentication url - to initiate authentication
     pub three_ds_authentication_url: String,
@@ -2930,7 +3308,7 @@ pub struct ThreeDsData {
     pub poll_config: PollConfigResponse,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(tag = "three_ds_method_key")]
 pub enum ThreeDsMethodData {
     #[serde(rename = "threeDSMethodData")]
@@ -2944,7 +3322,7 @@ pub enum ThreeDsMethodData {
     },
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct PollConfigResponse {
     /// Poll Id
     pub poll_id: String,
@@ -2962,18 +3340,39 @@ pub enum QrCodeInformation {
     QrCodeUrl {
         image_data_url: Url,
         qr_code_url: Url,
-        display_to_timestamp: Option<i64>,
+        display_to_timestamp: Option<ExpiresAt>,
     },
     QrDataUrl {
         image_data_url: Url,
-        display_to_timestamp: Option<i64>,
+        display_to_timestamp: Option<ExpiresAt>,
     },
     QrCodeImageUrl {
         qr_code_url: Url,
-        display_to_timestamp: Option<i64>,
+        display_to_timestamp: Option<ExpiresAt>,
     },
 }
 
+impl QrCodeInformation {
+    /// The time until which the QR code can be scanned, if the connector has shared the expiry of
+    /// the QR code
+    pub fn get_display_to_timestamp(&self) -> Option<ExpiresAt> {
+        match self {
+            Self::QrCodeUrl {
+                display_to_timestamp,
+                ..
+            }
+            | Self::QrDataUrl {
+                display_to_timestamp,
+                ..
+            }
+            | Self::QrCodeImageUrl {
+                display_to_timestamp,
+                ..
+            } => *display_to_timestamp,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct BankTransferNextStepsData {
     /// The instructions for performing a bank transfer
@@ -2985,8 +3384,9 @@ pub struct BankTransferNextStepsData {
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct VoucherNextStepData {
-    /// Voucher expiry date and time
-    pub expires_at: Option<i64>,
+    /// Voucher expiry date and time, in milliseconds since the UNIX epoch
+    #[schema(value_type = Option<i64>, example = 1707091200000_i64)]
+    pub expires_at: Option<ExpiresAt>,
     /// Reference number required for the transaction
     pub reference: String,
     /// Url to download the payment instruction
@@ -2995,10 +3395,30 @@ pub struct VoucherNextStepData {
     pub instructions_url: Option<Url>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct MicroDepositsVerificationDetails {
+    /// Whether the amounts or the statement descriptor code of the micro-deposits are required to verify the bank account
+    pub micro_deposit_type: MicroDepositType,
+    /// The time by which the micro-deposits are expected to arrive in the bank account of the customer
+    pub arrival_date: Option<i64>,
+    /// Url of the page hosted by the connector where the customer can verify the micro-deposits
+    #[schema(value_type = Option<String>)]
+    pub hosted_verification_url: Option<Url>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MicroDepositType {
+    /// Two micro-deposits are made, their amounts have to be provided for verification
+    Amounts,
+    /// A single micro-deposit is made, the code in its statement descriptor has to be provided for verification
+    DescriptorCode,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct QrCodeNextStepsInstruction {
     pub image_data_url: Url,
-    pub display_to_timestamp: Option<i64>,
+    pub display_to_timestamp: Option<ExpiresAt>,
     pub qr_code_url: Option<Url>,
 }
 
@@ -3032,6 +3452,9 @@ pub struct SepaBankTransferInstructions {
     pub country: String,
     #[schema(value_type = String, example = "123456789")]
     pub iban: Secret<String>,
+    /// The reference to be quoted in the transfer, with which the credit is matched to the payment
+    #[schema(value_type = Option<String>, example = "TMRS-4RSQ-97AZ")]
+    pub reference: Option<Secret<String>>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -3054,8 +3477,8 @@ pub struct MultibancoTransferInstructions {
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct DokuBankTransferInstructions {
-    #[schema(value_type = String, example = "1707091200000")]
-    pub expires_at: Option<i64>,
+    #[schema(value_type = Option<i64>, example = 1707091200000_i64)]
+    pub expires_at: Option<ExpiresAt>,
     #[schema(value_type = String, example = "122385736258")]
     pub reference: Secret<String>,
     #[schema(value_type = String)]
@@ -3076,14 +3499,16 @@ pub struct AchTransfer {
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct ReceiverDetails {
     /// The amount received by receiver
-    amount_received: i64,
+    pub amount_received: i64,
     /// The amount charged by ACH
-    amount_charged: Option<i64>,
+    pub amount_charged: Option<i64>,
     /// The amount remaining to be sent via ACH
-    amount_remaining: Option<i64>,
+    pub amount_remaining: Option<i64>,
+    /// The amount received in excess of the amount of the payment, which is to be refunded to the customer
+    pub amount_overpaid: Option<i64>,
 }
 
-#[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct PaymentsResponse {
     /// Unique identifier for the payment. This ensures idempotency for multiple payments
     /// that have been done by a single merchant.
@@ -3120,6 +3545,15 @@ pub struct PaymentsResponse {
     #[schema(minimum = 100, example = 6540)]
     pub amount_received: Option<i64>,
 
+    /// The amount which is released from the authorization of the payment, when the uncaptured
+    /// remainder of a partially captured payment is reversed
+    #[schema(example = 1000)]
+    pub amount_reversed: Option<i64>,
+
+    /// The number of installments the payment is paid in, if it is paid in installments
+    #[schema(example = 6)]
+    pub installment_tenure: Option<u8>,
+
     /// The connector used for the payment
     #[schema(example = "stripe")]
     pub connector: Option<String>,
@@ -3349,6 +3783,43 @@ pub struct PaymentsResponse {
     /// List of incremental authorizations happened to the payment
     pub incremental_authorizations: Option<Vec<IncrementalAuthorizationResponse>>,
 
+    /// The amount captured above the authorized amount of the payment, if it was overcaptured
+    #[schema(example = 500)]
+    pub overcaptured_amount: Option<i64>,
+
+    /// Whether the validity of the authorization of the payment has been extended by the connector
+    #[schema(example = true)]
+    pub extended_authorization_applied: Option<bool>,
+
+    /// The time until which the authorization of the payment can be captured, as returned by the
+    /// connector
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub capture_before: Option<PrimitiveDateTime>,
+
+    /// The tax on the order calculated for the shipping address of the payment, which is included
+    /// in its amount
+    #[schema(example = 350)]
+    pub order_tax_amount: Option<i64>,
+
+    /// Split of the amount of the payment between the platform and its sub-merchants
+    pub split_payments: Option<SplitPayments>,
+
+    /// The channel through which the customer has provided their payment details
+    #[schema(value_type = Option<PaymentChannel>, example = "moto")]
+    pub payment_channel: Option<api_enums::PaymentChannel>,
+
+    /// Level 2 / level 3 data of the card payment
+    pub l2_l3_data: Option<L2L3Data>,
+
+    /// Free-form tags of the payment
+    #[schema(example = json!(["black_friday", "web"]))]
+    pub tags: Option<Vec<String>>,
+
+    /// The reason the payment has been captured for less than the amount authorized
+    #[schema(value_type = Option<CaptureAdjustmentReason>, example = "out_of_stock")]
+    pub capture_adjustment_reason: Option<api_enums::CaptureAdjustmentReason>,
+
     /// Details of external authentication
     pub external_authentication_details: Option<ExternalAuthenticationDetailsResponse>,
 
@@ -3380,7 +3851,7 @@ pub struct PaymentsResponse {
     pub updated: Option<PrimitiveDateTime>,
 }
 
-#[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct ExternalAuthenticationDetailsResponse {
     /// Authentication Type - Challenge / Frictionless
     #[schema(value_type = Option<DecoupledAuthenticationType>)]
@@ -3467,7 +3938,7 @@ pub struct PaymentListResponse {
     pub data: Vec<PaymentsResponse>,
 }
 
-#[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct IncrementalAuthorizationResponse {
     /// The unique identifier of authorization
     pub authorization_id: String,
@@ -3526,6 +3997,81 @@ pub struct PaymentListFilterConstraints {
     pub authentication_type: Option<Vec<enums::AuthenticationType>>,
     /// The list of merchant connector ids to filter payments list for selected label
     pub merchant_connector_id: Option<Vec<String>>,
+    /// The list of tags which the payments should all be tagged with
+    pub tags: Option<Vec<String>>,
+    /// The name of a saved filter of the merchant to filter payments list with, over which the
+    /// other constraints of the request are applied
+    pub saved_filter: Option<String>,
+}
+
+impl PaymentListFilterConstraints {
+    /// Apply the constraints over the ones of a saved filter, keeping the pagination of the
+    /// constraints
+    pub fn apply_over_saved_filter(self, saved_filter: Self) -> Self {
+        Self {
+            payment_id: self.payment_id.or(saved_filter.payment_id),
+            profile_id: self.profile_id.or(saved_filter.profile_id),
+            customer_id: self.customer_id.or(saved_filter.customer_id),
+            limit: self.limit,
+            offset: self.offset,
+            amount_filter: self.amount_filter.or(saved_filter.amount_filter),
+            time_range: self.time_range.or(saved_filter.time_range),
+            connector: self.connector.or(saved_filter.connector),
+            currency: self.currency.or(saved_filter.currency),
+            status: self.status.or(saved_filter.status),
+            payment_method: self.payment_method.or(saved_filter.payment_method),
+            payment_method_type: self
+                .payment_method_type
+                .or(saved_filter.payment_method_type),
+            authentication_type: self
+                .authentication_type
+                .or(saved_filter.authentication_type),
+            merchant_connector_id: self
+                .merchant_connector_id
+                .or(saved_filter.merchant_connector_id),
+            tags: self.tags.or(saved_filter.tags),
+            saved_filter: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SavedPaymentFilterRequest {
+    /// The name of the saved filter, which replaces the saved filter of the merchant with the same
+    /// name
+    pub name: String,
+    /// The description of the saved filter
+    pub description: Option<String>,
+    /// The constraints the payments list is filtered with, without its pagination
+    pub filters: PaymentListFilterConstraints,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct SavedPaymentFilter {
+    /// The name of the saved filter
+    pub name: String,
+    /// The description of the saved filter
+    pub description: Option<String>,
+    /// The constraints the payments list is filtered with
+    pub filters: PaymentListFilterConstraints,
+    /// The time at which the saved filter was last saved
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct SavedPaymentFilterListResponse {
+    /// The number of saved filters of the merchant
+    pub count: usize,
+    /// The saved filters of the merchant
+    pub data: Vec<SavedPaymentFilter>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct SavedPaymentFilterDeleteRequest {
+    /// The name of the saved filter
+    pub name: String,
 }
 #[derive(Clone, Debug, serde::Serialize)]
 pub struct PaymentListFilters {
@@ -3609,6 +4155,7 @@ pub struct MandateValidationFields {
     pub mandate_data: Option<MandateData>,
     pub setup_future_usage: Option<api_enums::FutureUsage>,
     pub off_session: Option<bool>,
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
 }
 
 impl From<&PaymentsRequest> for MandateValidationFields {
@@ -3631,6 +4178,7 @@ impl From<&PaymentsRequest> for MandateValidationFields {
             mandate_data: req.mandate_data.clone(),
             setup_future_usage: req.setup_future_usage,
             off_session: req.off_session,
+            payment_method_type: req.payment_method_type,
         }
     }
 }
@@ -3644,6 +4192,7 @@ impl From<&VerifyRequest> for MandateValidationFields {
             mandate_data: req.mandate_data.clone(),
             off_session: req.off_session,
             setup_future_usage: req.setup_future_usage,
+            payment_method_type: None,
         }
     }
 }
@@ -3705,6 +4254,7 @@ impl From<AdditionalPaymentData> for PaymentMethodDataResponse {
             AdditionalPaymentData::GiftCard {} => Self::GiftCard {},
             AdditionalPaymentData::CardRedirect {} => Self::CardRedirect {},
             AdditionalPaymentData::CardToken {} => Self::CardToken {},
+            AdditionalPaymentData::RealTimePayment {} => Self::RealTimePayment {},
         }
     }
 }
@@ -3735,7 +4285,7 @@ pub struct PaymentsResponseForm {
     pub order_id: String,
 }
 
-#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[derive(Default, MaskedDebug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 pub struct PaymentsRetrieveRequest {
     /// The type of ID (ex: payment intent id, payment attempt id or connector txn id)
     pub resource_id: PaymentIdType,
@@ -3751,6 +4301,7 @@ pub struct PaymentsRetrieveRequest {
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>)]
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
     /// This is a token which expires after 15 minutes, used from the client to authenticate and create sessions from the SDK
+    #[masked]
     pub client_secret: Option<String>,
     /// If enabled provides list of captures linked to latest attempt
     pub expand_captures: Option<bool>,
@@ -3824,11 +4375,12 @@ pub struct RedirectResponse {
     pub json_payload: Option<pii::SecretSerdeValue>,
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[derive(MaskedDebug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 pub struct PaymentsSessionRequest {
     /// The identifier for the payment
     pub payment_id: String,
     /// This is a token which expires after 15 minutes, used from the client to authenticate and create sessions from the SDK
+    #[masked]
     pub client_secret: String,
     /// The list of the supported wallets
     #[schema(value_type = Vec<PaymentMethodType>)]
@@ -3909,6 +4461,19 @@ pub struct GpayMerchantInfo {
 pub struct GpayMetaData {
     pub merchant_info: GpayMerchantInfo,
     pub allowed_payment_methods: Vec<GpayAllowedPaymentMethods>,
+    /// Details to decrypt the payment token with, if the payment token is to be decrypted by
+    /// Hyperswitch instead of the connector
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_processing_details: Option<GooglePayPaymentProcessingDetails>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct GooglePayPaymentProcessingDetails {
+    /// The base64 encoded PKCS #8 private key, whose public key is registered with Google Pay
+    #[schema(value_type = String)]
+    pub google_pay_private_key: Secret<String>,
+    /// The recipient of the payment token, `merchant:<Google merchant ID>` for direct integrations
+    pub google_pay_recipient_id: String,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -3917,6 +4482,34 @@ pub struct GpaySessionTokenData {
     pub data: GpayMetaData,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SamsungPaySessionTokenData {
+    pub samsung_pay: SamsungPayMetadata,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SamsungPayMetadata {
+    /// The service ID of the merchant, issued on the Samsung Pay partner portal
+    pub service_id: String,
+    /// The name of the merchant to be displayed during checkout
+    pub merchant_display_name: String,
+    /// The country of the business of the merchant
+    pub merchant_business_country: api_enums::CountryAlpha2,
+    /// The card brands the customer can pay with
+    pub allowed_brands: Vec<String>,
+    /// Details to decrypt the payment token with, if the payment token is to be decrypted by
+    /// Hyperswitch instead of the connector
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_processing_details: Option<SamsungPayPaymentProcessingDetails>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct SamsungPayPaymentProcessingDetails {
+    /// The PEM encoded private key, whose certificate signing request was registered with Samsung Pay
+    #[schema(value_type = String)]
+    pub samsung_pay_private_key: Secret<String>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplepaySessionRequest {
@@ -3950,6 +4543,23 @@ pub struct ApplepayConnectorMetadataRequest {
     pub session_token_data: Option<SessionTokenInfo>,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ClickToPaySessionTokenData {
+    pub click_to_pay: ClickToPayMetadata,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ClickToPayMetadata {
+    /// Identifier of the Digital Payment Application of the merchant, registered with the card networks
+    pub dpa_id: String,
+    /// The name of the merchant to be displayed during checkout
+    pub dpa_name: String,
+    /// The card networks the customer can check out with
+    pub card_brands: Vec<api_enums::CardNetwork>,
+    /// The locale in which the checkout is to be displayed
+    pub locale: Option<String>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ApplepaySessionTokenData {
     pub apple_pay: ApplePayMetadata,
@@ -4005,6 +4615,30 @@ pub struct SessionTokenInfo {
     pub initiative_context: String,
     #[schema(value_type = Option<CountryAlpha2>)]
     pub merchant_business_country: Option<api_enums::CountryAlpha2>,
+    /// Where the Apple Pay payment token is decrypted, connectors which do not accept Apple Pay
+    /// tokens receive the decrypted card data if this is done by Hyperswitch
+    #[serde(flatten)]
+    pub payment_processing_details_at: Option<PaymentProcessingDetailsAt>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "payment_processing_details_at")]
+pub enum PaymentProcessingDetailsAt {
+    /// The payment token is decrypted by Hyperswitch using the payment processing certificate of
+    /// the merchant
+    Hyperswitch(PaymentProcessingDetails),
+    /// The payment token is forwarded to the connector as is
+    Connector,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentProcessingDetails {
+    /// Base64 encoded Apple Pay payment processing certificate of the merchant
+    #[schema(value_type = String)]
+    pub payment_processing_certificate: Secret<String>,
+    /// Private key of the payment processing certificate, in PEM format
+    #[schema(value_type = String)]
+    pub payment_processing_certificate_key: Secret<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -4014,7 +4648,7 @@ pub struct SessionTokenForSimplifiedApplePay {
     pub merchant_business_country: Option<api_enums::CountryAlpha2>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(tag = "wallet_name")]
 #[serde(rename_all = "snake_case")]
 pub enum SessionToken {
@@ -4026,11 +4660,15 @@ pub enum SessionToken {
     Paypal(Box<PaypalSessionTokenResponse>),
     /// The session response structure for Apple Pay
     ApplePay(Box<ApplepaySessionTokenResponse>),
+    /// The session response structure for Click to Pay
+    ClickToPay(Box<ClickToPaySessionResponse>),
+    /// The session response structure for Samsung Pay
+    SamsungPay(Box<SamsungPaySessionTokenResponse>),
     /// Whenever there is no session token response or an error in session response
     NoSessionTokenReceived,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(untagged)]
 pub enum GpaySessionTokenResponse {
     /// Google pay response involving third party sdk
@@ -4039,7 +4677,7 @@ pub enum GpaySessionTokenResponse {
     GooglePaySession(GooglePaySessionResponse),
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub struct GooglePayThirdPartySdk {
     /// Identifier for the delayed session response
@@ -4050,7 +4688,7 @@ pub struct GooglePayThirdPartySdk {
     pub sdk_next_action: SdkNextAction,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub struct GooglePaySessionResponse {
     /// The merchant info
@@ -4069,23 +4707,25 @@ pub struct GooglePaySessionResponse {
     pub secrets: Option<SecretInfoToInitiateSdk>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(MaskedDebug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub struct KlarnaSessionTokenResponse {
     /// The session token for Klarna
+    #[masked]
     pub session_token: String,
     /// The identifier for the session
     pub session_id: String,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(MaskedDebug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub struct PaypalSessionTokenResponse {
     /// The session token for PayPal
+    #[masked]
     pub session_token: String,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub struct ApplepaySessionTokenResponse {
     /// Session object for Apple Pay
@@ -4106,13 +4746,106 @@ pub struct ApplepaySessionTokenResponse {
     pub connector_merchant_id: Option<String>,
 }
 
-#[derive(Debug, Eq, PartialEq, serde::Serialize, Clone, ToSchema)]
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct SamsungPaySessionTokenResponse {
+    /// The version of the Samsung Pay SDK
+    #[schema(example = "2")]
+    pub version: String,
+    /// The service ID of the merchant, issued on the Samsung Pay partner portal
+    pub service_id: String,
+    /// The identifier of the order, the payment ID
+    pub order_number: String,
+    /// The merchant details to be displayed during checkout
+    #[serde(rename = "merchant")]
+    pub merchant_payment_information: SamsungPayMerchantPaymentInformation,
+    /// The amount of the payment
+    pub amount: SamsungPayAmountDetails,
+    /// The protocol of the payment token
+    pub protocol: SamsungPayProtocolType,
+    /// The card brands the customer can pay with
+    #[schema(example = json!(["visa", "mastercard"]))]
+    pub allowed_brands: Vec<String>,
+    /// The session token is w.r.t this connector
+    pub connector: String,
+    /// The next action for the sdk (ex: calling confirm or sync call)
+    pub sdk_next_action: SdkNextAction,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SamsungPayProtocolType {
+    Protocol3ds,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct SamsungPayMerchantPaymentInformation {
+    /// The name of the merchant
+    pub name: String,
+    /// The domain of the website of the merchant
+    pub url: Option<String>,
+    /// The country of the business of the merchant
+    #[schema(value_type = CountryAlpha2, example = "US")]
+    pub country_code: api_enums::CountryAlpha2,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct SamsungPayAmountDetails {
+    /// The format in which the amount is to be displayed
+    #[serde(rename = "option")]
+    pub amount_format: SamsungPayAmountFormat,
+    /// The currency of the payment
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency_code: api_enums::Currency,
+    /// The amount of the payment, in the base unit of the currency
+    #[serde(rename = "total")]
+    #[schema(example = "38.02")]
+    pub amount: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SamsungPayAmountFormat {
+    /// Display the total amount only
+    FormatTotalPriceOnly,
+    /// Display "Pending" in place of the amount
+    FormatTotalEstimatedAmount,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ClickToPaySessionResponse {
+    /// Identifier of Hyperswitch as a Click to Pay initiator, with which the SDK is initialised
+    pub src_initiator_id: String,
+    /// Identifier of the Digital Payment Application of the merchant, registered with the card networks
+    pub srci_dpa_id: String,
+    /// Identifier of the checkout, to be sent in every Click to Pay call made for the payment
+    pub srci_transaction_id: String,
+    /// The name of the merchant to be displayed during checkout
+    pub dpa_name: String,
+    /// The card networks the customer can check out with
+    #[schema(value_type = Vec<CardNetwork>, example = json!(["Visa", "Mastercard"]))]
+    pub card_brands: Vec<api_enums::CardNetwork>,
+    /// The amount of the payment, in the base unit of the currency
+    #[schema(example = "38.02")]
+    pub transaction_amount: String,
+    /// The currency of the payment
+    #[schema(value_type = Currency, example = "USD")]
+    pub transaction_currency_code: api_enums::Currency,
+    /// The locale in which the checkout is to be displayed
+    #[schema(example = "en_US")]
+    pub locale: Option<String>,
+    /// The session token is w.r.t this connector
+    pub connector: String,
+    /// The next action for the sdk (ex: calling confirm or sync call)
+    pub sdk_next_action: SdkNextAction,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub struct SdkNextAction {
     /// The type of next action
     pub next_action: NextActionCall,
 }
 
-#[derive(Debug, Eq, PartialEq, serde::Serialize, Clone, ToSchema)]
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum NextActionCall {
     /// The next action call is confirm
@@ -4121,7 +4854,7 @@ pub enum NextActionCall {
     Sync,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(untagged)]
 pub enum ApplePaySessionResponse {
     ///  We get this session response, when third party sdk is involved
@@ -4138,8 +4871,9 @@ pub enum ApplePaySessionResponse {
 pub struct NoThirdPartySdkSessionResponse {
     /// Timestamp at which session is requested
     pub epoch_timestamp: u64,
-    /// Timestamp at which session expires
-    pub expires_at: u64,
+    /// Timestamp at which session expires, in milliseconds since the UNIX epoch
+    #[schema(value_type = u64)]
+    pub expires_at: ExpiresAt,
     /// The identifier for the merchant session
     pub merchant_session_identifier: String,
     /// Apple pay generated unique ID (UUID) value
@@ -4160,7 +4894,7 @@ pub struct NoThirdPartySdkSessionResponse {
     pub psp_id: String,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct ThirdPartySdkSessionResponse {
     pub secrets: SecretInfoToInitiateSdk,
 }
@@ -4221,13 +4955,14 @@ pub struct PaymentsSessionResponse {
     pub session_token: Vec<SessionToken>,
 }
 
-#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[derive(Default, MaskedDebug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 pub struct PaymentRetrieveBody {
     /// The identifier for the Merchant Account.
     pub merchant_id: Option<String>,
     /// Decider to enable or disable the connector call for retrieve request
     pub force_sync: Option<bool>,
     /// This is a token which expires after 15 minutes, used from the client to authenticate and create sessions from the SDK
+    #[masked]
     pub client_secret: Option<String>,
     /// If enabled provides list of captures linked to latest attempt
     pub expand_captures: Option<bool>,
@@ -4271,6 +5006,45 @@ pub struct PaymentsIncrementalAuthorizationRequest {
     pub reason: Option<String>,
 }
 
+#[derive(Default, Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentsVerifyMicroDepositsRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// The amounts of the two micro-deposits made to the bank account of the customer, in the lowest denomination of the currency
+    #[schema(value_type = Option<Vec<i64>>, example = json!([32, 45]))]
+    pub amounts: Option<Vec<i64>>,
+    /// The code in the statement descriptor of the micro-deposit made to the bank account of the customer
+    #[schema(value_type = Option<String>, example = "SM11AA")]
+    pub descriptor_code: Option<Secret<String>>,
+}
+
+#[derive(Default, Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentsRetryRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// The connector to retry the payment with. If not passed, the connector is chosen by the routing configured for the business profile of the payment
+    #[schema(value_type = Option<Connector>, example = "adyen")]
+    pub connector: Option<api_enums::Connector>,
+    /// The payment method information to retry the payment with. If neither this nor the payment_token is passed, the payment is retried with the payment method of the failed attempt
+    #[schema(example = "bank_transfer")]
+    #[serde(with = "payment_method_data_serde", default)]
+    pub payment_method_data: Option<PaymentMethodDataRequest>,
+    /// The payment method that is to be used for the retry
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+    /// Payment Method Type
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    /// Provide a reference to a stored payment method to retry the payment with
+    #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
+    pub payment_token: Option<String>,
+    /// Additional details required by 3DS 2.0, if the payment is retried from a different browser. If not passed, the browser information of the failed attempt is used
+    #[schema(value_type = Option<BrowserInformation>)]
+    pub browser_info: Option<serde_json::Value>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub struct PaymentsExternalAuthenticationRequest {
     /// The identifier for the payment
@@ -4324,6 +5098,113 @@ pub struct SdkInformation {
     pub sdk_max_timeout: u8,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct ClickToPayIdentityLookupRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// Client Secret
+    #[schema(value_type = String)]
+    pub client_secret: Secret<String>,
+    /// The email of the customer, either the email or the phone number is to be provided
+    #[schema(value_type = Option<String>, example = "johntest@test.com")]
+    pub email: Option<Email>,
+    /// The phone number of the customer, either the email or the phone number is to be provided
+    pub phone: Option<PhoneDetails>,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct ClickToPayIdentityLookupResponse {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// Whether the customer has a Click to Pay profile with any of the card networks
+    pub consumer_present: bool,
+    /// The card networks with which the customer has a Click to Pay profile
+    #[schema(value_type = Vec<CardNetwork>, example = json!(["Visa"]))]
+    pub recognized_card_networks: Vec<api_enums::CardNetwork>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct ClickToPayEnrollmentRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// Client Secret
+    #[schema(value_type = String)]
+    pub client_secret: Secret<String>,
+    /// The card to be added to the Click to Pay profile of the customer
+    pub card: Card,
+    /// The card network of the card
+    #[schema(value_type = CardNetwork, example = "Visa")]
+    pub card_network: api_enums::CardNetwork,
+    /// The email of the customer, which identifies their Click to Pay profile
+    #[schema(value_type = String, example = "johntest@test.com")]
+    pub email: Email,
+    /// The phone number of the customer
+    pub phone: Option<PhoneDetails>,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct ClickToPayEnrollmentResponse {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// The card network with which the card was enrolled
+    #[schema(value_type = CardNetwork, example = "Visa")]
+    pub card_network: api_enums::CardNetwork,
+    /// Identifier of the card in the Click to Pay profile of the customer, to be used on checkout
+    pub src_digital_card_id: String,
+    /// The last four digits of the card
+    #[schema(example = "4242")]
+    pub card_last_four: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsTaxCalculationRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// Client Secret
+    #[schema(value_type = String)]
+    pub client_secret: Secret<String>,
+    /// The shipping address selected by the customer, such as in the payment sheet of their wallet
+    pub shipping: Address,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsTaxCalculationResponse {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// The amount of the payment, including the tax on the order
+    #[schema(example = 6890)]
+    pub net_amount: i64,
+    /// The tax on the order calculated for the shipping address
+    #[schema(example = 350)]
+    pub order_tax_amount: i64,
+    /// The currency of the payment
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsTagsUpdateRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// The tags replacing the current tags of the payment, which are removed if empty
+    #[schema(max_items = 20, example = json!(["black_friday", "web"]))]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsTagsUpdateResponse {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// The tags of the payment
+    #[schema(example = json!(["black_friday", "web"]))]
+    pub tags: Vec<String>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub struct PaymentsExternalAuthenticationResponse {
     /// Indicates the trans status
@@ -4555,14 +5436,59 @@ mod tests {
             r#"{"multi_use":null}"#
         )
     }
+
+    #[test]
+    fn test_apply_over_saved_filter() {
+        let saved_filter: PaymentListFilterConstraints =
+            serde_json::from_value(serde_json::json!({
+                "currency": ["USD"],
+                "status": ["succeeded"],
+                "tags": ["black_friday"],
+                "limit": 20,
+                "offset": 40
+            }))
+            .unwrap();
+        let constraints: PaymentListFilterConstraints = serde_json::from_value(serde_json::json!({
+            "status": ["failed"],
+            "saved_filter": "black_friday_sales",
+            "offset": 10
+        }))
+        .unwrap();
+
+        let constraints = constraints.apply_over_saved_filter(saved_filter);
+
+        assert_eq!(constraints.currency, Some(vec![enums::Currency::USD]));
+        assert_eq!(constraints.status, Some(vec![enums::IntentStatus::Failed]));
+        assert_eq!(constraints.tags, Some(vec!["black_friday".to_string()]));
+        assert_eq!(constraints.limit, 10);
+        assert_eq!(constraints.offset, Some(10));
+        assert_eq!(constraints.saved_filter, None);
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkCreateRequest {
+    /// The identifier of the payment the payment link is created for
+    #[schema(max_length = 64, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+
+    /// Configurations of the hosted payment page, which override the ones of the business profile
+    pub payment_link_config: Option<PaymentCreatePaymentLinkConfig>,
+
+    /// Time in seconds for which the payment link can be used, the payment link expires along with
+    /// the payment if not specified
+    #[schema(example = 900)]
+    pub session_expiry: Option<u32>,
 }
 
-#[derive(Default, Debug, serde::Deserialize, Clone, ToSchema, serde::Serialize)]
+#[derive(Default, MaskedDebug, serde::Deserialize, Clone, ToSchema, serde::Serialize)]
 pub struct RetrievePaymentLinkRequest {
+    #[masked]
     pub client_secret: Option<String>,
 }
 
-#[derive(Clone, Debug, serde::Serialize, PartialEq, ToSchema)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, ToSchema)]
 pub struct PaymentLinkResponse {
     pub link: String,
     pub payment_link_id: String,
@@ -4590,6 +5516,13 @@ pub struct PaymentLinkInitiateRequest {
     pub payment_id: String,
 }
 
+#[derive(Clone, MaskedDebug, serde::Deserialize, serde::Serialize)]
+pub struct HostedCheckoutInitiateRequest {
+    pub merchant_id: String,
+    #[masked]
+    pub client_secret: String,
+}
+
 #[derive(Debug, serde::Serialize)]
 #[serde(untagged)]
 pub enum PaymentLinkData<'a> {
@@ -4597,11 +5530,12 @@ pub enum PaymentLinkData<'a> {
     PaymentLinkStatusDetails(PaymentLinkStatusDetails),
 }
 
-#[derive(Debug, serde::Serialize, Clone)]
+#[derive(MaskedDebug, serde::Serialize, Clone)]
 pub struct PaymentLinkDetails {
     pub amount: String,
     pub currency: api_enums::Currency,
     pub pub_key: String,
+    #[masked]
     pub client_secret: String,
     pub payment_id: String,
     #[serde(with = "common_utils::custom_serde::iso8601")]
@@ -4715,6 +5649,7 @@ pub struct OrderDetailsWithStringAmount {
 pub enum PaymentLinkStatus {
     Active,
     Expired,
+    Paid,
 }
 
 #[derive(PartialEq, Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -4731,6 +5666,82 @@ pub struct ExtendedCardInfoResponse {
     pub payload: String,
 }
 
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentsSuggestedMethodsResponse {
+    /// The identifier for the payment
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+
+    /// The payment methods eligible for the payment, with the one most likely to succeed first
+    pub suggested_payment_methods: Vec<SuggestedPaymentMethod>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SuggestedPaymentMethod {
+    /// The payment method
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: api_enums::PaymentMethod,
+
+    /// The payment method type
+    #[schema(value_type = PaymentMethodType, example = "credit")]
+    pub payment_method_type: api_enums::PaymentMethodType,
+
+    /// The likelihood of a payment made with this payment method succeeding, between 0 and 1
+    #[schema(example = 0.75)]
+    pub score: f64,
+
+    /// The number of previous payments of the customer which succeeded with this payment method
+    #[schema(example = 2)]
+    pub successful_payments: u32,
+
+    /// The number of previous payments of the customer which failed with this payment method
+    #[schema(example = 0)]
+    pub failed_payments: u32,
+
+    /// The time at which the customer last paid successfully with this payment method
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_successful_at: Option<PrimitiveDateTime>,
+}
+
+/// The details of a failed off-session payment which is retried as per the dunning configuration
+/// of the business profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentRetryDetails {
+    /// The identifier for the payment
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+
+    /// The identifier for the attempt which failed
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub attempt_id: String,
+
+    /// The identifier for the customer
+    #[schema(max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+
+    /// The number of times the payment has been retried so far
+    #[schema(example = 1)]
+    pub retry_count: u8,
+
+    /// The maximum number of times the payment is retried
+    #[schema(example = 4)]
+    pub max_retries: u8,
+
+    /// The time at which the payment is retried next, absent once the retries are exhausted
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_retry_at: Option<PrimitiveDateTime>,
+
+    /// The error code returned by the connector for the failed attempt
+    #[schema(example = "card_declined")]
+    pub error_code: Option<String>,
+
+    /// The error message returned by the connector for the failed attempt
+    #[schema(example = "Insufficient funds")]
+    pub error_message: Option<String>,
+}
+
 #[cfg(test)]
 mod payments_request_api_contract {
     #![allow(clippy::unwrap_used)]
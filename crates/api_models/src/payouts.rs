@@ -4,7 +4,7 @@ use common_utils::{
     crypto,
     pii::{self, Email},
 };
-use masking::Secret;
+use masking::{MaskedDebug, Secret};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
@@ -18,7 +18,7 @@ pub enum PayoutRequest {
     PayoutRetrieveRequest(PayoutRetrieveRequest),
 }
 
-#[derive(Default, Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[derive(Default, MaskedDebug, Deserialize, Serialize, Clone, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PayoutCreateRequest {
     /// Unique identifier for the payout. This ensures idempotency for multiple payouts
@@ -111,6 +111,7 @@ pub struct PayoutCreateRequest {
 
     /// It's a token used for client side verification.
     #[schema(value_type = String, example = "pay_U42c409qyHwOkWo3vK60_secret_el9ksDkiB8hi6j9N78yo")]
+    #[masked]
     pub client_secret: Option<String>,
 
     /// The URL to redirect after the completion of the operation
@@ -290,6 +291,7 @@ pub struct PixBankTransfer {
 pub enum Wallet {
     Paypal(Paypal),
     Venmo(Venmo),
+    Payoneer(Payoneer),
 }
 
 #[derive(Default, Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
@@ -314,7 +316,14 @@ pub struct Venmo {
     pub telephone_number: Option<Secret<String>>,
 }
 
-#[derive(Debug, Default, ToSchema, Clone, Serialize)]
+#[derive(Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct Payoneer {
+    /// id of the payee registered with the payoneer program
+    #[schema(value_type = String, example = "payee_1234")]
+    pub payee_id: Secret<String>,
+}
+
+#[derive(MaskedDebug, Default, ToSchema, Clone, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct PayoutCreateResponse {
     /// Unique identifier for the payout. This ensures idempotency for multiple payouts
@@ -391,6 +400,7 @@ pub struct PayoutCreateResponse {
 
     /// It's a token used for client side verification.
     #[schema(value_type = String, example = "pay_U42c409qyHwOkWo3vK60_secret_el9ksDkiB8hi6j9N78yo")]
+    #[masked]
     pub client_secret: Option<String>,
 
     /// The URL to redirect after the completion of the operation
@@ -3,11 +3,13 @@ use common_utils::{
     events::{ApiEventMetric, ApiEventsType},
     impl_misc_api_event_type,
 };
+use masking::MaskedDebug;
 
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(MaskedDebug, Clone, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct LinkTokenCreateRequest {
     pub language: Option<String>, // optional language field to be passed
+    #[masked]
     pub client_secret: Option<String>, // client secret to be passed in req body
     pub payment_id: String, // payment_id to be passed in req body for redis pm_auth connector name fetch
     pub payment_method: PaymentMethod, // payment_method to be used for filtering pm_auth connector
@@ -20,19 +22,21 @@ pub struct LinkTokenCreateResponse {
     pub connector: String,  // pm_auth connector name in response
 }
 
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(MaskedDebug, Clone, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 
 pub struct ExchangeTokenCreateRequest {
     pub public_token: String,
+    #[masked]
     pub client_secret: Option<String>,
     pub payment_id: String,
     pub payment_method: PaymentMethod,
     pub payment_method_type: PaymentMethodType,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(MaskedDebug, Clone, serde::Serialize)]
 pub struct ExchangeTokenCreateResponse {
+    #[masked]
     pub access_token: String,
 }
 
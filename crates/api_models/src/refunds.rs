@@ -37,6 +37,10 @@ pub struct RefundRequest {
     #[schema(max_length = 255, example = "Customer returned the product")]
     pub reason: Option<String>,
 
+    /// The code of the reason for the refund, which is passed to the connectors accepting the reasons of the refunds. It may be required for the refunds of the merchant. The refunds with the `other` reason code should describe their reason in `reason`
+    #[schema(value_type = Option<RefundReasonCode>, example = "product_issue")]
+    pub reason_code: Option<enums::RefundReasonCode>,
+
     /// To indicate whether to refund needs to be instant or scheduled. Default value is instant
     #[schema(default = "Instant", example = "Instant")]
     pub refund_type: Option<RefundType>,
@@ -48,9 +52,13 @@ pub struct RefundRequest {
     /// Merchant connector details used to make payments.
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>)]
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
+
+    /// The identifier of the capture against which the refund is to be initiated, for payments captured in multiple partial captures. If not provided, the refund is initiated against the payment
+    #[schema(max_length = 64, example = "pay_mbabizu24mvu3mela5njyhpit4_1_1")]
+    pub capture_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, Deserialize)]
+#[derive(Default, Debug, Clone, Deserialize, Serialize)]
 pub struct RefundsRetrieveBody {
     pub force_sync: Option<bool>,
 }
@@ -82,6 +90,10 @@ pub struct RefundUpdateRequest {
     #[schema(max_length = 255, example = "Customer returned the product")]
     pub reason: Option<String>,
 
+    /// The code of the reason for the refund. The refunds with the `other` reason code should describe their reason in `reason`
+    #[schema(value_type = Option<RefundReasonCode>, example = "product_issue")]
+    pub reason_code: Option<enums::RefundReasonCode>,
+
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type  = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
     pub metadata: Option<pii::SecretSerdeValue>,
@@ -112,6 +124,9 @@ pub struct RefundResponse {
     pub status: RefundStatus,
     /// An arbitrary string attached to the object. Often useful for displaying to users and your customer support executive
     pub reason: Option<String>,
+    /// The code of the reason for the refund
+    #[schema(value_type = Option<RefundReasonCode>)]
+    pub reason_code: Option<enums::RefundReasonCode>,
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object
     #[schema(value_type = Option<Object>)]
     pub metadata: Option<pii::SecretSerdeValue>,
@@ -132,6 +147,8 @@ pub struct RefundResponse {
     pub profile_id: Option<String>,
     /// The merchant_connector_id of the processor through which this payment went through
     pub merchant_connector_id: Option<String>,
+    /// The identifier of the capture against which the refund was initiated
+    pub capture_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
@@ -209,7 +226,7 @@ impl From<enums::RefundStatus> for RefundStatus {
         match status {
             enums::RefundStatus::Failure | enums::RefundStatus::TransactionFailure => Self::Failed,
             enums::RefundStatus::ManualReview => Self::Review,
-            enums::RefundStatus::Pending => Self::Pending,
+            enums::RefundStatus::Pending | enums::RefundStatus::PendingEnqueue => Self::Pending,
             enums::RefundStatus::Success => Self::Succeeded,
         }
     }
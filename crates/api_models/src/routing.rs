@@ -96,6 +96,7 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::PayLaterType,
         DirKeyKind::WalletType,
         DirKeyKind::UpiType,
+        DirKeyKind::RealTimePaymentType,
         DirKeyKind::BankRedirectType,
         DirKeyKind::BankDebitType,
         DirKeyKind::CryptoType,
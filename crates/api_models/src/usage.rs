@@ -0,0 +1,56 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UsageExportRequest {
+    /// Only usage recorded in periods starting on or after this time is returned
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+
+    /// Only usage recorded in periods starting on or before this time is returned
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub end_time: PrimitiveDateTime,
+
+    /// Restrict the export to a single merchant, usage of all merchants is returned otherwise
+    pub merchant_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UsageRecord {
+    /// The merchant which the usage is billed to
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    /// The billable action which was metered
+    pub metric: common_enums::UsageMetric,
+
+    /// Start of the day, in UTC, over which the usage was aggregated
+    #[schema(example = "2024-05-15T00:00:00.000Z", value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: PrimitiveDateTime,
+
+    /// Number of times the action was performed in the period
+    #[schema(example = 42)]
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UsageExportResponse {
+    /// Usage records ordered by merchant and period
+    pub records: Vec<UsageRecord>,
+}
+
+impl ApiEventMetric for UsageExportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for UsageExportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -36,3 +36,43 @@ pub struct ApplepayGetVerifiedDomainsParam {
 pub struct ApplepayVerifiedDomainsResponse {
     pub verified_domains: Vec<String>,
 }
+
+/// Request to register the domains of the merchant for Apple Pay with all the connectors of a
+/// business profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ApplepayProfileDomainRegistrationRequest {
+    pub domain_names: Vec<String>,
+}
+
+/// Response to be sent for the registration of Apple Pay domains of a business profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ApplepayProfileDomainRegistrationResponse {
+    pub profile_id: String,
+    pub registrations: Vec<ApplepayDomainRegistration>,
+}
+
+/// Outcome of the registration of Apple Pay domains with a merchant connector account
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ApplepayDomainRegistration {
+    pub merchant_connector_account_id: String,
+    pub connector_name: String,
+    pub registration_method: ApplepayDomainRegistrationMethod,
+    /// All the domains verified for the merchant connector account, including the ones registered
+    /// earlier
+    pub verified_domains: Vec<String>,
+    /// Reason for the failure of the registration, if it failed
+    pub error_message: Option<String>,
+}
+
+/// How the domains are registered for Apple Pay
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApplepayDomainRegistrationMethod {
+    /// Registered with Apple using the Apple Pay merchant identity of Hyperswitch
+    Apple,
+    /// Registered by the connector, which uses its own Apple Pay merchant identity
+    Connector,
+}
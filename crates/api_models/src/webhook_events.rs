@@ -40,7 +40,7 @@ pub enum EventListConstraintsInternal {
 }
 
 /// The response body for each item when listing events.
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct EventListItemResponse {
     /// The identifier for the Event.
     #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
@@ -183,3 +183,32 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+/// The JSON Schemas of the payloads of the outgoing webhooks.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookSchemasResponse {
+    /// The version of the API the schemas describe the webhook payloads of.
+    #[schema(example = "0.1.0")]
+    pub api_version: String,
+
+    /// The schemas of the webhook payloads, one for each event type.
+    pub schemas: Vec<WebhookEventSchema>,
+}
+
+/// The JSON Schema of the payload of the outgoing webhooks sent for an event type.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEventSchema {
+    /// The type of event the webhooks are sent for.
+    pub event_type: EventType,
+
+    /// The JSON Schema (draft 2020-12) of the webhook payload, along with the schemas it
+    /// references in `$defs`.
+    #[schema(value_type = Object)]
+    pub schema: serde_json::Value,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookSchemasResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Miscellaneous)
+    }
+}
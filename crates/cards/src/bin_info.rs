@@ -0,0 +1,237 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// BINs are the first 6 to 8 digits of the card numbers, the longest of which identify the
+/// issuers most precisely
+const MIN_BIN_LENGTH: usize = 6;
+const MAX_BIN_LENGTH: usize = 8;
+
+const CSV_HEADER: &str = "bin";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CardType {
+    Credit,
+    Debit,
+    Prepaid,
+}
+
+impl CardType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Credit => "CREDIT",
+            Self::Debit => "DEBIT",
+            Self::Prepaid => "PREPAID",
+        }
+    }
+}
+
+impl fmt::Display for CardType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CardType {
+    type Err = BinDatabaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "CREDIT" => Ok(Self::Credit),
+            "DEBIT" => Ok(Self::Debit),
+            "PREPAID" => Ok(Self::Prepaid),
+            _ => Err(BinDatabaseError::InvalidCardType(s.to_string())),
+        }
+    }
+}
+
+/// Information on the issuer of the cards of a BIN
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BinInfo {
+    /// The card network, such as `Visa`
+    pub card_network: Option<String>,
+    /// The other networks the cards are co-badged with, such as `CartesBancaires` for the Visa
+    /// cards issued in France. The payments with co-badged cards can be authorized on any of
+    /// their networks.
+    #[serde(default)]
+    pub co_badged_card_networks: Vec<String>,
+    pub card_type: Option<CardType>,
+    pub card_issuer: Option<String>,
+    /// The country the issuer issues the cards in, such as `INDIA`
+    pub card_issuing_country: Option<String>,
+}
+
+impl BinInfo {
+    pub fn is_co_badged(&self) -> bool {
+        self.card_network.is_some() && !self.co_badged_card_networks.is_empty()
+    }
+
+    /// The card network followed by the networks the cards are co-badged with
+    pub fn card_networks(&self) -> impl Iterator<Item = &str> {
+        self.card_network
+            .iter()
+            .chain(self.co_badged_card_networks.iter())
+            .map(String::as_str)
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum BinDatabaseError {
+    #[error("invalid BIN database record on line {line}: {reason}")]
+    InvalidRecord { line: usize, reason: String },
+    #[error("invalid card type `{0}`")]
+    InvalidCardType(String),
+}
+
+/// BINs along with the information on the issuers of their cards, mapping the card numbers to
+/// their issuers without calling an external provider
+#[derive(Clone, Debug, Default)]
+pub struct BinDatabase {
+    bins: HashMap<String, BinInfo>,
+}
+
+impl BinDatabase {
+    /// Load the database from CSV records of
+    /// `bin,card_network,card_type,card_issuer,card_issuing_country`, where the values other than
+    /// the BIN may be empty. The card network of the co-badged cards lists all their networks
+    /// separated by `|`, such as `Visa|CartesBancaires`, starting with the network the cards are
+    /// branded with first. The header line, empty lines, and lines starting with `#` are skipped.
+    pub fn from_csv(csv: &str) -> Result<Self, BinDatabaseError> {
+        let mut bins = HashMap::new();
+
+        for (index, record) in csv.lines().enumerate() {
+            let record = record.trim();
+            if record.is_empty() || record.starts_with('#') || record.starts_with(CSV_HEADER) {
+                continue;
+            }
+            let line = index + 1;
+            let invalid_record = |reason: &str| BinDatabaseError::InvalidRecord {
+                line,
+                reason: reason.to_string(),
+            };
+
+            let values = record.split(',').map(str::trim).collect::<Vec<_>>();
+            let [bin, card_network, card_type, card_issuer, card_issuing_country] =
+                values.as_slice()
+            else {
+                return Err(invalid_record("expected 5 values"));
+            };
+            if !is_valid_bin(bin) {
+                return Err(invalid_record("the BIN should be 6 to 8 digits"));
+            }
+
+            let non_empty = |value: &str| (!value.is_empty()).then(|| value.to_string());
+            let mut card_networks = card_network.split('|').map(str::trim);
+            let bin_info = BinInfo {
+                card_network: card_networks.next().and_then(non_empty),
+                co_badged_card_networks: card_networks.filter_map(non_empty).collect(),
+                card_type: non_empty(card_type)
+                    .map(|card_type| card_type.parse())
+                    .transpose()
+                    .map_err(|error: BinDatabaseError| invalid_record(&error.to_string()))?,
+                card_issuer: non_empty(card_issuer),
+                card_issuing_country: non_empty(card_issuing_country),
+            };
+
+            if bins.insert(bin.to_string(), bin_info).is_some() {
+                return Err(invalid_record("the BIN is listed more than once"));
+            }
+        }
+
+        Ok(Self { bins })
+    }
+
+    /// The information of the longest BIN the card number starts with. The card number may be
+    /// given in full or as its first digits only.
+    pub fn lookup(&self, card_number: &str) -> Option<&BinInfo> {
+        (MIN_BIN_LENGTH..=MAX_BIN_LENGTH)
+            .rev()
+            .filter_map(|length| card_number.get(..length))
+            .find_map(|bin| self.bins.get(bin))
+    }
+
+    pub fn len(&self) -> usize {
+        self.bins.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bins.is_empty()
+    }
+}
+
+fn is_valid_bin(bin: &str) -> bool {
+    (MIN_BIN_LENGTH..=MAX_BIN_LENGTH).contains(&bin.len())
+        && bin.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    const BIN_DATABASE: &str = "bin,card_network,card_type,card_issuer,card_issuing_country
+# Test cards
+424242,Visa,CREDIT,Stripe Test Bank,UNITED STATES OF AMERICA
+42424242,Visa,DEBIT,,
+497010,Visa|CartesBancaires,DEBIT,,FRANCE
+555555,Mastercard,prepaid,,UNITED KINGDOM
+";
+
+    #[test]
+    fn test_bin_database_lookup() {
+        let database = BinDatabase::from_csv(BIN_DATABASE).unwrap();
+        assert_eq!(database.len(), 4);
+
+        assert_eq!(
+            database.lookup("4242424242424242"),
+            Some(&BinInfo {
+                card_network: Some("Visa".to_string()),
+                co_badged_card_networks: Vec::new(),
+                card_type: Some(CardType::Debit),
+                card_issuer: None,
+                card_issuing_country: None,
+            })
+        );
+        assert_eq!(
+            database
+                .lookup("424242")
+                .and_then(|bin_info| bin_info.card_issuer.as_deref()),
+            Some("Stripe Test Bank")
+        );
+        assert_eq!(
+            database
+                .lookup("5555554444")
+                .and_then(|bin_info| bin_info.card_type),
+            Some(CardType::Prepaid)
+        );
+        assert!(!database.lookup("4242424242424242").unwrap().is_co_badged());
+
+        let co_badged = database.lookup("4970101234567890").unwrap();
+        assert!(co_badged.is_co_badged());
+        assert_eq!(
+            co_badged.card_networks().collect::<Vec<_>>(),
+            vec!["Visa", "CartesBancaires"]
+        );
+
+        assert_eq!(database.lookup("4111111111111111"), None);
+        assert_eq!(database.lookup("42424"), None);
+    }
+
+    #[test]
+    fn test_invalid_bin_database() {
+        for (csv, line) in [
+            ("42424,Visa,CREDIT,,", 1),
+            ("424242,Visa,CREDIT,", 1),
+            ("424242,Visa,CHARGE,,", 1),
+            ("424242,Visa,CREDIT,,\n424242,Visa,DEBIT,,", 2),
+        ] {
+            assert!(matches!(
+                BinDatabase::from_csv(csv),
+                Err(BinDatabaseError::InvalidRecord { line: error_line, .. }) if error_line == line
+            ));
+        }
+    }
+}
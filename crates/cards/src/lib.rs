@@ -1,3 +1,4 @@
+pub mod bin_info;
 pub mod validate;
 use std::ops::Deref;
 
@@ -1,6 +1,6 @@
 use std::{fmt, ops::Deref, str::FromStr};
 
-use masking::{PeekInterface, Strategy, StrongSecret, WithType};
+use masking::{PeekInterface, PlaintextBuffer, Strategy, StrongSecret, WithType};
 #[cfg(not(target_arch = "wasm32"))]
 use router_env::{logger, which as router_env_which, Env};
 use serde::{Deserialize, Deserializer, Serialize};
@@ -65,7 +65,7 @@ impl FromStr for CardNumber {
         };
         if luhn::valid(s) || valid_test_cards.contains(&s) {
             let cc_no_whitespace: String = s.split_whitespace().collect();
-            Ok(Self(StrongSecret::from_str(&cc_no_whitespace)?))
+            Ok(Self(StrongSecret::new(cc_no_whitespace)))
         } else {
             Err(CCValError)
         }
@@ -76,6 +76,7 @@ impl TryFrom<String> for CardNumber {
     type Error = CCValError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
+        let value = PlaintextBuffer::new(value);
         Self::from_str(&value)
     }
 }
@@ -90,7 +91,7 @@ impl Deref for CardNumber {
 
 impl<'de> Deserialize<'de> for CardNumber {
     fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
-        let s = String::deserialize(d)?;
+        let s = PlaintextBuffer::new(String::deserialize(d)?);
         Self::from_str(&s).map_err(serde::de::Error::custom)
     }
 }
@@ -0,0 +1,35 @@
+#[path = "connector_manifest.rs"]
+mod connector_manifest;
+
+fn main() {
+    let manifest = connector_manifest::ConnectorManifest::load("connectors.toml".as_ref());
+
+    connector_manifest::write_generated_file(
+        "routable_connectors.rs",
+        &format!(
+            r#"#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    strum::VariantNames,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+/// Connectors eligible for payments routing
+pub enum RoutableConnectors {{
+{}}}
+"#,
+            manifest.routable_connector_variants()
+        ),
+    );
+}
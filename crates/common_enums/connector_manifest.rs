@@ -0,0 +1,148 @@
+//! Code generation from the connector manifest (`connectors.toml`), shared by the build scripts of
+//! the crates defining the connector enums and configuration structs.
+
+// Each of the build scripts only generates some of the code
+#![allow(dead_code)]
+
+use std::{collections::HashSet, fmt::Write, fs, path::Path};
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorManifest {
+    connectors: Vec<ConnectorEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConnectorEntry {
+    name: String,
+    variant: Option<String>,
+    feature: Option<String>,
+    #[serde(default = "default_true")]
+    connector: bool,
+    #[serde(default = "default_true")]
+    routable: bool,
+    #[serde(default = "default_true")]
+    config: bool,
+    #[serde(default = "default_params")]
+    params: String,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_params() -> String {
+    "ConnectorParams".to_string()
+}
+
+impl ConnectorEntry {
+    fn variant(&self) -> String {
+        self.variant.clone().unwrap_or_else(|| {
+            self.name
+                .split('_')
+                .map(|word| {
+                    let mut chars = word.chars();
+                    chars
+                        .next()
+                        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                        .unwrap_or_default()
+                })
+                .collect()
+        })
+    }
+
+    fn cfg_attribute(&self) -> Option<String> {
+        self.feature
+            .as_ref()
+            .map(|feature| format!("#[cfg(feature = \"{feature}\")]"))
+    }
+}
+
+impl ConnectorManifest {
+    /// Read and validate the manifest, and have the build script rerun when it changes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the manifest cannot be read or parsed, or if a connector or a variant is listed
+    /// more than once.
+    #[allow(clippy::panic)]
+    pub fn load(path: &Path) -> Self {
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let contents = fs::read_to_string(path)
+            .unwrap_or_else(|error| panic!("Failed to read `{}`: {error}", path.display()));
+        let manifest: Self = toml::from_str(&contents)
+            .unwrap_or_else(|error| panic!("Failed to parse `{}`: {error}", path.display()));
+
+        let mut names = HashSet::new();
+        let mut variants = HashSet::new();
+        for entry in &manifest.connectors {
+            assert!(
+                names.insert(entry.name.as_str()),
+                "Connector `{}` is listed more than once in the connector manifest",
+                entry.name
+            );
+            assert!(
+                !entry.connector || variants.insert(entry.variant()),
+                "Variant `{}` is used by more than one connector in the connector manifest",
+                entry.variant()
+            );
+        }
+        manifest
+    }
+
+    /// Variants of the enum listing the connectors matching the filter, along with their
+    /// attributes.
+    fn enum_variants(&self, filter: impl Fn(&ConnectorEntry) -> bool) -> String {
+        let mut variants = String::new();
+        for entry in self.connectors.iter().filter(|entry| filter(entry)) {
+            if let Some(cfg_attribute) = entry.cfg_attribute() {
+                let _ = writeln!(variants, "    {cfg_attribute}");
+            }
+            if entry.variant.is_some() {
+                let _ = writeln!(variants, "    #[serde(rename = \"{}\")]", entry.name);
+                let _ = writeln!(variants, "    #[strum(serialize = \"{}\")]", entry.name);
+            }
+            let _ = writeln!(variants, "    {},", entry.variant());
+        }
+        variants
+    }
+
+    /// Variants of the `Connector` enum.
+    pub fn connector_variants(&self) -> String {
+        self.enum_variants(|entry| entry.connector)
+    }
+
+    /// Variants of the `RoutableConnectors` enum.
+    pub fn routable_connector_variants(&self) -> String {
+        self.enum_variants(|entry| entry.connector && entry.routable)
+    }
+
+    /// Fields of the `Connectors` configuration struct.
+    pub fn config_fields(&self) -> String {
+        let mut fields = String::new();
+        for entry in self.connectors.iter().filter(|entry| entry.config) {
+            if let Some(cfg_attribute) = entry.cfg_attribute() {
+                let _ = writeln!(fields, "    {cfg_attribute}");
+            }
+            let _ = writeln!(fields, "    pub {}: {},", entry.name, entry.params);
+        }
+        fields
+    }
+}
+
+/// Write the generated code to the output directory of the build script.
+///
+/// # Panics
+///
+/// Panics if the file cannot be written.
+#[allow(clippy::panic, clippy::expect_used)]
+pub fn write_generated_file(file_name: &str, contents: &str) {
+    let out_dir = std::env::var("OUT_DIR").expect("`OUT_DIR` is set for build scripts");
+    let path = Path::new(&out_dir).join(file_name);
+    fs::write(&path, contents)
+        .unwrap_or_else(|error| panic!("Failed to write `{}`: {error}", path.display()));
+}
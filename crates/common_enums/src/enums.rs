@@ -63,112 +63,7 @@ pub enum AttemptStatus {
     DeviceDataCollectionPending,
 }
 
-#[derive(
-    Clone,
-    Copy,
-    Debug,
-    Eq,
-    Hash,
-    PartialEq,
-    serde::Serialize,
-    serde::Deserialize,
-    strum::Display,
-    strum::EnumString,
-    strum::EnumIter,
-    strum::VariantNames,
-    ToSchema,
-)]
-#[router_derive::diesel_enum(storage_type = "db_enum")]
-#[serde(rename_all = "snake_case")]
-#[strum(serialize_all = "snake_case")]
-/// Connectors eligible for payments routing
-pub enum RoutableConnectors {
-    #[cfg(feature = "dummy_connector")]
-    #[serde(rename = "phonypay")]
-    #[strum(serialize = "phonypay")]
-    DummyConnector1,
-    #[cfg(feature = "dummy_connector")]
-    #[serde(rename = "fauxpay")]
-    #[strum(serialize = "fauxpay")]
-    DummyConnector2,
-    #[cfg(feature = "dummy_connector")]
-    #[serde(rename = "pretendpay")]
-    #[strum(serialize = "pretendpay")]
-    DummyConnector3,
-    #[cfg(feature = "dummy_connector")]
-    #[serde(rename = "stripe_test")]
-    #[strum(serialize = "stripe_test")]
-    DummyConnector4,
-    #[cfg(feature = "dummy_connector")]
-    #[serde(rename = "adyen_test")]
-    #[strum(serialize = "adyen_test")]
-    DummyConnector5,
-    #[cfg(feature = "dummy_connector")]
-    #[serde(rename = "checkout_test")]
-    #[strum(serialize = "checkout_test")]
-    DummyConnector6,
-    #[cfg(feature = "dummy_connector")]
-    #[serde(rename = "paypal_test")]
-    #[strum(serialize = "paypal_test")]
-    DummyConnector7,
-    Aci,
-    Adyen,
-    Airwallex,
-    Authorizedotnet,
-    Bankofamerica,
-    Billwerk,
-    Bitpay,
-    Bambora,
-    Bluesnap,
-    Boku,
-    Braintree,
-    Cashtocode,
-    Checkout,
-    Coinbase,
-    Cryptopay,
-    Cybersource,
-    Dlocal,
-    Ebanx,
-    Fiserv,
-    Forte,
-    Globalpay,
-    Globepay,
-    Gocardless,
-    Helcim,
-    Iatapay,
-    Klarna,
-    Mollie,
-    Multisafepay,
-    Nexinets,
-    Nmi,
-    Noon,
-    Nuvei,
-    // Opayo, added as template code for future usage
-    Opennode,
-    // Payeezy, As psync and rsync are not supported by this connector, it is added as template code for future usage
-    Payme,
-    Paypal,
-    Payu,
-    Placetopay,
-    Powertranz,
-    Prophetpay,
-    Rapyd,
-    Riskified,
-    Shift4,
-    Signifyd,
-    Square,
-    Stax,
-    Stripe,
-    Trustpay,
-    // Tsys,
-    Tsys,
-    Volt,
-    Wise,
-    Worldline,
-    Worldpay,
-    Zen,
-    Zsl,
-}
+include!(concat!(env!("OUT_DIR"), "/routable_connectors.rs"));
 
 impl AttemptStatus {
     pub fn is_terminal_status(self) -> bool {
@@ -228,6 +123,88 @@ pub enum AuthenticationType {
     NoThreeDs,
 }
 
+/// The channel through which the customer has provided their payment details
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentChannel {
+    /// The customer has entered their payment details themselves, online
+    #[default]
+    Ecommerce,
+    /// Mail order / telephone order, the merchant has entered the payment details provided by the customer by mail or over the phone. The customer cannot be authenticated with 3DS.
+    Moto,
+}
+
+/// The reason a payment is captured for less than the amount authorized
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CaptureAdjustmentReason {
+    /// A discount, such as a coupon, has been applied to the order after it was authorized
+    Discount,
+    /// Some of the items of the order are out of stock and are not shipped
+    OutOfStock,
+    /// The amount of the order has been adjusted for another reason
+    Other,
+}
+
+/// The reason a payment is refunded
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundReasonCode {
+    /// The customer has asked for the payment to be refunded
+    RequestedByCustomer,
+    /// The payment has been made fraudulently
+    Fraud,
+    /// The payment has been made more than once
+    Duplicate,
+    /// The product or service paid for is faulty, or has not been delivered
+    ProductIssue,
+    /// The payment is refunded for another reason, which is described in the reason of the refund
+    Other,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -1078,6 +1055,7 @@ pub enum EventClass {
     serde::Deserialize,
     serde::Serialize,
     strum::Display,
+    strum::EnumIter,
     strum::EnumString,
     ToSchema,
 )]
@@ -1105,6 +1083,14 @@ pub enum EventType {
     DisputeLost,
     MandateActive,
     MandateRevoked,
+    /// A failed off-session payment was scheduled to be retried
+    PaymentRetryScheduled,
+    /// A failed off-session payment was retried as many times as configured, without success
+    PaymentRetriesExhausted,
+    /// A payment was not confirmed before its session expired
+    PaymentExpired,
+    /// A payment waited for a payment method or for its confirmation beyond the configured period
+    PaymentAbandoned,
 }
 
 #[derive(
@@ -1182,6 +1168,8 @@ pub enum IntentStatus {
     RequiresCapture,
     PartiallyCaptured,
     PartiallyCapturedAndCapturable,
+    /// The payment was not confirmed before its session expired
+    Expired,
 }
 
 #[derive(
@@ -1376,6 +1364,7 @@ pub enum PaymentMethodType {
     CimbVa,
     #[serde(rename = "classic")]
     ClassicReward,
+    ClickToPay,
     Credit,
     CryptoCurrency,
     Cashapp,
@@ -1413,6 +1402,7 @@ pub enum PaymentMethodType {
     PermataBankTransfer,
     OpenBankingUk,
     PayBright,
+    Payoneer,
     Paypal,
     Pix,
     PaySafeCard,
@@ -1422,6 +1412,7 @@ pub enum PaymentMethodType {
     RedPagos,
     SamsungPay,
     Sepa,
+    Skrill,
     Sofort,
     Swish,
     TouchNGo,
@@ -1439,6 +1430,9 @@ pub enum PaymentMethodType {
     Seicomart,
     PayEasy,
     LocalBankTransfer,
+    PayNow,
+    PromptPay,
+    UpiQr,
 }
 
 /// Indicates the type of payment method. Eg: 'card', 'wallet', etc.
@@ -1475,6 +1469,8 @@ pub enum PaymentMethod {
     Upi,
     Voucher,
     GiftCard,
+    /// Instant bank payments made by scanning a QR code, such as UPI QR, PromptPay and PayNow
+    RealTimePayment,
 }
 
 /// To be used to specify the type of payment. Use 'setup_mandate' in case of zero auth flow.
@@ -1522,6 +1518,8 @@ pub enum RefundStatus {
     ManualReview,
     #[default]
     Pending,
+    /// The refund is queued to be submitted to a connector which refunds are paced for
+    PendingEnqueue,
     Success,
     TransactionFailure,
 }
@@ -2108,6 +2106,8 @@ pub enum PayoutStatus {
     Success,
     Failed,
     Cancelled,
+    Expired,
+    Reversed,
     Pending,
     Ineligible,
     #[default]
@@ -2720,3 +2720,29 @@ pub enum TokenPurpose {
     AcceptInvite,
     UserInfo,
 }
+
+/// Billable actions which are metered per merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum UsageMetric {
+    /// An authenticated API call made by the merchant
+    ApiCall,
+    /// A payment which was successfully charged
+    SuccessfulPayment,
+    /// A payment method stored in the vault
+    VaultTokenStored,
+}
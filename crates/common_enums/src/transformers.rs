@@ -1844,11 +1844,13 @@ impl From<PaymentMethodType> for PaymentMethod {
             PaymentMethodType::Pse => Self::BankTransfer,
             PaymentMethodType::LocalBankTransfer => Self::BankTransfer,
             PaymentMethodType::PayBright => Self::PayLater,
+            PaymentMethodType::Payoneer => Self::Wallet,
             PaymentMethodType::Paypal => Self::Wallet,
             PaymentMethodType::PaySafeCard => Self::GiftCard,
             PaymentMethodType::Przelewy24 => Self::BankRedirect,
             PaymentMethodType::SamsungPay => Self::Wallet,
             PaymentMethodType::Sepa => Self::BankDebit,
+            PaymentMethodType::Skrill => Self::Wallet,
             PaymentMethodType::Sofort => Self::BankRedirect,
             PaymentMethodType::Swish => Self::BankRedirect,
             PaymentMethodType::Trustly => Self::BankRedirect,
@@ -1866,6 +1868,7 @@ impl From<PaymentMethodType> for PaymentMethod {
             PaymentMethodType::RedCompra => Self::Voucher,
             PaymentMethodType::RedPagos => Self::Voucher,
             PaymentMethodType::Cashapp => Self::Wallet,
+            PaymentMethodType::ClickToPay => Self::Wallet,
             PaymentMethodType::Givex => Self::GiftCard,
             PaymentMethodType::Oxxo => Self::Voucher,
             PaymentMethodType::OpenBankingUk => Self::BankRedirect,
@@ -1875,6 +1878,9 @@ impl From<PaymentMethodType> for PaymentMethod {
             PaymentMethodType::FamilyMart => Self::Voucher,
             PaymentMethodType::Seicomart => Self::Voucher,
             PaymentMethodType::PayEasy => Self::Voucher,
+            PaymentMethodType::PayNow => Self::RealTimePayment,
+            PaymentMethodType::PromptPay => Self::RealTimePayment,
+            PaymentMethodType::UpiQr => Self::RealTimePayment,
         }
     }
 }
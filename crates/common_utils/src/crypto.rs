@@ -2,7 +2,7 @@
 use std::ops::Deref;
 
 use error_stack::ResultExt;
-use masking::{ExposeInterface, Secret};
+use masking::{constant_time_eq, ExposeInterface, Secret};
 use md5;
 use ring::{
     aead::{self, BoundKey, OpeningKey, SealingKey, UnboundKey},
@@ -330,7 +330,7 @@ impl VerifySignature for Sha512 {
                 .change_context(errors::CryptoError::SignatureVerificationFailed)?,
         );
         let hashed_digest_into_bytes = hashed_digest.into_bytes();
-        Ok(hashed_digest_into_bytes == signature)
+        Ok(constant_time_eq(&hashed_digest_into_bytes, signature))
     }
 }
 /// MD5 hash function
@@ -354,7 +354,7 @@ impl VerifySignature for Md5 {
         let hashed_digest = Self
             .generate_digest(msg)
             .change_context(errors::CryptoError::SignatureVerificationFailed)?;
-        Ok(hashed_digest == signature)
+        Ok(constant_time_eq(&hashed_digest, signature))
     }
 }
 
@@ -376,7 +376,7 @@ impl VerifySignature for Sha256 {
             .generate_digest(msg)
             .change_context(errors::CryptoError::SignatureVerificationFailed)?;
         let hashed_digest_into_bytes = hashed_digest.as_slice();
-        Ok(hashed_digest_into_bytes == signature)
+        Ok(constant_time_eq(hashed_digest_into_bytes, signature))
     }
 }
 
@@ -469,6 +469,23 @@ where
     }
 }
 
+impl<'de, T: Clone> masking::Deserialize<'de> for Encryptable<T>
+where
+    T: masking::Deserialize<'de>,
+{
+    /// Only the inner value is part of the serialized form, so the deserialized value does not
+    /// carry any encrypted data and must not be persisted
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(|inner| Self {
+            inner,
+            encrypted: Secret::new(Vec::new()),
+        })
+    }
+}
+
 impl<T: Clone> PartialEq for Encryptable<T>
 where
     T: PartialEq,
@@ -10,10 +10,14 @@ use diesel::{
 };
 use error_stack::{report, ResultExt};
 use semver::Version;
-use serde::{de::Visitor, Deserialize, Deserializer};
+use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
+use time::{
+    format_description::{well_known::Iso8601, BorrowedFormatItem},
+    Duration, OffsetDateTime, PrimitiveDateTime, UtcOffset,
+};
 
 use crate::{
-    consts,
+    consts, date_time,
     errors::{CustomResult, ParsingError, PercentageError},
 };
 /// Represents Percentage Value between 0 and 100 both inclusive
@@ -212,3 +216,115 @@ where
         <serde_json::Value as ToSql<Jsonb, diesel::pg::Pg>>::to_sql(&value, &mut out.reborrow())
     }
 }
+
+/// The time at which something shared by a connector expires, such as an Apple Pay session, a QR
+/// code or a voucher. The time is held in UTC, and it is represented as the number of
+/// milliseconds since the UNIX epoch when serialized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExpiresAt(PrimitiveDateTime);
+
+impl ExpiresAt {
+    /// Expiry after the given duration from now
+    pub fn after(duration: Duration) -> Self {
+        Self(date_time::now().saturating_add(duration))
+    }
+
+    /// Construct the expiry from the number of seconds since the UNIX epoch
+    pub fn from_unix_timestamp(timestamp: i64) -> CustomResult<Self, ParsingError> {
+        OffsetDateTime::from_unix_timestamp(timestamp)
+            .map(Self::from)
+            .change_context(ParsingError::DateTimeParsingError)
+            .attach_printable_lazy(|| format!("{timestamp} is not a valid UNIX timestamp"))
+    }
+
+    /// Construct the expiry from the number of milliseconds since the UNIX epoch
+    pub fn from_unix_timestamp_millis(timestamp: i64) -> CustomResult<Self, ParsingError> {
+        OffsetDateTime::from_unix_timestamp_nanos(i128::from(timestamp) * 1_000_000)
+            .map(Self::from)
+            .change_context(ParsingError::DateTimeParsingError)
+            .attach_printable_lazy(|| {
+                format!("{timestamp} is not a valid UNIX timestamp in milliseconds")
+            })
+    }
+
+    /// Parse the expiry from its ISO 8601 representation, which should include the offset from
+    /// UTC, such as `2024-05-15T20:20:20.000+05:30`
+    pub fn parse_iso8601(date_time: &str) -> CustomResult<Self, ParsingError> {
+        OffsetDateTime::parse(date_time, &Iso8601::DEFAULT)
+            .map(Self::from)
+            .change_context(ParsingError::DateTimeParsingError)
+            .attach_printable_lazy(|| format!("{date_time} is not an ISO 8601 date time"))
+    }
+
+    /// Parse the expiry from a representation in the given format, which should include the offset
+    /// from UTC, such as `[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour]:[offset_minute]`
+    pub fn parse_with_format(
+        date_time: &str,
+        format: &[BorrowedFormatItem<'_>],
+    ) -> CustomResult<Self, ParsingError> {
+        OffsetDateTime::parse(date_time, &format)
+            .map(Self::from)
+            .change_context(ParsingError::DateTimeParsingError)
+            .attach_printable_lazy(|| format!("{date_time} is not in the expected format"))
+    }
+
+    /// The expiry in UTC
+    pub fn get_date_time(&self) -> PrimitiveDateTime {
+        self.0
+    }
+
+    /// The number of milliseconds since the UNIX epoch until the expiry
+    pub fn get_unix_timestamp_millis(&self) -> i64 {
+        let timestamp_millis = self.0.assume_utc().unix_timestamp_nanos() / 1_000_000;
+        // The timestamp in milliseconds of any date time supported by `time` fits in an `i64`
+        i64::try_from(timestamp_millis).unwrap_or(i64::MAX)
+    }
+
+    /// The time remaining until the expiry, which is zero once it has expired
+    pub fn get_time_remaining(&self) -> Duration {
+        (self.0 - date_time::now()).max(Duration::ZERO)
+    }
+
+    /// Whether the expiry has passed
+    pub fn is_expired(&self) -> bool {
+        self.0 <= date_time::now()
+    }
+}
+
+impl From<PrimitiveDateTime> for ExpiresAt {
+    /// The date time is assumed to be in UTC
+    fn from(date_time: PrimitiveDateTime) -> Self {
+        Self(date_time)
+    }
+}
+
+impl From<OffsetDateTime> for ExpiresAt {
+    fn from(date_time: OffsetDateTime) -> Self {
+        Self(date_time::convert_to_pdt(
+            date_time.to_offset(UtcOffset::UTC),
+        ))
+    }
+}
+
+impl Serialize for ExpiresAt {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.get_unix_timestamp_millis().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ExpiresAt {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let timestamp = i64::deserialize(deserializer)?;
+        Self::from_unix_timestamp_millis(timestamp).map_err(|_| {
+            serde::de::Error::custom(format!(
+                "{timestamp} is not a valid UNIX timestamp in milliseconds"
+            ))
+        })
+    }
+}
@@ -0,0 +1,59 @@
+#![allow(clippy::unwrap_used)]
+use common_utils::types::ExpiresAt;
+use time::{macros::datetime, Duration};
+
+#[test]
+fn expiry_is_serialized_as_unix_timestamp_in_milliseconds() {
+    let expires_at = ExpiresAt::from(datetime!(2024-02-05 00:00:00.123));
+
+    assert_eq!(serde_json::to_string(&expires_at).unwrap(), "1707091200123");
+    assert_eq!(
+        serde_json::from_str::<ExpiresAt>("1707091200123").unwrap(),
+        expires_at
+    );
+}
+
+#[test]
+fn expiry_is_converted_to_utc() {
+    let expires_at = ExpiresAt::parse_iso8601("2024-05-15T20:20:20.000+05:30").unwrap();
+    assert_eq!(expires_at.get_date_time(), datetime!(2024-05-15 14:50:20));
+
+    let expires_at = ExpiresAt::parse_with_format(
+        "2024-05-15T20:20:20.000-0300",
+        time::macros::format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond][offset_hour sign:mandatory][offset_minute]"
+        ),
+    )
+    .unwrap();
+    assert_eq!(expires_at.get_date_time(), datetime!(2024-05-15 23:20:20));
+}
+
+#[test]
+fn expiry_without_offset_is_not_parsed() {
+    assert!(ExpiresAt::parse_iso8601("2024-05-15T20:20:20").is_err());
+    assert!(ExpiresAt::parse_iso8601("not a date time").is_err());
+}
+
+#[test]
+fn expiry_from_unix_timestamp() {
+    let expires_at = ExpiresAt::from_unix_timestamp(1_707_091_200).unwrap();
+
+    assert_eq!(expires_at.get_date_time(), datetime!(2024-02-05 00:00:00));
+    assert_eq!(expires_at.get_unix_timestamp_millis(), 1_707_091_200_000);
+    assert_eq!(
+        ExpiresAt::from_unix_timestamp_millis(1_707_091_200_000).unwrap(),
+        expires_at
+    );
+    assert!(ExpiresAt::from_unix_timestamp(i64::MAX).is_err());
+}
+
+#[test]
+fn expiry_is_expired_once_passed() {
+    let expires_at = ExpiresAt::after(Duration::minutes(10));
+    assert!(!expires_at.is_expired());
+    assert!(expires_at.get_time_remaining() > Duration::minutes(9));
+
+    let expires_at = ExpiresAt::after(Duration::minutes(-10));
+    assert!(expires_at.is_expired());
+    assert_eq!(expires_at.get_time_remaining(), Duration::ZERO);
+}
@@ -0,0 +1,20 @@
+use std::path::PathBuf;
+
+/// Utility to add a connector to hyperswitch from a connector specification file. The connector
+/// module, its transformers and its tests are generated from the connector template, and the
+/// connector is registered in the enums, configuration files and test authentication files.
+#[derive(clap::Parser, Debug)]
+#[command(arg_required_else_help = true)]
+pub(crate) struct Args {
+    /// Connector specification file.
+    #[arg(short, long, value_name = "FILE")]
+    pub(crate) spec_file: PathBuf,
+
+    /// Root directory of the hyperswitch repository.
+    #[arg(short, long, value_name = "DIR", default_value = ".")]
+    pub(crate) repo_root: PathBuf,
+
+    /// Print the files that would be created or updated, without writing them.
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+}
@@ -0,0 +1,157 @@
+mod cli;
+mod registration;
+mod spec;
+mod template;
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{ensure, Context};
+
+/// Path of the connector module, relative to the repository root.
+const CONNECTOR_MODULE: &str = "crates/router/src/connector.rs";
+
+/// Path of the sample test authentication file, relative to the repository root.
+const SAMPLE_AUTH_FILE: &str = "crates/router/tests/connectors/sample_auth.toml";
+
+/// A file to be created or updated with the specified contents.
+struct FileChange {
+    path: PathBuf,
+    contents: String,
+    is_new: bool,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = <cli::Args as clap::Parser>::parse();
+    let spec = spec::ConnectorSpec::from_file(&args.spec_file)?;
+
+    let changes = generate(&args.repo_root, &spec)?;
+
+    for change in &changes {
+        let action = if change.is_new { "Create" } else { "Update" };
+        println!("{action} {}", change.path.display());
+        if args.dry_run {
+            continue;
+        }
+
+        if let Some(parent) = change.path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+        std::fs::write(&change.path, &change.contents)
+            .with_context(|| format!("Failed to write {}", change.path.display()))?;
+    }
+
+    if !args.dry_run {
+        println!(
+            "\nConnector `{}` has been generated. Format the code with `cargo +nightly fmt --all`, \
+             implement the connector in crates/router/src/connector/{}.rs and its transformers, \
+             and run its tests with `cargo test --package router --test connectors -- {}`.",
+            spec.name, spec.name, spec.name
+        );
+    }
+
+    Ok(())
+}
+
+/// Generate the files of the connector and register it in the existing files. None of the files
+/// are written until all the changes have been successfully generated.
+fn generate(repo_root: &Path, spec: &spec::ConnectorSpec) -> anyhow::Result<Vec<FileChange>> {
+    let template_dir = repo_root.join(template::TEMPLATE_DIR);
+    let read_template = |file: &str| {
+        let path = template_dir.join(file);
+        std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read template file {}", path.display()))
+    };
+
+    let connector_module = template::render(&read_template("mod.rs")?, &spec.name)?;
+    let transformers = template::render_auth_type(
+        &template::render(&read_template("transformers.rs")?, &spec.name)?,
+        spec,
+    )?;
+    let tests = template::render(&read_template("test.rs")?, &spec.name)?;
+
+    let name = spec.name.as_str();
+    let mut changes = vec![
+        new_file(
+            repo_root,
+            format!("crates/router/src/connector/{name}.rs"),
+            connector_module,
+        )?,
+        new_file(
+            repo_root,
+            format!("crates/router/src/connector/{name}/transformers.rs"),
+            transformers,
+        )?,
+        new_file(
+            repo_root,
+            format!("crates/router/tests/connectors/{name}.rs"),
+            tests,
+        )?,
+    ];
+
+    // Files registering the connector in multiple places are updated with all of their entries
+    let mut updated_files: Vec<(&str, String)> = Vec::new();
+    for registration in registration::registrations(spec) {
+        let index = match updated_files
+            .iter()
+            .position(|(path, _)| *path == registration.path)
+        {
+            Some(index) => index,
+            None => {
+                let contents = read_file(repo_root, registration.path)?;
+                updated_files.push((registration.path, contents));
+                updated_files.len() - 1
+            }
+        };
+        if let Some((_, contents)) = updated_files.get_mut(index) {
+            *contents = registration.apply(contents, name)?;
+        }
+    }
+    for (path, contents) in updated_files.iter_mut() {
+        if *path == CONNECTOR_MODULE {
+            *contents = registration::register_connector_struct(contents, spec)?;
+        }
+    }
+
+    let sample_auth = read_file(repo_root, SAMPLE_AUTH_FILE)?;
+    ensure!(
+        !sample_auth.contains(&format!("[{name}]")),
+        "Connector `{name}` already has credentials in `{SAMPLE_AUTH_FILE}`"
+    );
+    updated_files.push((
+        SAMPLE_AUTH_FILE,
+        format!("{sample_auth}{}", registration::sample_auth_section(spec)),
+    ));
+
+    changes.extend(
+        updated_files
+            .into_iter()
+            .map(|(path, contents)| FileChange {
+                path: repo_root.join(path),
+                contents,
+                is_new: false,
+            }),
+    );
+
+    Ok(changes)
+}
+
+fn new_file(repo_root: &Path, path: String, contents: String) -> anyhow::Result<FileChange> {
+    let path = repo_root.join(path);
+    ensure!(
+        !path.exists(),
+        "{} already exists, the connector may have already been generated",
+        path.display()
+    );
+
+    Ok(FileChange {
+        path,
+        contents,
+        is_new: true,
+    })
+}
+
+fn read_file(repo_root: &Path, path: &str) -> anyhow::Result<String> {
+    let path = repo_root.join(path);
+    std::fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))
+}
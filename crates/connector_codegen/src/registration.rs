@@ -0,0 +1,523 @@
+use anyhow::{bail, ensure, Context};
+
+use crate::spec::ConnectorSpec;
+
+/// The maximum width of the lines formatted by `rustfmt`.
+const MAX_LINE_WIDTH: usize = 100;
+
+/// An entry registering the connector in a file, such as an enum variant or a match arm. The
+/// entry is added in alphabetical order among the existing entries of the section.
+pub(crate) struct Registration {
+    /// Path of the file, relative to the repository root.
+    pub(crate) path: &'static str,
+    section: Section,
+    /// Pattern of the existing entries, with `{}` standing for the name of the connector (in any
+    /// case) in the first position the name appears in the entry.
+    pattern: &'static str,
+    /// Lines to be added, without indentation.
+    entry: Vec<String>,
+}
+
+/// Part of the file the entries are added to.
+enum Section {
+    /// The whole file.
+    File,
+    /// Each of the blocks opened by the lines starting with `starts_with` and ending with
+    /// `ends_with`, up to the line closing the block.
+    Blocks {
+        starts_with: &'static str,
+        ends_with: &'static str,
+    },
+    /// The TOML table with the specified header.
+    TomlTable(&'static str),
+}
+
+/// The development and deployment configuration files in which the base URLs of the connectors
+/// are specified, along with whether they list the supported connectors.
+const CONFIG_FILES: &[(&str, bool)] = &[
+    ("config/config.example.toml", true),
+    ("config/development.toml", true),
+    ("config/docker_compose.toml", true),
+    ("config/deployments/integration_test.toml", false),
+    ("config/deployments/sandbox.toml", false),
+    ("loadtest/config/development.toml", true),
+];
+
+const PRODUCTION_CONFIG_FILE: &str = "config/deployments/production.toml";
+
+pub(crate) fn registrations(spec: &ConnectorSpec) -> Vec<Registration> {
+    let name = spec.name.as_str();
+    let pascal_case_name = spec.pascal_case_name();
+    let auth_type = spec.auth_type.variant();
+
+    let mut registrations = vec![
+        Registration {
+            path: "crates/router/src/connector.rs",
+            section: Section::File,
+            pattern: "pub mod {};",
+            entry: vec![format!("pub mod {name};")],
+        },
+        Registration {
+            path: "crates/router/src/types/api.rs",
+            section: Section::File,
+            pattern: "enums::Connector::{} => Ok(Box::new(&connector::",
+            entry: vec![format!(
+                "enums::Connector::{pascal_case_name} => Ok(Box::new(&connector::{pascal_case_name})),"
+            )],
+        },
+        Registration {
+            path: "crates/router/src/types/transformers.rs",
+            section: Section::File,
+            pattern: "api_enums::Connector::{} => Self::",
+            entry: vec![format!(
+                "api_enums::Connector::{pascal_case_name} => Self::{pascal_case_name},"
+            )],
+        },
+        Registration {
+            path: "crates/router/src/core/admin.rs",
+            section: Section::Blocks {
+                starts_with: "match connector_name {",
+                ends_with: "{",
+            },
+            pattern: "api_enums::Connector::{} => {",
+            entry: vec![
+                format!("api_enums::Connector::{pascal_case_name} => {{"),
+                format!("    {name}::transformers::{pascal_case_name}AuthType::try_from(val)?;"),
+                "    Ok(())".to_string(),
+                "}".to_string(),
+            ],
+        },
+        Registration {
+            path: "crates/router/src/core/payments/flows.rs",
+            section: Section::Blocks {
+                starts_with: "default_imp_for_",
+                ends_with: "!(",
+            },
+            pattern: "connector::{},",
+            entry: vec![format!("connector::{pascal_case_name},")],
+        },
+        Registration {
+            path: "crates/common_enums/connectors.toml",
+            section: Section::Blocks {
+                starts_with: "connectors = [",
+                ends_with: "[",
+            },
+            pattern: "{ name = \"{}\"",
+            entry: vec![format!("{{ name = \"{name}\" }},")],
+        },
+        Registration {
+            path: "crates/router/tests/connectors/main.rs",
+            section: Section::File,
+            pattern: "mod {};",
+            entry: vec![format!("mod {name};")],
+        },
+        Registration {
+            path: "crates/test_utils/src/connector_auth.rs",
+            section: Section::Blocks {
+                starts_with: "pub struct ConnectorAuthentication {",
+                ends_with: "{",
+            },
+            pattern: "pub {}: Option<",
+            entry: vec![format!("pub {name}: Option<{auth_type}>,")],
+        },
+    ];
+
+    for (path, lists_connectors) in CONFIG_FILES {
+        registrations.push(base_url_registration(path, name, &spec.base_url));
+        if *lists_connectors {
+            registrations.push(Registration {
+                path,
+                section: Section::Blocks {
+                    starts_with: "cards = [",
+                    ends_with: "[",
+                },
+                pattern: "\"{}\",",
+                entry: vec![format!("\"{name}\",")],
+            });
+        }
+    }
+    registrations.push(base_url_registration(
+        PRODUCTION_CONFIG_FILE,
+        name,
+        spec.production_base_url(),
+    ));
+
+    registrations
+}
+
+fn base_url_registration(path: &'static str, name: &str, base_url: &str) -> Registration {
+    Registration {
+        path,
+        section: Section::TomlTable("[connectors]"),
+        pattern: "{}.base_url = ",
+        entry: vec![format!("{name}.base_url = \"{base_url}\"")],
+    }
+}
+
+impl Registration {
+    /// Add the entry of the connector named `name` to the file contents.
+    pub(crate) fn apply(&self, contents: &str, name: &str) -> anyhow::Result<String> {
+        let key = sort_key(name);
+        let mut lines = contents.lines().map(str::to_owned).collect::<Vec<_>>();
+        let sections = self.section.find(&lines);
+        ensure!(
+            !sections.is_empty(),
+            "Section to register the connector in not found in `{}`",
+            self.path
+        );
+
+        // The sections are processed from the last one so that the positions of the ones
+        // preceding them are not shifted by the entries added
+        let mut added = false;
+        for (start, end) in sections.into_iter().rev() {
+            let entries = lines
+                .get(start..end)
+                .unwrap_or_default()
+                .iter()
+                .enumerate()
+                .filter_map(|(offset, line)| {
+                    self.entry_key(line)
+                        .map(|entry_key| (start + offset, entry_key))
+                })
+                .collect::<Vec<_>>();
+
+            if entries.iter().any(|(_, entry_key)| *entry_key == key) {
+                bail!(
+                    "Connector `{name}` is already registered in `{}`",
+                    self.path
+                );
+            }
+
+            let preceding_entry = entries
+                .iter()
+                .filter(|(_, entry_key)| *entry_key < key)
+                .max_by(|(index, entry_key), (other_index, other_entry_key)| {
+                    entry_key.cmp(other_entry_key).then(index.cmp(other_index))
+                });
+            let (reference, position) = match (preceding_entry, entries.first()) {
+                (Some((index, _)), _) => (*index, entry_end(&lines, *index) + 1),
+                (None, Some((index, _))) => (*index, attributes_start(&lines, *index)),
+                (None, None) => continue,
+            };
+
+            let indentation = lines
+                .get(reference)
+                .map(|line| indentation(line).to_owned())
+                .unwrap_or_default();
+            let entry = self
+                .entry
+                .iter()
+                .map(|line| format!("{indentation}{line}"))
+                .collect::<Vec<_>>();
+            lines.splice(position..position, entry);
+            added = true;
+        }
+        ensure!(
+            added,
+            "No existing entries to register the connector along found in `{}`",
+            self.path
+        );
+
+        let mut updated = lines.join("\n");
+        if contents.ends_with('\n') {
+            updated.push('\n');
+        }
+        Ok(updated)
+    }
+
+    /// Sort key of the name of the connector registered by the line, if the line is an entry of
+    /// the pattern.
+    fn entry_key(&self, line: &str) -> Option<String> {
+        let (prefix, suffix) = self.pattern.split_once("{}")?;
+        let suffix = suffix.split("{}").next().unwrap_or_default();
+
+        let rest = line.trim_start().strip_prefix(prefix)?;
+        let name_length = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        let (entry_name, rest) = rest.split_at(name_length);
+
+        (!entry_name.is_empty() && rest.starts_with(suffix)).then(|| sort_key(entry_name))
+    }
+}
+
+/// Key the entries are sorted by, for the names in lowercase and in pascal case to be compared.
+fn sort_key(name: &str) -> String {
+    name.replace('_', "").to_ascii_lowercase()
+}
+
+impl Section {
+    /// Ranges of lines (start inclusive, end exclusive) of the section.
+    fn find(&self, lines: &[String]) -> Vec<(usize, usize)> {
+        match self {
+            Self::File => vec![(0, lines.len())],
+            Self::Blocks {
+                starts_with,
+                ends_with,
+            } => lines
+                .iter()
+                .enumerate()
+                .filter(|(_, line)| {
+                    line.trim_start().starts_with(starts_with)
+                        && line.trim_end().ends_with(ends_with)
+                })
+                .map(|(start, opener)| {
+                    let block_indentation = indentation(opener).len();
+                    let end = lines
+                        .iter()
+                        .enumerate()
+                        .skip(start + 1)
+                        .find(|(_, line)| {
+                            !line.trim().is_empty() && indentation(line).len() <= block_indentation
+                        })
+                        .map_or(lines.len(), |(end, _)| end);
+                    (start + 1, end)
+                })
+                .collect(),
+            Self::TomlTable(header) => lines
+                .iter()
+                .position(|line| line.trim() == *header)
+                .map(|start| {
+                    let end = lines
+                        .iter()
+                        .enumerate()
+                        .skip(start + 1)
+                        .find(|(_, line)| line.starts_with('['))
+                        .map_or(lines.len(), |(end, _)| end);
+                    vec![(start + 1, end)]
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Index of the last line of the entry starting at `index`, which spans up to the line closing
+/// the block it opens, if any.
+fn entry_end(lines: &[String], index: usize) -> usize {
+    let Some(line) = lines.get(index) else {
+        return index;
+    };
+    if !line.trim_end().ends_with('{') {
+        return index;
+    }
+
+    let entry_indentation = indentation(line).len();
+    lines
+        .iter()
+        .enumerate()
+        .skip(index + 1)
+        .find(|(_, line)| {
+            indentation(line).len() == entry_indentation && line.trim_start().starts_with('}')
+        })
+        .map_or(index, |(end, _)| end)
+}
+
+/// Index of the first of the attributes and comments preceding the entry at `index`, so that the
+/// entries added before it are not placed between the entry and its attributes.
+fn attributes_start(lines: &[String], index: usize) -> usize {
+    let mut start = index;
+    while let Some(previous) = start.checked_sub(1) {
+        match lines.get(previous).map(|line| line.trim_start()) {
+            Some(line) if line.starts_with("#[") || line.starts_with("//") => start = previous,
+            _ => break,
+        }
+    }
+    start
+}
+
+fn indentation(line: &str) -> &str {
+    let content_start = line.len() - line.trim_start().len();
+    line.split_at(content_start).0
+}
+
+/// Add the connector to the `pub use self::{..}` list of the connector structs in the connector
+/// module, which is sorted and wrapped the way `rustfmt` does.
+pub(crate) fn register_connector_struct(
+    contents: &str,
+    spec: &ConnectorSpec,
+) -> anyhow::Result<String> {
+    const LIST_START: &str = "pub use self::{";
+    const LIST_END: &str = "};";
+
+    let start = contents
+        .find(LIST_START)
+        .context("Connector structs list not found in connector module")?;
+    let (before, list) = contents.split_at(start + LIST_START.len());
+    let end = list
+        .find(LIST_END)
+        .context("Unterminated connector structs list in connector module")?;
+    let (list, after) = list.split_at(end);
+
+    let new_item = format!("{}::{}", spec.name, spec.pascal_case_name());
+    let mut items = list
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect::<Vec<_>>();
+    ensure!(
+        !items.contains(&new_item.as_str()),
+        "Connector `{}` is already registered in the connector module",
+        spec.name
+    );
+    items.push(&new_item);
+    items.sort_by_key(|item| sort_key(item));
+
+    Ok(format!("{before}{}{after}", wrap_list(&items)))
+}
+
+/// Wrap the items over lines indented by four spaces, filling each line up to the maximum width.
+fn wrap_list(items: &[&str]) -> String {
+    let mut wrapped = String::from("\n");
+    let mut line = String::new();
+
+    for item in items {
+        let item = format!("{item},");
+        if !line.is_empty() && line.len() + 1 + item.len() > MAX_LINE_WIDTH {
+            wrapped.push_str(&line);
+            wrapped.push('\n');
+            line.clear();
+        }
+        if line.is_empty() {
+            line.push_str("    ");
+        } else {
+            line.push(' ');
+        }
+        line.push_str(&item);
+    }
+    if !line.is_empty() {
+        wrapped.push_str(&line);
+        wrapped.push('\n');
+    }
+
+    wrapped
+}
+
+/// Credentials section of the connector in the sample test authentication file.
+pub(crate) fn sample_auth_section(spec: &ConnectorSpec) -> String {
+    let values = spec
+        .auth_type
+        .fields()
+        .iter()
+        .map(|field| format!("{field} = \"{}\"\n", field.replace('_', " ").to_uppercase()))
+        .collect::<String>();
+
+    format!("\n[{}]\n{values}", spec.name)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::spec::AuthType;
+
+    fn spec(name: &str) -> ConnectorSpec {
+        ConnectorSpec {
+            name: name.to_string(),
+            base_url: format!("https://sandbox.{name}.com/"),
+            production_base_url: None,
+            auth_type: AuthType::HeaderKey,
+        }
+    }
+
+    fn registration(path: &'static str, spec: &ConnectorSpec) -> Registration {
+        registrations(spec)
+            .into_iter()
+            .find(|registration| registration.path == path)
+            .unwrap()
+    }
+
+    #[test]
+    fn adds_entries_in_alphabetical_order() {
+        let contents = "pub mod aci;\npub mod adyen;\npub mod zen;\n";
+        let registration = registration("crates/router/src/connector.rs", &spec("bambora"));
+
+        assert_eq!(
+            registration.apply(contents, "bambora").unwrap(),
+            "pub mod aci;\npub mod adyen;\npub mod bambora;\npub mod zen;\n"
+        );
+        assert!(registration.apply(contents, "adyen").is_err());
+    }
+
+    #[test]
+    fn adds_entries_to_each_block_after_multiline_entries() {
+        let contents = "\
+match connector_name {
+    api_enums::Connector::Aci => {
+        aci::transformers::AciAuthType::try_from(val)?;
+        Ok(())
+    }
+    #[cfg(feature = \"dummy_connector\")]
+    api_enums::Connector::Zen => {
+        zen::transformers::ZenAuthType::try_from(val)?;
+        Ok(())
+    }
+}
+";
+        let registration = registration("crates/router/src/core/admin.rs", &spec("bambora"));
+        let updated = registration.apply(contents, "bambora").unwrap();
+
+        assert!(updated.contains(
+            "        Ok(())\n    }\n    api_enums::Connector::Bambora => {\n        bambora::transformers::BamboraAuthType::try_from(val)?;\n        Ok(())\n    }\n    #[cfg(feature = \"dummy_connector\")]\n"
+        ));
+    }
+
+    #[test]
+    fn adds_entries_to_toml_tables() {
+        let contents =
+            "[connectors]\naci.base_url = \"https://aci.com/\"\n\n[other]\nzen.base_url = \"x\"\n";
+        let registration = registration("config/development.toml", &spec("zsl"));
+
+        assert_eq!(
+            registration.apply(contents, "zsl").unwrap(),
+            "[connectors]\naci.base_url = \"https://aci.com/\"\nzsl.base_url = \"https://sandbox.zsl.com/\"\n\n[other]\nzen.base_url = \"x\"\n"
+        );
+    }
+
+    #[test]
+    fn adds_entries_to_connector_manifest() {
+        let contents = "\
+connectors = [
+    { name = \"aci\" },
+    { name = \"adyen_test\", variant = \"DummyConnector5\", config = false },
+    { name = \"zen\" },
+]
+";
+        let registration = registration("crates/common_enums/connectors.toml", &spec("bambora"));
+
+        assert_eq!(
+            registration.apply(contents, "bambora").unwrap(),
+            "\
+connectors = [
+    { name = \"aci\" },
+    { name = \"adyen_test\", variant = \"DummyConnector5\", config = false },
+    { name = \"bambora\" },
+    { name = \"zen\" },
+]
+"
+        );
+        assert!(registration.apply(contents, "adyen_test").is_err());
+    }
+
+    #[test]
+    fn wraps_connector_structs_list_like_rustfmt() {
+        let contents = "\
+pub use self::{
+    aci::Aci, adyen::Adyen, affirm::Affirm, afterpay::Afterpay, airwallex::Airwallex,
+    authorizedotnet::Authorizedotnet, bambora::Bambora, bankofamerica::Bankofamerica,
+    billwerk::Billwerk, bitpay::Bitpay,
+};
+";
+        let updated = register_connector_struct(contents, &spec("bluesnap")).unwrap();
+
+        assert_eq!(
+            updated,
+            "\
+pub use self::{
+    aci::Aci, adyen::Adyen, affirm::Affirm, afterpay::Afterpay, airwallex::Airwallex,
+    authorizedotnet::Authorizedotnet, bambora::Bambora, bankofamerica::Bankofamerica,
+    billwerk::Billwerk, bitpay::Bitpay, bluesnap::Bluesnap,
+};
+"
+        );
+    }
+}
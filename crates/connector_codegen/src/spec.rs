@@ -0,0 +1,108 @@
+use std::path::Path;
+
+use anyhow::{ensure, Context};
+
+/// The connector specification file contents.
+///
+/// ```toml
+/// name = "examplepay"
+/// base_url = "https://sandbox.examplepay.com/"
+/// production_base_url = "https://api.examplepay.com/"
+/// auth_type = "body_key"
+/// ```
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ConnectorSpec {
+    /// Name of the connector, in lowercase.
+    pub(crate) name: String,
+
+    /// Base URL of the sandbox environment of the connector.
+    pub(crate) base_url: String,
+
+    /// Base URL of the production environment of the connector. The sandbox base URL is used if
+    /// not specified.
+    pub(crate) production_base_url: Option<String>,
+
+    /// Authentication type of the connector, determining the credentials the connector is
+    /// configured with.
+    #[serde(default)]
+    pub(crate) auth_type: AuthType,
+}
+
+/// The variants match the ones of `ConnectorAuthType`, hence the common postfix.
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy, Default, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AuthType {
+    #[default]
+    HeaderKey,
+    BodyKey,
+    SignatureKey,
+    MultiAuthKey,
+}
+
+impl ConnectorSpec {
+    pub(crate) fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let contents =
+            std::fs::read_to_string(path).context("Failed to read connector specification file")?;
+        let spec = toml::from_str::<Self>(&contents)
+            .context("Failed to parse connector specification file contents")?;
+        spec.validate()?;
+
+        Ok(spec)
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        ensure!(
+            self.name.starts_with(|c: char| c.is_ascii_lowercase())
+                && self
+                    .name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'),
+            "Connector name `{}` must start with a lowercase letter and contain only lowercase \
+             letters, digits and underscores",
+            self.name
+        );
+        for base_url in std::iter::once(&self.base_url).chain(&self.production_base_url) {
+            ensure!(
+                base_url.starts_with("https://") || base_url.starts_with("http://"),
+                "Base URL `{base_url}` must be an HTTP(S) URL"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Name of the connector, in pascal case, as used by the connector enum variants and structs.
+    pub(crate) fn pascal_case_name(&self) -> String {
+        crate::template::pascal_case(&self.name)
+    }
+
+    pub(crate) fn production_base_url(&self) -> &str {
+        self.production_base_url
+            .as_deref()
+            .unwrap_or(&self.base_url)
+    }
+}
+
+impl AuthType {
+    /// Name of the `ConnectorAuthType` variant.
+    pub(crate) fn variant(self) -> &'static str {
+        match self {
+            Self::HeaderKey => "HeaderKey",
+            Self::BodyKey => "BodyKey",
+            Self::SignatureKey => "SignatureKey",
+            Self::MultiAuthKey => "MultiAuthKey",
+        }
+    }
+
+    /// Fields of the `ConnectorAuthType` variant.
+    pub(crate) fn fields(self) -> &'static [&'static str] {
+        match self {
+            Self::HeaderKey => &["api_key"],
+            Self::BodyKey => &["api_key", "key1"],
+            Self::SignatureKey => &["api_key", "key1", "api_secret"],
+            Self::MultiAuthKey => &["api_key", "key1", "api_secret", "key2"],
+        }
+    }
+}
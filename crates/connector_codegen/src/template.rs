@@ -0,0 +1,172 @@
+use anyhow::{bail, Context};
+
+use crate::spec::{AuthType, ConnectorSpec};
+
+/// The directory containing the connector template, relative to the repository root.
+pub(crate) const TEMPLATE_DIR: &str = "connector-template";
+
+/// Render the connector template, which uses the `cargo-generate` syntax of the `project-name`
+/// placeholder with the `downcase` and `pascal_case` filters.
+pub(crate) fn render(template: &str, name: &str) -> anyhow::Result<String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut remaining = template;
+
+    while let Some(start) = remaining.find("{{") {
+        let (before, placeholder) = remaining.split_at(start);
+        rendered.push_str(before);
+
+        let end = placeholder
+            .find("}}")
+            .context("Unterminated placeholder in connector template")?;
+        let (placeholder, after) = placeholder.split_at(end);
+        let mut parts = placeholder
+            .trim_start_matches("{{")
+            .split('|')
+            .map(str::trim);
+
+        if parts.next() != Some("project-name") {
+            bail!("Unknown placeholder `{placeholder}}}}}` in connector template");
+        }
+        let value = parts.try_fold(name.to_owned(), |value, filter| match filter {
+            "downcase" => Ok(value.to_lowercase()),
+            "pascal_case" => Ok(pascal_case(&value)),
+            filter => Err(anyhow::anyhow!(
+                "Unknown filter `{filter}` in connector template"
+            )),
+        })?;
+        rendered.push_str(&value);
+
+        remaining = after.trim_start_matches("}}");
+    }
+    rendered.push_str(remaining);
+
+    Ok(rendered)
+}
+
+pub(crate) fn pascal_case(value: &str) -> String {
+    value
+        .split(['_', '-'])
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
+/// Replace the authentication type struct of the rendered transformers template, which accepts
+/// `HeaderKey` credentials, with the one accepting the credentials of the connector.
+pub(crate) fn render_auth_type(transformers: &str, spec: &ConnectorSpec) -> anyhow::Result<String> {
+    let auth_type_name = format!("{}AuthType", spec.pascal_case_name());
+    let struct_start = transformers
+        .find(&format!("pub struct {auth_type_name}"))
+        .context("Authentication type struct not found in transformers template")?;
+    let impl_start = transformers
+        .find(&format!(
+            "impl TryFrom<&types::ConnectorAuthType> for {auth_type_name}"
+        ))
+        .context("Authentication type conversion not found in transformers template")?;
+    let impl_end = block_end(transformers, impl_start)
+        .context("Unterminated authentication type conversion in transformers template")?;
+
+    let (before, _) = transformers.split_at(struct_start);
+    let (_, after) = transformers.split_at(impl_end);
+
+    Ok(format!(
+        "{before}{}{after}",
+        auth_type_definition(&auth_type_name, spec.auth_type)
+    ))
+}
+
+/// Position following the brace closing the first block opened from `start`.
+fn block_end(contents: &str, start: usize) -> Option<usize> {
+    let mut depth = 0_usize;
+    for (index, c) in contents
+        .char_indices()
+        .skip_while(|(index, _)| *index < start)
+    {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(index + c.len_utf8());
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn auth_type_definition(auth_type_name: &str, auth_type: AuthType) -> String {
+    let fields = auth_type.fields();
+    let struct_fields = fields
+        .iter()
+        .map(|field| format!("    pub(super) {field}: Secret<String>,\n"))
+        .collect::<String>();
+    let field_assignments = fields
+        .iter()
+        .map(|field| format!("                {field}: {field}.to_owned(),\n"))
+        .collect::<String>();
+
+    format!(
+        "pub struct {auth_type_name} {{
+{struct_fields}}}
+
+impl TryFrom<&types::ConnectorAuthType> for {auth_type_name} {{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {{
+        match auth_type {{
+            types::ConnectorAuthType::{variant} {{ {field_list} }} => Ok(Self {{
+{field_assignments}            }}),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }}
+    }}
+}}",
+        variant = auth_type.variant(),
+        field_list = fields.join(", "),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn renders_placeholders_with_filters() {
+        let rendered = render(
+            "pub struct {{project-name | downcase | pascal_case}}; {{project-name| downcase}}.{{project-name}}",
+            "example_pay",
+        )
+        .unwrap();
+        assert_eq!(rendered, "pub struct ExamplePay; example_pay.example_pay");
+    }
+
+    #[test]
+    fn rejects_unknown_placeholders() {
+        assert!(render("{{crate_name}}", "examplepay").is_err());
+        assert!(render("{{project-name | upcase}}", "examplepay").is_err());
+    }
+
+    #[test]
+    fn replaces_the_auth_type_definition() {
+        let transformers = "use x;\n\npub struct ExamplepayAuthType {\n    pub(super) api_key: Secret<String>\n}\n\nimpl TryFrom<&types::ConnectorAuthType> for ExamplepayAuthType {\n    fn f() { match x { _ => {} } }\n}\n// PaymentsResponse\n";
+        let spec = ConnectorSpec {
+            name: "examplepay".to_string(),
+            base_url: "https://sandbox.examplepay.com/".to_string(),
+            production_base_url: None,
+            auth_type: AuthType::BodyKey,
+        };
+        let rendered = render_auth_type(transformers, &spec).unwrap();
+
+        assert!(rendered.starts_with("use x;\n\npub struct ExamplepayAuthType {\n"));
+        assert!(rendered.contains("    pub(super) key1: Secret<String>,\n"));
+        assert!(rendered.contains("types::ConnectorAuthType::BodyKey { api_key, key1 } =>"));
+        assert!(rendered.ends_with("}\n// PaymentsResponse\n"));
+    }
+}
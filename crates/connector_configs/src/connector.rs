@@ -111,6 +111,7 @@ pub struct ConnectorTomlConfig {
     pub voucher: Option<Vec<Provider>>,
     pub gift_card: Option<Vec<Provider>>,
     pub card_redirect: Option<Vec<Provider>>,
+    pub real_time_payment: Option<Vec<Provider>>,
     pub is_verifiable: Option<bool>,
 }
 #[serde_with::skip_serializing_none]
@@ -120,6 +121,8 @@ pub struct ConnectorConfig {
     pub adyen: Option<ConnectorTomlConfig>,
     #[cfg(feature = "payouts")]
     pub adyen_payout: Option<ConnectorTomlConfig>,
+    pub affirm: Option<ConnectorTomlConfig>,
+    pub afterpay: Option<ConnectorTomlConfig>,
     pub airwallex: Option<ConnectorTomlConfig>,
     pub authorizedotnet: Option<ConnectorTomlConfig>,
     pub bankofamerica: Option<ConnectorTomlConfig>,
@@ -151,7 +154,10 @@ pub struct ConnectorConfig {
     pub nmi: Option<ConnectorTomlConfig>,
     pub noon: Option<ConnectorTomlConfig>,
     pub nuvei: Option<ConnectorTomlConfig>,
+    #[cfg(feature = "payouts")]
+    pub nuvei_payout: Option<ConnectorTomlConfig>,
     pub payme: Option<ConnectorTomlConfig>,
+    pub payoneer_payout: Option<ConnectorTomlConfig>,
     pub paypal: Option<ConnectorTomlConfig>,
     #[cfg(feature = "payouts")]
     pub paypal_payout: Option<ConnectorTomlConfig>,
@@ -223,6 +229,8 @@ impl ConnectorConfig {
             PayoutConnectors::Wise => Ok(connector_data.wise_payout),
             PayoutConnectors::Paypal => Ok(connector_data.paypal_payout),
             PayoutConnectors::Ebanx => Ok(connector_data.ebanx_payout),
+            PayoutConnectors::Nuvei => Ok(connector_data.nuvei_payout),
+            PayoutConnectors::Payoneer => Ok(connector_data.payoneer_payout),
         }
     }
 
@@ -243,6 +251,8 @@ impl ConnectorConfig {
         match connector {
             Connector::Aci => Ok(connector_data.aci),
             Connector::Adyen => Ok(connector_data.adyen),
+            Connector::Affirm => Ok(connector_data.affirm),
+            Connector::Afterpay => Ok(connector_data.afterpay),
             Connector::Airwallex => Ok(connector_data.airwallex),
             Connector::Authorizedotnet => Ok(connector_data.authorizedotnet),
             Connector::Bankofamerica => Ok(connector_data.bankofamerica),
@@ -276,6 +286,7 @@ impl ConnectorConfig {
             Connector::Noon => Ok(connector_data.noon),
             Connector::Nuvei => Ok(connector_data.nuvei),
             Connector::Payme => Ok(connector_data.payme),
+            Connector::Payoneer => Ok(connector_data.payoneer_payout),
             Connector::Paypal => Ok(connector_data.paypal),
             Connector::Payu => Ok(connector_data.payu),
             Connector::Placetopay => Ok(connector_data.placetopay),
@@ -19,6 +19,7 @@ impl ConnectorApiIntegrationPayload {
         let mut voucher_details: Vec<Provider> = Vec::new();
         let mut gift_card_details: Vec<Provider> = Vec::new();
         let mut card_redirect_details: Vec<Provider> = Vec::new();
+        let mut real_time_payment_details: Vec<Provider> = Vec::new();
 
         if let Some(payment_methods_enabled) = response.payment_methods_enabled.clone() {
             for methods in payment_methods_enabled {
@@ -185,6 +186,17 @@ impl ConnectorApiIntegrationPayload {
                             }
                         }
                     }
+                    api_models::enums::PaymentMethod::RealTimePayment => {
+                        if let Some(payment_method_types) = methods.payment_method_types {
+                            for method_type in payment_method_types {
+                                real_time_payment_details.push(Provider {
+                                    payment_method_type: method_type.payment_method_type,
+                                    accepted_currencies: method_type.accepted_currencies.clone(),
+                                    accepted_countries: method_type.accepted_countries.clone(),
+                                })
+                            }
+                        }
+                    }
                 }
             }
         }
@@ -210,6 +222,13 @@ impl ConnectorApiIntegrationPayload {
             card_provider: None,
         };
 
+        let real_time_payment = DashboardPaymentMethodPayload {
+            payment_method: api_models::enums::PaymentMethod::RealTimePayment,
+            payment_method_type: api_models::enums::PaymentMethod::RealTimePayment.to_string(),
+            provider: Some(real_time_payment_details),
+            card_provider: None,
+        };
+
         let reward = DashboardPaymentMethodPayload {
             payment_method: api_models::enums::PaymentMethod::Reward,
             payment_method_type: api_models::enums::PaymentMethod::Reward.to_string(),
@@ -294,6 +313,7 @@ impl ConnectorApiIntegrationPayload {
                 debit_details,
                 credit_details,
                 gift_card,
+                real_time_payment,
             ]),
             metadata: meta_data,
         }
@@ -25,6 +25,7 @@ impl DashboardRequestPayload {
             maximum_amount: Some(68607706),
             recurring_enabled: true,
             installment_payment_enabled: false,
+            installment_plans: None,
             accepted_currencies: None,
             accepted_countries: None,
             payment_experience: None,
@@ -75,6 +76,7 @@ impl DashboardRequestPayload {
                 maximum_amount: Some(68607706),
                 recurring_enabled: true,
                 installment_payment_enabled: false,
+                installment_plans: None,
                 accepted_currencies: method_type.accepted_currencies,
                 accepted_countries: method_type.accepted_countries,
                 payment_experience: Self::get_payment_experience(
@@ -114,6 +116,7 @@ impl DashboardRequestPayload {
                                         maximum_amount: Some(68607706),
                                         recurring_enabled: true,
                                         installment_payment_enabled: false,
+                                        installment_plans: None,
                                         accepted_currencies: method.accepted_currencies,
                                         accepted_countries: method.accepted_countries,
                                         payment_experience: None,
@@ -134,7 +137,8 @@ impl DashboardRequestPayload {
                     | api_models::enums::PaymentMethod::Upi
                     | api_models::enums::PaymentMethod::Voucher
                     | api_models::enums::PaymentMethod::GiftCard
-                    | api_models::enums::PaymentMethod::CardRedirect => {
+                    | api_models::enums::PaymentMethod::CardRedirect
+                    | api_models::enums::PaymentMethod::RealTimePayment => {
                         if let Some(provider) = payload.provider {
                             let val = Self::transform_payment_method(
                                 request.connector,
@@ -301,6 +305,7 @@ impl DashboardRequestPayload {
                         GoogleApiModelData::Standard(payments::GpayMetaData {
                             merchant_info,
                             allowed_payment_methods: vec![allowed_payment_methods],
+                            payment_processing_details: None,
                         })
                     }
                     GooglePayData::Zen(data) => GoogleApiModelData::Zen(data),
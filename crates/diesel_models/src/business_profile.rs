@@ -1,7 +1,7 @@
 use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 
-use crate::schema::business_profile;
+use crate::{encryption::Encryption, schema::business_profile};
 
 #[derive(
     Clone,
@@ -37,6 +37,15 @@ pub struct BusinessProfile {
     pub authentication_connector_details: Option<serde_json::Value>,
     pub is_extended_card_info_enabled: Option<bool>,
     pub extended_card_info_config: Option<pii::SecretSerdeValue>,
+    pub dunning_config: Option<serde_json::Value>,
+    pub is_webhook_endpoint_disabled: Option<bool>,
+    pub is_network_tokenization_enabled: bool,
+    pub is_tax_calculation_enabled: bool,
+    pub requires_cvc_for_saved_cards: Option<bool>,
+    pub auto_void_after_in_hours: Option<i32>,
+    pub abandoned_payment_config: Option<serde_json::Value>,
+    pub max_auto_retries: Option<i16>,
+    pub wallet_certificates: Option<Encryption>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -65,6 +74,15 @@ pub struct BusinessProfileNew {
     pub authentication_connector_details: Option<serde_json::Value>,
     pub is_extended_card_info_enabled: Option<bool>,
     pub extended_card_info_config: Option<pii::SecretSerdeValue>,
+    pub dunning_config: Option<serde_json::Value>,
+    pub is_webhook_endpoint_disabled: Option<bool>,
+    pub is_network_tokenization_enabled: bool,
+    pub is_tax_calculation_enabled: bool,
+    pub requires_cvc_for_saved_cards: Option<bool>,
+    pub auto_void_after_in_hours: Option<i32>,
+    pub abandoned_payment_config: Option<serde_json::Value>,
+    pub max_auto_retries: Option<i16>,
+    pub wallet_certificates: Option<Encryption>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -90,6 +108,15 @@ pub struct BusinessProfileUpdateInternal {
     pub authentication_connector_details: Option<serde_json::Value>,
     pub is_extended_card_info_enabled: Option<bool>,
     pub extended_card_info_config: Option<pii::SecretSerdeValue>,
+    pub dunning_config: Option<serde_json::Value>,
+    pub is_webhook_endpoint_disabled: Option<bool>,
+    pub is_network_tokenization_enabled: Option<bool>,
+    pub is_tax_calculation_enabled: Option<bool>,
+    pub requires_cvc_for_saved_cards: Option<bool>,
+    pub auto_void_after_in_hours: Option<i32>,
+    pub abandoned_payment_config: Option<serde_json::Value>,
+    pub max_auto_retries: Option<i16>,
+    pub wallet_certificates: Option<Encryption>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -113,10 +140,23 @@ pub enum BusinessProfileUpdate {
         session_expiry: Option<i64>,
         authentication_connector_details: Option<serde_json::Value>,
         extended_card_info_config: Option<pii::SecretSerdeValue>,
+        dunning_config: Option<serde_json::Value>,
+        is_network_tokenization_enabled: Option<bool>,
+        is_tax_calculation_enabled: Option<bool>,
+        requires_cvc_for_saved_cards: Option<bool>,
+        auto_void_after_in_hours: Option<i32>,
+        abandoned_payment_config: Option<serde_json::Value>,
+        max_auto_retries: Option<i16>,
     },
     ExtendedCardInfoUpdate {
         is_extended_card_info_enabled: Option<bool>,
     },
+    WebhookEndpointStatusUpdate {
+        is_webhook_endpoint_disabled: Option<bool>,
+    },
+    WalletCertificatesUpdate {
+        wallet_certificates: Option<Encryption>,
+    },
 }
 
 impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
@@ -141,6 +181,13 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 session_expiry,
                 authentication_connector_details,
                 extended_card_info_config,
+                dunning_config,
+                is_network_tokenization_enabled,
+                is_tax_calculation_enabled,
+                requires_cvc_for_saved_cards,
+                auto_void_after_in_hours,
+                abandoned_payment_config,
+                max_auto_retries,
             } => Self {
                 profile_name,
                 modified_at,
@@ -160,6 +207,13 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 session_expiry,
                 authentication_connector_details,
                 extended_card_info_config,
+                dunning_config,
+                is_network_tokenization_enabled,
+                is_tax_calculation_enabled,
+                requires_cvc_for_saved_cards,
+                auto_void_after_in_hours,
+                abandoned_payment_config,
+                max_auto_retries,
                 ..Default::default()
             },
             BusinessProfileUpdate::ExtendedCardInfoUpdate {
@@ -168,6 +222,18 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 is_extended_card_info_enabled,
                 ..Default::default()
             },
+            BusinessProfileUpdate::WebhookEndpointStatusUpdate {
+                is_webhook_endpoint_disabled,
+            } => Self {
+                is_webhook_endpoint_disabled,
+                ..Default::default()
+            },
+            BusinessProfileUpdate::WalletCertificatesUpdate {
+                wallet_certificates,
+            } => Self {
+                wallet_certificates,
+                ..Default::default()
+            },
         }
     }
 }
@@ -197,6 +263,15 @@ impl From<BusinessProfileNew> for BusinessProfile {
             authentication_connector_details: new.authentication_connector_details,
             is_extended_card_info_enabled: new.is_extended_card_info_enabled,
             extended_card_info_config: new.extended_card_info_config,
+            dunning_config: new.dunning_config,
+            is_webhook_endpoint_disabled: new.is_webhook_endpoint_disabled,
+            is_network_tokenization_enabled: new.is_network_tokenization_enabled,
+            is_tax_calculation_enabled: new.is_tax_calculation_enabled,
+            requires_cvc_for_saved_cards: new.requires_cvc_for_saved_cards,
+            auto_void_after_in_hours: new.auto_void_after_in_hours,
+            abandoned_payment_config: new.abandoned_payment_config,
+            max_auto_retries: new.max_auto_retries,
+            wallet_certificates: new.wallet_certificates,
         }
     }
 }
@@ -223,6 +298,15 @@ impl BusinessProfileUpdate {
             authentication_connector_details,
             is_extended_card_info_enabled,
             extended_card_info_config,
+            dunning_config,
+            is_webhook_endpoint_disabled,
+            is_network_tokenization_enabled,
+            is_tax_calculation_enabled,
+            requires_cvc_for_saved_cards,
+            auto_void_after_in_hours,
+            abandoned_payment_config,
+            max_auto_retries,
+            wallet_certificates,
         } = self.into();
         BusinessProfile {
             profile_name: profile_name.unwrap_or(source.profile_name),
@@ -246,7 +330,28 @@ impl BusinessProfileUpdate {
             authentication_connector_details,
             is_extended_card_info_enabled,
             extended_card_info_config,
+            dunning_config,
+            is_webhook_endpoint_disabled: is_webhook_endpoint_disabled
+                .or(source.is_webhook_endpoint_disabled),
+            is_network_tokenization_enabled: is_network_tokenization_enabled
+                .unwrap_or(source.is_network_tokenization_enabled),
+            is_tax_calculation_enabled: is_tax_calculation_enabled
+                .unwrap_or(source.is_tax_calculation_enabled),
+            requires_cvc_for_saved_cards: requires_cvc_for_saved_cards
+                .or(source.requires_cvc_for_saved_cards),
+            auto_void_after_in_hours: auto_void_after_in_hours.or(source.auto_void_after_in_hours),
+            abandoned_payment_config: abandoned_payment_config.or(source.abandoned_payment_config),
+            max_auto_retries: max_auto_retries.or(source.max_auto_retries),
+            wallet_certificates: wallet_certificates.or(source.wallet_certificates),
             ..source
         }
     }
 }
+
+// Tracking data by process_tracker
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct ApplepayDomainRenewalTrackingData {
+    pub merchant_id: String,
+    // Business profile whose Apple Pay domains are registered again
+    pub profile_id: String,
+}
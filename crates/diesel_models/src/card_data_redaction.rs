@@ -0,0 +1,95 @@
+use diesel::{sql_types::Text, QueryableByName};
+
+/// A column of a table which is scanned for the card numbers stored in it by mistake. The names
+/// of the table and of the column are interpolated in the queries, so they are restricted to
+/// lowercase letters, digits and underscores.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RedactionTarget {
+    table: String,
+    column: String,
+}
+
+impl RedactionTarget {
+    pub fn new(table: &str, column: &str) -> Option<Self> {
+        (is_valid_identifier(table) && is_valid_identifier(column)).then(|| Self {
+            table: table.to_string(),
+            column: column.to_string(),
+        })
+    }
+
+    pub fn table(&self) -> &str {
+        &self.table
+    }
+
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+}
+
+fn is_valid_identifier(identifier: &str) -> bool {
+    identifier
+        .chars()
+        .next()
+        .is_some_and(|first| first.is_ascii_lowercase() || first == '_')
+        && identifier
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// The types of the columns which can be redacted, as the redacted values are written back as text
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RedactionColumnType {
+    Text,
+    Json,
+    Jsonb,
+}
+
+impl RedactionColumnType {
+    /// The type of the column, from its `data_type` in the information schema
+    pub fn from_data_type(data_type: &str) -> Option<Self> {
+        match data_type {
+            "text" | "character varying" | "character" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "jsonb" => Some(Self::Jsonb),
+            _ => None,
+        }
+    }
+
+    pub fn sql_type(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Json => "json",
+            Self::Jsonb => "jsonb",
+        }
+    }
+}
+
+/// A value of the column which may hold a card number, with the physical location of its row
+/// (`ctid`) when it was read
+#[derive(Clone, Debug, QueryableByName)]
+pub struct RedactionCandidate {
+    #[diesel(sql_type = Text)]
+    pub row_id: String,
+    #[diesel(sql_type = Text)]
+    pub value: String,
+}
+
+#[derive(Clone, Debug, QueryableByName)]
+pub struct ColumnDataType {
+    #[diesel(sql_type = Text)]
+    pub data_type: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RedactionTarget;
+
+    #[test]
+    fn test_redaction_target_identifiers() {
+        assert!(RedactionTarget::new("payment_attempt", "error_message").is_some());
+        assert!(RedactionTarget::new("payment_attempt", "").is_none());
+        assert!(RedactionTarget::new("1payment_attempt", "error_message").is_none());
+        assert!(RedactionTarget::new("payment_attempt", "error_message\" = ''; --").is_none());
+        assert!(RedactionTarget::new("Payment_Attempt", "error_message").is_none());
+    }
+}
@@ -3,6 +3,7 @@ pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
+pub mod card_data_redaction;
 pub mod cards_info;
 pub mod configs;
 
@@ -43,6 +44,7 @@ pub mod role;
 pub mod routing_algorithm;
 #[allow(unused_qualifications)]
 pub mod schema;
+pub mod usage_metering;
 pub mod user;
 pub mod user_role;
 
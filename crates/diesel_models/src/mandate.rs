@@ -32,6 +32,7 @@ pub struct Mandate {
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
     pub original_payment_id: Option<String>,
     pub merchant_connector_id: Option<String>,
+    pub pre_notification_days: Option<i16>,
 }
 
 #[derive(
@@ -69,6 +70,7 @@ pub struct MandateNew {
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
     pub original_payment_id: Option<String>,
     pub merchant_connector_id: Option<String>,
+    pub pre_notification_days: Option<i16>,
 }
 
 #[derive(Debug)]
@@ -88,6 +90,12 @@ pub enum MandateUpdate {
         payment_method_id: String,
         original_payment_id: Option<String>,
     },
+    ConnectorMigrationUpdate {
+        connector: String,
+        merchant_connector_id: String,
+        connector_mandate_id: Option<String>,
+        connector_mandate_ids: Option<pii::SecretSerdeValue>,
+    },
 }
 
 #[derive(Clone, Eq, PartialEq, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -109,10 +117,12 @@ pub struct SingleUseMandate {
 pub struct MandateUpdateInternal {
     mandate_status: Option<storage_enums::MandateStatus>,
     amount_captured: Option<i64>,
-    connector_mandate_ids: Option<pii::SecretSerdeValue>,
-    connector_mandate_id: Option<String>,
+    connector_mandate_ids: Option<Option<pii::SecretSerdeValue>>,
+    connector_mandate_id: Option<Option<String>>,
     payment_method_id: Option<String>,
     original_payment_id: Option<String>,
+    connector: Option<String>,
+    merchant_connector_id: Option<String>,
 }
 
 impl From<MandateUpdate> for MandateUpdateInternal {
@@ -125,6 +135,8 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 connector_mandate_id: None,
                 payment_method_id: None,
                 original_payment_id: None,
+                connector: None,
+                merchant_connector_id: None,
             },
             MandateUpdate::CaptureAmountUpdate { amount_captured } => Self {
                 mandate_status: None,
@@ -133,11 +145,13 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 connector_mandate_id: None,
                 payment_method_id: None,
                 original_payment_id: None,
+                connector: None,
+                merchant_connector_id: None,
             },
             MandateUpdate::ConnectorReferenceUpdate {
                 connector_mandate_ids,
             } => Self {
-                connector_mandate_ids,
+                connector_mandate_ids: connector_mandate_ids.map(Some),
                 ..Default::default()
             },
             MandateUpdate::ConnectorMandateIdUpdate {
@@ -146,12 +160,26 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 payment_method_id,
                 original_payment_id,
             } => Self {
-                connector_mandate_id,
-                connector_mandate_ids,
+                connector_mandate_id: connector_mandate_id.map(Some),
+                connector_mandate_ids: connector_mandate_ids.map(Some),
                 payment_method_id: Some(payment_method_id),
                 original_payment_id,
                 ..Default::default()
             },
+            // The connector references are replaced even if they are not specified, as the ones
+            // of the previous connector cannot be used with the new connector
+            MandateUpdate::ConnectorMigrationUpdate {
+                connector,
+                merchant_connector_id,
+                connector_mandate_id,
+                connector_mandate_ids,
+            } => Self {
+                connector_mandate_id: Some(connector_mandate_id),
+                connector_mandate_ids: Some(connector_mandate_ids),
+                connector: Some(connector),
+                merchant_connector_id: Some(merchant_connector_id),
+                ..Default::default()
+            },
         }
     }
 }
@@ -165,15 +193,19 @@ impl MandateUpdateInternal {
             connector_mandate_id,
             payment_method_id,
             original_payment_id,
+            connector,
+            merchant_connector_id,
         } = self;
 
         Mandate {
             mandate_status: mandate_status.unwrap_or(source.mandate_status),
             amount_captured: amount_captured.map_or(source.amount_captured, Some),
-            connector_mandate_ids: connector_mandate_ids.map_or(source.connector_mandate_ids, Some),
-            connector_mandate_id: connector_mandate_id.map_or(source.connector_mandate_id, Some),
+            connector_mandate_ids: connector_mandate_ids.unwrap_or(source.connector_mandate_ids),
+            connector_mandate_id: connector_mandate_id.unwrap_or(source.connector_mandate_id),
             payment_method_id: payment_method_id.unwrap_or(source.payment_method_id),
             original_payment_id: original_payment_id.map_or(source.original_payment_id, Some),
+            connector: connector.unwrap_or(source.connector),
+            merchant_connector_id: merchant_connector_id.map_or(source.merchant_connector_id, Some),
             ..source
         }
     }
@@ -208,6 +240,7 @@ impl From<&MandateNew> for Mandate {
             connector_mandate_ids: mandate_new.connector_mandate_ids.clone(),
             original_payment_id: mandate_new.original_payment_id.clone(),
             merchant_connector_id: mandate_new.merchant_connector_id.clone(),
+            pre_notification_days: mandate_new.pre_notification_days,
         }
     }
 }
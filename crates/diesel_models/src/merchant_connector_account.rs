@@ -124,3 +124,10 @@ impl MerchantConnectorAccountUpdateInternal {
         }
     }
 }
+
+// Tracking data by process_tracker
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct ConnectorSmokeTestTrackingData {
+    // Merchant whose enabled sandbox connectors are smoke tested
+    pub merchant_id: String,
+}
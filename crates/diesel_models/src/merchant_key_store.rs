@@ -20,6 +20,8 @@ pub struct MerchantKeyStore {
     pub key: Encryption,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    /// URI of the merchant provided key wrapping `key`, if the merchant holds custody of its key
+    pub external_key_uri: Option<String>,
 }
 
 #[derive(
@@ -30,6 +32,7 @@ pub struct MerchantKeyStoreNew {
     pub merchant_id: String,
     pub key: Encryption,
     pub created_at: PrimitiveDateTime,
+    pub external_key_uri: Option<String>,
 }
 
 #[derive(
@@ -37,6 +40,28 @@ pub struct MerchantKeyStoreNew {
 )]
 #[diesel(table_name = merchant_key_store)]
 pub struct MerchantKeyStoreUpdateInternal {
-    pub merchant_id: String,
-    pub key: Encryption,
+    pub key: Option<Encryption>,
+    pub external_key_uri: Option<Option<String>>,
+}
+
+#[derive(Debug)]
+pub enum MerchantKeyStoreUpdate {
+    KeyCustodyUpdate {
+        key: Encryption,
+        external_key_uri: Option<String>,
+    },
+}
+
+impl From<MerchantKeyStoreUpdate> for MerchantKeyStoreUpdateInternal {
+    fn from(merchant_key_store_update: MerchantKeyStoreUpdate) -> Self {
+        match merchant_key_store_update {
+            MerchantKeyStoreUpdate::KeyCustodyUpdate {
+                key,
+                external_key_uri,
+            } => Self {
+                key: Some(key),
+                external_key_uri: Some(external_key_uri),
+            },
+        }
+    }
 }
@@ -70,6 +70,11 @@ pub struct PaymentAttempt {
     pub mandate_data: Option<storage_enums::MandateDetails>,
     pub fingerprint_id: Option<String>,
     pub payment_method_billing_address_id: Option<String>,
+    pub connector_request_id: Option<String>,
+    pub extended_authorization_applied: Option<bool>,
+    pub capture_before: Option<PrimitiveDateTime>,
+    pub amount_reversed: Option<i64>,
+    pub overcaptured_amount: Option<i64>,
 }
 
 impl PaymentAttempt {
@@ -150,6 +155,11 @@ pub struct PaymentAttemptNew {
     pub mandate_data: Option<storage_enums::MandateDetails>,
     pub fingerprint_id: Option<String>,
     pub payment_method_billing_address_id: Option<String>,
+    pub connector_request_id: Option<String>,
+    pub extended_authorization_applied: Option<bool>,
+    pub capture_before: Option<PrimitiveDateTime>,
+    pub amount_reversed: Option<i64>,
+    pub overcaptured_amount: Option<i64>,
 }
 
 impl PaymentAttemptNew {
@@ -267,7 +277,11 @@ pub enum PaymentAttemptUpdate {
         error_message: Option<Option<String>>,
         error_reason: Option<Option<String>>,
         connector_response_reference_id: Option<String>,
+        connector_request_id: Option<String>,
+        extended_authorization_applied: Option<bool>,
+        capture_before: Option<PrimitiveDateTime>,
         amount_capturable: Option<i64>,
+        overcaptured_amount: Option<i64>,
         updated_by: String,
         authentication_data: Option<serde_json::Value>,
         encoded_data: Option<String>,
@@ -301,6 +315,7 @@ pub enum PaymentAttemptUpdate {
         unified_code: Option<Option<String>>,
         unified_message: Option<Option<String>>,
         connector_transaction_id: Option<String>,
+        connector_request_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
     },
     CaptureUpdate {
@@ -311,6 +326,13 @@ pub enum PaymentAttemptUpdate {
     AmountToCaptureUpdate {
         status: storage_enums::AttemptStatus,
         amount_capturable: i64,
+        overcaptured_amount: Option<i64>,
+        updated_by: String,
+    },
+    PartialReversalUpdate {
+        status: storage_enums::AttemptStatus,
+        amount_reversed: i64,
+        cancellation_reason: Option<String>,
         updated_by: String,
     },
     PreprocessingUpdate {
@@ -340,6 +362,11 @@ pub enum PaymentAttemptUpdate {
         authentication_id: Option<String>,
         updated_by: String,
     },
+    TaxCalculationUpdate {
+        amount: i64,
+        amount_capturable: i64,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -387,6 +414,11 @@ pub struct PaymentAttemptUpdateInternal {
     authentication_id: Option<String>,
     fingerprint_id: Option<String>,
     payment_method_billing_address_id: Option<String>,
+    connector_request_id: Option<String>,
+    extended_authorization_applied: Option<bool>,
+    capture_before: Option<PrimitiveDateTime>,
+    amount_reversed: Option<i64>,
+    overcaptured_amount: Option<i64>,
 }
 
 impl PaymentAttemptUpdateInternal {
@@ -452,6 +484,11 @@ impl PaymentAttemptUpdate {
             authentication_id,
             payment_method_billing_address_id,
             fingerprint_id,
+            connector_request_id,
+            extended_authorization_applied,
+            capture_before,
+            amount_reversed,
+            overcaptured_amount,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -500,6 +537,12 @@ impl PaymentAttemptUpdate {
             payment_method_billing_address_id: payment_method_billing_address_id
                 .or(source.payment_method_billing_address_id),
             fingerprint_id: fingerprint_id.or(source.fingerprint_id),
+            connector_request_id: connector_request_id.or(source.connector_request_id),
+            extended_authorization_applied: extended_authorization_applied
+                .or(source.extended_authorization_applied),
+            capture_before: capture_before.or(source.capture_before),
+            amount_reversed: amount_reversed.or(source.amount_reversed),
+            overcaptured_amount: overcaptured_amount.or(source.overcaptured_amount),
             ..source
         }
     }
@@ -672,7 +715,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_message,
                 error_reason,
                 connector_response_reference_id,
+                connector_request_id,
+                extended_authorization_applied,
+                capture_before,
                 amount_capturable,
+                overcaptured_amount,
                 updated_by,
                 authentication_data,
                 encoded_data,
@@ -693,7 +740,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_token,
                 error_reason,
                 connector_response_reference_id,
+                connector_request_id,
+                extended_authorization_applied,
+                capture_before,
                 amount_capturable,
+                overcaptured_amount,
                 updated_by,
                 authentication_data,
                 encoded_data,
@@ -713,6 +764,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_code,
                 unified_message,
                 connector_transaction_id,
+                connector_request_id,
                 payment_method_data,
             } => Self {
                 connector: connector.map(Some),
@@ -726,6 +778,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_code,
                 unified_message,
                 connector_transaction_id,
+                connector_request_id,
                 payment_method_data,
                 ..Default::default()
             },
@@ -809,10 +862,26 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
                 amount_capturable,
+                overcaptured_amount,
                 updated_by,
             } => Self {
                 status: Some(status),
                 amount_capturable: Some(amount_capturable),
+                overcaptured_amount,
+                updated_by,
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::PartialReversalUpdate {
+                status,
+                amount_reversed,
+                cancellation_reason,
+                updated_by,
+            } => Self {
+                status: Some(status),
+                // the uncaptured amount is released, so nothing more can be captured
+                amount_capturable: Some(0),
+                amount_reversed: Some(amount_reversed),
+                cancellation_reason,
                 updated_by,
                 ..Default::default()
             },
@@ -852,6 +921,16 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            PaymentAttemptUpdate::TaxCalculationUpdate {
+                amount,
+                amount_capturable,
+                updated_by,
+            } => Self {
+                amount: Some(amount),
+                amount_capturable: Some(amount_capturable),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
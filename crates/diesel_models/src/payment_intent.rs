@@ -58,6 +58,15 @@ pub struct PaymentIntent {
     pub session_expiry: Option<PrimitiveDateTime>,
     pub fingerprint_id: Option<String>,
     pub request_external_three_ds_authentication: Option<bool>,
+    pub request_overcapture: Option<bool>,
+    pub order_tax_amount: Option<i64>,
+    pub split_payments: Option<serde_json::Value>,
+    pub payment_channel: Option<storage_enums::PaymentChannel>,
+    pub l2_l3_data: Option<serde_json::Value>,
+    pub tags: Option<Vec<String>>,
+    pub request_extended_authorization: Option<bool>,
+    pub capture_adjustment_reason: Option<storage_enums::CaptureAdjustmentReason>,
+    pub installment_tenure: Option<i16>,
 }
 
 #[derive(
@@ -111,6 +120,15 @@ pub struct PaymentIntentNew {
     pub session_expiry: Option<PrimitiveDateTime>,
     pub fingerprint_id: Option<String>,
     pub request_external_three_ds_authentication: Option<bool>,
+    pub request_overcapture: Option<bool>,
+    pub order_tax_amount: Option<i64>,
+    pub split_payments: Option<serde_json::Value>,
+    pub payment_channel: Option<storage_enums::PaymentChannel>,
+    pub l2_l3_data: Option<serde_json::Value>,
+    pub tags: Option<Vec<String>>,
+    pub request_extended_authorization: Option<bool>,
+    pub capture_adjustment_reason: Option<storage_enums::CaptureAdjustmentReason>,
+    pub installment_tenure: Option<i16>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -167,6 +185,7 @@ pub enum PaymentIntentUpdate {
         session_expiry: Option<PrimitiveDateTime>,
         fingerprint_id: Option<String>,
         request_external_three_ds_authentication: Option<bool>,
+        installment_tenure: Option<i16>,
     },
     PaymentAttemptAndAttemptCountUpdate {
         active_attempt_id: String,
@@ -199,6 +218,29 @@ pub enum PaymentIntentUpdate {
     AuthorizationCountUpdate {
         authorization_count: i32,
     },
+    PaymentLinkUpdate {
+        payment_link_id: String,
+        updated_by: String,
+    },
+    TaxCalculationUpdate {
+        amount: i64,
+        order_tax_amount: i64,
+        shipping_address_id: Option<String>,
+        updated_by: String,
+    },
+    TagsUpdate {
+        tags: Vec<String>,
+        updated_by: String,
+    },
+    ExpiryUpdate {
+        status: storage_enums::IntentStatus,
+        updated_by: String,
+    },
+    CaptureAdjustmentUpdate {
+        capture_adjustment_reason: storage_enums::CaptureAdjustmentReason,
+        order_details: Option<Vec<pii::SecretSerdeValue>>,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -236,6 +278,12 @@ pub struct PaymentIntentUpdateInternal {
     pub session_expiry: Option<PrimitiveDateTime>,
     pub fingerprint_id: Option<String>,
     pub request_external_three_ds_authentication: Option<bool>,
+    pub payment_link_id: Option<String>,
+    pub order_tax_amount: Option<i64>,
+    pub tags: Option<Vec<String>>,
+    pub client_secret: Option<Option<String>>,
+    pub capture_adjustment_reason: Option<storage_enums::CaptureAdjustmentReason>,
+    pub installment_tenure: Option<i16>,
 }
 
 impl PaymentIntentUpdate {
@@ -271,6 +319,12 @@ impl PaymentIntentUpdate {
             session_expiry,
             fingerprint_id,
             request_external_three_ds_authentication,
+            payment_link_id,
+            order_tax_amount,
+            tags,
+            client_secret,
+            capture_adjustment_reason,
+            installment_tenure,
         } = self.into();
         PaymentIntent {
             amount: amount.unwrap_or(source.amount),
@@ -308,6 +362,13 @@ impl PaymentIntentUpdate {
             session_expiry: session_expiry.or(source.session_expiry),
             request_external_three_ds_authentication: request_external_three_ds_authentication
                 .or(source.request_external_three_ds_authentication),
+            payment_link_id: payment_link_id.or(source.payment_link_id),
+            order_tax_amount: order_tax_amount.or(source.order_tax_amount),
+            tags: tags.or(source.tags),
+            client_secret: client_secret.unwrap_or(source.client_secret),
+            capture_adjustment_reason: capture_adjustment_reason
+                .or(source.capture_adjustment_reason),
+            installment_tenure: installment_tenure.or(source.installment_tenure),
             ..source
         }
     }
@@ -337,6 +398,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 session_expiry,
                 fingerprint_id,
                 request_external_three_ds_authentication,
+                installment_tenure,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -359,6 +421,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 session_expiry,
                 fingerprint_id,
                 request_external_three_ds_authentication,
+                installment_tenure,
                 ..Default::default()
             },
             PaymentIntentUpdate::MetadataUpdate {
@@ -494,6 +557,49 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 authorization_count: Some(authorization_count),
                 ..Default::default()
             },
+            PaymentIntentUpdate::PaymentLinkUpdate {
+                payment_link_id,
+                updated_by,
+            } => Self {
+                payment_link_id: Some(payment_link_id),
+                updated_by,
+                ..Default::default()
+            },
+            PaymentIntentUpdate::TaxCalculationUpdate {
+                amount,
+                order_tax_amount,
+                shipping_address_id,
+                updated_by,
+            } => Self {
+                amount: Some(amount),
+                order_tax_amount: Some(order_tax_amount),
+                shipping_address_id,
+                updated_by,
+                ..Default::default()
+            },
+            PaymentIntentUpdate::TagsUpdate { tags, updated_by } => Self {
+                tags: Some(tags),
+                updated_by,
+                ..Default::default()
+            },
+            // The client secret is invalidated so that the payment cannot be confirmed anymore
+            PaymentIntentUpdate::ExpiryUpdate { status, updated_by } => Self {
+                status: Some(status),
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                client_secret: Some(None),
+                ..Default::default()
+            },
+            PaymentIntentUpdate::CaptureAdjustmentUpdate {
+                capture_adjustment_reason,
+                order_details,
+                updated_by,
+            } => Self {
+                capture_adjustment_reason: Some(capture_adjustment_reason),
+                order_details,
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -542,7 +648,16 @@ mod tests {
     "incremental_authorization_allowed": null,
     "authorization_count": null,
     "session_expiry": null,
-    "fingerprint_id": null
+    "fingerprint_id": null,
+    "request_overcapture": null,
+    "order_tax_amount": null,
+    "split_payments": null,
+    "payment_channel": null,
+    "l2_l3_data": null,
+    "tags": null,
+    "request_extended_authorization": null,
+    "capture_adjustment_reason": null,
+    "installment_tenure": null
 }"#;
         let deserialized_payment_intent =
             serde_json::from_str::<super::PaymentIntent>(serialized_payment_intent);
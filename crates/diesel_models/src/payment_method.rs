@@ -40,6 +40,8 @@ pub struct PaymentMethod {
     pub status: storage_enums::PaymentMethodStatus,
     pub network_transaction_id: Option<String>,
     pub client_secret: Option<String>,
+    pub network_token_requestor_reference_id: Option<String>,
+    pub network_token_locker_id: Option<String>,
 }
 
 #[derive(
@@ -75,6 +77,8 @@ pub struct PaymentMethodNew {
     pub status: storage_enums::PaymentMethodStatus,
     pub network_transaction_id: Option<String>,
     pub client_secret: Option<String>,
+    pub network_token_requestor_reference_id: Option<String>,
+    pub network_token_locker_id: Option<String>,
 }
 
 impl Default for PaymentMethodNew {
@@ -110,6 +114,8 @@ impl Default for PaymentMethodNew {
             status: storage_enums::PaymentMethodStatus::Active,
             network_transaction_id: Option::default(),
             client_secret: Option::default(),
+            network_token_requestor_reference_id: Option::default(),
+            network_token_locker_id: Option::default(),
         }
     }
 }
@@ -149,6 +155,10 @@ pub enum PaymentMethodUpdate {
     ConnectorMandateDetailsUpdate {
         connector_mandate_details: Option<serde_json::Value>,
     },
+    NetworkTokenDataUpdate {
+        network_token_requestor_reference_id: Option<String>,
+        network_token_locker_id: Option<String>,
+    },
 }
 
 #[derive(
@@ -166,6 +176,8 @@ pub struct PaymentMethodUpdateInternal {
     connector_mandate_details: Option<serde_json::Value>,
     payment_method_type: Option<storage_enums::PaymentMethodType>,
     payment_method_issuer: Option<String>,
+    network_token_requestor_reference_id: Option<String>,
+    network_token_locker_id: Option<String>,
 }
 
 impl PaymentMethodUpdateInternal {
@@ -183,6 +195,8 @@ impl PaymentMethodUpdateInternal {
             network_transaction_id,
             status,
             connector_mandate_details,
+            network_token_requestor_reference_id,
+            network_token_locker_id,
             ..
         } = self;
 
@@ -195,6 +209,10 @@ impl PaymentMethodUpdateInternal {
             status: status.unwrap_or(source.status),
             connector_mandate_details: connector_mandate_details
                 .map_or(source.connector_mandate_details, Some),
+            network_token_requestor_reference_id: network_token_requestor_reference_id
+                .map_or(source.network_token_requestor_reference_id, Some),
+            network_token_locker_id: network_token_locker_id
+                .map_or(source.network_token_locker_id, Some),
             ..source
         }
     }
@@ -214,6 +232,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 connector_mandate_details: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_requestor_reference_id: None,
+                network_token_locker_id: None,
             },
             PaymentMethodUpdate::PaymentMethodDataUpdate {
                 payment_method_data,
@@ -228,6 +248,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 connector_mandate_details: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_requestor_reference_id: None,
+                network_token_locker_id: None,
             },
             PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
                 metadata: None,
@@ -240,6 +262,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 connector_mandate_details: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_requestor_reference_id: None,
+                network_token_locker_id: None,
             },
             PaymentMethodUpdate::NetworkTransactionIdAndStatusUpdate {
                 network_transaction_id,
@@ -255,6 +279,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 connector_mandate_details: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_requestor_reference_id: None,
+                network_token_locker_id: None,
             },
             PaymentMethodUpdate::StatusUpdate { status } => Self {
                 metadata: None,
@@ -267,6 +293,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 connector_mandate_details: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_requestor_reference_id: None,
+                network_token_locker_id: None,
             },
             PaymentMethodUpdate::AdditionalDataUpdate {
                 payment_method_data,
@@ -286,6 +314,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 connector_mandate_details: None,
                 payment_method_issuer,
                 payment_method_type,
+                network_token_requestor_reference_id: None,
+                network_token_locker_id: None,
             },
             PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
                 connector_mandate_details,
@@ -300,6 +330,25 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_transaction_id: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_requestor_reference_id: None,
+                network_token_locker_id: None,
+            },
+            PaymentMethodUpdate::NetworkTokenDataUpdate {
+                network_token_requestor_reference_id,
+                network_token_locker_id,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                network_token_requestor_reference_id,
+                network_token_locker_id,
             },
         }
     }
@@ -337,6 +386,10 @@ impl From<&PaymentMethodNew> for PaymentMethod {
             status: payment_method_new.status,
             network_transaction_id: payment_method_new.network_transaction_id.clone(),
             client_secret: payment_method_new.client_secret.clone(),
+            network_token_requestor_reference_id: payment_method_new
+                .network_token_requestor_reference_id
+                .clone(),
+            network_token_locker_id: payment_method_new.network_token_locker_id.clone(),
         }
     }
 }
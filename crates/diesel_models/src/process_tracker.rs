@@ -210,6 +210,14 @@ pub enum ProcessTrackerRunner {
     ApiKeyExpiryWorkflow,
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
+    ConnectorSmokeTestWorkflow,
+    ApplepayDomainRenewalWorkflow,
+    PaymentDunningWorkflow,
+    ConnectorMigrationWorkflow,
+    AutoVoidWorkflow,
+    PaymentIntentExpiryWorkflow,
+    AbandonedPaymentNotificationWorkflow,
+    CustomerDataExportWorkflow,
 }
 
 #[cfg(test)]
@@ -3,6 +3,7 @@ pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
 mod capture;
+pub mod card_data_redaction;
 pub mod cards_info;
 pub mod configs;
 
@@ -35,5 +36,6 @@ pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod usage_metering;
 pub mod user;
 pub mod user_role;
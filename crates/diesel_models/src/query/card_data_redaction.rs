@@ -0,0 +1,94 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::sql_types::{BigInt, Text};
+use error_stack::ResultExt;
+
+use crate::{
+    card_data_redaction::{
+        ColumnDataType, RedactionCandidate, RedactionColumnType, RedactionTarget,
+    },
+    errors,
+    query::generics::db_metrics,
+    PgPooledConn, StorageResult,
+};
+
+/// Values holding a run of 13 to 19 digits, which may be grouped by spaces or dashes. The card
+/// numbers among them are told apart by the caller.
+const CARD_NUMBER_CANDIDATE_PATTERN: &str = "[0-9]([ -]?[0-9]){12,18}";
+
+impl RedactionTarget {
+    /// The type of the column as recorded in the information schema, if the column exists
+    pub async fn find_data_type(&self, conn: &PgPooledConn) -> StorageResult<Option<String>> {
+        let query = diesel::sql_query(
+            "SELECT data_type FROM information_schema.columns \
+            WHERE table_schema = current_schema() AND table_name = $1 AND column_name = $2",
+        )
+        .bind::<Text, _>(self.table().to_string())
+        .bind::<Text, _>(self.column().to_string());
+
+        db_metrics::track_database_call::<Self, _, _>(
+            query.get_results_async::<ColumnDataType>(conn),
+            db_metrics::DatabaseOperation::FindOne,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding the type of the column")
+        .map(|data_types| data_types.into_iter().next().map(|column| column.data_type))
+    }
+
+    /// The values of the column which may hold a card number, in the order of the rows, after the
+    /// row of the previous batch
+    pub async fn find_candidates(
+        &self,
+        conn: &PgPooledConn,
+        after_row_id: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<RedactionCandidate>> {
+        let query = diesel::sql_query(format!(
+            r#"SELECT ctid::text AS row_id, "{column}"::text AS value FROM "{table}"
+            WHERE ctid > $1::tid AND "{column}"::text ~ $2 ORDER BY ctid LIMIT $3"#,
+            table = self.table(),
+            column = self.column(),
+        ))
+        .bind::<Text, _>(after_row_id.unwrap_or_else(|| "(0,0)".to_string()))
+        .bind::<Text, _>(CARD_NUMBER_CANDIDATE_PATTERN)
+        .bind::<BigInt, _>(limit);
+
+        db_metrics::track_database_call::<Self, _, _>(
+            query.get_results_async::<RedactionCandidate>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding the values which may hold a card number")
+    }
+
+    /// Replace the value of the column with its redacted value, if the row has not been changed
+    /// since it was read. Returns whether the row was updated.
+    pub async fn update_candidate(
+        &self,
+        conn: &PgPooledConn,
+        column_type: RedactionColumnType,
+        candidate: RedactionCandidate,
+        redacted_value: String,
+    ) -> StorageResult<bool> {
+        let query = diesel::sql_query(format!(
+            r#"UPDATE "{table}" SET "{column}" = CAST($1 AS {column_type})
+            WHERE ctid = $2::tid AND "{column}"::text = $3"#,
+            table = self.table(),
+            column = self.column(),
+            column_type = column_type.sql_type(),
+        ))
+        .bind::<Text, _>(redacted_value)
+        .bind::<Text, _>(candidate.row_id)
+        .bind::<Text, _>(candidate.value);
+
+        db_metrics::track_database_call::<Self, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Update,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while redacting the value")
+        .map(|updated_rows| updated_rows > 0)
+    }
+}
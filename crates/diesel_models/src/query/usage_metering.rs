@@ -0,0 +1,69 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, upsert::excluded, BoolExpressionMethods,
+    ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+
+use crate::{
+    errors,
+    query::generics::db_metrics,
+    schema::usage_metering::dsl,
+    usage_metering::{UsageMetering, UsageMeteringNew},
+    PgPooledConn, StorageResult,
+};
+
+impl UsageMeteringNew {
+    /// Add the count to the usage recorded for the merchant in the period, creating the record
+    /// if this is the first usage of the metric in that period.
+    pub async fn increment(self, conn: &PgPooledConn) -> StorageResult<UsageMetering> {
+        let query = diesel::insert_into(<UsageMetering as HasTable>::table())
+            .values(self)
+            .on_conflict((dsl::merchant_id, dsl::metric, dsl::period_start))
+            .do_update()
+            .set((
+                dsl::count.eq(dsl::count + excluded(dsl::count)),
+                dsl::last_modified_at.eq(excluded(dsl::last_modified_at)),
+            ));
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<UsageMetering as HasTable>::Table, _, _>(
+            query.get_result_async::<UsageMetering>(conn),
+            db_metrics::DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while incrementing usage")
+    }
+}
+
+impl UsageMetering {
+    pub async fn filter_by_period(
+        conn: &PgPooledConn,
+        merchant_id: Option<String>,
+        start_time: time::PrimitiveDateTime,
+        end_time: time::PrimitiveDateTime,
+    ) -> StorageResult<Vec<Self>> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(
+                dsl::period_start
+                    .ge(start_time)
+                    .and(dsl::period_start.le(end_time)),
+            )
+            .order((dsl::merchant_id.asc(), dsl::period_start.asc()))
+            .into_boxed();
+
+        if let Some(merchant_id) = merchant_id {
+            filter = filter.filter(dsl::merchant_id.eq(merchant_id));
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async::<Self>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering usage records by period")
+    }
+}
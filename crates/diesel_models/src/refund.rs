@@ -39,6 +39,8 @@ pub struct Refund {
     pub profile_id: Option<String>,
     pub updated_by: String,
     pub merchant_connector_id: Option<String>,
+    pub capture_id: Option<String>,
+    pub refund_reason_code: Option<storage_enums::RefundReasonCode>,
 }
 
 #[derive(
@@ -81,6 +83,8 @@ pub struct RefundNew {
     pub profile_id: Option<String>,
     pub updated_by: String,
     pub merchant_connector_id: Option<String>,
+    pub capture_id: Option<String>,
+    pub refund_reason_code: Option<storage_enums::RefundReasonCode>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -96,6 +100,7 @@ pub enum RefundUpdate {
     MetadataAndReasonUpdate {
         metadata: Option<pii::SecretSerdeValue>,
         reason: Option<String>,
+        reason_code: Option<storage_enums::RefundReasonCode>,
         updated_by: String,
     },
     StatusUpdate {
@@ -122,6 +127,7 @@ pub struct RefundUpdateInternal {
     refund_arn: Option<String>,
     metadata: Option<pii::SecretSerdeValue>,
     refund_reason: Option<String>,
+    refund_reason_code: Option<storage_enums::RefundReasonCode>,
     refund_error_code: Option<String>,
     updated_by: String,
 }
@@ -136,6 +142,7 @@ impl RefundUpdateInternal {
             refund_arn: self.refund_arn,
             metadata: self.metadata,
             refund_reason: self.refund_reason,
+            refund_reason_code: self.refund_reason_code,
             refund_error_code: self.refund_error_code,
             updated_by: self.updated_by,
             ..source
@@ -165,10 +172,12 @@ impl From<RefundUpdate> for RefundUpdateInternal {
             RefundUpdate::MetadataAndReasonUpdate {
                 metadata,
                 reason,
+                reason_code,
                 updated_by,
             } => Self {
                 metadata,
                 refund_reason: reason,
+                refund_reason_code: reason_code,
                 updated_by,
                 ..Default::default()
             },
@@ -210,6 +219,7 @@ impl RefundUpdate {
             refund_arn,
             metadata,
             refund_reason,
+            refund_reason_code,
             refund_error_code,
             updated_by,
         } = self.into();
@@ -222,6 +232,7 @@ impl RefundUpdate {
             refund_arn: refund_arn.or(source.refund_arn),
             metadata: metadata.or(source.metadata),
             refund_reason: refund_reason.or(source.refund_reason),
+            refund_reason_code: refund_reason_code.or(source.refund_reason_code),
             updated_by,
             ..source
         }
@@ -194,6 +194,15 @@ diesel::table! {
         authentication_connector_details -> Nullable<Jsonb>,
         is_extended_card_info_enabled -> Nullable<Bool>,
         extended_card_info_config -> Nullable<Jsonb>,
+        dunning_config -> Nullable<Jsonb>,
+        is_webhook_endpoint_disabled -> Nullable<Bool>,
+        is_network_tokenization_enabled -> Bool,
+        is_tax_calculation_enabled -> Bool,
+        requires_cvc_for_saved_cards -> Nullable<Bool>,
+        auto_void_after_in_hours -> Nullable<Int4>,
+        abandoned_payment_config -> Nullable<Jsonb>,
+        max_auto_retries -> Nullable<Int2>,
+        wallet_certificates -> Nullable<Bytea>,
     }
 }
 
@@ -482,6 +491,7 @@ diesel::table! {
         unified_code -> Nullable<Varchar>,
         #[max_length = 1024]
         unified_message -> Nullable<Varchar>,
+        step_down_possible -> Bool,
     }
 }
 
@@ -588,6 +598,7 @@ diesel::table! {
         original_payment_id -> Nullable<Varchar>,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        pre_notification_days -> Nullable<Int2>,
     }
 }
 
@@ -631,6 +642,7 @@ diesel::table! {
         default_profile -> Nullable<Varchar>,
         recon_status -> ReconStatus,
         payment_link_config -> Nullable<Jsonb>,
+        feature_entitlements -> Nullable<Jsonb>,
     }
 }
 
@@ -681,6 +693,8 @@ diesel::table! {
         merchant_id -> Varchar,
         key -> Bytea,
         created_at -> Timestamp,
+        #[max_length = 2048]
+        external_key_uri -> Nullable<Varchar>,
     }
 }
 
@@ -776,6 +790,12 @@ diesel::table! {
         fingerprint_id -> Nullable<Varchar>,
         #[max_length = 64]
         payment_method_billing_address_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        connector_request_id -> Nullable<Varchar>,
+        extended_authorization_applied -> Nullable<Bool>,
+        capture_before -> Nullable<Timestamp>,
+        amount_reversed -> Nullable<Int8>,
+        overcaptured_amount -> Nullable<Int8>,
     }
 }
 
@@ -844,6 +864,17 @@ diesel::table! {
         #[max_length = 64]
         fingerprint_id -> Nullable<Varchar>,
         request_external_three_ds_authentication -> Nullable<Bool>,
+        request_overcapture -> Nullable<Bool>,
+        order_tax_amount -> Nullable<Int8>,
+        split_payments -> Nullable<Jsonb>,
+        #[max_length = 16]
+        payment_channel -> Nullable<Varchar>,
+        l2_l3_data -> Nullable<Jsonb>,
+        tags -> Nullable<Array<Nullable<Text>>>,
+        request_extended_authorization -> Nullable<Bool>,
+        #[max_length = 32]
+        capture_adjustment_reason -> Nullable<Varchar>,
+        installment_tenure -> Nullable<Int2>,
     }
 }
 
@@ -925,6 +956,10 @@ diesel::table! {
         network_transaction_id -> Nullable<Varchar>,
         #[max_length = 128]
         client_secret -> Nullable<Varchar>,
+        #[max_length = 128]
+        network_token_requestor_reference_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        network_token_locker_id -> Nullable<Varchar>,
     }
 }
 
@@ -1078,6 +1113,10 @@ diesel::table! {
         updated_by -> Varchar,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        capture_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        refund_reason_code -> Nullable<Varchar>,
     }
 }
 
@@ -1147,6 +1186,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    usage_metering (merchant_id, metric, period_start) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        metric -> Varchar,
+        period_start -> Timestamp,
+        count -> Int8,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1229,6 +1284,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     reverse_lookup,
     roles,
     routing_algorithm,
+    usage_metering,
     user_roles,
     users,
 );
@@ -0,0 +1,32 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::usage_metering;
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = usage_metering)]
+pub struct UsageMeteringNew {
+    pub merchant_id: String,
+    pub metric: common_enums::UsageMetric,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: time::PrimitiveDateTime,
+    pub count: i64,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = usage_metering, primary_key(merchant_id, metric, period_start))]
+pub struct UsageMetering {
+    pub merchant_id: String,
+    pub metric: common_enums::UsageMetric,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: time::PrimitiveDateTime,
+    pub count: i64,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: time::PrimitiveDateTime,
+}
@@ -73,6 +73,11 @@ pub struct PaymentAttemptBatchNew {
     pub mandate_data: Option<MandateDetails>,
     pub payment_method_billing_address_id: Option<String>,
     pub fingerprint_id: Option<String>,
+    pub connector_request_id: Option<String>,
+    pub extended_authorization_applied: Option<bool>,
+    pub capture_before: Option<PrimitiveDateTime>,
+    pub amount_reversed: Option<i64>,
+    pub overcaptured_amount: Option<i64>,
 }
 
 #[allow(dead_code)]
@@ -133,6 +138,11 @@ impl PaymentAttemptBatchNew {
             mandate_data: self.mandate_data,
             payment_method_billing_address_id: self.payment_method_billing_address_id,
             fingerprint_id: self.fingerprint_id,
+            connector_request_id: self.connector_request_id,
+            extended_authorization_applied: self.extended_authorization_applied,
+            capture_before: self.capture_before,
+            amount_reversed: self.amount_reversed,
+            overcaptured_amount: self.overcaptured_amount,
         }
     }
 }
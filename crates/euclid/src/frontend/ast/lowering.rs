@@ -268,6 +268,8 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::GiftCardType => lower_enum!(GiftCardType, value),
 
+        dir::DirKeyKind::RealTimePaymentType => lower_enum!(RealTimePaymentType, value),
+
         dir::DirKeyKind::BankTransferType => lower_enum!(BankTransferType, value),
 
         dir::DirKeyKind::CardRedirectType => lower_enum!(CardRedirectType, value),
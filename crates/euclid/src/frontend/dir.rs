@@ -161,6 +161,13 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "gift_card")]
     GiftCardType,
+    #[strum(
+        serialize = "real_time_payment",
+        detailed_message = "Supported types of Real Time Payment payment method",
+        props(Category = "Payment Method Types")
+    )]
+    #[serde(rename = "real_time_payment")]
+    RealTimePaymentType,
     #[strum(
         serialize = "mandate_acceptance_type",
         detailed_message = "Mode of customer acceptance for mandates - online and offline",
@@ -345,6 +352,7 @@ impl DirKeyKind {
             Self::VoucherType => types::DataType::EnumVariant,
             Self::BankTransferType => types::DataType::EnumVariant,
             Self::GiftCardType => types::DataType::EnumVariant,
+            Self::RealTimePaymentType => types::DataType::EnumVariant,
             Self::BankRedirectType => types::DataType::EnumVariant,
             Self::CryptoType => types::DataType::EnumVariant,
             Self::RewardType => types::DataType::EnumVariant,
@@ -417,6 +425,11 @@ impl DirKeyKind {
                     .map(DirValue::GiftCardType)
                     .collect(),
             ),
+            Self::RealTimePaymentType => Some(
+                enums::RealTimePaymentType::iter()
+                    .map(DirValue::RealTimePaymentType)
+                    .collect(),
+            ),
             Self::BankRedirectType => Some(
                 enums::BankRedirectType::iter()
                     .map(DirValue::BankRedirectType)
@@ -530,6 +543,8 @@ pub enum DirValue {
     RewardType(enums::RewardType),
     #[serde(rename = "gift_card")]
     GiftCardType(enums::GiftCardType),
+    #[serde(rename = "real_time_payment")]
+    RealTimePaymentType(enums::RealTimePaymentType),
     #[serde(rename = "amount")]
     PaymentAmount(types::NumValue),
     #[serde(rename = "currency")]
@@ -583,6 +598,7 @@ impl DirValue {
             Self::CardRedirectType(_) => (DirKeyKind::CardRedirectType, None),
             Self::VoucherType(_) => (DirKeyKind::VoucherType, None),
             Self::GiftCardType(_) => (DirKeyKind::GiftCardType, None),
+            Self::RealTimePaymentType(_) => (DirKeyKind::RealTimePaymentType, None),
         };
 
         DirKey::new(kind, data)
@@ -601,6 +617,7 @@ impl DirValue {
             Self::AuthenticationType(_) => None,
             Self::CaptureMethod(_) => None,
             Self::GiftCardType(_) => None,
+            Self::RealTimePaymentType(_) => None,
             Self::PaymentAmount(_) => None,
             Self::PaymentCurrency(_) => None,
             Self::BusinessCountry(_) => None,
@@ -646,6 +663,7 @@ impl DirValue {
             (Self::BankRedirectType(brt1), Self::BankRedirectType(brt2)) => brt1 == brt2,
             (Self::BankTransferType(btt1), Self::BankTransferType(btt2)) => btt1 == btt2,
             (Self::GiftCardType(gct1), Self::GiftCardType(gct2)) => gct1 == gct2,
+            (Self::RealTimePaymentType(rtpt1), Self::RealTimePaymentType(rtpt2)) => rtpt1 == rtpt2,
             (Self::CryptoType(ct1), Self::CryptoType(ct2)) => ct1 == ct2,
             (Self::AuthenticationType(at1), Self::AuthenticationType(at2)) => at1 == at2,
             (Self::CaptureMethod(cm1), Self::CaptureMethod(cm2)) => cm1 == cm2,
@@ -81,6 +81,7 @@ pub enum WalletType {
     SamsungPay,
     GoPay,
     KakaoPay,
+    Skrill,
     Twint,
     Gcash,
     Vipps,
@@ -90,6 +91,8 @@ pub enum WalletType {
     Swish,
     Cashapp,
     Venmo,
+    ClickToPay,
+    Payoneer,
 }
 
 #[derive(
@@ -210,6 +213,27 @@ pub enum GiftCardType {
     Givex,
 }
 
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::VariantNames,
+    strum::EnumIter,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RealTimePaymentType {
+    PayNow,
+    PromptPay,
+    UpiQr,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -322,5 +346,6 @@ collect_variants!(RewardType);
 collect_variants!(UpiType);
 collect_variants!(VoucherType);
 collect_variants!(GiftCardType);
+collect_variants!(RealTimePaymentType);
 collect_variants!(BankTransferType);
 collect_variants!(CardRedirectType);
@@ -48,6 +48,7 @@ impl From<enums::WalletType> for global_enums::PaymentMethodType {
             enums::WalletType::SamsungPay => Self::SamsungPay,
             enums::WalletType::GoPay => Self::GoPay,
             enums::WalletType::KakaoPay => Self::KakaoPay,
+            enums::WalletType::Skrill => Self::Skrill,
             enums::WalletType::Twint => Self::Twint,
             enums::WalletType::Gcash => Self::Gcash,
             enums::WalletType::Vipps => Self::Vipps,
@@ -57,6 +58,8 @@ impl From<enums::WalletType> for global_enums::PaymentMethodType {
             enums::WalletType::Swish => Self::Swish,
             enums::WalletType::Cashapp => Self::Cashapp,
             enums::WalletType::Venmo => Self::Venmo,
+            enums::WalletType::ClickToPay => Self::ClickToPay,
+            enums::WalletType::Payoneer => Self::Payoneer,
         }
     }
 }
@@ -130,6 +133,16 @@ impl From<enums::GiftCardType> for global_enums::PaymentMethodType {
     }
 }
 
+impl From<enums::RealTimePaymentType> for global_enums::PaymentMethodType {
+    fn from(value: enums::RealTimePaymentType) -> Self {
+        match value {
+            enums::RealTimePaymentType::PayNow => Self::PayNow,
+            enums::RealTimePaymentType::PromptPay => Self::PromptPay,
+            enums::RealTimePaymentType::UpiQr => Self::UpiQr,
+        }
+    }
+}
+
 impl From<enums::CardRedirectType> for global_enums::PaymentMethodType {
     fn from(value: enums::CardRedirectType) -> Self {
         match value {
@@ -204,6 +217,7 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::VoucherType(vt) => EuclidValue::PaymentMethodType(vt.into()),
         dir::DirValue::BankTransferType(btt) => EuclidValue::PaymentMethodType(btt.into()),
         dir::DirValue::GiftCardType(gct) => EuclidValue::PaymentMethodType(gct.into()),
+        dir::DirValue::RealTimePaymentType(rtpt) => EuclidValue::PaymentMethodType(rtpt.into()),
         dir::DirValue::CardRedirectType(crt) => EuclidValue::PaymentMethodType(crt.into()),
         dir::DirValue::BankRedirectType(brt) => EuclidValue::PaymentMethodType(brt.into()),
         dir::DirValue::CryptoType(ct) => EuclidValue::PaymentMethodType(ct.into()),
@@ -35,7 +35,10 @@ impl IntoDirValue for (global_enums::PaymentMethodType, global_enums::PaymentMet
                 | global_enums::PaymentMethod::Reward
                 | global_enums::PaymentMethod::Upi
                 | global_enums::PaymentMethod::Voucher
-                | global_enums::PaymentMethod::GiftCard => Err(AnalysisErrorType::NotSupported),
+                | global_enums::PaymentMethod::GiftCard
+                | global_enums::PaymentMethod::RealTimePayment => {
+                    Err(AnalysisErrorType::NotSupported)
+                }
             },
             global_enums::PaymentMethodType::Bacs => match self.1 {
                 global_enums::PaymentMethod::BankDebit => Ok(dirval!(BankDebitType = Bacs)),
@@ -49,7 +52,10 @@ impl IntoDirValue for (global_enums::PaymentMethodType, global_enums::PaymentMet
                 | global_enums::PaymentMethod::Reward
                 | global_enums::PaymentMethod::Upi
                 | global_enums::PaymentMethod::Voucher
-                | global_enums::PaymentMethod::GiftCard => Err(AnalysisErrorType::NotSupported),
+                | global_enums::PaymentMethod::GiftCard
+                | global_enums::PaymentMethod::RealTimePayment => {
+                    Err(AnalysisErrorType::NotSupported)
+                }
             },
             global_enums::PaymentMethodType::Becs => Ok(dirval!(BankDebitType = Becs)),
             global_enums::PaymentMethodType::Sepa => match self.1 {
@@ -64,7 +70,10 @@ impl IntoDirValue for (global_enums::PaymentMethodType, global_enums::PaymentMet
                 | global_enums::PaymentMethod::Reward
                 | global_enums::PaymentMethod::Upi
                 | global_enums::PaymentMethod::Voucher
-                | global_enums::PaymentMethod::GiftCard => Err(AnalysisErrorType::NotSupported),
+                | global_enums::PaymentMethod::GiftCard
+                | global_enums::PaymentMethod::RealTimePayment => {
+                    Err(AnalysisErrorType::NotSupported)
+                }
             },
             global_enums::PaymentMethodType::AliPay => Ok(dirval!(WalletType = AliPay)),
             global_enums::PaymentMethodType::AliPayHk => Ok(dirval!(WalletType = AliPayHk)),
@@ -75,6 +84,7 @@ impl IntoDirValue for (global_enums::PaymentMethodType, global_enums::PaymentMet
             global_enums::PaymentMethodType::MbWay => Ok(dirval!(WalletType = MbWay)),
             global_enums::PaymentMethodType::MobilePay => Ok(dirval!(WalletType = MobilePay)),
             global_enums::PaymentMethodType::Cashapp => Ok(dirval!(WalletType = Cashapp)),
+            global_enums::PaymentMethodType::ClickToPay => Ok(dirval!(WalletType = ClickToPay)),
             global_enums::PaymentMethodType::Multibanco => {
                 Ok(dirval!(BankTransferType = Multibanco))
             }
@@ -112,6 +122,8 @@ impl IntoDirValue for (global_enums::PaymentMethodType, global_enums::PaymentMet
             global_enums::PaymentMethodType::SamsungPay => Ok(dirval!(WalletType = SamsungPay)),
             global_enums::PaymentMethodType::GoPay => Ok(dirval!(WalletType = GoPay)),
             global_enums::PaymentMethodType::KakaoPay => Ok(dirval!(WalletType = KakaoPay)),
+            global_enums::PaymentMethodType::Skrill => Ok(dirval!(WalletType = Skrill)),
+            global_enums::PaymentMethodType::Payoneer => Ok(dirval!(WalletType = Payoneer)),
             global_enums::PaymentMethodType::Twint => Ok(dirval!(WalletType = Twint)),
             global_enums::PaymentMethodType::Gcash => Ok(dirval!(WalletType = Gcash)),
             global_enums::PaymentMethodType::Vipps => Ok(dirval!(WalletType = Vipps)),
@@ -156,6 +168,11 @@ impl IntoDirValue for (global_enums::PaymentMethodType, global_enums::PaymentMet
             global_enums::PaymentMethodType::FamilyMart => Ok(dirval!(VoucherType = FamilyMart)),
             global_enums::PaymentMethodType::Seicomart => Ok(dirval!(VoucherType = Seicomart)),
             global_enums::PaymentMethodType::PayEasy => Ok(dirval!(VoucherType = PayEasy)),
+            global_enums::PaymentMethodType::PayNow => Ok(dirval!(RealTimePaymentType = PayNow)),
+            global_enums::PaymentMethodType::PromptPay => {
+                Ok(dirval!(RealTimePaymentType = PromptPay))
+            }
+            global_enums::PaymentMethodType::UpiQr => Ok(dirval!(RealTimePaymentType = UpiQr)),
             global_enums::PaymentMethodType::Givex => Ok(dirval!(GiftCardType = Givex)),
             global_enums::PaymentMethodType::Benefit => Ok(dirval!(CardRedirectType = Benefit)),
             global_enums::PaymentMethodType::Knet => Ok(dirval!(CardRedirectType = Knet)),
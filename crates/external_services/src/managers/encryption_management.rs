@@ -3,6 +3,7 @@
 //!
 
 use common_utils::errors::CustomResult;
+use error_stack::report;
 use hyperswitch_interfaces::encryption_interface::{
     EncryptionError, EncryptionManagementInterface,
 };
@@ -51,3 +52,35 @@ impl EncryptionManagementConfig {
         })
     }
 }
+
+/// Prefix of the URIs of merchant provided AWS KMS keys, the key ARN being a valid URI.
+#[cfg(feature = "aws_kms")]
+const AWS_KMS_KEY_ARN_PREFIX: &str = "arn:aws:kms:";
+
+/// Retrieves the encryption client for a merchant provided key, identified by its URI.
+///
+/// Currently only AWS KMS keys are supported, identified by their key ARN
+/// (`arn:aws:kms:<region>:<account_id>:key/<key_id>`). The key is expected to be shared with
+/// the deployment's AWS account through a grant.
+pub async fn get_merchant_key_encryption_client(
+    key_uri: &str,
+) -> CustomResult<Box<dyn EncryptionManagementInterface>, EncryptionError> {
+    #[cfg(feature = "aws_kms")]
+    if let Some(key_arn) = key_uri.strip_prefix(AWS_KMS_KEY_ARN_PREFIX) {
+        let region = key_arn
+            .split(':')
+            .next()
+            .filter(|region| !region.is_empty())
+            .ok_or(report!(EncryptionError::InvalidKeyUri))?;
+
+        let config = aws_kms::core::AwsKmsConfig {
+            key_id: key_uri.to_owned(),
+            region: region.to_owned(),
+        };
+
+        return Ok(Box::new(aws_kms::core::AwsKmsClient::new(&config).await));
+    }
+
+    Err(report!(EncryptionError::InvalidKeyUri)
+        .attach_printable(format!("No key management service supports the key {key_uri}")))
+}
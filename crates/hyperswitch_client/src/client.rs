@@ -0,0 +1,161 @@
+//! HTTP client shared by all the API resources.
+
+use masking::{PeekInterface, Secret};
+use reqwest::{header, Method, RequestBuilder, Url};
+use serde::de::DeserializeOwned;
+
+use crate::errors::{ApiErrorEnvelope, ClientError, ClientResult};
+
+/// Header used to authenticate with the Hyperswitch API
+pub const API_KEY_HEADER: &str = "api-key";
+
+/// Client for the Hyperswitch API, authenticated with a merchant API key
+#[derive(Clone)]
+pub struct HyperswitchClient {
+    http: reqwest::Client,
+    base_url: Url,
+    api_key: Secret<String>,
+}
+
+impl std::fmt::Debug for HyperswitchClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HyperswitchClient")
+            .field("base_url", &self.base_url.as_str())
+            .finish_non_exhaustive()
+    }
+}
+
+impl HyperswitchClient {
+    /// Create a client for the Hyperswitch server hosted at `base_url`
+    pub fn new(base_url: &str, api_key: impl Into<String>) -> ClientResult<Self> {
+        let base_url =
+            Url::parse(base_url).map_err(|error| ClientError::InvalidUrl(error.to_string()))?;
+
+        if base_url.cannot_be_a_base() {
+            return Err(ClientError::InvalidUrl(format!(
+                "{base_url} cannot be used as a base URL"
+            )));
+        }
+
+        Ok(Self {
+            http: reqwest::Client::new(),
+            base_url,
+            api_key: Secret::new(api_key.into()),
+        })
+    }
+
+    /// Use the provided HTTP client, to configure timeouts or proxies
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Build the URL of an endpoint, percent encoding each of the path segments
+    pub(crate) fn endpoint(&self, segments: &[&str]) -> Url {
+        let mut url = self.base_url.clone();
+        if let Ok(mut path) = url.path_segments_mut() {
+            path.pop_if_empty().extend(segments);
+        }
+        url
+    }
+
+    pub(crate) fn request(&self, method: Method, segments: &[&str]) -> RequestBuilder {
+        self.http
+            .request(method, self.endpoint(segments))
+            .header(API_KEY_HEADER, self.api_key.peek())
+            .header(header::ACCEPT, "application/json")
+    }
+
+    pub(crate) async fn execute<T: DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+    ) -> ClientResult<T> {
+        let response = request.send().await?;
+        let status = response.status().as_u16();
+        let body = response.bytes().await?;
+
+        parse_response(status, &body)
+    }
+}
+
+pub(crate) fn parse_response<T: DeserializeOwned>(status: u16, body: &[u8]) -> ClientResult<T> {
+    if !(200..300).contains(&status) {
+        return Err(ClientError::Api {
+            status,
+            error: serde_json::from_slice::<ApiErrorEnvelope>(body)
+                .ok()
+                .map(|envelope| envelope.error),
+        });
+    }
+
+    Ok(serde_json::from_slice(body)?)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::errors::ApiErrorBody;
+
+    #[test]
+    fn test_endpoint_is_built_from_path_segments() {
+        let client = HyperswitchClient::new("https://sandbox.hyperswitch.io/", "api_key").unwrap();
+
+        assert_eq!(
+            client.endpoint(&["payments", "pay_1", "capture"]).as_str(),
+            "https://sandbox.hyperswitch.io/payments/pay_1/capture"
+        );
+        assert_eq!(
+            client.endpoint(&["customers", "cus/1"]).as_str(),
+            "https://sandbox.hyperswitch.io/customers/cus%2F1",
+            "identifiers must not be able to change the path"
+        );
+
+        let prefixed = HyperswitchClient::new("http://localhost:8080/api", "api_key").unwrap();
+        assert_eq!(
+            prefixed.endpoint(&["refunds"]).as_str(),
+            "http://localhost:8080/api/refunds"
+        );
+    }
+
+    #[test]
+    fn test_invalid_base_url_is_rejected() {
+        assert!(matches!(
+            HyperswitchClient::new("not a url", "api_key"),
+            Err(ClientError::InvalidUrl(_))
+        ));
+        assert!(matches!(
+            HyperswitchClient::new("mailto:merchant@example.com", "api_key"),
+            Err(ClientError::InvalidUrl(_))
+        ));
+    }
+
+    #[test]
+    fn test_error_response_is_parsed() {
+        let body = br#"{"error":{"type":"invalid_request","message":"Payment does not exist in our records","code":"HE_02"}}"#;
+
+        match parse_response::<serde_json::Value>(404, body) {
+            Err(ClientError::Api { status, error }) => {
+                assert_eq!(status, 404);
+                assert_eq!(
+                    error,
+                    Some(ApiErrorBody {
+                        error_type: "invalid_request".to_string(),
+                        message: "Payment does not exist in our records".to_string(),
+                        code: "HE_02".to_string(),
+                    })
+                );
+            }
+            other => panic!("expected an API error, got {other:?}"),
+        }
+
+        assert!(matches!(
+            parse_response::<serde_json::Value>(502, b"Bad Gateway"),
+            Err(ClientError::Api {
+                status: 502,
+                error: None
+            })
+        ));
+    }
+}
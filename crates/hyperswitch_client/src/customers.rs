@@ -0,0 +1,42 @@
+//! Customers API.
+
+use api_models::customers::{CustomerDeleteResponse, CustomerRequest, CustomerResponse};
+use reqwest::Method;
+
+use crate::{client::HyperswitchClient, errors::ClientResult};
+
+impl HyperswitchClient {
+    /// Create a customer, `POST /customers`
+    pub async fn create_customer(
+        &self,
+        request: &CustomerRequest,
+    ) -> ClientResult<CustomerResponse> {
+        self.execute(self.request(Method::POST, &["customers"]).json(request))
+            .await
+    }
+
+    /// Retrieve a customer, `GET /customers/{customer_id}`
+    pub async fn retrieve_customer(&self, customer_id: &str) -> ClientResult<CustomerResponse> {
+        self.execute(self.request(Method::GET, &["customers", customer_id]))
+            .await
+    }
+
+    /// Update a customer, `POST /customers/{customer_id}`
+    pub async fn update_customer(
+        &self,
+        customer_id: &str,
+        request: &CustomerRequest,
+    ) -> ClientResult<CustomerResponse> {
+        self.execute(
+            self.request(Method::POST, &["customers", customer_id])
+                .json(request),
+        )
+        .await
+    }
+
+    /// Delete a customer along with their payment methods, `DELETE /customers/{customer_id}`
+    pub async fn delete_customer(&self, customer_id: &str) -> ClientResult<CustomerDeleteResponse> {
+        self.execute(self.request(Method::DELETE, &["customers", customer_id]))
+            .await
+    }
+}
@@ -0,0 +1,44 @@
+/// Error body returned by the Hyperswitch API, of the form `{"error": {...}}`
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct ApiErrorBody {
+    /// Category of the error, like `invalid_request`
+    #[serde(rename = "type")]
+    pub error_type: String,
+    /// Human readable description of the error
+    pub message: String,
+    /// Error code, like `IR_06`
+    pub code: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct ApiErrorEnvelope {
+    pub(crate) error: ApiErrorBody,
+}
+
+/// Errors which can occur while talking to the Hyperswitch API
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    /// The base URL of the client is not valid
+    #[error("Invalid base URL: {0}")]
+    InvalidUrl(String),
+    /// The request could not be sent, or the response could not be read
+    #[error("Failed to send the request: {0}")]
+    Request(#[from] reqwest::Error),
+    /// The API responded with an error
+    #[error("API responded with status {status}: {}", error.as_ref().map(|error| error.message.as_str()).unwrap_or("unknown error"))]
+    Api {
+        /// HTTP status code of the response
+        status: u16,
+        /// Error returned by the API, absent if the body was not in the expected format
+        error: Option<ApiErrorBody>,
+    },
+    /// The response body could not be deserialized into the expected type
+    #[error("Failed to deserialize the response: {0}")]
+    Deserialization(#[from] serde_json::Error),
+    /// The webhook signature could not be verified
+    #[error("Failed to verify the webhook: {0}")]
+    WebhookVerification(#[from] hyperswitch_webhooks::VerificationError),
+}
+
+/// Result type returned by the client
+pub type ClientResult<T> = Result<T, ClientError>;
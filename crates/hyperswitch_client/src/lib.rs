@@ -0,0 +1,17 @@
+#![warn(missing_docs)]
+
+//! Typed asynchronous client for the Hyperswitch API.
+//!
+//! The request and response types are the ones from `api_models` used by the router itself, so
+//! the client cannot drift from the server.
+
+mod client;
+mod customers;
+mod errors;
+mod payments;
+mod refunds;
+pub mod webhooks;
+
+pub use api_models;
+pub use client::{HyperswitchClient, API_KEY_HEADER};
+pub use errors::{ApiErrorBody, ClientError, ClientResult};
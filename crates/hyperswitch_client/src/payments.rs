@@ -0,0 +1,85 @@
+//! Payments API.
+
+use api_models::payments::{
+    PaymentRetrieveBody, PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsRequest,
+    PaymentsResponse,
+};
+use reqwest::Method;
+
+use crate::{client::HyperswitchClient, errors::ClientResult};
+
+impl HyperswitchClient {
+    /// Create a payment, `POST /payments`
+    pub async fn create_payment(
+        &self,
+        request: &PaymentsRequest,
+    ) -> ClientResult<PaymentsResponse> {
+        self.execute(self.request(Method::POST, &["payments"]).json(request))
+            .await
+    }
+
+    /// Retrieve a payment, `GET /payments/{payment_id}`
+    pub async fn retrieve_payment(
+        &self,
+        payment_id: &str,
+        query: &PaymentRetrieveBody,
+    ) -> ClientResult<PaymentsResponse> {
+        self.execute(
+            self.request(Method::GET, &["payments", payment_id])
+                .query(query),
+        )
+        .await
+    }
+
+    /// Update a payment which has not been confirmed yet, `POST /payments/{payment_id}`
+    pub async fn update_payment(
+        &self,
+        payment_id: &str,
+        request: &PaymentsRequest,
+    ) -> ClientResult<PaymentsResponse> {
+        self.execute(
+            self.request(Method::POST, &["payments", payment_id])
+                .json(request),
+        )
+        .await
+    }
+
+    /// Confirm a payment, `POST /payments/{payment_id}/confirm`
+    pub async fn confirm_payment(
+        &self,
+        payment_id: &str,
+        request: &PaymentsRequest,
+    ) -> ClientResult<PaymentsResponse> {
+        self.execute(
+            self.request(Method::POST, &["payments", payment_id, "confirm"])
+                .json(request),
+        )
+        .await
+    }
+
+    /// Capture an authorized payment, `POST /payments/{payment_id}/capture`
+    pub async fn capture_payment(
+        &self,
+        payment_id: &str,
+        request: &PaymentsCaptureRequest,
+    ) -> ClientResult<PaymentsResponse> {
+        self.execute(
+            self.request(Method::POST, &["payments", payment_id, "capture"])
+                .json(request),
+        )
+        .await
+    }
+
+    /// Cancel a payment which has not been captured, `POST /payments/{payment_id}/cancel`
+    pub async fn cancel_payment(
+        &self,
+        payment_id: &str,
+        request: &PaymentsCancelRequest,
+    ) -> ClientResult<PaymentsResponse> {
+        self.execute(
+            self.request(Method::POST, &["payments", payment_id, "cancel"])
+                .json(request),
+        )
+        .await
+    }
+}
@@ -0,0 +1,42 @@
+//! Refunds API.
+
+use api_models::refunds::{
+    RefundRequest, RefundResponse, RefundUpdateRequest, RefundsRetrieveBody,
+};
+use reqwest::Method;
+
+use crate::{client::HyperswitchClient, errors::ClientResult};
+
+impl HyperswitchClient {
+    /// Refund a payment, `POST /refunds`
+    pub async fn create_refund(&self, request: &RefundRequest) -> ClientResult<RefundResponse> {
+        self.execute(self.request(Method::POST, &["refunds"]).json(request))
+            .await
+    }
+
+    /// Retrieve a refund, `GET /refunds/{refund_id}`
+    pub async fn retrieve_refund(
+        &self,
+        refund_id: &str,
+        query: &RefundsRetrieveBody,
+    ) -> ClientResult<RefundResponse> {
+        self.execute(
+            self.request(Method::GET, &["refunds", refund_id])
+                .query(query),
+        )
+        .await
+    }
+
+    /// Update the reason or metadata of a refund, `POST /refunds/{refund_id}`
+    pub async fn update_refund(
+        &self,
+        refund_id: &str,
+        request: &RefundUpdateRequest,
+    ) -> ClientResult<RefundResponse> {
+        self.execute(
+            self.request(Method::POST, &["refunds", refund_id])
+                .json(request),
+        )
+        .await
+    }
+}
@@ -0,0 +1,92 @@
+//! Verification of the webhooks sent by Hyperswitch.
+
+use api_models::webhooks::OutgoingWebhook;
+pub use hyperswitch_webhooks::{WebhookVerifier, SIGNATURE_HEADER};
+
+use crate::errors::ClientResult;
+
+/// Verify the `X-Webhook-Signature` header of a webhook and deserialize its body
+///
+/// The raw body, as received, must be passed in so that the signature can be checked before the
+/// contents are trusted.
+pub fn construct_event(
+    verifier: &WebhookVerifier,
+    signature_header: &str,
+    body: &[u8],
+) -> ClientResult<OutgoingWebhook> {
+    verifier.verify(signature_header, body)?;
+
+    Ok(serde_json::from_slice(body)?)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use api_models::webhooks::OutgoingWebhookContent;
+
+    use super::*;
+    use crate::errors::ClientError;
+
+    const SECRET: &[u8] = b"payment_response_hash_key";
+    const BODY: &[u8] = br#"{
+        "merchant_id": "merchant_1",
+        "event_id": "evt_1",
+        "event_type": "refund_succeeded",
+        "content": {
+            "type": "refund_details",
+            "object": {
+                "refund_id": "ref_1",
+                "payment_id": "pay_1",
+                "amount": 100,
+                "currency": "USD",
+                "status": "succeeded",
+                "reason": null,
+                "metadata": null,
+                "error_message": null,
+                "error_code": null,
+                "created_at": null,
+                "updated_at": null,
+                "connector": "stripe",
+                "profile_id": null,
+                "merchant_connector_id": null
+            }
+        },
+        "timestamp": "2024-05-06T12:00:00.000Z"
+    }"#;
+
+    fn now() -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_signed_webhook_is_deserialized() {
+        let header = hyperswitch_webhooks::sign(SECRET, now(), BODY).unwrap();
+        let webhook = construct_event(&WebhookVerifier::new(SECRET), &header, BODY).unwrap();
+
+        assert_eq!(webhook.event_id, "evt_1");
+        assert!(matches!(
+            webhook.content,
+            OutgoingWebhookContent::RefundDetails(refund) if refund.refund_id == "ref_1"
+        ));
+    }
+
+    #[test]
+    fn test_webhook_with_invalid_signature_is_rejected() {
+        let header = hyperswitch_webhooks::sign(b"another_key", now(), BODY).unwrap();
+
+        assert!(matches!(
+            construct_event(&WebhookVerifier::new(SECRET), &header, BODY),
+            Err(ClientError::WebhookVerification(
+                hyperswitch_webhooks::VerificationError::SignatureMismatch
+            ))
+        ));
+    }
+}
@@ -41,6 +41,8 @@ pub struct MandateData {
     pub customer_acceptance: Option<CustomerAcceptance>,
     /// A way to select the type of mandate used
     pub mandate_type: Option<MandateDataType>,
+    /// The number of days in advance that the customer is notified of each debit
+    pub pre_notification_days: Option<u8>,
 }
 
 #[derive(Default, Eq, PartialEq, Debug, Clone)]
@@ -99,6 +101,7 @@ impl From<ApiMandateData> for MandateData {
             customer_acceptance: value.customer_acceptance.map(|d| d.into()),
             mandate_type: value.mandate_type.map(|d| d.into()),
             update_mandate_id: value.update_mandate_id,
+            pre_notification_days: value.pre_notification_days,
         }
     }
 }
@@ -60,4 +60,13 @@ pub struct PaymentIntent {
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub session_expiry: Option<PrimitiveDateTime>,
     pub request_external_three_ds_authentication: Option<bool>,
+    pub request_overcapture: Option<bool>,
+    pub order_tax_amount: Option<i64>,
+    pub split_payments: Option<serde_json::Value>,
+    pub payment_channel: Option<storage_enums::PaymentChannel>,
+    pub l2_l3_data: Option<serde_json::Value>,
+    pub tags: Option<Vec<String>>,
+    pub request_extended_authorization: Option<bool>,
+    pub capture_adjustment_reason: Option<storage_enums::CaptureAdjustmentReason>,
+    pub installment_tenure: Option<i16>,
 }
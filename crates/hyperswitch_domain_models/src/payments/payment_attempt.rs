@@ -166,6 +166,11 @@ pub struct PaymentAttempt {
     pub mandate_data: Option<MandateDetails>,
     pub payment_method_billing_address_id: Option<String>,
     pub fingerprint_id: Option<String>,
+    pub connector_request_id: Option<String>,
+    pub extended_authorization_applied: Option<bool>,
+    pub capture_before: Option<PrimitiveDateTime>,
+    pub amount_reversed: Option<i64>,
+    pub overcaptured_amount: Option<i64>,
 }
 
 impl PaymentAttempt {
@@ -249,6 +254,11 @@ pub struct PaymentAttemptNew {
     pub mandate_data: Option<MandateDetails>,
     pub payment_method_billing_address_id: Option<String>,
     pub fingerprint_id: Option<String>,
+    pub connector_request_id: Option<String>,
+    pub extended_authorization_applied: Option<bool>,
+    pub capture_before: Option<PrimitiveDateTime>,
+    pub amount_reversed: Option<i64>,
+    pub overcaptured_amount: Option<i64>,
 }
 
 impl PaymentAttemptNew {
@@ -361,7 +371,11 @@ pub enum PaymentAttemptUpdate {
         error_message: Option<Option<String>>,
         error_reason: Option<Option<String>>,
         connector_response_reference_id: Option<String>,
+        connector_request_id: Option<String>,
+        extended_authorization_applied: Option<bool>,
+        capture_before: Option<PrimitiveDateTime>,
         amount_capturable: Option<i64>,
+        overcaptured_amount: Option<i64>,
         updated_by: String,
         authentication_data: Option<serde_json::Value>,
         encoded_data: Option<String>,
@@ -395,6 +409,7 @@ pub enum PaymentAttemptUpdate {
         unified_code: Option<Option<String>>,
         unified_message: Option<Option<String>>,
         connector_transaction_id: Option<String>,
+        connector_request_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
     },
     CaptureUpdate {
@@ -405,6 +420,13 @@ pub enum PaymentAttemptUpdate {
     AmountToCaptureUpdate {
         status: storage_enums::AttemptStatus,
         amount_capturable: i64,
+        overcaptured_amount: Option<i64>,
+        updated_by: String,
+    },
+    PartialReversalUpdate {
+        status: storage_enums::AttemptStatus,
+        amount_reversed: i64,
+        cancellation_reason: Option<String>,
         updated_by: String,
     },
     PreprocessingUpdate {
@@ -434,6 +456,11 @@ pub enum PaymentAttemptUpdate {
         authentication_id: Option<String>,
         updated_by: String,
     },
+    TaxCalculationUpdate {
+        amount: i64,
+        amount_capturable: i64,
+        updated_by: String,
+    },
 }
 
 impl ForeignIDRef for PaymentAttempt {
@@ -113,6 +113,15 @@ pub struct PaymentIntentNew {
     pub fingerprint_id: Option<String>,
     pub session_expiry: Option<PrimitiveDateTime>,
     pub request_external_three_ds_authentication: Option<bool>,
+    pub request_overcapture: Option<bool>,
+    pub order_tax_amount: Option<i64>,
+    pub split_payments: Option<serde_json::Value>,
+    pub payment_channel: Option<storage_enums::PaymentChannel>,
+    pub l2_l3_data: Option<serde_json::Value>,
+    pub tags: Option<Vec<String>>,
+    pub request_extended_authorization: Option<bool>,
+    pub capture_adjustment_reason: Option<storage_enums::CaptureAdjustmentReason>,
+    pub installment_tenure: Option<i16>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -169,6 +178,7 @@ pub enum PaymentIntentUpdate {
         fingerprint_id: Option<String>,
         session_expiry: Option<PrimitiveDateTime>,
         request_external_three_ds_authentication: Option<bool>,
+        installment_tenure: Option<i16>,
     },
     PaymentAttemptAndAttemptCountUpdate {
         active_attempt_id: String,
@@ -201,6 +211,29 @@ pub enum PaymentIntentUpdate {
     AuthorizationCountUpdate {
         authorization_count: i32,
     },
+    PaymentLinkUpdate {
+        payment_link_id: String,
+        updated_by: String,
+    },
+    TaxCalculationUpdate {
+        amount: i64,
+        order_tax_amount: i64,
+        shipping_address_id: Option<String>,
+        updated_by: String,
+    },
+    TagsUpdate {
+        tags: Vec<String>,
+        updated_by: String,
+    },
+    ExpiryUpdate {
+        status: storage_enums::IntentStatus,
+        updated_by: String,
+    },
+    CaptureAdjustmentUpdate {
+        capture_adjustment_reason: storage_enums::CaptureAdjustmentReason,
+        order_details: Option<Vec<pii::SecretSerdeValue>>,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default)]
@@ -237,6 +270,12 @@ pub struct PaymentIntentUpdateInternal {
     pub fingerprint_id: Option<String>,
     pub session_expiry: Option<PrimitiveDateTime>,
     pub request_external_three_ds_authentication: Option<bool>,
+    pub payment_link_id: Option<String>,
+    pub order_tax_amount: Option<i64>,
+    pub tags: Option<Vec<String>>,
+    pub client_secret: Option<Option<String>>,
+    pub capture_adjustment_reason: Option<storage_enums::CaptureAdjustmentReason>,
+    pub installment_tenure: Option<i16>,
 }
 
 impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
@@ -263,6 +302,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 fingerprint_id,
                 session_expiry,
                 request_external_three_ds_authentication,
+                installment_tenure,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -285,6 +325,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 fingerprint_id,
                 session_expiry,
                 request_external_three_ds_authentication,
+                installment_tenure,
                 ..Default::default()
             },
             PaymentIntentUpdate::MetadataUpdate {
@@ -420,6 +461,49 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 authorization_count: Some(authorization_count),
                 ..Default::default()
             },
+            PaymentIntentUpdate::PaymentLinkUpdate {
+                payment_link_id,
+                updated_by,
+            } => Self {
+                payment_link_id: Some(payment_link_id),
+                updated_by,
+                ..Default::default()
+            },
+            PaymentIntentUpdate::TaxCalculationUpdate {
+                amount,
+                order_tax_amount,
+                shipping_address_id,
+                updated_by,
+            } => Self {
+                amount: Some(amount),
+                order_tax_amount: Some(order_tax_amount),
+                shipping_address_id,
+                updated_by,
+                ..Default::default()
+            },
+            PaymentIntentUpdate::TagsUpdate { tags, updated_by } => Self {
+                tags: Some(tags),
+                updated_by,
+                ..Default::default()
+            },
+            // The client secret is invalidated so that the payment cannot be confirmed anymore
+            PaymentIntentUpdate::ExpiryUpdate { status, updated_by } => Self {
+                status: Some(status),
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                client_secret: Some(None),
+                ..Default::default()
+            },
+            PaymentIntentUpdate::CaptureAdjustmentUpdate {
+                capture_adjustment_reason,
+                order_details,
+                updated_by,
+            } => Self {
+                capture_adjustment_reason: Some(capture_adjustment_reason),
+                order_details,
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -441,6 +525,7 @@ pub struct PaymentIntentListParams {
     pub payment_method_type: Option<Vec<storage_enums::PaymentMethodType>>,
     pub authentication_type: Option<Vec<storage_enums::AuthenticationType>>,
     pub merchant_connector_id: Option<Vec<String>>,
+    pub tags: Option<Vec<String>>,
     pub profile_id: Option<String>,
     pub customer_id: Option<String>,
     pub starting_after_id: Option<String>,
@@ -462,6 +547,7 @@ impl From<api_models::payments::PaymentListConstraints> for PaymentIntentFetchCo
             payment_method_type: None,
             authentication_type: None,
             merchant_connector_id: None,
+            tags: None,
             profile_id: None,
             customer_id: value.customer_id,
             starting_after_id: value.starting_after,
@@ -485,6 +571,7 @@ impl From<api_models::payments::TimeRange> for PaymentIntentFetchConstraints {
             payment_method_type: None,
             authentication_type: None,
             merchant_connector_id: None,
+            tags: None,
             profile_id: None,
             customer_id: None,
             starting_after_id: None,
@@ -511,6 +598,7 @@ impl From<api_models::payments::PaymentListFilterConstraints> for PaymentIntentF
                 payment_method_type: value.payment_method_type,
                 authentication_type: value.authentication_type,
                 merchant_connector_id: value.merchant_connector_id,
+                tags: value.tags,
                 profile_id: value.profile_id,
                 customer_id: value.customer_id,
                 starting_after_id: None,
@@ -26,4 +26,8 @@ pub enum EncryptionError {
     /// An error occurred when decrypting input data.
     #[error("Failed to decrypt input data")]
     DecryptionFailed,
+
+    /// The provided key URI does not refer to a supported key management service.
+    #[error("Unsupported or invalid key URI")]
+    InvalidKeyUri,
 }
@@ -0,0 +1,91 @@
+//! Canonical form of webhook bodies, which is what gets signed.
+//!
+//! The canonical form of a JSON document has no insignificant whitespace and has the keys of
+//! every object sorted in lexicographic order, so that bodies which were re-serialized or
+//! re-indented by a proxy still produce the same signature.
+
+use serde_json::Value;
+
+use crate::errors::VerificationError;
+
+/// Convert a JSON webhook body into its canonical form
+pub fn canonicalize_body(body: &[u8]) -> Result<Vec<u8>, VerificationError> {
+    let value: Value =
+        serde_json::from_slice(body).map_err(|_| VerificationError::MalformedBody)?;
+
+    let mut canonical = Vec::with_capacity(body.len());
+    write_canonical(&value, &mut canonical)?;
+
+    Ok(canonical)
+}
+
+fn write_canonical(value: &Value, buffer: &mut Vec<u8>) -> Result<(), VerificationError> {
+    match value {
+        Value::Array(values) => {
+            buffer.push(b'[');
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    buffer.push(b',');
+                }
+                write_canonical(value, buffer)?;
+            }
+            buffer.push(b']');
+        }
+        Value::Object(map) => {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_unstable_by_key(|(key, _)| *key);
+
+            buffer.push(b'{');
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    buffer.push(b',');
+                }
+                serde_json::to_writer(&mut *buffer, key)
+                    .map_err(|_| VerificationError::MalformedBody)?;
+                buffer.push(b':');
+                write_canonical(value, buffer)?;
+            }
+            buffer.push(b'}');
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {
+            serde_json::to_writer(&mut *buffer, value)
+                .map_err(|_| VerificationError::MalformedBody)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_canonical_body_ignores_whitespace_and_key_order() {
+        let compact =
+            br#"{"event_type":"payment_succeeded","content":{"amount":100,"id":"pay_1"}}"#;
+        let pretty = br#"{
+            "content": { "id": "pay_1", "amount": 100 },
+            "event_type": "payment_succeeded"
+        }"#;
+
+        assert_eq!(
+            canonicalize_body(compact).unwrap(),
+            canonicalize_body(pretty).unwrap()
+        );
+        assert_eq!(
+            canonicalize_body(pretty).unwrap(),
+            br#"{"content":{"amount":100,"id":"pay_1"},"event_type":"payment_succeeded"}"#
+        );
+    }
+
+    #[test]
+    fn test_canonical_body_rejects_invalid_json() {
+        assert_eq!(
+            canonicalize_body(b"event_type=payment_succeeded"),
+            Err(VerificationError::MalformedBody)
+        );
+    }
+}
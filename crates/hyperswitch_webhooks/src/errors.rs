@@ -0,0 +1,16 @@
+/// Reasons for which a webhook fails verification
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum VerificationError {
+    /// The signature header could not be parsed
+    #[error("Signature header is missing or malformed")]
+    MalformedSignatureHeader,
+    /// The body could not be canonicalized
+    #[error("Webhook body is not valid JSON")]
+    MalformedBody,
+    /// The body was not signed with the secret being verified against
+    #[error("None of the signatures match the webhook body")]
+    SignatureMismatch,
+    /// The webhook was signed too long ago, or too far in the future
+    #[error("Webhook timestamp is outside the allowed tolerance")]
+    TimestampOutsideTolerance,
+}
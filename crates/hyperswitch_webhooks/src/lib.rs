@@ -0,0 +1,23 @@
+#![warn(missing_docs)]
+
+//! Helpers for verifying the signatures of webhooks sent by Hyperswitch.
+//!
+//! The router signs outgoing webhooks with the functions exposed here, so merchants embedding
+//! this crate verify webhooks the exact same way they were signed.
+
+mod canonical;
+mod errors;
+mod signature;
+
+pub use canonical::canonicalize_body;
+pub use errors::VerificationError;
+pub use signature::{
+    sign, sign_without_timestamp, verify_signature_without_timestamp, SignatureHeader,
+    WebhookVerifier, DEFAULT_TIMESTAMP_TOLERANCE,
+};
+
+/// Header carrying the timestamp and the signatures of the webhook
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+
+/// Header carrying the signature of the raw webhook body, without any replay protection
+pub const SIGNATURE_WITHOUT_TIMESTAMP_HEADER: &str = "X-Webhook-Signature-512";
@@ -0,0 +1,273 @@
+//! Signing and verification of webhook bodies.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ring::hmac;
+
+use crate::{canonical::canonicalize_body, errors::VerificationError};
+
+/// Maximum age of a webhook accepted by default, in either direction to allow for clock skew
+pub const DEFAULT_TIMESTAMP_TOLERANCE: Duration = Duration::from_secs(300);
+
+const TIMESTAMP_KEY: &str = "t";
+const SIGNATURE_KEY: &str = "v1";
+
+fn hmac_sha512(secret: &[u8], message: &[u8]) -> Vec<u8> {
+    let key = hmac::Key::new(hmac::HMAC_SHA512, secret);
+    hmac::sign(&key, message).as_ref().to_vec()
+}
+
+fn timestamped_message(timestamp: i64, canonical_body: &[u8]) -> Vec<u8> {
+    let mut message = format!("{timestamp}.").into_bytes();
+    message.extend_from_slice(canonical_body);
+    message
+}
+
+/// Sign the raw webhook body, producing the hex encoded HMAC-SHA512 of the body
+///
+/// This is the signature sent in the `X-Webhook-Signature-512` header, which carries no
+/// protection against replays.
+pub fn sign_without_timestamp(secret: &[u8], body: &[u8]) -> String {
+    hex::encode(hmac_sha512(secret, body))
+}
+
+/// Sign the webhook body along with the time it is being sent at, producing the value of the
+/// `X-Webhook-Signature` header
+pub fn sign(secret: &[u8], timestamp: i64, body: &[u8]) -> Result<String, VerificationError> {
+    let canonical_body = canonicalize_body(body)?;
+    let signature = hex::encode(hmac_sha512(
+        secret,
+        &timestamped_message(timestamp, &canonical_body),
+    ));
+
+    Ok(format!(
+        "{TIMESTAMP_KEY}={timestamp},{SIGNATURE_KEY}={signature}"
+    ))
+}
+
+/// Verify the `X-Webhook-Signature-512` header, which has no timestamp
+pub fn verify_signature_without_timestamp(
+    secret: &[u8],
+    signature: &str,
+    body: &[u8],
+) -> Result<(), VerificationError> {
+    let signature =
+        hex::decode(signature.trim()).map_err(|_| VerificationError::MalformedSignatureHeader)?;
+    let key = hmac::Key::new(hmac::HMAC_SHA512, secret);
+
+    hmac::verify(&key, body, &signature).map_err(|_| VerificationError::SignatureMismatch)
+}
+
+/// Contents of the `X-Webhook-Signature` header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureHeader {
+    /// Unix timestamp, in seconds, at which the webhook was signed
+    pub timestamp: i64,
+    /// Signatures of the webhook, more than one may be present while keys are being rotated
+    pub signatures: Vec<Vec<u8>>,
+}
+
+impl std::str::FromStr for SignatureHeader {
+    type Err = VerificationError;
+
+    fn from_str(header: &str) -> Result<Self, Self::Err> {
+        let mut timestamp = None;
+        let mut signatures = Vec::new();
+
+        for element in header.split(',') {
+            let (key, value) = element
+                .trim()
+                .split_once('=')
+                .ok_or(VerificationError::MalformedSignatureHeader)?;
+
+            match key {
+                TIMESTAMP_KEY => {
+                    timestamp = Some(
+                        value
+                            .parse::<i64>()
+                            .map_err(|_| VerificationError::MalformedSignatureHeader)?,
+                    )
+                }
+                SIGNATURE_KEY => signatures.push(
+                    hex::decode(value).map_err(|_| VerificationError::MalformedSignatureHeader)?,
+                ),
+                // Signatures of schemes unknown to this version of the library are skipped
+                _ => {}
+            }
+        }
+
+        match (timestamp, signatures.is_empty()) {
+            (Some(timestamp), false) => Ok(Self {
+                timestamp,
+                signatures,
+            }),
+            _ => Err(VerificationError::MalformedSignatureHeader),
+        }
+    }
+}
+
+/// Verifies the `X-Webhook-Signature` header of webhooks
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    key: hmac::Key,
+    tolerance: Duration,
+}
+
+impl std::fmt::Debug for WebhookVerifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookVerifier")
+            .field("tolerance", &self.tolerance)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebhookVerifier {
+    /// Create a verifier using the `payment_response_hash_key` of the business profile
+    pub fn new(secret: impl AsRef<[u8]>) -> Self {
+        Self {
+            key: hmac::Key::new(hmac::HMAC_SHA512, secret.as_ref()),
+            tolerance: DEFAULT_TIMESTAMP_TOLERANCE,
+        }
+    }
+
+    /// Override the maximum age of webhooks which are accepted
+    pub fn with_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Verify a webhook against the current system time
+    pub fn verify(&self, signature_header: &str, body: &[u8]) -> Result<(), VerificationError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| i64::try_from(duration.as_secs()).unwrap_or(i64::MAX))
+            .unwrap_or_default();
+
+        self.verify_at(signature_header, body, now)
+    }
+
+    /// Verify a webhook as if the current time was `now`, a unix timestamp in seconds
+    pub fn verify_at(
+        &self,
+        signature_header: &str,
+        body: &[u8],
+        now: i64,
+    ) -> Result<(), VerificationError> {
+        let header = signature_header.parse::<SignatureHeader>()?;
+
+        let tolerance = i64::try_from(self.tolerance.as_secs()).unwrap_or(i64::MAX);
+        if now.abs_diff(header.timestamp) > tolerance.unsigned_abs() {
+            return Err(VerificationError::TimestampOutsideTolerance);
+        }
+
+        let message = timestamped_message(header.timestamp, &canonicalize_body(body)?);
+
+        header
+            .signatures
+            .iter()
+            .any(|signature| hmac::verify(&self.key, &message, signature).is_ok())
+            .then_some(())
+            .ok_or(VerificationError::SignatureMismatch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    const SECRET: &[u8] = b"payment_response_hash_key";
+    const BODY: &[u8] = br#"{"event_type":"payment_succeeded","content":{"id":"pay_1"}}"#;
+    const TIMESTAMP: i64 = 1_715_000_000;
+
+    #[test]
+    fn test_signed_webhook_is_verified() {
+        let header = sign(SECRET, TIMESTAMP, BODY).unwrap();
+        let verifier = WebhookVerifier::new(SECRET);
+
+        assert_eq!(verifier.verify_at(&header, BODY, TIMESTAMP + 10), Ok(()));
+        assert_eq!(
+            verifier.verify_at(&header, BODY, TIMESTAMP - 10),
+            Ok(()),
+            "small clock skew must be tolerated"
+        );
+    }
+
+    #[test]
+    fn test_replayed_webhook_is_rejected() {
+        let header = sign(SECRET, TIMESTAMP, BODY).unwrap();
+        let verifier = WebhookVerifier::new(SECRET).with_tolerance(Duration::from_secs(60));
+
+        assert_eq!(
+            verifier.verify_at(&header, BODY, TIMESTAMP + 61),
+            Err(VerificationError::TimestampOutsideTolerance)
+        );
+    }
+
+    #[test]
+    fn test_tampered_webhook_is_rejected() {
+        let header = sign(SECRET, TIMESTAMP, BODY).unwrap();
+        let tampered_body = br#"{"event_type":"payment_failed","content":{"id":"pay_1"}}"#;
+        let moved_timestamp = header.replace(&TIMESTAMP.to_string(), &(TIMESTAMP + 1).to_string());
+        let verifier = WebhookVerifier::new(SECRET);
+
+        assert_eq!(
+            verifier.verify_at(&header, tampered_body, TIMESTAMP),
+            Err(VerificationError::SignatureMismatch)
+        );
+        assert_eq!(
+            verifier.verify_at(&moved_timestamp, BODY, TIMESTAMP),
+            Err(VerificationError::SignatureMismatch)
+        );
+        assert_eq!(
+            WebhookVerifier::new(b"another_key").verify_at(&header, BODY, TIMESTAMP),
+            Err(VerificationError::SignatureMismatch)
+        );
+    }
+
+    #[test]
+    fn test_any_of_multiple_signatures_is_accepted() {
+        let header = sign(SECRET, TIMESTAMP, BODY).unwrap();
+        let signature = header.split_once(",v1=").unwrap().1;
+        let rotated_header = format!("t={TIMESTAMP},v1={},v1={signature}", "ab".repeat(64));
+
+        assert_eq!(
+            WebhookVerifier::new(SECRET).verify_at(&rotated_header, BODY, TIMESTAMP),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_malformed_header_is_rejected() {
+        let verifier = WebhookVerifier::new(SECRET);
+
+        for header in [
+            "",
+            "t=1715000000",
+            "v1=abcd",
+            "t=now,v1=abcd",
+            "t=1715000000,v1=xyz",
+        ] {
+            assert_eq!(
+                verifier.verify_at(header, BODY, TIMESTAMP),
+                Err(VerificationError::MalformedSignatureHeader),
+                "{header:?} must be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_signature_without_timestamp() {
+        let signature = sign_without_timestamp(SECRET, BODY);
+
+        assert_eq!(
+            verify_signature_without_timestamp(SECRET, &signature, BODY),
+            Ok(())
+        );
+        assert_eq!(
+            verify_signature_without_timestamp(b"another_key", &signature, BODY),
+            Err(VerificationError::SignatureMismatch)
+        );
+    }
+}
@@ -42,6 +42,7 @@ fn build_test_data<'a>(
                 maximum_amount: Some(1000),
                 recurring_enabled: true,
                 installment_payment_enabled: true,
+                installment_plans: None,
             });
         }
 
@@ -370,6 +370,7 @@ mod tests {
                         maximum_amount: Some(1000),
                         recurring_enabled: true,
                         installment_payment_enabled: true,
+                        installment_plans: None,
                     },
                     RequestPaymentMethodTypes {
                         payment_method_type: api_enums::PaymentMethodType::Debit,
@@ -387,6 +388,7 @@ mod tests {
                         maximum_amount: Some(1000),
                         recurring_enabled: true,
                         installment_payment_enabled: true,
+                        installment_plans: None,
                     },
                 ]),
             }]),
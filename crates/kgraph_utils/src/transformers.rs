@@ -102,6 +102,7 @@ impl IntoDirValue for api_enums::PaymentMethod {
             Self::Voucher => Ok(dirval!(PaymentMethod = Voucher)),
             Self::GiftCard => Ok(dirval!(PaymentMethod = GiftCard)),
             Self::CardRedirect => Ok(dirval!(PaymentMethod = CardRedirect)),
+            Self::RealTimePayment => Ok(dirval!(PaymentMethod = RealTimePayment)),
         }
     }
 }
@@ -156,9 +157,10 @@ impl IntoDirValue for (api_enums::PaymentMethodType, api_enums::PaymentMethod) {
                 | api_enums::PaymentMethod::Reward
                 | api_enums::PaymentMethod::Upi
                 | api_enums::PaymentMethod::Voucher
-                | api_enums::PaymentMethod::GiftCard => Err(KgraphError::ContextConstructionError(
-                    AnalysisErrorType::NotSupported,
-                )),
+                | api_enums::PaymentMethod::GiftCard
+                | api_enums::PaymentMethod::RealTimePayment => Err(
+                    KgraphError::ContextConstructionError(AnalysisErrorType::NotSupported),
+                ),
             },
             api_enums::PaymentMethodType::Bacs => match self.1 {
                 api_enums::PaymentMethod::BankDebit => Ok(dirval!(BankDebitType = Bacs)),
@@ -172,9 +174,10 @@ impl IntoDirValue for (api_enums::PaymentMethodType, api_enums::PaymentMethod) {
                 | api_enums::PaymentMethod::Reward
                 | api_enums::PaymentMethod::Upi
                 | api_enums::PaymentMethod::Voucher
-                | api_enums::PaymentMethod::GiftCard => Err(KgraphError::ContextConstructionError(
-                    AnalysisErrorType::NotSupported,
-                )),
+                | api_enums::PaymentMethod::GiftCard
+                | api_enums::PaymentMethod::RealTimePayment => Err(
+                    KgraphError::ContextConstructionError(AnalysisErrorType::NotSupported),
+                ),
             },
             api_enums::PaymentMethodType::Becs => Ok(dirval!(BankDebitType = Becs)),
             api_enums::PaymentMethodType::Sepa => match self.1 {
@@ -189,9 +192,10 @@ impl IntoDirValue for (api_enums::PaymentMethodType, api_enums::PaymentMethod) {
                 | api_enums::PaymentMethod::Reward
                 | api_enums::PaymentMethod::Upi
                 | api_enums::PaymentMethod::Voucher
-                | api_enums::PaymentMethod::GiftCard => Err(KgraphError::ContextConstructionError(
-                    AnalysisErrorType::NotSupported,
-                )),
+                | api_enums::PaymentMethod::GiftCard
+                | api_enums::PaymentMethod::RealTimePayment => Err(
+                    KgraphError::ContextConstructionError(AnalysisErrorType::NotSupported),
+                ),
             },
             api_enums::PaymentMethodType::AliPay => Ok(dirval!(WalletType = AliPay)),
             api_enums::PaymentMethodType::AliPayHk => Ok(dirval!(WalletType = AliPayHk)),
@@ -202,6 +206,7 @@ impl IntoDirValue for (api_enums::PaymentMethodType, api_enums::PaymentMethod) {
             api_enums::PaymentMethodType::MbWay => Ok(dirval!(WalletType = MbWay)),
             api_enums::PaymentMethodType::MobilePay => Ok(dirval!(WalletType = MobilePay)),
             api_enums::PaymentMethodType::Cashapp => Ok(dirval!(WalletType = Cashapp)),
+            api_enums::PaymentMethodType::ClickToPay => Ok(dirval!(WalletType = ClickToPay)),
             api_enums::PaymentMethodType::Multibanco => Ok(dirval!(BankTransferType = Multibanco)),
             api_enums::PaymentMethodType::Pix => Ok(dirval!(BankTransferType = Pix)),
             api_enums::PaymentMethodType::Pse => Ok(dirval!(BankTransferType = Pse)),
@@ -233,6 +238,8 @@ impl IntoDirValue for (api_enums::PaymentMethodType, api_enums::PaymentMethod) {
             api_enums::PaymentMethodType::SamsungPay => Ok(dirval!(WalletType = SamsungPay)),
             api_enums::PaymentMethodType::GoPay => Ok(dirval!(WalletType = GoPay)),
             api_enums::PaymentMethodType::KakaoPay => Ok(dirval!(WalletType = KakaoPay)),
+            api_enums::PaymentMethodType::Skrill => Ok(dirval!(WalletType = Skrill)),
+            api_enums::PaymentMethodType::Payoneer => Ok(dirval!(WalletType = Payoneer)),
             api_enums::PaymentMethodType::Twint => Ok(dirval!(WalletType = Twint)),
             api_enums::PaymentMethodType::Gcash => Ok(dirval!(WalletType = Gcash)),
             api_enums::PaymentMethodType::Vipps => Ok(dirval!(WalletType = Vipps)),
@@ -275,6 +282,9 @@ impl IntoDirValue for (api_enums::PaymentMethodType, api_enums::PaymentMethod) {
             api_enums::PaymentMethodType::FamilyMart => Ok(dirval!(VoucherType = FamilyMart)),
             api_enums::PaymentMethodType::Seicomart => Ok(dirval!(VoucherType = Seicomart)),
             api_enums::PaymentMethodType::PayEasy => Ok(dirval!(VoucherType = PayEasy)),
+            api_enums::PaymentMethodType::PayNow => Ok(dirval!(RealTimePaymentType = PayNow)),
+            api_enums::PaymentMethodType::PromptPay => Ok(dirval!(RealTimePaymentType = PromptPay)),
+            api_enums::PaymentMethodType::UpiQr => Ok(dirval!(RealTimePaymentType = UpiQr)),
             api_enums::PaymentMethodType::Givex => Ok(dirval!(GiftCardType = Givex)),
             api_enums::PaymentMethodType::Benefit => Ok(dirval!(CardRedirectType = Benefit)),
             api_enums::PaymentMethodType::Knet => Ok(dirval!(CardRedirectType = Knet)),
@@ -10,11 +10,13 @@
 
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR" ), "/", "README.md"))]
 
+#[cfg(feature = "derive")]
+pub use masking_derive::MaskedDebug;
 pub use zeroize::{self, DefaultIsZeroes, Zeroize as ZeroizableSecret};
 
 mod strategy;
 
-pub use strategy::{Strategy, WithType, WithoutType};
+pub use strategy::{MaskedField, Strategy, WithType, WithoutType};
 mod abs;
 pub use abs::{ExposeInterface, ExposeOptionInterface, PeekInterface, SwitchStrategy};
 
@@ -23,6 +25,12 @@ mod strong_secret;
 pub use secret::Secret;
 pub use strong_secret::StrongSecret;
 
+mod strong_eq;
+pub use strong_eq::{constant_time_eq, StrongEq};
+
+mod plaintext;
+pub use plaintext::{set_long_lived_plaintext_reporter, PlaintextBuffer, MAX_PLAINTEXT_LIFETIME};
+
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
@@ -0,0 +1,98 @@
+//!
+//! Short lived plaintext copies of secrets.
+//!
+
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+    sync::OnceLock,
+    time::{Duration, Instant},
+};
+
+use zeroize::{Zeroize as ZeroizableSecret, ZeroizeOnDrop};
+
+/// Longest time a plaintext copy is expected to stay alive, exceeding it is reported.
+pub const MAX_PLAINTEXT_LIFETIME: Duration = Duration::from_secs(1);
+
+static LONG_LIVED_PLAINTEXT_REPORTER: OnceLock<fn(Duration)> = OnceLock::new();
+
+///
+/// Set the function called with the lifetime of the plaintext copies which were alive for longer
+/// than [`MAX_PLAINTEXT_LIFETIME`], for example to log a warning. Only the first reporter set is
+/// kept, and the long lived copies are not reported until one is set.
+///
+pub fn set_long_lived_plaintext_reporter(reporter: fn(Duration)) {
+    let _ = LONG_LIVED_PLAINTEXT_REPORTER.set(reporter);
+}
+
+///
+/// Buffer holding an exposed copy of a secret, like a serialized request body containing a card
+/// number which is required in plaintext for computing a signature.
+///
+/// The contents are wiped from memory when the buffer is dropped. Dropping a buffer which was
+/// alive for longer than [`MAX_PLAINTEXT_LIFETIME`] is reported to the reporter set with
+/// [`set_long_lived_plaintext_reporter`], so that plaintext copies which outlive the operation
+/// they were created for are noticed.
+///
+/// ```
+/// use masking::PlaintextBuffer;
+///
+/// let body = PlaintextBuffer::new(String::from("card_number=4111111111111111"));
+/// assert!(body.starts_with("card_number"));
+/// ```
+///
+pub struct PlaintextBuffer<T: ZeroizableSecret> {
+    inner: T,
+    created_at: Instant,
+}
+
+impl<T: ZeroizableSecret> PlaintextBuffer<T> {
+    /// Take ownership of a plaintext value
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            created_at: Instant::now(),
+        }
+    }
+}
+
+impl<T: ZeroizableSecret> Deref for PlaintextBuffer<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T: ZeroizableSecret> DerefMut for PlaintextBuffer<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T: ZeroizableSecret> From<T> for PlaintextBuffer<T> {
+    fn from(inner: T) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<T: ZeroizableSecret> fmt::Debug for PlaintextBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PlaintextBuffer([REDACTED])")
+    }
+}
+
+impl<T: ZeroizableSecret> Drop for PlaintextBuffer<T> {
+    fn drop(&mut self) {
+        self.inner.zeroize();
+
+        let lifetime = self.created_at.elapsed();
+        if lifetime > MAX_PLAINTEXT_LIFETIME {
+            if let Some(reporter) = LONG_LIVED_PLAINTEXT_REPORTER.get() {
+                reporter(lifetime);
+            }
+        }
+    }
+}
+
+impl<T: ZeroizableSecret> ZeroizeOnDrop for PlaintextBuffer<T> {}
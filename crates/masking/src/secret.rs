@@ -4,7 +4,7 @@
 
 use std::{fmt, marker::PhantomData};
 
-use crate::{strategy::Strategy, PeekInterface};
+use crate::{strategy::Strategy, PeekInterface, ZeroizableSecret};
 
 ///
 /// Secret thing.
@@ -143,6 +143,16 @@ where
     }
 }
 
+impl<SecretValue, MaskingStrategy> ZeroizableSecret for Secret<SecretValue, MaskingStrategy>
+where
+    SecretValue: ZeroizableSecret,
+    MaskingStrategy: Strategy<SecretValue>,
+{
+    fn zeroize(&mut self) {
+        self.inner_secret.zeroize();
+    }
+}
+
 impl<SecretValue, MaskingStrategy> Default for Secret<SecretValue, MaskingStrategy>
 where
     SecretValue: Default,
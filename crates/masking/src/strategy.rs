@@ -17,6 +17,23 @@ impl<T> Strategy<T> for WithType {
     }
 }
 
+/// Debug formats the value it borrows with the [`WithType`] strategy, which is how the fields
+/// marked `#[masked]` are formatted by `#[derive(MaskedDebug)]`
+pub struct MaskedField<'a, T>(&'a T);
+
+impl<'a, T> MaskedField<'a, T> {
+    /// Borrow the value to be masked
+    pub fn new(value: &'a T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> fmt::Debug for MaskedField<'_, T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <WithType as Strategy<T>>::fmt(self.0, fmt)
+    }
+}
+
 /// Debug without type
 pub enum WithoutType {}
 
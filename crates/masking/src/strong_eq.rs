@@ -0,0 +1,48 @@
+//!
+//! Constant time comparison of secrets.
+//!
+
+use subtle::ConstantTimeEq;
+
+///
+/// Equality check whose running time does not depend on the contents being compared.
+///
+/// Use this instead of `==` whenever one of the operands is a secret, like an API key, a client
+/// secret or a webhook signature, so that the comparison does not leak how many leading bytes
+/// matched. Only the length of the operands may be inferred from the time taken.
+///
+pub trait StrongEq {
+    /// Compare `self` with `other` in constant time
+    fn strong_eq(&self, other: &Self) -> bool;
+}
+
+impl StrongEq for [u8] {
+    fn strong_eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl StrongEq for Vec<u8> {
+    fn strong_eq(&self, other: &Self) -> bool {
+        self.as_slice().strong_eq(other.as_slice())
+    }
+}
+
+impl StrongEq for str {
+    fn strong_eq(&self, other: &Self) -> bool {
+        self.as_bytes().strong_eq(other.as_bytes())
+    }
+}
+
+impl StrongEq for String {
+    fn strong_eq(&self, other: &Self) -> bool {
+        self.as_bytes().strong_eq(other.as_bytes())
+    }
+}
+
+///
+/// Compare two byte slices in constant time.
+///
+pub fn constant_time_eq(lhs: &[u8], rhs: &[u8]) -> bool {
+    lhs.strong_eq(rhs)
+}
@@ -4,10 +4,9 @@
 
 use std::{fmt, marker::PhantomData};
 
-use subtle::ConstantTimeEq;
-use zeroize::{self, Zeroize as ZeroizableSecret};
+use zeroize::{self, Zeroize as ZeroizableSecret, ZeroizeOnDrop};
 
-use crate::{strategy::Strategy, PeekInterface};
+use crate::{strategy::Strategy, PeekInterface, StrongEq};
 
 ///
 /// Secret thing.
@@ -105,15 +104,15 @@ impl<Secret: ZeroizableSecret, MaskingStrategy> Drop for StrongSecret<Secret, Ma
     }
 }
 
-trait StrongEq {
-    fn strong_eq(&self, other: &Self) -> bool;
+impl<Secret: ZeroizableSecret, MaskingStrategy> ZeroizableSecret
+    for StrongSecret<Secret, MaskingStrategy>
+{
+    fn zeroize(&mut self) {
+        self.inner_secret.zeroize();
+    }
 }
 
-impl StrongEq for String {
-    fn strong_eq(&self, other: &Self) -> bool {
-        let lhs = self.as_bytes();
-        let rhs = other.as_bytes();
-
-        bool::from(lhs.ct_eq(rhs))
-    }
+impl<Secret: ZeroizableSecret, MaskingStrategy> ZeroizeOnDrop
+    for StrongSecret<Secret, MaskingStrategy>
+{
 }
@@ -158,3 +158,43 @@ fn for_string() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 
     Ok(())
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn zeroize() {
+    use masking::{PeekInterface, PlaintextBuffer, StrongSecret};
+
+    let mut secret = Secret::<String>::new("abc".to_string());
+    secret.zeroize();
+    assert!(secret.peek().is_empty());
+
+    let mut strong_secret = StrongSecret::<String>::new("abc".to_string());
+    strong_secret.zeroize();
+    assert!(strong_secret.peek().is_empty());
+
+    let mut buffer = PlaintextBuffer::new("abc".to_string());
+    assert_eq!(buffer.as_str(), "abc");
+    assert_eq!(format!("{buffer:?}"), "PlaintextBuffer([REDACTED])");
+    buffer.zeroize();
+    assert!(buffer.is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn long_lived_plaintext_buffer() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static REPORTED: AtomicUsize = AtomicUsize::new(0);
+    masking::set_long_lived_plaintext_reporter(|lifetime| {
+        assert!(lifetime > masking::MAX_PLAINTEXT_LIFETIME);
+        REPORTED.fetch_add(1, Ordering::SeqCst);
+    });
+
+    drop(masking::PlaintextBuffer::new("abc".to_string()));
+    assert_eq!(REPORTED.load(Ordering::SeqCst), 0);
+
+    let buffer = masking::PlaintextBuffer::new("abc".to_string());
+    std::thread::sleep(masking::MAX_PLAINTEXT_LIFETIME + std::time::Duration::from_millis(10));
+    drop(buffer);
+    assert_eq!(REPORTED.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "derive")]
+#![allow(dead_code)]
+
+use masking::{MaskedDebug, Secret};
+
+#[derive(MaskedDebug)]
+struct Customer {
+    customer_id: String,
+    name: Secret<String>,
+    #[masked]
+    client_secret: Option<String>,
+    r#type: &'static str,
+}
+
+#[derive(MaskedDebug)]
+struct SessionToken(String, #[masked] String);
+
+#[derive(MaskedDebug)]
+enum Credentials<T> {
+    None,
+    ApiKey {
+        #[masked]
+        api_key: String,
+        key_id: T,
+    },
+    Token(#[masked] String),
+}
+
+#[derive(MaskedDebug)]
+enum Never {}
+
+#[test]
+fn masked_debug_redacts_the_masked_fields() {
+    let customer = Customer {
+        customer_id: "cus_123".to_string(),
+        name: Secret::new("John Doe".to_string()),
+        client_secret: Some("pay_123_secret_456".to_string()),
+        r#type: "individual",
+    };
+    assert_eq!(
+        format!("{customer:?}"),
+        r#"Customer { customer_id: "cus_123", name: *** alloc::string::String ***, client_secret: *** core::option::Option<alloc::string::String> ***, type: "individual" }"#
+    );
+
+    let session_token = SessionToken("klarna".to_string(), "sess_123".to_string());
+    assert_eq!(
+        format!("{session_token:?}"),
+        r#"SessionToken("klarna", *** alloc::string::String ***)"#
+    );
+
+    assert_eq!(format!("{:?}", Credentials::<u8>::None), "None");
+    assert_eq!(
+        format!(
+            "{:?}",
+            Credentials::ApiKey {
+                api_key: "snd_123".to_string(),
+                key_id: 1,
+            }
+        ),
+        "ApiKey { api_key: *** alloc::string::String ***, key_id: 1 }"
+    );
+    assert_eq!(
+        format!("{:?}", Credentials::<u8>::Token("tok_123".to_string())),
+        "Token(*** alloc::string::String ***)"
+    );
+}
@@ -0,0 +1,120 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+//! Lint style check which flags secrets being compared with `==` or `!=` anywhere in the
+//! workspace, as such comparisons short circuit on the first mismatching byte and leak timing
+//! information. Use [`masking::StrongEq`] or [`masking::constant_time_eq`] instead.
+
+use std::{fs, path::Path};
+
+/// Method calls which hand out the inner value of a secret.
+const EXPOSING_CALLS: [&str; 3] = [".peek()", ".expose()", ".expose_option()"];
+
+/// Comparisons which do not involve secret material, as `(file suffix, line contents)`.
+const ALLOWED: [(&str, &str); 2] = [
+    // Checks whether a value is the default one, no secret is being compared against
+    (
+        "common_utils/src/ext_traits.rs",
+        "*self.peek() == T::default()",
+    ),
+    // Checks whether a customer name has been redacted
+    ("router/src/db/customers.rs", "name.peek() == REDACTED"),
+];
+
+fn is_exposed_operand(operand: &str) -> bool {
+    // The length of a secret is not secret material
+    !operand.ends_with(".len()")
+        && EXPOSING_CALLS
+            .iter()
+            .any(|call| operand.ends_with(call) || operand.contains(&format!("{call}.")))
+}
+
+/// Drop comments and the contents of string literals, which are not compared.
+fn strip_non_code(line: &str) -> String {
+    let line = line.split("//").next().unwrap_or_default();
+    let mut in_literal = false;
+
+    line.chars()
+        .filter(|character| {
+            if *character == '"' {
+                in_literal = !in_literal;
+            }
+            !in_literal && *character != '"'
+        })
+        .collect()
+}
+
+fn compares_secret(line: &str) -> bool {
+    let code = strip_non_code(line);
+
+    ["==", "!="].iter().any(|operator| {
+        code.match_indices(operator).any(|(index, _)| {
+            let lhs = code[..index].trim_end();
+            let rhs = code[index + operator.len()..]
+                .split_whitespace()
+                .next()
+                .unwrap_or_default();
+
+            is_exposed_operand(lhs) || is_exposed_operand(rhs)
+        })
+    })
+}
+
+fn is_allowed(path: &Path, line: &str) -> bool {
+    ALLOWED
+        .iter()
+        .any(|(file, contents)| path.ends_with(file) && line.contains(contents))
+}
+
+fn collect_violations(dir: &Path, violations: &mut Vec<String>) {
+    for entry in fs::read_dir(dir).expect("failed to read directory") {
+        let path = entry.expect("failed to read directory entry").path();
+
+        if path.is_dir() {
+            if !path.ends_with("target") {
+                collect_violations(&path, violations);
+            }
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            let source = fs::read_to_string(&path).expect("failed to read source file");
+
+            violations.extend(
+                source
+                    .lines()
+                    .enumerate()
+                    .filter(|(_, line)| compares_secret(line) && !is_allowed(&path, line))
+                    .map(|(number, line)| {
+                        format!("{}:{}: {}", path.display(), number + 1, line.trim())
+                    }),
+            );
+        }
+    }
+}
+
+#[test]
+fn detects_secret_comparisons() {
+    assert!(compares_secret("if api_key.peek() == other {"));
+    assert!(compares_secret("if request_key != admin_api_key.peek() {"));
+    assert!(compares_secret(
+        "Ok(secret.expose().as_bytes() == signature)"
+    ));
+    assert!(!compares_secret("if api_key.peek().strong_eq(other) {"));
+    assert!(!compares_secret("if state.peek().len() == 2 {"));
+    assert!(!compares_secret(
+        "let length = secret.peek().len(); // compared == later"
+    ));
+}
+
+#[test]
+fn secrets_are_not_compared_with_equality_operators() {
+    let crates_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("masking crate is expected to be in the crates directory");
+
+    let mut violations = Vec::new();
+    collect_violations(crates_dir, &mut violations);
+
+    assert!(
+        violations.is_empty(),
+        "secrets must be compared using `masking::StrongEq`, found:\n{}",
+        violations.join("\n")
+    );
+}
@@ -0,0 +1,50 @@
+//! Derive macros for the `masking` crate.
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+
+mod masked_debug;
+
+/// Derives a [`Debug`][Debug] implementation which redacts the sensitive fields of a type, so that
+/// the type can be logged with `{:?}` without leaking them.
+///
+/// The fields marked `#[masked]` are formatted with the [`WithType`][WithType] strategy, as
+/// `*** <type name> ***`. The fields of the [`Secret`][Secret] and [`StrongSecret`][StrongSecret]
+/// types are formatted with the masking strategy of the secret, and the other fields are formatted
+/// with their own [`Debug`][Debug] implementation, as the standard derive would format them.
+///
+/// [Debug]: ::core::fmt::Debug
+/// [Secret]: https://docs.rs/masking/latest/masking/struct.Secret.html
+/// [StrongSecret]: https://docs.rs/masking/latest/masking/struct.StrongSecret.html
+/// [WithType]: https://docs.rs/masking/latest/masking/enum.WithType.html
+///
+/// # Example
+///
+/// ```
+/// use masking::{MaskedDebug, Secret};
+///
+/// #[derive(MaskedDebug)]
+/// struct CustomerRequest {
+///     customer_id: String,
+///     name: Option<Secret<String>>,
+///     #[masked]
+///     description: Option<String>,
+/// }
+///
+/// let request = CustomerRequest {
+///     customer_id: "cus_123".to_string(),
+///     name: Some(Secret::new("John Doe".to_string())),
+///     description: Some("Prefers to be called by the first name".to_string()),
+/// };
+///
+/// // CustomerRequest { customer_id: "cus_123", name: Some(*** alloc::string::String ***),
+/// //     description: *** core::option::Option<alloc::string::String> *** }
+/// println!("{request:?}");
+/// ```
+#[proc_macro_derive(MaskedDebug, attributes(masked))]
+pub fn masked_debug_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    masked_debug::masked_debug_derive_inner(ast)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
@@ -0,0 +1,130 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, parse_quote, Data, DeriveInput, Fields};
+
+/// Helper attribute marking the fields to be redacted in the `Debug` output
+const MASKED_ATTRIBUTE: &str = "masked";
+
+fn is_masked(field: &syn::Field) -> syn::Result<bool> {
+    let mut is_masked = false;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(MASKED_ATTRIBUTE))
+    {
+        attr.meta.require_path_only()?;
+        is_masked = true;
+    }
+
+    Ok(is_masked)
+}
+
+/// The expression to pass to the debug builder for the field bound to `binding`
+fn field_debug_value(field: &syn::Field, binding: &syn::Ident) -> syn::Result<TokenStream> {
+    Ok(if is_masked(field)? {
+        quote! { &::masking::MaskedField::new(#binding) }
+    } else {
+        quote! { #binding }
+    })
+}
+
+/// The pattern binding the fields of a struct or an enum variant, and the expression formatting
+/// them under `name`
+fn fields_debug(name: &str, fields: &Fields) -> syn::Result<(TokenStream, TokenStream)> {
+    match fields {
+        Fields::Named(named_fields) => {
+            let mut bindings = Vec::with_capacity(named_fields.named.len());
+            let mut debug_fields = Vec::with_capacity(named_fields.named.len());
+            for field in &named_fields.named {
+                let binding = field
+                    .ident
+                    .clone()
+                    .ok_or_else(|| syn::Error::new_spanned(field, "expected a named field"))?;
+                let field_name = binding.unraw().to_string();
+                let value = field_debug_value(field, &binding)?;
+                debug_fields.push(quote! { .field(#field_name, #value) });
+                bindings.push(binding);
+            }
+
+            Ok((
+                quote! { { #(#bindings),* } },
+                quote! { formatter.debug_struct(#name) #(#debug_fields)* .finish() },
+            ))
+        }
+        Fields::Unnamed(unnamed_fields) => {
+            let mut bindings = Vec::with_capacity(unnamed_fields.unnamed.len());
+            let mut debug_fields = Vec::with_capacity(unnamed_fields.unnamed.len());
+            for (index, field) in unnamed_fields.unnamed.iter().enumerate() {
+                let binding = format_ident!("field_{index}");
+                let value = field_debug_value(field, &binding)?;
+                debug_fields.push(quote! { .field(#value) });
+                bindings.push(binding);
+            }
+
+            Ok((
+                quote! { ( #(#bindings),* ) },
+                quote! { formatter.debug_tuple(#name) #(#debug_fields)* .finish() },
+            ))
+        }
+        Fields::Unit => Ok((quote! {}, quote! { formatter.write_str(#name) })),
+    }
+}
+
+pub(crate) fn masked_debug_derive_inner(mut ast: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+
+    let arms = match &ast.data {
+        Data::Struct(data_struct) => {
+            let (pattern, body) = fields_debug(&name.unraw().to_string(), &data_struct.fields)?;
+            vec![quote! { Self #pattern => #body }]
+        }
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .map(|variant| {
+                let variant_name = &variant.ident;
+                let (pattern, body) =
+                    fields_debug(&variant_name.unraw().to_string(), &variant.fields)?;
+                Ok(quote! { Self::#variant_name #pattern => #body })
+            })
+            .collect::<syn::Result<Vec<_>>>()?,
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &ast,
+                "MaskedDebug cannot be derived for unions",
+            ))
+        }
+    };
+
+    // The type parameters are required to implement `Debug`, as with the standard derive
+    let type_params = ast
+        .generics
+        .type_params()
+        .map(|type_param| type_param.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = ast.generics.make_where_clause();
+    for type_param in type_params {
+        where_clause
+            .predicates
+            .push(parse_quote! { #type_param: ::core::fmt::Debug });
+    }
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    // Enums without variants cannot be matched by reference
+    let scrutinee = if arms.is_empty() {
+        quote! { *self }
+    } else {
+        quote! { self }
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+            fn fmt(&self, formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match #scrutinee {
+                    #(#arms,)*
+                }
+            }
+        }
+    })
+}
@@ -66,6 +66,9 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "payment link", description = "Create payment link"),
         (name = "Routing", description = "Create and manage routing configurations"),
         (name = "Event", description = "Manage events"),
+        (name = "Usage", description = "Export the billable usage of merchants"),
+        (name = "Connector Migration", description = "Migrate payment methods and mandates between connectors"),
+        (name = "Customer Data Export", description = "Export the data held about customers"),
     ),
     // The paths will be displayed in the same order as they are registered here
     paths(
@@ -79,8 +82,16 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_cancel,
         routes::payments::payments_list,
         routes::payments::payments_incremental_authorization,
+        routes::payments::payments_verify_micro_deposits,
+        routes::payments::payments_retry,
+        routes::payment_link::payment_link_create,
         routes::payment_link::payment_link_retrieve,
         routes::payments::payments_external_authentication,
+        routes::payments::payments_suggested_methods,
+        routes::payments::payments_click_to_pay_identity_lookup,
+        routes::payments::payments_click_to_pay_enroll,
+        routes::payments::payments_calculate_tax,
+        routes::payments::payments_update_tags,
 
         // Routes for refunds
         routes::refunds::refunds_create,
@@ -94,6 +105,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_account::update_merchant_account,
         routes::merchant_account::delete_merchant_account,
         routes::merchant_account::merchant_account_kv_status,
+        routes::merchant_account::update_merchant_feature_entitlements,
 
         // Routes for merchant connector account
         routes::merchant_connector_account::payment_connector_create,
@@ -136,6 +148,10 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::business_profile::business_profiles_retrieve,
         routes::business_profile::business_profiles_update,
         routes::business_profile::business_profiles_delete,
+        routes::business_profile::business_profile_webhook_endpoint_enable,
+        routes::business_profile::business_profile_wallet_certificates_retrieve,
+        routes::business_profile::business_profile_wallet_certificates_update,
+        routes::business_profile::business_profile_wallet_certificates_delete,
 
         // Routes for disputes
         routes::disputes::retrieve_dispute,
@@ -178,9 +194,21 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::list_webhook_schemas,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
+
+        // Routes for usage
+        routes::usage::usage_export,
+
+        // Routes for connector migration
+        routes::connector_migration::connector_migration_create,
+        routes::connector_migration::connector_migration_retrieve,
+
+        // Routes for customer data export
+        routes::customer_data_export::customer_data_export_create,
+        routes::customer_data_export::customer_data_export_retrieve,
     ),
     components(schemas(
         api_models::refunds::RefundRequest,
@@ -191,10 +219,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantAccountCreate,
         api_models::admin::MerchantAccountUpdate,
         api_models::admin::MerchantAccountDeleteResponse,
+        api_models::admin::MerchantFeatureEntitlements,
         api_models::admin::MerchantConnectorDeleteResponse,
         api_models::admin::MerchantConnectorResponse,
         api_models::admin::AuthenticationConnectorDetails,
         api_models::admin::ExtendedCardInfoConfig,
+        api_models::admin::DunningConfig,
+        api_models::admin::AbandonedPaymentConfig,
         api_models::customers::CustomerRequest,
         api_models::customers::CustomerDeleteResponse,
         api_models::payment_methods::PaymentMethodCreate,
@@ -211,6 +242,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::CardDetail,
         api_models::payment_methods::CardDetailUpdate,
         api_models::payment_methods::RequestPaymentMethodTypes,
+        api_models::payment_methods::InstallmentPlan,
         api_models::poll::PollResponse,
         api_models::poll::PollStatus,
         api_models::customers::CustomerResponse,
@@ -227,6 +259,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::CaptureMethod,
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
+        api_models::enums::PaymentChannel,
+        api_models::enums::CaptureAdjustmentReason,
+        api_models::enums::RefundReasonCode,
         api_models::enums::Connector,
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodIssuerCode,
@@ -259,6 +294,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorWebhookDetails,
         api_models::admin::BusinessProfileCreate,
         api_models::admin::BusinessProfileResponse,
+        api_models::admin::WalletCertificates,
+        api_models::admin::ApplePayCertificates,
+        api_models::admin::WalletCertificatesResponse,
+        api_models::admin::ApplePayCertificatesResponse,
+        api_models::admin::GooglePayCertificatesResponse,
         api_models::admin::BusinessPaymentLinkConfig,
         api_models::admin::PaymentLinkConfigRequest,
         api_models::admin::PaymentLinkConfig,
@@ -305,6 +345,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::FeatureMetadata,
         api_models::payments::ApplepayConnectorMetadataRequest,
         api_models::payments::SessionTokenInfo,
+        api_models::payments::PaymentProcessingDetailsAt,
+        api_models::payments::PaymentProcessingDetails,
+        api_models::payments::GooglePayPaymentProcessingDetails,
+        api_models::payments::SamsungPayPaymentProcessingDetails,
         api_models::payments::SwishQrData,
         api_models::payments::AirwallexData,
         api_models::payments::NoonData,
@@ -313,6 +357,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::NextActionType,
         api_models::payments::WalletData,
         api_models::payments::NextActionData,
+        api_models::payments::MicroDepositsVerificationDetails,
+        api_models::payments::MicroDepositType,
         api_models::payments::PayLaterData,
         api_models::payments::MandateData,
         api_models::payments::PhoneDetails,
@@ -363,6 +409,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::SdkNextAction,
         api_models::payments::NextActionCall,
         api_models::payments::SamsungPayWalletData,
+        api_models::payments::SamsungPayWalletCredentials,
+        api_models::payments::SamsungPayTokenData,
+        api_models::payments::SamsungPayCardBrand,
         api_models::payments::WeChatPay,
         api_models::payments::GpayTokenizationData,
         api_models::payments::GooglePayPaymentMethodInfo,
@@ -372,6 +421,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentListConstraints,
         api_models::payments::PaymentListResponse,
         api_models::payments::CashappQr,
+        api_models::payments::ClickToPayWalletData,
+        api_models::payments::ClickToPaySessionResponse,
+        api_models::payments::SamsungPaySessionTokenResponse,
+        api_models::payments::SamsungPayMerchantPaymentInformation,
+        api_models::payments::SamsungPayAmountDetails,
+        api_models::payments::SamsungPayAmountFormat,
+        api_models::payments::SamsungPayProtocolType,
         api_models::payments::BankTransferData,
         api_models::payments::BankTransferNextStepsData,
         api_models::payments::SepaAndBacsBillingDetails,
@@ -392,9 +448,16 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::BacsBankTransferInstructions,
         api_models::payments::RedirectResponse,
         api_models::payments::RequestSurchargeDetails,
+        api_models::payments::SplitPayments,
+        api_models::payments::SplitPaymentTransfer,
+        api_models::payments::L2L3Data,
+        api_models::payments::L3LineItem,
+        api_models::payments::ExternalThreeDsAuthenticationData,
         api_models::payments::PaymentAttemptResponse,
         api_models::payments::CaptureResponse,
         api_models::payments::PaymentsIncrementalAuthorizationRequest,
+        api_models::payments::PaymentsVerifyMicroDepositsRequest,
+        api_models::payments::PaymentsRetryRequest,
         api_models::payments::IncrementalAuthorizationResponse,
         api_models::payments::PaymentsExternalAuthenticationRequest,
         api_models::payments::PaymentsExternalAuthenticationResponse,
@@ -412,6 +475,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequiredFieldInfo,
         api_models::payment_methods::DefaultPaymentMethod,
         api_models::payment_methods::MaskedBankDetails,
+        api_models::payment_methods::BankAccountVerificationStatus,
         api_models::payment_methods::SurchargeDetailsResponse,
         api_models::payment_methods::SurchargeResponse,
         api_models::payment_methods::SurchargePercentage,
@@ -425,12 +489,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::ephemeral_key::EphemeralKeyCreateResponse,
         api_models::payments::CustomerDetails,
         api_models::payments::GiftCardData,
+        api_models::payments::RealTimePaymentData,
         api_models::payments::GiftCardDetails,
         api_models::payments::Address,
         api_models::payouts::Card,
         api_models::payouts::Wallet,
         api_models::payouts::Paypal,
         api_models::payouts::Venmo,
+        api_models::payouts::Payoneer,
         api_models::payouts::AchBankTransfer,
         api_models::payouts::BacsBankTransfer,
         api_models::payouts::SepaBankTransfer,
@@ -470,11 +536,23 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
+        api_models::payments::PaymentLinkCreateRequest,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
         api_models::payments::PaymentLinkInitiateRequest,
         api_models::payments::ExtendedCardInfoResponse,
+        api_models::payments::PaymentsSuggestedMethodsResponse,
+        api_models::payments::SuggestedPaymentMethod,
+        api_models::payments::PaymentRetryDetails,
+        api_models::payments::ClickToPayIdentityLookupRequest,
+        api_models::payments::ClickToPayIdentityLookupResponse,
+        api_models::payments::ClickToPayEnrollmentRequest,
+        api_models::payments::ClickToPayEnrollmentResponse,
+        api_models::payments::PaymentsTaxCalculationRequest,
+        api_models::payments::PaymentsTaxCalculationResponse,
+        api_models::payments::PaymentsTagsUpdateRequest,
+        api_models::payments::PaymentsTagsUpdateResponse,
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
         api_models::routing::RoutingKind,
@@ -510,7 +588,22 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
+        api_models::webhook_events::WebhookSchemasResponse,
+        api_models::webhook_events::WebhookEventSchema,
         api_models::enums::WebhookDeliveryAttempt,
+        api_models::enums::UsageMetric,
+        api_models::usage::UsageRecord,
+        api_models::usage::UsageExportResponse,
+        api_models::connector_migration::ConnectorMigrationRequest,
+        api_models::connector_migration::ConnectorMigrationResponse,
+        api_models::connector_migration::ConnectorMigrationStatus,
+        api_models::connector_migration::ConnectorMigrationOutcome,
+        api_models::connector_migration::ConnectorMigrationItem,
+        api_models::customer_data_export::CustomerDataExportRequest,
+        api_models::customer_data_export::CustomerDataExportResponse,
+        api_models::customer_data_export::CustomerDataExportStatus,
+        api_models::customer_data_export::CustomerDataExport,
+        api_models::customer_data_export::CustomerDataExportPaymentMethod,
     )),
     modifiers(&SecurityAddon)
 )]
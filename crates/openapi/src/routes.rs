@@ -3,6 +3,8 @@
 pub mod api_keys;
 pub mod blocklist;
 pub mod business_profile;
+pub mod connector_migration;
+pub mod customer_data_export;
 pub mod customers;
 pub mod disputes;
 pub mod gsm;
@@ -16,6 +18,7 @@ pub mod payouts;
 pub mod poll;
 pub mod refunds;
 pub mod routing;
+pub mod usage;
 pub mod webhook_events;
 
 pub use self::{
@@ -122,3 +122,85 @@ pub async fn business_profiles_delete() {}
     security(("api_key" = []))
 )]
 pub async fn business_profiles_retrieve() {}
+
+/// Business Profile - Enable Webhook Endpoint
+///
+/// Enable the webhook endpoint of a *business profile*, resuming the delivery of webhooks paused after consecutive delivery failures
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoint/enable",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint Enabled", body = BusinessProfileResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Business Profile",
+    operation_id = "Enable the Webhook Endpoint of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn business_profile_webhook_endpoint_enable() {}
+
+/// Business Profile - Retrieve Wallet Certificates
+///
+/// Retrieve the wallets a *business profile* has certificates for, along with the expiry of the Apple Pay certificates
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/wallet_certificates",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Wallet Certificates Retrieved", body = WalletCertificatesResponse),
+        (status = 404, description = "Business Profile not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Retrieve the Wallet Certificates of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn business_profile_wallet_certificates_retrieve() {}
+
+/// Business Profile - Update Wallet Certificates
+///
+/// Set the certificates and keys the Apple Pay sessions are created with and the Apple Pay, Google Pay and Samsung Pay payment tokens are decrypted with for a *business profile*, taking precedence over those in the metadata of its merchant connector accounts. The certificates of the wallets not given in the request are left as they are.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/wallet_certificates",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    request_body = WalletCertificates,
+    responses(
+        (status = 200, description = "Wallet Certificates Updated", body = WalletCertificatesResponse),
+        (status = 400, description = "Invalid or expired certificates")
+    ),
+    tag = "Business Profile",
+    operation_id = "Update the Wallet Certificates of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn business_profile_wallet_certificates_update() {}
+
+/// Business Profile - Delete Wallet Certificates
+///
+/// Remove the certificates of a wallet of a *business profile*, the wallet then falling back on the certificates in the metadata of the merchant connector accounts
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/wallet_certificates/{wallet}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile"),
+        ("wallet" = PaymentMethodType, Path, description = "The wallet to remove the certificates of, one of apple_pay, google_pay and samsung_pay")
+    ),
+    responses(
+        (status = 200, description = "Wallet Certificates Deleted", body = WalletCertificatesResponse),
+        (status = 400, description = "Not a wallet with certificates")
+    ),
+    tag = "Business Profile",
+    operation_id = "Delete the Wallet Certificates of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn business_profile_wallet_certificates_delete() {}
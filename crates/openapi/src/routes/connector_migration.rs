@@ -0,0 +1,38 @@
+/// Connector Migration - Create
+///
+/// Migrates the stored payment methods and active mandates of a merchant from one connector account to another, for merchants switching acquirers. Tokens imported by the target connector replace the ones of the source connector, and payment methods and mandates whose tokens have not been imported fall back to their network transaction ID where the target connector supports it. The migration runs in the background; retrieve it to get the outcome of every payment method and mandate.
+#[utoipa::path(
+    post,
+    path = "/connector_migration/{merchant_id}",
+    params(("merchant_id" = String, Path, description = "The unique identifier for the merchant account")),
+    request_body = ConnectorMigrationRequest,
+    responses(
+        (status = 200, description = "Connector migration scheduled", body = ConnectorMigrationResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant connector account not found")
+    ),
+    tag = "Connector Migration",
+    operation_id = "Create a Connector Migration",
+    security(("admin_api_key" = [])),
+)]
+pub async fn connector_migration_create() {}
+
+/// Connector Migration - Retrieve
+///
+/// Retrieves the status of a connector migration, along with the outcome of the migration of every payment method and mandate once it has been completed.
+#[utoipa::path(
+    get,
+    path = "/connector_migration/{merchant_id}/{migration_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("migration_id" = String, Path, description = "The unique identifier for the connector migration")
+    ),
+    responses(
+        (status = 200, description = "Connector migration retrieved", body = ConnectorMigrationResponse),
+        (status = 404, description = "Connector migration not found")
+    ),
+    tag = "Connector Migration",
+    operation_id = "Retrieve a Connector Migration",
+    security(("admin_api_key" = [])),
+)]
+pub async fn connector_migration_retrieve() {}
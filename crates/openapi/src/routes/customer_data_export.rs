@@ -0,0 +1,38 @@
+/// Customer Data Export - Create
+///
+/// Exports all the data held about a customer of a merchant, to answer the data subject access requests of the customer. The export holds the details of the customer, their payments, the metadata of their saved payment methods, the disputes raised on their payments and the webhook events sent for them. The export is produced in the background; retrieve it to get the exported data once it has been completed.
+#[utoipa::path(
+    post,
+    path = "/customer_data_export/{merchant_id}",
+    params(("merchant_id" = String, Path, description = "The unique identifier for the merchant account")),
+    request_body = CustomerDataExportRequest,
+    responses(
+        (status = 200, description = "Customer data export scheduled", body = CustomerDataExportResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Customer not found")
+    ),
+    tag = "Customer Data Export",
+    operation_id = "Create a Customer Data Export",
+    security(("admin_api_key" = [])),
+)]
+pub async fn customer_data_export_create() {}
+
+/// Customer Data Export - Retrieve
+///
+/// Retrieves the status of a customer data export, along with the exported data once it has been completed.
+#[utoipa::path(
+    get,
+    path = "/customer_data_export/{merchant_id}/{export_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("export_id" = String, Path, description = "The unique identifier for the customer data export")
+    ),
+    responses(
+        (status = 200, description = "Customer data export retrieved", body = CustomerDataExportResponse),
+        (status = 404, description = "Customer data export not found")
+    ),
+    tag = "Customer Data Export",
+    operation_id = "Retrieve a Customer Data Export",
+    security(("admin_api_key" = [])),
+)]
+pub async fn customer_data_export_retrieve() {}
@@ -155,3 +155,33 @@ pub async fn delete_merchant_account() {}
     security(("admin_api_key" = []))
 )]
 pub async fn merchant_account_kv_status() {}
+
+/// Merchant Account - Feature Entitlements
+///
+/// Update the features which the merchant account is entitled to use, like payouts, FRM, payment links and analytics
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/entitlements",
+    request_body (
+        content = MerchantFeatureEntitlements,
+        examples (
+            ("Disable payouts and payment links for Merchant" = (
+                value = json!({
+                "payouts": false,
+                "frm": true,
+                "payment_links": false,
+                "analytics": true
+                })
+        )))
+    ),
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Feature entitlements of the Merchant Account updated", body = MerchantAccountResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Update feature entitlements of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn update_merchant_feature_entitlements() {}
@@ -1,3 +1,20 @@
+/// Payment Link - Create
+///
+/// To create a payment link for a payment which has been created without one
+#[utoipa::path(
+    post,
+    path = "/payment_link",
+    request_body = PaymentLinkCreateRequest,
+    responses(
+        (status = 200, description = "Payment link created", body = RetrievePaymentLinkResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payments",
+    operation_id = "Create a Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_create() {}
+
 /// Payments Link - Retrieve
 ///
 /// To retrieve the properties of a Payment Link. This may be used to get the status of a previously initiated payment or next action for an ongoing payment
@@ -389,6 +389,8 @@ pub fn payments_connector_session() {}
 /// Payments - Cancel
 ///
 /// A Payment could can be cancelled when it is in one of these statuses: `requires_payment_method`, `requires_capture`, `requires_confirmation`, `requires_customer_action`.
+///
+/// Cancelling a payment which is `partially_captured` releases the part of the authorized amount which remains uncaptured, where the connector supports partial reversals, and the released amount is returned as `amount_reversed`.
 #[utoipa::path(
     post,
     path = "/payments/{payment_id}/cancel",
@@ -467,6 +469,46 @@ pub fn payments_list() {}
 )]
 pub fn payments_incremental_authorization() {}
 
+/// Payments - Verify Micro-deposits
+///
+/// Verify the bank account of an ACH bank debit payment which is in status: requires_customer_action, using the micro-deposits made to it
+#[utoipa::path(
+  post,
+  path = "/payments/{payment_id}/verify_micro_deposits",
+  request_body=PaymentsVerifyMicroDepositsRequest,
+  params(
+      ("payment_id" = String, Path, description = "The identifier for payment")
+  ),
+  responses(
+      (status = 200, description = "Micro-deposits verified", body = PaymentsResponse),
+      (status = 400, description = "Missing mandatory fields")
+  ),
+  tag = "Payments",
+  operation_id = "Verify micro-deposits of a Payment",
+  security(("api_key" = []))
+)]
+pub fn payments_verify_micro_deposits() {}
+
+/// Payments - Retry
+///
+/// Retry a payment which is in status: failed, with a new attempt on the same payment. The payment can be retried with another connector or with a new payment method
+#[utoipa::path(
+  post,
+  path = "/payments/{payment_id}/retry",
+  request_body=PaymentsRetryRequest,
+  params(
+      ("payment_id" = String, Path, description = "The identifier for payment")
+  ),
+  responses(
+      (status = 200, description = "Payment retried", body = PaymentsResponse),
+      (status = 400, description = "Missing mandatory fields")
+  ),
+  tag = "Payments",
+  operation_id = "Retry a Payment",
+  security(("api_key" = []))
+)]
+pub fn payments_retry() {}
+
 /// Payments - External 3DS Authentication
 ///
 /// External 3DS Authentication is performed and returns the AuthenticationResponse
@@ -486,3 +528,103 @@ pub fn payments_incremental_authorization() {}
   security(("publishable_key" = []))
 )]
 pub fn payments_external_authentication() {}
+
+/// Payments - Suggested payment methods
+///
+/// Lists the payment methods eligible for a payment, ranked by how likely a payment made with them is to succeed, based on the previous payments of the customer
+#[utoipa::path(
+  get,
+  path = "/payments/{payment_id}/suggested_methods",
+  params(
+      ("payment_id" = String, Path, description = "The identifier for payment")
+  ),
+  responses(
+      (status = 200, description = "Suggested payment methods retrieved", body = PaymentsSuggestedMethodsResponse),
+      (status = 404, description = "No payment found")
+  ),
+  tag = "Payments",
+  operation_id = "Retrieve the suggested payment methods for a Payment",
+  security(("api_key" = []))
+)]
+pub fn payments_suggested_methods() {}
+
+/// Payments - Click to Pay identity lookup
+///
+/// Checks whether the customer has a Click to Pay profile with any of the card networks accepted for the payment, using their email or phone number
+#[utoipa::path(
+  post,
+  path = "/payments/{payment_id}/click_to_pay/identity_lookup",
+  request_body=ClickToPayIdentityLookupRequest,
+  params(
+      ("payment_id" = String, Path, description = "The identifier for payment")
+  ),
+  responses(
+      (status = 200, description = "Click to Pay identity lookup performed", body = ClickToPayIdentityLookupResponse),
+      (status = 400, description = "Missing mandatory fields")
+  ),
+  tag = "Payments",
+  operation_id = "Look up the Click to Pay profiles of the customer of a Payment",
+  security(("publishable_key" = []))
+)]
+pub fn payments_click_to_pay_identity_lookup() {}
+
+/// Payments - Click to Pay card enrollment
+///
+/// Adds a card to the Click to Pay profile of the customer with the card network of the card, creating the profile if the customer does not have one
+#[utoipa::path(
+  post,
+  path = "/payments/{payment_id}/click_to_pay/enroll",
+  request_body=ClickToPayEnrollmentRequest,
+  params(
+      ("payment_id" = String, Path, description = "The identifier for payment")
+  ),
+  responses(
+      (status = 200, description = "Card enrolled in Click to Pay", body = ClickToPayEnrollmentResponse),
+      (status = 400, description = "Missing mandatory fields")
+  ),
+  tag = "Payments",
+  operation_id = "Enroll a card in Click to Pay for a Payment",
+  security(("publishable_key" = []))
+)]
+pub fn payments_click_to_pay_enroll() {}
+
+/// Payments - Calculate tax
+///
+/// Calculates the tax on the order of a payment for the shipping address selected by the customer, such as in the payment sheet of their wallet, and updates the amount of the payment to include it
+#[utoipa::path(
+  post,
+  path = "/payments/{payment_id}/calculate_tax",
+  request_body=PaymentsTaxCalculationRequest,
+  params(
+      ("payment_id" = String, Path, description = "The identifier for payment")
+  ),
+  responses(
+      (status = 200, description = "Tax calculated and included in the amount of the payment", body = PaymentsTaxCalculationResponse),
+      (status = 400, description = "Missing mandatory fields")
+  ),
+  tag = "Payments",
+  operation_id = "Calculate the tax on a Payment",
+  security(("publishable_key" = []))
+)]
+pub fn payments_calculate_tax() {}
+
+/// Payments - Update tags
+///
+/// Replaces the tags of a payment, which the payments can be listed by
+#[utoipa::path(
+  patch,
+  path = "/payments/{payment_id}/tags",
+  request_body=PaymentsTagsUpdateRequest,
+  params(
+      ("payment_id" = String, Path, description = "The identifier for payment")
+  ),
+  responses(
+      (status = 200, description = "Tags of the payment updated", body = PaymentsTagsUpdateResponse),
+      (status = 400, description = "Invalid tags"),
+      (status = 404, description = "No payment found with the given id")
+  ),
+  tag = "Payments",
+  operation_id = "Update the tags of a Payment",
+  security(("api_key" = []))
+)]
+pub fn payments_update_tags() {}
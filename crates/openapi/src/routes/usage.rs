@@ -0,0 +1,20 @@
+/// Usage - Export
+///
+/// Exports the billable usage (API calls, successful payments and tokens stored in the vault) metered per merchant, aggregated per day, for the given period. Usage of all merchants is exported unless a merchant is specified.
+#[utoipa::path(
+    get,
+    path = "/usage/export",
+    params(
+        ("start_time" = PrimitiveDateTime, Query, description = "Start of the period to export usage for"),
+        ("end_time" = PrimitiveDateTime, Query, description = "End of the period to export usage for"),
+        ("merchant_id" = Option<String>, Query, description = "The merchant to export usage for"),
+    ),
+    responses(
+        (status = 200, description = "Usage exported", body = UsageExportResponse),
+        (status = 400, description = "Invalid period provided")
+    ),
+    tag = "Usage",
+    operation_id = "Export Usage",
+    security(("admin_api_key" = [])),
+)]
+pub async fn usage_export() {}
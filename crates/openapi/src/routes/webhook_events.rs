@@ -92,3 +92,17 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Webhook Schemas
+///
+/// List the JSON Schemas of the payloads of the outgoing webhooks, one for each event type, to generate the handlers of the webhooks from or to validate their payloads with.
+#[utoipa::path(
+    get,
+    path = "/webhooks/schemas",
+    responses(
+        (status = 200, description = "The JSON Schemas of the webhook payloads", body = WebhookSchemasResponse),
+    ),
+    tag = "Event",
+    operation_id = "List the schemas of the webhook payloads"
+)]
+pub fn list_webhook_schemas() {}
@@ -15,7 +15,9 @@ use crate::{
     types::{
         self as auth_types,
         api::{
-            auth_service::{self, BankAccountCredentials, ExchangeToken, LinkToken},
+            auth_service::{
+                self, BankAccountBalance, BankAccountCredentials, ExchangeToken, LinkToken,
+            },
             ConnectorCommon, ConnectorCommonExt, ConnectorIntegration,
         },
     },
@@ -338,3 +340,89 @@ impl
         self.build_error_response(res)
     }
 }
+
+impl auth_service::AuthServiceBankAccountBalance for Plaid {}
+
+impl
+    ConnectorIntegration<
+        BankAccountBalance,
+        auth_types::BankAccountBalanceRequest,
+        auth_types::BankAccountBalanceResponse,
+    > for Plaid
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::BankBalanceRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::BankBalanceRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}{}",
+            self.base_url(connectors),
+            "/accounts/balance/get"
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::BankBalanceRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        let req_obj = plaid::PlaidBankAccountBalanceRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(req_obj)))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::BankBalanceRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&auth_types::PaymentAuthBankAccountBalanceType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentAuthBankAccountBalanceType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(
+                    auth_types::PaymentAuthBankAccountBalanceType::get_request_body(self, req)?,
+                )
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::BankBalanceRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::BankBalanceRouterData, errors::ConnectorError> {
+        let response: plaid::PlaidBankAccountBalanceResponse = res
+            .response
+            .parse_struct("PlaidBankAccountBalanceResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        <auth_types::BankBalanceRouterData>::try_from(auth_types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
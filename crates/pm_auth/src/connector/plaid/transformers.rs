@@ -330,6 +330,90 @@ impl<F, T>
         })
     }
 }
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct PlaidBankAccountBalanceRequest {
+    access_token: String,
+    options: Option<BankAccountCredentialsOptions>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct PlaidBankAccountBalanceResponse {
+    pub accounts: Vec<PlaidBankAccountBalanceAccounts>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct PlaidBankAccountBalanceAccounts {
+    pub account_id: String,
+    pub balances: PlaidBankAccountBalances,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct PlaidBankAccountBalances {
+    pub available: Option<f64>,
+    pub current: Option<f64>,
+    pub iso_currency_code: Option<String>,
+}
+
+impl TryFrom<&types::BankBalanceRouterData> for PlaidBankAccountBalanceRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::BankBalanceRouterData) -> Result<Self, Self::Error> {
+        let options = item.request.optional_ids.as_ref().map(|bank_account_ids| {
+            let ids = bank_account_ids
+                .ids
+                .iter()
+                .map(|id| id.peek().to_string())
+                .collect::<Vec<_>>();
+
+            BankAccountCredentialsOptions { account_ids: ids }
+        });
+
+        Ok(Self {
+            access_token: item.request.access_token.peek().to_string(),
+            options,
+        })
+    }
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            PlaidBankAccountBalanceResponse,
+            T,
+            types::BankAccountBalanceResponse,
+        >,
+    > for types::PaymentAuthRouterData<F, T, types::BankAccountBalanceResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            PlaidBankAccountBalanceResponse,
+            T,
+            types::BankAccountBalanceResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let balances = item
+            .response
+            .accounts
+            .into_iter()
+            .map(|account| types::BankAccountBalanceDetails {
+                account_id: account.account_id.into(),
+                available: account.balances.available,
+                current: account.balances.current,
+                iso_currency_code: account.balances.iso_currency_code,
+            })
+            .collect();
+
+        Ok(Self {
+            response: Ok(types::BankAccountBalanceResponse { balances }),
+            ..item.data
+        })
+    }
+}
+
 pub struct PlaidAuthType {
     pub client_id: Secret<String>,
     pub secret: Secret<String>,
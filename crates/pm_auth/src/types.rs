@@ -2,7 +2,7 @@ pub mod api;
 
 use std::marker::PhantomData;
 
-use api::auth_service::{BankAccountCredentials, ExchangeToken, LinkToken};
+use api::auth_service::{BankAccountBalance, BankAccountCredentials, ExchangeToken, LinkToken};
 use common_enums::{PaymentMethod, PaymentMethodType};
 use masking::Secret;
 #[derive(Debug, Clone)]
@@ -109,6 +109,33 @@ pub type BankDetailsRouterData = PaymentAuthRouterData<
     BankAccountCredentialsResponse,
 >;
 
+#[derive(Debug, Clone)]
+pub struct BankAccountBalanceRequest {
+    pub access_token: Secret<String>,
+    pub optional_ids: Option<BankAccountOptionalIDs>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BankAccountBalanceResponse {
+    pub balances: Vec<BankAccountBalanceDetails>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BankAccountBalanceDetails {
+    pub account_id: Secret<String>,
+    /// Balance available for spending, in the major unit of the currency
+    pub available: Option<f64>,
+    /// Total balance of the account, in the major unit of the currency
+    pub current: Option<f64>,
+    pub iso_currency_code: Option<String>,
+}
+
+pub type BankBalanceRouterData = PaymentAuthRouterData<
+    BankAccountBalance,
+    BankAccountBalanceRequest,
+    BankAccountBalanceResponse,
+>;
+
 pub type PaymentAuthLinkTokenType =
     dyn self::api::ConnectorIntegration<LinkToken, LinkTokenRequest, LinkTokenResponse>;
 
@@ -121,6 +148,12 @@ pub type PaymentAuthBankAccountDetailsType = dyn self::api::ConnectorIntegration
     BankAccountCredentialsResponse,
 >;
 
+pub type PaymentAuthBankAccountBalanceType = dyn self::api::ConnectorIntegration<
+    BankAccountBalance,
+    BankAccountBalanceRequest,
+    BankAccountBalanceResponse,
+>;
+
 #[derive(Clone, Debug, strum::EnumString, strum::Display)]
 #[strum(serialize_all = "snake_case")]
 pub enum PaymentMethodAuthConnectors {
@@ -1,6 +1,7 @@
 use crate::types::{
-    BankAccountCredentialsRequest, BankAccountCredentialsResponse, ExchangeTokenRequest,
-    ExchangeTokenResponse, LinkTokenRequest, LinkTokenResponse,
+    BankAccountBalanceRequest, BankAccountBalanceResponse, BankAccountCredentialsRequest,
+    BankAccountCredentialsResponse, ExchangeTokenRequest, ExchangeTokenResponse, LinkTokenRequest,
+    LinkTokenResponse,
 };
 
 pub trait AuthService:
@@ -8,6 +9,7 @@ pub trait AuthService:
     + AuthServiceLinkToken
     + AuthServiceExchangeToken
     + AuthServiceBankAccountCredentials
+    + AuthServiceBankAccountBalance
 {
 }
 
@@ -38,3 +40,15 @@ pub trait AuthServiceBankAccountCredentials:
 >
 {
 }
+
+#[derive(Debug, Clone)]
+pub struct BankAccountBalance;
+
+pub trait AuthServiceBankAccountBalance:
+    super::ConnectorIntegration<
+    BankAccountBalance,
+    BankAccountBalanceRequest,
+    BankAccountBalanceResponse,
+>
+{
+}
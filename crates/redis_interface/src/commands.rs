@@ -244,6 +244,14 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key(&self, key: &str) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .incr(key)
+            .await
+            .change_context(errors::RedisError::IncrementFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expiry(
         &self,
@@ -389,6 +397,41 @@ impl super::RedisConnectionPool {
             .await)
     }
 
+    /// Scan the hash, returning the values of the fields matching the pattern as they are stored,
+    /// including the values which are not valid UTF-8
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn hscan_raw(
+        &self,
+        key: &str,
+        pattern: &str,
+        count: Option<u32>,
+    ) -> CustomResult<Vec<Vec<u8>>, errors::RedisError> {
+        Ok(self
+            .pool
+            .next()
+            .hscan::<&str, &str>(key, pattern, count)
+            .filter_map(|value| async move {
+                match value {
+                    Ok(mut v) => {
+                        let v = v.take_results()?;
+
+                        let v: Vec<Vec<u8>> = v
+                            .values()
+                            .filter_map(|val| val.as_bytes().map(<[u8]>::to_vec))
+                            .collect();
+                        Some(futures::stream::iter(v))
+                    }
+                    Err(err) => {
+                        logger::error!(?err);
+                        None
+                    }
+                }
+            })
+            .flatten()
+            .collect::<Vec<_>>()
+            .await)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn hscan_and_deserialize<T>(
         &self,
@@ -16,6 +16,8 @@ pub enum RedisError {
     SetExpiryFailed,
     #[error("Failed to get key value in Redis")]
     GetFailed,
+    #[error("Failed to increment key value in Redis")]
+    IncrementFailed,
     #[error("Failed to delete key value in Redis")]
     DeleteFailed,
     #[error("Failed to append entry to Redis stream")]
@@ -46,6 +48,10 @@ pub enum RedisError {
     JsonSerializationFailed,
     #[error("Failed to deserialize application type from JSON")]
     JsonDeserializationFailed,
+    #[error("Failed to serialize application type to MessagePack")]
+    MessagePackSerializationFailed,
+    #[error("Failed to deserialize application type from MessagePack")]
+    MessagePackDeserializationFailed,
     #[error("Failed to set hash in Redis")]
     SetHashFailed,
     #[error("Failed to set hash field in Redis")]
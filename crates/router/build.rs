@@ -1,9 +1,26 @@
+#[path = "../common_enums/connector_manifest.rs"]
+mod connector_manifest;
+
 fn main() {
     // Set thread stack size to 8 MiB for debug builds
     // Reference: https://doc.rust-lang.org/std/thread/#stack-size
     #[cfg(debug_assertions)]
     println!("cargo:rustc-env=RUST_MIN_STACK=8388608"); // 8 * 1024 * 1024 = 8 MiB
 
+    let manifest =
+        connector_manifest::ConnectorManifest::load("../common_enums/connectors.toml".as_ref());
+    connector_manifest::write_generated_file(
+        "connectors.rs",
+        &format!(
+            r#"#[derive(Debug, Deserialize, Serialize, Clone, Default, router_derive::ConfigValidate)]
+#[serde(default)]
+pub struct Connectors {{
+{}}}
+"#,
+            manifest.config_fields()
+        ),
+    );
+
     #[cfg(feature = "vergen")]
     router_env::vergen::generate_cargo_instructions();
 }
@@ -29,7 +29,7 @@ pub mod routes {
             authorization::permissions::Permission,
             ApplicationResponse,
         },
-        types::domain::UserEmail,
+        types::{api::admin::MerchantFeature, domain::UserEmail},
     };
 
     pub struct Analytics;
@@ -163,7 +163,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -199,7 +202,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -235,7 +241,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -261,7 +270,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -287,7 +299,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -313,7 +328,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -335,7 +353,10 @@ pub mod routes {
                     .await
                     .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -359,7 +380,10 @@ pub mod routes {
                     .await
                     .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -385,7 +409,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -427,7 +454,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -469,7 +499,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -511,7 +544,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::PaymentWrite),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::PaymentWrite),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -547,7 +583,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -573,7 +612,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -595,7 +637,10 @@ pub mod routes {
                     .await
                     .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -621,7 +666,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -652,7 +700,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -678,7 +729,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -713,7 +767,10 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::Analytics),
+                feature: MerchantFeature::Analytics,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
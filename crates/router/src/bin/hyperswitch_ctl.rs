@@ -0,0 +1,394 @@
+//! Operational commands run against the databases and the redis of a deployment, such as
+//! inspecting the state of a payment while debugging it.
+//!
+//! ```text
+//! hyperswitch-ctl -f config/production.toml payment inspect <payment_id> --merchant-id <id>
+//! hyperswitch-ctl -f config/production.toml maintenance redact-card-data --column <table>.<column> --dry-run
+//! ```
+
+use std::path::PathBuf;
+
+use api_models::webhook_events::OutgoingWebhookResponseContent;
+use common_utils::ext_traits::StringExt;
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router::{
+    configs::settings::Settings,
+    core::{
+        api_locking::API_LOCK_PREFIX,
+        card_data_redaction,
+        errors::{self, RouterResult, StorageErrorExt},
+    },
+    routes::{self, lock_utils::ApiIdentifier},
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+use serde::Serialize;
+use tokio::sync::oneshot;
+
+#[derive(clap::Parser)]
+#[command(name = "hyperswitch-ctl")]
+struct Cli {
+    /// Config file.
+    /// The commands will look for "config/config.toml" if this option isn't specified.
+    #[arg(short = 'f', long, value_name = "FILE")]
+    config_path: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Commands for the payments.
+    #[command(subcommand)]
+    Payment(PaymentCommand),
+
+    /// Commands for the maintenance of the data of all the merchants.
+    #[command(subcommand)]
+    Maintenance(MaintenanceCommand),
+}
+
+#[derive(clap::Subcommand)]
+enum PaymentCommand {
+    /// Print the intent, the attempts, the routing decisions, the refunds, the webhook events and
+    /// the scheduler tasks of a payment.
+    Inspect {
+        payment_id: String,
+
+        /// Merchant the payment belongs to.
+        #[arg(long)]
+        merchant_id: String,
+    },
+}
+
+#[derive(clap::Subcommand)]
+enum MaintenanceCommand {
+    /// Scan columns for the card numbers stored in them by mistake, and redact all but the last
+    /// four digits of the card numbers in place. Prints a report of the redacted rows.
+    RedactCardData {
+        /// Column to scan, as `<table>.<column>`. Can be repeated.
+        #[arg(long = "column", value_name = "TABLE.COLUMN", required = true)]
+        columns: Vec<String>,
+
+        /// Report the card numbers found without redacting them.
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Number of rows read at a time.
+        #[arg(long, default_value_t = 500)]
+        batch_size: i64,
+    },
+}
+
+/// Everything recorded about a payment, gathered in one document
+#[derive(Serialize)]
+struct PaymentInspection {
+    payment_intent: storage::PaymentIntent,
+    payment_attempts: Vec<storage::PaymentAttempt>,
+    routing: PaymentRouting,
+    refunds: Vec<storage::Refund>,
+    webhook_events: Vec<WebhookEvent>,
+    scheduler_tasks: Vec<storage::ProcessTracker>,
+    /// Request holding the lock on the payment, if it is being processed right now
+    api_lock_holder: Option<String>,
+}
+
+#[derive(Serialize)]
+struct PaymentRouting {
+    profile_id: Option<String>,
+    /// Routing algorithm active for the business profile of the payment, which the connectors of
+    /// the attempts without a straight through algorithm have been chosen by
+    profile_routing_algorithm: Option<serde_json::Value>,
+    attempts: Vec<AttemptRouting>,
+}
+
+#[derive(Serialize)]
+struct AttemptRouting {
+    attempt_id: String,
+    status: enums::AttemptStatus,
+    connector: Option<String>,
+    merchant_connector_id: Option<String>,
+    straight_through_algorithm: Option<serde_json::Value>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Serialize)]
+struct WebhookEvent {
+    event_id: String,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    object_id: String,
+    is_delivery_successful: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: time::PrimitiveDateTime,
+    delivery_attempts: Vec<WebhookDelivery>,
+}
+
+#[derive(Serialize)]
+struct WebhookDelivery {
+    event_id: String,
+    delivery_attempt: Option<enums::WebhookDeliveryAttempt>,
+    is_delivery_successful: bool,
+    status_code: Option<u16>,
+    error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: time::PrimitiveDateTime,
+}
+
+#[tokio::main]
+async fn main() -> RouterResult<()> {
+    let cli = <Cli as clap::Parser>::parse();
+
+    #[allow(clippy::expect_used)]
+    let conf = Settings::with_config_path(cli.config_path)
+        .expect("Unable to construct application configuration");
+    #[allow(clippy::expect_used)]
+    let api_client = Box::new(
+        services::ProxyClient::new(
+            conf.proxy.clone(),
+            services::proxy_bypass_urls(&conf.locker),
+        )
+        .expect("Failed to construct the api client"),
+    );
+    let (redis_shutdown_signal_tx, _redis_shutdown_signal_rx) = oneshot::channel();
+    let state = Box::pin(routes::AppState::new(
+        conf,
+        redis_shutdown_signal_tx,
+        api_client,
+    ))
+    .await;
+
+    match cli.command {
+        Command::Payment(PaymentCommand::Inspect {
+            payment_id,
+            merchant_id,
+        }) => {
+            let inspection = inspect_payment(&state, &merchant_id, &payment_id).await?;
+            let output = serde_json::to_string_pretty(&inspection)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the payment inspection")?;
+            println!("{output}");
+        }
+        Command::Maintenance(MaintenanceCommand::RedactCardData {
+            columns,
+            dry_run,
+            batch_size,
+        }) => {
+            let targets = columns
+                .iter()
+                .map(|column| card_data_redaction::parse_redaction_target(column))
+                .collect::<RouterResult<Vec<_>>>()?;
+            let report =
+                card_data_redaction::redact_card_data(&state, targets, dry_run, batch_size).await?;
+            let output = serde_json::to_string_pretty(&report)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the card data redaction report")?;
+            println!("{output}");
+        }
+    }
+
+    Ok(())
+}
+
+async fn inspect_payment(
+    state: &routes::AppState,
+    merchant_id: &str,
+    payment_id: &str,
+) -> RouterResult<PaymentInspection> {
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(payment_id, merchant_id, storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempts = db
+        .find_attempts_by_merchant_id_payment_id(merchant_id, payment_id, storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the payment attempts")?;
+    let refunds = db
+        .find_refund_by_payment_id_merchant_id(payment_id, merchant_id, storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the refunds")?;
+
+    let profile_routing_algorithm = match payment_intent.profile_id.as_deref() {
+        Some(profile_id) => {
+            db.find_business_profile_by_profile_id(profile_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                    id: profile_id.to_string(),
+                })?
+                .routing_algorithm
+        }
+        None => None,
+    };
+    let routing = PaymentRouting {
+        profile_id: payment_intent.profile_id.clone(),
+        profile_routing_algorithm,
+        attempts: payment_attempts
+            .iter()
+            .map(|attempt| AttemptRouting {
+                attempt_id: attempt.attempt_id.clone(),
+                status: attempt.status,
+                connector: attempt.connector.clone(),
+                merchant_connector_id: attempt.merchant_connector_id.clone(),
+                straight_through_algorithm: attempt.straight_through_algorithm.clone(),
+                created_at: attempt.created_at,
+            })
+            .collect(),
+    };
+
+    let mut webhook_events = Vec::new();
+    for object_id in
+        std::iter::once(payment_id).chain(refunds.iter().map(|refund| refund.refund_id.as_str()))
+    {
+        for event in db
+            .list_initial_events_by_merchant_id_primary_object_id(
+                merchant_id,
+                object_id,
+                &key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the webhook events")?
+        {
+            webhook_events.push(get_webhook_event(state, merchant_id, &key_store, event).await?);
+        }
+    }
+
+    // The identifiers of the scheduler tasks are derived from the payment, so that they can be
+    // looked up directly instead of searching their tracking data
+    let mut process_tracker_ids = vec![
+        format!(
+            "{}_PAYMENT_DUNNING_{payment_id}",
+            storage::ProcessTrackerRunner::PaymentDunningWorkflow
+        ),
+        format!(
+            "{}_PAYMENT_INTENT_EXPIRY_{payment_id}",
+            storage::ProcessTrackerRunner::PaymentIntentExpiryWorkflow
+        ),
+        format!(
+            "{}_ABANDONED_PAYMENT_NOTIFICATION_{payment_id}",
+            storage::ProcessTrackerRunner::AbandonedPaymentNotificationWorkflow
+        ),
+    ];
+    process_tracker_ids.extend(payment_attempts.iter().map(|attempt| {
+        scheduler::utils::get_process_tracker_id(
+            storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+            "PAYMENTS_SYNC",
+            &attempt.attempt_id,
+            merchant_id,
+        )
+    }));
+    process_tracker_ids.extend(refunds.iter().flat_map(|refund| {
+        ["EXECUTE_REFUND", "SYNC_REFUND"].map(|task| {
+            format!(
+                "{}_{task}_{}",
+                storage::ProcessTrackerRunner::RefundWorkflowRouter,
+                refund.internal_reference_id
+            )
+        })
+    }));
+    process_tracker_ids.extend(webhook_events.iter().map(|event| {
+        scheduler::utils::get_process_tracker_id(
+            storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+            "OUTGOING_WEBHOOK_RETRY",
+            &event.event_id,
+            merchant_id,
+        )
+    }));
+    let mut scheduler_tasks = Vec::new();
+    for process_tracker_id in process_tracker_ids {
+        scheduler_tasks.extend(
+            db.find_process_by_id(&process_tracker_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the scheduler task")?,
+        );
+    }
+
+    let api_lock_holder = db
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_key::<Option<String>>(&format!(
+            "{API_LOCK_PREFIX}_{merchant_id}_{}_{payment_id}",
+            ApiIdentifier::Payments
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to read the lock on the payment")?;
+
+    Ok(PaymentInspection {
+        payment_intent,
+        payment_attempts,
+        routing,
+        refunds,
+        webhook_events,
+        scheduler_tasks,
+        api_lock_holder,
+    })
+}
+
+async fn get_webhook_event(
+    state: &routes::AppState,
+    merchant_id: &str,
+    key_store: &domain::MerchantKeyStore,
+    event: domain::Event,
+) -> RouterResult<WebhookEvent> {
+    let initial_attempt_id = event
+        .initial_attempt_id
+        .clone()
+        .unwrap_or_else(|| event.event_id.clone());
+    let delivery_attempts = state
+        .store
+        .list_events_by_merchant_id_initial_attempt_id(merchant_id, &initial_attempt_id, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the delivery attempts of the webhook event")?
+        .into_iter()
+        .map(|delivery_attempt| {
+            let response = delivery_attempt.response.and_then(|response| {
+                response
+                    .peek()
+                    .parse_struct::<OutgoingWebhookResponseContent>(
+                        "OutgoingWebhookResponseContent",
+                    )
+                    .ok()
+            });
+            WebhookDelivery {
+                event_id: delivery_attempt.event_id,
+                delivery_attempt: delivery_attempt.delivery_attempt,
+                is_delivery_successful: delivery_attempt.is_webhook_notified,
+                status_code: response.as_ref().and_then(|response| response.status_code),
+                error_message: response.and_then(|response| response.error_message),
+                created_at: delivery_attempt.created_at,
+            }
+        })
+        .collect();
+
+    Ok(WebhookEvent {
+        event_id: event.event_id,
+        event_type: event.event_type,
+        event_class: event.event_class,
+        object_id: event.primary_object_id,
+        is_delivery_successful: event.is_webhook_notified,
+        created_at: event.created_at,
+        delivery_attempts,
+    })
+}
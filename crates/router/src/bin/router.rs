@@ -1,8 +1,10 @@
 use router::{
-    configs::settings::{CmdLineConf, Settings},
+    configs::settings::{CmdLineConf, DeploymentRole, Settings},
     core::errors::{ApplicationError, ApplicationResult},
     logger,
 };
+#[cfg(feature = "drainer")]
+use router_env::tracing::Instrument;
 
 #[tokio::main]
 async fn main() -> ApplicationResult<()> {
@@ -10,11 +12,12 @@ async fn main() -> ApplicationResult<()> {
     let cmd_line = <CmdLineConf as clap::Parser>::parse();
 
     #[allow(clippy::expect_used)]
-    let conf = Settings::with_config_path(cmd_line.config_path)
+    let conf = Settings::with_config_path(cmd_line.config_path.clone())
         .expect("Unable to construct application configuration");
-    #[allow(clippy::expect_used)]
-    conf.validate()
-        .expect("Failed to validate router configuration");
+    if let Err(error) = conf.validate() {
+        eprintln!("Failed to validate router configuration: {error}");
+        return Err(error);
+    }
 
     #[cfg(feature = "vergen")]
     println!("Starting router (Version: {})", router_env::git_tag!());
@@ -25,7 +28,23 @@ async fn main() -> ApplicationResult<()> {
         [router_env::service_name!(), "actix_server"],
     );
 
-    logger::info!("Application started [{:?}] [{:?}]", conf.server, conf.log);
+    logger::info!(
+        "Application started [{:?}] [{:?}] [{:?}]",
+        conf.server,
+        conf.log,
+        conf.deployment
+    );
+
+    if conf.deployment.is_role_enabled(DeploymentRole::Drainer) {
+        #[cfg(feature = "drainer")]
+        let _drainer_handle =
+            tokio::spawn(router::start_drainer(cmd_line.config_path).in_current_span());
+
+        #[cfg(not(feature = "drainer"))]
+        logger::warn!(
+            "The drainer role is not started as the router is built without the drainer feature"
+        );
+    }
 
     #[allow(clippy::expect_used)]
     let server = Box::pin(router::start_server(conf))
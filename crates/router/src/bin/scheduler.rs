@@ -1,10 +1,8 @@
 #![recursion_limit = "256"]
-use std::{str::FromStr, sync::Arc};
+use std::str::FromStr;
 
 use actix_web::{dev::Server, web, Scope};
 use api_models::health_check::SchedulerHealthCheckResponse;
-use common_utils::ext_traits::{OptionExt, StringExt};
-use diesel_models::process_tracker as storage;
 use error_stack::ResultExt;
 use router::{
     configs::settings::{CmdLineConf, Settings},
@@ -14,16 +12,13 @@ use router::{
     },
     logger, routes,
     services::{self, api},
-    workflows,
+    workflows::start_scheduler,
 };
 use router_env::{
     instrument,
     tracing::{self, Instrument},
 };
-use scheduler::{
-    consumer::workflows::ProcessTrackerWorkflow, errors::ProcessTrackerError,
-    workflows::ProcessTrackerWorkflows, SchedulerAppState,
-};
+use scheduler::errors::ProcessTrackerError;
 use storage_impl::errors::ApplicationError;
 use tokio::sync::{mpsc, oneshot};
 
@@ -93,6 +88,14 @@ async fn main() -> CustomResult<(), ProcessTrackerError> {
 
     logger::debug!(startup_config=?state.conf);
 
+    #[cfg(feature = "olap")]
+    if state.conf.connector_smoke_test.enabled {
+        router::core::verify_connector::add_connector_smoke_test_task(&state)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to schedule connector smoke test"))
+            .ok();
+    }
+
     start_scheduler(&state, scheduler_flow, (tx, rx)).await?;
 
     eprintln!("Scheduler shut down");
@@ -210,123 +213,3 @@ pub async fn deep_health_check_func(
 
     Ok(response)
 }
-
-#[derive(Debug, Copy, Clone)]
-pub struct WorkflowRunner;
-
-#[async_trait::async_trait]
-impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
-    async fn trigger_workflow<'a>(
-        &'a self,
-        state: &'a routes::AppState,
-        process: storage::ProcessTracker,
-    ) -> CustomResult<(), ProcessTrackerError> {
-        let runner = process
-            .runner
-            .clone()
-            .get_required_value("runner")
-            .change_context(ProcessTrackerError::MissingRequiredField)
-            .attach_printable("Missing runner field in process information")?;
-        let runner: storage::ProcessTrackerRunner = runner
-            .parse_enum("ProcessTrackerRunner")
-            .change_context(ProcessTrackerError::UnexpectedFlow)
-            .attach_printable("Failed to parse workflow runner name")?;
-
-        let get_operation = |runner: storage::ProcessTrackerRunner| -> CustomResult<
-            Box<dyn ProcessTrackerWorkflow<routes::AppState>>,
-            ProcessTrackerError,
-        > {
-            match runner {
-                storage::ProcessTrackerRunner::PaymentsSyncWorkflow => {
-                    Ok(Box::new(workflows::payment_sync::PaymentsSyncWorkflow))
-                }
-                storage::ProcessTrackerRunner::RefundWorkflowRouter => {
-                    Ok(Box::new(workflows::refund_router::RefundWorkflowRouter))
-                }
-                storage::ProcessTrackerRunner::DeleteTokenizeDataWorkflow => Ok(Box::new(
-                    workflows::tokenized_data::DeleteTokenizeDataWorkflow,
-                )),
-                storage::ProcessTrackerRunner::ApiKeyExpiryWorkflow => {
-                    #[cfg(feature = "email")]
-                    {
-                        Ok(Box::new(workflows::api_key_expiry::ApiKeyExpiryWorkflow))
-                    }
-
-                    #[cfg(not(feature = "email"))]
-                    {
-                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
-                            .attach_printable(
-                                "Cannot run API key expiry workflow when email feature is disabled",
-                            )
-                    }
-                }
-                storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow => Ok(Box::new(
-                    workflows::outgoing_webhook_retry::OutgoingWebhookRetryWorkflow,
-                )),
-                storage::ProcessTrackerRunner::AttachPayoutAccountWorkflow => {
-                    #[cfg(feature = "payouts")]
-                    {
-                        Ok(Box::new(
-                            workflows::attach_payout_account_workflow::AttachPayoutAccountWorkflow,
-                        ))
-                    }
-                    #[cfg(not(feature = "payouts"))]
-                    {
-                        Err(
-                            error_stack::report!(ProcessTrackerError::UnexpectedFlow),
-                        )
-                        .attach_printable(
-                            "Cannot run Stripe external account workflow when payouts feature is disabled",
-                        )
-                    }
-                }
-            }
-        };
-
-        let operation = get_operation(runner)?;
-
-        let app_state = &state.clone();
-        let output = operation.execute_workflow(app_state, process.clone()).await;
-        match output {
-            Ok(_) => operation.success_handler(app_state, process).await,
-            Err(error) => match operation
-                .error_handler(app_state, process.clone(), error)
-                .await
-            {
-                Ok(_) => (),
-                Err(error) => {
-                    logger::error!(%error, "Failed while handling error");
-                    let status = state
-                        .get_db()
-                        .as_scheduler()
-                        .finish_process_with_business_status(process, "GLOBAL_FAILURE".to_string())
-                        .await;
-                    if let Err(err) = status {
-                        logger::error!(%err, "Failed while performing database operation: GLOBAL_FAILURE");
-                    }
-                }
-            },
-        };
-        Ok(())
-    }
-}
-
-async fn start_scheduler(
-    state: &routes::AppState,
-    scheduler_flow: scheduler::SchedulerFlow,
-    channel: (mpsc::Sender<()>, mpsc::Receiver<()>),
-) -> CustomResult<(), ProcessTrackerError> {
-    let scheduler_settings = state
-        .conf
-        .scheduler
-        .clone()
-        .ok_or(errors::ProcessTrackerError::ConfigurationError)?;
-    scheduler::start_process_tracker(
-        state,
-        scheduler_flow,
-        Arc::new(scheduler_settings),
-        channel,
-        WorkflowRunner {},
-    )
-    .await
-}
@@ -6,6 +6,7 @@ use common_utils::{
     date_time,
     ext_traits::StringExt,
     pii::{IpAddress, SecretSerdeValue, UpiVpaMaskingStrategy},
+    types::ExpiresAt,
 };
 use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
@@ -420,7 +421,7 @@ impl From<api_enums::IntentStatus> for StripePaymentStatus {
             api_enums::IntentStatus::RequiresConfirmation => Self::RequiresConfirmation,
             api_enums::IntentStatus::RequiresCapture
             | api_enums::IntentStatus::PartiallyCapturedAndCapturable => Self::RequiresCapture,
-            api_enums::IntentStatus::Cancelled => Self::Canceled,
+            api_enums::IntentStatus::Cancelled | api_enums::IntentStatus::Expired => Self::Canceled,
         }
     }
 }
@@ -766,6 +767,7 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                     }),
             }),
             update_mandate_id: None,
+            pre_notification_days: None,
         });
         Ok(mandate_data)
     }
@@ -808,7 +810,7 @@ pub enum StripeNextAction {
     },
     QrCodeInformation {
         image_data_url: Option<url::Url>,
-        display_to_timestamp: Option<i64>,
+        display_to_timestamp: Option<ExpiresAt>,
         qr_code_url: Option<url::Url>,
     },
     DisplayVoucherInformation {
@@ -818,6 +820,11 @@ pub enum StripeNextAction {
         display_from_timestamp: i128,
         display_to_timestamp: Option<i128>,
     },
+    VerifyWithMicrodeposits {
+        arrival_date: Option<i64>,
+        hosted_verification_url: Option<url::Url>,
+        microdeposit_type: payments::MicroDepositType,
+    },
 }
 
 pub(crate) fn into_stripe_next_action(
@@ -866,6 +873,13 @@ pub(crate) fn into_stripe_next_action(
                 url: None,
             },
         },
+        payments::NextActionData::VerifyWithMicroDeposits {
+            micro_deposits_details,
+        } => StripeNextAction::VerifyWithMicrodeposits {
+            arrival_date: micro_deposits_details.arrival_date,
+            hosted_verification_url: micro_deposits_details.hosted_verification_url,
+            microdeposit_type: micro_deposits_details.micro_deposit_type,
+        },
     })
 }
 
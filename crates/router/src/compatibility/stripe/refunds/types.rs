@@ -3,7 +3,7 @@ use std::{convert::From, default::Default};
 use common_utils::pii;
 use serde::{Deserialize, Serialize};
 
-use crate::types::api::{admin, refunds};
+use crate::types::api::{admin, enums as api_enums, refunds};
 
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Debug)]
 pub struct StripeCreateRefundRequest {
@@ -48,6 +48,7 @@ impl From<StripeCreateRefundRequest> for refunds::RefundRequest {
             refund_id: req.refund_id,
             amount: req.amount,
             payment_id: req.payment_intent,
+            reason_code: req.reason.as_deref().and_then(get_refund_reason_code),
             reason: req.reason,
             refund_type: Some(refunds::RefundType::Instant),
             metadata: req.metadata,
@@ -63,10 +64,21 @@ impl From<StripeUpdateRefundRequest> for refunds::RefundUpdateRequest {
             refund_id: req.refund_id,
             metadata: req.metadata,
             reason: None,
+            reason_code: None,
         }
     }
 }
 
+/// The reason codes of the reasons Stripe refunds the payments for
+fn get_refund_reason_code(reason: &str) -> Option<api_enums::RefundReasonCode> {
+    match reason {
+        "duplicate" => Some(api_enums::RefundReasonCode::Duplicate),
+        "fraudulent" => Some(api_enums::RefundReasonCode::Fraud),
+        "requested_by_customer" => Some(api_enums::RefundReasonCode::RequestedByCustomer),
+        _ => None,
+    }
+}
+
 impl From<refunds::RefundStatus> for StripeRefundStatus {
     fn from(status: refunds::RefundStatus) -> Self {
         match status {
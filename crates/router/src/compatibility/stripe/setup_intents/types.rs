@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 use api_models::payments;
-use common_utils::{date_time, ext_traits::StringExt, pii as secret};
+use common_utils::{date_time, ext_traits::StringExt, pii as secret, types::ExpiresAt};
 use error_stack::ResultExt;
 use router_env::logger;
 use serde::{Deserialize, Serialize};
@@ -331,7 +331,7 @@ impl From<api_enums::IntentStatus> for StripeSetupStatus {
                 logger::error!("Invalid status change");
                 Self::Canceled
             }
-            api_enums::IntentStatus::Cancelled => Self::Canceled,
+            api_enums::IntentStatus::Cancelled | api_enums::IntentStatus::Expired => Self::Canceled,
         }
     }
 }
@@ -379,7 +379,7 @@ pub enum StripeNextAction {
     },
     QrCodeInformation {
         image_data_url: Option<url::Url>,
-        display_to_timestamp: Option<i64>,
+        display_to_timestamp: Option<ExpiresAt>,
         qr_code_url: Option<url::Url>,
     },
     DisplayVoucherInformation {
@@ -389,6 +389,11 @@ pub enum StripeNextAction {
         display_from_timestamp: i128,
         display_to_timestamp: Option<i128>,
     },
+    VerifyWithMicrodeposits {
+        arrival_date: Option<i64>,
+        hosted_verification_url: Option<url::Url>,
+        microdeposit_type: payments::MicroDepositType,
+    },
 }
 
 pub(crate) fn into_stripe_next_action(
@@ -437,6 +442,13 @@ pub(crate) fn into_stripe_next_action(
                 url: None,
             },
         },
+        payments::NextActionData::VerifyWithMicroDeposits {
+            micro_deposits_details,
+        } => StripeNextAction::VerifyWithMicrodeposits {
+            arrival_date: micro_deposits_details.arrival_date,
+            hosted_verification_url: micro_deposits_details.hosted_verification_url,
+            microdeposit_type: micro_deposits_details.micro_deposit_type,
+        },
     })
 }
 
@@ -81,6 +81,7 @@ pub enum StripeWebhookObject {
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
     Mandate(StripeMandateResponse),
+    PaymentRetry(StripePaymentRetryResponse),
 }
 
 #[derive(Serialize, Debug)]
@@ -101,6 +102,17 @@ pub struct StripeMandateResponse {
     pub payment_method: String,
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripePaymentRetryResponse {
+    pub payment_intent: String,
+    pub customer: Option<String>,
+    pub attempt_count: u8,
+    pub max_attempts: u8,
+    pub next_payment_attempt: Option<i64>,
+    pub last_payment_error_code: Option<String>,
+    pub last_payment_error_message: Option<String>,
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum StripeMandateStatus {
@@ -146,6 +158,22 @@ impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
     }
 }
 
+impl From<api_models::payments::PaymentRetryDetails> for StripePaymentRetryResponse {
+    fn from(res: api_models::payments::PaymentRetryDetails) -> Self {
+        Self {
+            payment_intent: res.payment_id,
+            customer: res.customer_id,
+            attempt_count: res.retry_count,
+            max_attempts: res.max_retries,
+            next_payment_attempt: res
+                .next_retry_at
+                .map(|next_retry_at| next_retry_at.assume_utc().unix_timestamp()),
+            last_payment_error_code: res.error_code,
+            last_payment_error_message: res.error_message,
+        }
+    }
+}
+
 impl From<MandateStatus> for StripeMandateStatus {
     fn from(status: MandateStatus) -> Self {
         match status {
@@ -190,6 +218,10 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeLost => "dispute.lost",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        api_models::enums::EventType::PaymentRetryScheduled => "payment_intent.retry_scheduled",
+        api_models::enums::EventType::PaymentRetriesExhausted => "payment_intent.retries_exhausted",
+        api_models::enums::EventType::PaymentExpired => "payment_intent.expired",
+        api_models::enums::EventType::PaymentAbandoned => "payment_intent.abandoned",
 
         // as per this doc https://stripe.com/docs/api/events/types#event_types-payment_intent.amount_capturable_updated
         api_models::enums::EventType::PaymentAuthorized => {
@@ -237,6 +269,9 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::MandateDetails(mandate) => {
                 Self::Mandate((*mandate).into())
             }
+            api::OutgoingWebhookContent::PaymentRetryDetails(payment_retry) => {
+                Self::PaymentRetry((*payment_retry).into())
+            }
         }
     }
 }
@@ -31,6 +31,97 @@ impl Default for super::settings::CorsSettings {
         }
     }
 }
+impl Default for super::settings::ConnectorResponseCache {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            // 1 day
+            terminal_ttl_in_secs: 86400,
+            non_terminal_ttl_in_secs: 10,
+        }
+    }
+}
+
+impl Default for super::settings::Idempotency {
+    fn default() -> Self {
+        Self {
+            // 1 day
+            ttl_in_secs: 86400,
+            // 5 minutes
+            in_progress_ttl_in_secs: 300,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorWarmup {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            connector_list: HashSet::new(),
+            timeout_in_secs: 5,
+        }
+    }
+}
+
+impl Default for super::settings::DnsCache {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            positive_ttl_in_secs: 60,
+            negative_ttl_in_secs: 5,
+        }
+    }
+}
+
+impl Default for super::settings::RequestPayloadLimits {
+    fn default() -> Self {
+        Self {
+            max_json_depth: 32,
+            max_array_length: 1000,
+            max_object_entries: 1000,
+        }
+    }
+}
+
+impl Default for super::settings::Deployment {
+    fn default() -> Self {
+        Self {
+            roles: HashSet::from([super::settings::DeploymentRole::Api]),
+        }
+    }
+}
+
+impl Default for super::settings::RequestPriority {
+    fn default() -> Self {
+        Self {
+            background_flows: HashSet::from_iter(
+                [
+                    "PaymentsRetrieve",
+                    "PaymentsRetrieveForceSync",
+                    "PaymentsList",
+                    "PaymentsFilters",
+                    "RefundsRetrieve",
+                    "RefundsRetrieveForceSync",
+                    "RefundsList",
+                    "DisputesList",
+                    "PayoutsList",
+                    "GetPaymentMetrics",
+                    "GetRefundsMetrics",
+                    "GetSdkMetrics",
+                    "GetApiEventMetrics",
+                    "GetDisputeMetrics",
+                    "GetSearchResults",
+                    "GetGlobalSearchResults",
+                ]
+                .into_iter()
+                .map(ToString::to_string),
+            ),
+            background_max_concurrency: None,
+            background_max_wait_in_millis: 1000,
+        }
+    }
+}
+
 impl Default for super::settings::Database {
     fn default() -> Self {
         Self {
@@ -123,7 +214,10 @@ impl Default for super::settings::DrainerSettings {
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::KvConfig {
     fn default() -> Self {
-        Self { ttl: 900 }
+        Self {
+            ttl: 900,
+            serialization: Default::default(),
+        }
     }
 }
 
@@ -6350,6 +6444,79 @@ impl Default for super::settings::RequiredFields {
                         enums::PaymentMethodType::AfterpayClearpay,
                         ConnectorFields {
                             fields: HashMap::from([
+                                (
+                                    enums::Connector::Afterpay,
+                                    RequiredFieldFinal {
+                                        mandate: HashMap::new(),
+                                        non_mandate: HashMap::new(),
+                                        common: HashMap::from(
+                                            [
+                                                (
+                                                    "email".to_string(),
+                                                    RequiredFieldInfo {
+                                                        required_field: "email".to_string(),
+                                                        display_name: "email".to_string(),
+                                                        field_type: enums::FieldType::UserEmailAddress,
+                                                        value: None,
+                                                    }
+                                                ),
+                                                (
+                                                    "billing.address.first_name".to_string(),
+                                                    RequiredFieldInfo {
+                                                        required_field: "payment_method_data.billing.address.first_name".to_string(),
+                                                        display_name: "billing_first_name".to_string(),
+                                                        field_type: enums::FieldType::UserBillingName,
+                                                        value: None,
+                                                    }
+                                                ),
+                                                (
+                                                    "billing.address.last_name".to_string(),
+                                                    RequiredFieldInfo {
+                                                        required_field: "payment_method_data.billing.address.last_name".to_string(),
+                                                        display_name: "billing_last_name".to_string(),
+                                                        field_type: enums::FieldType::UserBillingName,
+                                                        value: None,
+                                                    }
+                                                ),
+                                                (
+                                                    "billing.address.line1".to_string(),
+                                                    RequiredFieldInfo {
+                                                        required_field: "payment_method_data.billing.address.line1".to_string(),
+                                                        display_name: "line1".to_string(),
+                                                        field_type: enums::FieldType::UserAddressLine1,
+                                                        value: None,
+                                                    }
+                                                ),
+                                                (
+                                                    "billing.address.zip".to_string(),
+                                                    RequiredFieldInfo {
+                                                        required_field: "payment_method_data.billing.address.zip".to_string(),
+                                                        display_name: "zip".to_string(),
+                                                        field_type: enums::FieldType::UserAddressPincode,
+                                                        value: None,
+                                                    }
+                                                ),
+                                                (
+                                                    "billing.address.country".to_string(),
+                                                    RequiredFieldInfo {
+                                                        required_field: "payment_method_data.billing.address.country".to_string(),
+                                                        display_name: "country".to_string(),
+                                                        field_type: enums::FieldType::UserAddressCountry{
+                                                            options: vec![
+                                                                "AU".to_string(),
+                                                                "NZ".to_string(),
+                                                                "US".to_string(),
+                                                                "CA".to_string(),
+                                                                "GB".to_string(),
+                                                            ]
+                                                        },
+                                                        value: None,
+                                                    }
+                                                ),
+                                            ]
+                                        ),
+                                    }
+                                ),
                                 (
                                     enums::Connector::Stripe,
                                     RequiredFieldFinal {
@@ -6414,6 +6581,93 @@ impl Default for super::settings::RequiredFields {
                         enums::PaymentMethodType::Affirm,
                         ConnectorFields {
                             fields: HashMap::from([
+                                (
+                                    enums::Connector::Affirm,
+                                    RequiredFieldFinal {
+                                        mandate: HashMap::new(),
+                                        non_mandate: HashMap::new(),
+                                        common: HashMap::from(
+                                            [
+                                                (
+                                                    "email".to_string(),
+                                                    RequiredFieldInfo {
+                                                        required_field: "email".to_string(),
+                                                        display_name: "email".to_string(),
+                                                        field_type: enums::FieldType::UserEmailAddress,
+                                                        value: None,
+                                                    }
+                                                ),
+                                                (
+                                                    "billing.address.first_name".to_string(),
+                                                    RequiredFieldInfo {
+                                                        required_field: "payment_method_data.billing.address.first_name".to_string(),
+                                                        display_name: "billing_first_name".to_string(),
+                                                        field_type: enums::FieldType::UserBillingName,
+                                                        value: None,
+                                                    }
+                                                ),
+                                                (
+                                                    "billing.address.last_name".to_string(),
+                                                    RequiredFieldInfo {
+                                                        required_field: "payment_method_data.billing.address.last_name".to_string(),
+                                                        display_name: "billing_last_name".to_string(),
+                                                        field_type: enums::FieldType::UserBillingName,
+                                                        value: None,
+                                                    }
+                                                ),
+                                                (
+                                                    "billing.address.line1".to_string(),
+                                                    RequiredFieldInfo {
+                                                        required_field: "payment_method_data.billing.address.line1".to_string(),
+                                                        display_name: "line1".to_string(),
+                                                        field_type: enums::FieldType::UserAddressLine1,
+                                                        value: None,
+                                                    }
+                                                ),
+                                                (
+                                                    "billing.address.city".to_string(),
+                                                    RequiredFieldInfo {
+                                                        required_field: "payment_method_data.billing.address.city".to_string(),
+                                                        display_name: "city".to_string(),
+                                                        field_type: enums::FieldType::UserAddressCity,
+                                                        value: None,
+                                                    }
+                                                ),
+                                                (
+                                                    "billing.address.state".to_string(),
+                                                    RequiredFieldInfo {
+                                                        required_field: "payment_method_data.billing.address.state".to_string(),
+                                                        display_name: "state".to_string(),
+                                                        field_type: enums::FieldType::UserAddressState,
+                                                        value: None,
+                                                    }
+                                                ),
+                                                (
+                                                    "billing.address.zip".to_string(),
+                                                    RequiredFieldInfo {
+                                                        required_field: "payment_method_data.billing.address.zip".to_string(),
+                                                        display_name: "zip".to_string(),
+                                                        field_type: enums::FieldType::UserAddressPincode,
+                                                        value: None,
+                                                    }
+                                                ),
+                                                (
+                                                    "billing.address.country".to_string(),
+                                                    RequiredFieldInfo {
+                                                        required_field: "payment_method_data.billing.address.country".to_string(),
+                                                        display_name: "country".to_string(),
+                                                        field_type: enums::FieldType::UserAddressCountry{
+                                                            options: vec![
+                                                                "US".to_string(),
+                                                            ]
+                                                        },
+                                                        value: None,
+                                                    }
+                                                ),
+                                            ]
+                                        ),
+                                    }
+                                ),
                                 (
                                     enums::Connector::Stripe,
                                     RequiredFieldFinal {
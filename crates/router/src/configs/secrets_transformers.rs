@@ -197,6 +197,92 @@ impl SecretsHandler for settings::PaymentMethodAuth {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for settings::ClickToPay {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let click_to_pay = value.get_inner();
+
+        let visa_api_key = secret_management_client
+            .get_secret(click_to_pay.visa.api_key.clone())
+            .await?;
+        let mastercard_api_key = secret_management_client
+            .get_secret(click_to_pay.mastercard.api_key.clone())
+            .await?;
+
+        Ok(value.transition_state(|click_to_pay| Self {
+            visa: settings::ClickToPayNetworkConfig {
+                api_key: visa_api_key,
+                ..click_to_pay.visa
+            },
+            mastercard: settings::ClickToPayNetworkConfig {
+                api_key: mastercard_api_key,
+                ..click_to_pay.mastercard
+            },
+            ..click_to_pay
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretsHandler for settings::NetworkTokenizationService {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let network_tokenization_service = value.get_inner();
+
+        let api_key = secret_management_client
+            .get_secret(network_tokenization_service.api_key.clone())
+            .await?;
+
+        Ok(value.transition_state(|network_tokenization_service| Self {
+            api_key,
+            ..network_tokenization_service
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretsHandler for settings::TaxCalculationService {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let tax_calculation_service = value.get_inner();
+
+        let api_key = secret_management_client
+            .get_secret(tax_calculation_service.api_key.clone())
+            .await?;
+
+        Ok(value.transition_state(|tax_calculation_service| Self {
+            api_key,
+            ..tax_calculation_service
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretsHandler for settings::BinLookupService {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let bin_lookup_service = value.get_inner();
+
+        let api_key = secret_management_client
+            .get_secret(bin_lookup_service.api_key.clone())
+            .await?;
+
+        Ok(value.transition_state(|bin_lookup_service| Self {
+            api_key,
+            ..bin_lookup_service
+        }))
+    }
+}
+
 #[async_trait::async_trait]
 impl SecretsHandler for settings::Secrets {
     async fn convert_to_raw_secret(
@@ -302,6 +388,36 @@ pub(crate) async fn fetch_raw_secrets(
     .await
     .expect("Failed to decrypt payment method auth configs");
 
+    #[allow(clippy::expect_used)]
+    let click_to_pay =
+        settings::ClickToPay::convert_to_raw_secret(conf.click_to_pay, secret_management_client)
+            .await
+            .expect("Failed to decrypt click to pay configs");
+
+    #[allow(clippy::expect_used)]
+    let network_tokenization_service = settings::NetworkTokenizationService::convert_to_raw_secret(
+        conf.network_tokenization_service,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt network tokenization service configs");
+
+    #[allow(clippy::expect_used)]
+    let tax_calculation_service = settings::TaxCalculationService::convert_to_raw_secret(
+        conf.tax_calculation_service,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt tax calculation service configs");
+
+    #[allow(clippy::expect_used)]
+    let bin_lookup_service = settings::BinLookupService::convert_to_raw_secret(
+        conf.bin_lookup_service,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt BIN lookup service configs");
+
     Settings {
         server: conf.server,
         master_database,
@@ -319,7 +435,20 @@ pub(crate) async fn fetch_raw_secrets(
         locker: conf.locker,
         connectors: conf.connectors,
         forex_api,
+        amount_localization: conf.amount_localization,
         refund: conf.refund,
+        refund_batching: conf.refund_batching,
+        overcapture: conf.overcapture,
+        connector_rate_limit: conf.connector_rate_limit,
+        request_priority: conf.request_priority,
+        connector_response_cache: conf.connector_response_cache,
+        idempotency: conf.idempotency,
+        raw_card_data: conf.raw_card_data,
+        connector_warmup: conf.connector_warmup,
+        dns_cache: conf.dns_cache,
+        compression: conf.compression,
+        request_payload_limits: conf.request_payload_limits,
+        deployment: conf.deployment,
         eph_key: conf.eph_key,
         scheduler: conf.scheduler,
         jwekey,
@@ -337,6 +466,13 @@ pub(crate) async fn fetch_raw_secrets(
         mandates: conf.mandates,
         network_transaction_id_supported_connectors: conf
             .network_transaction_id_supported_connectors,
+        partial_reversal_supported_connectors: conf.partial_reversal_supported_connectors,
+        network_tokenization_service,
+        network_tokenization_supported_card_networks: conf
+            .network_tokenization_supported_card_networks,
+        network_tokenization_supported_connectors: conf.network_tokenization_supported_connectors,
+        tax_calculation_service,
+        bin_lookup_service,
         required_fields: conf.required_fields,
         delayed_session_response: conf.delayed_session_response,
         webhook_source_verification_call: conf.webhook_source_verification_call,
@@ -345,6 +481,8 @@ pub(crate) async fn fetch_raw_secrets(
         #[cfg(feature = "payouts")]
         payouts: conf.payouts,
         applepay_decrypt_keys,
+        google_pay_decrypt_keys: conf.google_pay_decrypt_keys,
+        click_to_pay,
         multiple_api_version_supported_connectors: conf.multiple_api_version_supported_connectors,
         applepay_merchant_configs,
         lock_settings: conf.lock_settings,
@@ -366,5 +504,11 @@ pub(crate) async fn fetch_raw_secrets(
         cors: conf.cors,
         unmasked_headers: conf.unmasked_headers,
         saved_payment_methods: conf.saved_payment_methods,
+        usage_metering: conf.usage_metering,
+        payment_intent_expiry: conf.payment_intent_expiry,
+        #[cfg(feature = "olap")]
+        connector_smoke_test: conf.connector_smoke_test,
+        #[cfg(feature = "olap")]
+        applepay_domain_renewal: conf.applepay_domain_renewal,
     }
 }
@@ -25,13 +25,13 @@ use redis_interface::RedisSettings;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use rust_decimal::Decimal;
 use scheduler::SchedulerSettings;
-use serde::Deserialize;
-use storage_impl::config::QueueStrategy;
+use serde::{Deserialize, Serialize};
+use storage_impl::{config::QueueStrategy, redis::kv_serialization::KvSerializationConfig};
 
 #[cfg(feature = "olap")]
 use crate::analytics::AnalyticsConfig;
 use crate::{
-    core::errors::{ApplicationError, ApplicationResult},
+    core::errors::{ApplicationError, ApplicationResult, ConfigValidationErrors},
     env::{self, logger, Env},
     events::EventsConfig,
 };
@@ -70,7 +70,20 @@ pub struct Settings<S: SecretState> {
     pub locker: Locker,
     pub connectors: Connectors,
     pub forex_api: SecretStateContainer<ForexApi, S>,
+    pub amount_localization: AmountLocalization,
     pub refund: Refund,
+    pub refund_batching: RefundBatching,
+    pub overcapture: Overcapture,
+    pub connector_rate_limit: ConnectorRateLimit,
+    pub request_priority: RequestPriority,
+    pub connector_response_cache: ConnectorResponseCache,
+    pub idempotency: Idempotency,
+    pub raw_card_data: RawCardData,
+    pub connector_warmup: ConnectorWarmup,
+    pub dns_cache: DnsCache,
+    pub compression: Compression,
+    pub request_payload_limits: RequestPayloadLimits,
+    pub deployment: Deployment,
     pub eph_key: EphemeralConfig,
     pub scheduler: Option<SchedulerSettings>,
     #[cfg(feature = "kv_store")]
@@ -92,6 +105,12 @@ pub struct Settings<S: SecretState> {
     pub cors: CorsSettings,
     pub mandates: Mandates,
     pub network_transaction_id_supported_connectors: NetworkTransactionIdSupportedConnectors,
+    pub partial_reversal_supported_connectors: PartialReversalSupportedConnectors,
+    pub network_tokenization_service: SecretStateContainer<NetworkTokenizationService, S>,
+    pub network_tokenization_supported_card_networks: NetworkTokenizationSupportedCardNetworks,
+    pub network_tokenization_supported_connectors: NetworkTokenizationSupportedConnectors,
+    pub tax_calculation_service: SecretStateContainer<TaxCalculationService, S>,
+    pub bin_lookup_service: SecretStateContainer<BinLookupService, S>,
     pub required_fields: RequiredFields,
     pub delayed_session_response: DelayedSessionConfig,
     pub webhook_source_verification_call: WebhookSourceVerificationCall,
@@ -100,6 +119,8 @@ pub struct Settings<S: SecretState> {
     #[cfg(feature = "payouts")]
     pub payouts: Payouts,
     pub applepay_decrypt_keys: SecretStateContainer<ApplePayDecryptConifg, S>,
+    pub google_pay_decrypt_keys: GooglePayDecryptConfig,
+    pub click_to_pay: SecretStateContainer<ClickToPay, S>,
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
@@ -120,6 +141,12 @@ pub struct Settings<S: SecretState> {
     pub connector_onboarding: SecretStateContainer<ConnectorOnboarding, S>,
     pub unmasked_headers: UnmaskedHeaders,
     pub saved_payment_methods: EligiblePaymentMethods,
+    pub usage_metering: UsageMetering,
+    pub payment_intent_expiry: PaymentIntentExpiry,
+    #[cfg(feature = "olap")]
+    pub connector_smoke_test: ConnectorSmokeTest,
+    #[cfg(feature = "olap")]
+    pub applepay_domain_renewal: ApplepayDomainRenewal,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -134,9 +161,38 @@ pub struct Frm {
     pub enabled: bool,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct UsageMetering {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PaymentIntentExpiry {
+    /// Whether the payments waiting for the customer are expired at the end of their session
+    pub enabled: bool,
+}
+
+#[cfg(feature = "olap")]
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ConnectorSmokeTest {
+    pub enabled: bool,
+    /// Merchant whose enabled sandbox connectors are smoke tested every night
+    pub merchant_id: String,
+}
+
+#[cfg(feature = "olap")]
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ApplepayDomainRenewal {
+    /// Number of days after which the Apple Pay domains registered for a business profile are
+    /// registered again
+    pub interval_in_days: i64,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct KvConfig {
     pub ttl: u32,
+    #[serde(default)]
+    pub serialization: KvSerializationConfig,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -264,6 +320,53 @@ pub struct NetworkTransactionIdSupportedConnectors {
     pub connector_list: HashSet<api_models::enums::Connector>,
 }
 
+/// The connectors which can reverse a part of the authorized amount, releasing the amount which
+/// remains uncaptured after a partial capture
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PartialReversalSupportedConnectors {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<api_models::enums::Connector>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct NetworkTokenizationService {
+    /// Base URL of the API of the token service provider, which provisions network tokens with
+    /// the card networks
+    pub base_url: String,
+    pub api_key: Secret<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct TaxCalculationService {
+    /// Base URL of the API of the tax provider, which calculates the tax on the orders for their
+    /// shipping address
+    pub base_url: String,
+    pub api_key: Secret<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct BinLookupService {
+    /// Path of the CSV file of the BIN database, which is loaded at startup and looked up before
+    /// the BIN provider
+    pub database_path: Option<String>,
+    /// Base URL of the API of the BIN provider, which is called for the BINs missing in the BIN
+    /// database. The provider is not called if this is not set.
+    pub base_url: Option<String>,
+    pub api_key: Secret<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct NetworkTokenizationSupportedCardNetworks {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub card_networks: HashSet<api_models::enums::CardNetwork>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct NetworkTokenizationSupportedConnectors {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<api_models::enums::Connector>,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct SupportedPaymentMethodsForMandate(
     pub HashMap<enums::PaymentMethod, SupportedPaymentMethodTypesForMandate>,
@@ -408,6 +511,196 @@ pub struct Refund {
     pub max_age: i64,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct RefundBatching {
+    /// The connectors with strict rate limits, whose refunds are queued and submitted in batches
+    #[serde(default)]
+    pub connectors: HashMap<String, RefundBatchingConnectorConfig>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RefundBatchingConnectorConfig {
+    /// Maximum number of refunds submitted to the connector in every interval
+    pub batch_size: i64,
+    /// Number of seconds between the batches of refunds submitted to the connector
+    pub interval_in_secs: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct AmountLocalization {
+    /// Markup added to the amounts converted with the forex rates, in basis points of the
+    /// converted amount
+    #[serde(default)]
+    pub markup_in_basis_points: u16,
+    /// Round the converted amounts up to charm prices, which are one lowest denomination below a
+    /// whole amount, such as 12.99
+    #[serde(default)]
+    pub charm_pricing: bool,
+    /// The currencies the amounts are presented in to the shoppers of each country
+    #[serde(default)]
+    pub countries: HashMap<api_models::enums::CountryAlpha2, AmountLocalizationCountryConfig>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AmountLocalizationCountryConfig {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub currencies: HashSet<api_models::enums::Currency>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct Overcapture {
+    /// The connectors supporting capturing more than the authorized amount of the payments which
+    /// requested an overcapture
+    #[serde(default)]
+    pub connectors: HashMap<String, OvercaptureConnectorConfig>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct OvercaptureConnectorConfig {
+    /// Maximum amount captured above the authorized amount, as a percentage of the authorized
+    /// amount
+    pub max_overcapture_percentage: u8,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ConnectorRateLimit {
+    /// The connectors whose calls are rate limited, so that the calls made for one merchant do not
+    /// get the connector credentials throttled for all of them
+    #[serde(default)]
+    pub connectors: HashMap<String, ConnectorRateLimitConfig>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ConnectorRateLimitConfig {
+    /// Number of calls made to the connector every second
+    pub requests_per_second: i64,
+    /// Number of calls allowed every second on top of `requests_per_second`, which can only be
+    /// used by calls other than syncs
+    pub burst: i64,
+    /// Maximum time for which a call other than a sync waits for the rate limit to allow it
+    pub max_queue_time_in_millis: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorResponseCache {
+    /// Whether the responses of the payment and refund syncs made to the connectors are cached, so
+    /// that the repeated force syncs of an attempt or a refund are not all made to the connector
+    pub enabled: bool,
+    /// Time for which the responses with a terminal status are cached
+    pub terminal_ttl_in_secs: i64,
+    /// Time for which the responses with a non terminal status are cached
+    pub non_terminal_ttl_in_secs: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct Idempotency {
+    /// Time for which the response of a request made with an `Idempotency-Key` header is replayed
+    /// to the retries of the request
+    pub ttl_in_secs: i64,
+    /// Time for which a request made with an `Idempotency-Key` header is considered to be in
+    /// progress, after which it can be retried if its response could not be recorded
+    pub in_progress_ttl_in_secs: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RawCardData {
+    /// Whether raw card data is accepted in the payments confirmed from the server of the merchant
+    /// only if the merchant account is marked as PCI compliant
+    pub pci_compliance_required: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorWarmup {
+    /// Whether connections are established to the connectors before the first calls are made to
+    /// them, when the application starts and when a merchant connector account is created
+    pub enabled: bool,
+    /// Connectors to which connections are established when the application starts
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<api_models::enums::Connector>,
+    /// Maximum time for which a connection to a connector being warmed up is waited on
+    pub timeout_in_secs: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DnsCache {
+    /// Whether the addresses that the hostnames of the connectors resolve to are cached
+    pub enabled: bool,
+    /// Time for which the addresses a hostname resolved to are cached
+    pub positive_ttl_in_secs: u64,
+    /// Time for which a failure to resolve a hostname is cached
+    pub negative_ttl_in_secs: u64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Compression {
+    /// Whether the responses are compressed with the gzip, brotli or zstd encoding accepted by
+    /// the client, which reduces the size of the large payment list and analytics responses
+    pub enabled: bool,
+}
+
+/// Limits the JSON request bodies are checked against before they are deserialized, so that the
+/// deeply nested or very long payloads are rejected without being parsed. The size of the bodies
+/// is limited by the request body limit of the server.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RequestPayloadLimits {
+    /// Maximum number of nested arrays and objects
+    pub max_json_depth: usize,
+    /// Maximum number of elements of each array, such as the line items of the order details
+    pub max_array_length: usize,
+    /// Maximum number of entries of each object, such as the entries of the metadata
+    pub max_object_entries: usize,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct Deployment {
+    /// Subsystems started in the process, which allows the API servers, the scheduler and the
+    /// drainer to be scaled independently while being deployed from the same binary
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub roles: HashSet<DeploymentRole>,
+}
+
+impl Deployment {
+    pub fn is_role_enabled(&self, role: DeploymentRole) -> bool {
+        self.roles.contains(&role) || self.roles.contains(&DeploymentRole::AllInOne)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum DeploymentRole {
+    /// Serves the API
+    Api,
+    /// Executes the tasks of the process tracker
+    Consumer,
+    /// Schedules the tasks of the process tracker to be executed by the consumers
+    Producer,
+    /// Drains the queries queued in the redis streams to the database
+    Drainer,
+    /// Runs all of the above in the same process
+    AllInOne,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RequestPriority {
+    /// Flows whose requests can be served after the interactive ones under load
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub background_flows: HashSet<String>,
+    /// Maximum number of requests of the background flows served at the same time, which is not
+    /// limited if it is not set
+    pub background_max_concurrency: Option<usize>,
+    /// Maximum time for which a request of a background flow waits to be served
+    pub background_max_wait_in_millis: u64,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct EphemeralConfig {
@@ -480,83 +773,16 @@ pub struct SupportedConnectors {
     pub wallets: Vec<String>,
 }
 
-#[derive(Debug, Deserialize, Clone, Default, router_derive::ConfigValidate)]
-#[serde(default)]
-pub struct Connectors {
-    pub aci: ConnectorParams,
-    #[cfg(feature = "payouts")]
-    pub adyen: ConnectorParamsWithSecondaryBaseUrl,
-    #[cfg(not(feature = "payouts"))]
-    pub adyen: ConnectorParams,
-    pub airwallex: ConnectorParams,
-    pub applepay: ConnectorParams,
-    pub authorizedotnet: ConnectorParams,
-    pub bambora: ConnectorParams,
-    pub bankofamerica: ConnectorParams,
-    pub billwerk: ConnectorParams,
-    pub bitpay: ConnectorParams,
-    pub bluesnap: ConnectorParamsWithSecondaryBaseUrl,
-    pub boku: ConnectorParams,
-    pub braintree: ConnectorParams,
-    pub cashtocode: ConnectorParams,
-    pub checkout: ConnectorParams,
-    pub coinbase: ConnectorParams,
-    pub cryptopay: ConnectorParams,
-    pub cybersource: ConnectorParams,
-    pub dlocal: ConnectorParams,
-    #[cfg(feature = "dummy_connector")]
-    pub dummyconnector: ConnectorParams,
-    pub ebanx: ConnectorParams,
-    pub fiserv: ConnectorParams,
-    pub forte: ConnectorParams,
-    pub globalpay: ConnectorParams,
-    pub globepay: ConnectorParams,
-    pub gocardless: ConnectorParams,
-    pub helcim: ConnectorParams,
-    pub iatapay: ConnectorParams,
-    pub klarna: ConnectorParams,
-    pub mollie: ConnectorParams,
-    pub multisafepay: ConnectorParams,
-    pub netcetera: ConnectorParams,
-    pub nexinets: ConnectorParams,
-    pub nmi: ConnectorParams,
-    pub noon: ConnectorParamsWithModeType,
-    pub nuvei: ConnectorParams,
-    pub opayo: ConnectorParams,
-    pub opennode: ConnectorParams,
-    pub payeezy: ConnectorParams,
-    pub payme: ConnectorParams,
-    pub paypal: ConnectorParams,
-    pub payu: ConnectorParams,
-    pub placetopay: ConnectorParams,
-    pub powertranz: ConnectorParams,
-    pub prophetpay: ConnectorParams,
-    pub rapyd: ConnectorParams,
-    pub riskified: ConnectorParams,
-    pub shift4: ConnectorParams,
-    pub signifyd: ConnectorParams,
-    pub square: ConnectorParams,
-    pub stax: ConnectorParams,
-    pub stripe: ConnectorParamsWithFileUploadUrl,
-    pub threedsecureio: ConnectorParams,
-    pub trustpay: ConnectorParamsWithMoreUrls,
-    pub tsys: ConnectorParams,
-    pub volt: ConnectorParams,
-    pub wise: ConnectorParams,
-    pub worldline: ConnectorParams,
-    pub worldpay: ConnectorParams,
-    pub zen: ConnectorParams,
-    pub zsl: ConnectorParams,
-}
-
-#[derive(Debug, Deserialize, Clone, Default, router_derive::ConfigValidate)]
+include!(concat!(env!("OUT_DIR"), "/connectors.rs"));
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, router_derive::ConfigValidate)]
 #[serde(default)]
 pub struct ConnectorParams {
     pub base_url: String,
     pub secondary_base_url: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Clone, Default, router_derive::ConfigValidate)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default, router_derive::ConfigValidate)]
 #[serde(default)]
 pub struct ConnectorParamsWithModeType {
     pub base_url: String,
@@ -565,27 +791,35 @@ pub struct ConnectorParamsWithModeType {
     pub key_mode: String,
 }
 
-#[derive(Debug, Deserialize, Clone, Default, router_derive::ConfigValidate)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default, router_derive::ConfigValidate)]
 #[serde(default)]
 pub struct ConnectorParamsWithMoreUrls {
     pub base_url: String,
     pub base_url_bank_redirects: String,
 }
 
-#[derive(Debug, Deserialize, Clone, Default, router_derive::ConfigValidate)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default, router_derive::ConfigValidate)]
 #[serde(default)]
 pub struct ConnectorParamsWithFileUploadUrl {
     pub base_url: String,
     pub base_url_file_upload: String,
 }
 
-#[derive(Debug, Deserialize, Clone, Default, router_derive::ConfigValidate)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default, router_derive::ConfigValidate)]
 #[serde(default)]
 pub struct ConnectorParamsWithSecondaryBaseUrl {
     pub base_url: String,
     pub secondary_base_url: String,
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone, Default, router_derive::ConfigValidate)]
+#[serde(default)]
+pub struct ConnectorParamsWithManagementUrl {
+    pub base_url: String,
+    pub secondary_base_url: String,
+    pub base_url_management: String,
+}
+
 #[cfg(feature = "kv_store")]
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
@@ -602,6 +836,9 @@ pub struct DrainerSettings {
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
+    /// Number of consecutive failed deliveries to a webhook endpoint after which deliveries to
+    /// the endpoint are paused. Endpoints are never paused if not specified.
+    pub endpoint_failure_threshold: Option<u32>,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -643,6 +880,28 @@ pub struct ApplePayDecryptConifg {
     pub apple_pay_merchant_cert_key: Secret<String>,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct GooglePayDecryptConfig {
+    /// JSON of the root signing keys published by Google, which sign the keys used to sign the
+    /// Google Pay payment tokens
+    pub google_pay_root_signing_keys: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ClickToPay {
+    /// Identifier of Hyperswitch as a Click to Pay initiator, assigned by the card networks
+    pub src_initiator_id: String,
+    pub visa: ClickToPayNetworkConfig,
+    pub mastercard: ClickToPayNetworkConfig,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ClickToPayNetworkConfig {
+    /// Base URL of the Click to Pay API of the card network
+    pub base_url: String,
+    pub api_key: Secret<String>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ConnectorRequestReferenceIdConfig {
     pub merchant_ids_send_payment_id_as_connector_request_id: HashSet<String>,
@@ -693,60 +952,91 @@ impl Settings<SecuredSecret> {
         })
     }
 
+    /// Validate the entire configuration, reporting all the invalid configuration values at once.
     pub fn validate(&self) -> ApplicationResult<()> {
-        self.server.validate()?;
-        self.master_database.get_inner().validate()?;
+        let mut errors = ConfigValidationErrors::default();
+
+        errors.check(self.server.validate());
+        errors.check(self.proxy.validate());
+        errors.check(self.master_database.get_inner().validate());
         #[cfg(feature = "olap")]
-        self.replica_database.get_inner().validate()?;
-        self.redis.validate().map_err(|error| {
-            println!("{error}");
-            ApplicationError::InvalidConfigurationValueError("Redis configuration".into())
-        })?;
+        errors.check(self.replica_database.get_inner().validate());
+        errors.check(self.redis.validate().map_err(|error| {
+            ApplicationError::InvalidConfigurationValueError(format!(
+                "Redis configuration: {error}"
+            ))
+        }));
         if self.log.file.enabled {
             if self.log.file.file_name.is_default_or_empty() {
-                return Err(ApplicationError::InvalidConfigurationValueError(
-                    "log file name must not be empty".into(),
-                ));
+                errors.push("log file name must not be empty");
             }
 
             if self.log.file.path.is_default_or_empty() {
-                return Err(ApplicationError::InvalidConfigurationValueError(
-                    "log directory path must not be empty".into(),
-                ));
+                errors.push("log directory path must not be empty");
             }
         }
-        self.secrets.get_inner().validate()?;
-        self.locker.validate()?;
-        self.connectors.validate("connectors")?;
+        errors.check(self.secrets.get_inner().validate());
+        errors.check(self.locker.validate());
+        if !self.locker.mock_locker {
+            errors.check(self.jwekey.get_inner().validate());
+        }
+        errors.check(self.connectors.validate("connectors"));
 
-        self.cors.validate()?;
+        errors.check(self.cors.validate());
 
-        self.scheduler
-            .as_ref()
-            .map(|scheduler_settings| scheduler_settings.validate())
-            .transpose()?;
+        if let Some(scheduler_settings) = self.scheduler.as_ref() {
+            errors.check(scheduler_settings.validate());
+        }
         #[cfg(feature = "kv_store")]
-        self.drainer.validate()?;
-        self.api_keys.get_inner().validate()?;
+        errors.check(self.drainer.validate());
+        errors.check(self.api_keys.get_inner().validate());
 
-        self.file_storage
-            .validate()
-            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
+        errors.check(
+            self.file_storage
+                .validate()
+                .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string())),
+        );
 
-        self.lock_settings.validate()?;
-        self.events.validate()?;
+        errors.check(self.lock_settings.validate());
+        errors.check(self.events.validate());
+        errors.check(self.refund_batching.validate());
+        errors.check(self.overcapture.validate());
+        errors.check(self.amount_localization.validate());
+        errors.check(self.connector_rate_limit.validate());
+        errors.check(self.request_priority.validate());
+        errors.check(self.connector_response_cache.validate());
+        errors.check(self.idempotency.validate());
+        errors.check(self.connector_warmup.validate());
+        errors.check(self.dns_cache.validate());
+        errors.check(self.request_payload_limits.validate());
+        errors.check(self.deployment.validate());
+        errors.check(self.webhooks.validate());
+        if (self.deployment.is_role_enabled(DeploymentRole::Consumer)
+            || self.deployment.is_role_enabled(DeploymentRole::Producer))
+            && self.scheduler.is_none()
+        {
+            errors.push("scheduler must be configured for the consumer and producer roles");
+        }
 
         #[cfg(feature = "olap")]
-        self.opensearch.validate()?;
+        errors.check(self.opensearch.validate());
 
-        self.encryption_management
-            .validate()
-            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
+        #[cfg(feature = "olap")]
+        errors.check(self.applepay_domain_renewal.validate());
+
+        errors.check(
+            self.encryption_management
+                .validate()
+                .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into())),
+        );
+
+        errors.check(
+            self.secrets_management
+                .validate()
+                .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into())),
+        );
 
-        self.secrets_management
-            .validate()
-            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
-        Ok(())
+        errors.into_result()
     }
 }
 
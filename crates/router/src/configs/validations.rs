@@ -43,6 +43,69 @@ impl super::settings::Locker {
                     "basilisk host must not be empty when mock locker is disabled".into(),
                 ))
             },
+        )?;
+
+        when(
+            !self.mock_locker && self.locker_enabled && self.host_rs.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "rust locker host must not be empty when locker is enabled and mock locker is disabled".into(),
+                ))
+            },
+        )?;
+
+        when(
+            !self.mock_locker
+                && [&self.host, &self.host_rs, &self.basilisk_host]
+                    .into_iter()
+                    .any(|host| !host.is_empty() && url::Url::parse(host).is_err()),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "locker hosts must be valid URLs when mock locker is disabled".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::Jwekey {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.vault_encryption_key.peek().is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "vault encryption key must not be empty when mock locker is disabled".into(),
+            ))
+        })?;
+
+        when(self.rust_locker_encryption_key.peek().is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rust locker encryption key must not be empty when mock locker is disabled".into(),
+            ))
+        })?;
+
+        when(self.vault_private_key.peek().is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "vault private key must not be empty when mock locker is disabled".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::Proxy {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            [&self.http_url, &self.https_url]
+                .into_iter()
+                .flatten()
+                .any(|proxy_url| url::Url::parse(proxy_url).is_err()),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "proxy URLs must be valid URLs".into(),
+                ))
+            },
         )
     }
 }
@@ -61,6 +124,12 @@ impl super::settings::Server {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "number of workers must be greater than 0".into(),
             ))
+        })?;
+
+        when(url::Url::parse(&self.base_url).is_err(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "server base URL must be a valid URL".into(),
+            ))
         })
     }
 }
@@ -180,3 +249,217 @@ impl super::settings::LockSettings {
         })
     }
 }
+
+#[cfg(feature = "olap")]
+impl super::settings::ApplepayDomainRenewal {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.interval_in_days <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "applepay domain renewal interval_in_days must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RefundBatching {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.connectors
+            .iter()
+            .try_for_each(|(connector, batching_config)| {
+                when(
+                    batching_config.batch_size <= 0 || batching_config.interval_in_secs <= 0,
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(format!(
+                            "refund batching batch_size and interval_in_secs of {connector} must be greater than 0"
+                        )))
+                    },
+                )
+            })
+    }
+}
+
+impl super::settings::Overcapture {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.connectors
+            .iter()
+            .try_for_each(|(connector, overcapture_config)| {
+                when(
+                    overcapture_config.max_overcapture_percentage == 0
+                        || overcapture_config.max_overcapture_percentage > 100,
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(format!(
+                            "overcapture max_overcapture_percentage of {connector} must be between 1 and 100"
+                        )))
+                    },
+                )
+            })
+    }
+}
+
+impl super::settings::AmountLocalization {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.markup_in_basis_points > 10_000, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "amount localization markup_in_basis_points must not be greater than 10000".into(),
+            ))
+        })?;
+
+        self.countries
+            .iter()
+            .try_for_each(|(country, country_config)| {
+                when(country_config.currencies.is_empty(), || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "amount localization currencies of {country} must not be empty"
+                    )))
+                })
+            })
+    }
+}
+
+impl super::settings::ConnectorRateLimit {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.connectors
+            .iter()
+            .try_for_each(|(connector, rate_limit_config)| {
+                when(
+                    rate_limit_config.requests_per_second <= 0 || rate_limit_config.burst < 0,
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(format!(
+                            "connector rate limit requests_per_second of {connector} must be greater than 0, and its burst must not be negative"
+                        )))
+                    },
+                )
+            })
+    }
+}
+
+impl super::settings::Idempotency {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.ttl_in_secs <= 0 || self.in_progress_ttl_in_secs <= 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "idempotency ttls must be greater than 0".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ConnectorResponseCache {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.enabled && (self.terminal_ttl_in_secs <= 0 || self.non_terminal_ttl_in_secs <= 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector response cache ttls must be greater than 0".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ConnectorWarmup {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.enabled && self.timeout_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector warmup timeout must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::DnsCache {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.enabled && (self.positive_ttl_in_secs == 0 || self.negative_ttl_in_secs == 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "dns cache ttls must be greater than 0".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::RequestPayloadLimits {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.max_json_depth == 0 || self.max_array_length == 0 || self.max_object_entries == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "request payload limits must be greater than 0".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::Deployment {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.roles.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "deployment roles must not be empty".into(),
+            ))
+        })?;
+
+        when(
+            cfg!(not(feature = "drainer"))
+                && self
+                    .roles
+                    .contains(&super::settings::DeploymentRole::Drainer),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "drainer deployment role requires the router to be built with the drainer feature"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::RequestPriority {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.background_max_concurrency == Some(0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "request priority background_max_concurrency must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::WebhooksSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.endpoint_failure_threshold == Some(0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhooks endpoint_failure_threshold must be greater than 0".into(),
+            ))
+        })
+    }
+}
@@ -145,6 +145,8 @@ impl TryFrom<(&domain::WalletData, &types::PaymentsAuthorizeRouterData)> for Pay
             | domain::WalletData::WeChatPayQr(_)
             | domain::WalletData::CashappQr(_)
             | domain::WalletData::SwishQr(_)
+            | domain::WalletData::ClickToPay(_)
+            | domain::WalletData::SkrillRedirect {}
             | domain::WalletData::AliPayQr(_)
             | domain::WalletData::ApplePayRedirect(_)
             | domain::WalletData::GooglePayRedirect(_) => Err(
@@ -468,7 +470,8 @@ impl TryFrom<&AciRouterData<&types::PaymentsAuthorizeRouterData>> for AciPayment
             | domain::PaymentMethodData::CardRedirect(_)
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Aci"),
                 ))?
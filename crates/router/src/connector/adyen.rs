@@ -99,6 +99,7 @@ impl ConnectorValidation for Adyen {
                 PaymentMethodType::Affirm
                 | PaymentMethodType::AfterpayClearpay
                 | PaymentMethodType::ApplePay
+                | PaymentMethodType::ClickToPay
                 | PaymentMethodType::Credit
                 | PaymentMethodType::Debit
                 | PaymentMethodType::GooglePay
@@ -162,6 +163,9 @@ impl ConnectorValidation for Adyen {
                 | PaymentMethodType::Oxxo
                 | PaymentMethodType::PaySafeCard
                 | PaymentMethodType::Pix
+                | PaymentMethodType::PayNow
+                | PaymentMethodType::PromptPay
+                | PaymentMethodType::UpiQr
                 | PaymentMethodType::Swish
                 | PaymentMethodType::TouchNGo
                 | PaymentMethodType::Trustly
@@ -210,6 +214,8 @@ impl ConnectorValidation for Adyen {
                 | PaymentMethodType::RedPagos
                 | PaymentMethodType::CryptoCurrency
                 | PaymentMethodType::SamsungPay
+                | PaymentMethodType::Skrill
+                | PaymentMethodType::Payoneer
                 | PaymentMethodType::Evoucher
                 | PaymentMethodType::Cashapp
                 | PaymentMethodType::UpiCollect => {
@@ -238,6 +244,13 @@ impl ConnectorValidation for Adyen {
         }
         .into())
     }
+    fn validate_split_payments(
+        &self,
+        _split_payments: Option<&api_models::payments::SplitPayments>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        // The splits are sent to Adyen for Platforms along with the payment
+        Ok(())
+    }
     fn is_webhook_source_verification_mandatory(&self) -> bool {
         true
     }
@@ -670,16 +683,33 @@ impl
             types::SyncRequestType::MultipleCaptureSync(_) => true,
             types::SyncRequestType::SinglePaymentSync => false,
         };
-        types::RouterData::try_from((
-            types::ResponseRouterData {
-                response,
-                data: data.clone(),
-                http_code: res.status_code,
-            },
-            data.request.capture_method,
-            is_multiple_capture_sync,
-            data.request.payment_method_type,
-        ))
+        match (
+            response,
+            adyen::get_bank_transfer_next_steps(data.request.connector_meta.clone()),
+        ) {
+            // The amount credited to the virtual IBAN of a bank transfer is reconciled
+            (
+                adyen::AdyenPaymentResponse::WebhookResponse(response),
+                Some(bank_transfer_metadata),
+            ) if !is_multiple_capture_sync => types::RouterData::try_from((
+                types::ResponseRouterData {
+                    response: *response,
+                    data: data.clone(),
+                    http_code: res.status_code,
+                },
+                bank_transfer_metadata,
+            )),
+            (response, _) => types::RouterData::try_from((
+                types::ResponseRouterData {
+                    response,
+                    data: data.clone(),
+                    http_code: res.status_code,
+                },
+                data.request.capture_method,
+                is_multiple_capture_sync,
+                data.request.payment_method_type,
+            )),
+        }
         .change_context(errors::ConnectorError::ResponseHandlingFailed)
     }
 
@@ -2,7 +2,11 @@
 use api_models::payouts::PayoutMethodData;
 use api_models::{enums, payments, webhooks};
 use cards::CardNumber;
-use common_utils::{ext_traits::Encode, pii};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    pii,
+    types::ExpiresAt,
+};
 use error_stack::{report, ResultExt};
 use masking::{ExposeInterface, PeekInterface};
 use reqwest::Url;
@@ -33,6 +37,10 @@ use crate::{types::api::payouts, utils::OptionExt};
 
 type Error = error_stack::Report<errors::ConnectorError>;
 
+const SHOPPER_STATEMENT_MAX_LENGTH: usize = 22;
+const SHOPPER_STATEMENT_SPECIAL_CHARS: [char; 10] =
+    [' ', '.', ',', '\'', '_', '-', '?', '+', '*', '/'];
+
 #[derive(Debug, Serialize)]
 pub struct AdyenRouterData<T> {
     pub amount: i64,
@@ -119,6 +127,9 @@ pub struct AdditionalData {
     #[cfg(feature = "payouts")]
     payout_eligible: Option<PayoutEligibility>,
     funds_availability: Option<String>,
+    /// Payment metadata mapped to the additional data fields by the merchant connector account
+    #[serde(flatten, skip_deserializing)]
+    metadata: std::collections::HashMap<String, String>,
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -139,7 +150,7 @@ pub struct Address {
     street: Secret<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LineItem {
     amount_excluding_tax: Option<i64>,
@@ -179,6 +190,32 @@ pub struct AdyenPaymentRequest<'a> {
     line_items: Option<Vec<LineItem>>,
     channel: Option<Channel>,
     metadata: Option<pii::SecretSerdeValue>,
+    splits: Option<Vec<AdyenSplitData>>,
+    installments: Option<AdyenInstallments>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdyenInstallments {
+    value: u8,
+}
+
+/// Split of a payment of Adyen for Platforms between the balance accounts of the sub-merchants and
+/// the commission of the platform
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenSplitData {
+    amount: Amount,
+    #[serde(rename = "type")]
+    split_type: AdyenSplitType,
+    account: Option<String>,
+    reference: String,
+}
+
+#[derive(Debug, Serialize)]
+pub enum AdyenSplitType {
+    BalanceAccount,
+    Commission,
 }
 
 #[derive(Debug, Serialize)]
@@ -263,7 +300,14 @@ impl ForeignFrom<(bool, AdyenStatus, Option<common_enums::PaymentMethodType>)>
             | AdyenStatus::PresentToShopper => Self::AuthenticationPending,
             AdyenStatus::Error | AdyenStatus::Refused => Self::Failure,
             AdyenStatus::Pending => match pmt {
-                Some(common_enums::PaymentMethodType::Pix) => Self::AuthenticationPending,
+                // The customer is yet to scan the QR code and approve the payment
+                Some(
+                    common_enums::PaymentMethodType::Pix
+                    | common_enums::PaymentMethodType::WeChatPay
+                    | common_enums::PaymentMethodType::PayNow
+                    | common_enums::PaymentMethodType::PromptPay
+                    | common_enums::PaymentMethodType::UpiQr,
+                ) => Self::AuthenticationPending,
                 _ => Self::Pending,
             },
             #[cfg(feature = "payouts")]
@@ -345,6 +389,9 @@ pub struct AdyenThreeDS {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum AdyenPaymentResponse {
+    // The response for bank transfers to a virtual IBAN carries the fields of `Response` as well,
+    // so it has to be tried first
+    BankTransferResponse(Box<BankTransferResponse>),
     Response(Box<Response>),
     PresentToShopper(Box<PresentToShopperResponse>),
     QrCodeResponse(Box<QrCodeResponseResponse>),
@@ -388,6 +435,8 @@ pub struct AdyenWebhookResponse {
     refusal_reason: Option<String>,
     refusal_reason_code: Option<String>,
     event_code: WebhookEventCode,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    event_date: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -420,6 +469,17 @@ pub struct PresentToShopperResponse {
     merchant_reference: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BankTransferResponse {
+    psp_reference: Option<String>,
+    result_code: AdyenStatus,
+    action: AdyenBankTransferAction,
+    refusal_reason: Option<String>,
+    refusal_reason_code: Option<String>,
+    merchant_reference: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QrCodeResponseResponse {
@@ -443,6 +503,21 @@ pub struct AdyenQrCodeAction {
     qr_code_data: String,
 }
 
+/// The details of the virtual IBAN issued for the payment, to which the shopper has to transfer the
+/// total amount quoting the reference
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenBankTransferAction {
+    payment_method_type: PaymentType,
+    #[serde(rename = "type")]
+    type_of_response: ActionType,
+    beneficiary: Secret<String>,
+    iban: Secret<String>,
+    bic: Secret<String>,
+    reference: Secret<String>,
+    total_amount: Amount,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QrCodeAdditionalData {
     #[serde(rename = "pix.expirationDate")]
@@ -490,6 +565,8 @@ pub enum ActionType {
     #[serde(rename = "qrCode")]
     QrCode,
     Voucher,
+    #[serde(rename = "bankTransfer")]
+    BankTransfer,
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
@@ -504,6 +581,7 @@ pub struct Amount {
 pub enum AdyenPaymentMethod<'a> {
     AdyenAffirm(Box<PmdForPaymentType>),
     AdyenCard(Box<AdyenCard>),
+    AdyenClickToPay(Box<AdyenClickToPay>),
     AdyenKlarna(Box<PmdForPaymentType>),
     AdyenPaypal(Box<PmdForPaymentType>),
     #[serde(rename = "afterpaytouch")]
@@ -568,6 +646,8 @@ pub enum AdyenPaymentMethod<'a> {
     Walley,
     #[serde(rename = "wechatpayWeb")]
     WeChatPayWeb,
+    #[serde(rename = "wechatpayQR")]
+    WeChatPayQr,
     AchDirectDebit(Box<AchDirectDebitData>),
     #[serde(rename = "sepadirectdebit")]
     SepaDirectDebit(Box<SepaDirectDebitData>),
@@ -614,6 +694,11 @@ pub enum AdyenPaymentMethod<'a> {
     #[serde(rename = "econtext_stores")]
     PayEasy(Box<JCSVoucherData>),
     Pix(Box<PmdForPaymentType>),
+    #[serde(rename = "bankTransfer_IBAN")]
+    SepaBankTransfer(Box<PmdForPaymentType>),
+    PayNow(Box<PmdForPaymentType>),
+    PromptPay(Box<PmdForPaymentType>),
+    UpiQr(Box<PmdForPaymentType>),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1130,12 +1215,25 @@ pub struct AdyenCard {
     network_payment_reference: Option<Secret<String>>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenClickToPay {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+    src_correlation_id: String,
+    src_digital_card_id: String,
+    src_scheme: CardBrand,
+    src_token_reference: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum CardBrand {
     Visa,
     MC,
     Amex,
+    #[serde(rename = "cartebancaire")]
+    CartesBancaires,
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -1292,6 +1390,8 @@ pub enum PaymentType {
     Walley,
     #[serde(rename = "wechatpayWeb")]
     WeChatPayWeb,
+    #[serde(rename = "wechatpayQR")]
+    WeChatPayQr,
     #[serde(rename = "ach")]
     AchDirectDebit,
     SepaDirectDebit,
@@ -1331,6 +1431,14 @@ pub enum PaymentType {
     #[serde(rename = "econtext_stores")]
     PayEasy,
     Pix,
+    #[serde(rename = "bankTransfer_IBAN")]
+    SepaBankTransfer,
+    #[serde(rename = "paynow")]
+    PayNow,
+    #[serde(rename = "promptpay")]
+    PromptPay,
+    #[serde(rename = "upi_qr")]
+    UpiQr,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1573,6 +1681,9 @@ impl<'a> TryFrom<&AdyenRouterData<&types::PaymentsAuthorizeRouterData>>
                 domain::PaymentMethodData::GiftCard(ref gift_card_data) => {
                     AdyenPaymentRequest::try_from((item, gift_card_data.as_ref()))
                 }
+                domain::PaymentMethodData::RealTimePayment(ref real_time_payment_data) => {
+                    AdyenPaymentRequest::try_from((item, real_time_payment_data.as_ref()))
+                }
                 domain::PaymentMethodData::Crypto(_)
                 | domain::PaymentMethodData::MandatePayment
                 | domain::PaymentMethodData::Reward
@@ -1626,8 +1737,9 @@ impl<'a> TryFrom<&types::PaymentsPreProcessingRouterData> for AdyenBalanceReques
 
 impl From<&types::PaymentsAuthorizeRouterData> for AdyenShopperInteraction {
     fn from(item: &types::PaymentsAuthorizeRouterData) -> Self {
-        match item.request.off_session {
-            Some(true) => Self::ContinuedAuthentication,
+        match (item.request.off_session, item.request.payment_channel) {
+            (Some(true), _) => Self::ContinuedAuthentication,
+            (_, Some(storage_enums::PaymentChannel::Moto)) => Self::Moto,
             _ => Self::Ecommerce,
         }
     }
@@ -1680,20 +1792,42 @@ fn get_browser_info(
     }
 }
 
-fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<AdditionalData> {
-    let (authorisation_type, manual_capture) = match item.request.capture_method {
+/// Adyen shows at most 22 characters of the shopper statements, which may only contain letters,
+/// digits, spaces and some punctuation
+fn get_shopper_statement(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<Option<String>, Error> {
+    utils::validate_statement_descriptor(
+        item.request.statement_descriptor.as_ref(),
+        "statement_descriptor_name",
+        SHOPPER_STATEMENT_MAX_LENGTH,
+        |character| {
+            character.is_ascii_alphanumeric()
+                || SHOPPER_STATEMENT_SPECIAL_CHARS.contains(&character)
+        },
+    )
+}
+
+fn get_additional_data(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<Option<AdditionalData>, Error> {
+    let manual_capture = match item.request.capture_method {
         Some(diesel_models::enums::CaptureMethod::Manual)
-        | Some(diesel_models::enums::CaptureMethod::ManualMultiple) => {
-            (Some(AuthType::PreAuth), Some("true".to_string()))
-        }
-        _ => (None, None),
+        | Some(diesel_models::enums::CaptureMethod::ManualMultiple) => Some("true".to_string()),
+        _ => None,
     };
+    // Adyen captures more than the authorized amount of card payments only when they are
+    // pre-authorised
+    let authorisation_type = (manual_capture.is_some()
+        || (item.request.request_overcapture
+            && item.payment_method == storage_enums::PaymentMethod::Card))
+        .then_some(AuthType::PreAuth);
     let execute_three_d = if matches!(item.auth_type, enums::AuthenticationType::ThreeDs) {
         Some("true".to_string())
     } else {
         None
     };
-    Some(AdditionalData {
+    Ok(Some(AdditionalData {
         authorisation_type,
         manual_capture,
         execute_three_d,
@@ -1701,8 +1835,9 @@ fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<Addi
         recurring_detail_reference: None,
         recurring_shopper_reference: None,
         recurring_processing_model: None,
+        metadata: utils::get_mapped_connector_metadata(item)?,
         ..AdditionalData::default()
-    })
+    }))
 }
 
 fn get_channel_type(pm_type: &Option<storage_enums::PaymentMethodType>) -> Option<Channel> {
@@ -1719,6 +1854,58 @@ fn get_amount_data(item: &AdyenRouterData<&types::PaymentsAuthorizeRouterData>)
     }
 }
 
+/// The card payments paid in installments are split by the issuer into the number of
+/// installments of the plan chosen by the customer
+fn get_installments(
+    item: &AdyenRouterData<&types::PaymentsAuthorizeRouterData>,
+) -> Option<AdyenInstallments> {
+    item.router_data
+        .request
+        .installment_tenure
+        .map(|value| AdyenInstallments { value })
+}
+
+/// Adyen requires the splits to add up to the amount of the payment, so the commission of the
+/// platform is whatever is not transferred to the sub-merchants
+fn get_splits(
+    item: &AdyenRouterData<&types::PaymentsAuthorizeRouterData>,
+) -> Option<Vec<AdyenSplitData>> {
+    let split_payments = item.router_data.request.split_payments.as_ref()?;
+    let currency = item.router_data.request.currency;
+    let reference = &item.router_data.connector_request_reference_id;
+
+    let commission = AdyenSplitData {
+        amount: Amount {
+            currency,
+            value: item.amount - split_payments.get_transferred_amount(),
+        },
+        split_type: AdyenSplitType::Commission,
+        account: None,
+        reference: format!("{reference}_commission"),
+    };
+    let transfers = split_payments
+        .transfers
+        .iter()
+        .enumerate()
+        .map(|(index, transfer)| AdyenSplitData {
+            amount: Amount {
+                currency,
+                value: transfer.amount,
+            },
+            split_type: AdyenSplitType::BalanceAccount,
+            account: Some(transfer.account_id.clone()),
+            reference: transfer
+                .reference
+                .clone()
+                .unwrap_or_else(|| format!("{reference}_{index}")),
+        });
+    Some(
+        transfers
+            .chain((commission.amount.value > 0).then_some(commission))
+            .collect(),
+    )
+}
+
 fn get_address_info(
     address: Option<&api_models::payments::Address>,
 ) -> Option<Result<Address, error_stack::Report<errors::ConnectorError>>> {
@@ -1949,10 +2136,43 @@ impl<'a> TryFrom<&domain::GiftCardData> for AdyenPaymentMethod<'a> {
     }
 }
 
-impl<'a> TryFrom<(&domain::Card, Option<Secret<String>>)> for AdyenPaymentMethod<'a> {
+impl<'a> TryFrom<&domain::RealTimePaymentData> for AdyenPaymentMethod<'a> {
+    type Error = Error;
+    fn try_from(real_time_payment_data: &domain::RealTimePaymentData) -> Result<Self, Self::Error> {
+        match real_time_payment_data {
+            domain::RealTimePaymentData::PayNow {} => {
+                Ok(AdyenPaymentMethod::PayNow(Box::new(PmdForPaymentType {
+                    payment_type: PaymentType::PayNow,
+                })))
+            }
+            domain::RealTimePaymentData::PromptPay {} => {
+                Ok(AdyenPaymentMethod::PromptPay(Box::new(PmdForPaymentType {
+                    payment_type: PaymentType::PromptPay,
+                })))
+            }
+            domain::RealTimePaymentData::UpiQr {} => {
+                Ok(AdyenPaymentMethod::UpiQr(Box::new(PmdForPaymentType {
+                    payment_type: PaymentType::UpiQr,
+                })))
+            }
+        }
+    }
+}
+
+impl<'a>
+    TryFrom<(
+        &domain::Card,
+        Option<Secret<String>>,
+        Option<enums::CardNetwork>,
+    )> for AdyenPaymentMethod<'a>
+{
     type Error = Error;
     fn try_from(
-        (card, card_holder_name): (&domain::Card, Option<Secret<String>>),
+        (card, card_holder_name, preferred_card_network): (
+            &domain::Card,
+            Option<Secret<String>>,
+            Option<enums::CardNetwork>,
+        ),
     ) -> Result<Self, Self::Error> {
         let adyen_card = AdyenCard {
             payment_type: PaymentType::Scheme,
@@ -1961,7 +2181,11 @@ impl<'a> TryFrom<(&domain::Card, Option<Secret<String>>)> for AdyenPaymentMethod
             expiry_year: card.get_expiry_year_4_digit(),
             cvc: Some(card.card_cvc.clone()),
             holder_name: card_holder_name,
-            brand: None,
+            // The brand selects the network co-badged cards are authorized on, and is left for
+            // Adyen to choose if Adyen is not known to accept the network
+            brand: preferred_card_network
+                .as_ref()
+                .and_then(|card_network| CardBrand::try_from(card_network).ok()),
             network_payment_reference: None,
         };
         Ok(AdyenPaymentMethod::AdyenCard(Box::new(adyen_card)))
@@ -2021,6 +2245,19 @@ impl TryFrom<&utils::CardIssuer> for CardBrand {
     }
 }
 
+impl TryFrom<&enums::CardNetwork> for CardBrand {
+    type Error = Error;
+    fn try_from(card_network: &enums::CardNetwork) -> Result<Self, Self::Error> {
+        match card_network {
+            enums::CardNetwork::AmericanExpress => Ok(Self::Amex),
+            enums::CardNetwork::Mastercard => Ok(Self::MC),
+            enums::CardNetwork::Visa => Ok(Self::Visa),
+            enums::CardNetwork::CartesBancaires => Ok(Self::CartesBancaires),
+            _ => Err(errors::ConnectorError::NotImplemented("CardBrand".to_string()).into()),
+        }
+    }
+}
+
 impl<'a> TryFrom<(&domain::WalletData, &types::PaymentsAuthorizeRouterData)>
     for AdyenPaymentMethod<'a>
 {
@@ -2098,10 +2335,11 @@ impl<'a> TryFrom<(&domain::WalletData, &types::PaymentsAuthorizeRouterData)>
                 Ok(AdyenPaymentMethod::MobilePay(Box::new(data)))
             }
             domain::WalletData::WeChatPayRedirect(_) => Ok(AdyenPaymentMethod::WeChatPayWeb),
+            domain::WalletData::WeChatPayQr(_) => Ok(AdyenPaymentMethod::WeChatPayQr),
             domain::WalletData::SamsungPay(samsung_data) => {
                 let data = SamsungPayPmData {
                     payment_type: PaymentType::Samsungpay,
-                    samsung_pay_token: samsung_data.token.to_owned(),
+                    samsung_pay_token: samsung_data.payment_credential.token_data.data.to_owned(),
                 };
                 Ok(AdyenPaymentMethod::SamsungPay(Box::new(data)))
             }
@@ -2109,13 +2347,25 @@ impl<'a> TryFrom<(&domain::WalletData, &types::PaymentsAuthorizeRouterData)>
             domain::WalletData::VippsRedirect { .. } => Ok(AdyenPaymentMethod::Vipps),
             domain::WalletData::DanaRedirect { .. } => Ok(AdyenPaymentMethod::Dana),
             domain::WalletData::SwishQr(_) => Ok(AdyenPaymentMethod::Swish),
+            domain::WalletData::ClickToPay(data) => {
+                let click_to_pay_data = AdyenClickToPay {
+                    payment_type: PaymentType::Scheme,
+                    src_correlation_id: data.src_correlation_id.clone(),
+                    src_digital_card_id: data.src_digital_card_id.clone(),
+                    src_scheme: CardBrand::try_from(&data.card_network)?,
+                    src_token_reference: data.src_token_reference.clone(),
+                };
+                Ok(AdyenPaymentMethod::AdyenClickToPay(Box::new(
+                    click_to_pay_data,
+                )))
+            }
             domain::WalletData::AliPayQr(_)
             | domain::WalletData::ApplePayRedirect(_)
             | domain::WalletData::ApplePayThirdPartySdk(_)
             | domain::WalletData::GooglePayRedirect(_)
             | domain::WalletData::GooglePayThirdPartySdk(_)
             | domain::WalletData::PaypalSdk(_)
-            | domain::WalletData::WeChatPayQr(_)
+            | domain::WalletData::SkrillRedirect {}
             | domain::WalletData::CashappQr(_) => Err(errors::ConnectorError::NotImplemented(
                 utils::get_unimplemented_payment_method_error_message("Adyen"),
             )
@@ -2480,8 +2730,12 @@ impl<'a> TryFrom<&domain::BankTransferData> for AdyenPaymentMethod<'a> {
                     payment_type: PaymentType::Pix,
                 })))
             }
+            domain::BankTransferData::SepaBankTransfer { .. } => Ok(
+                AdyenPaymentMethod::SepaBankTransfer(Box::new(PmdForPaymentType {
+                    payment_type: PaymentType::SepaBankTransfer,
+                })),
+            ),
             domain::BankTransferData::AchBankTransfer { .. }
-            | domain::BankTransferData::SepaBankTransfer { .. }
             | domain::BankTransferData::BacsBankTransfer { .. }
             | domain::BankTransferData::MultibancoBankTransfer { .. }
             | domain::BankTransferData::LocalBankTransfer { .. }
@@ -2543,7 +2797,7 @@ impl<'a>
         let (recurring_processing_model, store_payment_method, shopper_reference) =
             get_recurring_processing_model(item.router_data)?;
         let browser_info = get_browser_info(item.router_data)?;
-        let additional_data = get_additional_data(item.router_data);
+        let additional_data = get_additional_data(item.router_data)?;
         let return_url = item.router_data.request.get_return_url()?;
         let payment_method_type = item
             .router_data
@@ -2593,7 +2847,8 @@ impl<'a>
                     | domain::PaymentMethodData::Upi(_)
                     | domain::PaymentMethodData::Voucher(_)
                     | domain::PaymentMethodData::GiftCard(_)
-                    | domain::PaymentMethodData::CardToken(_) => {
+                    | domain::PaymentMethodData::CardToken(_)
+                    | domain::PaymentMethodData::RealTimePayment(_) => {
                         Err(errors::ConnectorError::NotSupported {
                             message: "Network tokenization for payment method".to_string(),
                             connector: "Adyen",
@@ -2624,9 +2879,11 @@ impl<'a>
             shopper_reference,
             store_payment_method,
             channel: None,
-            shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            shopper_statement: get_shopper_statement(item.router_data)?,
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            splits: get_splits(item),
+            installments: None,
         })
     }
 }
@@ -2657,10 +2914,14 @@ impl<'a>
         let billing_address =
             get_address_info(item.router_data.get_optional_billing()).transpose()?;
         let country_code = get_country_code(item.router_data.get_optional_billing());
-        let additional_data = get_additional_data(item.router_data);
+        let additional_data = get_additional_data(item.router_data)?;
         let return_url = item.router_data.request.get_return_url()?;
         let card_holder_name = item.router_data.get_optional_billing_full_name();
-        let payment_method = AdyenPaymentMethod::try_from((card_data, card_holder_name))?;
+        let payment_method = AdyenPaymentMethod::try_from((
+            card_data,
+            card_holder_name,
+            item.router_data.request.preferred_card_network,
+        ))?;
         let shopper_email = item.router_data.request.email.clone();
         let shopper_name = get_shopper_name(item.router_data.get_optional_billing());
 
@@ -2686,9 +2947,11 @@ impl<'a>
             shopper_reference,
             store_payment_method,
             channel: None,
-            shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            shopper_statement: get_shopper_statement(item.router_data)?,
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            splits: get_splits(item),
+            installments: get_installments(item),
         })
     }
 }
@@ -2713,7 +2976,7 @@ impl<'a>
         let shopper_interaction = AdyenShopperInteraction::from(item.router_data);
         let recurring_processing_model = get_recurring_processing_model(item.router_data)?.0;
         let browser_info = get_browser_info(item.router_data)?;
-        let additional_data = get_additional_data(item.router_data);
+        let additional_data = get_additional_data(item.router_data)?;
         let return_url = item.router_data.request.get_return_url()?;
         let payment_method = AdyenPaymentMethod::try_from(bank_debit_data)?;
         let country_code = get_country_code(item.router_data.get_optional_billing());
@@ -2739,9 +3002,11 @@ impl<'a>
             shopper_reference: None,
             store_payment_method: None,
             channel: None,
-            shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            shopper_statement: get_shopper_statement(item.router_data)?,
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            splits: get_splits(item),
+            installments: None,
         };
         Ok(request)
     }
@@ -2767,7 +3032,7 @@ impl<'a>
         let shopper_interaction = AdyenShopperInteraction::from(item.router_data);
         let recurring_processing_model = get_recurring_processing_model(item.router_data)?.0;
         let browser_info = get_browser_info(item.router_data)?;
-        let additional_data = get_additional_data(item.router_data);
+        let additional_data = get_additional_data(item.router_data)?;
         let payment_method = AdyenPaymentMethod::try_from((voucher_data, item.router_data))?;
         let return_url = item.router_data.request.get_return_url()?;
         let social_security_number = get_social_security_number(voucher_data);
@@ -2793,9 +3058,11 @@ impl<'a>
             shopper_reference: None,
             store_payment_method: None,
             channel: None,
-            shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            shopper_statement: get_shopper_statement(item.router_data)?,
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            splits: get_splits(item),
+            installments: None,
         };
         Ok(request)
     }
@@ -2821,6 +3088,70 @@ impl<'a>
         let shopper_interaction = AdyenShopperInteraction::from(item.router_data);
         let payment_method = AdyenPaymentMethod::try_from(bank_transfer_data)?;
         let return_url = item.router_data.request.get_return_url()?;
+        // The name and email of the shopper are required to issue a virtual IBAN for the payment
+        let (shopper_name, shopper_email, country_code) = match bank_transfer_data {
+            domain::BankTransferData::SepaBankTransfer {
+                billing_details,
+                country,
+            } => (
+                get_shopper_name(item.router_data.get_optional_billing()),
+                Some(billing_details.email.clone()),
+                Some(*country),
+            ),
+            _ => (None, item.router_data.request.email.clone(), None),
+        };
+        let request = AdyenPaymentRequest {
+            amount,
+            merchant_account: auth_type.merchant_account,
+            payment_method,
+            reference: item.router_data.connector_request_reference_id.to_string(),
+            return_url,
+            browser_info: None,
+            shopper_interaction,
+            recurring_processing_model: None,
+            additional_data: None,
+            shopper_name,
+            shopper_locale: None,
+            shopper_email,
+            social_security_number: None,
+            telephone_number: None,
+            billing_address: None,
+            delivery_address: None,
+            country_code,
+            line_items: None,
+            shopper_reference: None,
+            store_payment_method: None,
+            channel: None,
+            shopper_statement: get_shopper_statement(item.router_data)?,
+            shopper_ip: item.router_data.request.get_ip_address_as_optional(),
+            metadata: item.router_data.request.metadata.clone(),
+            splits: get_splits(item),
+            installments: None,
+        };
+        Ok(request)
+    }
+}
+
+impl<'a>
+    TryFrom<(
+        &AdyenRouterData<&types::PaymentsAuthorizeRouterData>,
+        &domain::RealTimePaymentData,
+    )> for AdyenPaymentRequest<'a>
+{
+    type Error = Error;
+
+    fn try_from(
+        value: (
+            &AdyenRouterData<&types::PaymentsAuthorizeRouterData>,
+            &domain::RealTimePaymentData,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (item, real_time_payment_data) = value;
+        let amount = get_amount_data(item);
+        let auth_type = AdyenAuthType::try_from(&item.router_data.connector_auth_type)?;
+        let shopper_interaction = AdyenShopperInteraction::from(item.router_data);
+        let payment_method = AdyenPaymentMethod::try_from(real_time_payment_data)?;
+        let return_url = item.router_data.request.get_return_url()?;
         let request = AdyenPaymentRequest {
             amount,
             merchant_account: auth_type.merchant_account,
@@ -2843,9 +3174,11 @@ impl<'a>
             shopper_reference: None,
             store_payment_method: None,
             channel: None,
-            shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            shopper_statement: get_shopper_statement(item.router_data)?,
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            splits: get_splits(item),
+            installments: None,
         };
         Ok(request)
     }
@@ -2893,9 +3226,11 @@ impl<'a>
             store_payment_method: None,
             channel: None,
             social_security_number: None,
-            shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            shopper_statement: get_shopper_statement(item.router_data)?,
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            splits: get_splits(item),
+            installments: None,
         };
         Ok(request)
     }
@@ -2921,7 +3256,7 @@ impl<'a>
         let (recurring_processing_model, store_payment_method, shopper_reference) =
             get_recurring_processing_model(item.router_data)?;
         let browser_info = get_browser_info(item.router_data)?;
-        let additional_data = get_additional_data(item.router_data);
+        let additional_data = get_additional_data(item.router_data)?;
         let return_url = item.router_data.request.get_return_url()?;
         let payment_method =
             AdyenPaymentMethod::try_from((bank_redirect_data, item.router_data.test_mode))?;
@@ -2950,9 +3285,11 @@ impl<'a>
             shopper_reference,
             store_payment_method,
             channel: None,
-            shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            shopper_statement: get_shopper_statement(item.router_data)?,
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            splits: get_splits(item),
+            installments: None,
         })
     }
 }
@@ -3014,7 +3351,7 @@ impl<'a>
         let amount = get_amount_data(item);
         let auth_type = AdyenAuthType::try_from(&item.router_data.connector_auth_type)?;
         let browser_info = get_browser_info(item.router_data)?;
-        let additional_data = get_additional_data(item.router_data);
+        let additional_data = get_additional_data(item.router_data)?;
         let payment_method = AdyenPaymentMethod::try_from((wallet_data, item.router_data))?;
         let shopper_interaction = AdyenShopperInteraction::from(item.router_data);
         let channel = get_channel_type(&item.router_data.request.payment_method_type);
@@ -3045,9 +3382,11 @@ impl<'a>
             shopper_reference,
             store_payment_method,
             channel,
-            shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            shopper_statement: get_shopper_statement(item.router_data)?,
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            splits: get_splits(item),
+            installments: None,
         })
     }
 }
@@ -3069,7 +3408,7 @@ impl<'a>
         let amount = get_amount_data(item);
         let auth_type = AdyenAuthType::try_from(&item.router_data.connector_auth_type)?;
         let browser_info = get_browser_info(item.router_data)?;
-        let additional_data = get_additional_data(item.router_data);
+        let additional_data = get_additional_data(item.router_data)?;
         let country_code = get_country_code(item.router_data.get_optional_billing());
         let shopper_interaction = AdyenShopperInteraction::from(item.router_data);
         let shopper_reference = build_shopper_reference(
@@ -3120,9 +3459,11 @@ impl<'a>
             shopper_reference,
             store_payment_method,
             channel: None,
-            shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            shopper_statement: get_shopper_statement(item.router_data)?,
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            splits: get_splits(item),
+            installments: None,
         })
     }
 }
@@ -3178,9 +3519,11 @@ impl<'a>
             store_payment_method: None,
             channel: None,
             social_security_number: None,
-            shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            shopper_statement: get_shopper_statement(item.router_data)?,
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            splits: get_splits(item),
+            installments: None,
         })
     }
 }
@@ -3508,6 +3851,84 @@ pub fn get_present_to_shopper_response(
     Ok((status, error, payments_response_data))
 }
 
+pub fn get_bank_transfer_response(
+    response: BankTransferResponse,
+    status_code: u16,
+) -> errors::CustomResult<
+    (
+        storage_enums::AttemptStatus,
+        Option<types::ErrorResponse>,
+        types::PaymentsResponseData,
+    ),
+    errors::ConnectorError,
+> {
+    let status = match response.result_code {
+        // The payment is pending until the shopper transfers the amount to the virtual IBAN
+        AdyenStatus::Received | AdyenStatus::Pending | AdyenStatus::PresentToShopper => {
+            storage_enums::AttemptStatus::AuthenticationPending
+        }
+        result_code => storage_enums::AttemptStatus::foreign_from((false, result_code, None)),
+    };
+    let error = if response.refusal_reason.is_some() || response.refusal_reason_code.is_some() {
+        Some(types::ErrorResponse {
+            code: response
+                .refusal_reason_code
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .refusal_reason
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: response.refusal_reason.to_owned(),
+            status_code,
+            attempt_status: None,
+            connector_transaction_id: response.psp_reference.clone(),
+        })
+    } else {
+        None
+    };
+
+    let action = response.action;
+    let bank_transfer_next_steps = payments::BankTransferNextStepsData {
+        bank_transfer_instructions: payments::BankTransferInstructions::SepaBankInstructions(
+            Box::new(payments::SepaBankTransferInstructions {
+                // The country of the account is the one the IBAN starts with
+                country: action.iban.peek().chars().take(2).collect(),
+                account_holder_name: action.beneficiary,
+                bic: action.bic,
+                iban: action.iban,
+                reference: Some(action.reference),
+            }),
+        ),
+        receiver: Some(payments::ReceiverDetails {
+            amount_received: 0,
+            amount_charged: None,
+            amount_remaining: Some(action.total_amount.value),
+            amount_overpaid: None,
+        }),
+    };
+    let connector_metadata = Some(bank_transfer_next_steps.encode_to_value())
+        .transpose()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+
+    let payments_response_data = types::PaymentsResponseData::TransactionResponse {
+        resource_id: match response.psp_reference.as_ref() {
+            Some(psp) => types::ResponseId::ConnectorTransactionId(psp.to_string()),
+            None => types::ResponseId::NoResponseId,
+        },
+        redirection_data: None,
+        mandate_reference: None,
+        connector_metadata,
+        network_txn_id: None,
+        connector_response_reference_id: response
+            .merchant_reference
+            .clone()
+            .or(response.psp_reference),
+        incremental_authorization_allowed: None,
+    };
+    Ok((status, error, payments_response_data))
+}
+
 pub fn get_qr_code_response(
     response: QrCodeResponseResponse,
     is_manual_capture: bool,
@@ -3613,7 +4034,7 @@ pub fn get_qr_metadata(
         .additional_data
         .clone()
         .and_then(|additional_data| additional_data.pix_expiration_date)
-        .map(|time| utils::get_timestamp_in_milliseconds(&time));
+        .map(ExpiresAt::from);
 
     if let (Some(image_data_url), Some(qr_code_url)) = (image_data_url.clone(), qr_code_url.clone())
     {
@@ -3712,6 +4133,7 @@ pub fn get_wait_screen_metadata(
         | PaymentType::TouchNGo
         | PaymentType::Walley
         | PaymentType::WeChatPayWeb
+        | PaymentType::WeChatPayQr
         | PaymentType::AchDirectDebit
         | PaymentType::SepaDirectDebit
         | PaymentType::BacsDirectDebit
@@ -3736,7 +4158,11 @@ pub fn get_wait_screen_metadata(
         | PaymentType::FamilyMart
         | PaymentType::Seicomart
         | PaymentType::PayEasy
-        | PaymentType::Pix => Ok(None),
+        | PaymentType::Pix
+        | PaymentType::SepaBankTransfer
+        | PaymentType::PayNow
+        | PaymentType::PromptPay
+        | PaymentType::UpiQr => Ok(None),
     }
 }
 
@@ -3744,10 +4170,7 @@ pub fn get_present_to_shopper_metadata(
     response: &PresentToShopperResponse,
 ) -> errors::CustomResult<Option<serde_json::Value>, errors::ConnectorError> {
     let reference = response.action.reference.clone();
-    let expires_at = response
-        .action
-        .expires_at
-        .map(|time| utils::get_timestamp_in_milliseconds(&time));
+    let expires_at = response.action.expires_at.map(ExpiresAt::from);
 
     match response.action.payment_method_type {
         PaymentType::Alfamart
@@ -3831,6 +4254,7 @@ pub fn get_present_to_shopper_metadata(
         | PaymentType::TouchNGo
         | PaymentType::Walley
         | PaymentType::WeChatPayWeb
+        | PaymentType::WeChatPayQr
         | PaymentType::AchDirectDebit
         | PaymentType::SepaDirectDebit
         | PaymentType::BacsDirectDebit
@@ -3840,7 +4264,11 @@ pub fn get_present_to_shopper_metadata(
         | PaymentType::Swish
         | PaymentType::PaySafeCard
         | PaymentType::SevenEleven
-        | PaymentType::Pix => Ok(None),
+        | PaymentType::Pix
+        | PaymentType::SepaBankTransfer
+        | PaymentType::PayNow
+        | PaymentType::PromptPay
+        | PaymentType::UpiQr => Ok(None),
     }
 }
 
@@ -3863,6 +4291,9 @@ impl<F, Req>
     ) -> Result<Self, Self::Error> {
         let is_manual_capture = utils::is_manual_capture(capture_method);
         let (status, error, payment_response_data) = match item.response {
+            AdyenPaymentResponse::BankTransferResponse(response) => {
+                get_bank_transfer_response(*response, item.http_code)?
+            }
             AdyenPaymentResponse::Response(response) => {
                 get_adyen_response(*response, is_manual_capture, item.http_code, pmt)?
             }
@@ -3894,12 +4325,127 @@ impl<F, Req>
     }
 }
 
+/// The connector metadata of a bank transfer to a virtual IBAN, which holds the details of the
+/// bank transfer shown to the shopper along with the references of the credits reconciled so far
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AdyenBankTransferMetadata {
+    #[serde(flatten)]
+    bank_transfer_next_steps: payments::BankTransferNextStepsData,
+    #[serde(default)]
+    credited_references: Vec<String>,
+}
+
+/// The details of a bank transfer to a virtual IBAN shown to the shopper, if the payment is one,
+/// against which the amount credited to the virtual IBAN is to be reconciled
+pub fn get_bank_transfer_next_steps(
+    connector_meta: Option<serde_json::Value>,
+) -> Option<AdyenBankTransferMetadata> {
+    connector_meta
+        .and_then(|connector_meta| {
+            connector_meta
+                .parse_value::<AdyenBankTransferMetadata>("AdyenBankTransferMetadata")
+                .ok()
+        })
+        .filter(|bank_transfer_metadata| {
+            matches!(
+                bank_transfer_metadata
+                    .bank_transfer_next_steps
+                    .bank_transfer_instructions,
+                payments::BankTransferInstructions::SepaBankInstructions(_)
+            ) && bank_transfer_metadata
+                .bank_transfer_next_steps
+                .receiver
+                .is_some()
+        })
+}
+
+impl
+    TryFrom<(
+        types::PaymentsSyncResponseRouterData<AdyenWebhookResponse>,
+        AdyenBankTransferMetadata,
+    )> for types::PaymentsSyncRouterData
+{
+    type Error = Error;
+    fn try_from(
+        (item, bank_transfer_metadata): (
+            types::PaymentsSyncResponseRouterData<AdyenWebhookResponse>,
+            AdyenBankTransferMetadata,
+        ),
+    ) -> Result<Self, Self::Error> {
+        // The authorisation of a bank transfer is notified with the amount credited to the virtual IBAN
+        let amount_credited = item.response.amount.as_ref().map(|amount| amount.value);
+        // A notification delivered again has the same PSP reference and event date as the
+        // notification of the credit delivered first
+        let credit_reference = match item.response.event_date {
+            Some(event_date) => format!("{}_{event_date}", item.response.transaction_id),
+            None => item.response.transaction_id.clone(),
+        };
+        let AdyenBankTransferMetadata {
+            bank_transfer_next_steps,
+            mut credited_references,
+        } = bank_transfer_metadata;
+        let (status, error, mut payment_response_data) =
+            get_webhook_response(item.response, false, false, item.http_code)?;
+
+        let (status, amount_captured) = match (
+            status,
+            amount_credited,
+            bank_transfer_next_steps.receiver.as_ref(),
+        ) {
+            (storage_enums::AttemptStatus::Charged, Some(amount_credited), Some(receiver)) => {
+                let (status, receiver) = utils::reconcile_bank_transfer_credit(
+                    receiver,
+                    &mut credited_references,
+                    &credit_reference,
+                    amount_credited,
+                );
+                // The amount received is captured when it differs from the amount of the payment,
+                // so that the merchant sees what has been paid, including any overpayment
+                let amount_captured = (status == storage_enums::AttemptStatus::PartialCharged
+                    || receiver.amount_overpaid.is_some())
+                .then_some(receiver.amount_received);
+                let bank_transfer_metadata = AdyenBankTransferMetadata {
+                    bank_transfer_next_steps: payments::BankTransferNextStepsData {
+                        receiver: Some(receiver),
+                        ..bank_transfer_next_steps
+                    },
+                    credited_references,
+                };
+                if let types::PaymentsResponseData::TransactionResponse {
+                    ref mut connector_metadata,
+                    ..
+                } = payment_response_data
+                {
+                    *connector_metadata = Some(
+                        bank_transfer_metadata
+                            .encode_to_value()
+                            .change_context(errors::ConnectorError::ResponseHandlingFailed)?,
+                    );
+                }
+                (status, amount_captured)
+            }
+            (status, _, _) => (status, None),
+        };
+
+        Ok(Self {
+            status,
+            response: error.map_or_else(|| Ok(payment_response_data), Err),
+            amount_captured: amount_captured.or(item.data.amount_captured),
+            ..item.data
+        })
+    }
+}
+
+#[serde_with::skip_serializing_none]
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdyenCaptureRequest {
     merchant_account: Secret<String>,
     amount: Amount,
     reference: String,
+    /// The items captured, which are sent when the amount of the order has been adjusted since
+    /// it was authorized, so that the open invoice payment methods capture only these items
+    line_items: Option<Vec<LineItem>>,
 }
 
 impl TryFrom<&AdyenRouterData<&types::PaymentsCaptureRouterData>> for AdyenCaptureRequest {
@@ -3914,6 +4460,26 @@ impl TryFrom<&AdyenRouterData<&types::PaymentsCaptureRouterData>> for AdyenCaptu
             // if single capture request, send connector_request_reference_id(attempt_id)
             None => item.router_data.connector_request_reference_id.clone(),
         };
+        let line_items = item
+            .router_data
+            .request
+            .order_details
+            .as_ref()
+            .filter(|_| item.router_data.request.capture_adjustment_reason.is_some())
+            .map(|order_details| {
+                order_details
+                    .iter()
+                    .enumerate()
+                    .map(|(i, data)| LineItem {
+                        amount_including_tax: Some(data.amount),
+                        amount_excluding_tax: Some(data.amount),
+                        description: Some(data.product_name.clone()),
+                        id: Some(format!("Items #{i}")),
+                        tax_amount: None,
+                        quantity: Some(data.quantity),
+                    })
+                    .collect()
+            });
         Ok(Self {
             merchant_account: auth_type.merchant_account,
             reference,
@@ -3921,6 +4487,7 @@ impl TryFrom<&AdyenRouterData<&types::PaymentsCaptureRouterData>> for AdyenCaptu
                 currency: item.router_data.request.currency,
                 value: item.amount.to_owned(),
             },
+            line_items,
         })
     }
 }
@@ -4013,12 +4580,30 @@ impl<F> TryFrom<&AdyenRouterData<&types::RefundsRouterData<F>>> for AdyenRefundR
                 currency: item.router_data.request.currency,
                 value: item.router_data.request.refund_amount,
             },
-            merchant_refund_reason: item.router_data.request.reason.clone(),
+            merchant_refund_reason: get_merchant_refund_reason(&item.router_data.request),
             reference: item.router_data.request.refund_id.clone(),
         })
     }
 }
 
+/// Adyen accepts the reasons of the refunds as one of its reason codes, which are passed from the
+/// reason codes of the refunds, or from the reasons of the refunds if they have no reason code
+fn get_merchant_refund_reason(request: &types::RefundsData) -> Option<String> {
+    match request.reason_code {
+        Some(reason_code) => Some(
+            match reason_code {
+                enums::RefundReasonCode::RequestedByCustomer => "CUSTOMER REQUEST",
+                enums::RefundReasonCode::Fraud => "FRAUD",
+                enums::RefundReasonCode::Duplicate => "DUPLICATE",
+                enums::RefundReasonCode::ProductIssue => "RETURN",
+                enums::RefundReasonCode::Other => "OTHER",
+            }
+            .to_string(),
+        ),
+        None => request.reason.clone(),
+    }
+}
+
 // Refund Response Transform
 impl<F> TryFrom<types::RefundsResponseRouterData<F, AdyenRefundResponse>>
     for types::RefundsRouterData<F>
@@ -4113,12 +4698,16 @@ pub enum WebhookEventCode {
     SecondChargeback,
     PrearbitrationWon,
     PrearbitrationLost,
+    OfferClosed,
     #[serde(other)]
     Unknown,
 }
 
 pub fn is_transaction_event(event_code: &WebhookEventCode) -> bool {
-    matches!(event_code, WebhookEventCode::Authorisation)
+    matches!(
+        event_code,
+        WebhookEventCode::Authorisation | WebhookEventCode::OfferClosed
+    )
 }
 
 pub fn is_capture_or_cancel_event(event_code: &WebhookEventCode) -> bool {
@@ -4211,6 +4800,15 @@ impl ForeignFrom<(WebhookEventCode, String, Option<DisputeStatus>)>
                 }
             }
             WebhookEventCode::CaptureFailed => Self::PaymentIntentCaptureFailure,
+            // Sent when an asynchronous payment, like a QR code payment, expires without the
+            // customer completing it
+            WebhookEventCode::OfferClosed => {
+                if is_success_scenario(is_success) {
+                    Self::PaymentIntentFailure
+                } else {
+                    Self::EventNotSupported
+                }
+            }
             WebhookEventCode::Unknown => Self::EventNotSupported,
         }
     }
@@ -4285,6 +4883,13 @@ impl From<AdyenNotificationRequestItemWH> for AdyenWebhookResponse {
                     }
                 }
                 WebhookEventCode::CaptureFailed => AdyenWebhookStatus::CaptureFailed,
+                WebhookEventCode::OfferClosed => {
+                    if is_success_scenario(notif.success) {
+                        AdyenWebhookStatus::AuthorisationFailed
+                    } else {
+                        AdyenWebhookStatus::UnexpectedEvent
+                    }
+                }
                 WebhookEventCode::CancelOrRefund
                 | WebhookEventCode::Refund
                 | WebhookEventCode::RefundFailed
@@ -4305,6 +4910,7 @@ impl From<AdyenNotificationRequestItemWH> for AdyenWebhookResponse {
             refusal_reason: None,
             refusal_reason_code: None,
             event_code: notif.event_code,
+            event_date: notif.event_date,
         }
     }
 }
@@ -4680,6 +5286,12 @@ impl<F> TryFrom<&AdyenRouterData<&types::PayoutsRouterData<F>>> for AdyenPayoutC
                             connector: "Adyen",
                         })?
                     }
+                    api_models::payouts::Wallet::Payoneer(_) => {
+                        Err(errors::ConnectorError::NotSupported {
+                            message: "Payoneer Wallet is not supported".to_string(),
+                            connector: "Adyen",
+                        })?
+                    }
                 };
                 let address: &payments::AddressDetails = item.router_data.get_billing_address()?;
                 let payout_wallet = PayoutWalletData {
@@ -0,0 +1,486 @@
+use common_utils::pii::Email;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils::{
+        self, AddressDetailsData, PaymentsAuthorizeRequestData, RefundsRequestData, RouterData,
+    },
+    core::errors,
+    services,
+    types::{self, api, domain, storage::enums},
+};
+
+pub struct AffirmRouterData<T> {
+    pub amount: i64,
+    pub router_data: T,
+}
+
+impl<T>
+    TryFrom<(
+        &types::api::CurrencyUnit,
+        types::storage::enums::Currency,
+        i64,
+        T,
+    )> for AffirmRouterData<T>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (_currency_unit, _currency, amount, item): (
+            &types::api::CurrencyUnit,
+            types::storage::enums::Currency,
+            i64,
+            T,
+        ),
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount,
+            router_data: item,
+        })
+    }
+}
+
+pub struct AffirmAuthType {
+    pub(super) public_api_key: Secret<String>,
+    pub(super) private_api_key: Secret<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for AffirmAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                public_api_key: api_key.to_owned(),
+                private_api_key: key1.to_owned(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmCheckoutRequest {
+    merchant: AffirmMerchant,
+    billing: AffirmContact,
+    shipping: AffirmContact,
+    items: Vec<AffirmItem>,
+    order_id: String,
+    currency: enums::Currency,
+    total: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmMerchant {
+    user_confirmation_url: String,
+    user_cancel_url: String,
+    user_confirmation_url_action: AffirmConfirmationUrlAction,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AffirmConfirmationUrlAction {
+    Get,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmContact {
+    name: AffirmName,
+    address: AffirmAddress,
+    phone_number: Option<Secret<String>>,
+    email: Option<Email>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmName {
+    first: Secret<String>,
+    last: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmAddress {
+    line1: Secret<String>,
+    line2: Option<Secret<String>>,
+    city: String,
+    state: Secret<String>,
+    zipcode: Secret<String>,
+    country: api_models::enums::CountryAlpha2,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmItem {
+    display_name: String,
+    sku: Option<String>,
+    unit_price: i64,
+    qty: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    item_image_url: Option<String>,
+}
+
+impl TryFrom<(&api::Address, Option<Email>)> for AffirmContact {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from((address, email): (&api::Address, Option<Email>)) -> Result<Self, Self::Error> {
+        let details =
+            address
+                .address
+                .as_ref()
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "address",
+                })?;
+        Ok(Self {
+            name: AffirmName {
+                first: details.get_first_name()?.to_owned(),
+                last: details.get_last_name()?.to_owned(),
+            },
+            address: AffirmAddress {
+                line1: details.get_line1()?.to_owned(),
+                line2: details.line2.clone(),
+                city: details.get_city()?.to_owned(),
+                state: details.to_state_code()?,
+                zipcode: details.get_zip()?.to_owned(),
+                country: *details.get_country()?,
+            },
+            phone_number: address
+                .phone
+                .as_ref()
+                .and_then(|phone| phone.number.clone()),
+            email: address.email.clone().or(email),
+        })
+    }
+}
+
+impl TryFrom<&AffirmRouterData<&types::PaymentsAuthorizeRouterData>> for AffirmCheckoutRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &AffirmRouterData<&types::PaymentsAuthorizeRouterData>,
+    ) -> Result<Self, Self::Error> {
+        match item.router_data.request.payment_method_data {
+            domain::PaymentMethodData::PayLater(
+                domain::payments::PayLaterData::AffirmRedirect {},
+            ) => {
+                let request = &item.router_data.request;
+                let items = request
+                    .get_order_details()?
+                    .into_iter()
+                    .map(|order_details| AffirmItem {
+                        display_name: order_details.product_name,
+                        sku: order_details.product_id,
+                        unit_price: order_details.amount,
+                        qty: order_details.quantity,
+                        item_image_url: order_details.product_img_link,
+                    })
+                    .collect();
+                let redirect_url = request.get_complete_authorize_url()?;
+                let billing = AffirmContact::try_from((
+                    item.router_data.get_billing()?,
+                    request.email.clone(),
+                ))?;
+                // Affirm requires a shipping address, which is the billing address for orders that
+                // are not shipped
+                let shipping = match item.router_data.get_optional_shipping() {
+                    Some(shipping) => AffirmContact::try_from((shipping, request.email.clone()))?,
+                    None => AffirmContact::try_from((
+                        item.router_data.get_billing()?,
+                        request.email.clone(),
+                    ))?,
+                };
+
+                Ok(Self {
+                    merchant: AffirmMerchant {
+                        user_confirmation_url: redirect_url.clone(),
+                        user_cancel_url: redirect_url,
+                        user_confirmation_url_action: AffirmConfirmationUrlAction::Get,
+                    },
+                    billing,
+                    shipping,
+                    items,
+                    order_id: item.router_data.connector_request_reference_id.clone(),
+                    currency: request.currency,
+                    total: item.amount,
+                })
+            }
+            _ => Err(errors::ConnectorError::NotImplemented(
+                utils::get_unimplemented_payment_method_error_message("affirm"),
+            )
+            .into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AffirmCheckoutResponse {
+    checkout_id: String,
+    redirect_url: url::Url,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, AffirmCheckoutResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, AffirmCheckoutResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: enums::AttemptStatus::AuthenticationPending,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::NoResponseId,
+                redirection_data: Some(services::RedirectForm::from((
+                    item.response.redirect_url,
+                    services::Method::Get,
+                ))),
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: Some(item.response.checkout_id),
+                incremental_authorization_allowed: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+/// The customer is redirected back with a checkout token once they have confirmed the checkout,
+/// and without one if they cancelled it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AffirmRedirectionResponse {
+    pub checkout_token: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmAuthorizeRequest {
+    transaction_id: Secret<String>,
+    order_id: String,
+}
+
+impl TryFrom<&types::PaymentsCompleteAuthorizeRouterData> for AffirmAuthorizeRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCompleteAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let params = item
+            .request
+            .redirect_response
+            .as_ref()
+            .and_then(|redirect_response| redirect_response.params.as_ref())
+            .ok_or(errors::ConnectorError::MissingConnectorRedirectionPayload {
+                field_name: "request.redirect_response.params",
+            })?;
+        let transaction_id = serde_urlencoded::from_str::<AffirmRedirectionResponse>(params.peek())
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?
+            .checkout_token
+            .ok_or(errors::ConnectorError::MissingConnectorRedirectionPayload {
+                field_name: "checkout_token",
+            })?;
+        Ok(Self {
+            transaction_id,
+            order_id: item.connector_request_reference_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AffirmTransactionStatus {
+    Authorized,
+    AuthExpired,
+    PartiallyCaptured,
+    Captured,
+    Voided,
+    PartiallyRefunded,
+    Refunded,
+}
+
+impl From<AffirmTransactionStatus> for enums::AttemptStatus {
+    fn from(status: AffirmTransactionStatus) -> Self {
+        match status {
+            AffirmTransactionStatus::Authorized => Self::Authorized,
+            AffirmTransactionStatus::AuthExpired => Self::Failure,
+            AffirmTransactionStatus::PartiallyCaptured => Self::PartialCharged,
+            AffirmTransactionStatus::Captured
+            | AffirmTransactionStatus::PartiallyRefunded
+            | AffirmTransactionStatus::Refunded => Self::Charged,
+            AffirmTransactionStatus::Voided => Self::Voided,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AffirmEventType {
+    Auth,
+    Capture,
+    Void,
+    Refund,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The events of a transaction, which Affirm returns for every capture, void and refund
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AffirmEvent {
+    id: String,
+    #[serde(rename = "type")]
+    event_type: AffirmEventType,
+    transaction_id: Option<String>,
+    amount: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AffirmTransactionResponse {
+    id: String,
+    status: AffirmTransactionStatus,
+    #[serde(default)]
+    events: Vec<AffirmEvent>,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, AffirmTransactionResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            AffirmTransactionResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: enums::AttemptStatus::from(item.response.status),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id.clone()),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: Some(item.response.id),
+                incremental_authorization_allowed: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmCaptureRequest {
+    order_id: String,
+    amount: i64,
+}
+
+impl TryFrom<&AffirmRouterData<&types::PaymentsCaptureRouterData>> for AffirmCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &AffirmRouterData<&types::PaymentsCaptureRouterData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            order_id: item.router_data.connector_request_reference_id.clone(),
+            amount: item.amount,
+        })
+    }
+}
+
+impl<F, T> TryFrom<types::ResponseRouterData<F, AffirmEvent, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, AffirmEvent, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let status = match item.response.event_type {
+            AffirmEventType::Capture => enums::AttemptStatus::Charged,
+            AffirmEventType::Void => enums::AttemptStatus::Voided,
+            AffirmEventType::Auth | AffirmEventType::Refund | AffirmEventType::Unknown => {
+                item.data.status
+            }
+        };
+        let resource_id = match item.response.transaction_id {
+            Some(transaction_id) => types::ResponseId::ConnectorTransactionId(transaction_id),
+            None => types::ResponseId::NoResponseId,
+        };
+
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: Some(item.response.id),
+                incremental_authorization_allowed: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffirmRefundRequest {
+    amount: i64,
+}
+
+impl<F> TryFrom<&AffirmRouterData<&types::RefundsRouterData<F>>> for AffirmRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &AffirmRouterData<&types::RefundsRouterData<F>>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.amount,
+        })
+    }
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::Execute, AffirmEvent>>
+    for types::RefundsRouterData<api::Execute>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::Execute, AffirmEvent>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: enums::RefundStatus::Success,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Affirm has no endpoint to look up a refund, the refunds of a transaction are listed in its
+// events instead
+impl TryFrom<types::RefundsResponseRouterData<api::RSync, AffirmTransactionResponse>>
+    for types::RefundsRouterData<api::RSync>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::RSync, AffirmTransactionResponse>,
+    ) -> Result<Self, Self::Error> {
+        let refund_id = item.data.request.get_connector_refund_id()?;
+        let refund_status = if item.response.events.iter().any(|event| {
+            matches!(event.event_type, AffirmEventType::Refund) && event.id == refund_id
+        }) {
+            enums::RefundStatus::Success
+        } else {
+            enums::RefundStatus::Failure
+        };
+
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: refund_id,
+                refund_status,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AffirmErrorResponse {
+    pub status_code: Option<u16>,
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+    pub code: String,
+    pub message: String,
+    pub field: Option<String>,
+}
@@ -0,0 +1,490 @@
+use common_utils::pii::Email;
+use error_stack::ResultExt;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils::{
+        self, AddressDetailsData, PaymentsAuthorizeRequestData, PaymentsCancelRequestData,
+        RefundsRequestData, RouterData,
+    },
+    core::errors,
+    services,
+    types::{self, api, domain, storage::enums},
+};
+
+pub struct AfterpayRouterData<T> {
+    pub amount: String,
+    pub router_data: T,
+}
+
+impl<T>
+    TryFrom<(
+        &types::api::CurrencyUnit,
+        types::storage::enums::Currency,
+        i64,
+        T,
+    )> for AfterpayRouterData<T>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (currency_unit, currency, amount, item): (
+            &types::api::CurrencyUnit,
+            types::storage::enums::Currency,
+            i64,
+            T,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let amount = utils::get_amount_as_string(currency_unit, amount, currency)?;
+        Ok(Self {
+            amount,
+            router_data: item,
+        })
+    }
+}
+
+pub struct AfterpayAuthType {
+    pub(super) merchant_id: Secret<String>,
+    pub(super) secret_key: Secret<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for AfterpayAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                merchant_id: api_key.to_owned(),
+                secret_key: key1.to_owned(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AfterpayMoney {
+    amount: String,
+    currency: enums::Currency,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayCheckoutRequest {
+    amount: AfterpayMoney,
+    consumer: AfterpayConsumer,
+    billing: AfterpayContact,
+    shipping: Option<AfterpayContact>,
+    merchant: AfterpayMerchant,
+    merchant_reference: String,
+    items: Vec<AfterpayItem>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayConsumer {
+    email: Email,
+    given_names: Option<Secret<String>>,
+    surname: Option<Secret<String>>,
+    phone_number: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayContact {
+    name: Secret<String>,
+    line1: Secret<String>,
+    line2: Option<Secret<String>>,
+    area1: Option<String>,
+    region: Option<Secret<String>>,
+    postcode: Secret<String>,
+    country_code: api_models::enums::CountryAlpha2,
+    phone_number: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayMerchant {
+    redirect_confirm_url: String,
+    redirect_cancel_url: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayItem {
+    name: String,
+    sku: Option<String>,
+    quantity: u16,
+    price: AfterpayMoney,
+}
+
+impl TryFrom<&api::Address> for AfterpayContact {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(address: &api::Address) -> Result<Self, Self::Error> {
+        let details =
+            address
+                .address
+                .as_ref()
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "address",
+                })?;
+        Ok(Self {
+            name: details.get_full_name()?,
+            line1: details.get_line1()?.to_owned(),
+            line2: details.line2.clone(),
+            area1: details.city.clone(),
+            region: details.state.clone(),
+            postcode: details.get_zip()?.to_owned(),
+            country_code: *details.get_country()?,
+            phone_number: address
+                .phone
+                .as_ref()
+                .and_then(|phone| phone.number.clone()),
+        })
+    }
+}
+
+impl TryFrom<&AfterpayRouterData<&types::PaymentsAuthorizeRouterData>> for AfterpayCheckoutRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &AfterpayRouterData<&types::PaymentsAuthorizeRouterData>,
+    ) -> Result<Self, Self::Error> {
+        match item.router_data.request.payment_method_data {
+            domain::PaymentMethodData::PayLater(
+                domain::payments::PayLaterData::AfterpayClearpayRedirect { .. },
+            ) => {
+                let request = &item.router_data.request;
+                let currency = request.currency;
+                let items = request
+                    .get_order_details()?
+                    .into_iter()
+                    .map(|order_details| {
+                        Ok(AfterpayItem {
+                            name: order_details.product_name,
+                            sku: order_details.product_id,
+                            quantity: order_details.quantity,
+                            price: AfterpayMoney {
+                                amount: utils::to_currency_base_unit(
+                                    order_details.amount,
+                                    currency,
+                                )?,
+                                currency,
+                            },
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+                let redirect_url = request.get_complete_authorize_url()?;
+
+                Ok(Self {
+                    amount: AfterpayMoney {
+                        amount: item.amount.clone(),
+                        currency,
+                    },
+                    consumer: AfterpayConsumer {
+                        email: request.get_email()?,
+                        given_names: item.router_data.get_optional_billing_first_name(),
+                        surname: item.router_data.get_optional_billing_last_name(),
+                        phone_number: item.router_data.get_optional_billing_phone_number(),
+                    },
+                    billing: AfterpayContact::try_from(item.router_data.get_billing()?)?,
+                    shipping: item
+                        .router_data
+                        .get_optional_shipping()
+                        .map(AfterpayContact::try_from)
+                        .transpose()?,
+                    merchant: AfterpayMerchant {
+                        redirect_confirm_url: redirect_url.clone(),
+                        redirect_cancel_url: redirect_url,
+                    },
+                    merchant_reference: item.router_data.connector_request_reference_id.clone(),
+                    items,
+                })
+            }
+            _ => Err(errors::ConnectorError::NotImplemented(
+                utils::get_unimplemented_payment_method_error_message("afterpay"),
+            )
+            .into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayCheckoutResponse {
+    token: String,
+    redirect_checkout_url: url::Url,
+}
+
+/// The checkout token is needed to confirm the payment once the customer is redirected back, and
+/// to look it up until Afterpay has assigned it an order id
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AfterpayMeta {
+    pub token: String,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, AfterpayCheckoutResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            AfterpayCheckoutResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let connector_metadata = serde_json::to_value(AfterpayMeta {
+            token: item.response.token,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+
+        Ok(Self {
+            status: enums::AttemptStatus::AuthenticationPending,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::NoResponseId,
+                redirection_data: Some(services::RedirectForm::from((
+                    item.response.redirect_checkout_url,
+                    services::Method::Get,
+                ))),
+                mandate_reference: None,
+                connector_metadata: Some(connector_metadata),
+                network_txn_id: None,
+                connector_response_reference_id: None,
+                incremental_authorization_allowed: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AfterpayRedirectionStatus {
+    Success,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayRedirectionResponse {
+    pub order_token: String,
+    pub status: AfterpayRedirectionStatus,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayPaymentRequest {
+    token: String,
+    merchant_reference: String,
+}
+
+impl TryFrom<&types::PaymentsCompleteAuthorizeRouterData> for AfterpayPaymentRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCompleteAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let connector_meta: AfterpayMeta =
+            utils::to_connector_meta(item.request.connector_meta.clone())?;
+        Ok(Self {
+            token: connector_meta.token,
+            merchant_reference: item.connector_request_reference_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AfterpayPaymentStatus {
+    Approved,
+    Declined,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AfterpayPaymentState {
+    AuthApproved,
+    AuthDeclined,
+    PartiallyCaptured,
+    Captured,
+    CaptureDeclined,
+    Voided,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayPaymentResponse {
+    id: String,
+    status: AfterpayPaymentStatus,
+    payment_state: AfterpayPaymentState,
+    #[serde(default)]
+    refunds: Vec<AfterpayRefundResponse>,
+}
+
+impl From<(AfterpayPaymentStatus, AfterpayPaymentState)> for enums::AttemptStatus {
+    fn from((status, payment_state): (AfterpayPaymentStatus, AfterpayPaymentState)) -> Self {
+        match (status, payment_state) {
+            (AfterpayPaymentStatus::Declined, _) => Self::Failure,
+            (AfterpayPaymentStatus::Approved, payment_state) => match payment_state {
+                AfterpayPaymentState::AuthApproved => Self::Authorized,
+                AfterpayPaymentState::AuthDeclined => Self::Failure,
+                AfterpayPaymentState::PartiallyCaptured => Self::PartialCharged,
+                AfterpayPaymentState::Captured => Self::Charged,
+                AfterpayPaymentState::CaptureDeclined => Self::CaptureFailed,
+                AfterpayPaymentState::Voided => Self::Voided,
+            },
+        }
+    }
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, AfterpayPaymentResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, AfterpayPaymentResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: enums::AttemptStatus::from((item.response.status, item.response.payment_state)),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id.clone()),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: Some(item.response.id),
+                incremental_authorization_allowed: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayCaptureRequest {
+    amount: AfterpayMoney,
+    merchant_reference: String,
+}
+
+impl TryFrom<&AfterpayRouterData<&types::PaymentsCaptureRouterData>> for AfterpayCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &AfterpayRouterData<&types::PaymentsCaptureRouterData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: AfterpayMoney {
+                amount: item.amount.clone(),
+                currency: item.router_data.request.currency,
+            },
+            merchant_reference: item.router_data.connector_request_reference_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AfterpayVoidRequest {
+    amount: AfterpayMoney,
+}
+
+impl TryFrom<&types::PaymentsCancelRouterData> for AfterpayVoidRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        let currency = item.request.get_currency()?;
+        Ok(Self {
+            amount: AfterpayMoney {
+                amount: utils::to_currency_base_unit(item.request.get_amount()?, currency)?,
+                currency,
+            },
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayRefundRequest {
+    request_id: String,
+    amount: AfterpayMoney,
+    merchant_reference: String,
+    refund_merchant_reference: String,
+}
+
+impl<F> TryFrom<&AfterpayRouterData<&types::RefundsRouterData<F>>> for AfterpayRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &AfterpayRouterData<&types::RefundsRouterData<F>>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            request_id: item.router_data.request.refund_id.clone(),
+            amount: AfterpayMoney {
+                amount: item.amount.clone(),
+                currency: item.router_data.request.currency,
+            },
+            merchant_reference: item.router_data.connector_request_reference_id.clone(),
+            refund_merchant_reference: item.router_data.request.refund_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayRefundResponse {
+    refund_id: String,
+    refund_merchant_reference: Option<String>,
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::Execute, AfterpayRefundResponse>>
+    for types::RefundsRouterData<api::Execute>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::Execute, AfterpayRefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.refund_id,
+                refund_status: enums::RefundStatus::Success,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Afterpay has no endpoint to look up a refund, the refunds of a payment are listed in the
+// payment instead
+impl TryFrom<types::RefundsResponseRouterData<api::RSync, AfterpayPaymentResponse>>
+    for types::RefundsRouterData<api::RSync>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::RSync, AfterpayPaymentResponse>,
+    ) -> Result<Self, Self::Error> {
+        let refund_id = item.data.request.get_connector_refund_id()?;
+        let refund_status = if item
+            .response
+            .refunds
+            .iter()
+            .any(|refund| refund.refund_id == refund_id)
+        {
+            enums::RefundStatus::Success
+        } else {
+            enums::RefundStatus::Failure
+        };
+
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: refund_id,
+                refund_status,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterpayErrorResponse {
+    pub error_code: String,
+    pub error_id: Option<String>,
+    pub message: String,
+    pub http_status_code: Option<u16>,
+}
@@ -6,7 +6,7 @@ use base64::Engine;
 use common_utils::request::RequestContent;
 use diesel_models::enums;
 use error_stack::{report, ResultExt};
-use masking::{ExposeInterface, PeekInterface};
+use masking::{ExposeInterface, PeekInterface, PlaintextBuffer};
 use ring::{digest, hmac};
 use time::OffsetDateTime;
 use transformers as bankofamerica;
@@ -138,7 +138,8 @@ where
             .chars()
             .skip(base_url.len() - 1)
             .collect();
-        let sha256 = self.generate_digest(boa_req.get_inner_value().expose().as_bytes());
+        let sha256 = self
+            .generate_digest(PlaintextBuffer::new(boa_req.get_inner_value().expose()).as_bytes());
         let signature = self.generate_signature(
             auth,
             host.to_string(),
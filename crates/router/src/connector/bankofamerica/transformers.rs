@@ -8,7 +8,7 @@ use serde_json::Value;
 
 use crate::{
     connector::utils::{
-        self, AddressDetailsData, ApplePayDecrypt, CardData, CardIssuer,
+        self, AddressDetailsData, ApplePayDecrypt, CardData, CardIssuer, GooglePayDecrypt,
         PaymentsAuthorizeRequestData, PaymentsCompleteAuthorizeRequestData,
         PaymentsPreProcessingData, PaymentsSetupMandateRequestData, PaymentsSyncRequestData,
         RecurringMandateData, RouterData,
@@ -22,7 +22,7 @@ use crate::{
         domain,
         storage::enums,
         transformers::ForeignFrom,
-        ApplePayPredecryptData,
+        ApplePayPredecryptData, GooglePayPredecryptData,
     },
     unimplemented_payment_method,
 };
@@ -140,12 +140,14 @@ pub struct BankOfAmericaPaymentInitiator {
 #[serde(rename_all = "camelCase")]
 pub enum BankOfAmericaPaymentInitiatorTypes {
     Customer,
+    Merchant,
 }
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MerchantInitiatedTransaction {
     reason: Option<String>,
+    previous_transaction_id: Option<Secret<String>>,
     //Required for recurring mandates payment
     original_authorized_amount: Option<String>,
 }
@@ -211,11 +213,18 @@ pub struct ApplePayPaymentInformation {
     tokenized_card: TokenizedCard,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayPredecryptPaymentInformation {
+    tokenized_card: GooglePayTokenizedCard,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(untagged)]
 pub enum PaymentInformation {
     Cards(CardPaymentInformation),
     GooglePay(GooglePayPaymentInformation),
+    GooglePayPredecrypt(GooglePayPredecryptPaymentInformation),
     ApplePay(ApplePayPaymentInformation),
     ApplePayToken(ApplePayTokenPaymentInformation),
     MandatePayment(MandatePaymentInformation),
@@ -233,7 +242,8 @@ pub struct Card {
     number: cards::CardNumber,
     expiration_month: Secret<String>,
     expiration_year: Secret<String>,
-    security_code: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    security_code: Option<Secret<String>>,
     #[serde(rename = "type")]
     card_type: Option<String>,
 }
@@ -248,6 +258,16 @@ pub struct TokenizedCard {
     transaction_type: TransactionType,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayTokenizedCard {
+    number: cards::CardNumber,
+    expiration_month: Secret<String>,
+    expiration_year: Secret<String>,
+    cryptogram: Secret<String>,
+    transaction_type: TransactionType,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FluidData {
@@ -316,9 +336,13 @@ impl TryFrom<&types::SetupMandateRouterData> for BankOfAmericaPaymentsRequest {
                 | domain::WalletData::WeChatPayRedirect(_)
                 | domain::WalletData::WeChatPayQr(_)
                 | domain::WalletData::CashappQr(_)
-                | domain::WalletData::SwishQr(_) => Err(errors::ConnectorError::NotImplemented(
-                    utils::get_unimplemented_payment_method_error_message("BankOfAmerica"),
-                ))?,
+                | domain::WalletData::SwishQr(_)
+                | domain::WalletData::ClickToPay(_)
+                | domain::WalletData::SkrillRedirect {} => {
+                    Err(errors::ConnectorError::NotImplemented(
+                        utils::get_unimplemented_payment_method_error_message("BankOfAmerica"),
+                    ))?
+                }
             },
             domain::PaymentMethodData::CardRedirect(_)
             | domain::PaymentMethodData::PayLater(_)
@@ -331,7 +355,8 @@ impl TryFrom<&types::SetupMandateRouterData> for BankOfAmericaPaymentsRequest {
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("BankOfAmerica"),
                 ))?
@@ -404,7 +429,8 @@ impl<F, T>
                     | common_enums::PaymentMethod::Reward
                     | common_enums::PaymentMethod::Upi
                     | common_enums::PaymentMethod::Voucher
-                    | common_enums::PaymentMethod::GiftCard => None,
+                    | common_enums::PaymentMethod::GiftCard
+                    | common_enums::PaymentMethod::RealTimePayment => None,
                 };
 
                 Ok(Self {
@@ -506,6 +532,8 @@ impl From<PaymentSolution> for String {
 pub enum TransactionType {
     #[serde(rename = "1")]
     ApplePay,
+    #[serde(rename = "1")]
+    GooglePay,
 }
 
 impl
@@ -586,21 +614,25 @@ impl
                     })) {
             get_boa_mandate_action_details()
         } else if item.router_data.request.connector_mandate_id().is_some() {
-            let original_amount = item
-                .router_data
-                .get_recurring_mandate_payment_data()?
-                .get_original_payment_amount()?;
-            let original_currency = item
-                .router_data
-                .get_recurring_mandate_payment_data()?
-                .get_original_payment_currency()?;
+            let recurring_mandate_payment_data =
+                item.router_data.get_recurring_mandate_payment_data()?;
+            let original_amount = recurring_mandate_payment_data.get_original_payment_amount()?;
+            let original_currency =
+                recurring_mandate_payment_data.get_original_payment_currency()?;
             (
                 None,
                 None,
                 Some(BankOfAmericaAuthorizationOptions {
-                    initiator: None,
+                    initiator: Some(BankOfAmericaPaymentInitiator {
+                        initiator_type: Some(BankOfAmericaPaymentInitiatorTypes::Merchant),
+                        credential_stored_on_file: None,
+                        stored_credential_used: Some(true),
+                    }),
                     merchant_intitiated_transaction: Some(MerchantInitiatedTransaction {
                         reason: None,
+                        previous_transaction_id: recurring_mandate_payment_data
+                            .network_transaction_id
+                            .map(Secret::new),
                         original_authorized_amount: Some(utils::get_amount_as_string(
                             &types::api::CurrencyUnit::Base,
                             original_amount,
@@ -1033,6 +1065,49 @@ impl
     }
 }
 
+impl
+    TryFrom<(
+        &BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>,
+        Box<GooglePayPredecryptData>,
+        domain::GooglePayWalletData,
+    )> for BankOfAmericaPaymentsRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (item, google_pay_decrypted_data, google_pay_wallet_data): (
+            &BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>,
+            Box<GooglePayPredecryptData>,
+            domain::GooglePayWalletData,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let email = item.router_data.request.get_email()?;
+        let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
+        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let processing_information = ProcessingInformation::try_from((
+            item,
+            Some(PaymentSolution::GooglePay),
+            Some(google_pay_wallet_data.info.card_network.clone()),
+        ))?;
+        let client_reference_information = ClientReferenceInformation::from(item);
+        let payment_information = PaymentInformation::try_from(&google_pay_decrypted_data)?;
+        let merchant_defined_information =
+            item.router_data.request.metadata.clone().map(|metadata| {
+                Vec::<MerchantDefinedInformation>::foreign_from(metadata.peek().to_owned())
+            });
+
+        Ok(Self {
+            processing_information,
+            payment_information,
+            order_information,
+            client_reference_information,
+            merchant_defined_information,
+            consumer_authentication_information: Some(BankOfAmericaConsumerAuthInformation::from(
+                &google_pay_wallet_data,
+            )),
+        })
+    }
+}
+
 impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>>
     for BankOfAmericaPaymentsRequest
 {
@@ -1059,6 +1134,24 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>>
                                             "Bank Of America"
                                         ))?
                                     }
+                                    types::PaymentMethodToken::GooglePayDecrypt(_) => {
+                                        Err(unimplemented_payment_method!(
+                                            "Google Pay",
+                                            "Bank Of America"
+                                        ))?
+                                    }
+                                    types::PaymentMethodToken::SamsungPayDecrypt(_) => {
+                                        Err(unimplemented_payment_method!(
+                                            "Samsung Pay",
+                                            "Bank Of America"
+                                        ))?
+                                    }
+                                    types::PaymentMethodToken::NetworkToken(_) => {
+                                        Err(unimplemented_payment_method!(
+                                            "Network Token",
+                                            "Bank Of America"
+                                        ))?
+                                    }
                                 },
                                 None => {
                                     let email = item.router_data.request.get_email()?;
@@ -1112,7 +1205,12 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>>
                             }
                         }
                         domain::WalletData::GooglePay(google_pay_data) => {
-                            Self::try_from((item, google_pay_data))
+                            match item.router_data.payment_method_token.clone() {
+                                Some(types::PaymentMethodToken::GooglePayDecrypt(decrypt_data)) => {
+                                    Self::try_from((item, decrypt_data, google_pay_data))
+                                }
+                                _ => Self::try_from((item, google_pay_data)),
+                            }
                         }
 
                         domain::WalletData::AliPayQr(_)
@@ -1138,7 +1236,9 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>>
                         | domain::WalletData::WeChatPayRedirect(_)
                         | domain::WalletData::WeChatPayQr(_)
                         | domain::WalletData::CashappQr(_)
-                        | domain::WalletData::SwishQr(_) => {
+                        | domain::WalletData::SwishQr(_)
+                        | domain::WalletData::ClickToPay(_)
+                        | domain::WalletData::SkrillRedirect {} => {
                             Err(errors::ConnectorError::NotImplemented(
                                 utils::get_unimplemented_payment_method_error_message(
                                     "Bank of America",
@@ -1168,7 +1268,8 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>>
                     | domain::PaymentMethodData::Upi(_)
                     | domain::PaymentMethodData::Voucher(_)
                     | domain::PaymentMethodData::GiftCard(_)
-                    | domain::PaymentMethodData::CardToken(_) => {
+                    | domain::PaymentMethodData::CardToken(_)
+                    | domain::PaymentMethodData::RealTimePayment(_) => {
                         Err(errors::ConnectorError::NotImplemented(
                             utils::get_unimplemented_payment_method_error_message(
                                 "Bank of America",
@@ -1218,7 +1319,8 @@ impl TryFrom<(&domain::PaymentMethodData, String)> for BankOfAmericaAuthSetupReq
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Bank Of America"),
                 )
@@ -1805,7 +1907,8 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsPreProcessingRouterData>>
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("BankOfAmerica"),
                 )
@@ -1907,7 +2010,8 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsCompleteAuthorizeRouterDat
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("BankOfAmerica"),
                 )
@@ -2129,7 +2233,8 @@ impl<F>
                     | common_enums::PaymentMethod::Reward
                     | common_enums::PaymentMethod::Upi
                     | common_enums::PaymentMethod::Voucher
-                    | common_enums::PaymentMethod::GiftCard => None,
+                    | common_enums::PaymentMethod::GiftCard
+                    | common_enums::PaymentMethod::RealTimePayment => None,
                 };
 
                 Ok(Self {
@@ -2202,7 +2307,8 @@ impl<F>
                     | common_enums::PaymentMethod::Reward
                     | common_enums::PaymentMethod::Upi
                     | common_enums::PaymentMethod::Voucher
-                    | common_enums::PaymentMethod::GiftCard => None,
+                    | common_enums::PaymentMethod::GiftCard
+                    | common_enums::PaymentMethod::RealTimePayment => None,
                 };
 
                 Ok(Self {
@@ -2423,7 +2529,8 @@ impl<F>
                     | common_enums::PaymentMethod::Reward
                     | common_enums::PaymentMethod::Upi
                     | common_enums::PaymentMethod::Voucher
-                    | common_enums::PaymentMethod::GiftCard => None,
+                    | common_enums::PaymentMethod::GiftCard
+                    | common_enums::PaymentMethod::RealTimePayment => None,
                 };
 
                 let risk_info: Option<ClientRiskInformation> = None;
@@ -2837,6 +2944,16 @@ impl TryFrom<(&types::SetupMandateRouterData, domain::ApplePayWalletData)>
                     "Manual",
                     "Bank Of America"
                 ))?,
+                types::PaymentMethodToken::GooglePayDecrypt(_) => Err(
+                    unimplemented_payment_method!("Google Pay", "Bank Of America"),
+                )?,
+                types::PaymentMethodToken::SamsungPayDecrypt(_) => Err(
+                    unimplemented_payment_method!("Samsung Pay", "Bank Of America"),
+                )?,
+                types::PaymentMethodToken::NetworkToken(_) => Err(unimplemented_payment_method!(
+                    "Network Token",
+                    "Bank Of America"
+                ))?,
             },
             None => PaymentInformation::from(&apple_pay_data),
         };
@@ -2885,9 +3002,22 @@ impl TryFrom<(&types::SetupMandateRouterData, domain::GooglePayWalletData)>
         let merchant_defined_information = item.request.metadata.clone().map(|metadata| {
             Vec::<MerchantDefinedInformation>::foreign_from(metadata.peek().to_owned())
         });
-        let payment_information = PaymentInformation::from(&google_pay_data);
-        let processing_information =
-            ProcessingInformation::try_from((Some(PaymentSolution::GooglePay), None))?;
+        let (payment_information, processing_information, consumer_authentication_information) =
+            match item.payment_method_token.clone() {
+                Some(types::PaymentMethodToken::GooglePayDecrypt(decrypt_data)) => (
+                    PaymentInformation::try_from(&decrypt_data)?,
+                    ProcessingInformation::try_from((
+                        Some(PaymentSolution::GooglePay),
+                        Some(google_pay_data.info.card_network.clone()),
+                    ))?,
+                    Some(BankOfAmericaConsumerAuthInformation::from(&google_pay_data)),
+                ),
+                _ => (
+                    PaymentInformation::from(&google_pay_data),
+                    ProcessingInformation::try_from((Some(PaymentSolution::GooglePay), None))?,
+                    None,
+                ),
+            };
 
         Ok(Self {
             processing_information,
@@ -2895,7 +3025,7 @@ impl TryFrom<(&types::SetupMandateRouterData, domain::GooglePayWalletData)>
             order_information,
             client_reference_information,
             merchant_defined_information,
-            consumer_authentication_information: None,
+            consumer_authentication_information,
         })
     }
 }
@@ -2953,7 +3083,7 @@ impl TryFrom<&domain::Card> for PaymentInformation {
                 number: ccard.card_number.clone(),
                 expiration_month: ccard.card_exp_month.clone(),
                 expiration_year: ccard.card_exp_year.clone(),
-                security_code: ccard.card_cvc.clone(),
+                security_code: ccard.get_optional_card_cvc(),
                 card_type,
             },
         }))
@@ -2982,6 +3112,43 @@ impl TryFrom<&Box<ApplePayPredecryptData>> for PaymentInformation {
     }
 }
 
+impl TryFrom<&Box<GooglePayPredecryptData>> for PaymentInformation {
+    type Error = error_stack::Report<errors::ConnectorError>;
+
+    fn try_from(google_pay_data: &Box<GooglePayPredecryptData>) -> Result<Self, Self::Error> {
+        Ok(Self::GooglePayPredecrypt(
+            GooglePayPredecryptPaymentInformation {
+                tokenized_card: GooglePayTokenizedCard {
+                    number: google_pay_data.payment_method_details.pan.clone(),
+                    expiration_month: google_pay_data.get_expiry_month(),
+                    expiration_year: google_pay_data.get_four_digit_expiry_year(),
+                    cryptogram: google_pay_data.get_cryptogram()?,
+                    transaction_type: TransactionType::GooglePay,
+                },
+            },
+        ))
+    }
+}
+
+impl From<&domain::GooglePayWalletData> for BankOfAmericaConsumerAuthInformation {
+    fn from(google_pay_data: &domain::GooglePayWalletData) -> Self {
+        let ucaf_collection_indicator =
+            match google_pay_data.info.card_network.to_lowercase().as_str() {
+                "mastercard" => Some("2".to_string()),
+                _ => None,
+            };
+
+        Self {
+            ucaf_collection_indicator,
+            cavv: None,
+            ucaf_authentication_data: None,
+            xid: None,
+            directory_server_transaction_id: None,
+            specification_version: None,
+        }
+    }
+}
+
 impl From<&domain::ApplePayWalletData> for PaymentInformation {
     fn from(apple_pay_data: &domain::ApplePayWalletData) -> Self {
         Self::ApplePayToken(ApplePayTokenPaymentInformation {
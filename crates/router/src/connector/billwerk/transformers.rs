@@ -111,7 +111,8 @@ impl TryFrom<&types::TokenizationRouterData> for BillwerkTokenRequest {
             | domain::payments::PaymentMethodData::Upi(_)
             | domain::payments::PaymentMethodData::Voucher(_)
             | domain::payments::PaymentMethodData::GiftCard(_)
-            | domain::payments::PaymentMethodData::CardToken(_) => {
+            | domain::payments::PaymentMethodData::CardToken(_)
+            | domain::payments::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("billwerk"),
                 )
@@ -140,7 +140,8 @@ impl TryFrom<&BraintreeRouterData<&types::PaymentsAuthorizeRouterData>>
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("braintree"),
                 )
@@ -171,7 +172,8 @@ impl TryFrom<&BraintreeRouterData<&types::PaymentsCompleteAuthorizeRouterData>>
             | api_models::enums::PaymentMethod::Reward
             | api_models::enums::PaymentMethod::Upi
             | api_models::enums::PaymentMethod::Voucher
-            | api_models::enums::PaymentMethod::GiftCard => {
+            | api_models::enums::PaymentMethod::GiftCard
+            | api_models::enums::PaymentMethod::RealTimePayment => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message(
                         "complete authorize flow",
@@ -890,7 +892,8 @@ impl TryFrom<&types::TokenizationRouterData> for BraintreeTokenRequest {
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("braintree"),
                 )
@@ -1340,6 +1343,15 @@ impl
                         types::PaymentMethodToken::ApplePayDecrypt(_) => Err(
                             unimplemented_payment_method!("Apple Pay", "Simplified", "Braintree"),
                         )?,
+                        types::PaymentMethodToken::GooglePayDecrypt(_) => {
+                            Err(unimplemented_payment_method!("Google Pay", "Braintree"))?
+                        }
+                        types::PaymentMethodToken::SamsungPayDecrypt(_) => {
+                            Err(unimplemented_payment_method!("Samsung Pay", "Braintree"))?
+                        }
+                        types::PaymentMethodToken::NetworkToken(_) => {
+                            Err(unimplemented_payment_method!("Network Token", "Braintree"))?
+                        }
                     },
                     transaction: TransactionBody {
                         amount: item.amount.to_owned(),
@@ -1423,6 +1435,15 @@ fn get_braintree_redirect_form(
                 "Simplified",
                 "Braintree"
             ))?,
+            types::PaymentMethodToken::GooglePayDecrypt(_) => {
+                Err(unimplemented_payment_method!("Google Pay", "Braintree"))?
+            }
+            types::PaymentMethodToken::SamsungPayDecrypt(_) => {
+                Err(unimplemented_payment_method!("Samsung Pay", "Braintree"))?
+            }
+            types::PaymentMethodToken::NetworkToken(_) => {
+                Err(unimplemented_payment_method!("Network Token", "Braintree"))?
+            }
         },
         bin: match card_details {
             domain::PaymentMethodData::Card(card_details) => {
@@ -1440,7 +1461,8 @@ fn get_braintree_redirect_form(
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => Err(
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => Err(
                 errors::ConnectorError::NotImplemented("given payment method".to_owned()),
             )?,
         },
@@ -5,7 +5,7 @@ use base64::Engine;
 use common_utils::request::RequestContent;
 use diesel_models::enums;
 use error_stack::ResultExt;
-use masking::{PeekInterface, Secret};
+use masking::{PeekInterface, Secret, StrongEq};
 use transformers as cashtocode;
 
 use super::utils as connector_utils;
@@ -389,7 +389,7 @@ impl api::IncomingWebhook for Cashtocode {
         let signature_auth = String::from_utf8(signature.to_vec())
             .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
             .attach_printable("Could not convert secret to UTF-8")?;
-        Ok(signature_auth == secret_auth)
+        Ok(signature_auth.strong_eq(&secret_auth))
     }
 
     fn get_webhook_object_reference_id(
@@ -7,8 +7,8 @@ use url::Url;
 
 use crate::{
     connector::utils::{
-        self, to_connector_meta, ApplePayDecrypt, PaymentsCaptureRequestData, RouterData,
-        WalletData,
+        self, to_connector_meta, ApplePayDecrypt, GooglePayDecrypt, PaymentsCaptureRequestData,
+        RouterData, SamsungPayDecrypt, WalletData,
     },
     consts,
     core::errors,
@@ -124,6 +124,8 @@ impl TryFrom<&types::TokenizationRouterData> for TokenRequest {
                 | domain::WalletData::WeChatPayRedirect(_)
                 | domain::WalletData::CashappQr(_)
                 | domain::WalletData::SwishQr(_)
+                | domain::WalletData::ClickToPay(_)
+                | domain::WalletData::SkrillRedirect {}
                 | domain::WalletData::WeChatPayQr(_) => {
                     Err(errors::ConnectorError::NotImplemented(
                         utils::get_unimplemented_payment_method_error_message("checkout"),
@@ -143,7 +145,8 @@ impl TryFrom<&types::TokenizationRouterData> for TokenRequest {
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::CardRedirect(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("checkout"),
                 )
@@ -198,6 +201,9 @@ pub enum PaymentSource {
     Card(CardSource),
     Wallets(WalletSource),
     ApplePayPredecrypt(Box<ApplePayPredecrypt>),
+    GooglePayPredecrypt(Box<GooglePayPredecrypt>),
+    SamsungPayPredecrypt(Box<SamsungPayPredecrypt>),
+    NetworkToken(Box<NetworkTokenSource>),
 }
 
 #[derive(Debug, Serialize)]
@@ -212,6 +218,42 @@ pub struct ApplePayPredecrypt {
     cryptogram: Secret<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct GooglePayPredecrypt {
+    token: cards::CardNumber,
+    #[serde(rename = "type")]
+    decrypt_type: String,
+    token_type: String,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    eci: Option<String>,
+    cryptogram: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SamsungPayPredecrypt {
+    token: cards::CardNumber,
+    #[serde(rename = "type")]
+    decrypt_type: String,
+    token_type: String,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    eci: Option<String>,
+    cryptogram: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NetworkTokenSource {
+    token: cards::CardNumber,
+    #[serde(rename = "type")]
+    source_type: String,
+    token_type: String,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    eci: Option<String>,
+    cryptogram: Secret<String>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum CheckoutSourceTypes {
@@ -244,8 +286,21 @@ pub struct PaymentsRequest {
     pub capture: bool,
     pub reference: String,
     pub metadata: Option<Secret<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_descriptor: Option<BillingDescriptor>,
 }
 
+/// The description of the payment on the statements of the customer, which Checkout expects
+/// along with the city of the merchant
+#[derive(Debug, Serialize)]
+pub struct BillingDescriptor {
+    pub name: String,
+    pub city: String,
+}
+
+const BILLING_DESCRIPTOR_NAME_MAX_LENGTH: usize = 25;
+const BILLING_DESCRIPTOR_CITY_MAX_LENGTH: usize = 13;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CheckoutMeta {
     pub psync_flow: CheckoutPaymentIntent,
@@ -293,25 +348,61 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
     ) -> Result<Self, Self::Error> {
         let source_var = match item.router_data.request.payment_method_data.clone() {
             domain::PaymentMethodData::Card(ccard) => {
-                let a = PaymentSource::Card(CardSource {
-                    source_type: CheckoutSourceTypes::Card,
-                    number: ccard.card_number.clone(),
-                    expiry_month: ccard.card_exp_month.clone(),
-                    expiry_year: ccard.card_exp_year.clone(),
-                    cvv: ccard.card_cvc,
-                });
-                Ok(a)
+                match item.router_data.payment_method_token.clone() {
+                    Some(types::PaymentMethodToken::NetworkToken(network_token_data)) => {
+                        Ok(PaymentSource::NetworkToken(Box::new(NetworkTokenSource {
+                            token_type: get_network_token_type(network_token_data.card_network)?,
+                            token: network_token_data.token_number,
+                            source_type: "network_token".to_string(),
+                            expiry_month: network_token_data.token_exp_month,
+                            expiry_year: network_token_data.token_exp_year,
+                            eci: network_token_data.eci,
+                            cryptogram: network_token_data.cryptogram,
+                        })))
+                    }
+                    _ => {
+                        let a = PaymentSource::Card(CardSource {
+                            source_type: CheckoutSourceTypes::Card,
+                            number: ccard.card_number.clone(),
+                            expiry_month: ccard.card_exp_month.clone(),
+                            expiry_year: ccard.card_exp_year.clone(),
+                            cvv: ccard.card_cvc,
+                        });
+                        Ok(a)
+                    }
+                }
             }
             domain::PaymentMethodData::Wallet(wallet_data) => match wallet_data {
-                domain::WalletData::GooglePay(_) => Ok(PaymentSource::Wallets(WalletSource {
-                    source_type: CheckoutSourceTypes::Token,
-                    token: match item.router_data.get_payment_method_token()? {
-                        types::PaymentMethodToken::Token(token) => token.into(),
+                domain::WalletData::GooglePay(_) => {
+                    match item.router_data.get_payment_method_token()? {
+                        types::PaymentMethodToken::Token(token) => {
+                            Ok(PaymentSource::Wallets(WalletSource {
+                                source_type: CheckoutSourceTypes::Token,
+                                token: token.into(),
+                            }))
+                        }
                         types::PaymentMethodToken::ApplePayDecrypt(_) => Err(
                             unimplemented_payment_method!("Apple Pay", "Simplified", "Checkout"),
                         )?,
-                    },
-                })),
+                        types::PaymentMethodToken::GooglePayDecrypt(decrypt_data) => Ok(
+                            PaymentSource::GooglePayPredecrypt(Box::new(GooglePayPredecrypt {
+                                expiry_month: decrypt_data.get_expiry_month(),
+                                expiry_year: decrypt_data.get_four_digit_expiry_year(),
+                                cryptogram: decrypt_data.get_cryptogram()?,
+                                decrypt_type: "network_token".to_string(),
+                                token_type: "googlepay".to_string(),
+                                eci: decrypt_data.payment_method_details.eci_indicator,
+                                token: decrypt_data.payment_method_details.pan,
+                            })),
+                        ),
+                        types::PaymentMethodToken::SamsungPayDecrypt(_) => {
+                            Err(unimplemented_payment_method!("Samsung Pay", "Checkout"))?
+                        }
+                        types::PaymentMethodToken::NetworkToken(_) => {
+                            Err(unimplemented_payment_method!("Network Token", "Checkout"))?
+                        }
+                    }
+                }
                 domain::WalletData::ApplePay(_) => {
                     let payment_method_token = item.router_data.get_payment_method_token()?;
                     match payment_method_token {
@@ -336,6 +427,39 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
                                 },
                             )))
                         }
+                        types::PaymentMethodToken::GooglePayDecrypt(_) => {
+                            Err(unimplemented_payment_method!("Google Pay", "Checkout"))?
+                        }
+                        types::PaymentMethodToken::SamsungPayDecrypt(_) => {
+                            Err(unimplemented_payment_method!("Samsung Pay", "Checkout"))?
+                        }
+                        types::PaymentMethodToken::NetworkToken(_) => {
+                            Err(unimplemented_payment_method!("Network Token", "Checkout"))?
+                        }
+                    }
+                }
+                domain::WalletData::SamsungPay(samsung_pay_data) => {
+                    match item.router_data.payment_method_token.clone() {
+                        Some(types::PaymentMethodToken::SamsungPayDecrypt(decrypt_data)) => Ok(
+                            PaymentSource::SamsungPayPredecrypt(Box::new(SamsungPayPredecrypt {
+                                expiry_month: decrypt_data.get_expiry_month()?,
+                                expiry_year: decrypt_data.get_four_digit_expiry_year()?,
+                                decrypt_type: "network_token".to_string(),
+                                token_type: get_samsung_pay_token_type(
+                                    samsung_pay_data.payment_credential.card_brand,
+                                )?,
+                                eci: decrypt_data.eci_indicator,
+                                cryptogram: decrypt_data.cryptogram,
+                                token: decrypt_data.token_pan,
+                            })),
+                        ),
+                        // Checkout does not accept encrypted Samsung Pay tokens, they have to be
+                        // decrypted using the private key in the connector metadata
+                        _ => Err(unimplemented_payment_method!(
+                            "Samsung Pay",
+                            "Encrypted token",
+                            "Checkout"
+                        ))?,
                     }
                 }
                 domain::WalletData::AliPayQr(_)
@@ -354,13 +478,14 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
                 | domain::WalletData::MobilePayRedirect(_)
                 | domain::WalletData::PaypalRedirect(_)
                 | domain::WalletData::PaypalSdk(_)
-                | domain::WalletData::SamsungPay(_)
                 | domain::WalletData::TwintRedirect {}
                 | domain::WalletData::VippsRedirect {}
                 | domain::WalletData::TouchNGoRedirect(_)
                 | domain::WalletData::WeChatPayRedirect(_)
                 | domain::WalletData::CashappQr(_)
                 | domain::WalletData::SwishQr(_)
+                | domain::WalletData::ClickToPay(_)
+                | domain::WalletData::SkrillRedirect {}
                 | domain::WalletData::WeChatPayQr(_) => {
                     Err(errors::ConnectorError::NotImplemented(
                         utils::get_unimplemented_payment_method_error_message("checkout"),
@@ -379,7 +504,8 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::CardRedirect(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("checkout"),
                 ))
@@ -388,23 +514,29 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
 
         let authentication_data = item.router_data.request.authentication_data.as_ref();
 
-        let three_ds = match item.router_data.auth_type {
-            enums::AuthenticationType::ThreeDs => CheckoutThreeDS {
+        // The payments authenticated by the merchant carry the result of the authentication, even
+        // though no 3DS is requested from the connector
+        let three_ds = if item.router_data.auth_type == enums::AuthenticationType::ThreeDs
+            || authentication_data.is_some()
+        {
+            CheckoutThreeDS {
                 enabled: true,
                 force_3ds: true,
                 eci: authentication_data.and_then(|auth| auth.eci.clone()),
                 cryptogram: authentication_data.map(|auth| auth.cavv.clone()),
-                xid: authentication_data.map(|auth| auth.threeds_server_transaction_id.clone()),
+                xid: authentication_data
+                    .and_then(|auth| auth.threeds_server_transaction_id.clone()),
                 version: authentication_data.map(|auth| auth.message_version.clone()),
-            },
-            enums::AuthenticationType::NoThreeDs => CheckoutThreeDS {
+            }
+        } else {
+            CheckoutThreeDS {
                 enabled: false,
                 force_3ds: false,
                 eci: None,
                 cryptogram: None,
                 xid: None,
                 version: None,
-            },
+            }
         };
 
         let return_url = ReturnUrl {
@@ -430,7 +562,8 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
         let connector_auth = &item.router_data.connector_auth_type;
         let auth_type: CheckoutAuthType = connector_auth.try_into()?;
         let processing_channel_id = auth_type.processing_channel_id;
-        let metadata = item.router_data.request.metadata.clone();
+        let metadata = get_payment_metadata(item.router_data)?;
+        let billing_descriptor = get_billing_descriptor(item.router_data)?;
         Ok(Self {
             source: source_var,
             amount: item.amount.to_owned(),
@@ -441,6 +574,7 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
             capture,
             reference: item.router_data.connector_request_reference_id.clone(),
             metadata,
+            billing_descriptor,
         })
     }
 }
@@ -629,6 +763,83 @@ pub struct Balances {
     available_to_capture: i32,
 }
 
+/// Checkout identifies network tokens by the token service of the card network
+fn get_network_token_type(
+    card_network: Option<common_enums::CardNetwork>,
+) -> Result<String, errors::ConnectorError> {
+    match card_network {
+        Some(common_enums::CardNetwork::Visa) => Ok("vts".to_string()),
+        Some(common_enums::CardNetwork::Mastercard) => Ok("mdes".to_string()),
+        _ => Err(unimplemented_payment_method!(
+            "Network Token",
+            format!("{card_network:?}"),
+            "Checkout"
+        )),
+    }
+}
+
+fn get_samsung_pay_token_type(
+    card_brand: domain::SamsungPayCardBrand,
+) -> Result<String, errors::ConnectorError> {
+    match card_brand {
+        domain::SamsungPayCardBrand::Visa => Ok("vts".to_string()),
+        domain::SamsungPayCardBrand::MasterCard => Ok("mdes".to_string()),
+        domain::SamsungPayCardBrand::Amex
+        | domain::SamsungPayCardBrand::Discover
+        | domain::SamsungPayCardBrand::Unknown => Err(unimplemented_payment_method!(
+            "Samsung Pay",
+            format!("{card_brand:?}"),
+            "Checkout"
+        )),
+    }
+}
+
+/// The payment metadata, along with the fields, like the `udf` fields, which the payment metadata
+/// is mapped to by the merchant connector account
+/// The billing descriptor is sent only if both the statement descriptor and its suffix are given,
+/// and the suffix is sent as the city shown on the statements
+fn get_billing_descriptor(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> CustomResult<Option<BillingDescriptor>, errors::ConnectorError> {
+    let name = utils::validate_statement_descriptor(
+        item.request.statement_descriptor.as_ref(),
+        "statement_descriptor_name",
+        BILLING_DESCRIPTOR_NAME_MAX_LENGTH,
+        |character| !character.is_control(),
+    )?;
+    let city = utils::validate_statement_descriptor(
+        item.request.statement_descriptor_suffix.as_ref(),
+        "statement_descriptor_suffix",
+        BILLING_DESCRIPTOR_CITY_MAX_LENGTH,
+        |character| !character.is_control(),
+    )?;
+
+    Ok(name
+        .zip(city)
+        .map(|(name, city)| BillingDescriptor { name, city }))
+}
+
+fn get_payment_metadata(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> CustomResult<Option<Secret<serde_json::Value>>, errors::ConnectorError> {
+    let mapped_metadata = utils::get_mapped_connector_metadata(item)?;
+    if mapped_metadata.is_empty() {
+        return Ok(item.request.metadata.clone());
+    }
+
+    let mut metadata = match item.request.metadata.clone().map(ExposeInterface::expose) {
+        Some(serde_json::Value::Object(metadata)) => metadata,
+        _ => serde_json::Map::new(),
+    };
+    metadata.extend(
+        mapped_metadata
+            .into_iter()
+            .map(|(field, value)| (field, serde_json::Value::String(value))),
+    );
+
+    Ok(Some(Secret::new(serde_json::Value::Object(metadata))))
+}
+
 fn get_connector_meta(
     capture_method: enums::CaptureMethod,
 ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
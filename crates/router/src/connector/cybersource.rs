@@ -6,13 +6,12 @@ use base64::Engine;
 use common_utils::request::RequestContent;
 use diesel_models::enums;
 use error_stack::{report, Report, ResultExt};
-use masking::{ExposeInterface, PeekInterface};
+use masking::{ExposeInterface, PeekInterface, PlaintextBuffer};
 use ring::{digest, hmac};
 use time::OffsetDateTime;
 use transformers as cybersource;
 use url::Url;
 
-use super::utils::{PaymentsAuthorizeRequestData, RouterData};
 use crate::{
     configs::settings,
     connector::{utils as connector_utils, utils::RefundsRequestData},
@@ -250,7 +249,9 @@ where
             .chars()
             .skip(base_url.len() - 1)
             .collect();
-        let sha256 = self.generate_digest(cybersource_req.get_inner_value().expose().as_bytes());
+        let sha256 = self.generate_digest(
+            PlaintextBuffer::new(cybersource_req.get_inner_value().expose()).as_bytes(),
+        );
         let http_method = self.get_http_method();
         let signature = self.generate_signature(
             auth,
@@ -827,10 +828,7 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
         req: &types::PaymentsAuthorizeRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        if req.is_three_ds()
-            && req.request.is_card()
-            && req.request.connector_mandate_id().is_none()
-        {
+        if cybersource::is_auth_setup_required(req.auth_type, &req.request) {
             Ok(format!(
                 "{}risk/v1/authentication-setups",
                 api::ConnectorCommon::base_url(self, connectors)
@@ -854,10 +852,7 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
             req.request.amount,
             req,
         ))?;
-        if req.is_three_ds()
-            && req.request.is_card()
-            && req.request.connector_mandate_id().is_none()
-        {
+        if cybersource::is_auth_setup_required(req.auth_type, &req.request) {
             let connector_req =
                 cybersource::CybersourceAuthSetupRequest::try_from(&connector_router_data)?;
             Ok(RequestContent::Json(Box::new(connector_req)))
@@ -894,10 +889,7 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
         event_builder: Option<&mut ConnectorEvent>,
         res: types::Response,
     ) -> CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError> {
-        if data.is_three_ds()
-            && data.request.is_card()
-            && data.request.connector_mandate_id().is_none()
-        {
+        if cybersource::is_auth_setup_required(data.auth_type, &data.request) {
             let response: cybersource::CybersourceAuthSetupResponse = res
                 .response
                 .parse_struct("Cybersource AuthSetupResponse")
@@ -1128,11 +1120,11 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
                 .ok_or(errors::ConnectorError::MissingRequiredField {
                     field_name: "Currency",
                 })?,
-            req.request
-                .amount
-                .ok_or(errors::ConnectorError::MissingRequiredField {
+            req.request.amount_to_reverse.or(req.request.amount).ok_or(
+                errors::ConnectorError::MissingRequiredField {
                     field_name: "Amount",
-                })?,
+                },
+            )?,
             req,
         ))?;
         let connector_req = cybersource::CybersourceVoidRequest::try_from(&connector_router_data)?;
@@ -9,12 +9,13 @@ use serde_json::Value;
 
 use crate::{
     connector::utils::{
-        self, AddressDetailsData, ApplePayDecrypt, CardData, PaymentsAuthorizeRequestData,
-        PaymentsCompleteAuthorizeRequestData, PaymentsPreProcessingData,
-        PaymentsSetupMandateRequestData, PaymentsSyncRequestData, RecurringMandateData, RouterData,
+        self, AddressDetailsData, ApplePayDecrypt, CardData, GooglePayDecrypt,
+        PaymentsAuthorizeRequestData, PaymentsCompleteAuthorizeRequestData,
+        PaymentsPreProcessingData, PaymentsSetupMandateRequestData, PaymentsSyncRequestData,
+        RecurringMandateData, RouterData, SamsungPayDecrypt,
     },
     consts,
-    core::errors,
+    core::{errors, payments::types::AuthenticationData},
     services,
     types::{
         self,
@@ -22,7 +23,7 @@ use crate::{
         domain,
         storage::enums,
         transformers::ForeignFrom,
-        ApplePayPredecryptData,
+        ApplePayPredecryptData, GooglePayPredecryptData, SamsungPayPredecryptData,
     },
     unimplemented_payment_method,
 };
@@ -78,8 +79,12 @@ impl TryFrom<&types::SetupMandateRouterData> for CybersourceZeroMandateRequest {
             amount_details: Amount {
                 total_amount: "0".to_string(),
                 currency: item.request.currency,
+                level_2_amounts: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
+            shipping_details: None,
         };
         let (action_list, action_token_types, authorization_options) = (
             Some(vec![CybersourceActionsList::TokenCreate]),
@@ -111,7 +116,7 @@ impl TryFrom<&types::SetupMandateRouterData> for CybersourceZeroMandateRequest {
                             number: ccard.card_number,
                             expiration_month: ccard.card_exp_month,
                             expiration_year: ccard.card_exp_year,
-                            security_code: ccard.card_cvc,
+                            security_code: ccard.get_optional_card_cvc(),
                             card_type,
                         },
                     }),
@@ -144,6 +149,15 @@ impl TryFrom<&types::SetupMandateRouterData> for CybersourceZeroMandateRequest {
                             types::PaymentMethodToken::Token(_) => Err(
                                 unimplemented_payment_method!("Apple Pay", "Manual", "Cybersource"),
                             )?,
+                            types::PaymentMethodToken::GooglePayDecrypt(_) => {
+                                Err(unimplemented_payment_method!("Google Pay", "Cybersource"))?
+                            }
+                            types::PaymentMethodToken::SamsungPayDecrypt(_) => {
+                                Err(unimplemented_payment_method!("Samsung Pay", "Cybersource"))?
+                            }
+                            types::PaymentMethodToken::NetworkToken(_) => Err(
+                                unimplemented_payment_method!("Network Token", "Cybersource"),
+                            )?,
                         },
                         None => (
                             PaymentInformation::ApplePayToken(ApplePayTokenPaymentInformation {
@@ -159,18 +173,26 @@ impl TryFrom<&types::SetupMandateRouterData> for CybersourceZeroMandateRequest {
                         ),
                     }
                 }
-                domain::WalletData::GooglePay(google_pay_data) => (
-                    PaymentInformation::GooglePay(GooglePayPaymentInformation {
-                        fluid_data: FluidData {
-                            value: Secret::from(
-                                consts::BASE64_ENGINE
-                                    .encode(google_pay_data.tokenization_data.token),
-                            ),
-                            descriptor: None,
-                        },
-                    }),
-                    Some(PaymentSolution::GooglePay),
-                ),
+                domain::WalletData::GooglePay(google_pay_data) => {
+                    match item.payment_method_token.clone() {
+                        Some(types::PaymentMethodToken::GooglePayDecrypt(decrypt_data)) => (
+                            PaymentInformation::try_from(&decrypt_data)?,
+                            Some(PaymentSolution::GooglePay),
+                        ),
+                        _ => (
+                            PaymentInformation::GooglePay(GooglePayPaymentInformation {
+                                fluid_data: FluidData {
+                                    value: Secret::from(
+                                        consts::BASE64_ENGINE
+                                            .encode(google_pay_data.tokenization_data.token),
+                                    ),
+                                    descriptor: None,
+                                },
+                            }),
+                            Some(PaymentSolution::GooglePay),
+                        ),
+                    }
+                }
                 domain::WalletData::AliPayQr(_)
                 | domain::WalletData::AliPayRedirect(_)
                 | domain::WalletData::AliPayHkRedirect(_)
@@ -194,9 +216,13 @@ impl TryFrom<&types::SetupMandateRouterData> for CybersourceZeroMandateRequest {
                 | domain::WalletData::WeChatPayRedirect(_)
                 | domain::WalletData::WeChatPayQr(_)
                 | domain::WalletData::CashappQr(_)
-                | domain::WalletData::SwishQr(_) => Err(errors::ConnectorError::NotImplemented(
-                    utils::get_unimplemented_payment_method_error_message("Cybersource"),
-                ))?,
+                | domain::WalletData::SwishQr(_)
+                | domain::WalletData::ClickToPay(_)
+                | domain::WalletData::SkrillRedirect {} => {
+                    Err(errors::ConnectorError::NotImplemented(
+                        utils::get_unimplemented_payment_method_error_message("Cybersource"),
+                    ))?
+                }
             },
             domain::PaymentMethodData::CardRedirect(_)
             | domain::PaymentMethodData::PayLater(_)
@@ -209,7 +235,8 @@ impl TryFrom<&types::SetupMandateRouterData> for CybersourceZeroMandateRequest {
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Cybersource"),
                 ))?
@@ -259,6 +286,74 @@ pub struct ProcessingInformation {
     payment_solution: Option<String>,
 }
 
+/// The 3DS of the card payments is set up with Cybersource before they are authorized, unless they
+/// are paid with a mandate or have been authenticated by an external 3DS authentication provider,
+/// in which case they are authorized directly with the authentication values of the provider
+pub fn is_auth_setup_required(
+    auth_type: enums::AuthenticationType,
+    request: &types::PaymentsAuthorizeData,
+) -> bool {
+    auth_type == enums::AuthenticationType::ThreeDs
+        && request.is_card()
+        && request.connector_mandate_id().is_none()
+        && request.authentication_data.is_none()
+}
+
+/// The 3DS commerce indicator of a payment authenticated by an external 3DS authentication
+/// provider, by the ECI of the authentication. The payments which could not be authenticated, and
+/// those of the card issuers without a known 3DS commerce indicator, keep the commerce indicator of
+/// the payments which have not been authenticated.
+fn get_authenticated_commerce_indicator(
+    card_issuer: Option<utils::CardIssuer>,
+    eci: Option<&str>,
+) -> Option<&'static str> {
+    // The ECIs are sent by the providers with or without their leading zero
+    let eci = eci.map(|eci| eci.trim_start_matches('0'));
+    match (card_issuer?, eci) {
+        (utils::CardIssuer::Visa, Some("5") | None) => Some("vbv"),
+        (utils::CardIssuer::Visa, Some("6")) => Some("vbv_attempted"),
+        (utils::CardIssuer::Master | utils::CardIssuer::Maestro, Some("2" | "1") | None) => {
+            Some("spa")
+        }
+        (utils::CardIssuer::AmericanExpress, Some("5") | None) => Some("aesk"),
+        (utils::CardIssuer::AmericanExpress, Some("6")) => Some("aesk_attempted"),
+        (utils::CardIssuer::Discover | utils::CardIssuer::DinersClub, Some("5" | "6") | None) => {
+            Some("dipb")
+        }
+        (utils::CardIssuer::JCB, Some("5") | None) => Some("js"),
+        (utils::CardIssuer::JCB, Some("6")) => Some("js_attempted"),
+        _ => None,
+    }
+}
+
+/// The authentication values of Mastercard are sent as UCAF data, with a collection indicator
+/// telling the attempted authentications apart, and the ones of the other card issuers as CAVV
+fn get_consumer_authentication_information(
+    authentication_data: &AuthenticationData,
+    card_issuer: Option<utils::CardIssuer>,
+) -> CybersourceConsumerAuthInformation {
+    let is_mastercard = matches!(
+        card_issuer,
+        Some(utils::CardIssuer::Master | utils::CardIssuer::Maestro)
+    );
+    let is_attempted = authentication_data
+        .eci
+        .as_deref()
+        .is_some_and(|eci| eci.trim_start_matches('0') == "1");
+
+    CybersourceConsumerAuthInformation {
+        ucaf_collection_indicator: is_mastercard
+            .then(|| if is_attempted { "1" } else { "2" }.to_string()),
+        cavv: (!is_mastercard).then(|| authentication_data.cavv.clone()),
+        ucaf_authentication_data: is_mastercard
+            .then(|| Secret::new(authentication_data.cavv.clone())),
+        xid: authentication_data.threeds_server_transaction_id.clone(),
+        directory_server_transaction_id: authentication_data.ds_trans_id.clone().map(Secret::new),
+        specification_version: Some(authentication_data.message_version.clone()),
+        eci_raw: authentication_data.eci.clone(),
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CybersourceConsumerAuthInformation {
@@ -268,6 +363,7 @@ pub struct CybersourceConsumerAuthInformation {
     xid: Option<String>,
     directory_server_transaction_id: Option<Secret<String>>,
     specification_version: Option<String>,
+    eci_raw: Option<String>,
 }
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -362,6 +458,76 @@ pub struct ApplePayPaymentInformation {
     tokenized_card: TokenizedCard,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayTokenizedCard {
+    number: cards::CardNumber,
+    expiration_month: Secret<String>,
+    expiration_year: Secret<String>,
+    cryptogram: Secret<String>,
+    transaction_type: TransactionType,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayPredecryptPaymentInformation {
+    tokenized_card: GooglePayTokenizedCard,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SamsungPayTokenizedCard {
+    number: cards::CardNumber,
+    expiration_month: Secret<String>,
+    expiration_year: Secret<String>,
+    cryptogram: Secret<String>,
+    transaction_type: TransactionType,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SamsungPayPredecryptPaymentInformation {
+    tokenized_card: SamsungPayTokenizedCard,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkTokenizedCard {
+    number: cards::CardNumber,
+    expiration_month: Secret<String>,
+    expiration_year: Secret<String>,
+    cryptogram: Secret<String>,
+    transaction_type: TransactionType,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkTokenPaymentInformation {
+    tokenized_card: NetworkTokenizedCard,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SamsungPayPaymentInformation {
+    fluid_data: FluidData,
+    tokenized_card: SamsungPayTokenTransaction,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SamsungPayTokenTransaction {
+    transaction_type: TransactionType,
+}
+
+/// Value of the fluid data of Samsung Pay payments, which is base64 encoded before being sent
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SamsungPayFluidDataValue {
+    public_key_hash: Secret<String>,
+    version: String,
+    data: Secret<String>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MandatePaymentInformation {
@@ -378,6 +544,8 @@ pub struct FluidData {
 
 pub const FLUID_DATA_DESCRIPTOR: &str = "RklEPUNPTU1PTi5BUFBMRS5JTkFQUC5QQVlNRU5U";
 
+pub const SAMSUNG_PAY_FLUID_DATA_DESCRIPTOR: &str = "RklEPUNPTU1PTi5TQU1TVU5HLklOQVBQLlBBWU1FTlQ=";
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GooglePayPaymentInformation {
@@ -389,8 +557,12 @@ pub struct GooglePayPaymentInformation {
 pub enum PaymentInformation {
     Cards(CardPaymentInformation),
     GooglePay(GooglePayPaymentInformation),
+    GooglePayPredecrypt(GooglePayPredecryptPaymentInformation),
     ApplePay(ApplePayPaymentInformation),
     ApplePayToken(ApplePayTokenPaymentInformation),
+    SamsungPay(Box<SamsungPayPaymentInformation>),
+    SamsungPayPredecrypt(Box<SamsungPayPredecryptPaymentInformation>),
+    NetworkToken(Box<NetworkTokenPaymentInformation>),
     MandatePayment(MandatePaymentInformation),
 }
 
@@ -404,7 +576,8 @@ pub struct Card {
     number: cards::CardNumber,
     expiration_month: Secret<String>,
     expiration_year: Secret<String>,
-    security_code: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    security_code: Option<Secret<String>>,
     #[serde(rename = "type")]
     card_type: Option<String>,
 }
@@ -413,6 +586,12 @@ pub struct Card {
 pub struct OrderInformationWithBill {
     amount_details: Amount,
     bill_to: Option<BillTo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invoice_details: Option<InvoiceDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping_details: Option<ShippingDetails>,
 }
 
 #[derive(Debug, Serialize)]
@@ -432,6 +611,53 @@ pub struct OrderInformation {
 pub struct Amount {
     total_amount: String,
     currency: api_models::enums::Currency,
+    #[serde(flatten)]
+    level_2_amounts: Option<Level2Amounts>,
+}
+
+/// Amounts of the level 2 / level 3 data of a card payment, which are included in its total amount
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Level2Amounts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    freight_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duty_amount: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    product_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commodity_code: Option<String>,
+    quantity: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measure: Option<String>,
+    unit_price: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+    total_amount: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceDetails {
+    purchase_order_number: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShippingDetails {
+    ship_from_postal_code: Secret<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -445,12 +671,19 @@ pub struct AdditionalAmount {
 pub enum PaymentSolution {
     ApplePay,
     GooglePay,
+    SamsungPay,
 }
 
 #[derive(Debug, Serialize)]
 pub enum TransactionType {
     #[serde(rename = "1")]
     ApplePay,
+    #[serde(rename = "1")]
+    GooglePay,
+    #[serde(rename = "1")]
+    SamsungPay,
+    #[serde(rename = "3")]
+    StoredCredentials,
 }
 
 impl From<PaymentSolution> for String {
@@ -458,6 +691,7 @@ impl From<PaymentSolution> for String {
         let payment_solution = match solution {
             PaymentSolution::ApplePay => "001",
             PaymentSolution::GooglePay => "012",
+            PaymentSolution::SamsungPay => "008",
         };
         payment_solution.to_string()
     }
@@ -496,6 +730,98 @@ impl From<&CybersourceRouterData<&types::PaymentsCompleteAuthorizeRouterData>>
     }
 }
 
+impl TryFrom<&Box<GooglePayPredecryptData>> for PaymentInformation {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(google_pay_data: &Box<GooglePayPredecryptData>) -> Result<Self, Self::Error> {
+        Ok(Self::GooglePayPredecrypt(
+            GooglePayPredecryptPaymentInformation {
+                tokenized_card: GooglePayTokenizedCard {
+                    number: google_pay_data.payment_method_details.pan.clone(),
+                    expiration_month: google_pay_data.get_expiry_month(),
+                    expiration_year: google_pay_data.get_four_digit_expiry_year(),
+                    cryptogram: google_pay_data.get_cryptogram()?,
+                    transaction_type: TransactionType::GooglePay,
+                },
+            },
+        ))
+    }
+}
+
+impl TryFrom<&Box<SamsungPayPredecryptData>> for PaymentInformation {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(samsung_pay_data: &Box<SamsungPayPredecryptData>) -> Result<Self, Self::Error> {
+        Ok(Self::SamsungPayPredecrypt(Box::new(
+            SamsungPayPredecryptPaymentInformation {
+                tokenized_card: SamsungPayTokenizedCard {
+                    number: samsung_pay_data.token_pan.clone(),
+                    expiration_month: samsung_pay_data.get_expiry_month()?,
+                    expiration_year: samsung_pay_data.get_four_digit_expiry_year()?,
+                    cryptogram: samsung_pay_data.cryptogram.clone(),
+                    transaction_type: TransactionType::SamsungPay,
+                },
+            },
+        )))
+    }
+}
+
+impl From<&Box<types::NetworkTokenData>> for PaymentInformation {
+    fn from(network_token_data: &Box<types::NetworkTokenData>) -> Self {
+        Self::NetworkToken(Box::new(NetworkTokenPaymentInformation {
+            tokenized_card: NetworkTokenizedCard {
+                number: network_token_data.token_number.clone(),
+                expiration_month: network_token_data.token_exp_month.clone(),
+                expiration_year: network_token_data.token_exp_year.clone(),
+                cryptogram: network_token_data.cryptogram.clone(),
+                transaction_type: TransactionType::StoredCredentials,
+            },
+        }))
+    }
+}
+
+impl TryFrom<&domain::SamsungPayTokenData> for PaymentInformation {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(token_data: &domain::SamsungPayTokenData) -> Result<Self, Self::Error> {
+        // The public key hash is the ID of the key the token is encrypted with, in the JWE header
+        let public_key_hash = josekit::jwt::decode_header(token_data.data.peek())
+            .change_context(errors::ConnectorError::RequestEncodingFailed)
+            .attach_printable("Failed to decode the header of the Samsung Pay token")?
+            .claim("kid")
+            .and_then(|kid| kid.as_str())
+            .map(|kid| Secret::new(kid.to_string()))
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "payment_credential.3_d_s.data.kid",
+            })?;
+
+        let fluid_data_value = SamsungPayFluidDataValue {
+            public_key_hash,
+            version: token_data.version.clone(),
+            data: Secret::new(consts::BASE64_ENGINE.encode(token_data.data.peek())),
+        };
+        let fluid_data_value = serde_json::to_vec(&fluid_data_value)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+
+        Ok(Self::SamsungPay(Box::new(SamsungPayPaymentInformation {
+            fluid_data: FluidData {
+                value: Secret::new(consts::BASE64_ENGINE.encode(fluid_data_value)),
+                descriptor: Some(SAMSUNG_PAY_FLUID_DATA_DESCRIPTOR.to_string()),
+            },
+            tokenized_card: SamsungPayTokenTransaction {
+                transaction_type: TransactionType::SamsungPay,
+            },
+        })))
+    }
+}
+
+fn get_samsung_pay_card_network(card_brand: domain::SamsungPayCardBrand) -> Option<String> {
+    match card_brand {
+        domain::SamsungPayCardBrand::Visa => Some("visa".to_string()),
+        domain::SamsungPayCardBrand::MasterCard => Some("mastercard".to_string()),
+        domain::SamsungPayCardBrand::Amex => Some("amex".to_string()),
+        domain::SamsungPayCardBrand::Discover => Some("discover".to_string()),
+        domain::SamsungPayCardBrand::Unknown => None,
+    }
+}
+
 impl
     TryFrom<(
         &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
@@ -514,7 +840,9 @@ impl
         let mut commerce_indicator = solution
             .as_ref()
             .map(|pm_solution| match pm_solution {
-                PaymentSolution::ApplePay => network
+                PaymentSolution::ApplePay
+                | PaymentSolution::GooglePay
+                | PaymentSolution::SamsungPay => network
                     .as_ref()
                     .map(|card_network| match card_network.to_lowercase().as_str() {
                         "amex" => "aesk",
@@ -524,10 +852,14 @@ impl
                         _ => "internet",
                     })
                     .unwrap_or("internet"),
-                PaymentSolution::GooglePay => "internet",
             })
             .unwrap_or("internet")
             .to_string();
+        if solution.is_none()
+            && item.router_data.request.payment_channel == Some(enums::PaymentChannel::Moto)
+        {
+            commerce_indicator = "moto".to_string();
+        }
 
         let (action_list, action_token_types, authorization_options) = if item
             .router_data
@@ -566,19 +898,21 @@ impl
                 .and_then(|mandate_id| mandate_id.mandate_reference_id)
             {
                 Some(api_models::payments::MandateReferenceId::ConnectorMandateId(_)) => {
-                    let original_amount = item
-                        .router_data
-                        .get_recurring_mandate_payment_data()?
-                        .get_original_payment_amount()?;
-                    let original_currency = item
-                        .router_data
-                        .get_recurring_mandate_payment_data()?
-                        .get_original_payment_currency()?;
+                    let recurring_mandate_payment_data =
+                        item.router_data.get_recurring_mandate_payment_data()?;
+                    let original_amount =
+                        recurring_mandate_payment_data.get_original_payment_amount()?;
+                    let original_currency =
+                        recurring_mandate_payment_data.get_original_payment_currency()?;
                     (
                         None,
                         None,
                         Some(CybersourceAuthorizationOptions {
-                            initiator: None,
+                            initiator: Some(CybersourcePaymentInitiator {
+                                initiator_type: Some(CybersourcePaymentInitiatorTypes::Merchant),
+                                credential_stored_on_file: None,
+                                stored_credential_used: Some(true),
+                            }),
                             merchant_intitiated_transaction: Some(MerchantInitiatedTransaction {
                                 reason: None,
                                 original_authorized_amount: Some(utils::get_amount_as_string(
@@ -586,7 +920,9 @@ impl
                                     original_amount,
                                     original_currency,
                                 )?),
-                                previous_transaction_id: None,
+                                previous_transaction_id: recurring_mandate_payment_data
+                                    .network_transaction_id
+                                    .map(Secret::new),
                             }),
                         }),
                     )
@@ -736,24 +1072,83 @@ impl
 }
 
 impl
-    From<(
+    TryFrom<(
         &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
         BillTo,
     )> for OrderInformationWithBill
 {
-    fn from(
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
         (item, bill_to): (
             &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
             BillTo,
         ),
-    ) -> Self {
-        Self {
+    ) -> Result<Self, Self::Error> {
+        let currency = item.router_data.request.currency;
+        let to_base_unit = |amount: Option<i64>| {
+            amount
+                .map(|amount| utils::to_currency_base_unit(amount, currency))
+                .transpose()
+        };
+        let l2_l3_data = item.router_data.request.l2_l3_data.as_ref();
+
+        let level_2_amounts = l2_l3_data
+            .map(|l2_l3_data| {
+                Ok::<_, Self::Error>(Level2Amounts {
+                    tax_amount: to_base_unit(l2_l3_data.tax_amount)?,
+                    discount_amount: to_base_unit(l2_l3_data.discount_amount)?,
+                    freight_amount: to_base_unit(l2_l3_data.shipping_amount)?,
+                    duty_amount: to_base_unit(l2_l3_data.duty_amount)?,
+                })
+            })
+            .transpose()?;
+        let line_items = l2_l3_data
+            .and_then(|l2_l3_data| l2_l3_data.line_items.as_ref())
+            .map(|line_items| {
+                line_items
+                    .iter()
+                    .map(|line_item| {
+                        Ok(LineItem {
+                            product_name: line_item.product_name.clone(),
+                            product_code: line_item.product_code.clone(),
+                            commodity_code: line_item.commodity_code.clone(),
+                            quantity: line_item.quantity,
+                            unit_of_measure: line_item.unit_of_measure.clone(),
+                            unit_price: utils::to_currency_base_unit(
+                                line_item.unit_amount,
+                                currency,
+                            )?,
+                            tax_amount: to_base_unit(line_item.tax_amount)?,
+                            discount_amount: to_base_unit(line_item.discount_amount)?,
+                            total_amount: utils::to_currency_base_unit(
+                                line_item.get_total_amount(),
+                                currency,
+                            )?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Self::Error>>()
+            })
+            .transpose()?;
+
+        Ok(Self {
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
-                currency: item.router_data.request.currency,
+                currency,
+                level_2_amounts,
             },
             bill_to: Some(bill_to),
-        }
+            line_items,
+            invoice_details: l2_l3_data
+                .and_then(|l2_l3_data| l2_l3_data.purchase_order_number.clone())
+                .map(|purchase_order_number| InvoiceDetails {
+                    purchase_order_number,
+                }),
+            shipping_details: l2_l3_data
+                .and_then(|l2_l3_data| l2_l3_data.ship_from_postal_code.clone())
+                .map(|ship_from_postal_code| ShippingDetails {
+                    ship_from_postal_code: Secret::new(ship_from_postal_code),
+                }),
+        })
     }
 }
 
@@ -773,8 +1168,12 @@ impl
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
                 currency: item.router_data.request.currency,
+                level_2_amounts: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
+            shipping_details: None,
         }
     }
 }
@@ -836,37 +1235,98 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
 
-        let card_issuer = ccard.get_card_issuer();
-        let card_type = match card_issuer {
-            Ok(issuer) => Some(String::from(issuer)),
-            Err(_) => None,
-        };
+        let card_issuer = ccard.get_card_issuer().ok();
+        let card_type = card_issuer.map(String::from);
 
         let payment_information = PaymentInformation::Cards(CardPaymentInformation {
             card: Card {
                 number: ccard.card_number,
                 expiration_month: ccard.card_exp_month,
                 expiration_year: ccard.card_exp_year,
-                security_code: ccard.card_cvc,
+                security_code: ccard.get_optional_card_cvc(),
                 card_type: card_type.clone(),
             },
         });
 
-        let processing_information = ProcessingInformation::try_from((item, None, card_type))?;
+        let mut processing_information = ProcessingInformation::try_from((item, None, card_type))?;
         let client_reference_information = ClientReferenceInformation::from(item);
         let merchant_defined_information =
             item.router_data.request.metadata.clone().map(|metadata| {
                 Vec::<MerchantDefinedInformation>::foreign_from(metadata.peek().to_owned())
             });
 
+        // Set if the payment has been authenticated by an external 3DS authentication provider
+        let consumer_authentication_information = item
+            .router_data
+            .request
+            .authentication_data
+            .as_ref()
+            .map(|authentication_data| {
+                if let Some(commerce_indicator) = get_authenticated_commerce_indicator(
+                    card_issuer,
+                    authentication_data.eci.as_deref(),
+                ) {
+                    processing_information.commerce_indicator = commerce_indicator.to_string();
+                }
+                get_consumer_authentication_information(authentication_data, card_issuer)
+            });
+
         Ok(Self {
             processing_information,
             payment_information,
             order_information,
             client_reference_information,
-            consumer_authentication_information: None,
+            consumer_authentication_information,
+            merchant_defined_information,
+        })
+    }
+}
+
+impl
+    TryFrom<(
+        &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
+        Box<types::NetworkTokenData>,
+    )> for CybersourcePaymentsRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (item, network_token_data): (
+            &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
+            Box<types::NetworkTokenData>,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let email = item.router_data.request.get_email()?;
+        let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
+
+        let payment_information = PaymentInformation::from(&network_token_data);
+        let processing_information = ProcessingInformation::try_from((item, None, None))?;
+        let client_reference_information = ClientReferenceInformation::from(item);
+        let merchant_defined_information =
+            item.router_data.request.metadata.clone().map(|metadata| {
+                Vec::<MerchantDefinedInformation>::foreign_from(metadata.peek().to_owned())
+            });
+        let ucaf_collection_indicator = match network_token_data.card_network {
+            Some(common_enums::CardNetwork::Mastercard) => Some("2".to_string()),
+            _ => None,
+        };
+
+        Ok(Self {
+            processing_information,
+            payment_information,
+            order_information,
+            client_reference_information,
+            consumer_authentication_information: Some(CybersourceConsumerAuthInformation {
+                ucaf_collection_indicator,
+                cavv: None,
+                ucaf_authentication_data: None,
+                xid: None,
+                directory_server_transaction_id: None,
+                specification_version: None,
+                eci_raw: None,
+            }),
             merchant_defined_information,
         })
     }
@@ -900,7 +1360,7 @@ impl
                 number: ccard.card_number,
                 expiration_month: ccard.card_exp_month,
                 expiration_year: ccard.card_exp_year,
-                security_code: ccard.card_cvc,
+                security_code: ccard.get_optional_card_cvc(),
                 card_type,
             },
         });
@@ -931,6 +1391,7 @@ impl
                 .three_ds_data
                 .directory_server_transaction_id,
             specification_version: three_ds_info.three_ds_data.specification_version,
+            eci_raw: None,
         });
 
         let merchant_defined_information =
@@ -966,7 +1427,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let processing_information = ProcessingInformation::try_from((
             item,
             Some(PaymentSolution::ApplePay),
@@ -1009,6 +1470,64 @@ impl
                 xid: None,
                 directory_server_transaction_id: None,
                 specification_version: None,
+                eci_raw: None,
+            }),
+            merchant_defined_information,
+        })
+    }
+}
+
+impl
+    TryFrom<(
+        &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
+        Box<GooglePayPredecryptData>,
+        domain::GooglePayWalletData,
+    )> for CybersourcePaymentsRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (item, google_pay_decrypted_data, google_pay_wallet_data): (
+            &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
+            Box<GooglePayPredecryptData>,
+            domain::GooglePayWalletData,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let email = item.router_data.request.get_email()?;
+        let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
+        let processing_information = ProcessingInformation::try_from((
+            item,
+            Some(PaymentSolution::GooglePay),
+            Some(google_pay_wallet_data.info.card_network.clone()),
+        ))?;
+        let client_reference_information = ClientReferenceInformation::from(item);
+        let payment_information = PaymentInformation::try_from(&google_pay_decrypted_data)?;
+        let merchant_defined_information =
+            item.router_data.request.metadata.clone().map(|metadata| {
+                Vec::<MerchantDefinedInformation>::foreign_from(metadata.peek().to_owned())
+            });
+        let ucaf_collection_indicator = match google_pay_wallet_data
+            .info
+            .card_network
+            .to_lowercase()
+            .as_str()
+        {
+            "mastercard" => Some("2".to_string()),
+            _ => None,
+        };
+        Ok(Self {
+            processing_information,
+            payment_information,
+            order_information,
+            client_reference_information,
+            consumer_authentication_information: Some(CybersourceConsumerAuthInformation {
+                ucaf_collection_indicator,
+                cavv: None,
+                ucaf_authentication_data: None,
+                xid: None,
+                directory_server_transaction_id: None,
+                specification_version: None,
+                eci_raw: None,
             }),
             merchant_defined_information,
         })
@@ -1030,7 +1549,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
 
         let payment_information = PaymentInformation::GooglePay(GooglePayPaymentInformation {
             fluid_data: FluidData {
@@ -1059,6 +1578,68 @@ impl
     }
 }
 
+impl
+    TryFrom<(
+        &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
+        Option<Box<SamsungPayPredecryptData>>,
+        Box<domain::SamsungPayWalletData>,
+    )> for CybersourcePaymentsRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (item, samsung_pay_decrypted_data, samsung_pay_wallet_data): (
+            &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
+            Option<Box<SamsungPayPredecryptData>>,
+            Box<domain::SamsungPayWalletData>,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let email = item.router_data.request.get_email()?;
+        let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
+        let card_network =
+            get_samsung_pay_card_network(samsung_pay_wallet_data.payment_credential.card_brand);
+        let processing_information = ProcessingInformation::try_from((
+            item,
+            Some(PaymentSolution::SamsungPay),
+            card_network.clone(),
+        ))?;
+        let client_reference_information = ClientReferenceInformation::from(item);
+        // The token is forwarded to Cybersource to be decrypted, unless it was decrypted using the
+        // private key in the connector metadata
+        let payment_information = match samsung_pay_decrypted_data {
+            Some(decrypted_data) => PaymentInformation::try_from(&decrypted_data)?,
+            None => PaymentInformation::try_from(
+                &samsung_pay_wallet_data.payment_credential.token_data,
+            )?,
+        };
+        let merchant_defined_information =
+            item.router_data.request.metadata.clone().map(|metadata| {
+                Vec::<MerchantDefinedInformation>::foreign_from(metadata.peek().to_owned())
+            });
+        let ucaf_collection_indicator = match card_network.as_deref() {
+            Some("mastercard") => Some("2".to_string()),
+            _ => None,
+        };
+
+        Ok(Self {
+            processing_information,
+            payment_information,
+            order_information,
+            client_reference_information,
+            consumer_authentication_information: Some(CybersourceConsumerAuthInformation {
+                ucaf_collection_indicator,
+                cavv: None,
+                ucaf_authentication_data: None,
+                xid: None,
+                directory_server_transaction_id: None,
+                specification_version: None,
+                eci_raw: None,
+            }),
+            merchant_defined_information,
+        })
+    }
+}
+
 impl TryFrom<&CybersourceRouterData<&types::PaymentsAuthorizeRouterData>>
     for CybersourcePaymentsRequest
 {
@@ -1070,7 +1651,18 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsAuthorizeRouterData>>
             Some(connector_mandate_id) => Self::try_from((item, connector_mandate_id)),
             None => {
                 match item.router_data.request.payment_method_data.clone() {
-                    domain::PaymentMethodData::Card(ccard) => Self::try_from((item, ccard)),
+                    domain::PaymentMethodData::Card(ccard) => {
+                        match item.router_data.payment_method_token.clone() {
+                            // The card is authenticated by the external 3DS authentication rather
+                            // than by the cryptogram of its network token
+                            Some(types::PaymentMethodToken::NetworkToken(network_token_data))
+                                if item.router_data.request.authentication_data.is_none() =>
+                            {
+                                Self::try_from((item, network_token_data))
+                            }
+                            _ => Self::try_from((item, ccard)),
+                        }
+                    }
                     domain::PaymentMethodData::Wallet(wallet_data) => match wallet_data {
                         domain::WalletData::ApplePay(apple_pay_data) => {
                             match item.router_data.payment_method_token.clone() {
@@ -1085,13 +1677,25 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsAuthorizeRouterData>>
                                             "Cybersource"
                                         ))?
                                     }
+                                    types::PaymentMethodToken::GooglePayDecrypt(_) => Err(
+                                        unimplemented_payment_method!("Google Pay", "Cybersource"),
+                                    )?,
+                                    types::PaymentMethodToken::SamsungPayDecrypt(_) => Err(
+                                        unimplemented_payment_method!("Samsung Pay", "Cybersource"),
+                                    )?,
+                                    types::PaymentMethodToken::NetworkToken(_) => {
+                                        Err(unimplemented_payment_method!(
+                                            "Network Token",
+                                            "Cybersource"
+                                        ))?
+                                    }
                                 },
                                 None => {
                                     let email = item.router_data.request.get_email()?;
                                     let bill_to =
                                         build_bill_to(item.router_data.get_billing()?, email)?;
                                     let order_information =
-                                        OrderInformationWithBill::from((item, bill_to));
+                                        OrderInformationWithBill::try_from((item, bill_to))?;
                                     let processing_information =
                                         ProcessingInformation::try_from((
                                             item,
@@ -1140,6 +1744,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsAuthorizeRouterData>>
                                                 xid: None,
                                                 directory_server_transaction_id: None,
                                                 specification_version: None,
+                                                eci_raw: None,
                                             },
                                         ),
                                     })
@@ -1147,7 +1752,22 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsAuthorizeRouterData>>
                             }
                         }
                         domain::WalletData::GooglePay(google_pay_data) => {
-                            Self::try_from((item, google_pay_data))
+                            match item.router_data.payment_method_token.clone() {
+                                Some(types::PaymentMethodToken::GooglePayDecrypt(decrypt_data)) => {
+                                    Self::try_from((item, decrypt_data, google_pay_data))
+                                }
+                                _ => Self::try_from((item, google_pay_data)),
+                            }
+                        }
+                        domain::WalletData::SamsungPay(samsung_pay_data) => {
+                            let samsung_pay_decrypted_data =
+                                match item.router_data.payment_method_token.clone() {
+                                    Some(types::PaymentMethodToken::SamsungPayDecrypt(
+                                        decrypt_data,
+                                    )) => Some(decrypt_data),
+                                    _ => None,
+                                };
+                            Self::try_from((item, samsung_pay_decrypted_data, samsung_pay_data))
                         }
                         domain::WalletData::AliPayQr(_)
                         | domain::WalletData::AliPayRedirect(_)
@@ -1165,14 +1785,15 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsAuthorizeRouterData>>
                         | domain::WalletData::MobilePayRedirect(_)
                         | domain::WalletData::PaypalRedirect(_)
                         | domain::WalletData::PaypalSdk(_)
-                        | domain::WalletData::SamsungPay(_)
                         | domain::WalletData::TwintRedirect {}
                         | domain::WalletData::VippsRedirect {}
                         | domain::WalletData::TouchNGoRedirect(_)
                         | domain::WalletData::WeChatPayRedirect(_)
                         | domain::WalletData::WeChatPayQr(_)
                         | domain::WalletData::CashappQr(_)
-                        | domain::WalletData::SwishQr(_) => {
+                        | domain::WalletData::SwishQr(_)
+                        | domain::WalletData::ClickToPay(_)
+                        | domain::WalletData::SkrillRedirect {} => {
                             Err(errors::ConnectorError::NotImplemented(
                                 utils::get_unimplemented_payment_method_error_message(
                                     "Cybersource",
@@ -1202,7 +1823,8 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsAuthorizeRouterData>>
                     | domain::PaymentMethodData::Upi(_)
                     | domain::PaymentMethodData::Voucher(_)
                     | domain::PaymentMethodData::GiftCard(_)
-                    | domain::PaymentMethodData::CardToken(_) => {
+                    | domain::PaymentMethodData::CardToken(_)
+                    | domain::PaymentMethodData::RealTimePayment(_) => {
                         Err(errors::ConnectorError::NotImplemented(
                             utils::get_unimplemented_payment_method_error_message("Cybersource"),
                         )
@@ -1233,7 +1855,7 @@ impl
         };
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let payment_information =
             PaymentInformation::MandatePayment(MandatePaymentInformation { payment_instrument });
         let client_reference_information = ClientReferenceInformation::from(item);
@@ -1278,7 +1900,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsAuthorizeRouterData>>
                         number: ccard.card_number,
                         expiration_month: ccard.card_exp_month,
                         expiration_year: ccard.card_exp_year,
-                        security_code: ccard.card_cvc,
+                        security_code: ccard.get_optional_card_cvc(),
                         card_type,
                     },
                 });
@@ -1300,7 +1922,8 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsAuthorizeRouterData>>
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Cybersource"),
                 )
@@ -1355,8 +1978,12 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsCaptureRouterData>>
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    level_2_amounts: None,
                 },
                 bill_to: None,
+                line_items: None,
+                invoice_details: None,
+                shipping_details: None,
             },
             client_reference_information: ClientReferenceInformation {
                 code: Some(item.router_data.connector_request_reference_id.clone()),
@@ -1442,6 +2069,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsCancelRouterData>> for Cyber
                             field_name: "Currency",
                         },
                     )?,
+                    level_2_amounts: None,
                 },
                 reason: value
                     .router_data
@@ -1982,7 +2610,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsPreProcessingRouterData>>
                         number: ccard.card_number,
                         expiration_month: ccard.card_exp_month,
                         expiration_year: ccard.card_exp_year,
-                        security_code: ccard.card_cvc,
+                        security_code: ccard.get_optional_card_cvc(),
                         card_type,
                     },
                 }))
@@ -1999,7 +2627,8 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsPreProcessingRouterData>>
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Cybersource"),
                 ))
@@ -2019,6 +2648,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsPreProcessingRouterData>>
                     field_name: "currency",
                 },
             )?,
+            level_2_amounts: None,
         };
 
         match redirect_response.params {
@@ -2037,6 +2667,9 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsPreProcessingRouterData>>
                 let order_information = OrderInformationWithBill {
                     amount_details,
                     bill_to: Some(bill_to),
+                    line_items: None,
+                    invoice_details: None,
+                    shipping_details: None,
                 };
                 Ok(Self::AuthEnrollment(CybersourceAuthEnrollmentRequest {
                     payment_information,
@@ -2100,7 +2733,8 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsCompleteAuthorizeRouterData>
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Cybersource"),
                 )
@@ -2688,6 +3322,7 @@ impl<F> TryFrom<&CybersourceRouterData<&types::RefundsRouterData<F>>> for Cybers
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    level_2_amounts: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -2900,3 +3535,197 @@ impl
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn get_authentication_data(eci: Option<&str>) -> AuthenticationData {
+        AuthenticationData {
+            eci: eci.map(str::to_string),
+            cavv: "AAABBZkZiQAAAAAAAAAAAAAAAAA=".to_string(),
+            threeds_server_transaction_id: Some("3ds_trans_1".to_string()),
+            ds_trans_id: Some("ds_trans_1".to_string()),
+            message_version: "2.2.0".to_string(),
+        }
+    }
+
+    fn get_card_authorize_data(
+        authentication_data: Option<AuthenticationData>,
+    ) -> types::PaymentsAuthorizeData {
+        types::PaymentsAuthorizeData {
+            payment_method_data: domain::PaymentMethodData::Card(domain::Card {
+                card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
+                card_exp_month: Secret::new("03".to_string()),
+                card_exp_year: Secret::new("2030".to_string()),
+                card_cvc: Secret::new("737".to_string()),
+                card_issuer: None,
+                card_network: None,
+                card_type: None,
+                card_issuing_country: None,
+                bank_code: None,
+                nick_name: None,
+            }),
+            email: None,
+            customer_name: None,
+            amount: 1000,
+            confirm: true,
+            currency: enums::Currency::USD,
+            metadata: None,
+            mandate_id: None,
+            webhook_url: None,
+            customer_id: None,
+            off_session: None,
+            browser_info: None,
+            session_token: None,
+            order_details: None,
+            order_category: None,
+            capture_method: None,
+            enrolled_for_3ds: true,
+            router_return_url: None,
+            surcharge_details: None,
+            setup_future_usage: None,
+            payment_experience: None,
+            payment_method_type: None,
+            statement_descriptor: None,
+            setup_mandate_details: None,
+            complete_authorize_url: None,
+            related_transaction_id: None,
+            statement_descriptor_suffix: None,
+            request_incremental_authorization: false,
+            request_overcapture: false,
+            request_extended_authorization: false,
+            split_payments: None,
+            payment_channel: None,
+            l2_l3_data: None,
+            installment_tenure: None,
+            authentication_data,
+            customer_acceptance: None,
+            preferred_card_network: None,
+        }
+    }
+
+    #[test]
+    fn test_get_authenticated_commerce_indicator() {
+        let visa = Some(utils::CardIssuer::Visa);
+        assert_eq!(
+            get_authenticated_commerce_indicator(visa, Some("05")),
+            Some("vbv")
+        );
+        assert_eq!(
+            get_authenticated_commerce_indicator(visa, Some("5")),
+            Some("vbv")
+        );
+        assert_eq!(
+            get_authenticated_commerce_indicator(visa, Some("06")),
+            Some("vbv_attempted")
+        );
+        assert_eq!(get_authenticated_commerce_indicator(visa, Some("07")), None);
+        assert_eq!(
+            get_authenticated_commerce_indicator(visa, None),
+            Some("vbv")
+        );
+
+        let mastercard = Some(utils::CardIssuer::Master);
+        assert_eq!(
+            get_authenticated_commerce_indicator(mastercard, Some("02")),
+            Some("spa")
+        );
+        assert_eq!(
+            get_authenticated_commerce_indicator(mastercard, Some("01")),
+            Some("spa")
+        );
+        assert_eq!(
+            get_authenticated_commerce_indicator(mastercard, Some("00")),
+            None
+        );
+
+        let amex = Some(utils::CardIssuer::AmericanExpress);
+        assert_eq!(
+            get_authenticated_commerce_indicator(amex, Some("05")),
+            Some("aesk")
+        );
+        assert_eq!(
+            get_authenticated_commerce_indicator(amex, Some("06")),
+            Some("aesk_attempted")
+        );
+        assert_eq!(get_authenticated_commerce_indicator(amex, Some("07")), None);
+
+        assert_eq!(
+            get_authenticated_commerce_indicator(Some(utils::CardIssuer::CarteBlanche), Some("05")),
+            None
+        );
+        assert_eq!(get_authenticated_commerce_indicator(None, Some("05")), None);
+    }
+
+    #[test]
+    fn test_get_consumer_authentication_information() {
+        let visa_information = get_consumer_authentication_information(
+            &get_authentication_data(Some("05")),
+            Some(utils::CardIssuer::Visa),
+        );
+        assert_eq!(
+            visa_information.cavv.as_deref(),
+            Some("AAABBZkZiQAAAAAAAAAAAAAAAAA=")
+        );
+        assert!(visa_information.ucaf_collection_indicator.is_none());
+        assert!(visa_information.ucaf_authentication_data.is_none());
+        assert_eq!(visa_information.eci_raw.as_deref(), Some("05"));
+        assert_eq!(visa_information.xid.as_deref(), Some("3ds_trans_1"));
+
+        let mastercard_information = get_consumer_authentication_information(
+            &get_authentication_data(Some("02")),
+            Some(utils::CardIssuer::Master),
+        );
+        assert!(mastercard_information.cavv.is_none());
+        assert_eq!(
+            mastercard_information.ucaf_collection_indicator.as_deref(),
+            Some("2")
+        );
+        assert_eq!(
+            mastercard_information
+                .ucaf_authentication_data
+                .as_ref()
+                .map(|ucaf_authentication_data| ucaf_authentication_data.peek().as_str()),
+            Some("AAABBZkZiQAAAAAAAAAAAAAAAAA=")
+        );
+        assert_eq!(mastercard_information.eci_raw.as_deref(), Some("02"));
+
+        let attempted_mastercard_information = get_consumer_authentication_information(
+            &get_authentication_data(Some("01")),
+            Some(utils::CardIssuer::Master),
+        );
+        assert_eq!(
+            attempted_mastercard_information
+                .ucaf_collection_indicator
+                .as_deref(),
+            Some("1")
+        );
+
+        let serialized_information = serde_json::to_value(get_consumer_authentication_information(
+            &get_authentication_data(Some("05")),
+            Some(utils::CardIssuer::AmericanExpress),
+        ))
+        .unwrap();
+        assert_eq!(serialized_information["eciRaw"], "05");
+    }
+
+    #[test]
+    fn test_is_auth_setup_required() {
+        assert!(is_auth_setup_required(
+            enums::AuthenticationType::ThreeDs,
+            &get_card_authorize_data(None)
+        ));
+        assert!(!is_auth_setup_required(
+            enums::AuthenticationType::ThreeDs,
+            &get_card_authorize_data(Some(get_authentication_data(Some("05"))))
+        ));
+        assert!(!is_auth_setup_required(
+            enums::AuthenticationType::NoThreeDs,
+            &get_card_authorize_data(None)
+        ));
+    }
+}
@@ -10,7 +10,7 @@ use common_utils::{
 use diesel_models::enums;
 use error_stack::{report, ResultExt};
 use hex::encode;
-use masking::PeekInterface;
+use masking::{ExposeInterface, PeekInterface, PlaintextBuffer};
 use transformers as dlocal;
 
 use crate::{
@@ -27,7 +27,7 @@ use crate::{
     types::{
         self,
         api::{self, ConnectorCommon, ConnectorCommonExt},
-        ErrorResponse, Response,
+        domain, ErrorResponse, Response,
     },
     utils::BytesExt,
 };
@@ -63,12 +63,13 @@ where
         let date = date_time::date_as_yyyymmddthhmmssmmmz()
             .change_context(errors::ConnectorError::RequestEncodingFailed)?;
         let auth = dlocal::DlocalAuthType::try_from(&req.connector_auth_type)?;
-        let sign_req: String = format!(
+        let request_payload = PlaintextBuffer::new(dlocal_req.get_inner_value().expose());
+        let sign_req = PlaintextBuffer::new(format!(
             "{}{}{}",
             auth.x_login.peek(),
             date,
-            dlocal_req.get_inner_value().peek().to_owned()
-        );
+            request_payload.as_str()
+        ));
         let authz = crypto::HmacSha256::sign_message(
             &crypto::HmacSha256,
             auth.secret.peek().as_bytes(),
@@ -216,10 +217,15 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
 
     fn get_url(
         &self,
-        _req: &types::PaymentsAuthorizeRouterData,
+        req: &types::PaymentsAuthorizeRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Ok(format!("{}secure_payments", self.base_url(connectors)))
+        match req.request.payment_method_data {
+            domain::PaymentMethodData::Card(_) => {
+                Ok(format!("{}secure_payments", self.base_url(connectors)))
+            }
+            _ => Ok(format!("{}payments", self.base_url(connectors))),
+        }
     }
 
     fn get_request_body(
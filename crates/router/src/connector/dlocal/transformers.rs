@@ -1,5 +1,5 @@
-use api_models::payments::AddressDetails;
-use common_utils::pii::Email;
+use api_models::payments::{AddressDetails, QrCodeInformation, VoucherNextStepData};
+use common_utils::{ext_traits::Encode, pii::Email, types::ExpiresAt};
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
@@ -10,6 +10,7 @@ use crate::{
     core::errors,
     services,
     types::{self, api, domain, storage::enums},
+    utils as crate_utils,
 };
 
 #[derive(Debug, Default, Eq, PartialEq, Serialize)]
@@ -41,6 +42,12 @@ pub struct ThreeDSecureReqData {
 pub enum PaymentMethodId {
     #[default]
     Card,
+    #[serde(rename = "PQ")]
+    Pix,
+    #[serde(rename = "BL")]
+    Boleto,
+    #[serde(rename = "OX")]
+    Oxxo,
 }
 
 #[derive(Debug, Serialize, Default, Deserialize, Clone, Eq, PartialEq)]
@@ -144,9 +151,14 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
                         installments: item
                             .router_data
                             .request
-                            .mandate_id
-                            .clone()
-                            .map(|_| "1".to_string()),
+                            .installment_tenure
+                            .map(|installment_tenure| installment_tenure.to_string())
+                            .or(item
+                                .router_data
+                                .request
+                                .mandate_id
+                                .clone()
+                                .map(|_| "1".to_string())),
                     }),
                     order_id: item.router_data.connector_request_reference_id.clone(),
                     three_dsecure: match item.router_data.auth_type {
@@ -160,19 +172,94 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
                 };
                 Ok(payment_request)
             }
+            domain::PaymentMethodData::BankTransfer(ref bank_transfer_data) => {
+                match bank_transfer_data.as_ref() {
+                    domain::BankTransferData::Pix {} => Self::try_from_alternative_payment_method(
+                        item,
+                        PaymentMethodId::Pix,
+                        Payer {
+                            name,
+                            email,
+                            document: get_doc_from_currency(country.to_string()),
+                        },
+                        country.to_string(),
+                    ),
+                    domain::BankTransferData::AchBankTransfer { .. }
+                    | domain::BankTransferData::SepaBankTransfer { .. }
+                    | domain::BankTransferData::BacsBankTransfer { .. }
+                    | domain::BankTransferData::MultibancoBankTransfer { .. }
+                    | domain::BankTransferData::PermataBankTransfer { .. }
+                    | domain::BankTransferData::BcaBankTransfer { .. }
+                    | domain::BankTransferData::BniVaBankTransfer { .. }
+                    | domain::BankTransferData::BriVaBankTransfer { .. }
+                    | domain::BankTransferData::CimbVaBankTransfer { .. }
+                    | domain::BankTransferData::DanamonVaBankTransfer { .. }
+                    | domain::BankTransferData::MandiriVaBankTransfer { .. }
+                    | domain::BankTransferData::Pse {}
+                    | domain::BankTransferData::LocalBankTransfer { .. } => {
+                        Err(errors::ConnectorError::NotImplemented(
+                            crate::connector::utils::get_unimplemented_payment_method_error_message(
+                                "Dlocal",
+                            ),
+                        ))?
+                    }
+                }
+            }
+            domain::PaymentMethodData::Voucher(ref voucher_data) => match voucher_data {
+                domain::VoucherData::Boleto(boleto_data) => {
+                    Self::try_from_alternative_payment_method(
+                        item,
+                        PaymentMethodId::Boleto,
+                        Payer {
+                            name,
+                            email,
+                            document: boleto_data
+                                .social_security_number
+                                .clone()
+                                .unwrap_or_else(|| get_doc_from_currency(country.to_string())),
+                        },
+                        country.to_string(),
+                    )
+                }
+                domain::VoucherData::Oxxo => Self::try_from_alternative_payment_method(
+                    item,
+                    PaymentMethodId::Oxxo,
+                    Payer {
+                        name,
+                        email,
+                        document: get_doc_from_currency(country.to_string()),
+                    },
+                    country.to_string(),
+                ),
+                domain::VoucherData::Efecty
+                | domain::VoucherData::PagoEfectivo
+                | domain::VoucherData::RedCompra
+                | domain::VoucherData::RedPagos
+                | domain::VoucherData::Alfamart(_)
+                | domain::VoucherData::Indomaret(_)
+                | domain::VoucherData::SevenEleven(_)
+                | domain::VoucherData::Lawson(_)
+                | domain::VoucherData::MiniStop(_)
+                | domain::VoucherData::FamilyMart(_)
+                | domain::VoucherData::Seicomart(_)
+                | domain::VoucherData::PayEasy(_) => Err(errors::ConnectorError::NotImplemented(
+                    crate::connector::utils::get_unimplemented_payment_method_error_message(
+                        "Dlocal",
+                    ),
+                ))?,
+            },
             domain::PaymentMethodData::CardRedirect(_)
             | domain::PaymentMethodData::Wallet(_)
             | domain::PaymentMethodData::PayLater(_)
             | domain::PaymentMethodData::BankRedirect(_)
             | domain::PaymentMethodData::BankDebit(_)
-            | domain::PaymentMethodData::BankTransfer(_)
             | domain::PaymentMethodData::Crypto(_)
             | domain::PaymentMethodData::MandatePayment
             | domain::PaymentMethodData::Reward
             | domain::PaymentMethodData::Upi(_)
-            | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     crate::connector::utils::get_unimplemented_payment_method_error_message(
                         "Dlocal",
@@ -183,6 +270,31 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
     }
 }
 
+impl DlocalPaymentsRequest {
+    /// Alternative payment methods are always processed in the direct flow, the customer
+    /// completes the payment using the voucher or QR code returned in the response
+    fn try_from_alternative_payment_method(
+        item: &DlocalRouterData<&types::PaymentsAuthorizeRouterData>,
+        payment_method_id: PaymentMethodId,
+        payer: Payer,
+        country: String,
+    ) -> Result<Self, error_stack::Report<errors::ConnectorError>> {
+        Ok(Self {
+            amount: item.amount,
+            currency: item.router_data.request.currency,
+            country,
+            payment_method_id,
+            payment_method_flow: PaymentMethodFlow::Direct,
+            payer,
+            card: None,
+            order_id: item.router_data.connector_request_reference_id.clone(),
+            three_dsecure: None,
+            callback_url: Some(item.router_data.request.get_router_return_url()?),
+            description: item.router_data.description.clone(),
+        })
+    }
+}
+
 fn get_payer_name(address: &AddressDetails) -> Option<Secret<String>> {
     let first_name = address
         .first_name
@@ -305,12 +417,74 @@ pub struct ThreeDSecureResData {
     pub redirect_url: Option<Url>,
 }
 
+#[derive(Eq, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DlocalTicket {
+    /// Reference of the voucher, which is the digitable line for Boleto
+    number: String,
+    /// Time until which the voucher can be paid, like `2024-05-15T20:20:20.000+0000`
+    expiration_date: Option<String>,
+    /// Printable version of the voucher
+    image_url: Option<Url>,
+}
+
+#[derive(Eq, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DlocalPixData {
+    /// Pix copy and paste code, which is rendered as a QR code for the customer to scan
+    qr_code: Option<String>,
+}
+
 #[derive(Debug, Default, Eq, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DlocalPaymentsResponse {
     status: DlocalPaymentStatus,
     id: String,
     three_dsecure: Option<ThreeDSecureResData>,
     order_id: Option<String>,
+    ticket: Option<DlocalTicket>,
+    pix: Option<DlocalPixData>,
+}
+
+fn get_expiry_from_dlocal_date(date: &str) -> Option<ExpiresAt> {
+    let format = time::format_description::parse(
+        "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond][offset_hour sign:mandatory][offset_minute]",
+    )
+    .ok()?;
+
+    ExpiresAt::parse_with_format(date, &format).ok()
+}
+
+fn get_next_action_metadata(
+    response: &DlocalPaymentsResponse,
+) -> Result<Option<serde_json::Value>, error_stack::Report<errors::ConnectorError>> {
+    if let Some(ticket) = &response.ticket {
+        let voucher_data = VoucherNextStepData {
+            expires_at: ticket
+                .expiration_date
+                .as_deref()
+                .and_then(get_expiry_from_dlocal_date),
+            reference: ticket.number.clone(),
+            download_url: None,
+            instructions_url: ticket.image_url.clone(),
+        };
+
+        Some(voucher_data.encode_to_value())
+            .transpose()
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    } else if let Some(qr_code) = response.pix.as_ref().and_then(|pix| pix.qr_code.clone()) {
+        let image_data = crate_utils::QrImage::new_from_data(qr_code)
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+        let image_data_url = Url::parse(image_data.data.as_str())
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+        let qr_code_info = QrCodeInformation::QrDataUrl {
+            image_data_url,
+            display_to_timestamp: None,
+        };
+
+        Some(qr_code_info.encode_to_value())
+            .transpose()
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    } else {
+        Ok(None)
+    }
 }
 
 impl<F, T>
@@ -321,6 +495,7 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, DlocalPaymentsResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
+        let connector_metadata = get_next_action_metadata(&item.response)?;
         let redirection_data = item
             .response
             .three_dsecure
@@ -333,7 +508,7 @@ impl<F, T>
             resource_id: types::ResponseId::ConnectorTransactionId(item.response.id.clone()),
             redirection_data,
             mandate_reference: None,
-            connector_metadata: None,
+            connector_metadata,
             network_txn_id: None,
             connector_response_reference_id: item.response.order_id.clone(),
             incremental_authorization_allowed: None,
@@ -6,7 +6,7 @@ use base64::Engine;
 use common_utils::request::RequestContent;
 use diesel_models::enums;
 use error_stack::{report, ResultExt};
-use masking::{ExposeInterface, PeekInterface};
+use masking::{ExposeInterface, PeekInterface, PlaintextBuffer};
 use ring::hmac;
 use time::OffsetDateTime;
 use transformers as fiserv;
@@ -78,7 +78,7 @@ where
             .generate_authorization_signature(
                 auth,
                 &client_request_id,
-                fiserv_req.get_inner_value().peek(),
+                &PlaintextBuffer::new(fiserv_req.get_inner_value().expose()),
                 timestamp,
             )
             .change_context(errors::ConnectorError::RequestEncodingFailed)?;
@@ -52,6 +52,8 @@ pub struct FiservPaymentsRequest {
     transaction_details: TransactionDetails,
     merchant_details: MerchantDetails,
     transaction_interaction: TransactionInteraction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_data: Option<OrderData>,
 }
 
 #[derive(Debug, Serialize)]
@@ -90,6 +92,55 @@ pub struct Amount {
     #[serde(serialize_with = "utils::str_to_f32")]
     total: String,
     currency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<AmountComponents>,
+}
+
+/// Amounts of the level 2 / level 3 data of a card payment, which are included in its total
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmountComponents {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    local_tax: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping_amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duty: Option<f64>,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_order_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ship_from_postal_code: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    item_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_line_items: Option<Vec<OrderLineItem>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderLineItem {
+    item_sequence_number: usize,
+    item_description: String,
+    quantity: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measurement: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    item_commodity_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    item_product_code: Option<String>,
+    unit_price: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<f64>,
+    line_item_total: f64,
 }
 
 #[derive(Default, Debug, Serialize)]
@@ -140,9 +191,11 @@ impl TryFrom<&FiservRouterData<&types::PaymentsAuthorizeRouterData>> for FiservP
         item: &FiservRouterData<&types::PaymentsAuthorizeRouterData>,
     ) -> Result<Self, Self::Error> {
         let auth: FiservAuthType = FiservAuthType::try_from(&item.router_data.connector_auth_type)?;
+        let (components, order_data) = get_level2_and_level3_data(item.router_data)?;
         let amount = Amount {
             total: item.amount.clone(),
             currency: item.router_data.request.currency.to_string(),
+            components,
         };
         let transaction_details = TransactionDetails {
             capture_flag: Some(matches!(
@@ -194,7 +247,8 @@ impl TryFrom<&FiservRouterData<&types::PaymentsAuthorizeRouterData>> for FiservP
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("fiserv"),
                 ))
@@ -206,10 +260,73 @@ impl TryFrom<&FiservRouterData<&types::PaymentsAuthorizeRouterData>> for FiservP
             transaction_details,
             merchant_details,
             transaction_interaction,
+            order_data,
         })
     }
 }
 
+fn get_level2_and_level3_data(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<
+    (Option<AmountComponents>, Option<OrderData>),
+    error_stack::Report<errors::ConnectorError>,
+> {
+    let Some(l2_l3_data) = item.request.l2_l3_data.as_ref() else {
+        return Ok((None, None));
+    };
+    let currency = item.request.currency;
+    let to_base_unit = |amount: Option<i64>| {
+        amount
+            .map(|amount| utils::to_currency_base_unit_asf64(amount, currency))
+            .transpose()
+    };
+
+    let components = AmountComponents {
+        local_tax: to_base_unit(l2_l3_data.tax_amount)?,
+        discount: to_base_unit(l2_l3_data.discount_amount)?,
+        shipping_amount: to_base_unit(l2_l3_data.shipping_amount)?,
+        duty: to_base_unit(l2_l3_data.duty_amount)?,
+    };
+    let order_line_items = l2_l3_data
+        .line_items
+        .as_ref()
+        .map(|line_items| {
+            line_items
+                .iter()
+                .enumerate()
+                .map(|(index, line_item)| {
+                    Ok(OrderLineItem {
+                        item_sequence_number: index + 1,
+                        item_description: line_item.product_name.clone(),
+                        quantity: line_item.quantity,
+                        unit_of_measurement: line_item.unit_of_measure.clone(),
+                        item_commodity_code: line_item.commodity_code.clone(),
+                        item_product_code: line_item.product_code.clone(),
+                        unit_price: utils::to_currency_base_unit_asf64(
+                            line_item.unit_amount,
+                            currency,
+                        )?,
+                        tax_amount: to_base_unit(line_item.tax_amount)?,
+                        discount_amount: to_base_unit(line_item.discount_amount)?,
+                        line_item_total: utils::to_currency_base_unit_asf64(
+                            line_item.get_total_amount(),
+                            currency,
+                        )?,
+                    })
+                })
+                .collect::<Result<Vec<_>, error_stack::Report<errors::ConnectorError>>>()
+        })
+        .transpose()?;
+    let order_data = OrderData {
+        purchase_order_number: l2_l3_data.purchase_order_number.clone(),
+        ship_from_postal_code: l2_l3_data.ship_from_postal_code.clone().map(Secret::new),
+        item_count: order_line_items.as_ref().map(Vec::len),
+        order_line_items,
+    };
+
+    Ok((Some(components), Some(order_data)))
+}
+
 pub struct FiservAuthType {
     pub(super) api_key: Secret<String>,
     pub(super) merchant_account: Secret<String>,
@@ -476,6 +593,7 @@ impl TryFrom<&FiservRouterData<&types::PaymentsCaptureRouterData>> for FiservCap
             amount: Amount {
                 total: item.amount.clone(),
                 currency: item.router_data.request.currency.to_string(),
+                components: None,
             },
             transaction_details: TransactionDetails {
                 capture_flag: Some(true),
@@ -571,6 +689,7 @@ impl<F> TryFrom<&FiservRouterData<&types::RefundsRouterData<F>>> for FiservRefun
             amount: Amount {
                 total: item.amount.clone(),
                 currency: item.router_data.request.currency.to_string(),
+                components: None,
             },
             merchant_details: MerchantDetails {
                 merchant_id: auth.merchant_account,
@@ -817,6 +817,15 @@ impl api::IncomingWebhook for Gocardless {
                 transformers::PaymentsAction::Confirmed | transformers::PaymentsAction::PaidOut => {
                     api::IncomingWebhookEvent::PaymentIntentSuccess
                 }
+                transformers::PaymentsAction::ChargedBack => {
+                    api::IncomingWebhookEvent::DisputeOpened
+                }
+                transformers::PaymentsAction::ChargebackCancelled => {
+                    api::IncomingWebhookEvent::DisputeCancelled
+                }
+                transformers::PaymentsAction::ChargebackSettled => {
+                    api::IncomingWebhookEvent::DisputeLost
+                }
                 transformers::PaymentsAction::SurchargeFeeDebited
                 | transformers::PaymentsAction::ResubmissionRequired => {
                     api::IncomingWebhookEvent::EventNotSupported
@@ -870,6 +879,9 @@ impl api::IncomingWebhook for Gocardless {
             .ok_or_else(|| errors::ConnectorError::WebhookReferenceIdNotFound)?
             .clone();
         match first_event.resource_type {
+            transformers::WebhookResourceType::Payments if first_event.is_chargeback_event() => {
+                Ok(Box::new(first_event))
+            }
             transformers::WebhookResourceType::Payments => Ok(Box::new(
                 gocardless::GocardlessPaymentsResponse::try_from(&first_event)?,
             )),
@@ -877,4 +889,19 @@ impl api::IncomingWebhook for Gocardless {
             | transformers::WebhookResourceType::Mandates => Ok(Box::new(first_event)),
         }
     }
+
+    fn get_dispute_details(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::disputes::DisputePayload, errors::ConnectorError> {
+        let details: gocardless::GocardlessWebhookEvent = request
+            .body
+            .parse_struct("GocardlessWebhookEvent")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        let first_event = details
+            .events
+            .first()
+            .ok_or_else(|| errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        api::disputes::DisputePayload::try_from(first_event)
+    }
 }
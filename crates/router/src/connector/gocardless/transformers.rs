@@ -5,6 +5,7 @@ use api_models::{
 use common_utils::pii::{self, IpAddress};
 use masking::{ExposeInterface, Secret};
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 
 use crate::{
     connector::utils::{
@@ -110,7 +111,8 @@ impl TryFrom<&types::ConnectorCustomerRouterData> for GocardlessCustomerRequest
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Gocardless"),
                 ))
@@ -300,7 +302,8 @@ impl TryFrom<&types::TokenizationRouterData> for CustomerBankAccount {
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Gocardless"),
                 )
@@ -489,7 +492,8 @@ impl TryFrom<&types::SetupMandateRouterData> for GocardlessMandateRequest {
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     "Setup Mandate flow for selected payment method through Gocardless".to_string(),
                 ))
@@ -498,7 +502,10 @@ impl TryFrom<&types::SetupMandateRouterData> for GocardlessMandateRequest {
         let payment_method_token = item.get_payment_method_token()?;
         let customer_bank_account = match payment_method_token {
             types::PaymentMethodToken::Token(token) => Ok(token),
-            types::PaymentMethodToken::ApplePayDecrypt(_) => {
+            types::PaymentMethodToken::ApplePayDecrypt(_)
+            | types::PaymentMethodToken::GooglePayDecrypt(_)
+            | types::PaymentMethodToken::SamsungPayDecrypt(_)
+            | types::PaymentMethodToken::NetworkToken(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     "Setup Mandate flow for selected payment method through Gocardless".to_string(),
                 ))
@@ -523,11 +530,18 @@ fn get_ip_if_required(
     bank_debit_data: &domain::BankDebitData,
     item: &types::SetupMandateRouterData,
 ) -> Result<Option<Secret<String, IpAddress>>, error_stack::Report<errors::ConnectorError>> {
-    let ip_address = item.request.get_browser_info()?.get_ip_address()?;
     match bank_debit_data {
-        domain::BankDebitData::AchBankDebit { .. } => Ok(Some(ip_address)),
-        domain::BankDebitData::SepaBankDebit { .. }
-        | domain::BankDebitData::BecsBankDebit { .. }
+        domain::BankDebitData::AchBankDebit { .. } => {
+            Ok(Some(item.request.get_browser_info()?.get_ip_address()?))
+        }
+        // The IP address is a part of the record of SEPA mandates accepted online by the customer
+        domain::BankDebitData::SepaBankDebit { .. } => Ok(item
+            .request
+            .customer_acceptance
+            .as_ref()
+            .and_then(|customer_acceptance| customer_acceptance.online.as_ref())
+            .and_then(|online| online.ip_address.clone())),
+        domain::BankDebitData::BecsBankDebit { .. }
         | domain::BankDebitData::BacsBankDebit { .. } => Ok(None),
     }
 }
@@ -608,6 +622,7 @@ pub struct GocardlessPayment {
     amount: i64,
     currency: enums::Currency,
     description: Option<String>,
+    charge_date: Option<String>,
     metadata: PaymentMetaData,
     links: PaymentLink,
 }
@@ -640,10 +655,25 @@ impl TryFrom<&GocardlessRouterData<&types::PaymentsAuthorizeRouterData>>
             )
             .into())
         }?;
+        // The debit is delayed for the customer to be notified of it the agreed number of days in
+        // advance, GoCardless otherwise charges it on the earliest possible date
+        let charge_date = item
+            .router_data
+            .recurring_mandate_payment_data
+            .as_ref()
+            .and_then(|recurring_mandate_payment_data| {
+                recurring_mandate_payment_data.pre_notification_days
+            })
+            .map(|days| {
+                (common_utils::date_time::now() + time::Duration::days(i64::from(days)))
+                    .date()
+                    .to_string()
+            });
         let payments = GocardlessPayment {
             amount: item.router_data.request.amount,
             currency: item.router_data.request.currency,
             description: item.router_data.description.clone(),
+            charge_date,
             metadata: PaymentMetaData {
                 payment_reference: item.router_data.connector_request_reference_id.clone(),
             },
@@ -683,6 +713,7 @@ pub enum GocardlessPaymentStatus {
     Cancelled,
     CustomerApprovalDenied,
     Failed,
+    ChargedBack,
 }
 
 impl From<GocardlessPaymentStatus> for enums::AttemptStatus {
@@ -691,7 +722,10 @@ impl From<GocardlessPaymentStatus> for enums::AttemptStatus {
             GocardlessPaymentStatus::PendingCustomerApproval
             | GocardlessPaymentStatus::PendingSubmission
             | GocardlessPaymentStatus::Submitted => Self::Pending,
-            GocardlessPaymentStatus::Confirmed | GocardlessPaymentStatus::PaidOut => Self::Charged,
+            // A charged back payment was charged, the chargeback is tracked as a dispute
+            GocardlessPaymentStatus::Confirmed
+            | GocardlessPaymentStatus::PaidOut
+            | GocardlessPaymentStatus::ChargedBack => Self::Charged,
             GocardlessPaymentStatus::Cancelled => Self::Voided,
             GocardlessPaymentStatus::CustomerApprovalDenied => Self::AuthenticationFailed,
             GocardlessPaymentStatus::Failed => Self::Failure,
@@ -878,6 +912,17 @@ pub struct WebhookEvent {
     pub resource_type: WebhookResourceType,
     pub action: WebhookAction,
     pub links: WebhooksLink,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_at: Option<PrimitiveDateTime>,
+    pub details: Option<WebhookEventDetails>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WebhookEventDetails {
+    pub cause: Option<String>,
+    pub description: Option<String>,
+    /// The scheme reason code of the event, such as the SEPA R-transaction reason code
+    pub reason_code: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -910,6 +955,10 @@ pub enum PaymentsAction {
     Failed,
     Cancelled,
     ResubmissionRequired,
+    // Payments charged back by the bank of the customer
+    ChargedBack,
+    ChargebackCancelled,
+    ChargebackSettled,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -999,7 +1048,12 @@ impl TryFrom<&WebhookAction> for GocardlessPaymentStatus {
                 PaymentsAction::PaidOut => Ok(Self::PaidOut),
                 PaymentsAction::SurchargeFeeDebited
                 | PaymentsAction::ResubmissionRequired
-                | PaymentsAction::Created => Err(errors::ConnectorError::WebhookEventTypeNotFound)?,
+                | PaymentsAction::Created
+                | PaymentsAction::ChargedBack
+                | PaymentsAction::ChargebackCancelled
+                | PaymentsAction::ChargebackSettled => {
+                    Err(errors::ConnectorError::WebhookEventTypeNotFound)?
+                }
             },
             WebhookAction::RefundsAction(_) | WebhookAction::MandatesAction(_) => {
                 Err(errors::ConnectorError::WebhookEventTypeNotFound)?
@@ -1007,3 +1061,64 @@ impl TryFrom<&WebhookAction> for GocardlessPaymentStatus {
         }
     }
 }
+
+impl WebhookEvent {
+    pub fn is_chargeback_event(&self) -> bool {
+        matches!(
+            self.action,
+            WebhookAction::PaymentsAction(
+                PaymentsAction::ChargedBack
+                    | PaymentsAction::ChargebackCancelled
+                    | PaymentsAction::ChargebackSettled
+            )
+        )
+    }
+}
+
+impl TryFrom<&WebhookEvent> for api::disputes::DisputePayload {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &WebhookEvent) -> Result<Self, Self::Error> {
+        // GoCardless does not identify chargebacks separately, a payment is charged back in full
+        // at most once
+        let payment_id = match &item.links {
+            WebhooksLink::PaymentWebhooksLink(link) => link.payment.to_owned(),
+            WebhooksLink::RefundWebhookLink(_) | WebhooksLink::MandateWebhookLink(_) => {
+                Err(errors::ConnectorError::WebhookReferenceIdNotFound)?
+            }
+        };
+        let connector_status = match &item.action {
+            WebhookAction::PaymentsAction(PaymentsAction::ChargedBack) => "charged_back",
+            WebhookAction::PaymentsAction(PaymentsAction::ChargebackCancelled) => {
+                "chargeback_cancelled"
+            }
+            WebhookAction::PaymentsAction(PaymentsAction::ChargebackSettled) => {
+                "chargeback_settled"
+            }
+            WebhookAction::PaymentsAction(_)
+            | WebhookAction::RefundsAction(_)
+            | WebhookAction::MandatesAction(_) => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound)?
+            }
+        };
+        Ok(Self {
+            // The amount of the payment is not a part of the event, chargebacks are always for
+            // the full amount of the payment
+            amount: String::new(),
+            currency: String::new(),
+            dispute_stage: api_models::enums::DisputeStage::Dispute,
+            connector_status: connector_status.to_string(),
+            connector_dispute_id: payment_id,
+            connector_reason: item
+                .details
+                .as_ref()
+                .and_then(|details| details.description.clone()),
+            connector_reason_code: item
+                .details
+                .as_ref()
+                .and_then(|details| details.reason_code.clone()),
+            challenge_required_by: None,
+            created_at: item.created_at,
+            updated_at: None,
+        })
+    }
+}
@@ -145,7 +145,8 @@ impl
             | PaymentMethod::BankDebit
             | PaymentMethod::Reward
             | PaymentMethod::Voucher
-            | PaymentMethod::GiftCard => item.router_data.get_billing_country()?.to_string(),
+            | PaymentMethod::GiftCard
+            | PaymentMethod::RealTimePayment => item.router_data.get_billing_country()?.to_string(),
         };
         let return_url = item.router_data.get_return_url()?;
         let payer_info = match item.router_data.request.payment_method_data.clone() {
@@ -164,7 +165,8 @@ impl
             | domain::PaymentMethodData::Reward
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => None,
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => None,
         };
         let payload = Self {
             merchant_id: IatapayAuthType::try_from(&item.router_data.connector_auth_type)?
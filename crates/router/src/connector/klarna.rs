@@ -346,6 +346,7 @@ impl
                         | common_enums::PaymentMethodType::CardRedirect
                         | common_enums::PaymentMethodType::CimbVa
                         | common_enums::PaymentMethodType::ClassicReward
+                        | common_enums::PaymentMethodType::ClickToPay
                         | common_enums::PaymentMethodType::Credit
                         | common_enums::PaymentMethodType::CryptoCurrency
                         | common_enums::PaymentMethodType::Cashapp
@@ -392,6 +393,8 @@ impl
                         | common_enums::PaymentMethodType::RedPagos
                         | common_enums::PaymentMethodType::SamsungPay
                         | common_enums::PaymentMethodType::Sepa
+                        | common_enums::PaymentMethodType::Skrill
+                        | common_enums::PaymentMethodType::Payoneer
                         | common_enums::PaymentMethodType::Sofort
                         | common_enums::PaymentMethodType::Swish
                         | common_enums::PaymentMethodType::TouchNGo
@@ -408,7 +411,10 @@ impl
                         | common_enums::PaymentMethodType::MiniStop
                         | common_enums::PaymentMethodType::FamilyMart
                         | common_enums::PaymentMethodType::Seicomart
-                        | common_enums::PaymentMethodType::PayEasy,
+                        | common_enums::PaymentMethodType::PayEasy
+                        | common_enums::PaymentMethodType::PayNow
+                        | common_enums::PaymentMethodType::PromptPay
+                        | common_enums::PaymentMethodType::UpiQr,
                     ) => Err(error_stack::report!(errors::ConnectorError::NotSupported {
                         message: payment_method_type.to_string(),
                         connector: "klarna",
@@ -429,7 +435,8 @@ impl
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => Err(error_stack::report!(
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => Err(error_stack::report!(
                 errors::ConnectorError::MismatchedPaymentData
             )),
         }
@@ -184,6 +184,15 @@ impl TryFrom<&MollieRouterData<&types::PaymentsAuthorizeRouterData>> for MollieP
                                             "Mollie"
                                         ))?
                                     }
+                                    types::PaymentMethodToken::GooglePayDecrypt(_) => {
+                                        Err(unimplemented_payment_method!("Google Pay", "Mollie"))?
+                                    }
+                                    types::PaymentMethodToken::SamsungPayDecrypt(_) => {
+                                        Err(unimplemented_payment_method!("Samsung Pay", "Mollie"))?
+                                    }
+                                    types::PaymentMethodToken::NetworkToken(_) => Err(
+                                        unimplemented_payment_method!("Network Token", "Mollie"),
+                                    )?,
                                 })),
                             },
                         )))
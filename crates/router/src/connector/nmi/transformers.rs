@@ -583,7 +583,9 @@ impl
                 | domain::WalletData::WeChatPayRedirect(_)
                 | domain::WalletData::WeChatPayQr(_)
                 | domain::WalletData::CashappQr(_)
-                | domain::WalletData::SwishQr(_) => {
+                | domain::WalletData::SwishQr(_)
+                | domain::WalletData::ClickToPay(_)
+                | domain::WalletData::SkrillRedirect {} => {
                     Err(report!(errors::ConnectorError::NotImplemented(
                         utils::get_unimplemented_payment_method_error_message("nmi"),
                     )))
@@ -600,7 +602,8 @@ impl
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("nmi"),
                 )
@@ -631,7 +634,11 @@ impl TryFrom<(&domain::payments::Card, &types::PaymentsAuthorizeData)> for Payme
             eci: auth_data.eci.clone(),
             cardholder_auth: None,
             three_ds_version: Some(auth_data.message_version.clone()),
-            directory_server_id: Some(auth_data.threeds_server_transaction_id.clone().into()),
+            directory_server_id: auth_data
+                .ds_trans_id
+                .clone()
+                .or_else(|| auth_data.threeds_server_transaction_id.clone())
+                .map(Into::into),
         };
 
         Ok(Self::CardThreeDs(Box::new(card_3ds_details)))
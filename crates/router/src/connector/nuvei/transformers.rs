@@ -11,6 +11,8 @@ use masking::{ExposeInterface, PeekInterface, Secret};
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "payouts")]
+use crate::connector::utils::{CustomerDetails, PayoutsData};
 use crate::{
     connector::utils::{
         self, AddressDetailsData, BrowserInformationData, PaymentsAuthorizeRequestData,
@@ -79,6 +81,8 @@ pub struct NuveiPaymentsRequest {
     pub billing_address: Option<BillingAddress>,
     pub related_transaction_id: Option<String>,
     pub url_details: Option<UrlDetails>,
+    /// The number of installments the card payment is paid in
+    pub installments: Option<String>,
 }
 
 #[derive(Debug, Serialize, Default)]
@@ -173,6 +177,9 @@ pub struct AlternativePaymentMethod {
     pub payment_method: AlternativePaymentMethodType,
     #[serde(rename = "BIC")]
     pub bank_id: Option<NuveiBIC>,
+    /// Email of the Skrill account of the customer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<Email>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -193,6 +200,8 @@ pub enum AlternativePaymentMethodType {
     AfterPay,
     #[serde(rename = "apmgw_Klarna")]
     Klarna,
+    #[serde(rename = "apmgw_MoneyBookers")]
+    Skrill,
 }
 
 #[serde_with::skip_serializing_none]
@@ -653,7 +662,8 @@ impl<F>
                 }),
                 None,
             ),
-            (AlternativePaymentMethodType::Giropay, _) => (
+            (AlternativePaymentMethodType::Giropay, _)
+            | (AlternativePaymentMethodType::Skrill, _) => (
                 Some(BillingAddress {
                     email: item.request.get_email()?,
                     country: item.get_billing_country()?,
@@ -696,11 +706,16 @@ impl<F>
                 utils::get_unimplemented_payment_method_error_message("Nuvei"),
             ))?,
         };
+        let account_id = match payment_method {
+            AlternativePaymentMethodType::Skrill => Some(item.request.get_email()?),
+            _ => None,
+        };
         Ok(Self {
             payment_option: PaymentOption {
                 alternative_payment_method: Some(AlternativePaymentMethod {
                     payment_method,
                     bank_id,
+                    account_id,
                 }),
                 ..Default::default()
             },
@@ -764,6 +779,9 @@ impl<F>
                     None,
                     item,
                 )),
+                domain::WalletData::SkrillRedirect {} => {
+                    Self::foreign_try_from((AlternativePaymentMethodType::Skrill, None, item))
+                }
                 domain::WalletData::AliPayQr(_)
                 | domain::WalletData::AliPayRedirect(_)
                 | domain::WalletData::AliPayHkRedirect(_)
@@ -786,6 +804,7 @@ impl<F>
                 | domain::WalletData::WeChatPayRedirect(_)
                 | domain::WalletData::CashappQr(_)
                 | domain::WalletData::SwishQr(_)
+                | domain::WalletData::ClickToPay(_)
                 | domain::WalletData::WeChatPayQr(_) => {
                     Err(errors::ConnectorError::NotImplemented(
                         utils::get_unimplemented_payment_method_error_message("nuvei"),
@@ -860,7 +879,8 @@ impl<F>
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::CardRedirect(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("nuvei"),
                 )
@@ -1006,6 +1026,10 @@ fn get_card_info<F>(
             card_holder_name: item.get_optional_billing_full_name(),
         }),
         billing_address,
+        installments: item
+            .request
+            .installment_tenure
+            .map(|installment_tenure| installment_tenure.to_string()),
         ..Default::default()
     })
 }
@@ -1057,6 +1081,7 @@ impl TryFrom<(&types::PaymentsCompleteAuthorizeRouterData, Secret<String>)>
             | Some(domain::PaymentMethodData::Reward)
             | Some(domain::PaymentMethodData::Upi(..))
             | Some(domain::PaymentMethodData::CardToken(..))
+            | Some(domain::PaymentMethodData::RealTimePayment(..))
             | None => Err(errors::ConnectorError::NotImplemented(
                 utils::get_unimplemented_payment_method_error_message("nuvei"),
             )),
@@ -1719,3 +1744,136 @@ impl From<NuveiWebhookDetails> for NuveiPaymentsResponse {
         }
     }
 }
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NuveiPayoutRequest {
+    pub merchant_id: Secret<String>,
+    pub merchant_site_id: Secret<String>,
+    pub user_token_id: Secret<String>,
+    pub client_unique_id: String,
+    pub client_request_id: String,
+    pub amount: String,
+    pub currency: diesel_models::enums::Currency,
+    pub card_data: Card,
+    pub time_stamp: String,
+    pub checksum: Secret<String>,
+}
+
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for NuveiPayoutRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let card_data = match item.get_payout_method_data()? {
+            api_models::payouts::PayoutMethodData::Card(card) => Card {
+                card_number: Some(card.card_number),
+                card_holder_name: card.card_holder_name,
+                expiration_month: Some(card.expiry_month),
+                expiration_year: Some(card.expiry_year),
+                ..Default::default()
+            },
+            api_models::payouts::PayoutMethodData::Bank(_)
+            | api_models::payouts::PayoutMethodData::Wallet(_) => {
+                Err(errors::ConnectorError::NotImplemented(
+                    utils::get_unimplemented_payment_method_error_message("nuvei"),
+                ))?
+            }
+        };
+        let connector_auth: NuveiAuthType = NuveiAuthType::try_from(&item.connector_auth_type)?;
+        let customer_details = item.request.get_customer_details()?;
+        let client_request_id = item.connector_request_reference_id.clone();
+        let amount =
+            utils::to_currency_base_unit(item.request.amount, item.request.destination_currency)?;
+        let currency = item.request.destination_currency;
+        let time_stamp =
+            date_time::format_date(date_time::now(), date_time::DateFormat::YYYYMMDDHHmmss)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        let checksum = Secret::new(encode_payload(&[
+            connector_auth.merchant_id.peek(),
+            connector_auth.merchant_site_id.peek(),
+            &client_request_id,
+            &amount,
+            &currency.to_string(),
+            &time_stamp,
+            connector_auth.merchant_secret.peek(),
+        ])?);
+        Ok(Self {
+            merchant_id: connector_auth.merchant_id,
+            merchant_site_id: connector_auth.merchant_site_id,
+            user_token_id: Secret::new(customer_details.get_customer_id()?),
+            client_unique_id: item.request.payout_id.clone(),
+            client_request_id,
+            amount,
+            currency,
+            card_data,
+            time_stamp,
+            checksum,
+        })
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NuveiPayoutResponse {
+    pub status: NuveiPaymentStatus,
+    pub transaction_status: Option<NuveiTransactionStatus>,
+    pub transaction_id: Option<String>,
+    pub err_code: Option<i64>,
+    pub reason: Option<String>,
+    pub gw_error_code: Option<i64>,
+    pub gw_error_reason: Option<String>,
+}
+
+#[cfg(feature = "payouts")]
+impl From<NuveiTransactionStatus> for enums::PayoutStatus {
+    fn from(item: NuveiTransactionStatus) -> Self {
+        match item {
+            NuveiTransactionStatus::Approved => Self::Success,
+            NuveiTransactionStatus::Declined | NuveiTransactionStatus::Error => Self::Failed,
+            NuveiTransactionStatus::Redirect
+            | NuveiTransactionStatus::Pending
+            | NuveiTransactionStatus::Processing => Self::Pending,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, NuveiPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, NuveiPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        let response = item.response;
+        let http_code = item.http_code;
+        let response = match response.status {
+            NuveiPaymentStatus::Error => {
+                get_error_response(response.err_code, &response.reason, http_code)
+            }
+            _ => match response.transaction_status {
+                Some(NuveiTransactionStatus::Error) | Some(NuveiTransactionStatus::Declined) => {
+                    get_error_response(response.gw_error_code, &response.gw_error_reason, http_code)
+                }
+                transaction_status => Ok(types::PayoutsResponseData {
+                    status: Some(
+                        transaction_status
+                            .map(enums::PayoutStatus::from)
+                            .unwrap_or(enums::PayoutStatus::Pending),
+                    ),
+                    connector_payout_id: response
+                        .transaction_id
+                        .ok_or(errors::ConnectorError::MissingConnectorTransactionID)?,
+                    payout_eligible: None,
+                    should_add_next_step_to_process_tracker: false,
+                }),
+            },
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
@@ -6,7 +6,7 @@ use base64::Engine;
 use common_utils::request::RequestContent;
 use diesel_models::enums;
 use error_stack::{report, ResultExt};
-use masking::ExposeInterface;
+use masking::{ExposeInterface, PlaintextBuffer};
 use rand::distributions::DistString;
 use ring::hmac;
 use transformers as payeezy;
@@ -44,10 +44,11 @@ where
         connectors: &settings::Connectors,
     ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
         let auth = payeezy::PayeezyAuthType::try_from(&req.connector_auth_type)?;
-        let request_payload = self
-            .get_request_body(req, connectors)?
-            .get_inner_value()
-            .expose();
+        let request_payload = PlaintextBuffer::new(
+            self.get_request_body(req, connectors)?
+                .get_inner_value()
+                .expose(),
+        );
         let timestamp = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .ok()
@@ -55,16 +56,24 @@ where
             .as_millis()
             .to_string();
         let nonce = rand::distributions::Alphanumeric.sample_string(&mut rand::thread_rng(), 19);
-        let signature_string = auth.api_key.clone().zip(auth.merchant_token.clone()).map(
-            |(api_key, merchant_token)| {
-                format!(
-                    "{}{}{}{}{}",
-                    api_key, nonce, timestamp, merchant_token, request_payload
-                )
-            },
+        let signature_string = PlaintextBuffer::new(
+            auth.api_key
+                .clone()
+                .zip(auth.merchant_token.clone())
+                .map(|(api_key, merchant_token)| {
+                    format!(
+                        "{}{}{}{}{}",
+                        api_key,
+                        nonce,
+                        timestamp,
+                        merchant_token,
+                        request_payload.as_str()
+                    )
+                })
+                .expose(),
         );
         let key = hmac::Key::new(hmac::HMAC_SHA256, auth.api_secret.expose().as_bytes());
-        let tag = hmac::sign(&key, signature_string.expose().as_bytes());
+        let tag = hmac::sign(&key, signature_string.as_bytes());
         let hmac_sign = hex::encode(tag);
         let signature_value = consts::BASE64_ENGINE_URL_SAFE.encode(hmac_sign);
         Ok(vec![
@@ -101,6 +101,38 @@ pub struct PayeezyPaymentsRequest {
     pub credit_card: PayeezyPaymentMethod,
     pub stored_credentials: Option<StoredCredentials>,
     pub reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level2: Option<PayeezyLevel2>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level3: Option<PayeezyLevel3>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PayeezyLevel2 {
+    pub tax1_amount: Option<String>,
+    pub customer_ref: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PayeezyLevel3 {
+    pub discount_amount: Option<String>,
+    pub duty_amount: Option<String>,
+    pub freight_amount: Option<String>,
+    pub ship_from_zip: Option<Secret<String>>,
+    pub line_items: Vec<PayeezyLineItem>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PayeezyLineItem {
+    pub description: String,
+    pub quantity: String,
+    pub commodity_code: Option<String>,
+    pub product_code: Option<String>,
+    pub unit_of_measure: Option<String>,
+    pub unit_cost: String,
+    pub tax_amount: Option<String>,
+    pub discount_amount: Option<String>,
+    pub line_item_total: String,
 }
 
 #[derive(Serialize, Debug)]
@@ -143,7 +175,8 @@ impl TryFrom<&PayeezyRouterData<&types::PaymentsAuthorizeRouterData>> for Payeez
             | diesel_models::enums::PaymentMethod::Reward
             | diesel_models::enums::PaymentMethod::Upi
             | diesel_models::enums::PaymentMethod::Voucher
-            | diesel_models::enums::PaymentMethod::GiftCard => {
+            | diesel_models::enums::PaymentMethod::GiftCard
+            | diesel_models::enums::PaymentMethod::RealTimePayment => {
                 Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into())
             }
         }
@@ -160,6 +193,7 @@ fn get_card_specific_payment_data(
     let credit_card = get_payment_method_data(item)?;
     let (transaction_type, stored_credentials) =
         get_transaction_type_and_stored_creds(item.router_data)?;
+    let (level2, level3) = get_level2_and_level3_data(item.router_data)?;
     Ok(PayeezyPaymentsRequest {
         merchant_ref,
         transaction_type,
@@ -169,8 +203,68 @@ fn get_card_specific_payment_data(
         credit_card,
         stored_credentials,
         reference: item.router_data.connector_request_reference_id.clone(),
+        level2,
+        level3,
     })
 }
+
+fn get_level2_and_level3_data(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<
+    (Option<PayeezyLevel2>, Option<PayeezyLevel3>),
+    error_stack::Report<errors::ConnectorError>,
+> {
+    let Some(l2_l3_data) = item.request.l2_l3_data.as_ref() else {
+        return Ok((None, None));
+    };
+    let currency = item.request.currency;
+    let to_base_unit = |amount: Option<i64>| {
+        amount
+            .map(|amount| utils::to_currency_base_unit(amount, currency))
+            .transpose()
+    };
+
+    let level2 = PayeezyLevel2 {
+        tax1_amount: to_base_unit(l2_l3_data.tax_amount)?,
+        customer_ref: l2_l3_data.purchase_order_number.clone(),
+    };
+    // Level 3 data is only accepted along with the line items of the order
+    let level3 = l2_l3_data
+        .line_items
+        .as_ref()
+        .map(|line_items| {
+            let line_items = line_items
+                .iter()
+                .map(|line_item| {
+                    Ok(PayeezyLineItem {
+                        description: line_item.product_name.clone(),
+                        quantity: line_item.quantity.to_string(),
+                        commodity_code: line_item.commodity_code.clone(),
+                        product_code: line_item.product_code.clone(),
+                        unit_of_measure: line_item.unit_of_measure.clone(),
+                        unit_cost: utils::to_currency_base_unit(line_item.unit_amount, currency)?,
+                        tax_amount: to_base_unit(line_item.tax_amount)?,
+                        discount_amount: to_base_unit(line_item.discount_amount)?,
+                        line_item_total: utils::to_currency_base_unit(
+                            line_item.get_total_amount(),
+                            currency,
+                        )?,
+                    })
+                })
+                .collect::<Result<Vec<_>, error_stack::Report<errors::ConnectorError>>>()?;
+            Ok::<_, error_stack::Report<errors::ConnectorError>>(PayeezyLevel3 {
+                discount_amount: to_base_unit(l2_l3_data.discount_amount)?,
+                duty_amount: to_base_unit(l2_l3_data.duty_amount)?,
+                freight_amount: to_base_unit(l2_l3_data.shipping_amount)?,
+                ship_from_zip: l2_l3_data.ship_from_postal_code.clone().map(Secret::new),
+                line_items,
+            })
+        })
+        .transpose()?;
+
+    Ok((Some(level2), level3))
+}
+
 fn get_transaction_type_and_stored_creds(
     item: &types::PaymentsAuthorizeRouterData,
 ) -> Result<
@@ -264,9 +358,12 @@ fn get_payment_method_data(
         | domain::PaymentMethodData::Upi(_)
         | domain::PaymentMethodData::Voucher(_)
         | domain::PaymentMethodData::GiftCard(_)
-        | domain::PaymentMethodData::CardToken(_) => Err(errors::ConnectorError::NotImplemented(
-            utils::get_unimplemented_payment_method_error_message("Payeezy"),
-        ))?,
+        | domain::PaymentMethodData::CardToken(_)
+        | domain::PaymentMethodData::RealTimePayment(_) => {
+            Err(errors::ConnectorError::NotImplemented(
+                utils::get_unimplemented_payment_method_error_message("Payeezy"),
+            ))?
+        }
     }
 }
 
@@ -410,11 +410,15 @@ impl TryFrom<&PaymentMethodData> for SalePaymentMethod {
                 | domain::WalletData::WeChatPayQr(_)
                 | domain::WalletData::CashappQr(_)
                 | domain::WalletData::ApplePay(_)
-                | domain::WalletData::SwishQr(_) => Err(errors::ConnectorError::NotSupported {
-                    message: "Wallet".to_string(),
-                    connector: "payme",
+                | domain::WalletData::SwishQr(_)
+                | domain::WalletData::ClickToPay(_)
+                | domain::WalletData::SkrillRedirect {} => {
+                    Err(errors::ConnectorError::NotSupported {
+                        message: "Wallet".to_string(),
+                        connector: "payme",
+                    }
+                    .into())
                 }
-                .into()),
             },
             PaymentMethodData::PayLater(_)
             | PaymentMethodData::BankRedirect(_)
@@ -427,7 +431,8 @@ impl TryFrom<&PaymentMethodData> for SalePaymentMethod {
             | PaymentMethodData::CardRedirect(_)
             | PaymentMethodData::Upi(_)
             | PaymentMethodData::Voucher(_)
-            | PaymentMethodData::CardToken(_) => {
+            | PaymentMethodData::CardToken(_)
+            | PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into())
             }
         }
@@ -664,7 +669,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PayRequest {
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("payme"),
                 ))?
@@ -703,6 +709,15 @@ impl TryFrom<&types::PaymentsCompleteAuthorizeRouterData> for Pay3dsRequest {
                     types::PaymentMethodToken::ApplePayDecrypt(_) => Err(
                         unimplemented_payment_method!("Apple Pay", "Simplified", "Payme"),
                     )?,
+                    types::PaymentMethodToken::GooglePayDecrypt(_) => {
+                        Err(unimplemented_payment_method!("Google Pay", "Payme"))?
+                    }
+                    types::PaymentMethodToken::SamsungPayDecrypt(_) => {
+                        Err(unimplemented_payment_method!("Samsung Pay", "Payme"))?
+                    }
+                    types::PaymentMethodToken::NetworkToken(_) => {
+                        Err(unimplemented_payment_method!("Network Token", "Payme"))?
+                    }
                 };
                 Ok(Self {
                     buyer_email,
@@ -725,6 +740,7 @@ impl TryFrom<&types::PaymentsCompleteAuthorizeRouterData> for Pay3dsRequest {
             | Some(domain::PaymentMethodData::Voucher(_))
             | Some(domain::PaymentMethodData::GiftCard(_))
             | Some(domain::PaymentMethodData::CardToken(_))
+            | Some(domain::PaymentMethodData::RealTimePayment(_))
             | None => {
                 Err(errors::ConnectorError::NotImplemented("Tokenize Flow".to_string()).into())
             }
@@ -762,7 +778,8 @@ impl TryFrom<&types::TokenizationRouterData> for CaptureBuyerRequest {
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented("Tokenize Flow".to_string()).into())
             }
         }
@@ -0,0 +1,348 @@
+#[cfg(feature = "payouts")]
+use api_models::payouts::{PayoutMethodData, Wallet};
+use base64::Engine;
+use common_enums::Currency;
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils,
+    consts,
+    core::errors,
+    types::{self, api::CurrencyUnit},
+};
+#[cfg(feature = "payouts")]
+use crate::{
+    connector::utils::RouterData,
+    types::{api, storage::enums as storage_enums},
+};
+
+pub struct PayoneerRouterData<T> {
+    pub amount: f64,
+    pub router_data: T,
+}
+
+impl<T> TryFrom<(&CurrencyUnit, Currency, i64, T)> for PayoneerRouterData<T> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (_currency_unit, currency, amount, item): (&CurrencyUnit, Currency, i64, T),
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: utils::to_currency_base_unit_asf64(amount, currency)?,
+            router_data: item,
+        })
+    }
+}
+
+pub struct PayoneerAuthType {
+    pub client_id: Secret<String>,
+    pub program_id: Secret<String>,
+    pub client_secret: Secret<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for PayoneerAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::SignatureKey {
+                api_key,
+                key1,
+                api_secret,
+            } => Ok(Self {
+                client_id: api_key.to_owned(),
+                program_id: key1.to_owned(),
+                client_secret: api_secret.to_owned(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+impl PayoneerAuthType {
+    pub fn generate_authorization_value(&self) -> String {
+        let auth_id = format!("{}:{}", self.client_id.peek(), self.client_secret.peek());
+        format!("Basic {}", consts::BASE64_ENGINE.encode(auth_id))
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PayoneerAuthUpdateRequest {
+    grant_type: String,
+    scope: String,
+}
+
+impl TryFrom<&types::RefreshTokenRouterData> for PayoneerAuthUpdateRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(_item: &types::RefreshTokenRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            grant_type: "client_credentials".to_string(),
+            scope: "read write".to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PayoneerAuthUpdateResponse {
+    pub access_token: Secret<String>,
+    pub token_type: String,
+    pub expires_in: i64,
+}
+
+impl<F, T> TryFrom<types::ResponseRouterData<F, PayoneerAuthUpdateResponse, T, types::AccessToken>>
+    for types::RouterData<F, T, types::AccessToken>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, PayoneerAuthUpdateResponse, T, types::AccessToken>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::AccessToken {
+                token: item.response.access_token,
+                expires: item.response.expires_in,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PayoneerErrorResponse {
+    pub error: String,
+    pub error_description: Option<String>,
+}
+
+#[cfg(feature = "payouts")]
+pub fn get_payee_id<F>(
+    item: &types::PayoutsRouterData<F>,
+) -> Result<Secret<String>, error_stack::Report<errors::ConnectorError>> {
+    match item.get_payout_method_data()? {
+        PayoutMethodData::Wallet(Wallet::Payoneer(payoneer_data)) => Ok(payoneer_data.payee_id),
+        PayoutMethodData::Card(_) | PayoutMethodData::Bank(_) | PayoutMethodData::Wallet(_) => {
+            Err(errors::ConnectorError::NotSupported {
+                message: "Payout Method Not Supported".to_string(),
+                connector: "Payoneer",
+            })?
+        }
+    }
+}
+
+// Payee statuses - https://developer.payoneer.com/docs/mass-payouts-and-services.html
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PayoneerPayeeStatusDescription {
+    Active,
+    Inactive,
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoneerPayeeStatus {
+    #[serde(rename = "type")]
+    status_type: i32,
+    description: PayoneerPayeeStatusDescription,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoneerPayeeStatusResult {
+    status: PayoneerPayeeStatus,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoneerPayeeStatusResponse {
+    result: PayoneerPayeeStatusResult,
+}
+
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, PayoneerPayeeStatusResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, PayoneerPayeeStatusResponse>,
+    ) -> Result<Self, Self::Error> {
+        let payout_eligible = matches!(
+            item.response.result.status.description,
+            PayoneerPayeeStatusDescription::Active
+        );
+        let status = if payout_eligible {
+            storage_enums::PayoutStatus::RequiresFulfillment
+        } else {
+            storage_enums::PayoutStatus::Ineligible
+        };
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                status: Some(status),
+                connector_payout_id: item.data.request.payout_id.clone(),
+                payout_eligible: Some(payout_eligible),
+                should_add_next_step_to_process_tracker: false,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize)]
+pub struct PayoneerPayoutRequest {
+    #[serde(rename = "Payments")]
+    payments: Vec<PayoneerPayment>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize)]
+pub struct PayoneerPayment {
+    client_reference_id: String,
+    payee_id: Secret<String>,
+    description: String,
+    currency: Currency,
+    amount: f64,
+}
+
+#[cfg(feature = "payouts")]
+impl TryFrom<&PayoneerRouterData<&types::PayoutsRouterData<api::PoFulfill>>>
+    for PayoneerPayoutRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &PayoneerRouterData<&types::PayoutsRouterData<api::PoFulfill>>,
+    ) -> Result<Self, Self::Error> {
+        let request = &item.router_data.request;
+        let payment = PayoneerPayment {
+            client_reference_id: request.payout_id.to_owned(),
+            payee_id: get_payee_id(item.router_data)?,
+            description: item
+                .router_data
+                .description
+                .clone()
+                .unwrap_or_else(|| request.payout_id.to_owned()),
+            currency: request.destination_currency,
+            amount: item.amount,
+        };
+        Ok(Self {
+            payments: vec![payment],
+        })
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoneerPayoutResponse {
+    result: String,
+    code: i32,
+}
+
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, PayoneerPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, PayoneerPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        // The payouts submitted are processed asynchronously, their status is updated through
+        // the webhooks sent for the client_reference_id
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                status: Some(storage_enums::PayoutStatus::Pending),
+                connector_payout_id: item.data.request.payout_id.clone(),
+                payout_eligible: None,
+                should_add_next_step_to_process_tracker: false,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PayoneerPayoutStatus {
+    Cancelled,
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoneerCancelResult {
+    payout_id: String,
+    status: PayoneerPayoutStatus,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoneerCancelResponse {
+    result: PayoneerCancelResult,
+}
+
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, PayoneerCancelResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, PayoneerCancelResponse>,
+    ) -> Result<Self, Self::Error> {
+        let status = match item.response.result.status {
+            PayoneerPayoutStatus::Cancelled => storage_enums::PayoutStatus::Cancelled,
+            PayoneerPayoutStatus::Unknown => storage_enums::PayoutStatus::Failed,
+        };
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                status: Some(status),
+                connector_payout_id: item.response.result.payout_id,
+                payout_eligible: None,
+                should_add_next_step_to_process_tracker: false,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayoneerWebhookBody {
+    pub client_reference_id: String,
+    pub status: PayoneerWebhookPayoutStatus,
+}
+
+// Payout statuses - https://developer.payoneer.com/docs/mass-payouts-and-services.html
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+pub enum PayoneerWebhookPayoutStatus {
+    Pending,
+    Funded,
+    #[serde(rename = "Pending Approval")]
+    PendingApproval,
+    Transferred,
+    Deposited,
+    Cancelled,
+    Failed,
+    Refunded,
+    Returned,
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(feature = "payouts")]
+impl From<PayoneerWebhookPayoutStatus> for api_models::webhooks::IncomingWebhookEvent {
+    fn from(status: PayoneerWebhookPayoutStatus) -> Self {
+        match status {
+            PayoneerWebhookPayoutStatus::Pending
+            | PayoneerWebhookPayoutStatus::Funded
+            | PayoneerWebhookPayoutStatus::PendingApproval => Self::PayoutProcessing,
+            PayoneerWebhookPayoutStatus::Transferred | PayoneerWebhookPayoutStatus::Deposited => {
+                Self::PayoutSuccess
+            }
+            PayoneerWebhookPayoutStatus::Cancelled => Self::PayoutCancelled,
+            PayoneerWebhookPayoutStatus::Failed => Self::PayoutFailure,
+            PayoneerWebhookPayoutStatus::Refunded | PayoneerWebhookPayoutStatus::Returned => {
+                Self::PayoutReversed
+            }
+            PayoneerWebhookPayoutStatus::Unknown => Self::EventNotSupported,
+        }
+    }
+}
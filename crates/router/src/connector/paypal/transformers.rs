@@ -74,6 +74,9 @@ pub enum PaypalPaymentIntent {
     Capture,
     Authorize,
     Authenticate,
+    // only set when a PayPal account is vaulted during setup mandate
+    // the approved setup token is exchanged for a payment token during payment sync
+    Vault,
 }
 
 #[derive(Default, Debug, Clone, Serialize, Eq, PartialEq, Deserialize)]
@@ -247,11 +250,18 @@ pub struct PaypalRedirectionRequest {
     experience_context: ContextStruct,
 }
 
+#[derive(Debug, Serialize)]
+pub struct PaypalVaultRequest {
+    vault_id: Secret<String>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum PaymentSourceItem {
     Card(CardRequest),
     Paypal(PaypalRedirectionRequest),
+    #[serde(rename = "paypal")]
+    PaypalVault(PaypalVaultRequest),
     IDeal(RedirectRequest),
     Eps(RedirectRequest),
     Giropay(RedirectRequest),
@@ -551,9 +561,13 @@ impl TryFrom<&PaypalRouterData<&types::PaymentsAuthorizeRouterData>> for PaypalP
                 | domain::WalletData::WeChatPayRedirect(_)
                 | domain::WalletData::WeChatPayQr(_)
                 | domain::WalletData::CashappQr(_)
-                | domain::WalletData::SwishQr(_) => Err(errors::ConnectorError::NotImplemented(
-                    utils::get_unimplemented_payment_method_error_message("Paypal"),
-                ))?,
+                | domain::WalletData::SwishQr(_)
+                | domain::WalletData::ClickToPay(_)
+                | domain::WalletData::SkrillRedirect {} => {
+                    Err(errors::ConnectorError::NotImplemented(
+                        utils::get_unimplemented_payment_method_error_message("Paypal"),
+                    ))?
+                }
             },
             domain::PaymentMethodData::BankRedirect(ref bank_redirection_data) => {
                 let intent = if item.router_data.request.is_auto_capture()? {
@@ -603,15 +617,42 @@ impl TryFrom<&PaypalRouterData<&types::PaymentsAuthorizeRouterData>> for PaypalP
                 Self::try_from(giftcard_data.as_ref())
             }
             domain::PaymentMethodData::MandatePayment => {
-                Err(errors::ConnectorError::NotImplemented(
-                    utils::get_unimplemented_payment_method_error_message("Paypal"),
-                )
-                .into())
+                let intent = if item.router_data.request.is_auto_capture()? {
+                    PaypalPaymentIntent::Capture
+                } else {
+                    PaypalPaymentIntent::Authorize
+                };
+                let amount = OrderRequestAmount::from(item);
+                let connector_req_reference_id =
+                    item.router_data.connector_request_reference_id.clone();
+                let shipping_address = ShippingAddress::try_from(item)?;
+                let item_details = vec![ItemDetails::from(item)];
+
+                let purchase_units = vec![PurchaseUnitRequest {
+                    reference_id: Some(connector_req_reference_id.clone()),
+                    custom_id: Some(connector_req_reference_id.clone()),
+                    invoice_id: Some(connector_req_reference_id),
+                    amount,
+                    payee,
+                    shipping: Some(shipping_address),
+                    items: item_details,
+                }];
+                // the payment token of the vaulted PayPal account is charged as a reference transaction
+                let payment_source = Some(PaymentSourceItem::PaypalVault(PaypalVaultRequest {
+                    vault_id: Secret::new(item.router_data.request.get_connector_mandate_id()?),
+                }));
+
+                Ok(Self {
+                    intent,
+                    purchase_units,
+                    payment_source,
+                })
             }
             domain::PaymentMethodData::Reward
             | domain::PaymentMethodData::Crypto(_)
             | domain::PaymentMethodData::Upi(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Paypal"),
                 )
@@ -742,6 +783,101 @@ impl TryFrom<&domain::GiftCardData> for PaypalPaymentsRequest {
     }
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalVaultUsageType {
+    Merchant,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalVaultExperienceContext {
+    return_url: Option<String>,
+    cancel_url: Option<String>,
+    shipping_preference: ShippingPreference,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalVaultSetupRequest {
+    usage_type: PaypalVaultUsageType,
+    experience_context: PaypalVaultExperienceContext,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalSetupTokenPaymentSource {
+    paypal: PaypalVaultSetupRequest,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalSetupTokenRequest {
+    payment_source: PaypalSetupTokenPaymentSource,
+}
+
+impl TryFrom<&types::SetupMandateRouterData> for PaypalSetupTokenRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::SetupMandateRouterData) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data {
+            domain::PaymentMethodData::Wallet(domain::WalletData::PaypalRedirect(_)) => Ok(Self {
+                payment_source: PaypalSetupTokenPaymentSource {
+                    paypal: PaypalVaultSetupRequest {
+                        usage_type: PaypalVaultUsageType::Merchant,
+                        experience_context: PaypalVaultExperienceContext {
+                            return_url: item.request.router_return_url.clone(),
+                            cancel_url: item.request.router_return_url.clone(),
+                            shipping_preference: ShippingPreference::GetFromFile,
+                        },
+                    },
+                },
+            }),
+            _ => Err(errors::ConnectorError::NotImplemented(
+                utils::get_unimplemented_payment_method_error_message("Paypal"),
+            )
+            .into()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalTokenType {
+    SetupToken,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalTokenSource {
+    id: String,
+    #[serde(rename = "type")]
+    token_type: PaypalTokenType,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalPaymentTokenSource {
+    token: PaypalTokenSource,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaypalPaymentTokenRequest {
+    payment_source: PaypalPaymentTokenSource,
+}
+
+impl TryFrom<&types::PaymentsSyncRouterData> for PaypalPaymentTokenRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsSyncRouterData) -> Result<Self, Self::Error> {
+        let setup_token_id = item
+            .request
+            .connector_transaction_id
+            .get_connector_transaction_id()
+            .change_context(errors::ConnectorError::MissingConnectorTransactionID)?;
+        Ok(Self {
+            payment_source: PaypalPaymentTokenSource {
+                token: PaypalTokenSource {
+                    id: setup_token_id,
+                    token_type: PaypalTokenType::SetupToken,
+                },
+            },
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct PaypalAuthUpdateRequest {
     grant_type: String,
@@ -1067,6 +1203,54 @@ pub enum PaypalSyncResponse {
     PaypalThreeDsSyncResponse(PaypalThreeDsSyncResponse),
     PaypalRedirectSyncResponse(PaypalRedirectResponse),
     PaypalPaymentsSyncResponse(PaypalPaymentsSyncResponse),
+    PaypalVaultSyncResponse(PaypalPaymentTokenResponse),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalSetupTokenStatus {
+    Created,
+    PayerActionRequired,
+    Approved,
+    Vaulted,
+    Tokenized,
+}
+
+impl From<PaypalSetupTokenStatus> for storage_enums::AttemptStatus {
+    fn from(item: PaypalSetupTokenStatus) -> Self {
+        match item {
+            PaypalSetupTokenStatus::Created | PaypalSetupTokenStatus::PayerActionRequired => {
+                Self::AuthenticationPending
+            }
+            PaypalSetupTokenStatus::Approved => Self::AuthenticationSuccessful,
+            PaypalSetupTokenStatus::Vaulted | PaypalSetupTokenStatus::Tokenized => Self::Charged,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalSetupTokenResponse {
+    id: String,
+    status: PaypalSetupTokenStatus,
+    links: Vec<PaypalLinks>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalVaultedAccount {
+    email_address: Option<Secret<String>>,
+    payer_id: Option<Secret<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalVaultedPaymentSource {
+    paypal: PaypalVaultedAccount,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalPaymentTokenResponse {
+    id: String,
+    // provided to separate the vaulted payment token from other sync responses
+    payment_source: PaypalVaultedPaymentSource,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1127,7 +1311,7 @@ fn get_id_based_on_intent(
                     .next()?
                     .id,
             ),
-            PaypalPaymentIntent::Authenticate => None,
+            PaypalPaymentIntent::Authenticate | PaypalPaymentIntent::Vault => None,
         }
     }()
     .ok_or_else(|| errors::ConnectorError::MissingConnectorTransactionID.into())
@@ -1167,7 +1351,7 @@ impl<F, T>
                 types::ResponseId::ConnectorTransactionId(item.response.id.clone()),
             ),
 
-            PaypalPaymentIntent::Authenticate => {
+            PaypalPaymentIntent::Authenticate | PaypalPaymentIntent::Vault => {
                 Err(errors::ConnectorError::ResponseDeserializationFailed)?
             }
         };
@@ -1270,10 +1454,100 @@ impl<F>
                     http_code: item.http_code,
                 })
             }
+            PaypalSyncResponse::PaypalVaultSyncResponse(response) => {
+                Self::try_from(types::ResponseRouterData {
+                    response,
+                    data: item.data,
+                    http_code: item.http_code,
+                })
+            }
         }
     }
 }
 
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, PaypalSetupTokenResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            PaypalSetupTokenResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let status = storage_enums::AttemptStatus::from(item.response.status);
+        // customer has to approve the billing agreement on PayPal before it can be vaulted
+        let redirection_data = item
+            .response
+            .links
+            .into_iter()
+            .find(|link| link.rel == "approve")
+            .and_then(|link| link.href)
+            .map(|link| services::RedirectForm::from((link, services::Method::Get)));
+        let connector_meta = serde_json::json!(PaypalMeta {
+            authorize_id: None,
+            capture_id: None,
+            psync_flow: PaypalPaymentIntent::Vault
+        });
+
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata: Some(connector_meta),
+                network_txn_id: None,
+                connector_response_reference_id: None,
+                incremental_authorization_allowed: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            PaypalPaymentTokenResponse,
+            types::PaymentsSyncData,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, types::PaymentsSyncData, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            PaypalPaymentTokenResponse,
+            types::PaymentsSyncData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            // a payment token is only created once the customer has approved the setup token
+            status: storage_enums::AttemptStatus::Charged,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: item.data.request.connector_transaction_id.clone(),
+                redirection_data: None,
+                mandate_reference: Some(types::MandateReference {
+                    connector_mandate_id: Some(item.response.id),
+                    payment_method_id: None,
+                }),
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: None,
+                incremental_authorization_allowed: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
 impl<F, T>
     TryFrom<types::ResponseRouterData<F, PaypalRedirectResponse, T, types::PaymentsResponseData>>
     for types::RouterData<F, T, types::PaymentsResponseData>
@@ -1602,6 +1876,10 @@ impl TryFrom<&PaypalRouterData<&types::PayoutsRouterData<api::PoFulfill>>> for P
                         receiver,
                     }
                 }
+                api::WalletPayout::Payoneer(_) => Err(errors::ConnectorError::NotSupported {
+                    message: "Payoneer Wallet is not supported".to_string(),
+                    connector: "Paypal",
+                })?,
             },
             _ => Err(errors::ConnectorError::NotSupported {
                 message: "PayoutMethodType is not supported".to_string(),
@@ -167,7 +167,8 @@ impl<T> TryFrom<&types::RouterData<T, types::PaymentsAuthorizeData, types::Payme
             | domain::PaymentMethodData::MandatePayment
             | domain::PaymentMethodData::Reward
             | domain::PaymentMethodData::Upi(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Shift4"),
                 )
@@ -206,7 +207,9 @@ impl TryFrom<&domain::WalletData> for Shift4PaymentMethod {
             | domain::WalletData::TouchNGoRedirect(_)
             | domain::WalletData::WeChatPayQr(_)
             | domain::WalletData::CashappQr(_)
-            | domain::WalletData::SwishQr(_) => Err(errors::ConnectorError::NotImplemented(
+            | domain::WalletData::SwishQr(_)
+            | domain::WalletData::ClickToPay(_)
+            | domain::WalletData::SkrillRedirect {} => Err(errors::ConnectorError::NotImplemented(
                 utils::get_unimplemented_payment_method_error_message("Shift4"),
             )
             .into()),
@@ -384,6 +387,7 @@ impl<T> TryFrom<&types::RouterData<T, types::CompleteAuthorizeData, types::Payme
             | Some(domain::PaymentMethodData::Reward)
             | Some(domain::PaymentMethodData::Upi(_))
             | Some(domain::PaymentMethodData::CardToken(_))
+            | Some(domain::PaymentMethodData::RealTimePayment(_))
             | None => Err(errors::ConnectorError::NotImplemented(
                 utils::get_unimplemented_payment_method_error_message("Shift4"),
             )
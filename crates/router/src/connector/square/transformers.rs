@@ -127,9 +127,13 @@ impl TryFrom<(&types::TokenizationRouterData, domain::WalletData)> for SquareTok
             | domain::WalletData::WeChatPayRedirect(_)
             | domain::WalletData::WeChatPayQr(_)
             | domain::WalletData::CashappQr(_)
-            | domain::WalletData::SwishQr(_) => Err(errors::ConnectorError::NotImplemented(
-                utils::get_unimplemented_payment_method_error_message("Square"),
-            ))?,
+            | domain::WalletData::SwishQr(_)
+            | domain::WalletData::ClickToPay(_)
+            | domain::WalletData::SkrillRedirect {} => {
+                Err(errors::ConnectorError::NotImplemented(
+                    utils::get_unimplemented_payment_method_error_message("Square"),
+                ))?
+            }
         }
     }
 }
@@ -175,7 +179,8 @@ impl TryFrom<&types::TokenizationRouterData> for SquareTokenRequest {
             | domain::PaymentMethodData::Reward
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Square"),
                 ))?
@@ -266,6 +271,15 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for SquarePaymentsRequest {
                         types::PaymentMethodToken::ApplePayDecrypt(_) => Err(
                             unimplemented_payment_method!("Apple Pay", "Simplified", "Square"),
                         )?,
+                        types::PaymentMethodToken::GooglePayDecrypt(_) => {
+                            Err(unimplemented_payment_method!("Google Pay", "Square"))?
+                        }
+                        types::PaymentMethodToken::SamsungPayDecrypt(_) => {
+                            Err(unimplemented_payment_method!("Samsung Pay", "Square"))?
+                        }
+                        types::PaymentMethodToken::NetworkToken(_) => {
+                            Err(unimplemented_payment_method!("Network Token", "Square"))?
+                        }
                     }),
                     amount_money: SquarePaymentsAmountData {
                         amount: item.request.amount,
@@ -290,7 +304,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for SquarePaymentsRequest {
             | domain::PaymentMethodData::Reward
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Square"),
                 ))?
@@ -84,6 +84,15 @@ impl TryFrom<&StaxRouterData<&types::PaymentsAuthorizeRouterData>> for StaxPayme
                         types::PaymentMethodToken::ApplePayDecrypt(_) => Err(
                             unimplemented_payment_method!("Apple Pay", "Simplified", "Stax"),
                         )?,
+                        types::PaymentMethodToken::GooglePayDecrypt(_) => {
+                            Err(unimplemented_payment_method!("Google Pay", "Stax"))?
+                        }
+                        types::PaymentMethodToken::SamsungPayDecrypt(_) => {
+                            Err(unimplemented_payment_method!("Samsung Pay", "Stax"))?
+                        }
+                        types::PaymentMethodToken::NetworkToken(_) => {
+                            Err(unimplemented_payment_method!("Network Token", "Stax"))?
+                        }
                     }),
                     idempotency_id: Some(item.router_data.connector_request_reference_id.clone()),
                 })
@@ -103,6 +112,15 @@ impl TryFrom<&StaxRouterData<&types::PaymentsAuthorizeRouterData>> for StaxPayme
                         types::PaymentMethodToken::ApplePayDecrypt(_) => Err(
                             unimplemented_payment_method!("Apple Pay", "Simplified", "Stax"),
                         )?,
+                        types::PaymentMethodToken::GooglePayDecrypt(_) => {
+                            Err(unimplemented_payment_method!("Google Pay", "Stax"))?
+                        }
+                        types::PaymentMethodToken::SamsungPayDecrypt(_) => {
+                            Err(unimplemented_payment_method!("Samsung Pay", "Stax"))?
+                        }
+                        types::PaymentMethodToken::NetworkToken(_) => {
+                            Err(unimplemented_payment_method!("Network Token", "Stax"))?
+                        }
                     }),
                     idempotency_id: Some(item.router_data.connector_request_reference_id.clone()),
                 })
@@ -119,7 +137,8 @@ impl TryFrom<&StaxRouterData<&types::PaymentsAuthorizeRouterData>> for StaxPayme
             | domain::PaymentMethodData::GiftCard(_)
             | domain::PaymentMethodData::CardRedirect(_)
             | domain::PaymentMethodData::Upi(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Stax"),
                 ))?
@@ -271,7 +290,8 @@ impl TryFrom<&types::TokenizationRouterData> for StaxTokenRequest {
             | domain::PaymentMethodData::GiftCard(_)
             | domain::PaymentMethodData::CardRedirect(_)
             | domain::PaymentMethodData::Upi(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Stax"),
                 ))?
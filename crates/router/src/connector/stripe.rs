@@ -132,6 +132,23 @@ impl ConnectorValidation for Stripe {
             ),
         }
     }
+
+    fn validate_split_payments(
+        &self,
+        split_payments: Option<&api_models::payments::SplitPayments>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        // Destination charges of Stripe Connect transfer the payment to a single connected account
+        match split_payments {
+            Some(split_payments) if split_payments.transfers.len() > 1 => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: "Split payments with more than one transfer".to_string(),
+                    connector: self.id(),
+                }
+                .into())
+            }
+            Some(_) | None => Ok(()),
+        }
+    }
 }
 
 impl api::Payment for Stripe {}
@@ -140,6 +157,7 @@ impl api::PaymentAuthorize for Stripe {}
 impl api::PaymentSync for Stripe {}
 impl api::PaymentVoid for Stripe {}
 impl api::PaymentCapture for Stripe {}
+impl api::PaymentIncrementalAuthorization for Stripe {}
 impl api::PaymentSession for Stripe {}
 impl api::ConnectorAccessToken for Stripe {}
 
@@ -689,6 +707,139 @@ impl
     }
 }
 
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            Self::common_get_content_type(self).to_string().into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let id = req.request.connector_transaction_id.as_str();
+
+        Ok(format!(
+            "{}{}/{}/increment_authorization",
+            self.base_url(connectors),
+            "v1/payment_intents",
+            id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req = stripe::IncrementalAuthorizationRequest::try_from(req)?;
+        Ok(RequestContent::FormUrlEncoded(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::IncrementalAuthorizationType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(types::IncrementalAuthorizationType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(types::IncrementalAuthorizationType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsIncrementalAuthorizationRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsIncrementalAuthorizationRouterData, errors::ConnectorError>
+    {
+        let response: stripe::IncrementalAuthorizationResponse = res
+            .response
+            .parse_struct("IncrementalAuthorizationResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_error_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: response.error.message.map(|message| {
+                response
+                    .error
+                    .decline_code
+                    .map(|decline_code| {
+                        format!("message - {}, decline_code - {}", message, decline_code)
+                    })
+                    .unwrap_or(message)
+            }),
+            attempt_status: None,
+            connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+        })
+    }
+}
+
 impl
     services::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
     for Stripe
@@ -1157,6 +1308,131 @@ impl
     }
 }
 
+impl api::PaymentVerifyMicroDeposits for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyMicroDeposits,
+        types::PaymentsVerifyMicroDepositsData,
+        types::PaymentsResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsVerifyMicroDepositsRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::PaymentsVerifyMicroDepositsType::get_content_type(self)
+                .to_string()
+                .into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsVerifyMicroDepositsRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let payment_id = &req.request.connector_transaction_id;
+        Ok(format!(
+            "{}v1/payment_intents/{}/verify_microdeposits",
+            self.base_url(connectors),
+            payment_id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsVerifyMicroDepositsRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req = stripe::StripeVerifyMicroDepositsRequest::try_from(req)?;
+        Ok(RequestContent::FormUrlEncoded(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsVerifyMicroDepositsRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::PaymentsVerifyMicroDepositsType::get_url(
+                self, req, connectors,
+            )?)
+            .attach_default_headers()
+            .headers(types::PaymentsVerifyMicroDepositsType::get_headers(
+                self, req, connectors,
+            )?)
+            .set_body(types::PaymentsVerifyMicroDepositsType::get_request_body(
+                self, req, connectors,
+            )?)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsVerifyMicroDepositsRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsVerifyMicroDepositsRouterData, errors::ConnectorError> {
+        let response: stripe::PaymentIntentResponse = res
+            .response
+            .parse_struct("PaymentIntentResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_error_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: response.error.message,
+            attempt_status: None,
+            connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+        })
+    }
+}
+
 type Verify = dyn services::ConnectorIntegration<
     api::SetupMandate,
     types::SetupMandateRequestData,
@@ -2130,6 +2406,33 @@ impl api::IncomingWebhook for Stripe {
                     ),
                 }
             }
+            // the stored connector payout id is the transfer id until the payout is fulfilled to
+            // the connected account, and the payout id after that, so both the transfer and the
+            // payout carry the payout attempt id as the order_id
+            #[cfg(feature = "payouts")]
+            stripe::WebhookEventObjectType::Payout | stripe::WebhookEventObjectType::Transfer => {
+                match details
+                    .event_data
+                    .event_object
+                    .metadata
+                    .and_then(|meta_data| meta_data.order_id)
+                {
+                    // if order_id is present
+                    Some(order_id) => api_models::webhooks::ObjectReferenceId::PayoutId(
+                        api_models::webhooks::PayoutIdType::PayoutAttemptId(order_id),
+                    ),
+                    // else use connector_payout_id
+                    None => api_models::webhooks::ObjectReferenceId::PayoutId(
+                        api_models::webhooks::PayoutIdType::ConnectorPayoutId(
+                            details.event_data.event_object.id,
+                        ),
+                    ),
+                }
+            }
+            #[cfg(not(feature = "payouts"))]
+            stripe::WebhookEventObjectType::Payout | stripe::WebhookEventObjectType::Transfer => {
+                Err(errors::ConnectorError::WebhookReferenceIdNotFound)?
+            }
         })
     }
 
@@ -2202,7 +2505,23 @@ impl api::IncomingWebhook for Stripe {
             stripe::WebhookEventType::ChargeDisputeFundsReinstated => {
                 api::IncomingWebhookEvent::DisputeWon
             }
+            #[cfg(feature = "payouts")]
+            stripe::WebhookEventType::PayoutPaid => api::IncomingWebhookEvent::PayoutSuccess,
+            #[cfg(feature = "payouts")]
+            stripe::WebhookEventType::PayoutFailed => api::IncomingWebhookEvent::PayoutFailure,
+            #[cfg(feature = "payouts")]
+            stripe::WebhookEventType::PayoutCanceled => api::IncomingWebhookEvent::PayoutCancelled,
+            #[cfg(feature = "payouts")]
+            stripe::WebhookEventType::TransferReversed => api::IncomingWebhookEvent::PayoutReversed,
+            #[cfg(not(feature = "payouts"))]
+            stripe::WebhookEventType::PayoutPaid
+            | stripe::WebhookEventType::PayoutFailed
+            | stripe::WebhookEventType::PayoutCanceled
+            | stripe::WebhookEventType::TransferReversed => {
+                api::IncomingWebhookEvent::EventNotSupported
+            }
             stripe::WebhookEventType::Unknown
+            | stripe::WebhookEventType::PayoutUpdated
             | stripe::WebhookEventType::ChargeCaptured
             | stripe::WebhookEventType::ChargeExpired
             | stripe::WebhookEventType::ChargeFailed
@@ -6,6 +6,7 @@ use common_utils::{
     ext_traits::{ByteSliceExt, Encode},
     pii::{self, Email},
     request::RequestContent,
+    types::ExpiresAt,
 };
 use error_stack::ResultExt;
 use hyperswitch_domain_models::mandates::AcceptanceType;
@@ -42,6 +43,9 @@ pub mod auth_headers {
     pub const STRIPE_VERSION: &str = "2022-11-15";
 }
 
+const STATEMENT_DESCRIPTOR_MAX_LENGTH: usize = 22;
+const STATEMENT_DESCRIPTOR_RESERVED_CHARS: [char; 6] = ['<', '>', '\\', '\'', '"', '*'];
+
 pub struct StripeAuthType {
     pub(super) api_key: Secret<String>,
 }
@@ -110,6 +114,59 @@ pub struct StripeMandateRequest {
     mandate_type: StripeMandateType,
 }
 
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeRequestIncrementalAuthorization {
+    IfAvailable,
+    Never,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeRequestOvercapture {
+    IfAvailable,
+    Never,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeRequestExtendedAuthorization {
+    IfAvailable,
+    Never,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeCardNetwork {
+    Amex,
+    CartesBancaires,
+    Diners,
+    Discover,
+    Interac,
+    Jcb,
+    Mastercard,
+    Unionpay,
+    Visa,
+}
+
+impl StripeCardNetwork {
+    /// The network of the card which stripe authorizes the payment on, if stripe accepts it
+    fn from_card_network(card_network: enums::CardNetwork) -> Option<Self> {
+        match card_network {
+            enums::CardNetwork::AmericanExpress => Some(Self::Amex),
+            enums::CardNetwork::CartesBancaires => Some(Self::CartesBancaires),
+            enums::CardNetwork::DinersClub => Some(Self::Diners),
+            enums::CardNetwork::Discover => Some(Self::Discover),
+            enums::CardNetwork::Interac => Some(Self::Interac),
+            enums::CardNetwork::JCB => Some(Self::Jcb),
+            enums::CardNetwork::Mastercard => Some(Self::Mastercard),
+            enums::CardNetwork::UnionPay => Some(Self::Unionpay),
+            enums::CardNetwork::Visa => Some(Self::Visa),
+            enums::CardNetwork::RuPay | enums::CardNetwork::Maestro => None,
+        }
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ExpandableObjects {
@@ -158,6 +215,27 @@ pub struct PaymentIntentRequest {
     pub expand: Option<ExpandableObjects>,
     #[serde(flatten)]
     pub browser_info: Option<StripeBrowserInformation>,
+    #[serde(rename = "payment_method_options[card][request_incremental_authorization]")]
+    pub request_incremental_authorization: Option<StripeRequestIncrementalAuthorization>,
+    #[serde(rename = "payment_method_options[card][request_overcapture]")]
+    pub request_overcapture: Option<StripeRequestOvercapture>,
+    #[serde(rename = "payment_method_options[card][request_extended_authorization]")]
+    pub request_extended_authorization: Option<StripeRequestExtendedAuthorization>,
+    #[serde(rename = "payment_method_options[card][moto]")]
+    pub moto: Option<bool>,
+    #[serde(rename = "payment_method_options[card][network]")]
+    pub network: Option<StripeCardNetwork>,
+    #[serde(flatten)]
+    pub transfer_data: Option<StripeTransferData>,
+}
+
+/// Destination charge of Stripe Connect, transferring the payment to a connected account
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeTransferData {
+    /// Amount kept by the platform, which is the amount not transferred to the connected account
+    pub application_fee_amount: i64,
+    #[serde(rename = "transfer_data[destination]")]
+    pub destination: String,
 }
 
 // Field rename is required only in case of serialization as it is passed in the request to the connector.
@@ -665,6 +743,9 @@ impl TryFrom<enums::PaymentMethodType> for StripePaymentMethodType {
             | enums::PaymentMethodType::Pix
             | enums::PaymentMethodType::UpiCollect
             | enums::PaymentMethodType::Cashapp
+            | enums::PaymentMethodType::ClickToPay
+            | enums::PaymentMethodType::Skrill
+            | enums::PaymentMethodType::Payoneer
             | enums::PaymentMethodType::Oxxo => Err(errors::ConnectorError::NotImplemented(
                 connector_util::get_unimplemented_payment_method_error_message("stripe"),
             )
@@ -723,6 +804,9 @@ impl TryFrom<enums::PaymentMethodType> for StripePaymentMethodType {
             | enums::PaymentMethodType::Seicomart
             | enums::PaymentMethodType::PayEasy
             | enums::PaymentMethodType::LocalBankTransfer
+            | enums::PaymentMethodType::PayNow
+            | enums::PaymentMethodType::PromptPay
+            | enums::PaymentMethodType::UpiQr
             | enums::PaymentMethodType::Walley => Err(errors::ConnectorError::NotImplemented(
                 connector_util::get_unimplemented_payment_method_error_message("stripe"),
             )
@@ -1037,6 +1121,8 @@ impl ForeignTryFrom<&domain::WalletData> for Option<StripePaymentMethodType> {
             | domain::WalletData::VippsRedirect {}
             | domain::WalletData::TouchNGoRedirect(_)
             | domain::WalletData::SwishQr(_)
+            | domain::WalletData::ClickToPay(_)
+            | domain::WalletData::SkrillRedirect {}
             | domain::WalletData::WeChatPayRedirect(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     connector_util::get_unimplemented_payment_method_error_message("stripe"),
@@ -1463,10 +1549,13 @@ fn create_stripe_payment_method(
 
         domain::PaymentMethodData::Upi(_)
         | domain::PaymentMethodData::MandatePayment
-        | domain::PaymentMethodData::CardToken(_) => Err(errors::ConnectorError::NotImplemented(
-            connector_util::get_unimplemented_payment_method_error_message("stripe"),
-        )
-        .into()),
+        | domain::PaymentMethodData::CardToken(_)
+        | domain::PaymentMethodData::RealTimePayment(_) => {
+            Err(errors::ConnectorError::NotImplemented(
+                connector_util::get_unimplemented_payment_method_error_message("stripe"),
+            )
+            .into())
+        }
     }
 }
 
@@ -1579,6 +1668,8 @@ impl TryFrom<(&domain::WalletData, Option<types::PaymentMethodToken>)> for Strip
             | domain::WalletData::VippsRedirect {}
             | domain::WalletData::TouchNGoRedirect(_)
             | domain::WalletData::SwishQr(_)
+            | domain::WalletData::ClickToPay(_)
+            | domain::WalletData::SkrillRedirect {}
             | domain::WalletData::WeChatPayRedirect(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     connector_util::get_unimplemented_payment_method_error_message("stripe"),
@@ -1827,7 +1918,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
                         | domain::payments::PaymentMethodData::Upi(_)
                         | domain::payments::PaymentMethodData::Voucher(_)
                         | domain::payments::PaymentMethodData::GiftCard(_)
-                        | domain::payments::PaymentMethodData::CardToken(_) => {
+                        | domain::payments::PaymentMethodData::CardToken(_)
+                        | domain::payments::PaymentMethodData::RealTimePayment(_) => {
                             Err(errors::ConnectorError::NotSupported {
                                 message: "Network tokenization for payment method".to_string(),
                                 connector: "Stripe",
@@ -1884,6 +1976,15 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
                     types::PaymentMethodToken::ApplePayDecrypt(_) => Err(
                         unimplemented_payment_method!("Apple Pay", "Simplified", "Stripe"),
                     )?,
+                    types::PaymentMethodToken::GooglePayDecrypt(_) => {
+                        Err(unimplemented_payment_method!("Google Pay", "Stripe"))?
+                    }
+                    types::PaymentMethodToken::SamsungPayDecrypt(_) => {
+                        Err(unimplemented_payment_method!("Samsung Pay", "Stripe"))?
+                    }
+                    types::PaymentMethodToken::NetworkToken(_) => {
+                        Err(unimplemented_payment_method!("Network Token", "Stripe"))?
+                    }
                 };
                 Some(StripePaymentMethodData::Wallet(
                     StripeWallet::ApplepayPayment(ApplepayPayment {
@@ -1959,7 +2060,12 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
                 }
             });
 
-        let meta_data = get_transaction_metadata(item.request.metadata.clone(), order_id);
+        let mut meta_data = get_transaction_metadata(item.request.metadata.clone(), order_id);
+        meta_data.extend(
+            connector_util::get_mapped_connector_metadata(item)?
+                .into_iter()
+                .map(|(field, value)| (format!("metadata[{field}]"), value)),
+        );
 
         // We pass browser_info only when payment_data exists.
         // Hence, we're pass Null during recurring payments as payment_method_data[type] is not passed
@@ -1972,11 +2078,63 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             None
         };
 
+        // Incremental authorizations are supported by stripe only for card payments
+        let request_incremental_authorization = (item.request.request_incremental_authorization
+            && item.payment_method == enums::PaymentMethod::Card)
+            .then_some(StripeRequestIncrementalAuthorization::IfAvailable);
+
+        // Overcaptures are supported by stripe only for card payments
+        let request_overcapture = (item.request.request_overcapture
+            && item.payment_method == enums::PaymentMethod::Card)
+            .then_some(StripeRequestOvercapture::IfAvailable);
+
+        // Extended authorizations are supported by stripe only for card payments captured manually
+        let request_extended_authorization = (item.request.request_extended_authorization
+            && item.payment_method == enums::PaymentMethod::Card
+            && matches!(
+                item.request.capture_method,
+                Some(enums::CaptureMethod::Manual | enums::CaptureMethod::ManualMultiple)
+            ))
+        .then_some(StripeRequestExtendedAuthorization::IfAvailable);
+
+        // Card payments can be flagged as MOTO, exempting them from strong customer authentication
+        let moto = (item.request.payment_channel == Some(enums::PaymentChannel::Moto)
+            && item.payment_method == enums::PaymentMethod::Card)
+            .then_some(true);
+
+        // Co-badged cards are authorized on the network preferred by the merchant or the customer
+        let network = item
+            .request
+            .preferred_card_network
+            .filter(|_| item.payment_method == enums::PaymentMethod::Card)
+            .and_then(StripeCardNetwork::from_card_network);
+
+        let transfer_data = item
+            .request
+            .split_payments
+            .as_ref()
+            .and_then(|split_payments| {
+                split_payments
+                    .transfers
+                    .first()
+                    .map(|transfer| StripeTransferData {
+                        application_fee_amount: item.request.amount
+                            - split_payments.get_transferred_amount(),
+                        destination: transfer.account_id.clone(),
+                    })
+            });
+
         Ok(Self {
             amount: item.request.amount, //hopefully we don't loose some cents here
             currency: item.request.currency.to_string(), //we need to copy the value and not transfer ownership
-            statement_descriptor_suffix: item.request.statement_descriptor_suffix.clone(),
-            statement_descriptor: item.request.statement_descriptor.clone(),
+            statement_descriptor_suffix: validate_statement_descriptor(
+                item.request.statement_descriptor_suffix.as_ref(),
+                "statement_descriptor_suffix",
+            )?,
+            statement_descriptor: validate_statement_descriptor(
+                item.request.statement_descriptor.as_ref(),
+                "statement_descriptor_name",
+            )?,
             meta_data,
             return_url: item
                 .request
@@ -1998,10 +2156,46 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             payment_method_types,
             expand: Some(ExpandableObjects::LatestCharge),
             browser_info,
+            request_incremental_authorization,
+            request_overcapture,
+            request_extended_authorization,
+            moto,
+            network,
+            transfer_data,
         })
     }
 }
 
+/// Stripe shows the statement descriptors in latin characters, and rejects the ones which are
+/// longer than 22 characters, contain no letters, or contain any of the characters it reserves
+fn validate_statement_descriptor(
+    statement_descriptor: Option<&String>,
+    field_name: &'static str,
+) -> Result<Option<String>, error_stack::Report<errors::ConnectorError>> {
+    let statement_descriptor = connector_util::validate_statement_descriptor(
+        statement_descriptor,
+        field_name,
+        STATEMENT_DESCRIPTOR_MAX_LENGTH,
+        |character| {
+            character.is_ascii() && !STATEMENT_DESCRIPTOR_RESERVED_CHARS.contains(&character)
+        },
+    )?;
+
+    if statement_descriptor
+        .as_ref()
+        .is_some_and(|statement_descriptor| {
+            !statement_descriptor
+                .chars()
+                .any(|character| character.is_ascii_alphabetic())
+        })
+    {
+        return Err(errors::ConnectorError::InvalidDataFormat { field_name })
+            .attach_printable(format!("{field_name} should contain at least one letter"));
+    }
+
+    Ok(statement_descriptor)
+}
+
 fn get_payment_method_type_for_saved_payment_method_payment(
     item: &types::PaymentsAuthorizeRouterData,
 ) -> Result<Option<StripePaymentMethodType>, error_stack::Report<errors::ConnectorError>> {
@@ -2216,7 +2410,7 @@ pub struct SepaAndBacsBankTransferInstructions {
 #[derive(Clone, Debug, Serialize)]
 pub struct QrCodeNextInstructions {
     pub image_data_url: Url,
-    pub display_to_timestamp: Option<i64>,
+    pub display_to_timestamp: Option<ExpiresAt>,
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
@@ -2279,6 +2473,35 @@ pub struct StripeAdditionalCardDetails {
     checks: Option<Value>,
     three_d_secure: Option<Value>,
     network_transaction_id: Option<String>,
+    incremental_authorization: Option<StripeIncrementalAuthorizationDetails>,
+    extended_authorization: Option<StripeExtendedAuthorizationDetails>,
+    /// Time until which the authorization of the payment can be captured
+    #[serde(default, with = "common_utils::custom_serde::timestamp::option")]
+    capture_before: Option<PrimitiveDateTime>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct StripeExtendedAuthorizationDetails {
+    status: StripeExtendedAuthorizationStatus,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeExtendedAuthorizationStatus {
+    Enabled,
+    Disabled,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct StripeIncrementalAuthorizationDetails {
+    status: StripeIncrementalAuthorizationAvailability,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeIncrementalAuthorizationAvailability {
+    Available,
+    Unavailable,
 }
 
 #[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
@@ -2338,6 +2561,26 @@ impl From<AdditionalPaymentMethodDetails> for types::AdditionalPaymentMethodConn
 }
 
 impl StripePaymentMethodDetailsResponse {
+    pub fn get_extended_authorization_response_data(
+        &self,
+    ) -> Option<types::ExtendedAuthorizationResponseData> {
+        if let Self::Card { card } = self {
+            card.extended_authorization
+                .as_ref()
+                .map(
+                    |extended_authorization| types::ExtendedAuthorizationResponseData {
+                        extended_authorization_applied: Some(
+                            extended_authorization.status
+                                == StripeExtendedAuthorizationStatus::Enabled,
+                        ),
+                        capture_before: card.capture_before,
+                    },
+                )
+        } else {
+            None
+        }
+    }
+
     pub fn get_additional_payment_method_data(&self) -> Option<AdditionalPaymentMethodDetails> {
         match self {
             Self::Card { card } => Some(AdditionalPaymentMethodDetails {
@@ -2430,16 +2673,41 @@ pub struct SetupIntentResponse {
 fn extract_payment_method_connector_response_from_latest_charge(
     stripe_charge_enum: &StripeChargeEnum,
 ) -> Option<types::ConnectorResponseData> {
-    if let StripeChargeEnum::ChargeObject(charge_object) = stripe_charge_enum {
-        charge_object
-            .payment_method_details
+    let payment_method_details = match stripe_charge_enum {
+        StripeChargeEnum::ChargeObject(charge_object) => {
+            charge_object.payment_method_details.as_ref()
+        }
+        StripeChargeEnum::ChargeId(_) => None,
+    };
+    let additional_payment_method_data = payment_method_details
+        .and_then(StripePaymentMethodDetailsResponse::get_additional_payment_method_data)
+        .map(types::AdditionalPaymentMethodConnectorResponse::from);
+    let extended_authorization_response_data = payment_method_details
+        .and_then(StripePaymentMethodDetailsResponse::get_extended_authorization_response_data);
+
+    (additional_payment_method_data.is_some() || extended_authorization_response_data.is_some())
+        .then_some(types::ConnectorResponseData {
+            additional_payment_method_data,
+            extended_authorization_response_data,
+        })
+}
+
+fn extract_incremental_authorization_allowed_from_latest_charge(
+    stripe_charge_enum: &StripeChargeEnum,
+) -> Option<bool> {
+    match stripe_charge_enum {
+        StripeChargeEnum::ChargeObject(StripeCharge {
+            payment_method_details: Some(StripePaymentMethodDetailsResponse::Card { card }),
+            ..
+        }) => card
+            .incremental_authorization
             .as_ref()
-            .and_then(StripePaymentMethodDetailsResponse::get_additional_payment_method_data)
-    } else {
-        None
+            .map(|incremental_authorization| {
+                incremental_authorization.status
+                    == StripeIncrementalAuthorizationAvailability::Available
+            }),
+        StripeChargeEnum::ChargeObject(_) | StripeChargeEnum::ChargeId(_) => None,
     }
-    .map(types::AdditionalPaymentMethodConnectorResponse::from)
-    .map(types::ConnectorResponseData::with_additional_payment_method_data)
 }
 
 fn extract_payment_method_connector_response_from_latest_attempt(
@@ -2493,6 +2761,12 @@ impl<F, T>
 
         let status = enums::AttemptStatus::from(item.response.status);
 
+        let incremental_authorization_allowed = item
+            .response
+            .latest_charge
+            .as_ref()
+            .and_then(extract_incremental_authorization_allowed_from_latest_charge);
+
         let response = if connector_util::is_payment_failure(status) {
             types::PaymentsResponseData::try_from((
                 &item.response.last_payment_error,
@@ -2507,7 +2781,7 @@ impl<F, T>
                 connector_metadata,
                 network_txn_id,
                 connector_response_reference_id: Some(item.response.id),
-                incremental_authorization_allowed: None,
+                incremental_authorization_allowed,
             })
         };
 
@@ -2569,10 +2843,23 @@ pub fn get_connector_metadata(
             StripeNextActionResponse::CashappHandleRedirectOrDisplayQrCode(response) => {
                 let cashapp_qr_instructions: QrCodeNextInstructions = QrCodeNextInstructions {
                     image_data_url: response.qr_code.image_url_png.to_owned(),
-                    display_to_timestamp: response.qr_code.expires_at.to_owned(),
+                    display_to_timestamp: response.qr_code.expires_at.map(ExpiresAt::from),
                 };
                 Some(cashapp_qr_instructions.encode_to_value())
             }
+            StripeNextActionResponse::VerifyWithMicrodeposits(response) => {
+                let micro_deposits_instructions =
+                    api_models::payments::MicroDepositsVerificationDetails {
+                        // Stripe sends amounts micro-deposits unless descriptor codes are enabled
+                        micro_deposit_type: response
+                            .microdeposit_type
+                            .map(api_models::payments::MicroDepositType::from)
+                            .unwrap_or(api_models::payments::MicroDepositType::Amounts),
+                        arrival_date: response.arrival_date,
+                        hosted_verification_url: Some(response.hosted_verification_url.to_owned()),
+                    };
+                Some(micro_deposits_instructions.encode_to_value())
+            }
             _ => None,
         })
         .transpose()
@@ -2812,9 +3099,9 @@ impl StripeNextActionResponse {
                 Some(redirect_to_url.url.to_owned())
             }
             Self::WechatPayDisplayQrCode(_) => None,
-            Self::VerifyWithMicrodeposits(verify_with_microdeposits) => {
-                Some(verify_with_microdeposits.hosted_verification_url.to_owned())
-            }
+            // The micro-deposits take days to arrive, so the hosted verification url is shared
+            // in the next action instead of redirecting the customer to it
+            Self::VerifyWithMicrodeposits(_) => None,
             Self::CashappHandleRedirectOrDisplayQrCode(_) => None,
             Self::DisplayBankTransferInstructions(_) => None,
             Self::NoNextActionBody => None,
@@ -2890,7 +3177,25 @@ pub struct WechatPayRedirectToQr {
 
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct StripeVerifyWithMicroDepositsResponse {
+    arrival_date: Option<i64>,
     hosted_verification_url: Url,
+    microdeposit_type: Option<StripeMicroDepositType>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeMicroDepositType {
+    Amounts,
+    DescriptorCode,
+}
+
+impl From<StripeMicroDepositType> for api_models::payments::MicroDepositType {
+    fn from(micro_deposit_type: StripeMicroDepositType) -> Self {
+        match micro_deposit_type {
+            StripeMicroDepositType::Amounts => Self::Amounts,
+            StripeMicroDepositType::DescriptorCode => Self::DescriptorCode,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
@@ -2913,7 +3218,8 @@ pub struct StripeCashappQrResponse {
 
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct QrCodeResponse {
-    pub expires_at: Option<i64>,
+    #[serde(default, with = "common_utils::custom_serde::timestamp::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
     pub image_url_png: Url,
     pub image_url_svg: Url,
 }
@@ -2949,10 +3255,32 @@ pub struct BacsFinancialDetails {
 pub struct RefundRequest {
     pub amount: Option<i64>, //amount in cents, hence passed as integer
     pub payment_intent: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<StripeRefundReason>,
     #[serde(flatten)]
     pub meta_data: StripeMetadata,
 }
 
+/// The reasons Stripe accepts for the refunds, the other reasons are not passed to Stripe
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeRefundReason {
+    Duplicate,
+    Fraudulent,
+    RequestedByCustomer,
+}
+
+impl StripeRefundReason {
+    fn from_reason_code(reason_code: enums::RefundReasonCode) -> Option<Self> {
+        match reason_code {
+            enums::RefundReasonCode::Duplicate => Some(Self::Duplicate),
+            enums::RefundReasonCode::Fraud => Some(Self::Fraudulent),
+            enums::RefundReasonCode::RequestedByCustomer => Some(Self::RequestedByCustomer),
+            enums::RefundReasonCode::ProductIssue | enums::RefundReasonCode::Other => None,
+        }
+    }
+}
+
 impl<F> TryFrom<&types::RefundsRouterData<F>> for RefundRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
@@ -2961,6 +3289,10 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for RefundRequest {
         Ok(Self {
             amount: Some(amount),
             payment_intent,
+            reason: item
+                .request
+                .reason_code
+                .and_then(StripeRefundReason::from_reason_code),
             meta_data: StripeMetadata {
                 order_id: Some(item.request.refund_id.clone()),
                 is_refund_id_as_reference: Some("true".to_string()),
@@ -3146,6 +3478,42 @@ pub struct StripeRedirectResponse {
     pub source_type: Option<Secret<String>>,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum StripeVerifyMicroDepositsRequest {
+    Amounts {
+        #[serde(rename = "amounts[0]")]
+        first_amount: i64,
+        #[serde(rename = "amounts[1]")]
+        second_amount: i64,
+    },
+    DescriptorCode {
+        descriptor_code: Secret<String>,
+    },
+}
+
+impl TryFrom<&types::PaymentsVerifyMicroDepositsRouterData> for StripeVerifyMicroDepositsRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsVerifyMicroDepositsRouterData) -> Result<Self, Self::Error> {
+        match (
+            item.request.amounts.as_deref(),
+            item.request.descriptor_code.as_ref(),
+        ) {
+            (Some([first_amount, second_amount]), _) => Ok(Self::Amounts {
+                first_amount: *first_amount,
+                second_amount: *second_amount,
+            }),
+            (_, Some(descriptor_code)) => Ok(Self::DescriptorCode {
+                descriptor_code: descriptor_code.clone(),
+            }),
+            _ => Err(errors::ConnectorError::MissingRequiredField {
+                field_name: "amounts",
+            }
+            .into()),
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct CancelRequest {
     cancellation_reason: Option<String>,
@@ -3238,6 +3606,80 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for CaptureRequest {
     }
 }
 
+/// Represents the incremental authorization request body for stripe connector.
+#[derive(Debug, Serialize, Clone, Copy)]
+pub struct IncrementalAuthorizationRequest {
+    /// The updated total amount to be authorized, including the amounts already authorized.
+    amount: i64,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData>
+    for IncrementalAuthorizationRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.total_amount,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IncrementalAuthorizationResponse {
+    pub id: String,
+    pub amount: i64,
+    pub status: StripePaymentStatus,
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::IncrementalAuthorization,
+            IncrementalAuthorizationResponse,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+    > for types::PaymentsIncrementalAuthorizationRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::IncrementalAuthorization,
+            IncrementalAuthorizationResponse,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        // Stripe responds with an error when the incremental authorization is declined, and with
+        // the payment intent with its amount updated when it succeeds
+        let response = if item.response.amount == item.data.request.total_amount {
+            types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                status: common_enums::AuthorizationStatus::Success,
+                error_code: None,
+                error_message: None,
+                connector_authorization_id: None,
+            }
+        } else {
+            types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                status: common_enums::AuthorizationStatus::Failure,
+                error_code: None,
+                error_message: Some(format!(
+                    "Authorized amount {} does not match the requested amount {}",
+                    item.response.amount, item.data.request.total_amount
+                )),
+                connector_authorization_id: None,
+            }
+        };
+
+        Ok(Self {
+            response: Ok(response),
+            ..item.data
+        })
+    }
+}
+
 impl TryFrom<&types::PaymentsPreProcessingRouterData> for StripeCreditTransferSourceRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsPreProcessingRouterData) -> Result<Self, Self::Error> {
@@ -3300,6 +3742,7 @@ impl TryFrom<&types::PaymentsPreProcessingRouterData> for StripeCreditTransferSo
             | Some(domain::PaymentMethodData::CardRedirect(..))
             | Some(domain::PaymentMethodData::Voucher(..))
             | Some(domain::PaymentMethodData::CardToken(..))
+            | Some(domain::PaymentMethodData::RealTimePayment(..))
             | None => Err(errors::ConnectorError::NotImplemented(
                 connector_util::get_unimplemented_payment_method_error_message("stripe"),
             )
@@ -3567,6 +4010,8 @@ pub enum WebhookEventObjectType {
     Charge,
     Source,
     Refund,
+    Payout,
+    Transfer,
 }
 
 #[derive(Debug, Deserialize)]
@@ -3617,6 +4062,16 @@ pub enum WebhookEventType {
     SourceTransactionCreated,
     #[serde(rename = "payment_intent.partially_funded")]
     PaymentIntentPartiallyFunded,
+    #[serde(rename = "payout.paid")]
+    PayoutPaid,
+    #[serde(rename = "payout.failed")]
+    PayoutFailed,
+    #[serde(rename = "payout.canceled")]
+    PayoutCanceled,
+    #[serde(rename = "payout.updated")]
+    PayoutUpdated,
+    #[serde(rename = "transfer.reversed")]
+    TransferReversed,
     #[serde(other)]
     Unknown,
 }
@@ -3749,7 +4204,8 @@ impl
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::CardRedirect(_)
             | domain::PaymentMethodData::Voucher(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::CardToken(_)
+            | domain::PaymentMethodData::RealTimePayment(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     connector_util::get_unimplemented_payment_method_error_message("stripe"),
                 ))?
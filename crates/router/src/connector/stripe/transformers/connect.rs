@@ -36,6 +36,8 @@ pub struct StripeConnectPayoutCreateRequest {
     currency: enums::Currency,
     destination: String,
     transfer_group: String,
+    #[serde(rename = "metadata[order_id]")]
+    order_id: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -57,6 +59,8 @@ pub struct TransferReversals {
 pub struct StripeConnectPayoutFulfillRequest {
     amount: i64,
     currency: enums::Currency,
+    #[serde(rename = "metadata[order_id]")]
+    order_id: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -217,6 +221,7 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for StripeConnectPayoutCreateReque
             currency: request.destination_currency,
             destination: connector_customer_id,
             transfer_group: request.payout_id,
+            order_id: request.payout_attempt_id,
         })
     }
 }
@@ -251,6 +256,7 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for StripeConnectPayoutFulfillRequ
         Ok(Self {
             amount: request.amount,
             currency: request.destination_currency,
+            order_id: request.payout_attempt_id,
         })
     }
 }
@@ -19,7 +19,6 @@ use masking::{ExposeInterface, Secret};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::Serializer;
-use time::PrimitiveDateTime;
 
 #[cfg(feature = "frm")]
 use crate::types::{fraud_check, storage::enums as storage_enums};
@@ -32,7 +31,8 @@ use crate::{
     pii::PeekInterface,
     types::{
         self, api, domain, transformers::ForeignTryFrom, ApplePayPredecryptData,
-        BrowserInformation, PaymentsCancelData, ResponseId,
+        BrowserInformation, GooglePayPredecryptData, PaymentsCancelData, ResponseId,
+        SamsungPayPredecryptData,
     },
     utils::{OptionExt, ValueExt},
 };
@@ -1076,6 +1076,9 @@ pub trait CardData {
     fn get_expiry_date_as_yymm(&self) -> Result<Secret<String>, errors::ConnectorError>;
     fn get_expiry_month_as_i8(&self) -> Result<Secret<i8>, Error>;
     fn get_expiry_year_as_i32(&self) -> Result<Secret<i32>, Error>;
+    /// The security code is absent for cards which were on file with a wallet, such as the
+    /// `PAN_ONLY` Google Pay tokens
+    fn get_optional_card_cvc(&self) -> Option<Secret<String>>;
 }
 
 impl CardData for domain::Card {
@@ -1149,6 +1152,9 @@ impl CardData for domain::Card {
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)
             .map(Secret::new)
     }
+    fn get_optional_card_cvc(&self) -> Option<Secret<String>> {
+        Some(self.card_cvc.clone()).filter(|card_cvc| !card_cvc.peek().is_empty())
+    }
 }
 
 #[track_caller]
@@ -1257,6 +1263,59 @@ impl ApplePayDecrypt for Box<ApplePayPredecryptData> {
     }
 }
 
+pub trait GooglePayDecrypt {
+    fn get_expiry_month(&self) -> Secret<String>;
+    fn get_four_digit_expiry_year(&self) -> Secret<String>;
+    fn get_cryptogram(&self) -> Result<Secret<String>, Error>;
+}
+
+impl GooglePayDecrypt for Box<GooglePayPredecryptData> {
+    fn get_expiry_month(&self) -> Secret<String> {
+        Secret::new(format!(
+            "{:02}",
+            self.payment_method_details.expiration_month
+        ))
+    }
+
+    fn get_four_digit_expiry_year(&self) -> Secret<String> {
+        Secret::new(self.payment_method_details.expiration_year.to_string())
+    }
+
+    fn get_cryptogram(&self) -> Result<Secret<String>, Error> {
+        self.payment_method_details
+            .cryptogram
+            .clone()
+            .ok_or_else(missing_field_err("payment_method_details.cryptogram"))
+    }
+}
+
+pub trait SamsungPayDecrypt {
+    fn get_expiry_month(&self) -> Result<Secret<String>, Error>;
+    fn get_four_digit_expiry_year(&self) -> Result<Secret<String>, Error>;
+}
+
+impl SamsungPayDecrypt for Box<SamsungPayPredecryptData> {
+    fn get_expiry_month(&self) -> Result<Secret<String>, Error> {
+        Ok(Secret::new(
+            self.token_pan_expiration
+                .peek()
+                .get(0..2)
+                .ok_or(errors::ConnectorError::RequestEncodingFailed)?
+                .to_string(),
+        ))
+    }
+
+    fn get_four_digit_expiry_year(&self) -> Result<Secret<String>, Error> {
+        Ok(Secret::new(format!(
+            "20{}",
+            self.token_pan_expiration
+                .peek()
+                .get(2..4)
+                .ok_or(errors::ConnectorError::RequestEncodingFailed)?
+        )))
+    }
+}
+
 pub trait CryptoData {
     fn get_pay_currency(&self) -> Result<String, Error>;
 }
@@ -1557,6 +1616,66 @@ where
     json.parse_value(std::any::type_name::<T>()).switch()
 }
 
+/// Mapping of the payment metadata keys to the connector metadata fields they are propagated to,
+/// configured as `metadata_mapping` in the metadata of the merchant connector account.
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct ConnectorMetadataMapping(HashMap<String, String>);
+
+impl TryFrom<&Option<pii::SecretSerdeValue>> for ConnectorMetadataMapping {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(connector_meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
+        connector_meta_data
+            .as_ref()
+            .and_then(|connector_meta_data| connector_meta_data.peek().get("metadata_mapping"))
+            .cloned()
+            .map(|metadata_mapping| {
+                metadata_mapping
+                    .parse_value::<Self>("ConnectorMetadataMapping")
+                    .change_context(errors::ConnectorError::InvalidConnectorConfig {
+                        config: "metadata.metadata_mapping",
+                    })
+            })
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+}
+
+impl ConnectorMetadataMapping {
+    /// The connector metadata fields along with the values of the payment metadata keys mapped to
+    /// them. Keys missing from the payment metadata are skipped.
+    pub fn get_mapped_metadata(
+        &self,
+        metadata: Option<&pii::SecretSerdeValue>,
+    ) -> HashMap<String, String> {
+        let Some(metadata) = metadata else {
+            return HashMap::new();
+        };
+
+        self.0
+            .iter()
+            .filter_map(|(metadata_key, connector_field)| {
+                metadata
+                    .peek()
+                    .get(metadata_key)
+                    .map(|value| match value {
+                        serde_json::Value::String(value) => value.clone(),
+                        value => value.to_string(),
+                    })
+                    .map(|value| (connector_field.clone(), value))
+            })
+            .collect()
+    }
+}
+
+/// Values of the payment metadata keys mapped to the connector metadata fields by the merchant
+/// connector account of the payment.
+pub fn get_mapped_connector_metadata(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<HashMap<String, String>, Error> {
+    let metadata_mapping = ConnectorMetadataMapping::try_from(&item.connector_meta_data)?;
+    Ok(metadata_mapping.get_mapped_metadata(item.request.metadata.as_ref()))
+}
+
 impl common_utils::errors::ErrorSwitch<errors::ConnectorError> for errors::ParsingError {
     fn switch(&self) -> errors::ConnectorError {
         errors::ConnectorError::ParsingFailed
@@ -1931,11 +2050,6 @@ pub fn validate_currency(
     Ok(())
 }
 
-pub fn get_timestamp_in_milliseconds(datetime: &PrimitiveDateTime) -> i64 {
-    let utc_datetime = datetime.assume_utc();
-    utc_datetime.unix_timestamp() * 1000
-}
-
 #[cfg(feature = "frm")]
 pub trait FraudCheckSaleRequest {
     fn get_order_details(&self) -> Result<Vec<OrderDetailsWithAmount>, Error>;
@@ -2100,6 +2214,7 @@ pub fn is_refund_failure(status: enums::RefundStatus) -> bool {
         }
         common_enums::RefundStatus::ManualReview
         | common_enums::RefundStatus::Pending
+        | common_enums::RefundStatus::PendingEnqueue
         | common_enums::RefundStatus::Success => false,
     }
 }
@@ -2150,6 +2265,87 @@ pub fn get_card_details(
     }
 }
 
+/// Validate a statement descriptor of the payment against the limits of the connector, which
+/// shows it on the statements of the customers as it is sent, instead of having the connector
+/// decline the payment or alter the descriptor
+pub fn validate_statement_descriptor(
+    statement_descriptor: Option<&String>,
+    field_name: &'static str,
+    max_length: usize,
+    is_allowed_char: impl Fn(char) -> bool,
+) -> Result<Option<String>, Error> {
+    let Some(statement_descriptor) = statement_descriptor else {
+        return Ok(None);
+    };
+
+    if statement_descriptor.chars().count() > max_length {
+        return Err(report!(errors::ConnectorError::InvalidDataFormat {
+            field_name
+        }))
+        .attach_printable(format!(
+            "{field_name} should be at most {max_length} characters long"
+        ));
+    }
+    if let Some(invalid_char) = statement_descriptor
+        .chars()
+        .find(|character| !is_allowed_char(*character))
+    {
+        return Err(report!(errors::ConnectorError::InvalidDataFormat {
+            field_name
+        }))
+        .attach_printable(format!("{field_name} should not contain `{invalid_char}`"));
+    }
+
+    Ok(Some(statement_descriptor.clone()))
+}
+
+/// Reconcile an amount credited to the virtual account issued for a bank transfer against the
+/// amount the customer was asked to transfer, as per the receiver details shown to the customer
+///
+/// The credit is added to the amounts received before, as the customer may pay in several
+/// transfers. An underpaid payment is partially charged, with the amount remaining shown to the
+/// customer, while the amount received in excess of an overpaid payment is recorded to be refunded.
+///
+/// The connectors may notify a credit more than once, so a credit whose reference is among the
+/// `credited_references` is not counted again, while the reference of a new credit is added to them.
+pub fn reconcile_bank_transfer_credit(
+    receiver: &payments::ReceiverDetails,
+    credited_references: &mut Vec<String>,
+    credit_reference: &str,
+    amount_credited: i64,
+) -> (enums::AttemptStatus, payments::ReceiverDetails) {
+    let amount_credited = if credited_references
+        .iter()
+        .any(|credited_reference| credited_reference == credit_reference)
+    {
+        0
+    } else {
+        credited_references.push(credit_reference.to_string());
+        amount_credited
+    };
+    let amount_expected = receiver.amount_received + receiver.amount_remaining.unwrap_or_default()
+        - receiver.amount_overpaid.unwrap_or_default();
+    let amount_received = receiver.amount_received + amount_credited;
+    let status = if amount_received <= 0 {
+        enums::AttemptStatus::AuthenticationPending
+    } else if amount_received < amount_expected {
+        enums::AttemptStatus::PartialCharged
+    } else {
+        enums::AttemptStatus::Charged
+    };
+
+    (
+        status,
+        payments::ReceiverDetails {
+            amount_received,
+            amount_charged: receiver.amount_charged,
+            amount_remaining: Some((amount_expected - amount_received).max(0)),
+            amount_overpaid: Some(amount_received - amount_expected)
+                .filter(|amount_overpaid| amount_overpaid.is_positive()),
+        },
+    )
+}
+
 #[cfg(test)]
 mod error_code_error_message_tests {
     #![allow(clippy::unwrap_used)]
@@ -2229,3 +2425,196 @@ mod error_code_error_message_tests {
         assert_eq!(error_code_error_message_none, None);
     }
 }
+
+#[cfg(test)]
+mod connector_metadata_mapping_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_mapped_metadata() {
+        let connector_meta_data = Some(Secret::new(serde_json::json!({
+            "endpoint_prefix": "prefix",
+            "metadata_mapping": { "order_ref": "udf1", "attempt": "udf2", "missing": "udf3" }
+        })));
+        let metadata_mapping = ConnectorMetadataMapping::try_from(&connector_meta_data).unwrap();
+        let metadata = Secret::new(serde_json::json!({ "order_ref": "ord_123", "attempt": 2 }));
+
+        assert_eq!(
+            metadata_mapping.get_mapped_metadata(Some(&metadata)),
+            HashMap::from([
+                ("udf1".to_string(), "ord_123".to_string()),
+                ("udf2".to_string(), "2".to_string()),
+            ])
+        );
+        assert!(metadata_mapping.get_mapped_metadata(None).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_metadata_mapping() {
+        let metadata_mapping = ConnectorMetadataMapping::try_from(&None).unwrap();
+        assert!(metadata_mapping.0.is_empty());
+
+        let connector_meta_data = Some(Secret::new(serde_json::json!({
+            "metadata_mapping": ["order_ref"]
+        })));
+        assert!(ConnectorMetadataMapping::try_from(&connector_meta_data).is_err());
+    }
+}
+
+#[cfg(test)]
+mod statement_descriptor_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_validate_statement_descriptor() {
+        let is_allowed_char =
+            |character: char| character.is_ascii_alphanumeric() || character == ' ';
+
+        assert_eq!(
+            validate_statement_descriptor(None, "statement_descriptor", 10, is_allowed_char)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            validate_statement_descriptor(
+                Some(&"Shoe Store".to_string()),
+                "statement_descriptor",
+                10,
+                is_allowed_char
+            )
+            .unwrap(),
+            Some("Shoe Store".to_string())
+        );
+        for statement_descriptor in ["Shoe Store 1", "Shoe*Store"] {
+            assert!(validate_statement_descriptor(
+                Some(&statement_descriptor.to_string()),
+                "statement_descriptor",
+                10,
+                is_allowed_char
+            )
+            .is_err());
+        }
+    }
+}
+
+#[cfg(test)]
+mod bank_transfer_reconciliation_tests {
+    use super::*;
+
+    fn get_receiver_details(amount: i64) -> payments::ReceiverDetails {
+        payments::ReceiverDetails {
+            amount_received: 0,
+            amount_charged: None,
+            amount_remaining: Some(amount),
+            amount_overpaid: None,
+        }
+    }
+
+    #[test]
+    fn test_bank_transfer_paid_in_full() {
+        let (status, receiver) = reconcile_bank_transfer_credit(
+            &get_receiver_details(1000),
+            &mut Vec::new(),
+            "credit_1",
+            1000,
+        );
+
+        assert_eq!(status, enums::AttemptStatus::Charged);
+        assert_eq!(receiver.amount_received, 1000);
+        assert_eq!(receiver.amount_remaining, Some(0));
+        assert_eq!(receiver.amount_overpaid, None);
+    }
+
+    #[test]
+    fn test_bank_transfer_underpaid() {
+        let (status, receiver) = reconcile_bank_transfer_credit(
+            &get_receiver_details(1000),
+            &mut Vec::new(),
+            "credit_1",
+            600,
+        );
+
+        assert_eq!(status, enums::AttemptStatus::PartialCharged);
+        assert_eq!(receiver.amount_remaining, Some(400));
+        assert_eq!(receiver.amount_overpaid, None);
+
+        let (status, _) = reconcile_bank_transfer_credit(
+            &get_receiver_details(1000),
+            &mut Vec::new(),
+            "credit_1",
+            0,
+        );
+        assert_eq!(status, enums::AttemptStatus::AuthenticationPending);
+    }
+
+    #[test]
+    fn test_bank_transfer_paid_in_several_credits() {
+        let mut credited_references = Vec::new();
+        let (status, receiver) = reconcile_bank_transfer_credit(
+            &get_receiver_details(1000),
+            &mut credited_references,
+            "credit_1",
+            600,
+        );
+        assert_eq!(status, enums::AttemptStatus::PartialCharged);
+
+        let (status, receiver) =
+            reconcile_bank_transfer_credit(&receiver, &mut credited_references, "credit_2", 300);
+        assert_eq!(status, enums::AttemptStatus::PartialCharged);
+        assert_eq!(receiver.amount_received, 900);
+        assert_eq!(receiver.amount_remaining, Some(100));
+
+        let (status, receiver) =
+            reconcile_bank_transfer_credit(&receiver, &mut credited_references, "credit_3", 100);
+        assert_eq!(status, enums::AttemptStatus::Charged);
+        assert_eq!(receiver.amount_received, 1000);
+        assert_eq!(receiver.amount_remaining, Some(0));
+        assert_eq!(receiver.amount_overpaid, None);
+        assert_eq!(credited_references, ["credit_1", "credit_2", "credit_3"]);
+    }
+
+    #[test]
+    fn test_bank_transfer_credit_notified_twice() {
+        let mut credited_references = Vec::new();
+        let (_, receiver) = reconcile_bank_transfer_credit(
+            &get_receiver_details(1000),
+            &mut credited_references,
+            "credit_1",
+            600,
+        );
+
+        // The credit notified again is not counted twice, so the payment remains underpaid
+        let (status, receiver) =
+            reconcile_bank_transfer_credit(&receiver, &mut credited_references, "credit_1", 600);
+        assert_eq!(status, enums::AttemptStatus::PartialCharged);
+        assert_eq!(receiver.amount_received, 600);
+        assert_eq!(receiver.amount_remaining, Some(400));
+        assert_eq!(receiver.amount_overpaid, None);
+        assert_eq!(credited_references, ["credit_1"]);
+    }
+
+    #[test]
+    fn test_bank_transfer_overpaid() {
+        let mut credited_references = Vec::new();
+        let (status, receiver) = reconcile_bank_transfer_credit(
+            &get_receiver_details(1000),
+            &mut credited_references,
+            "credit_1",
+            1250,
+        );
+
+        assert_eq!(status, enums::AttemptStatus::Charged);
+        assert_eq!(receiver.amount_remaining, Some(0));
+        assert_eq!(receiver.amount_overpaid, Some(250));
+
+        // A further credit is reconciled against the same amount expected
+        let (status, receiver) =
+            reconcile_bank_transfer_credit(&receiver, &mut credited_references, "credit_2", 100);
+        assert_eq!(status, enums::AttemptStatus::Charged);
+        assert_eq!(receiver.amount_received, 1350);
+        assert_eq!(receiver.amount_remaining, Some(0));
+        assert_eq!(receiver.amount_overpaid, Some(350));
+    }
+}
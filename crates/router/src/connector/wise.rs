@@ -1,12 +1,18 @@
 pub mod transformers;
 use std::fmt::Debug;
 
+#[cfg(feature = "payouts")]
+use base64::Engine;
 #[cfg(feature = "payouts")]
 use common_utils::request::RequestContent;
-use error_stack::{report, ResultExt};
+#[cfg(not(feature = "payouts"))]
+use error_stack::report;
+use error_stack::ResultExt;
 #[cfg(feature = "payouts")]
 use masking::PeekInterface;
 #[cfg(feature = "payouts")]
+use openssl::{hash::MessageDigest, pkey::PKey, sign::Verifier};
+#[cfg(feature = "payouts")]
 use router_env::{instrument, tracing};
 
 use self::transformers as wise;
@@ -27,7 +33,7 @@ use crate::{
     utils::BytesExt,
 };
 #[cfg(feature = "payouts")]
-use crate::{core::payments, routes};
+use crate::{connector::utils as connector_utils, consts, core::payments, routes, types::domain};
 
 #[derive(Debug, Clone)]
 pub struct Wise;
@@ -744,24 +750,132 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Wise {
+    #[cfg(feature = "payouts")]
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature =
+            connector_utils::get_header_key_value("X-Signature-SHA256", request.headers)?;
+        consts::BASE64_ENGINE
+            .decode(signature)
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    #[cfg(feature = "payouts")]
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(request.body.to_vec())
+    }
+
+    // Wise signs webhooks with SHA256withRSA, the merchant secret is expected to be Wise's public
+    // key in PEM format - https://docs.wise.com/api-docs/features/webhooks-notifications/event-handling
+    #[cfg(feature = "payouts")]
+    async fn verify_webhook_source(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        merchant_account: &domain::MerchantAccount,
+        merchant_connector_account: domain::MerchantConnectorAccount,
+        connector_label: &str,
+    ) -> CustomResult<bool, errors::ConnectorError> {
+        let connector_webhook_secrets = self
+            .get_webhook_source_verification_merchant_secret(
+                merchant_account,
+                connector_label,
+                merchant_connector_account,
+            )
+            .await
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let signature = self
+            .get_webhook_source_verification_signature(request, &connector_webhook_secrets)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let message = self
+            .get_webhook_source_verification_message(
+                request,
+                &merchant_account.merchant_id,
+                &connector_webhook_secrets,
+            )
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+
+        let Ok(public_key) = PKey::public_key_from_pem(&connector_webhook_secrets.secret) else {
+            router_env::logger::warn!("Wise webhook public key is not configured for the merchant");
+            return Ok(false);
+        };
+        let mut verifier = Verifier::new(MessageDigest::sha256(), &public_key)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        verifier
+            .update(&message)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        verifier
+            .verify(&signature)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
+    }
+
     fn get_webhook_object_reference_id(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        #[cfg(feature = "payouts")]
+        {
+            let webhook_body: wise::WiseWebhookBody = _request
+                .body
+                .parse_struct("WiseWebhookBody")
+                .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+            Ok(api_models::webhooks::ObjectReferenceId::PayoutId(
+                api_models::webhooks::PayoutIdType::ConnectorPayoutId(
+                    webhook_body.data.resource.id.to_string(),
+                ),
+            ))
+        }
+        #[cfg(not(feature = "payouts"))]
+        {
+            Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        }
     }
 
     fn get_webhook_event_type(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        #[cfg(feature = "payouts")]
+        {
+            let webhook_body: wise::WiseWebhookBody = _request
+                .body
+                .parse_struct("WiseWebhookBody")
+                .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+            Ok(match webhook_body.event_type {
+                wise::WiseWebhookEventType::TransferStateChange => {
+                    api::IncomingWebhookEvent::from(webhook_body.data.current_state)
+                }
+                wise::WiseWebhookEventType::Unknown => api::IncomingWebhookEvent::EventNotSupported,
+            })
+        }
+        #[cfg(not(feature = "payouts"))]
+        {
+            Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        }
     }
 
     fn get_webhook_resource_object(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        #[cfg(feature = "payouts")]
+        {
+            let webhook_body: wise::WiseWebhookBody = _request
+                .body
+                .parse_struct("WiseWebhookBody")
+                .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+            Ok(Box::new(webhook_body))
+        }
+        #[cfg(not(feature = "payouts"))]
+        {
+            Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        }
     }
 }
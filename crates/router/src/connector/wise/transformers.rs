@@ -289,6 +289,74 @@ pub enum WiseStatus {
     IncomingPaymentWaiting,
 }
 
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WiseWebhookBody {
+    pub event_type: WiseWebhookEventType,
+    pub data: WiseWebhookData,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WiseWebhookData {
+    pub resource: WiseWebhookResource,
+    pub current_state: WiseWebhookTransferState,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WiseWebhookResource {
+    pub id: i64,
+    #[serde(rename = "type")]
+    pub resource_type: String,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+pub enum WiseWebhookEventType {
+    #[serde(rename = "transfers#state-change")]
+    TransferStateChange,
+    #[serde(other)]
+    Unknown,
+}
+
+// Transfer states - https://docs.wise.com/api-docs/guides/send-money/tracking
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WiseWebhookTransferState {
+    IncomingPaymentWaiting,
+    IncomingPaymentInitiated,
+    Processing,
+    FundsConverted,
+    OutgoingPaymentSent,
+    Cancelled,
+    FundsRefunded,
+    BouncedBack,
+    ChargedBack,
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(feature = "payouts")]
+impl From<WiseWebhookTransferState> for api_models::webhooks::IncomingWebhookEvent {
+    fn from(state: WiseWebhookTransferState) -> Self {
+        match state {
+            WiseWebhookTransferState::IncomingPaymentWaiting
+            | WiseWebhookTransferState::IncomingPaymentInitiated
+            | WiseWebhookTransferState::Processing
+            | WiseWebhookTransferState::FundsConverted => Self::PayoutProcessing,
+            WiseWebhookTransferState::OutgoingPaymentSent => Self::PayoutSuccess,
+            WiseWebhookTransferState::Cancelled => Self::PayoutCancelled,
+            WiseWebhookTransferState::FundsRefunded => Self::PayoutFailure,
+            WiseWebhookTransferState::BouncedBack | WiseWebhookTransferState::ChargedBack => {
+                Self::PayoutReversed
+            }
+            WiseWebhookTransferState::Unknown => Self::EventNotSupported,
+        }
+    }
+}
+
 #[cfg(feature = "payouts")]
 fn get_payout_address_details(
     address: Option<&api_models::payments::Address>,
@@ -553,11 +621,18 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, WiseFulfillResponse>>
         item: types::PayoutsResponseRouterData<F, WiseFulfillResponse>,
     ) -> Result<Self, Self::Error> {
         let response: WiseFulfillResponse = item.response;
+        // Fund transfer response does not carry the transfer id, keep the one from transfer creation
+        let connector_payout_id = item
+            .data
+            .request
+            .connector_payout_id
+            .clone()
+            .unwrap_or_default();
 
         Ok(Self {
             response: Ok(types::PayoutsResponseData {
                 status: Some(storage_enums::PayoutStatus::from(response.status)),
-                connector_payout_id: "".to_string(),
+                connector_payout_id,
                 payout_eligible: None,
                 should_add_next_step_to_process_tracker: false,
             }),
@@ -749,11 +749,21 @@ impl api::IncomingWebhook for Worldpay {
             .body
             .parse_struct("WorldpayWebhookTransactionId")
             .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
-        Ok(api_models::webhooks::ObjectReferenceId::PaymentId(
-            types::api::PaymentIdType::ConnectorTransactionId(
-                body.event_details.transaction_reference,
-            ),
-        ))
+        if worldpay::is_refund_event(&body.event_details.event_type) {
+            let refund_id = body
+                .event_details
+                .reference
+                .ok_or(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+            Ok(api_models::webhooks::ObjectReferenceId::RefundId(
+                api_models::webhooks::RefundIdType::RefundId(refund_id),
+            ))
+        } else {
+            Ok(api_models::webhooks::ObjectReferenceId::PaymentId(
+                types::api::PaymentIdType::ConnectorTransactionId(
+                    body.event_details.transaction_reference,
+                ),
+            ))
+        }
     }
 
     fn get_webhook_event_type(
@@ -764,22 +774,9 @@ impl api::IncomingWebhook for Worldpay {
             .body
             .parse_struct("WorldpayWebhookEventType")
             .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
-        match body.event_details.event_type {
-            EventType::SentForSettlement | EventType::Charged => {
-                Ok(api::IncomingWebhookEvent::PaymentIntentSuccess)
-            }
-            EventType::Error | EventType::Expired => {
-                Ok(api::IncomingWebhookEvent::PaymentIntentFailure)
-            }
-            EventType::Unknown
-            | EventType::Authorized
-            | EventType::Cancelled
-            | EventType::Refused
-            | EventType::Refunded
-            | EventType::SentForRefund
-            | EventType::CaptureFailed
-            | EventType::RefundFailed => Ok(api::IncomingWebhookEvent::EventNotSupported),
-        }
+        Ok(api::IncomingWebhookEvent::from(
+            body.event_details.event_type,
+        ))
     }
 
     fn get_webhook_resource_object(
@@ -790,7 +787,26 @@ impl api::IncomingWebhook for Worldpay {
             .body
             .parse_struct("WorldpayWebhookEventType")
             .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
-        let psync_body = WorldpayEventResponse::try_from(body)?;
-        Ok(Box::new(psync_body))
+        if worldpay::is_dispute_event(&body.event_details.event_type) {
+            let dispute_details = body
+                .event_details
+                .dispute
+                .ok_or(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+            Ok(Box::new(dispute_details))
+        } else {
+            let psync_body = WorldpayEventResponse::try_from(body)?;
+            Ok(Box::new(psync_body))
+        }
+    }
+
+    fn get_dispute_details(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::disputes::DisputePayload, errors::ConnectorError> {
+        let body: WorldpayWebhookEventType = request
+            .body
+            .parse_struct("WorldpayWebhookEventType")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        api::disputes::DisputePayload::try_from(body.event_details)
     }
 }
@@ -1,5 +1,6 @@
 use masking::Secret;
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 
 use crate::{core::errors, types};
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -38,8 +39,9 @@ pub struct WorldpayEventResponse {
     pub links: Option<EventLinks>,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, strum::Display)]
 #[serde(rename_all = "camelCase")]
+#[strum(serialize_all = "camelCase")]
 pub enum EventType {
     Authorized,
     Cancelled,
@@ -52,6 +54,11 @@ pub enum EventType {
     SentForSettlement,
     Expired,
     CaptureFailed,
+    InformationRequested,
+    InformationSupplied,
+    ChargedBack,
+    ChargebackReversed,
+    DisputeExpired,
     #[serde(other)]
     Unknown,
 }
@@ -323,8 +330,45 @@ pub struct WorldpayWebhookTransactionId {
 #[serde(rename_all = "camelCase")]
 pub struct EventDetails {
     pub transaction_reference: String,
+    /// Reference of the refund, for the refund events
+    pub reference: Option<String>,
     #[serde(rename = "type")]
     pub event_type: EventType,
+    /// Details of the dispute, for the dispute events
+    pub dispute: Option<WorldpayDisputeDetails>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldpayDisputeDetails {
+    pub dispute_id: String,
+    pub value: WorldpayDisputeValue,
+    pub reason: Option<String>,
+    pub reason_code: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub raised_at: Option<PrimitiveDateTime>,
+    /// Stage of the dispute lifecycle, which is not sent for the first chargeback
+    pub stage: Option<WorldpayDisputeStage>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WorldpayDisputeStage {
+    Chargeback,
+    SecondChargeback,
+    PreArbitration,
+    Arbitration,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldpayDisputeValue {
+    pub amount: i64,
+    pub currency: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -9,7 +9,7 @@ use crate::{
     connector::utils,
     consts,
     core::errors,
-    types::{self, domain, PaymentsAuthorizeData, PaymentsResponseData},
+    types::{self, api, domain, PaymentsAuthorizeData, PaymentsResponseData},
 };
 
 #[derive(Debug, Serialize)]
@@ -88,6 +88,8 @@ fn fetch_payment_instrument(
             | domain::WalletData::WeChatPayRedirect(_)
             | domain::WalletData::CashappQr(_)
             | domain::WalletData::SwishQr(_)
+            | domain::WalletData::ClickToPay(_)
+            | domain::WalletData::SkrillRedirect {}
             | domain::WalletData::WeChatPayQr(_) => Err(errors::ConnectorError::NotImplemented(
                 utils::get_unimplemented_payment_method_error_message("worldpay"),
             )
@@ -104,10 +106,13 @@ fn fetch_payment_instrument(
         | domain::PaymentMethodData::Voucher(_)
         | domain::PaymentMethodData::CardRedirect(_)
         | domain::PaymentMethodData::GiftCard(_)
-        | domain::PaymentMethodData::CardToken(_) => Err(errors::ConnectorError::NotImplemented(
-            utils::get_unimplemented_payment_method_error_message("worldpay"),
-        )
-        .into()),
+        | domain::PaymentMethodData::CardToken(_)
+        | domain::PaymentMethodData::RealTimePayment(_) => {
+            Err(errors::ConnectorError::NotImplemented(
+                utils::get_unimplemented_payment_method_error_message("worldpay"),
+            )
+            .into())
+        }
     }
 }
 
@@ -205,6 +210,11 @@ impl From<EventType> for enums::AttemptStatus {
             | EventType::Refunded
             | EventType::Error
             | EventType::Expired
+            | EventType::InformationRequested
+            | EventType::InformationSupplied
+            | EventType::ChargedBack
+            | EventType::ChargebackReversed
+            | EventType::DisputeExpired
             | EventType::Unknown => Self::Pending,
         }
     }
@@ -224,11 +234,104 @@ impl From<EventType> for enums::RefundStatus {
             | EventType::SentForSettlement
             | EventType::Expired
             | EventType::CaptureFailed
+            | EventType::InformationRequested
+            | EventType::InformationSupplied
+            | EventType::ChargedBack
+            | EventType::ChargebackReversed
+            | EventType::DisputeExpired
             | EventType::Unknown => Self::Pending,
         }
     }
 }
 
+pub fn is_refund_event(event_type: &EventType) -> bool {
+    matches!(
+        event_type,
+        EventType::SentForRefund | EventType::Refunded | EventType::RefundFailed
+    )
+}
+
+pub fn is_dispute_event(event_type: &EventType) -> bool {
+    matches!(
+        event_type,
+        EventType::InformationRequested
+            | EventType::InformationSupplied
+            | EventType::ChargedBack
+            | EventType::ChargebackReversed
+            | EventType::DisputeExpired
+    )
+}
+
+/// Stage of the dispute, as Worldpay notifies the retrieval requests before raising the chargeback
+/// and the second chargebacks and arbitrations with the same events as the first chargeback
+pub fn get_dispute_stage(
+    event_type: &EventType,
+    stage: Option<&WorldpayDisputeStage>,
+) -> api_models::enums::DisputeStage {
+    match (event_type, stage) {
+        (EventType::InformationRequested | EventType::InformationSupplied, _) => {
+            api_models::enums::DisputeStage::PreDispute
+        }
+        (
+            _,
+            Some(
+                WorldpayDisputeStage::SecondChargeback
+                | WorldpayDisputeStage::PreArbitration
+                | WorldpayDisputeStage::Arbitration,
+            ),
+        ) => api_models::enums::DisputeStage::PreArbitration,
+        _ => api_models::enums::DisputeStage::Dispute,
+    }
+}
+
+impl TryFrom<EventDetails> for api::disputes::DisputePayload {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(event_details: EventDetails) -> Result<Self, Self::Error> {
+        let dispute_details =
+            event_details
+                .dispute
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "eventDetails.dispute",
+                })?;
+        Ok(Self {
+            amount: dispute_details.value.amount.to_string(),
+            currency: dispute_details.value.currency,
+            dispute_stage: get_dispute_stage(
+                &event_details.event_type,
+                dispute_details.stage.as_ref(),
+            ),
+            connector_status: event_details.event_type.to_string(),
+            connector_dispute_id: dispute_details.dispute_id,
+            connector_reason: dispute_details.reason,
+            connector_reason_code: dispute_details.reason_code,
+            challenge_required_by: dispute_details.challenge_required_by,
+            created_at: dispute_details.raised_at,
+            updated_at: None,
+        })
+    }
+}
+
+impl From<EventType> for api::IncomingWebhookEvent {
+    fn from(event_type: EventType) -> Self {
+        match event_type {
+            EventType::SentForSettlement | EventType::Charged => Self::PaymentIntentSuccess,
+            EventType::Authorized => Self::PaymentIntentAuthorizationSuccess,
+            EventType::Refused => Self::PaymentIntentAuthorizationFailure,
+            EventType::Error | EventType::Expired => Self::PaymentIntentFailure,
+            EventType::Cancelled => Self::PaymentIntentCancelled,
+            EventType::CaptureFailed => Self::PaymentIntentCaptureFailure,
+            EventType::Refunded => Self::RefundSuccess,
+            EventType::RefundFailed => Self::RefundFailure,
+            EventType::InformationRequested => Self::DisputeOpened,
+            EventType::InformationSupplied => Self::DisputeChallenged,
+            EventType::ChargedBack => Self::DisputeLost,
+            EventType::ChargebackReversed => Self::DisputeWon,
+            EventType::DisputeExpired => Self::DisputeExpired,
+            EventType::SentForRefund | EventType::Unknown => Self::EventNotSupported,
+        }
+    }
+}
+
 impl TryFrom<types::PaymentsResponseRouterData<WorldpayPaymentsResponse>>
     for types::PaymentsAuthorizeRouterData
 {
@@ -262,7 +365,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for WorldpayRefundRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
         Ok(Self {
-            reference: item.request.connector_transaction_id.clone(),
+            reference: item.request.refund_id.clone(),
             value: PaymentValue {
                 amount: item.request.refund_amount,
                 currency: item.request.currency.to_string(),
@@ -280,3 +383,200 @@ impl TryFrom<WorldpayWebhookEventType> for WorldpayEventResponse {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_event_details(event_details: serde_json::Value) -> EventDetails {
+        let body = serde_json::json!({
+            "eventId": "evt_1",
+            "eventTimestamp": "2024-01-10T10:00:00.000Z",
+            "eventDetails": event_details,
+        });
+        serde_json::from_value::<WorldpayWebhookEventType>(body)
+            .unwrap()
+            .event_details
+    }
+
+    fn get_dispute_event_details(event_type: &str, stage: Option<&str>) -> EventDetails {
+        get_event_details(serde_json::json!({
+            "transactionReference": "txn_1",
+            "type": event_type,
+            "dispute": {
+                "disputeId": "dsp_1",
+                "value": { "amount": 1000, "currency": "GBP" },
+                "reason": "Fraud",
+                "reasonCode": "10.4",
+                "challengeRequiredBy": "2024-01-20T10:00:00.000Z",
+                "raisedAt": "2024-01-10T10:00:00.000Z",
+                "stage": stage,
+            },
+        }))
+    }
+
+    #[test]
+    fn test_refund_notification() {
+        let event_details = get_event_details(serde_json::json!({
+            "transactionReference": "txn_1",
+            "reference": "ref_1",
+            "type": "refunded",
+        }));
+
+        assert_eq!(event_details.event_type, EventType::Refunded);
+        assert!(is_refund_event(&event_details.event_type));
+        assert_eq!(event_details.reference.as_deref(), Some("ref_1"));
+        assert_eq!(
+            enums::RefundStatus::from(event_details.event_type.clone()),
+            enums::RefundStatus::Success
+        );
+        assert_eq!(
+            api::IncomingWebhookEvent::from(event_details.event_type),
+            api::IncomingWebhookEvent::RefundSuccess
+        );
+
+        let event_details = get_event_details(serde_json::json!({
+            "transactionReference": "txn_1",
+            "reference": "ref_1",
+            "type": "refundFailed",
+        }));
+
+        assert!(is_refund_event(&event_details.event_type));
+        assert_eq!(
+            enums::RefundStatus::from(event_details.event_type.clone()),
+            enums::RefundStatus::Failure
+        );
+        assert_eq!(
+            api::IncomingWebhookEvent::from(event_details.event_type),
+            api::IncomingWebhookEvent::RefundFailure
+        );
+
+        let event_details = get_event_details(serde_json::json!({
+            "transactionReference": "txn_1",
+            "reference": "ref_1",
+            "type": "sentForRefund",
+        }));
+
+        assert!(is_refund_event(&event_details.event_type));
+        assert_eq!(
+            api::IncomingWebhookEvent::from(event_details.event_type),
+            api::IncomingWebhookEvent::EventNotSupported
+        );
+    }
+
+    #[test]
+    fn test_dispute_notification() {
+        let event_details = get_dispute_event_details("chargedBack", None);
+
+        assert!(is_dispute_event(&event_details.event_type));
+        assert!(!is_refund_event(&event_details.event_type));
+        assert_eq!(
+            api::IncomingWebhookEvent::from(event_details.event_type.clone()),
+            api::IncomingWebhookEvent::DisputeLost
+        );
+
+        let dispute_payload = api::disputes::DisputePayload::try_from(event_details).unwrap();
+        assert_eq!(dispute_payload.amount, "1000");
+        assert_eq!(dispute_payload.currency, "GBP");
+        assert_eq!(
+            dispute_payload.dispute_stage,
+            api_models::enums::DisputeStage::Dispute
+        );
+        assert_eq!(dispute_payload.connector_status, "chargedBack");
+        assert_eq!(dispute_payload.connector_dispute_id, "dsp_1");
+        assert_eq!(dispute_payload.connector_reason.as_deref(), Some("Fraud"));
+        assert_eq!(
+            dispute_payload.connector_reason_code.as_deref(),
+            Some("10.4")
+        );
+        assert!(dispute_payload.challenge_required_by.is_some());
+        assert!(dispute_payload.created_at.is_some());
+    }
+
+    #[test]
+    fn test_dispute_notification_events() {
+        for (event_type, webhook_event) in [
+            (
+                "informationRequested",
+                api::IncomingWebhookEvent::DisputeOpened,
+            ),
+            (
+                "informationSupplied",
+                api::IncomingWebhookEvent::DisputeChallenged,
+            ),
+            ("chargedBack", api::IncomingWebhookEvent::DisputeLost),
+            ("chargebackReversed", api::IncomingWebhookEvent::DisputeWon),
+            ("disputeExpired", api::IncomingWebhookEvent::DisputeExpired),
+        ] {
+            let event_details = get_dispute_event_details(event_type, None);
+            assert!(is_dispute_event(&event_details.event_type));
+            assert_eq!(
+                api::IncomingWebhookEvent::from(event_details.event_type),
+                webhook_event
+            );
+        }
+    }
+
+    #[test]
+    fn test_dispute_notification_stages() {
+        for (event_type, stage, dispute_stage) in [
+            (
+                "informationRequested",
+                None,
+                api_models::enums::DisputeStage::PreDispute,
+            ),
+            (
+                "informationSupplied",
+                None,
+                api_models::enums::DisputeStage::PreDispute,
+            ),
+            (
+                "chargedBack",
+                Some("chargeback"),
+                api_models::enums::DisputeStage::Dispute,
+            ),
+            (
+                "chargedBack",
+                Some("secondChargeback"),
+                api_models::enums::DisputeStage::PreArbitration,
+            ),
+            (
+                "chargebackReversed",
+                None,
+                api_models::enums::DisputeStage::Dispute,
+            ),
+            (
+                "chargebackReversed",
+                Some("preArbitration"),
+                api_models::enums::DisputeStage::PreArbitration,
+            ),
+            (
+                "chargedBack",
+                Some("arbitration"),
+                api_models::enums::DisputeStage::PreArbitration,
+            ),
+            (
+                "chargedBack",
+                Some("somethingNew"),
+                api_models::enums::DisputeStage::Dispute,
+            ),
+        ] {
+            let dispute_payload = api::disputes::DisputePayload::try_from(
+                get_dispute_event_details(event_type, stage),
+            )
+            .unwrap();
+            assert_eq!(dispute_payload.dispute_stage, dispute_stage);
+        }
+    }
+
+    #[test]
+    fn test_dispute_notification_without_dispute_details() {
+        let event_details = get_event_details(serde_json::json!({
+            "transactionReference": "txn_1",
+            "type": "chargedBack",
+        }));
+
+        assert!(api::disputes::DisputePayload::try_from(event_details).is_err());
+    }
+}
@@ -16,10 +16,23 @@ pub(crate) const ALPHABETS: [char; 62] = [
 /// API client request timeout (in seconds)
 pub const REQUEST_TIME_OUT: u64 = 30;
 pub const REQUEST_TIMEOUT_ERROR_CODE: &str = "TIMEOUT";
+pub const CONNECTOR_RATE_LIMITED_ERROR_CODE: &str = "RATE_LIMITED";
+pub const CONNECTOR_RATE_LIMITED_ERROR_MESSAGE: &str =
+    "Rate limit exceeded for the calls made to the connector";
+pub const CONNECTOR_RATE_LIMITED_UNIFIED_CODE: &str = "UE_7000";
+pub const CONNECTOR_RATE_LIMITED_UNIFIED_MESSAGE: &str =
+    "The payment processor is temporarily unavailable, please try again later";
 pub const REQUEST_TIMEOUT_ERROR_MESSAGE: &str = "Connector did not respond in specified time";
 pub const REQUEST_TIMEOUT_PAYMENT_NOT_FOUND: &str = "Timed out ,payment not found";
 pub const REQUEST_TIMEOUT_ERROR_MESSAGE_FROM_PSYNC: &str =
     "This Payment has been moved to failed as there is no response from the connector";
+pub const QR_CODE_EXPIRED_ERROR_MESSAGE_FROM_PSYNC: &str =
+    "This Payment has been moved to failed as the QR code was not scanned before it expired";
+pub const QR_CODE_EXPIRED_ERROR_CODE: &str = "QR_CODE_EXPIRED";
+pub const QR_CODE_EXPIRED_ERROR_MESSAGE: &str = "The QR code expired before it was scanned";
+pub const PAYMENT_EXPIRED_ERROR_CODE: &str = "PAYMENT_EXPIRED";
+pub const PAYMENT_EXPIRED_ERROR_MESSAGE: &str =
+    "This Payment has been moved to expired as it was not confirmed before its session expired";
 
 ///Payment intent fulfillment default timeout (in seconds)
 pub const DEFAULT_FULFILLMENT_TIME: i64 = 15 * 60;
@@ -100,6 +113,9 @@ pub const MAX_SESSION_EXPIRY: u32 = 7890000;
 /// Min payment session expiry
 pub const MIN_SESSION_EXPIRY: u32 = 60;
 
+/// Max automatic retries of a payment on the next connectors, which business profiles can configure
+pub const MAX_AUTO_RETRIES: u8 = 5;
+
 pub const LOCKER_HEALTH_CALL_PATH: &str = "/health";
 
 pub const AUTHENTICATION_ID_PREFIX: &str = "authn";
@@ -113,3 +129,31 @@ pub const POLL_ID_TTL: i64 = 900;
 // Default Poll Config
 pub const DEFAULT_POLL_DELAY_IN_SECS: i8 = 2;
 pub const DEFAULT_POLL_FREQUENCY: i8 = 5;
+
+/// Default number of days in advance that customers are notified of SEPA Direct Debit debits
+pub const DEFAULT_SEPA_PRE_NOTIFICATION_DAYS: u8 = 14;
+
+/// Max number of tags of a payment
+pub const MAX_PAYMENT_TAGS: usize = 20;
+
+/// Max length of a tag of a payment
+pub const MAX_PAYMENT_TAG_LENGTH: usize = 64;
+
+/// Min and max number of installments of the installment plans of the card payments
+pub const MIN_INSTALLMENT_TENURE: u8 = 2;
+pub const MAX_INSTALLMENT_TENURE: u8 = 60;
+
+/// Max number of saved payment filters of a merchant
+#[cfg(feature = "olap")]
+pub const MAX_SAVED_PAYMENT_FILTERS: usize = 50;
+
+/// Response headers carrying the identifier connectors assign to the requests made to them, in
+/// the order they are looked up in
+pub const CONNECTOR_REQUEST_ID_HEADERS: [&str; 6] = [
+    "request-id",
+    "cko-request-id",
+    "v-c-correlation-id",
+    "pspreference",
+    "paypal-debug-id",
+    "x-request-id",
+];
@@ -4,13 +4,19 @@ pub mod api_locking;
 pub mod authentication;
 pub mod blocklist;
 pub mod cache;
+pub mod card_data_redaction;
 pub mod cards_info;
+pub mod click_to_pay;
 pub mod conditional_config;
 pub mod configs;
 #[cfg(feature = "olap")]
+pub mod connector_migration;
+#[cfg(feature = "olap")]
 pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
+#[cfg(feature = "olap")]
+pub mod customer_data_export;
 pub mod customers;
 pub mod disputes;
 pub mod errors;
@@ -19,6 +25,7 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+pub mod idempotency;
 pub mod locker_migration;
 pub mod mandate;
 pub mod metrics;
@@ -30,8 +37,11 @@ pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
 pub mod refunds;
+pub mod request_priority;
 pub mod routing;
 pub mod surcharge_decision_config;
+pub mod tax_calculation;
+pub mod usage;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
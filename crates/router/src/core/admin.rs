@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{collections::HashSet, str::FromStr};
 
 use api_models::{
     admin::{self as admin_types},
@@ -15,15 +15,17 @@ use error_stack::{report, FutureExt, ResultExt};
 use futures::future::try_join_all;
 use masking::{PeekInterface, Secret};
 use pm_auth::connector::plaid::transformers::PlaidAuthType;
+use router_env::tracing::Instrument;
 use uuid::Uuid;
 
 use crate::{
     consts,
     core::{
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
-        payments::helpers,
+        payments::{abandoned_payment, auto_void, dunning, helpers, wallets},
         routing::helpers as routing_helpers,
         utils as core_utils,
+        webhooks::endpoint_health,
     },
     db::StorageInterface,
     routes::{metrics, AppState},
@@ -113,6 +115,7 @@ pub async fn create_merchant_account(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to decrypt data from key store")?,
         created_at: date_time::now(),
+        external_key_uri: None,
     };
 
     let enable_payment_response_hash = req.enable_payment_response_hash.unwrap_or(true);
@@ -145,6 +148,18 @@ pub async fn create_merchant_account(
         .transpose()?
         .map(Secret::new);
 
+    let feature_entitlements = req
+        .feature_entitlements
+        .as_ref()
+        .map(|feature_entitlements| {
+            feature_entitlements.encode_to_value().change_context(
+                errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "feature_entitlements",
+                },
+            )
+        })
+        .transpose()?;
+
     let fingerprint = Some(utils::generate_id(consts::FINGERPRINT_SECRET_LENGTH, "fs"));
     if let Some(fingerprint) = fingerprint {
         db.insert_config(configs::ConfigNew {
@@ -216,6 +231,7 @@ pub async fn create_merchant_account(
             default_profile: None,
             recon_status: diesel_models::enums::ReconStatus::NotRequested,
             payment_link_config: None,
+            feature_entitlements,
         })
     }
     .await
@@ -440,6 +456,13 @@ pub async fn update_business_profile_cascade(
             session_expiry: None,
             authentication_connector_details: None,
             extended_card_info_config: None,
+            dunning_config: None,
+            is_network_tokenization_enabled: None,
+            is_tax_calculation_enabled: None,
+            requires_cvc_for_saved_cards: None,
+            auto_void_after_in_hours: None,
+            abandoned_payment_config: None,
+            max_auto_retries: None,
         };
 
         let update_futures = business_profiles.iter().map(|business_profile| async {
@@ -715,9 +738,23 @@ fn validate_certificate_in_mca_metadata(
                     let api_models::payments::SessionTokenInfo {
                         certificate,
                         certificate_keys,
+                        payment_processing_details_at,
                         ..
                     } = session_token_data;
 
+                    if let Some(api_models::payments::PaymentProcessingDetailsAt::Hyperswitch(
+                        payment_processing_details,
+                    )) = payment_processing_details_at
+                    {
+                        wallets::validate_apple_pay_payment_processing_details(
+                            &payment_processing_details,
+                        )
+                        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                            field_name:
+                                "payment_processing_certificate/payment_processing_certificate_key",
+                        })?;
+                    }
+
                     helpers::create_identity_from_certificate_and_key(certificate, certificate_keys)
                         .change_context(errors::ApiErrorResponse::InvalidDataValue {
                             field_name: "certificate/certificate key",
@@ -730,6 +767,63 @@ fn validate_certificate_in_mca_metadata(
     Ok(())
 }
 
+/// The installment plans are offered for the card payment method types only, each over a distinct
+/// tenure, and restricted to the cards of valid BINs
+fn validate_installment_plans(
+    payment_methods_enabled: &[api_models::admin::PaymentMethodsEnabled],
+) -> Result<(), errors::ApiErrorResponse> {
+    let invalid_data = |message: String| errors::ApiErrorResponse::InvalidRequestData { message };
+    let is_valid_card_bin = |card_bin: &String| {
+        (6..=8).contains(&card_bin.len()) && card_bin.chars().all(|c| c.is_ascii_digit())
+    };
+
+    for payment_methods in payment_methods_enabled {
+        for payment_method_type in payment_methods.payment_method_types.iter().flatten() {
+            let Some(installment_plans) = payment_method_type.installment_plans.as_ref() else {
+                continue;
+            };
+            if payment_methods.payment_method != api_enums::PaymentMethod::Card {
+                return Err(invalid_data(format!(
+                    "installment_plans are supported only for card payment method types, not for {}",
+                    payment_method_type.payment_method_type
+                )));
+            }
+
+            let mut tenures = HashSet::new();
+            for installment_plan in installment_plans {
+                if !(consts::MIN_INSTALLMENT_TENURE..=consts::MAX_INSTALLMENT_TENURE)
+                    .contains(&installment_plan.tenure)
+                {
+                    return Err(invalid_data(format!(
+                        "the tenure of the installment plans should be between {} and {}",
+                        consts::MIN_INSTALLMENT_TENURE,
+                        consts::MAX_INSTALLMENT_TENURE
+                    )));
+                }
+                if !tenures.insert(installment_plan.tenure) {
+                    return Err(invalid_data(format!(
+                        "the installment plans of {} have more than one plan of tenure {}",
+                        payment_method_type.payment_method_type, installment_plan.tenure
+                    )));
+                }
+                if !installment_plan
+                    .card_bins
+                    .iter()
+                    .flatten()
+                    .all(is_valid_card_bin)
+                {
+                    return Err(invalid_data(
+                        "the card_bins of the installment plans should be 6 to 8 digits"
+                            .to_string(),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn create_payment_connector(
     state: AppState,
     req: api::MerchantConnectorCreate,
@@ -750,6 +844,9 @@ pub async fn create_payment_connector(
         .clone()
         .map(validate_certificate_in_mca_metadata)
         .transpose()?;
+    if let Some(payment_methods_enabled) = req.payment_methods_enabled.as_ref() {
+        validate_installment_plans(payment_methods_enabled)?;
+    }
 
     let merchant_account = state
         .store
@@ -1022,6 +1119,19 @@ pub async fn create_payment_connector(
         }
     }
 
+    let warmup_state = state.clone();
+    let warmup_connector_name = req.connector_name.to_string();
+    tokio::spawn(
+        async move {
+            service_api::connection_warmup::warm_up_connector_connection(
+                &warmup_state,
+                warmup_connector_name,
+            )
+            .await
+        }
+        .in_current_span(),
+    );
+
     metrics::MCA_CREATE.add(
         &metrics::CONTEXT,
         1,
@@ -1179,6 +1289,9 @@ pub async fn update_payment_connector(
             id: merchant_connector_id.to_string(),
         })?;
 
+    if let Some(payment_methods_enabled) = req.payment_methods_enabled.as_ref() {
+        validate_installment_plans(payment_methods_enabled)?;
+    }
     let payment_methods_enabled = req.payment_methods_enabled.map(|pm_enabled| {
         pm_enabled
             .iter()
@@ -1418,6 +1531,132 @@ pub async fn kv_for_merchant(
     ))
 }
 
+pub async fn update_merchant_key_custody(
+    state: AppState,
+    req: admin_types::MerchantKeyCustodyRequest,
+) -> RouterResponse<admin_types::MerchantKeyCustodyResponse> {
+    let db = state.store.as_ref();
+    let master_key = db.get_master_key();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&req.merchant_id, &master_key.to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    if key_store.external_key_uri == req.external_key_uri {
+        return Ok(service_api::ApplicationResponse::Json(
+            admin_types::MerchantKeyCustodyResponse {
+                merchant_id: key_store.merchant_id,
+                external_key_uri: key_store.external_key_uri,
+            },
+        ));
+    }
+
+    // The key is wrapped with the merchant provided key before being encrypted with the master
+    // key, so that the merchant can revoke our access to its data at any time
+    let key = match req.external_key_uri.as_deref() {
+        Some(external_key_uri) => services::encryption::wrap_merchant_key(
+            external_key_uri,
+            key_store.key.get_inner().peek(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Unable to use the provided key, ensure that it has been shared with a grant"
+                .to_string(),
+        })?,
+        None => key_store.key.get_inner().peek().clone(),
+    };
+
+    let key = domain_types::encrypt(key.into(), master_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt merchant key")?;
+
+    let updated_key_store = db
+        .update_merchant_key_store_by_merchant_id(
+            &req.merchant_id,
+            storage::MerchantKeyStoreUpdate::KeyCustodyUpdate {
+                key: key.into(),
+                external_key_uri: req.external_key_uri,
+            },
+            &master_key.to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Failed to update merchant key custody")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        admin_types::MerchantKeyCustodyResponse {
+            merchant_id: updated_key_store.merchant_id,
+            external_key_uri: updated_key_store.external_key_uri,
+        },
+    ))
+}
+
+pub async fn update_merchant_feature_entitlements(
+    state: AppState,
+    merchant_id: String,
+    req: api::MerchantFeatureEntitlements,
+) -> RouterResponse<api::MerchantAccountResponse> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let feature_entitlements = req
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode feature entitlements")?;
+
+    let updated_merchant_account = db
+        .update_merchant(
+            merchant_account,
+            storage::MerchantAccountUpdate::FeatureEntitlementsUpdate {
+                feature_entitlements,
+            },
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Failed to update merchant feature entitlements")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::MerchantAccountResponse::try_from(updated_merchant_account)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while generating response")?,
+    ))
+}
+
+/// Reject the request if the merchant is not entitled to use the feature
+pub fn validate_feature_entitlement(
+    merchant_account: &domain::MerchantAccount,
+    feature: api::MerchantFeature,
+) -> RouterResult<()> {
+    let feature_entitlements = merchant_account
+        .get_feature_entitlements()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse feature entitlements of the merchant account")?;
+
+    if feature_entitlements.is_entitled_to(feature) {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: feature.to_string(),
+        }))
+        .attach_printable_lazy(|| {
+            format!(
+                "Merchant {} is not entitled to use {feature}",
+                merchant_account.merchant_id
+            )
+        })
+    }
+}
+
 pub async fn check_merchant_account_kv_status(
     state: AppState,
     merchant_id: String,
@@ -1498,6 +1737,18 @@ pub async fn create_business_profile(
     if let Some(session_expiry) = &request.session_expiry {
         helpers::validate_session_expiry(session_expiry.to_owned())?;
     }
+    if let Some(dunning_config) = &request.dunning_config {
+        dunning::validate_dunning_config(dunning_config)?;
+    }
+    if let Some(auto_void_after_in_hours) = request.auto_void_after_in_hours {
+        auto_void::validate_auto_void_after_in_hours(auto_void_after_in_hours)?;
+    }
+    if let Some(abandoned_payment_config) = &request.abandoned_payment_config {
+        abandoned_payment::validate_abandoned_payment_config(abandoned_payment_config)?;
+    }
+    if let Some(max_auto_retries) = request.max_auto_retries {
+        helpers::validate_max_auto_retries(max_auto_retries)?;
+    }
     let db = state.store.as_ref();
     let key_store = db
         .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
@@ -1614,6 +1865,18 @@ pub async fn update_business_profile(
     if let Some(session_expiry) = &request.session_expiry {
         helpers::validate_session_expiry(session_expiry.to_owned())?;
     }
+    if let Some(dunning_config) = &request.dunning_config {
+        dunning::validate_dunning_config(dunning_config)?;
+    }
+    if let Some(auto_void_after_in_hours) = request.auto_void_after_in_hours {
+        auto_void::validate_auto_void_after_in_hours(auto_void_after_in_hours)?;
+    }
+    if let Some(abandoned_payment_config) = &request.abandoned_payment_config {
+        abandoned_payment::validate_abandoned_payment_config(abandoned_payment_config)?;
+    }
+    if let Some(max_auto_retries) = request.max_auto_retries {
+        helpers::validate_max_auto_retries(max_auto_retries)?;
+    }
 
     let webhook_details = request
         .webhook_details
@@ -1691,6 +1954,27 @@ pub async fn update_business_profile(
                 field_name: "authentication_connector_details",
             })?,
         extended_card_info_config,
+        dunning_config: request
+            .dunning_config
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "dunning_config",
+            })?,
+        is_network_tokenization_enabled: request.is_network_tokenization_enabled,
+        is_tax_calculation_enabled: request.is_tax_calculation_enabled,
+        requires_cvc_for_saved_cards: request.requires_cvc_for_saved_cards,
+        auto_void_after_in_hours: request.auto_void_after_in_hours.map(i32::from),
+        abandoned_payment_config: request
+            .abandoned_payment_config
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "abandoned_payment_config",
+            })?,
+        max_auto_retries: request.max_auto_retries.map(i16::from),
     };
 
     let updated_business_profile = db
@@ -1739,12 +2023,56 @@ pub async fn extended_card_info_toggle(
     Ok(service_api::ApplicationResponse::Json(ext_card_info_choice))
 }
 
+pub async fn enable_webhook_endpoint(
+    state: AppState,
+    profile_id: &str,
+    merchant_id: &str,
+) -> RouterResponse<api::BusinessProfileResponse> {
+    let db = state.store.as_ref();
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    if business_profile.merchant_id != merchant_id {
+        Err(errors::ApiErrorResponse::AccessForbidden {
+            resource: profile_id.to_string(),
+        })?
+    }
+
+    endpoint_health::reset_consecutive_failures(&state, profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let business_profile = if endpoint_health::is_webhook_endpoint_disabled(&business_profile) {
+        let business_profile_update =
+            storage::business_profile::BusinessProfileUpdate::WebhookEndpointStatusUpdate {
+                is_webhook_endpoint_disabled: Some(false),
+            };
+
+        db.update_business_profile_by_profile_id(business_profile, business_profile_update)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.to_owned(),
+            })?
+    } else {
+        business_profile
+    };
+
+    Ok(service_api::ApplicationResponse::Json(
+        api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+    ))
+}
+
 pub(crate) fn validate_auth_and_metadata_type(
     connector_name: api_models::enums::Connector,
     val: &types::ConnectorAuthType,
     connector_meta_data: &Option<pii::SecretSerdeValue>,
 ) -> Result<(), error_stack::Report<errors::ConnectorError>> {
-    use crate::connector::*;
+    use crate::connector::{utils::ConnectorMetadataMapping, *};
 
     match connector_name {
         #[cfg(feature = "dummy_connector")]
@@ -1765,6 +2093,15 @@ pub(crate) fn validate_auth_and_metadata_type(
         api_enums::Connector::Adyen => {
             adyen::transformers::AdyenAuthType::try_from(val)?;
             adyen::transformers::AdyenConnectorMetadataObject::try_from(connector_meta_data)?;
+            ConnectorMetadataMapping::try_from(connector_meta_data)?;
+            Ok(())
+        }
+        api_enums::Connector::Affirm => {
+            affirm::transformers::AffirmAuthType::try_from(val)?;
+            Ok(())
+        }
+        api_enums::Connector::Afterpay => {
+            afterpay::transformers::AfterpayAuthType::try_from(val)?;
             Ok(())
         }
         api_enums::Connector::Airwallex => {
@@ -1812,6 +2149,7 @@ pub(crate) fn validate_auth_and_metadata_type(
         }
         api_enums::Connector::Checkout => {
             checkout::transformers::CheckoutAuthType::try_from(val)?;
+            ConnectorMetadataMapping::try_from(connector_meta_data)?;
             Ok(())
         }
         api_enums::Connector::Coinbase => {
@@ -1905,6 +2243,10 @@ pub(crate) fn validate_auth_and_metadata_type(
             payme::transformers::PaymeAuthType::try_from(val)?;
             Ok(())
         }
+        api_enums::Connector::Payoneer => {
+            payoneer::transformers::PayoneerAuthType::try_from(val)?;
+            Ok(())
+        }
         api_enums::Connector::Paypal => {
             paypal::transformers::PaypalAuthType::try_from(val)?;
             Ok(())
@@ -1943,6 +2285,7 @@ pub(crate) fn validate_auth_and_metadata_type(
         }
         api_enums::Connector::Stripe => {
             stripe::transformers::StripeAuthType::try_from(val)?;
+            ConnectorMetadataMapping::try_from(connector_meta_data)?;
             Ok(())
         }
         api_enums::Connector::Trustpay => {
@@ -2,7 +2,7 @@ use common_utils::date_time;
 #[cfg(feature = "email")]
 use diesel_models::{api_keys::ApiKey, enums as storage_enums};
 use error_stack::{report, ResultExt};
-use masking::{PeekInterface, StrongSecret};
+use masking::{PeekInterface, StrongEq, StrongSecret};
 use router_env::{instrument, tracing};
 
 use crate::{
@@ -48,9 +48,18 @@ impl settings::ApiKeys {
 // of plaintext API key being stored in the data store.
 pub struct PlaintextApiKey(StrongSecret<String>);
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug)]
 pub struct HashedApiKey(String);
 
+// Hashes are compared in constant time, so that a stored hash cannot be guessed byte by byte.
+impl PartialEq for HashedApiKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.strong_eq(&other.0)
+    }
+}
+
+impl Eq for HashedApiKey {}
+
 impl PlaintextApiKey {
     const HASH_KEY_LEN: usize = 32;
 
@@ -44,6 +44,17 @@ impl LockingInput {
             API_LOCK_PREFIX, merchant_id, self.api_identifier, self.unique_locking_key
         )
     }
+
+    /// The lock is held with the request which acquired it. The scheduled tasks are not run for a
+    /// request, so the locks acquired by them are held with the locking key instead.
+    fn get_redis_locking_value<A>(state: &A, redis_locking_key: &str) -> String
+    where
+        A: AppStateInfo,
+    {
+        state
+            .get_request_id()
+            .unwrap_or_else(|| redis_locking_key.to_string())
+    }
 }
 
 impl LockAction {
@@ -60,6 +71,8 @@ impl LockAction {
                     .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
                 let redis_locking_key = input.get_redis_locking_key(merchant_id);
+                let redis_locking_value =
+                    LockingInput::get_redis_locking_value(state, &redis_locking_key);
                 let delay_between_retries_in_milliseconds = state
                     .conf()
                     .lock_settings
@@ -73,7 +86,7 @@ impl LockAction {
                     let redis_lock_result = redis_conn
                         .set_key_if_not_exists_with_expiry(
                             redis_locking_key.as_str(),
-                            state.get_request_id(),
+                            redis_locking_value.as_str(),
                             Some(i64::from(redis_lock_expiry_seconds)),
                         )
                         .await;
@@ -121,13 +134,15 @@ impl LockAction {
                     .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
                 let redis_locking_key = input.get_redis_locking_key(merchant_id);
+                let redis_locking_value =
+                    LockingInput::get_redis_locking_value(state, &redis_locking_key);
 
                 match redis_conn
                     .get_key::<Option<String>>(&redis_locking_key)
                     .await
                 {
                     Ok(val) => {
-                        if val == state.get_request_id() {
+                        if val.as_deref() == Some(redis_locking_value.as_str()) {
                             match redis_conn.delete_key(redis_locking_key.as_str()).await {
                                 Ok(redis::types::DelReply::KeyDeleted) => {
                                     logger::info!("Lock freed for locking input {:?}", input);
@@ -177,6 +177,7 @@ pub fn construct_router_data<F: Clone, Req, Res>(
         test_mode,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         dispute_id: None,
@@ -0,0 +1,358 @@
+use error_stack::{report, ResultExt};
+use router_env::logger;
+use serde::Serialize;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::AppState,
+    types::storage,
+};
+
+/// Card numbers are between 13 and 19 digits long
+const MIN_CARD_NUMBER_DIGITS: usize = 13;
+const MAX_CARD_NUMBER_DIGITS: usize = 19;
+
+/// Number of the last digits of a card number which are kept when it is redacted
+const UNREDACTED_CARD_NUMBER_DIGITS: usize = 4;
+
+const REDACTED_DIGIT: char = '*';
+
+#[derive(Debug, Serialize)]
+pub struct CardDataRedactionReport {
+    pub dry_run: bool,
+    pub columns: Vec<ColumnRedactionReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ColumnRedactionReport {
+    pub table: String,
+    pub column: String,
+    /// Rows whose value holds a run of digits as long as a card number
+    pub rows_scanned: usize,
+    pub rows_redacted: usize,
+    /// Rows which were changed after they were scanned, and are left for a later run
+    pub rows_skipped: usize,
+    pub card_numbers_found: usize,
+    pub redacted_rows: Vec<RedactedRow>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RedactedRow {
+    /// Physical location (`ctid`) of the row when it was scanned
+    pub row_id: String,
+    /// The card numbers found in the row, with all but their last four digits redacted
+    pub card_numbers: Vec<String>,
+}
+
+/// The redacted value of a column, along with the card numbers which were redacted in it
+#[derive(Debug, PartialEq)]
+struct Redaction {
+    value: String,
+    card_numbers: Vec<String>,
+}
+
+/// Parse a column to scan, given as `<table>.<column>`
+pub fn parse_redaction_target(target: &str) -> RouterResult<storage::RedactionTarget> {
+    target
+        .split_once('.')
+        .and_then(|(table, column)| storage::RedactionTarget::new(table, column))
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`{target}` should be a table and a column of lowercase letters, digits and underscores, as `<table>.<column>`"
+                ),
+            })
+        })
+}
+
+/// Scan the columns for the card numbers stored in them by mistake, and redact the card numbers
+/// in place unless this is a dry run. The rows are read in batches, in the order they are stored.
+pub async fn redact_card_data(
+    state: &AppState,
+    targets: Vec<storage::RedactionTarget>,
+    dry_run: bool,
+    batch_size: i64,
+) -> RouterResult<CardDataRedactionReport> {
+    if batch_size < 1 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "batch size should be at least 1".to_string(),
+        }));
+    }
+
+    let db = &*state.store;
+    let mut columns = Vec::with_capacity(targets.len());
+
+    for target in targets {
+        let column_type = db
+            .find_redaction_target_data_type(&target)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the type of the column")?
+            .as_deref()
+            .and_then(storage::RedactionColumnType::from_data_type)
+            .ok_or_else(|| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "`{}.{}` should be an existing text or json column",
+                        target.table(),
+                        target.column()
+                    ),
+                })
+            })?;
+
+        let mut column_report = ColumnRedactionReport {
+            table: target.table().to_string(),
+            column: target.column().to_string(),
+            rows_scanned: 0,
+            rows_redacted: 0,
+            rows_skipped: 0,
+            card_numbers_found: 0,
+            redacted_rows: Vec::new(),
+        };
+        let mut after_row_id = None;
+
+        loop {
+            let candidates = db
+                .find_redaction_candidates(&target, after_row_id.clone(), batch_size)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the values which may hold a card number")?;
+            let Some(last_candidate) = candidates.last() else {
+                break;
+            };
+            after_row_id = Some(last_candidate.row_id.clone());
+
+            for candidate in candidates {
+                column_report.rows_scanned += 1;
+                let Some(redaction) = redact_value(column_type, &candidate.value) else {
+                    continue;
+                };
+                let row_id = candidate.row_id.clone();
+
+                if !dry_run {
+                    let is_updated = db
+                        .update_redaction_candidate(
+                            &target,
+                            column_type,
+                            candidate,
+                            redaction.value,
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to redact the value")?;
+                    if !is_updated {
+                        logger::warn!(
+                            table = target.table(),
+                            column = target.column(),
+                            row_id = %row_id,
+                            "Row was changed after it was scanned"
+                        );
+                        column_report.rows_skipped += 1;
+                        continue;
+                    }
+                }
+
+                column_report.rows_redacted += 1;
+                column_report.card_numbers_found += redaction.card_numbers.len();
+                column_report.redacted_rows.push(RedactedRow {
+                    row_id,
+                    card_numbers: redaction.card_numbers,
+                });
+            }
+        }
+
+        columns.push(column_report);
+    }
+
+    Ok(CardDataRedactionReport { dry_run, columns })
+}
+
+/// Redact the card numbers in the value of a column, if there are any
+fn redact_value(column_type: storage::RedactionColumnType, value: &str) -> Option<Redaction> {
+    let redaction = match column_type {
+        storage::RedactionColumnType::Text => redact_card_numbers_in_text(value),
+        storage::RedactionColumnType::Json | storage::RedactionColumnType::Jsonb => {
+            let mut json = serde_json::from_str::<serde_json::Value>(value)
+                .map_err(|error| logger::warn!(json_parse_error=?error))
+                .ok()?;
+            let mut card_numbers = Vec::new();
+            redact_card_numbers_in_json(&mut json, &mut card_numbers);
+            Redaction {
+                value: json.to_string(),
+                card_numbers,
+            }
+        }
+    };
+
+    (!redaction.card_numbers.is_empty()).then_some(redaction)
+}
+
+/// Redact the card numbers in the strings of the value, and in its numbers which are card numbers
+/// themselves, which are replaced by strings
+fn redact_card_numbers_in_json(value: &mut serde_json::Value, card_numbers: &mut Vec<String>) {
+    match value {
+        serde_json::Value::String(text) => {
+            let redaction = redact_card_numbers_in_text(text);
+            if !redaction.card_numbers.is_empty() {
+                *text = redaction.value;
+                card_numbers.extend(redaction.card_numbers);
+            }
+        }
+        serde_json::Value::Number(number) => {
+            let redaction = redact_card_numbers_in_text(&number.to_string());
+            if !redaction.card_numbers.is_empty() {
+                *value = serde_json::Value::String(redaction.value);
+                card_numbers.extend(redaction.card_numbers);
+            }
+        }
+        serde_json::Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| redact_card_numbers_in_json(value, card_numbers)),
+        serde_json::Value::Object(map) => map
+            .values_mut()
+            .for_each(|value| redact_card_numbers_in_json(value, card_numbers)),
+        serde_json::Value::Bool(_) | serde_json::Value::Null => {}
+    }
+}
+
+/// Redact the card numbers in the text, which are the runs of 13 to 19 digits passing the Luhn
+/// check. The digits of a run may be grouped by single spaces or dashes, and are not redacted if
+/// the run is longer, as it is then not a card number.
+fn redact_card_numbers_in_text(text: &str) -> Redaction {
+    let mut redaction = Redaction {
+        value: String::with_capacity(text.len()),
+        card_numbers: Vec::new(),
+    };
+    let mut run = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let is_separator_in_run = (c == ' ' || c == '-')
+            && run.ends_with(|last: char| last.is_ascii_digit())
+            && chars.peek().is_some_and(char::is_ascii_digit);
+
+        if c.is_ascii_digit() || is_separator_in_run {
+            run.push(c);
+        } else {
+            redact_run(&run, &mut redaction);
+            run.clear();
+            redaction.value.push(c);
+        }
+    }
+    redact_run(&run, &mut redaction);
+
+    redaction
+}
+
+fn redact_run(run: &str, redaction: &mut Redaction) {
+    let digits = run
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .collect::<Vec<_>>();
+
+    if !(MIN_CARD_NUMBER_DIGITS..=MAX_CARD_NUMBER_DIGITS).contains(&digits.len())
+        || !is_luhn_valid(&digits)
+    {
+        redaction.value.push_str(run);
+        return;
+    }
+
+    let redacted_digits = digits.len().saturating_sub(UNREDACTED_CARD_NUMBER_DIGITS);
+    let mut seen_digits = 0;
+    let mut card_number = String::with_capacity(digits.len());
+    for c in run.chars() {
+        if c.is_ascii_digit() {
+            let redacted = if seen_digits < redacted_digits {
+                REDACTED_DIGIT
+            } else {
+                c
+            };
+            seen_digits += 1;
+            card_number.push(redacted);
+            redaction.value.push(redacted);
+        } else {
+            redaction.value.push(c);
+        }
+    }
+    redaction.card_numbers.push(card_number);
+}
+
+fn is_luhn_valid(digits: &[u32]) -> bool {
+    let checksum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| {
+            if index % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                *digit
+            }
+        })
+        .sum();
+
+    checksum % 10 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_card_numbers_in_text() {
+        let redaction = redact_card_numbers_in_text(
+            "Card 4111 1111 1111 1111 was declined, retry 4242-4242-4242-4242",
+        );
+        assert_eq!(
+            redaction.value,
+            "Card **** **** **** 1111 was declined, retry ****-****-****-4242"
+        );
+        assert_eq!(
+            redaction.card_numbers,
+            vec!["************1111", "************4242"]
+        );
+
+        // Runs failing the Luhn check, and runs longer than a card number, are left as they are
+        for text in ["order 4111111111111112", "reference 41111111111111110000"] {
+            let redaction = redact_card_numbers_in_text(text);
+            assert_eq!(redaction.value, text);
+            assert!(redaction.card_numbers.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_redact_json_value() {
+        let redaction = redact_value(
+            storage::RedactionColumnType::Jsonb,
+            r#"{"card": {"number": 5555555555554444}, "notes": ["378282246310005"]}"#,
+        );
+        assert_eq!(
+            redaction,
+            Some(Redaction {
+                value: r#"{"card":{"number":"************4444"},"notes":["***********0005"]}"#
+                    .to_string(),
+                card_numbers: vec![
+                    "************4444".to_string(),
+                    "***********0005".to_string()
+                ],
+            })
+        );
+
+        assert_eq!(
+            redact_value(storage::RedactionColumnType::Jsonb, r#"{"amount": 6540}"#),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_redaction_target() {
+        assert!(parse_redaction_target("payment_attempt.error_message").is_ok());
+        assert!(parse_redaction_target("payment_attempt").is_err());
+        assert!(parse_redaction_target("payment_attempt.error message").is_err());
+    }
+}
@@ -1,17 +1,70 @@
-use common_utils::fp_utils::when;
+use cards::bin_info::{BinInfo, CardType};
+use common_utils::{ext_traits::BytesExt, fp_utils::when};
 use error_stack::{report, ResultExt};
-use router_env::{instrument, tracing};
+use masking::{Mask, PeekInterface};
+use router_env::{instrument, logger, tracing};
+use serde::Deserialize;
 
 use crate::{
     core::{
-        errors::{self, RouterResponse},
-        payments::helpers,
+        errors::{self, RouterResponse, RouterResult},
+        payments::{helpers, PaymentData},
+    },
+    headers, routes,
+    services::{self, ApplicationResponse},
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
     },
-    routes,
-    services::ApplicationResponse,
-    types::{domain, transformers::ForeignFrom},
 };
 
+/// The cards info table is keyed by the first 6 digits of the card numbers
+const CARD_ISIN_LENGTH: usize = 6;
+
+/// The BIN provider is sent at most the first 8 digits of the card numbers
+const MAX_PROVIDER_BIN_LENGTH: usize = 8;
+
+#[derive(Debug, Deserialize)]
+struct BinProviderResponse {
+    card_network: Option<String>,
+    #[serde(default)]
+    co_badged_card_networks: Vec<String>,
+    card_type: Option<String>,
+    card_issuer: Option<String>,
+    card_issuing_country: Option<String>,
+}
+
+impl ForeignFrom<storage::CardInfo> for BinInfo {
+    fn foreign_from(card_info: storage::CardInfo) -> Self {
+        Self {
+            card_network: card_info
+                .card_network
+                .map(|card_network| card_network.to_string()),
+            co_badged_card_networks: Vec::new(),
+            card_type: card_info
+                .card_type
+                .and_then(|card_type| card_type.parse().ok()),
+            card_issuer: card_info.card_issuer,
+            card_issuing_country: card_info.card_issuing_country,
+        }
+    }
+}
+
+impl From<BinProviderResponse> for BinInfo {
+    fn from(response: BinProviderResponse) -> Self {
+        Self {
+            card_network: response.card_network,
+            co_badged_card_networks: response.co_badged_card_networks,
+            card_type: response
+                .card_type
+                .and_then(|card_type| card_type.parse().ok()),
+            card_issuer: response.card_issuer,
+            card_issuing_country: response.card_issuing_country,
+        }
+    }
+}
+
 fn verify_iin_length(card_iin: &str) -> Result<(), errors::ApiErrorResponse> {
     let is_bin_length_in_range = card_iin.len() == 6 || card_iin.len() == 8;
     when(!is_bin_length_in_range, || {
@@ -39,10 +92,197 @@ pub async fn retrieve_card_info(
         .get_card_info(&request.card_iin)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to retrieve card information")?
-        .ok_or(report!(errors::ApiErrorResponse::InvalidCardIin))?;
+        .attach_printable("Failed to retrieve card information")?;
+
+    let card_info_response = match card_info {
+        Some(card_info) => api_models::cards_info::CardInfoResponse::foreign_from(card_info),
+        None => lookup_bin_info(&state, &request.card_iin)
+            .await
+            .map(|bin_info| api_models::cards_info::CardInfoResponse {
+                card_iin: request.card_iin,
+                card_issuer: bin_info.card_issuer,
+                co_badged_card_networks: bin_info
+                    .is_co_badged()
+                    .then(|| bin_info.card_networks().map(String::from).collect()),
+                card_network: bin_info.card_network,
+                card_type: bin_info
+                    .card_type
+                    .map(|card_type| card_type.as_str().to_string()),
+                card_sub_type: None,
+                card_issuing_country: bin_info.card_issuing_country,
+            })
+            .ok_or(report!(errors::ApiErrorResponse::InvalidCardIin))?,
+    };
+
+    Ok(ApplicationResponse::Json(card_info_response))
+}
+
+/// The information on the issuer of the card, which is looked up in the cards info table, then in
+/// the BIN database, and then with the BIN provider. The card number may be given in full or as its
+/// first digits only. Failures to look up the card are logged, and the card is then treated as
+/// unknown.
+#[instrument(skip_all)]
+pub async fn get_bin_info(state: &routes::AppState, card_number: &str) -> Option<BinInfo> {
+    let card_info = match card_number.get(..CARD_ISIN_LENGTH) {
+        Some(card_isin) => state
+            .store
+            .get_card_info(card_isin)
+            .await
+            .map_err(|error| logger::warn!(card_info_error=?error))
+            .ok()
+            .flatten(),
+        None => None,
+    };
+
+    match card_info {
+        Some(card_info) => Some(BinInfo::foreign_from(card_info)),
+        None => lookup_bin_info(state, card_number).await,
+    }
+}
+
+/// Look up the card in the BIN database, and with the BIN provider if the BIN database does not
+/// list it
+async fn lookup_bin_info(state: &routes::AppState, card_number: &str) -> Option<BinInfo> {
+    if let Some(bin_info) = state
+        .bin_database
+        .as_ref()
+        .and_then(|bin_database| bin_database.lookup(card_number))
+    {
+        return Some(bin_info.clone());
+    }
+
+    let bin = card_number
+        .get(..MAX_PROVIDER_BIN_LENGTH)
+        .unwrap_or(card_number);
+    call_bin_provider_api(state, bin)
+        .await
+        .map_err(|error| logger::warn!(bin_provider_error=?error))
+        .ok()
+        .flatten()
+}
+
+/// Fill in the details of the card of the payment which are not known from the request, so that
+/// the 3DS decision, the routing rules and the surcharge rules can be applied on them. The
+/// payment method type of the payment is also inferred from the card type if it is not known.
+///
+/// Co-badged cards are authorized on the network chosen in the request by the merchant or the
+/// customer, or on the network the cards are branded with if none is chosen, which is then
+/// passed to the connectors as the preferred network.
+#[instrument(skip_all)]
+pub async fn populate_card_bin_info<F: Clone>(
+    state: &routes::AppState,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    let Some(api::PaymentMethodData::Card(card)) = payment_data.payment_method_data.as_mut() else {
+        return Ok(());
+    };
+
+    let Some(bin_info) = get_bin_info(state, card.card_number.peek()).await else {
+        return Ok(());
+    };
+
+    let preferred_card_network = if bin_info.is_co_badged() {
+        let card_networks = bin_info
+            .card_networks()
+            .filter_map(|card_network| card_network.parse().ok())
+            .collect::<Vec<storage_enums::CardNetwork>>();
+        let preferred_card_network = card.card_network.or(card_networks.first().copied());
+        if let Some(card_network) = preferred_card_network
+            .as_ref()
+            .filter(|card_network| !card_networks.contains(card_network))
+        {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "card_network {card_network} is not a network of the co-badged card, which can be authorized on {}",
+                    card_networks
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }));
+        }
+        preferred_card_network
+    } else {
+        None
+    };
+
+    card.card_network = card
+        .card_network
+        .take()
+        .or(preferred_card_network)
+        .or_else(|| {
+            bin_info
+                .card_network
+                .as_ref()
+                .and_then(|card_network| card_network.parse().ok())
+        });
+    card.card_type = card.card_type.take().or_else(|| {
+        bin_info
+            .card_type
+            .map(|card_type| card_type.as_str().to_string())
+    });
+    card.card_issuer = card.card_issuer.take().or(bin_info.card_issuer);
+    card.card_issuing_country = card
+        .card_issuing_country
+        .take()
+        .or(bin_info.card_issuing_country);
+
+    let payment_attempt = &mut payment_data.payment_attempt;
+    if payment_attempt.payment_method == Some(storage_enums::PaymentMethod::Card)
+        && payment_attempt.payment_method_type.is_none()
+    {
+        payment_attempt.payment_method_type = match bin_info.card_type {
+            Some(CardType::Credit) => Some(storage_enums::PaymentMethodType::Credit),
+            Some(CardType::Debit) => Some(storage_enums::PaymentMethodType::Debit),
+            Some(CardType::Prepaid) | None => None,
+        };
+    }
+    payment_data.preferred_card_network = preferred_card_network;
+
+    Ok(())
+}
+
+/// The information on the issuer of the cards of the BIN from the BIN provider, or `None` if the
+/// BIN provider is not configured or does not know the BIN
+async fn call_bin_provider_api(
+    state: &routes::AppState,
+    bin: &str,
+) -> RouterResult<Option<BinInfo>> {
+    let bin_lookup_service = state.conf.bin_lookup_service.get_inner();
+    let Some(base_url) = bin_lookup_service.base_url.as_ref() else {
+        return Ok(None);
+    };
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Get)
+        .url(&format!("{base_url}bins/{bin}"))
+        .attach_default_headers()
+        .headers(vec![(
+            headers::AUTHORIZATION.to_string(),
+            format!("Bearer {}", bin_lookup_service.api_key.peek()).into_masked(),
+        )])
+        .build();
+
+    let response = services::call_connector_api(state, request, "lookup_bin")
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the API of the BIN provider")?;
 
-    Ok(ApplicationResponse::Json(
-        api_models::cards_info::CardInfoResponse::foreign_from(card_info),
-    ))
+    match response {
+        Ok(response) => response
+            .response
+            .parse_struct::<BinProviderResponse>("BinProviderResponse")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the response of the BIN provider")
+            .map(|response| Some(response.into())),
+        Err(error_response) if error_response.status_code == 404 => Ok(None),
+        Err(error_response) => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable_lazy(|| {
+                format!(
+                    "BIN provider responded with status code {}",
+                    error_response.status_code
+                )
+            }),
+    }
 }
@@ -0,0 +1,300 @@
+pub mod transformers;
+
+use api_models::{enums, payments as payment_types};
+use common_utils::{
+    ext_traits::{BytesExt, ValueExt},
+    request::RequestContent,
+};
+use error_stack::ResultExt;
+use masking::{ExposeInterface, Mask, PeekInterface, Secret};
+use router_env::logger;
+use serde::de::DeserializeOwned;
+
+use self::transformers as click_to_pay_types;
+use crate::{
+    configs::settings,
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::helpers,
+    },
+    headers, services,
+    types::{domain, storage},
+    AppState,
+};
+
+/// The card networks which Click to Pay is integrated with
+const SUPPORTED_CARD_NETWORKS: [enums::CardNetwork; 2] =
+    [enums::CardNetwork::Visa, enums::CardNetwork::Mastercard];
+
+/// Check whether the customer has a Click to Pay profile with any of the card networks accepted
+/// by the merchant, so that the SDK can offer them the cards of their profile
+pub async fn identity_lookup(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payment_types::ClickToPayIdentityLookupRequest,
+) -> RouterResponse<payment_types::ClickToPayIdentityLookupResponse> {
+    let payment_intent = get_payment_intent(
+        &state,
+        &merchant_account,
+        &req.payment_id,
+        &req.client_secret,
+    )
+    .await?;
+    let metadata =
+        get_click_to_pay_metadata(&state, &merchant_account, &key_store, &payment_intent).await?;
+    let consumer_identity = click_to_pay_types::ConsumerIdentity::try_from(&req)?;
+    let click_to_pay_config = state.conf.click_to_pay.get_inner();
+
+    let lookups = metadata
+        .card_brands
+        .iter()
+        .filter(|card_network| SUPPORTED_CARD_NETWORKS.contains(card_network))
+        .map(|card_network| {
+            let request_body = click_to_pay_types::IdentityLookupRequest {
+                src_initiator_id: click_to_pay_config.src_initiator_id.clone(),
+                srci_dpa_id: metadata.dpa_id.clone(),
+                srci_transaction_id: payment_intent.payment_id.clone(),
+                consumer_identity: consumer_identity.clone(),
+            };
+
+            async {
+                let response: click_to_pay_types::IdentityLookupResponse = call_network_api(
+                    &state,
+                    card_network,
+                    "identities/lookup",
+                    RequestContent::Json(Box::new(request_body)),
+                    "click_to_pay_identity_lookup",
+                )
+                .await?;
+
+                RouterResult::Ok((card_network.clone(), response.consumer_present))
+            }
+        });
+
+    let recognized_card_networks = futures::future::join_all(lookups)
+        .await
+        .into_iter()
+        .filter_map(|lookup| match lookup {
+            Ok((card_network, consumer_present)) => consumer_present.then_some(card_network),
+            // A lookup failing with one card network must not prevent the customer from checking
+            // out with the profiles they have with the others
+            Err(error) => {
+                logger::error!(?error, "Click to Pay identity lookup failed");
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        payment_types::ClickToPayIdentityLookupResponse {
+            payment_id: payment_intent.payment_id,
+            consumer_present: !recognized_card_networks.is_empty(),
+            recognized_card_networks,
+        },
+    ))
+}
+
+/// Add a card to the Click to Pay profile of the customer with the card network of the card,
+/// creating the profile if the customer does not have one
+pub async fn enroll_card(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payment_types::ClickToPayEnrollmentRequest,
+) -> RouterResponse<payment_types::ClickToPayEnrollmentResponse> {
+    let payment_intent = get_payment_intent(
+        &state,
+        &merchant_account,
+        &req.payment_id,
+        &req.client_secret,
+    )
+    .await?;
+    let metadata =
+        get_click_to_pay_metadata(&state, &merchant_account, &key_store, &payment_intent).await?;
+
+    if !metadata.card_brands.contains(&req.card_network)
+        || !SUPPORTED_CARD_NETWORKS.contains(&req.card_network)
+    {
+        Err(errors::ApiErrorResponse::NotSupported {
+            message: format!("Click to Pay enrollment of {} cards", req.card_network),
+        })?
+    }
+
+    let click_to_pay_config = state.conf.click_to_pay.get_inner();
+    let request_body = click_to_pay_types::EnrollCardRequest {
+        src_initiator_id: click_to_pay_config.src_initiator_id.clone(),
+        srci_dpa_id: metadata.dpa_id,
+        srci_transaction_id: payment_intent.payment_id.clone(),
+        card: click_to_pay_types::SrcCard::from(&req),
+        consumer: click_to_pay_types::SrcConsumer::from(&req),
+    };
+
+    let response: click_to_pay_types::EnrollCardResponse = call_network_api(
+        &state,
+        &req.card_network,
+        "cards",
+        RequestContent::Json(Box::new(request_body)),
+        "click_to_pay_enroll_card",
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_types::ClickToPayEnrollmentResponse {
+            payment_id: payment_intent.payment_id,
+            card_network: req.card_network,
+            src_digital_card_id: response.src_digital_card_id,
+            card_last_four: response.masked_card.pan_last_four,
+        },
+    ))
+}
+
+async fn get_payment_intent(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_id: &str,
+    client_secret: &Secret<String>,
+) -> RouterResult<storage::PaymentIntent> {
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    helpers::authenticate_client_secret(Some(client_secret.peek()), &payment_intent)?;
+    helpers::validate_payment_status_against_allowed_statuses(
+        &payment_intent.status,
+        &[
+            storage::enums::IntentStatus::RequiresPaymentMethod,
+            storage::enums::IntentStatus::RequiresConfirmation,
+        ],
+        "check out with Click to Pay",
+    )?;
+
+    Ok(payment_intent)
+}
+
+/// The Click to Pay details of the merchant are configured in the metadata of any of the merchant
+/// connector accounts of the business profile of the payment
+async fn get_click_to_pay_metadata(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<payment_types::ClickToPayMetadata> {
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_account.merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_account.merchant_id.clone(),
+        })?;
+
+    helpers::filter_mca_based_on_business_profile(
+        merchant_connector_accounts,
+        payment_intent.profile_id.clone(),
+    )
+    .into_iter()
+    .find_map(|merchant_connector_account| {
+        merchant_connector_account
+            .metadata?
+            .expose()
+            .parse_value::<payment_types::ClickToPaySessionTokenData>("ClickToPaySessionTokenData")
+            .ok()
+    })
+    .map(|session_token_data| session_token_data.click_to_pay)
+    .ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "Click to Pay is not configured for the business profile of the payment"
+                .to_string(),
+        }
+        .into(),
+    )
+}
+
+fn get_network_config<'a>(
+    click_to_pay_config: &'a settings::ClickToPay,
+    card_network: &enums::CardNetwork,
+) -> RouterResult<&'a settings::ClickToPayNetworkConfig> {
+    match card_network {
+        enums::CardNetwork::Visa => Ok(&click_to_pay_config.visa),
+        enums::CardNetwork::Mastercard => Ok(&click_to_pay_config.mastercard),
+        _ => Err(errors::ApiErrorResponse::NotSupported {
+            message: format!("Click to Pay with {card_network} cards"),
+        }
+        .into()),
+    }
+}
+
+async fn call_network_api<T: DeserializeOwned>(
+    state: &AppState,
+    card_network: &enums::CardNetwork,
+    path: &str,
+    request_body: RequestContent,
+    flow: &str,
+) -> RouterResult<T> {
+    let network_config = get_network_config(state.conf.click_to_pay.get_inner(), card_network)?;
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(&format!("{}{path}", network_config.base_url))
+        .attach_default_headers()
+        .headers(vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                "application/json".to_string().into(),
+            ),
+            (
+                headers::AUTHORIZATION.to_string(),
+                format!("Bearer {}", network_config.api_key.peek()).into_masked(),
+            ),
+        ])
+        .set_body(request_body)
+        .build();
+
+    let response = services::call_connector_api(state, request, flow)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to call the {card_network} Click to Pay API"))?;
+
+    match response {
+        Ok(response) => response
+            .response
+            .parse_struct(std::any::type_name::<T>())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Failed to parse the response of the {card_network} Click to Pay API")
+            }),
+        Err(error_response) => {
+            let error = error_response
+                .response
+                .parse_struct::<click_to_pay_types::SrcErrorResponse>("SrcErrorResponse")
+                .ok()
+                .map(|response| response.error);
+
+            Err(errors::ApiErrorResponse::ExternalConnectorError {
+                code: error
+                    .as_ref()
+                    .map(|error| error.reason.clone())
+                    .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                message: error
+                    .as_ref()
+                    .and_then(|error| error.message.clone())
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                connector: card_network.to_string(),
+                status_code: error_response.status_code,
+                reason: None,
+            }
+            .into())
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use api_models::payments as payment_types;
+use common_utils::pii::Email;
+use masking::{ExposeInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::core::errors;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityLookupRequest {
+    pub src_initiator_id: String,
+    pub srci_dpa_id: String,
+    pub srci_transaction_id: String,
+    pub consumer_identity: ConsumerIdentity,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsumerIdentity {
+    pub identity_provider: IdentityProvider,
+    pub identity_type: IdentityType,
+    pub identity_value: Secret<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IdentityProvider {
+    Src,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IdentityType {
+    EmailAddress,
+    MobilePhoneNumber,
+}
+
+impl TryFrom<&payment_types::ClickToPayIdentityLookupRequest> for ConsumerIdentity {
+    type Error = errors::ApiErrorResponse;
+
+    fn try_from(req: &payment_types::ClickToPayIdentityLookupRequest) -> Result<Self, Self::Error> {
+        // The email is preferred as it is the primary identity of Click to Pay profiles
+        match (&req.email, get_phone_number(req.phone.as_ref())) {
+            (Some(email), _) => Ok(Self {
+                identity_provider: IdentityProvider::Src,
+                identity_type: IdentityType::EmailAddress,
+                identity_value: Secret::new(email.clone().expose().expose()),
+            }),
+            (None, Some(phone_number)) => Ok(Self {
+                identity_provider: IdentityProvider::Src,
+                identity_type: IdentityType::MobilePhoneNumber,
+                identity_value: phone_number,
+            }),
+            (None, None) => Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "email or phone",
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityLookupResponse {
+    pub consumer_present: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnrollCardRequest {
+    pub src_initiator_id: String,
+    pub srci_dpa_id: String,
+    pub srci_transaction_id: String,
+    pub card: SrcCard,
+    pub consumer: SrcConsumer,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SrcCard {
+    pub primary_account_number: cards::CardNumber,
+    pub pan_expiration_month: Secret<String>,
+    pub pan_expiration_year: Secret<String>,
+    pub card_security_code: Secret<String>,
+    pub cardholder_full_name: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SrcConsumer {
+    pub email_address: Email,
+    pub mobile_number: Option<SrcMobileNumber>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SrcMobileNumber {
+    pub country_code: String,
+    pub phone_number: Secret<String>,
+}
+
+impl From<&payment_types::ClickToPayEnrollmentRequest> for SrcCard {
+    fn from(req: &payment_types::ClickToPayEnrollmentRequest) -> Self {
+        Self {
+            primary_account_number: req.card.card_number.clone(),
+            pan_expiration_month: req.card.card_exp_month.clone(),
+            pan_expiration_year: req.card.card_exp_year.clone(),
+            card_security_code: req.card.card_cvc.clone(),
+            cardholder_full_name: req.card.card_holder_name.clone(),
+        }
+    }
+}
+
+impl From<&payment_types::ClickToPayEnrollmentRequest> for SrcConsumer {
+    fn from(req: &payment_types::ClickToPayEnrollmentRequest) -> Self {
+        let mobile_number = req.phone.as_ref().and_then(|phone| {
+            Some(SrcMobileNumber {
+                country_code: phone.country_code.clone()?,
+                phone_number: phone.number.clone()?,
+            })
+        });
+
+        Self {
+            email_address: req.email.clone(),
+            mobile_number,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnrollCardResponse {
+    pub src_digital_card_id: String,
+    pub masked_card: MaskedCard,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaskedCard {
+    pub pan_last_four: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SrcErrorResponse {
+    pub error: SrcErrorDetails,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SrcErrorDetails {
+    pub reason: String,
+    pub message: Option<String>,
+}
+
+fn get_phone_number(phone: Option<&payment_types::PhoneDetails>) -> Option<Secret<String>> {
+    let phone = phone?;
+    let number = phone.number.clone()?.expose();
+
+    Some(Secret::new(format!(
+        "{}{number}",
+        phone.country_code.clone().unwrap_or_default()
+    )))
+}
@@ -3,11 +3,29 @@ use error_stack::ResultExt;
 use crate::{
     core::errors::{self, utils::StorageErrorExt, RouterResponse},
     routes::AppState,
-    services::ApplicationResponse,
+    services::{api::base_url_override, ApplicationResponse},
     types::{api, transformers::ForeignInto},
 };
 
+/// Validate the value of the configs which are read as structured values, so that invalid values
+/// are rejected instead of being skipped when they are read
+fn validate_config(
+    state: &AppState,
+    key: &str,
+    value: &str,
+) -> Result<(), errors::ApiErrorResponse> {
+    match base_url_override::get_overridden_connector(key) {
+        Some(connector_name) => base_url_override::validate_base_url_override(
+            &state.conf.connectors,
+            connector_name,
+            value,
+        ),
+        None => Ok(()),
+    }
+}
+
 pub async fn set_config(state: AppState, config: api::Config) -> RouterResponse<api::Config> {
+    validate_config(&state, &config.key, &config.value)?;
     let store = state.store.as_ref();
     let config = store
         .insert_config(diesel_models::configs::ConfigNew {
@@ -34,6 +52,7 @@ pub async fn update_config(
     state: AppState,
     config_update: &api::ConfigUpdate,
 ) -> RouterResponse<api::Config> {
+    validate_config(&state, &config_update.key, &config_update.value)?;
     let store = state.store.as_ref();
     let config = store
         .update_config_by_key(&config_update.key, config_update.foreign_into())
@@ -0,0 +1,513 @@
+use std::collections::HashMap;
+
+use api_models::{
+    connector_migration::{
+        ConnectorMigrationItem, ConnectorMigrationOutcome, ConnectorMigrationRequest,
+        ConnectorMigrationResponse, ConnectorMigrationStatus,
+    },
+    enums as api_enums,
+    mandates::MandateListConstraints,
+    payments::ConnectorMandateReferenceId,
+};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    fp_utils::when,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use super::errors::{self, RouterResponse, RouterResult, StorageErrorExt};
+use crate::{
+    consts,
+    routes::{metrics, AppState},
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+const CONNECTOR_MIGRATION_TAG: &str = "CONNECTOR_MIGRATION";
+const CONNECTOR_MIGRATION_NAME: &str = "CONNECTOR_MIGRATION";
+const CONNECTOR_MIGRATION_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::ConnectorMigrationWorkflow;
+
+const TOKEN_NOT_MIGRATABLE_MESSAGE: &str = "The token has not been imported by the target \
+    connector, and no network transaction ID supported by the target connector is available";
+
+/// The business status the connector migration task is finished with once all the items have
+/// been processed
+pub(crate) const CONNECTOR_MIGRATION_COMPLETED_STATUS: &str = "COMPLETED_BY_PT";
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct ConnectorMigrationTrackingData {
+    pub(crate) migration_id: String,
+    pub(crate) merchant_id: String,
+    pub(crate) source_merchant_connector_id: String,
+    pub(crate) target_merchant_connector_id: String,
+    pub(crate) token_mapping: HashMap<String, String>,
+    /// Outcomes of the migrated items, recorded once the migration has been completed
+    #[serde(default)]
+    pub(crate) items: Vec<ConnectorMigrationItem>,
+}
+
+fn get_process_tracker_id(migration_id: &str) -> String {
+    format!("{CONNECTOR_MIGRATION_RUNNER}_{CONNECTOR_MIGRATION_NAME}_{migration_id}")
+}
+
+/// Schedule the migration of the payment methods and active mandates of the merchant from the
+/// source connector to the target connector
+#[instrument(skip_all)]
+pub async fn create_connector_migration(
+    state: AppState,
+    merchant_id: String,
+    request: ConnectorMigrationRequest,
+) -> RouterResponse<ConnectorMigrationResponse> {
+    when(
+        request.source_merchant_connector_id == request.target_merchant_connector_id,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`source_merchant_connector_id` and `target_merchant_connector_id` must \
+                          be different"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    for merchant_connector_id in [
+        &request.source_merchant_connector_id,
+        &request.target_merchant_connector_id,
+    ] {
+        db.find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &merchant_id,
+            merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(
+            errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                id: merchant_connector_id.clone(),
+            },
+        )?;
+    }
+
+    let migration_id = common_utils::generate_id(consts::ID_LENGTH, "cm");
+    let tracking_data = ConnectorMigrationTrackingData {
+        migration_id: migration_id.clone(),
+        merchant_id,
+        source_merchant_connector_id: request.source_merchant_connector_id,
+        target_merchant_connector_id: request.target_merchant_connector_id,
+        token_mapping: request.token_mapping,
+        items: Vec::new(),
+    };
+    let response =
+        get_connector_migration_response(&tracking_data, ConnectorMigrationStatus::Pending);
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_process_tracker_id(&migration_id),
+        CONNECTOR_MIGRATION_NAME,
+        CONNECTOR_MIGRATION_RUNNER,
+        [CONNECTOR_MIGRATION_TAG],
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct connector migration process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting connector migration task to process_tracker: migration_id: {migration_id}"
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "flow",
+            "ConnectorMigration",
+        )],
+    );
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_connector_migration(
+    state: AppState,
+    merchant_id: String,
+    migration_id: String,
+) -> RouterResponse<ConnectorMigrationResponse> {
+    let not_found_error = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Connector migration not found".to_string(),
+    };
+
+    let process = state
+        .store
+        .find_process_by_id(&get_process_tracker_id(&migration_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the connector migration task")?
+        .ok_or_else(not_found_error)?;
+    let tracking_data: ConnectorMigrationTrackingData = process
+        .tracking_data
+        .parse_value("ConnectorMigrationTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    when(tracking_data.merchant_id != merchant_id, || {
+        Err(not_found_error())
+    })?;
+
+    let status = match process.status {
+        enums::ProcessTrackerStatus::Finish
+            if process.business_status == CONNECTOR_MIGRATION_COMPLETED_STATUS =>
+        {
+            ConnectorMigrationStatus::Completed
+        }
+        enums::ProcessTrackerStatus::Finish => ConnectorMigrationStatus::Failed,
+        enums::ProcessTrackerStatus::Processing
+        | enums::ProcessTrackerStatus::New
+        | enums::ProcessTrackerStatus::Pending
+        | enums::ProcessTrackerStatus::ProcessStarted => ConnectorMigrationStatus::Pending,
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        get_connector_migration_response(&tracking_data, status),
+    ))
+}
+
+/// Migrate the payment methods and active mandates of the merchant, returning the outcome of the
+/// migration of each of them. The migration of an item failing does not stop the migration of
+/// the remaining items.
+pub(crate) async fn migrate_connector(
+    state: &AppState,
+    tracking_data: &ConnectorMigrationTrackingData,
+) -> RouterResult<Vec<ConnectorMigrationItem>> {
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let source_connector_account = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &tracking_data.merchant_id,
+            &tracking_data.source_merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: tracking_data.source_merchant_connector_id.clone(),
+        })?;
+    let target_connector_account = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &tracking_data.merchant_id,
+            &tracking_data.target_merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: tracking_data.target_merchant_connector_id.clone(),
+        })?;
+
+    let migration = ConnectorMigration {
+        state,
+        merchant_account: &merchant_account,
+        tracking_data,
+        target_connector_account: &target_connector_account,
+        is_network_transaction_id_supported: target_connector_account
+            .connector_name
+            .parse::<api_enums::Connector>()
+            .map(|connector| {
+                state
+                    .conf
+                    .network_transaction_id_supported_connectors
+                    .connector_list
+                    .contains(&connector)
+            })
+            .unwrap_or(false),
+    };
+    let mut items = Vec::new();
+
+    let customers = db
+        .list_customers_by_merchant_id(&tracking_data.merchant_id, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the customers of the merchant")?;
+    for customer in customers {
+        let payment_methods = db
+            .find_payment_method_by_customer_id_merchant_id_list(
+                &customer.customer_id,
+                &tracking_data.merchant_id,
+                None,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the payment methods of the customer")?;
+        for payment_method in payment_methods {
+            if let Some(item) = migration.migrate_payment_method(payment_method).await {
+                items.push(item);
+            }
+        }
+    }
+
+    // Mandates are tied to a single connector, which is why only the ones of the source connector
+    // account are migrated
+    let mandates = db
+        .find_mandates_by_merchant_id(
+            &tracking_data.merchant_id,
+            MandateListConstraints {
+                limit: None,
+                offset: None,
+                mandate_status: Some(api_enums::MandateStatus::Active),
+                connector: Some(source_connector_account.connector_name.clone()),
+                created_time: None,
+                created_time_lt: None,
+                created_time_gt: None,
+                created_time_lte: None,
+                created_time_gte: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the mandates of the merchant")?;
+    for mandate in mandates.into_iter().filter(|mandate| {
+        mandate.merchant_connector_id.as_ref() == Some(&tracking_data.source_merchant_connector_id)
+    }) {
+        items.push(migration.migrate_mandate(mandate).await);
+    }
+
+    Ok(items)
+}
+
+struct ConnectorMigration<'a> {
+    state: &'a AppState,
+    merchant_account: &'a domain::MerchantAccount,
+    tracking_data: &'a ConnectorMigrationTrackingData,
+    target_connector_account: &'a domain::MerchantConnectorAccount,
+    is_network_transaction_id_supported: bool,
+}
+
+impl ConnectorMigration<'_> {
+    /// Migrate the token of the payment method stored with the source connector account, if any.
+    /// The token of the source connector account is kept, so that payments can still be made with
+    /// the source connector while the merchant switches over.
+    async fn migrate_payment_method(
+        &self,
+        payment_method: storage::PaymentMethod,
+    ) -> Option<ConnectorMigrationItem> {
+        let payment_method_id = payment_method.payment_method_id.clone();
+        let mut connector_mandate_details = match payment_method
+            .connector_mandate_details
+            .clone()
+            .map(|details| {
+                details.parse_value::<storage::PaymentsMandateReference>("PaymentsMandateReference")
+            })
+            .transpose()
+        {
+            Ok(connector_mandate_details) => connector_mandate_details?,
+            Err(error) => {
+                logger::error!(?error, %payment_method_id, "Failed to parse connector mandate details");
+                return Some(failed_item(
+                    payment_method_id,
+                    None,
+                    "The connector mandate details of the payment method are invalid",
+                ));
+            }
+        };
+        let source_record = connector_mandate_details
+            .get(&self.tracking_data.source_merchant_connector_id)?
+            .clone();
+
+        let Some(imported_token) = self
+            .tracking_data
+            .token_mapping
+            .get(&source_record.connector_mandate_id)
+        else {
+            return Some(self.network_transaction_id_fallback(&payment_method));
+        };
+
+        connector_mandate_details.insert(
+            self.tracking_data.target_merchant_connector_id.clone(),
+            storage::PaymentsMandateReferenceRecord {
+                connector_mandate_id: imported_token.clone(),
+                ..source_record
+            },
+        );
+        let result = async {
+            let connector_mandate_details = connector_mandate_details
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            self.state
+                .store
+                .update_payment_method(
+                    payment_method,
+                    storage::PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
+                        connector_mandate_details: Some(connector_mandate_details),
+                    },
+                    self.merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        }
+        .await;
+
+        Some(match result {
+            Ok(_) => imported_item(payment_method_id, None),
+            Err(error) => {
+                logger::error!(?error, %payment_method_id, "Failed to migrate payment method");
+                failed_item(
+                    payment_method_id,
+                    None,
+                    "Failed to update the payment method",
+                )
+            }
+        })
+    }
+
+    /// Move the mandate to the target connector account, along with the token imported by the
+    /// target connector or without any token if the network transaction ID is used instead
+    async fn migrate_mandate(&self, mandate: storage::Mandate) -> ConnectorMigrationItem {
+        let payment_method_id = mandate.payment_method_id.clone();
+        let mandate_id = mandate.mandate_id.clone();
+
+        let imported_token = mandate
+            .connector_mandate_id
+            .as_ref()
+            .and_then(|token| self.tracking_data.token_mapping.get(token));
+        if imported_token.is_none()
+            && !(self.is_network_transaction_id_supported
+                && mandate.network_transaction_id.is_some())
+        {
+            return failed_item(
+                payment_method_id,
+                Some(mandate_id),
+                TOKEN_NOT_MIGRATABLE_MESSAGE,
+            );
+        }
+
+        let result = async {
+            let connector_mandate_ids = imported_token
+                .map(|imported_token| {
+                    ConnectorMandateReferenceId {
+                        connector_mandate_id: Some(imported_token.clone()),
+                        payment_method_id: Some(payment_method_id.clone()),
+                        update_history: None,
+                    }
+                    .encode_to_value()
+                    .map(masking::Secret::new)
+                })
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            self.state
+                .store
+                .update_mandate_by_merchant_id_mandate_id(
+                    &self.tracking_data.merchant_id,
+                    &mandate_id,
+                    storage::MandateUpdate::ConnectorMigrationUpdate {
+                        connector: self.target_connector_account.connector_name.clone(),
+                        merchant_connector_id: self
+                            .tracking_data
+                            .target_merchant_connector_id
+                            .clone(),
+                        connector_mandate_id: imported_token.cloned(),
+                        connector_mandate_ids,
+                    },
+                    mandate,
+                    self.merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        }
+        .await;
+
+        match result {
+            Ok(_) if imported_token.is_some() => imported_item(payment_method_id, Some(mandate_id)),
+            Ok(_) => ConnectorMigrationItem {
+                payment_method_id,
+                mandate_id: Some(mandate_id),
+                outcome: ConnectorMigrationOutcome::NetworkTransactionIdFallback,
+                error_message: None,
+            },
+            Err(error) => {
+                logger::error!(?error, %mandate_id, "Failed to migrate mandate");
+                failed_item(
+                    payment_method_id,
+                    Some(mandate_id),
+                    "Failed to update the mandate",
+                )
+            }
+        }
+    }
+
+    /// Payments made with the payment method through the target connector use the network
+    /// transaction ID of the payment method, which requires no change to the payment method
+    fn network_transaction_id_fallback(
+        &self,
+        payment_method: &storage::PaymentMethod,
+    ) -> ConnectorMigrationItem {
+        let payment_method_id = payment_method.payment_method_id.clone();
+        if self.is_network_transaction_id_supported
+            && payment_method.payment_method == Some(enums::PaymentMethod::Card)
+            && payment_method.network_transaction_id.is_some()
+        {
+            ConnectorMigrationItem {
+                payment_method_id,
+                mandate_id: None,
+                outcome: ConnectorMigrationOutcome::NetworkTransactionIdFallback,
+                error_message: None,
+            }
+        } else {
+            failed_item(payment_method_id, None, TOKEN_NOT_MIGRATABLE_MESSAGE)
+        }
+    }
+}
+
+fn imported_item(payment_method_id: String, mandate_id: Option<String>) -> ConnectorMigrationItem {
+    ConnectorMigrationItem {
+        payment_method_id,
+        mandate_id,
+        outcome: ConnectorMigrationOutcome::TokenImported,
+        error_message: None,
+    }
+}
+
+fn failed_item(
+    payment_method_id: String,
+    mandate_id: Option<String>,
+    error_message: &str,
+) -> ConnectorMigrationItem {
+    ConnectorMigrationItem {
+        payment_method_id,
+        mandate_id,
+        outcome: ConnectorMigrationOutcome::Failed,
+        error_message: Some(error_message.to_string()),
+    }
+}
+
+fn get_connector_migration_response(
+    tracking_data: &ConnectorMigrationTrackingData,
+    status: ConnectorMigrationStatus,
+) -> ConnectorMigrationResponse {
+    ConnectorMigrationResponse {
+        migration_id: tracking_data.migration_id.clone(),
+        merchant_id: tracking_data.merchant_id.clone(),
+        source_merchant_connector_id: tracking_data.source_merchant_connector_id.clone(),
+        target_merchant_connector_id: tracking_data.target_merchant_connector_id.clone(),
+        status,
+        items: tracking_data.items.clone(),
+    }
+}
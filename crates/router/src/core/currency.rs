@@ -1,10 +1,10 @@
 use common_utils::errors::CustomResult;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 
 use crate::{
     core::errors::ApiErrorResponse,
     services::ApplicationResponse,
-    utils::currency::{self, convert_currency, get_forex_rates},
+    utils::currency::{self, convert_currency, get_forex_rates, localize_amount},
     AppState,
 };
 
@@ -46,3 +46,28 @@ pub async fn convert_forex(
         .change_context(ApiErrorResponse::InternalServerError)?,
     ))
 }
+
+pub async fn localize_forex_amount(
+    state: AppState,
+    params: api_models::currency::AmountLocalizationParams,
+) -> CustomResult<
+    ApplicationResponse<api_models::currency::AmountLocalizationResponse>,
+    ApiErrorResponse,
+> {
+    if params.amount <= 0 {
+        return Err(report!(ApiErrorResponse::InvalidRequestData {
+            message: "amount should be greater than 0".to_string(),
+        }));
+    }
+
+    Ok(ApplicationResponse::Json(
+        Box::pin(localize_amount(
+            state,
+            params.amount,
+            params.currency,
+            params.country,
+        ))
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)?,
+    ))
+}
@@ -0,0 +1,366 @@
+use api_models::customer_data_export::{
+    CustomerDataExport, CustomerDataExportPaymentMethod, CustomerDataExportRequest,
+    CustomerDataExportResponse, CustomerDataExportStatus,
+};
+use common_utils::ext_traits::{ByteSliceExt, Encode, ValueExt};
+use error_stack::ResultExt;
+use futures::future::try_join_all;
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use router_env::{instrument, logger, tracing};
+
+use super::errors::{self, RouterResponse, RouterResult, StorageErrorExt};
+use crate::{
+    consts,
+    routes::{metrics, AppState},
+    services,
+    types::{
+        api,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const CUSTOMER_DATA_EXPORT_TAG: &str = "CUSTOMER_DATA_EXPORT";
+const CUSTOMER_DATA_EXPORT_NAME: &str = "CUSTOMER_DATA_EXPORT";
+const CUSTOMER_DATA_EXPORT_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::CustomerDataExportWorkflow;
+
+/// The business status the customer data export task is finished with once the export has been
+/// uploaded to the file storage
+pub(crate) const CUSTOMER_DATA_EXPORT_COMPLETED_STATUS: &str = "COMPLETED_BY_PT";
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct CustomerDataExportTrackingData {
+    pub(crate) export_id: String,
+    pub(crate) merchant_id: String,
+    pub(crate) customer_id: String,
+}
+
+fn get_process_tracker_id(export_id: &str) -> String {
+    format!("{CUSTOMER_DATA_EXPORT_RUNNER}_{CUSTOMER_DATA_EXPORT_NAME}_{export_id}")
+}
+
+/// The exports are stored as JSON files along with the other files of the merchant
+fn get_file_key(merchant_id: &str, export_id: &str) -> String {
+    format!("{merchant_id}/customer_data_exports/{export_id}.json")
+}
+
+/// Schedule the export of all the data held about the customer by the merchant, to answer the
+/// data subject access requests of the customer
+#[instrument(skip_all)]
+pub async fn create_customer_data_export(
+    state: AppState,
+    merchant_id: String,
+    request: CustomerDataExportRequest,
+) -> RouterResponse<CustomerDataExportResponse> {
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    db.find_customer_by_customer_id_merchant_id(
+        &request.customer_id,
+        &merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let export_id = common_utils::generate_id(consts::ID_LENGTH, "cde");
+    let tracking_data = CustomerDataExportTrackingData {
+        export_id: export_id.clone(),
+        merchant_id,
+        customer_id: request.customer_id,
+    };
+    let response =
+        get_customer_data_export_response(&tracking_data, CustomerDataExportStatus::Pending, None);
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_process_tracker_id(&export_id),
+        CUSTOMER_DATA_EXPORT_NAME,
+        CUSTOMER_DATA_EXPORT_RUNNER,
+        [CUSTOMER_DATA_EXPORT_TAG],
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct customer data export process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting customer data export task to process_tracker: export_id: {export_id}"
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "flow",
+            "CustomerDataExport",
+        )],
+    );
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_customer_data_export(
+    state: AppState,
+    merchant_id: String,
+    export_id: String,
+) -> RouterResponse<CustomerDataExportResponse> {
+    let not_found_error = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Customer data export not found".to_string(),
+    };
+
+    let process = state
+        .store
+        .find_process_by_id(&get_process_tracker_id(&export_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the customer data export task")?
+        .ok_or_else(not_found_error)?;
+    let tracking_data: CustomerDataExportTrackingData = process
+        .tracking_data
+        .parse_value("CustomerDataExportTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    if tracking_data.merchant_id != merchant_id {
+        return Err(not_found_error().into());
+    }
+
+    let response = match process.status {
+        enums::ProcessTrackerStatus::Finish
+            if process.business_status == CUSTOMER_DATA_EXPORT_COMPLETED_STATUS =>
+        {
+            let data = state
+                .file_storage_client
+                .retrieve_file(&get_file_key(&merchant_id, &export_id))
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to retrieve the customer data export file")?
+                .parse_struct::<CustomerDataExport>("CustomerDataExport")
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            get_customer_data_export_response(
+                &tracking_data,
+                CustomerDataExportStatus::Completed,
+                Some(data),
+            )
+        }
+        enums::ProcessTrackerStatus::Finish => get_customer_data_export_response(
+            &tracking_data,
+            CustomerDataExportStatus::Failed,
+            None,
+        ),
+        enums::ProcessTrackerStatus::Processing
+        | enums::ProcessTrackerStatus::New
+        | enums::ProcessTrackerStatus::Pending
+        | enums::ProcessTrackerStatus::ProcessStarted => get_customer_data_export_response(
+            &tracking_data,
+            CustomerDataExportStatus::Pending,
+            None,
+        ),
+    };
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Compile all the data held about the customer and upload it to the file storage, from which it
+/// is returned when the export is retrieved
+pub(crate) async fn export_customer_data(
+    state: &AppState,
+    tracking_data: &CustomerDataExportTrackingData,
+) -> RouterResult<()> {
+    let data = compile_customer_data(state, tracking_data).await?;
+    logger::info!(
+        export_id = %tracking_data.export_id,
+        payments = data.payments.len(),
+        payment_methods = data.payment_methods.len(),
+        disputes = data.disputes.len(),
+        webhook_events = data.webhook_events.len(),
+        "Compiled customer data export"
+    );
+
+    let file = data
+        .encode_to_vec()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the customer data export")?;
+    state
+        .file_storage_client
+        .upload_file(
+            &get_file_key(&tracking_data.merchant_id, &tracking_data.export_id),
+            file,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload the customer data export file")
+}
+
+async fn compile_customer_data(
+    state: &AppState,
+    tracking_data: &CustomerDataExportTrackingData,
+) -> RouterResult<CustomerDataExport> {
+    let db = &*state.store;
+    let merchant_id = tracking_data.merchant_id.as_str();
+    let customer_id = tracking_data.customer_id.as_str();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            customer_id,
+            merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+    let address = match &customer.address_id {
+        Some(address_id) => Some(api_models::payments::AddressDetails::from(
+            db.find_address_by_address_id(address_id, &key_store)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?,
+        )),
+        None => None,
+    };
+
+    let payment_intents = db
+        .filter_payment_intent_by_constraints(
+            merchant_id,
+            &PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+                offset: 0,
+                starting_at: None,
+                ending_at: None,
+                amount_filter: None,
+                connector: None,
+                currency: None,
+                status: None,
+                payment_method: None,
+                payment_method_type: None,
+                authentication_type: None,
+                merchant_connector_id: None,
+                tags: None,
+                profile_id: None,
+                customer_id: Some(customer_id.to_string()),
+                starting_after_id: None,
+                ending_before_id: None,
+                limit: None,
+            })),
+            // since OLAP doesn't have KV. Force to get the data from PSQL.
+            enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_ids = payment_intents
+        .iter()
+        .map(|payment_intent| payment_intent.payment_id.clone())
+        .collect::<Vec<_>>();
+
+    let payments = try_join_all(payment_intents.into_iter().map(|payment_intent| async {
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &payment_intent.payment_id,
+                merchant_id,
+                &payment_intent.active_attempt.get_id(),
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(api::PaymentsResponse::foreign_from(
+            (payment_intent, payment_attempt),
+        ))
+    }))
+    .await?;
+
+    let payment_methods = db
+        .find_payment_method_by_customer_id_merchant_id_list(customer_id, merchant_id, None)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the payment methods of the customer")?
+        .into_iter()
+        .map(|payment_method| CustomerDataExportPaymentMethod {
+            payment_method_id: payment_method.payment_method_id,
+            payment_method: payment_method.payment_method,
+            payment_method_type: payment_method.payment_method_type,
+            payment_method_issuer: payment_method.payment_method_issuer,
+            scheme: payment_method.scheme,
+            issuer_country: payment_method.issuer_country,
+            status: payment_method.status,
+            created: payment_method.created_at,
+            last_used_at: payment_method.last_used_at,
+        })
+        .collect();
+
+    let disputes = try_join_all(
+        payment_ids
+            .iter()
+            .map(|payment_id| db.find_disputes_by_merchant_id_payment_id(merchant_id, payment_id)),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to find the disputes of the payments of the customer")?
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    // The events of the payments and of their disputes, as the events of the refunds are not
+    // bound to the payments
+    let primary_object_ids = payment_ids
+        .iter()
+        .chain(disputes.iter().map(|dispute| &dispute.dispute_id));
+    let webhook_events = try_join_all(primary_object_ids.map(|primary_object_id| {
+        db.list_initial_events_by_merchant_id_primary_object_id(
+            merchant_id,
+            primary_object_id,
+            &key_store,
+        )
+    }))
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to find the webhook events of the customer")?
+    .into_iter()
+    .flatten()
+    .map(api_models::webhook_events::EventListItemResponse::try_from)
+    .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(CustomerDataExport {
+        customer: api::CustomerResponse::from((customer, address)).into_inner(),
+        payments,
+        payment_methods,
+        disputes: disputes
+            .into_iter()
+            .map(api_models::disputes::DisputeResponse::foreign_from)
+            .collect(),
+        webhook_events,
+        exported_at: common_utils::date_time::now(),
+    })
+}
+
+fn get_customer_data_export_response(
+    tracking_data: &CustomerDataExportTrackingData,
+    status: CustomerDataExportStatus,
+    data: Option<CustomerDataExport>,
+) -> CustomerDataExportResponse {
+    CustomerDataExportResponse {
+        export_id: tracking_data.export_id.clone(),
+        merchant_id: tracking_data.merchant_id.clone(),
+        customer_id: tracking_data.customer_id.clone(),
+        status,
+        data,
+    }
+}
@@ -294,6 +294,8 @@ pub enum WebhooksFlowError {
     OutgoingWebhookRetrySchedulingFailed,
     #[error("Outgoing webhook response encoding failed")]
     OutgoingWebhookResponseEncodingFailed,
+    #[error("Failed to update the health of the merchant webhook endpoint")]
+    WebhookEndpointHealthUpdateFailed,
 }
 
 impl WebhooksFlowError {
@@ -311,7 +313,8 @@ impl WebhooksFlowError {
             | Self::DisputeWebhookValidationFailed
             | Self::OutgoingWebhookEncodingFailed
             | Self::OutgoingWebhookProcessTrackerTaskUpdateFailed
-            | Self::OutgoingWebhookRetrySchedulingFailed => true,
+            | Self::OutgoingWebhookRetrySchedulingFailed
+            | Self::WebhookEndpointHealthUpdateFailed => true,
         }
     }
 }
@@ -332,6 +335,26 @@ pub enum ApplePayDecryptionError {
     DerivingSharedSecretKeyFailed,
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum GooglePayDecryptionError {
+    #[error("Failed to parse the payment token")]
+    TokenParsingFailed,
+    #[error("Unsupported protocol version of the payment token")]
+    UnsupportedProtocolVersion,
+    #[error("Failed to base64 decode input data")]
+    Base64DecodingFailed,
+    #[error("Key Deserialization failure")]
+    KeyDeserializationFailed,
+    #[error("Failed to verify the signature of the payment token")]
+    SignatureVerificationFailed,
+    #[error("The signing key or the payment token has expired")]
+    Expired,
+    #[error("Failed to Derive a shared secret key")]
+    DerivingSharedSecretKeyFailed,
+    #[error("Failed to decrypt input data")]
+    DecryptionFailed,
+}
+
 impl ConnectorError {
     pub fn is_connector_timeout(&self) -> bool {
         self == &Self::RequestTimeoutReceived
@@ -271,6 +271,10 @@ pub enum ApiErrorResponse {
     InvalidCookie,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_27", message = "Extended card info does not exist")]
     ExtendedCardInfoNotFound,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_28", message = "Raw card data is not allowed for the merchant as it is not marked as PCI compliant")]
+    RawCardDataNotAllowed,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_29", message = "The request body is larger than the limit of {limit} bytes")]
+    PayloadTooLarge { limit: usize },
 }
 
 impl PTError for ApiErrorResponse {
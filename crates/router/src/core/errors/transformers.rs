@@ -304,6 +304,12 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::ExtendedCardInfoNotFound => {
                 AER::NotFound(ApiError::new("IR", 27, "Extended card info does not exist", None))
             }
+            Self::RawCardDataNotAllowed => {
+                AER::ForbiddenCommonResource(ApiError::new("IR", 28, "Raw card data is not allowed for the merchant as it is not marked as PCI compliant", None))
+            }
+            Self::PayloadTooLarge { limit } => {
+                AER::PayloadTooLarge(ApiError::new("IR", 29, format!("The request body is larger than the limit of {limit} bytes"), None))
+            }
         }
     }
 }
@@ -136,6 +136,16 @@ pub async fn should_call_frm<F>(
 where
     F: Send + Clone,
 {
+    let is_merchant_entitled = merchant_account
+        .get_feature_entitlements()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse feature entitlements of the merchant account")?
+        .is_entitled_to(api_models::admin::MerchantFeature::Frm);
+    if !is_merchant_entitled {
+        logger::debug!("Merchant is not entitled to use FRM, skipping the fraud check");
+        return Ok((false, None, None, None));
+    }
+
     match merchant_account.frm_routing_algorithm.clone() {
         Some(frm_routing_algorithm_value) => {
             let frm_routing_algorithm_struct: FrmRoutingAlgorithm = frm_routing_algorithm_value
@@ -116,6 +116,7 @@ impl ConstructFlowSpecificData<frm_api::Checkout, FraudCheckCheckoutData, FraudC
             payment_method_balance: None,
             connector_http_status_code: None,
             external_latency: None,
+            connector_request_id: None,
             connector_api_version: None,
             apple_pay_flow: None,
             frm_metadata: self.frm_metadata.clone(),
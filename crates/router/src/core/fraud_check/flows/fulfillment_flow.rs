@@ -104,6 +104,7 @@ pub async fn construct_fulfillment_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -94,6 +94,7 @@ impl ConstructFlowSpecificData<RecordReturn, FraudCheckRecordReturnData, FraudCh
             payment_method_balance: None,
             connector_http_status_code: None,
             external_latency: None,
+            connector_request_id: None,
             connector_api_version: None,
             apple_pay_flow: None,
             frm_metadata: None,
@@ -90,6 +90,7 @@ impl ConstructFlowSpecificData<frm_api::Sale, FraudCheckSaleData, FraudCheckResp
             payment_method_balance: None,
             connector_http_status_code: None,
             external_latency: None,
+            connector_request_id: None,
             connector_api_version: None,
             apple_pay_flow: None,
             frm_metadata: None,
@@ -260,6 +260,8 @@ impl<F: Send + Clone> Domain<F> for FraudCheckPost {
                 statement_descriptor_suffix: None,
                 statement_descriptor_prefix: None,
                 merchant_connector_details: None,
+                capture_adjustment_reason: None,
+                order_details: None,
             };
             let capture_response = Box::pin(payments::payments_core::<
                 Capture,
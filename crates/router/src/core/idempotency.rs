@@ -0,0 +1,353 @@
+use std::future::Future;
+
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    ext_traits::Encode,
+};
+use error_stack::{report, ResultExt};
+use masking::Maskable;
+use redis_interface::SetnxReply;
+use router_env::{logger, Flow};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::errors::{self, CustomResult, RouterResponse, RouterResult};
+use crate::{headers, routes::AppState, services};
+
+const IDEMPOTENCY_KEY_PREFIX: &str = "IDEMPOTENCY";
+
+/// Maximum length of the `Idempotency-Key` header accepted
+const IDEMPOTENCY_KEY_MAX_LENGTH: usize = 255;
+
+/// Request made with an `Idempotency-Key` header, whose response is replayed when it is retried
+/// with the same key instead of being performed again.
+#[derive(Clone, Debug)]
+pub struct IdempotentRequest {
+    idempotency_key: String,
+    flow: String,
+    request_hash: String,
+}
+
+/// State of a request made with an idempotency key, as recorded in redis
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum IdempotencyRecord {
+    InProgress {
+        request_hash: String,
+    },
+    Completed {
+        request_hash: String,
+        response: serde_json::Value,
+        /// Headers of the response, which are replayed along with it
+        #[serde(default)]
+        headers: Vec<RecordedHeader>,
+    },
+}
+
+/// Header of a recorded response, which is masked again when it is replayed if it was masked
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedHeader {
+    name: String,
+    value: String,
+    is_masked: bool,
+}
+
+impl From<&(String, Maskable<String>)> for RecordedHeader {
+    fn from((name, value): &(String, Maskable<String>)) -> Self {
+        Self {
+            name: name.clone(),
+            is_masked: value.is_masked(),
+            value: value.clone().into_inner(),
+        }
+    }
+}
+
+impl From<RecordedHeader> for (String, Maskable<String>) {
+    fn from(header: RecordedHeader) -> Self {
+        let value = if header.is_masked {
+            Maskable::new_masked(header.value.into())
+        } else {
+            Maskable::new_normal(header.value)
+        };
+        (header.name, value)
+    }
+}
+
+impl IdempotencyRecord {
+    fn request_hash(&self) -> &str {
+        match self {
+            Self::InProgress { request_hash } | Self::Completed { request_hash, .. } => {
+                request_hash
+            }
+        }
+    }
+}
+
+impl IdempotentRequest {
+    /// Idempotent request for the `Idempotency-Key` header of the request, if any.
+    ///
+    /// The payload is hashed as it was received, before any identifiers are generated for it, so
+    /// that the retries of the request can be told apart from the requests reusing the key.
+    pub fn from_request<T: Serialize>(
+        request: &actix_web::HttpRequest,
+        flow: &Flow,
+        payload: &T,
+    ) -> RouterResult<Option<Self>> {
+        let Some(idempotency_key) = request.headers().get(headers::IDEMPOTENCY_KEY) else {
+            return Ok(None);
+        };
+        let idempotency_key = idempotency_key
+            .to_str()
+            .ok()
+            .map(str::trim)
+            .filter(|idempotency_key| {
+                !idempotency_key.is_empty() && idempotency_key.len() <= IDEMPOTENCY_KEY_MAX_LENGTH
+            })
+            .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The `{}` header must be a non empty string of at most {IDEMPOTENCY_KEY_MAX_LENGTH} characters",
+                    headers::IDEMPOTENCY_KEY
+                ),
+            })?;
+
+        let serialized_payload = serde_json::to_vec(payload)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the request for the idempotency key")?;
+        let request_hash = Sha256
+            .generate_digest(&serialized_payload)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to hash the request for the idempotency key")?;
+
+        Ok(Some(Self {
+            idempotency_key: idempotency_key.to_string(),
+            flow: flow.to_string(),
+            request_hash: hex::encode(request_hash),
+        }))
+    }
+
+    fn get_redis_key(&self, merchant_id: &str) -> String {
+        format!(
+            "{IDEMPOTENCY_KEY_PREFIX}_{merchant_id}_{}_{}",
+            self.flow, self.idempotency_key
+        )
+    }
+}
+
+/// Perform the request, unless it is a retry of a request made with the same idempotency key, in
+/// which case the response of the original request is replayed.
+///
+/// Requests reusing the idempotency key of a different request are rejected, as are the retries
+/// of a request which is still being processed. Only JSON responses are recorded, along with their
+/// headers, the key is released for the other responses and for errors so that the request can be
+/// retried.
+pub async fn perform_idempotent_request<Q, Fut>(
+    state: AppState,
+    merchant_id: String,
+    idempotent_request: Option<IdempotentRequest>,
+    request: Fut,
+) -> RouterResponse<Q>
+where
+    Q: Serialize + DeserializeOwned,
+    Fut: Future<Output = RouterResponse<Q>>,
+{
+    let Some(idempotent_request) = idempotent_request else {
+        return request.await;
+    };
+    let redis_key = idempotent_request.get_redis_key(&merchant_id);
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let in_progress_record = IdempotencyRecord::InProgress {
+        request_hash: idempotent_request.request_hash.clone(),
+    }
+    .encode_to_string_of_json()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize the idempotency record")?;
+    let reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &redis_key,
+            in_progress_record,
+            Some(state.conf.idempotency.in_progress_ttl_in_secs),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the request for the idempotency key")?;
+
+    if reply == SetnxReply::KeyNotSet {
+        return replay_response(&state, &redis_key, &idempotent_request).await;
+    }
+
+    let response = request.await;
+
+    let result = match &response {
+        Ok(services::ApplicationResponse::Json(data)) => {
+            record_response(&state, &redis_key, &idempotent_request, data, &[]).await
+        }
+        Ok(services::ApplicationResponse::JsonWithHeaders((data, headers))) => {
+            record_response(&state, &redis_key, &idempotent_request, data, headers).await
+        }
+        Ok(_) | Err(_) => release_idempotency_key(&state, &redis_key).await,
+    };
+    // The request has been performed already, so only the failure to record its outcome is logged
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            flow = %idempotent_request.flow,
+            "Failed to record the response for the idempotency key"
+        );
+    }
+
+    response
+}
+
+async fn replay_response<Q: DeserializeOwned>(
+    state: &AppState,
+    redis_key: &str,
+    idempotent_request: &IdempotentRequest,
+) -> RouterResponse<Q> {
+    let record = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_and_deserialize_key::<IdempotencyRecord>(redis_key, "IdempotencyRecord")
+        .await
+        .map_err(|error| match error.current_context() {
+            // The original request has been released in the meantime, so it can be retried
+            errors::RedisError::NotFound => {
+                error.change_context(errors::ApiErrorResponse::ResourceBusy)
+            }
+            _ => error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to read the request for the idempotency key"),
+        })?;
+
+    if record.request_hash() != idempotent_request.request_hash {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The `{}` header has already been used with a different request",
+                headers::IDEMPOTENCY_KEY
+            ),
+        }));
+    }
+
+    match record {
+        IdempotencyRecord::InProgress { .. } => {
+            Err(report!(errors::ApiErrorResponse::ResourceBusy)
+                .attach_printable("The request for the idempotency key is still being processed"))
+        }
+        IdempotencyRecord::Completed {
+            response, headers, ..
+        } => {
+            logger::info!(
+                flow = %idempotent_request.flow,
+                "Replaying the response for the idempotency key"
+            );
+            let response = serde_json::from_value(response)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to deserialize the response for the idempotency key")?;
+
+            Ok(if headers.is_empty() {
+                services::ApplicationResponse::Json(response)
+            } else {
+                services::ApplicationResponse::JsonWithHeaders((
+                    response,
+                    headers.into_iter().map(Into::into).collect(),
+                ))
+            })
+        }
+    }
+}
+
+async fn record_response<Q: Serialize>(
+    state: &AppState,
+    redis_key: &str,
+    idempotent_request: &IdempotentRequest,
+    response: &Q,
+    headers: &[(String, Maskable<String>)],
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let completed_record = IdempotencyRecord::Completed {
+        request_hash: idempotent_request.request_hash.clone(),
+        response: serde_json::to_value(response)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the response for the idempotency key")?,
+        headers: headers.iter().map(RecordedHeader::from).collect(),
+    };
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .serialize_and_set_key_with_expiry(
+            redis_key,
+            completed_record,
+            state.conf.idempotency.ttl_in_secs,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the response for the idempotency key")
+}
+
+async fn release_idempotency_key(
+    state: &AppState,
+    redis_key: &str,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .delete_key(redis_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to release the idempotency key")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_recorded_header_preserves_masking() {
+        let headers = vec![
+            (
+                "x-request-id".to_string(),
+                Maskable::new_normal("req_1".to_string()),
+            ),
+            (
+                "x-client-secret".to_string(),
+                Maskable::new_masked("secret_1".to_string().into()),
+            ),
+        ];
+
+        let recorded: Vec<RecordedHeader> = headers.iter().map(RecordedHeader::from).collect();
+        let replayed: Vec<(String, Maskable<String>)> =
+            recorded.into_iter().map(Into::into).collect();
+
+        assert_eq!(replayed[0].0, "x-request-id");
+        assert!(replayed[0].1.is_normal());
+        assert_eq!(replayed[0].1.clone().into_inner(), "req_1");
+        assert_eq!(replayed[1].0, "x-client-secret");
+        assert!(replayed[1].1.is_masked());
+        assert_eq!(replayed[1].1.clone().into_inner(), "secret_1");
+    }
+
+    #[test]
+    fn test_completed_record_without_headers() {
+        let record: IdempotencyRecord = serde_json::from_str(
+            r#"{"status":"completed","request_hash":"hash","response":{"id":"pay_1"}}"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            record,
+            IdempotencyRecord::Completed { headers, .. } if headers.is_empty()
+        ));
+    }
+}
@@ -4,26 +4,170 @@ use common_utils::{
         DEFAULT_BACKGROUND_COLOR, DEFAULT_DISPLAY_SDK_ONLY, DEFAULT_ENABLE_SAVED_PAYMENT_METHOD,
         DEFAULT_MERCHANT_LOGO, DEFAULT_PRODUCT_IMG, DEFAULT_SDK_LAYOUT, DEFAULT_SESSION_EXPIRY,
     },
-    ext_traits::{OptionExt, ValueExt},
+    ext_traits::{Encode, OptionExt, ValueExt},
 };
 use error_stack::ResultExt;
 use futures::future;
 use masking::{PeekInterface, Secret};
 use time::PrimitiveDateTime;
 
-use super::errors::{self, RouterResult, StorageErrorExt};
+use super::{
+    errors::{self, RouterResult, StorageErrorExt},
+    payments::helpers,
+};
 use crate::{
+    consts,
     errors::RouterResponse,
     routes::AppState,
     services,
     types::{
-        api::payment_link::PaymentLinkResponseExt, domain, storage::enums as storage_enums,
+        api::payment_link::PaymentLinkResponseExt,
+        domain,
+        storage::{self, enums as storage_enums},
         transformers::ForeignFrom,
     },
+    utils,
 };
 
+/// Create a payment link for a payment which has been created without one, so that the payment
+/// can be made on the hosted payment page.
+pub async fn create_payment_link(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    request: api_models::payments::PaymentLinkCreateRequest,
+) -> RouterResponse<api_models::payments::RetrievePaymentLinkResponse> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &request.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    helpers::validate_payment_status_against_allowed_statuses(
+        &payment_intent.status,
+        &[storage_enums::IntentStatus::RequiresPaymentMethod],
+        "create a payment link for",
+    )?;
+
+    if payment_intent.payment_link_id.is_some() {
+        return Err(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "payment link already exists!".to_string(),
+        }
+        .into());
+    }
+
+    let currency = payment_intent
+        .currency
+        .get_required_value("currency")
+        .change_context(errors::ApiErrorResponse::PreconditionFailed {
+            message: "You cannot create a payment link for a payment without a currency"
+                .to_string(),
+        })?;
+
+    let profile_id = payment_intent
+        .profile_id
+        .clone()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Profile id missing in payment intent")?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.clone(),
+        })?;
+
+    let merchant_name = merchant_account
+        .merchant_name
+        .clone()
+        .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+        .unwrap_or_default();
+
+    let (payment_link_config, domain_name) = get_payment_link_config_based_on_priority(
+        request.payment_link_config,
+        business_profile.payment_link_config,
+        merchant_name,
+        state.conf.server.base_url.clone(),
+    )?;
+
+    let created_at = common_utils::date_time::now();
+    let payment_expiry = payment_intent.session_expiry.unwrap_or_else(|| {
+        payment_intent
+            .created_at
+            .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
+    });
+    // The payment link cannot be used once the client secret of the payment has expired
+    let payment_link_expiry = match request.session_expiry {
+        Some(session_expiry) => {
+            let payment_link_expiry =
+                created_at.saturating_add(time::Duration::seconds(i64::from(session_expiry)));
+            utils::when(payment_link_expiry > payment_expiry, || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "session_expiry of the payment link must not exceed the expiry of the payment".to_string(),
+                })
+            })?;
+            payment_link_expiry
+        }
+        None => payment_expiry,
+    };
+
+    let payment_link_config_encoded_value = payment_link_config.encode_to_value().change_context(
+        errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_link_config",
+        },
+    )?;
+
+    let payment_link_new = storage::PaymentLinkNew {
+        payment_link_id: utils::generate_id(consts::ID_LENGTH, "plink"),
+        payment_id: payment_intent.payment_id.clone(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        link_to_pay: format!(
+            "{domain_name}/payment_link/{}/{}",
+            merchant_account.merchant_id, payment_intent.payment_id
+        ),
+        amount: payment_intent.amount,
+        currency: Some(currency),
+        created_at: Some(created_at),
+        last_modified_at: Some(created_at),
+        fulfilment_time: Some(payment_link_expiry),
+        custom_merchant_name: Some(payment_link_config.seller_name),
+        description: payment_intent.description.clone(),
+        payment_link_config: Some(payment_link_config_encoded_value),
+        profile_id: Some(profile_id),
+    };
+    let payment_link = db
+        .insert_payment_link(payment_link_new)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "payment link already exists!".to_string(),
+        })?;
+
+    let payment_intent = db
+        .update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::PaymentLinkUpdate {
+                payment_link_id: payment_link.payment_link_id.clone(),
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let status = check_payment_link_status(payment_link_expiry, payment_intent.status);
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::RetrievePaymentLinkResponse::foreign_from((payment_link, status)),
+    ))
+}
+
 pub async fn retrieve_payment_link(
     state: AppState,
+    merchant_account: domain::MerchantAccount,
     payment_link_id: String,
 ) -> RouterResponse<api_models::payments::RetrievePaymentLinkResponse> {
     let db = &*state.store;
@@ -32,12 +176,21 @@ pub async fn retrieve_payment_link(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
 
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_link_config.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
     let session_expiry = payment_link_config.fulfilment_time.unwrap_or_else(|| {
         common_utils::date_time::now()
             .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
     });
 
-    let status = check_payment_link_status(session_expiry);
+    let status = check_payment_link_status(session_expiry, payment_intent.status);
 
     let response = api_models::payments::RetrievePaymentLinkResponse::foreign_from((
         payment_link_config,
@@ -114,6 +267,108 @@ pub async fn initiate_payment_link_flow(
             })?
     };
 
+    let session_expiry = payment_link.fulfilment_time.unwrap_or_else(|| {
+        payment_intent
+            .created_at
+            .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
+    });
+
+    render_payment_page(
+        &state,
+        merchant_account,
+        payment_intent,
+        payment_link_config,
+        return_url,
+        session_expiry,
+        payment_link.created_at,
+    )
+    .await
+}
+
+/// Render the hosted checkout page for the client secret of the payment, so that merchants
+/// without a frontend can redirect their customers to it. Unlike the payment link page, the
+/// payment need not have a payment link, and the page is configured by the business profile.
+pub async fn initiate_hosted_checkout_flow(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    client_secret: String,
+) -> RouterResponse<services::PaymentLinkFormData> {
+    let db = &*state.store;
+    let payment_intent = helpers::verify_payment_intent_time_and_client_secret(
+        db,
+        &merchant_account,
+        Some(client_secret),
+    )
+    .await?
+    .get_required_value("payment_intent")
+    .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let profile_id = payment_intent
+        .profile_id
+        .clone()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Profile id missing in payment intent")?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+
+    let merchant_name = merchant_account
+        .merchant_name
+        .clone()
+        .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+        .unwrap_or_default();
+
+    let (payment_link_config, _) = get_payment_link_config_based_on_priority(
+        None,
+        business_profile.payment_link_config,
+        merchant_name,
+        state.conf.server.base_url.clone(),
+    )?;
+
+    let return_url = payment_intent
+        .return_url
+        .clone()
+        .or(business_profile.return_url)
+        .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "return_url",
+        })?;
+
+    let session_expiry = payment_intent.session_expiry.unwrap_or_else(|| {
+        payment_intent
+            .created_at
+            .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
+    });
+    let created_at = payment_intent.created_at;
+
+    render_payment_page(
+        &state,
+        merchant_account,
+        payment_intent,
+        payment_link_config,
+        return_url,
+        session_expiry,
+        created_at,
+    )
+    .await
+}
+
+/// Render the payment page of the payment, or its status page if the payment can no longer be
+/// made on the payment page
+async fn render_payment_page(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_intent: storage::PaymentIntent,
+    payment_link_config: admin_types::PaymentLinkConfig,
+    return_url: String,
+    session_expiry: PrimitiveDateTime,
+    created_at: PrimitiveDateTime,
+) -> RouterResponse<services::PaymentLinkFormData> {
+    let db = &*state.store;
     let (pub_key, currency, client_secret) = validate_sdk_requirements(
         merchant_account.publishable_key,
         payment_intent.currency,
@@ -124,16 +379,10 @@ pub async fn initiate_payment_link_flow(
         .change_context(errors::ApiErrorResponse::CurrencyConversionFailed)?;
     let order_details = validate_order_details(payment_intent.order_details.clone(), currency)?;
 
-    let session_expiry = payment_link.fulfilment_time.unwrap_or_else(|| {
-        payment_intent
-            .created_at
-            .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
-    });
-
     // converting first letter of merchant name to upperCase
     let merchant_name = capitalize_first_char(&payment_link_config.seller_name);
     let css_script = get_color_scheme_css(payment_link_config.clone());
-    let payment_link_status = check_payment_link_status(session_expiry);
+    let payment_link_status = check_payment_link_status(session_expiry, payment_intent.status);
 
     let is_terminal_state = check_payment_link_invalid_conditions(
         &payment_intent.status,
@@ -150,7 +399,8 @@ pub async fn initiate_payment_link_flow(
     if is_terminal_state || payment_link_status == api_models::payments::PaymentLinkStatus::Expired
     {
         let status = match payment_link_status {
-            api_models::payments::PaymentLinkStatus::Active => {
+            api_models::payments::PaymentLinkStatus::Active
+            | api_models::payments::PaymentLinkStatus::Paid => {
                 PaymentLinkStatusWrap::IntentStatus(payment_intent.status)
             }
             api_models::payments::PaymentLinkStatus::Expired => {
@@ -168,7 +418,7 @@ pub async fn initiate_payment_link_flow(
         let payment_attempt = db
             .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
                 &payment_intent.payment_id,
-                &merchant_id,
+                &payment_intent.merchant_id,
                 &attempt_id.clone(),
                 merchant_account.storage_scheme,
             )
@@ -180,7 +430,7 @@ pub async fn initiate_payment_link_flow(
             payment_id: payment_intent.payment_id,
             merchant_name,
             merchant_logo: payment_link_config.logo.clone(),
-            created: payment_link.created_at,
+            created: created_at,
             status,
             error_code: payment_attempt.error_code,
             error_message: payment_attempt.error_message,
@@ -298,18 +548,41 @@ pub async fn list_payment_link(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to retrieve payment link")?;
     let payment_link_list = future::try_join_all(payment_link.into_iter().map(|payment_link| {
-        api_models::payments::RetrievePaymentLinkResponse::from_db_payment_link(payment_link)
+        let merchant = &merchant;
+        async move {
+            let payment_intent = db
+                .find_payment_intent_by_payment_id_merchant_id(
+                    &payment_link.payment_id,
+                    &merchant.merchant_id,
+                    merchant.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+            api_models::payments::RetrievePaymentLinkResponse::from_db_payment_link(
+                payment_link,
+                payment_intent.status,
+            )
+            .await
+        }
     }))
     .await?;
     Ok(services::ApplicationResponse::Json(payment_link_list))
 }
 
+/// The payment link is paid once its payment has succeeded, and it expires if the payment has not
+/// succeeded by the expiry of the payment link.
 pub fn check_payment_link_status(
     payment_link_expiry: PrimitiveDateTime,
+    intent_status: storage_enums::IntentStatus,
 ) -> api_models::payments::PaymentLinkStatus {
     let curr_time = common_utils::date_time::now();
 
-    if curr_time > payment_link_expiry {
+    if matches!(
+        intent_status,
+        storage_enums::IntentStatus::Succeeded | storage_enums::IntentStatus::PartiallyCaptured
+    ) {
+        api_models::payments::PaymentLinkStatus::Paid
+    } else if curr_time > payment_link_expiry {
         api_models::payments::PaymentLinkStatus::Expired
     } else {
         api_models::payments::PaymentLinkStatus::Active
@@ -1,4 +1,5 @@
 pub mod cards;
+pub mod network_tokenization;
 pub mod surcharge_decision_configs;
 pub mod transformers;
 pub mod vault;
@@ -73,6 +74,7 @@ impl PaymentMethodRetrieve for Oss {
             pm @ Some(api::PaymentMethodData::Reward) => Ok((pm.to_owned(), None)),
             pm @ Some(api::PaymentMethodData::CardRedirect(_)) => Ok((pm.to_owned(), None)),
             pm @ Some(api::PaymentMethodData::GiftCard(_)) => Ok((pm.to_owned(), None)),
+            pm @ Some(api::PaymentMethodData::RealTimePayment(_)) => Ok((pm.to_owned(), None)),
             pm_opt @ Some(pm @ api::PaymentMethodData::BankTransfer(_)) => {
                 let payment_token = helpers::store_payment_method_data_in_vault(
                     state,
@@ -8,10 +8,11 @@ use api_models::{
     enums::{self as api_enums},
     payment_methods::{
         BankAccountTokenData, Card, CardDetailUpdate, CardDetailsPaymentMethod, CardNetworkTypes,
-        CountryCodeWithName, CustomerDefaultPaymentMethodResponse, ListCountriesCurrenciesRequest,
+        CountryCodeWithName, CustomerDefaultPaymentMethodResponse, EligibilityContext,
+        EligibilityCustomerType, InstallmentOption, ListCountriesCurrenciesRequest,
         ListCountriesCurrenciesResponse, MaskedBankDetails, PaymentExperienceTypes,
-        PaymentMethodsData, RequestPaymentMethodTypes, RequiredFieldInfo,
-        ResponsePaymentMethodIntermediate, ResponsePaymentMethodTypes,
+        PaymentMethodEligibilityRules, PaymentMethodsData, RequestPaymentMethodTypes,
+        RequiredFieldInfo, ResponsePaymentMethodIntermediate, ResponsePaymentMethodTypes,
         ResponsePaymentMethodsEnabled,
     },
     payments::BankCodeResponse,
@@ -30,7 +31,7 @@ use diesel_models::{
 };
 use domain::CustomerUpdate;
 use error_stack::{report, ResultExt};
-use masking::Secret;
+use masking::{Secret, StrongEq};
 use router_env::{instrument, tracing};
 use strum::IntoEnumIterator;
 
@@ -49,7 +50,7 @@ use crate::{
             helpers,
             routing::{self, SessionFlowRoutingInput},
         },
-        utils as core_utils,
+        usage, utils as core_utils,
     },
     db, logger,
     pii::prelude::*,
@@ -301,7 +302,7 @@ pub fn authenticate_pm_client_secret_and_check_expiry(
         })
         .attach_printable("client secret not found in db")?;
 
-    if req_client_secret != &stored_client_secret {
+    if !req_client_secret.strong_eq(&stored_client_secret) {
         Err((errors::ApiErrorResponse::ClientSecretInvalid).into())
     } else {
         let current_timestamp = common_utils::date_time::now();
@@ -1042,6 +1043,11 @@ pub async fn add_bank_to_locker(
         api_enums::LockerChoice::HyperswitchCardVault,
     )
     .await?;
+    record_vault_token_usage(
+        state,
+        &merchant_account.merchant_id,
+        store_resp.duplication_check.as_ref(),
+    );
     let payment_method_resp = payment_methods::mk_add_bank_response_hs(
         bank.clone(),
         store_resp.card_reference,
@@ -1051,6 +1057,24 @@ pub async fn add_bank_to_locker(
     Ok((payment_method_resp, store_resp.duplication_check))
 }
 
+/// Meter the token stored in the vault, unless the locker already held the same data
+fn record_vault_token_usage(
+    state: &routes::AppState,
+    merchant_id: &str,
+    duplication_check: Option<&payment_methods::DataDuplicationCheck>,
+) {
+    if !matches!(
+        duplication_check,
+        Some(payment_methods::DataDuplicationCheck::Duplicated)
+    ) {
+        usage::record_usage(
+            state,
+            merchant_id,
+            common_enums::UsageMetric::VaultTokenStored,
+        );
+    }
+}
+
 /// The response will be the tuple of PaymentMethodResponse and the duplication check of payment_method
 pub async fn add_card_to_locker(
     state: &routes::AppState,
@@ -1096,6 +1120,12 @@ pub async fn add_card_to_locker(
     )
     .await?;
 
+    record_vault_token_usage(
+        state,
+        &merchant_account.merchant_id,
+        add_card_to_hs_resp.1.as_ref(),
+    );
+
     logger::debug!("card added to hyperswitch-card-vault");
     Ok(add_card_to_hs_resp)
 }
@@ -1875,6 +1905,34 @@ pub async fn list_payment_methods(
         .await?;
     }
 
+    // Filter out the payment method types the order does not qualify for, as per the rules
+    // configured by the merchant
+    if let Some(payment_intent) = payment_intent.as_ref() {
+        if let Some(eligibility_rules) =
+            get_payment_method_eligibility_rules(db, &merchant_account.merchant_id).await
+        {
+            let eligibility_context = EligibilityContext {
+                amount: payment_intent.amount,
+                currency: payment_intent.currency,
+                country: billing_address
+                    .as_ref()
+                    .and_then(|address| address.country)
+                    .or(shipping_address
+                        .as_ref()
+                        .and_then(|address| address.country)),
+                customer_type: if payment_intent.customer_id.is_some() {
+                    EligibilityCustomerType::Registered
+                } else {
+                    EligibilityCustomerType::Guest
+                },
+            };
+            response.retain(|intermediate| {
+                eligibility_rules
+                    .is_eligible(intermediate.payment_method_type, &eligibility_context)
+            });
+        }
+    }
+
     // Filter out wallet payment method from mca if customer has already saved it
     customer
         .as_ref()
@@ -2139,6 +2197,11 @@ pub async fn list_payment_methods(
         billing_address.as_ref(),
         customer.as_ref(),
     ));
+    let installment_options_hm = get_installment_options(
+        &response,
+        payment_intent.as_ref().map(|pi| pi.amount).or(req.amount),
+        req.card_bin.as_deref(),
+    );
     let req_val = serde_json::to_value(req).ok();
     logger::debug!(filtered_payment_methods=?response);
 
@@ -2364,6 +2427,7 @@ pub async fn list_payment_methods(
                 pm_auth_connector: pmt_to_auth_connector
                     .get(payment_method_types_hm.0)
                     .cloned(),
+                installment_options: None,
             })
         }
 
@@ -2401,6 +2465,9 @@ pub async fn list_payment_methods(
                 pm_auth_connector: pmt_to_auth_connector
                     .get(payment_method_types_hm.0)
                     .cloned(),
+                installment_options: installment_options_hm
+                    .get(payment_method_types_hm.0)
+                    .cloned(),
             })
         }
 
@@ -2431,6 +2498,7 @@ pub async fn list_payment_methods(
                     .cloned(),
                 surcharge_details: None,
                 pm_auth_connector: pmt_to_auth_connector.get(&payment_method_type).cloned(),
+                installment_options: None,
             }
         })
     }
@@ -2464,6 +2532,7 @@ pub async fn list_payment_methods(
                     .cloned(),
                 surcharge_details: None,
                 pm_auth_connector: pmt_to_auth_connector.get(&payment_method_type).cloned(),
+                installment_options: None,
             }
         })
     }
@@ -2497,6 +2566,7 @@ pub async fn list_payment_methods(
                     .cloned(),
                 surcharge_details: None,
                 pm_auth_connector: pmt_to_auth_connector.get(&payment_method_type).cloned(),
+                installment_options: None,
             }
         })
     }
@@ -3145,6 +3215,45 @@ fn filter_disabled_enum_based<T: Eq + std::hash::Hash + Clone>(
     }
 }
 
+/// The installment plans the payment can be paid in, by the card payment method types of the
+/// connectors offering them. The plans are listed only when the amount of the payment is known,
+/// sorted by their tenure.
+fn get_installment_options(
+    response: &[ResponsePaymentMethodIntermediate],
+    amount: Option<i64>,
+    card_bin: Option<&str>,
+) -> HashMap<api_enums::PaymentMethodType, Vec<InstallmentOption>> {
+    let mut installment_options_hm =
+        HashMap::<api_enums::PaymentMethodType, Vec<InstallmentOption>>::new();
+    let Some(amount) = amount else {
+        return installment_options_hm;
+    };
+
+    for element in response
+        .iter()
+        .filter(|element| element.payment_method == api_enums::PaymentMethod::Card)
+    {
+        for installment_plan in element
+            .installment_plans
+            .iter()
+            .flatten()
+            .filter(|installment_plan| installment_plan.is_offered_for(amount, card_bin))
+        {
+            installment_options_hm
+                .entry(element.payment_method_type)
+                .or_default()
+                .push(installment_plan.get_installment_option(element.connector.clone(), amount));
+        }
+    }
+
+    for installment_options in installment_options_hm.values_mut() {
+        installment_options.sort_by(|a, b| (a.tenure, &a.connector).cmp(&(b.tenure, &b.connector)));
+        installment_options.dedup();
+    }
+
+    installment_options_hm
+}
+
 fn filter_amount_based(payment_method: &RequestPaymentMethodTypes, amount: Option<i64>) -> bool {
     let min_check = amount
         .and_then(|amt| {
@@ -3194,6 +3303,32 @@ fn filter_installment_based(
     })
 }
 
+/// Rules of the merchant for the payment method types to be listed, which are configured with the
+/// `pm_eligibility_rules_{merchant_id}` config
+async fn get_payment_method_eligibility_rules(
+    db: &dyn db::StorageInterface,
+    merchant_id: &str,
+) -> Option<PaymentMethodEligibilityRules> {
+    let key = format!("pm_eligibility_rules_{merchant_id}");
+    let config = match db.find_config_by_key(&key).await {
+        Ok(config) => config,
+        Err(error) => {
+            if !error.current_context().is_db_not_found() {
+                logger::error!(pm_eligibility_rules_error=?error);
+            }
+            return None;
+        }
+    };
+
+    config
+        .config
+        .parse_struct::<PaymentMethodEligibilityRules>("PaymentMethodEligibilityRules")
+        .map_err(|error| {
+            logger::error!(pm_eligibility_rules_error=?error, "Invalid payment method eligibility rules");
+        })
+        .ok()
+}
+
 async fn filter_payment_country_based(
     pm: &RequestPaymentMethodTypes,
     address: Option<&domain::Address>,
@@ -3300,6 +3435,21 @@ pub async fn do_list_customer_pm_fetch_customer_if_not_passed(
     }
 }
 
+/// Whether the business profile requires the CVC of the saved cards to be collected with their
+/// payment token for the customer initiated payments
+pub async fn get_requires_cvc_for_saved_cards(
+    db: &dyn db::StorageInterface,
+    profile_id: &str,
+) -> errors::RouterResult<Option<bool>> {
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+    Ok(business_profile.requires_cvc_for_saved_cards)
+}
+
 pub async fn list_customer_payment_method(
     state: &routes::AppState,
     merchant_account: domain::MerchantAccount,
@@ -3340,7 +3490,17 @@ pub async fn list_customer_payment_method(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to fetch requires_cvv config")?;
 
-    let requires_cvv = is_requires_cvv.config != "false";
+    // The setting of the business profile of the payment takes precedence over the config of the
+    // merchant
+    let requires_cvc_for_saved_cards = payment_intent
+        .as_ref()
+        .and_then(|payment_intent| payment_intent.profile_id.as_deref())
+        .async_map(|profile_id| get_requires_cvc_for_saved_cards(db, profile_id))
+        .await
+        .transpose()?
+        .flatten();
+    let requires_cvv = requires_cvc_for_saved_cards.unwrap_or(is_requires_cvv.config != "false");
+    let requires_cvc = requires_cvc_for_saved_cards.unwrap_or(false);
 
     let resp = db
         .find_payment_method_by_customer_id_merchant_id_status(
@@ -3474,6 +3634,9 @@ pub async fn list_customer_payment_method(
             surcharge_details: None,
             requires_cvv: requires_cvv
                 && !(off_session_payment_flag && pm.connector_mandate_details.is_some()),
+            requires_cvc: requires_cvc
+                && payment_method == enums::PaymentMethod::Card
+                && !(off_session_payment_flag && pm.connector_mandate_details.is_some()),
             last_used_at: Some(pm.last_used_at),
             default_payment_method_set: customer.default_payment_method_id.is_some()
                 && customer.default_payment_method_id == Some(pm.payment_method_id),
@@ -3717,6 +3880,7 @@ async fn get_masked_bank_details(
             PaymentMethodsData::Card(_) => Ok(None),
             PaymentMethodsData::BankDetails(bank_details) => Ok(Some(MaskedBankDetails {
                 mask: bank_details.mask,
+                verification_status: bank_details.verification_status,
             })),
         },
         None => Err(report!(errors::ApiErrorResponse::InternalServerError))
@@ -3762,15 +3926,16 @@ async fn get_bank_account_connector_details(
                     .get_required_value("payment_method_type")
                     .attach_printable("PaymentMethodType not found")?;
 
-                let pm = pm
+                let payment_method = pm
                     .payment_method
                     .get_required_value("payment_method")
                     .attach_printable("PaymentMethod not found")?;
 
                 let token_data = BankAccountTokenData {
                     payment_method_type: pm_type,
-                    payment_method: pm,
+                    payment_method,
                     connector_details: connector_details.clone(),
+                    payment_method_id: Some(pm.payment_method_id.clone()),
                 };
 
                 Ok(Some(token_data))
@@ -4128,6 +4293,8 @@ pub async fn delete_payment_method(
             logger::error!("Error: Deleting Card From Locker!\n{:#?}", response);
             Err(errors::ApiErrorResponse::InternalServerError)?
         }
+
+        super::network_tokenization::delete_network_token_if_present(&state, &key).await;
     }
 
     db.delete_payment_method_by_merchant_id_payment_method_id(
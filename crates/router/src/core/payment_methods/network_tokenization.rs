@@ -0,0 +1,376 @@
+use api_models::enums as api_enums;
+use common_utils::{ext_traits::BytesExt, request::RequestContent};
+use error_stack::ResultExt;
+use masking::{Mask, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+    },
+    headers, services,
+    types::{self, api, domain, storage},
+    AppState,
+};
+
+/// Identifier of the token service provider in the errors of its API
+const TOKEN_SERVICE_PROVIDER: &str = "network_tokenization_service";
+
+#[derive(Debug, Serialize)]
+struct ProvisionTokenRequest {
+    merchant_id: String,
+    customer_id: String,
+    card_network: api_enums::CardNetwork,
+    card: TokenServiceCard,
+}
+
+#[derive(Debug, Serialize)]
+struct TokenServiceCard {
+    number: ::cards::CardNumber,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    holder_name: Option<Secret<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProvisionTokenResponse {
+    /// Reference of the network token with the token service provider, which the cryptograms of
+    /// the payments made with the token are requested for
+    token_requestor_reference_id: String,
+    token: NetworkToken,
+}
+
+#[derive(Debug, Deserialize)]
+struct NetworkToken {
+    number: ::cards::CardNumber,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct CryptogramRequest {
+    amount: i64,
+    currency: api_enums::Currency,
+}
+
+#[derive(Debug, Deserialize)]
+struct CryptogramResponse {
+    cryptogram: Secret<String>,
+    eci: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeleteTokenResponse {
+    status: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenServiceErrorResponse {
+    error: TokenServiceError,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenServiceError {
+    code: String,
+    message: Option<String>,
+}
+
+/// Provision a network token for a card saved by a customer of a business profile which has
+/// network tokenization enabled, and store it in the locker alongside the card. Failures are only
+/// logged, as the saved card can still be used without a network token.
+#[instrument(skip_all)]
+pub async fn provision_network_token_if_required(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    profile_id: Option<&str>,
+    payment_method_create_request: &api::PaymentMethodCreate,
+    payment_method_id: &str,
+) {
+    if let Err(error) = provision_network_token(
+        state,
+        merchant_account,
+        profile_id,
+        payment_method_create_request,
+        payment_method_id,
+    )
+    .await
+    {
+        logger::error!(
+            ?error,
+            %payment_method_id,
+            "Failed to provision a network token for the saved card"
+        );
+    }
+}
+
+async fn provision_network_token(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    profile_id: Option<&str>,
+    payment_method_create_request: &api::PaymentMethodCreate,
+    payment_method_id: &str,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let (Some(card), Some(profile_id)) = (payment_method_create_request.card.as_ref(), profile_id)
+    else {
+        return Ok(());
+    };
+    let Some(card_network) = card.card_network.clone().filter(|card_network| {
+        state
+            .conf
+            .network_tokenization_supported_card_networks
+            .card_networks
+            .contains(card_network)
+    }) else {
+        return Ok(());
+    };
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+    if !business_profile.is_network_tokenization_enabled {
+        return Ok(());
+    }
+
+    let payment_method = db
+        .find_payment_method(payment_method_id, merchant_account.storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    // The card may have been saved again, in which case its network token is reused
+    if payment_method
+        .network_token_requestor_reference_id
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    let request = ProvisionTokenRequest {
+        merchant_id: merchant_account.merchant_id.clone(),
+        customer_id: payment_method.customer_id.clone(),
+        card_network: card_network.clone(),
+        card: TokenServiceCard {
+            number: card.card_number.clone(),
+            expiry_month: card.card_exp_month.clone(),
+            expiry_year: card.card_exp_year.clone(),
+            holder_name: card.card_holder_name.clone(),
+        },
+    };
+    let response: ProvisionTokenResponse = call_token_service_api(
+        state,
+        services::Method::Post,
+        "tokens",
+        Some(RequestContent::Json(Box::new(request))),
+        "provision_network_token",
+    )
+    .await?;
+
+    let token_card = api::CardDetail {
+        card_number: response.token.number,
+        card_exp_month: response.token.expiry_month,
+        card_exp_year: response.token.expiry_year,
+        card_holder_name: card.card_holder_name.clone(),
+        nick_name: None,
+        card_issuing_country: card.card_issuing_country.clone(),
+        card_network: Some(card_network),
+        card_issuer: card.card_issuer.clone(),
+        card_type: card.card_type.clone(),
+    };
+    let (locker_response, _) = cards::add_card_to_locker(
+        state,
+        api::PaymentMethodCreate {
+            card: Some(token_card.clone()),
+            ..payment_method_create_request.clone()
+        },
+        &token_card,
+        &payment_method.customer_id,
+        merchant_account,
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the network token in the locker")?;
+
+    db.update_payment_method(
+        payment_method,
+        storage::PaymentMethodUpdate::NetworkTokenDataUpdate {
+            network_token_requestor_reference_id: Some(response.token_requestor_reference_id),
+            network_token_locker_id: Some(locker_response.payment_method_id),
+        },
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the network token reference of the payment method")?;
+
+    logger::info!(%payment_method_id, "Provisioned a network token for the saved card");
+
+    Ok(())
+}
+
+/// Network token of a saved card retrieved from the locker, along with a cryptogram generated by
+/// the token service provider for the payment, if a network token has been provisioned for the card
+#[instrument(skip_all)]
+pub async fn get_network_token_data(
+    state: &AppState,
+    payment_method: &storage::PaymentMethod,
+    card_network: Option<api_enums::CardNetwork>,
+    amount: i64,
+    currency: api_enums::Currency,
+) -> RouterResult<Option<types::NetworkTokenData>> {
+    let (Some(token_requestor_reference_id), Some(network_token_locker_id)) = (
+        payment_method.network_token_requestor_reference_id.as_ref(),
+        payment_method.network_token_locker_id.as_ref(),
+    ) else {
+        return Ok(None);
+    };
+
+    let token_card = cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        network_token_locker_id,
+    )
+    .await
+    .attach_printable("Failed to retrieve the network token from the locker")?;
+
+    let response: CryptogramResponse = call_token_service_api(
+        state,
+        services::Method::Post,
+        &format!("tokens/{token_requestor_reference_id}/cryptograms"),
+        Some(RequestContent::Json(Box::new(CryptogramRequest {
+            amount,
+            currency,
+        }))),
+        "fetch_network_token_cryptogram",
+    )
+    .await?;
+
+    let token_exp_year = token_card.card_exp_year.peek();
+    Ok(Some(types::NetworkTokenData {
+        token_number: token_card.card_number,
+        token_exp_month: Secret::new(format!("{:0>2}", token_card.card_exp_month.peek())),
+        token_exp_year: Secret::new(if token_exp_year.len() == 2 {
+            format!("20{token_exp_year}")
+        } else {
+            token_exp_year.to_owned()
+        }),
+        cryptogram: response.cryptogram,
+        eci: response.eci,
+        card_network,
+    }))
+}
+
+/// Delete the network token of a saved card which is being deleted, from the token service
+/// provider and from the locker. Failures are only logged, so that the card can still be deleted.
+#[instrument(skip_all)]
+pub async fn delete_network_token_if_present(
+    state: &AppState,
+    payment_method: &storage::PaymentMethod,
+) {
+    let Some(token_requestor_reference_id) =
+        payment_method.network_token_requestor_reference_id.as_ref()
+    else {
+        return;
+    };
+
+    let result: RouterResult<DeleteTokenResponse> = call_token_service_api(
+        state,
+        services::Method::Delete,
+        &format!("tokens/{token_requestor_reference_id}"),
+        None,
+        "delete_network_token",
+    )
+    .await;
+    match result {
+        Ok(response) => logger::info!(
+            status = %response.status,
+            "Deleted the network token of the payment method"
+        ),
+        Err(error) => logger::error!(
+            ?error,
+            "Failed to delete the network token with the token service provider"
+        ),
+    }
+
+    if let Some(network_token_locker_id) = payment_method.network_token_locker_id.as_ref() {
+        if let Err(error) = cards::delete_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            network_token_locker_id,
+        )
+        .await
+        {
+            logger::error!(?error, "Failed to delete the network token from the locker");
+        }
+    }
+}
+
+async fn call_token_service_api<T: DeserializeOwned>(
+    state: &AppState,
+    method: services::Method,
+    path: &str,
+    request_body: Option<RequestContent>,
+    flow: &str,
+) -> RouterResult<T> {
+    let token_service = state.conf.network_tokenization_service.get_inner();
+
+    let mut request = services::RequestBuilder::new()
+        .method(method)
+        .url(&format!("{}{path}", token_service.base_url))
+        .attach_default_headers()
+        .headers(vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                "application/json".to_string().into(),
+            ),
+            (
+                headers::AUTHORIZATION.to_string(),
+                format!("Bearer {}", token_service.api_key.peek()).into_masked(),
+            ),
+        ]);
+    if let Some(request_body) = request_body {
+        request = request.set_body(request_body);
+    }
+
+    let response = services::call_connector_api(state, request.build(), flow)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the API of the token service provider")?;
+
+    match response {
+        Ok(response) => response
+            .response
+            .parse_struct(std::any::type_name::<T>())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the response of the token service provider"),
+        Err(error_response) => {
+            let error = error_response
+                .response
+                .parse_struct::<TokenServiceErrorResponse>("TokenServiceErrorResponse")
+                .ok()
+                .map(|response| response.error);
+
+            Err(errors::ApiErrorResponse::ExternalConnectorError {
+                code: error
+                    .as_ref()
+                    .map(|error| error.code.clone())
+                    .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                message: error
+                    .as_ref()
+                    .and_then(|error| error.message.clone())
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                connector: TOKEN_SERVICE_PROVIDER.to_string(),
+                status_code: error_response.status_code,
+                reason: None,
+            }
+            .into())
+        }
+    }
+}
@@ -276,6 +276,61 @@ where
     }
     Ok(surcharge_metadata)
 }
+
+/// Generate the surcharge of the payment method a payment is confirmed with through the surcharge
+/// rules of the merchant, for when it has not been calculated while listing the payment methods
+/// or has expired since.
+pub async fn perform_surcharge_decision_management_for_payment_method<O>(
+    state: &AppState,
+    algorithm_ref: routing::RoutingAlgorithmRef,
+    payment_data: &PaymentData<O>,
+    (payment_method, payment_method_type, card_network): (
+        common_enums::PaymentMethod,
+        common_enums::PaymentMethodType,
+        Option<common_enums::CardNetwork>,
+    ),
+) -> ConditionalConfigResult<Option<types::SurchargeDetails>>
+where
+    O: Send + Clone,
+{
+    let Some(algorithm_id) = algorithm_ref.surcharge_config_algo_id else {
+        return Ok(None);
+    };
+    let key = ensure_algorithm_cached(
+        &*state.store,
+        &payment_data.payment_attempt.merchant_id,
+        algorithm_ref.timestamp,
+        algorithm_id.as_str(),
+    )
+    .await?;
+    let cached_algo = CONF_CACHE
+        .retrieve(&key)
+        .change_context(ConfigError::CacheMiss)
+        .attach_printable("Unable to retrieve cached routing algorithm even after refresh")?;
+
+    let mut backend_input = make_dsl_input_for_surcharge(
+        &payment_data.payment_attempt,
+        &payment_data.payment_intent,
+        payment_data.address.get_payment_method_billing().cloned(),
+    )
+    .change_context(ConfigError::InputConstructionError)?;
+    backend_input.payment_method.payment_method = Some(payment_method);
+    backend_input.payment_method.payment_method_type = Some(payment_method_type);
+    backend_input.payment_method.card_network = card_network;
+
+    let surcharge_output =
+        execute_dsl_and_get_conditional_config(backend_input, &cached_algo.cached_alogorith)?;
+    surcharge_output
+        .surcharge_details
+        .map(|surcharge_details| {
+            get_surcharge_details_from_surcharge_output(
+                surcharge_details,
+                &payment_data.payment_attempt,
+            )
+        })
+        .transpose()
+}
+
 pub async fn perform_surcharge_decision_management_for_saved_cards(
     state: &AppState,
     algorithm_ref: routing::RoutingAlgorithmRef,
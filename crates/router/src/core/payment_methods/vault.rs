@@ -446,6 +446,12 @@ impl Vaultable for api::WalletPayout {
                 wallet_id: None,
                 wallet_type: PaymentMethodType::Venmo,
             },
+            Self::Payoneer(payoneer_data) => TokenizedWalletSensitiveValues {
+                email: None,
+                telephone_number: None,
+                wallet_id: Some(payoneer_data.payee_id.clone()),
+                wallet_type: PaymentMethodType::Payoneer,
+            },
         };
 
         value1
@@ -486,6 +492,13 @@ impl Vaultable for api::WalletPayout {
             PaymentMethodType::Venmo => Self::Venmo(api_models::payouts::Venmo {
                 telephone_number: value1.telephone_number,
             }),
+            PaymentMethodType::Payoneer => Self::Payoneer(api_models::payouts::Payoneer {
+                payee_id: value1
+                    .wallet_id
+                    .ok_or(errors::VaultError::MissingRequiredField {
+                        field_name: "payee_id",
+                    })?,
+            }),
             _ => Err(errors::VaultError::PayoutMethodNotSupported)?,
         };
         let supp_data = SupplementaryVaultData {
@@ -1,15 +1,25 @@
+pub mod abandoned_payment;
 pub mod access_token;
+pub mod auto_void;
 pub mod conditional_configs;
 pub mod customers;
+pub mod dunning;
 pub mod flows;
 pub mod helpers;
+pub mod intent_expiry;
 pub mod operations;
+pub mod payment_tasks;
 #[cfg(feature = "retry")]
 pub mod retry;
 pub mod routing;
+#[cfg(feature = "olap")]
+pub mod saved_filters;
+#[cfg(feature = "olap")]
+pub mod suggestions;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
+pub mod wallets;
 
 #[cfg(feature = "olap")]
 use std::collections::{HashMap, HashSet};
@@ -31,7 +41,6 @@ use diesel_models::{ephemeral_key, fraud_check::FraudCheck};
 use error_stack::{report, ResultExt};
 use events::EventInfo;
 use futures::future::join_all;
-use helpers::ApplePayData;
 use hyperswitch_domain_models::mandates::{CustomerAcceptance, MandateData};
 use masking::{ExposeInterface, Secret};
 pub use payment_address::PaymentAddress;
@@ -47,7 +56,7 @@ use time;
 pub use self::operations::{
     PaymentApprove, PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate,
     PaymentIncrementalAuthorization, PaymentReject, PaymentResponse, PaymentSession, PaymentStatus,
-    PaymentUpdate,
+    PaymentUpdate, PaymentVerifyMicroDeposits,
 };
 use self::{
     conditional_configs::perform_decision_management,
@@ -57,7 +66,9 @@ use self::{
     routing::{self as self_routing, SessionFlowRoutingInput},
 };
 use super::{
-    errors::StorageErrorExt, payment_methods::surcharge_decision_configs, routing::TransactionData,
+    errors::StorageErrorExt,
+    payment_methods::{network_tokenization, surcharge_decision_configs},
+    routing::TransactionData,
 };
 #[cfg(feature = "frm")]
 use crate::core::fraud_check as frm_core;
@@ -65,7 +76,7 @@ use crate::{
     configs::settings::{ApplePayPreDecryptFlow, PaymentMethodTypeTokenFilter},
     connector::utils::missing_field_err,
     core::{
-        authentication as authentication_core,
+        authentication as authentication_core, cards_info,
         errors::{self, CustomResult, RouterResponse, RouterResult},
         payment_methods::PaymentMethodRetrieve,
         utils,
@@ -168,6 +179,8 @@ where
         .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
         .attach_printable("Failed while fetching/creating customer")?;
 
+    cards_info::populate_card_bin_info(state, &mut payment_data).await?;
+
     call_decision_manager(state, &merchant_account, &mut payment_data).await?;
 
     let connector = get_connector_choice(
@@ -372,9 +385,10 @@ where
                     #[cfg(feature = "retry")]
                     {
                         use crate::core::payments::retry::{self, GsmValidation};
-                        let config_bool = retry::config_should_call_gsm(
+                        let config_bool = retry::is_auto_retry_enabled(
                             &*state.store,
                             &merchant_account.merchant_id,
+                            &business_profile,
                         )
                         .await;
 
@@ -388,6 +402,7 @@ where
                                 router_data,
                                 &merchant_account,
                                 &key_store,
+                                &business_profile,
                                 &operation,
                                 &customer,
                                 &validate_result,
@@ -537,6 +552,48 @@ where
         )
         .await?;
 
+    dunning::schedule_retry_if_required(
+        state,
+        &merchant_account,
+        &key_store,
+        &business_profile,
+        &operation,
+        &payment_data,
+    )
+    .await
+    .map_err(|error| logger::error!(payment_dunning_schedule_error=?error))
+    .ok();
+
+    auto_void::schedule_void_if_required(
+        state,
+        &merchant_account,
+        &business_profile,
+        &payment_data,
+    )
+    .await
+    .map_err(|error| logger::error!(auto_void_schedule_error=?error))
+    .ok();
+
+    intent_expiry::schedule_expiry_if_required(
+        state,
+        &merchant_account,
+        &business_profile,
+        &payment_data,
+    )
+    .await
+    .map_err(|error| logger::error!(payment_intent_expiry_schedule_error=?error))
+    .ok();
+
+    abandoned_payment::schedule_notification_if_required(
+        state,
+        &merchant_account,
+        &business_profile,
+        &payment_data,
+    )
+    .await
+    .map_err(|error| logger::error!(abandoned_payment_notification_schedule_error=?error))
+    .ok();
+
     crate::utils::trigger_payments_webhook(
         merchant_account,
         business_profile,
@@ -598,6 +655,7 @@ where
 async fn populate_surcharge_details<F>(
     state: &AppState,
     payment_data: &mut PaymentData<F>,
+    merchant_account: &domain::MerchantAccount,
 ) -> RouterResult<()>
 where
     F: Send + Clone,
@@ -614,17 +672,19 @@ where
             payment_data.surcharge_details = Some(surcharge_details);
             return Ok(());
         }
-        let raw_card_key = payment_data
+        let surcharge_key_params = payment_data
             .payment_method_data
             .as_ref()
-            .and_then(get_key_params_for_surcharge_details)
-            .map(|(payment_method, payment_method_type, card_network)| {
+            .and_then(get_key_params_for_surcharge_details);
+        let raw_card_key = surcharge_key_params.clone().map(
+            |(payment_method, payment_method_type, card_network)| {
                 types::SurchargeKey::PaymentMethodData(
                     payment_method,
                     payment_method_type,
                     card_network,
                 )
-            });
+            },
+        );
         let saved_card_key = payment_data.token.clone().map(types::SurchargeKey::Token);
 
         let surcharge_key = raw_card_key
@@ -646,7 +706,43 @@ where
                     Err(err).change_context(errors::ApiErrorResponse::InternalServerError)?
                 }
             };
+        // The surcharge may not have been calculated while listing the payment methods, or may
+        // have expired since, in which case it is generated through the surcharge rules instead
+        let calculated_surcharge_details = match (
+            calculated_surcharge_details,
+            surcharge_key_params,
+        ) {
+            (None, Some(surcharge_key_params)) => {
+                let algorithm_ref: api::routing::RoutingAlgorithmRef = merchant_account
+                    .routing_algorithm
+                    .clone()
+                    .map(|val| val.parse_value("routing algorithm"))
+                    .transpose()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Could not decode the routing algorithm")?
+                    .unwrap_or_default();
+                surcharge_decision_configs::perform_surcharge_decision_management_for_payment_method(
+                    state,
+                    algorithm_ref,
+                    payment_data,
+                    surcharge_key_params,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("error performing surcharge decision operation")?
+            }
+            (calculated_surcharge_details, _) => calculated_surcharge_details,
+        };
 
+        if let Some(surcharge_details) = calculated_surcharge_details.as_ref() {
+            logger::info!(
+                attempt_id = %payment_data.payment_attempt.attempt_id,
+                surcharge_amount = surcharge_details.surcharge_amount,
+                tax_on_surcharge_amount = surcharge_details.tax_on_surcharge_amount,
+                final_amount = surcharge_details.final_amount,
+                "Applying surcharge to the payment attempt"
+            );
+        }
         payment_data.surcharge_details = calculated_surcharge_details;
     } else {
         let surcharge_details =
@@ -1004,6 +1100,7 @@ impl<Ctx: PaymentMethodRetrieve> PaymentRedirectFlow<Ctx> for PaymentRedirectCom
                         api_models::payments::NextActionData::DisplayVoucherInformation{ .. } => None,
                         api_models::payments::NextActionData::WaitScreenInformation{..} => None,
                         api_models::payments::NextActionData::ThreeDsInvoke{..} => None,
+                        api_models::payments::NextActionData::VerifyWithMicroDeposits{..} => None,
                     })
                     .ok_or(errors::ApiErrorResponse::InternalServerError)
 
@@ -1435,6 +1532,9 @@ where
         .populate_payment_data(state, payment_data, merchant_account)
         .await?;
 
+    let wallet_certificates =
+        wallets::get_wallet_certificates_if_required(state, key_store, payment_data).await?;
+
     let (pd, tokenization_action) = get_connector_tokenization_action_when_confirm_true(
         state,
         operation,
@@ -1443,6 +1543,7 @@ where
         &merchant_connector_account,
         key_store,
         customer,
+        wallet_certificates.as_ref(),
     )
     .await?;
     *payment_data = pd;
@@ -1460,6 +1561,20 @@ where
     )
     .await?;
 
+    let google_pay_predecrypt_data = wallets::decrypt_google_pay_token_if_required(
+        state,
+        payment_data,
+        &merchant_connector_account,
+        wallet_certificates.as_ref(),
+    )?;
+    let samsung_pay_predecrypt_data = wallets::decrypt_samsung_pay_token_if_required(
+        payment_data,
+        &merchant_connector_account,
+        wallet_certificates.as_ref(),
+    )
+    .await?;
+    let network_token_data = get_network_token_if_required(state, payment_data, &connector).await;
+
     let mut router_data = payment_data
         .construct_router_data(
             state,
@@ -1481,6 +1596,26 @@ where
         &call_connector_action,
     );
 
+    if let Some(google_pay_predecrypt_data) = google_pay_predecrypt_data {
+        router_data.payment_method_token =
+            Some(router_types::PaymentMethodToken::GooglePayDecrypt(
+                Box::new(google_pay_predecrypt_data),
+            ));
+    }
+
+    if let Some(samsung_pay_predecrypt_data) = samsung_pay_predecrypt_data {
+        router_data.payment_method_token =
+            Some(router_types::PaymentMethodToken::SamsungPayDecrypt(
+                Box::new(samsung_pay_predecrypt_data),
+            ));
+    }
+
+    if let Some(network_token_data) = network_token_data {
+        router_data.payment_method_token = Some(router_types::PaymentMethodToken::NetworkToken(
+            Box::new(network_token_data),
+        ));
+    }
+
     // Tokenization Action will be DecryptApplePayToken, only when payment method type is Apple Pay
     // and the connector supports Apple Pay predecrypt
     if matches!(
@@ -1488,28 +1623,13 @@ where
         TokenizationAction::DecryptApplePayToken
             | TokenizationAction::TokenizeInConnectorAndApplepayPreDecrypt
     ) {
-        let apple_pay_data = match payment_data.payment_method_data.clone() {
-            Some(payment_data) => {
-                let domain_data = domain::PaymentMethodData::from(payment_data);
-                match domain_data {
-                    domain::PaymentMethodData::Wallet(domain::WalletData::ApplePay(
-                        wallet_data,
-                    )) => Some(
-                        ApplePayData::token_json(domain::WalletData::ApplePay(wallet_data))
-                            .change_context(errors::ApiErrorResponse::InternalServerError)?
-                            .decrypt(state)
-                            .await
-                            .change_context(errors::ApiErrorResponse::InternalServerError)?,
-                    ),
-                    _ => None,
-                }
-            }
-            _ => None,
-        };
-
-        let apple_pay_predecrypt = apple_pay_data
-            .parse_value::<router_types::ApplePayPredecryptData>("ApplePayPredecryptData")
-            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        let apple_pay_predecrypt = wallets::decrypt_apple_pay_token(
+            state,
+            payment_data,
+            &merchant_connector_account,
+            wallet_certificates.as_ref(),
+        )
+        .await?;
 
         logger::debug!(?apple_pay_predecrypt);
 
@@ -2084,66 +2204,44 @@ fn is_apple_pay_pre_decrypt_type_connector_tokenization(
     }
 }
 
-fn decide_apple_pay_flow(
-    payment_method_type: &Option<api_models::enums::PaymentMethodType>,
-    merchant_connector_account: Option<&helpers::MerchantConnectorAccountType>,
-) -> Option<enums::ApplePayFlow> {
-    payment_method_type.and_then(|pmt| match pmt {
-        api_models::enums::PaymentMethodType::ApplePay => {
-            check_apple_pay_metadata(merchant_connector_account)
-        }
-        _ => None,
-    })
-}
+/// Network token of the saved card the payment is made with, if one has been provisioned for the
+/// card and the connector supports network tokens
+///
+/// Mandate payments keep using the card they were set up with. Failures to retrieve the network
+/// token are only logged, the card is passed to the connector instead.
+async fn get_network_token_if_required<F: Clone>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+    connector: &api::ConnectorData,
+) -> Option<router_types::NetworkTokenData> {
+    if !state
+        .conf
+        .network_tokenization_supported_connectors
+        .connector_list
+        .contains(&connector.connector_name)
+        || payment_data.mandate_id.is_some()
+    {
+        return None;
+    }
 
-fn check_apple_pay_metadata(
-    merchant_connector_account: Option<&helpers::MerchantConnectorAccountType>,
-) -> Option<enums::ApplePayFlow> {
-    merchant_connector_account.and_then(|mca| {
-        let metadata = mca.get_metadata();
-        metadata.and_then(|apple_pay_metadata| {
-            let parsed_metadata = apple_pay_metadata
-                .clone()
-                .parse_value::<api_models::payments::ApplepayCombinedSessionTokenData>(
-                    "ApplepayCombinedSessionTokenData",
-                )
-                .map(|combined_metadata| {
-                    api_models::payments::ApplepaySessionTokenMetadata::ApplePayCombined(
-                        combined_metadata.apple_pay_combined,
-                    )
-                })
-                .or_else(|_| {
-                    apple_pay_metadata
-                        .parse_value::<api_models::payments::ApplepaySessionTokenData>(
-                            "ApplepaySessionTokenData",
-                        )
-                        .map(|old_metadata| {
-                            api_models::payments::ApplepaySessionTokenMetadata::ApplePay(
-                                old_metadata.apple_pay,
-                            )
-                        })
-                })
-                .map_err(
-                    |error| logger::warn!(%error, "Failed to Parse Value to ApplepaySessionTokenData"),
-                );
+    let payment_method = payment_data.payment_method_info.as_ref()?;
+    let card_network = match payment_data.payment_method_data.as_ref() {
+        Some(api::PaymentMethodData::Card(card)) => card.card_network.clone(),
+        _ => return None,
+    };
+    let currency = payment_data.payment_attempt.currency?;
 
-            parsed_metadata.ok().map(|metadata| match metadata {
-                api_models::payments::ApplepaySessionTokenMetadata::ApplePayCombined(
-                    apple_pay_combined,
-                ) => match apple_pay_combined {
-                    api_models::payments::ApplePayCombinedMetadata::Simplified { .. } => {
-                        enums::ApplePayFlow::Simplified
-                    }
-                    api_models::payments::ApplePayCombinedMetadata::Manual { .. } => {
-                        enums::ApplePayFlow::Manual
-                    }
-                },
-                api_models::payments::ApplepaySessionTokenMetadata::ApplePay(_) => {
-                    enums::ApplePayFlow::Manual
-                }
-            })
-        })
-    })
+    network_tokenization::get_network_token_data(
+        state,
+        payment_method,
+        card_network,
+        payment_data.payment_attempt.amount,
+        currency,
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to retrieve the network token of the card"))
+    .ok()
+    .flatten()
 }
 
 fn is_payment_method_type_allowed_for_connector(
@@ -2166,11 +2264,8 @@ async fn decide_payment_method_tokenize_action(
     payment_method: &storage::enums::PaymentMethod,
     pm_parent_token: Option<&String>,
     is_connector_tokenization_enabled: bool,
-    apple_pay_flow: Option<enums::ApplePayFlow>,
+    is_apple_pay_predecrypt_supported: bool,
 ) -> RouterResult<TokenizationAction> {
-    let is_apple_pay_predecrypt_supported =
-        matches!(apple_pay_flow, Some(enums::ApplePayFlow::Simplified));
-
     match pm_parent_token {
         None => {
             if is_connector_tokenization_enabled && is_apple_pay_predecrypt_supported {
@@ -2241,6 +2336,7 @@ pub async fn get_connector_tokenization_action_when_confirm_true<F, Req, Ctx>(
     merchant_connector_account: &helpers::MerchantConnectorAccountType,
     merchant_key_store: &domain::MerchantKeyStore,
     customer: &Option<domain::Customer>,
+    wallet_certificates: Option<&api_models::admin::WalletCertificates>,
 ) -> RouterResult<(PaymentData<F>, TokenizationAction)>
 where
     F: Send + Clone,
@@ -2270,8 +2366,10 @@ where
                 .get_required_value("payment_method")?;
             let payment_method_type = &payment_data.payment_attempt.payment_method_type;
 
-            let apple_pay_flow =
-                decide_apple_pay_flow(payment_method_type, Some(merchant_connector_account));
+            let apple_pay_flow = wallets::decide_apple_pay_flow(
+                payment_method_type,
+                Some(merchant_connector_account),
+            );
 
             let is_connector_tokenization_enabled =
                 is_payment_method_tokenization_enabled_for_connector(
@@ -2294,7 +2392,13 @@ where
                 payment_method,
                 payment_data.token.as_ref(),
                 is_connector_tokenization_enabled,
-                apple_pay_flow,
+                wallets::get_apple_pay_payment_processing_details(
+                    state,
+                    payment_method_type,
+                    merchant_connector_account,
+                    wallet_certificates,
+                )
+                .is_some(),
             )
             .await?;
 
@@ -2538,6 +2642,10 @@ where
     pub frm_metadata: Option<serde_json::Value>,
     pub recurring_details: Option<RecurringDetails>,
     pub poll_config: Option<router_types::PollConfig>,
+    pub micro_deposits_details: Option<MicroDepositsDetails>,
+    pub preferred_card_network: Option<storage_enums::CardNetwork>,
+    pub external_three_ds_authentication_data:
+        Option<api_models::payments::ExternalThreeDsAuthenticationData>,
 }
 
 #[derive(Clone, serde::Serialize, Debug)]
@@ -2574,11 +2682,21 @@ pub struct IncrementalAuthorizationDetails {
     pub authorization_id: Option<String>,
 }
 
+#[derive(Debug, Default, Clone)]
+pub struct MicroDepositsDetails {
+    pub amounts: Option<Vec<i64>>,
+    pub descriptor_code: Option<Secret<String>>,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct RecurringMandatePaymentData {
     pub payment_method_type: Option<storage_enums::PaymentMethodType>, //required for making recurring payment using saved payment method through stripe
     pub original_payment_authorized_amount: Option<i64>,
     pub original_payment_authorized_currency: Option<storage_enums::Currency>,
+    pub pre_notification_days: Option<u8>,
+    /// Network transaction ID of the customer initiated payment the payment method was saved with,
+    /// forwarded as the stored credential reference of the merchant initiated payments
+    pub network_transaction_id: Option<String>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -2695,6 +2813,10 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
             payment_data.payment_intent.status,
             storage_enums::IntentStatus::RequiresCapture
         ),
+        "PaymentVerifyMicroDeposits" => matches!(
+            payment_data.payment_intent.status,
+            storage_enums::IntentStatus::RequiresCustomerAction
+        ),
         _ => false,
     }
 }
@@ -2782,6 +2904,16 @@ pub async fn apply_filters_on_payments(
     let limit = &constraints.limit;
     helpers::validate_payment_list_request_for_joins(*limit)?;
     let db = state.store.as_ref();
+    let constraints = match &constraints.saved_filter {
+        Some(saved_filter) => constraints.clone().apply_over_saved_filter(
+            saved_filters::get_saved_filter_constraints(db, &merchant.merchant_id, saved_filter)
+                .await?,
+        ),
+        None => constraints,
+    };
+    if let Some(tags) = &constraints.tags {
+        helpers::validate_payment_tags(tags)?;
+    }
     let list: Vec<(storage::PaymentIntent, storage::PaymentAttempt)> = db
         .get_filtered_payment_intents_attempt(
             &merchant.merchant_id,
@@ -2826,6 +2958,44 @@ pub async fn apply_filters_on_payments(
     ))
 }
 
+pub async fn update_payment_tags(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    request: api::PaymentsTagsUpdateRequest,
+) -> RouterResponse<api::PaymentsTagsUpdateResponse> {
+    helpers::validate_payment_tags(&request.tags)?;
+
+    let db = state.store.as_ref();
+    let storage_scheme = merchant_account.storage_scheme;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &request.payment_id,
+            &merchant_account.merchant_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_intent = db
+        .update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::TagsUpdate {
+                tags: request.tags,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentsTagsUpdateResponse {
+            payment_id: payment_intent.payment_id,
+            tags: payment_intent.tags.unwrap_or_default(),
+        },
+    ))
+}
+
 #[cfg(feature = "olap")]
 pub async fn get_filters_for_payments(
     state: AppState,
@@ -3532,6 +3702,10 @@ pub async fn decide_multiplex_connector_for_normal_or_recurring_payment<F: Clone
                                         .original_payment_authorized_amount,
                                     original_payment_authorized_currency: mandate_reference_record
                                         .original_payment_authorized_currency,
+                                    pre_notification_days: None,
+                                    network_transaction_id: payment_method_info
+                                        .network_transaction_id
+                                        .clone(),
                                 });
 
                             connector_choice = Some((connector_data, mandate_reference_id.clone()));
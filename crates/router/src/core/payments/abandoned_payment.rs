@@ -0,0 +1,202 @@
+use api_models::admin::AbandonedPaymentConfig;
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+#[cfg(feature = "email")]
+use masking::PeekInterface;
+#[cfg(feature = "email")]
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use super::{
+    payment_tasks::{self, PaymentTask},
+    PaymentData,
+};
+#[cfg(feature = "email")]
+use crate::{
+    core::errors::StorageErrorExt, services::email::types as email_types, utils::OptionExt,
+};
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::AppState,
+    types::{domain, storage},
+};
+
+const ABANDONED_PAYMENT_TASK: PaymentTask = PaymentTask {
+    runner: diesel_models::ProcessTrackerRunner::AbandonedPaymentNotificationWorkflow,
+    name: "ABANDONED_PAYMENT_NOTIFICATION",
+    flow: "AbandonedPaymentNotification",
+};
+
+/// Payments are notified as abandoned at the earliest a few minutes after their creation, so that
+/// the customers still checking out are not reached out to, and at the latest a week after it
+const MIN_NOTIFY_AFTER_IN_MINUTES: u16 = 5;
+const MAX_NOTIFY_AFTER_IN_MINUTES: u16 = 10080;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct AbandonedPaymentTrackingData {
+    pub(crate) merchant_id: String,
+    pub(crate) profile_id: String,
+    pub(crate) payment_id: String,
+}
+
+pub fn validate_abandoned_payment_config(
+    abandoned_payment_config: &AbandonedPaymentConfig,
+) -> Result<(), errors::ApiErrorResponse> {
+    if !(MIN_NOTIFY_AFTER_IN_MINUTES..=MAX_NOTIFY_AFTER_IN_MINUTES)
+        .contains(&abandoned_payment_config.notify_after_in_minutes)
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "notify_after_in_minutes should be between {MIN_NOTIFY_AFTER_IN_MINUTES} and {MAX_NOTIFY_AFTER_IN_MINUTES}"
+            ),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+pub fn get_abandoned_payment_config(
+    business_profile: &storage::BusinessProfile,
+) -> RouterResult<Option<AbandonedPaymentConfig>> {
+    business_profile
+        .abandoned_payment_config
+        .clone()
+        .map(|abandoned_payment_config| {
+            abandoned_payment_config.parse_value("AbandonedPaymentConfig")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the abandoned payment config of the business profile")
+}
+
+pub fn get_notify_time(
+    abandoned_payment_config: &AbandonedPaymentConfig,
+    created_at: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    created_at.saturating_add(time::Duration::minutes(i64::from(
+        abandoned_payment_config.notify_after_in_minutes,
+    )))
+}
+
+/// Schedule the notification of a payment waiting for the customer, if the business profile of
+/// the payment notifies the abandoned payments. The notification is scheduled when the payment is
+/// first seen waiting for the customer, and is dropped by the workflow if the payment has moved on
+/// by then.
+pub async fn schedule_notification_if_required<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &storage::BusinessProfile,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let payment_intent = &payment_data.payment_intent;
+
+    if !payment_tasks::is_awaiting_customer(payment_intent.status) {
+        return Ok(());
+    }
+
+    let Some(abandoned_payment_config) = get_abandoned_payment_config(business_profile)? else {
+        return Ok(());
+    };
+
+    payment_tasks::schedule_payment_task(
+        state,
+        &ABANDONED_PAYMENT_TASK,
+        &payment_intent.payment_id,
+        AbandonedPaymentTrackingData {
+            merchant_id: merchant_account.merchant_id.clone(),
+            profile_id: business_profile.profile_id.clone(),
+            payment_id: payment_intent.payment_id.clone(),
+        },
+        get_notify_time(&abandoned_payment_config, payment_intent.created_at),
+    )
+    .await
+    .map(|_| ())
+}
+
+/// Send the customer of the abandoned payment an email with the payment link of the payment. The
+/// email is not sent if the email of the customer is not known, or if the payment has no payment
+/// link which can still be used.
+#[cfg(feature = "email")]
+pub async fn notify_customer(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: &storage::PaymentIntent,
+    customer: Option<domain::Customer>,
+) -> RouterResult<()> {
+    let Some(customer_email) = customer.and_then(|customer| customer.email) else {
+        logger::info!("Abandoned payment email not sent as the email of the customer is not known");
+        return Ok(());
+    };
+    let Some(payment_link_id) = payment_intent.payment_link_id.as_deref() else {
+        logger::info!("Abandoned payment email not sent as the payment has no payment link");
+        return Ok(());
+    };
+
+    let payment_link = state
+        .store
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    if payment_link
+        .fulfilment_time
+        .is_some_and(|fulfilment_time| fulfilment_time <= common_utils::date_time::now())
+    {
+        logger::info!("Abandoned payment email not sent as the payment link has expired");
+        return Ok(());
+    }
+
+    let merchant_name = payment_link
+        .custom_merchant_name
+        .or_else(|| {
+            merchant_account
+                .merchant_name
+                .clone()
+                .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+        })
+        .get_required_value("merchant_name")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing the name of the merchant for the abandoned payment email")?;
+
+    let email_contents = email_types::AbandonedPaymentReminder {
+        recipient_email: domain::UserEmail::from_pii_email(customer_email.into())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert the customer email to UserEmail")?,
+        subject: "Complete Your Payment",
+        merchant_name,
+        payment_link: payment_link.link_to_pay,
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send the abandoned payment email")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_abandoned_payment_config() {
+        let config = |notify_after_in_minutes| AbandonedPaymentConfig {
+            notify_after_in_minutes,
+            email_customer: false,
+        };
+
+        assert!(validate_abandoned_payment_config(&config(60)).is_ok());
+        assert!(validate_abandoned_payment_config(&config(MIN_NOTIFY_AFTER_IN_MINUTES)).is_ok());
+        assert!(validate_abandoned_payment_config(&config(MAX_NOTIFY_AFTER_IN_MINUTES)).is_ok());
+        assert!(validate_abandoned_payment_config(&config(1)).is_err());
+        assert!(
+            validate_abandoned_payment_config(&config(MAX_NOTIFY_AFTER_IN_MINUTES + 1)).is_err()
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use time::PrimitiveDateTime;
+
+use super::{
+    payment_tasks::{self, PaymentTask},
+    PaymentData,
+};
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::AppState,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+const AUTO_VOID_TASK: PaymentTask = PaymentTask {
+    runner: diesel_models::ProcessTrackerRunner::AutoVoidWorkflow,
+    name: "AUTO_VOID",
+    flow: "AutoVoid",
+};
+
+/// Authorizations are held by the issuers for at most about a month, after which there is
+/// nothing left to void
+const MAX_AUTO_VOID_AFTER_IN_HOURS: u16 = 720;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct AutoVoidTrackingData {
+    pub(crate) merchant_id: String,
+    pub(crate) profile_id: String,
+    pub(crate) payment_id: String,
+    pub(crate) auto_void_after_in_hours: u16,
+}
+
+pub fn validate_auto_void_after_in_hours(
+    auto_void_after_in_hours: u16,
+) -> Result<(), errors::ApiErrorResponse> {
+    if !(1..=MAX_AUTO_VOID_AFTER_IN_HOURS).contains(&auto_void_after_in_hours) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "auto_void_after_in_hours should be between 1 and {MAX_AUTO_VOID_AFTER_IN_HOURS}"
+            ),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+pub fn get_auto_void_after_in_hours(business_profile: &storage::BusinessProfile) -> Option<u16> {
+    business_profile
+        .auto_void_after_in_hours
+        .and_then(|hours| u16::try_from(hours).ok())
+        .filter(|hours| *hours > 0)
+}
+
+pub fn get_auto_void_time(
+    auto_void_after_in_hours: u16,
+    authorized_at: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    authorized_at.saturating_add(time::Duration::hours(i64::from(auto_void_after_in_hours)))
+}
+
+/// The reason the payment is cancelled with, which is recorded on its attempt and shared with the
+/// connector
+pub fn get_cancellation_reason(auto_void_after_in_hours: u16) -> String {
+    format!("Voided automatically as the payment was not captured within {auto_void_after_in_hours} hours of its authorization")
+}
+
+/// Schedule the void of a manual capture payment which has been authorized, if the business
+/// profile of the payment voids its uncaptured payments. The void is scheduled when the
+/// authorization is first seen, whether it was returned by the connector on confirmation or later
+/// by a sync or a webhook.
+pub async fn schedule_void_if_required<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &storage::BusinessProfile,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let payment_intent = &payment_data.payment_intent;
+
+    if payment_intent.status != enums::IntentStatus::RequiresCapture {
+        return Ok(());
+    }
+
+    let Some(auto_void_after_in_hours) = get_auto_void_after_in_hours(business_profile) else {
+        return Ok(());
+    };
+
+    payment_tasks::schedule_payment_task(
+        state,
+        &AUTO_VOID_TASK,
+        &payment_intent.payment_id,
+        AutoVoidTrackingData {
+            merchant_id: merchant_account.merchant_id.clone(),
+            profile_id: business_profile.profile_id.clone(),
+            payment_id: payment_intent.payment_id.clone(),
+            auto_void_after_in_hours,
+        },
+        get_auto_void_time(auto_void_after_in_hours, common_utils::date_time::now()),
+    )
+    .await
+    .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_auto_void_after_in_hours_validation() {
+        assert!(validate_auto_void_after_in_hours(0).is_err());
+        assert!(validate_auto_void_after_in_hours(1).is_ok());
+        assert!(validate_auto_void_after_in_hours(MAX_AUTO_VOID_AFTER_IN_HOURS).is_ok());
+        assert!(validate_auto_void_after_in_hours(MAX_AUTO_VOID_AFTER_IN_HOURS + 1).is_err());
+    }
+
+    #[test]
+    fn test_auto_void_time() {
+        assert_eq!(
+            get_auto_void_time(36, datetime!(2024-06-10 18:30:00)),
+            datetime!(2024-06-12 06:30:00)
+        );
+    }
+}
@@ -0,0 +1,409 @@
+use std::{fmt::Debug, str::FromStr};
+
+use api_models::{
+    admin::DunningConfig, gsm::GsmDecision, payments::PaymentRetryDetails,
+    webhooks::OutgoingWebhookContent,
+};
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{configs::settings::JitterSettings, utils as scheduler_utils};
+use time::PrimitiveDateTime;
+
+use super::{
+    helpers,
+    payment_tasks::{self, PaymentTask},
+    PaymentData,
+};
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::AppState,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+const PAYMENT_DUNNING_TASK: PaymentTask = PaymentTask {
+    runner: diesel_models::ProcessTrackerRunner::PaymentDunningWorkflow,
+    name: "PAYMENT_DUNNING",
+    flow: "PaymentDunning",
+};
+
+/// The flow whose GSM rules decide whether a failed payment is a soft decline
+const AUTHORIZE_FLOW_NAME: &str = "Authorize";
+
+const MAX_RETRIES: u8 = 10;
+
+/// A retry is moved to the next balance refresh day of the customer only if it is at most these
+/// many days later, so that the customer is not left waiting for their next payday
+const BALANCE_REFRESH_LOOKAHEAD_DAYS: i64 = 7;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct PaymentDunningTrackingData {
+    pub(crate) merchant_id: String,
+    pub(crate) profile_id: String,
+    pub(crate) payment_id: String,
+}
+
+pub fn validate_dunning_config(
+    dunning_config: &DunningConfig,
+) -> Result<(), errors::ApiErrorResponse> {
+    if !(1..=MAX_RETRIES).contains(&dunning_config.max_retries) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("max_retries should be between 1 and {MAX_RETRIES}"),
+        })
+    } else if dunning_config.min_days_between_retries == 0 {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "min_days_between_retries should be at least 1".to_string(),
+        })
+    } else if dunning_config.retry_hour > 23 {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "retry_hour should be between 0 and 23".to_string(),
+        })
+    } else if dunning_config
+        .utc_offset
+        .as_deref()
+        .is_some_and(|utc_offset| scheduler_utils::parse_utc_offset(utc_offset).is_err())
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "utc_offset should be formatted as +HH:MM or -HH:MM".to_string(),
+        })
+    } else if dunning_config
+        .balance_refresh_days
+        .iter()
+        .any(|day| !(1..=31).contains(day))
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "balance_refresh_days should be between 1 and 31".to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+pub fn get_dunning_config(
+    business_profile: &storage::BusinessProfile,
+) -> RouterResult<Option<DunningConfig>> {
+    business_profile
+        .dunning_config
+        .clone()
+        .map(|dunning_config| dunning_config.parse_value("DunningConfig"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the dunning config of the business profile")
+}
+
+/// Soft declines, such as insufficient funds or issuer unavailable, are the failures which the
+/// GSM rules of the connector allow retrying
+pub async fn is_soft_decline(state: &AppState, payment_attempt: &storage::PaymentAttempt) -> bool {
+    let Some(connector) = payment_attempt.connector.clone() else {
+        return false;
+    };
+
+    helpers::get_gsm_record(
+        state,
+        payment_attempt.error_code.clone(),
+        payment_attempt.error_message.clone(),
+        connector,
+        AUTHORIZE_FLOW_NAME.to_string(),
+    )
+    .await
+    .and_then(|gsm| GsmDecision::from_str(gsm.decision.as_str()).ok())
+    .is_some_and(|decision| matches!(decision, GsmDecision::Retry | GsmDecision::Requeue))
+}
+
+/// The payment is retried at the configured hour of the day in the timezone of the merchant, once
+/// the minimum number of days have passed since the failure. The retry is postponed to a balance
+/// refresh day if one follows shortly, as the payment is more likely to be approved once the
+/// customer has been paid.
+///
+/// The retries scheduled for the same hour are spread over the jitter of the scheduler, as the
+/// retries of all the payments of the merchant which failed on a day would be due at once.
+pub fn get_next_retry_time(
+    dunning_config: &DunningConfig,
+    failed_at: PrimitiveDateTime,
+    jitter: &JitterSettings,
+) -> PrimitiveDateTime {
+    let utc_offset = dunning_config
+        .utc_offset
+        .as_deref()
+        .map(scheduler_utils::parse_utc_offset)
+        .transpose()
+        .map_err(|error| logger::error!(dunning_utc_offset_error=?error))
+        .ok()
+        .flatten()
+        .unwrap_or(time::UtcOffset::UTC);
+    let failed_on = failed_at.assume_utc().to_offset(utc_offset).date();
+
+    let earliest_retry_date =
+        failed_on + time::Duration::days(i64::from(dunning_config.min_days_between_retries));
+
+    let retry_date = (0..=BALANCE_REFRESH_LOOKAHEAD_DAYS)
+        .map(|offset| earliest_retry_date + time::Duration::days(offset))
+        .find(|date| is_balance_refresh_day(dunning_config, *date))
+        .unwrap_or(earliest_retry_date);
+
+    let retry_at = scheduler_utils::get_utc_schedule_time(
+        retry_date,
+        time::Time::MIDNIGHT + time::Duration::hours(i64::from(dunning_config.retry_hour)),
+        utc_offset,
+    );
+    scheduler_utils::add_jitter(retry_at, jitter)
+}
+
+pub(crate) fn get_jitter_settings(state: &AppState) -> JitterSettings {
+    state
+        .conf
+        .scheduler
+        .as_ref()
+        .map(|scheduler| scheduler.jitter.clone())
+        .unwrap_or_default()
+}
+
+/// Balance refresh days which do not exist in a month, such as the 31st, fall on its last day
+fn is_balance_refresh_day(dunning_config: &DunningConfig, date: time::Date) -> bool {
+    let days_in_month = time::util::days_in_year_month(date.year(), date.month());
+
+    dunning_config
+        .balance_refresh_days
+        .iter()
+        .any(|day| (*day).min(days_in_month) == date.day())
+}
+
+/// Schedule the retry of an off-session payment which failed with a soft decline, if dunning is
+/// configured for the business profile of the payment
+pub async fn schedule_retry_if_required<F: Clone, Op: Debug>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &storage::BusinessProfile,
+    operation: &Op,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let payment_intent = &payment_data.payment_intent;
+    let payment_attempt = &payment_data.payment_attempt;
+
+    let is_failed_merchant_initiated_payment = payment_intent.status == enums::IntentStatus::Failed
+        && payment_intent.off_session == Some(true)
+        && (payment_attempt.mandate_id.is_some() || payment_attempt.payment_method_id.is_some());
+
+    if !is_authorize_operation(operation) || !is_failed_merchant_initiated_payment {
+        return Ok(());
+    }
+
+    let Some(dunning_config) = get_dunning_config(business_profile)? else {
+        return Ok(());
+    };
+
+    if !is_soft_decline(state, payment_attempt).await {
+        return Ok(());
+    }
+
+    let next_retry_at = get_next_retry_time(
+        &dunning_config,
+        common_utils::date_time::now(),
+        &get_jitter_settings(state),
+    );
+    let is_task_added = add_payment_dunning_task(
+        state,
+        &merchant_account.merchant_id,
+        &business_profile.profile_id,
+        &payment_intent.payment_id,
+        next_retry_at,
+    )
+    .await?;
+
+    // The retries of the payment are already scheduled if the task exists, such as when the
+    // failed payment is itself a retry
+    if is_task_added {
+        trigger_dunning_webhook(
+            state,
+            merchant_account.clone(),
+            key_store,
+            business_profile.clone(),
+            enums::EventType::PaymentRetryScheduled,
+            get_payment_retry_details(
+                payment_intent,
+                payment_attempt,
+                0,
+                dunning_config.max_retries,
+                Some(next_retry_at),
+            ),
+            payment_intent.created_at,
+        )
+        .await;
+    }
+
+    Ok(())
+}
+
+fn is_authorize_operation<Op: Debug>(operation: &Op) -> bool {
+    matches!(
+        format!("{operation:?}").as_str(),
+        "PaymentConfirm" | "PaymentCreate"
+    )
+}
+
+async fn add_payment_dunning_task(
+    state: &AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    payment_id: &str,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<bool> {
+    payment_tasks::schedule_payment_task(
+        state,
+        &PAYMENT_DUNNING_TASK,
+        payment_id,
+        PaymentDunningTrackingData {
+            merchant_id: merchant_id.to_string(),
+            profile_id: profile_id.to_string(),
+            payment_id: payment_id.to_string(),
+        },
+        schedule_time,
+    )
+    .await
+}
+
+pub fn get_payment_retry_details(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    retry_count: u8,
+    max_retries: u8,
+    next_retry_at: Option<PrimitiveDateTime>,
+) -> PaymentRetryDetails {
+    PaymentRetryDetails {
+        payment_id: payment_intent.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        customer_id: payment_intent.customer_id.clone(),
+        retry_count,
+        max_retries,
+        next_retry_at,
+        error_code: payment_attempt.error_code.clone(),
+        error_message: payment_attempt.error_message.clone(),
+    }
+}
+
+/// The events are raised for the failed attempt, so that an event is sent for every retry of the
+/// payment
+pub async fn trigger_dunning_webhook(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: storage::BusinessProfile,
+    event_type: enums::EventType,
+    payment_retry_details: PaymentRetryDetails,
+    payment_created_at: PrimitiveDateTime,
+) {
+    let primary_object_id = payment_retry_details.attempt_id.clone();
+
+    payment_tasks::trigger_payment_webhook(
+        state,
+        merchant_account,
+        key_store,
+        business_profile,
+        event_type,
+        primary_object_id,
+        OutgoingWebhookContent::PaymentRetryDetails(Box::new(payment_retry_details)),
+        Some(payment_created_at),
+    )
+    .await
+    .map_err(|error| logger::warn!(payment_dunning_outgoing_webhook_error=?error))
+    .ok();
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn dunning_config(balance_refresh_days: Vec<u8>) -> DunningConfig {
+        DunningConfig {
+            max_retries: 4,
+            min_days_between_retries: 3,
+            retry_hour: 10,
+            utc_offset: None,
+            balance_refresh_days,
+        }
+    }
+
+    #[test]
+    fn test_next_retry_time_without_balance_refresh_days() {
+        let next_retry_at = get_next_retry_time(
+            &dunning_config(vec![]),
+            datetime!(2024-05-02 18:30:00),
+            &JitterSettings::default(),
+        );
+
+        assert_eq!(next_retry_at, datetime!(2024-05-05 10:00:00));
+    }
+
+    #[test]
+    fn test_next_retry_time_postponed_to_balance_refresh_day() {
+        let next_retry_at = get_next_retry_time(
+            &dunning_config(vec![1, 10]),
+            datetime!(2024-05-02 18:30:00),
+            &JitterSettings::default(),
+        );
+
+        assert_eq!(next_retry_at, datetime!(2024-05-10 10:00:00));
+    }
+
+    #[test]
+    fn test_next_retry_time_not_postponed_beyond_lookahead() {
+        let next_retry_at = get_next_retry_time(
+            &dunning_config(vec![20]),
+            datetime!(2024-05-02 18:30:00),
+            &JitterSettings::default(),
+        );
+
+        assert_eq!(next_retry_at, datetime!(2024-05-05 10:00:00));
+    }
+
+    #[test]
+    fn test_next_retry_time_balance_refresh_day_beyond_month_end() {
+        let next_retry_at = get_next_retry_time(
+            &dunning_config(vec![31]),
+            datetime!(2024-04-25 08:00:00),
+            &JitterSettings::default(),
+        );
+
+        assert_eq!(next_retry_at, datetime!(2024-04-30 10:00:00));
+    }
+
+    #[test]
+    fn test_invalid_dunning_config() {
+        let dunning_config = DunningConfig {
+            retry_hour: 24,
+            ..dunning_config(vec![1])
+        };
+
+        assert!(validate_dunning_config(&dunning_config).is_err());
+
+        let dunning_config = DunningConfig {
+            utc_offset: Some("05:30".to_string()),
+            ..dunning_config(vec![1])
+        };
+
+        assert!(validate_dunning_config(&dunning_config).is_err());
+    }
+
+    #[test]
+    fn test_next_retry_time_in_timezone_of_merchant() {
+        let dunning_config = DunningConfig {
+            utc_offset: Some("+05:30".to_string()),
+            ..dunning_config(vec![])
+        };
+        // The payment failed on the 3rd in the timezone of the merchant
+        let next_retry_at = get_next_retry_time(
+            &dunning_config,
+            datetime!(2024-05-02 20:00:00),
+            &JitterSettings::default(),
+        );
+
+        assert_eq!(next_retry_at, datetime!(2024-05-06 04:30:00));
+    }
+}
@@ -8,6 +8,7 @@ pub mod psync_flow;
 pub mod reject_flow;
 pub mod session_flow;
 pub mod setup_mandate_flow;
+pub mod verify_micro_deposits_flow;
 
 use async_trait::async_trait;
 
@@ -167,6 +168,7 @@ default_imp_for_complete_authorize!(
     connector::Opayo,
     connector::Opennode,
     connector::Payeezy,
+    connector::Payoneer,
     connector::Payu,
     connector::Placetopay,
     connector::Rapyd,
@@ -214,6 +216,8 @@ impl<const T: u8>
 default_imp_for_webhook_source_verification!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -249,6 +253,7 @@ default_imp_for_webhook_source_verification!(
     connector::Opennode,
     connector::Payeezy,
     connector::Payme,
+    connector::Payoneer,
     connector::Payu,
     connector::Placetopay,
     connector::Powertranz,
@@ -301,6 +306,8 @@ impl<const T: u8>
 default_imp_for_create_customer!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -336,6 +343,7 @@ default_imp_for_create_customer!(
     connector::Payeezy,
     connector::Paypal,
     connector::Payme,
+    connector::Payoneer,
     connector::Payu,
     connector::Placetopay,
     connector::Powertranz,
@@ -411,6 +419,7 @@ default_imp_for_connector_redirect_response!(
     connector::Opayo,
     connector::Opennode,
     connector::Payeezy,
+    connector::Payoneer,
     connector::Payu,
     connector::Placetopay,
     connector::Powertranz,
@@ -445,6 +454,8 @@ default_imp_for_connector_request_id!(
     connector::Zsl,
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -478,6 +489,7 @@ default_imp_for_connector_request_id!(
     connector::Opennode,
     connector::Payeezy,
     connector::Payme,
+    connector::Payoneer,
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
@@ -533,6 +545,8 @@ impl<const T: u8>
 default_imp_for_accept_dispute!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -568,6 +582,7 @@ default_imp_for_accept_dispute!(
     connector::Payeezy,
     connector::Paypal,
     connector::Payme,
+    connector::Payoneer,
     connector::Payu,
     connector::Placetopay,
     connector::Powertranz,
@@ -642,6 +657,8 @@ impl<const T: u8>
 default_imp_for_file_upload!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -676,6 +693,7 @@ default_imp_for_file_upload!(
     connector::Payeezy,
     connector::Paypal,
     connector::Payme,
+    connector::Payoneer,
     connector::Payu,
     connector::Placetopay,
     connector::Powertranz,
@@ -728,6 +746,8 @@ impl<const T: u8>
 default_imp_for_submit_evidence!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -762,6 +782,7 @@ default_imp_for_submit_evidence!(
     connector::Payeezy,
     connector::Paypal,
     connector::Payme,
+    connector::Payoneer,
     connector::Payu,
     connector::Placetopay,
     connector::Powertranz,
@@ -814,6 +835,8 @@ impl<const T: u8>
 default_imp_for_defend_dispute!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -848,6 +871,7 @@ default_imp_for_defend_dispute!(
     connector::Payeezy,
     connector::Paypal,
     connector::Payme,
+    connector::Payoneer,
     connector::Payu,
     connector::Placetopay,
     connector::Powertranz,
@@ -900,6 +924,8 @@ impl<const T: u8>
 
 default_imp_for_pre_processing_steps!(
     connector::Aci,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -930,6 +956,7 @@ default_imp_for_pre_processing_steps!(
     connector::Opayo,
     connector::Opennode,
     connector::Payeezy,
+    connector::Payoneer,
     connector::Payu,
     connector::Placetopay,
     connector::Powertranz,
@@ -963,6 +990,8 @@ impl<const T: u8> api::Payouts for connector::DummyConnector<T> {}
 
 default_imp_for_payouts!(
     connector::Aci,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -1047,6 +1076,8 @@ impl<const T: u8>
 #[cfg(feature = "payouts")]
 default_imp_for_payouts_create!(
     connector::Aci,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -1134,6 +1165,8 @@ impl<const T: u8>
 #[cfg(feature = "payouts")]
 default_imp_for_payouts_eligibility!(
     connector::Aci,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -1220,6 +1253,8 @@ impl<const T: u8>
 #[cfg(feature = "payouts")]
 default_imp_for_payouts_fulfill!(
     connector::Aci,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -1249,7 +1284,6 @@ default_imp_for_payouts_fulfill!(
     connector::Nexinets,
     connector::Nmi,
     connector::Noon,
-    connector::Nuvei,
     connector::Opayo,
     connector::Opennode,
     connector::Payeezy,
@@ -1304,6 +1338,8 @@ impl<const T: u8>
 #[cfg(feature = "payouts")]
 default_imp_for_payouts_cancel!(
     connector::Aci,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -1390,6 +1426,8 @@ impl<const T: u8>
 default_imp_for_payouts_quote!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -1477,6 +1515,8 @@ impl<const T: u8>
 default_imp_for_payouts_recipient!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -1566,6 +1606,8 @@ impl<const T: u8>
 default_imp_for_payouts_recipient_account!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -1601,6 +1643,7 @@ default_imp_for_payouts_recipient_account!(
     connector::Opennode,
     connector::Payeezy,
     connector::Payme,
+    connector::Payoneer,
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
@@ -1653,6 +1696,8 @@ impl<const T: u8>
 default_imp_for_approve!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -1688,6 +1733,7 @@ default_imp_for_approve!(
     connector::Opennode,
     connector::Payeezy,
     connector::Payme,
+    connector::Payoneer,
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
@@ -1741,6 +1787,8 @@ impl<const T: u8>
 default_imp_for_reject!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -1776,6 +1824,7 @@ default_imp_for_reject!(
     connector::Opennode,
     connector::Payeezy,
     connector::Payme,
+    connector::Payoneer,
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
@@ -1813,6 +1862,8 @@ impl<const T: u8> api::FraudCheck for connector::DummyConnector<T> {}
 default_imp_for_fraud_check!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -1848,6 +1899,7 @@ default_imp_for_fraud_check!(
     connector::Opennode,
     connector::Payeezy,
     connector::Payme,
+    connector::Payoneer,
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
@@ -1901,6 +1953,8 @@ impl<const T: u8>
 default_imp_for_frm_sale!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -1936,6 +1990,7 @@ default_imp_for_frm_sale!(
     connector::Opennode,
     connector::Payeezy,
     connector::Payme,
+    connector::Payoneer,
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
@@ -1989,6 +2044,8 @@ impl<const T: u8>
 default_imp_for_frm_checkout!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -2024,6 +2081,7 @@ default_imp_for_frm_checkout!(
     connector::Opennode,
     connector::Payeezy,
     connector::Payme,
+    connector::Payoneer,
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
@@ -2077,6 +2135,8 @@ impl<const T: u8>
 default_imp_for_frm_transaction!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -2112,6 +2172,7 @@ default_imp_for_frm_transaction!(
     connector::Opennode,
     connector::Payeezy,
     connector::Payme,
+    connector::Payoneer,
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
@@ -2165,6 +2226,8 @@ impl<const T: u8>
 default_imp_for_frm_fulfillment!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -2200,6 +2263,7 @@ default_imp_for_frm_fulfillment!(
     connector::Opennode,
     connector::Payeezy,
     connector::Payme,
+    connector::Payoneer,
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
@@ -2253,6 +2317,8 @@ impl<const T: u8>
 default_imp_for_frm_record_return!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -2288,6 +2354,7 @@ default_imp_for_frm_record_return!(
     connector::Opennode,
     connector::Payeezy,
     connector::Payme,
+    connector::Payoneer,
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
@@ -2339,6 +2406,8 @@ impl<const T: u8>
 default_imp_for_incremental_authorization!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -2373,6 +2442,97 @@ default_imp_for_incremental_authorization!(
     connector::Opennode,
     connector::Payeezy,
     connector::Payme,
+    connector::Payoneer,
+    connector::Paypal,
+    connector::Payu,
+    connector::Placetopay,
+    connector::Powertranz,
+    connector::Prophetpay,
+    connector::Rapyd,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Square,
+    connector::Stax,
+    connector::Shift4,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Tsys,
+    connector::Volt,
+    connector::Wise,
+    connector::Worldline,
+    connector::Worldpay,
+    connector::Zen,
+    connector::Zsl
+);
+
+macro_rules! default_imp_for_verify_micro_deposits {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::PaymentVerifyMicroDeposits for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::VerifyMicroDeposits,
+            types::PaymentsVerifyMicroDepositsData,
+            types::PaymentsResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PaymentVerifyMicroDeposits for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::VerifyMicroDeposits,
+        types::PaymentsVerifyMicroDepositsData,
+        types::PaymentsResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+
+default_imp_for_verify_micro_deposits!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
+    connector::Airwallex,
+    connector::Authorizedotnet,
+    connector::Bambora,
+    connector::Bankofamerica,
+    connector::Billwerk,
+    connector::Bitpay,
+    connector::Bluesnap,
+    connector::Boku,
+    connector::Braintree,
+    connector::Cashtocode,
+    connector::Checkout,
+    connector::Cryptopay,
+    connector::Cybersource,
+    connector::Coinbase,
+    connector::Dlocal,
+    connector::Ebanx,
+    connector::Fiserv,
+    connector::Forte,
+    connector::Globalpay,
+    connector::Globepay,
+    connector::Gocardless,
+    connector::Helcim,
+    connector::Iatapay,
+    connector::Klarna,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Netcetera,
+    connector::Nexinets,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Payeezy,
+    connector::Payme,
+    connector::Payoneer,
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
@@ -2383,7 +2543,6 @@ default_imp_for_incremental_authorization!(
     connector::Signifyd,
     connector::Square,
     connector::Stax,
-    connector::Stripe,
     connector::Shift4,
     connector::Threedsecureio,
     connector::Trustpay,
@@ -2424,6 +2583,8 @@ impl<const T: u8>
 default_imp_for_revoking_mandates!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -2457,6 +2618,7 @@ default_imp_for_revoking_mandates!(
     connector::Opennode,
     connector::Payeezy,
     connector::Payme,
+    connector::Payoneer,
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
@@ -2550,6 +2712,8 @@ impl<const T: u8>
 default_imp_for_connector_authentication!(
     connector::Aci,
     connector::Adyen,
+    connector::Affirm,
+    connector::Afterpay,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -2584,6 +2748,7 @@ default_imp_for_connector_authentication!(
     connector::Opennode,
     connector::Payeezy,
     connector::Payme,
+    connector::Payoneer,
     connector::Paypal,
     connector::Payu,
     connector::Placetopay,
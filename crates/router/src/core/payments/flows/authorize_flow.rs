@@ -74,6 +74,13 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
         if self.should_proceed_with_authorize() {
             self.decide_authentication_type();
             logger::debug!(auth_type=?self.auth_type);
+            if helpers::is_zero_amount_verification(
+                self.request.amount,
+                self.request.mandate_id.as_ref(),
+            ) {
+                return verify_payment_method(state, &self, connector, call_connector_action).await;
+            }
+
             let resp = services::execute_connector_processing_step(
                 state,
                 connector_integration,
@@ -154,6 +161,10 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
                         self.request.payment_method_type,
                     )
                     .to_payment_failed_response()?;
+                connector
+                    .connector
+                    .validate_split_payments(self.request.split_payments.as_ref())
+                    .to_payment_failed_response()?;
                 let connector_integration: services::BoxedConnectorIntegration<
                     '_,
                     api::Authorize,
@@ -183,9 +194,21 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
                     self.decide_authentication_type();
                     logger::debug!(auth_type=?self.auth_type);
 
+                    // The request of the verification is built by the setup mandate flow
+                    if helpers::is_zero_amount_verification(
+                        self.request.amount,
+                        self.request.mandate_id.as_ref(),
+                    ) {
+                        return Ok((None, true));
+                    }
+
+                    let connectors =
+                        services::api::base_url_override::get_connectors(state, &self.connector)
+                            .await;
+
                     Ok((
                         connector_integration
-                            .build_request(self, &state.conf.connectors)
+                            .build_request(self, &connectors)
                             .to_payment_failed_response()?,
                         true,
                     ))
@@ -245,6 +268,50 @@ impl mandate::MandateBehaviour for types::PaymentsAuthorizeData {
     }
 }
 
+/// Verify the payment method of a zero amount payment with the setup mandate flow of the connector,
+/// which is received back as the authorization of the payment
+async fn verify_payment_method(
+    state: &AppState,
+    router_data: &types::PaymentsAuthorizeRouterData,
+    connector: &api::ConnectorData,
+    call_connector_action: payments::CallConnectorAction,
+) -> RouterResult<types::PaymentsAuthorizeRouterData> {
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::SetupMandate,
+        types::SetupMandateRequestData,
+        types::PaymentsResponseData,
+    > = connector.connector.get_connector_integration();
+
+    let setup_mandate_router_data =
+        payments::helpers::router_data_type_conversion::<_, api::SetupMandate, _, _, _, _>(
+            router_data.clone(),
+            types::SetupMandateRequestData::try_from(router_data.request.to_owned())?,
+            router_data.response.clone(),
+        );
+
+    let resp = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &setup_mandate_router_data,
+        call_connector_action,
+        None,
+    )
+    .await
+    .to_setup_mandate_failed_response()?;
+
+    metrics::PAYMENT_COUNT.add(&metrics::CONTEXT, 1, &[]);
+
+    let authorize_router_data =
+        payments::helpers::router_data_type_conversion::<_, api::Authorize, _, _, _, _>(
+            resp.clone(),
+            router_data.request.to_owned(),
+            resp.response,
+        );
+
+    Ok(authorize_router_data)
+}
+
 pub async fn authorize_preprocessing_steps<F: Clone>(
     state: &AppState,
     router_data: &types::RouterData<F, types::PaymentsAuthorizeData, types::PaymentsResponseData>,
@@ -348,6 +415,33 @@ impl TryFrom<types::PaymentsAuthorizeData> for types::PaymentMethodTokenizationD
     }
 }
 
+impl TryFrom<types::PaymentsAuthorizeData> for types::SetupMandateRequestData {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(data: types::PaymentsAuthorizeData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            currency: data.currency,
+            payment_method_data: data.payment_method_data,
+            amount: Some(data.amount),
+            confirm: data.confirm,
+            statement_descriptor_suffix: data.statement_descriptor_suffix,
+            customer_acceptance: data.customer_acceptance,
+            mandate_id: data.mandate_id,
+            setup_future_usage: data.setup_future_usage,
+            off_session: data.off_session,
+            setup_mandate_details: data.setup_mandate_details,
+            router_return_url: data.router_return_url,
+            browser_info: data.browser_info,
+            email: data.email,
+            customer_name: data.customer_name,
+            return_url: None,
+            payment_method_type: data.payment_method_type,
+            request_incremental_authorization: data.request_incremental_authorization,
+            metadata: data.metadata,
+        })
+    }
+}
+
 impl TryFrom<types::PaymentsAuthorizeData> for types::PaymentsPreProcessingData {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
 
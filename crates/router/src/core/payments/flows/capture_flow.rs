@@ -96,8 +96,11 @@ impl Feature<api::Capture, types::PaymentsCaptureData>
                     types::PaymentsResponseData,
                 > = connector.connector.get_connector_integration();
 
+                let connectors =
+                    services::api::base_url_override::get_connectors(state, &self.connector).await;
+
                 connector_integration
-                    .build_request(self, &state.conf.connectors)
+                    .build_request(self, &connectors)
                     .to_payment_failed_response()?
             }
             _ => None,
@@ -73,7 +73,7 @@ impl Feature<api::CompleteAuthorize, types::CompleteAuthorizeData>
             types::PaymentsResponseData,
         > = connector.connector.get_connector_integration();
 
-        let resp = services::execute_connector_processing_step(
+        let mut resp = services::execute_connector_processing_step(
             state,
             connector_integration,
             &self,
@@ -83,6 +83,17 @@ impl Feature<api::CompleteAuthorize, types::CompleteAuthorizeData>
         .await
         .to_payment_failed_response()?;
 
+        let connector_integration: services::BoxedConnectorIntegration<
+            '_,
+            api::CompleteAuthorize,
+            types::CompleteAuthorizeData,
+            types::PaymentsResponseData,
+        > = connector.connector.get_connector_integration();
+        connector_integration
+            .execute_posttasks(&mut resp, state)
+            .await
+            .to_payment_failed_response()?;
+
         Ok(resp)
     }
 
@@ -132,8 +143,11 @@ impl Feature<api::CompleteAuthorize, types::CompleteAuthorizeData>
                     types::PaymentsResponseData,
                 > = connector.connector.get_connector_integration();
 
+                let connectors =
+                    services::api::base_url_override::get_connectors(state, &self.connector).await;
+
                 connector_integration
-                    .build_request(self, &state.conf.connectors)
+                    .build_request(self, &connectors)
                     .to_payment_failed_response()?
             }
             _ => None,
@@ -1,22 +1,22 @@
 use api_models::payments as payment_types;
 use async_trait::async_trait;
-use common_utils::{ext_traits::ByteSliceExt, request::RequestContent};
-use error_stack::{Report, ResultExt};
-use masking::ExposeInterface;
+use error_stack::ResultExt;
 
 use super::{ConstructFlowSpecificData, Feature};
 use crate::{
     core::{
-        errors::{self, ConnectorErrorExt, RouterResult},
-        payments::{self, access_token, helpers, transformers, PaymentData},
+        errors::{self, ConnectorErrorExt, RouterResult, StorageErrorExt},
+        payments::{self, access_token, helpers, transformers, wallets, PaymentData},
     },
-    headers, logger,
     routes::{self, metrics},
     services,
     types::{self, api, domain},
     utils::OptionExt,
 };
 
+/// Version of the Samsung Pay web checkout SDK the session token is created for
+const SAMSUNG_PAY_SDK_VERSION: &str = "2";
+
 #[async_trait]
 impl
     ConstructFlowSpecificData<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
@@ -78,58 +78,25 @@ impl Feature<api::Session, types::PaymentsSessionData> for types::PaymentsSessio
     }
 }
 
-fn get_applepay_metadata(
-    connector_metadata: Option<common_utils::pii::SecretSerdeValue>,
-) -> RouterResult<payment_types::ApplepaySessionTokenMetadata> {
-    connector_metadata
-        .clone()
-        .parse_value::<api_models::payments::ApplepayCombinedSessionTokenData>(
-            "ApplepayCombinedSessionTokenData",
+/// Certificates of the wallets of the business profile of the payment, which the Apple Pay
+/// session is created with instead of those in the metadata of the merchant connector account
+async fn get_profile_wallet_certificates(
+    state: &routes::AppState,
+    router_data: &types::PaymentsSessionRouterData,
+) -> RouterResult<Option<api_models::admin::WalletCertificates>> {
+    let Some(profile_id) = router_data.request.profile_id.as_deref() else {
+        return Ok(None);
+    };
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &router_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
         )
-        .map(|combined_metadata| {
-            api_models::payments::ApplepaySessionTokenMetadata::ApplePayCombined(
-                combined_metadata.apple_pay_combined,
-            )
-        })
-        .or_else(|_| {
-            connector_metadata
-                .parse_value::<api_models::payments::ApplepaySessionTokenData>(
-                    "ApplepaySessionTokenData",
-                )
-                .map(|old_metadata| {
-                    api_models::payments::ApplepaySessionTokenMetadata::ApplePay(
-                        old_metadata.apple_pay,
-                    )
-                })
-        })
-        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
-            field_name: "connector_metadata".to_string(),
-            expected_format: "applepay_metadata_format".to_string(),
-        })
-}
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-fn build_apple_pay_session_request(
-    state: &routes::AppState,
-    request: payment_types::ApplepaySessionRequest,
-    apple_pay_merchant_cert: masking::Secret<String>,
-    apple_pay_merchant_cert_key: masking::Secret<String>,
-) -> RouterResult<services::Request> {
-    let mut url = state.conf.connectors.applepay.base_url.to_owned();
-    url.push_str("paymentservices/paymentSession");
-
-    let session_request = services::RequestBuilder::new()
-        .method(services::Method::Post)
-        .url(url.as_str())
-        .attach_default_headers()
-        .headers(vec![(
-            headers::CONTENT_TYPE.to_string(),
-            "application/json".to_string().into(),
-        )])
-        .set_body(RequestContent::Json(Box::new(request)))
-        .add_certificate(Some(apple_pay_merchant_cert))
-        .add_certificate_key(Some(apple_pay_merchant_cert_key))
-        .build();
-    Ok(session_request)
+    wallets::get_wallet_certificates(state, &key_store, profile_id).await
 }
 
 async fn create_applepay_session_token(
@@ -150,148 +117,38 @@ async fn create_applepay_session_token(
             payment_types::NextActionCall::Confirm,
         )
     } else {
-        // Get the apple pay metadata
-        let apple_pay_metadata = get_applepay_metadata(router_data.connector_meta_data.clone())?;
-
-        // Get payment request data , apple pay session request and merchant keys
-        let (
-            payment_request_data,
-            apple_pay_session_request,
-            apple_pay_merchant_cert,
-            apple_pay_merchant_cert_key,
-            merchant_business_country,
-        ) = match apple_pay_metadata {
-            payment_types::ApplepaySessionTokenMetadata::ApplePayCombined(
-                apple_pay_combined_metadata,
-            ) => match apple_pay_combined_metadata {
-                payment_types::ApplePayCombinedMetadata::Simplified {
-                    payment_request_data,
-                    session_token_data,
-                } => {
-                    let merchant_identifier = state
-                        .conf
-                        .applepay_merchant_configs
-                        .get_inner()
-                        .common_merchant_identifier
-                        .clone()
-                        .expose();
-
-                    let merchant_business_country = session_token_data.merchant_business_country;
-
-                    let apple_pay_session_request = get_session_request_for_simplified_apple_pay(
-                        merchant_identifier,
-                        session_token_data,
-                    );
-
-                    let apple_pay_merchant_cert = state
-                        .conf
-                        .applepay_decrypt_keys
-                        .get_inner()
-                        .apple_pay_merchant_cert
-                        .clone();
-
-                    let apple_pay_merchant_cert_key = state
-                        .conf
-                        .applepay_decrypt_keys
-                        .get_inner()
-                        .apple_pay_merchant_cert_key
-                        .clone();
-
-                    (
-                        payment_request_data,
-                        apple_pay_session_request,
-                        apple_pay_merchant_cert,
-                        apple_pay_merchant_cert_key,
-                        merchant_business_country,
-                    )
-                }
-                payment_types::ApplePayCombinedMetadata::Manual {
-                    payment_request_data,
-                    session_token_data,
-                } => {
-                    let apple_pay_session_request =
-                        get_session_request_for_manual_apple_pay(session_token_data.clone());
-
-                    let merchant_business_country = session_token_data.merchant_business_country;
-
-                    (
-                        payment_request_data,
-                        apple_pay_session_request,
-                        session_token_data.certificate.clone(),
-                        session_token_data.certificate_keys,
-                        merchant_business_country,
-                    )
-                }
-            },
-            payment_types::ApplepaySessionTokenMetadata::ApplePay(apple_pay_metadata) => {
-                let apple_pay_session_request = get_session_request_for_manual_apple_pay(
-                    apple_pay_metadata.session_token_data.clone(),
-                );
-
-                let merchant_business_country = apple_pay_metadata
-                    .session_token_data
-                    .merchant_business_country;
-                (
-                    apple_pay_metadata.payment_request_data,
-                    apple_pay_session_request,
-                    apple_pay_metadata.session_token_data.certificate.clone(),
-                    apple_pay_metadata.session_token_data.certificate_keys,
-                    merchant_business_country,
-                )
-            }
-        };
+        let wallet_certificates = get_profile_wallet_certificates(state, router_data).await?;
+
+        // Get payment request data, apple pay session request and merchant keys
+        let apple_pay_session_details = wallets::get_apple_pay_session_details(
+            state,
+            router_data.connector_meta_data.clone(),
+            wallet_certificates.as_ref(),
+        )?;
 
         // Get amount info for apple pay
         let amount_info = get_apple_pay_amount_info(
-            payment_request_data.label.as_str(),
+            apple_pay_session_details
+                .payment_request_data
+                .label
+                .as_str(),
             router_data.request.to_owned(),
         )?;
 
         // Get apple pay payment request
         let applepay_payment_request = get_apple_pay_payment_request(
             amount_info,
-            payment_request_data,
+            apple_pay_session_details.payment_request_data.clone(),
             router_data.request.to_owned(),
-            apple_pay_session_request.merchant_identifier.as_str(),
-            merchant_business_country,
+            apple_pay_session_details
+                .session_request
+                .merchant_identifier
+                .as_str(),
+            apple_pay_session_details.merchant_business_country,
         )?;
 
-        let applepay_session_request = build_apple_pay_session_request(
-            state,
-            apple_pay_session_request,
-            apple_pay_merchant_cert,
-            apple_pay_merchant_cert_key,
-        )?;
-        let response = services::call_connector_api(
-            state,
-            applepay_session_request,
-            "create_apple_pay_session_token",
-        )
-        .await;
-
-        // logging the error if present in session call response
-        log_session_response_if_error(&response);
-
-        let apple_pay_session_response = response
-            .ok()
-            .and_then(|apple_pay_res| {
-                apple_pay_res
-                    .map(|res| {
-                        let response: Result<
-                            payment_types::NoThirdPartySdkSessionResponse,
-                            Report<common_utils::errors::ParsingError>,
-                        > = res.response.parse_struct("NoThirdPartySdkSessionResponse");
-
-                        // logging the parsing failed error
-                        if let Err(error) = response.as_ref() {
-                            logger::error!(?error);
-                        };
-
-                        response.ok()
-                    })
-                    .ok()
-            })
-            .flatten();
+        let apple_pay_session_response =
+            wallets::create_apple_pay_session(state, &apple_pay_session_details).await?;
 
         let session_response =
             apple_pay_session_response.map(payment_types::ApplePaySessionResponse::NoThirdPartySdk);
@@ -307,29 +164,6 @@ async fn create_applepay_session_token(
     }
 }
 
-fn get_session_request_for_simplified_apple_pay(
-    apple_pay_merchant_identifier: String,
-    session_token_data: payment_types::SessionTokenForSimplifiedApplePay,
-) -> payment_types::ApplepaySessionRequest {
-    payment_types::ApplepaySessionRequest {
-        merchant_identifier: apple_pay_merchant_identifier,
-        display_name: "Apple pay".to_string(),
-        initiative: "web".to_string(),
-        initiative_context: session_token_data.initiative_context,
-    }
-}
-
-fn get_session_request_for_manual_apple_pay(
-    session_token_data: payment_types::SessionTokenInfo,
-) -> payment_types::ApplepaySessionRequest {
-    payment_types::ApplepaySessionRequest {
-        merchant_identifier: session_token_data.merchant_identifier.clone(),
-        display_name: session_token_data.display_name.clone(),
-        initiative: session_token_data.initiative.clone(),
-        initiative_context: session_token_data.initiative_context,
-    }
-}
-
 fn get_apple_pay_amount_info(
     label: &str,
     session_data: types::PaymentsSessionData,
@@ -483,6 +317,118 @@ fn create_gpay_session_token(
     }
 }
 
+fn create_click_to_pay_session_token(
+    state: &routes::AppState,
+    router_data: &types::PaymentsSessionRouterData,
+    connector: &api::ConnectorData,
+) -> RouterResult<types::PaymentsSessionRouterData> {
+    let connector_metadata = router_data.connector_meta_data.clone();
+
+    let click_to_pay_data = connector_metadata
+        .clone()
+        .parse_value::<payment_types::ClickToPaySessionTokenData>("ClickToPaySessionTokenData")
+        .change_context(errors::ConnectorError::NoConnectorMetaData)
+        .attach_printable(format!(
+            "cannot parse click to pay metadata from the given value {connector_metadata:?}"
+        ))
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "connector_metadata".to_string(),
+            expected_format: "click_to_pay_metadata_format".to_string(),
+        })?
+        .click_to_pay;
+
+    let transaction_amount = router_data
+        .request
+        .currency
+        .to_currency_base_unit(router_data.request.amount)
+        .attach_printable("Cannot convert given amount to base currency denomination".to_string())
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "amount",
+        })?;
+
+    Ok(types::PaymentsSessionRouterData {
+        response: Ok(types::PaymentsResponseData::SessionResponse {
+            session_token: payment_types::SessionToken::ClickToPay(Box::new(
+                payment_types::ClickToPaySessionResponse {
+                    src_initiator_id: state.conf.click_to_pay.get_inner().src_initiator_id.clone(),
+                    srci_dpa_id: click_to_pay_data.dpa_id,
+                    // The payment is the checkout as far as the card networks are concerned
+                    srci_transaction_id: router_data.payment_id.clone(),
+                    dpa_name: click_to_pay_data.dpa_name,
+                    card_brands: click_to_pay_data.card_brands,
+                    transaction_amount,
+                    transaction_currency_code: router_data.request.currency,
+                    locale: click_to_pay_data.locale,
+                    connector: connector.connector_name.to_string(),
+                    sdk_next_action: payment_types::SdkNextAction {
+                        next_action: payment_types::NextActionCall::Confirm,
+                    },
+                },
+            )),
+        }),
+        ..router_data.clone()
+    })
+}
+
+fn create_samsung_pay_session_token(
+    router_data: &types::PaymentsSessionRouterData,
+    connector: &api::ConnectorData,
+) -> RouterResult<types::PaymentsSessionRouterData> {
+    let connector_metadata = router_data.connector_meta_data.clone();
+
+    let samsung_pay_data = connector_metadata
+        .clone()
+        .parse_value::<payment_types::SamsungPaySessionTokenData>("SamsungPaySessionTokenData")
+        .change_context(errors::ConnectorError::NoConnectorMetaData)
+        .attach_printable(format!(
+            "cannot parse samsung pay metadata from the given value {connector_metadata:?}"
+        ))
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "connector_metadata".to_string(),
+            expected_format: "samsung_pay_metadata_format".to_string(),
+        })?
+        .samsung_pay;
+
+    let amount = router_data
+        .request
+        .currency
+        .to_currency_base_unit(router_data.request.amount)
+        .attach_printable("Cannot convert given amount to base currency denomination".to_string())
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "amount",
+        })?;
+
+    Ok(types::PaymentsSessionRouterData {
+        response: Ok(types::PaymentsResponseData::SessionResponse {
+            session_token: payment_types::SessionToken::SamsungPay(Box::new(
+                payment_types::SamsungPaySessionTokenResponse {
+                    version: SAMSUNG_PAY_SDK_VERSION.to_string(),
+                    service_id: samsung_pay_data.service_id,
+                    order_number: router_data.payment_id.clone(),
+                    merchant_payment_information:
+                        payment_types::SamsungPayMerchantPaymentInformation {
+                            name: samsung_pay_data.merchant_display_name,
+                            url: None,
+                            country_code: samsung_pay_data.merchant_business_country,
+                        },
+                    amount: payment_types::SamsungPayAmountDetails {
+                        amount_format: payment_types::SamsungPayAmountFormat::FormatTotalPriceOnly,
+                        currency_code: router_data.request.currency,
+                        amount,
+                    },
+                    protocol: payment_types::SamsungPayProtocolType::Protocol3ds,
+                    allowed_brands: samsung_pay_data.allowed_brands,
+                    connector: connector.connector_name.to_string(),
+                    sdk_next_action: payment_types::SdkNextAction {
+                        next_action: payment_types::NextActionCall::Confirm,
+                    },
+                },
+            )),
+        }),
+        ..router_data.clone()
+    })
+}
+
 fn is_session_response_delayed(state: &routes::AppState, connector: &api::ConnectorData) -> bool {
     let connectors_with_delayed_response = &state
         .conf
@@ -492,18 +438,6 @@ fn is_session_response_delayed(state: &routes::AppState, connector: &api::Connec
     connectors_with_delayed_response.contains(&connector.connector_name)
 }
 
-fn log_session_response_if_error(
-    response: &Result<Result<types::Response, types::Response>, Report<errors::ApiClientError>>,
-) {
-    if let Err(error) = response.as_ref() {
-        logger::error!(?error);
-    };
-    response
-        .as_ref()
-        .ok()
-        .map(|res| res.as_ref().map_err(|error| logger::error!(?error)));
-}
-
 impl types::PaymentsSessionRouterData {
     pub async fn decide_flow<'a, 'b>(
         &'b self,
@@ -514,6 +448,10 @@ impl types::PaymentsSessionRouterData {
     ) -> RouterResult<Self> {
         match connector.get_token {
             api::GetToken::GpayMetadata => create_gpay_session_token(state, self, connector),
+            api::GetToken::ClickToPayMetadata => {
+                create_click_to_pay_session_token(state, self, connector)
+            }
+            api::GetToken::SamsungPayMetadata => create_samsung_pay_session_token(self, connector),
             api::GetToken::ApplePayMetadata => {
                 create_applepay_session_token(state, self, connector).await
             }
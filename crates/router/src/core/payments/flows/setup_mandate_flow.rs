@@ -130,9 +130,12 @@ impl Feature<api::SetupMandate, types::SetupMandateRequestData> for types::Setup
                     types::PaymentsResponseData,
                 > = connector.connector.get_connector_integration();
 
+                let connectors =
+                    services::api::base_url_override::get_connectors(state, &self.connector).await;
+
                 Ok((
                     connector_integration
-                        .build_request(self, &state.conf.connectors)
+                        .build_request(self, &connectors)
                         .to_payment_failed_response()?,
                     true,
                 ))
@@ -1,8 +1,8 @@
-use std::{borrow::Cow, str::FromStr};
+use std::{borrow::Cow, collections::HashSet, str::FromStr};
 
 use api_models::{
     mandates::RecurringDetails,
-    payments::{CardToken, GetPaymentMethodType, RequestSurchargeDetails},
+    payments::{CardToken, GetPaymentMethodType, L2L3Data, RequestSurchargeDetails, SplitPayments},
 };
 use base64::Engine;
 use common_utils::{
@@ -17,23 +17,18 @@ use hyperswitch_domain_models::{
     payments::{payment_attempt::PaymentAttempt, PaymentIntent},
 };
 use josekit::jwe;
-use masking::{ExposeInterface, PeekInterface};
-use openssl::{
-    derive::Deriver,
-    pkey::PKey,
-    symm::{decrypt_aead, Cipher},
-};
+use masking::{ExposeInterface, PeekInterface, StrongEq};
 use router_env::{instrument, logger, tracing};
 use uuid::Uuid;
-use x509_parser::parse_x509_certificate;
 
 use super::{
     operations::{BoxedOperation, Operation, PaymentResponse},
     CustomerDetails, PaymentData,
 };
 use crate::{
-    configs::settings::{ConnectorRequestReferenceIdConfig, Server, TempLockerEnableConfig},
-    connector,
+    configs::settings::{
+        ConnectorRequestReferenceIdConfig, Overcapture, Server, TempLockerEnableConfig,
+    },
     consts::{self, BASE64_ENGINE},
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
@@ -47,7 +42,9 @@ use crate::{
     services,
     types::{
         self as core_types,
-        api::{self, admin, enums as api_enums, MandateValidationFieldsExt},
+        api::{
+            self, admin, enums as api_enums, payments::PaymentIdTypeExt, MandateValidationFieldsExt,
+        },
         domain::{
             self,
             types::{self, AsyncLift},
@@ -601,6 +598,9 @@ pub async fn get_token_for_recurring_mandate(
     let original_payment_authorized_amount = original_payment_intent.clone().map(|pi| pi.amount);
     let original_payment_authorized_currency =
         original_payment_intent.clone().and_then(|pi| pi.currency);
+    let pre_notification_days = mandate
+        .pre_notification_days
+        .and_then(|days| u8::try_from(days).ok());
 
     let customer = req.customer_id.clone().get_required_value("customer_id")?;
 
@@ -630,6 +630,10 @@ pub async fn get_token_for_recurring_mandate(
 
     let token = Uuid::new_v4().to_string();
     let payment_method_type = payment_method.payment_method_type;
+    let network_transaction_id = mandate
+        .network_transaction_id
+        .clone()
+        .or_else(|| payment_method.network_transaction_id.clone());
     let mandate_connector_details = payments::MandateConnectorDetails {
         connector: mandate.connector,
         merchant_connector_id: mandate.merchant_connector_id,
@@ -668,6 +672,8 @@ pub async fn get_token_for_recurring_mandate(
                 payment_method_type,
                 original_payment_authorized_amount,
                 original_payment_authorized_currency,
+                pre_notification_days,
+                network_transaction_id,
             }),
             payment_method_type: payment_method.payment_method_type,
             mandate_connector: Some(mandate_connector_details),
@@ -682,6 +688,8 @@ pub async fn get_token_for_recurring_mandate(
                 payment_method_type,
                 original_payment_authorized_amount,
                 original_payment_authorized_currency,
+                pre_notification_days,
+                network_transaction_id,
             }),
             payment_method_type: payment_method.payment_method_type,
             mandate_connector: Some(mandate_connector_details),
@@ -750,6 +758,318 @@ pub fn validate_request_amount_and_amount_to_capture(
     }
 }
 
+/// The platform fee and the transfers of the split payments must add up to the amount of the
+/// payment
+pub fn validate_split_payments(
+    amount: Option<api::Amount>,
+    split_payments: Option<&SplitPayments>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let Some(split_payments) = split_payments else {
+        return Ok(());
+    };
+
+    if split_payments.transfers.is_empty() {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "split_payments.transfers should not be empty".to_string(),
+        }))
+    } else if split_payments.platform_fee < 0 {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "split_payments.platform_fee should not be negative".to_string(),
+        }))
+    } else if split_payments
+        .transfers
+        .iter()
+        .any(|transfer| transfer.amount <= 0)
+    {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "split_payments.transfers.amount should be positive".to_string(),
+        }))
+    } else {
+        let amount = amount.map(i64::from).unwrap_or(0);
+        let split_amount = split_payments.platform_fee + split_payments.get_transferred_amount();
+        utils::when(split_amount != amount, || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The platform fee and the transfers of split_payments add up to {split_amount}, which does not match the amount {amount} of the payment"
+                ),
+            }))
+        })
+    }
+}
+
+/// The amounts of the level 2 / level 3 data must be included in the amount of the payment, and
+/// the fields must fit in the limits of the connectors receiving them
+pub fn validate_l2_l3_data(
+    amount: Option<api::Amount>,
+    l2_l3_data: Option<&L2L3Data>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let Some(l2_l3_data) = l2_l3_data else {
+        return Ok(());
+    };
+    let amount = amount.map(i64::from).unwrap_or(0);
+    let invalid_data = |message: String| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message
+        }))
+    };
+
+    for (field_name, field_amount) in [
+        ("tax_amount", l2_l3_data.tax_amount),
+        ("discount_amount", l2_l3_data.discount_amount),
+        ("shipping_amount", l2_l3_data.shipping_amount),
+        ("duty_amount", l2_l3_data.duty_amount),
+    ] {
+        if let Some(field_amount) = field_amount {
+            if field_amount < 0 || field_amount > amount {
+                return invalid_data(format!(
+                    "l2_l3_data.{field_name} should be between 0 and the amount {amount} of the payment"
+                ));
+            }
+        }
+    }
+
+    for (field_name, value, max_length) in [
+        (
+            "purchase_order_number",
+            l2_l3_data.purchase_order_number.as_ref(),
+            25,
+        ),
+        (
+            "ship_from_postal_code",
+            l2_l3_data.ship_from_postal_code.as_ref(),
+            10,
+        ),
+    ] {
+        if value.is_some_and(|value| value.len() > max_length) {
+            return invalid_data(format!(
+                "l2_l3_data.{field_name} should not exceed {max_length} characters"
+            ));
+        }
+    }
+
+    let Some(line_items) = l2_l3_data.line_items.as_ref() else {
+        return Ok(());
+    };
+    if line_items.is_empty() {
+        return invalid_data("l2_l3_data.line_items should not be empty".to_string());
+    }
+    for line_item in line_items {
+        if line_item.quantity == 0 {
+            return invalid_data("l2_l3_data.line_items.quantity should be positive".to_string());
+        }
+        if line_item.unit_amount < 0
+            || line_item
+                .tax_amount
+                .is_some_and(|tax_amount| tax_amount < 0)
+            || line_item
+                .discount_amount
+                .is_some_and(|discount_amount| discount_amount < 0)
+        {
+            return invalid_data(
+                "l2_l3_data.line_items amounts should not be negative".to_string(),
+            );
+        }
+        for (field_name, value, max_length) in [
+            ("product_name", Some(&line_item.product_name), 35),
+            ("commodity_code", line_item.commodity_code.as_ref(), 12),
+            ("product_code", line_item.product_code.as_ref(), 12),
+            ("unit_of_measure", line_item.unit_of_measure.as_ref(), 12),
+        ] {
+            if value.is_some_and(|value| value.len() > max_length) {
+                return invalid_data(format!(
+                    "l2_l3_data.line_items.{field_name} should not exceed {max_length} characters"
+                ));
+            }
+        }
+    }
+
+    let line_items_amount: i64 = line_items
+        .iter()
+        .map(api_models::payments::L3LineItem::get_total_amount)
+        .sum();
+    utils::when(line_items_amount > amount, || {
+        invalid_data(format!(
+            "The line items of l2_l3_data add up to {line_items_amount}, which exceeds the amount {amount} of the payment"
+        ))
+    })
+}
+
+/// Mail order / telephone order payments are made without the customer being present, so they
+/// cannot be authenticated with 3DS
+pub fn validate_payment_channel(
+    request: &api_models::payments::PaymentsRequest,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    if request.payment_channel != Some(api_enums::PaymentChannel::Moto) {
+        return Ok(());
+    }
+
+    if request.authentication_type == Some(api_enums::AuthenticationType::ThreeDs) {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "authentication_type three_ds is not supported for moto payments".to_string(),
+        }))
+    } else if request.request_external_three_ds_authentication == Some(true) {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "request_external_three_ds_authentication is not supported for moto payments"
+                .to_string(),
+        }))
+    } else {
+        Ok(())
+    }
+}
+
+/// The validity of an authorization is extended only to delay its capture, so an extended
+/// authorization cannot be requested for a payment captured automatically
+pub fn validate_extended_authorization_request(
+    request: &api_models::payments::PaymentsRequest,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    if request.request_extended_authorization == Some(true)
+        && !matches!(
+            request.capture_method,
+            Some(api_enums::CaptureMethod::Manual | api_enums::CaptureMethod::ManualMultiple)
+        )
+    {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message:
+                "request_extended_authorization is supported only for payments captured manually"
+                    .to_string(),
+        }))
+    } else {
+        Ok(())
+    }
+}
+
+/// Only the card payments can be paid in installments, over one of the tenures the connectors
+/// offer installment plans for
+pub fn validate_installment_tenure(
+    request: &api_models::payments::PaymentsRequest,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let Some(installment_tenure) = request.installment_tenure else {
+        return Ok(());
+    };
+
+    if !(consts::MIN_INSTALLMENT_TENURE..=consts::MAX_INSTALLMENT_TENURE)
+        .contains(&installment_tenure)
+    {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "installment_tenure should be between {} and {}",
+                consts::MIN_INSTALLMENT_TENURE,
+                consts::MAX_INSTALLMENT_TENURE
+            ),
+        }))
+    } else if request
+        .payment_method
+        .is_some_and(|payment_method| payment_method != api_enums::PaymentMethod::Card)
+    {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "installment_tenure is supported only for card payments".to_string(),
+        }))
+    } else {
+        Ok(())
+    }
+}
+
+/// Card payments authenticated with 3DS by the merchant are only authorized by the connector, so
+/// the authentication data is accepted when the payment is confirmed, unless Hyperswitch is asked
+/// to authenticate the payment as well. The directory server transaction ID is only assigned in
+/// 3DS 2, so the authentications of the retired 3DS 1 are not accepted.
+pub fn validate_external_three_ds_authentication_data(
+    request: &api_models::payments::PaymentsRequest,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let Some(authentication_data) = request.external_three_ds_authentication_data.as_ref() else {
+        return Ok(());
+    };
+    let precondition_failed = |message: &str| {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("external_three_ds_authentication_data {message}"),
+        }))
+    };
+    let invalid_data = |message: &str| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: message.to_string(),
+        }))
+    };
+
+    if request.confirm != Some(true) {
+        return precondition_failed("can only be given when the payment is confirmed");
+    }
+    if request
+        .payment_method
+        .is_some_and(|payment_method| payment_method != api_enums::PaymentMethod::Card)
+    {
+        return precondition_failed("is supported only for card payments");
+    }
+    if request.request_external_three_ds_authentication == Some(true) {
+        return precondition_failed(
+            "cannot be given when request_external_three_ds_authentication is set",
+        );
+    }
+    if request.payment_channel == Some(api_enums::PaymentChannel::Moto) {
+        return precondition_failed("is not supported for moto payments");
+    }
+
+    if authentication_data.cavv.peek().trim().is_empty() {
+        return invalid_data("cavv should not be empty");
+    }
+    if authentication_data
+        .eci
+        .as_ref()
+        .is_some_and(|eci| eci.len() != 2 || !eci.chars().all(|c| c.is_ascii_digit()))
+    {
+        return invalid_data("eci should be 2 digits");
+    }
+    if Uuid::parse_str(&authentication_data.ds_transaction_id).is_err() {
+        return invalid_data("ds_transaction_id should be a UUID");
+    }
+    if authentication_data
+        .three_ds_server_transaction_id
+        .as_ref()
+        .is_some_and(|transaction_id| Uuid::parse_str(transaction_id).is_err())
+    {
+        return invalid_data("three_ds_server_transaction_id should be a UUID");
+    }
+    if authentication_data.message_version.get_major() != 2 {
+        return invalid_data("message_version should be a version of 3DS 2");
+    }
+
+    Ok(())
+}
+
+/// The tags of a payment are matched exactly when listing the payments, so they must not be blank
+/// or repeated
+pub fn validate_payment_tags(tags: &[String]) -> CustomResult<(), errors::ApiErrorResponse> {
+    let invalid_data = |message: String| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message
+        }))
+    };
+
+    if tags.len() > consts::MAX_PAYMENT_TAGS {
+        return invalid_data(format!(
+            "A payment can have at most {} tags",
+            consts::MAX_PAYMENT_TAGS
+        ));
+    }
+
+    let mut seen_tags = HashSet::with_capacity(tags.len());
+    for tag in tags {
+        if tag.trim().is_empty() {
+            return invalid_data("tags should not be empty".to_string());
+        }
+        if tag.len() > consts::MAX_PAYMENT_TAG_LENGTH {
+            return invalid_data(format!(
+                "tags should not exceed {} characters",
+                consts::MAX_PAYMENT_TAG_LENGTH
+            ));
+        }
+        if !seen_tags.insert(tag) {
+            return invalid_data(format!("tag `{tag}` is repeated"));
+        }
+    }
+
+    Ok(())
+}
+
 /// if capture method = automatic, amount_to_capture(if provided) must be equal to amount
 #[instrument(skip_all)]
 pub fn validate_amount_to_capture_and_capture_method(
@@ -894,6 +1214,17 @@ pub fn infer_payment_type(
     }
 }
 
+/// Payments of zero amount which are not paid with a mandate are card verifications, which are
+/// performed with the setup mandate flow of the connector instead of authorizing a zero amount. The
+/// AVS and CVC results of the verification are returned in the payment method data of the payment,
+/// as they are for the authorizations.
+pub fn is_zero_amount_verification(
+    amount: i64,
+    mandate_id: Option<&api_models::payments::MandateIds>,
+) -> bool {
+    amount == 0 && mandate_id.is_none()
+}
+
 pub fn validate_mandate(
     req: impl Into<api::MandateValidationFields>,
     is_confirm_operation: bool,
@@ -960,6 +1291,29 @@ fn validate_new_mandate_request(
         .clone()
         .get_required_value("mandate_data")?;
 
+    // SEPA Direct Debit mandates are only valid with a record of how and when the customer
+    // accepted them, including the IP address of the customer when accepted online
+    if req.payment_method_type == Some(api_enums::PaymentMethodType::Sepa) {
+        match &mandate_data.customer_acceptance {
+            None => Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "mandate_data.customer_acceptance"
+            }))?,
+            Some(customer_acceptance)
+                if customer_acceptance.acceptance_type == api::AcceptanceType::Online
+                    && customer_acceptance
+                        .online
+                        .as_ref()
+                        .and_then(|online| online.ip_address.as_ref())
+                        .is_none() =>
+            {
+                Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "mandate_data.customer_acceptance.online.ip_address"
+                }))?
+            }
+            Some(_) => (),
+        }
+    }
+
     // Only use this validation if the customer_acceptance is present
     if mandate_data
         .customer_acceptance
@@ -1686,32 +2040,28 @@ pub async fn retrieve_payment_method_with_temporary_token(
 
             updated_card.card_holder_name = name_on_card;
 
-            if let Some(token_data) = card_token_data {
-                if let Some(cvc) = token_data.card_cvc.clone() {
-                    is_card_updated = true;
-                    updated_card.card_cvc = cvc;
-                }
-            }
-
             if is_card_updated {
-                let updated_pm = api::PaymentMethodData::Card(updated_card);
                 vault::Vault::store_payment_method_data_in_locker(
                     state,
                     Some(token.to_owned()),
-                    &updated_pm,
+                    &api::PaymentMethodData::Card(updated_card.clone()),
                     payment_intent.customer_id.to_owned(),
                     enums::PaymentMethod::Card,
                     merchant_key_store,
                 )
                 .await?;
+            }
 
-                Some((updated_pm, enums::PaymentMethod::Card))
-            } else {
-                Some((
-                    api::PaymentMethodData::Card(card),
-                    enums::PaymentMethod::Card,
-                ))
+            // The CVC collected with the token is only passed to the connector, and not stored
+            // with the card in the locker
+            if let Some(cvc) = card_token_data.and_then(|token_data| token_data.card_cvc.clone()) {
+                updated_card.card_cvc = cvc;
             }
+
+            Some((
+                api::PaymentMethodData::Card(updated_card),
+                enums::PaymentMethod::Card,
+            ))
         }
 
         Some(the_pm @ api::PaymentMethodData::Wallet(_)) => {
@@ -1934,6 +2284,14 @@ pub async fn make_pm_data<'a, F: Clone, R, Ctx: PaymentMethodRetrieve>(
     // TODO: Handle case where payment method and token both are present in request properly.
     let (payment_method, pm_id) = match (request, payment_data.token_data.as_ref()) {
         (_, Some(hyperswitch_token)) => {
+            validate_saved_card_cvc(
+                state,
+                payment_data,
+                hyperswitch_token,
+                card_token_data.as_ref(),
+            )
+            .await?;
+
             let pm_data = Ctx::retrieve_payment_method_with_token(
                 state,
                 merchant_key_store,
@@ -1981,6 +2339,47 @@ pub async fn make_pm_data<'a, F: Clone, R, Ctx: PaymentMethodRetrieve>(
     Ok((operation, payment_method, pm_id))
 }
 
+/// Require the CVC of saved cards to be passed with their payment token for the customer
+/// initiated payments of the business profiles requiring it
+async fn validate_saved_card_cvc<F: Clone>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+    token_data: &storage::PaymentTokenData,
+    card_token_data: Option<&CardToken>,
+) -> RouterResult<()> {
+    let is_saved_card =
+        match token_data {
+            storage::PaymentTokenData::PermanentCard(_)
+            | storage::PaymentTokenData::Permanent(_) => true,
+            storage::PaymentTokenData::TemporaryGeneric(_)
+            | storage::PaymentTokenData::Temporary(_) => {
+                payment_data.payment_attempt.payment_method == Some(enums::PaymentMethod::Card)
+            }
+            storage::PaymentTokenData::AuthBankDebit(_)
+            | storage::PaymentTokenData::WalletToken(_) => false,
+        };
+    let is_merchant_initiated =
+        payment_data.payment_intent.off_session == Some(true) || payment_data.mandate_id.is_some();
+    let is_cvc_passed = card_token_data
+        .and_then(|card_token_data| card_token_data.card_cvc.as_ref())
+        .is_some_and(|card_cvc| !card_cvc.peek().is_empty());
+    if !is_saved_card || is_merchant_initiated || is_cvc_passed {
+        return Ok(());
+    }
+
+    let Some(profile_id) = payment_data.payment_intent.profile_id.as_deref() else {
+        return Ok(());
+    };
+    let requires_cvc = cards::get_requires_cvc_for_saved_cards(&*state.store, profile_id)
+        .await?
+        .unwrap_or(false);
+    utils::when(requires_cvc, || {
+        Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "card_cvc"
+        }))
+    })
+}
+
 pub async fn store_in_vault_and_generate_ppmt(
     state: &AppState,
     payment_method_data: &api_models::payments::PaymentMethodData,
@@ -2125,6 +2524,118 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+/// The amount of a payment can be decreased at capture, such as for a discount applied after the
+/// authorization or for the items out of stock, by capturing less than the amount capturable.
+/// The reason of the adjustment is required, and the adjusted order details are optional.
+pub(crate) fn validate_capture_adjustment(
+    request: &api_models::payments::PaymentsCaptureRequest,
+    amount_capturable: i64,
+) -> RouterResult<()> {
+    let invalid_data = |message: String| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message
+        }))
+    };
+
+    if request.capture_adjustment_reason.is_none() {
+        return if request.order_details.is_some() {
+            invalid_data(
+                "capture_adjustment_reason is required when the order details are adjusted"
+                    .to_string(),
+            )
+        } else {
+            Ok(())
+        };
+    }
+
+    let Some(amount_to_capture) = request
+        .amount_to_capture
+        .filter(|amount_to_capture| *amount_to_capture < amount_capturable)
+    else {
+        return invalid_data(format!(
+            "amount_to_capture should be less than the amount capturable {amount_capturable} when capture_adjustment_reason is provided"
+        ));
+    };
+
+    let Some(order_details) = request.order_details.as_ref() else {
+        return Ok(());
+    };
+    if order_details.is_empty() {
+        return invalid_data("order_details should not be empty".to_string());
+    }
+    let order_amount: i64 = order_details
+        .iter()
+        .map(|order_detail| order_detail.amount * i64::from(order_detail.quantity))
+        .sum();
+    if order_amount > amount_to_capture {
+        return invalid_data(format!(
+            "The order details add up to {order_amount}, which exceeds the amount to capture {amount_to_capture}"
+        ));
+    }
+
+    Ok(())
+}
+
+/// The maximum amount which can be captured from the authorized amount. The authorized amount can
+/// be exceeded by the overcapture limit of the connector, if the payment requested an overcapture.
+pub(crate) fn get_max_amount_to_capture(
+    overcapture: &Overcapture,
+    request_overcapture: Option<bool>,
+    connector: Option<&str>,
+    authorized_amount: i64,
+) -> i64 {
+    let max_overcapture_percentage = connector
+        .filter(|_| request_overcapture == Some(true))
+        .and_then(|connector| overcapture.connectors.get(connector))
+        .map(|overcapture_config| i64::from(overcapture_config.max_overcapture_percentage))
+        .unwrap_or(0);
+
+    authorized_amount
+        .saturating_add(authorized_amount.saturating_mul(max_overcapture_percentage) / 100)
+}
+
+/// The amount captured above the authorized amount of the payment, if it was overcaptured
+pub(crate) fn get_overcaptured_amount(
+    amount_captured: Option<i64>,
+    authorized_amount: i64,
+) -> Option<i64> {
+    amount_captured
+        .map(|amount_captured| amount_captured.saturating_sub(authorized_amount))
+        .filter(|overcaptured_amount| *overcaptured_amount > 0)
+}
+
+/// The amount of a partial capture, along with the amounts of the previous captures which are
+/// either charged or pending, must not exceed the authorized amount
+#[instrument(skip_all)]
+pub(crate) fn validate_amount_to_capture_against_previous_captures(
+    authorized_amount: i64,
+    previous_captures: &[storage::Capture],
+    amount_to_capture: i64,
+) -> RouterResult<()> {
+    let total_blocked_amount: i64 = previous_captures
+        .iter()
+        .filter(|capture| {
+            matches!(
+                capture.status,
+                storage_enums::CaptureStatus::Charged | storage_enums::CaptureStatus::Pending
+            )
+        })
+        .map(|capture| capture.amount)
+        .sum();
+
+    utils::when(
+        total_blocked_amount + amount_to_capture > authorized_amount,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "amount_to_capture exceeds the remaining authorized amount of {}",
+                    authorized_amount - total_blocked_amount
+                )
+            }))
+        },
+    )
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
     req: &api::PaymentsRequest,
@@ -2258,6 +2769,9 @@ pub fn validate_payment_method_type_against_payment_method(
                 | api_enums::PaymentMethodType::Momo
                 | api_enums::PaymentMethodType::KakaoPay
                 | api_enums::PaymentMethodType::Cashapp
+                | api_enums::PaymentMethodType::ClickToPay
+                | api_enums::PaymentMethodType::Skrill
+                | api_enums::PaymentMethodType::Payoneer
         ),
         api_enums::PaymentMethod::BankRedirect => matches!(
             payment_method_type,
@@ -2338,6 +2852,12 @@ pub fn validate_payment_method_type_against_payment_method(
                 api_enums::PaymentMethodType::Givex | api_enums::PaymentMethodType::PaySafeCard
             )
         }
+        api_enums::PaymentMethod::RealTimePayment => matches!(
+            payment_method_type,
+            api_enums::PaymentMethodType::PayNow
+                | api_enums::PaymentMethodType::PromptPay
+                | api_enums::PaymentMethodType::UpiQr
+        ),
         api_enums::PaymentMethod::CardRedirect => matches!(
             payment_method_type,
             api_enums::PaymentMethodType::Knet
@@ -2649,6 +3169,15 @@ pub fn generate_mandate(
             let customer_acceptance = data
                 .customer_acceptance
                 .get_required_value("customer_acceptance")?;
+            let pre_notification_days = data.pre_notification_days.or_else(|| {
+                matches!(
+                    payment_method_data_option,
+                    Some(domain::payments::PaymentMethodData::BankDebit(
+                        domain::payments::BankDebitData::SepaBankDebit { .. }
+                    ))
+                )
+                .then_some(consts::DEFAULT_SEPA_PRE_NOTIFICATION_DAYS)
+            });
             new_mandate
                 .set_mandate_id(mandate_id)
                 .set_customer_id(cus_id.clone())
@@ -2666,6 +3195,7 @@ pub fn generate_mandate(
                 )
                 .set_customer_user_agent(customer_acceptance.get_user_agent())
                 .set_customer_accepted_at(Some(customer_acceptance.get_accepted_at()))
+                .set_pre_notification_days(pre_notification_days.map(i16::from))
                 .set_metadata(payment_method_data_option.map(|payment_method_data| {
                     pii::SecretSerdeValue::new(
                         serde_json::to_value(payment_method_data).unwrap_or_default(),
@@ -2714,7 +3244,7 @@ pub fn authenticate_client_secret(
 ) -> Result<(), errors::ApiErrorResponse> {
     match (request_client_secret, &payment_intent.client_secret) {
         (Some(req_cs), Some(pi_cs)) => {
-            if req_cs != pi_cs {
+            if !req_cs.strong_eq(pi_cs) {
                 Err(errors::ApiErrorResponse::ClientSecretInvalid)
             } else {
                 let current_timestamp = common_utils::date_time::now();
@@ -2750,6 +3280,27 @@ pub(crate) fn validate_payment_status_against_allowed_statuses(
     })
 }
 
+/// The voids of the payments which are partially captured do not cancel the payments, and instead
+/// reverse the part of the authorized amount which remains uncaptured
+pub fn is_partial_reversal(intent_status: storage_enums::IntentStatus) -> bool {
+    matches!(
+        intent_status,
+        storage_enums::IntentStatus::PartiallyCaptured
+            | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+    )
+}
+
+/// The part of the authorized amount of a partially captured payment which is neither captured
+/// nor released yet
+pub fn get_uncaptured_amount(
+    payment_intent: &PaymentIntent,
+    payment_attempt: &PaymentAttempt,
+) -> i64 {
+    payment_attempt.get_total_amount()
+        - payment_intent.amount_captured.unwrap_or(0)
+        - payment_attempt.amount_reversed.unwrap_or(0)
+}
+
 pub(crate) fn validate_payment_status_against_not_allowed_statuses(
     intent_status: &storage_enums::IntentStatus,
     not_allowed_statuses: &[storage_enums::IntentStatus],
@@ -2947,6 +3498,15 @@ mod tests {
                     .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
             ),
             request_external_three_ds_authentication: None,
+            request_overcapture: None,
+            request_extended_authorization: None,
+            order_tax_amount: None,
+            split_payments: None,
+            payment_channel: None,
+            l2_l3_data: None,
+            tags: None,
+            capture_adjustment_reason: None,
+            installment_tenure: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
@@ -3005,6 +3565,15 @@ mod tests {
                     .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
             ),
             request_external_three_ds_authentication: None,
+            request_overcapture: None,
+            request_extended_authorization: None,
+            order_tax_amount: None,
+            split_payments: None,
+            payment_channel: None,
+            l2_l3_data: None,
+            tags: None,
+            capture_adjustment_reason: None,
+            installment_tenure: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
@@ -3062,10 +3631,347 @@ mod tests {
                     .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
             ),
             request_external_three_ds_authentication: None,
+            request_overcapture: None,
+            request_extended_authorization: None,
+            order_tax_amount: None,
+            split_payments: None,
+            payment_channel: None,
+            l2_l3_data: None,
+            tags: None,
+            capture_adjustment_reason: None,
+            installment_tenure: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
     }
+
+    #[test]
+    fn test_validate_split_payments() {
+        let amount = Some(api::Amount::from(6540));
+        let transfer = |amount| api_models::payments::SplitPaymentTransfer {
+            account_id: "acct_1NcUqnDBtvAbbJWv".to_string(),
+            amount,
+            reference: None,
+        };
+        let split_payments = SplitPayments {
+            platform_fee: 500,
+            transfers: vec![transfer(4000), transfer(2040)],
+        };
+        assert!(validate_split_payments(amount, Some(&split_payments)).is_ok());
+
+        let split_payments = SplitPayments {
+            platform_fee: 500,
+            transfers: vec![transfer(4000), transfer(2000)],
+        };
+        assert!(validate_split_payments(amount, Some(&split_payments)).is_err());
+
+        let split_payments = SplitPayments {
+            platform_fee: 6540,
+            transfers: vec![],
+        };
+        assert!(validate_split_payments(amount, Some(&split_payments)).is_err());
+    }
+
+    #[test]
+    fn test_validate_l2_l3_data() {
+        let amount = Some(api::Amount::from(6540));
+        let line_item = |quantity, unit_amount| api_models::payments::L3LineItem {
+            product_name: "Printer paper".to_string(),
+            quantity,
+            unit_amount,
+            commodity_code: Some("44121618".to_string()),
+            product_code: None,
+            unit_of_measure: Some("EA".to_string()),
+            tax_amount: Some(175),
+            discount_amount: None,
+        };
+        let l2_l3_data = L2L3Data {
+            purchase_order_number: Some("PO-2024-00042".to_string()),
+            tax_amount: Some(350),
+            line_items: Some(vec![line_item(2, 1250), line_item(1, 3340)]),
+            ..Default::default()
+        };
+        assert!(validate_l2_l3_data(amount, Some(&l2_l3_data)).is_ok());
+
+        let l2_l3_data = L2L3Data {
+            tax_amount: Some(-1),
+            ..Default::default()
+        };
+        assert!(validate_l2_l3_data(amount, Some(&l2_l3_data)).is_err());
+
+        let l2_l3_data = L2L3Data {
+            line_items: Some(vec![line_item(0, 1250)]),
+            ..Default::default()
+        };
+        assert!(validate_l2_l3_data(amount, Some(&l2_l3_data)).is_err());
+
+        let l2_l3_data = L2L3Data {
+            line_items: Some(vec![line_item(6, 1250)]),
+            ..Default::default()
+        };
+        assert!(validate_l2_l3_data(amount, Some(&l2_l3_data)).is_err());
+    }
+
+    #[test]
+    fn test_validate_payment_tags() {
+        let tags = |tags: &[&str]| tags.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        assert!(validate_payment_tags(&tags(&["black_friday", "web"])).is_ok());
+        assert!(validate_payment_tags(&tags(&["black_friday", " "])).is_err());
+        assert!(validate_payment_tags(&tags(&["web", "black_friday", "web"])).is_err());
+        assert!(validate_payment_tags(&["a".repeat(consts::MAX_PAYMENT_TAG_LENGTH + 1)]).is_err());
+        assert!(validate_payment_tags(
+            &(0..=consts::MAX_PAYMENT_TAGS)
+                .map(|index| format!("tag_{index}"))
+                .collect::<Vec<_>>()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_payment_channel() {
+        let request = api_models::payments::PaymentsRequest {
+            payment_channel: Some(api_enums::PaymentChannel::Moto),
+            ..Default::default()
+        };
+        assert!(validate_payment_channel(&request).is_ok());
+
+        let request = api_models::payments::PaymentsRequest {
+            payment_channel: Some(api_enums::PaymentChannel::Moto),
+            authentication_type: Some(api_enums::AuthenticationType::ThreeDs),
+            ..Default::default()
+        };
+        assert!(validate_payment_channel(&request).is_err());
+
+        let request = api_models::payments::PaymentsRequest {
+            payment_channel: Some(api_enums::PaymentChannel::Ecommerce),
+            authentication_type: Some(api_enums::AuthenticationType::ThreeDs),
+            ..Default::default()
+        };
+        assert!(validate_payment_channel(&request).is_ok());
+    }
+
+    #[test]
+    fn test_validate_extended_authorization_request() {
+        let request = api_models::payments::PaymentsRequest {
+            request_extended_authorization: Some(true),
+            capture_method: Some(api_enums::CaptureMethod::Manual),
+            ..Default::default()
+        };
+        assert!(validate_extended_authorization_request(&request).is_ok());
+
+        let request = api_models::payments::PaymentsRequest {
+            request_extended_authorization: Some(true),
+            ..Default::default()
+        };
+        assert!(validate_extended_authorization_request(&request).is_err());
+
+        let request = api_models::payments::PaymentsRequest {
+            request_extended_authorization: Some(false),
+            capture_method: Some(api_enums::CaptureMethod::Automatic),
+            ..Default::default()
+        };
+        assert!(validate_extended_authorization_request(&request).is_ok());
+    }
+
+    #[test]
+    fn test_validate_installment_tenure() {
+        let request = api_models::payments::PaymentsRequest {
+            installment_tenure: Some(6),
+            payment_method: Some(api_enums::PaymentMethod::Card),
+            ..Default::default()
+        };
+        assert!(validate_installment_tenure(&request).is_ok());
+
+        let request = api_models::payments::PaymentsRequest {
+            installment_tenure: Some(1),
+            ..Default::default()
+        };
+        assert!(validate_installment_tenure(&request).is_err());
+
+        let request = api_models::payments::PaymentsRequest {
+            installment_tenure: Some(6),
+            payment_method: Some(api_enums::PaymentMethod::BankRedirect),
+            ..Default::default()
+        };
+        assert!(validate_installment_tenure(&request).is_err());
+    }
+
+    #[test]
+    fn test_validate_external_three_ds_authentication_data() {
+        let authentication_data = api_models::payments::ExternalThreeDsAuthenticationData {
+            cavv: masking::Secret::new("AAABBZV3cGEAAAAAJXdwYQAAAAA=".to_string()),
+            eci: Some("05".to_string()),
+            ds_transaction_id: "f25084f0-5b16-4c0a-ae5d-b24808a95e4b".to_string(),
+            three_ds_server_transaction_id: None,
+            message_version: common_utils::types::SemanticVersion::new(2, 2, 0),
+        };
+        let request = |authentication_data| api_models::payments::PaymentsRequest {
+            confirm: Some(true),
+            payment_method: Some(api_enums::PaymentMethod::Card),
+            external_three_ds_authentication_data: Some(authentication_data),
+            ..Default::default()
+        };
+
+        assert!(validate_external_three_ds_authentication_data(&request(
+            authentication_data.clone()
+        ))
+        .is_ok());
+        assert!(validate_external_three_ds_authentication_data(
+            &api_models::payments::PaymentsRequest {
+                confirm: Some(false),
+                ..request(authentication_data.clone())
+            }
+        )
+        .is_err());
+        assert!(validate_external_three_ds_authentication_data(
+            &api_models::payments::PaymentsRequest {
+                request_external_three_ds_authentication: Some(true),
+                ..request(authentication_data.clone())
+            }
+        )
+        .is_err());
+
+        for invalid_authentication_data in [
+            api_models::payments::ExternalThreeDsAuthenticationData {
+                eci: Some("5".to_string()),
+                ..authentication_data.clone()
+            },
+            api_models::payments::ExternalThreeDsAuthenticationData {
+                ds_transaction_id: "ds_transaction_id".to_string(),
+                ..authentication_data.clone()
+            },
+            api_models::payments::ExternalThreeDsAuthenticationData {
+                message_version: common_utils::types::SemanticVersion::new(1, 0, 2),
+                ..authentication_data.clone()
+            },
+        ] {
+            assert!(validate_external_three_ds_authentication_data(&request(
+                invalid_authentication_data
+            ))
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_capture_adjustment() {
+        let order_detail = |amount| api_models::payments::OrderDetailsWithAmount {
+            product_name: "shirt".to_string(),
+            quantity: 2,
+            amount,
+            ..Default::default()
+        };
+        let request =
+            |amount_to_capture, order_details| api_models::payments::PaymentsCaptureRequest {
+                amount_to_capture,
+                capture_adjustment_reason: Some(api_enums::CaptureAdjustmentReason::OutOfStock),
+                order_details,
+                ..Default::default()
+            };
+
+        assert!(validate_capture_adjustment(&request(Some(4000), None), 6000).is_ok());
+        assert!(validate_capture_adjustment(
+            &request(Some(4000), Some(vec![order_detail(2000)])),
+            6000
+        )
+        .is_ok());
+        assert!(validate_capture_adjustment(
+            &request(Some(4000), Some(vec![order_detail(2500)])),
+            6000
+        )
+        .is_err());
+        assert!(validate_capture_adjustment(&request(Some(6000), None), 6000).is_err());
+        assert!(validate_capture_adjustment(&request(None, None), 6000).is_err());
+        assert!(validate_capture_adjustment(
+            &api_models::payments::PaymentsCaptureRequest {
+                amount_to_capture: Some(4000),
+                order_details: Some(vec![order_detail(2000)]),
+                ..Default::default()
+            },
+            6000
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_get_max_amount_to_capture() {
+        let overcapture = Overcapture {
+            connectors: std::collections::HashMap::from([(
+                "stripe".to_string(),
+                crate::configs::settings::OvercaptureConnectorConfig {
+                    max_overcapture_percentage: 20,
+                },
+            )]),
+        };
+
+        assert_eq!(
+            get_max_amount_to_capture(&overcapture, Some(true), Some("stripe"), 1000),
+            1200
+        );
+        // The overcapture limit is rounded down, so that it is never exceeded
+        assert_eq!(
+            get_max_amount_to_capture(&overcapture, Some(true), Some("stripe"), 1999),
+            2398
+        );
+        assert_eq!(
+            get_max_amount_to_capture(&overcapture, Some(true), Some("stripe"), 4),
+            4
+        );
+        assert_eq!(
+            get_max_amount_to_capture(&overcapture, Some(false), Some("stripe"), 1000),
+            1000
+        );
+        assert_eq!(
+            get_max_amount_to_capture(&overcapture, None, Some("stripe"), 1000),
+            1000
+        );
+        assert_eq!(
+            get_max_amount_to_capture(&overcapture, Some(true), Some("adyen"), 1000),
+            1000
+        );
+        assert_eq!(
+            get_max_amount_to_capture(&overcapture, Some(true), None, 1000),
+            1000
+        );
+    }
+
+    #[test]
+    fn test_get_overcaptured_amount() {
+        assert_eq!(get_overcaptured_amount(Some(1200), 1000), Some(200));
+        assert_eq!(get_overcaptured_amount(Some(1000), 1000), None);
+        assert_eq!(get_overcaptured_amount(Some(800), 1000), None);
+        assert_eq!(get_overcaptured_amount(None, 1000), None);
+    }
+
+    #[test]
+    fn test_is_zero_amount_verification() {
+        let mandate_id = api_models::payments::MandateIds {
+            mandate_id: Some("man_1".to_string()),
+            mandate_reference_id: None,
+        };
+
+        assert!(is_zero_amount_verification(0, None));
+        assert!(!is_zero_amount_verification(1000, None));
+        assert!(!is_zero_amount_verification(0, Some(&mandate_id)));
+        assert!(!is_zero_amount_verification(1000, Some(&mandate_id)));
+    }
+
+    #[test]
+    fn test_validate_manual_retry_status() {
+        assert!(validate_manual_retry_status(storage_enums::IntentStatus::Failed).is_ok());
+        assert!(validate_manual_retry_status(storage_enums::IntentStatus::Succeeded).is_err());
+        assert!(
+            validate_manual_retry_status(storage_enums::IntentStatus::RequiresCapture).is_err()
+        );
+        assert!(validate_manual_retry_status(storage_enums::IntentStatus::Processing).is_err());
+    }
+
+    #[test]
+    fn test_validate_manual_retry_payment_method() {
+        assert!(validate_manual_retry_payment_method(Some("token_1"), None).is_ok());
+        assert!(validate_manual_retry_payment_method(None, Some("man_1")).is_ok());
+        assert!(validate_manual_retry_payment_method(None, None).is_err());
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -3282,6 +4188,7 @@ pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
         connector_api_version: router_data.connector_api_version,
         connector_http_status_code: router_data.connector_http_status_code,
         external_latency: router_data.external_latency,
+        connector_request_id: router_data.connector_request_id,
         apple_pay_flow: router_data.apple_pay_flow,
         frm_metadata: router_data.frm_metadata,
         refund_id: router_data.refund_id,
@@ -3387,7 +4294,8 @@ pub fn get_attempt_type(
         | enums::IntentStatus::PartiallyCaptured
         | enums::IntentStatus::PartiallyCapturedAndCapturable
         | enums::IntentStatus::Processing
-        | enums::IntentStatus::Succeeded => {
+        | enums::IntentStatus::Succeeded
+        | enums::IntentStatus::Expired => {
             Err(report!(errors::ApiErrorResponse::PreconditionFailed {
                 message: format!(
                     "You cannot {action} this payment because it has status {}",
@@ -3494,6 +4402,11 @@ impl AttemptType {
             // New payment method billing address can be passed for a retry
             payment_method_billing_address_id: None,
             fingerprint_id: None,
+            connector_request_id: None,
+            extended_authorization_applied: None,
+            capture_before: None,
+            amount_reversed: None,
+            overcaptured_amount: None,
         }
     }
 
@@ -3562,6 +4475,103 @@ impl AttemptType {
     }
 }
 
+pub fn validate_manual_retry_status(
+    status: enums::IntentStatus,
+) -> Result<(), errors::ApiErrorResponse> {
+    if status != enums::IntentStatus::Failed {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("You cannot retry this payment because it has status {status}"),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// The payment method of the failed attempt can be used again only if it was saved by a token or
+/// by a mandate, as the payment method data itself is not stored with the attempt
+pub fn validate_manual_retry_payment_method(
+    payment_token: Option<&str>,
+    mandate_id: Option<&str>,
+) -> Result<(), errors::ApiErrorResponse> {
+    if payment_token.is_none() && mandate_id.is_none() {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "You cannot retry this payment with the payment method of its failed attempt because the payment method was not saved, pass a payment_method_data or a payment_token to retry it".to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Build the confirm request which retries a failed payment with a new attempt. The payment is
+/// retried with the payment method of the failed attempt, unless a new payment method is passed.
+#[instrument(skip_all)]
+pub async fn get_manual_retry_request(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    request: api::PaymentsRetryRequest,
+) -> RouterResult<api::PaymentsRequest> {
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &request.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    validate_manual_retry_status(payment_intent.status)?;
+
+    let failed_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            &merchant_account.merchant_id,
+            payment_intent.active_attempt.get_id().as_str(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let is_new_payment_method =
+        request.payment_method_data.is_some() || request.payment_token.is_some();
+    let (payment_method, payment_method_type, payment_token, mandate_id) = if is_new_payment_method
+    {
+        (
+            request.payment_method,
+            request.payment_method_type,
+            request.payment_token,
+            None,
+        )
+    } else {
+        validate_manual_retry_payment_method(
+            failed_attempt.payment_token.as_deref(),
+            failed_attempt.mandate_id.as_deref(),
+        )?;
+        (
+            failed_attempt.payment_method,
+            failed_attempt.payment_method_type,
+            failed_attempt.payment_token,
+            failed_attempt.mandate_id,
+        )
+    };
+
+    Ok(api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(
+            payment_intent.payment_id,
+        )),
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        confirm: Some(true),
+        retry_action: Some(api_models::enums::RetryAction::ManualRetry),
+        connector: request.connector.map(|connector| vec![connector]),
+        payment_method_data: request.payment_method_data,
+        payment_method,
+        payment_method_type,
+        payment_token,
+        mandate_id,
+        browser_info: request.browser_info.or(failed_attempt.browser_info),
+        ..Default::default()
+    })
+}
+
 #[inline(always)]
 pub fn is_manual_retry_allowed(
     intent_status: &storage_enums::IntentStatus,
@@ -3606,7 +4616,8 @@ pub fn is_manual_retry_allowed(
         | enums::IntentStatus::PartiallyCaptured
         | enums::IntentStatus::PartiallyCapturedAndCapturable
         | enums::IntentStatus::Processing
-        | enums::IntentStatus::Succeeded => Some(false),
+        | enums::IntentStatus::Succeeded
+        | enums::IntentStatus::Expired => Some(false),
 
         enums::IntentStatus::RequiresCustomerAction
         | enums::IntentStatus::RequiresMerchantAction
@@ -3797,6 +4808,9 @@ pub async fn get_additional_payment_data(
         api_models::payments::PaymentMethodData::GiftCard(_) => {
             api_models::payments::AdditionalPaymentData::GiftCard {}
         }
+        api_models::payments::PaymentMethodData::RealTimePayment(_) => {
+            api_models::payments::AdditionalPaymentData::RealTimePayment {}
+        }
         api_models::payments::PaymentMethodData::CardToken(_) => {
             api_models::payments::AdditionalPaymentData::CardToken {}
         }
@@ -3819,176 +4833,43 @@ pub fn validate_customer_access(
     Ok(())
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
-pub struct ApplePayData {
-    version: masking::Secret<String>,
-    data: masking::Secret<String>,
-    signature: masking::Secret<String>,
-    header: ApplePayHeader,
-}
-
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ApplePayHeader {
-    ephemeral_public_key: masking::Secret<String>,
-    public_key_hash: masking::Secret<String>,
-    transaction_id: masking::Secret<String>,
-}
-
-impl ApplePayData {
-    pub fn token_json(
-        wallet_data: domain::WalletData,
-    ) -> CustomResult<Self, errors::ConnectorError> {
-        let json_wallet_data: Self = connector::utils::WalletData::get_wallet_token_as_json(
-            &wallet_data,
-            "Apple Pay".to_string(),
-        )?;
-        Ok(json_wallet_data)
-    }
-
-    pub async fn decrypt(
-        &self,
-        state: &AppState,
-    ) -> CustomResult<serde_json::Value, errors::ApplePayDecryptionError> {
-        let merchant_id = self.merchant_id(state).await?;
-        let shared_secret = self.shared_secret(state).await?;
-        let symmetric_key = self.symmetric_key(&merchant_id, &shared_secret)?;
-        let decrypted = self.decrypt_ciphertext(&symmetric_key)?;
-        let parsed_decrypted: serde_json::Value = serde_json::from_str(&decrypted)
-            .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?;
-        Ok(parsed_decrypted)
-    }
-
-    pub async fn merchant_id(
-        &self,
-        state: &AppState,
-    ) -> CustomResult<String, errors::ApplePayDecryptionError> {
-        let cert_data = state
-            .conf
-            .applepay_decrypt_keys
-            .get_inner()
-            .apple_pay_ppc
-            .clone()
-            .expose();
-
-        let base64_decode_cert_data = BASE64_ENGINE
-            .decode(cert_data)
-            .change_context(errors::ApplePayDecryptionError::Base64DecodingFailed)?;
-
-        // Parsing the certificate using x509-parser
-        let (_, certificate) = parse_x509_certificate(&base64_decode_cert_data)
-            .change_context(errors::ApplePayDecryptionError::CertificateParsingFailed)
-            .attach_printable("Error parsing apple pay PPC")?;
-
-        // Finding the merchant ID extension
-        let apple_pay_m_id = certificate
-            .extensions()
-            .iter()
-            .find(|extension| {
-                extension
-                    .oid
-                    .to_string()
-                    .eq(consts::MERCHANT_ID_FIELD_EXTENSION_ID)
-            })
-            .map(|ext| {
-                let merchant_id = String::from_utf8_lossy(ext.value)
-                    .trim()
-                    .trim_start_matches('@')
-                    .to_string();
-
-                merchant_id
-            })
-            .ok_or(errors::ApplePayDecryptionError::MissingMerchantId)
-            .attach_printable("Unable to find merchant ID extension in the certificate")?;
-
-        Ok(apple_pay_m_id)
-    }
-
-    pub async fn shared_secret(
-        &self,
-        state: &AppState,
-    ) -> CustomResult<Vec<u8>, errors::ApplePayDecryptionError> {
-        let public_ec_bytes = BASE64_ENGINE
-            .decode(self.header.ephemeral_public_key.peek().as_bytes())
-            .change_context(errors::ApplePayDecryptionError::Base64DecodingFailed)?;
-
-        let public_key = PKey::public_key_from_der(&public_ec_bytes)
-            .change_context(errors::ApplePayDecryptionError::KeyDeserializationFailed)
-            .attach_printable("Failed to deserialize the public key")?;
-
-        let decrypted_apple_pay_ppc_key = state
-            .conf
-            .applepay_decrypt_keys
-            .get_inner()
-            .apple_pay_ppc_key
-            .clone()
-            .expose();
-
-        // Create PKey objects from EcKey
-        let private_key = PKey::private_key_from_pem(decrypted_apple_pay_ppc_key.as_bytes())
-            .change_context(errors::ApplePayDecryptionError::KeyDeserializationFailed)
-            .attach_printable("Failed to deserialize the private key")?;
-
-        // Create the Deriver object and set the peer public key
-        let mut deriver = Deriver::new(&private_key)
-            .change_context(errors::ApplePayDecryptionError::DerivingSharedSecretKeyFailed)
-            .attach_printable("Failed to create a deriver for the private key")?;
-
-        deriver
-            .set_peer(&public_key)
-            .change_context(errors::ApplePayDecryptionError::DerivingSharedSecretKeyFailed)
-            .attach_printable("Failed to set the peer key for the secret derivation")?;
-
-        // Compute the shared secret
-        let shared_secret = deriver
-            .derive_to_vec()
-            .change_context(errors::ApplePayDecryptionError::DerivingSharedSecretKeyFailed)
-            .attach_printable("Final key derivation failed")?;
-        Ok(shared_secret)
-    }
-
-    pub fn symmetric_key(
-        &self,
-        merchant_id: &str,
-        shared_secret: &[u8],
-    ) -> CustomResult<Vec<u8>, errors::ApplePayDecryptionError> {
-        let kdf_algorithm = b"\x0did-aes256-GCM";
-        let kdf_party_v = hex::decode(merchant_id)
-            .change_context(errors::ApplePayDecryptionError::Base64DecodingFailed)?;
-        let kdf_party_u = b"Apple";
-        let kdf_info = [&kdf_algorithm[..], kdf_party_u, &kdf_party_v[..]].concat();
-
-        let mut hash = openssl::sha::Sha256::new();
-        hash.update(b"\x00\x00\x00");
-        hash.update(b"\x01");
-        hash.update(shared_secret);
-        hash.update(&kdf_info[..]);
-        let symmetric_key = hash.finish();
-        Ok(symmetric_key.to_vec())
+/// Raw card data sent from the server of the merchant is only accepted if the merchant account is
+/// marked as PCI compliant, when the deployment requires it. Card data collected by the SDK is not
+/// handled by the merchant, so it is always accepted.
+pub fn validate_raw_card_data_access(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    auth_flow: services::AuthFlow,
+    request: &api::PaymentsRequest,
+) -> RouterResult<()> {
+    let is_raw_card_data = matches!(
+        request
+            .payment_method_data
+            .as_ref()
+            .and_then(|payment_method_data| payment_method_data.payment_method_data.as_ref()),
+        Some(api::PaymentMethodData::Card(_))
+    );
+    if !state.conf.raw_card_data.pci_compliance_required
+        || auth_flow != services::AuthFlow::Merchant
+        || !is_raw_card_data
+    {
+        return Ok(());
     }
 
-    pub fn decrypt_ciphertext(
-        &self,
-        symmetric_key: &[u8],
-    ) -> CustomResult<String, errors::ApplePayDecryptionError> {
-        let data = BASE64_ENGINE
-            .decode(self.data.peek().as_bytes())
-            .change_context(errors::ApplePayDecryptionError::Base64DecodingFailed)?;
-        let iv = [0u8; 16]; //Initialization vector IV is typically used in AES-GCM (Galois/Counter Mode) encryption for randomizing the encryption process.
-        let ciphertext = data
-            .get(..data.len() - 16)
-            .ok_or(errors::ApplePayDecryptionError::DecryptionFailed)?;
-        let tag = data
-            .get(data.len() - 16..)
-            .ok_or(errors::ApplePayDecryptionError::DecryptionFailed)?;
-        let cipher = Cipher::aes_256_gcm();
-        let decrypted_data = decrypt_aead(cipher, symmetric_key, Some(&iv), &[], ciphertext, tag)
-            .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?;
-        let decrypted = String::from_utf8(decrypted_data)
-            .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?;
-
-        Ok(decrypted)
-    }
+    let is_pci_compliant = merchant_account
+        .get_feature_entitlements()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse feature entitlements of the merchant account")?
+        .is_entitled_to(api_models::admin::MerchantFeature::RawCardData);
+
+    fp_utils::when(!is_pci_compliant, || {
+        Err(report!(errors::ApiErrorResponse::RawCardDataNotAllowed)).attach_printable_lazy(|| {
+            format!(
+                "Merchant {} is not marked as PCI compliant",
+                merchant_account.merchant_id
+            )
+        })
+    })
 }
 
 pub fn get_key_params_for_surcharge_details(
@@ -4060,6 +4941,11 @@ pub fn get_key_params_for_surcharge_details(
             gift_card.get_payment_method_type(),
             None,
         )),
+        api_models::payments::PaymentMethodData::RealTimePayment(real_time_payment) => Some((
+            common_enums::PaymentMethod::RealTimePayment,
+            real_time_payment.get_payment_method_type(),
+            None,
+        )),
         api_models::payments::PaymentMethodData::CardToken(_) => None,
     }
 }
@@ -4122,6 +5008,22 @@ pub async fn get_gsm_record(
         .ok()
 }
 
+/// The unified code and message of an error, from its gsm record, or the ones of the rate limited
+/// calls if the connector has rate limited the call and no gsm record has been configured for it
+pub fn get_unified_error(
+    option_gsm: Option<storage::gsm::GatewayStatusMap>,
+    error_code: &str,
+) -> Option<(Option<String>, Option<String>)> {
+    match option_gsm {
+        Some(gsm) => Some((gsm.unified_code, gsm.unified_message)),
+        None if error_code == consts::CONNECTOR_RATE_LIMITED_ERROR_CODE => Some((
+            Some(consts::CONNECTOR_RATE_LIMITED_UNIFIED_CODE.to_string()),
+            Some(consts::CONNECTOR_RATE_LIMITED_UNIFIED_MESSAGE.to_string()),
+        )),
+        None => None,
+    }
+}
+
 pub fn validate_order_details_amount(
     order_details: Vec<api_models::payments::OrderDetailsWithAmount>,
     amount: i64,
@@ -4146,6 +5048,55 @@ pub fn validate_order_details_amount(
     }
 }
 
+/// Afterpay / Clearpay and Affirm require the line items of the order, so the order details of the
+/// payment must be present and add up to the amount of the payment
+pub fn validate_order_details_for_payment_method_type(
+    payment_method_type: Option<api_enums::PaymentMethodType>,
+    order_details: Option<&Vec<api_models::payments::OrderDetailsWithAmount>>,
+    payment_intent: &PaymentIntent,
+) -> RouterResult<()> {
+    if !matches!(
+        payment_method_type,
+        Some(api_enums::PaymentMethodType::AfterpayClearpay | api_enums::PaymentMethodType::Affirm)
+    ) {
+        return Ok(());
+    }
+
+    let order_details = match order_details {
+        Some(order_details) => order_details.to_owned(),
+        None => payment_intent
+            .order_details
+            .as_ref()
+            .map(|order_details| {
+                order_details
+                    .iter()
+                    .map(|data| {
+                        data.to_owned()
+                            .parse_value("OrderDetailsWithAmount")
+                            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                                field_name: "OrderDetailsWithAmount",
+                            })
+                            .attach_printable("Unable to parse OrderDetailsWithAmount")
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?
+            .filter(|order_details| !order_details.is_empty())
+            .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "order_details",
+            })?,
+    };
+
+    // The tax calculated for the shipping address of the payment is not one of its line items
+    validate_order_details_amount(
+        order_details,
+        payment_intent.amount - payment_intent.order_tax_amount.unwrap_or(0),
+        true,
+    )?;
+
+    Ok(())
+}
+
 // This function validates the client secret expiry set by the merchant in the request
 pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErrorResponse> {
     if !(consts::MIN_SESSION_EXPIRY..=consts::MAX_SESSION_EXPIRY).contains(&session_expiry) {
@@ -4157,6 +5108,19 @@ pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErr
     }
 }
 
+pub fn validate_max_auto_retries(max_auto_retries: u8) -> Result<(), errors::ApiErrorResponse> {
+    if max_auto_retries > consts::MAX_AUTO_RETRIES {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "max_auto_retries should be between 0 and {}",
+                consts::MAX_AUTO_RETRIES
+            ),
+        })
+    } else {
+        Ok(())
+    }
+}
+
 pub fn add_connector_response_to_additional_payment_data(
     additional_payment_data: api_models::payments::AdditionalPaymentData,
     connector_response_payment_method_data: core_types::AdditionalPaymentMethodConnectorResponse,
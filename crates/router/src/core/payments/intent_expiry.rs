@@ -0,0 +1,59 @@
+use super::{
+    payment_tasks::{self, PaymentTask},
+    PaymentData,
+};
+use crate::{
+    core::errors::RouterResult,
+    routes::AppState,
+    types::{domain, storage},
+};
+
+const INTENT_EXPIRY_TASK: PaymentTask = PaymentTask {
+    runner: diesel_models::ProcessTrackerRunner::PaymentIntentExpiryWorkflow,
+    name: "PAYMENT_INTENT_EXPIRY",
+    flow: "PaymentIntentExpiry",
+};
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct IntentExpiryTrackingData {
+    pub(crate) merchant_id: String,
+    pub(crate) profile_id: String,
+    pub(crate) payment_id: String,
+}
+
+/// Schedule the expiry of a payment which has not been confirmed, at the end of its session, if
+/// the payments are expired by the deployment. The expiry is scheduled when the payment is first
+/// seen waiting for the customer, and is moved by the workflow if the session of the payment is
+/// extended afterwards.
+pub async fn schedule_expiry_if_required<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &storage::BusinessProfile,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let payment_intent = &payment_data.payment_intent;
+
+    if !state.conf.payment_intent_expiry.enabled
+        || !payment_tasks::is_awaiting_customer(payment_intent.status)
+    {
+        return Ok(());
+    }
+
+    let Some(session_expiry) = payment_intent.session_expiry else {
+        return Ok(());
+    };
+
+    payment_tasks::schedule_payment_task(
+        state,
+        &INTENT_EXPIRY_TASK,
+        &payment_intent.payment_id,
+        IntentExpiryTrackingData {
+            merchant_id: merchant_account.merchant_id.clone(),
+            profile_id: business_profile.profile_id.clone(),
+            payment_id: payment_intent.payment_id.clone(),
+        },
+        session_expiry,
+    )
+    .await
+    .map(|_| ())
+}
@@ -179,6 +179,9 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            micro_deposits_details: None,
+            preferred_card_network: None,
+            external_three_ds_authentication_data: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -65,6 +65,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 enums::IntentStatus::Cancelled,
                 enums::IntentStatus::Processing,
                 enums::IntentStatus::RequiresMerchantAction,
+                enums::IntentStatus::Expired,
             ],
             "cancel",
         )?;
@@ -109,6 +110,10 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         )
         .await?;
 
+        if helpers::is_partial_reversal(payment_intent.status) {
+            validate_partial_reversal(state, &payment_intent, &payment_attempt)?;
+        }
+
         let currency = payment_attempt.currency.get_required_value("currency")?;
         let amount = payment_attempt.get_total_amount().into();
 
@@ -189,6 +194,9 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            micro_deposits_details: None,
+            preferred_card_network: None,
+            external_three_ds_authentication_data: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -228,7 +236,10 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
     {
         let cancellation_reason = payment_data.payment_attempt.cancellation_reason.clone();
         let (intent_status_update, attempt_status_update) =
-            if payment_data.payment_intent.status != enums::IntentStatus::RequiresCapture {
+            if helpers::is_partial_reversal(payment_data.payment_intent.status) {
+                // The payment stays captured, and only the uncaptured remainder is released
+                (None, payment_data.payment_attempt.status)
+            } else if payment_data.payment_intent.status != enums::IntentStatus::RequiresCapture {
                 let payment_intent_update = storage::PaymentIntentUpdate::PGStatusUpdate {
                     status: enums::IntentStatus::Cancelled,
                     updated_by: storage_scheme.to_string(),
@@ -274,6 +285,47 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
     }
 }
 
+/// The remainder of a partially captured payment can only be released once, and only with the
+/// connectors which can reverse a part of the authorized amount
+fn validate_partial_reversal(
+    state: &AppState,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    if helpers::get_uncaptured_amount(payment_intent, payment_attempt) <= 0 {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message:
+                "You cannot cancel this payment because it has no uncaptured amount to release"
+                    .to_string(),
+        }
+        .into());
+    }
+
+    let connector = payment_attempt
+        .connector
+        .as_deref()
+        .get_required_value("connector")?;
+    let is_partial_reversal_supported = connector
+        .parse::<api_models::enums::Connector>()
+        .is_ok_and(|connector| {
+            state
+                .conf
+                .partial_reversal_supported_connectors
+                .connector_list
+                .contains(&connector)
+        });
+    if !is_partial_reversal_supported {
+        return Err(errors::ApiErrorResponse::NotSupported {
+            message: format!(
+                "Releasing the uncaptured amount of partially captured payments is not supported by {connector}"
+            ),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
 impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
     ValidateRequest<F, api::PaymentsCancelRequest, Ctx> for PaymentCancel
 {
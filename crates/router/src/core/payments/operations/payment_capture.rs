@@ -46,7 +46,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         let db = &*state.store;
         let merchant_id = &merchant_account.merchant_id;
         let storage_scheme = merchant_account.storage_scheme;
-        let (payment_intent, mut payment_attempt, currency, amount);
+        let (mut payment_intent, mut payment_attempt, currency, amount);
 
         let payment_id = payment_id
             .get_payment_intent_id()
@@ -77,10 +77,16 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
 
         helpers::validate_status_with_capture_method(payment_intent.status, capture_method)?;
 
-        helpers::validate_amount_to_capture(
+        let max_amount_to_capture = helpers::get_max_amount_to_capture(
+            &state.conf.overcapture,
+            payment_intent.request_overcapture,
+            payment_attempt.connector.as_deref(),
             payment_attempt.amount_capturable,
-            request.amount_to_capture,
-        )?;
+        );
+
+        helpers::validate_amount_to_capture(max_amount_to_capture, request.amount_to_capture)?;
+
+        helpers::validate_capture_adjustment(request, payment_attempt.amount_capturable)?;
 
         helpers::validate_capture_method(capture_method)?;
 
@@ -89,10 +95,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 .amount_to_capture
                 .get_required_value("amount_to_capture")?;
 
-            helpers::validate_amount_to_capture(
-                payment_attempt.amount_capturable,
-                Some(amount_to_capture),
-            )?;
+            helpers::validate_amount_to_capture(max_amount_to_capture, Some(amount_to_capture))?;
 
             let previous_captures = db
                 .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
@@ -104,6 +107,17 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+            helpers::validate_amount_to_capture_against_previous_captures(
+                helpers::get_max_amount_to_capture(
+                    &state.conf.overcapture,
+                    payment_intent.request_overcapture,
+                    payment_attempt.connector.as_deref(),
+                    payment_attempt.get_total_amount(),
+                ),
+                &previous_captures,
+                amount_to_capture,
+            )?;
+
             let capture = db
                 .insert_capture(
                     payment_attempt
@@ -123,6 +137,27 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             None
         };
 
+        // The adjustment is recorded before the capture is sent to the connector, so that the
+        // connector is sent the adjusted order details
+        if let Some(capture_adjustment_reason) = request.capture_adjustment_reason {
+            let order_details = request
+                .get_order_details_as_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to convert order details to value")?;
+            payment_intent = db
+                .update_payment_intent(
+                    payment_intent,
+                    storage::PaymentIntentUpdate::CaptureAdjustmentUpdate {
+                        capture_adjustment_reason,
+                        order_details,
+                        updated_by: storage_scheme.to_string(),
+                    },
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        }
+
         currency = payment_attempt.currency.get_required_value("currency")?;
 
         amount = payment_attempt.get_total_amount().into();
@@ -232,6 +267,9 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            micro_deposits_details: None,
+            preferred_card_network: None,
+            external_three_ds_authentication_data: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
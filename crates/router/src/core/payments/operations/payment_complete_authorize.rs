@@ -67,6 +67,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             &[
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::Expired,
             ],
             "confirm",
         )?;
@@ -308,6 +309,9 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             frm_metadata: None,
             recurring_details,
             poll_config: None,
+            micro_deposits_details: None,
+            preferred_card_network: None,
+            external_three_ds_authentication_data: None,
         };
 
         let customer_details = Some(CustomerDetails {
@@ -83,8 +83,16 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             )?;
         }
 
+        helpers::validate_order_details_for_payment_method_type(
+            request.payment_method_type,
+            request.order_details.as_ref(),
+            &payment_intent,
+        )?;
+
         helpers::validate_customer_access(&payment_intent, auth_flow, request)?;
 
+        helpers::validate_raw_card_data_access(state, merchant_account, auth_flow, request)?;
+
         if [
             Some(common_enums::PaymentSource::Webhook),
             Some(common_enums::PaymentSource::ExternalAuthenticator),
@@ -99,6 +107,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                     storage_enums::IntentStatus::Processing,
                     storage_enums::IntentStatus::RequiresCapture,
                     storage_enums::IntentStatus::RequiresMerchantAction,
+                    storage_enums::IntentStatus::Expired,
                 ],
                 "confirm",
             )?;
@@ -112,6 +121,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                     storage_enums::IntentStatus::RequiresCapture,
                     storage_enums::IntentStatus::RequiresMerchantAction,
                     storage_enums::IntentStatus::RequiresCustomerAction,
+                    storage_enums::IntentStatus::Expired,
                 ],
                 "confirm",
             )?;
@@ -395,6 +405,18 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .attach_printable("Error converting feature_metadata to Value")?
             .or(payment_intent.feature_metadata);
         payment_intent.metadata = request.metadata.clone().or(payment_intent.metadata);
+        payment_intent.statement_descriptor_name = request
+            .statement_descriptor_name
+            .clone()
+            .or(payment_intent.statement_descriptor_name);
+        payment_intent.statement_descriptor_suffix = request
+            .statement_descriptor_suffix
+            .clone()
+            .or(payment_intent.statement_descriptor_suffix);
+        payment_intent.installment_tenure = request
+            .installment_tenure
+            .map(i16::from)
+            .or(payment_intent.installment_tenure);
         payment_intent.request_incremental_authorization = request
             .request_incremental_authorization
             .map(|request_incremental_authorization| {
@@ -647,6 +669,11 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             authentication,
             recurring_details,
             poll_config: None,
+            micro_deposits_details: None,
+            preferred_card_network: None,
+            external_three_ds_authentication_data: request
+                .external_three_ds_authentication_data
+                .clone(),
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -771,9 +798,9 @@ impl<F: Clone + Send, Ctx: PaymentMethodRetrieve> Domain<F, api::PaymentsRequest
         &'a self,
         state: &AppState,
         payment_data: &mut PaymentData<F>,
-        _merchant_account: &domain::MerchantAccount,
+        merchant_account: &domain::MerchantAccount,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
-        populate_surcharge_details(state, payment_data).await
+        populate_surcharge_details(state, payment_data, merchant_account).await
     }
 
     async fn call_external_three_ds_authentication_if_eligible<'a>(
@@ -1212,6 +1239,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
         let m_db = state.clone().store;
         let m_storage_scheme = storage_scheme.to_string();
         let session_expiry = m_payment_data_payment_intent.session_expiry;
+        let installment_tenure = m_payment_data_payment_intent.installment_tenure;
 
         let payment_intent_fut = tokio::spawn(
             async move {
@@ -1238,6 +1266,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                         fingerprint_id: None,
                         session_expiry,
                         request_external_three_ds_authentication: None,
+                        installment_tenure,
                     },
                     storage_scheme,
                 )
@@ -1314,6 +1343,8 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve> ValidateRequest<F, api::Paymen
             })?;
 
         helpers::validate_payment_method_fields_present(request)?;
+        helpers::validate_installment_tenure(request)?;
+        helpers::validate_external_three_ds_authentication_data(request)?;
 
         let _mandate_type =
             helpers::validate_mandate(request, payments::is_operation_confirm(self))?;
@@ -20,6 +20,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     consts,
     core::{
+        admin,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         payment_link,
@@ -60,7 +61,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         request: &api::PaymentsRequest,
         merchant_account: &domain::MerchantAccount,
         merchant_key_store: &domain::MerchantKeyStore,
-        _auth_flow: services::AuthFlow,
+        auth_flow: services::AuthFlow,
         _payment_confirm_source: Option<common_enums::PaymentSource>,
     ) -> RouterResult<operations::GetTrackerResponse<'a, F, api::PaymentsRequest, Ctx>> {
         let db = &*state.store;
@@ -71,6 +72,10 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
 
         let money @ (amount, currency) = payments_create_request_validation(request)?;
 
+        if request.confirm == Some(true) {
+            helpers::validate_raw_card_data_access(state, merchant_account, auth_flow, request)?;
+        }
+
         let payment_id = payment_id
             .get_payment_intent_id()
             .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
@@ -210,6 +215,11 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
 
         let payment_link_data = if let Some(payment_link_create) = request.payment_link {
             if payment_link_create {
+                admin::validate_feature_entitlement(
+                    merchant_account,
+                    api_models::admin::MerchantFeature::PaymentLinks,
+                )?;
+
                 let merchant_name = merchant_account
                     .merchant_name
                     .clone()
@@ -296,6 +306,14 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             )?;
         }
 
+        if request.confirm.unwrap_or(false) {
+            helpers::validate_order_details_for_payment_method_type(
+                payment_method_type,
+                request.order_details.as_ref(),
+                &payment_intent,
+            )?;
+        }
+
         payment_attempt = db
             .insert_payment_attempt(payment_attempt_new, storage_scheme)
             .await
@@ -452,6 +470,11 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             frm_metadata: request.frm_metadata.clone(),
             recurring_details,
             poll_config: None,
+            micro_deposits_details: None,
+            preferred_card_network: None,
+            external_three_ds_authentication_data: request
+                .external_three_ds_authentication_data
+                .clone(),
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -702,6 +725,21 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve> ValidateRequest<F, api::Paymen
         })?;
 
         helpers::validate_amount_to_capture_and_capture_method(None, request)?;
+        helpers::validate_split_payments(request.amount, request.split_payments.as_ref())?;
+        if request.payment_channel == Some(enums::PaymentChannel::Moto) {
+            admin::validate_feature_entitlement(
+                merchant_account,
+                api_models::admin::MerchantFeature::Moto,
+            )?;
+        }
+        helpers::validate_payment_channel(request)?;
+        helpers::validate_extended_authorization_request(request)?;
+        helpers::validate_installment_tenure(request)?;
+        helpers::validate_external_three_ds_authentication_data(request)?;
+        helpers::validate_l2_l3_data(request.amount, request.l2_l3_data.as_ref())?;
+        if let Some(tags) = &request.tags {
+            helpers::validate_payment_tags(tags)?;
+        }
         helpers::validate_card_data(
             request
                 .payment_method_data
@@ -896,7 +934,11 @@ impl PaymentCreate {
                 created_at,
                 modified_at,
                 last_synced,
-                authentication_type: request.authentication_type,
+                // The customer is not present to be authenticated during MOTO payments
+                authentication_type: match request.payment_channel {
+                    Some(enums::PaymentChannel::Moto) => Some(enums::AuthenticationType::NoThreeDs),
+                    _ => request.authentication_type,
+                },
                 browser_info,
                 payment_experience: request.payment_experience,
                 payment_method_type,
@@ -940,6 +982,11 @@ impl PaymentCreate {
                 fingerprint_id: None,
                 authentication_connector: None,
                 authentication_id: None,
+                connector_request_id: None,
+                extended_authorization_applied: None,
+                capture_before: None,
+                amount_reversed: None,
+                overcaptured_amount: None,
             },
             additional_pm_data,
         ))
@@ -994,6 +1041,16 @@ impl PaymentCreate {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error converting feature_metadata to Value")?;
 
+        let split_payments = request
+            .get_split_payments_as_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error converting split_payments to Value")?;
+
+        let l2_l3_data = request
+            .get_l2_l3_data_as_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error converting l2_l3_data to Value")?;
+
         let payment_link_id = payment_link_data.map(|pl_data| pl_data.payment_link_id);
 
         let request_incremental_authorization =
@@ -1047,6 +1104,15 @@ impl PaymentCreate {
             session_expiry: Some(session_expiry),
             request_external_three_ds_authentication: request
                 .request_external_three_ds_authentication,
+            request_overcapture: request.request_overcapture,
+            request_extended_authorization: request.request_extended_authorization,
+            order_tax_amount: None,
+            split_payments,
+            payment_channel: request.payment_channel,
+            l2_l3_data,
+            tags: request.tags.clone().filter(|tags| !tags.is_empty()),
+            capture_adjustment_reason: None,
+            installment_tenure: request.installment_tenure.map(i16::from),
         })
     }
 
@@ -61,6 +61,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 enums::IntentStatus::Failed,
                 enums::IntentStatus::Succeeded,
                 enums::IntentStatus::Processing,
+                enums::IntentStatus::Expired,
             ],
             "reject",
         )?;
@@ -175,6 +176,9 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             frm_metadata: None,
             recurring_details: None,
             poll_config: None,
+            micro_deposits_details: None,
+            preferred_card_network: None,
+            external_three_ds_authentication_data: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -28,7 +28,7 @@ use crate::{
             types::MultipleCaptureData,
             PaymentData,
         },
-        utils as core_utils,
+        usage, utils as core_utils,
     },
     routes::{metrics, AppState},
     types::{
@@ -43,7 +43,7 @@ use crate::{
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
 #[operation(
     operations = "post_update_tracker",
-    flow = "sync_data, cancel_data, authorize_data, capture_data, complete_authorize_data, approve_data, reject_data, setup_mandate_data, session_data,incremental_authorization_data"
+    flow = "sync_data, cancel_data, authorize_data, capture_data, complete_authorize_data, approve_data, reject_data, setup_mandate_data, session_data,incremental_authorization_data, verify_micro_deposits_data"
 )]
 pub struct PaymentResponse;
 
@@ -474,14 +474,28 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCancelData> f
     where
         F: 'b + Send,
     {
-        payment_data = Box::pin(payment_response_update_tracker(
-            db,
-            payment_id,
-            payment_data,
-            router_data,
-            storage_scheme,
-        ))
-        .await?;
+        payment_data = match router_data.request.amount_to_reverse {
+            Some(amount_to_reverse) => {
+                Box::pin(partial_reversal_update_tracker(
+                    db,
+                    payment_data,
+                    router_data,
+                    amount_to_reverse,
+                    storage_scheme,
+                ))
+                .await?
+            }
+            None => {
+                Box::pin(payment_response_update_tracker(
+                    db,
+                    payment_id,
+                    payment_data,
+                    router_data,
+                    storage_scheme,
+                ))
+                .await?
+            }
+        };
 
         Ok(payment_data)
     }
@@ -690,6 +704,36 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
     }
 }
 
+#[async_trait]
+impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsVerifyMicroDepositsData>
+    for PaymentResponse
+{
+    async fn update_tracker<'b>(
+        &'b self,
+        db: &'b AppState,
+        payment_id: &api::PaymentIdType,
+        payment_data: PaymentData<F>,
+        response: types::RouterData<
+            F,
+            types::PaymentsVerifyMicroDepositsData,
+            types::PaymentsResponseData,
+        >,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<PaymentData<F>>
+    where
+        F: 'b + Send,
+    {
+        Box::pin(payment_response_update_tracker(
+            db,
+            payment_id,
+            payment_data,
+            response,
+            storage_scheme,
+        ))
+        .await
+    }
+}
+
 #[instrument(skip_all)]
 async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     state: &AppState,
@@ -746,6 +790,8 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                         flow_name.clone(),
                     )
                     .await;
+                    let (unified_code, unified_message) =
+                        payments_helpers::get_unified_error(option_gsm, &err.code).unzip();
 
                     let status = match err.attempt_status {
                         // Use the status sent by connector in error_response if it's present
@@ -786,10 +832,11 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                 .request
                                 .get_amount_capturable(&payment_data, status),
                             updated_by: storage_scheme.to_string(),
-                            unified_code: option_gsm.clone().map(|gsm| gsm.unified_code),
-                            unified_message: option_gsm.map(|gsm| gsm.unified_message),
+                            unified_code,
+                            unified_message,
                             connector_transaction_id: err.connector_transaction_id,
                             payment_method_data: additional_payment_method_data,
+                            connector_request_id: router_data.connector_request_id.clone(),
                         }),
                     )
                 }
@@ -894,6 +941,15 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                         payment_data.payment_intent.fingerprint_id =
                             payment_data.payment_attempt.fingerprint_id.clone();
                         metrics::SUCCESSFUL_PAYMENT.add(&metrics::CONTEXT, 1, &[]);
+
+                        // Syncs of an already charged attempt are not billed again
+                        if payment_data.payment_attempt.status != enums::AttemptStatus::Charged {
+                            usage::record_usage(
+                                state,
+                                &payment_data.payment_attempt.merchant_id,
+                                common_enums::UsageMetric::SuccessfulPayment,
+                            );
+                        }
                     }
 
                     let payment_method_id = payment_data.payment_attempt.payment_method_id.clone();
@@ -905,6 +961,16 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                         payment_data.payment_attempt.merchant_id.clone(),
                     );
 
+                    let overcaptured_amount = payments_helpers::get_overcaptured_amount(
+                        get_total_amount_captured(
+                            &router_data.request,
+                            router_data.amount_captured,
+                            router_data.status,
+                            &payment_data,
+                        ),
+                        payment_data.payment_attempt.get_total_amount(),
+                    );
+
                     let (capture_updates, payment_attempt_update) = match payment_data
                         .multiple_capture_data
                     {
@@ -930,6 +996,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                 amount_capturable: router_data
                                     .request
                                     .get_amount_capturable(&payment_data, updated_attempt_status),
+                                overcaptured_amount,
                                 payment_method_id,
                                 mandate_id: payment_data.payment_attempt.mandate_id.clone(),
                                 connector_metadata,
@@ -944,6 +1011,16 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                 authentication_data,
                                 encoded_data,
                                 payment_method_data: additional_payment_method_data,
+                                connector_request_id: router_data.connector_request_id.clone(),
+                                extended_authorization_applied: router_data
+                                    .connector_response
+                                    .as_ref()
+                                    .and_then(|connector_response| {
+                                        connector_response.get_extended_authorization_applied()
+                                    }),
+                                capture_before: router_data.connector_response.as_ref().and_then(
+                                    |connector_response| connector_response.get_capture_before(),
+                                ),
                             }),
                         ),
                     };
@@ -1018,6 +1095,10 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                 status: multiple_capture_data.get_attempt_status(authorized_amount),
                 amount_capturable: authorized_amount
                     - multiple_capture_data.get_total_blocked_amount(),
+                overcaptured_amount: payments_helpers::get_overcaptured_amount(
+                    Some(multiple_capture_data.get_total_charged_amount()),
+                    authorized_amount,
+                ),
                 updated_by: storage_scheme.to_string(),
             });
             Some(multiple_capture_data)
@@ -1218,6 +1299,80 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     Ok(payment_data)
 }
 
+/// The partial reversals release the part of the authorized amount which remains uncaptured,
+/// without cancelling the payments. The captured amount of the payment is left as is, and the
+/// payment can no longer be captured once the remainder is released.
+async fn partial_reversal_update_tracker<F: Clone>(
+    state: &AppState,
+    mut payment_data: PaymentData<F>,
+    router_data: types::RouterData<F, types::PaymentsCancelData, types::PaymentsResponseData>,
+    amount_to_reverse: i64,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    let payment_attempt_update = match router_data.response {
+        Ok(_) if router_data.status == enums::AttemptStatus::Voided => {
+            storage::PaymentAttemptUpdate::PartialReversalUpdate {
+                status: enums::AttemptStatus::PartialCharged,
+                amount_reversed: payment_data.payment_attempt.amount_reversed.unwrap_or(0)
+                    + amount_to_reverse,
+                cancellation_reason: payment_data.payment_attempt.cancellation_reason.clone(),
+                updated_by: storage_scheme.to_string(),
+            }
+        }
+        Ok(_) => {
+            logger::info!(
+                connector_status=?router_data.status,
+                "The remainder of the payment is not released by the partial reversal"
+            );
+            return Ok(payment_data);
+        }
+        Err(err) => storage::PaymentAttemptUpdate::ErrorUpdate {
+            connector: None,
+            status: payment_data.payment_attempt.status,
+            error_message: Some(Some(err.message)),
+            error_code: Some(Some(err.code)),
+            error_reason: Some(err.reason),
+            amount_capturable: None,
+            updated_by: storage_scheme.to_string(),
+            unified_code: None,
+            unified_message: None,
+            connector_transaction_id: None,
+            payment_method_data: None,
+            connector_request_id: router_data.connector_request_id.clone(),
+        },
+    };
+
+    payment_data.payment_attempt = state
+        .store
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt,
+            payment_attempt_update,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let intent_status =
+        api_models::enums::IntentStatus::foreign_from(payment_data.payment_attempt.status);
+    if payment_data.payment_intent.status != intent_status {
+        payment_data.payment_intent = state
+            .store
+            .update_payment_intent(
+                payment_data.payment_intent,
+                storage::PaymentIntentUpdate::PGStatusUpdate {
+                    status: intent_status,
+                    updated_by: storage_scheme.to_string(),
+                    incremental_authorization_allowed: Some(false),
+                },
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    }
+
+    Ok(payment_data)
+}
+
 async fn update_payment_method_status_and_ntid<F: Clone>(
     state: &AppState,
     payment_data: &mut PaymentData<F>,
@@ -1242,6 +1397,13 @@ async fn update_payment_method_status_and_ntid<F: Clone>(
     .ok()
     .flatten();
 
+        // Only the network transaction ID of the customer initiated payment is stored, which is
+        // the one referenced by the merchant initiated payments made with the payment method
+        let is_merchant_initiated_payment = payment_data
+            .mandate_id
+            .as_ref()
+            .and_then(|mandate_ids| mandate_ids.mandate_reference_id.as_ref())
+            .is_some();
         let network_transaction_id =
             if let Some(network_transaction_id) = pm_resp_network_transaction_id {
                 let profile_id = payment_data
@@ -1261,6 +1423,7 @@ async fn update_payment_method_status_and_ntid<F: Clone>(
                     .attach_printable("The pg_agnostic config was not found in the DB")?;
 
                 if &pg_agnostic.config == "true"
+                    && !is_merchant_initiated_payment
                     && payment_data.payment_intent.setup_future_usage
                         == Some(diesel_models::enums::FutureUsage::OffSession)
                 {
@@ -491,6 +491,9 @@ async fn get_tracker_for_sync<
         frm_metadata: None,
         recurring_details: None,
         poll_config: None,
+        micro_deposits_details: None,
+        preferred_card_network: None,
+        external_three_ds_authentication_data: None,
     };
 
     let get_trackers_response = operations::GetTrackerResponse {
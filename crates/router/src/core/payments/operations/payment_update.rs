@@ -71,6 +71,18 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             )?;
         }
 
+        // The split payments of the payment have been validated against its amount at creation
+        if payment_intent.split_payments.is_some()
+            && request
+                .amount
+                .is_some_and(|amount| i64::from(amount) != payment_intent.amount)
+        {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The amount of a payment with split_payments cannot be updated"
+                    .to_string(),
+            })?
+        }
+
         payment_intent.setup_future_usage = request
             .setup_future_usage
             .or(payment_intent.setup_future_usage);
@@ -91,6 +103,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 storage_enums::IntentStatus::Succeeded,
                 storage_enums::IntentStatus::PartiallyCaptured,
                 storage_enums::IntentStatus::RequiresCapture,
+                storage_enums::IntentStatus::Expired,
             ],
             "update",
         )?;
@@ -361,6 +374,10 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         payment_intent.request_external_three_ds_authentication = request
             .request_external_three_ds_authentication
             .or(payment_intent.request_external_three_ds_authentication);
+        payment_intent.installment_tenure = request
+            .installment_tenure
+            .map(i16::from)
+            .or(payment_intent.installment_tenure);
 
         Self::populate_payment_attempt_with_request(&mut payment_attempt, request);
 
@@ -454,6 +471,11 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             frm_metadata: request.frm_metadata.clone(),
             recurring_details,
             poll_config: None,
+            micro_deposits_details: None,
+            preferred_card_network: None,
+            external_three_ds_authentication_data: request
+                .external_three_ds_authentication_data
+                .clone(),
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -721,6 +743,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                     request_external_three_ds_authentication: payment_data
                         .payment_intent
                         .request_external_three_ds_authentication,
+                    installment_tenure: payment_data.payment_intent.installment_tenure,
                 },
                 storage_scheme,
             )
@@ -775,6 +798,8 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve> ValidateRequest<F, api::Paymen
         })?;
 
         helpers::validate_payment_method_fields_present(request)?;
+        helpers::validate_installment_tenure(request)?;
+        helpers::validate_external_three_ds_authentication_data(request)?;
 
         let _mandate_type = helpers::validate_mandate(request, false)?;
 
@@ -0,0 +1,295 @@
+use std::marker::PhantomData;
+
+use api_models::{enums::FrmSuggestion, payments::PaymentsVerifyMicroDepositsRequest};
+use async_trait::async_trait;
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payment_methods::PaymentMethodRetrieve,
+        payments::{
+            self, helpers, operations, CustomerDetails, MicroDepositsDetails, PaymentAddress,
+        },
+    },
+    routes::{
+        app::{ReqState, StorageInterface},
+        AppState,
+    },
+    services,
+    types::{
+        api::{self, PaymentIdTypeExt},
+        domain,
+        storage::{self, enums},
+    },
+    utils::OptionExt,
+};
+
+/// The number of micro-deposits made to the bank account when they are verified using amounts
+const MICRO_DEPOSITS_COUNT: usize = 2;
+
+#[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
+#[operation(operations = "all", flow = "verify_micro_deposits")]
+pub struct PaymentVerifyMicroDeposits;
+
+#[async_trait]
+impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
+    GetTracker<F, payments::PaymentData<F>, PaymentsVerifyMicroDepositsRequest, Ctx>
+    for PaymentVerifyMicroDeposits
+{
+    #[instrument(skip_all)]
+    async fn get_trackers<'a>(
+        &'a self,
+        state: &'a AppState,
+        payment_id: &api::PaymentIdType,
+        request: &PaymentsVerifyMicroDepositsRequest,
+        merchant_account: &domain::MerchantAccount,
+        _key_store: &domain::MerchantKeyStore,
+        _auth_flow: services::AuthFlow,
+        _payment_confirm_source: Option<common_enums::PaymentSource>,
+    ) -> RouterResult<operations::GetTrackerResponse<'a, F, PaymentsVerifyMicroDepositsRequest, Ctx>>
+    {
+        let db = &*state.store;
+        let merchant_id = &merchant_account.merchant_id;
+        let storage_scheme = merchant_account.storage_scheme;
+        let payment_id = payment_id
+            .get_payment_intent_id()
+            .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(&payment_id, merchant_id, storage_scheme)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        helpers::validate_payment_status_against_allowed_statuses(
+            &payment_intent.status,
+            &[enums::IntentStatus::RequiresCustomerAction],
+            "verify micro-deposits",
+        )?;
+
+        let attempt_id = payment_intent.active_attempt.get_id().clone();
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                payment_intent.payment_id.as_str(),
+                merchant_id,
+                attempt_id.clone().as_str(),
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        if payment_attempt.payment_method_type != Some(enums::PaymentMethodType::Ach) {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Micro-deposits can only be verified for ACH bank debit payments"
+                    .to_owned(),
+            })?
+        }
+
+        let currency = payment_attempt.currency.get_required_value("currency")?;
+        let amount = payment_attempt.get_total_amount();
+
+        let profile_id = payment_intent
+            .profile_id
+            .as_ref()
+            .get_required_value("profile_id")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("'profile_id' not set in payment intent")?;
+
+        let business_profile = state
+            .store
+            .find_business_profile_by_profile_id(profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.to_string(),
+            })?;
+
+        let payment_data = payments::PaymentData {
+            flow: PhantomData,
+            payment_intent,
+            payment_attempt,
+            currency,
+            amount: amount.into(),
+            email: None,
+            mandate_id: None,
+            mandate_connector: None,
+            setup_mandate: None,
+            customer_acceptance: None,
+            token: None,
+            token_data: None,
+            address: PaymentAddress::new(None, None, None),
+            confirm: None,
+            payment_method_data: None,
+            payment_method_info: None,
+            force_sync: None,
+            refunds: vec![],
+            disputes: vec![],
+            attempts: None,
+            sessions_token: vec![],
+            card_cvc: None,
+            creds_identifier: None,
+            pm_token: None,
+            connector_customer_id: None,
+            recurring_mandate_payment_data: None,
+            ephemeral_key: None,
+            multiple_capture_data: None,
+            redirect_response: None,
+            surcharge_details: None,
+            frm_message: None,
+            payment_link_data: None,
+            incremental_authorization_details: None,
+            authorizations: vec![],
+            authentication: None,
+            frm_metadata: None,
+            recurring_details: None,
+            poll_config: None,
+            micro_deposits_details: Some(MicroDepositsDetails {
+                amounts: request.amounts.clone(),
+                descriptor_code: request.descriptor_code.clone(),
+            }),
+            preferred_card_network: None,
+            external_three_ds_authentication_data: None,
+        };
+
+        let get_trackers_response = operations::GetTrackerResponse {
+            operation: Box::new(self),
+            customer_details: None,
+            payment_data,
+            business_profile,
+            mandate_type: None,
+        };
+
+        Ok(get_trackers_response)
+    }
+}
+
+#[async_trait]
+impl<F: Clone, Ctx: PaymentMethodRetrieve>
+    UpdateTracker<F, payments::PaymentData<F>, PaymentsVerifyMicroDepositsRequest, Ctx>
+    for PaymentVerifyMicroDeposits
+{
+    #[instrument(skip_all)]
+    async fn update_trackers<'b>(
+        &'b self,
+        _state: &'b AppState,
+        _req_state: ReqState,
+        payment_data: payments::PaymentData<F>,
+        _customer: Option<domain::Customer>,
+        _storage_scheme: enums::MerchantStorageScheme,
+        _updated_customer: Option<storage::CustomerUpdate>,
+        _mechant_key_store: &domain::MerchantKeyStore,
+        _frm_suggestion: Option<FrmSuggestion>,
+        _header_payload: api::HeaderPayload,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, PaymentsVerifyMicroDepositsRequest, Ctx>,
+        payments::PaymentData<F>,
+    )>
+    where
+        F: 'b + Send,
+    {
+        Ok((Box::new(self), payment_data))
+    }
+}
+
+impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
+    ValidateRequest<F, PaymentsVerifyMicroDepositsRequest, Ctx> for PaymentVerifyMicroDeposits
+{
+    #[instrument(skip_all)]
+    fn validate_request<'a, 'b>(
+        &'b self,
+        request: &PaymentsVerifyMicroDepositsRequest,
+        merchant_account: &'a domain::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, PaymentsVerifyMicroDepositsRequest, Ctx>,
+        operations::ValidateResult<'a>,
+    )> {
+        match (&request.amounts, &request.descriptor_code) {
+            (Some(amounts), None) if amounts.len() != MICRO_DEPOSITS_COUNT => {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Expected the amounts of {MICRO_DEPOSITS_COUNT} micro-deposits but got {}",
+                        amounts.len()
+                    ),
+                })?
+            }
+            (Some(_), None) | (None, Some(_)) => (),
+            (Some(_), Some(_)) | (None, None) => {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Expected one out of amounts and descriptor_code".to_owned(),
+                })?
+            }
+        }
+
+        Ok((
+            Box::new(self),
+            operations::ValidateResult {
+                merchant_id: &merchant_account.merchant_id,
+                payment_id: api::PaymentIdType::PaymentIntentId(request.payment_id.to_owned()),
+                storage_scheme: merchant_account.storage_scheme,
+                requeue: false,
+            },
+        ))
+    }
+}
+
+#[async_trait]
+impl<F: Clone + Send, Ctx: PaymentMethodRetrieve> Domain<F, PaymentsVerifyMicroDepositsRequest, Ctx>
+    for PaymentVerifyMicroDeposits
+{
+    #[instrument(skip_all)]
+    async fn get_or_create_customer_details<'a>(
+        &'a self,
+        _db: &dyn StorageInterface,
+        _payment_data: &mut payments::PaymentData<F>,
+        _request: Option<CustomerDetails>,
+        _merchant_key_store: &domain::MerchantKeyStore,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<
+        (
+            BoxedOperation<'a, F, PaymentsVerifyMicroDepositsRequest, Ctx>,
+            Option<domain::Customer>,
+        ),
+        errors::StorageError,
+    > {
+        Ok((Box::new(self), None))
+    }
+
+    #[instrument(skip_all)]
+    async fn make_pm_data<'a>(
+        &'a self,
+        _state: &'a AppState,
+        _payment_data: &mut payments::PaymentData<F>,
+        _storage_scheme: enums::MerchantStorageScheme,
+        _merchant_key_store: &domain::MerchantKeyStore,
+        _customer: &Option<domain::Customer>,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, PaymentsVerifyMicroDepositsRequest, Ctx>,
+        Option<api::PaymentMethodData>,
+        Option<String>,
+    )> {
+        Ok((Box::new(self), None, None))
+    }
+
+    async fn get_connector<'a>(
+        &'a self,
+        _merchant_account: &domain::MerchantAccount,
+        state: &AppState,
+        _request: &PaymentsVerifyMicroDepositsRequest,
+        _payment_intent: &storage::PaymentIntent,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<api::ConnectorChoice, errors::ApiErrorResponse> {
+        helpers::get_connector_default(state, None).await
+    }
+
+    #[instrument(skip_all)]
+    async fn guard_payment_against_blocklist<'a>(
+        &'a self,
+        _state: &AppState,
+        _merchant_account: &domain::MerchantAccount,
+        _payment_data: &mut payments::PaymentData<F>,
+    ) -> CustomResult<bool, errors::ApiErrorResponse> {
+        Ok(false)
+    }
+}
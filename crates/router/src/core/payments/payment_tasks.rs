@@ -0,0 +1,206 @@
+use std::fmt::Debug;
+
+use api_models::webhooks::OutgoingWebhookContent;
+use error_stack::ResultExt;
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use super::{transformers, PaymentData};
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        webhooks as webhooks_core,
+    },
+    routes::{metrics, AppState},
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+/// A task scheduled for a payment, such as its expiry or its automatic void. The task is scheduled
+/// at most once for each payment, as it is identified by the payment.
+pub struct PaymentTask {
+    pub runner: diesel_models::ProcessTrackerRunner,
+    /// Name of the task, which is also its tag
+    pub name: &'static str,
+    /// Flow recorded by the metrics of the scheduled tasks
+    pub flow: &'static str,
+}
+
+impl PaymentTask {
+    pub fn get_process_tracker_id(&self, payment_id: &str) -> String {
+        format!("{}_{}_{payment_id}", self.runner, self.name)
+    }
+}
+
+/// The payments which are waiting for the customer to pay, and which are expired at the end of
+/// their session or notified as abandoned if the customer does not come back. The payments waiting
+/// for the customer to authenticate are left to their connector, as the customer may still
+/// complete the authentication.
+pub fn is_awaiting_customer(status: enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        enums::IntentStatus::RequiresPaymentMethod | enums::IntentStatus::RequiresConfirmation
+    )
+}
+
+/// Schedule the task for the payment, returning whether it has been scheduled. The task is not
+/// scheduled again if it has already been scheduled when the payment was seen before.
+pub async fn schedule_payment_task<T>(
+    state: &AppState,
+    task: &PaymentTask,
+    payment_id: &str,
+    tracking_data: T,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<bool>
+where
+    T: serde::Serialize + Debug,
+{
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        task.get_process_tracker_id(payment_id),
+        task.name,
+        task.runner,
+        [task.name],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Failed to construct {} process tracker task", task.name))?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes("flow", task.flow)],
+            );
+            Ok(true)
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(false),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while inserting {} task to process_tracker: payment_id: {payment_id}",
+                    task.name
+                )
+            }),
+    }
+}
+
+/// Trigger the webhook of an event of the payment raised by a scheduled task, with the details of
+/// the payment as retrieved after the operation of the task. Such events are not notified by the
+/// payment webhooks triggered by the payment flows.
+pub async fn trigger_payment_event_webhook<F, Op>(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: storage::BusinessProfile,
+    event_type: enums::EventType,
+    operation: Op,
+    payment_data: PaymentData<F>,
+) -> RouterResult<()>
+where
+    F: Clone,
+    Op: Debug,
+{
+    let payment_id = payment_data.payment_intent.payment_id.clone();
+    let payments_response = transformers::payments_to_payments_response(
+        payment_data,
+        None,
+        None,
+        services::AuthFlow::Merchant,
+        &state.conf.server,
+        &operation,
+        &state.conf.connector_request_reference_id_config,
+        None,
+        None,
+        None,
+    )?;
+
+    let services::ApplicationResponse::JsonWithHeaders((payments_response_json, _)) =
+        payments_response
+    else {
+        logger::warn!("Outgoing webhook not sent as the payments response is not a json");
+        return Ok(());
+    };
+    let primary_object_created_at = payments_response_json.created;
+
+    trigger_payment_webhook(
+        state,
+        merchant_account,
+        key_store,
+        business_profile,
+        event_type,
+        payment_id,
+        OutgoingWebhookContent::PaymentDetails(payments_response_json),
+        primary_object_created_at,
+    )
+    .await
+}
+
+/// Trigger the webhook of an event of the payment, with the content describing the event
+#[allow(clippy::too_many_arguments)]
+pub async fn trigger_payment_webhook(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: storage::BusinessProfile,
+    event_type: enums::EventType,
+    primary_object_id: String,
+    content: OutgoingWebhookContent,
+    primary_object_created_at: Option<PrimitiveDateTime>,
+) -> RouterResult<()> {
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        key_store,
+        event_type,
+        diesel_models::enums::EventClass::Payments,
+        primary_object_id,
+        diesel_models::enums::EventObjectType::PaymentDetails,
+        content,
+        primary_object_created_at,
+    ))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_awaiting_customer() {
+        assert!(is_awaiting_customer(
+            enums::IntentStatus::RequiresPaymentMethod
+        ));
+        assert!(is_awaiting_customer(
+            enums::IntentStatus::RequiresConfirmation
+        ));
+        assert!(!is_awaiting_customer(
+            enums::IntentStatus::RequiresCustomerAction
+        ));
+        assert!(!is_awaiting_customer(enums::IntentStatus::Succeeded));
+        assert!(!is_awaiting_customer(enums::IntentStatus::Expired));
+    }
+
+    #[test]
+    fn test_get_process_tracker_id() {
+        let task = PaymentTask {
+            runner: diesel_models::ProcessTrackerRunner::PaymentIntentExpiryWorkflow,
+            name: "PAYMENT_INTENT_EXPIRY",
+            flow: "PaymentIntentExpiry",
+        };
+
+        assert_eq!(
+            task.get_process_tracker_id("pay_1"),
+            format!(
+                "{}_PAYMENT_INTENT_EXPIRY_pay_1",
+                diesel_models::ProcessTrackerRunner::PaymentIntentExpiryWorkflow
+            )
+        );
+    }
+}
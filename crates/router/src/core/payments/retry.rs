@@ -1,6 +1,6 @@
 use std::{str::FromStr, vec::IntoIter};
 
-use common_utils::ext_traits::Encode;
+use common_utils::ext_traits::{Encode, ValueExt};
 use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
 use router_env::{
@@ -9,6 +9,7 @@ use router_env::{
 };
 
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         payment_methods::PaymentMethodRetrieve,
@@ -40,6 +41,7 @@ pub async fn do_gsm_actions<F, ApiRequest, FData, Ctx>(
     mut router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
     merchant_account: &domain::MerchantAccount,
     key_store: &domain::MerchantKeyStore,
+    business_profile: &storage::BusinessProfile,
     operation: &operations::BoxedOperation<'_, F, ApiRequest, Ctx>,
     customer: &Option<domain::Customer>,
     validate_result: &operations::ValidateResult<'_>,
@@ -62,27 +64,17 @@ where
 
     let mut initial_gsm = get_gsm(state, &router_data).await?;
 
-    //Check if step-up to threeDS is possible and merchant has enabled
-    let step_up_possible = initial_gsm
-        .clone()
-        .map(|gsm| gsm.step_up_possible)
-        .unwrap_or(false);
-    let is_no_three_ds_payment = matches!(
-        payment_data.payment_attempt.authentication_type,
-        Some(storage_enums::AuthenticationType::NoThreeDs)
-    );
-    let should_step_up = if step_up_possible && is_no_three_ds_payment {
-        is_step_up_enabled_for_merchant_connector(
-            state,
-            &merchant_account.merchant_id,
-            original_connector_data.connector_name,
-        )
-        .await
-    } else {
-        false
-    };
+    //Check if step-up to threeDS or step-down from threeDS is possible and merchant has enabled
+    let retry_authentication_type = get_retry_authentication_type(
+        state,
+        merchant_account,
+        payment_data,
+        initial_gsm.as_ref(),
+        original_connector_data.connector_name,
+    )
+    .await;
 
-    if should_step_up {
+    if let Some(authentication_type) = retry_authentication_type {
         router_data = do_retry(
             &state.clone(),
             req_state.clone(),
@@ -95,12 +87,12 @@ where
             router_data,
             validate_result,
             schedule_time,
-            true,
+            Some(authentication_type),
             frm_suggestion,
         )
         .await?;
     }
-    // Step up is not applicable so proceed with auto retries flow
+    // Step up and step down are not applicable so proceed with auto retries flow
     else {
         loop {
             // Use initial_gsm for first time alone
@@ -109,9 +101,15 @@ where
                 None => get_gsm(state, &router_data).await?,
             };
 
-            match get_gsm_decision(gsm) {
+            match get_retry_decision(gsm, &router_data) {
                 api_models::gsm::GsmDecision::Retry => {
-                    retries = get_retries(state, retries, &merchant_account.merchant_id).await;
+                    retries = get_retries(
+                        state,
+                        retries,
+                        &merchant_account.merchant_id,
+                        business_profile,
+                    )
+                    .await;
 
                     if retries.is_none() || retries == Some(0) {
                         metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(&metrics::CONTEXT, 1, &[]);
@@ -139,8 +137,8 @@ where
                         router_data,
                         validate_result,
                         schedule_time,
-                        //this is an auto retry payment, but not step-up
-                        false,
+                        //this is an auto retry payment, but not step-up or step-down
+                        None,
                         frm_suggestion,
                     )
                     .await?;
@@ -162,6 +160,78 @@ where
     Ok(router_data)
 }
 
+/// The authentication type the payment is to be retried with on the same connector, if the
+/// connector declined the payment with an error allowing a step-up to 3DS or a step-down from 3DS
+/// and the decision manager of the merchant allows it.
+#[instrument(skip_all)]
+pub async fn get_retry_authentication_type<F: Clone>(
+    state: &app::AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &mut payments::PaymentData<F>,
+    gsm: Option<&storage::gsm::GatewayStatusMap>,
+    connector_name: types::Connector,
+) -> Option<storage_enums::AuthenticationType> {
+    let gsm = gsm?;
+    match payment_data.payment_attempt.authentication_type {
+        Some(storage_enums::AuthenticationType::NoThreeDs) if gsm.step_up_possible => {
+            let decision_manager_output =
+                get_decision_manager_output(state, merchant_account, payment_data).await;
+            let is_step_up_allowed = match decision_manager_output.allow_3ds_step_up {
+                Some(is_step_up_allowed) => is_step_up_allowed,
+                None => {
+                    is_step_up_enabled_for_merchant_connector(
+                        state,
+                        &merchant_account.merchant_id,
+                        connector_name,
+                    )
+                    .await
+                }
+            };
+            is_step_up_allowed.then_some(storage_enums::AuthenticationType::ThreeDs)
+        }
+        Some(storage_enums::AuthenticationType::ThreeDs) if gsm.step_down_possible => {
+            get_decision_manager_output(state, merchant_account, payment_data)
+                .await
+                .allow_3ds_step_down
+                .unwrap_or(false)
+                .then_some(storage_enums::AuthenticationType::NoThreeDs)
+        }
+        _ => None,
+    }
+}
+
+#[instrument(skip_all)]
+async fn get_decision_manager_output<F: Clone>(
+    state: &app::AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &mut payments::PaymentData<F>,
+) -> api_models::conditional_configs::ConditionalConfigs {
+    let algorithm_ref = match merchant_account
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value::<api::routing::RoutingAlgorithmRef>("routing algorithm"))
+        .transpose()
+    {
+        Ok(algorithm_ref) => algorithm_ref.unwrap_or_default(),
+        Err(err) => {
+            logger::error!(decision_manager_error=?err);
+            return api_models::conditional_configs::ConditionalConfigs::default();
+        }
+    };
+
+    payments::conditional_configs::perform_decision_management(
+        state,
+        algorithm_ref,
+        merchant_account.merchant_id.as_str(),
+        payment_data,
+    )
+    .await
+    .map_err(|err| {
+        logger::error!(decision_manager_error=?err);
+    })
+    .unwrap_or_default()
+}
+
 #[instrument(skip_all)]
 pub async fn is_step_up_enabled_for_merchant_connector(
     state: &app::AppState,
@@ -186,15 +256,25 @@ pub async fn is_step_up_enabled_for_merchant_connector(
         .unwrap_or(false)
 }
 
+/// The number of automatic retries the business profile caps the retries of its payments to, which
+/// takes precedence over the config of the merchant
+pub fn get_profile_max_auto_retries(business_profile: &storage::BusinessProfile) -> Option<u8> {
+    business_profile
+        .max_auto_retries
+        .and_then(|max_auto_retries| u8::try_from(max_auto_retries).ok())
+}
+
 #[instrument(skip_all)]
 pub async fn get_retries(
     state: &app::AppState,
     retries: Option<i32>,
     merchant_id: &str,
+    business_profile: &storage::BusinessProfile,
 ) -> Option<i32> {
-    match retries {
-        Some(retries) => Some(retries),
-        None => {
+    match (retries, get_profile_max_auto_retries(business_profile)) {
+        (Some(retries), _) => Some(retries),
+        (None, Some(max_auto_retries)) => Some(i32::from(max_auto_retries)),
+        (None, None) => {
             let key = format!("max_auto_retries_enabled_{merchant_id}");
             let db = &*state.store;
             db.find_config_by_key(key.as_str())
@@ -254,6 +334,23 @@ pub fn get_gsm_decision(
     option_gsm_decision.unwrap_or_default()
 }
 
+/// The decision of the gsm record, or a retry with the next connector if the connector has rate
+/// limited the call and no gsm record has been configured for it, as the call has not been
+/// processed by the connector
+fn get_retry_decision<F, FData>(
+    option_gsm: Option<storage::gsm::GatewayStatusMap>,
+    router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
+) -> api_models::gsm::GsmDecision {
+    let is_rate_limited = router_data.response.as_ref().is_err_and(|error_response| {
+        error_response.code == consts::CONNECTOR_RATE_LIMITED_ERROR_CODE
+    });
+    if option_gsm.is_none() && is_rate_limited {
+        logger::info!("retrying the payment rate limited by the connector");
+        return api_models::gsm::GsmDecision::Retry;
+    }
+    get_gsm_decision(option_gsm)
+}
+
 #[inline]
 fn get_flow_name<F>() -> RouterResult<String> {
     Ok(std::any::type_name::<F>()
@@ -279,7 +376,7 @@ pub async fn do_retry<F, ApiRequest, FData, Ctx>(
     router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
     validate_result: &operations::ValidateResult<'_>,
     schedule_time: Option<time::PrimitiveDateTime>,
-    is_step_up: bool,
+    retry_authentication_type: Option<storage_enums::AuthenticationType>,
     frm_suggestion: Option<storage_enums::FrmSuggestion>,
 ) -> RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
 where
@@ -300,7 +397,7 @@ where
         payment_data,
         merchant_account.storage_scheme,
         router_data,
-        is_step_up,
+        retry_authentication_type,
     )
     .await?;
 
@@ -329,7 +426,7 @@ pub async fn modify_trackers<F, FData>(
     payment_data: &mut payments::PaymentData<F>,
     storage_scheme: storage_enums::MerchantStorageScheme,
     router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
-    is_step_up: bool,
+    retry_authentication_type: Option<storage_enums::AuthenticationType>,
 ) -> RouterResult<()>
 where
     F: Clone + Send,
@@ -340,7 +437,7 @@ where
         connector,
         payment_data.payment_attempt.clone(),
         new_attempt_count,
-        is_step_up,
+        retry_authentication_type,
     );
 
     let db = &*state.store;
@@ -399,12 +496,24 @@ where
                     } else {
                         None
                     },
+                    overcaptured_amount: None,
                     updated_by: storage_scheme.to_string(),
                     authentication_data,
                     encoded_data,
                     unified_code: None,
                     unified_message: None,
                     payment_method_data: additional_payment_method_data,
+                    connector_request_id: router_data.connector_request_id.clone(),
+                    extended_authorization_applied: router_data
+                        .connector_response
+                        .as_ref()
+                        .and_then(|connector_response| {
+                            connector_response.get_extended_authorization_applied()
+                        }),
+                    capture_before: router_data
+                        .connector_response
+                        .as_ref()
+                        .and_then(|connector_response| connector_response.get_capture_before()),
                 },
                 storage_scheme,
             )
@@ -417,6 +526,8 @@ where
         }
         Err(ref error_response) => {
             let option_gsm = get_gsm(state, &router_data).await?;
+            let (unified_code, unified_message) =
+                payments::helpers::get_unified_error(option_gsm, &error_response.code).unzip();
 
             db.update_payment_attempt_with_attempt_id(
                 payment_data.payment_attempt.clone(),
@@ -428,10 +539,11 @@ where
                     error_reason: Some(error_response.reason.clone()),
                     amount_capturable: Some(0),
                     updated_by: storage_scheme.to_string(),
-                    unified_code: option_gsm.clone().map(|gsm| gsm.unified_code),
-                    unified_message: option_gsm.map(|gsm| gsm.unified_message),
+                    unified_code,
+                    unified_message,
                     connector_transaction_id: error_response.connector_transaction_id.clone(),
                     payment_method_data: additional_payment_method_data,
+                    connector_request_id: router_data.connector_request_id.clone(),
                 },
                 storage_scheme,
             )
@@ -471,7 +583,7 @@ pub fn make_new_payment_attempt(
     connector: String,
     old_payment_attempt: storage::PaymentAttempt,
     new_attempt_count: i16,
-    is_step_up: bool,
+    retry_authentication_type: Option<storage_enums::AuthenticationType>,
 ) -> storage::PaymentAttemptNew {
     let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
     storage::PaymentAttemptNew {
@@ -496,11 +608,7 @@ pub fn make_new_payment_attempt(
         capture_method: old_payment_attempt.capture_method,
         capture_on: old_payment_attempt.capture_on,
         confirm: old_payment_attempt.confirm,
-        authentication_type: if is_step_up {
-            Some(storage_enums::AuthenticationType::ThreeDs)
-        } else {
-            old_payment_attempt.authentication_type
-        },
+        authentication_type: retry_authentication_type.or(old_payment_attempt.authentication_type),
 
         amount_to_capture: old_payment_attempt.amount_to_capture,
         mandate_id: old_payment_attempt.mandate_id,
@@ -514,6 +622,21 @@ pub fn make_new_payment_attempt(
     }
 }
 
+/// Whether the failed payments of the business profile are classified by the gateway status map,
+/// and retried on the next connectors if the connector declined them with a soft decline. The
+/// retries are enabled by the business profile if it caps them, or else by the config of the
+/// merchant.
+pub async fn is_auto_retry_enabled(
+    db: &dyn StorageInterface,
+    merchant_id: &String,
+    business_profile: &storage::BusinessProfile,
+) -> bool {
+    match get_profile_max_auto_retries(business_profile) {
+        Some(max_auto_retries) => max_auto_retries > 0,
+        None => config_should_call_gsm(db, merchant_id).await,
+    }
+}
+
 pub async fn config_should_call_gsm(db: &dyn StorageInterface, merchant_id: &String) -> bool {
     let config = db
         .find_config_by_key_unwrap_or(
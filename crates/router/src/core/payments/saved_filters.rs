@@ -0,0 +1,167 @@
+use api_models::payments::{
+    PaymentListFilterConstraints, SavedPaymentFilter, SavedPaymentFilterDeleteRequest,
+    SavedPaymentFilterListResponse, SavedPaymentFilterRequest,
+};
+use common_utils::ext_traits::{Encode, StringExt};
+use error_stack::{report, ResultExt};
+
+use super::helpers;
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{domain, storage},
+};
+
+/// Max length of the name of a saved payment filter
+const MAX_SAVED_FILTER_NAME_LENGTH: usize = 64;
+
+/// The saved payment filters of a merchant are stored together in a config of the merchant
+fn get_saved_filters_key(merchant_id: &str) -> String {
+    format!("saved_payment_filters_{merchant_id}")
+}
+
+async fn get_saved_filters(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Vec<SavedPaymentFilter>> {
+    db.find_config_by_key_unwrap_or(&get_saved_filters_key(merchant_id), Some("[]".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the saved payment filters of the merchant")?
+        .config
+        .parse_struct::<Vec<SavedPaymentFilter>>("Vec<SavedPaymentFilter>")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the saved payment filters of the merchant")
+}
+
+async fn store_saved_filters(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    saved_filters: Vec<SavedPaymentFilter>,
+) -> RouterResult<()> {
+    let config = saved_filters
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the saved payment filters of the merchant")?;
+
+    db.update_config_by_key(
+        &get_saved_filters_key(merchant_id),
+        storage::ConfigUpdate::Update {
+            config: Some(config),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the saved payment filters of the merchant")?;
+
+    Ok(())
+}
+
+/// The constraints of the saved filter with the name, which the payments list requests of the
+/// merchant refer to
+pub async fn get_saved_filter_constraints(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    name: &str,
+) -> RouterResult<PaymentListFilterConstraints> {
+    get_saved_filters(db, merchant_id)
+        .await?
+        .into_iter()
+        .find(|saved_filter| saved_filter.name == name)
+        .map(|saved_filter| saved_filter.filters)
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Saved payment filter `{name}` does not exist"),
+            })
+        })
+}
+
+pub async fn save_payment_filter(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    request: SavedPaymentFilterRequest,
+) -> RouterResponse<SavedPaymentFilter> {
+    let name = request.name.trim();
+    if name.is_empty() || name.len() > MAX_SAVED_FILTER_NAME_LENGTH {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "name should be between 1 and {MAX_SAVED_FILTER_NAME_LENGTH} characters"
+            ),
+        }));
+    }
+    if request.filters.saved_filter.is_some() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "filters of a saved filter cannot refer to another saved filter".to_string(),
+        }));
+    }
+    if let Some(tags) = &request.filters.tags {
+        helpers::validate_payment_tags(tags)?;
+    }
+
+    let saved_filter = SavedPaymentFilter {
+        name: name.to_string(),
+        description: request.description,
+        // The pagination is always taken from the payments list requests using the saved filter
+        filters: PaymentListFilterConstraints {
+            limit: common_utils::consts::default_payments_list_limit(),
+            offset: None,
+            ..request.filters
+        },
+        modified_at: common_utils::date_time::now(),
+    };
+
+    let db = state.store.as_ref();
+    let mut saved_filters = get_saved_filters(db, &merchant_account.merchant_id).await?;
+    saved_filters.retain(|existing_filter| existing_filter.name != saved_filter.name);
+    if saved_filters.len() >= consts::MAX_SAVED_PAYMENT_FILTERS {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "A merchant can have at most {} saved payment filters",
+                consts::MAX_SAVED_PAYMENT_FILTERS
+            ),
+        }));
+    }
+    saved_filters.push(saved_filter.clone());
+    store_saved_filters(db, &merchant_account.merchant_id, saved_filters).await?;
+
+    Ok(services::ApplicationResponse::Json(saved_filter))
+}
+
+pub async fn list_saved_payment_filters(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<SavedPaymentFilterListResponse> {
+    let saved_filters =
+        get_saved_filters(state.store.as_ref(), &merchant_account.merchant_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        SavedPaymentFilterListResponse {
+            count: saved_filters.len(),
+            data: saved_filters,
+        },
+    ))
+}
+
+pub async fn delete_saved_payment_filter(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    request: SavedPaymentFilterDeleteRequest,
+) -> RouterResponse<SavedPaymentFilter> {
+    let db = state.store.as_ref();
+    let mut saved_filters = get_saved_filters(db, &merchant_account.merchant_id).await?;
+    let position = saved_filters
+        .iter()
+        .position(|saved_filter| saved_filter.name == request.name)
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Saved payment filter `{}` does not exist", request.name),
+            })
+        })?;
+    let saved_filter = saved_filters.remove(position);
+    store_saved_filters(db, &merchant_account.merchant_id, saved_filters).await?;
+
+    Ok(services::ApplicationResponse::Json(saved_filter))
+}
@@ -0,0 +1,450 @@
+//! Suggestion of the payment methods most likely to succeed for a payment, based on the previous
+//! payments of the customer.
+
+use std::collections::HashMap;
+
+use api_models::payments as payments_api;
+use error_stack::ResultExt;
+use futures::future::join_all;
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::helpers;
+use crate::{
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        payment_methods::cards,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+/// Number of the most recent payments of the customer which are taken into account
+const CUSTOMER_PAYMENT_HISTORY_LIMIT: u32 = 50;
+/// Additional weight of a previous payment made from the same kind of device as this payment
+const SAME_DEVICE_TYPE_WEIGHT: f64 = 0.5;
+/// Additional weight of a previous payment billed to the same country as this payment
+const SAME_COUNTRY_WEIGHT: f64 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeviceType {
+    Mobile,
+    Tablet,
+    Desktop,
+}
+
+impl DeviceType {
+    fn from_browser_info(browser_info: Option<&serde_json::Value>) -> Option<Self> {
+        let user_agent = browser_info?.get("user_agent")?.as_str()?;
+
+        // Android tablets do not have `Mobile` in their user agent, unlike Android phones
+        if user_agent.contains("iPad")
+            || user_agent.contains("Tablet")
+            || (user_agent.contains("Android") && !user_agent.contains("Mobi"))
+        {
+            Some(Self::Tablet)
+        } else if user_agent.contains("Mobi") || user_agent.contains("iPhone") {
+            Some(Self::Mobile)
+        } else {
+            Some(Self::Desktop)
+        }
+    }
+}
+
+/// Attributes of a payment which the previous payments of the customer are matched against
+#[derive(Debug, Clone, Copy, Default)]
+struct PaymentContext {
+    device_type: Option<DeviceType>,
+    country: Option<storage_enums::CountryAlpha2>,
+}
+
+impl PaymentContext {
+    fn weight_of(&self, previous: &Self) -> f64 {
+        let mut weight = 1.0;
+        if self.device_type.is_some() && self.device_type == previous.device_type {
+            weight += SAME_DEVICE_TYPE_WEIGHT;
+        }
+        if self.country.is_some() && self.country == previous.country {
+            weight += SAME_COUNTRY_WEIGHT;
+        }
+        weight
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PreviousPayment {
+    payment_method: storage_enums::PaymentMethod,
+    payment_method_type: storage_enums::PaymentMethodType,
+    succeeded: bool,
+    created_at: PrimitiveDateTime,
+    context: PaymentContext,
+}
+
+#[derive(Debug, Default)]
+struct PaymentMethodHistory {
+    successful_payments: u32,
+    failed_payments: u32,
+    success_weight: f64,
+    failure_weight: f64,
+    last_successful_at: Option<PrimitiveDateTime>,
+}
+
+impl PaymentMethodHistory {
+    fn record(&mut self, payment: &PreviousPayment, weight: f64) {
+        if payment.succeeded {
+            self.successful_payments = self.successful_payments.saturating_add(1);
+            self.success_weight += weight;
+            self.last_successful_at = self.last_successful_at.max(Some(payment.created_at));
+        } else {
+            self.failed_payments = self.failed_payments.saturating_add(1);
+            self.failure_weight += weight;
+        }
+    }
+
+    /// Weighted success rate with Laplace smoothing, so that payment methods which were never
+    /// used by the customer score 0.5, and a single failure does not rule a method out
+    fn score(&self) -> f64 {
+        (self.success_weight + 1.0) / (self.success_weight + self.failure_weight + 2.0)
+    }
+}
+
+/// Rank the eligible payment methods by the weighted success rate of the previous payments of the
+/// customer, the order of the eligible payment methods is retained for ties
+fn rank_payment_methods(
+    eligible_payment_methods: Vec<(
+        storage_enums::PaymentMethod,
+        storage_enums::PaymentMethodType,
+    )>,
+    previous_payments: &[PreviousPayment],
+    context: &PaymentContext,
+) -> Vec<payments_api::SuggestedPaymentMethod> {
+    let mut history = HashMap::<_, PaymentMethodHistory>::new();
+    for payment in previous_payments {
+        history
+            .entry((payment.payment_method, payment.payment_method_type))
+            .or_default()
+            .record(payment, context.weight_of(&payment.context));
+    }
+
+    let mut suggested_payment_methods = eligible_payment_methods
+        .into_iter()
+        .map(|(payment_method, payment_method_type)| {
+            let history = history
+                .remove(&(payment_method, payment_method_type))
+                .unwrap_or_default();
+
+            payments_api::SuggestedPaymentMethod {
+                payment_method,
+                payment_method_type,
+                score: history.score(),
+                successful_payments: history.successful_payments,
+                failed_payments: history.failed_payments,
+                last_successful_at: history.last_successful_at,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    suggested_payment_methods.sort_by(|first, second| {
+        second
+            .score
+            .total_cmp(&first.score)
+            .then(second.successful_payments.cmp(&first.successful_payments))
+    });
+
+    suggested_payment_methods
+}
+
+async fn get_billing_country(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<Option<storage_enums::CountryAlpha2>> {
+    Ok(helpers::get_address_by_id(
+        db,
+        payment_intent.billing_address_id.clone(),
+        key_store,
+        &payment_intent.payment_id,
+        &merchant_account.merchant_id,
+        merchant_account.storage_scheme,
+    )
+    .await?
+    .and_then(|address| address.country))
+}
+
+async fn get_previous_payments(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: String,
+    payment_id: &str,
+) -> errors::RouterResult<Vec<PreviousPayment>> {
+    let db = &*state.store;
+    let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+        offset: 0,
+        starting_at: None,
+        ending_at: None,
+        amount_filter: None,
+        connector: None,
+        currency: None,
+        status: Some(vec![
+            storage_enums::IntentStatus::Succeeded,
+            storage_enums::IntentStatus::PartiallyCaptured,
+            storage_enums::IntentStatus::Failed,
+        ]),
+        payment_method: None,
+        payment_method_type: None,
+        authentication_type: None,
+        merchant_connector_id: None,
+        tags: None,
+        profile_id: None,
+        customer_id: Some(customer_id),
+        starting_after_id: None,
+        ending_before_id: None,
+        limit: Some(CUSTOMER_PAYMENT_HISTORY_LIMIT),
+    }));
+
+    let payments = db
+        .get_filtered_payment_intents_attempt(
+            &merchant_account.merchant_id,
+            &constraints,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the previous payments of the customer")?;
+
+    join_all(
+        payments
+            .into_iter()
+            .filter(|(payment_intent, _)| payment_intent.payment_id != payment_id)
+            .filter_map(|(payment_intent, payment_attempt)| {
+                let payment_method = payment_attempt.payment_method?;
+                let payment_method_type = payment_attempt.payment_method_type?;
+
+                Some(async move {
+                    let country =
+                        get_billing_country(db, &payment_intent, merchant_account, key_store)
+                            .await?;
+
+                    Ok(PreviousPayment {
+                        payment_method,
+                        payment_method_type,
+                        succeeded: matches!(
+                            payment_intent.status,
+                            storage_enums::IntentStatus::Succeeded
+                                | storage_enums::IntentStatus::PartiallyCaptured
+                        ),
+                        created_at: payment_intent.created_at,
+                        context: PaymentContext {
+                            device_type: DeviceType::from_browser_info(
+                                payment_attempt.browser_info.as_ref(),
+                            ),
+                            country,
+                        },
+                    })
+                })
+            }),
+    )
+    .await
+    .into_iter()
+    .collect()
+}
+
+#[instrument(skip_all)]
+pub async fn get_suggested_payment_methods(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: String,
+) -> RouterResponse<payments_api::PaymentsSuggestedMethodsResponse> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            &merchant_account.merchant_id,
+            &payment_intent.active_attempt.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let context = PaymentContext {
+        device_type: DeviceType::from_browser_info(payment_attempt.browser_info.as_ref()),
+        country: get_billing_country(db, &payment_intent, &merchant_account, &key_store).await?,
+    };
+
+    // The eligibility of a payment method for the payment is the same as in the payment methods
+    // list shown during checkout
+    let payment_method_list = match cards::list_payment_methods(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        api::PaymentMethodListRequest {
+            client_secret: payment_intent.client_secret.clone(),
+            ..Default::default()
+        },
+    )
+    .await?
+    {
+        services::ApplicationResponse::Json(response) => Ok(response),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get the payment methods list in json"),
+    }?;
+
+    let eligible_payment_methods = payment_method_list
+        .payment_methods
+        .into_iter()
+        .flat_map(|payment_methods_enabled| {
+            let payment_method = payment_methods_enabled.payment_method;
+            payment_methods_enabled
+                .payment_method_types
+                .into_iter()
+                .map(move |payment_method_types| {
+                    (payment_method, payment_method_types.payment_method_type)
+                })
+        })
+        .collect();
+
+    let previous_payments = match payment_intent.customer_id.clone() {
+        Some(customer_id) => {
+            get_previous_payments(
+                &state,
+                &merchant_account,
+                &key_store,
+                customer_id,
+                &payment_intent.payment_id,
+            )
+            .await?
+        }
+        None => Vec::new(),
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        payments_api::PaymentsSuggestedMethodsResponse {
+            suggested_payment_methods: rank_payment_methods(
+                eligible_payment_methods,
+                &previous_payments,
+                &context,
+            ),
+            payment_id: payment_intent.payment_id,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn previous_payment(
+        payment_method_type: storage_enums::PaymentMethodType,
+        succeeded: bool,
+        context: PaymentContext,
+    ) -> PreviousPayment {
+        PreviousPayment {
+            payment_method: storage_enums::PaymentMethod::Wallet,
+            payment_method_type,
+            succeeded,
+            created_at: common_utils::date_time::now(),
+            context,
+        }
+    }
+
+    #[test]
+    fn test_payment_methods_are_ranked_by_previous_successes() {
+        let eligible_payment_methods = vec![
+            (
+                storage_enums::PaymentMethod::Wallet,
+                storage_enums::PaymentMethodType::ApplePay,
+            ),
+            (
+                storage_enums::PaymentMethod::Wallet,
+                storage_enums::PaymentMethodType::GooglePay,
+            ),
+            (
+                storage_enums::PaymentMethod::Wallet,
+                storage_enums::PaymentMethodType::Paypal,
+            ),
+        ];
+        let mobile_in_germany = PaymentContext {
+            device_type: Some(DeviceType::Mobile),
+            country: Some(storage_enums::CountryAlpha2::DE),
+        };
+        let previous_payments = [
+            previous_payment(
+                storage_enums::PaymentMethodType::ApplePay,
+                false,
+                mobile_in_germany,
+            ),
+            previous_payment(
+                storage_enums::PaymentMethodType::Paypal,
+                true,
+                PaymentContext::default(),
+            ),
+            previous_payment(
+                storage_enums::PaymentMethodType::GooglePay,
+                true,
+                mobile_in_germany,
+            ),
+        ];
+
+        let suggested_payment_methods = rank_payment_methods(
+            eligible_payment_methods,
+            &previous_payments,
+            &mobile_in_germany,
+        );
+
+        assert_eq!(
+            suggested_payment_methods
+                .iter()
+                .map(|suggestion| suggestion.payment_method_type)
+                .collect::<Vec<_>>(),
+            vec![
+                storage_enums::PaymentMethodType::GooglePay,
+                storage_enums::PaymentMethodType::Paypal,
+                storage_enums::PaymentMethodType::ApplePay,
+            ]
+        );
+        assert!(suggested_payment_methods
+            .iter()
+            .all(|suggestion| suggestion.successful_payments + suggestion.failed_payments == 1));
+    }
+
+    #[test]
+    fn test_device_type_from_user_agent() {
+        let device_type = |user_agent: &str| {
+            DeviceType::from_browser_info(Some(&serde_json::json!({ "user_agent": user_agent })))
+        };
+
+        assert_eq!(
+            device_type("Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) Mobile/15E148"),
+            Some(DeviceType::Mobile)
+        );
+        assert_eq!(
+            device_type("Mozilla/5.0 (Linux; Android 14; SM-X710) Chrome/120.0 Safari/537.36"),
+            Some(DeviceType::Tablet)
+        );
+        assert_eq!(
+            device_type("Mozilla/5.0 (Windows NT 10.0; Win64; x64) Chrome/120.0 Safari/537.36"),
+            Some(DeviceType::Desktop)
+        );
+        assert_eq!(DeviceType::from_browser_info(None), None);
+    }
+}
@@ -130,6 +130,21 @@ where
                             message: "Apple Pay Decrypt token is not supported".to_string(),
                         })?
                     }
+                    types::PaymentMethodToken::GooglePayDecrypt(_) => {
+                        Err(errors::ApiErrorResponse::NotSupported {
+                            message: "Google Pay Decrypt token is not supported".to_string(),
+                        })?
+                    }
+                    types::PaymentMethodToken::SamsungPayDecrypt(_) => {
+                        Err(errors::ApiErrorResponse::NotSupported {
+                            message: "Samsung Pay Decrypt token is not supported".to_string(),
+                        })?
+                    }
+                    types::PaymentMethodToken::NetworkToken(_) => {
+                        Err(errors::ApiErrorResponse::NotSupported {
+                            message: "Network token is not supported".to_string(),
+                        })?
+                    }
                 };
                 Some((connector_name, token))
             } else {
@@ -446,7 +461,7 @@ where
 
                                 let add_card_resp = payment_methods::cards::add_card_hs(
                                     state,
-                                    payment_method_create_request,
+                                    payment_method_create_request.clone(),
                                     &card,
                                     customer_id.clone(),
                                     merchant_account,
@@ -555,6 +570,17 @@ where
                     }
                 }
 
+                if state.conf.locker.locker_enabled {
+                    payment_methods::network_tokenization::provision_network_token_if_required(
+                        state,
+                        merchant_account,
+                        profile_id.as_deref(),
+                        &payment_method_create_request,
+                        &resp.payment_method_id,
+                    )
+                    .await;
+                }
+
                 Some(resp.payment_method_id)
             } else {
                 None
@@ -119,7 +119,7 @@ where
         None
     };
 
-    let apple_pay_flow = payments::decide_apple_pay_flow(
+    let apple_pay_flow = payments::wallets::decide_apple_pay_flow(
         &payment_data.payment_attempt.payment_method_type,
         Some(merchant_connector_account),
     );
@@ -174,6 +174,7 @@ where
         connector_api_version,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow,
         frm_metadata: None,
         refund_id: None,
@@ -529,11 +530,14 @@ where
         let next_action_containing_wait_screen =
             wait_screen_next_steps_check(payment_attempt.clone())?;
 
+        let next_action_micro_deposits = micro_deposits_next_steps_check(payment_attempt.clone())?;
+
         if payment_intent.status == enums::IntentStatus::RequiresCustomerAction
             || bank_transfer_next_steps.is_some()
             || next_action_voucher.is_some()
             || next_action_containing_qr_code_url.is_some()
             || next_action_containing_wait_screen.is_some()
+            || next_action_micro_deposits.is_some()
             || payment_data.authentication.is_some()
         {
             next_action_response = bank_transfer_next_steps
@@ -556,6 +560,11 @@ where
                                 display_to_timestamp: wait_screen_data.display_to_timestamp,
                             }
                         }))
+                        .or(next_action_micro_deposits.map(|micro_deposits_details| {
+                            api_models::payments::NextActionData::VerifyWithMicroDeposits {
+                                micro_deposits_details,
+                            }
+                        }))
                         .or(payment_attempt.authentication_data.as_ref().map(|_| {
                             api_models::payments::NextActionData::RedirectToUrl {
                                 redirect_to_url: helpers::create_startpay_url(
@@ -625,6 +634,28 @@ where
             )
         });
 
+        let split_payments = payment_intent
+            .split_payments
+            .clone()
+            .map(|split_payments| {
+                split_payments
+                    .parse_value("SplitPayments")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to parse the split payments of the payment intent")
+            })
+            .transpose()?;
+
+        let l2_l3_data = payment_intent
+            .l2_l3_data
+            .clone()
+            .map(|l2_l3_data| {
+                l2_l3_data
+                    .parse_value("L2L3Data")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to parse the l2 l3 data of the payment intent")
+            })
+            .transpose()?;
+
         services::ApplicationResponse::JsonWithHeaders((
             response
                 .set_net_amount(payment_attempt.net_amount)
@@ -634,6 +665,7 @@ where
                 .set_amount(payment_attempt.amount)
                 .set_amount_capturable(Some(payment_attempt.amount_capturable))
                 .set_amount_received(payment_intent.amount_captured)
+                .set_amount_reversed(payment_attempt.amount_reversed)
                 .set_surcharge_details(surcharge_details)
                 .set_connector(routed_through)
                 .set_client_secret(payment_intent.client_secret.map(masking::Secret::new))
@@ -699,6 +731,7 @@ where
                             }
                         }),
                         update_mandate_id: d.update_mandate_id,
+                        pre_notification_days: d.pre_notification_days,
                     }),
                     auth_flow == services::AuthFlow::Merchant,
                 )
@@ -767,6 +800,20 @@ where
                 .set_fingerprint(payment_intent.fingerprint_id)
                 .set_authorization_count(payment_intent.authorization_count)
                 .set_incremental_authorizations(incremental_authorizations_response)
+                .set_overcaptured_amount(payment_attempt.overcaptured_amount)
+                .set_extended_authorization_applied(payment_attempt.extended_authorization_applied)
+                .set_capture_before(payment_attempt.capture_before)
+                .set_order_tax_amount(payment_intent.order_tax_amount)
+                .set_split_payments(split_payments)
+                .set_payment_channel(payment_intent.payment_channel)
+                .set_tags(payment_intent.tags)
+                .set_capture_adjustment_reason(payment_intent.capture_adjustment_reason)
+                .set_installment_tenure(
+                    payment_intent
+                        .installment_tenure
+                        .and_then(|installment_tenure| u8::try_from(installment_tenure).ok()),
+                )
+                .set_l2_l3_data(l2_l3_data)
                 .set_expires_on(payment_intent.session_expiry)
                 .set_external_3ds_authentication_attempted(
                     payment_attempt.external_three_ds_authentication_attempted,
@@ -850,6 +897,24 @@ pub fn wait_screen_next_steps_check(
     Ok(display_info_with_timer_instructions)
 }
 
+pub fn micro_deposits_next_steps_check(
+    payment_attempt: storage::PaymentAttempt,
+) -> RouterResult<Option<api_models::payments::MicroDepositsVerificationDetails>> {
+    // Only payments pending verification of the micro-deposits have to display them
+    if payment_attempt.status != enums::AttemptStatus::AuthenticationPending {
+        return Ok(None);
+    }
+
+    let micro_deposits_steps: Option<
+        Result<api_models::payments::MicroDepositsVerificationDetails, _>,
+    > = payment_attempt
+        .connector_metadata
+        .map(|metadata| metadata.parse_value("MicroDepositsVerificationDetails"));
+
+    let micro_deposits_instructions = micro_deposits_steps.transpose().ok().flatten();
+    Ok(micro_deposits_instructions)
+}
+
 impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::PaymentsResponse {
     fn foreign_from(item: (storage::PaymentIntent, storage::PaymentAttempt)) -> Self {
         let pi = item.0;
@@ -880,6 +945,15 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             attempt_count: pi.attempt_count,
             profile_id: pi.profile_id,
             merchant_connector_id: pa.merchant_connector_id,
+            split_payments: pi
+                .split_payments
+                .and_then(|split_payments| split_payments.parse_value("SplitPayments").ok()),
+            payment_channel: pi.payment_channel,
+            tags: pi.tags,
+            capture_adjustment_reason: pi.capture_adjustment_reason,
+            l2_l3_data: pi
+                .l2_l3_data
+                .and_then(|l2_l3_data| l2_l3_data.parse_value("L2L3Data").ok()),
             ..Default::default()
         }
     }
@@ -1110,6 +1184,38 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             })
             .transpose()?;
 
+        let split_payments = payment_data
+            .payment_intent
+            .split_payments
+            .clone()
+            .map(|split_payments| {
+                split_payments
+                    .parse_value("SplitPayments")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to parse the split payments of the payment intent")
+            })
+            .transpose()?;
+
+        // The order tax amount of the payment is forwarded as the tax amount of the level 2 data
+        // when the merchant has not provided it
+        let l2_l3_data = payment_data
+            .payment_intent
+            .l2_l3_data
+            .clone()
+            .map(|l2_l3_data| {
+                l2_l3_data
+                    .parse_value::<api_models::payments::L2L3Data>("L2L3Data")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to parse the l2 l3 data of the payment intent")
+            })
+            .transpose()?
+            .map(|l2_l3_data| api_models::payments::L2L3Data {
+                tax_amount: l2_l3_data
+                    .tax_amount
+                    .or(payment_data.payment_intent.order_tax_amount),
+                ..l2_l3_data
+            });
+
         let complete_authorize_url = Some(helpers::create_complete_authorize_url(
             router_base_url,
             attempt,
@@ -1188,13 +1294,32 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                 Some(RequestIncrementalAuthorization::True)
                     | Some(RequestIncrementalAuthorization::Default)
             ),
+            request_overcapture: payment_data.payment_intent.request_overcapture == Some(true),
+            request_extended_authorization: payment_data
+                .payment_intent
+                .request_extended_authorization
+                == Some(true),
+            split_payments,
+            payment_channel: payment_data.payment_intent.payment_channel,
+            l2_l3_data,
+            installment_tenure: payment_data
+                .payment_intent
+                .installment_tenure
+                .and_then(|installment_tenure| u8::try_from(installment_tenure).ok()),
             metadata: additional_data.payment_data.payment_intent.metadata,
             authentication_data: payment_data
                 .authentication
                 .as_ref()
                 .map(AuthenticationData::foreign_try_from)
-                .transpose()?,
+                .transpose()?
+                .or_else(|| {
+                    payment_data
+                        .external_three_ds_authentication_data
+                        .as_ref()
+                        .map(AuthenticationData::foreign_from)
+                }),
             customer_acceptance: payment_data.customer_acceptance,
+            preferred_card_network: payment_data.preferred_card_network,
         })
     }
 }
@@ -1271,6 +1396,34 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>>
     }
 }
 
+impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsVerifyMicroDepositsData {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(additional_data: PaymentAdditionalData<'_, F>) -> Result<Self, Self::Error> {
+        let payment_data = additional_data.payment_data;
+        let connector = api::ConnectorData::get_connector_by_name(
+            &additional_data.state.conf.connectors,
+            &additional_data.connector_name,
+            api::GetToken::Connector,
+            payment_data.payment_attempt.merchant_connector_id.clone(),
+        )?;
+        let micro_deposits_details = payment_data.micro_deposits_details.ok_or(
+            report!(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("missing micro_deposits_details in payment_data"),
+        )?;
+        Ok(Self {
+            amounts: micro_deposits_details.amounts,
+            descriptor_code: micro_deposits_details.descriptor_code,
+            currency: payment_data.currency,
+            connector_transaction_id: connector
+                .connector
+                .connector_transaction_id(payment_data.payment_attempt.clone())?
+                .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)?,
+            connector_meta: payment_data.payment_attempt.connector_metadata,
+        })
+    }
+}
+
 impl api::ConnectorTransactionId for Helcim {
     fn connector_transaction_id(
         &self,
@@ -1320,6 +1473,23 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "browser_info",
             })?;
+        let order_details = payment_data
+            .payment_intent
+            .order_details
+            .map(|order_details| {
+                order_details
+                    .iter()
+                    .map(|data| {
+                        data.to_owned()
+                            .parse_value("OrderDetailsWithAmount")
+                            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                                field_name: "OrderDetailsWithAmount",
+                            })
+                            .attach_printable("Unable to parse OrderDetailsWithAmount")
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
 
         Ok(Self {
             amount_to_capture,
@@ -1342,6 +1512,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
             },
             browser_info,
             metadata: payment_data.payment_intent.metadata,
+            order_details,
+            capture_adjustment_reason: payment_data.payment_intent.capture_adjustment_reason,
         })
     }
 }
@@ -1366,6 +1538,13 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "browser_info",
             })?;
+        let amount_to_reverse = helpers::is_partial_reversal(payment_data.payment_intent.status)
+            .then(|| {
+                helpers::get_uncaptured_amount(
+                    &payment_data.payment_intent,
+                    &payment_data.payment_attempt,
+                )
+            });
         Ok(Self {
             amount: Some(payment_data.amount.into()),
             currency: Some(payment_data.currency),
@@ -1377,6 +1556,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
             connector_meta: payment_data.payment_attempt.connector_metadata,
             browser_info,
             metadata: payment_data.payment_intent.metadata,
+            amount_to_reverse,
         })
     }
 }
@@ -1447,6 +1627,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsSessionD
             ),
             order_details,
             surcharge_details: payment_data.surcharge_details,
+            profile_id: payment_data.payment_intent.profile_id,
         })
     }
 }
@@ -10,6 +10,7 @@ use common_utils::{
 use diesel_models::business_profile::BusinessProfile;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt;
+use masking::ExposeInterface;
 use redis_interface::errors::RedisError;
 use router_env::{instrument, tracing};
 
@@ -19,7 +20,7 @@ use crate::{
     routes::AppState,
     types::{
         storage::{self, enums as storage_enums},
-        transformers::ForeignTryFrom,
+        transformers::{ForeignFrom, ForeignTryFrom},
     },
 };
 
@@ -379,10 +380,29 @@ impl SurchargeMetadata {
 pub struct AuthenticationData {
     pub eci: Option<String>,
     pub cavv: String,
-    pub threeds_server_transaction_id: String,
+    pub threeds_server_transaction_id: Option<String>,
+    /// The transaction ID of the directory server, which is only known for the payments
+    /// authenticated by the merchant
+    pub ds_trans_id: Option<String>,
     pub message_version: String,
 }
 
+impl ForeignFrom<&api_models::payments::ExternalThreeDsAuthenticationData> for AuthenticationData {
+    fn foreign_from(
+        authentication_data: &api_models::payments::ExternalThreeDsAuthenticationData,
+    ) -> Self {
+        Self {
+            eci: authentication_data.eci.clone(),
+            cavv: authentication_data.cavv.clone().expose(),
+            threeds_server_transaction_id: authentication_data
+                .three_ds_server_transaction_id
+                .clone(),
+            ds_trans_id: Some(authentication_data.ds_transaction_id.clone()),
+            message_version: authentication_data.message_version.to_string(),
+        }
+    }
+}
+
 impl ForeignTryFrom<&storage::Authentication> for AuthenticationData {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
     fn foreign_try_from(authentication: &storage::Authentication) -> Result<Self, Self::Error> {
@@ -410,7 +430,8 @@ impl ForeignTryFrom<&storage::Authentication> for AuthenticationData {
             Ok(Self {
                 eci: authentication.eci.clone(),
                 cavv,
-                threeds_server_transaction_id,
+                threeds_server_transaction_id: Some(threeds_server_transaction_id),
+                ds_trans_id: None,
                 message_version: message_version.to_string(),
             })
         } else {
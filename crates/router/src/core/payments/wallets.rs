@@ -0,0 +1,1759 @@
+use api_models::{
+    admin::{self as admin_types, WalletCertificates},
+    enums,
+    payments::{self as payment_types, PaymentProcessingDetailsAt},
+};
+use base64::Engine;
+use common_utils::{
+    ext_traits::{ByteSliceExt, Encode},
+    pii,
+};
+use diesel_models::encryption::Encryption;
+use error_stack::{report, Report, ResultExt};
+use josekit::jwe;
+use masking::{ExposeInterface, PeekInterface, Secret};
+use openssl::{
+    derive::Deriver,
+    pkey::PKey,
+    symm::{decrypt_aead, Cipher},
+};
+use router_env::{instrument, logger, tracing};
+use x509_parser::{certificate::X509Certificate, parse_x509_certificate};
+
+use super::{helpers, PaymentData};
+use crate::{
+    connector,
+    consts::{self, BASE64_ENGINE},
+    core::errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    headers,
+    routes::AppState,
+    services::{self, ApplicationResponse},
+    types::{
+        self as router_types, api,
+        domain::{self, types as domain_types},
+        storage,
+    },
+    utils::{OptionExt, ValueExt},
+};
+
+/// Certificates and keys of the wallets of the business profile, `None` if none have been set for
+/// the business profile
+#[instrument(skip_all)]
+pub async fn get_wallet_certificates(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &str,
+) -> RouterResult<Option<WalletCertificates>> {
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+
+    decrypt_wallet_certificates(&business_profile, key_store).await
+}
+
+/// Certificates and keys of the wallets of the business profile of the payment, which are only
+/// looked up for the payments made with the wallets they are used for
+pub async fn get_wallet_certificates_if_required<F: Clone>(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<Option<WalletCertificates>> {
+    let is_wallet_with_certificates = matches!(
+        payment_data.payment_method_data,
+        Some(api::PaymentMethodData::Wallet(
+            payment_types::WalletData::ApplePay(_)
+                | payment_types::WalletData::GooglePay(_)
+                | payment_types::WalletData::SamsungPay(_)
+        ))
+    );
+
+    match payment_data.payment_intent.profile_id.as_deref() {
+        Some(profile_id) if is_wallet_with_certificates => {
+            get_wallet_certificates(state, key_store, profile_id).await
+        }
+        _ => Ok(None),
+    }
+}
+
+async fn decrypt_wallet_certificates(
+    business_profile: &storage::BusinessProfile,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<WalletCertificates>> {
+    domain_types::decrypt::<serde_json::Value, masking::WithType>(
+        business_profile.wallet_certificates.clone(),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the wallet certificates of the business profile")?
+    .map(|wallet_certificates| {
+        wallet_certificates
+            .into_inner()
+            .expose()
+            .parse_value::<WalletCertificates>("WalletCertificates")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the wallet certificates of the business profile")
+    })
+    .transpose()
+}
+
+async fn encrypt_wallet_certificates(
+    wallet_certificates: &WalletCertificates,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Encryption> {
+    let wallet_certificates = wallet_certificates
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the wallet certificates")?;
+
+    domain_types::encrypt(
+        Secret::<_, masking::WithType>::new(wallet_certificates),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the wallet certificates")
+    .map(Encryption::from)
+}
+
+/// Parse the Apple Pay metadata of the merchant connector account, in the format of the combined
+/// metadata of the simplified and manual flows or in the older format of the manual flow
+fn parse_apple_pay_metadata(
+    connector_metadata: Option<pii::SecretSerdeValue>,
+) -> CustomResult<payment_types::ApplepaySessionTokenMetadata, errors::ParsingError> {
+    connector_metadata
+        .clone()
+        .parse_value::<payment_types::ApplepayCombinedSessionTokenData>(
+            "ApplepayCombinedSessionTokenData",
+        )
+        .map(|combined_metadata| {
+            payment_types::ApplepaySessionTokenMetadata::ApplePayCombined(
+                combined_metadata.apple_pay_combined,
+            )
+        })
+        .or_else(|_| {
+            connector_metadata
+                .parse_value::<payment_types::ApplepaySessionTokenData>("ApplepaySessionTokenData")
+                .map(|old_metadata| {
+                    payment_types::ApplepaySessionTokenMetadata::ApplePay(old_metadata.apple_pay)
+                })
+        })
+}
+
+fn get_apple_pay_metadata(
+    merchant_connector_account: Option<&helpers::MerchantConnectorAccountType>,
+) -> Option<payment_types::ApplepaySessionTokenMetadata> {
+    merchant_connector_account.and_then(|mca| {
+        mca.get_metadata().and_then(|apple_pay_metadata| {
+            parse_apple_pay_metadata(Some(apple_pay_metadata))
+                .map_err(
+                    |error| logger::warn!(%error, "Failed to Parse Value to ApplepaySessionTokenData"),
+                )
+                .ok()
+        })
+    })
+}
+
+pub fn decide_apple_pay_flow(
+    payment_method_type: &Option<enums::PaymentMethodType>,
+    merchant_connector_account: Option<&helpers::MerchantConnectorAccountType>,
+) -> Option<enums::ApplePayFlow> {
+    payment_method_type.and_then(|pmt| match pmt {
+        enums::PaymentMethodType::ApplePay => check_apple_pay_metadata(merchant_connector_account),
+        _ => None,
+    })
+}
+
+fn check_apple_pay_metadata(
+    merchant_connector_account: Option<&helpers::MerchantConnectorAccountType>,
+) -> Option<enums::ApplePayFlow> {
+    get_apple_pay_metadata(merchant_connector_account).map(|metadata| match metadata {
+        payment_types::ApplepaySessionTokenMetadata::ApplePayCombined(apple_pay_combined) => {
+            match apple_pay_combined {
+                payment_types::ApplePayCombinedMetadata::Simplified { .. } => {
+                    enums::ApplePayFlow::Simplified
+                }
+                payment_types::ApplePayCombinedMetadata::Manual { .. } => {
+                    enums::ApplePayFlow::Manual
+                }
+            }
+        }
+        payment_types::ApplepaySessionTokenMetadata::ApplePay(_) => enums::ApplePayFlow::Manual,
+    })
+}
+
+/// Certificate and key to decrypt the Apple Pay payment token with, `None` if the payment token
+/// has to be forwarded to the connector as is
+///
+/// The payment processing certificate of Hyperswitch is used in the simplified flow. In the manual
+/// flow, the certificate of the business profile takes precedence over the one in the metadata of
+/// the merchant connector account, unless the metadata has the connector decrypt the payment
+/// token.
+pub fn get_apple_pay_payment_processing_details(
+    state: &AppState,
+    payment_method_type: &Option<enums::PaymentMethodType>,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    wallet_certificates: Option<&WalletCertificates>,
+) -> Option<payment_types::PaymentProcessingDetails> {
+    match decide_apple_pay_flow(payment_method_type, Some(merchant_connector_account))? {
+        enums::ApplePayFlow::Simplified => {
+            let applepay_decrypt_keys = state.conf.applepay_decrypt_keys.get_inner();
+            Some(payment_types::PaymentProcessingDetails {
+                payment_processing_certificate: applepay_decrypt_keys.apple_pay_ppc.clone(),
+                payment_processing_certificate_key: applepay_decrypt_keys.apple_pay_ppc_key.clone(),
+            })
+        }
+        enums::ApplePayFlow::Manual => {
+            let payment_processing_details_at =
+                get_apple_pay_metadata(Some(merchant_connector_account)).and_then(|metadata| {
+                    match metadata {
+                        payment_types::ApplepaySessionTokenMetadata::ApplePayCombined(
+                            payment_types::ApplePayCombinedMetadata::Manual {
+                                session_token_data,
+                                ..
+                            },
+                        )
+                        | payment_types::ApplepaySessionTokenMetadata::ApplePay(
+                            payment_types::ApplePayMetadata {
+                                session_token_data, ..
+                            },
+                        ) => session_token_data.payment_processing_details_at,
+                        payment_types::ApplepaySessionTokenMetadata::ApplePayCombined(
+                            payment_types::ApplePayCombinedMetadata::Simplified { .. },
+                        ) => None,
+                    }
+                });
+            let profile_payment_processing_details = wallet_certificates
+                .and_then(|wallet_certificates| wallet_certificates.apple_pay.as_ref())
+                .and_then(|apple_pay| apple_pay.payment_processing_details.clone());
+
+            match payment_processing_details_at {
+                Some(PaymentProcessingDetailsAt::Connector) => None,
+                Some(PaymentProcessingDetailsAt::Hyperswitch(payment_processing_details)) => {
+                    Some(profile_payment_processing_details.unwrap_or(payment_processing_details))
+                }
+                None => profile_payment_processing_details,
+            }
+        }
+    }
+}
+
+/// Decrypt the Apple Pay payment token, for the connectors which are passed the decrypted payment
+/// data instead of the payment token
+pub async fn decrypt_apple_pay_token<F: Clone>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    wallet_certificates: Option<&WalletCertificates>,
+) -> RouterResult<router_types::ApplePayPredecryptData> {
+    let payment_processing_details = get_apple_pay_payment_processing_details(
+        state,
+        &payment_data.payment_attempt.payment_method_type,
+        merchant_connector_account,
+        wallet_certificates,
+    )
+    .ok_or(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Apple Pay payment processing details are not configured")?;
+
+    let wallet_data = match payment_data
+        .payment_method_data
+        .clone()
+        .map(domain::PaymentMethodData::from)
+    {
+        Some(domain::PaymentMethodData::Wallet(wallet_data @ domain::WalletData::ApplePay(_))) => {
+            wallet_data
+        }
+        _ => {
+            return Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Payment method data of the payment is not of Apple Pay")
+        }
+    };
+
+    ApplePayData::token_json(wallet_data)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .decrypt(&payment_processing_details)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .parse_value::<router_types::ApplePayPredecryptData>("ApplePayPredecryptData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+/// Request to create the Apple Pay session with, along with the merchant identity certificate it
+/// is created for and the payment request data of the merchant
+#[derive(Clone, Debug)]
+pub struct ApplePaySessionDetails {
+    pub payment_request_data: payment_types::PaymentRequestMetadata,
+    pub session_request: payment_types::ApplepaySessionRequest,
+    pub merchant_certificate: Secret<String>,
+    pub merchant_certificate_key: Secret<String>,
+    pub merchant_business_country: Option<enums::CountryAlpha2>,
+}
+
+/// The Apple Pay session is created for the merchant identity of Hyperswitch in the simplified
+/// flow. In the manual flow, the merchant identity certificate of the business profile takes
+/// precedence over the one in the metadata of the merchant connector account.
+pub fn get_apple_pay_session_details(
+    state: &AppState,
+    connector_metadata: Option<pii::SecretSerdeValue>,
+    wallet_certificates: Option<&WalletCertificates>,
+) -> RouterResult<ApplePaySessionDetails> {
+    let apple_pay_metadata = parse_apple_pay_metadata(connector_metadata).change_context(
+        errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "connector_metadata".to_string(),
+            expected_format: "applepay_metadata_format".to_string(),
+        },
+    )?;
+
+    let (payment_request_data, session_token_data) = match apple_pay_metadata {
+        payment_types::ApplepaySessionTokenMetadata::ApplePayCombined(
+            payment_types::ApplePayCombinedMetadata::Simplified {
+                payment_request_data,
+                session_token_data,
+            },
+        ) => {
+            let applepay_decrypt_keys = state.conf.applepay_decrypt_keys.get_inner();
+            return Ok(ApplePaySessionDetails {
+                payment_request_data,
+                session_request: payment_types::ApplepaySessionRequest {
+                    merchant_identifier: state
+                        .conf
+                        .applepay_merchant_configs
+                        .get_inner()
+                        .common_merchant_identifier
+                        .clone()
+                        .expose(),
+                    display_name: "Apple pay".to_string(),
+                    initiative: "web".to_string(),
+                    initiative_context: session_token_data.initiative_context,
+                },
+                merchant_certificate: applepay_decrypt_keys.apple_pay_merchant_cert.clone(),
+                merchant_certificate_key: applepay_decrypt_keys.apple_pay_merchant_cert_key.clone(),
+                merchant_business_country: session_token_data.merchant_business_country,
+            });
+        }
+        payment_types::ApplepaySessionTokenMetadata::ApplePayCombined(
+            payment_types::ApplePayCombinedMetadata::Manual {
+                payment_request_data,
+                session_token_data,
+            },
+        ) => (payment_request_data, session_token_data),
+        payment_types::ApplepaySessionTokenMetadata::ApplePay(apple_pay_metadata) => (
+            apple_pay_metadata.payment_request_data,
+            apple_pay_metadata.session_token_data,
+        ),
+    };
+
+    let (merchant_identifier, merchant_certificate, merchant_certificate_key) =
+        match wallet_certificates
+            .and_then(|wallet_certificates| wallet_certificates.apple_pay.as_ref())
+        {
+            Some(apple_pay_certificates) => (
+                apple_pay_certificates.merchant_identifier.clone(),
+                apple_pay_certificates.merchant_certificate.clone(),
+                apple_pay_certificates.merchant_certificate_key.clone(),
+            ),
+            None => (
+                session_token_data.merchant_identifier,
+                session_token_data.certificate,
+                session_token_data.certificate_keys,
+            ),
+        };
+
+    Ok(ApplePaySessionDetails {
+        payment_request_data,
+        session_request: payment_types::ApplepaySessionRequest {
+            merchant_identifier,
+            display_name: session_token_data.display_name,
+            initiative: session_token_data.initiative,
+            initiative_context: session_token_data.initiative_context,
+        },
+        merchant_certificate,
+        merchant_certificate_key,
+        merchant_business_country: session_token_data.merchant_business_country,
+    })
+}
+
+/// Create the Apple Pay session with the merchant identity certificate, `None` if Apple Pay did
+/// not create the session, in which case the error is logged
+#[instrument(skip_all)]
+pub async fn create_apple_pay_session(
+    state: &AppState,
+    session_details: &ApplePaySessionDetails,
+) -> RouterResult<Option<payment_types::NoThirdPartySdkSessionResponse>> {
+    let applepay_session_request = build_apple_pay_session_request(state, session_details)?;
+    let response = services::call_connector_api(
+        state,
+        applepay_session_request,
+        "create_apple_pay_session_token",
+    )
+    .await;
+
+    // logging the error if present in session call response
+    log_session_response_if_error(&response);
+
+    Ok(response
+        .ok()
+        .and_then(|apple_pay_res| {
+            apple_pay_res
+                .map(|res| {
+                    let response: Result<
+                        payment_types::NoThirdPartySdkSessionResponse,
+                        Report<common_utils::errors::ParsingError>,
+                    > = res.response.parse_struct("NoThirdPartySdkSessionResponse");
+
+                    // logging the parsing failed error
+                    if let Err(error) = response.as_ref() {
+                        logger::error!(?error);
+                    };
+
+                    response.ok()
+                })
+                .ok()
+        })
+        .flatten())
+}
+
+fn build_apple_pay_session_request(
+    state: &AppState,
+    session_details: &ApplePaySessionDetails,
+) -> RouterResult<services::Request> {
+    let mut url = state.conf.connectors.applepay.base_url.to_owned();
+    url.push_str("paymentservices/paymentSession");
+
+    let session_request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(url.as_str())
+        .attach_default_headers()
+        .headers(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            "application/json".to_string().into(),
+        )])
+        .set_body(common_utils::request::RequestContent::Json(Box::new(
+            session_details.session_request.clone(),
+        )))
+        .add_certificate(Some(session_details.merchant_certificate.clone()))
+        .add_certificate_key(Some(session_details.merchant_certificate_key.clone()))
+        .build();
+    Ok(session_request)
+}
+
+fn log_session_response_if_error(
+    response: &Result<
+        Result<router_types::Response, router_types::Response>,
+        Report<errors::ApiClientError>,
+    >,
+) {
+    if let Err(error) = response.as_ref() {
+        logger::error!(?error);
+    };
+    response
+        .as_ref()
+        .ok()
+        .map(|res| res.as_ref().map_err(|error| logger::error!(?error)));
+}
+
+/// Private key and recipient to decrypt the Google Pay payment token with, those of the business
+/// profile taking precedence over the ones in the metadata of the merchant connector account
+fn get_google_pay_payment_processing_details(
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    wallet_certificates: Option<&WalletCertificates>,
+) -> Option<payment_types::GooglePayPaymentProcessingDetails> {
+    wallet_certificates
+        .and_then(|wallet_certificates| wallet_certificates.google_pay.clone())
+        .or_else(|| {
+            merchant_connector_account
+                .get_metadata()
+                .and_then(|google_pay_metadata| {
+                    google_pay_metadata
+                        .parse_value::<payment_types::GpaySessionTokenData>("GpaySessionTokenData")
+                        .map_err(|error| {
+                            logger::warn!(%error, "Failed to Parse Value to GpaySessionTokenData")
+                        })
+                        .ok()
+                })
+                .and_then(|google_pay_metadata| google_pay_metadata.data.payment_processing_details)
+        })
+}
+
+/// Decrypt the Google Pay payment token, if the merchant has provided their Google Pay private key
+/// for the business profile or in the metadata of the merchant connector account
+///
+/// Tokens of cards on file with Google (`PAN_ONLY`) carry no cryptogram, so the payment method
+/// data is replaced with the card details and the payment goes through the card flow, including
+/// 3DS if it was requested. Network tokens (`CRYPTOGRAM_3DS`) are returned, to be passed to the
+/// connector along with their cryptogram.
+pub fn decrypt_google_pay_token_if_required<F: Clone>(
+    state: &AppState,
+    payment_data: &mut PaymentData<F>,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    wallet_certificates: Option<&WalletCertificates>,
+) -> RouterResult<Option<router_types::GooglePayPredecryptData>> {
+    let wallet_data = match payment_data
+        .payment_method_data
+        .clone()
+        .map(domain::PaymentMethodData::from)
+    {
+        Some(domain::PaymentMethodData::Wallet(wallet_data @ domain::WalletData::GooglePay(_))) => {
+            wallet_data
+        }
+        _ => return Ok(None),
+    };
+
+    let payment_processing_details = match get_google_pay_payment_processing_details(
+        merchant_connector_account,
+        wallet_certificates,
+    ) {
+        Some(payment_processing_details) => payment_processing_details,
+        None => return Ok(None),
+    };
+
+    let google_pay_predecrypt_data = GooglePayData::token_json(&wallet_data)
+        .change_context(errors::ApiErrorResponse::InvalidWalletToken {
+            wallet_name: "Google Pay".to_string(),
+        })?
+        .decrypt(
+            &state
+                .conf
+                .google_pay_decrypt_keys
+                .google_pay_root_signing_keys,
+            &payment_processing_details,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt the Google Pay payment token")?;
+
+    match google_pay_predecrypt_data
+        .payment_method_details
+        .auth_method
+    {
+        router_types::GooglePayAuthMethod::PanOnly => {
+            let payment_method_details = google_pay_predecrypt_data.payment_method_details;
+            payment_data.payment_method_data = Some(api::PaymentMethodData::Card(api::Card {
+                card_number: payment_method_details.pan,
+                card_exp_month: Secret::new(format!(
+                    "{:02}",
+                    payment_method_details.expiration_month
+                )),
+                card_exp_year: Secret::new(payment_method_details.expiration_year.to_string()),
+                card_holder_name: None,
+                // Google Pay does not share the security code of cards on file
+                card_cvc: Secret::default(),
+                card_issuer: None,
+                card_network: None,
+                card_type: None,
+                card_issuing_country: None,
+                bank_code: None,
+                nick_name: None,
+            }));
+            Ok(None)
+        }
+        router_types::GooglePayAuthMethod::Cryptogram3ds => Ok(Some(google_pay_predecrypt_data)),
+    }
+}
+
+/// Private key to decrypt the Samsung Pay payment token with, that of the business profile taking
+/// precedence over the one in the metadata of the merchant connector account
+fn get_samsung_pay_payment_processing_details(
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    wallet_certificates: Option<&WalletCertificates>,
+) -> Option<payment_types::SamsungPayPaymentProcessingDetails> {
+    wallet_certificates
+        .and_then(|wallet_certificates| wallet_certificates.samsung_pay.clone())
+        .or_else(|| {
+            merchant_connector_account
+                .get_metadata()
+                .and_then(|samsung_pay_metadata| {
+                    samsung_pay_metadata
+                        .parse_value::<payment_types::SamsungPaySessionTokenData>(
+                            "SamsungPaySessionTokenData",
+                        )
+                        .map_err(|error| {
+                            logger::warn!(%error, "Failed to Parse Value to SamsungPaySessionTokenData")
+                        })
+                        .ok()
+                })
+                .and_then(|samsung_pay_metadata| {
+                    samsung_pay_metadata.samsung_pay.payment_processing_details
+                })
+        })
+}
+
+/// Decrypt the Samsung Pay payment token, if the merchant has provided their Samsung Pay private
+/// key for the business profile or in the metadata of the merchant connector account
+pub async fn decrypt_samsung_pay_token_if_required<F: Clone>(
+    payment_data: &PaymentData<F>,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    wallet_certificates: Option<&WalletCertificates>,
+) -> RouterResult<Option<router_types::SamsungPayPredecryptData>> {
+    let samsung_pay_wallet_data = match payment_data
+        .payment_method_data
+        .clone()
+        .map(domain::PaymentMethodData::from)
+    {
+        Some(domain::PaymentMethodData::Wallet(domain::WalletData::SamsungPay(wallet_data))) => {
+            wallet_data
+        }
+        _ => return Ok(None),
+    };
+
+    let payment_processing_details = match get_samsung_pay_payment_processing_details(
+        merchant_connector_account,
+        wallet_certificates,
+    ) {
+        Some(payment_processing_details) => payment_processing_details,
+        None => return Ok(None),
+    };
+
+    decrypt_samsung_pay_token(
+        &samsung_pay_wallet_data.payment_credential.token_data,
+        &payment_processing_details,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InvalidWalletToken {
+        wallet_name: "Samsung Pay".to_string(),
+    })
+    .map(Some)
+}
+
+async fn find_business_profile_of_merchant(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    profile_id: &str,
+) -> RouterResult<storage::BusinessProfile> {
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    if business_profile.merchant_id != merchant_id {
+        Err(errors::ApiErrorResponse::AccessForbidden {
+            resource: profile_id.to_string(),
+        })?
+    }
+
+    Ok(business_profile)
+}
+
+async fn get_merchant_key_store(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<domain::MerchantKeyStore> {
+    db.get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+}
+
+pub async fn retrieve_wallet_certificates(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+) -> RouterResponse<admin_types::WalletCertificatesResponse> {
+    let db = state.store.as_ref();
+    let business_profile = find_business_profile_of_merchant(db, merchant_id, profile_id).await?;
+    let key_store = get_merchant_key_store(db, merchant_id).await?;
+
+    let wallet_certificates = decrypt_wallet_certificates(&business_profile, &key_store)
+        .await?
+        .unwrap_or_default();
+
+    get_wallet_certificates_response(profile_id, &wallet_certificates)
+        .map(ApplicationResponse::Json)
+}
+
+/// Set the certificates and keys of the wallets given in the request, leaving those of the other
+/// wallets as they are
+pub async fn update_wallet_certificates(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    request: WalletCertificates,
+) -> RouterResponse<admin_types::WalletCertificatesResponse> {
+    validate_wallet_certificates(&request)?;
+
+    let db = state.store.as_ref();
+    let business_profile = find_business_profile_of_merchant(db, merchant_id, profile_id).await?;
+    let key_store = get_merchant_key_store(db, merchant_id).await?;
+
+    let existing_wallet_certificates = decrypt_wallet_certificates(&business_profile, &key_store)
+        .await?
+        .unwrap_or_default();
+    let wallet_certificates = WalletCertificates {
+        apple_pay: request.apple_pay.or(existing_wallet_certificates.apple_pay),
+        google_pay: request
+            .google_pay
+            .or(existing_wallet_certificates.google_pay),
+        samsung_pay: request
+            .samsung_pay
+            .or(existing_wallet_certificates.samsung_pay),
+    };
+
+    store_wallet_certificates(db, business_profile, &wallet_certificates, &key_store).await?;
+
+    get_wallet_certificates_response(profile_id, &wallet_certificates)
+        .map(ApplicationResponse::Json)
+}
+
+/// Remove the certificates and keys of the wallet, the wallet then falling back on the
+/// certificates in the metadata of the merchant connector accounts
+pub async fn delete_wallet_certificates(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    wallet: enums::PaymentMethodType,
+) -> RouterResponse<admin_types::WalletCertificatesResponse> {
+    let db = state.store.as_ref();
+    let business_profile = find_business_profile_of_merchant(db, merchant_id, profile_id).await?;
+    let key_store = get_merchant_key_store(db, merchant_id).await?;
+
+    let mut wallet_certificates = decrypt_wallet_certificates(&business_profile, &key_store)
+        .await?
+        .unwrap_or_default();
+    match wallet {
+        enums::PaymentMethodType::ApplePay => wallet_certificates.apple_pay = None,
+        enums::PaymentMethodType::GooglePay => wallet_certificates.google_pay = None,
+        enums::PaymentMethodType::SamsungPay => wallet_certificates.samsung_pay = None,
+        _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{wallet} is not a wallet with certificates"),
+        })?,
+    }
+
+    store_wallet_certificates(db, business_profile, &wallet_certificates, &key_store).await?;
+
+    get_wallet_certificates_response(profile_id, &wallet_certificates)
+        .map(ApplicationResponse::Json)
+}
+
+async fn store_wallet_certificates(
+    db: &dyn StorageInterface,
+    business_profile: storage::BusinessProfile,
+    wallet_certificates: &WalletCertificates,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<storage::BusinessProfile> {
+    let profile_id = business_profile.profile_id.clone();
+    let business_profile_update =
+        storage::business_profile::BusinessProfileUpdate::WalletCertificatesUpdate {
+            wallet_certificates: Some(
+                encrypt_wallet_certificates(wallet_certificates, key_store).await?,
+            ),
+        };
+
+    db.update_business_profile_by_profile_id(business_profile, business_profile_update)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound { id: profile_id })
+}
+
+fn get_wallet_certificates_response(
+    profile_id: &str,
+    wallet_certificates: &WalletCertificates,
+) -> RouterResult<admin_types::WalletCertificatesResponse> {
+    let apple_pay = wallet_certificates
+        .apple_pay
+        .as_ref()
+        .map(|apple_pay_certificates| {
+            let merchant_certificate_expires_at =
+                get_merchant_certificate_expiry(&apple_pay_certificates.merchant_certificate)?;
+            let payment_processing_certificate_expires_at = apple_pay_certificates
+                .payment_processing_details
+                .as_ref()
+                .map(|payment_processing_details| {
+                    get_payment_processing_certificate_expiry(
+                        &payment_processing_details.payment_processing_certificate,
+                    )
+                })
+                .transpose()?;
+
+            Ok::<_, Report<errors::ApplePayDecryptionError>>(
+                admin_types::ApplePayCertificatesResponse {
+                    merchant_identifier: apple_pay_certificates.merchant_identifier.clone(),
+                    merchant_certificate_expires_at,
+                    payment_processing_certificate_expires_at,
+                },
+            )
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the stored Apple Pay certificates")?;
+
+    Ok(admin_types::WalletCertificatesResponse {
+        profile_id: profile_id.to_string(),
+        apple_pay,
+        google_pay: wallet_certificates.google_pay.as_ref().map(|google_pay| {
+            admin_types::GooglePayCertificatesResponse {
+                recipient_id: google_pay.google_pay_recipient_id.clone(),
+            }
+        }),
+        is_samsung_pay_private_key_set: wallet_certificates.samsung_pay.is_some(),
+    })
+}
+
+fn invalid_value<E>(
+    field_name: &'static str,
+) -> impl FnOnce(Report<E>) -> errors::ApiErrorResponse {
+    move |error| {
+        logger::error!(?error);
+        errors::ApiErrorResponse::InvalidDataValue { field_name }
+    }
+}
+
+/// Check that the certificates and keys of the wallets can be parsed, and that the certificates
+/// have not expired
+fn validate_wallet_certificates(
+    wallet_certificates: &WalletCertificates,
+) -> Result<(), errors::ApiErrorResponse> {
+    let now = common_utils::date_time::now();
+
+    if let Some(apple_pay_certificates) = wallet_certificates.apple_pay.as_ref() {
+        helpers::create_identity_from_certificate_and_key(
+            apple_pay_certificates.merchant_certificate.clone(),
+            apple_pay_certificates.merchant_certificate_key.clone(),
+        )
+        .map_err(invalid_value("apple_pay.merchant_certificate"))?;
+        let merchant_certificate_expires_at =
+            get_merchant_certificate_expiry(&apple_pay_certificates.merchant_certificate)
+                .map_err(invalid_value("apple_pay.merchant_certificate"))?;
+        if merchant_certificate_expires_at <= now {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "the Apple Pay merchant identity certificate has expired".to_string(),
+            });
+        }
+
+        if let Some(payment_processing_details) =
+            apple_pay_certificates.payment_processing_details.as_ref()
+        {
+            validate_apple_pay_payment_processing_details(payment_processing_details)
+                .map_err(invalid_value("apple_pay.payment_processing_details"))?;
+            let payment_processing_certificate_expires_at =
+                get_payment_processing_certificate_expiry(
+                    &payment_processing_details.payment_processing_certificate,
+                )
+                .map_err(invalid_value("apple_pay.payment_processing_details"))?;
+            if payment_processing_certificate_expires_at <= now {
+                return Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "the Apple Pay payment processing certificate has expired".to_string(),
+                });
+            }
+        }
+    }
+
+    if let Some(google_pay) = wallet_certificates.google_pay.as_ref() {
+        BASE64_ENGINE
+            .decode(google_pay.google_pay_private_key.peek())
+            .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)
+            .and_then(|private_key| {
+                PKey::private_key_from_pkcs8(&private_key)
+                    .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)
+            })
+            .map_err(invalid_value("google_pay.google_pay_private_key"))?;
+    }
+
+    if let Some(samsung_pay) = wallet_certificates.samsung_pay.as_ref() {
+        PKey::private_key_from_pem(samsung_pay.samsung_pay_private_key.peek().as_bytes())
+            .change_context(errors::EncryptionError)
+            .map_err(invalid_value("samsung_pay.samsung_pay_private_key"))?;
+    }
+
+    Ok(())
+}
+
+/// Check that the Apple Pay payment processing certificate and its key, used for decrypting
+/// payment tokens, can be parsed
+pub fn validate_apple_pay_payment_processing_details(
+    payment_processing_details: &api_models::payments::PaymentProcessingDetails,
+) -> CustomResult<(), errors::ApplePayDecryptionError> {
+    let decoded_certificate = BASE64_ENGINE
+        .decode(
+            payment_processing_details
+                .payment_processing_certificate
+                .peek(),
+        )
+        .change_context(errors::ApplePayDecryptionError::Base64DecodingFailed)?;
+
+    parse_x509_certificate(&decoded_certificate)
+        .change_context(errors::ApplePayDecryptionError::CertificateParsingFailed)
+        .attach_printable("Error parsing apple pay PPC")?;
+
+    PKey::private_key_from_pem(
+        payment_processing_details
+            .payment_processing_certificate_key
+            .peek()
+            .as_bytes(),
+    )
+    .change_context(errors::ApplePayDecryptionError::KeyDeserializationFailed)
+    .attach_printable("Failed to deserialize the private key")?;
+
+    Ok(())
+}
+
+/// The time the base64 encoded merchant identity certificate, in PEM format, expires at
+fn get_merchant_certificate_expiry(
+    merchant_certificate: &Secret<String>,
+) -> CustomResult<time::PrimitiveDateTime, errors::ApplePayDecryptionError> {
+    let decoded_certificate = BASE64_ENGINE
+        .decode(merchant_certificate.peek())
+        .change_context(errors::ApplePayDecryptionError::Base64DecodingFailed)?;
+    let (_, pem) = x509_parser::pem::parse_x509_pem(&decoded_certificate)
+        .change_context(errors::ApplePayDecryptionError::CertificateParsingFailed)
+        .attach_printable("Error parsing the apple pay merchant certificate")?;
+    let certificate = pem
+        .parse_x509()
+        .change_context(errors::ApplePayDecryptionError::CertificateParsingFailed)
+        .attach_printable("Error parsing the apple pay merchant certificate")?;
+
+    Ok(get_certificate_expiry(&certificate))
+}
+
+/// The time the base64 encoded payment processing certificate, in DER format, expires at
+fn get_payment_processing_certificate_expiry(
+    payment_processing_certificate: &Secret<String>,
+) -> CustomResult<time::PrimitiveDateTime, errors::ApplePayDecryptionError> {
+    let decoded_certificate = BASE64_ENGINE
+        .decode(payment_processing_certificate.peek())
+        .change_context(errors::ApplePayDecryptionError::Base64DecodingFailed)?;
+    let (_, certificate) = parse_x509_certificate(&decoded_certificate)
+        .change_context(errors::ApplePayDecryptionError::CertificateParsingFailed)
+        .attach_printable("Error parsing apple pay PPC")?;
+
+    Ok(get_certificate_expiry(&certificate))
+}
+
+fn get_certificate_expiry(certificate: &X509Certificate<'_>) -> time::PrimitiveDateTime {
+    let not_after = certificate
+        .validity()
+        .not_after
+        .to_datetime()
+        .to_offset(time::UtcOffset::UTC);
+    time::PrimitiveDateTime::new(not_after.date(), not_after.time())
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ApplePayData {
+    version: masking::Secret<String>,
+    data: masking::Secret<String>,
+    signature: masking::Secret<String>,
+    header: ApplePayHeader,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplePayHeader {
+    ephemeral_public_key: masking::Secret<String>,
+    public_key_hash: masking::Secret<String>,
+    transaction_id: masking::Secret<String>,
+}
+
+impl ApplePayData {
+    pub fn token_json(
+        wallet_data: domain::WalletData,
+    ) -> CustomResult<Self, errors::ConnectorError> {
+        let json_wallet_data: Self = connector::utils::WalletData::get_wallet_token_as_json(
+            &wallet_data,
+            "Apple Pay".to_string(),
+        )?;
+        Ok(json_wallet_data)
+    }
+
+    pub async fn decrypt(
+        &self,
+        payment_processing_details: &api_models::payments::PaymentProcessingDetails,
+    ) -> CustomResult<serde_json::Value, errors::ApplePayDecryptionError> {
+        let merchant_id = self
+            .merchant_id(&payment_processing_details.payment_processing_certificate)
+            .await?;
+        let shared_secret = self
+            .shared_secret(&payment_processing_details.payment_processing_certificate_key)
+            .await?;
+        let symmetric_key = self.symmetric_key(&merchant_id, &shared_secret)?;
+        let decrypted = self.decrypt_ciphertext(&symmetric_key)?;
+        let parsed_decrypted: serde_json::Value = serde_json::from_str(&decrypted)
+            .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?;
+        Ok(parsed_decrypted)
+    }
+
+    pub async fn merchant_id(
+        &self,
+        payment_processing_certificate: &masking::Secret<String>,
+    ) -> CustomResult<String, errors::ApplePayDecryptionError> {
+        let cert_data = payment_processing_certificate.clone().expose();
+
+        let base64_decode_cert_data = BASE64_ENGINE
+            .decode(cert_data)
+            .change_context(errors::ApplePayDecryptionError::Base64DecodingFailed)?;
+
+        // Parsing the certificate using x509-parser
+        let (_, certificate) = parse_x509_certificate(&base64_decode_cert_data)
+            .change_context(errors::ApplePayDecryptionError::CertificateParsingFailed)
+            .attach_printable("Error parsing apple pay PPC")?;
+
+        // Finding the merchant ID extension
+        let apple_pay_m_id = certificate
+            .extensions()
+            .iter()
+            .find(|extension| {
+                extension
+                    .oid
+                    .to_string()
+                    .eq(consts::MERCHANT_ID_FIELD_EXTENSION_ID)
+            })
+            .map(|ext| {
+                let merchant_id = String::from_utf8_lossy(ext.value)
+                    .trim()
+                    .trim_start_matches('@')
+                    .to_string();
+
+                merchant_id
+            })
+            .ok_or(errors::ApplePayDecryptionError::MissingMerchantId)
+            .attach_printable("Unable to find merchant ID extension in the certificate")?;
+
+        Ok(apple_pay_m_id)
+    }
+
+    pub async fn shared_secret(
+        &self,
+        payment_processing_certificate_key: &masking::Secret<String>,
+    ) -> CustomResult<Vec<u8>, errors::ApplePayDecryptionError> {
+        let public_ec_bytes = BASE64_ENGINE
+            .decode(self.header.ephemeral_public_key.peek().as_bytes())
+            .change_context(errors::ApplePayDecryptionError::Base64DecodingFailed)?;
+
+        let public_key = PKey::public_key_from_der(&public_ec_bytes)
+            .change_context(errors::ApplePayDecryptionError::KeyDeserializationFailed)
+            .attach_printable("Failed to deserialize the public key")?;
+
+        let decrypted_apple_pay_ppc_key = payment_processing_certificate_key.clone().expose();
+
+        // Create PKey objects from EcKey
+        let private_key = PKey::private_key_from_pem(decrypted_apple_pay_ppc_key.as_bytes())
+            .change_context(errors::ApplePayDecryptionError::KeyDeserializationFailed)
+            .attach_printable("Failed to deserialize the private key")?;
+
+        // Create the Deriver object and set the peer public key
+        let mut deriver = Deriver::new(&private_key)
+            .change_context(errors::ApplePayDecryptionError::DerivingSharedSecretKeyFailed)
+            .attach_printable("Failed to create a deriver for the private key")?;
+
+        deriver
+            .set_peer(&public_key)
+            .change_context(errors::ApplePayDecryptionError::DerivingSharedSecretKeyFailed)
+            .attach_printable("Failed to set the peer key for the secret derivation")?;
+
+        // Compute the shared secret
+        let shared_secret = deriver
+            .derive_to_vec()
+            .change_context(errors::ApplePayDecryptionError::DerivingSharedSecretKeyFailed)
+            .attach_printable("Final key derivation failed")?;
+        Ok(shared_secret)
+    }
+
+    pub fn symmetric_key(
+        &self,
+        merchant_id: &str,
+        shared_secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ApplePayDecryptionError> {
+        let kdf_algorithm = b"\x0did-aes256-GCM";
+        let kdf_party_v = hex::decode(merchant_id)
+            .change_context(errors::ApplePayDecryptionError::Base64DecodingFailed)?;
+        let kdf_party_u = b"Apple";
+        let kdf_info = [&kdf_algorithm[..], kdf_party_u, &kdf_party_v[..]].concat();
+
+        let mut hash = openssl::sha::Sha256::new();
+        hash.update(b"\x00\x00\x00");
+        hash.update(b"\x01");
+        hash.update(shared_secret);
+        hash.update(&kdf_info[..]);
+        let symmetric_key = hash.finish();
+        Ok(symmetric_key.to_vec())
+    }
+
+    pub fn decrypt_ciphertext(
+        &self,
+        symmetric_key: &[u8],
+    ) -> CustomResult<String, errors::ApplePayDecryptionError> {
+        let data = BASE64_ENGINE
+            .decode(self.data.peek().as_bytes())
+            .change_context(errors::ApplePayDecryptionError::Base64DecodingFailed)?;
+        let iv = [0u8; 16]; //Initialization vector IV is typically used in AES-GCM (Galois/Counter Mode) encryption for randomizing the encryption process.
+        let ciphertext = data
+            .get(..data.len() - 16)
+            .ok_or(errors::ApplePayDecryptionError::DecryptionFailed)?;
+        let tag = data
+            .get(data.len() - 16..)
+            .ok_or(errors::ApplePayDecryptionError::DecryptionFailed)?;
+        let cipher = Cipher::aes_256_gcm();
+        let decrypted_data = decrypt_aead(cipher, symmetric_key, Some(&iv), &[], ciphertext, tag)
+            .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?;
+        let decrypted = String::from_utf8(decrypted_data)
+            .change_context(errors::ApplePayDecryptionError::DecryptionFailed)?;
+
+        Ok(decrypted)
+    }
+}
+
+const GOOGLE_PAY_PROTOCOL_VERSION: &str = "ECv2";
+const GOOGLE_PAY_SENDER_ID: &str = "Google";
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayData {
+    signature: masking::Secret<String>,
+    intermediate_signing_key: GooglePayIntermediateSigningKey,
+    protocol_version: String,
+    signed_message: masking::Secret<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GooglePayIntermediateSigningKey {
+    signed_key: masking::Secret<String>,
+    signatures: Vec<masking::Secret<String>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GooglePaySignedKey {
+    key_value: masking::Secret<String>,
+    key_expiration: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GooglePaySignedMessage {
+    encrypted_message: masking::Secret<String>,
+    ephemeral_public_key: masking::Secret<String>,
+    tag: masking::Secret<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct GooglePayRootSigningKeys {
+    keys: Vec<GooglePayRootSigningKey>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GooglePayRootSigningKey {
+    key_value: String,
+    protocol_version: String,
+    key_expiration: Option<String>,
+}
+
+/// Length of the symmetric encryption key and the MAC key derived for the `ECv2` protocol
+struct GooglePayDerivedKeysLength;
+
+impl ring::hkdf::KeyType for GooglePayDerivedKeysLength {
+    fn len(&self) -> usize {
+        64
+    }
+}
+
+impl GooglePayData {
+    pub fn token_json(
+        wallet_data: &domain::WalletData,
+    ) -> CustomResult<Self, errors::ConnectorError> {
+        connector::utils::WalletData::get_wallet_token_as_json(
+            wallet_data,
+            "Google Pay".to_string(),
+        )
+    }
+
+    /// Verify and decrypt a payment token of the `ECv2` protocol, as described in
+    /// https://developers.google.com/pay/api/web/guides/resources/payment-data-cryptography
+    pub fn decrypt(
+        &self,
+        root_signing_keys: &str,
+        payment_processing_details: &api_models::payments::GooglePayPaymentProcessingDetails,
+    ) -> CustomResult<router_types::GooglePayPredecryptData, errors::GooglePayDecryptionError> {
+        if self.protocol_version != GOOGLE_PAY_PROTOCOL_VERSION {
+            return Err(report!(
+                errors::GooglePayDecryptionError::UnsupportedProtocolVersion
+            ))
+            .attach_printable(format!("protocol version: {}", self.protocol_version));
+        }
+
+        let now = common_utils::date_time::now()
+            .assume_utc()
+            .unix_timestamp_nanos()
+            / 1_000_000;
+
+        let intermediate_signing_key =
+            self.verify_intermediate_signing_key(root_signing_keys, now)?;
+        self.verify_message_signature(
+            &intermediate_signing_key,
+            &payment_processing_details.google_pay_recipient_id,
+        )?;
+
+        let signed_message: GooglePaySignedMessage =
+            serde_json::from_str(self.signed_message.peek())
+                .change_context(errors::GooglePayDecryptionError::TokenParsingFailed)?;
+        let decrypted =
+            signed_message.decrypt(&payment_processing_details.google_pay_private_key)?;
+
+        let decrypted_data: router_types::GooglePayPredecryptData =
+            serde_json::from_slice(&decrypted)
+                .change_context(errors::GooglePayDecryptionError::DecryptionFailed)
+                .attach_printable("Failed to parse the decrypted payment token")?;
+
+        if is_google_pay_key_expired(&decrypted_data.message_expiration, now) {
+            return Err(report!(errors::GooglePayDecryptionError::Expired))
+                .attach_printable("Google Pay payment token has expired");
+        }
+
+        Ok(decrypted_data)
+    }
+
+    /// Verify that the intermediate signing key is signed by one of the root signing keys of
+    /// Google, returning the intermediate signing key
+    fn verify_intermediate_signing_key(
+        &self,
+        root_signing_keys: &str,
+        now: i128,
+    ) -> CustomResult<PKey<openssl::pkey::Public>, errors::GooglePayDecryptionError> {
+        let root_signing_keys: GooglePayRootSigningKeys = serde_json::from_str(root_signing_keys)
+            .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)
+            .attach_printable("Failed to parse the Google Pay root signing keys")?;
+
+        let signed_data = google_pay_length_value_bytes(&[
+            GOOGLE_PAY_SENDER_ID.as_bytes(),
+            GOOGLE_PAY_PROTOCOL_VERSION.as_bytes(),
+            self.intermediate_signing_key.signed_key.peek().as_bytes(),
+        ]);
+
+        let is_signed_by_root_key = root_signing_keys
+            .keys
+            .iter()
+            .filter(|root_key| {
+                root_key.protocol_version == GOOGLE_PAY_PROTOCOL_VERSION
+                    && !root_key
+                        .key_expiration
+                        .as_ref()
+                        .is_some_and(|expiration| is_google_pay_key_expired(expiration, now))
+            })
+            .filter_map(|root_key| google_pay_public_key_from_base64(&root_key.key_value).ok())
+            .any(|root_key| {
+                self.intermediate_signing_key
+                    .signatures
+                    .iter()
+                    .any(|signature| {
+                        verify_google_pay_signature(&root_key, signature, &signed_data)
+                            .unwrap_or(false)
+                    })
+            });
+        if !is_signed_by_root_key {
+            return Err(report!(
+                errors::GooglePayDecryptionError::SignatureVerificationFailed
+            ))
+            .attach_printable("Intermediate signing key is not signed by a root signing key");
+        }
+
+        let signed_key: GooglePaySignedKey =
+            serde_json::from_str(self.intermediate_signing_key.signed_key.peek())
+                .change_context(errors::GooglePayDecryptionError::TokenParsingFailed)?;
+        if is_google_pay_key_expired(&signed_key.key_expiration, now) {
+            return Err(report!(errors::GooglePayDecryptionError::Expired))
+                .attach_printable("Intermediate signing key has expired");
+        }
+
+        google_pay_public_key_from_base64(signed_key.key_value.peek())
+    }
+
+    fn verify_message_signature(
+        &self,
+        intermediate_signing_key: &PKey<openssl::pkey::Public>,
+        recipient_id: &str,
+    ) -> CustomResult<(), errors::GooglePayDecryptionError> {
+        let signed_data = google_pay_length_value_bytes(&[
+            GOOGLE_PAY_SENDER_ID.as_bytes(),
+            recipient_id.as_bytes(),
+            GOOGLE_PAY_PROTOCOL_VERSION.as_bytes(),
+            self.signed_message.peek().as_bytes(),
+        ]);
+
+        verify_google_pay_signature(intermediate_signing_key, &self.signature, &signed_data)?
+            .then_some(())
+            .ok_or(errors::GooglePayDecryptionError::SignatureVerificationFailed)
+            .attach_printable("Signature of the signed message does not match")
+    }
+}
+
+impl GooglePaySignedMessage {
+    fn decrypt(
+        &self,
+        private_key: &masking::Secret<String>,
+    ) -> CustomResult<Vec<u8>, errors::GooglePayDecryptionError> {
+        let ephemeral_public_key_bytes = BASE64_ENGINE
+            .decode(self.ephemeral_public_key.peek())
+            .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+        let encrypted_message = BASE64_ENGINE
+            .decode(self.encrypted_message.peek())
+            .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+        let tag = BASE64_ENGINE
+            .decode(self.tag.peek())
+            .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+
+        let shared_secret = google_pay_shared_secret(private_key, &ephemeral_public_key_bytes)?;
+
+        // The input key material is the ephemeral public key followed by the shared secret
+        let input_key_material = [ephemeral_public_key_bytes, shared_secret].concat();
+        let mut derived_keys = [0u8; 64];
+        ring::hkdf::Salt::new(ring::hkdf::HKDF_SHA256, &[0u8; 32])
+            .extract(&input_key_material)
+            .expand(
+                &[GOOGLE_PAY_SENDER_ID.as_bytes()],
+                GooglePayDerivedKeysLength,
+            )
+            .and_then(|okm| okm.fill(&mut derived_keys))
+            .map_err(|_| report!(errors::GooglePayDecryptionError::DerivingSharedSecretKeyFailed))
+            .attach_printable("Failed to derive the symmetric encryption and MAC keys")?;
+        let (symmetric_encryption_key, mac_key) = derived_keys.split_at(32);
+
+        ring::hmac::verify(
+            &ring::hmac::Key::new(ring::hmac::HMAC_SHA256, mac_key),
+            &encrypted_message,
+            &tag,
+        )
+        .map_err(|_| report!(errors::GooglePayDecryptionError::SignatureVerificationFailed))
+        .attach_printable("Tag of the encrypted message does not match")?;
+
+        openssl::symm::decrypt(
+            Cipher::aes_256_ctr(),
+            symmetric_encryption_key,
+            Some(&[0u8; 16]),
+            &encrypted_message,
+        )
+        .change_context(errors::GooglePayDecryptionError::DecryptionFailed)
+    }
+}
+
+/// Concatenate the values, each prefixed with its length as 4 bytes in little endian order
+fn google_pay_length_value_bytes(values: &[&[u8]]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|value| {
+            u32::try_from(value.len())
+                .unwrap_or(u32::MAX)
+                .to_le_bytes()
+                .into_iter()
+                .chain(value.iter().copied())
+        })
+        .collect()
+}
+
+/// Expirations are the milliseconds since the UNIX epoch, as strings
+fn is_google_pay_key_expired(expiration: &str, now: i128) -> bool {
+    !matches!(expiration.parse::<i128>(), Ok(expiration) if expiration > now)
+}
+
+fn google_pay_public_key_from_base64(
+    key_value: &str,
+) -> CustomResult<PKey<openssl::pkey::Public>, errors::GooglePayDecryptionError> {
+    let key_bytes = BASE64_ENGINE
+        .decode(key_value)
+        .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+
+    PKey::public_key_from_der(&key_bytes)
+        .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)
+        .attach_printable("Failed to deserialize the signing key")
+}
+
+fn verify_google_pay_signature(
+    public_key: &PKey<openssl::pkey::Public>,
+    signature: &masking::Secret<String>,
+    signed_data: &[u8],
+) -> CustomResult<bool, errors::GooglePayDecryptionError> {
+    let signature = BASE64_ENGINE
+        .decode(signature.peek())
+        .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+
+    let mut verifier =
+        openssl::sign::Verifier::new(openssl::hash::MessageDigest::sha256(), public_key)
+            .change_context(errors::GooglePayDecryptionError::SignatureVerificationFailed)?;
+    verifier
+        .update(signed_data)
+        .change_context(errors::GooglePayDecryptionError::SignatureVerificationFailed)?;
+
+    verifier
+        .verify(&signature)
+        .change_context(errors::GooglePayDecryptionError::SignatureVerificationFailed)
+}
+
+fn google_pay_shared_secret(
+    private_key: &masking::Secret<String>,
+    ephemeral_public_key_bytes: &[u8],
+) -> CustomResult<Vec<u8>, errors::GooglePayDecryptionError> {
+    let private_key_bytes = BASE64_ENGINE
+        .decode(private_key.peek())
+        .change_context(errors::GooglePayDecryptionError::Base64DecodingFailed)?;
+    let private_key = PKey::private_key_from_pkcs8(&private_key_bytes)
+        .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)
+        .attach_printable("Failed to deserialize the private key")?;
+
+    // The ephemeral public key is an uncompressed point on the prime256v1 curve
+    let group = openssl::ec::EcGroup::from_curve_name(openssl::nid::Nid::X9_62_PRIME256V1)
+        .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)?;
+    let mut big_num_context = openssl::bn::BigNumContext::new()
+        .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)?;
+    let ephemeral_public_key =
+        openssl::ec::EcPoint::from_bytes(&group, ephemeral_public_key_bytes, &mut big_num_context)
+            .and_then(|point| openssl::ec::EcKey::from_public_key(&group, &point))
+            .and_then(PKey::from_ec_key)
+            .change_context(errors::GooglePayDecryptionError::KeyDeserializationFailed)
+            .attach_printable("Failed to deserialize the ephemeral public key")?;
+
+    let mut deriver = Deriver::new(&private_key)
+        .change_context(errors::GooglePayDecryptionError::DerivingSharedSecretKeyFailed)
+        .attach_printable("Failed to create a deriver for the private key")?;
+    deriver
+        .set_peer(&ephemeral_public_key)
+        .change_context(errors::GooglePayDecryptionError::DerivingSharedSecretKeyFailed)
+        .attach_printable("Failed to set the peer key for the secret derivation")?;
+
+    deriver
+        .derive_to_vec()
+        .change_context(errors::GooglePayDecryptionError::DerivingSharedSecretKeyFailed)
+        .attach_printable("Final key derivation failed")
+}
+
+/// Decrypt a Samsung Pay payment token, a JWE encrypted with the public key registered by the
+/// merchant with Samsung Pay
+pub async fn decrypt_samsung_pay_token(
+    token_data: &domain::SamsungPayTokenData,
+    payment_processing_details: &api_models::payments::SamsungPayPaymentProcessingDetails,
+) -> CustomResult<router_types::SamsungPayPredecryptData, errors::EncryptionError> {
+    let decrypted = services::decrypt_jwe(
+        token_data.data.peek(),
+        services::KeyIdCheck::SkipKeyIdCheck,
+        payment_processing_details.samsung_pay_private_key.peek(),
+        jwe::RSA1_5,
+    )
+    .await
+    .attach_printable("Failed to decrypt the Samsung Pay payment token")?;
+
+    serde_json::from_str(&decrypted)
+        .change_context(errors::EncryptionError)
+        .attach_printable("Failed to parse the decrypted Samsung Pay payment token")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_get_wallet_certificates_response() {
+        let wallet_certificates = WalletCertificates {
+            apple_pay: None,
+            google_pay: Some(payment_types::GooglePayPaymentProcessingDetails {
+                google_pay_private_key: Secret::new("private_key".to_string()),
+                google_pay_recipient_id: "merchant:12345678901234567890".to_string(),
+            }),
+            samsung_pay: None,
+        };
+
+        let response = get_wallet_certificates_response("pro_test", &wallet_certificates).unwrap();
+
+        assert_eq!(response.profile_id, "pro_test");
+        assert!(response.apple_pay.is_none());
+        assert_eq!(
+            response
+                .google_pay
+                .map(|google_pay| google_pay.recipient_id),
+            Some("merchant:12345678901234567890".to_string())
+        );
+        assert!(!response.is_samsung_pay_private_key_set);
+    }
+
+    #[test]
+    fn test_validate_wallet_certificates() {
+        assert!(validate_wallet_certificates(&WalletCertificates::default()).is_ok());
+
+        let invalid_google_pay_key = WalletCertificates {
+            google_pay: Some(payment_types::GooglePayPaymentProcessingDetails {
+                google_pay_private_key: Secret::new("not a private key".to_string()),
+                google_pay_recipient_id: "merchant:12345678901234567890".to_string(),
+            }),
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_wallet_certificates(&invalid_google_pay_key),
+            Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "google_pay.google_pay_private_key"
+            })
+        ));
+
+        let invalid_samsung_pay_key = WalletCertificates {
+            samsung_pay: Some(payment_types::SamsungPayPaymentProcessingDetails {
+                samsung_pay_private_key: Secret::new("not a private key".to_string()),
+            }),
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_wallet_certificates(&invalid_samsung_pay_key),
+            Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "samsung_pay.samsung_pay_private_key"
+            })
+        ));
+    }
+
+    const GOOGLE_PAY_RECIPIENT_ID: &str = "merchant:12345678901234567890";
+
+    fn generate_google_pay_key() -> PKey<openssl::pkey::Private> {
+        let group =
+            openssl::ec::EcGroup::from_curve_name(openssl::nid::Nid::X9_62_PRIME256V1).unwrap();
+        PKey::from_ec_key(openssl::ec::EcKey::generate(&group).unwrap()).unwrap()
+    }
+
+    fn google_pay_public_key_base64(key: &PKey<openssl::pkey::Private>) -> String {
+        BASE64_ENGINE.encode(key.public_key_to_der().unwrap())
+    }
+
+    fn google_pay_sign(key: &PKey<openssl::pkey::Private>, values: &[&[u8]]) -> String {
+        let mut signer =
+            openssl::sign::Signer::new(openssl::hash::MessageDigest::sha256(), key).unwrap();
+        signer
+            .update(&google_pay_length_value_bytes(values))
+            .unwrap();
+        BASE64_ENGINE.encode(signer.sign_to_vec().unwrap())
+    }
+
+    /// Milliseconds since the UNIX epoch, offset by the given number of seconds
+    fn google_pay_expiration(offset_in_secs: i64) -> String {
+        common_utils::date_time::now()
+            .assume_utc()
+            .saturating_add(time::Duration::seconds(offset_in_secs))
+            .unix_timestamp_nanos()
+            .saturating_div(1_000_000)
+            .to_string()
+    }
+
+    /// Encrypt the message for the recipient as described by the `ECv2` protocol, returning the
+    /// signed message
+    fn google_pay_encrypt(
+        recipient_key: &PKey<openssl::pkey::Private>,
+        message: &serde_json::Value,
+    ) -> String {
+        let ephemeral_key = generate_google_pay_key();
+        let ephemeral_public_key_bytes = ephemeral_key
+            .ec_key()
+            .unwrap()
+            .public_key()
+            .to_bytes(
+                &openssl::ec::EcGroup::from_curve_name(openssl::nid::Nid::X9_62_PRIME256V1)
+                    .unwrap(),
+                openssl::ec::PointConversionForm::UNCOMPRESSED,
+                &mut openssl::bn::BigNumContext::new().unwrap(),
+            )
+            .unwrap();
+        let mut deriver = Deriver::new(&ephemeral_key).unwrap();
+        deriver.set_peer(recipient_key).unwrap();
+        let shared_secret = deriver.derive_to_vec().unwrap();
+
+        let mut derived_keys = [0u8; 64];
+        ring::hkdf::Salt::new(ring::hkdf::HKDF_SHA256, &[0u8; 32])
+            .extract(&[ephemeral_public_key_bytes.clone(), shared_secret].concat())
+            .expand(
+                &[GOOGLE_PAY_SENDER_ID.as_bytes()],
+                GooglePayDerivedKeysLength,
+            )
+            .unwrap()
+            .fill(&mut derived_keys)
+            .unwrap();
+        let (symmetric_encryption_key, mac_key) = derived_keys.split_at(32);
+
+        let encrypted_message = openssl::symm::encrypt(
+            Cipher::aes_256_ctr(),
+            symmetric_encryption_key,
+            Some(&[0u8; 16]),
+            message.to_string().as_bytes(),
+        )
+        .unwrap();
+        let tag = ring::hmac::sign(
+            &ring::hmac::Key::new(ring::hmac::HMAC_SHA256, mac_key),
+            &encrypted_message,
+        );
+
+        serde_json::json!({
+            "encryptedMessage": BASE64_ENGINE.encode(&encrypted_message),
+            "ephemeralPublicKey": BASE64_ENGINE.encode(&ephemeral_public_key_bytes),
+            "tag": BASE64_ENGINE.encode(tag.as_ref()),
+        })
+        .to_string()
+    }
+
+    /// A payment token signed by an intermediate signing key expiring after the given number of
+    /// seconds, along with the root signing keys and the payment processing details to decrypt it
+    fn google_pay_token(
+        intermediate_key_expires_in_secs: i64,
+    ) -> (
+        serde_json::Value,
+        String,
+        payment_types::GooglePayPaymentProcessingDetails,
+    ) {
+        let root_key = generate_google_pay_key();
+        let intermediate_key = generate_google_pay_key();
+        let recipient_key = generate_google_pay_key();
+
+        let signed_key = serde_json::json!({
+            "keyValue": google_pay_public_key_base64(&intermediate_key),
+            "keyExpiration": google_pay_expiration(intermediate_key_expires_in_secs),
+        })
+        .to_string();
+        let signed_message = google_pay_encrypt(
+            &recipient_key,
+            &serde_json::json!({
+                "messageExpiration": google_pay_expiration(3600),
+                "messageId": "AH2EjtdLz-qtKZ1ErfGiAqiE2SguhpRMwg",
+                "paymentMethod": "CARD",
+                "paymentMethodDetails": {
+                    "authMethod": "CRYPTOGRAM_3DS",
+                    "expirationMonth": 12,
+                    "expirationYear": 2030,
+                    "pan": "4111111111111111",
+                    "cryptogram": "AAAAAAAAAAAAAAAAAAAAAAAAAAA=",
+                    "eciIndicator": "05",
+                },
+            }),
+        );
+
+        let token = serde_json::json!({
+            "signature": google_pay_sign(
+                &intermediate_key,
+                &[
+                    GOOGLE_PAY_SENDER_ID.as_bytes(),
+                    GOOGLE_PAY_RECIPIENT_ID.as_bytes(),
+                    GOOGLE_PAY_PROTOCOL_VERSION.as_bytes(),
+                    signed_message.as_bytes(),
+                ],
+            ),
+            "intermediateSigningKey": {
+                "signedKey": signed_key,
+                "signatures": [google_pay_sign(
+                    &root_key,
+                    &[
+                        GOOGLE_PAY_SENDER_ID.as_bytes(),
+                        GOOGLE_PAY_PROTOCOL_VERSION.as_bytes(),
+                        signed_key.as_bytes(),
+                    ],
+                )],
+            },
+            "protocolVersion": GOOGLE_PAY_PROTOCOL_VERSION,
+            "signedMessage": signed_message,
+        });
+        let root_signing_keys = serde_json::json!({
+            "keys": [{
+                "keyValue": google_pay_public_key_base64(&root_key),
+                "protocolVersion": GOOGLE_PAY_PROTOCOL_VERSION,
+            }],
+        })
+        .to_string();
+        let payment_processing_details = payment_types::GooglePayPaymentProcessingDetails {
+            google_pay_private_key: Secret::new(
+                BASE64_ENGINE.encode(recipient_key.private_key_to_pkcs8().unwrap()),
+            ),
+            google_pay_recipient_id: GOOGLE_PAY_RECIPIENT_ID.to_string(),
+        };
+
+        (token, root_signing_keys, payment_processing_details)
+    }
+
+    fn decrypt_google_pay_token(
+        token: serde_json::Value,
+        root_signing_keys: &str,
+        payment_processing_details: &payment_types::GooglePayPaymentProcessingDetails,
+    ) -> CustomResult<router_types::GooglePayPredecryptData, errors::GooglePayDecryptionError> {
+        serde_json::from_value::<GooglePayData>(token)
+            .unwrap()
+            .decrypt(root_signing_keys, payment_processing_details)
+    }
+
+    #[test]
+    fn test_decrypt_google_pay_token() {
+        let (token, root_signing_keys, payment_processing_details) = google_pay_token(3600);
+
+        let decrypted_data =
+            decrypt_google_pay_token(token, &root_signing_keys, &payment_processing_details)
+                .unwrap();
+
+        assert_eq!(
+            decrypted_data.message_id,
+            "AH2EjtdLz-qtKZ1ErfGiAqiE2SguhpRMwg"
+        );
+        assert_eq!(decrypted_data.payment_method_type, "CARD");
+        let payment_method_details = decrypted_data.payment_method_details;
+        assert_eq!(
+            payment_method_details.auth_method,
+            router_types::GooglePayAuthMethod::Cryptogram3ds
+        );
+        assert_eq!(payment_method_details.pan.get_card_no(), "4111111111111111");
+        assert_eq!(payment_method_details.expiration_month, 12);
+        assert_eq!(payment_method_details.expiration_year, 2030);
+        assert_eq!(
+            payment_method_details
+                .cryptogram
+                .map(|cryptogram| cryptogram.expose()),
+            Some("AAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_string())
+        );
+        assert_eq!(payment_method_details.eci_indicator, Some("05".to_string()));
+    }
+
+    #[test]
+    fn test_decrypt_google_pay_token_with_tampered_signature() {
+        let (mut token, root_signing_keys, payment_processing_details) = google_pay_token(3600);
+        let (other_token, _, _) = google_pay_token(3600);
+        token["signature"] = other_token["signature"].clone();
+
+        assert!(matches!(
+            decrypt_google_pay_token(token, &root_signing_keys, &payment_processing_details)
+                .unwrap_err()
+                .current_context(),
+            errors::GooglePayDecryptionError::SignatureVerificationFailed
+        ));
+    }
+
+    #[test]
+    fn test_decrypt_google_pay_token_with_tampered_message() {
+        let (mut token, root_signing_keys, payment_processing_details) = google_pay_token(3600);
+        let (other_token, _, _) = google_pay_token(3600);
+        token["signedMessage"] = other_token["signedMessage"].clone();
+
+        assert!(matches!(
+            decrypt_google_pay_token(token, &root_signing_keys, &payment_processing_details)
+                .unwrap_err()
+                .current_context(),
+            errors::GooglePayDecryptionError::SignatureVerificationFailed
+        ));
+    }
+
+    #[test]
+    fn test_decrypt_google_pay_token_with_expired_intermediate_signing_key() {
+        let (token, root_signing_keys, payment_processing_details) = google_pay_token(-60);
+
+        assert!(matches!(
+            decrypt_google_pay_token(token, &root_signing_keys, &payment_processing_details)
+                .unwrap_err()
+                .current_context(),
+            errors::GooglePayDecryptionError::Expired
+        ));
+    }
+
+    #[test]
+    fn test_decrypt_google_pay_token_for_another_recipient() {
+        let (token, root_signing_keys, payment_processing_details) = google_pay_token(3600);
+        let payment_processing_details = payment_types::GooglePayPaymentProcessingDetails {
+            google_pay_recipient_id: "merchant:09876543210987654321".to_string(),
+            ..payment_processing_details
+        };
+
+        assert!(matches!(
+            decrypt_google_pay_token(token, &root_signing_keys, &payment_processing_details)
+                .unwrap_err()
+                .current_context(),
+            errors::GooglePayDecryptionError::SignatureVerificationFailed
+        ));
+    }
+}
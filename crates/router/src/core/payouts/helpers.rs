@@ -897,6 +897,7 @@ pub fn is_payout_err_state(status: api_enums::PayoutStatus) -> bool {
         status,
         api_enums::PayoutStatus::Cancelled
             | api_enums::PayoutStatus::Failed
+            | api_enums::PayoutStatus::Expired
             | api_enums::PayoutStatus::Ineligible
     )
 }
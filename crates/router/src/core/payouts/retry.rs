@@ -345,6 +345,8 @@ impl GsmValidation for PayoutData {
         match self.payout_attempt.status {
             common_enums::PayoutStatus::Success
             | common_enums::PayoutStatus::Cancelled
+            | common_enums::PayoutStatus::Expired
+            | common_enums::PayoutStatus::Reversed
             | common_enums::PayoutStatus::Pending
             | common_enums::PayoutStatus::Ineligible
             | common_enums::PayoutStatus::RequiresCreation
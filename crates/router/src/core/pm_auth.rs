@@ -19,13 +19,13 @@ use common_utils::{
 use error_stack::ResultExt;
 use helpers::PaymentAuthConnectorDataExt;
 use hyperswitch_domain_models::payments::PaymentIntent;
-use masking::{ExposeInterface, PeekInterface, Secret};
+use masking::{ExposeInterface, PeekInterface, Secret, StrongEq};
 use pm_auth::{
     connector::plaid::transformers::PlaidAuthType,
     types::{
         self as pm_auth_types,
         api::{
-            auth_service::{BankAccountCredentials, ExchangeToken, LinkToken},
+            auth_service::{BankAccountBalance, BankAccountCredentials, ExchangeToken, LinkToken},
             BoxedConnectorIntegration, PaymentAuthConnectorData,
         },
     },
@@ -410,6 +410,7 @@ async fn store_bank_details_in_payment_methods(
                 ),
                 account_id: creds.account_id,
             }],
+            verification_status: Some(payment_methods::BankAccountVerificationStatus::Verified),
         };
 
         if let Some((pm, details)) = contains_account {
@@ -554,6 +555,163 @@ pub async fn get_bank_account_creds(
     Ok(bank_account_details_resp)
 }
 
+pub async fn get_bank_account_balance(
+    connector: PaymentAuthConnectorData,
+    merchant_account: &domain::MerchantAccount,
+    connector_name: &str,
+    access_token: &Secret<String>,
+    auth_type: pm_auth_types::ConnectorAuthType,
+    state: &AppState,
+    bank_account_id: Option<Secret<String>>,
+) -> RouterResult<pm_auth_types::BankAccountBalanceResponse> {
+    let connector_integration_bank_balance: BoxedConnectorIntegration<
+        '_,
+        BankAccountBalance,
+        pm_auth_types::BankAccountBalanceRequest,
+        pm_auth_types::BankAccountBalanceResponse,
+    > = connector.connector.get_connector_integration();
+
+    let router_data_bank_balance = pm_auth_types::BankBalanceRouterData {
+        flow: std::marker::PhantomData,
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        connector: Some(connector_name.to_string()),
+        request: pm_auth_types::BankAccountBalanceRequest {
+            access_token: access_token.clone(),
+            optional_ids: bank_account_id
+                .map(|id| pm_auth_types::BankAccountOptionalIDs { ids: vec![id] }),
+        },
+        response: Ok(pm_auth_types::BankAccountBalanceResponse {
+            balances: Vec::new(),
+        }),
+        connector_http_status_code: None,
+        connector_auth_type: auth_type,
+    };
+
+    let bank_balance_resp = pm_auth_services::execute_connector_processing_step(
+        state,
+        connector_integration_bank_balance,
+        &router_data_bank_balance,
+        &connector.connector_name,
+    )
+    .await
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while calling bank account balance connector api")?;
+
+    let bank_account_balance_resp =
+        bank_balance_resp
+            .response
+            .map_err(|err| ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: connector.connector_name.to_string(),
+                status_code: err.status_code,
+                reason: err.reason,
+            })?;
+
+    Ok(bank_account_balance_resp)
+}
+
+/// Checks the balance of the bank account against the amount of the payment before it is debited
+fn get_verification_status_from_balance(
+    bank_account_balance: &pm_auth_types::BankAccountBalanceResponse,
+    account_id: &Secret<String>,
+    payment_intent: &PaymentIntent,
+) -> payment_methods::BankAccountVerificationStatus {
+    let balance = bank_account_balance
+        .balances
+        .iter()
+        .find(|balance| balance.account_id.peek().strong_eq(account_id.peek()));
+
+    let currency = payment_intent.currency;
+    let amount = currency.and_then(|currency| {
+        currency
+            .to_currency_base_unit_asf64(payment_intent.amount)
+            .map_err(|error| logger::error!(?error, "unable to convert payment amount"))
+            .ok()
+    });
+
+    match (balance, currency, amount) {
+        (Some(balance), Some(currency), Some(amount))
+            if !balance
+                .iso_currency_code
+                .as_ref()
+                .is_some_and(|iso_code| iso_code != &currency.to_string()) =>
+        {
+            match balance.available.or(balance.current) {
+                Some(available) if available >= amount => {
+                    payment_methods::BankAccountVerificationStatus::Verified
+                }
+                Some(_) => payment_methods::BankAccountVerificationStatus::InsufficientBalance,
+                None => payment_methods::BankAccountVerificationStatus::Failed,
+            }
+        }
+        _ => payment_methods::BankAccountVerificationStatus::Failed,
+    }
+}
+
+async fn update_bank_account_verification_status(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+    merchant_account: &domain::MerchantAccount,
+    payment_method_id: &str,
+    verification_status: payment_methods::BankAccountVerificationStatus,
+) -> RouterResult<()> {
+    let payment_method = db
+        .find_payment_method(payment_method_id, merchant_account.storage_scheme)
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentMethodNotFound)?;
+
+    let bank_details = decrypt::<serde_json::Value, masking::WithType>(
+        payment_method.payment_method_data.clone(),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("unable to decrypt bank account details")?
+    .map(|x| x.into_inner().expose())
+    .map(|v| {
+        serde_json::from_value::<payment_methods::PaymentMethodsData>(v)
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to deserialize bank account details")
+    })
+    .transpose()?
+    .and_then(|pmd| match pmd {
+        payment_methods::PaymentMethodsData::BankDetails(bank_creds) => Some(bank_creds),
+        _ => None,
+    })
+    .ok_or(ApiErrorResponse::InternalServerError)
+    .attach_printable("Bank account details not found in payment method")?;
+
+    if bank_details.verification_status == Some(verification_status) {
+        return Ok(());
+    }
+
+    let payment_method_data = payment_methods::PaymentMethodsData::BankDetails(
+        payment_methods::PaymentMethodDataBankCreds {
+            verification_status: Some(verification_status),
+            ..bank_details
+        },
+    );
+    let encrypted_data =
+        cards::create_encrypted_payment_method_data(key_store, Some(payment_method_data))
+            .await
+            .ok_or(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt bank account details")?;
+
+    db.update_payment_method(
+        payment_method,
+        storage::PaymentMethodUpdate::PaymentMethodDataUpdate {
+            payment_method_data: Some(encrypted_data),
+        },
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update bank account verification status")?;
+
+    Ok(())
+}
+
 async fn get_access_token_from_exchange_api(
     connector: &PaymentAuthConnectorData,
     connector_name: &str,
@@ -680,6 +838,17 @@ pub async fn retrieve_payment_method_from_auth_service(
         &auth_token.connector_details.access_token;
 
     let bank_account_creds = get_bank_account_creds(
+        connector.clone(),
+        &merchant_account,
+        &auth_token.connector_details.connector,
+        access_token,
+        auth_type.clone(),
+        state,
+        Some(auth_token.connector_details.account_id.clone()),
+    )
+    .await?;
+
+    let bank_account_balance = get_bank_account_balance(
         connector,
         &merchant_account,
         &auth_token.connector_details.connector,
@@ -690,6 +859,32 @@ pub async fn retrieve_payment_method_from_auth_service(
     )
     .await?;
 
+    let verification_status = get_verification_status_from_balance(
+        &bank_account_balance,
+        &auth_token.connector_details.account_id,
+        payment_intent,
+    );
+
+    if let Some(payment_method_id) = auth_token.payment_method_id.as_ref() {
+        update_bank_account_verification_status(
+            db,
+            key_store,
+            &merchant_account,
+            payment_method_id,
+            verification_status,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to store bank account verification status"))
+        .ok();
+    }
+
+    if verification_status == payment_methods::BankAccountVerificationStatus::InsufficientBalance {
+        return Err(ApiErrorResponse::PreconditionFailed {
+            message: "Bank account does not have sufficient balance for this payment".to_string(),
+        }
+        .into());
+    }
+
     let bank_account = bank_account_creds
         .credentials
         .iter()
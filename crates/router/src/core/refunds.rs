@@ -173,6 +173,15 @@ pub async fn trigger_refund_to_gateway(
     )
     .await?;
 
+    // Refunds of a partial capture are initiated against the capture's reference at the connector
+    if let Some(capture_id) = &refund.capture_id {
+        let capture =
+            get_refund_capture(state, payment_attempt, capture_id, storage_scheme).await?;
+        if let Some(connector_capture_id) = capture.connector_capture_id {
+            router_data.request.connector_transaction_id = connector_capture_id;
+        }
+    }
+
     let add_access_token_result =
         access_token::add_access_token(state, &connector, merchant_account, &router_data).await?;
 
@@ -253,6 +262,19 @@ pub async fn trigger_refund_to_gateway(
     };
 
     let refund_update = match router_data_res.response {
+        // A queued refund which is rate limited by the connector stays queued, so that it is
+        // submitted again with the next batch
+        Err(err)
+            if refund.refund_status == enums::RefundStatus::PendingEnqueue
+                && err.status_code == http::StatusCode::TOO_MANY_REQUESTS.as_u16() =>
+        {
+            storage::RefundUpdate::ErrorUpdate {
+                refund_status: Some(enums::RefundStatus::PendingEnqueue),
+                refund_error_message: err.reason.or(Some(err.message)),
+                refund_error_code: Some(err.code),
+                updated_by: storage_scheme.to_string(),
+            }
+        }
         Err(err) => storage::RefundUpdate::ErrorUpdate {
             refund_status: Some(enums::RefundStatus::Failure),
             refund_error_message: err.reason.or(Some(err.message)),
@@ -537,12 +559,19 @@ pub async fn refund_update_core(
         .await
         .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
 
+    validator::validate_refund_reason(
+        req.reason_code,
+        req.reason.as_deref().or(refund.refund_reason.as_deref()),
+        false,
+    )?;
+
     let response = db
         .update_refund(
             refund,
             storage::RefundUpdate::MetadataAndReasonUpdate {
                 metadata: req.metadata,
                 reason: req.reason,
+                reason_code: req.reason_code,
                 updated_by: merchant_account.storage_scheme.to_string(),
             },
             merchant_account.storage_scheme,
@@ -592,6 +621,14 @@ pub async fn validate_and_create_refund(
         .attach_printable("invalid merchant_id in request"))
     })?;
 
+    let is_reason_code_required =
+        is_refund_reason_code_required(state, &merchant_account.merchant_id).await;
+    validator::validate_refund_reason(
+        req.reason_code,
+        req.reason.as_deref(),
+        is_reason_code_required,
+    )?;
+
     let connecter_transaction_id = payment_attempt.clone().connector_transaction_id.ok_or_else(|| {
         report!(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Transaction in invalid. Missing field \"connector_transaction_id\" in payment_attempt.")
@@ -625,6 +662,32 @@ pub async fn validate_and_create_refund(
     validator::validate_refund_amount(total_amount_captured, &all_refunds, refund_amount)
         .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
 
+    if let Some(capture_id) = &req.capture_id {
+        let capture = get_refund_capture(
+            state,
+            payment_attempt,
+            capture_id,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+        utils::when(capture.status != enums::CaptureStatus::Charged, || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("capture {capture_id} has not been charged"),
+            }))
+        })?;
+
+        let capture_refunds = all_refunds
+            .iter()
+            .filter(|refund| refund.capture_id.as_ref() == Some(capture_id))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        validator::validate_refund_amount(capture.amount, &capture_refunds, refund_amount)
+            .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)
+            .attach_printable("refund amount exceeds the remaining amount of the capture")?;
+    }
+
     validator::validate_maximum_refund_against_payment_attempt(
         &all_refunds,
         state.conf.refund.max_attempts,
@@ -637,6 +700,19 @@ pub async fn validate_and_create_refund(
         .ok_or(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("No connector populated in payment attempt")?;
 
+    // Refunds to connectors with strict rate limits are queued and submitted in batches, instead
+    // of being submitted as soon as they are created
+    let refund_status = if state
+        .conf
+        .refund_batching
+        .connectors
+        .contains_key(&connector)
+    {
+        enums::RefundStatus::PendingEnqueue
+    } else {
+        enums::RefundStatus::Pending
+    };
+
     let refund_create_req = storage::RefundNew::default()
         .set_refund_id(refund_id.to_string())
         .set_internal_reference_id(utils::generate_id(consts::ID_LENGTH, "refid"))
@@ -651,13 +727,15 @@ pub async fn validate_and_create_refund(
         .set_currency(currency)
         .set_created_at(Some(common_utils::date_time::now()))
         .set_modified_at(Some(common_utils::date_time::now()))
-        .set_refund_status(enums::RefundStatus::Pending)
+        .set_refund_status(refund_status)
         .set_metadata(req.metadata)
         .set_description(req.reason.clone())
         .set_attempt_id(payment_attempt.attempt_id.clone())
         .set_refund_reason(req.reason)
+        .set_refund_reason_code(req.reason_code)
         .set_profile_id(payment_intent.profile_id.clone())
         .set_merchant_connector_id(payment_attempt.merchant_connector_id.clone())
+        .set_capture_id(req.capture_id)
         .to_owned();
 
     let refund = match db
@@ -767,6 +845,53 @@ pub async fn refund_filter_list(
     Ok(services::ApplicationResponse::Json(filter_list))
 }
 
+/// Find the capture of the payment attempt against which a refund is initiated
+async fn get_refund_capture(
+    state: &AppState,
+    payment_attempt: &storage::PaymentAttempt,
+    capture_id: &str,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<storage::Capture> {
+    state
+        .store
+        .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+            &payment_attempt.merchant_id,
+            &payment_attempt.payment_id,
+            &payment_attempt.attempt_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the captures of the payment attempt")?
+        .into_iter()
+        .find(|capture| capture.capture_id == capture_id)
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("capture {capture_id} does not belong to the payment"),
+            })
+        })
+}
+
+/// Whether the merchant is required to give the reason codes of their refunds, which is read from
+/// the configs. The reason codes are not required if the config cannot be read.
+async fn is_refund_reason_code_required(state: &AppState, merchant_id: &str) -> bool {
+    let reason_code_required = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &validator::get_refund_reason_code_required_key(merchant_id),
+            Some("false".to_string()),
+        )
+        .await;
+
+    match reason_code_required {
+        Ok(config) => serde_json::from_str(&config.config).unwrap_or(false),
+        Err(error) => {
+            logger::error!(refund_reason_code_required_error=?error);
+            false
+        }
+    }
+}
+
 impl ForeignFrom<storage::Refund> for api::RefundResponse {
     fn foreign_from(refund: storage::Refund) -> Self {
         let refund = refund;
@@ -776,6 +901,7 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             amount: refund.refund_amount,
             currency: refund.currency.to_string(),
             reason: refund.refund_reason,
+            reason_code: refund.refund_reason_code,
             status: refund.refund_status.foreign_into(),
             profile_id: refund.profile_id,
             metadata: refund.metadata,
@@ -785,6 +911,7 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             updated_at: Some(refund.updated_at),
             connector: refund.connector,
             merchant_connector_id: refund.merchant_connector_id,
+            capture_id: refund.capture_id,
         }
     }
 }
@@ -816,6 +943,23 @@ pub async fn schedule_refund_execution(
         .attach_printable("Failed to find the process id")?;
 
     let result = match refund.refund_status {
+        // Queued refunds are always submitted by the scheduler, whatever the refund type
+        enums::RefundStatus::PendingEnqueue => match (refund.sent_to_gateway, refund_process) {
+            (false, None) => {
+                add_refund_execute_task(db, &refund, runner)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable_lazy(|| {
+                        format!(
+                            "Failed while pushing refund execute task to scheduler, refund_id: {}",
+                            refund.refund_id
+                        )
+                    })?;
+
+                Ok(refund)
+            }
+            _ => Ok(refund),
+        },
         enums::RefundStatus::Pending | enums::RefundStatus::ManualReview => {
             match (refund.sent_to_gateway, refund_process) {
                 (false, None) => {
@@ -992,7 +1136,14 @@ pub async fn trigger_refund_execute_workflow(
         .await
         .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
     match (&refund.sent_to_gateway, &refund.refund_status) {
-        (false, enums::RefundStatus::Pending) => {
+        (false, enums::RefundStatus::Pending | enums::RefundStatus::PendingEnqueue) => {
+            if let Some(retry_time) = reserve_refund_batch_slot(state, &refund).await? {
+                db.as_scheduler()
+                    .retry_process(refund_tracker.clone(), retry_time)
+                    .await?;
+                return Ok(());
+            }
+
             let merchant_account = db
                 .find_merchant_account_by_merchant_id(&refund.merchant_id, &key_store)
                 .await
@@ -1028,6 +1179,20 @@ pub async fn trigger_refund_execute_workflow(
                 None,
             )
             .await?;
+
+            // The refund was rate limited by the connector, it is submitted again with the next
+            // batch
+            if updated_refund.refund_status == enums::RefundStatus::PendingEnqueue {
+                if let Some(retry_time) =
+                    get_next_refund_batch_time(state, &updated_refund.connector)
+                {
+                    db.as_scheduler()
+                        .retry_process(refund_tracker.clone(), retry_time)
+                        .await?;
+                    return Ok(());
+                }
+            }
+
             add_refund_sync_task(
                 db,
                 &updated_refund,
@@ -1145,6 +1310,63 @@ pub async fn add_refund_execute_task(
     Ok(response)
 }
 
+/// Reserve a place for a queued refund in the batch of refunds submitted to its connector in the
+/// current interval, returning the time of the next interval if the batch is already full
+async fn reserve_refund_batch_slot(
+    state: &AppState,
+    refund: &storage::Refund,
+) -> RouterResult<Option<time::PrimitiveDateTime>> {
+    let batching_config = match state.conf.refund_batching.connectors.get(&refund.connector) {
+        Some(batching_config) if refund.refund_status == enums::RefundStatus::PendingEnqueue => {
+            batching_config
+        }
+        _ => return Ok(None),
+    };
+
+    let interval_in_secs = batching_config.interval_in_secs;
+    let current_interval =
+        common_utils::date_time::now().assume_utc().unix_timestamp() / interval_in_secs;
+    let batch_key = format!("refund_batch_{}_{current_interval}", refund.connector);
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let refunds_in_batch = redis_conn
+        .increment_key(&batch_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reserve a slot in the refund batch")?;
+    if refunds_in_batch == 1 {
+        redis_conn
+            .set_expiry(&batch_key, interval_in_secs)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to set the expiry of the refund batch")?;
+    }
+
+    Ok((refunds_in_batch > batching_config.batch_size)
+        .then(|| get_next_refund_batch_time(state, &refund.connector))
+        .flatten())
+}
+
+fn get_next_refund_batch_time(
+    state: &AppState,
+    connector: &str,
+) -> Option<time::PrimitiveDateTime> {
+    let interval_in_secs = state
+        .conf
+        .refund_batching
+        .connectors
+        .get(connector)?
+        .interval_in_secs;
+    let now = common_utils::date_time::now();
+    let elapsed_in_interval = now.assume_utc().unix_timestamp() % interval_in_secs;
+
+    Some(now + time::Duration::seconds(interval_in_secs - elapsed_in_interval))
+}
+
 pub async fn get_refund_sync_process_schedule_time(
     db: &dyn db::StorageInterface,
     connector: &str,
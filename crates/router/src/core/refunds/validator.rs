@@ -8,6 +8,10 @@ use crate::{
     utils::{self, OptionExt},
 };
 
+/// Prefix of the keys of the configs requiring the merchants to give the reason codes of their
+/// refunds, which is followed by the merchant id
+const REFUND_REASON_CODE_REQUIRED_KEY_PREFIX: &str = "refund_reason_code_required_";
+
 // Limit constraints for refunds list flow
 pub const LOWER_LIMIT: i64 = 1;
 pub const UPPER_LIMIT: i64 = 100;
@@ -106,6 +110,33 @@ pub fn validate_refund_list(limit: Option<i64>) -> CustomResult<i64, errors::Api
     }
 }
 
+pub fn get_refund_reason_code_required_key(merchant_id: &str) -> String {
+    format!("{REFUND_REASON_CODE_REQUIRED_KEY_PREFIX}{merchant_id}")
+}
+
+/// Validate the reason of the refund, which should be given as a reason code if the merchant is
+/// required to give the reason codes of their refunds. The refunds for other reasons should
+/// describe the reason in the reason of the refund.
+pub fn validate_refund_reason(
+    reason_code: Option<enums::RefundReasonCode>,
+    reason: Option<&str>,
+    is_reason_code_required: bool,
+) -> Result<(), errors::ApiErrorResponse> {
+    match reason_code {
+        None if is_reason_code_required => Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "reason_code",
+        }),
+        Some(enums::RefundReasonCode::Other)
+            if reason.filter(|reason| !reason.trim().is_empty()).is_none() =>
+        {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "reason should describe the refund when reason_code is other".to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
 pub fn validate_for_valid_refunds(
     payment_attempt: &hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt,
     connector: api_models::enums::Connector,
@@ -144,3 +175,26 @@ pub fn validate_for_valid_refunds(
         _ => Ok(()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_refund_reason() {
+        assert!(validate_refund_reason(None, None, false).is_ok());
+        assert!(validate_refund_reason(None, Some("Customer returned the product"), true).is_err());
+        assert!(validate_refund_reason(Some(enums::RefundReasonCode::Fraud), None, true).is_ok());
+        assert!(validate_refund_reason(
+            Some(enums::RefundReasonCode::Other),
+            Some("Goodwill gesture"),
+            true
+        )
+        .is_ok());
+        assert!(validate_refund_reason(Some(enums::RefundReasonCode::Other), None, false).is_err());
+        assert!(
+            validate_refund_reason(Some(enums::RefundReasonCode::Other), Some("  "), false)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use std::{sync::Arc, time::Duration};
+
+use actix_web::rt::time as actix_time;
+use error_stack::{report, ResultExt};
+use once_cell::sync::OnceCell;
+use router_env::logger;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use super::errors::{self, RouterResult};
+use crate::{configs::settings, routes::AppState};
+
+/// Permits for the requests of the background flows being served, shared by all the requests
+/// served by this instance
+static BACKGROUND_REQUEST_PERMITS: OnceCell<Arc<Semaphore>> = OnceCell::new();
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RequestPriority {
+    /// Requests which a customer or a merchant waits on, like confirms and captures
+    Interactive,
+    /// Requests which can be served later on, like syncs, lists and analytics
+    Background,
+}
+
+impl RequestPriority {
+    pub fn from_flow_name(config: &settings::RequestPriority, flow_name: &str) -> Self {
+        if config.background_flows.contains(flow_name) {
+            Self::Background
+        } else {
+            Self::Interactive
+        }
+    }
+}
+
+/// Admit a request to be served, returning the permit which must be held until it has been
+/// served.
+///
+/// Only `background_max_concurrency` requests of the background flows are served at the same
+/// time, so that the interactive requests are preferred under load. Interactive requests are
+/// always admitted, while background requests are rejected if they could not be admitted within
+/// `background_max_wait_in_millis`.
+pub async fn admit_request(
+    state: &AppState,
+    flow_name: &str,
+) -> RouterResult<Option<OwnedSemaphorePermit>> {
+    let config = &state.conf.request_priority;
+    let Some(background_max_concurrency) = config.background_max_concurrency else {
+        return Ok(None);
+    };
+    if RequestPriority::from_flow_name(config, flow_name) == RequestPriority::Interactive {
+        return Ok(None);
+    }
+
+    let permits = BACKGROUND_REQUEST_PERMITS
+        .get_or_init(|| Arc::new(Semaphore::new(background_max_concurrency)))
+        .clone();
+    let permit = actix_time::timeout(
+        Duration::from_millis(config.background_max_wait_in_millis),
+        permits.acquire_owned(),
+    )
+    .await
+    .map_err(|_| {
+        logger::warn!(
+            flow = flow_name,
+            "Background request could not be admitted in time"
+        );
+        report!(errors::ApiErrorResponse::ResourceBusy)
+    })?
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Background request permits have been closed")?;
+
+    Ok(Some(permit))
+}
@@ -264,6 +264,13 @@ pub async fn update_business_profile_active_algorithm_ref(
         session_expiry: None,
         authentication_connector_details: None,
         extended_card_info_config: None,
+        dunning_config: None,
+        is_network_tokenization_enabled: None,
+        is_tax_calculation_enabled: None,
+        requires_cvc_for_saved_cards: None,
+        auto_void_after_in_hours: None,
+        abandoned_payment_config: None,
+        max_auto_retries: None,
     };
     db.update_business_profile_by_profile_id(current_business_profile, business_profile_update)
         .await
@@ -0,0 +1,310 @@
+use api_models::{enums as api_enums, payments as payment_types};
+use common_utils::{
+    ext_traits::{BytesExt, OptionExt, ValueExt},
+    request::RequestContent,
+};
+use error_stack::ResultExt;
+use masking::{Mask, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::helpers,
+    },
+    headers, services,
+    types::{domain, storage},
+    AppState,
+};
+
+/// Identifier of the tax provider in the errors of its API
+const TAX_PROVIDER: &str = "tax_calculation_service";
+
+#[derive(Debug, Serialize)]
+struct TaxCalculationRequest {
+    /// Amount of the order excluding the tax, in the lowest denomination of the currency
+    order_amount: i64,
+    currency: api_enums::Currency,
+    shipping_address: TaxShippingAddress,
+    line_items: Vec<TaxLineItem>,
+}
+
+#[derive(Debug, Serialize)]
+struct TaxShippingAddress {
+    country: api_enums::CountryAlpha2,
+    state: Option<Secret<String>>,
+    city: Option<String>,
+    zip: Option<Secret<String>>,
+    line1: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize)]
+struct TaxLineItem {
+    product_id: Option<String>,
+    product_name: String,
+    quantity: u16,
+    unit_amount: i64,
+    category: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TaxCalculationResponse {
+    /// Tax on the order, in the lowest denomination of the currency
+    order_tax_amount: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct TaxProviderErrorResponse {
+    error: TaxProviderError,
+}
+
+#[derive(Debug, Deserialize)]
+struct TaxProviderError {
+    code: String,
+    message: Option<String>,
+}
+
+/// Calculate the tax on the order of a payment for the shipping address selected by the customer,
+/// and update the amount of the payment to include it, so that the customer is shown the amount
+/// they will be charged before confirming the payment.
+///
+/// This is to be called every time the shipping address changes, such as when the customer
+/// selects another address in the payment sheet of their wallet. The tax calculated for the
+/// previous address is replaced.
+#[instrument(skip_all)]
+pub async fn calculate_tax(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payment_types::PaymentsTaxCalculationRequest,
+) -> RouterResponse<payment_types::PaymentsTaxCalculationResponse> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &req.payment_id,
+            &merchant_account.merchant_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    helpers::authenticate_client_secret(Some(req.client_secret.peek()), &payment_intent)?;
+    helpers::validate_payment_status_against_allowed_statuses(
+        &payment_intent.status,
+        &[
+            storage::enums::IntentStatus::RequiresPaymentMethod,
+            storage::enums::IntentStatus::RequiresConfirmation,
+        ],
+        "calculate tax",
+    )?;
+
+    let profile_id = payment_intent
+        .profile_id
+        .as_ref()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Profile id missing in payment intent")?;
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+    if !business_profile.is_tax_calculation_enabled {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Tax calculation is not enabled for the business profile of the payment"
+                .to_string(),
+        })?
+    }
+
+    let currency = payment_intent
+        .currency
+        .get_required_value("currency")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Currency missing in payment intent")?;
+    let shipping_address = req
+        .shipping
+        .address
+        .as_ref()
+        .and_then(|address| {
+            address.country.map(|country| TaxShippingAddress {
+                country,
+                state: address.state.clone(),
+                city: address.city.clone(),
+                zip: address.zip.clone(),
+                line1: address.line1.clone(),
+            })
+        })
+        .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "shipping.address.country",
+        })?;
+
+    // The tax calculated for a previous shipping address is replaced
+    let order_amount = payment_intent.amount - payment_intent.order_tax_amount.unwrap_or(0);
+    let request = TaxCalculationRequest {
+        order_amount,
+        currency,
+        shipping_address,
+        line_items: get_line_items(&payment_intent)?,
+    };
+    let response: TaxCalculationResponse = call_tax_provider_api(
+        &state,
+        "taxes",
+        RequestContent::Json(Box::new(request)),
+        "calculate_tax",
+    )
+    .await?;
+    let net_amount = order_amount + response.order_tax_amount;
+
+    let shipping_address = helpers::create_or_update_address_for_payment_by_request(
+        db,
+        Some(&req.shipping),
+        payment_intent.shipping_address_id.as_deref(),
+        &merchant_account.merchant_id,
+        payment_intent.customer_id.as_ref(),
+        &key_store,
+        &payment_intent.payment_id,
+        storage_scheme,
+    )
+    .await?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            &merchant_account.merchant_id,
+            payment_intent.active_attempt.get_id().as_str(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        storage::PaymentAttemptUpdate::TaxCalculationUpdate {
+            amount: net_amount,
+            amount_capturable: net_amount,
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_intent = db
+        .update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::TaxCalculationUpdate {
+                amount: net_amount,
+                order_tax_amount: response.order_tax_amount,
+                shipping_address_id: shipping_address.map(|address| address.address_id),
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    logger::info!(
+        order_tax_amount = response.order_tax_amount,
+        "Updated the amount of the payment with the tax calculated for its shipping address"
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        payment_types::PaymentsTaxCalculationResponse {
+            payment_id: payment_intent.payment_id,
+            net_amount: payment_intent.amount,
+            order_tax_amount: response.order_tax_amount,
+            currency,
+        },
+    ))
+}
+
+/// The tax provider calculates the tax of every line item of the order, if the order details of
+/// the payment are available
+fn get_line_items(payment_intent: &storage::PaymentIntent) -> RouterResult<Vec<TaxLineItem>> {
+    payment_intent
+        .order_details
+        .iter()
+        .flatten()
+        .map(|order_details| {
+            order_details
+                .clone()
+                .parse_value::<payment_types::OrderDetailsWithAmount>("OrderDetailsWithAmount")
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "OrderDetailsWithAmount",
+                })
+                .attach_printable("Unable to parse OrderDetailsWithAmount")
+                .map(|order_details| TaxLineItem {
+                    product_id: order_details.product_id,
+                    product_name: order_details.product_name,
+                    quantity: order_details.quantity,
+                    unit_amount: order_details.amount,
+                    category: order_details.category,
+                })
+        })
+        .collect()
+}
+
+async fn call_tax_provider_api<T: DeserializeOwned>(
+    state: &AppState,
+    path: &str,
+    request_body: RequestContent,
+    flow: &str,
+) -> RouterResult<T> {
+    let tax_calculation_service = state.conf.tax_calculation_service.get_inner();
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(&format!("{}{path}", tax_calculation_service.base_url))
+        .attach_default_headers()
+        .headers(vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                "application/json".to_string().into(),
+            ),
+            (
+                headers::AUTHORIZATION.to_string(),
+                format!("Bearer {}", tax_calculation_service.api_key.peek()).into_masked(),
+            ),
+        ])
+        .set_body(request_body)
+        .build();
+
+    let response = services::call_connector_api(state, request, flow)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the API of the tax provider")?;
+
+    match response {
+        Ok(response) => response
+            .response
+            .parse_struct(std::any::type_name::<T>())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the response of the tax provider"),
+        Err(error_response) => {
+            let error = error_response
+                .response
+                .parse_struct::<TaxProviderErrorResponse>("TaxProviderErrorResponse")
+                .ok()
+                .map(|response| response.error);
+
+            Err(errors::ApiErrorResponse::ExternalConnectorError {
+                code: error
+                    .as_ref()
+                    .map(|error| error.code.clone())
+                    .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                message: error
+                    .as_ref()
+                    .and_then(|error| error.message.clone())
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                connector: TAX_PROVIDER.to_string(),
+                status_code: error_response.status_code,
+                reason: None,
+            }
+            .into())
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use api_models::usage as usage_api_types;
+use error_stack::ResultExt;
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+};
+
+use crate::{
+    core::errors::{self, RouterResponse},
+    db::usage_metering::UsageMeteringInterface,
+    services,
+    types::storage,
+    AppState,
+};
+
+/// Record one occurrence of a billable action for the merchant
+///
+/// Usage is aggregated into daily buckets and is written in the background, so that metering
+/// does not add to the latency of the request being metered.
+pub fn record_usage(state: &AppState, merchant_id: &str, metric: common_enums::UsageMetric) {
+    if !state.conf.usage_metering.enabled {
+        return;
+    }
+
+    let now = common_utils::date_time::now();
+    let usage = storage::UsageMeteringNew {
+        merchant_id: merchant_id.to_owned(),
+        metric,
+        period_start: now.replace_time(time::Time::MIDNIGHT),
+        count: 1,
+        created_at: now,
+        last_modified_at: now,
+    };
+    let store = state.store.clone();
+
+    tokio::spawn(
+        async move {
+            store
+                .increment_usage(usage)
+                .await
+                .map_err(|error| logger::error!(usage_metering_error=?error))
+                .ok();
+        }
+        .in_current_span(),
+    );
+}
+
+#[instrument(skip_all)]
+pub async fn export_usage(
+    state: AppState,
+    request: usage_api_types::UsageExportRequest,
+) -> RouterResponse<usage_api_types::UsageExportResponse> {
+    if request.start_time > request.end_time {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`start_time` must not be later than `end_time`".to_string(),
+        }
+        .into());
+    }
+
+    let records = state
+        .store
+        .filter_usage_by_period(request.merchant_id, request.start_time, request.end_time)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the metered usage")?
+        .into_iter()
+        .map(|usage| usage_api_types::UsageRecord {
+            merchant_id: usage.merchant_id,
+            metric: usage.metric,
+            period_start: usage.period_start,
+            count: usage.count,
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        usage_api_types::UsageExportResponse { records },
+    ))
+}
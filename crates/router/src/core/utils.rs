@@ -167,6 +167,7 @@ pub async fn construct_payout_router_data<'a, F>(
         payment_method_status: None,
         request: types::PayoutsData {
             payout_id: payouts.payout_id.to_owned(),
+            payout_attempt_id: payout_attempt.payout_attempt_id.to_owned(),
             amount: payouts.amount,
             connector_payout_id: Some(payout_attempt.connector_payout_id.to_owned()),
             destination_currency: payouts.destination_currency,
@@ -200,6 +201,7 @@ pub async fn construct_payout_router_data<'a, F>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -325,6 +327,7 @@ pub async fn construct_refund_router_data<'a, F>(
             webhook_url,
             connector_metadata: payment_attempt.connector_metadata.clone(),
             reason: refund.refund_reason.clone(),
+            reason_code: refund.refund_reason_code,
             connector_refund_id: refund.connector_refund_id.clone(),
             browser_info,
         },
@@ -354,6 +357,7 @@ pub async fn construct_refund_router_data<'a, F>(
         connector_api_version,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: Some(refund.refund_id.clone()),
@@ -587,6 +591,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         dispute_id: Some(dispute.dispute_id.clone()),
@@ -678,6 +683,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -775,6 +781,7 @@ pub async fn construct_upload_file_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -869,6 +876,7 @@ pub async fn construct_defend_dispute_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -954,6 +962,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -1,13 +1,42 @@
+mod transformers;
 pub mod utils;
-use api_models::verifications::{self, ApplepayMerchantResponse};
-use common_utils::{errors::CustomResult, request::RequestContent};
-use error_stack::ResultExt;
-use masking::ExposeInterface;
+use api_models::{
+    enums as api_enums, payments as payment_types,
+    verifications::{self, ApplepayMerchantResponse},
+};
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::{BytesExt, ValueExt},
+    request::RequestContent,
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, Mask, PeekInterface};
 
-use crate::{core::errors::api_error_response, headers, logger, routes::AppState, services};
+use self::transformers as registration_types;
+use crate::{
+    consts,
+    core::{
+        errors::{api_error_response, RouterResult, StorageErrorExt},
+        payments::helpers,
+    },
+    headers, logger,
+    routes::{metrics, AppState},
+    services,
+    types::{self, domain, storage},
+};
 
 const APPLEPAY_INTERNAL_MERCHANT_NAME: &str = "Applepay_merchant";
 
+const APPLEPAY_DOMAIN_RENEWAL_TAG: &str = "APPLEPAY_DOMAIN_RENEWAL";
+const APPLEPAY_DOMAIN_RENEWAL_NAME: &str = "APPLEPAY_DOMAIN_RENEWAL";
+const APPLEPAY_DOMAIN_RENEWAL_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::ApplepayDomainRenewalWorkflow;
+
+/// Connectors which register the domains of the merchant with Apple using their own Apple Pay
+/// merchant identity
+const CONNECTORS_REGISTERING_APPLEPAY_DOMAINS: [api_enums::Connector; 2] =
+    [api_enums::Connector::Stripe, api_enums::Connector::Adyen];
+
 pub async fn verify_merchant_creds_for_applepay(
     state: AppState,
     body: verifications::ApplepayMerchantVerificationRequest,
@@ -16,6 +45,28 @@ pub async fn verify_merchant_creds_for_applepay(
     services::ApplicationResponse<ApplepayMerchantResponse>,
     api_error_response::ApiErrorResponse,
 > {
+    register_domains_with_apple(&state, body.domain_names.clone()).await?;
+
+    utils::check_existence_and_add_domain_to_db(
+        &state,
+        merchant_id,
+        body.merchant_connector_account_id.clone(),
+        body.domain_names.clone(),
+    )
+    .await
+    .change_context(api_error_response::ApiErrorResponse::InternalServerError)?;
+
+    Ok(services::api::ApplicationResponse::Json(
+        ApplepayMerchantResponse {
+            status_message: "Applepay verification Completed".to_string(),
+        },
+    ))
+}
+
+async fn register_domains_with_apple(
+    state: &AppState,
+    domain_names: Vec<String>,
+) -> RouterResult<()> {
     let applepay_merchant_configs = state.conf.applepay_merchant_configs.get_inner();
 
     let applepay_internal_merchant_identifier = applepay_merchant_configs
@@ -27,7 +78,7 @@ pub async fn verify_merchant_creds_for_applepay(
     let applepay_endpoint = &applepay_merchant_configs.applepay_endpoint;
 
     let request_body = verifications::ApplepayMerchantVerificationConfigs {
-        domain_names: body.domain_names.clone(),
+        domain_names,
         encrypt_to: applepay_internal_merchant_identifier.clone(),
         partner_internal_merchant_identifier: applepay_internal_merchant_identifier,
         partner_merchant_name: APPLEPAY_INTERNAL_MERCHANT_NAME.to_string(),
@@ -47,7 +98,7 @@ pub async fn verify_merchant_creds_for_applepay(
         .build();
 
     let response = services::call_connector_api(
-        &state,
+        state,
         apple_pay_merch_verification_req,
         "verify_merchant_creds_for_applepay",
     )
@@ -59,21 +110,7 @@ pub async fn verify_merchant_creds_for_applepay(
 
     // Error is already logged
     match applepay_response {
-        Ok(_) => {
-            utils::check_existence_and_add_domain_to_db(
-                &state,
-                merchant_id,
-                body.merchant_connector_account_id.clone(),
-                body.domain_names.clone(),
-            )
-            .await
-            .change_context(api_error_response::ApiErrorResponse::InternalServerError)?;
-            Ok(services::api::ApplicationResponse::Json(
-                ApplepayMerchantResponse {
-                    status_message: "Applepay verification Completed".to_string(),
-                },
-            ))
-        }
+        Ok(_) => Ok(()),
         Err(error) => {
             logger::error!(?error);
             Err(api_error_response::ApiErrorResponse::InvalidRequestData {
@@ -113,3 +150,464 @@ pub async fn get_verified_apple_domains_with_mid_mca_id(
         api_models::verifications::ApplepayVerifiedDomainsResponse { verified_domains },
     ))
 }
+
+/// Register the domains of the merchant for Apple Pay with all the connectors of the business
+/// profile, and schedule their periodic renewal
+pub async fn register_applepay_domains_for_profile(
+    state: AppState,
+    merchant_id: String,
+    profile_id: String,
+    body: verifications::ApplepayProfileDomainRegistrationRequest,
+) -> CustomResult<
+    services::ApplicationResponse<verifications::ApplepayProfileDomainRegistrationResponse>,
+    api_error_response::ApiErrorResponse,
+> {
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(
+            api_error_response::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.clone(),
+            },
+        )?;
+
+    if business_profile.merchant_id != merchant_id {
+        Err(
+            api_error_response::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.clone(),
+            },
+        )?
+    }
+
+    if body.domain_names.is_empty() {
+        Err(api_error_response::ApiErrorResponse::InvalidRequestData {
+            message: "domain_names must not be empty".to_string(),
+        })?
+    }
+
+    let registrations =
+        register_domains_for_profile(&state, &merchant_id, &profile_id, Some(body.domain_names))
+            .await?;
+
+    if registrations.is_empty() {
+        Err(api_error_response::ApiErrorResponse::PreconditionFailed {
+            message: "Apple Pay is not configured for any connector of the business profile"
+                .to_string(),
+        })?
+    }
+
+    add_applepay_domain_renewal_task(&state, &merchant_id, &profile_id).await?;
+
+    Ok(services::api::ApplicationResponse::Json(
+        verifications::ApplepayProfileDomainRegistrationResponse {
+            profile_id,
+            registrations,
+        },
+    ))
+}
+
+/// Register the domains with every merchant connector account of the business profile which has
+/// Apple Pay configured
+///
+/// If no domains are provided, the domains already verified for each merchant connector account
+/// are registered again. A failure with one merchant connector account does not prevent the
+/// registration with the others, it is reported in its registration instead.
+pub async fn register_domains_for_profile(
+    state: &AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    domain_names: Option<Vec<String>>,
+) -> RouterResult<Vec<verifications::ApplepayDomainRegistration>> {
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(api_error_response::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            merchant_id,
+            false,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(
+            api_error_response::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                id: merchant_id.to_string(),
+            },
+        )?;
+
+    let mut registrations = Vec::new();
+    for merchant_connector_account in helpers::filter_mca_based_on_business_profile(
+        merchant_connector_accounts,
+        Some(profile_id.to_string()),
+    )
+    .into_iter()
+    .filter(|mca| mca.connector_type == storage::enums::ConnectorType::PaymentProcessor)
+    {
+        let Some(registration_method) = get_registration_method(&merchant_connector_account) else {
+            continue;
+        };
+
+        let already_verified_domains = merchant_connector_account
+            .applepay_verified_domains
+            .clone()
+            .unwrap_or_default();
+        let domains_to_register = domain_names
+            .clone()
+            .unwrap_or_else(|| already_verified_domains.clone());
+        if domains_to_register.is_empty() {
+            continue;
+        }
+
+        let registration_result = match registration_method {
+            verifications::ApplepayDomainRegistrationMethod::Apple => {
+                register_domains_with_apple(state, domains_to_register.clone()).await
+            }
+            verifications::ApplepayDomainRegistrationMethod::Connector => {
+                register_domains_with_connector(
+                    state,
+                    &merchant_connector_account,
+                    &domains_to_register,
+                )
+                .await
+            }
+        };
+
+        let registration = match registration_result {
+            Ok(()) => {
+                let verified_domains = utils::check_existence_and_add_domain_to_db(
+                    state,
+                    merchant_id.to_string(),
+                    merchant_connector_account.merchant_connector_id.clone(),
+                    domains_to_register,
+                )
+                .await?;
+
+                verifications::ApplepayDomainRegistration {
+                    merchant_connector_account_id: merchant_connector_account
+                        .merchant_connector_id
+                        .clone(),
+                    connector_name: merchant_connector_account.connector_name.clone(),
+                    registration_method,
+                    verified_domains,
+                    error_message: None,
+                }
+            }
+            Err(error) => {
+                logger::error!(
+                    merchant_connector_id = %merchant_connector_account.merchant_connector_id,
+                    ?error,
+                    "Failed to register Apple Pay domains"
+                );
+
+                verifications::ApplepayDomainRegistration {
+                    merchant_connector_account_id: merchant_connector_account
+                        .merchant_connector_id
+                        .clone(),
+                    connector_name: merchant_connector_account.connector_name.clone(),
+                    registration_method,
+                    verified_domains: already_verified_domains,
+                    error_message: Some(error.current_context().error_message()),
+                }
+            }
+        };
+        registrations.push(registration);
+    }
+
+    Ok(registrations)
+}
+
+/// Domains of the simplified Apple Pay flow are registered with Apple, as the payment sessions are
+/// created using the Apple Pay merchant identity of Hyperswitch. The connectors which create the
+/// payment sessions using their own Apple Pay merchant identity register the domains themselves.
+fn get_registration_method(
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> Option<verifications::ApplepayDomainRegistrationMethod> {
+    let metadata = merchant_connector_account.metadata.clone()?.expose();
+
+    let combined_metadata = metadata
+        .clone()
+        .parse_value::<payment_types::ApplepayCombinedSessionTokenData>(
+            "ApplepayCombinedSessionTokenData",
+        )
+        .map(|session_token_data| session_token_data.apple_pay_combined);
+    let is_apple_pay_configured = combined_metadata.is_ok()
+        || metadata
+            .parse_value::<payment_types::ApplepaySessionTokenData>("ApplepaySessionTokenData")
+            .is_ok();
+
+    let connector = merchant_connector_account
+        .connector_name
+        .parse::<api_enums::Connector>()
+        .ok();
+
+    match combined_metadata {
+        Ok(payment_types::ApplePayCombinedMetadata::Simplified { .. }) => {
+            Some(verifications::ApplepayDomainRegistrationMethod::Apple)
+        }
+        _ if is_apple_pay_configured
+            && connector.is_some_and(|connector| {
+                CONNECTORS_REGISTERING_APPLEPAY_DOMAINS.contains(&connector)
+            }) =>
+        {
+            Some(verifications::ApplepayDomainRegistrationMethod::Connector)
+        }
+        _ => None,
+    }
+}
+
+async fn register_domains_with_connector(
+    state: &AppState,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    domain_names: &[String],
+) -> RouterResult<()> {
+    let connector_auth: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .clone()
+        .parse_value("ConnectorAuthType")
+        .change_context(api_error_response::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the connector credentials")?;
+
+    match merchant_connector_account
+        .connector_name
+        .parse::<api_enums::Connector>()
+    {
+        Ok(api_enums::Connector::Stripe) => {
+            register_domains_with_stripe(state, &connector_auth, domain_names).await
+        }
+        Ok(api_enums::Connector::Adyen) => {
+            register_domains_with_adyen(state, &connector_auth, domain_names).await
+        }
+        _ => Err(api_error_response::ApiErrorResponse::NotSupported {
+            message: format!(
+                "Apple Pay domain registration with {}",
+                merchant_connector_account.connector_name
+            ),
+        }
+        .into()),
+    }
+}
+
+async fn register_domains_with_stripe(
+    state: &AppState,
+    connector_auth: &types::ConnectorAuthType,
+    domain_names: &[String],
+) -> RouterResult<()> {
+    let types::ConnectorAuthType::HeaderKey { api_key } = connector_auth else {
+        return Err(report!(
+            api_error_response::ApiErrorResponse::InternalServerError
+        ))
+        .attach_printable("Unexpected connector credentials for Stripe");
+    };
+
+    // Stripe registers a single domain per request
+    for domain_name in domain_names {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&format!(
+                "{}v1/apple_pay/domains",
+                state.conf.connectors.stripe.base_url
+            ))
+            .attach_default_headers()
+            .headers(vec![
+                (
+                    headers::CONTENT_TYPE.to_string(),
+                    "application/x-www-form-urlencoded".to_string().into(),
+                ),
+                (
+                    headers::AUTHORIZATION.to_string(),
+                    format!("Bearer {}", api_key.peek()).into_masked(),
+                ),
+            ])
+            .set_body(RequestContent::FormUrlEncoded(Box::new(
+                registration_types::StripeApplePayDomainRequest {
+                    domain_name: domain_name.clone(),
+                },
+            )))
+            .build();
+
+        call_registration_api(
+            state,
+            request,
+            "register_applepay_domain_with_stripe",
+            api_enums::Connector::Stripe,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+async fn register_domains_with_adyen(
+    state: &AppState,
+    connector_auth: &types::ConnectorAuthType,
+    domain_names: &[String],
+) -> RouterResult<()> {
+    let (api_key, merchant_account) = match connector_auth {
+        types::ConnectorAuthType::BodyKey { api_key, key1 }
+        | types::ConnectorAuthType::SignatureKey { api_key, key1, .. } => (api_key, key1),
+        _ => Err(report!(
+            api_error_response::ApiErrorResponse::InternalServerError
+        ))
+        .attach_printable("Unexpected connector credentials for Adyen")?,
+    };
+    let payment_method_settings_url = format!(
+        "{}v3/merchants/{}/paymentMethodSettings",
+        state.conf.connectors.adyen.base_url_management,
+        merchant_account.peek()
+    );
+    let request_headers = vec![
+        (
+            headers::CONTENT_TYPE.to_string(),
+            "application/json".to_string().into(),
+        ),
+        (
+            headers::X_API_KEY.to_string(),
+            api_key.peek().to_string().into_masked(),
+        ),
+    ];
+
+    // The domains are added to the Apple Pay payment method of the merchant account
+    let list_request = services::RequestBuilder::new()
+        .method(services::Method::Get)
+        .url(&format!("{payment_method_settings_url}?pageSize=100"))
+        .attach_default_headers()
+        .headers(request_headers.clone())
+        .build();
+    let payment_method_settings: registration_types::AdyenPaymentMethodSettingsResponse =
+        call_registration_api(
+            state,
+            list_request,
+            "list_adyen_payment_method_settings",
+            api_enums::Connector::Adyen,
+        )
+        .await?
+        .response
+        .parse_struct("AdyenPaymentMethodSettingsResponse")
+        .change_context(api_error_response::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the payment method settings of Adyen")?;
+
+    let payment_method_id = payment_method_settings
+        .data
+        .into_iter()
+        .find(|setting| setting.payment_method_type == "applepay")
+        .map(|setting| setting.id)
+        .ok_or(api_error_response::ApiErrorResponse::PreconditionFailed {
+            message: "Apple Pay is not enabled for the Adyen merchant account".to_string(),
+        })?;
+
+    let add_domains_request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(&format!(
+            "{payment_method_settings_url}/{payment_method_id}/addApplePayDomains"
+        ))
+        .attach_default_headers()
+        .headers(request_headers)
+        .set_body(RequestContent::Json(Box::new(
+            registration_types::AdyenApplePayDomainsRequest {
+                domains: domain_names.to_vec(),
+            },
+        )))
+        .build();
+
+    call_registration_api(
+        state,
+        add_domains_request,
+        "register_applepay_domains_with_adyen",
+        api_enums::Connector::Adyen,
+    )
+    .await
+    .map(|_| ())
+}
+
+async fn call_registration_api(
+    state: &AppState,
+    request: services::Request,
+    flow: &str,
+    connector: api_enums::Connector,
+) -> RouterResult<types::Response> {
+    let response = services::call_connector_api(state, request, flow)
+        .await
+        .change_context(api_error_response::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to call {connector} for {flow}"))?;
+
+    response.map_err(|error_response| {
+        let (code, message) = match connector {
+            api_enums::Connector::Stripe => error_response
+                .response
+                .parse_struct::<registration_types::StripeErrorResponse>("StripeErrorResponse")
+                .map(|response| (response.error.code, response.error.message))
+                .unwrap_or_default(),
+            api_enums::Connector::Adyen => error_response
+                .response
+                .parse_struct::<registration_types::AdyenManagementErrorResponse>(
+                    "AdyenManagementErrorResponse",
+                )
+                .map(|response| (response.error_code, response.detail.or(response.title)))
+                .unwrap_or_default(),
+            _ => (None, None),
+        };
+
+        report!(
+            api_error_response::ApiErrorResponse::ExternalConnectorError {
+                code: code.unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                message: message.unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                connector: connector.to_string(),
+                status_code: error_response.status_code,
+                reason: None,
+            }
+        )
+    })
+}
+
+/// Schedule the renewal of the Apple Pay domains of the business profile
+///
+/// The task reschedules itself after every run, so this is a no-op if it was already added.
+pub async fn add_applepay_domain_renewal_task(
+    state: &AppState,
+    merchant_id: &str,
+    profile_id: &str,
+) -> RouterResult<()> {
+    let schedule_time = common_utils::date_time::now()
+        + time::Duration::days(state.conf.applepay_domain_renewal.interval_in_days);
+
+    let process_tracker_id =
+        format!("{APPLEPAY_DOMAIN_RENEWAL_RUNNER}_{APPLEPAY_DOMAIN_RENEWAL_NAME}_{profile_id}");
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        APPLEPAY_DOMAIN_RENEWAL_NAME,
+        APPLEPAY_DOMAIN_RENEWAL_RUNNER,
+        [APPLEPAY_DOMAIN_RENEWAL_TAG],
+        storage::ApplepayDomainRenewalTrackingData {
+            merchant_id: merchant_id.to_string(),
+            profile_id: profile_id.to_string(),
+        },
+        schedule_time,
+    )
+    .change_context(api_error_response::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct Apple Pay domain renewal process tracker task")?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes(
+                    "flow",
+                    "ApplepayDomainRenewal",
+                )],
+            );
+            Ok(())
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(api_error_response::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while inserting Apple Pay domain renewal task to process_tracker: profile_id: {profile_id}"
+                )
+            }),
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+pub struct StripeApplePayDomainRequest {
+    pub domain_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeErrorResponse {
+    pub error: StripeErrorDetails,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeErrorDetails {
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AdyenPaymentMethodSettingsResponse {
+    #[serde(default)]
+    pub data: Vec<AdyenPaymentMethodSetting>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AdyenPaymentMethodSetting {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub payment_method_type: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdyenApplePayDomainsRequest {
+    pub domains: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenManagementErrorResponse {
+    pub error_code: Option<String>,
+    pub title: Option<String>,
+    pub detail: Option<String>,
+}
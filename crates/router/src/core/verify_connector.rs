@@ -4,51 +4,72 @@ use error_stack::ResultExt;
 use crate::{
     connector,
     core::errors,
+    routes::metrics,
     services,
     types::{
-        api,
+        self as router_types, api,
         api::verify_connector::{self as types, VerifyConnector},
+        storage,
     },
     utils::verify_connector as utils,
     AppState,
 };
 
+const CONNECTOR_SMOKE_TEST_TAG: &str = "CONNECTOR_SMOKE_TEST";
+const CONNECTOR_SMOKE_TEST_NAME: &str = "CONNECTOR_SMOKE_TEST";
+const CONNECTOR_SMOKE_TEST_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::ConnectorSmokeTestWorkflow;
+
 pub async fn verify_connector_credentials(
     state: AppState,
     req: VerifyConnectorRequest,
+) -> errors::RouterResponse<()> {
+    verify_connector(
+        &state,
+        req.connector_name,
+        req.connector_account_details.into(),
+    )
+    .await
+}
+
+/// Run a test card payment against the connector using the given credentials
+pub async fn verify_connector(
+    state: &AppState,
+    connector_name: Connector,
+    connector_auth: router_types::ConnectorAuthType,
 ) -> errors::RouterResponse<()> {
     let boxed_connector = api::ConnectorData::get_connector_by_name(
         &state.conf.connectors,
-        &req.connector_name.to_string(),
+        &connector_name.to_string(),
         api::GetToken::Connector,
         None,
     )
     .change_context(errors::ApiErrorResponse::IncorrectConnectorNameGiven)?;
 
-    let card_details = utils::get_test_card_details(req.connector_name)?.ok_or(
+    let card_details = utils::get_test_card_details(connector_name)?.ok_or(
         errors::ApiErrorResponse::FlowNotSupported {
             flow: "Verify credentials".to_string(),
-            connector: req.connector_name.to_string(),
+            connector: connector_name.to_string(),
         },
     )?;
 
-    match req.connector_name {
+    match connector_name {
         Connector::Stripe => {
             connector::Stripe::verify(
-                &state,
+                state,
                 types::VerifyConnectorData {
                     connector: *boxed_connector.connector,
-                    connector_auth: req.connector_account_details.into(),
+                    connector_auth,
                     card_details,
                 },
             )
             .await
         }
         Connector::Paypal => connector::Paypal::get_access_token(
-            &state,
+            state,
             types::VerifyConnectorData {
                 connector: *boxed_connector.connector,
-                connector_auth: req.connector_account_details.into(),
+                connector_auth,
                 card_details,
             },
         )
@@ -56,8 +77,51 @@ pub async fn verify_connector_credentials(
         .map(|_| services::ApplicationResponse::StatusOk),
         _ => Err(errors::ApiErrorResponse::FlowNotSupported {
             flow: "Verify credentials".to_string(),
-            connector: req.connector_name.to_string(),
+            connector: connector_name.to_string(),
         }
         .into()),
     }
 }
+
+/// Schedule the nightly smoke test of the sandbox connectors of the configured merchant
+///
+/// The task reschedules itself after every run, so this is a no-op if it was already added.
+pub async fn add_connector_smoke_test_task(state: &AppState) -> errors::RouterResult<()> {
+    let merchant_id = state.conf.connector_smoke_test.merchant_id.clone();
+    let schedule_time =
+        (common_utils::date_time::now().date() + time::Duration::days(1)).midnight();
+
+    let process_tracker_id =
+        format!("{CONNECTOR_SMOKE_TEST_RUNNER}_{CONNECTOR_SMOKE_TEST_NAME}_{merchant_id}");
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        CONNECTOR_SMOKE_TEST_NAME,
+        CONNECTOR_SMOKE_TEST_RUNNER,
+        [CONNECTOR_SMOKE_TEST_TAG],
+        storage::ConnectorSmokeTestTrackingData {
+            merchant_id: merchant_id.clone(),
+        },
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct connector smoke test process tracker task")?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes("flow", "ConnectorSmokeTest")],
+            );
+            Ok(())
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while inserting connector smoke test task to process_tracker: merchant_id: {merchant_id}"
+                )
+            }),
+    }
+}
@@ -1,3 +1,5 @@
+pub mod endpoint_health;
+pub mod schemas;
 pub mod types;
 pub mod utils;
 #[cfg(feature = "olap")]
@@ -12,7 +14,8 @@ use api_models::{
     webhooks::{self, WebhookResponseTracker},
 };
 use common_utils::{
-    errors::ReportSwitchExt, events::ApiEventsType, ext_traits::Encode, request::RequestContent,
+    date_time, errors::ReportSwitchExt, events::ApiEventsType, ext_traits::Encode,
+    request::RequestContent,
 };
 use error_stack::{report, ResultExt};
 use masking::{ExposeInterface, Mask, PeekInterface, Secret};
@@ -38,7 +41,7 @@ use crate::{
         api_logs::ApiEvent,
         outgoing_webhook_logs::{OutgoingWebhookEvent, OutgoingWebhookEventMetric},
     },
-    logger,
+    headers, logger,
     routes::{
         app::{AppStateInfo, ReqState},
         lock_utils,
@@ -303,6 +306,106 @@ pub async fn refunds_incoming_webhook_flow(
     })
 }
 
+#[cfg(feature = "payouts")]
+pub async fn payouts_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    event_type: webhooks::IncomingWebhookEvent,
+) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
+    metrics::INCOMING_PAYOUT_WEBHOOK_METRIC.add(&metrics::CONTEXT, 1, &[]);
+    if source_verified {
+        let db = &*state.store;
+        //find payout_attempt by object_reference_id
+        let payout_attempt = match webhook_details.object_reference_id {
+            webhooks::ObjectReferenceId::PayoutId(payout_id_type) => match payout_id_type {
+                webhooks::PayoutIdType::PayoutAttemptId(id) => db
+                    .find_payout_attempt_by_merchant_id_payout_attempt_id(
+                        &merchant_account.merchant_id,
+                        &id,
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
+                    .attach_printable("Failed to fetch the payout attempt")?,
+                webhooks::PayoutIdType::ConnectorPayoutId(id) => db
+                    .find_payout_attempt_by_merchant_id_connector_payout_id(
+                        &merchant_account.merchant_id,
+                        &id,
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
+                    .attach_printable("Failed to fetch the payout attempt")?,
+            },
+            _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure)
+                .attach_printable("received a non-payout id when processing payout webhooks")?,
+        };
+
+        let payouts = db
+            .find_payout_by_merchant_id_payout_id(
+                &merchant_account.merchant_id,
+                &payout_attempt.payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
+            .attach_printable("Failed to fetch the payout")?;
+
+        let status = common_enums::PayoutStatus::foreign_try_from(event_type)
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("failed payout status mapping from event type")?;
+
+        let payout_attempt_update = storage::PayoutAttemptUpdate::StatusUpdate {
+            connector_payout_id: payout_attempt.connector_payout_id.clone(),
+            status,
+            error_message: None,
+            error_code: None,
+            is_eligible: payout_attempt.is_eligible,
+        };
+        let updated_payout_attempt = db
+            .update_payout_attempt(
+                &payout_attempt,
+                payout_attempt_update,
+                &payouts,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while updating payout attempt: payout_attempt_id: {}",
+                    payout_attempt.payout_attempt_id
+                )
+            })?;
+        db.update_payout(
+            &payouts,
+            storage::PayoutsUpdate::StatusUpdate { status },
+            &updated_payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while updating payout: payout_id: {}",
+                payouts.payout_id
+            )
+        })?;
+
+        Ok(WebhookResponseTracker::Payout {
+            payout_id: updated_payout_attempt.payout_id,
+            status: updated_payout_attempt.status,
+        })
+    } else {
+        metrics::INCOMING_PAYOUT_WEBHOOK_SIGNATURE_FAILURE_METRIC.add(&metrics::CONTEXT, 1, &[]);
+        Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ))
+    }
+}
+
 pub async fn get_payment_attempt_from_object_reference_id(
     state: &AppState,
     object_reference_id: api_models::webhooks::ObjectReferenceId,
@@ -696,13 +799,22 @@ pub async fn disputes_incoming_webhook_flow(
     metrics::INCOMING_DISPUTE_WEBHOOK_METRIC.add(&metrics::CONTEXT, 1, &[]);
     if source_verified {
         let db = &*state.store;
-        let dispute_details = connector.get_dispute_details(request_details).switch()?;
+        let mut dispute_details = connector.get_dispute_details(request_details).switch()?;
         let payment_attempt = get_payment_attempt_from_object_reference_id(
             &state,
             webhook_details.object_reference_id,
             &merchant_account,
         )
         .await?;
+        // Connectors which only allow disputing the full amount of a payment, such as bank debit
+        // chargebacks, may not send the disputed amount
+        if dispute_details.amount.is_empty() {
+            dispute_details.amount = payment_attempt.amount.to_string();
+            dispute_details.currency = payment_attempt
+                .currency
+                .map(|currency| currency.to_string())
+                .unwrap_or_default();
+        }
         let option_dispute = db
             .find_by_merchant_id_payment_id_connector_dispute_id(
                 &merchant_account.merchant_id,
@@ -957,6 +1069,17 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     })
     .ok();
 
+    // The event and its retry task are stored, so that the webhook is delivered once the
+    // endpoint is enabled again
+    if endpoint_health::is_webhook_endpoint_disabled(&business_profile) {
+        logger::debug!(
+            business_profile_id=%business_profile.profile_id,
+            %idempotent_event_id,
+            "Merchant webhook endpoint is disabled; pausing delivery of outgoing webhook for event"
+        );
+        return Ok(());
+    }
+
     let cloned_key_store = merchant_key_store.clone();
     // Using a tokio spawn here and not arbiter because not all caller of this function
     // may have an actix arbiter
@@ -1050,11 +1173,29 @@ async fn trigger_webhook_to_merchant(
 
     let event_id = event.event_id;
 
-    let headers = request_content
+    let mut headers: Vec<_> = request_content
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
         .collect();
+
+    // The timestamped signature is computed on every delivery attempt rather than being stored
+    // along with the request, so that retried deliveries are not rejected as replays
+    if let Some(payment_response_hash_key) = business_profile.payment_response_hash_key.as_ref() {
+        let signature = hyperswitch_webhooks::sign(
+            payment_response_hash_key.as_bytes(),
+            date_time::now_unix_timestamp(),
+            request_content.body.peek().as_bytes(),
+        )
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the outgoing webhook with a timestamp")?;
+
+        headers.push((
+            headers::X_WEBHOOK_TIMESTAMPED_SIGNATURE.to_string(),
+            signature.into_masked(),
+        ));
+    }
+
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
         .url(&webhook_url)
@@ -1080,6 +1221,16 @@ async fn trigger_webhook_to_merchant(
     );
     logger::debug!(outgoing_webhook_response=?response);
 
+    endpoint_health::record_delivery_outcome(
+        &state,
+        &business_profile,
+        merchant_key_store,
+        response
+            .as_ref()
+            .is_ok_and(|response| response.status().is_success()),
+    )
+    .await;
+
     let update_event_if_client_error =
         |state: AppState,
          merchant_key_store: domain::MerchantKeyStore,
@@ -1833,6 +1984,17 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
                 .attach_printable("Incoming webhook flow for external authentication failed")?
             }
 
+            #[cfg(feature = "payouts")]
+            api::WebhookFlow::Payout => Box::pin(payouts_incoming_webhook_flow(
+                state.clone(),
+                merchant_account,
+                webhook_details,
+                source_verified,
+                event_type,
+            ))
+            .await
+            .attach_printable("Incoming webhook flow for payouts failed")?,
+
             _ => Err(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
         }
@@ -0,0 +1,204 @@
+use error_stack::ResultExt;
+use router_env::logger;
+
+use crate::{
+    core::{
+        errors::{self, CustomResult},
+        metrics,
+    },
+    routes::AppState,
+    types::{domain, storage},
+};
+#[cfg(feature = "email")]
+use crate::{services::email::types as email_types, types::api, utils::OptionExt};
+
+const CONSECUTIVE_FAILURES_KEY_PREFIX: &str = "WH_ENDPOINT_CONSECUTIVE_FAILURES";
+
+fn get_consecutive_failures_key(profile_id: &str) -> String {
+    format!("{CONSECUTIVE_FAILURES_KEY_PREFIX}_{profile_id}")
+}
+
+/// Deliveries to the webhook endpoint of the business profile are paused if the endpoint has been
+/// disabled after consecutive delivery failures.
+pub(crate) fn is_webhook_endpoint_disabled(
+    business_profile: &storage::business_profile::BusinessProfile,
+) -> bool {
+    business_profile
+        .is_webhook_endpoint_disabled
+        .unwrap_or(false)
+}
+
+/// Track the outcome of a webhook delivery to the webhook endpoint of the business profile. The
+/// endpoint is disabled once the configured number of consecutive deliveries have failed.
+/// Failures to track the outcome are logged and do not affect the delivery.
+pub(crate) async fn record_delivery_outcome(
+    state: &AppState,
+    business_profile: &storage::business_profile::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    is_delivered: bool,
+) {
+    let Some(failure_threshold) = state.conf.webhooks.endpoint_failure_threshold else {
+        return;
+    };
+
+    let result = if is_delivered {
+        reset_consecutive_failures(state, &business_profile.profile_id).await
+    } else {
+        record_delivery_failure(
+            state,
+            business_profile,
+            merchant_key_store,
+            failure_threshold,
+        )
+        .await
+    };
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            profile_id = %business_profile.profile_id,
+            "Failed to update the health of the merchant webhook endpoint"
+        );
+    }
+}
+
+pub(crate) async fn reset_consecutive_failures(
+    state: &AppState,
+    profile_id: &str,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::WebhooksFlowError::WebhookEndpointHealthUpdateFailed)
+        .attach_printable("Failed to get redis connection")?
+        .delete_key(&get_consecutive_failures_key(profile_id))
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEndpointHealthUpdateFailed)
+        .attach_printable("Failed to reset the consecutive failures of the webhook endpoint")?;
+
+    Ok(())
+}
+
+#[allow(unused_variables)]
+async fn record_delivery_failure(
+    state: &AppState,
+    business_profile: &storage::business_profile::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    failure_threshold: u32,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    if is_webhook_endpoint_disabled(business_profile) {
+        return Ok(());
+    }
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::WebhooksFlowError::WebhookEndpointHealthUpdateFailed)
+        .attach_printable("Failed to get redis connection")?;
+    let consecutive_failures_key = get_consecutive_failures_key(&business_profile.profile_id);
+    let consecutive_failures = redis_conn
+        .increment_key(&consecutive_failures_key)
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEndpointHealthUpdateFailed)
+        .attach_printable("Failed to increment the consecutive failures of the webhook endpoint")?;
+
+    // Only the failure reaching the threshold disables the endpoint, so that concurrent failures
+    // do not disable the endpoint and notify the merchant more than once
+    if consecutive_failures != i64::from(failure_threshold) {
+        return Ok(());
+    }
+
+    state
+        .store
+        .update_business_profile_by_profile_id(
+            business_profile.clone(),
+            storage::business_profile::BusinessProfileUpdate::WebhookEndpointStatusUpdate {
+                is_webhook_endpoint_disabled: Some(true),
+            },
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEndpointHealthUpdateFailed)
+        .attach_printable("Failed to disable the webhook endpoint")?;
+
+    redis_conn
+        .delete_key(&consecutive_failures_key)
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEndpointHealthUpdateFailed)
+        .attach_printable("Failed to reset the consecutive failures of the webhook endpoint")?;
+
+    metrics::WEBHOOK_OUTGOING_ENDPOINT_DISABLED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::KeyValue::new(
+            "merchant_id",
+            business_profile.merchant_id.clone(),
+        )],
+    );
+    logger::warn!(
+        profile_id = %business_profile.profile_id,
+        %consecutive_failures,
+        "Disabled merchant webhook endpoint after consecutive delivery failures"
+    );
+
+    #[cfg(feature = "email")]
+    if let Err(error) = notify_merchant(
+        state,
+        business_profile,
+        merchant_key_store,
+        failure_threshold,
+    )
+    .await
+    {
+        logger::error!(
+            ?error,
+            "Failed to notify the merchant of the disabled webhook endpoint"
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "email")]
+async fn notify_merchant(
+    state: &AppState,
+    business_profile: &storage::business_profile::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    consecutive_failures: u32,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(&business_profile.merchant_id, merchant_key_store)
+        .await
+        .change_context(errors::WebhooksFlowError::MerchantConfigNotFound)?;
+
+    let primary_email = merchant_account
+        .merchant_details
+        .parse_value::<api::MerchantDetails>("MerchantDetails")
+        .change_context(errors::WebhooksFlowError::MerchantConfigNotFound)?
+        .primary_email
+        .get_required_value("primary_email")
+        .change_context(errors::WebhooksFlowError::MerchantConfigNotFound)?;
+
+    let email_contents = email_types::WebhookEndpointDisabled {
+        recipient_email: domain::UserEmail::from_pii_email(primary_email)
+            .change_context(errors::WebhooksFlowError::MerchantConfigNotFound)
+            .attach_printable("Failed to convert the merchant email to UserEmail")?,
+        subject: "Webhook Endpoint Disabled",
+        profile_id: business_profile.profile_id.clone(),
+        profile_name: business_profile.profile_name.clone(),
+        consecutive_failures,
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEndpointHealthUpdateFailed)
+        .attach_printable("Failed to send the webhook endpoint disabled email")?;
+
+    Ok(())
+}
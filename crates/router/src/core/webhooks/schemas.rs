@@ -0,0 +1,357 @@
+use std::collections::BTreeMap;
+
+use api_models::webhook_events::{WebhookEventSchema, WebhookSchemasResponse};
+use error_stack::{report, ResultExt};
+use once_cell::sync::Lazy;
+use serde_json::{json, Map, Value};
+use strum::IntoEnumIterator;
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    services::ApplicationResponse,
+    types::storage::enums,
+};
+
+/// The OpenAPI specification of the API, which the webhook schemas are generated from, so that
+/// they are versioned along with the API
+const OPENAPI_SPECIFICATION: &str = include_str!("../../../../../openapi/openapi_spec.json");
+
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+const COMPONENT_SCHEMA_REFERENCE_PREFIX: &str = "#/components/schemas/";
+const DEFINITION_REFERENCE_PREFIX: &str = "#/$defs/";
+
+const OUTGOING_WEBHOOK_SCHEMA: &str = "OutgoingWebhook";
+const OUTGOING_WEBHOOK_CONTENT_SCHEMA: &str = "OutgoingWebhookContent";
+
+/// The webhook schemas are generated once, as the specification they are generated from is
+/// embedded in the binary
+static WEBHOOK_SCHEMAS: Lazy<RouterResult<WebhookSchemasResponse>> = Lazy::new(|| {
+    let specification = serde_json::from_str::<Value>(OPENAPI_SPECIFICATION)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the OpenAPI specification")?;
+
+    get_webhook_schemas(&specification)
+});
+
+pub async fn list_webhook_schemas() -> RouterResponse<WebhookSchemasResponse> {
+    match &*WEBHOOK_SCHEMAS {
+        Ok(webhook_schemas) => Ok(ApplicationResponse::Json(webhook_schemas.clone())),
+        Err(error) => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable_lazy(|| format!("Failed to get the webhook schemas: {error:?}")),
+    }
+}
+
+fn get_webhook_schemas(specification: &Value) -> RouterResult<WebhookSchemasResponse> {
+    let api_version = specification
+        .pointer("/info/version")
+        .and_then(Value::as_str)
+        .ok_or_else(|| report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Missing the version of the API in the OpenAPI specification")?
+        .to_string();
+    let components = specification
+        .pointer("/components/schemas")
+        .and_then(Value::as_object)
+        .ok_or_else(|| report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Missing the schemas in the OpenAPI specification")?;
+
+    let schemas = enums::EventType::iter()
+        .map(|event_type| {
+            get_webhook_event_schema(components, event_type)
+                .map(|schema| WebhookEventSchema { event_type, schema })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(WebhookSchemasResponse {
+        api_version,
+        schemas,
+    })
+}
+
+/// The type of the content of the webhooks sent for the event type, as tagged in
+/// `OutgoingWebhookContent`
+fn get_content_type(event_type: enums::EventType) -> &'static str {
+    match event_type {
+        enums::EventType::PaymentSucceeded
+        | enums::EventType::PaymentFailed
+        | enums::EventType::PaymentProcessing
+        | enums::EventType::PaymentCancelled
+        | enums::EventType::PaymentAuthorized
+        | enums::EventType::PaymentCaptured
+        | enums::EventType::ActionRequired
+        | enums::EventType::PaymentExpired
+        | enums::EventType::PaymentAbandoned => "payment_details",
+        enums::EventType::RefundSucceeded | enums::EventType::RefundFailed => "refund_details",
+        enums::EventType::DisputeOpened
+        | enums::EventType::DisputeExpired
+        | enums::EventType::DisputeAccepted
+        | enums::EventType::DisputeCancelled
+        | enums::EventType::DisputeChallenged
+        | enums::EventType::DisputeWon
+        | enums::EventType::DisputeLost => "dispute_details",
+        enums::EventType::MandateActive | enums::EventType::MandateRevoked => "mandate_details",
+        enums::EventType::PaymentRetryScheduled | enums::EventType::PaymentRetriesExhausted => {
+            "payment_retry_details"
+        }
+    }
+}
+
+/// The schema of `OutgoingWebhook`, narrowed down to the event type and to the content sent for
+/// it, along with the schemas it references
+fn get_webhook_event_schema(
+    components: &Map<String, Value>,
+    event_type: enums::EventType,
+) -> RouterResult<Value> {
+    let content_type = get_content_type(event_type);
+    let content_schema = components
+        .get(OUTGOING_WEBHOOK_CONTENT_SCHEMA)
+        .and_then(|schema| schema.get("oneOf"))
+        .and_then(Value::as_array)
+        .and_then(|variants| {
+            variants.iter().find(|variant| {
+                variant
+                    .pointer("/properties/type/enum")
+                    .and_then(Value::as_array)
+                    .is_some_and(|types| types.iter().any(|t| t.as_str() == Some(content_type)))
+            })
+        })
+        .ok_or_else(|| report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable_lazy(|| {
+            format!("Missing the schema of the `{content_type}` webhook content in the OpenAPI specification")
+        })?;
+
+    let mut webhook_schema = components
+        .get(OUTGOING_WEBHOOK_SCHEMA)
+        .cloned()
+        .ok_or_else(|| report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable(
+            "Missing the schema of the outgoing webhooks in the OpenAPI specification",
+        )?;
+    if let Some(properties) = webhook_schema
+        .get_mut("properties")
+        .and_then(Value::as_object_mut)
+    {
+        properties.insert(
+            "event_type".to_string(),
+            json!({ "type": "string", "enum": [event_type.to_string()] }),
+        );
+        properties.insert("content".to_string(), content_schema.clone());
+    }
+
+    let webhook_schema = to_json_schema(webhook_schema);
+    let definitions = collect_definitions(components, &webhook_schema)?;
+
+    let mut schema = Map::new();
+    schema.insert("$schema".to_string(), json!(JSON_SCHEMA_DIALECT));
+    schema.insert("title".to_string(), json!(event_type.to_string()));
+    if let Value::Object(webhook_schema) = webhook_schema {
+        schema.extend(webhook_schema);
+    }
+    schema.insert(
+        "$defs".to_string(),
+        Value::Object(definitions.into_iter().collect()),
+    );
+
+    Ok(Value::Object(schema))
+}
+
+/// The schemas referenced by the schema, directly or through the schemas it references
+fn collect_definitions(
+    components: &Map<String, Value>,
+    schema: &Value,
+) -> RouterResult<BTreeMap<String, Value>> {
+    let mut definitions = BTreeMap::new();
+    let mut references = Vec::new();
+    collect_references(schema, &mut references);
+
+    while let Some(name) = references.pop() {
+        if definitions.contains_key(&name) {
+            continue;
+        }
+        let definition = components
+            .get(&name)
+            .cloned()
+            .map(to_json_schema)
+            .ok_or_else(|| report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable_lazy(|| {
+                format!("Missing the `{name}` schema referenced in the OpenAPI specification")
+            })?;
+        collect_references(&definition, &mut references);
+        definitions.insert(name, definition);
+    }
+
+    Ok(definitions)
+}
+
+fn collect_references(schema: &Value, references: &mut Vec<String>) {
+    match schema {
+        Value::Object(object) => {
+            for (keyword, value) in object {
+                match (keyword.as_str(), value) {
+                    ("$ref", Value::String(reference)) => {
+                        if let Some(name) = reference.strip_prefix(DEFINITION_REFERENCE_PREFIX) {
+                            references.push(name.to_string());
+                        }
+                    }
+                    _ => collect_references(value, references),
+                }
+            }
+        }
+        Value::Array(values) => values
+            .iter()
+            .for_each(|value| collect_references(value, references)),
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {}
+    }
+}
+
+/// Convert an OpenAPI 3.0 schema to a JSON Schema: the nullable schemas also accept `null`, the
+/// example is listed in `examples`, the references point to `$defs`, and the OpenAPI
+/// `discriminator` is dropped, as the tagged schemas are told apart by their `type` already
+fn to_json_schema(schema: Value) -> Value {
+    let Value::Object(object) = schema else {
+        return schema;
+    };
+
+    let mut is_nullable = false;
+    let mut converted = Map::new();
+    for (keyword, value) in object {
+        match keyword.as_str() {
+            "nullable" => is_nullable = value.as_bool().unwrap_or(false),
+            "discriminator" => {}
+            "example" => {
+                converted.insert("examples".to_string(), Value::Array(vec![value]));
+            }
+            "$ref" => {
+                let value = match value {
+                    Value::String(reference) => Value::String(reference.replacen(
+                        COMPONENT_SCHEMA_REFERENCE_PREFIX,
+                        DEFINITION_REFERENCE_PREFIX,
+                        1,
+                    )),
+                    value => value,
+                };
+                converted.insert(keyword, value);
+            }
+            "properties" => {
+                let value = match value {
+                    Value::Object(properties) => Value::Object(
+                        properties
+                            .into_iter()
+                            .map(|(name, property)| (name, to_json_schema(property)))
+                            .collect(),
+                    ),
+                    value => value,
+                };
+                converted.insert(keyword, value);
+            }
+            "items" | "additionalProperties" | "not" => {
+                converted.insert(keyword, to_json_schema(value));
+            }
+            "allOf" | "anyOf" | "oneOf" => {
+                let value = match value {
+                    Value::Array(schemas) => {
+                        Value::Array(schemas.into_iter().map(to_json_schema).collect())
+                    }
+                    value => value,
+                };
+                converted.insert(keyword, value);
+            }
+            _ => {
+                converted.insert(keyword, value);
+            }
+        }
+    }
+
+    if !is_nullable {
+        return Value::Object(converted);
+    }
+    if let Some(Value::Array(values)) = converted.get_mut("enum") {
+        values.push(Value::Null);
+    }
+    match converted.remove("type") {
+        Some(Value::String(schema_type)) => {
+            converted.insert("type".to_string(), json!([schema_type, "null"]));
+            Value::Object(converted)
+        }
+        schema_type => {
+            if let Some(schema_type) = schema_type {
+                converted.insert("type".to_string(), schema_type);
+            }
+            json!({ "anyOf": [converted, { "type": "null" }] })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_to_json_schema() {
+        let schema = to_json_schema(json!({
+            "type": "object",
+            "properties": {
+                "example": {
+                    "type": "string",
+                    "enum": ["first", "second"],
+                    "example": "first",
+                    "nullable": true
+                },
+                "address": {
+                    "allOf": [{ "$ref": "#/components/schemas/Address" }],
+                    "nullable": true
+                }
+            }
+        }));
+
+        assert_eq!(
+            schema,
+            json!({
+                "type": "object",
+                "properties": {
+                    "example": {
+                        "type": ["string", "null"],
+                        "enum": ["first", "second", null],
+                        "examples": ["first"]
+                    },
+                    "address": {
+                        "anyOf": [
+                            { "allOf": [{ "$ref": "#/$defs/Address" }] },
+                            { "type": "null" }
+                        ]
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_get_webhook_schemas() {
+        let specification = serde_json::from_str::<Value>(OPENAPI_SPECIFICATION)
+            .expect("Failed to parse the OpenAPI specification");
+        let response =
+            get_webhook_schemas(&specification).expect("Failed to get the webhook schemas");
+
+        assert_eq!(response.schemas.len(), enums::EventType::iter().count());
+        for WebhookEventSchema { event_type, schema } in response.schemas {
+            assert_eq!(
+                schema.pointer("/properties/event_type/enum/0"),
+                Some(&json!(event_type.to_string()))
+            );
+            assert_eq!(
+                schema.pointer("/properties/content/properties/type/enum/0"),
+                Some(&json!(get_content_type(event_type)))
+            );
+
+            let definitions = schema
+                .get("$defs")
+                .and_then(Value::as_object)
+                .expect("Missing the definitions of the schema");
+            let mut references = Vec::new();
+            collect_references(&schema, &mut references);
+            assert!(references
+                .iter()
+                .all(|reference| definitions.contains_key(reference)));
+        }
+    }
+}
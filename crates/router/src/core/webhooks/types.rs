@@ -1,5 +1,5 @@
 use api_models::webhooks;
-use common_utils::{crypto::SignMessage, ext_traits::Encode};
+use common_utils::ext_traits::Encode;
 use error_stack::ResultExt;
 use masking::Secret;
 use serde::Serialize;
@@ -32,18 +32,12 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
             .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
             .attach_printable("failed encoding outgoing webhook payload")?;
 
-        let signature = payment_response_hash_key
-            .map(|key| {
-                common_utils::crypto::HmacSha512::sign_message(
-                    &common_utils::crypto::HmacSha512,
-                    key.as_ref(),
-                    webhook_signature_payload.as_bytes(),
-                )
-            })
-            .transpose()
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
-            .attach_printable("Failed to sign the message")?
-            .map(hex::encode);
+        let signature = payment_response_hash_key.map(|key| {
+            hyperswitch_webhooks::sign_without_timestamp(
+                key.as_ref(),
+                webhook_signature_payload.as_bytes(),
+            )
+        });
 
         Ok(OutgoingWebhookPayloadWithSignature {
             payload: webhook_signature_payload.into(),
@@ -8,6 +8,7 @@ pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod cache;
 pub mod capture;
+pub mod card_data_redaction;
 pub mod cards_info;
 pub mod configs;
 pub mod customers;
@@ -32,6 +33,7 @@ pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod usage_metering;
 pub mod user;
 pub mod user_role;
 
@@ -119,6 +121,8 @@ pub trait StorageInterface:
     + health_check::HealthCheckDbInterface
     + role::RoleInterface
     + authentication::AuthenticationInterface
+    + usage_metering::UsageMeteringInterface
+    + card_data_redaction::CardDataRedactionInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
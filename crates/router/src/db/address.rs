@@ -417,9 +417,6 @@ mod storage {
                     let field = format!("add_{}", address.address_id);
                     let updated_address = AddressUpdateInternal::from(address_update.clone())
                         .create_address(address.clone());
-                    let redis_value = serde_json::to_string(&updated_address)
-                        .change_context(errors::StorageError::KVError)?;
-
                     let redis_entry = kv::TypedSql {
                         op: kv::DBOperation::Update {
                             updatable: kv::Updateable::AddressUpdate(Box::new(
@@ -434,7 +431,7 @@ mod storage {
                     kv_wrapper::<(), _, _>(
                         self,
                         KvOperation::Hset::<storage_types::Address>(
-                            (&field, redis_value),
+                            (&field, &updated_address),
                             redis_entry,
                         ),
                         key,
@@ -0,0 +1,146 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CardDataRedactionInterface {
+    async fn find_redaction_target_data_type(
+        &self,
+        target: &storage::RedactionTarget,
+    ) -> CustomResult<Option<String>, errors::StorageError>;
+
+    async fn find_redaction_candidates(
+        &self,
+        target: &storage::RedactionTarget,
+        after_row_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::RedactionCandidate>, errors::StorageError>;
+
+    async fn update_redaction_candidate(
+        &self,
+        target: &storage::RedactionTarget,
+        column_type: storage::RedactionColumnType,
+        candidate: storage::RedactionCandidate,
+        redacted_value: String,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CardDataRedactionInterface for Store {
+    #[instrument(skip_all)]
+    async fn find_redaction_target_data_type(
+        &self,
+        target: &storage::RedactionTarget,
+    ) -> CustomResult<Option<String>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        target
+            .find_data_type(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    // The candidates are read from the master database, as the rows are updated right after
+    #[instrument(skip_all)]
+    async fn find_redaction_candidates(
+        &self,
+        target: &storage::RedactionTarget,
+        after_row_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::RedactionCandidate>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        target
+            .find_candidates(&conn, after_row_id, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_redaction_candidate(
+        &self,
+        target: &storage::RedactionTarget,
+        column_type: storage::RedactionColumnType,
+        candidate: storage::RedactionCandidate,
+        redacted_value: String,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        target
+            .update_candidate(&conn, column_type, candidate, redacted_value)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl CardDataRedactionInterface for MockDb {
+    async fn find_redaction_target_data_type(
+        &self,
+        _target: &storage::RedactionTarget,
+    ) -> CustomResult<Option<String>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_redaction_candidates(
+        &self,
+        _target: &storage::RedactionTarget,
+        _after_row_id: Option<String>,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::RedactionCandidate>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_redaction_candidate(
+        &self,
+        _target: &storage::RedactionTarget,
+        _column_type: storage::RedactionColumnType,
+        _candidate: storage::RedactionCandidate,
+        _redacted_value: String,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl CardDataRedactionInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn find_redaction_target_data_type(
+        &self,
+        target: &storage::RedactionTarget,
+    ) -> CustomResult<Option<String>, errors::StorageError> {
+        self.diesel_store
+            .find_redaction_target_data_type(target)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_redaction_candidates(
+        &self,
+        target: &storage::RedactionTarget,
+        after_row_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::RedactionCandidate>, errors::StorageError> {
+        self.diesel_store
+            .find_redaction_candidates(target, after_row_id, limit)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_redaction_candidate(
+        &self,
+        target: &storage::RedactionTarget,
+        column_type: storage::RedactionColumnType,
+        candidate: storage::RedactionCandidate,
+        redacted_value: String,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .update_redaction_candidate(target, column_type, candidate, redacted_value)
+            .await
+    }
+}
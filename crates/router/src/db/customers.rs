@@ -197,9 +197,6 @@ mod storage {
                         diesel_models::CustomerUpdateInternal::from(customer_update.clone())
                             .apply_changeset(customer.clone());
 
-                    let redis_value = serde_json::to_string(&updated_customer)
-                        .change_context(errors::StorageError::KVError)?;
-
                     let redis_entry = kv::TypedSql {
                         op: kv::DBOperation::Update {
                             updatable: kv::Updateable::CustomerUpdate(kv::CustomerUpdateMems {
@@ -212,7 +209,7 @@ mod storage {
                     kv_wrapper::<(), _, _>(
                         self,
                         KvOperation::Hset::<diesel_models::Customer>(
-                            (&field, redis_value),
+                            (&field, &updated_customer),
                             redis_entry,
                         ),
                         key,
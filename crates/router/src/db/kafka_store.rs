@@ -1535,6 +1535,21 @@ impl PayoutAttemptInterface for KafkaStore {
             .await
     }
 
+    async fn find_payout_attempt_by_merchant_id_connector_payout_id(
+        &self,
+        merchant_id: &str,
+        connector_payout_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage::PayoutAttempt, errors::DataStorageError> {
+        self.diesel_store
+            .find_payout_attempt_by_merchant_id_connector_payout_id(
+                merchant_id,
+                connector_payout_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     async fn update_payout_attempt(
         &self,
         this: &storage::PayoutAttempt,
@@ -1997,6 +2012,17 @@ impl MerchantKeyStoreInterface for KafkaStore {
             .await
     }
 
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        merchant_key_store_update: storage::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        self.diesel_store
+            .update_merchant_key_store_by_merchant_id(merchant_id, merchant_key_store_update, key)
+            .await
+    }
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
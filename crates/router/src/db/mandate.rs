@@ -210,6 +210,10 @@ mod storage {
                     if let diesel_models::MandateUpdate::ConnectorMandateIdUpdate {
                         connector_mandate_id: Some(val),
                         ..
+                    }
+                    | diesel_models::MandateUpdate::ConnectorMigrationUpdate {
+                        connector_mandate_id: Some(val),
+                        ..
                     } = &mandate_update
                     {
                         let rev_lookup = diesel_models::ReverseLookupNew {
@@ -226,9 +230,6 @@ mod storage {
                     let m_update = diesel_models::MandateUpdateInternal::from(mandate_update);
                     let updated_mandate = m_update.clone().apply_changeset(mandate.clone());
 
-                    let redis_value = serde_json::to_string(&updated_mandate)
-                        .change_context(errors::StorageError::SerializationFailed)?;
-
                     let redis_entry = kv::TypedSql {
                         op: kv::DBOperation::Update {
                             updatable: kv::Updateable::MandateUpdate(kv::MandateUpdateMems {
@@ -241,7 +242,7 @@ mod storage {
                     kv_wrapper::<(), _, _>(
                         self,
                         KvOperation::<diesel_models::Mandate>::Hset(
-                            (&field, redis_value),
+                            (&field, &updated_mandate),
                             redis_entry,
                         ),
                         key,
@@ -540,6 +541,17 @@ impl MandateInterface for MockDb {
                         mandate.payment_method_id = payment_method_id;
                         mandate.original_payment_id = original_payment_id
                     }
+                    diesel_models::MandateUpdate::ConnectorMigrationUpdate {
+                        connector,
+                        merchant_connector_id,
+                        connector_mandate_id,
+                        connector_mandate_ids,
+                    } => {
+                        mandate.connector = connector;
+                        mandate.merchant_connector_id = Some(merchant_connector_id);
+                        mandate.connector_mandate_id = connector_mandate_id;
+                        mandate.connector_mandate_ids = connector_mandate_ids;
+                    }
                 }
                 Ok(mandate.clone())
             }
@@ -634,6 +646,7 @@ impl MandateInterface for MockDb {
             metadata: mandate_new.metadata,
             connector_mandate_ids: mandate_new.connector_mandate_ids,
             merchant_connector_id: mandate_new.merchant_connector_id,
+            pre_notification_days: mandate_new.pre_notification_days,
         };
         mandates.push(mandate.clone());
         Ok(mandate)
@@ -807,6 +807,7 @@ mod merchant_connector_account_cache_tests {
                 .await
                 .unwrap(),
                 created_at: datetime!(2023-02-01 0:00),
+                external_key_uri: None,
             },
             &master_key.to_vec().into(),
         )
@@ -7,11 +7,14 @@ use storage_impl::redis::cache::{CacheKind, ACCOUNTS_CACHE};
 use crate::{
     connection,
     core::errors::{self, CustomResult},
-    db::MockDb,
-    services::Store,
-    types::domain::{
-        self,
-        behaviour::{Conversion, ReverseConversion},
+    db::{MasterKeyInterface, MockDb},
+    services::{encryption, Store},
+    types::{
+        domain::{
+            self,
+            behaviour::{Conversion, ReverseConversion},
+        },
+        storage,
     },
 };
 
@@ -29,6 +32,13 @@ pub trait MerchantKeyStoreInterface {
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError>;
 
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        merchant_key_store_update: storage::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError>;
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -81,16 +91,12 @@ impl MerchantKeyStoreInterface for Store {
         };
 
         #[cfg(not(feature = "accounts_cache"))]
-        {
-            fetch_func()
-                .await?
-                .convert(key)
-                .await
-                .change_context(errors::StorageError::DecryptionError)
-        }
+        let key_store = fetch_func().await?;
 
+        // The key store is cached as it is stored, so that the key provided by the merchant is
+        // still used for each read and a revocation of access to it takes effect immediately
         #[cfg(feature = "accounts_cache")]
-        {
+        let key_store = {
             let key_store_cache_key = format!("merchant_key_store_{}", merchant_id);
             super::cache::get_or_populate_in_memory(
                 self,
@@ -99,10 +105,54 @@ impl MerchantKeyStoreInterface for Store {
                 &ACCOUNTS_CACHE,
             )
             .await?
+        };
+
+        encryption::resolve_merchant_key_store(key_store, self.get_master_key())
+            .await
+            .change_context(errors::StorageError::DecryptionError)?
+            .convert(key)
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        merchant_key_store_update: storage::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let update_func = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            diesel_models::merchant_key_store::MerchantKeyStore::update_by_merchant_id(
+                &conn,
+                merchant_id,
+                merchant_key_store_update,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        let key_store = update_func().await?;
+
+        #[cfg(feature = "accounts_cache")]
+        let key_store = {
+            let key_store_cache_key = format!("merchant_key_store_{}", merchant_id);
+            super::cache::publish_and_redact(
+                self,
+                CacheKind::Accounts(key_store_cache_key.into()),
+                update_func,
+            )
+            .await?
+        };
+
+        encryption::resolve_merchant_key_store(key_store, self.get_master_key())
+            .await
+            .change_context(errors::StorageError::DecryptionError)?
             .convert(key)
             .await
             .change_context(errors::StorageError::DecryptionError)
-        }
     }
 
     #[instrument(skip_all)]
@@ -156,7 +206,9 @@ impl MerchantKeyStoreInterface for Store {
         };
 
         futures::future::try_join_all(fetch_func().await?.into_iter().map(|key_store| async {
-            key_store
+            encryption::resolve_merchant_key_store(key_store, self.get_master_key())
+                .await
+                .change_context(errors::StorageError::DecryptionError)?
                 .convert(key)
                 .await
                 .change_context(errors::StorageError::DecryptionError)
@@ -214,6 +266,42 @@ impl MerchantKeyStoreInterface for MockDb {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        merchant_key_store_update: storage::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let updated_key_store = {
+            let mut merchant_key_stores = self.merchant_key_store.lock().await;
+            let key_store = merchant_key_stores
+                .iter_mut()
+                .find(|merchant_key| merchant_key.merchant_id == merchant_id)
+                .ok_or(errors::StorageError::ValueNotFound(String::from(
+                    "merchant_key_store",
+                )))?;
+
+            match merchant_key_store_update {
+                storage::MerchantKeyStoreUpdate::KeyCustodyUpdate {
+                    key,
+                    external_key_uri,
+                } => {
+                    key_store.key = key;
+                    key_store.external_key_uri = external_key_uri;
+                }
+            }
+
+            key_store.clone()
+        };
+
+        encryption::resolve_merchant_key_store(updated_key_store, self.get_master_key())
+            .await
+            .change_context(errors::StorageError::DecryptionError)?
+            .convert(key)
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -284,6 +372,7 @@ mod tests {
                     .await
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    external_key_uri: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -309,6 +398,7 @@ mod tests {
                     .await
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    external_key_uri: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -300,9 +300,6 @@ mod storage {
                     let updated_payment_method =
                         p_update.clone().apply_changeset(payment_method.clone());
 
-                    let redis_value = serde_json::to_string(&updated_payment_method)
-                        .change_context(errors::StorageError::SerializationFailed)?;
-
                     let redis_entry = kv::TypedSql {
                         op: kv::DBOperation::Update {
                             updatable: kv::Updateable::PaymentMethodUpdate(
@@ -317,7 +314,7 @@ mod storage {
                     kv_wrapper::<(), _, _>(
                         self,
                         KvOperation::<diesel_models::PaymentMethod>::Hset(
-                            (&field, redis_value),
+                            (&field, &updated_payment_method),
                             redis_entry,
                         ),
                         key,
@@ -662,6 +659,9 @@ impl PaymentMethodInterface for MockDb {
             status: payment_method_new.status,
             client_secret: payment_method_new.client_secret,
             network_transaction_id: payment_method_new.network_transaction_id,
+            network_token_requestor_reference_id: payment_method_new
+                .network_token_requestor_reference_id,
+            network_token_locker_id: payment_method_new.network_token_locker_id,
         };
         payment_methods.push(payment_method.clone());
         Ok(payment_method)
@@ -271,7 +271,7 @@ mod storage {
 
 #[cfg(feature = "kv_store")]
 mod storage {
-    use common_utils::{date_time, ext_traits::Encode, fallback_reverse_lookup_not_found};
+    use common_utils::{date_time, fallback_reverse_lookup_not_found};
     use error_stack::{report, ResultExt};
     use redis_interface::HsetnxReply;
     use router_env::{instrument, tracing};
@@ -386,6 +386,8 @@ mod storage {
                         profile_id: new.profile_id.clone(),
                         updated_by: new.updated_by.clone(),
                         merchant_connector_id: new.merchant_connector_id.clone(),
+                        capture_id: new.capture_id.clone(),
+                        refund_reason_code: new.refund_reason_code,
                     };
 
                     let field = format!(
@@ -544,10 +546,6 @@ mod storage {
                     let field = format!("pa_{}_ref_{}", &this.attempt_id, &this.refund_id);
                     let updated_refund = refund.clone().apply_changeset(this.clone());
 
-                    let redis_value = updated_refund
-                        .encode_to_string_of_json()
-                        .change_context(errors::StorageError::SerializationFailed)?;
-
                     let redis_entry = kv::TypedSql {
                         op: kv::DBOperation::Update {
                             updatable: kv::Updateable::RefundUpdate(kv::RefundUpdateMems {
@@ -560,7 +558,7 @@ mod storage {
                     kv_wrapper::<(), _, _>(
                         self,
                         KvOperation::Hset::<storage_types::Refund>(
-                            (&field, redis_value),
+                            (&field, &updated_refund),
                             redis_entry,
                         ),
                         key,
@@ -822,6 +820,8 @@ impl RefundInterface for MockDb {
             profile_id: new.profile_id,
             updated_by: new.updated_by,
             merchant_connector_id: new.merchant_connector_id,
+            capture_id: new.capture_id,
+            refund_reason_code: new.refund_reason_code,
         };
         refunds.push(refund.clone());
         Ok(refund)
@@ -0,0 +1,96 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait UsageMeteringInterface {
+    async fn increment_usage(
+        &self,
+        usage: storage::UsageMeteringNew,
+    ) -> CustomResult<storage::UsageMetering, errors::StorageError>;
+
+    async fn filter_usage_by_period(
+        &self,
+        merchant_id: Option<String>,
+        start_time: time::PrimitiveDateTime,
+        end_time: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::UsageMetering>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl UsageMeteringInterface for Store {
+    #[instrument(skip_all)]
+    async fn increment_usage(
+        &self,
+        usage: storage::UsageMeteringNew,
+    ) -> CustomResult<storage::UsageMetering, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        usage
+            .increment(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn filter_usage_by_period(
+        &self,
+        merchant_id: Option<String>,
+        start_time: time::PrimitiveDateTime,
+        end_time: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::UsageMetering>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::UsageMetering::filter_by_period(&conn, merchant_id, start_time, end_time)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl UsageMeteringInterface for MockDb {
+    async fn increment_usage(
+        &self,
+        _usage: storage::UsageMeteringNew,
+    ) -> CustomResult<storage::UsageMetering, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn filter_usage_by_period(
+        &self,
+        _merchant_id: Option<String>,
+        _start_time: time::PrimitiveDateTime,
+        _end_time: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::UsageMetering>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl UsageMeteringInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn increment_usage(
+        &self,
+        usage: storage::UsageMeteringNew,
+    ) -> CustomResult<storage::UsageMetering, errors::StorageError> {
+        self.diesel_store.increment_usage(usage).await
+    }
+
+    #[instrument(skip_all)]
+    async fn filter_usage_by_period(
+        &self,
+        merchant_id: Option<String>,
+        start_time: time::PrimitiveDateTime,
+        end_time: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::UsageMetering>, errors::StorageError> {
+        self.diesel_store
+            .filter_usage_by_period(merchant_id, start_time, end_time)
+            .await
+    }
+}
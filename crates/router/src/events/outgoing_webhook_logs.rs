@@ -74,6 +74,13 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&mandate_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::PaymentRetryDetails(payment_retry_payload) => {
+                Some(OutgoingWebhookEventContent::Payment {
+                    payment_id: Some(payment_retry_payload.payment_id.clone()),
+                    content: masking::masked_serialize(&payment_retry_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
@@ -27,7 +27,7 @@ pub mod utils;
 use actix_web::{
     body::MessageBody,
     dev::{Server, ServerHandle, ServiceFactory, ServiceRequest},
-    middleware::ErrorHandlers,
+    middleware::{Compress, Condition, ErrorHandlers},
 };
 use http::StatusCode;
 use hyperswitch_interfaces::secrets_interface::secret_state::SecuredSecret;
@@ -68,7 +68,8 @@ pub mod headers {
     pub const X_CC_VERSION: &str = "X-CC-Version";
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
-    pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SIGNATURE: &str = hyperswitch_webhooks::SIGNATURE_WITHOUT_TIMESTAMP_HEADER;
+    pub const X_WEBHOOK_TIMESTAMPED_SIGNATURE: &str = hyperswitch_webhooks::SIGNATURE_HEADER;
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
@@ -94,7 +95,12 @@ pub fn mk_app(
         InitError = (),
     >,
 > {
-    let mut server_app = get_application_builder(request_body_limit, state.conf.cors.clone());
+    let mut server_app = get_application_builder(
+        request_body_limit,
+        state.conf.cors.clone(),
+        state.conf.compression.clone(),
+        state.conf.request_payload_limits.clone(),
+    );
 
     #[cfg(feature = "dummy_connector")]
     {
@@ -141,9 +147,13 @@ pub fn mk_app(
             .service(routes::Blocklist::server(state.clone()))
             .service(routes::Gsm::server(state.clone()))
             .service(routes::PaymentLink::server(state.clone()))
+            .service(routes::HostedCheckout::server(state.clone()))
             .service(routes::User::server(state.clone()))
             .service(routes::ConnectorOnboarding::server(state.clone()))
             .service(routes::Verify::server(state.clone()))
+            .service(routes::Usage::server(state.clone()))
+            .service(routes::ConnectorMigration::server(state.clone()))
+            .service(routes::CustomerDataExport::server(state.clone()))
             .service(routes::WebhookEvents::server(state.clone()));
     }
 
@@ -177,6 +187,12 @@ pub fn mk_app(
 #[allow(clippy::expect_used, clippy::unwrap_used)]
 pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> ApplicationResult<Server> {
     logger::debug!(startup_config=?conf);
+    masking::set_long_lived_plaintext_reporter(|lifetime| {
+        logger::warn!(
+            ?lifetime,
+            "Plaintext copy of a secret was alive for longer than expected"
+        );
+    });
     let server = conf.server.clone();
     let (tx, rx) = oneshot::channel();
     let api_client = Box::new(
@@ -189,16 +205,94 @@ pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> Applicatio
         })?,
     );
     let state = Box::pin(routes::AppState::new(conf, tx, api_client)).await;
-    let request_body_limit = server.request_body_limit;
-    let server = actix_web::HttpServer::new(move || mk_app(state.clone(), request_body_limit))
+    start_scheduler_roles(&state);
+    let server = if state
+        .conf
+        .deployment
+        .is_role_enabled(settings::DeploymentRole::Api)
+    {
+        let _warmup_handle = tokio::spawn(
+            services::api::connection_warmup::warm_up_connector_connections(state.clone())
+                .in_current_span(),
+        );
+        let request_body_limit = server.request_body_limit;
+        actix_web::HttpServer::new(move || mk_app(state.clone(), request_body_limit))
+            .bind((server.host.as_str(), server.port))?
+            .workers(server.workers)
+            .shutdown_timeout(server.shutdown_timeout)
+            .run()
+    } else {
+        // Only the health checks are served when the API is not served by the process, so that
+        // the readiness of its roles can still be probed
+        actix_web::HttpServer::new(move || {
+            actix_web::App::new().service(routes::Health::server(state.clone()))
+        })
         .bind((server.host.as_str(), server.port))?
         .workers(server.workers)
         .shutdown_timeout(server.shutdown_timeout)
-        .run();
+        .run()
+    };
     let _task_handle = tokio::spawn(receiver_for_error(rx, server.handle()).in_current_span());
     Ok(server)
 }
 
+/// Start the scheduler flows of the consumer and producer roles enabled for the process, in the
+/// background.
+fn start_scheduler_roles(state: &AppState) {
+    for (role, scheduler_flow) in [
+        (
+            settings::DeploymentRole::Producer,
+            scheduler::SchedulerFlow::Producer,
+        ),
+        (
+            settings::DeploymentRole::Consumer,
+            scheduler::SchedulerFlow::Consumer,
+        ),
+    ] {
+        if !state.conf.deployment.is_role_enabled(role) {
+            continue;
+        }
+
+        logger::info!(%role, "Starting deployment role");
+        let state = state.clone();
+        let _task_handle = tokio::spawn(
+            async move {
+                if let Err(error) =
+                    workflows::start_scheduler(&state, scheduler_flow, mpsc::channel(1)).await
+                {
+                    logger::error!(?error, %role, "Deployment role stopped");
+                }
+            }
+            .in_current_span(),
+        );
+    }
+}
+
+/// Start the drainer of the drainer role, with the drainer configuration read from the
+/// configuration file of the router.
+#[cfg(feature = "drainer")]
+pub async fn start_drainer(config_path: Option<std::path::PathBuf>) {
+    let role = settings::DeploymentRole::Drainer;
+    let conf = match drainer::settings::Settings::with_config_path(config_path) {
+        Ok(conf) => conf,
+        Err(error) => {
+            logger::error!(?error, %role, "Unable to construct drainer configuration");
+            return;
+        }
+    };
+    if let Err(error) = conf.validate() {
+        logger::error!(?error, %role, "Failed to validate drainer configuration");
+        return;
+    }
+
+    logger::info!(%role, "Starting deployment role");
+    let state = drainer::settings::AppState::new(conf).await;
+    let store = std::sync::Arc::new(drainer::services::Store::new(&state.conf, false).await);
+    if let Err(error) = drainer::start_drainer(store, state.conf.drainer.clone()).await {
+        logger::error!(?error, %role, "Deployment role stopped");
+    }
+}
+
 pub async fn receiver_for_error(rx: oneshot::Receiver<()>, mut server: impl Stop) {
     match rx.await {
         Ok(_) => {
@@ -232,6 +326,8 @@ impl Stop for mpsc::Sender<()> {
 pub fn get_application_builder(
     request_body_limit: usize,
     cors: settings::CorsSettings,
+    compression: settings::Compression,
+    request_payload_limits: settings::RequestPayloadLimits,
 ) -> actix_web::App<
     impl ServiceFactory<
         ServiceRequest,
@@ -256,6 +352,13 @@ pub fn get_application_builder(
             StatusCode::METHOD_NOT_ALLOWED,
             errors::error_handlers::custom_error_handlers,
         ))
+        // compresses the responses with the encoding negotiated through the `Accept-Encoding`
+        // header, request bodies sent with a `Content-Encoding` are decompressed regardless
+        .wrap(middleware::RequestPayloadGuard::new(
+            request_body_limit,
+            request_payload_limits,
+        ))
+        .wrap(Condition::new(compression.enabled, Compress::default()))
         .wrap(middleware::default_response_headers())
         .wrap(middleware::RequestId)
         .wrap(cors::cors(cors))
@@ -1,8 +1,12 @@
+use actix_web::http::header;
 use futures::StreamExt;
 use router_env::{
     logger,
     tracing::{field::Empty, Instrument},
 };
+
+use crate::{configs::settings, core::errors::ApiErrorResponse};
+
 /// Middleware to include request ID in response header.
 pub struct RequestId;
 
@@ -284,3 +288,276 @@ where
         })
     }
 }
+
+/// Middleware guarding the deserialization of the JSON request bodies. The bodies larger than the
+/// request body limit are rejected with a 413, and the bodies nested too deeply or holding too
+/// long arrays or objects are rejected with a 400, without being parsed. The bodies sent with a
+/// `Content-Encoding` are checked once they are decompressed. The incoming webhooks are not
+/// guarded, as their bodies are sent by the connectors and cannot be changed by the sender.
+#[derive(Clone)]
+pub struct RequestPayloadGuard {
+    request_body_limit: usize,
+    limits: settings::RequestPayloadLimits,
+}
+
+impl RequestPayloadGuard {
+    pub fn new(request_body_limit: usize, limits: settings::RequestPayloadLimits) -> Self {
+        Self {
+            request_body_limit,
+            limits,
+        }
+    }
+}
+
+impl<S: 'static, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest>
+    for RequestPayloadGuard
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Transform = RequestPayloadGuardMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(RequestPayloadGuardMiddleware {
+            service: std::rc::Rc::new(service),
+            guard: std::rc::Rc::new(self.clone()),
+        }))
+    }
+}
+
+pub struct RequestPayloadGuardMiddleware<S> {
+    service: std::rc::Rc<S>,
+    guard: std::rc::Rc<RequestPayloadGuard>,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest>
+    for RequestPayloadGuardMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, mut req: actix_web::dev::ServiceRequest) -> Self::Future {
+        let svc = self.service.clone();
+        let guard = self.guard.clone();
+        Box::pin(async move {
+            if is_json_request(&req) && !is_incoming_webhook_request(&req) {
+                let checked_body = read_request_body(&mut req, guard.request_body_limit)
+                    .await
+                    .and_then(|body| {
+                        check_json_limits(&body, &guard.limits)
+                            .map(|()| body)
+                            .map_err(|message| {
+                                ApiErrorResponse::InvalidRequestData { message }.into()
+                            })
+                    });
+                match checked_body {
+                    Ok(body) => {
+                        // the body is passed on decompressed, as it has been read
+                        req.headers_mut().remove(header::CONTENT_ENCODING);
+                        req.headers_mut().insert(
+                            header::CONTENT_LENGTH,
+                            header::HeaderValue::from(body.len()),
+                        );
+                        let (_, mut payload) = actix_http::h1::Payload::create(true);
+                        payload.unread_data(body);
+                        req.set_payload(payload.into());
+                    }
+                    Err(error) => {
+                        logger::warn!(request_payload_error=?error);
+                        return Ok(req.error_response(error).map_into_right_body());
+                    }
+                }
+            }
+
+            svc.call(req)
+                .await
+                .map(actix_web::dev::ServiceResponse::map_into_left_body)
+        })
+    }
+}
+
+fn is_incoming_webhook_request(req: &actix_web::dev::ServiceRequest) -> bool {
+    req.path().starts_with("/webhooks/")
+}
+
+fn is_json_request(req: &actix_web::dev::ServiceRequest) -> bool {
+    req.headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .and_then(|content_type| content_type.parse::<mime::Mime>().ok())
+        .is_some_and(|content_type| {
+            content_type.subtype() == mime::JSON || content_type.suffix() == Some(mime::JSON)
+        })
+}
+
+/// Read the decompressed body of the request, failing as soon as it is larger than the limit
+async fn read_request_body(
+    req: &mut actix_web::dev::ServiceRequest,
+    limit: usize,
+) -> Result<bytes::Bytes, actix_web::Error> {
+    let content_length = req
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|content_length| content_length.to_str().ok())
+        .and_then(|content_length| content_length.parse::<usize>().ok());
+    if !req.headers().contains_key(header::CONTENT_ENCODING)
+        && content_length.is_some_and(|content_length| content_length > limit)
+    {
+        return Err(ApiErrorResponse::PayloadTooLarge { limit }.into());
+    }
+
+    let (_, payload) = req.parts_mut();
+    let mut payload = actix_http::encoding::Decoder::from_headers(payload.take(), req.headers());
+    let mut body = bytes::BytesMut::new();
+    while let Some(chunk) = payload.next().await {
+        let chunk = chunk?;
+        if body.len() + chunk.len() > limit {
+            return Err(ApiErrorResponse::PayloadTooLarge { limit }.into());
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(body.freeze())
+}
+
+/// Check the nesting and the lengths of the arrays and objects of the JSON body by scanning it,
+/// without parsing it. Bodies which are not valid JSON are left to be rejected when they are
+/// deserialized.
+fn check_json_limits(json: &[u8], limits: &settings::RequestPayloadLimits) -> Result<(), String> {
+    // The opening bracket and the number of elements of each array or object the scan is in
+    let mut containers: Vec<(u8, usize)> = Vec::new();
+    let mut is_in_string = false;
+    let mut is_escaped = false;
+
+    for &byte in json {
+        if is_in_string {
+            match byte {
+                _ if is_escaped => is_escaped = false,
+                b'\\' => is_escaped = true,
+                b'"' => is_in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        if byte.is_ascii_whitespace() {
+            continue;
+        }
+
+        // the first value or key in an array or object starts its first element
+        if !matches!(byte, b']' | b'}') {
+            if let Some((_, length)) = containers.last_mut().filter(|(_, length)| *length == 0) {
+                *length = 1;
+            }
+        }
+
+        match byte {
+            b'"' => is_in_string = true,
+            b'[' | b'{' => {
+                containers.push((byte, 0));
+                if containers.len() > limits.max_json_depth {
+                    return Err(format!(
+                        "The request body should not be nested deeper than {} levels",
+                        limits.max_json_depth
+                    ));
+                }
+            }
+            b']' | b'}' => {
+                containers.pop();
+            }
+            b',' => match containers.last_mut() {
+                Some((b'[', length)) => {
+                    *length += 1;
+                    if *length > limits.max_array_length {
+                        return Err(format!(
+                            "Arrays in the request body should not have more than {} elements",
+                            limits.max_array_length
+                        ));
+                    }
+                }
+                Some((_, length)) => {
+                    *length += 1;
+                    if *length > limits.max_object_entries {
+                        return Err(format!(
+                            "Objects in the request body should not have more than {} entries",
+                            limits.max_object_entries
+                        ));
+                    }
+                }
+                None => {}
+            },
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_json_limits() {
+        let limits = settings::RequestPayloadLimits {
+            max_json_depth: 3,
+            max_array_length: 2,
+            max_object_entries: 2,
+        };
+
+        for json in [
+            r#"{"amount": 100, "metadata": {"order": {"id": "1"}}}"#,
+            r#"{"order_details": [{"product_name": "shoes"}, {"product_name": "socks"}]}"#,
+            r#"{"description": "[[[{,,,}]]]", "reference": "\"[[[[\""}"#,
+            r#"{"metadata": {}, "order_details": []}"#,
+        ] {
+            assert_eq!(check_json_limits(json.as_bytes(), &limits), Ok(()));
+        }
+        for json in [
+            r#"{"metadata": {"order": {"items": [1]}}}"#,
+            r#"{"order_details": [1, 2, 3]}"#,
+            r#"{"metadata": {"a": 1, "b": 2, "c": 3}}"#,
+        ] {
+            assert!(check_json_limits(json.as_bytes(), &limits).is_err());
+        }
+    }
+
+    #[test]
+    fn test_is_incoming_webhook_request() {
+        let request = |uri| {
+            actix_web::test::TestRequest::post()
+                .uri(uri)
+                .to_srv_request()
+        };
+
+        assert!(is_incoming_webhook_request(&request(
+            "/webhooks/merchant_1/stripe"
+        )));
+        assert!(is_incoming_webhook_request(&request(
+            "/webhooks/merchant_1/mca_1?source=verification"
+        )));
+        assert!(!is_incoming_webhook_request(&request("/payments")));
+        assert!(!is_incoming_webhook_request(&request(
+            "/account/merchant_1/webhooks"
+        )));
+    }
+}
@@ -7,9 +7,13 @@ pub mod cache;
 pub mod cards_info;
 pub mod configs;
 #[cfg(feature = "olap")]
+pub mod connector_migration;
+#[cfg(feature = "olap")]
 pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
+#[cfg(feature = "olap")]
+pub mod customer_data_export;
 pub mod customers;
 pub mod disputes;
 #[cfg(feature = "dummy_connector")]
@@ -38,6 +42,8 @@ pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
 #[cfg(feature = "olap")]
+pub mod usage;
+#[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
 pub mod user_role;
@@ -59,11 +65,13 @@ pub use self::app::Payouts;
 pub use self::app::Recon;
 pub use self::app::{
     ApiKeys, AppState, BusinessProfile, Cache, Cards, Configs, ConnectorOnboarding, Customers,
-    Disputes, EphemeralKey, Files, Gsm, Health, Mandates, MerchantAccount,
+    Disputes, EphemeralKey, Files, Gsm, Health, HostedCheckout, Mandates, MerchantAccount,
     MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments, Poll, Refunds, User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Routing, Verify, WebhookEvents};
+pub use self::app::{
+    Blocklist, ConnectorMigration, CustomerDataExport, Routing, Usage, Verify, WebhookEvents,
+};
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
 #[cfg(feature = "olap")]
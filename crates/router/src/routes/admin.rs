@@ -3,7 +3,7 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{admin::*, api_locking},
+    core::{admin::*, api_locking, payments::wallets},
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::admin,
 };
@@ -458,6 +458,58 @@ pub async fn merchant_account_toggle_kv(
     )
     .await
 }
+/// Merchant Account - Key Custody
+///
+/// Set the key provided by the merchant to wrap its encryption key with, or hand custody of the
+/// key back to Hyperswitch
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyCustodyUpdate))]
+pub async fn merchant_key_custody_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<admin::MerchantKeyCustodyRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyCustodyUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| update_merchant_key_custody(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+/// Merchant Account - Feature Entitlements
+///
+/// Restrict the features which the merchant account is allowed to use
+#[instrument(skip_all, fields(flow = ?Flow::MerchantFeatureEntitlementsUpdate))]
+pub async fn merchant_feature_entitlements_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<admin::MerchantFeatureEntitlements>,
+) -> HttpResponse {
+    let flow = Flow::MerchantFeatureEntitlementsUpdate;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| {
+            update_merchant_feature_entitlements(state, merchant_id.clone(), payload)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
 #[instrument(skip_all, fields(flow = ?Flow::BusinessProfileCreate))]
 pub async fn business_profile_create(
     state: web::Data<AppState>,
@@ -634,3 +686,120 @@ pub async fn toggle_extended_card_info(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointEnable))]
+pub async fn webhook_endpoint_enable(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointEnable;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| enable_webhook_endpoint(state, &profile_id, &merchant_id),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WalletCertificatesRetrieve))]
+pub async fn wallet_certificates_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::WalletCertificatesRetrieve;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| wallets::retrieve_wallet_certificates(state, &merchant_id, &profile_id),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WalletCertificatesUpdate))]
+pub async fn wallet_certificates_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_models::admin::WalletCertificates>,
+) -> HttpResponse {
+    let flow = Flow::WalletCertificatesUpdate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| {
+            wallets::update_wallet_certificates(state, &merchant_id, &profile_id, req)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WalletCertificatesDelete))]
+pub async fn wallet_certificates_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String, api_models::enums::PaymentMethodType)>,
+) -> HttpResponse {
+    let flow = Flow::WalletCertificatesDelete;
+    let (merchant_id, profile_id, wallet) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| {
+            wallets::delete_wallet_certificates(state, &merchant_id, &profile_id, wallet)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
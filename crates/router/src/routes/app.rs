@@ -3,6 +3,7 @@ use std::sync::Arc;
 use actix_web::{web, Scope};
 #[cfg(all(feature = "business_profile_routing", feature = "olap"))]
 use api_models::routing::RoutingRetrieveQuery;
+use cards::bin_info::BinDatabase;
 #[cfg(feature = "olap")]
 use common_enums::TransactionType;
 #[cfg(feature = "email")]
@@ -26,11 +27,15 @@ use super::payouts::*;
 #[cfg(feature = "olap")]
 use super::routing as cloud_routing;
 #[cfg(feature = "olap")]
-use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
+use super::verification::{
+    apple_pay_merchant_registration, apple_pay_profile_domain_registration,
+    retrieve_apple_pay_verified_domains,
+};
 #[cfg(feature = "olap")]
 use super::{
-    admin::*, api_keys::*, connector_onboarding::*, disputes::*, files::*, gsm::*, payment_link::*,
-    user::*, user_role::*, webhook_events::*,
+    admin::*, api_keys::*, connector_migration::*, connector_onboarding::*,
+    customer_data_export::*, disputes::*, files::*, gsm::*, payment_link::*, usage::*, user::*,
+    user_role::*, webhook_events::*,
 };
 use super::{cache::*, health::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -80,6 +85,7 @@ pub struct AppState {
     pub request_id: Option<RequestId>,
     pub file_storage_client: Box<dyn FileStorageInterface>,
     pub encryption_client: Box<dyn EncryptionManagementInterface>,
+    pub bin_database: Option<Arc<BinDatabase>>,
 }
 
 impl scheduler::SchedulerAppState for AppState {
@@ -225,6 +231,20 @@ impl AppState {
 
             let file_storage_client = conf.file_storage.get_file_storage_client().await;
 
+            #[allow(clippy::expect_used)]
+            let bin_database = conf
+                .bin_lookup_service
+                .get_inner()
+                .database_path
+                .as_ref()
+                .map(|database_path| {
+                    let records = std::fs::read_to_string(database_path)
+                        .expect("Failed to read the BIN database");
+                    BinDatabase::from_csv(&records)
+                        .map(Arc::new)
+                        .expect("Failed to load the BIN database")
+                });
+
             Self {
                 flow_name: String::from("default"),
                 store,
@@ -240,6 +260,7 @@ impl AppState {
                 request_id: None,
                 file_storage_client,
                 encryption_client,
+                bin_database,
             }
         })
         .await
@@ -274,6 +295,7 @@ impl Health {
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::get().to(health)))
             .service(web::resource("/ready").route(web::get().to(deep_health_check)))
+            .service(web::resource("/ready/{role}").route(web::get().to(role_health_check)))
     }
 }
 
@@ -333,6 +355,19 @@ impl Payments {
                 )
                 .service(web::resource("/filter").route(web::post().to(get_filters_for_payments)))
                 .service(web::resource("/filter_v2").route(web::get().to(get_payment_filters)))
+                .service(
+                    web::resource("/filters/saved")
+                        .route(web::get().to(list_saved_payment_filters))
+                        .route(web::post().to(save_payment_filter)),
+                )
+                .service(
+                    web::resource("/filters/saved/{name}")
+                        .route(web::delete().to(delete_saved_payment_filter)),
+                )
+                .service(
+                    web::resource("/{payment_id}/suggested_methods")
+                        .route(web::get().to(payments_suggested_methods)),
+                )
         }
         #[cfg(feature = "oltp")]
         {
@@ -391,6 +426,12 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/incremental_authorization").route(web::post().to(payments_incremental_authorization)),
                 )
+                .service(
+                    web::resource("/{payment_id}/verify_micro_deposits").route(web::post().to(payments_verify_micro_deposits)),
+                )
+                .service(
+                    web::resource("/{payment_id}/retry").route(web::post().to(payments_retry)),
+                )
                 .service(
                     web::resource("/{payment_id}/{merchant_id}/authorize/{connector}").route(web::post().to(post_3ds_payments_authorize)),
                 )
@@ -399,6 +440,18 @@ impl Payments {
                 )
                 .service(
                     web::resource("/{payment_id}/extended_card_info").route(web::get().to(retrieve_extended_card_info)),
+                )
+                .service(
+                    web::resource("/{payment_id}/click_to_pay/identity_lookup").route(web::post().to(payments_click_to_pay_identity_lookup)),
+                )
+                .service(
+                    web::resource("/{payment_id}/click_to_pay/enroll").route(web::post().to(payments_click_to_pay_enroll)),
+                )
+                .service(
+                    web::resource("/{payment_id}/calculate_tax").route(web::post().to(payments_calculate_tax)),
+                )
+                .service(
+                    web::resource("/{payment_id}/tags").route(web::patch().to(payments_update_tags)),
                 );
         }
         route
@@ -418,6 +471,10 @@ impl Forex {
             .service(
                 web::resource("/convert_from_minor").route(web::get().to(currency::convert_forex)),
             )
+            .service(
+                web::resource("/localize_amount")
+                    .route(web::get().to(currency::localize_forex_amount)),
+            )
     }
 }
 
@@ -868,6 +925,14 @@ impl MerchantAccount {
                     .route(web::post().to(merchant_account_toggle_kv))
                     .route(web::get().to(merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/key_custody")
+                    .route(web::post().to(merchant_key_custody_update)),
+            )
+            .service(
+                web::resource("/{id}/entitlements")
+                    .route(web::post().to(merchant_feature_entitlements_update)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(retrieve_merchant_account))
@@ -968,7 +1033,8 @@ impl Webhooks {
                     .route(
                         web::put().to(receive_incoming_webhook::<webhook_type::OutgoingWebhook>),
                     ),
-            );
+            )
+            .service(web::resource("/schemas").route(web::get().to(list_webhook_schemas)));
 
         #[cfg(feature = "frm")]
         {
@@ -1093,6 +1159,7 @@ impl PaymentLink {
     pub fn server(state: AppState) -> Scope {
         web::scope("/payment_link")
             .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(payment_link_create)))
             .service(web::resource("/list").route(web::post().to(payments_link_list)))
             .service(
                 web::resource("/{payment_link_id}").route(web::get().to(payment_link_retrieve)),
@@ -1108,6 +1175,19 @@ impl PaymentLink {
     }
 }
 
+pub struct HostedCheckout;
+#[cfg(feature = "olap")]
+impl HostedCheckout {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/checkout")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{merchant_id}/{client_secret}")
+                    .route(web::get().to(initiate_hosted_checkout)),
+            )
+    }
+}
+
 pub struct BusinessProfile;
 
 #[cfg(feature = "olap")]
@@ -1131,6 +1211,19 @@ impl BusinessProfile {
                     .service(
                         web::resource("/toggle_extended_card_info")
                             .route(web::post().to(toggle_extended_card_info)),
+                    )
+                    .service(
+                        web::resource("/webhook_endpoint/enable")
+                            .route(web::post().to(webhook_endpoint_enable)),
+                    )
+                    .service(
+                        web::resource("/wallet_certificates")
+                            .route(web::get().to(wallet_certificates_retrieve))
+                            .route(web::post().to(wallet_certificates_update)),
+                    )
+                    .service(
+                        web::resource("/wallet_certificates/{wallet}")
+                            .route(web::delete().to(wallet_certificates_delete)),
                     ),
             )
     }
@@ -1162,6 +1255,10 @@ impl Verify {
                 web::resource("/apple_pay/{merchant_id}")
                     .route(web::post().to(apple_pay_merchant_registration)),
             )
+            .service(
+                web::resource("/apple_pay/{merchant_id}/business_profile/{profile_id}")
+                    .route(web::post().to(apple_pay_profile_domain_registration)),
+            )
             .service(
                 web::resource("/applepay_verified_domains")
                     .route(web::get().to(retrieve_apple_pay_verified_domains)),
@@ -1309,3 +1406,45 @@ impl WebhookEvents {
             )
     }
 }
+
+#[cfg(feature = "olap")]
+pub struct Usage;
+
+#[cfg(feature = "olap")]
+impl Usage {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/usage")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/export").route(web::get().to(usage_export)))
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct ConnectorMigration;
+
+#[cfg(feature = "olap")]
+impl ConnectorMigration {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connector_migration/{merchant_id}")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(connector_migration_create)))
+            .service(
+                web::resource("/{migration_id}").route(web::get().to(connector_migration_retrieve)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct CustomerDataExport;
+
+#[cfg(feature = "olap")]
+impl CustomerDataExport {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/customer_data_export/{merchant_id}")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(customer_data_export_create)))
+            .service(
+                web::resource("/{export_id}").route(web::get().to(customer_data_export_retrieve)),
+            )
+    }
+}
@@ -0,0 +1,58 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::connector_migration as connector_migration_api_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, connector_migration},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorMigrationCreate))]
+pub async fn connector_migration_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<connector_migration_api_types::ConnectorMigrationRequest>,
+) -> impl Responder {
+    let flow = Flow::ConnectorMigrationCreate;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, _| {
+            connector_migration::create_connector_migration(state, merchant_id.clone(), request)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorMigrationRetrieve))]
+pub async fn connector_migration_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::ConnectorMigrationRetrieve;
+    let (merchant_id, migration_id) = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        migration_id,
+        |state, _, migration_id, _| {
+            connector_migration::retrieve_connector_migration(
+                state,
+                merchant_id.clone(),
+                migration_id,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -56,3 +56,27 @@ pub async fn convert_forex(
     ))
     .await
 }
+
+pub async fn localize_forex_amount(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    params: web::Query<api_models::currency::AmountLocalizationParams>,
+) -> HttpResponse {
+    let flow = Flow::LocalizeForexAmountFlow;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        params.into_inner(),
+        |state, _: auth::AuthenticationData, params, _| {
+            currency::localize_forex_amount(state, params)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::DashboardNoPermissionAuth,
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
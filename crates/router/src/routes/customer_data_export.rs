@@ -0,0 +1,58 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::customer_data_export as customer_data_export_api_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, customer_data_export},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::CustomerDataExportCreate))]
+pub async fn customer_data_export_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<customer_data_export_api_types::CustomerDataExportRequest>,
+) -> impl Responder {
+    let flow = Flow::CustomerDataExportCreate;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, _| {
+            customer_data_export::create_customer_data_export(state, merchant_id.clone(), request)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CustomerDataExportRetrieve))]
+pub async fn customer_data_export_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::CustomerDataExportRetrieve;
+    let (merchant_id, export_id) = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        export_id,
+        |state, _, export_id, _| {
+            customer_data_export::retrieve_customer_data_export(
+                state,
+                merchant_id.clone(),
+                export_id,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -65,12 +65,13 @@ pub async fn dummy_connector_payment(
 ) -> impl actix_web::Responder {
     let payload = json_payload.into_inner();
     let flow = types::Flow::DummyPaymentCreate;
+    let merchant_id = utils::get_merchant_id(&req);
     api::server_wrap(
         flow,
         state,
         &req,
         payload,
-        |state, _, req, _| core::payment(state, req),
+        |state, _, req, _| core::payment(state, req, merchant_id.clone()),
         &auth::NoAuth,
         api_locking::LockAction::NotApplicable,
     )
@@ -83,6 +84,7 @@ pub async fn dummy_connector_payment_data(
     path: web::Path<String>,
 ) -> impl actix_web::Responder {
     let flow = types::Flow::DummyPaymentRetrieve;
+    let merchant_id = utils::get_merchant_id(&req);
     let payment_id = path.into_inner();
     let payload = types::DummyConnectorPaymentRetrieveRequest { payment_id };
     api::server_wrap(
@@ -90,7 +92,7 @@ pub async fn dummy_connector_payment_data(
         state,
         &req,
         payload,
-        |state, _, req, _| core::payment_data(state, req),
+        |state, _, req, _| core::payment_data(state, req, merchant_id.clone()),
         &auth::NoAuth,
         api_locking::LockAction::NotApplicable,
     )
@@ -104,6 +106,7 @@ pub async fn dummy_connector_refund(
     path: web::Path<String>,
 ) -> impl actix_web::Responder {
     let flow = types::Flow::DummyRefundCreate;
+    let merchant_id = utils::get_merchant_id(&req);
     let mut payload = json_payload.into_inner();
     payload.payment_id = Some(path.to_string());
     api::server_wrap(
@@ -111,7 +114,7 @@ pub async fn dummy_connector_refund(
         state,
         &req,
         payload,
-        |state, _, req, _| core::refund_payment(state, req),
+        |state, _, req, _| core::refund_payment(state, req, merchant_id.clone()),
         &auth::NoAuth,
         api_locking::LockAction::NotApplicable,
     )
@@ -124,6 +127,7 @@ pub async fn dummy_connector_refund_data(
     path: web::Path<String>,
 ) -> impl actix_web::Responder {
     let flow = types::Flow::DummyRefundRetrieve;
+    let merchant_id = utils::get_merchant_id(&req);
     let refund_id = path.into_inner();
     let payload = types::DummyConnectorRefundRetrieveRequest { refund_id };
     api::server_wrap(
@@ -131,7 +135,7 @@ pub async fn dummy_connector_refund_data(
         state,
         &req,
         payload,
-        |state, _, req, _| core::refund_data(state, req),
+        |state, _, req, _| core::refund_data(state, req, merchant_id.clone()),
         &auth::NoAuth,
         api_locking::LockAction::NotApplicable,
     )
@@ -1,4 +1,5 @@
 pub const PAYMENT_ID_PREFIX: &str = "dummy_pay";
 pub const ATTEMPT_ID_PREFIX: &str = "dummy_attempt";
 pub const REFUND_ID_PREFIX: &str = "dummy_ref";
+pub const MERCHANT_PROFILE_KEY_PREFIX: &str = "dummy_connector_profile";
 pub const THREE_DS_CSS: &str = include_str!("threeds_page.css");
@@ -12,13 +12,25 @@ use crate::{
 pub async fn payment(
     state: AppState,
     req: types::DummyConnectorPaymentRequest,
+    merchant_id: Option<String>,
 ) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
-    utils::tokio_mock_sleep(
+    let profile = utils::get_merchant_profile(&state, merchant_id.as_deref()).await;
+    utils::simulate_latency(
+        profile.payment_latency,
         state.conf.dummy_connector.payment_duration,
         state.conf.dummy_connector.payment_tolerance,
     )
     .await;
 
+    if utils::should_simulate_failure(profile.error_ratio) {
+        Err(errors::DummyConnectorErrors::ServiceUnavailable)?;
+    }
+    if utils::should_simulate_failure(profile.payment_decline_ratio) {
+        Err(errors::DummyConnectorErrors::PaymentDeclined {
+            message: "Do not honor",
+        })?;
+    }
+
     let payment_attempt: types::DummyConnectorPaymentAttempt = req.into();
     let payment_data =
         types::DummyConnectorPaymentData::process_payment_attempt(&state, payment_attempt)?;
@@ -43,13 +55,20 @@ pub async fn payment(
 pub async fn payment_data(
     state: AppState,
     req: types::DummyConnectorPaymentRetrieveRequest,
+    merchant_id: Option<String>,
 ) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
-    utils::tokio_mock_sleep(
+    let profile = utils::get_merchant_profile(&state, merchant_id.as_deref()).await;
+    utils::simulate_latency(
+        profile.payment_retrieve_latency,
         state.conf.dummy_connector.payment_retrieve_duration,
         state.conf.dummy_connector.payment_retrieve_tolerance,
     )
     .await;
 
+    if utils::should_simulate_failure(profile.error_ratio) {
+        Err(errors::DummyConnectorErrors::ServiceUnavailable)?;
+    }
+
     let payment_data = utils::get_payment_data_from_payment_id(&state, req.payment_id).await?;
     Ok(api::ApplicationResponse::Json(payment_data.into()))
 }
@@ -147,13 +166,20 @@ pub async fn payment_complete(
 pub async fn refund_payment(
     state: AppState,
     req: types::DummyConnectorRefundRequest,
+    merchant_id: Option<String>,
 ) -> types::DummyConnectorResponse<types::DummyConnectorRefundResponse> {
-    utils::tokio_mock_sleep(
+    let profile = utils::get_merchant_profile(&state, merchant_id.as_deref()).await;
+    utils::simulate_latency(
+        profile.refund_latency,
         state.conf.dummy_connector.refund_duration,
         state.conf.dummy_connector.refund_tolerance,
     )
     .await;
 
+    if utils::should_simulate_failure(profile.error_ratio) {
+        Err(errors::DummyConnectorErrors::ServiceUnavailable)?;
+    }
+
     let payment_id = req
         .payment_id
         .get_required_value("payment_id")
@@ -199,14 +225,21 @@ pub async fn refund_payment(
 pub async fn refund_data(
     state: AppState,
     req: types::DummyConnectorRefundRetrieveRequest,
+    merchant_id: Option<String>,
 ) -> types::DummyConnectorResponse<types::DummyConnectorRefundResponse> {
     let refund_id = req.refund_id;
-    utils::tokio_mock_sleep(
+    let profile = utils::get_merchant_profile(&state, merchant_id.as_deref()).await;
+    utils::simulate_latency(
+        profile.refund_retrieve_latency,
         state.conf.dummy_connector.refund_retrieve_duration,
         state.conf.dummy_connector.refund_retrieve_tolerance,
     )
     .await;
 
+    if utils::should_simulate_failure(profile.error_ratio) {
+        Err(errors::DummyConnectorErrors::ServiceUnavailable)?;
+    }
+
     let redis_conn = state
         .store
         .get_redis_conn()
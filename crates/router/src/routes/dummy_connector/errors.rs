@@ -37,6 +37,9 @@ pub enum DummyConnectorErrors {
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "DC_08", message = "Payment declined: {message}")]
     PaymentDeclined { message: &'static str },
+
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "DC_09", message = "Service is temporarily unavailable")]
+    ServiceUnavailable,
 }
 
 impl core::fmt::Display for DummyConnectorErrors {
@@ -83,6 +86,9 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::PaymentDeclined { message: _ } => {
                 AER::BadRequest(ApiError::new("DC", 8, self.error_message(), None))
             }
+            Self::ServiceUnavailable => {
+                AER::InternalServerError(ApiError::new("DC", 9, self.error_message(), None))
+            }
         }
     }
 }
@@ -372,6 +372,31 @@ pub struct DummyConnectorRefundRetrieveRequest {
     pub refund_id: String,
 }
 
+/// Simulated behaviour of the dummy connector for the payments of a merchant, configured with the
+/// `dummy_connector_profile_{merchant_id}` config. The latencies which are not configured default to
+/// the ones of the dummy connector.
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DummyConnectorMerchantProfile {
+    pub payment_latency: Option<DummyConnectorLatency>,
+    pub payment_retrieve_latency: Option<DummyConnectorLatency>,
+    pub refund_latency: Option<DummyConnectorLatency>,
+    pub refund_retrieve_latency: Option<DummyConnectorLatency>,
+    /// Ratio of the payments declined by the dummy connector, between 0 and 1
+    pub payment_decline_ratio: f64,
+    /// Ratio of the requests failed by the dummy connector with a server error, between 0 and 1
+    pub error_ratio: f64,
+}
+
+/// Latency of the dummy connector, uniformly distributed within the tolerance of the duration
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorLatency {
+    /// Duration in milliseconds
+    pub duration: u64,
+    /// Tolerance in milliseconds
+    pub tolerance: u64,
+}
+
 pub type DummyConnectorResponse<T> =
     CustomResult<services::ApplicationResponse<T>, DummyConnectorErrors>;
 
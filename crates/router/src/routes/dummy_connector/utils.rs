@@ -1,30 +1,87 @@
 use std::fmt::Debug;
 
-use common_utils::ext_traits::AsyncExt;
+use common_utils::ext_traits::{AsyncExt, StringExt};
 use error_stack::{report, ResultExt};
 use masking::PeekInterface;
 use maud::html;
-use rand::{distributions::Uniform, prelude::Distribution};
+use rand::{distributions::Uniform, prelude::Distribution, Rng};
+use router_env::logger;
 use tokio::time as tokio;
 
 use super::{
     consts, errors,
     types::{self, GetPaymentMethodDetails},
 };
-use crate::{configs::settings, routes::AppState};
+use crate::{configs::settings, headers, routes::AppState};
 
 pub async fn tokio_mock_sleep(delay: u64, tolerance: u64) {
     let mut rng = rand::thread_rng();
-    // TODO: change this to `Uniform::try_from`
-    // this would require changing the fn signature
-    // to return a Result
-    let effective_delay = Uniform::from((delay - tolerance)..(delay + tolerance));
+    // The range is inclusive and saturating, as the latencies of the merchant profiles may have no
+    // tolerance or a tolerance larger than the delay
+    let effective_delay = Uniform::new_inclusive(
+        delay.saturating_sub(tolerance),
+        delay.saturating_add(tolerance),
+    );
     tokio::sleep(tokio::Duration::from_millis(
         effective_delay.sample(&mut rng),
     ))
     .await
 }
 
+/// Merchant the request has been made for, as sent by the dummy connector integration
+pub fn get_merchant_id(request: &actix_web::HttpRequest) -> Option<String> {
+    request
+        .headers()
+        .get(headers::X_MERCHANT_ID)
+        .and_then(|merchant_id| merchant_id.to_str().ok())
+        .map(str::to_owned)
+}
+
+/// Simulated behaviour of the dummy connector for the merchant, if a profile has been configured
+/// for the merchant. Invalid profiles are logged and ignored, so that the payments still go through.
+pub async fn get_merchant_profile(
+    state: &AppState,
+    merchant_id: Option<&str>,
+) -> types::DummyConnectorMerchantProfile {
+    let Some(merchant_id) = merchant_id else {
+        return types::DummyConnectorMerchantProfile::default();
+    };
+    let key = format!("{}_{merchant_id}", consts::MERCHANT_PROFILE_KEY_PREFIX);
+
+    match state.store.find_config_by_key(&key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("DummyConnectorMerchantProfile")
+            .map_err(
+                |error| logger::error!(?error, %merchant_id, "Invalid dummy connector profile"),
+            )
+            .unwrap_or_default(),
+        Err(error) => {
+            if !error.current_context().is_db_not_found() {
+                logger::error!(?error, %merchant_id, "Failed to fetch dummy connector profile");
+            }
+            types::DummyConnectorMerchantProfile::default()
+        }
+    }
+}
+
+/// Sleep for the latency of the merchant profile, or for the default latency of the flow
+pub async fn simulate_latency(
+    latency: Option<types::DummyConnectorLatency>,
+    default_duration: u64,
+    default_tolerance: u64,
+) {
+    let (duration, tolerance) = latency.map_or((default_duration, default_tolerance), |latency| {
+        (latency.duration, latency.tolerance)
+    });
+    tokio_mock_sleep(duration, tolerance).await
+}
+
+/// Whether a request fails, occurring at the given ratio of the requests
+pub fn should_simulate_failure(ratio: f64) -> bool {
+    rand::thread_rng().gen::<f64>() < ratio
+}
+
 pub async fn store_data_in_redis(
     state: &AppState,
     key: String,
@@ -5,7 +5,7 @@ use router_env::Flow;
 use crate::{
     core::{api_locking, fraud_check as frm_core},
     services::{self, api},
-    types::fraud_check::FraudCheckResponseData,
+    types::{api::admin::MerchantFeature, fraud_check::FraudCheckResponseData},
     AppState,
 };
 
@@ -23,7 +23,10 @@ pub async fn frm_fulfillment(
         |state, auth, req, _| {
             frm_core::frm_fulfillment_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &services::authentication::ApiKeyAuth,
+        &services::authentication::FeatureEntitlementAuth {
+            auth: services::authentication::ApiKeyAuth,
+            feature: MerchantFeature::Frm,
+        },
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -1,9 +1,10 @@
 use actix_web::{web, HttpRequest};
-use api_models::health_check::RouterHealthCheckResponse;
+use api_models::health_check::{DeploymentRoleHealthCheckResponse, RouterHealthCheckResponse};
 use router_env::{instrument, logger, tracing, Flow};
 
 use super::app;
 use crate::{
+    configs::settings::DeploymentRole,
     core::{api_locking, health_check::HealthCheckInterface},
     errors::{self, RouterResponse},
     routes::metrics,
@@ -124,3 +125,86 @@ async fn deep_health_check_func(state: app::AppState) -> RouterResponse<RouterHe
 
     Ok(api::ApplicationResponse::Json(response))
 }
+
+/// Readiness of a deployment role of the process, which checks only the components required by
+/// the role.
+#[instrument(skip_all, fields(flow = ?Flow::DeepHealthCheck))]
+pub async fn role_health_check(
+    state: web::Data<app::AppState>,
+    request: HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    metrics::HEALTH_METRIC.add(&metrics::CONTEXT, 1, &[]);
+
+    let flow = Flow::DeepHealthCheck;
+    let role = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &request,
+        role,
+        |state, _, role, _| role_health_check_func(state, role),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+async fn role_health_check_func(
+    state: app::AppState,
+    role: String,
+) -> RouterResponse<DeploymentRoleHealthCheckResponse> {
+    let deployment_role = role.parse::<DeploymentRole>().map_err(|_| {
+        error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Unknown deployment role `{role}`"),
+        })
+    })?;
+    logger::info!(%deployment_role, "Deployment role health check was called");
+
+    if !state.conf.deployment.is_role_enabled(deployment_role) {
+        return Err(error_stack::report!(
+            errors::ApiErrorResponse::HealthCheckError {
+                component: "Deployment",
+                message: format!("The {deployment_role} role is not enabled for the process"),
+            }
+        ));
+    }
+
+    let db_status = state.health_check_db().await.map_err(|err| {
+        error_stack::report!(errors::ApiErrorResponse::HealthCheckError {
+            component: "Database",
+            message: err.to_string()
+        })
+    })?;
+
+    let redis_status = state.health_check_redis().await.map_err(|err| {
+        error_stack::report!(errors::ApiErrorResponse::HealthCheckError {
+            component: "Redis",
+            message: err.to_string()
+        })
+    })?;
+
+    // The drainer does not make any outgoing requests
+    let outgoing_status = match deployment_role {
+        DeploymentRole::Drainer => None,
+        DeploymentRole::Api
+        | DeploymentRole::Consumer
+        | DeploymentRole::Producer
+        | DeploymentRole::AllInOne => Some(state.health_check_outgoing().await.map_err(|err| {
+            error_stack::report!(errors::ApiErrorResponse::HealthCheckError {
+                component: "Outgoing Request",
+                message: err.to_string()
+            })
+        })?),
+    };
+
+    let response = DeploymentRoleHealthCheckResponse {
+        role: deployment_role.to_string(),
+        database: db_status.into(),
+        redis: redis_status.into(),
+        outgoing_request: outgoing_status.map(Into::into),
+    };
+
+    Ok(api::ApplicationResponse::Json(response))
+}
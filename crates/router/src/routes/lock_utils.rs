@@ -35,6 +35,9 @@ pub enum ApiIdentifier {
     ConnectorOnboarding,
     Recon,
     Poll,
+    Usage,
+    ConnectorMigration,
+    CustomerDataExport,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -44,7 +47,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountRetrieve
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
-            | Flow::MerchantAccountList => Self::MerchantAccount,
+            | Flow::MerchantAccountList
+            | Flow::MerchantKeyCustodyUpdate
+            | Flow::MerchantFeatureEntitlementsUpdate => Self::MerchantAccount,
 
             Flow::RoutingCreateConfig
             | Flow::RoutingLinkConfig
@@ -60,7 +65,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::DecisionManagerRetrieveConfig
             | Flow::DecisionManagerUpsertConfig => Self::Routing,
 
-            Flow::RetrieveForexFlow => Self::Forex,
+            Flow::RetrieveForexFlow | Flow::LocalizeForexAmountFlow => Self::Forex,
 
             Flow::AddToBlocklist => Self::Blocklist,
             Flow::DeleteFromBlocklist => Self::Blocklist,
@@ -119,9 +124,19 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsFilters
             | Flow::PaymentsRedirect
             | Flow::PaymentsIncrementalAuthorization
+            | Flow::PaymentsVerifyMicroDeposits
+            | Flow::PaymentsRetry
             | Flow::PaymentsExternalAuthentication
             | Flow::PaymentsAuthorize
-            | Flow::GetExtendedCardInfo => Self::Payments,
+            | Flow::GetExtendedCardInfo
+            | Flow::PaymentsSuggestedMethods
+            | Flow::ClickToPayIdentityLookup
+            | Flow::ClickToPayEnrollment
+            | Flow::PaymentsTaxCalculation
+            | Flow::PaymentsTagsUpdate
+            | Flow::SavedPaymentFilterSave
+            | Flow::SavedPaymentFilterList
+            | Flow::SavedPaymentFilterDelete => Self::Payments,
 
             Flow::PayoutsCreate
             | Flow::PayoutsRetrieve
@@ -142,7 +157,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::IncomingWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
-            | Flow::WebhookEventDeliveryRetry => Self::Webhooks,
+            | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookSchemasList => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
@@ -168,14 +184,20 @@ impl From<Flow> for ApiIdentifier {
             | Flow::BusinessProfileRetrieve
             | Flow::BusinessProfileDelete
             | Flow::BusinessProfileList
-            | Flow::ToggleExtendedCardInfo => Self::Business,
+            | Flow::ToggleExtendedCardInfo
+            | Flow::WebhookEndpointEnable
+            | Flow::WalletCertificatesRetrieve
+            | Flow::WalletCertificatesUpdate
+            | Flow::WalletCertificatesDelete => Self::Business,
 
-            Flow::PaymentLinkRetrieve
+            Flow::PaymentLinkCreate
+            | Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
+            | Flow::HostedCheckoutInitiate
             | Flow::PaymentLinkList
             | Flow::PaymentLinkStatus => Self::PaymentLink,
 
-            Flow::Verification => Self::Verification,
+            Flow::Verification | Flow::ApplepayProfileDomainRegistration => Self::Verification,
 
             Flow::RustLockerMigration => Self::RustLockerMigration,
             Flow::GsmRuleCreate
@@ -233,6 +255,16 @@ impl From<Flow> for ApiIdentifier {
             Flow::CreateConnectorAgnosticMandateConfig => Self::Routing,
 
             Flow::RetrievePollStatus => Self::Poll,
+
+            Flow::UsageExport => Self::Usage,
+
+            Flow::ConnectorMigrationCreate | Flow::ConnectorMigrationRetrieve => {
+                Self::ConnectorMigration
+            }
+
+            Flow::CustomerDataExportCreate | Flow::CustomerDataExportRetrieve => {
+                Self::CustomerDataExport
+            }
         }
     }
 }
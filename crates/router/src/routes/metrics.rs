@@ -123,5 +123,9 @@ counter_metric!(TASKS_ADDED_COUNT, GLOBAL_METER); // Tasks added to process trac
 counter_metric!(TASK_ADDITION_FAILURES_COUNT, GLOBAL_METER); // Failures in task addition to process tracker
 counter_metric!(TASKS_RESET_COUNT, GLOBAL_METER); // Tasks reset in process tracker for requeue flow
 
+// Connector smoke test metrics, alerted on to catch breaking changes made by connectors
+counter_metric!(CONNECTOR_SMOKE_TEST_SUCCESS_COUNT, GLOBAL_METER);
+counter_metric!(CONNECTOR_SMOKE_TEST_FAILURE_COUNT, GLOBAL_METER);
+
 pub mod request;
 pub mod utils;
@@ -4,9 +4,47 @@ use router_env::{instrument, tracing, Flow};
 use crate::{
     core::{api_locking, payment_link::*},
     services::{api, authentication as auth},
+    types::api::admin::MerchantFeature,
     AppState,
 };
 
+/// Payment Link - Create
+///
+/// To create a payment link for a payment which has been created without one
+#[utoipa::path(
+    post,
+    path = "/payment_link",
+    request_body = PaymentLinkCreateRequest,
+    responses(
+        (status = 200, description = "Payment link created", body = RetrievePaymentLinkResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payments",
+    operation_id = "Create a Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkCreate))]
+pub async fn payment_link_create(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_models::payments::PaymentLinkCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| create_payment_link(state, auth.merchant_account, req),
+        &auth::FeatureEntitlementAuth {
+            auth: auth::ApiKeyAuth,
+            feature: MerchantFeature::PaymentLinks,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Payments Link - Retrieve
 ///
 /// To retrieve the properties of a Payment Link. This may be used to get the status of a previously initiated payment or next action for an ongoing payment
@@ -44,7 +82,7 @@ pub async fn payment_link_retrieve(
         state,
         &req,
         payload.clone(),
-        |state, _auth, _, _| retrieve_payment_link(state, path.clone()),
+        |state, auth, _, _| retrieve_payment_link(state, auth.merchant_account, path.clone()),
         &*auth_type,
         api_locking::LockAction::NotApplicable,
     )
@@ -81,6 +119,31 @@ pub async fn initiate_payment_link(
     .await
 }
 
+pub async fn initiate_hosted_checkout(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::HostedCheckoutInitiate;
+    let (merchant_id, client_secret) = path.into_inner();
+    let payload = api_models::payments::HostedCheckoutInitiateRequest {
+        merchant_id: merchant_id.clone(),
+        client_secret,
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, payload, _| {
+            initiate_hosted_checkout_flow(state, auth.merchant_account, payload.client_secret)
+        },
+        &crate::services::authentication::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Payment Link - List
 ///
 /// To list the payment links
@@ -100,7 +163,7 @@ pub async fn initiate_payment_link(
         (status = 200, description = "The payment link list was retrieved successfully"),
         (status = 401, description = "Unauthorized request")
     ),
-    tag = "Payment Link",
+    tag = "Payments",
     operation_id = "List all Payment links",
     security(("api_key" = []))
 )]
@@ -118,7 +181,10 @@ pub async fn payments_link_list(
         &req,
         payload,
         |state, auth, payload, _| list_payment_link(state, auth.merchant_account, payload),
-        &auth::ApiKeyAuth,
+        &auth::FeatureEntitlementAuth {
+            auth: auth::ApiKeyAuth,
+            feature: MerchantFeature::PaymentLinks,
+        },
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -13,10 +13,12 @@ use super::app::ReqState;
 use crate::{
     self as app,
     core::{
+        click_to_pay,
         errors::{self, http_not_implemented},
+        idempotency,
         payment_methods::{Oss, PaymentMethodRetrieve},
         payments::{self, PaymentRedirectFlow},
-        utils as core_utils,
+        tax_calculation, utils as core_utils,
     },
     routes::lock_utils,
     services::{api, authentication as auth},
@@ -102,6 +104,12 @@ pub async fn payments_create(
         return http_not_implemented();
     };
 
+    let idempotent_request =
+        match idempotency::IdempotentRequest::from_request(&req, &flow, &payload) {
+            Ok(idempotent_request) => idempotent_request,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
     if let Err(err) = get_or_generate_payment_id(&mut payload) {
         return api::log_and_return_error_response(err);
     }
@@ -125,15 +133,20 @@ pub async fn payments_create(
         &req,
         payload,
         |state, auth, req, req_state| {
-            authorize_verify_select::<_, Oss>(
-                payments::PaymentCreate,
-                state,
-                req_state,
-                auth.merchant_account,
-                auth.key_store,
-                payment_types::HeaderPayload::default(),
-                req,
-                api::AuthFlow::Merchant,
+            idempotency::perform_idempotent_request(
+                state.clone(),
+                auth.merchant_account.merchant_id.clone(),
+                idempotent_request.clone(),
+                authorize_verify_select::<_, Oss>(
+                    payments::PaymentCreate,
+                    state,
+                    req_state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    payment_types::HeaderPayload::default(),
+                    req,
+                    api::AuthFlow::Merchant,
+                ),
             )
         },
         match env::which() {
@@ -471,6 +484,13 @@ pub async fn payments_confirm(
     tracing::Span::current().record("payment_id", &payment_id);
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
     payload.confirm = Some(true);
+
+    let idempotent_request =
+        match idempotency::IdempotentRequest::from_request(&req, &flow, &payload) {
+            Ok(idempotent_request) => idempotent_request,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
     let header_payload = match payment_types::HeaderPayload::foreign_try_from(req.headers()) {
         Ok(headers) => headers,
         Err(err) => {
@@ -492,15 +512,20 @@ pub async fn payments_confirm(
         &req,
         payload,
         |state, auth, req, req_state| {
-            authorize_verify_select::<_, Oss>(
-                payments::PaymentConfirm,
-                state,
-                req_state,
-                auth.merchant_account,
-                auth.key_store,
-                header_payload,
-                req,
-                auth_flow,
+            idempotency::perform_idempotent_request(
+                state.clone(),
+                auth.merchant_account.merchant_id.clone(),
+                idempotent_request.clone(),
+                authorize_verify_select::<_, Oss>(
+                    payments::PaymentConfirm,
+                    state,
+                    req_state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    header_payload,
+                    req,
+                    auth_flow,
+                ),
             )
         },
         &*auth_type,
@@ -543,6 +568,12 @@ pub async fn payments_capture(
         ..json_payload.into_inner()
     };
 
+    let idempotent_request =
+        match idempotency::IdempotentRequest::from_request(&req, &flow, &payload) {
+            Ok(idempotent_request) => idempotent_request,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
     let locking_action = payload.get_locking_input(flow.clone());
 
     Box::pin(api::server_wrap(
@@ -551,24 +582,29 @@ pub async fn payments_capture(
         &req,
         payload,
         |state, auth, payload, req_state| {
-            payments::payments_core::<
-                api_types::Capture,
-                payment_types::PaymentsResponse,
-                _,
-                _,
-                _,
-                Oss,
-            >(
-                state,
-                req_state,
-                auth.merchant_account,
-                auth.key_store,
-                payments::PaymentCapture,
-                payload,
-                api::AuthFlow::Merchant,
-                payments::CallConnectorAction::Trigger,
-                None,
-                HeaderPayload::default(),
+            idempotency::perform_idempotent_request(
+                state.clone(),
+                auth.merchant_account.merchant_id.clone(),
+                idempotent_request.clone(),
+                payments::payments_core::<
+                    api_types::Capture,
+                    payment_types::PaymentsResponse,
+                    _,
+                    _,
+                    _,
+                    Oss,
+                >(
+                    state,
+                    req_state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    payments::PaymentCapture,
+                    payload,
+                    api::AuthFlow::Merchant,
+                    payments::CallConnectorAction::Trigger,
+                    None,
+                    HeaderPayload::default(),
+                ),
             )
         },
         &auth::ApiKeyAuth,
@@ -804,6 +840,8 @@ pub async fn payments_complete_authorize(
 /// Payments - Cancel
 ///
 /// A Payment could can be cancelled when it is in one of these statuses: requires_payment_method, requires_capture, requires_confirmation, requires_customer_action
+///
+/// Cancelling a payment which is partially_captured releases the part of the authorized amount which remains uncaptured, where the connector supports partial reversals
 #[utoipa::path(
     post,
     path = "/payments/{payment_id}/cancel",
@@ -925,7 +963,11 @@ pub async fn payments_list_by_filter(
         |state, auth: auth::AuthenticationData, req, _| {
             payments::apply_filters_on_payments(state, auth.merchant_account, req)
         },
-        &auth::JWTAuth(Permission::PaymentRead),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -1245,6 +1287,143 @@ pub async fn payments_incremental_authorization(
     .await
 }
 
+/// Payments - Verify Micro-deposits
+///
+/// Verify the bank account of an ACH bank debit payment which is in status: requires_customer_action, using the micro-deposits made to it
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/verify_micro_deposits",
+    request_body=PaymentsVerifyMicroDepositsRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Micro-deposits verified", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Verify micro-deposits of a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsVerifyMicroDeposits, payment_id))]
+pub async fn payments_verify_micro_deposits(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsVerifyMicroDepositsRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsVerifyMicroDeposits;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    payload.payment_id = payment_id;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, req_state| {
+            payments::payments_core::<
+                api_types::VerifyMicroDeposits,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                Oss,
+            >(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                payments::PaymentVerifyMicroDeposits,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                HeaderPayload::default(),
+            )
+        },
+        &auth::ApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+
+/// Payments - Retry
+///
+/// Retry a payment which is in status: failed, with a new attempt on the same payment. The payment can be retried with another connector or with a new payment method
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/retry",
+    request_body=PaymentsRetryRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Payment retried", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Retry a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRetry, payment_id))]
+pub async fn payments_retry(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsRetryRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsRetry;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    payload.payment_id = payment_id;
+
+    let header_payload = match HeaderPayload::foreign_try_from(req.headers()) {
+        Ok(headers) => headers,
+        Err(err) => {
+            return api::log_and_return_error_response(err);
+        }
+    };
+
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, req_state| async move {
+            let request = payments::helpers::get_manual_retry_request(
+                &*state.store,
+                &auth.merchant_account,
+                req,
+            )
+            .await?;
+
+            authorize_verify_select::<_, Oss>(
+                payments::PaymentConfirm,
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                header_payload,
+                request,
+                api::AuthFlow::Merchant,
+            )
+            .await
+        },
+        &auth::ApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+
 /// Payments - External 3DS Authentication
 ///
 /// External 3DS Authentication is performed and returns the AuthenticationResponse
@@ -1381,6 +1560,240 @@ pub async fn retrieve_extended_card_info(
     .await
 }
 
+/// Suggest the payment methods eligible for a payment, with the one most likely to succeed first
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsSuggestedMethods, payment_id))]
+pub async fn payments_suggested_methods(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsSuggestedMethods;
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth, payment_id, _| {
+            payments::suggestions::get_suggested_payment_methods(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Look up the Click to Pay profiles of the customer making a payment
+#[instrument(skip_all, fields(flow = ?Flow::ClickToPayIdentityLookup, payment_id))]
+pub async fn payments_click_to_pay_identity_lookup(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::ClickToPayIdentityLookupRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ClickToPayIdentityLookup;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    payload.payment_id = payment_id;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            click_to_pay::identity_lookup(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::PublishableKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Enroll a card in the Click to Pay profile of the customer making a payment
+#[instrument(skip_all, fields(flow = ?Flow::ClickToPayEnrollment, payment_id))]
+pub async fn payments_click_to_pay_enroll(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::ClickToPayEnrollmentRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ClickToPayEnrollment;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    payload.payment_id = payment_id;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            click_to_pay::enroll_card(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::PublishableKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Calculate the tax on a payment for the shipping address selected by the customer
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsTaxCalculation, payment_id))]
+pub async fn payments_calculate_tax(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsTaxCalculationRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsTaxCalculation;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    payload.payment_id = payment_id;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            tax_calculation::calculate_tax(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::PublishableKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+
+/// Replace the tags of a payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsTagsUpdate, payment_id))]
+pub async fn payments_update_tags(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsTagsUpdateRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsTagsUpdate;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    payload.payment_id = payment_id;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::update_payment_tags(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentWrite),
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
+/// Save a filter of the payments list, replacing the saved filter with the same name
+#[instrument(skip_all, fields(flow = ?Flow::SavedPaymentFilterSave))]
+#[cfg(feature = "olap")]
+pub async fn save_payment_filter(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::SavedPaymentFilterRequest>,
+) -> impl Responder {
+    let flow = Flow::SavedPaymentFilterSave;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::saved_filters::save_payment_filter(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SavedPaymentFilterList))]
+#[cfg(feature = "olap")]
+pub async fn list_saved_payment_filters(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+) -> impl Responder {
+    let flow = Flow::SavedPaymentFilterList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            payments::saved_filters::list_saved_payment_filters(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SavedPaymentFilterDelete))]
+#[cfg(feature = "olap")]
+pub async fn delete_saved_payment_filter(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::SavedPaymentFilterDelete;
+    let payload = payment_types::SavedPaymentFilterDeleteRequest {
+        name: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::saved_filters::delete_saved_payment_filter(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub fn get_or_generate_payment_id(
     payload: &mut payment_types::PaymentsRequest,
 ) -> errors::RouterResult<()> {
@@ -1582,6 +1995,38 @@ impl GetLockingInput for payment_types::PaymentsIncrementalAuthorizationRequest
     }
 }
 
+impl GetLockingInput for payment_types::PaymentsVerifyMicroDepositsRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
+impl GetLockingInput for payment_types::PaymentsRetryRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
 impl GetLockingInput for payment_types::PaymentsExternalAuthenticationRequest {
     fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
     where
@@ -1597,3 +2042,35 @@ impl GetLockingInput for payment_types::PaymentsExternalAuthenticationRequest {
         }
     }
 }
+
+impl GetLockingInput for payment_types::PaymentsTaxCalculationRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
+impl GetLockingInput for payment_types::PaymentsTagsUpdateRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
@@ -10,7 +10,7 @@ use crate::types::api::payments as payment_types;
 use crate::{
     core::{api_locking, payouts::*},
     services::{api, authentication as auth, authorization::permissions::Permission},
-    types::api::payouts as payout_types,
+    types::api::{admin::MerchantFeature, payouts as payout_types},
 };
 
 /// Payouts - Create
@@ -41,7 +41,10 @@ pub async fn payouts_create(
         |state, auth, req, _| {
             payouts_create_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::FeatureEntitlementAuth {
+            auth: auth::ApiKeyAuth,
+            feature: MerchantFeature::Payouts,
+        },
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -83,8 +86,14 @@ pub async fn payouts_retrieve(
             payouts_retrieve_core(state, auth.merchant_account, auth.key_store, req)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
-            &auth::JWTAuth(Permission::PayoutRead),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::ApiKeyAuth,
+                feature: MerchantFeature::Payouts,
+            },
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::PayoutRead),
+                feature: MerchantFeature::Payouts,
+            },
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -126,7 +135,10 @@ pub async fn payouts_update(
         |state, auth, req, _| {
             payouts_update_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::FeatureEntitlementAuth {
+            auth: auth::ApiKeyAuth,
+            feature: MerchantFeature::Payouts,
+        },
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -166,7 +178,10 @@ pub async fn payouts_cancel(
         |state, auth, req, _| {
             payouts_cancel_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::FeatureEntitlementAuth {
+            auth: auth::ApiKeyAuth,
+            feature: MerchantFeature::Payouts,
+        },
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -206,7 +221,10 @@ pub async fn payouts_fulfill(
         |state, auth, req, _| {
             payouts_fulfill_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::FeatureEntitlementAuth {
+            auth: auth::ApiKeyAuth,
+            feature: MerchantFeature::Payouts,
+        },
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -241,8 +259,14 @@ pub async fn payouts_list(
         payload,
         |state, auth, req, _| payouts_list_core(state, auth.merchant_account, auth.key_store, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
-            &auth::JWTAuth(Permission::PayoutRead),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::ApiKeyAuth,
+                feature: MerchantFeature::Payouts,
+            },
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::PayoutRead),
+                feature: MerchantFeature::Payouts,
+            },
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -281,8 +305,14 @@ pub async fn payouts_list_by_filter(
             payouts_filtered_list_core(state, auth.merchant_account, auth.key_store, req)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
-            &auth::JWTAuth(Permission::PayoutRead),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::ApiKeyAuth,
+                feature: MerchantFeature::Payouts,
+            },
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::PayoutRead),
+                feature: MerchantFeature::Payouts,
+            },
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -321,8 +351,14 @@ pub async fn payouts_list_available_filters(
             payouts_list_available_filters_core(state, auth.merchant_account, req)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
-            &auth::JWTAuth(Permission::PayoutRead),
+            &auth::FeatureEntitlementAuth {
+                auth: auth::ApiKeyAuth,
+                feature: MerchantFeature::Payouts,
+            },
+            &auth::FeatureEntitlementAuth {
+                auth: auth::JWTAuth(Permission::PayoutRead),
+                feature: MerchantFeature::Payouts,
+            },
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
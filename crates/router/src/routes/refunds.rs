@@ -3,7 +3,7 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{api_locking, refunds::*},
+    core::{api_locking, idempotency, refunds::*},
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::refunds,
 };
@@ -31,12 +31,27 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
+    let payload = json_payload.into_inner();
+
+    let idempotent_request =
+        match idempotency::IdempotentRequest::from_request(&req, &flow, &payload) {
+            Ok(idempotent_request) => idempotent_request,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
-        |state, auth, req, _| refund_create_core(state, auth.merchant_account, auth.key_store, req),
+        payload,
+        |state, auth, req, _| {
+            idempotency::perform_idempotent_request(
+                state.clone(),
+                auth.merchant_account.merchant_id.clone(),
+                idempotent_request.clone(),
+                refund_create_core(state, auth.merchant_account, auth.key_store, req),
+            )
+        },
         auth::auth_type(
             &auth::ApiKeyAuth,
             &auth::JWTAuth(Permission::RefundWrite),
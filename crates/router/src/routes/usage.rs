@@ -0,0 +1,28 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::usage as usage_api_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, usage},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::UsageExport))]
+pub async fn usage_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<usage_api_types::UsageExportRequest>,
+) -> impl Responder {
+    let flow = Flow::UsageExport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, request, _| usage::export_usage(state, request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
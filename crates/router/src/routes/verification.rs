@@ -70,3 +70,38 @@ pub async fn retrieve_apple_pay_verified_domains(
     )
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::ApplepayProfileDomainRegistration))]
+pub async fn apple_pay_profile_domain_registration(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<verifications::ApplepayProfileDomainRegistrationRequest>,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::ApplepayProfileDomainRegistration;
+    let (merchant_id, profile_id) = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, body, _| {
+            verification::register_applepay_domains_for_profile(
+                state,
+                merchant_id.clone(),
+                profile_id.clone(),
+                body,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
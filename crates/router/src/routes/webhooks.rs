@@ -44,6 +44,22 @@ pub async fn receive_incoming_webhook<W: types::OutgoingWebhookType>(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSchemasList))]
+pub async fn list_webhook_schemas(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::WebhookSchemasList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |_, _, _, _| webhooks::schemas::list_webhook_schemas(),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[derive(Debug)]
 struct WebhookBytes(web::Bytes);
 
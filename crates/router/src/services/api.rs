@@ -1,5 +1,10 @@
+pub mod base_url_override;
 pub mod client;
+pub mod connection_warmup;
+pub mod dns_cache;
+pub mod rate_limit;
 pub mod request;
+pub mod response_cache;
 use std::{
     collections::HashMap,
     error::Error,
@@ -37,7 +42,7 @@ use crate::{
     core::{
         api_locking,
         errors::{self, CustomResult},
-        payments,
+        payments, request_priority, usage,
     },
     events::{
         api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
@@ -102,6 +107,20 @@ pub trait ConnectorValidation: ConnectorCommon {
             .map(|_| ())
     }
 
+    fn validate_split_payments(
+        &self,
+        split_payments: Option<&api_models::payments::SplitPayments>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        match split_payments {
+            None => Ok(()),
+            Some(_) => Err(errors::ConnectorError::NotSupported {
+                message: "Split payments".to_string(),
+                connector: self.id(),
+            }
+            .into()),
+        }
+    }
+
     fn is_webhook_source_verification_mandatory(&self) -> bool {
         false
     }
@@ -325,46 +344,80 @@ where
             Ok(router_data)
         }
         payments::CallConnectorAction::Trigger => {
+            let flow_name = std::any::type_name::<T>()
+                .split("::")
+                .last()
+                .unwrap_or_default();
+            let response_cache_key = response_cache::get_cache_key(state, req, flow_name);
+            if let Some(cache_key) = &response_cache_key {
+                if let Some(cached_response) =
+                    response_cache::get_cached_response(state, cache_key).await
+                {
+                    logger::info!(flow = flow_name, "Handling the cached connector response");
+                    let connector_http_status_code = Some(cached_response.status_code);
+                    let mut data =
+                        connector_integration.handle_response(req, None, cached_response)?;
+                    data.connector_http_status_code = connector_http_status_code;
+                    return Ok(data);
+                }
+            }
             metrics::CONNECTOR_CALL_COUNT.add(
                 &metrics::CONTEXT,
                 1,
                 &[
                     metrics::request::add_attributes("connector", req.connector.to_string()),
-                    metrics::request::add_attributes(
-                        "flow",
-                        std::any::type_name::<T>()
-                            .split("::")
-                            .last()
-                            .unwrap_or_default()
-                            .to_string(),
-                    ),
+                    metrics::request::add_attributes("flow", flow_name.to_string()),
                 ],
             );
 
             let connector_request = match connector_request {
                 Some(connector_request) => Some(connector_request),
-                None => connector_integration
-                    .build_request(req, &state.conf.connectors)
-                    .map_err(|error| {
-                        if matches!(
-                            error.current_context(),
-                            &errors::ConnectorError::RequestEncodingFailed
-                                | &errors::ConnectorError::RequestEncodingFailedWithReason(_)
-                        ) {
-                            metrics::REQUEST_BUILD_FAILURE.add(
-                                &metrics::CONTEXT,
-                                1,
-                                &[metrics::request::add_attributes(
-                                    "connector",
-                                    req.connector.to_string(),
-                                )],
-                            )
-                        }
-                        error
-                    })?,
+                None => {
+                    let connectors = base_url_override::get_connectors(state, &req.connector).await;
+                    connector_integration
+                        .build_request(req, &connectors)
+                        .map_err(|error| {
+                            if matches!(
+                                error.current_context(),
+                                &errors::ConnectorError::RequestEncodingFailed
+                                    | &errors::ConnectorError::RequestEncodingFailedWithReason(_)
+                            ) {
+                                metrics::REQUEST_BUILD_FAILURE.add(
+                                    &metrics::CONTEXT,
+                                    1,
+                                    &[metrics::request::add_attributes(
+                                        "connector",
+                                        req.connector.to_string(),
+                                    )],
+                                )
+                            }
+                            error
+                        })?
+                }
             };
 
             match connector_request {
+                Some(_)
+                    if !rate_limit::acquire_connector_call_slot(
+                        state,
+                        &req.merchant_id,
+                        &req.connector,
+                        rate_limit::CallPriority::from_flow_name(flow_name),
+                    )
+                    .await =>
+                {
+                    // The call is failed the same way as if the connector had rate limited it
+                    let error_response = ErrorResponse {
+                        code: consts::CONNECTOR_RATE_LIMITED_ERROR_CODE.to_string(),
+                        message: consts::CONNECTOR_RATE_LIMITED_ERROR_MESSAGE.to_string(),
+                        reason: Some(consts::CONNECTOR_RATE_LIMITED_ERROR_MESSAGE.to_string()),
+                        status_code: http::StatusCode::TOO_MANY_REQUESTS.as_u16(),
+                        attempt_status: None,
+                        connector_transaction_id: None,
+                    };
+                    router_data.response = Err(error_response);
+                    Ok(router_data)
+                }
                 Some(request) => {
                     let masked_request_body = match &request.body {
                         Some(request) => match request {
@@ -384,6 +437,11 @@ where
                     let response =
                         call_connector_api(state, request, "execute_connector_processing_step")
                             .await;
+                    if let Some(outdated_cache_key) =
+                        response_cache::get_outdated_cache_key(state, req, flow_name)
+                    {
+                        response_cache::delete_cached_response(state, &outdated_cache_key).await;
+                    }
                     let external_latency = current_time.elapsed().as_millis();
                     logger::info!(raw_connector_request=?masked_request_body);
                     let status_code = response
@@ -413,6 +471,10 @@ where
                             let response = match body {
                                 Ok(body) => {
                                     let connector_http_status_code = Some(body.status_code);
+                                    let connector_request_id =
+                                        get_connector_request_id(body.headers.as_ref());
+                                    let body_to_cache =
+                                        response_cache_key.as_ref().map(|_| body.clone());
                                     let handle_response_result = connector_integration
                                         .handle_response(req, Some(&mut connector_event), body)
                                         .map_err(|error| {
@@ -433,8 +495,18 @@ where
                                     match handle_response_result {
                                         Ok(mut data) => {
                                             state.event_handler().log_event(&connector_event);
+                                            if let Some((cache_key, body)) = response_cache_key
+                                                .as_ref()
+                                                .zip(body_to_cache.as_ref())
+                                            {
+                                                response_cache::cache_response(
+                                                    state, cache_key, body, &data,
+                                                )
+                                                .await;
+                                            }
                                             data.connector_http_status_code =
                                                 connector_http_status_code;
+                                            data.connector_request_id = connector_request_id;
                                             // Add up multiple external latencies in case of multiple external calls within the same request.
                                             data.external_latency = Some(
                                                 data.external_latency
@@ -455,6 +527,8 @@ where
                                 }
                                 Err(body) => {
                                     router_data.connector_http_status_code = Some(body.status_code);
+                                    router_data.connector_request_id =
+                                        get_connector_request_id(body.headers.as_ref());
                                     router_data.external_latency = Some(
                                         router_data
                                             .external_latency
@@ -469,6 +543,8 @@ where
                                         )],
                                     );
 
+                                    let throttled_error =
+                                        rate_limit::ThrottledError::from_response(&body);
                                     let error = match body.status_code {
                                         500..=511 => {
                                             let error_res = connector_integration
@@ -485,13 +561,32 @@ where
                                                     body,
                                                     Some(&mut connector_event),
                                                 )?;
-                                            if let Some(status) = error_res.attempt_status {
+                                            // A rate limited call has not been processed by
+                                            // the connector, whatever its status is said to be
+                                            if let Some(status) = error_res
+                                                .attempt_status
+                                                .filter(|_| throttled_error.is_none())
+                                            {
                                                 router_data.status = status;
                                             };
                                             error_res
                                         }
                                     };
 
+                                    let error = match throttled_error {
+                                        Some(throttled_error) => {
+                                            rate_limit::record_throttling(
+                                                state,
+                                                &req.merchant_id,
+                                                &req.connector,
+                                                &throttled_error,
+                                            )
+                                            .await;
+                                            throttled_error.into_error_response(error)
+                                        }
+                                        None => error,
+                                    };
+
                                     router_data.response = Err(error);
 
                                     router_data
@@ -589,6 +684,7 @@ pub async fn send_request(
     let should_bypass_proxy = proxy_bypass_urls(&state.conf.locker).contains(&url.to_string());
     let client = client::create_client(
         &state.conf.proxy,
+        &state.conf.dns_cache,
         should_bypass_proxy,
         request.certificate,
         request.certificate_key,
@@ -736,6 +832,15 @@ pub async fn send_request(
     }
 }
 
+/// Identifier the connector assigned to the request, from the headers of its response
+fn get_connector_request_id(headers: Option<&http::HeaderMap>) -> Option<String> {
+    let headers = headers?;
+    consts::CONNECTOR_REQUEST_ID_HEADERS
+        .iter()
+        .find_map(|header| headers.get(*header)?.to_str().ok())
+        .map(ToString::to_string)
+}
+
 fn is_connection_closed_before_message_could_complete(error: &reqwest::Error) -> bool {
     let mut source = error.source();
     while let Some(err) = source {
@@ -997,6 +1102,10 @@ where
 
     request_state.event_context.record_info(auth_type.clone());
 
+    if let Some(merchant_id) = auth_type.get_merchant_id() {
+        usage::record_usage(&app_state, merchant_id, common_enums::UsageMetric::ApiCall);
+    }
+
     let merchant_id = auth_type
         .get_merchant_id()
         .unwrap_or("MERCHANT_ID_NOT_FOUND")
@@ -1008,6 +1117,10 @@ where
 
     tracing::Span::current().record("merchant_id", &merchant_id);
 
+    let _background_request_permit = request_priority::admit_request(&app_state, &flow.to_string())
+        .await
+        .switch()?;
+
     let output = {
         lock_action
             .clone()
@@ -1439,6 +1552,8 @@ impl Authenticate for api_models::payments::PaymentsRetrieveRequest {}
 impl Authenticate for api_models::payments::PaymentsCancelRequest {}
 impl Authenticate for api_models::payments::PaymentsCaptureRequest {}
 impl Authenticate for api_models::payments::PaymentsIncrementalAuthorizationRequest {}
+impl Authenticate for api_models::payments::PaymentsVerifyMicroDepositsRequest {}
+impl Authenticate for api_models::payments::PaymentsRetryRequest {}
 impl Authenticate for api_models::payments::PaymentsStartRequest {}
 // impl Authenticate for api_models::payments::PaymentsApproveRequest {}
 impl Authenticate for api_models::payments::PaymentsRejectRequest {}
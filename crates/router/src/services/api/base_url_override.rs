@@ -0,0 +1,225 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use common_utils::ext_traits::StringExt;
+use error_stack::ResultExt;
+use router_env::logger;
+use serde_json::Value;
+
+use crate::{
+    configs::settings::Connectors,
+    core::errors::{self, CustomResult},
+    routes::AppState,
+};
+
+/// Prefix of the keys of the configs overriding the base URLs of the connectors, which is
+/// followed by the name of the connector, such as `connector_base_url_override_adyen`
+const BASE_URL_OVERRIDE_KEY_PREFIX: &str = "connector_base_url_override_";
+
+/// The connectors are called on their configured base URLs while the configs of the overrides
+/// hold no base URLs
+const NO_BASE_URL_OVERRIDE: &str = "{}";
+
+/// Base URLs of a connector overriding the base URLs it is configured with, by the name of their
+/// configuration parameter, such as `{"base_url": "https://mock.example.com/"}`. The base URLs
+/// which are not overridden are left as configured.
+type BaseUrlOverride = HashMap<String, String>;
+
+pub fn get_base_url_override_key(connector_name: &str) -> String {
+    format!("{BASE_URL_OVERRIDE_KEY_PREFIX}{connector_name}")
+}
+
+/// The connector whose base URLs are overridden by the config of the key, if it is the key of a
+/// base URL override
+pub fn get_overridden_connector(key: &str) -> Option<&str> {
+    key.strip_prefix(BASE_URL_OVERRIDE_KEY_PREFIX)
+}
+
+/// Validate the config overriding the base URLs of the connector, which should only override the
+/// base URLs the connector is configured with, with absolute HTTP(S) URLs
+pub fn validate_base_url_override(
+    connectors: &Connectors,
+    connector_name: &str,
+    config: &str,
+) -> Result<(), errors::ApiErrorResponse> {
+    let invalid_override =
+        |message: String| errors::ApiErrorResponse::InvalidRequestData { message };
+
+    let base_url_override = serde_json::from_str::<BaseUrlOverride>(config).map_err(|_| {
+        invalid_override(format!(
+            "the base URL override of {connector_name} should be a JSON object of the base URLs by their name"
+        ))
+    })?;
+    let connector_params = serde_json::to_value(connectors)
+        .ok()
+        .and_then(|mut connectors| connectors.get_mut(connector_name).map(Value::take))
+        .ok_or_else(|| {
+            invalid_override(format!(
+                "{connector_name} is not a connector configured with base URLs"
+            ))
+        })?;
+
+    for (name, base_url) in base_url_override {
+        if !is_base_url_param(&connector_params, &name) {
+            return Err(invalid_override(format!(
+                "{name} is not a base URL of {connector_name}"
+            )));
+        }
+        let is_http_url = url::Url::parse(&base_url)
+            .is_ok_and(|base_url| matches!(base_url.scheme(), "http" | "https"));
+        if !is_http_url {
+            return Err(invalid_override(format!(
+                "{name} of {connector_name} should be an absolute HTTP(S) URL"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// The base URLs are the configuration parameters of the connectors named after them, and the
+/// other parameters, such as the key mode of Noon, cannot be overridden
+fn is_base_url_param(connector_params: &Value, name: &str) -> bool {
+    name.contains("base_url")
+        && connector_params
+            .get(name)
+            .is_some_and(|param| param.is_string() || param.is_null())
+}
+
+/// The connectors configuration to call the connector with, which has the base URLs of the
+/// connector overridden by the config of its base URL override, if any
+pub fn apply_base_url_override(
+    connectors: &Connectors,
+    connector_name: &str,
+    base_url_override: BaseUrlOverride,
+) -> CustomResult<Connectors, errors::ConnectorError> {
+    let mut overridden_connectors = serde_json::to_value(connectors)
+        .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+        .attach_printable("Failed to serialize the connectors configuration")?;
+    let connector_params = overridden_connectors
+        .get_mut(connector_name)
+        .and_then(Value::as_object_mut)
+        .ok_or(errors::ConnectorError::InvalidConnectorName)
+        .attach_printable_lazy(|| format!("{connector_name} is not configured"))?;
+    connector_params.extend(
+        base_url_override
+            .into_iter()
+            .map(|(name, base_url)| (name, Value::String(base_url))),
+    );
+
+    serde_json::from_value(overridden_connectors)
+        .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+        .attach_printable("Failed to deserialize the overridden connectors configuration")
+}
+
+/// The connectors configuration to build the requests to the connector with. The override of the
+/// base URLs of the connector is read from the configs, which are cached and invalidated when
+/// they are updated, so that the connector can be pointed at other endpoints without a redeploy.
+/// Failures to read the override are only logged, and the connector is then called on its
+/// configured base URLs.
+pub async fn get_connectors<'a>(state: &'a AppState, connector_name: &str) -> Cow<'a, Connectors> {
+    let connectors = &state.conf.connectors;
+    let base_url_override: CustomResult<BaseUrlOverride, errors::ConnectorError> = async {
+        state
+            .store
+            .find_config_by_key_unwrap_or(
+                &get_base_url_override_key(connector_name),
+                Some(NO_BASE_URL_OVERRIDE.to_string()),
+            )
+            .await
+            .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+            .attach_printable("Failed to read the base URL override of the connector")?
+            .config
+            .parse_struct("BaseUrlOverride")
+            .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+    }
+    .await;
+
+    match base_url_override {
+        Ok(base_url_override) if base_url_override.is_empty() => Cow::Borrowed(connectors),
+        Ok(base_url_override) => {
+            match apply_base_url_override(connectors, connector_name, base_url_override) {
+                Ok(overridden_connectors) => {
+                    logger::debug!(
+                        connector = connector_name,
+                        "Overriding the base URLs of the connector"
+                    );
+                    Cow::Owned(overridden_connectors)
+                }
+                Err(error) => {
+                    logger::error!(connector_base_url_override_error=?error);
+                    Cow::Borrowed(connectors)
+                }
+            }
+        }
+        Err(error) => {
+            logger::error!(connector_base_url_override_error=?error);
+            Cow::Borrowed(connectors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_test_connectors() -> Connectors {
+        let mut connectors = Connectors::default();
+        connectors.adyen.base_url = "https://checkout-test.adyen.com/".to_string();
+        connectors.adyen.secondary_base_url = "https://pal-test.adyen.com/".to_string();
+        connectors.stripe.base_url = "https://api.stripe.com/".to_string();
+        connectors
+    }
+
+    #[test]
+    fn test_validate_base_url_override() {
+        let connectors = get_test_connectors();
+
+        for config in [
+            "{}",
+            r#"{"base_url": "https://checkout-live.adyen.com/"}"#,
+            r#"{"base_url": "http://localhost:8080/", "secondary_base_url": "http://localhost:8081/"}"#,
+        ] {
+            assert!(validate_base_url_override(&connectors, "adyen", config).is_ok());
+        }
+        for (connector_name, config) in [
+            ("adyen", r#"["https://checkout-live.adyen.com/"]"#),
+            ("adyen", r#"{"base_url": "checkout-live.adyen.com"}"#),
+            ("adyen", r#"{"base_url": "ftp://checkout-live.adyen.com/"}"#),
+            (
+                "adyen",
+                r#"{"webhook_url": "https://checkout-live.adyen.com/"}"#,
+            ),
+            (
+                "unknown",
+                r#"{"base_url": "https://checkout-live.adyen.com/"}"#,
+            ),
+        ] {
+            assert!(validate_base_url_override(&connectors, connector_name, config).is_err());
+        }
+    }
+
+    #[test]
+    fn test_apply_base_url_override() {
+        let connectors = get_test_connectors();
+        let base_url_override =
+            HashMap::from([("base_url".to_string(), "http://localhost:8080/".to_string())]);
+
+        let overridden_connectors =
+            apply_base_url_override(&connectors, "adyen", base_url_override).unwrap();
+
+        assert_eq!(
+            overridden_connectors.adyen.base_url,
+            "http://localhost:8080/"
+        );
+        assert_eq!(
+            overridden_connectors.adyen.secondary_base_url,
+            connectors.adyen.secondary_base_url
+        );
+        assert_eq!(
+            overridden_connectors.stripe.base_url,
+            connectors.stripe.base_url
+        );
+    }
+}
@@ -9,7 +9,7 @@ use router_env::tracing_actix_web::RequestId;
 
 use super::{request::Maskable, Request};
 use crate::{
-    configs::settings::{Locker, Proxy},
+    configs::settings::{DnsCache, Locker, Proxy},
     consts::LOCKER_HEALTH_CALL_PATH,
     core::{
         errors::{ApiClientError, CustomResult},
@@ -23,6 +23,7 @@ static PROXIED_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
 
 fn get_client_builder(
     proxy_config: &Proxy,
+    dns_cache_config: &DnsCache,
     should_bypass_proxy: bool,
 ) -> CustomResult<reqwest::ClientBuilder, ApiClientError> {
     let mut client_builder = reqwest::Client::builder()
@@ -33,6 +34,10 @@ fn get_client_builder(
                 .unwrap_or_default(),
         ));
 
+    if let Some(resolver) = super::dns_cache::get_resolver(dns_cache_config) {
+        client_builder = client_builder.dns_resolver(resolver);
+    }
+
     if should_bypass_proxy {
         return Ok(client_builder);
     }
@@ -60,6 +65,7 @@ fn get_client_builder(
 
 fn get_base_client(
     proxy_config: &Proxy,
+    dns_cache_config: &DnsCache,
     should_bypass_proxy: bool,
 ) -> CustomResult<reqwest::Client, ApiClientError> {
     Ok(if should_bypass_proxy
@@ -70,7 +76,7 @@ fn get_base_client(
         &PROXIED_CLIENT
     }
     .get_or_try_init(|| {
-        get_client_builder(proxy_config, should_bypass_proxy)?
+        get_client_builder(proxy_config, dns_cache_config, should_bypass_proxy)?
             .build()
             .change_context(ApiClientError::ClientConstructionFailed)
             .attach_printable("Failed to construct base client")
@@ -82,13 +88,15 @@ fn get_base_client(
 // Precedence will be the environment variables, followed by the config.
 pub(super) fn create_client(
     proxy_config: &Proxy,
+    dns_cache_config: &DnsCache,
     should_bypass_proxy: bool,
     client_certificate: Option<masking::Secret<String>>,
     client_certificate_key: Option<masking::Secret<String>>,
 ) -> CustomResult<reqwest::Client, ApiClientError> {
     match (client_certificate, client_certificate_key) {
         (Some(encoded_certificate), Some(encoded_certificate_key)) => {
-            let client_builder = get_client_builder(proxy_config, should_bypass_proxy)?;
+            let client_builder =
+                get_client_builder(proxy_config, dns_cache_config, should_bypass_proxy)?;
 
             let identity = payments::helpers::create_identity_from_certificate_and_key(
                 encoded_certificate,
@@ -101,7 +109,7 @@ pub(super) fn create_client(
                 .change_context(ApiClientError::ClientConstructionFailed)
                 .attach_printable("Failed to construct client with certificate and certificate key")
         }
-        _ => get_base_client(proxy_config, should_bypass_proxy),
+        _ => get_base_client(proxy_config, dns_cache_config, should_bypass_proxy),
     }
 }
 
@@ -0,0 +1,76 @@
+use router_env::logger;
+
+use super::{Method, RequestBuilder};
+use crate::{
+    routes::AppState,
+    types::api::{self, ConnectorCommon},
+};
+
+/// Establish connections to the connectors in `connector_warmup.connector_list` when the
+/// application starts, so that the first calls made to them after a deploy are made on the
+/// connections pooled by the client instead of waiting on new connections being established.
+pub async fn warm_up_connector_connections(state: AppState) {
+    if !state.conf.connector_warmup.enabled {
+        return;
+    }
+
+    futures::future::join_all(
+        state
+            .conf
+            .connector_warmup
+            .connector_list
+            .iter()
+            .map(|connector| warm_up_connector_connection(&state, connector.to_string())),
+    )
+    .await;
+}
+
+/// Establish a connection to the connector, whose response is only used to have the connection
+/// pooled by the client. The hostname of the connector is cached along the way, if the DNS cache
+/// is enabled.
+pub async fn warm_up_connector_connection(state: &AppState, connector_name: String) {
+    if !state.conf.connector_warmup.enabled {
+        return;
+    }
+
+    let base_url =
+        match api::ConnectorData::convert_connector(&state.conf.connectors, &connector_name) {
+            Ok(connector) => connector.base_url(&state.conf.connectors).to_owned(),
+            Err(error) => {
+                logger::warn!(
+                    connector = %connector_name,
+                    ?error,
+                    "Unknown connector to warm up"
+                );
+                return;
+            }
+        };
+    let request = RequestBuilder::new()
+        .method(Method::Get)
+        .url(&base_url)
+        .build();
+
+    let response = super::send_request(
+        state,
+        request,
+        Some(state.conf.connector_warmup.timeout_in_secs),
+    )
+    .await;
+    match response {
+        Ok(response) => {
+            let status_code = response.status().as_u16();
+            // The connection is pooled by the client only once the response has been read
+            let _ = response.bytes().await;
+            logger::info!(
+                connector = %connector_name,
+                status_code,
+                "Warmed up the connection to the connector"
+            );
+        }
+        Err(error) => logger::warn!(
+            connector = %connector_name,
+            ?error,
+            "Failed to warm up the connection to the connector"
+        ),
+    }
+}
@@ -0,0 +1,115 @@
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use hyper::client::connect::dns::Name;
+use once_cell::sync::OnceCell;
+use reqwest::dns::{Addrs, Resolve, Resolving};
+use router_env::logger;
+
+use crate::configs::settings::DnsCache;
+
+/// Resolver shared by all the clients, so that the addresses cached are reused by the clients
+/// constructed for the calls made with a client certificate as well
+static CACHING_RESOLVER: OnceCell<Arc<CachingResolver>> = OnceCell::new();
+
+#[derive(Clone, Debug)]
+enum CachedLookup {
+    Resolved(Vec<SocketAddr>),
+    Failed(String),
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    lookup: CachedLookup,
+    expires_at: Instant,
+}
+
+/// Resolver which caches the addresses that the hostnames resolve to for `positive_ttl_in_secs`,
+/// and the failures to resolve them for `negative_ttl_in_secs`, so that a lookup is not made for
+/// every connection made to a connector.
+#[derive(Debug)]
+pub struct CachingResolver {
+    positive_ttl: Duration,
+    negative_ttl: Duration,
+    cache: Arc<RwLock<HashMap<String, CacheEntry>>>,
+}
+
+/// Resolver to be used by the clients, if the DNS cache is enabled.
+pub fn get_resolver(config: &DnsCache) -> Option<Arc<CachingResolver>> {
+    config.enabled.then(|| {
+        CACHING_RESOLVER
+            .get_or_init(|| {
+                Arc::new(CachingResolver {
+                    positive_ttl: Duration::from_secs(config.positive_ttl_in_secs),
+                    negative_ttl: Duration::from_secs(config.negative_ttl_in_secs),
+                    cache: Arc::new(RwLock::new(HashMap::new())),
+                })
+            })
+            .clone()
+    })
+}
+
+impl CachingResolver {
+    fn get_cached_lookup(&self, host: &str) -> Option<CachedLookup> {
+        let cache = self
+            .cache
+            .read()
+            .map_err(|_| logger::error!("DNS cache lock has been poisoned"))
+            .ok()?;
+        cache
+            .get(host)
+            .filter(|entry| entry.expires_at > Instant::now())
+            .map(|entry| entry.lookup.clone())
+    }
+}
+
+impl Resolve for CachingResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_owned();
+        let cached_lookup = self.get_cached_lookup(&host);
+        let cache = self.cache.clone();
+        let positive_ttl = self.positive_ttl;
+        let negative_ttl = self.negative_ttl;
+
+        Box::pin(async move {
+            let lookup = match cached_lookup {
+                Some(lookup) => lookup,
+                None => {
+                    // The port is set by the connector on the addresses resolved
+                    let (lookup, ttl) = match tokio::net::lookup_host((host.as_str(), 0)).await {
+                        Ok(addrs) => (CachedLookup::Resolved(addrs.collect()), positive_ttl),
+                        Err(error) => {
+                            logger::warn!(?error, "Failed to resolve the hostname {host}");
+                            (CachedLookup::Failed(error.to_string()), negative_ttl)
+                        }
+                    };
+                    match cache.write() {
+                        Ok(mut cache) => {
+                            cache.insert(
+                                host,
+                                CacheEntry {
+                                    lookup: lookup.clone(),
+                                    expires_at: Instant::now() + ttl,
+                                },
+                            );
+                        }
+                        Err(_) => logger::error!("DNS cache lock has been poisoned"),
+                    }
+                    lookup
+                }
+            };
+
+            match lookup {
+                CachedLookup::Resolved(addrs) => {
+                    let addrs: Addrs = Box::new(addrs.into_iter());
+                    Ok(addrs)
+                }
+                CachedLookup::Failed(error) => Err(error.into()),
+            }
+        })
+    }
+}
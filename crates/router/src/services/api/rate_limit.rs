@@ -0,0 +1,390 @@
+use std::time::Duration;
+
+use actix_web::rt::time as actix_time;
+use error_stack::ResultExt;
+use router_env::logger;
+
+use crate::{
+    configs::settings::ConnectorRateLimitConfig,
+    consts,
+    core::errors::{self, CustomResult},
+    routes::AppState,
+    types,
+};
+
+/// Calls to connectors which can be made again later on without any impact, and are hence the
+/// first ones to be shed when a connector is being rate limited
+const LOW_PRIORITY_FLOWS: [&str; 2] = ["PSync", "RSync"];
+
+/// Time for which the calls to a connector are held back after it has rate limited a call,
+/// when it has not told when calls can be made again
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Longest time for which the calls to a connector are held back after it has rate limited a
+/// call, so that a misconfigured connector cannot stop all the calls made to it
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+const RETRY_AFTER_HEADER: &str = "retry-after";
+const RATE_LIMIT_LIMIT_HEADERS: [&str; 2] = ["ratelimit-limit", "x-ratelimit-limit"];
+const RATE_LIMIT_REMAINING_HEADERS: [&str; 2] = ["ratelimit-remaining", "x-ratelimit-remaining"];
+const RATE_LIMIT_RESET_HEADERS: [&str; 2] = ["ratelimit-reset", "x-ratelimit-reset"];
+
+/// Signal sent by a connector that it is rate limiting the calls made to it, with a `429 Too Many
+/// Requests` response or with a `503 Service Unavailable` response telling when to retry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThrottledError {
+    pub status_code: u16,
+    /// Time after which the calls can be made again, from the `Retry-After` header or from the
+    /// reset of the rate limit window
+    pub retry_after: Option<Duration>,
+    /// Number of calls allowed in the rate limit window of the connector
+    pub limit: Option<u64>,
+    /// Number of calls left in the rate limit window of the connector
+    pub remaining: Option<u64>,
+}
+
+impl ThrottledError {
+    pub fn from_response(response: &types::Response) -> Option<Self> {
+        Self::from_headers(
+            response.status_code,
+            response.headers.as_ref(),
+            time::OffsetDateTime::now_utc(),
+        )
+    }
+
+    fn from_headers(
+        status_code: u16,
+        headers: Option<&http::HeaderMap>,
+        now: time::OffsetDateTime,
+    ) -> Option<Self> {
+        let get_header = |names: &[&str]| {
+            headers.and_then(|headers| {
+                names
+                    .iter()
+                    .find_map(|name| headers.get(*name)?.to_str().ok())
+                    .map(str::trim)
+            })
+        };
+        let retry_after = get_header(&[RETRY_AFTER_HEADER])
+            .and_then(|retry_after| parse_retry_after(retry_after, now))
+            .or_else(|| {
+                get_header(&RATE_LIMIT_RESET_HEADERS)
+                    .and_then(|reset| parse_rate_limit_reset(reset, now))
+            });
+
+        let is_throttled = match status_code {
+            429 => true,
+            503 => retry_after.is_some(),
+            _ => false,
+        };
+        is_throttled.then(|| Self {
+            status_code,
+            retry_after,
+            limit: get_header(&RATE_LIMIT_LIMIT_HEADERS).and_then(|limit| limit.parse().ok()),
+            remaining: get_header(&RATE_LIMIT_REMAINING_HEADERS)
+                .and_then(|remaining| remaining.parse().ok()),
+        })
+    }
+
+    /// Time for which the calls to the connector are held back
+    pub fn get_hold_back_duration(&self) -> Duration {
+        self.retry_after
+            .unwrap_or(DEFAULT_RETRY_AFTER)
+            .clamp(DEFAULT_RETRY_AFTER, MAX_RETRY_AFTER)
+    }
+
+    /// The error the call is failed with, which is the same whichever connector has rate limited
+    /// it and however it has done so, so that it can be told apart from the declines of the
+    /// connector
+    pub fn into_error_response(
+        self,
+        connector_error: types::ErrorResponse,
+    ) -> types::ErrorResponse {
+        let retry_after = self
+            .retry_after
+            .map(|retry_after| format!(", retry after {} seconds", retry_after.as_secs()))
+            .unwrap_or_default();
+        types::ErrorResponse {
+            code: consts::CONNECTOR_RATE_LIMITED_ERROR_CODE.to_string(),
+            message: consts::CONNECTOR_RATE_LIMITED_ERROR_MESSAGE.to_string(),
+            reason: Some(format!(
+                "{}: {}{retry_after}",
+                connector_error.code, connector_error.message
+            )),
+            status_code: http::StatusCode::TOO_MANY_REQUESTS.as_u16(),
+            attempt_status: None,
+            connector_transaction_id: connector_error.connector_transaction_id,
+        }
+    }
+}
+
+/// `Retry-After` is either a number of seconds or an HTTP date
+fn parse_retry_after(retry_after: &str, now: time::OffsetDateTime) -> Option<Duration> {
+    if let Ok(seconds) = retry_after.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let retry_at =
+        time::OffsetDateTime::parse(retry_after, &time::format_description::well_known::Rfc2822)
+            .ok()?;
+    Some((retry_at - now).try_into().unwrap_or_default())
+}
+
+/// The reset of the rate limit window is either a number of seconds, or a unix timestamp for the
+/// connectors following the convention of the `X-RateLimit-Reset` header
+fn parse_rate_limit_reset(reset: &str, now: time::OffsetDateTime) -> Option<Duration> {
+    let reset = reset.parse::<i64>().ok()?;
+    if reset > now.unix_timestamp() / 2 {
+        let reset_at = time::OffsetDateTime::from_unix_timestamp(reset).ok()?;
+        Some((reset_at - now).try_into().unwrap_or_default())
+    } else {
+        Some(Duration::from_secs(reset.try_into().ok()?))
+    }
+}
+
+/// Hold back the calls made to the connector for the merchant until the connector allows them
+/// again.
+pub async fn record_throttling(
+    state: &AppState,
+    merchant_id: &str,
+    connector: &str,
+    throttled_error: &ThrottledError,
+) {
+    let hold_back_duration = throttled_error.get_hold_back_duration();
+    logger::warn!(
+        connector,
+        ?throttled_error,
+        "Calls to the connector are rate limited by the connector"
+    );
+
+    let held_back_until = time::OffsetDateTime::now_utc() + hold_back_duration;
+    let result = async {
+        state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+            .attach_printable("Failed to get redis connection")?
+            .set_key_with_expiry(
+                &get_throttling_key(merchant_id, connector),
+                get_unix_timestamp_in_millis(held_back_until),
+                // Rounded up, so that the key outlives the time it holds the calls back until
+                i64::try_from(hold_back_duration.as_secs())
+                    .unwrap_or(i64::MAX)
+                    .saturating_add(1),
+            )
+            .await
+            .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+            .attach_printable("Failed to record the throttling of the calls to the connector")
+    }
+    .await;
+    if let Err(error) = result {
+        logger::error!(rate_limit_error=?error);
+    }
+}
+
+/// Time for which the calls to the connector are still held back for the merchant, after the
+/// connector has rate limited a call
+async fn get_held_back_duration(
+    state: &AppState,
+    merchant_id: &str,
+    connector: &str,
+) -> CustomResult<Option<Duration>, errors::ConnectorError> {
+    let held_back_until = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+        .attach_printable("Failed to get redis connection")?
+        .get_key::<Option<i64>>(&get_throttling_key(merchant_id, connector))
+        .await
+        .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+        .attach_printable("Failed to read the throttling of the calls to the connector")?;
+
+    let now_in_millis = get_unix_timestamp_in_millis(time::OffsetDateTime::now_utc());
+    Ok(held_back_until
+        .and_then(|held_back_until| {
+            u64::try_from(held_back_until.saturating_sub(now_in_millis)).ok()
+        })
+        .filter(|held_back_for_in_millis| *held_back_for_in_millis > 0)
+        .map(Duration::from_millis))
+}
+
+fn get_unix_timestamp_in_millis(date_time: time::OffsetDateTime) -> i64 {
+    i64::try_from(date_time.unix_timestamp_nanos() / 1_000_000).unwrap_or(i64::MAX)
+}
+
+fn get_throttling_key(merchant_id: &str, connector: &str) -> String {
+    format!("connector_throttled_{merchant_id}_{connector}")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallPriority {
+    High,
+    Low,
+}
+
+impl CallPriority {
+    pub fn from_flow_name(flow_name: &str) -> Self {
+        if LOW_PRIORITY_FLOWS.contains(&flow_name) {
+            Self::Low
+        } else {
+            Self::High
+        }
+    }
+}
+
+/// Reserve a slot for a call to the connector in the current one second window, returning `false`
+/// if the call must not be made.
+///
+/// Low priority calls are only allowed within `requests_per_second`, while high priority calls can
+/// use the `burst` on top of it and wait for the next windows for up to `max_queue_time_in_millis`.
+/// Calls are allowed if the number of calls made could not be tracked, so that an outage of redis
+/// does not stop all the calls to the connector.
+///
+/// Once the connector has rate limited a call made for the merchant, the calls are not made until
+/// the connector allows them again, unless high priority calls can wait for it.
+pub async fn acquire_connector_call_slot(
+    state: &AppState,
+    merchant_id: &str,
+    connector: &str,
+    priority: CallPriority,
+) -> bool {
+    let rate_limit_config = state.conf.connector_rate_limit.connectors.get(connector);
+
+    match get_held_back_duration(state, merchant_id, connector).await {
+        Ok(Some(held_back_for)) => {
+            let max_queue_time = match (priority, rate_limit_config) {
+                (CallPriority::High, Some(rate_limit_config)) => {
+                    Duration::from_millis(rate_limit_config.max_queue_time_in_millis)
+                }
+                (CallPriority::High, None) | (CallPriority::Low, _) => Duration::ZERO,
+            };
+            if held_back_for > max_queue_time {
+                logger::warn!(
+                    connector,
+                    ?priority,
+                    ?held_back_for,
+                    "Calls to the connector are held back after it has rate limited a call"
+                );
+                return false;
+            }
+            actix_time::sleep(held_back_for).await;
+        }
+        Ok(None) => {}
+        Err(error) => logger::error!(rate_limit_error=?error),
+    }
+
+    let Some(rate_limit_config) = rate_limit_config else {
+        return true;
+    };
+
+    let mut queued_for_in_millis = 0;
+    loop {
+        let now = time::OffsetDateTime::now_utc();
+        match count_call_in_window(state, connector, now.unix_timestamp()).await {
+            Ok(calls_in_window) => {
+                if calls_in_window <= get_calls_allowed(rate_limit_config, priority) {
+                    return true;
+                }
+            }
+            Err(error) => {
+                logger::error!(rate_limit_error=?error);
+                return true;
+            }
+        }
+
+        let time_to_next_window_in_millis = 1000 - u64::from(now.millisecond());
+        queued_for_in_millis += time_to_next_window_in_millis;
+        if priority == CallPriority::Low
+            || queued_for_in_millis > rate_limit_config.max_queue_time_in_millis
+        {
+            logger::warn!(
+                connector,
+                ?priority,
+                "Rate limit exceeded for the calls made to the connector"
+            );
+            return false;
+        }
+
+        actix_time::sleep(Duration::from_millis(time_to_next_window_in_millis)).await;
+    }
+}
+
+fn get_calls_allowed(rate_limit_config: &ConnectorRateLimitConfig, priority: CallPriority) -> i64 {
+    match priority {
+        CallPriority::High => rate_limit_config.requests_per_second + rate_limit_config.burst,
+        CallPriority::Low => rate_limit_config.requests_per_second,
+    }
+}
+
+async fn count_call_in_window(
+    state: &AppState,
+    connector: &str,
+    window: i64,
+) -> CustomResult<i64, errors::ConnectorError> {
+    let window_key = format!("connector_rate_limit_{connector}_{window}");
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+        .attach_printable("Failed to get redis connection")?;
+
+    let calls_in_window = redis_conn
+        .increment_key(&window_key)
+        .await
+        .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+        .attach_printable("Failed to count the call made to the connector")?;
+    if calls_in_window == 1 {
+        redis_conn
+            .set_expiry(&window_key, 2)
+            .await
+            .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+            .attach_printable("Failed to set the expiry of the connector rate limit window")?;
+    }
+
+    Ok(calls_in_window)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_headers(headers: &[(&'static str, &'static str)]) -> http::HeaderMap {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                (
+                    http::HeaderName::from_static(name),
+                    http::HeaderValue::from_static(value),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_throttled_error_from_headers() {
+        let now = time::OffsetDateTime::from_unix_timestamp(1_718_000_000).unwrap();
+
+        let headers = get_headers(&[("retry-after", "30"), ("x-ratelimit-limit", "100")]);
+        let throttled_error = ThrottledError::from_headers(429, Some(&headers), now).unwrap();
+        assert_eq!(throttled_error.retry_after, Some(Duration::from_secs(30)));
+        assert_eq!(throttled_error.limit, Some(100));
+
+        let headers = get_headers(&[("retry-after", "Mon, 10 Jun 2024 06:13:40 GMT")]);
+        let throttled_error = ThrottledError::from_headers(503, Some(&headers), now).unwrap();
+        assert_eq!(throttled_error.retry_after, Some(Duration::from_secs(20)));
+
+        let headers = get_headers(&[("x-ratelimit-reset", "1718000005")]);
+        let throttled_error = ThrottledError::from_headers(429, Some(&headers), now).unwrap();
+        assert_eq!(throttled_error.retry_after, Some(Duration::from_secs(5)));
+
+        let throttled_error = ThrottledError::from_headers(429, None, now).unwrap();
+        assert_eq!(
+            throttled_error.get_hold_back_duration(),
+            DEFAULT_RETRY_AFTER
+        );
+
+        assert!(ThrottledError::from_headers(503, None, now).is_none());
+        assert!(ThrottledError::from_headers(400, Some(&headers), now).is_none());
+    }
+}
@@ -0,0 +1,274 @@
+use std::any::Any;
+
+use error_stack::ResultExt;
+use router_env::logger;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::errors::{self, CustomResult},
+    routes::AppState,
+    types::{self, storage::enums, ErrorResponse},
+};
+
+/// Response of a sync made to the connector, which is handled again instead of making the sync
+/// to the connector while it is cached
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedConnectorResponse {
+    response: Vec<u8>,
+    status_code: u16,
+}
+
+/// Key of the cached response of the sync, if the response of the flow can be cached.
+///
+/// Only the responses of the payment and refund syncs are cached, since these can be handled
+/// any number of times without any impact.
+pub fn get_cache_key<T, Req, Resp>(
+    state: &AppState,
+    router_data: &types::RouterData<T, Req, Resp>,
+    flow_name: &str,
+) -> Option<String> {
+    if !state.conf.connector_response_cache.enabled {
+        return None;
+    }
+
+    get_sync_cache_key(
+        &router_data.merchant_id,
+        &router_data.connector,
+        flow_name,
+        &router_data.attempt_id,
+        router_data.refund_id.as_deref(),
+    )
+}
+
+/// Key of the cached response of the payment sync which is outdated by the flow, if the flow
+/// changes the payment at the connector.
+///
+/// The captures, voids and refunds change the payment, so the response of the payment sync cached
+/// before them must not be handled after them.
+pub fn get_outdated_cache_key<T, Req, Resp>(
+    state: &AppState,
+    router_data: &types::RouterData<T, Req, Resp>,
+    flow_name: &str,
+) -> Option<String> {
+    if !state.conf.connector_response_cache.enabled
+        || !matches!(flow_name, "Capture" | "Void" | "Execute")
+    {
+        return None;
+    }
+
+    get_sync_cache_key(
+        &router_data.merchant_id,
+        &router_data.connector,
+        "PSync",
+        &router_data.attempt_id,
+        None,
+    )
+}
+
+fn get_sync_cache_key(
+    merchant_id: &str,
+    connector: &str,
+    flow_name: &str,
+    attempt_id: &str,
+    refund_id: Option<&str>,
+) -> Option<String> {
+    let synced_id = match flow_name {
+        "PSync" => Some(attempt_id),
+        "RSync" => refund_id,
+        _ => None,
+    }?;
+    Some(format!(
+        "connector_response_{merchant_id}_{connector}_{flow_name}_{synced_id}"
+    ))
+}
+
+/// Response cached for the sync, if any. Errors are only logged, so that the sync is made to the
+/// connector if the cache could not be read.
+pub async fn get_cached_response(state: &AppState, cache_key: &str) -> Option<types::Response> {
+    let cached_response: CustomResult<Option<CachedConnectorResponse>, errors::ConnectorError> =
+        async {
+            state
+                .store
+                .get_redis_conn()
+                .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+                .attach_printable("Failed to get redis connection")?
+                .get_and_deserialize_key::<CachedConnectorResponse>(
+                    cache_key,
+                    "CachedConnectorResponse",
+                )
+                .await
+                .map(Some)
+                .or_else(|error| match error.current_context() {
+                    errors::RedisError::NotFound => Ok(None),
+                    _ => Err(error),
+                })
+                .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+                .attach_printable("Failed to read the cached connector response")
+        }
+        .await;
+
+    match cached_response {
+        Ok(cached_response) => cached_response.map(|cached_response| types::Response {
+            headers: None,
+            response: cached_response.response.into(),
+            status_code: cached_response.status_code,
+        }),
+        Err(error) => {
+            logger::error!(connector_response_cache_error=?error);
+            None
+        }
+    }
+}
+
+/// Cache the response of the sync, for a longer time if the status it was handled into is
+/// terminal, since it would not change anymore at the connector.
+///
+/// The responses handled into an error are not cached, even if the connector responded with a
+/// 2xx, as the status of such responses is decided from the error rather than from the connector.
+pub async fn cache_response<T, Req, Resp: 'static>(
+    state: &AppState,
+    cache_key: &str,
+    response: &types::Response,
+    router_data: &types::RouterData<T, Req, Resp>,
+) {
+    if router_data.response.is_err() {
+        return;
+    }
+
+    let cache_config = &state.conf.connector_response_cache;
+    let ttl_in_secs = if is_terminal_response(router_data.status, &router_data.response) {
+        cache_config.terminal_ttl_in_secs
+    } else {
+        cache_config.non_terminal_ttl_in_secs
+    };
+    let cached_response = CachedConnectorResponse {
+        response: response.response.to_vec(),
+        status_code: response.status_code,
+    };
+
+    let result: CustomResult<(), errors::ConnectorError> = async {
+        state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+            .attach_printable("Failed to get redis connection")?
+            .serialize_and_set_key_with_expiry(cache_key, cached_response, ttl_in_secs)
+            .await
+            .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+            .attach_printable("Failed to cache the connector response")
+    }
+    .await;
+    if let Err(error) = result {
+        logger::error!(connector_response_cache_error=?error);
+    }
+}
+
+/// Delete the cached response which is outdated. Errors are only logged, as the cached response
+/// expires anyway.
+pub async fn delete_cached_response(state: &AppState, cache_key: &str) {
+    let result: CustomResult<(), errors::ConnectorError> = async {
+        state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+            .attach_printable("Failed to get redis connection")?
+            .delete_key(cache_key)
+            .await
+            .map(|_| ())
+            .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+            .attach_printable("Failed to delete the cached connector response")
+    }
+    .await;
+    if let Err(error) = result {
+        logger::error!(connector_response_cache_error=?error);
+    }
+}
+
+fn is_terminal_response<Resp: 'static>(
+    status: enums::AttemptStatus,
+    response: &Result<Resp, ErrorResponse>,
+) -> bool {
+    let refund_response =
+        (response as &dyn Any).downcast_ref::<Result<types::RefundsResponseData, ErrorResponse>>();
+    match refund_response {
+        Some(Ok(refund_response)) => matches!(
+            refund_response.refund_status,
+            enums::RefundStatus::Success
+                | enums::RefundStatus::Failure
+                | enums::RefundStatus::TransactionFailure
+        ),
+        Some(Err(_)) => false,
+        None => status.is_terminal_status(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_sync_cache_key() {
+        assert_eq!(
+            get_sync_cache_key("merchant_1", "stripe", "PSync", "pay_1_1", None),
+            Some("connector_response_merchant_1_stripe_PSync_pay_1_1".to_string())
+        );
+        assert_eq!(
+            get_sync_cache_key("merchant_1", "stripe", "RSync", "pay_1_1", Some("ref_1")),
+            Some("connector_response_merchant_1_stripe_RSync_ref_1".to_string())
+        );
+        assert_eq!(
+            get_sync_cache_key("merchant_1", "stripe", "RSync", "pay_1_1", None),
+            None
+        );
+        for flow_name in ["Authorize", "Capture", "Void", "Execute"] {
+            assert_eq!(
+                get_sync_cache_key("merchant_1", "stripe", flow_name, "pay_1_1", Some("ref_1")),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_terminal_response() {
+        let payments_response = || -> Result<types::PaymentsResponseData, ErrorResponse> {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::NoResponseId,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: None,
+                incremental_authorization_allowed: None,
+            })
+        };
+        let refunds_response =
+            |refund_status| -> Result<types::RefundsResponseData, ErrorResponse> {
+                Ok(types::RefundsResponseData {
+                    connector_refund_id: "re_1".to_string(),
+                    refund_status,
+                })
+            };
+
+        assert!(is_terminal_response(
+            enums::AttemptStatus::Charged,
+            &payments_response()
+        ));
+        assert!(!is_terminal_response(
+            enums::AttemptStatus::Pending,
+            &payments_response()
+        ));
+        // The status of the attempt is not that of the refund
+        assert!(is_terminal_response(
+            enums::AttemptStatus::Pending,
+            &refunds_response(enums::RefundStatus::Success)
+        ));
+        assert!(!is_terminal_response(
+            enums::AttemptStatus::Charged,
+            &refunds_response(enums::RefundStatus::Pending)
+        ));
+        assert!(!is_terminal_response(
+            enums::AttemptStatus::Charged,
+            &Err::<types::RefundsResponseData, _>(ErrorResponse::default())
+        ));
+    }
+}
@@ -1,5 +1,6 @@
 use actix_web::http::header::HeaderMap;
 use api_models::{
+    admin::MerchantFeature,
     payment_methods::{PaymentMethodCreate, PaymentMethodListRequest},
     payments,
 };
@@ -8,7 +9,7 @@ use common_enums::TokenPurpose;
 use common_utils::date_time;
 use error_stack::{report, ResultExt};
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
-use masking::PeekInterface;
+use masking::{PeekInterface, StrongEq};
 use router_env::logger;
 use serde::Serialize;
 
@@ -28,7 +29,7 @@ use crate::core::errors::UserResult;
 use crate::routes::AppState;
 use crate::{
     core::{
-        api_keys,
+        admin, api_keys,
         errors::{self, utils::StorageErrorExt, RouterResult},
     },
     db::StorageInterface,
@@ -264,13 +265,18 @@ where
 
         let stored_api_key = state
             .store()
-            .find_api_key_by_hash_optional(hashed_api_key.into())
+            .find_api_key_by_hash_optional(hashed_api_key.clone().into())
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
             .attach_printable("Failed to retrieve API key")?
             .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
             .attach_printable("Merchant not authenticated")?;
 
+        if api_keys::HashedApiKey::from(stored_api_key.hashed_api_key.clone()) != hashed_api_key {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("API key hash mismatch");
+        }
+
         if stored_api_key
             .expires_at
             .map(|expires_at| expires_at < date_time::now())
@@ -366,7 +372,7 @@ where
 
         let admin_api_key = &conf.secrets.get_inner().admin_api_key;
 
-        if request_admin_api_key != admin_api_key.peek() {
+        if !request_admin_api_key.strong_eq(admin_api_key.peek()) {
             Err(report!(errors::ApiErrorResponse::Unauthorized)
                 .attach_printable("Admin Authentication Failure"))?;
         }
@@ -848,6 +854,53 @@ where
     }
 }
 
+pub trait GetMerchantAccount {
+    fn get_merchant_account(&self) -> &domain::MerchantAccount;
+}
+
+impl GetMerchantAccount for AuthenticationData {
+    fn get_merchant_account(&self) -> &domain::MerchantAccount {
+        &self.merchant_account
+    }
+}
+
+impl GetMerchantAccount for AuthenticationDataWithUserId {
+    fn get_merchant_account(&self) -> &domain::MerchantAccount {
+        &self.0.merchant_account
+    }
+}
+
+/// Authenticates the request using the wrapped authentication, and then rejects it if the
+/// merchant is not entitled to use the feature
+#[derive(Debug)]
+pub struct FeatureEntitlementAuth<T> {
+    pub auth: T,
+    pub feature: MerchantFeature,
+}
+
+#[async_trait]
+impl<A, T, U> AuthenticateAndFetch<U, A> for FeatureEntitlementAuth<T>
+where
+    A: AppStateInfo + Sync,
+    T: AuthenticateAndFetch<U, A> + Sync,
+    U: GetMerchantAccount + Send,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(U, AuthenticationType)> {
+        let (auth, auth_type) = self
+            .auth
+            .authenticate_and_fetch(request_headers, state)
+            .await?;
+
+        admin::validate_feature_entitlement(auth.get_merchant_account(), self.feature)?;
+
+        Ok((auth, auth_type))
+    }
+}
+
 pub trait ClientSecretFetch {
     fn get_client_secret(&self) -> Option<&String>;
 }
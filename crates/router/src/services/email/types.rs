@@ -56,6 +56,15 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    WebhookEndpointDisabled {
+        profile_id: String,
+        profile_name: String,
+        consecutive_failures: u32,
+    },
+    AbandonedPaymentReminder {
+        merchant_name: String,
+        payment_link: String,
+    },
 }
 
 pub mod html {
@@ -143,6 +152,24 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::WebhookEndpointDisabled {
+                profile_id,
+                profile_name,
+                consecutive_failures,
+            } => format!(
+                include_str!("assets/webhook_endpoint_disabled.html"),
+                profile_id = profile_id,
+                profile_name = profile_name,
+                consecutive_failures = consecutive_failures,
+            ),
+            EmailBody::AbandonedPaymentReminder {
+                merchant_name,
+                payment_link,
+            } => format!(
+                include_str!("assets/abandoned_payment_reminder.html"),
+                merchant_name = merchant_name,
+                payment_link = payment_link,
+            ),
         }
     }
 }
@@ -500,3 +527,55 @@ impl EmailData for ApiKeyExpiryReminder {
         })
     }
 }
+
+pub struct WebhookEndpointDisabled {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub profile_id: String,
+    pub profile_name: String,
+    pub consecutive_failures: u32,
+}
+
+#[async_trait::async_trait]
+impl EmailData for WebhookEndpointDisabled {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::WebhookEndpointDisabled {
+            profile_id: self.profile_id.clone(),
+            profile_name: self.profile_name.clone(),
+            consecutive_failures: self.consecutive_failures,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
+pub struct AbandonedPaymentReminder {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub merchant_name: String,
+    pub payment_link: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for AbandonedPaymentReminder {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::AbandonedPaymentReminder {
+            merchant_name: self.merchant_name.clone(),
+            payment_link: self.payment_link.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
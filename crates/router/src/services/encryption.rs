@@ -1,11 +1,16 @@
 use std::str;
 
+use common_utils::crypto::{Encryptable, GcmAes256};
 use error_stack::{report, ResultExt};
+use external_services::managers::encryption_management;
+use hyperswitch_interfaces::encryption_interface::EncryptionManagementInterface;
 use josekit::{jwe, jws};
+use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     core::errors::{self, CustomResult},
+    types::{domain::types::TypeEncryption, storage},
     utils,
 };
 
@@ -114,6 +119,107 @@ pub fn verify_sign(
     Ok(resp)
 }
 
+/// Wraps a merchant's data encryption key with the key provided by the merchant, identified by
+/// `external_key_uri`.
+pub async fn wrap_merchant_key(
+    external_key_uri: &str,
+    key: &[u8],
+) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+    let client = get_merchant_key_encryption_client(external_key_uri).await?;
+    wrap_merchant_key_with_client(&*client, key).await
+}
+
+/// Unwraps a merchant's data encryption key previously wrapped with [`wrap_merchant_key`].
+///
+/// This fails once the merchant revokes our access to the key it provided.
+pub async fn unwrap_merchant_key(
+    external_key_uri: &str,
+    wrapped_key: &[u8],
+) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+    let client = get_merchant_key_encryption_client(external_key_uri).await?;
+    unwrap_merchant_key_with_client(&*client, wrapped_key).await
+}
+
+async fn get_merchant_key_encryption_client(
+    external_key_uri: &str,
+) -> CustomResult<Box<dyn EncryptionManagementInterface>, errors::EncryptionError> {
+    encryption_management::get_merchant_key_encryption_client(external_key_uri)
+        .await
+        .change_context(errors::EncryptionError)
+        .attach_printable("Failed to construct the client for the merchant provided key")
+}
+
+async fn wrap_merchant_key_with_client(
+    client: &dyn EncryptionManagementInterface,
+    key: &[u8],
+) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+    client
+        .encrypt(hex::encode(key).as_bytes())
+        .await
+        .change_context(errors::EncryptionError)
+        .attach_printable("Failed to wrap the merchant key with the merchant provided key")
+}
+
+async fn unwrap_merchant_key_with_client(
+    client: &dyn EncryptionManagementInterface,
+    wrapped_key: &[u8],
+) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+    let key = client
+        .decrypt(wrapped_key)
+        .await
+        .change_context(errors::EncryptionError)
+        .attach_printable(
+            "Failed to unwrap the merchant key, access to the merchant provided key may have been revoked",
+        )?;
+
+    hex::decode(key)
+        .change_context(errors::EncryptionError)
+        .attach_printable("Unwrapped merchant key is not hex encoded")
+}
+
+/// Resolves the key of a merchant holding custody of its key.
+///
+/// For such merchants the stored key is wrapped with the merchant provided key before being
+/// encrypted with the master key. The returned key store holds the data encryption key encrypted
+/// with just the master key, the same as merchants whose key is managed by us, so that it can be
+/// converted like any other key store. The returned key store is not meant to be cached, as
+/// access to the merchant provided key must be checked on each use.
+pub async fn resolve_merchant_key_store(
+    key_store: storage::MerchantKeyStore,
+    master_key: &[u8],
+) -> CustomResult<storage::MerchantKeyStore, errors::EncryptionError> {
+    let Some(external_key_uri) = key_store.external_key_uri.as_deref() else {
+        return Ok(key_store);
+    };
+
+    let client = get_merchant_key_encryption_client(external_key_uri).await?;
+    resolve_merchant_key_store_with_client(key_store, master_key, &*client).await
+}
+
+async fn resolve_merchant_key_store_with_client(
+    key_store: storage::MerchantKeyStore,
+    master_key: &[u8],
+    client: &dyn EncryptionManagementInterface,
+) -> CustomResult<storage::MerchantKeyStore, errors::EncryptionError> {
+    let wrapped_key: Encryptable<Secret<Vec<u8>>> =
+        Encryptable::decrypt(key_store.key.clone(), master_key, GcmAes256)
+            .await
+            .change_context(errors::EncryptionError)
+            .attach_printable("Failed to decrypt the wrapped merchant key")?;
+
+    let key = unwrap_merchant_key_with_client(client, wrapped_key.peek()).await?;
+
+    let key: Encryptable<Secret<Vec<u8>>> = Encryptable::encrypt(key.into(), master_key, GcmAes256)
+        .await
+        .change_context(errors::EncryptionError)
+        .attach_printable("Failed to encrypt the unwrapped merchant key")?;
+
+    Ok(storage::MerchantKeyStore {
+        key: key.into(),
+        ..key_store
+    })
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used)]
@@ -226,4 +332,128 @@ VuY3OeNxi+dC2r7HppP3O/MJ4gX/RJJfSrcaGP8/Ke1W5+jE97Qy
         let payload = verify_sign(jwt, SIGNATURE_VERIFICATION_KEY).unwrap();
         assert_eq!("jws payload".to_string(), payload)
     }
+
+    const MASTER_KEY: [u8; 32] = [1; 32];
+    const MERCHANT_KEY: [u8; 32] = [2; 32];
+
+    /// Client of a merchant provided key to which our access has been revoked
+    #[derive(Clone)]
+    struct RevokedKeyClient;
+
+    #[async_trait::async_trait]
+    impl EncryptionManagementInterface for RevokedKeyClient {
+        async fn encrypt(
+            &self,
+            _input: &[u8],
+        ) -> CustomResult<Vec<u8>, hyperswitch_interfaces::encryption_interface::EncryptionError>
+        {
+            Err(report!(
+                hyperswitch_interfaces::encryption_interface::EncryptionError::EncryptionFailed
+            ))
+        }
+
+        async fn decrypt(
+            &self,
+            _input: &[u8],
+        ) -> CustomResult<Vec<u8>, hyperswitch_interfaces::encryption_interface::EncryptionError>
+        {
+            Err(report!(
+                hyperswitch_interfaces::encryption_interface::EncryptionError::DecryptionFailed
+            ))
+        }
+    }
+
+    async fn merchant_key_store(
+        key: &[u8],
+        external_key_uri: Option<&str>,
+    ) -> storage::MerchantKeyStore {
+        let key: Encryptable<Secret<Vec<u8>>> =
+            Encryptable::encrypt(key.to_vec().into(), &MASTER_KEY, GcmAes256)
+                .await
+                .unwrap();
+        storage::MerchantKeyStore {
+            merchant_id: "merchant_1".to_string(),
+            key: key.into(),
+            created_at: common_utils::date_time::now(),
+            external_key_uri: external_key_uri.map(str::to_string),
+        }
+    }
+
+    async fn decrypt_key(key_store: storage::MerchantKeyStore) -> Vec<u8> {
+        let key: Encryptable<Secret<Vec<u8>>> =
+            Encryptable::decrypt(key_store.key, &MASTER_KEY, GcmAes256)
+                .await
+                .unwrap();
+        key.peek().clone()
+    }
+
+    #[actix_rt::test]
+    async fn test_wrap_unwrap_merchant_key() {
+        let client = external_services::no_encryption::core::NoEncryption;
+        let wrapped_key = wrap_merchant_key_with_client(&client, &MERCHANT_KEY)
+            .await
+            .unwrap();
+        let key = unwrap_merchant_key_with_client(&client, &wrapped_key)
+            .await
+            .unwrap();
+        assert_eq!(key, MERCHANT_KEY);
+    }
+
+    #[actix_rt::test]
+    async fn test_unwrap_merchant_key_with_revoked_key() {
+        assert!(
+            unwrap_merchant_key_with_client(&RevokedKeyClient, &MERCHANT_KEY)
+                .await
+                .is_err()
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_resolve_merchant_key_store_without_external_key() {
+        let key_store = merchant_key_store(&MERCHANT_KEY, None).await;
+        let resolved_key_store = resolve_merchant_key_store(key_store.clone(), &MASTER_KEY)
+            .await
+            .unwrap();
+        assert_eq!(resolved_key_store.key, key_store.key);
+    }
+
+    #[actix_rt::test]
+    async fn test_resolve_merchant_key_store_with_external_key() {
+        let client = external_services::no_encryption::core::NoEncryption;
+        let wrapped_key = wrap_merchant_key_with_client(&client, &MERCHANT_KEY)
+            .await
+            .unwrap();
+        let key_store = merchant_key_store(&wrapped_key, Some("merchant_key_uri")).await;
+        let resolved_key_store =
+            resolve_merchant_key_store_with_client(key_store, &MASTER_KEY, &client)
+                .await
+                .unwrap();
+        assert_eq!(
+            resolved_key_store.external_key_uri.as_deref(),
+            Some("merchant_key_uri")
+        );
+        assert_eq!(decrypt_key(resolved_key_store).await, MERCHANT_KEY);
+    }
+
+    #[actix_rt::test]
+    async fn test_resolve_merchant_key_store_with_revoked_key() {
+        let key_store = merchant_key_store(&MERCHANT_KEY, Some("merchant_key_uri")).await;
+        assert!(
+            resolve_merchant_key_store_with_client(key_store, &MASTER_KEY, &RevokedKeyClient)
+                .await
+                .is_err()
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_resolve_merchant_key_store_with_unreachable_key() {
+        let key_store = merchant_key_store(&MERCHANT_KEY, Some("vault://merchant_key")).await;
+        let error = resolve_merchant_key_store(key_store, &MASTER_KEY)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<hyperswitch_interfaces::encryption_interface::EncryptionError>(),
+            Some(hyperswitch_interfaces::encryption_interface::EncryptionError::InvalidKeyUri)
+        ));
+    }
 }
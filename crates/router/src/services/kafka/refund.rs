@@ -26,6 +26,7 @@ pub struct KafkaRefund<'a> {
     pub description: Option<&'a String>,
     pub attempt_id: &'a String,
     pub refund_reason: Option<&'a String>,
+    pub refund_reason_code: Option<&'a storage_enums::RefundReasonCode>,
     pub refund_error_code: Option<&'a String>,
 }
 
@@ -53,6 +54,7 @@ impl<'a> KafkaRefund<'a> {
             description: refund.description.as_ref(),
             attempt_id: &refund.attempt_id,
             refund_reason: refund.refund_reason.as_ref(),
+            refund_reason_code: refund.refund_reason_code.as_ref(),
             refund_error_code: refund.refund_error_code.as_ref(),
         }
     }
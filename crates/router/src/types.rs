@@ -28,6 +28,7 @@ use error_stack::ResultExt;
 use hyperswitch_domain_models::mandates::{CustomerAcceptance, MandateData};
 use masking::Secret;
 use serde::Serialize;
+use time::PrimitiveDateTime;
 
 use self::storage::enums as storage_enums;
 pub use crate::core::payments::{payment_address::PaymentAddress, CustomerDetails};
@@ -65,6 +66,8 @@ pub type PaymentsIncrementalAuthorizationRouterData = RouterData<
     PaymentsIncrementalAuthorizationData,
     PaymentsResponseData,
 >;
+pub type PaymentsVerifyMicroDepositsRouterData =
+    RouterData<api::VerifyMicroDeposits, PaymentsVerifyMicroDepositsData, PaymentsResponseData>;
 pub type PaymentsCancelRouterData = RouterData<api::Void, PaymentsCancelData, PaymentsResponseData>;
 pub type PaymentsRejectRouterData =
     RouterData<api::Reject, PaymentsRejectData, PaymentsResponseData>;
@@ -165,6 +168,11 @@ pub type IncrementalAuthorizationType = dyn services::ConnectorIntegration<
     PaymentsIncrementalAuthorizationData,
     PaymentsResponseData,
 >;
+pub type PaymentsVerifyMicroDepositsType = dyn services::ConnectorIntegration<
+    api::VerifyMicroDeposits,
+    PaymentsVerifyMicroDepositsData,
+    PaymentsResponseData,
+>;
 
 pub type ConnectorCustomerType = dyn services::ConnectorIntegration<
     api::CreateConnectorCustomer,
@@ -317,6 +325,8 @@ pub struct RouterData<Flow, Request, Response> {
     pub test_mode: Option<bool>,
     pub connector_http_status_code: Option<u16>,
     pub external_latency: Option<u128>,
+    /// Identifier the connector assigned to its last request, as returned in its response headers
+    pub connector_request_id: Option<String>,
     /// Contains apple pay flow type simplified or manual
     pub apple_pay_flow: Option<storage_enums::ApplePayFlow>,
 
@@ -340,9 +350,19 @@ pub enum AdditionalPaymentMethodConnectorResponse {
     },
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ExtendedAuthorizationResponseData {
+    /// Whether the connector has extended the validity of the authorization of the payment
+    pub extended_authorization_applied: Option<bool>,
+    /// The time until which the authorization can be captured
+    pub capture_before: Option<PrimitiveDateTime>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ConnectorResponseData {
     pub additional_payment_method_data: Option<AdditionalPaymentMethodConnectorResponse>,
+    /// Result of the extended authorization requested for the payment
+    pub extended_authorization_response_data: Option<ExtendedAuthorizationResponseData>,
 }
 
 impl ConnectorResponseData {
@@ -351,14 +371,30 @@ impl ConnectorResponseData {
     ) -> Self {
         Self {
             additional_payment_method_data: Some(additional_payment_method_data),
+            extended_authorization_response_data: None,
         }
     }
+
+    pub fn get_extended_authorization_applied(&self) -> Option<bool> {
+        self.extended_authorization_response_data
+            .as_ref()
+            .and_then(|data| data.extended_authorization_applied)
+    }
+
+    pub fn get_capture_before(&self) -> Option<PrimitiveDateTime> {
+        self.extended_authorization_response_data
+            .as_ref()
+            .and_then(|data| data.capture_before)
+    }
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub enum PaymentMethodToken {
     Token(String),
     ApplePayDecrypt(Box<ApplePayPredecryptData>),
+    GooglePayDecrypt(Box<GooglePayPredecryptData>),
+    SamsungPayDecrypt(Box<SamsungPayPredecryptData>),
+    NetworkToken(Box<NetworkTokenData>),
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -380,6 +416,65 @@ pub struct ApplePayCryptogramData {
     pub eci_indicator: Option<String>,
 }
 
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayPredecryptData {
+    pub message_expiration: String,
+    pub message_id: String,
+    #[serde(rename = "paymentMethod")]
+    pub payment_method_type: String,
+    pub payment_method_details: GooglePayPaymentMethodDetails,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayPaymentMethodDetails {
+    pub auth_method: GooglePayAuthMethod,
+    pub expiration_month: u8,
+    pub expiration_year: u16,
+    pub pan: cards::CardNumber,
+    pub cryptogram: Option<Secret<String>>,
+    pub eci_indicator: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SamsungPayPredecryptData {
+    pub amount: String,
+    pub currency_code: String,
+    pub utc: String,
+    pub eci_indicator: Option<String>,
+    #[serde(rename = "tokenPAN")]
+    pub token_pan: cards::CardNumber,
+    /// Expiry of the network token, in the `MMYY` format
+    #[serde(rename = "tokenPanExpiration")]
+    pub token_pan_expiration: Secret<String>,
+    pub cryptogram: Secret<String>,
+}
+
+/// Network token provisioned for a saved card, along with the cryptogram generated for the payment
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct NetworkTokenData {
+    pub token_number: cards::CardNumber,
+    /// Expiry month of the network token, in the `MM` format
+    pub token_exp_month: Secret<String>,
+    /// Expiry year of the network token, in the `YYYY` format
+    pub token_exp_year: Secret<String>,
+    pub cryptogram: Secret<String>,
+    pub eci: Option<String>,
+    pub card_network: Option<common_enums::CardNetwork>,
+}
+
+/// Authentication method of the card in a Google Pay payment token
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+pub enum GooglePayAuthMethod {
+    /// The card is on file with Google, the payment may require 3DS
+    #[serde(rename = "PAN_ONLY")]
+    PanOnly,
+    /// The card is a network token bound to the device, authenticated with a cryptogram
+    #[serde(rename = "CRYPTOGRAM_3DS")]
+    Cryptogram3ds,
+}
+
 #[derive(Debug, Clone)]
 pub struct PaymentMethodBalance {
     pub amount: i64,
@@ -390,6 +485,7 @@ pub struct PaymentMethodBalance {
 #[derive(Debug, Clone)]
 pub struct PayoutsData {
     pub payout_id: String,
+    pub payout_attempt_id: String,
     pub amount: i64,
     pub connector_payout_id: Option<String>,
     pub destination_currency: storage_enums::Currency,
@@ -464,8 +560,18 @@ pub struct PaymentsAuthorizeData {
     pub surcharge_details: Option<types::SurchargeDetails>,
     pub customer_id: Option<String>,
     pub request_incremental_authorization: bool,
+    pub request_overcapture: bool,
+    pub request_extended_authorization: bool,
+    pub split_payments: Option<api_models::payments::SplitPayments>,
+    pub payment_channel: Option<storage_enums::PaymentChannel>,
+    pub l2_l3_data: Option<api_models::payments::L2L3Data>,
+    /// The number of installments the card payment is paid in, set only for the payments paid in
+    /// installments
+    pub installment_tenure: Option<u8>,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub authentication_data: Option<AuthenticationData>,
+    /// The network to authorize the payment on, set only for co-badged cards
+    pub preferred_card_network: Option<storage_enums::CardNetwork>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -479,6 +585,10 @@ pub struct PaymentsCaptureData {
     pub browser_info: Option<BrowserInformation>,
     pub metadata: Option<pii::SecretSerdeValue>,
     // This metadata is used to store the metadata shared during the payment intent request.
+    pub order_details: Option<Vec<api_models::payments::OrderDetailsWithAmount>>,
+    /// The reason the payment is captured for less than the amount authorized, in which case the
+    /// order details are the adjusted ones
+    pub capture_adjustment_reason: Option<storage_enums::CaptureAdjustmentReason>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -490,6 +600,15 @@ pub struct PaymentsIncrementalAuthorizationData {
     pub connector_transaction_id: String,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct PaymentsVerifyMicroDepositsData {
+    pub amounts: Option<Vec<i64>>,
+    pub descriptor_code: Option<Secret<String>>,
+    pub currency: storage_enums::Currency,
+    pub connector_transaction_id: String,
+    pub connector_meta: Option<serde_json::Value>,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Default)]
 pub struct MultipleCaptureRequestData {
@@ -600,6 +719,9 @@ pub struct PaymentsCancelData {
     pub browser_info: Option<BrowserInformation>,
     pub metadata: Option<pii::SecretSerdeValue>,
     // This metadata is used to store the metadata shared during the payment intent request.
+    /// The uncaptured amount to release, when the void is a partial reversal of a partially
+    /// captured payment
+    pub amount_to_reverse: Option<i64>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -621,6 +743,9 @@ pub struct PaymentsSessionData {
     pub country: Option<api::enums::CountryAlpha2>,
     pub surcharge_details: Option<types::SurchargeDetails>,
     pub order_details: Option<Vec<api_models::payments::OrderDetailsWithAmount>>,
+    /// The business profile of the payment, whose wallet certificates the wallet sessions are
+    /// created with
+    pub profile_id: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -709,7 +834,8 @@ impl Capturable for PaymentsAuthorizeData {
                     | common_enums::IntentStatus::RequiresPaymentMethod
                     | common_enums::IntentStatus::RequiresConfirmation
                     | common_enums::IntentStatus::RequiresCapture
-                    | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+                    | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+                    | common_enums::IntentStatus::Expired => None,
                 }
             },
             common_enums::CaptureMethod::Manual => Some(payment_data.payment_attempt.get_total_amount()),
@@ -748,7 +874,8 @@ impl Capturable for PaymentsCaptureData {
             | common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation
             | common_enums::IntentStatus::RequiresCapture
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::Expired => None,
         }
     }
 }
@@ -786,7 +913,8 @@ impl Capturable for CompleteAuthorizeData {
                     | common_enums::IntentStatus::RequiresPaymentMethod
                     | common_enums::IntentStatus::RequiresConfirmation
                     | common_enums::IntentStatus::RequiresCapture
-                    | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+                    | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+                    | common_enums::IntentStatus::Expired => None,
                 }
             },
             common_enums::CaptureMethod::Manual => Some(payment_data.payment_attempt.get_total_amount()),
@@ -826,13 +954,15 @@ impl Capturable for PaymentsCancelData {
             | common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation
             | common_enums::IntentStatus::RequiresCapture
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::Expired => None,
         }
     }
 }
 impl Capturable for PaymentsApproveData {}
 impl Capturable for PaymentsRejectData {}
 impl Capturable for PaymentsSessionData {}
+impl Capturable for PaymentsVerifyMicroDepositsData {}
 impl Capturable for PaymentsIncrementalAuthorizationData {
     fn get_amount_capturable<F>(
         &self,
@@ -1013,6 +1143,7 @@ pub struct RefundsData {
     /// Amount for the payment against which this refund is issued
     pub payment_amount: i64,
     pub reason: Option<String>,
+    pub reason_code: Option<storage_enums::RefundReasonCode>,
     pub webhook_url: Option<String>,
     /// Amount to be refunded
     pub refund_amount: i64,
@@ -1508,9 +1639,16 @@ impl From<&SetupMandateRouterData> for PaymentsAuthorizeData {
             customer_id: None,
             surcharge_details: None,
             request_incremental_authorization: data.request.request_incremental_authorization,
+            request_overcapture: false,
+            request_extended_authorization: false,
+            split_payments: None,
+            payment_channel: None,
+            l2_l3_data: None,
+            installment_tenure: None,
             metadata: None,
             authentication_data: None,
             customer_acceptance: data.request.customer_acceptance.clone(),
+            preferred_card_network: None,
         }
     }
 }
@@ -1557,6 +1695,7 @@ impl<F1, F2, T1, T2> From<(&RouterData<F1, T1, PaymentsResponseData>, T2)>
             connector_api_version: data.connector_api_version.clone(),
             connector_http_status_code: data.connector_http_status_code,
             external_latency: data.external_latency,
+            connector_request_id: data.connector_request_id.clone(),
             apple_pay_flow: data.apple_pay_flow.clone(),
             frm_metadata: data.frm_metadata.clone(),
             dispute_id: data.dispute_id.clone(),
@@ -1616,6 +1755,7 @@ impl<F1, F2>
             connector_api_version: None,
             connector_http_status_code: data.connector_http_status_code,
             external_latency: data.external_latency,
+            connector_request_id: data.connector_request_id.clone(),
             apple_pay_flow: None,
             frm_metadata: None,
             refund_id: None,
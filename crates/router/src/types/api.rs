@@ -217,6 +217,8 @@ type BoxedConnector = Box<&'static (dyn Connector + Sync)>;
 pub enum GetToken {
     GpayMetadata,
     ApplePayMetadata,
+    ClickToPayMetadata,
+    SamsungPayMetadata,
     Connector,
 }
 
@@ -323,6 +325,8 @@ impl ConnectorData {
             Ok(name) => match name {
                 enums::Connector::Aci => Ok(Box::new(&connector::Aci)),
                 enums::Connector::Adyen => Ok(Box::new(&connector::Adyen)),
+                enums::Connector::Affirm => Ok(Box::new(&connector::Affirm)),
+                enums::Connector::Afterpay => Ok(Box::new(&connector::Afterpay)),
                 enums::Connector::Airwallex => Ok(Box::new(&connector::Airwallex)),
                 enums::Connector::Authorizedotnet => Ok(Box::new(&connector::Authorizedotnet)),
                 enums::Connector::Bambora => Ok(Box::new(&connector::Bambora)),
@@ -368,6 +372,7 @@ impl ConnectorData {
                 enums::Connector::Opennode => Ok(Box::new(&connector::Opennode)),
                 // "payeezy" => Ok(Box::new(&connector::Payeezy)), As psync and rsync are not supported by this connector, it is added as template code for future usage
                 enums::Connector::Payme => Ok(Box::new(&connector::Payme)),
+                enums::Connector::Payoneer => Ok(Box::new(&connector::Payoneer)),
                 enums::Connector::Payu => Ok(Box::new(&connector::Payu)),
                 enums::Connector::Placetopay => Ok(Box::new(&connector::Placetopay)),
                 enums::Connector::Powertranz => Ok(Box::new(&connector::Powertranz)),
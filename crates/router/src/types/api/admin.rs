@@ -3,7 +3,8 @@ pub use api_models::admin::{
     MerchantAccountDeleteResponse, MerchantAccountResponse, MerchantAccountUpdate,
     MerchantConnectorCreate, MerchantConnectorDeleteResponse, MerchantConnectorDetails,
     MerchantConnectorDetailsWrap, MerchantConnectorId, MerchantConnectorResponse, MerchantDetails,
-    MerchantId, PaymentMethodsEnabled, ToggleKVRequest, ToggleKVResponse, WebhookDetails,
+    MerchantFeature, MerchantFeatureEntitlements, MerchantId, PaymentMethodsEnabled,
+    ToggleKVRequest, ToggleKVResponse, WebhookDetails,
 };
 use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
@@ -20,6 +21,7 @@ impl TryFrom<domain::MerchantAccount> for MerchantAccountResponse {
         let primary_business_details: Vec<api_models::admin::PrimaryBusinessDetails> = item
             .primary_business_details
             .parse_value("primary_business_details")?;
+        let feature_entitlements = item.get_feature_entitlements()?;
 
         Ok(Self {
             merchant_id: item.merchant_id,
@@ -45,6 +47,7 @@ impl TryFrom<domain::MerchantAccount> for MerchantAccountResponse {
             is_recon_enabled: item.is_recon_enabled,
             default_profile: item.default_profile,
             recon_status: item.recon_status,
+            feature_entitlements,
         })
     }
 }
@@ -79,6 +82,26 @@ impl ForeignTryFrom<storage::business_profile::BusinessProfile> for BusinessProf
                     authentication_connector_details.parse_value("AuthenticationDetails")
                 })
                 .transpose()?,
+            dunning_config: item
+                .dunning_config
+                .map(|dunning_config| dunning_config.parse_value("DunningConfig"))
+                .transpose()?,
+            is_webhook_endpoint_disabled: item.is_webhook_endpoint_disabled.unwrap_or(false),
+            is_network_tokenization_enabled: item.is_network_tokenization_enabled,
+            is_tax_calculation_enabled: item.is_tax_calculation_enabled,
+            requires_cvc_for_saved_cards: item.requires_cvc_for_saved_cards,
+            auto_void_after_in_hours: item
+                .auto_void_after_in_hours
+                .and_then(|hours| u16::try_from(hours).ok()),
+            abandoned_payment_config: item
+                .abandoned_payment_config
+                .map(|abandoned_payment_config| {
+                    abandoned_payment_config.parse_value("AbandonedPaymentConfig")
+                })
+                .transpose()?,
+            max_auto_retries: item
+                .max_auto_retries
+                .and_then(|retries| u8::try_from(retries).ok()),
         })
     }
 }
@@ -177,6 +200,31 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
                 })?,
             is_extended_card_info_enabled: None,
             extended_card_info_config: None,
+            dunning_config: request
+                .dunning_config
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "dunning_config",
+                })?,
+            is_webhook_endpoint_disabled: None,
+            is_network_tokenization_enabled: request
+                .is_network_tokenization_enabled
+                .unwrap_or(false),
+            is_tax_calculation_enabled: request.is_tax_calculation_enabled.unwrap_or(false),
+            requires_cvc_for_saved_cards: request.requires_cvc_for_saved_cards,
+            auto_void_after_in_hours: request.auto_void_after_in_hours.map(i32::from),
+            abandoned_payment_config: request
+                .abandoned_payment_config
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "abandoned_payment_config",
+                })?,
+            max_auto_retries: request.max_auto_retries.map(i16::from),
+            wallet_certificates: None,
         })
     }
 }
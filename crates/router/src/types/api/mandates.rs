@@ -105,6 +105,9 @@ impl MandateResponseExt for MandateResponse {
             payment_method: pm.to_string(),
             payment_method_type,
             payment_method_id: mandate.payment_method_id,
+            pre_notification_days: mandate
+                .pre_notification_days
+                .and_then(|days| u8::try_from(days).ok()),
         })
     }
 }
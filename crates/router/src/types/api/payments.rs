@@ -1,17 +1,19 @@
 pub use api_models::payments::{
     AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse, Card,
-    CryptoData, CustomerAcceptance, HeaderPayload, MandateAmountData, MandateData,
-    MandateTransactionType, MandateType, MandateValidationFields, NextActionType, OnlineMandate,
-    PayLaterData, PaymentIdType, PaymentListConstraints, PaymentListFilterConstraints,
-    PaymentListFilters, PaymentListFiltersV2, PaymentListResponse, PaymentListResponseV2,
-    PaymentMethodData, PaymentMethodDataRequest, PaymentMethodDataResponse, PaymentOp,
-    PaymentRetrieveBody, PaymentRetrieveBodyWithCredentials, PaymentsApproveRequest,
-    PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsExternalAuthenticationRequest,
-    PaymentsIncrementalAuthorizationRequest, PaymentsRedirectRequest, PaymentsRedirectionResponse,
-    PaymentsRejectRequest, PaymentsRequest, PaymentsResponse, PaymentsResponseForm,
-    PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse, PaymentsStartRequest,
-    PgRedirectResponse, PhoneDetails, RedirectionResponse, SessionToken, TimeRange, UrlDetails,
-    VerifyRequest, VerifyResponse, WalletData,
+    ClickToPayEnrollmentRequest, ClickToPayIdentityLookupRequest, CryptoData, CustomerAcceptance,
+    HeaderPayload, MandateAmountData, MandateData, MandateTransactionType, MandateType,
+    MandateValidationFields, NextActionType, OnlineMandate, PayLaterData, PaymentIdType,
+    PaymentListConstraints, PaymentListFilterConstraints, PaymentListFilters, PaymentListFiltersV2,
+    PaymentListResponse, PaymentListResponseV2, PaymentMethodData, PaymentMethodDataRequest,
+    PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody, PaymentRetrieveBodyWithCredentials,
+    PaymentsApproveRequest, PaymentsCancelRequest, PaymentsCaptureRequest,
+    PaymentsExternalAuthenticationRequest, PaymentsIncrementalAuthorizationRequest,
+    PaymentsRedirectRequest, PaymentsRedirectionResponse, PaymentsRejectRequest, PaymentsRequest,
+    PaymentsResponse, PaymentsResponseForm, PaymentsRetrieveRequest, PaymentsRetryRequest,
+    PaymentsSessionRequest, PaymentsSessionResponse, PaymentsStartRequest,
+    PaymentsTagsUpdateRequest, PaymentsTagsUpdateResponse, PaymentsTaxCalculationRequest,
+    PaymentsVerifyMicroDepositsRequest, PgRedirectResponse, PhoneDetails, RedirectionResponse,
+    SessionToken, TimeRange, UrlDetails, VerifyRequest, VerifyResponse, WalletData,
 };
 use error_stack::ResultExt;
 
@@ -86,6 +88,9 @@ pub struct PreProcessing;
 #[derive(Debug, Clone)]
 pub struct IncrementalAuthorization;
 
+#[derive(Debug, Clone)]
+pub struct VerifyMicroDeposits;
+
 pub trait PaymentIdTypeExt {
     fn get_payment_intent_id(&self) -> errors::CustomResult<String, errors::ValidationError>;
 }
@@ -178,6 +183,15 @@ pub trait PaymentIncrementalAuthorization:
 {
 }
 
+pub trait PaymentVerifyMicroDeposits:
+    api::ConnectorIntegration<
+    VerifyMicroDeposits,
+    types::PaymentsVerifyMicroDepositsData,
+    types::PaymentsResponseData,
+>
+{
+}
+
 pub trait PaymentsCompleteAuthorize:
     api::ConnectorIntegration<
     CompleteAuthorize,
@@ -230,6 +244,7 @@ pub trait Payment:
     + PaymentsPreProcessing
     + ConnectorCustomer
     + PaymentIncrementalAuthorization
+    + PaymentVerifyMicroDeposits
 {
 }
 
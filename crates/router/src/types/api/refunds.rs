@@ -15,7 +15,9 @@ impl ForeignFrom<storage_enums::RefundStatus> for RefundStatus {
             storage_enums::RefundStatus::Failure
             | storage_enums::RefundStatus::TransactionFailure => Self::Failed,
             storage_enums::RefundStatus::ManualReview => Self::Review,
-            storage_enums::RefundStatus::Pending => Self::Pending,
+            storage_enums::RefundStatus::Pending | storage_enums::RefundStatus::PendingEnqueue => {
+                Self::Pending
+            }
             storage_enums::RefundStatus::Success => Self::Succeeded,
         }
     }
@@ -50,8 +50,15 @@ impl VerifyConnectorData {
             related_transaction_id: None,
             statement_descriptor_suffix: None,
             request_incremental_authorization: false,
+            request_overcapture: false,
+            request_extended_authorization: false,
+            split_payments: None,
+            payment_channel: None,
+            l2_l3_data: None,
+            installment_tenure: None,
             authentication_data: None,
             customer_acceptance: None,
+            preferred_card_network: None,
         }
     }
 
@@ -100,6 +107,7 @@ impl VerifyConnectorData {
             payment_method_balance: None,
             connector_http_status_code: None,
             external_latency: None,
+            connector_request_id: None,
             apple_pay_flow: None,
             frm_metadata: None,
             refund_id: None,
@@ -13,7 +13,7 @@ use masking::{PeekInterface, Secret};
 use router_env::logger;
 
 use crate::{
-    errors::{CustomResult, ValidationError},
+    errors::{CustomResult, ParsingError, ValidationError},
     types::domain::types::{self, AsyncLift},
 };
 
@@ -46,6 +46,7 @@ pub struct MerchantAccount {
     pub default_profile: Option<String>,
     pub recon_status: diesel_models::enums::ReconStatus,
     pub payment_link_config: Option<serde_json::Value>,
+    pub feature_entitlements: Option<serde_json::Value>,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -78,6 +79,9 @@ pub enum MerchantAccountUpdate {
     ReconUpdate {
         recon_status: diesel_models::enums::ReconStatus,
     },
+    FeatureEntitlementsUpdate {
+        feature_entitlements: serde_json::Value,
+    },
     UnsetDefaultProfile,
     ModifiedAtUpdate,
 }
@@ -137,6 +141,13 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status,
                 ..Default::default()
             },
+            MerchantAccountUpdate::FeatureEntitlementsUpdate {
+                feature_entitlements,
+            } => Self {
+                feature_entitlements: Some(feature_entitlements),
+                modified_at: Some(date_time::now()),
+                ..Default::default()
+            },
             MerchantAccountUpdate::UnsetDefaultProfile => Self {
                 default_profile: Some(None),
                 ..Default::default()
@@ -184,6 +195,7 @@ impl super::behaviour::Conversion for MerchantAccount {
             default_profile: self.default_profile,
             recon_status: self.recon_status,
             payment_link_config: self.payment_link_config,
+            feature_entitlements: self.feature_entitlements,
         })
     }
 
@@ -229,6 +241,7 @@ impl super::behaviour::Conversion for MerchantAccount {
                 default_profile: item.default_profile,
                 recon_status: item.recon_status,
                 payment_link_config: item.payment_link_config,
+                feature_entitlements: item.feature_entitlements,
             })
         }
         .await
@@ -265,6 +278,7 @@ impl super::behaviour::Conversion for MerchantAccount {
             default_profile: self.default_profile,
             recon_status: self.recon_status,
             payment_link_config: self.payment_link_config,
+            feature_entitlements: self.feature_entitlements,
         })
     }
 }
@@ -280,4 +294,16 @@ impl MerchantAccount {
             });
         metadata.and_then(|a| a.compatible_connector)
     }
+
+    pub fn get_feature_entitlements(
+        &self,
+    ) -> CustomResult<api_models::admin::MerchantFeatureEntitlements, ParsingError> {
+        self.feature_entitlements
+            .clone()
+            .map(|feature_entitlements| {
+                feature_entitlements.parse_value("MerchantFeatureEntitlements")
+            })
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
 }
@@ -17,6 +17,7 @@ pub struct MerchantKeyStore {
     pub key: Encryptable<Secret<Vec<u8>>>,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub external_key_uri: Option<String>,
 }
 
 #[async_trait::async_trait]
@@ -28,6 +29,7 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             key: self.key.into(),
             merchant_id: self.merchant_id,
             created_at: self.created_at,
+            external_key_uri: self.external_key_uri,
         })
     }
 
@@ -46,6 +48,7 @@ impl super::behaviour::Conversion for MerchantKeyStore {
                 })?,
             merchant_id: item.merchant_id,
             created_at: item.created_at,
+            external_key_uri: item.external_key_uri,
         })
     }
 
@@ -54,6 +57,7 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             merchant_id: self.merchant_id,
             key: self.key.into(),
             created_at: date_time::now(),
+            external_key_uri: self.external_key_uri,
         })
     }
 }
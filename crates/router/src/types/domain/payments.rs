@@ -19,6 +19,7 @@ pub enum PaymentMethodData {
     Upi(UpiData),
     Voucher(VoucherData),
     GiftCard(Box<GiftCardData>),
+    RealTimePayment(Box<RealTimePaymentData>),
     CardToken(CardToken),
 }
 
@@ -37,6 +38,7 @@ impl PaymentMethodData {
             Self::Upi(_) => Some(common_enums::PaymentMethod::Upi),
             Self::Voucher(_) => Some(common_enums::PaymentMethod::Voucher),
             Self::GiftCard(_) => Some(common_enums::PaymentMethod::GiftCard),
+            Self::RealTimePayment(_) => Some(common_enums::PaymentMethod::RealTimePayment),
             Self::CardToken(_) | Self::MandatePayment => None,
         }
     }
@@ -98,6 +100,7 @@ pub enum WalletData {
     PaypalRedirect(PaypalRedirection),
     PaypalSdk(PayPalWalletData),
     SamsungPay(Box<SamsungPayWalletData>),
+    SkrillRedirect {},
     TwintRedirect {},
     VippsRedirect {},
     TouchNGoRedirect(Box<TouchNGoRedirection>),
@@ -105,13 +108,40 @@ pub enum WalletData {
     WeChatPayQr(Box<WeChatPayQr>),
     CashappQr(Box<CashappQr>),
     SwishQr(SwishQrData),
+    ClickToPay(Box<ClickToPayWalletData>),
 }
 
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize)]
 
 pub struct SamsungPayWalletData {
-    /// The encrypted payment token from Samsung
-    pub token: Secret<String>,
+    /// The payment credential returned by the Samsung Pay SDK
+    pub payment_credential: SamsungPayWalletCredentials,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct SamsungPayWalletCredentials {
+    pub method: Option<String>,
+    pub recurring_payment: Option<bool>,
+    pub card_brand: SamsungPayCardBrand,
+    pub card_last_four_digits: String,
+    pub token_data: SamsungPayTokenData,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct SamsungPayTokenData {
+    pub three_ds_type: Option<String>,
+    pub version: String,
+    /// The payment token, a JWE encrypted with the public key of the merchant
+    pub data: Secret<String>,
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug, serde::Deserialize, serde::Serialize)]
+pub enum SamsungPayCardBrand {
+    Visa,
+    MasterCard,
+    Amex,
+    Discover,
+    Unknown,
 }
 
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -205,6 +235,18 @@ pub struct TouchNGoRedirection {}
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct SwishQrData {}
 
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ClickToPayWalletData {
+    /// The card network of the card the customer checked out with
+    pub card_network: common_enums::CardNetwork,
+    /// Identifier of the Click to Pay checkout
+    pub src_correlation_id: String,
+    /// Identifier of the card in the Click to Pay profile of the customer
+    pub src_digital_card_id: String,
+    /// Reference to the network token of the card
+    pub src_token_reference: Option<String>,
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct GpayTokenizationData {
     /// The type of the token
@@ -368,6 +410,14 @@ pub struct GiftCardDetails {
     pub cvc: Secret<String>,
 }
 
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RealTimePaymentData {
+    PayNow {},
+    PromptPay {},
+    UpiQr {},
+}
+
 #[derive(Eq, PartialEq, Debug, serde::Deserialize, serde::Serialize, Clone, Default)]
 #[serde(rename_all = "snake_case")]
 pub struct CardToken {
@@ -541,6 +591,9 @@ impl From<api_models::payments::PaymentMethodData> for PaymentMethodData {
             api_models::payments::PaymentMethodData::GiftCard(gift_card) => {
                 Self::GiftCard(Box::new(From::from(*gift_card)))
             }
+            api_models::payments::PaymentMethodData::RealTimePayment(real_time_payment) => {
+                Self::RealTimePayment(Box::new(From::from(*real_time_payment)))
+            }
             api_models::payments::PaymentMethodData::CardToken(card_token) => {
                 Self::CardToken(From::from(card_token))
             }
@@ -648,10 +701,9 @@ impl From<api_models::payments::WalletData> for WalletData {
                 })
             }
             api_models::payments::WalletData::SamsungPay(samsung_pay_data) => {
-                Self::SamsungPay(Box::new(SamsungPayWalletData {
-                    token: samsung_pay_data.token,
-                }))
+                Self::SamsungPay(Box::new(SamsungPayWalletData::from(*samsung_pay_data)))
             }
+            api_models::payments::WalletData::SkrillRedirect {} => Self::SkrillRedirect {},
             api_models::payments::WalletData::TwintRedirect {} => Self::TwintRedirect {},
             api_models::payments::WalletData::VippsRedirect {} => Self::VippsRedirect {},
             api_models::payments::WalletData::TouchNGoRedirect(_) => {
@@ -667,6 +719,14 @@ impl From<api_models::payments::WalletData> for WalletData {
                 Self::CashappQr(Box::new(CashappQr {}))
             }
             api_models::payments::WalletData::SwishQr(_) => Self::SwishQr(SwishQrData {}),
+            api_models::payments::WalletData::ClickToPay(click_to_pay_data) => {
+                Self::ClickToPay(Box::new(ClickToPayWalletData {
+                    card_network: click_to_pay_data.card_network,
+                    src_correlation_id: click_to_pay_data.src_correlation_id,
+                    src_digital_card_id: click_to_pay_data.src_digital_card_id,
+                    src_token_reference: click_to_pay_data.src_token_reference,
+                }))
+            }
         }
     }
 }
@@ -688,6 +748,37 @@ impl From<api_models::payments::GooglePayWalletData> for GooglePayWalletData {
     }
 }
 
+impl From<api_models::payments::SamsungPayWalletData> for SamsungPayWalletData {
+    fn from(value: api_models::payments::SamsungPayWalletData) -> Self {
+        let credentials = value.payment_credential;
+        Self {
+            payment_credential: SamsungPayWalletCredentials {
+                method: credentials.method,
+                recurring_payment: credentials.recurring_payment,
+                card_brand: SamsungPayCardBrand::from(credentials.card_brand),
+                card_last_four_digits: credentials.card_last_four_digits,
+                token_data: SamsungPayTokenData {
+                    three_ds_type: credentials.token_data.three_ds_type,
+                    version: credentials.token_data.version,
+                    data: credentials.token_data.data,
+                },
+            },
+        }
+    }
+}
+
+impl From<api_models::payments::SamsungPayCardBrand> for SamsungPayCardBrand {
+    fn from(value: api_models::payments::SamsungPayCardBrand) -> Self {
+        match value {
+            api_models::payments::SamsungPayCardBrand::Visa => Self::Visa,
+            api_models::payments::SamsungPayCardBrand::MasterCard => Self::MasterCard,
+            api_models::payments::SamsungPayCardBrand::Amex => Self::Amex,
+            api_models::payments::SamsungPayCardBrand::Discover => Self::Discover,
+            api_models::payments::SamsungPayCardBrand::Unknown => Self::Unknown,
+        }
+    }
+}
+
 impl From<api_models::payments::ApplePayWalletData> for ApplePayWalletData {
     fn from(value: api_models::payments::ApplePayWalletData) -> Self {
         Self {
@@ -882,6 +973,16 @@ impl From<api_models::payments::GiftCardData> for GiftCardData {
     }
 }
 
+impl From<api_models::payments::RealTimePaymentData> for RealTimePaymentData {
+    fn from(value: api_models::payments::RealTimePaymentData) -> Self {
+        match value {
+            api_models::payments::RealTimePaymentData::PayNow {} => Self::PayNow {},
+            api_models::payments::RealTimePaymentData::PromptPay {} => Self::PromptPay {},
+            api_models::payments::RealTimePaymentData::UpiQr {} => Self::UpiQr {},
+        }
+    }
+}
+
 impl From<api_models::payments::CardToken> for CardToken {
     fn from(value: api_models::payments::CardToken) -> Self {
         let api_models::payments::CardToken {
@@ -356,6 +356,7 @@ impl NewUserMerchant {
                 payment_response_hash_key: None,
                 enable_payment_response_hash: None,
                 redirect_to_merchant_with_http_post: None,
+                feature_entitlements: None,
             },
         ))
         .await
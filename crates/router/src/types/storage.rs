@@ -7,6 +7,7 @@ pub mod blocklist_fingerprint;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
+pub mod card_data_redaction;
 pub mod cards_info;
 pub mod configs;
 pub mod customers;
@@ -34,6 +35,7 @@ pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod usage_metering;
 pub mod user;
 pub mod user_role;
 
@@ -56,12 +58,12 @@ pub use scheduler::db::process_tracker;
 
 pub use self::{
     address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
-    blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
-    configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*, user::*,
-    user_role::*,
+    blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*,
+    card_data_redaction::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*,
+    dispute::*, ephemeral_key::*, events::*, file::*, fraud_check::*, gsm::*, locker_mock_up::*,
+    mandate::*, merchant_account::*, merchant_connector_account::*, merchant_key_store::*,
+    payment_link::*, payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
+    routing_algorithm::*, usage_metering::*, user::*, user_role::*,
 };
 use crate::types::api::routing;
 
@@ -1,3 +1,4 @@
 pub use diesel_models::business_profile::{
-    BusinessProfile, BusinessProfileNew, BusinessProfileUpdate, BusinessProfileUpdateInternal,
+    ApplepayDomainRenewalTrackingData, BusinessProfile, BusinessProfileNew, BusinessProfileUpdate,
+    BusinessProfileUpdateInternal,
 };
@@ -0,0 +1,3 @@
+pub use diesel_models::card_data_redaction::{
+    RedactionCandidate, RedactionColumnType, RedactionTarget,
+};
@@ -0,0 +1 @@
+pub use diesel_models::usage_metering::{UsageMetering, UsageMeteringNew};
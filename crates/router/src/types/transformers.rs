@@ -21,7 +21,7 @@ use crate::{
     services::authentication::get_header_value_by_key,
     types::{
         api::{self as api_types, routing as routing_types},
-        storage,
+        storage::{self, payment_attempt::PaymentAttemptExt},
     },
 };
 
@@ -209,6 +209,8 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
         Ok(match from {
             api_enums::Connector::Aci => Self::Aci,
             api_enums::Connector::Adyen => Self::Adyen,
+            api_enums::Connector::Affirm => Self::Affirm,
+            api_enums::Connector::Afterpay => Self::Afterpay,
             api_enums::Connector::Airwallex => Self::Airwallex,
             api_enums::Connector::Authorizedotnet => Self::Authorizedotnet,
             api_enums::Connector::Bambora => Self::Bambora,
@@ -246,6 +248,7 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
             api_enums::Connector::Nuvei => Self::Nuvei,
             api_enums::Connector::Opennode => Self::Opennode,
             api_enums::Connector::Payme => Self::Payme,
+            api_enums::Connector::Payoneer => Self::Payoneer,
             api_enums::Connector::Paypal => Self::Paypal,
             api_enums::Connector::Payu => Self::Payu,
             api_models::enums::Connector::Placetopay => Self::Placetopay,
@@ -396,6 +399,7 @@ impl ForeignFrom<api_enums::IntentStatus> for Option<storage_enums::EventType> {
                 Some(storage_enums::EventType::ActionRequired)
             }
             api_enums::IntentStatus::Cancelled => Some(storage_enums::EventType::PaymentCancelled),
+            api_enums::IntentStatus::Expired => Some(storage_enums::EventType::PaymentExpired),
             api_enums::IntentStatus::PartiallyCaptured
             | api_enums::IntentStatus::PartiallyCapturedAndCapturable => {
                 Some(storage_enums::EventType::PaymentCaptured)
@@ -430,6 +434,9 @@ impl ForeignFrom<api_enums::PaymentMethodType> for api_enums::PaymentMethod {
             | api_enums::PaymentMethodType::Gcash
             | api_enums::PaymentMethodType::Momo
             | api_enums::PaymentMethodType::Cashapp
+            | api_enums::PaymentMethodType::ClickToPay
+            | api_enums::PaymentMethodType::Skrill
+            | api_enums::PaymentMethodType::Payoneer
             | api_enums::PaymentMethodType::KakaoPay
             | api_enums::PaymentMethodType::Venmo => Self::Wallet,
             api_enums::PaymentMethodType::Affirm
@@ -495,6 +502,9 @@ impl ForeignFrom<api_enums::PaymentMethodType> for api_enums::PaymentMethod {
             api_enums::PaymentMethodType::Givex | api_enums::PaymentMethodType::PaySafeCard => {
                 Self::GiftCard
             }
+            api_enums::PaymentMethodType::PayNow
+            | api_enums::PaymentMethodType::PromptPay
+            | api_enums::PaymentMethodType::UpiQr => Self::RealTimePayment,
             api_enums::PaymentMethodType::Benefit
             | api_enums::PaymentMethodType::Knet
             | api_enums::PaymentMethodType::MomoAtm
@@ -521,6 +531,9 @@ impl ForeignTryFrom<api_models::payments::PaymentMethodData> for api_enums::Paym
             api_models::payments::PaymentMethodData::Upi(..) => Ok(Self::Upi),
             api_models::payments::PaymentMethodData::Voucher(..) => Ok(Self::Voucher),
             api_models::payments::PaymentMethodData::GiftCard(..) => Ok(Self::GiftCard),
+            api_models::payments::PaymentMethodData::RealTimePayment(..) => {
+                Ok(Self::RealTimePayment)
+            }
             api_models::payments::PaymentMethodData::CardRedirect(..) => Ok(Self::CardRedirect),
             api_models::payments::PaymentMethodData::MandatePayment => {
                 Err(errors::ApiErrorResponse::InvalidRequestData {
@@ -538,6 +551,7 @@ impl ForeignFrom<storage_enums::RefundStatus> for Option<storage_enums::EventTyp
             storage_enums::RefundStatus::Failure => Some(storage_enums::EventType::RefundFailed),
             api_enums::RefundStatus::ManualReview
             | api_enums::RefundStatus::Pending
+            | api_enums::RefundStatus::PendingEnqueue
             | api_enums::RefundStatus::TransactionFailure => None,
         }
     }
@@ -599,6 +613,27 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enum
     }
 }
 
+#[cfg(feature = "payouts")]
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::PayoutStatus {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::PayoutSuccess => Ok(Self::Success),
+            api_models::webhooks::IncomingWebhookEvent::PayoutFailure => Ok(Self::Failed),
+            api_models::webhooks::IncomingWebhookEvent::PayoutCancelled => Ok(Self::Cancelled),
+            api_models::webhooks::IncomingWebhookEvent::PayoutProcessing => Ok(Self::Pending),
+            api_models::webhooks::IncomingWebhookEvent::PayoutExpired => Ok(Self::Expired),
+            api_models::webhooks::IncomingWebhookEvent::PayoutReversed => Ok(Self::Reversed),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
 impl ForeignFrom<storage::Config> for api_types::Config {
     fn foreign_from(config: storage::Config) -> Self {
         Self {
@@ -838,6 +873,7 @@ impl ForeignFrom<diesel_models::cards_info::CardInfo> for api_models::cards_info
             card_network: item.card_network.map(|x| x.to_string()),
             card_issuer: item.card_issuer,
             card_issuing_country: item.card_issuing_country,
+            co_badged_card_networks: None,
         }
     }
 }
@@ -904,6 +940,7 @@ impl TryFrom<domain::MerchantConnectorAccount> for api_models::admin::MerchantCo
 
 impl ForeignFrom<storage::PaymentAttempt> for api_models::payments::PaymentAttemptResponse {
     fn foreign_from(payment_attempt: storage::PaymentAttempt) -> Self {
+        let surcharge_details = payment_attempt.get_surcharge_details();
         Self {
             attempt_id: payment_attempt.attempt_id,
             status: payment_attempt.status,
@@ -925,6 +962,8 @@ impl ForeignFrom<storage::PaymentAttempt> for api_models::payments::PaymentAttem
             reference_id: payment_attempt.connector_response_reference_id,
             unified_code: payment_attempt.unified_code,
             unified_message: payment_attempt.unified_message,
+            connector_request_id: payment_attempt.connector_request_id,
+            surcharge_details,
         }
     }
 }
@@ -977,6 +1016,7 @@ impl ForeignFrom<api_models::payouts::Wallet> for api_enums::PaymentMethodType {
         match value {
             api_models::payouts::Wallet::Paypal(_) => Self::Paypal,
             api_models::payouts::Wallet::Venmo(_) => Self::Venmo,
+            api_models::payouts::Wallet::Payoneer(_) => Self::Payoneer,
         }
     }
 }
@@ -1149,6 +1189,7 @@ impl ForeignFrom<gsm_api_types::GsmCreateRequest> for storage::GatewayStatusMapp
             step_up_possible: value.step_up_possible,
             unified_code: value.unified_code,
             unified_message: value.unified_message,
+            step_down_possible: value.step_down_possible,
         }
     }
 }
@@ -1167,6 +1208,7 @@ impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
             step_up_possible: value.step_up_possible,
             unified_code: value.unified_code,
             unified_message: value.unified_message,
+            step_down_possible: value.step_down_possible,
         }
     }
 }
@@ -7,7 +7,7 @@ use error_stack::ResultExt;
 use masking::PeekInterface;
 use once_cell::sync::Lazy;
 use redis_interface::DelReply;
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use strum::IntoEnumIterator;
 use tokio::{sync::RwLock, time::sleep};
 
@@ -540,3 +540,164 @@ pub async fn convert_currency(
         currency: to_currency.to_string(),
     })
 }
+
+pub async fn localize_amount(
+    state: AppState,
+    amount: i64,
+    currency: enums::Currency,
+    country: enums::CountryAlpha2,
+) -> CustomResult<api_models::currency::AmountLocalizationResponse, ForexCacheError> {
+    let amount_localization = &state.conf.amount_localization;
+    let mut presentment_currencies = amount_localization
+        .countries
+        .get(&country)
+        .map(|country_config| {
+            country_config
+                .currencies
+                .iter()
+                .copied()
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    // The currencies are configured as a set, they are sorted for the options to be listed in a
+    // stable order
+    presentment_currencies.sort_by_key(|presentment_currency| presentment_currency.iso_4217());
+
+    let rates = if presentment_currencies
+        .iter()
+        .any(|presentment_currency| *presentment_currency != currency)
+    {
+        let forex_api = state.conf.forex_api.get_inner();
+        Some(
+            get_forex_rates(
+                &state,
+                forex_api.call_delay,
+                forex_api.local_fetch_retry_delay,
+                forex_api.local_fetch_retry_count,
+            )
+            .await
+            .change_context(ForexCacheError::ApiError)?,
+        )
+    } else {
+        None
+    };
+
+    let presentment_options = presentment_currencies
+        .into_iter()
+        .map(|presentment_currency| {
+            let (localized_amount, markup_in_basis_points, charm_priced) =
+                match rates.as_ref().filter(|_| presentment_currency != currency) {
+                    // The amount is presented as it is in its own currency
+                    None => (amount, 0, false),
+                    Some(rates) => {
+                        let converted_amount = currency_conversion::conversion::convert(
+                            &rates.data,
+                            currency,
+                            presentment_currency,
+                            amount,
+                        )
+                        .change_context(ForexCacheError::ConversionError)?;
+                        let localized_amount = get_localized_amount(
+                            converted_amount,
+                            presentment_currency,
+                            amount_localization.markup_in_basis_points,
+                            amount_localization.charm_pricing,
+                        )
+                        .ok_or(ForexCacheError::ConversionError)?;
+                        (
+                            localized_amount,
+                            amount_localization.markup_in_basis_points,
+                            amount_localization.charm_pricing,
+                        )
+                    }
+                };
+
+            Ok(api_models::currency::LocalizedAmount {
+                currency: presentment_currency,
+                amount: localized_amount,
+                display_amount: presentment_currency
+                    .to_currency_base_unit(localized_amount)
+                    .change_context(ForexCacheError::ConversionError)?,
+                markup_in_basis_points,
+                charm_priced,
+            })
+        })
+        .collect::<CustomResult<Vec<_>, ForexCacheError>>()?;
+
+    Ok(api_models::currency::AmountLocalizationResponse {
+        amount,
+        currency,
+        country,
+        presentment_options,
+    })
+}
+
+/// The amount converted to a currency, in the base unit of the currency, is marked up and then
+/// rounded up to the lowest denomination of the currency, so that the localized amount is never
+/// below the converted amount
+fn get_localized_amount(
+    converted_amount: Decimal,
+    currency: enums::Currency,
+    markup_in_basis_points: u16,
+    charm_pricing: bool,
+) -> Option<i64> {
+    let marked_up_amount = converted_amount
+        .checked_mul(Decimal::from(10_000 + u32::from(markup_in_basis_points)))?
+        .checked_div(Decimal::from(10_000))?;
+    let localized_amount = marked_up_amount
+        .checked_mul(Decimal::from(10_i64.checked_pow(u32::from(
+            currency.number_of_digits_after_decimal_point(),
+        ))?))?
+        .ceil()
+        .to_i64()?;
+
+    Some(if charm_pricing {
+        get_charm_price(localized_amount, currency)
+    } else {
+        localized_amount
+    })
+}
+
+/// Round an amount, in the lowest denomination of its currency, up to the closest charm price,
+/// which is one lowest denomination below a whole amount of the currency, like 12.99. The amounts
+/// of the currencies without a minor unit are rounded up below the next hundred, like 1299 yen.
+fn get_charm_price(amount: i64, currency: enums::Currency) -> i64 {
+    let step = 10_i64.pow(u32::from(
+        currency.number_of_digits_after_decimal_point().max(2),
+    ));
+    (amount / step)
+        .saturating_add(1)
+        .saturating_mul(step)
+        .saturating_sub(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_charm_price() {
+        assert_eq!(get_charm_price(1237, enums::Currency::EUR), 1299);
+        assert_eq!(get_charm_price(1299, enums::Currency::EUR), 1299);
+        assert_eq!(get_charm_price(1300, enums::Currency::EUR), 1399);
+        assert_eq!(get_charm_price(1234, enums::Currency::JPY), 1299);
+        assert_eq!(get_charm_price(12345, enums::Currency::KWD), 12999);
+    }
+
+    #[test]
+    fn test_get_localized_amount() {
+        // 12.34 EUR marked up by 2.5% is 12.6485 EUR, which is rounded up to 12.65 EUR
+        assert_eq!(
+            get_localized_amount(Decimal::new(1234, 2), enums::Currency::EUR, 250, false),
+            Some(1265)
+        );
+        assert_eq!(
+            get_localized_amount(Decimal::new(1234, 2), enums::Currency::EUR, 250, true),
+            Some(1299)
+        );
+        assert_eq!(
+            get_localized_amount(Decimal::new(1234, 0), enums::Currency::JPY, 0, false),
+            Some(1234)
+        );
+    }
+}
@@ -218,6 +218,15 @@ pub async fn generate_sample_data(
             fingerprint_id: None,
             session_expiry: Some(session_expiry),
             request_external_three_ds_authentication: None,
+            request_overcapture: None,
+            request_extended_authorization: None,
+            order_tax_amount: None,
+            split_payments: None,
+            payment_channel: None,
+            l2_l3_data: None,
+            tags: None,
+            capture_adjustment_reason: None,
+            installment_tenure: None,
         };
         let payment_attempt = PaymentAttemptBatchNew {
             attempt_id: attempt_id.clone(),
@@ -294,6 +303,8 @@ pub async fn generate_sample_data(
                 profile_id: payment_intent.profile_id.clone(),
                 updated_by: merchant_from_db.storage_scheme.to_string(),
                 merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+                capture_id: None,
+                refund_reason_code: Some(common_enums::RefundReasonCode::RequestedByCustomer),
             })
         } else {
             None
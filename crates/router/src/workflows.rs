@@ -1,8 +1,220 @@
+pub mod abandoned_payment_notification;
 #[cfg(feature = "email")]
 pub mod api_key_expiry;
+#[cfg(feature = "olap")]
+pub mod applepay_domain_renewal;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod auto_void;
+#[cfg(feature = "olap")]
+pub mod connector_migration;
+#[cfg(feature = "olap")]
+pub mod connector_smoke_test;
+#[cfg(feature = "olap")]
+pub mod customer_data_export;
 pub mod outgoing_webhook_retry;
+pub mod payment_dunning;
+pub mod payment_intent_expiry;
 pub mod payment_sync;
 pub mod refund_router;
 pub mod tokenized_data;
+
+use std::sync::Arc;
+
+use common_utils::ext_traits::{OptionExt, StringExt};
+use diesel_models::process_tracker as storage;
+use error_stack::ResultExt;
+use scheduler::{
+    consumer::workflows::ProcessTrackerWorkflow, errors::ProcessTrackerError,
+    workflows::ProcessTrackerWorkflows, SchedulerAppState,
+};
+use tokio::sync::mpsc;
+
+use crate::{core::errors::CustomResult, logger, routes::AppState};
+
+#[derive(Debug, Copy, Clone)]
+pub struct WorkflowRunner;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflows<AppState> for WorkflowRunner {
+    async fn trigger_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> CustomResult<(), ProcessTrackerError> {
+        let runner = process
+            .runner
+            .clone()
+            .get_required_value("runner")
+            .change_context(ProcessTrackerError::MissingRequiredField)
+            .attach_printable("Missing runner field in process information")?;
+        let runner: storage::ProcessTrackerRunner = runner
+            .parse_enum("ProcessTrackerRunner")
+            .change_context(ProcessTrackerError::UnexpectedFlow)
+            .attach_printable("Failed to parse workflow runner name")?;
+
+        let get_operation = |runner: storage::ProcessTrackerRunner| -> CustomResult<
+            Box<dyn ProcessTrackerWorkflow<AppState>>,
+            ProcessTrackerError,
+        > {
+            match runner {
+                storage::ProcessTrackerRunner::PaymentsSyncWorkflow => {
+                    Ok(Box::new(payment_sync::PaymentsSyncWorkflow))
+                }
+                storage::ProcessTrackerRunner::RefundWorkflowRouter => {
+                    Ok(Box::new(refund_router::RefundWorkflowRouter))
+                }
+                storage::ProcessTrackerRunner::DeleteTokenizeDataWorkflow => {
+                    Ok(Box::new(tokenized_data::DeleteTokenizeDataWorkflow))
+                }
+                storage::ProcessTrackerRunner::ApiKeyExpiryWorkflow => {
+                    #[cfg(feature = "email")]
+                    {
+                        Ok(Box::new(api_key_expiry::ApiKeyExpiryWorkflow))
+                    }
+
+                    #[cfg(not(feature = "email"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run API key expiry workflow when email feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow => Ok(Box::new(
+                    outgoing_webhook_retry::OutgoingWebhookRetryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentDunningWorkflow => {
+                    Ok(Box::new(payment_dunning::PaymentDunningWorkflow))
+                }
+                storage::ProcessTrackerRunner::AutoVoidWorkflow => {
+                    Ok(Box::new(auto_void::AutoVoidWorkflow))
+                }
+                storage::ProcessTrackerRunner::PaymentIntentExpiryWorkflow => {
+                    Ok(Box::new(payment_intent_expiry::PaymentIntentExpiryWorkflow))
+                }
+                storage::ProcessTrackerRunner::AbandonedPaymentNotificationWorkflow => Ok(
+                    Box::new(abandoned_payment_notification::AbandonedPaymentNotificationWorkflow),
+                ),
+                storage::ProcessTrackerRunner::AttachPayoutAccountWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(
+                            attach_payout_account_workflow::AttachPayoutAccountWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(
+                            error_stack::report!(ProcessTrackerError::UnexpectedFlow),
+                        )
+                        .attach_printable(
+                            "Cannot run Stripe external account workflow when payouts feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::ConnectorSmokeTestWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(connector_smoke_test::ConnectorSmokeTestWorkflow))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run connector smoke test workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::ApplepayDomainRenewalWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            applepay_domain_renewal::ApplepayDomainRenewalWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run Apple Pay domain renewal workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::ConnectorMigrationWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(connector_migration::ConnectorMigrationWorkflow))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run connector migration workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::CustomerDataExportWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(customer_data_export::CustomerDataExportWorkflow))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run customer data export workflow when olap feature is disabled",
+                            )
+                    }
+                }
+            }
+        };
+
+        let operation = get_operation(runner)?;
+
+        let app_state = &state.clone();
+        let output = operation.execute_workflow(app_state, process.clone()).await;
+        match output {
+            Ok(_) => operation.success_handler(app_state, process).await,
+            Err(error) => match operation
+                .error_handler(app_state, process.clone(), error)
+                .await
+            {
+                Ok(_) => (),
+                Err(error) => {
+                    logger::error!(%error, "Failed while handling error");
+                    let status = state
+                        .get_db()
+                        .as_scheduler()
+                        .finish_process_with_business_status(process, "GLOBAL_FAILURE".to_string())
+                        .await;
+                    if let Err(err) = status {
+                        logger::error!(%err, "Failed while performing database operation: GLOBAL_FAILURE");
+                    }
+                }
+            },
+        };
+        Ok(())
+    }
+}
+
+/// Start the process tracker flow, executing the workflows with the [`WorkflowRunner`].
+pub async fn start_scheduler(
+    state: &AppState,
+    scheduler_flow: scheduler::SchedulerFlow,
+    channel: (mpsc::Sender<()>, mpsc::Receiver<()>),
+) -> CustomResult<(), ProcessTrackerError> {
+    let scheduler_settings = state
+        .conf
+        .scheduler
+        .clone()
+        .ok_or(ProcessTrackerError::ConfigurationError)?;
+    scheduler::start_process_tracker(
+        state,
+        scheduler_flow,
+        Arc::new(scheduler_settings),
+        channel,
+        WorkflowRunner {},
+    )
+    .await
+}
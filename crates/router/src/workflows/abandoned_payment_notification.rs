@@ -0,0 +1,141 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::{
+        payment_methods::Oss,
+        payments::{self as payment_flows, abandoned_payment, operations, payment_tasks},
+    },
+    errors,
+    routes::AppState,
+    services,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+};
+
+pub struct AbandonedPaymentNotificationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for AbandonedPaymentNotificationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: abandoned_payment::AbandonedPaymentTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AbandonedPaymentTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+        let business_profile = db
+            .find_business_profile_by_profile_id(&tracking_data.profile_id)
+            .await?;
+
+        let abandoned_payment_config =
+            abandoned_payment::get_abandoned_payment_config(&business_profile)?;
+
+        // The payment is notified once, and is not notified at all if it has been paid or
+        // cancelled since the notification was scheduled, or if the business profile no longer
+        // notifies the abandoned payments
+        db.as_scheduler()
+            .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+            .await?;
+        let Some(abandoned_payment_config) = abandoned_payment_config
+            .filter(|_| payment_tasks::is_awaiting_customer(payment_intent.status))
+        else {
+            return Ok(());
+        };
+
+        let request = api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(tracking_data.payment_id.clone()),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            force_sync: false,
+            ..Default::default()
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        let (payment_data, _, customer, _, _) = Box::pin(payment_flows::payments_operation_core::<
+            api::PSync,
+            _,
+            _,
+            _,
+            Oss,
+        >(
+            state,
+            state.get_req_state(),
+            merchant_account.clone(),
+            key_store.clone(),
+            operations::PaymentStatus,
+            request,
+            payment_flows::CallConnectorAction::Avoid,
+            services::AuthFlow::Merchant,
+            None,
+            api::HeaderPayload::default(),
+        ))
+        .await?;
+
+        #[cfg(feature = "email")]
+        if abandoned_payment_config.email_customer {
+            abandoned_payment::notify_customer(
+                state,
+                &merchant_account,
+                &payment_data.payment_intent,
+                customer,
+            )
+            .await
+            .map_err(|error| logger::warn!(abandoned_payment_email_error=?error))
+            .ok();
+        }
+        #[cfg(not(feature = "email"))]
+        if abandoned_payment_config.email_customer {
+            logger::info!(
+                customer_id=?customer.map(|customer| customer.customer_id),
+                "Abandoned payment email not sent as emails are not enabled"
+            );
+        }
+
+        payment_tasks::trigger_payment_event_webhook(
+            state,
+            merchant_account,
+            &key_store,
+            business_profile,
+            enums::EventType::PaymentAbandoned,
+            operations::PaymentStatus,
+            payment_data,
+        )
+        .await
+        .map_err(|error| logger::warn!(abandoned_payment_outgoing_webhook_error=?error))
+        .ok();
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,83 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::verification,
+    errors,
+    routes::AppState,
+    types::storage::{self, enums},
+};
+
+pub struct ApplepayDomainRenewalWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for ApplepayDomainRenewalWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::ApplepayDomainRenewalTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ApplepayDomainRenewalTrackingData")?;
+
+        let registrations = verification::register_domains_for_profile(
+            state,
+            &tracking_data.merchant_id,
+            &tracking_data.profile_id,
+            None,
+        )
+        .await?;
+
+        for registration in registrations
+            .iter()
+            .filter(|registration| registration.error_message.is_some())
+        {
+            logger::error!(
+                profile_id = %tracking_data.profile_id,
+                merchant_connector_id = %registration.merchant_connector_account_id,
+                error_message = ?registration.error_message,
+                "Failed to renew Apple Pay domains"
+            );
+        }
+
+        // Nothing is left to renew once Apple Pay is no longer configured for the business profile
+        if registrations.is_empty() {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?;
+        } else {
+            let schedule_time = common_utils::date_time::now()
+                + time::Duration::days(state.conf.applepay_domain_renewal.interval_in_days);
+            let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: Some(schedule_time),
+                tracking_data: None,
+                business_status: None,
+                status: Some(enums::ProcessTrackerStatus::New),
+                updated_at: Some(common_utils::date_time::now()),
+            };
+            db.process_tracker_update_process_status_by_ids(
+                vec![process.id.clone()],
+                updated_process_tracker_data,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a AppState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing Apple Pay domain renewal workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::{
+        payment_methods::Oss,
+        payments::{self as payment_flows, auto_void, operations, payment_tasks},
+    },
+    errors,
+    routes::AppState,
+    services,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+};
+
+pub struct AutoVoidWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for AutoVoidWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: auto_void::AutoVoidTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AutoVoidTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // Nothing is left to void if the payment has been captured or cancelled by the merchant
+        // since the void was scheduled
+        if payment_intent.status != enums::IntentStatus::RequiresCapture {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?;
+            return Ok(());
+        }
+
+        let request = api::PaymentsCancelRequest {
+            payment_id: payment_intent.payment_id.clone(),
+            cancellation_reason: Some(auto_void::get_cancellation_reason(
+                tracking_data.auto_void_after_in_hours,
+            )),
+            merchant_connector_details: None,
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        let (payment_data, _, _, _, _) = Box::pin(payment_flows::payments_operation_core::<
+            api::Void,
+            _,
+            _,
+            _,
+            Oss,
+        >(
+            state,
+            state.get_req_state(),
+            merchant_account.clone(),
+            key_store.clone(),
+            operations::PaymentCancel,
+            request,
+            payment_flows::CallConnectorAction::Trigger,
+            services::AuthFlow::Merchant,
+            None,
+            api::HeaderPayload::default(),
+        ))
+        .await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+            .await?;
+
+        if payment_data.payment_intent.status == enums::IntentStatus::Cancelled {
+            let business_profile = db
+                .find_business_profile_by_profile_id(&tracking_data.profile_id)
+                .await?;
+            payment_tasks::trigger_payment_event_webhook(
+                state,
+                merchant_account,
+                &key_store,
+                business_profile,
+                enums::EventType::PaymentCancelled,
+                operations::PaymentCancel,
+                payment_data,
+            )
+            .await
+            .map_err(|error| logger::warn!(auto_void_outgoing_webhook_error=?error))
+            .ok();
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,70 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::connector_migration,
+    errors,
+    routes::AppState,
+    types::storage::{self, enums},
+};
+
+pub struct ConnectorMigrationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for ConnectorMigrationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let mut tracking_data: connector_migration::ConnectorMigrationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ConnectorMigrationTrackingData")?;
+
+        tracking_data.items = connector_migration::migrate_connector(state, &tracking_data).await?;
+        logger::info!(
+            migration_id = %tracking_data.migration_id,
+            items = tracking_data.items.len(),
+            "Completed connector migration"
+        );
+
+        // The outcomes of the items are recorded in the task, which is what the migration is
+        // retrieved from
+        let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: None,
+            tracking_data: Some(
+                tracking_data
+                    .encode_to_value()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to serialize connector migration tracking data")?,
+            ),
+            business_status: Some(
+                connector_migration::CONNECTOR_MIGRATION_COMPLETED_STATUS.to_string(),
+            ),
+            status: Some(enums::ProcessTrackerStatus::Finish),
+            updated_at: Some(common_utils::date_time::now()),
+        };
+        state
+            .store
+            .as_scheduler()
+            .update_process(process, updated_process_tracker_data)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing connector migration workflow");
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
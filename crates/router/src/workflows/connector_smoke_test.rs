@@ -0,0 +1,146 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::{errors::RouterResult, verify_connector},
+    errors,
+    routes::{metrics, AppState},
+    types::{
+        self, api,
+        storage::{self, enums},
+    },
+    utils::verify_connector as verify_connector_utils,
+};
+
+pub struct ConnectorSmokeTestWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for ConnectorSmokeTestWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::ConnectorSmokeTestTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ConnectorSmokeTestTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                tracking_data.merchant_id.as_str(),
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_connector_accounts = db
+            .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+                tracking_data.merchant_id.as_str(),
+                false,
+                &key_store,
+            )
+            .await?;
+
+        // Only test mode accounts are smoke tested, as transactions made using live credentials
+        // would move real money
+        for merchant_connector_account in merchant_connector_accounts.iter().filter(|mca| {
+            mca.connector_type == enums::ConnectorType::PaymentProcessor
+                && mca.test_mode == Some(true)
+        }) {
+            let connector_name = merchant_connector_account.connector_name.as_str();
+            let connector = match connector_name.parse::<api::enums::Connector>() {
+                Ok(connector) if is_smoke_test_supported(connector) => connector,
+                _ => {
+                    logger::debug!(connector_name, "Smoke test is not supported for connector");
+                    continue;
+                }
+            };
+
+            match smoke_test_connector(state, connector, merchant_connector_account).await {
+                Ok(()) => {
+                    metrics::CONNECTOR_SMOKE_TEST_SUCCESS_COUNT.add(
+                        &metrics::CONTEXT,
+                        1,
+                        &[metrics::request::add_attributes(
+                            "connector",
+                            connector_name.to_owned(),
+                        )],
+                    );
+                }
+                Err(error) => {
+                    metrics::CONNECTOR_SMOKE_TEST_FAILURE_COUNT.add(
+                        &metrics::CONTEXT,
+                        1,
+                        &[metrics::request::add_attributes(
+                            "connector",
+                            connector_name.to_owned(),
+                        )],
+                    );
+                    logger::error!(
+                        connector_name,
+                        merchant_connector_id = %merchant_connector_account.merchant_connector_id,
+                        ?error,
+                        "Connector smoke test failed"
+                    );
+                }
+            }
+        }
+
+        // The task is never finished, it is picked up again the next night
+        let schedule_time =
+            (common_utils::date_time::now().date() + time::Duration::days(1)).midnight();
+        let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: Some(schedule_time),
+            tracking_data: None,
+            business_status: None,
+            status: Some(enums::ProcessTrackerStatus::New),
+            updated_at: Some(common_utils::date_time::now()),
+        };
+        db.process_tracker_update_process_status_by_ids(
+            vec![process.id.clone()],
+            updated_process_tracker_data,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a AppState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing connector smoke test workflow");
+        Ok(())
+    }
+}
+
+fn is_smoke_test_supported(connector: api::enums::Connector) -> bool {
+    matches!(
+        verify_connector_utils::get_test_card_details(connector),
+        Ok(Some(_))
+    )
+}
+
+async fn smoke_test_connector(
+    state: &AppState,
+    connector: api::enums::Connector,
+    merchant_connector_account: &types::domain::MerchantConnectorAccount,
+) -> RouterResult<()> {
+    let connector_auth: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .clone()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the connector credentials")?;
+
+    verify_connector::verify_connector(state, connector, connector_auth)
+        .await
+        .map(|_| ())
+}
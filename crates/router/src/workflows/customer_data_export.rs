@@ -0,0 +1,46 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+use crate::{core::customer_data_export, errors, routes::AppState, types::storage};
+
+pub struct CustomerDataExportWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for CustomerDataExportWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: customer_data_export::CustomerDataExportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CustomerDataExportTrackingData")?;
+
+        customer_data_export::export_customer_data(state, &tracking_data).await?;
+
+        // The export is retrieved from the file storage once the task is finished with the
+        // completed status
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(
+                process,
+                customer_data_export::CUSTOMER_DATA_EXPORT_COMPLETED_STATUS.to_string(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing customer data export workflow");
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -21,6 +21,9 @@ use crate::{
     types::{domain, storage},
 };
 
+/// Interval after which a paused webhook delivery to a disabled endpoint is attempted again.
+const PAUSED_DELIVERY_RESCHEDULE_INTERVAL_IN_SECS: i64 = 60 * 60;
+
 pub struct OutgoingWebhookRetryWorkflow;
 
 #[async_trait::async_trait]
@@ -48,6 +51,15 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
             .find_business_profile_by_profile_id(&tracking_data.business_profile_id)
             .await?;
 
+        if webhooks_core::endpoint_health::is_webhook_endpoint_disabled(&business_profile) {
+            logger::debug!(
+                business_profile_id = %business_profile.profile_id,
+                "Merchant webhook endpoint is disabled; pausing retry of outgoing webhook"
+            );
+            pause_webhook_delivery_task(db, process).await?;
+            return Ok(());
+        }
+
         let event_id = webhooks_core::utils::generate_event_id();
         let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
             &tracking_data.primary_object_id,
@@ -311,6 +323,31 @@ pub(crate) async fn retry_webhook_delivery_task(
     }
 }
 
+/// Reschedule the delivery task of a disabled webhook endpoint without incrementing its retry
+/// count, so that the delivery is resumed with its remaining retries once the endpoint is enabled.
+pub(crate) async fn pause_webhook_delivery_task(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+) -> errors::CustomResult<(), errors::StorageError> {
+    let retry_count = process.retry_count;
+    let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+        PAUSED_DELIVERY_RESCHEDULE_INTERVAL_IN_SECS,
+    ));
+
+    db.as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                status: storage::enums::ProcessTrackerStatus::Pending,
+                retry_count,
+                schedule_time,
+            },
+        )
+        .await?;
+
+    Ok(())
+}
+
 #[instrument(skip_all)]
 async fn get_outgoing_webhook_content_and_event_type(
     state: AppState,
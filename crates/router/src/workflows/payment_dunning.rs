@@ -0,0 +1,175 @@
+use api_models::{enums::RetryAction, mandates::RecurringDetails};
+use common_utils::ext_traits::ValueExt;
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::{
+        payment_methods::Oss,
+        payments::{self as payment_flows, dunning, operations},
+    },
+    errors,
+    routes::AppState,
+    services,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+};
+
+pub struct PaymentDunningWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for PaymentDunningWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: dunning::PaymentDunningTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentDunningTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+        let business_profile = db
+            .find_business_profile_by_profile_id(&tracking_data.profile_id)
+            .await?;
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // Nothing is left to retry if dunning has been disabled for the business profile, or if the
+        // payment has been retried successfully by the merchant since the retry was scheduled
+        let Some(dunning_config) = dunning::get_dunning_config(&business_profile)?
+            .filter(|_| payment_intent.status == enums::IntentStatus::Failed)
+        else {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?;
+            return Ok(());
+        };
+
+        let failed_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &payment_intent.payment_id,
+                &merchant_account.merchant_id,
+                payment_intent.active_attempt.get_id().as_str(),
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        let request = api::PaymentsRequest {
+            payment_id: Some(api::PaymentIdType::PaymentIntentId(
+                payment_intent.payment_id.clone(),
+            )),
+            merchant_id: Some(merchant_account.merchant_id.clone()),
+            confirm: Some(true),
+            off_session: Some(true),
+            retry_action: Some(RetryAction::ManualRetry),
+            mandate_id: failed_attempt.mandate_id.clone(),
+            recurring_details: match failed_attempt.mandate_id {
+                Some(_) => None,
+                None => failed_attempt
+                    .payment_method_id
+                    .clone()
+                    .map(RecurringDetails::PaymentMethodId),
+            },
+            ..Default::default()
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        let (payment_data, _, _, _, _) = Box::pin(payment_flows::payments_operation_core::<
+            api::Authorize,
+            _,
+            _,
+            _,
+            Oss,
+        >(
+            state,
+            state.get_req_state(),
+            merchant_account.clone(),
+            key_store.clone(),
+            operations::PaymentConfirm,
+            request,
+            payment_flows::CallConnectorAction::Trigger,
+            services::AuthFlow::Merchant,
+            None,
+            api::HeaderPayload::default(),
+        ))
+        .await?;
+
+        // The payment either succeeded, or is being processed and its outcome is notified by the
+        // payment webhooks
+        if payment_data.payment_intent.status != enums::IntentStatus::Failed {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?;
+            return Ok(());
+        }
+
+        let retry_count = u8::try_from(process.retry_count.saturating_add(1)).unwrap_or(u8::MAX);
+        let next_retry_at = if retry_count < dunning_config.max_retries
+            && dunning::is_soft_decline(state, &payment_data.payment_attempt).await
+        {
+            let next_retry_at = dunning::get_next_retry_time(
+                &dunning_config,
+                common_utils::date_time::now(),
+                &dunning::get_jitter_settings(state),
+            );
+            db.as_scheduler()
+                .retry_process(process, next_retry_at)
+                .await?;
+            Some(next_retry_at)
+        } else {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, "RETRIES_EXHAUSTED".to_string())
+                .await?;
+            None
+        };
+
+        let event_type = match next_retry_at {
+            Some(_) => enums::EventType::PaymentRetryScheduled,
+            None => enums::EventType::PaymentRetriesExhausted,
+        };
+        dunning::trigger_dunning_webhook(
+            state,
+            merchant_account,
+            &key_store,
+            business_profile,
+            event_type,
+            dunning::get_payment_retry_details(
+                &payment_data.payment_intent,
+                &payment_data.payment_attempt,
+                retry_count,
+                dunning_config.max_retries,
+                next_retry_at,
+            ),
+            payment_data.payment_intent.created_at,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,211 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    consts,
+    core::{
+        api_locking,
+        payment_methods::Oss,
+        payments::{self as payment_flows, intent_expiry, operations, payment_tasks},
+    },
+    errors,
+    routes::{lock_utils, AppState},
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+pub struct PaymentIntentExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for PaymentIntentExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: intent_expiry::IntentExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("IntentExpiryTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        // The payment is held under the lock of the payment APIs, so that it is not confirmed by
+        // the customer while it is being expired
+        let lock_action = api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: tracking_data.payment_id.clone(),
+                api_identifier: lock_utils::ApiIdentifier::Payments,
+                override_lock_retries: None,
+            },
+        };
+        lock_action
+            .clone()
+            .perform_locking_action(state, merchant_account.merchant_id.clone())
+            .await?;
+        let expiry_result =
+            expire_payment_if_awaiting_customer(state, &merchant_account, &tracking_data, process)
+                .await;
+        lock_action
+            .free_lock_action(state, merchant_account.merchant_id.clone())
+            .await?;
+        if !expiry_result? {
+            return Ok(());
+        }
+
+        let request = api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(tracking_data.payment_id.clone()),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            force_sync: false,
+            ..Default::default()
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        let (payment_data, _, _, _, _) = Box::pin(payment_flows::payments_operation_core::<
+            api::PSync,
+            _,
+            _,
+            _,
+            Oss,
+        >(
+            state,
+            state.get_req_state(),
+            merchant_account.clone(),
+            key_store.clone(),
+            operations::PaymentStatus,
+            request,
+            payment_flows::CallConnectorAction::Avoid,
+            services::AuthFlow::Merchant,
+            None,
+            api::HeaderPayload::default(),
+        ))
+        .await?;
+
+        let business_profile = db
+            .find_business_profile_by_profile_id(&tracking_data.profile_id)
+            .await?;
+        payment_tasks::trigger_payment_event_webhook(
+            state,
+            merchant_account,
+            &key_store,
+            business_profile,
+            enums::EventType::PaymentExpired,
+            operations::PaymentStatus,
+            payment_data,
+        )
+        .await
+        .map_err(|error| logger::warn!(payment_intent_expiry_outgoing_webhook_error=?error))
+        .ok();
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Expire the payment if it is still waiting for the customer at the end of its session, returning
+/// whether it has been expired. The task is completed or rescheduled in either case.
+async fn expire_payment_if_awaiting_customer(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    tracking_data: &intent_expiry::IntentExpiryTrackingData,
+    process: storage::ProcessTracker,
+) -> Result<bool, errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &tracking_data.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+    // Nothing is left to expire if the payment has been confirmed or cancelled since the expiry
+    // was scheduled, or if the payments are not expired anymore
+    if !state.conf.payment_intent_expiry.enabled
+        || !payment_tasks::is_awaiting_customer(payment_intent.status)
+    {
+        db.as_scheduler()
+            .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+            .await?;
+        return Ok(false);
+    }
+
+    // The session of the payment may have been extended by an update since the expiry was
+    // scheduled
+    if let Some(session_expiry) = payment_intent
+        .session_expiry
+        .filter(|session_expiry| *session_expiry > common_utils::date_time::now())
+    {
+        db.as_scheduler()
+            .reset_process(process, session_expiry)
+            .await?;
+        return Ok(false);
+    }
+
+    // The attempt is failed along with the intent, so that the attempt does not remain awaiting a
+    // confirmation which cannot be made anymore
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            &merchant_account.merchant_id,
+            payment_intent.active_attempt.get_id().as_str(),
+            merchant_account.storage_scheme,
+        )
+        .await?;
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        storage::PaymentAttemptUpdate::ErrorUpdate {
+            connector: None,
+            status: enums::AttemptStatus::Failure,
+            error_code: Some(Some(consts::PAYMENT_EXPIRED_ERROR_CODE.to_string())),
+            error_message: Some(Some(consts::PAYMENT_EXPIRED_ERROR_MESSAGE.to_string())),
+            error_reason: None,
+            amount_capturable: Some(0),
+            updated_by: merchant_account.storage_scheme.to_string(),
+            unified_code: None,
+            unified_message: None,
+            connector_transaction_id: None,
+            payment_method_data: None,
+            connector_request_id: None,
+        },
+        merchant_account.storage_scheme,
+    )
+    .await?;
+
+    db.update_payment_intent(
+        payment_intent,
+        storage::PaymentIntentUpdate::ExpiryUpdate {
+            status: enums::IntentStatus::Expired,
+            updated_by: merchant_account.storage_scheme.to_string(),
+        },
+        merchant_account.storage_scheme,
+    )
+    .await?;
+
+    db.as_scheduler()
+        .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+        .await?;
+
+    Ok(true)
+}
@@ -105,17 +105,39 @@ impl ProcessTrackerWorkflow<AppState> for PaymentsSyncWorkflow {
                     .clone()
                     .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
 
-                let is_last_retry = retry_sync_task(
-                    db,
-                    connector,
-                    payment_data.payment_attempt.merchant_id.clone(),
-                    process,
-                )
-                .await?;
+                // Real time payments are completed by the customer scanning the QR code, which
+                // cannot be done once it has expired, so there is no point in syncing them further
+                let is_qr_code_expired =
+                    is_real_time_payment_qr_code_expired(&payment_data.payment_attempt);
+                let is_last_retry = if is_qr_code_expired {
+                    db.as_scheduler()
+                        .finish_process_with_business_status(process, "QR_CODE_EXPIRED".to_string())
+                        .await?;
+                    true
+                } else {
+                    retry_sync_task(
+                        db,
+                        connector,
+                        payment_data.payment_attempt.merchant_id.clone(),
+                        process,
+                    )
+                    .await?
+                };
 
-                // If the payment status is still processing and there is no connector transaction_id
-                // then change the payment status to failed if all retries exceeded
-                if is_last_retry
+                // The error code and message of the failure, if the cause of the failure is
+                // known, along with the reason of the failure
+                let failure_details = if is_qr_code_expired
+                    || (is_last_retry
+                        && is_real_time_payment_awaiting_customer(&payment_data.payment_attempt))
+                {
+                    Some((
+                        Some((
+                            consts::QR_CODE_EXPIRED_ERROR_CODE,
+                            consts::QR_CODE_EXPIRED_ERROR_MESSAGE,
+                        )),
+                        consts::QR_CODE_EXPIRED_ERROR_MESSAGE_FROM_PSYNC,
+                    ))
+                } else if is_last_retry
                     && payment_data.payment_attempt.status == enums::AttemptStatus::Pending
                     && payment_data
                         .payment_attempt
@@ -123,22 +145,29 @@ impl ProcessTrackerWorkflow<AppState> for PaymentsSyncWorkflow {
                         .as_ref()
                         .is_none()
                 {
+                    // If the payment status is still processing and there is no connector transaction_id
+                    // then change the payment status to failed if all retries exceeded
+                    Some((None, consts::REQUEST_TIMEOUT_ERROR_MESSAGE_FROM_PSYNC))
+                } else {
+                    None
+                };
+
+                if let Some((error, failure_reason)) = failure_details {
                     let payment_intent_update = hyperswitch_domain_models::payments::payment_intent::PaymentIntentUpdate::PGStatusUpdate { status: api_models::enums::IntentStatus::Failed,updated_by: merchant_account.storage_scheme.to_string(), incremental_authorization_allowed: Some(false) };
                     let payment_attempt_update =
                         hyperswitch_domain_models::payments::payment_attempt::PaymentAttemptUpdate::ErrorUpdate {
                             connector: None,
                             status: api_models::enums::AttemptStatus::AuthenticationFailed,
-                            error_code: None,
-                            error_message: None,
-                            error_reason: Some(Some(
-                                consts::REQUEST_TIMEOUT_ERROR_MESSAGE_FROM_PSYNC.to_string(),
-                            )),
+                            error_code: error.map(|(code, _)| Some(code.to_string())),
+                            error_message: error.map(|(_, message)| Some(message.to_string())),
+                            error_reason: Some(Some(failure_reason.to_string())),
                             amount_capturable: Some(0),
                             updated_by: merchant_account.storage_scheme.to_string(),
                             unified_code: None,
                             unified_message: None,
                             connector_transaction_id: None,
                             payment_method_data: None,
+                            connector_request_id: None,
                         };
 
                     payment_data.payment_attempt = db
@@ -206,6 +235,34 @@ impl ProcessTrackerWorkflow<AppState> for PaymentsSyncWorkflow {
     }
 }
 
+/// Whether the payment is a real time payment for which the customer is yet to scan the QR code
+fn is_real_time_payment_awaiting_customer(payment_attempt: &storage::PaymentAttempt) -> bool {
+    payment_attempt.payment_method == Some(enums::PaymentMethod::RealTimePayment)
+        && payment_attempt.status == enums::AttemptStatus::AuthenticationPending
+}
+
+/// Whether the QR code of a real time payment, for which the customer is yet to scan the QR code,
+/// has expired. The expiry is known only if the connector has shared it along with the QR code.
+fn is_real_time_payment_qr_code_expired(payment_attempt: &storage::PaymentAttempt) -> bool {
+    is_real_time_payment_awaiting_customer(payment_attempt)
+        && is_qr_code_expired(payment_attempt.connector_metadata.clone())
+}
+
+/// Whether the QR code held in the connector metadata of a payment has expired
+fn is_qr_code_expired(connector_metadata: Option<serde_json::Value>) -> bool {
+    connector_metadata
+        .and_then(|metadata| {
+            metadata
+                .parse_value::<api_models::payments::QrCodeInformation>("QrCodeInformation")
+                .map_err(
+                    |error| logger::warn!(%error, "Failed to Parse Value to QrCodeInformation"),
+                )
+                .ok()
+        })
+        .and_then(|qr_code_information| qr_code_information.get_display_to_timestamp())
+        .is_some_and(|display_to_timestamp| display_to_timestamp.is_expired())
+}
+
 /// Get the next schedule time
 ///
 /// The schedule time can be configured in configs by this key `pt_mapping_trustpay`
@@ -304,4 +361,25 @@ mod tests {
             ]
         );
     }
+
+    fn qr_code_metadata(
+        display_to_timestamp: Option<common_utils::types::ExpiresAt>,
+    ) -> Option<serde_json::Value> {
+        let qr_code_information = api_models::payments::QrCodeInformation::QrDataUrl {
+            image_data_url: url::Url::parse("data:image/png;base64,iVBORw0KGgo=").unwrap(),
+            display_to_timestamp,
+        };
+        Some(serde_json::to_value(qr_code_information).unwrap())
+    }
+
+    #[test]
+    fn test_is_qr_code_expired() {
+        let expired_at = common_utils::types::ExpiresAt::after(time::Duration::minutes(-5));
+        let expires_at = common_utils::types::ExpiresAt::after(time::Duration::minutes(5));
+
+        assert!(is_qr_code_expired(qr_code_metadata(Some(expired_at))));
+        assert!(!is_qr_code_expired(qr_code_metadata(Some(expires_at))));
+        assert!(!is_qr_code_expired(qr_code_metadata(None)));
+        assert!(!is_qr_code_expired(None));
+    }
 }
@@ -15,8 +15,9 @@ use tokio::sync::oneshot;
 use crate::{connector_auth::ConnectorAuthentication, utils};
 
 fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
-    let auth = ConnectorAuthentication::new()
+    let auth = ConnectorAuthentication::get()
         .aci
+        .clone()
         .expect("Missing ACI connector authentication configuration");
 
     types::RouterData {
@@ -72,9 +73,16 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             customer_id: None,
             surcharge_details: None,
             request_incremental_authorization: false,
+            request_overcapture: false,
+            request_extended_authorization: false,
+            split_payments: None,
+            payment_channel: None,
+            l2_l3_data: None,
+            installment_tenure: None,
             metadata: None,
             authentication_data: None,
             customer_acceptance: None,
+            preferred_card_network: None,
         },
         response: Err(types::ErrorResponse::default()),
         address: PaymentAddress::new(
@@ -114,6 +122,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         connector_http_status_code: None,
         apple_pay_flow: None,
         external_latency: None,
+        connector_request_id: None,
         frm_metadata: None,
         refund_id: None,
         dispute_id: None,
@@ -121,8 +130,9 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
 }
 
 fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
-    let auth = ConnectorAuthentication::new()
+    let auth = ConnectorAuthentication::get()
         .aci
+        .clone()
         .expect("Missing ACI connector authentication configuration");
 
     types::RouterData {
@@ -149,6 +159,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
             webhook_url: None,
             connector_metadata: None,
             reason: None,
+            reason_code: None,
             connector_refund_id: None,
             browser_info: None,
         },
@@ -175,6 +186,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         connector_http_status_code: None,
         apple_pay_flow: None,
         external_latency: None,
+        connector_request_id: None,
         frm_metadata: None,
         refund_id: None,
         dispute_id: None,
@@ -36,8 +36,9 @@ impl utils::Connector for AdyenTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .adyen_uk
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -177,9 +178,16 @@ impl AdyenTest {
             customer_id: None,
             surcharge_details: None,
             request_incremental_authorization: false,
+            request_overcapture: false,
+            request_extended_authorization: false,
+            split_payments: None,
+            payment_channel: None,
+            l2_l3_data: None,
+            installment_tenure: None,
             metadata: None,
             authentication_data: None,
             customer_acceptance: None,
+            preferred_card_network: None,
         })
     }
 }
@@ -0,0 +1,112 @@
+use std::str::FromStr;
+
+use api_models::payments::OrderDetailsWithAmount;
+use common_utils::pii::Email;
+use masking::Secret;
+use router::types::{self, domain, storage::enums};
+use test_utils::connector_auth;
+
+use crate::utils::{self, ConnectorActions};
+
+#[derive(Clone, Copy)]
+struct AffirmTest;
+impl ConnectorActions for AffirmTest {}
+impl utils::Connector for AffirmTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Affirm;
+        types::api::ConnectorData {
+            connector: Box::new(&Affirm),
+            connector_name: types::Connector::Affirm,
+            get_token: types::api::GetToken::Connector,
+            merchant_connector_id: None,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        utils::to_connector_auth_type(
+            connector_auth::ConnectorAuthentication::get()
+                .affirm
+                .clone()
+                .expect("Missing connector authentication configuration")
+                .into(),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "affirm".to_string()
+    }
+}
+
+static CONNECTOR: AffirmTest = AffirmTest {};
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        address: Some(types::PaymentAddress::new(
+            None,
+            None,
+            Some(types::api::Address {
+                address: Some(types::api::AddressDetails {
+                    first_name: Some(Secret::new("John".to_string())),
+                    last_name: Some(Secret::new("Doe".to_string())),
+                    line1: Some(Secret::new("325 Pacific Ave".to_string())),
+                    city: Some("San Francisco".to_string()),
+                    state: Some(Secret::new("CA".to_string())),
+                    zip: Some(Secret::new("94111".to_string())),
+                    country: Some(enums::CountryAlpha2::US),
+                    ..Default::default()
+                }),
+                phone: None,
+                email: None,
+            }),
+        )),
+        currency: Some(enums::Currency::USD),
+        ..Default::default()
+    })
+}
+
+fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        amount: 1000,
+        currency: enums::Currency::USD,
+        payment_method_data: domain::PaymentMethodData::PayLater(
+            domain::PayLaterData::AffirmRedirect {},
+        ),
+        payment_method_type: Some(enums::PaymentMethodType::Affirm),
+        email: Email::from_str("john.doe@example.com").ok(),
+        order_details: Some(vec![OrderDetailsWithAmount {
+            product_name: "shirt".to_string(),
+            quantity: 2,
+            amount: 500,
+            ..Default::default()
+        }]),
+        complete_authorize_url: Some("https://hyperswitch.io".to_string()),
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+// Creates an Affirm checkout, which the customer is redirected to.
+#[actix_web::test]
+async fn should_create_checkout() {
+    let response = CONNECTOR
+        .authorize_payment(payment_method_details(), get_default_payment_info())
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+}
+
+// Creates a checkout with a payment method that Affirm does not support.
+#[actix_web::test]
+async fn should_fail_checkout_for_unsupported_payment_method() {
+    let response = CONNECTOR
+        .authorize_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: domain::PaymentMethodData::PayLater(
+                    domain::PayLaterData::AfterpayClearpayRedirect {},
+                ),
+                ..payment_method_details().unwrap()
+            }),
+            get_default_payment_info(),
+        )
+        .await;
+    assert!(response.is_err());
+}
@@ -0,0 +1,112 @@
+use std::str::FromStr;
+
+use api_models::payments::OrderDetailsWithAmount;
+use common_utils::pii::Email;
+use masking::Secret;
+use router::types::{self, domain, storage::enums};
+use test_utils::connector_auth;
+
+use crate::utils::{self, ConnectorActions};
+
+#[derive(Clone, Copy)]
+struct AfterpayTest;
+impl ConnectorActions for AfterpayTest {}
+impl utils::Connector for AfterpayTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Afterpay;
+        types::api::ConnectorData {
+            connector: Box::new(&Afterpay),
+            connector_name: types::Connector::Afterpay,
+            get_token: types::api::GetToken::Connector,
+            merchant_connector_id: None,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        utils::to_connector_auth_type(
+            connector_auth::ConnectorAuthentication::get()
+                .afterpay
+                .clone()
+                .expect("Missing connector authentication configuration")
+                .into(),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "afterpay".to_string()
+    }
+}
+
+static CONNECTOR: AfterpayTest = AfterpayTest {};
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        address: Some(types::PaymentAddress::new(
+            None,
+            None,
+            Some(types::api::Address {
+                address: Some(types::api::AddressDetails {
+                    first_name: Some(Secret::new("John".to_string())),
+                    last_name: Some(Secret::new("Doe".to_string())),
+                    line1: Some(Secret::new("1 George Street".to_string())),
+                    city: Some("Sydney".to_string()),
+                    state: Some(Secret::new("NSW".to_string())),
+                    zip: Some(Secret::new("2000".to_string())),
+                    country: Some(enums::CountryAlpha2::AU),
+                    ..Default::default()
+                }),
+                phone: None,
+                email: None,
+            }),
+        )),
+        currency: Some(enums::Currency::AUD),
+        ..Default::default()
+    })
+}
+
+fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        amount: 1000,
+        currency: enums::Currency::AUD,
+        payment_method_data: domain::PaymentMethodData::PayLater(
+            domain::PayLaterData::AfterpayClearpayRedirect {},
+        ),
+        payment_method_type: Some(enums::PaymentMethodType::AfterpayClearpay),
+        email: Email::from_str("john.doe@example.com").ok(),
+        order_details: Some(vec![OrderDetailsWithAmount {
+            product_name: "shirt".to_string(),
+            quantity: 2,
+            amount: 500,
+            ..Default::default()
+        }]),
+        complete_authorize_url: Some("https://hyperswitch.io".to_string()),
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+// Creates an Afterpay checkout, which the customer is redirected to.
+#[actix_web::test]
+async fn should_create_checkout() {
+    let response = CONNECTOR
+        .authorize_payment(payment_method_details(), get_default_payment_info())
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+}
+
+// Creates a checkout with a payment method that Afterpay does not support.
+#[actix_web::test]
+async fn should_fail_checkout_for_unsupported_payment_method() {
+    let response = CONNECTOR
+        .authorize_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: domain::PaymentMethodData::PayLater(
+                    domain::PayLaterData::AffirmRedirect {},
+                ),
+                ..payment_method_details().unwrap()
+            }),
+            get_default_payment_info(),
+        )
+        .await;
+    assert!(response.is_err());
+}
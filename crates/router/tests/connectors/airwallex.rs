@@ -28,8 +28,9 @@ impl Connector for AirwallexTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .airwallex
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -21,8 +21,9 @@ impl utils::Connector for BankofamericaTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .bankofamerica
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -27,8 +27,9 @@ impl utils::Connector for BluesnapTest {
 
     fn get_auth_token(&self) -> ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .bluesnap
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -20,8 +20,9 @@ impl utils::Connector for BokuTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .boku
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -23,8 +23,9 @@ impl utils::Connector for DummyConnectorTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .dummyconnector
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
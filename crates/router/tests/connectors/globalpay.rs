@@ -25,8 +25,9 @@ impl Connector for Globalpay {
 
     fn get_auth_token(&self) -> ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .globalpay
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
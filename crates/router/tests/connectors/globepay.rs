@@ -22,8 +22,9 @@ impl utils::Connector for GlobepayTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .globepay
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
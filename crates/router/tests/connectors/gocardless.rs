@@ -20,8 +20,9 @@ impl utils::Connector for GocardlessTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .gocardless
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -20,8 +20,9 @@ impl utils::Connector for HelcimTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .helcim
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -8,6 +8,8 @@ use test_utils::connector_auth;
 
 mod aci;
 mod adyen;
+mod affirm;
+mod afterpay;
 mod airwallex;
 mod authorizedotnet;
 mod bambora;
@@ -47,6 +49,7 @@ mod opennode;
 #[cfg(feature = "dummy_connector")]
 mod payeezy;
 mod payme;
+mod payoneer;
 mod paypal;
 mod payu;
 mod placetopay;
@@ -58,6 +61,7 @@ mod shift4;
 mod square;
 mod stax;
 mod stripe;
+mod transcript;
 mod trustpay;
 mod tsys;
 mod utils;
@@ -23,8 +23,9 @@ impl utils::Connector for MultisafepayTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .multisafepay
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
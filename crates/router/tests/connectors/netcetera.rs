@@ -19,8 +19,9 @@ impl utils::Connector for NetceteraTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .netcetera
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
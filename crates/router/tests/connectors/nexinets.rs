@@ -26,8 +26,9 @@ impl utils::Connector for NexinetsTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .nexinets
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -27,8 +27,9 @@ impl utils::Connector for NoonTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .noon
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -28,8 +28,9 @@ impl utils::Connector for NuveiTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .nuvei
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
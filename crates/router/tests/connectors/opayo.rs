@@ -25,8 +25,9 @@ impl utils::Connector for OpayoTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .opayo
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -31,8 +31,9 @@ impl utils::Connector for PayeezyTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .payeezy
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -0,0 +1,129 @@
+#[cfg(feature = "payouts")]
+use masking::Secret;
+use router::types;
+#[cfg(feature = "payouts")]
+use router::types::{api, storage::enums, AccessToken, ConnectorAuthType};
+
+#[cfg(feature = "payouts")]
+use crate::utils::PaymentInfo;
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+struct PayoneerTest;
+impl ConnectorActions for PayoneerTest {}
+impl utils::Connector for PayoneerTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Payoneer;
+        types::api::ConnectorData {
+            connector: Box::new(&Payoneer),
+            connector_name: types::Connector::Payoneer,
+            get_token: types::api::GetToken::Connector,
+            merchant_connector_id: None,
+        }
+    }
+
+    #[cfg(feature = "payouts")]
+    fn get_payout_data(&self) -> Option<types::api::ConnectorData> {
+        use router::connector::Payoneer;
+        Some(types::api::ConnectorData {
+            connector: Box::new(&Payoneer),
+            connector_name: types::Connector::Payoneer,
+            get_token: types::api::GetToken::Connector,
+            merchant_connector_id: None,
+        })
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        utils::to_connector_auth_type(
+            connector_auth::ConnectorAuthentication::get()
+                .payoneer
+                .clone()
+                .expect("Missing connector authentication configuration")
+                .into(),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "payoneer".to_string()
+    }
+}
+
+impl PayoneerTest {
+    #[cfg(feature = "payouts")]
+    fn get_access_token() -> Option<AccessToken> {
+        match utils::Connector::get_auth_token(&Self) {
+            ConnectorAuthType::SignatureKey { api_key, .. } => Some(AccessToken {
+                token: api_key,
+                expires: 18600,
+            }),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "payouts")]
+    fn get_payout_info() -> Option<PaymentInfo> {
+        Some(PaymentInfo {
+            currency: Some(enums::Currency::USD),
+            access_token: Self::get_access_token(),
+            payout_method_data: Some(api::PayoutMethodData::Wallet(
+                api::payouts::WalletPayout::Payoneer(api_models::payouts::Payoneer {
+                    payee_id: Secret::new("payee_1234".to_string()),
+                }),
+            )),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "payouts")]
+static CONNECTOR: PayoneerTest = PayoneerTest {};
+
+/******************** Payouts test cases ********************/
+// Checks the status of the payee registered with the program
+#[cfg(feature = "payouts")]
+#[actix_web::test]
+async fn should_verify_payout_eligibility() {
+    let payout_type = enums::PayoutType::Wallet;
+    let payout_info = PayoneerTest::get_payout_info();
+    let response = CONNECTOR
+        .verify_payout_eligibility(payout_type, payout_info)
+        .await
+        .expect("Payout eligibility response");
+    assert_eq!(
+        response.status.unwrap(),
+        enums::PayoutStatus::RequiresFulfillment
+    );
+}
+
+// Submits a payout to the payee
+#[cfg(feature = "payouts")]
+#[actix_web::test]
+async fn should_fulfill_payout() {
+    let payout_type = enums::PayoutType::Wallet;
+    let payout_info = PayoneerTest::get_payout_info();
+    let response = CONNECTOR
+        .fulfill_payout(None, payout_type, payout_info)
+        .await
+        .expect("Payout fulfill response");
+    assert_eq!(response.status.unwrap(), enums::PayoutStatus::Pending);
+}
+
+// Submits a payout to the payee and cancels it
+#[cfg(feature = "payouts")]
+#[actix_web::test]
+async fn should_fulfill_and_cancel_payout() {
+    let payout_type = enums::PayoutType::Wallet;
+    let payout_info = PayoneerTest::get_payout_info();
+    let fulfill_res = CONNECTOR
+        .fulfill_payout(None, payout_type, payout_info.to_owned())
+        .await
+        .expect("Payout fulfill response");
+    assert_eq!(fulfill_res.status.unwrap(), enums::PayoutStatus::Pending);
+    let response = CONNECTOR
+        .cancel_payout(fulfill_res.connector_payout_id, payout_type, payout_info)
+        .await
+        .expect("Payout cancel response");
+    assert_eq!(response.status.unwrap(), enums::PayoutStatus::Cancelled);
+}
@@ -23,8 +23,9 @@ impl Connector for PaypalTest {
 
     fn get_auth_token(&self) -> ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .paypal
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
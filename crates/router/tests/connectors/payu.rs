@@ -20,8 +20,9 @@ impl Connector for Payu {
 
     fn get_auth_token(&self) -> ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .payu
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -20,8 +20,9 @@ impl utils::Connector for PlacetopayTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .placetopay
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
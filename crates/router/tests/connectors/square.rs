@@ -26,8 +26,9 @@ impl Connector for SquareTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            ConnectorAuthentication::new()
+            ConnectorAuthentication::get()
                 .square
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -22,8 +22,9 @@ impl utils::Connector for StaxTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .stax
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
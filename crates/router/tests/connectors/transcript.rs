@@ -0,0 +1,169 @@
+//! Transcripts of the calls made to the sandboxes of the connectors by the tests, written when
+//! the `CONNECTOR_TRANSCRIPTS_DIR` environment variable is set.
+//!
+//! The request bodies are serialized with the masked serializer, which renders every secret with
+//! the masking strategy of its type, and the responses are recorded with their masked debug
+//! representation, so that the transcripts can be shared without leaking credentials or card
+//! details.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use common_utils::request::RequestContent;
+use error_stack::Report;
+use masking::Maskable;
+use once_cell::sync::Lazy;
+use router::{
+    core::{errors::ConnectorError, payments},
+    routes, services,
+    types::{storage::enums, RouterData},
+};
+use serde::Serialize;
+
+/// Directory the transcripts are written to, in a subdirectory for each connector
+static TRANSCRIPTS_DIR: Lazy<Option<PathBuf>> =
+    Lazy::new(|| std::env::var_os("CONNECTOR_TRANSCRIPTS_DIR").map(PathBuf::from));
+
+/// Sequence number of the calls made by the test run, which orders the transcripts of a test
+static CALL_SEQUENCE: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Serialize)]
+struct Transcript {
+    test: String,
+    connector: String,
+    flow: String,
+    request: Option<TranscriptRequest>,
+    status: Option<enums::AttemptStatus>,
+    response: String,
+}
+
+#[derive(Serialize)]
+struct TranscriptRequest {
+    method: String,
+    /// URL of the request without its query, which some connectors pass credentials in
+    url: String,
+    headers: BTreeMap<String, String>,
+    body: Option<serde_json::Value>,
+}
+
+/// Make the call to the connector, writing its transcript if transcripts have been enabled for
+/// the test run.
+pub async fn execute_connector_processing_step<
+    T: Debug + Clone + 'static,
+    Req: Debug + Clone + 'static,
+    Resp: Debug + Clone + 'static,
+>(
+    state: &routes::AppState,
+    integration: services::BoxedConnectorIntegration<'_, T, Req, Resp>,
+    request: &RouterData<T, Req, Resp>,
+) -> Result<RouterData<T, Req, Resp>, Report<ConnectorError>> {
+    let Some(transcripts_dir) = TRANSCRIPTS_DIR.as_ref() else {
+        return services::api::execute_connector_processing_step(
+            state,
+            integration,
+            request,
+            payments::CallConnectorAction::Trigger,
+            None,
+        )
+        .await;
+    };
+
+    // The request is built once more for the transcript, as the one sent to the connector is
+    // consumed by the call
+    let connector_request = integration
+        .build_request(request, &state.conf.connectors)
+        .ok()
+        .flatten()
+        .map(TranscriptRequest::from);
+    let result = services::api::execute_connector_processing_step(
+        state,
+        integration,
+        request,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await;
+
+    let flow = std::any::type_name::<T>()
+        .rsplit("::")
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let (status, response) = match &result {
+        Ok(router_data) => (
+            Some(router_data.status),
+            format!("{:?}", router_data.response),
+        ),
+        Err(error) => (None, error.current_context().to_string()),
+    };
+    let transcript = Transcript {
+        test: std::thread::current()
+            .name()
+            .unwrap_or("unknown")
+            .to_string(),
+        connector: request.connector.clone(),
+        flow,
+        request: connector_request,
+        status,
+        response,
+    };
+    write_transcript(transcripts_dir, &transcript);
+
+    result
+}
+
+fn write_transcript(transcripts_dir: &std::path::Path, transcript: &Transcript) {
+    let connector_dir = transcripts_dir.join(&transcript.connector);
+    std::fs::create_dir_all(&connector_dir).expect("Failed to create the transcripts directory");
+    let file_name = format!(
+        "{:05}_{}_{}.json",
+        CALL_SEQUENCE.fetch_add(1, Ordering::Relaxed),
+        transcript.test.replace("::", "."),
+        transcript.flow
+    );
+    let contents =
+        serde_json::to_string_pretty(transcript).expect("Failed to serialize the transcript");
+    std::fs::write(connector_dir.join(file_name), contents)
+        .expect("Failed to write the transcript");
+}
+
+impl From<services::Request> for TranscriptRequest {
+    fn from(request: services::Request) -> Self {
+        let headers = request
+            .headers
+            .into_iter()
+            .map(|(name, value)| {
+                let value = match value {
+                    Maskable::Normal(value) => value,
+                    Maskable::Masked(value) => format!("{value:?}"),
+                };
+                (name, value)
+            })
+            .collect();
+        let body = request.body.map(|body| match body {
+            RequestContent::Json(payload)
+            | RequestContent::FormUrlEncoded(payload)
+            | RequestContent::Xml(payload) => payload
+                .masked_serialize()
+                .unwrap_or_else(|error| serde_json::Value::String(error.to_string())),
+            RequestContent::FormData(_) => serde_json::json!({ "request_type": "FORM_DATA" }),
+            RequestContent::RawBytes(_) => serde_json::json!({ "request_type": "RAW_BYTES" }),
+        });
+
+        Self {
+            method: request.method.to_string(),
+            url: request
+                .url
+                .split('?')
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            headers,
+            body,
+        }
+    }
+}
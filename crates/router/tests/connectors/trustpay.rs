@@ -24,8 +24,9 @@ impl utils::Connector for TrustpayTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .trustpay
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -25,8 +25,9 @@ impl utils::Connector for TsysTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .tsys
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -1,9 +1,17 @@
-use std::{fmt::Debug, marker::PhantomData, str::FromStr, time::Duration};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    marker::PhantomData,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use async_trait::async_trait;
 use common_utils::pii::Email;
 use error_stack::Report;
 use masking::Secret;
+use once_cell::sync::Lazy;
 #[cfg(feature = "payouts")]
 use router::core::utils as core_utils;
 use router::{
@@ -13,10 +21,17 @@ use router::{
     routes, services,
     types::{self, storage::enums, AccessToken, PaymentAddress, RouterData},
 };
-use test_utils::connector_auth::ConnectorAuthType;
-use tokio::sync::oneshot;
+use test_utils::{connector_auth::ConnectorAuthType, lifecycle};
+use tokio::sync::{oneshot, OwnedSemaphorePermit, Semaphore};
 use wiremock::{Mock, MockServer};
 
+use crate::transcript;
+
+/// Permits for the requests made to the sandbox of each connector, shared by the tests running in
+/// parallel
+static CONNECTOR_REQUEST_PERMITS: Lazy<Mutex<HashMap<String, Arc<Semaphore>>>> =
+    Lazy::new(Default::default);
+
 pub trait Connector {
     fn get_data(&self) -> types::api::ConnectorData;
 
@@ -33,6 +48,12 @@ pub trait Connector {
         5
     }
 
+    /// maximum number of requests made to the sandbox of the connector at the same time by the
+    /// tests running in parallel, to stay within the rate limits of the sandbox
+    fn get_max_concurrent_requests(&self) -> usize {
+        2
+    }
+
     #[cfg(feature = "payouts")]
     fn get_payout_data(&self) -> Option<types::api::ConnectorData> {
         None
@@ -102,7 +123,12 @@ pub trait ConnectorActions: Connector {
         ))
         .await;
         integration.execute_pretasks(&mut request, &state).await?;
-        Box::pin(call_connector(request, integration)).await
+        Box::pin(call_connector(
+            request,
+            integration,
+            self.get_max_concurrent_requests(),
+        ))
+        .await
     }
 
     async fn create_connector_customer(
@@ -127,7 +153,12 @@ pub trait ConnectorActions: Connector {
         ))
         .await;
         integration.execute_pretasks(&mut request, &state).await?;
-        Box::pin(call_connector(request, integration)).await
+        Box::pin(call_connector(
+            request,
+            integration,
+            self.get_max_concurrent_requests(),
+        ))
+        .await
     }
 
     async fn create_connector_pm_token(
@@ -152,7 +183,12 @@ pub trait ConnectorActions: Connector {
         ))
         .await;
         integration.execute_pretasks(&mut request, &state).await?;
-        Box::pin(call_connector(request, integration)).await
+        Box::pin(call_connector(
+            request,
+            integration,
+            self.get_max_concurrent_requests(),
+        ))
+        .await
     }
 
     /// For initiating payments when `CaptureMethod` is set to `Automatic`
@@ -181,7 +217,12 @@ pub trait ConnectorActions: Connector {
         ))
         .await;
         integration.execute_pretasks(&mut request, &state).await?;
-        Box::pin(call_connector(request, integration)).await
+        Box::pin(call_connector(
+            request,
+            integration,
+            self.get_max_concurrent_requests(),
+        ))
+        .await
     }
 
     async fn sync_payment(
@@ -194,7 +235,12 @@ pub trait ConnectorActions: Connector {
             payment_data.unwrap_or_else(|| PaymentSyncType::default().0),
             payment_info,
         );
-        Box::pin(call_connector(request, integration)).await
+        Box::pin(call_connector(
+            request,
+            integration,
+            self.get_max_concurrent_requests(),
+        ))
+        .await
     }
 
     /// will retry the psync till the given status matches or retry max 3 times
@@ -232,7 +278,12 @@ pub trait ConnectorActions: Connector {
             },
             payment_info,
         );
-        Box::pin(call_connector(request, integration)).await
+        Box::pin(call_connector(
+            request,
+            integration,
+            self.get_max_concurrent_requests(),
+        ))
+        .await
     }
 
     async fn authorize_and_capture_payment(
@@ -251,6 +302,7 @@ pub trait ConnectorActions: Connector {
             .capture_payment(txn_id.unwrap(), capture_data, payment_info)
             .await
             .unwrap();
+        lifecycle::assert_status_progression(&[authorize_response.status, response.status]);
         return Ok(response);
     }
 
@@ -268,7 +320,12 @@ pub trait ConnectorActions: Connector {
             },
             payment_info,
         );
-        Box::pin(call_connector(request, integration)).await
+        Box::pin(call_connector(
+            request,
+            integration,
+            self.get_max_concurrent_requests(),
+        ))
+        .await
     }
 
     async fn authorize_and_void_payment(
@@ -288,6 +345,7 @@ pub trait ConnectorActions: Connector {
             .void_payment(txn_id.unwrap(), void_data, payment_info)
             .await
             .unwrap();
+        lifecycle::assert_status_progression(&[authorize_response.status, response.status]);
         return Ok(response);
     }
 
@@ -305,7 +363,12 @@ pub trait ConnectorActions: Connector {
             },
             payment_info,
         );
-        Box::pin(call_connector(request, integration)).await
+        Box::pin(call_connector(
+            request,
+            integration,
+            self.get_max_concurrent_requests(),
+        ))
+        .await
     }
 
     async fn capture_payment_and_refund(
@@ -385,7 +448,9 @@ pub trait ConnectorActions: Connector {
             .unwrap();
 
         //try refund for previous payment
+        let payment_amount = response.request.amount;
         let transaction_id = get_connector_transaction_id(response.response).unwrap();
+        let mut refunded_amounts = Vec::new();
         for _x in 0..2 {
             tokio::time::sleep(Duration::from_secs(self.get_request_interval())).await; // to avoid 404 error
             let refund_response = self
@@ -396,11 +461,13 @@ pub trait ConnectorActions: Connector {
                 )
                 .await
                 .unwrap();
+            refunded_amounts.push(refund_response.request.refund_amount);
             assert_eq!(
                 refund_response.response.unwrap().refund_status,
                 enums::RefundStatus::Success,
             );
         }
+        lifecycle::assert_amount_conservation(payment_amount, &[payment_amount], &refunded_amounts);
     }
 
     async fn sync_refund(
@@ -420,12 +487,18 @@ pub trait ConnectorActions: Connector {
                 refund_amount: 100,
                 connector_metadata: None,
                 reason: None,
+                reason_code: None,
                 connector_refund_id: Some(refund_id),
                 browser_info: None,
             }),
             payment_info,
         );
-        Box::pin(call_connector(request, integration)).await
+        Box::pin(call_connector(
+            request,
+            integration,
+            self.get_max_concurrent_requests(),
+        ))
+        .await
     }
 
     /// will retry the rsync till the given status matches or retry max 3 times
@@ -467,6 +540,8 @@ pub trait ConnectorActions: Connector {
             types::PayoutsData {
                 payout_id: core_utils::get_or_generate_uuid("payout_id", None)
                     .map_or("payout_3154763247".to_string(), |p| p),
+                payout_attempt_id: core_utils::get_or_generate_uuid("payout_attempt_id", None)
+                    .map_or("payout_attempt_3154763247".to_string(), |p| p),
                 amount: 1,
                 connector_payout_id,
                 destination_currency: payment_info.to_owned().map_or(enums::Currency::EUR, |pi| {
@@ -498,7 +573,13 @@ pub trait ConnectorActions: Connector {
         RouterData {
             flow: PhantomData,
             merchant_id: self.get_name(),
-            customer_id: Some(self.get_name()),
+            // The references of the test are unique, so that the tests running in parallel do not
+            // share any objects with the connector
+            customer_id: Some(format!(
+                "{}_{}",
+                self.get_name(),
+                uuid::Uuid::new_v4().simple()
+            )),
             connector: self.get_name(),
             payment_id: uuid::Uuid::new_v4().to_string(),
             attempt_id: uuid::Uuid::new_v4().to_string(),
@@ -546,6 +627,7 @@ pub trait ConnectorActions: Connector {
             connector_http_status_code: None,
             apple_pay_flow: None,
             external_latency: None,
+            connector_request_id: None,
             frm_metadata: None,
             refund_id: None,
             dispute_id: None,
@@ -603,14 +685,11 @@ pub trait ConnectorActions: Connector {
         connector_integration
             .execute_pretasks(&mut request, &state)
             .await?;
-        let res = services::api::execute_connector_processing_step(
-            &state,
-            connector_integration,
-            &request,
-            payments::CallConnectorAction::Trigger,
-            None,
-        )
-        .await?;
+        let _permit =
+            acquire_request_permit(&request.connector, self.get_max_concurrent_requests()).await;
+        let res =
+            transcript::execute_connector_processing_step(&state, connector_integration, &request)
+                .await?;
         Ok(res.response.unwrap())
     }
 
@@ -644,14 +723,11 @@ pub trait ConnectorActions: Connector {
         connector_integration
             .execute_pretasks(&mut request, &state)
             .await?;
-        let res = services::api::execute_connector_processing_step(
-            &state,
-            connector_integration,
-            &request,
-            payments::CallConnectorAction::Trigger,
-            None,
-        )
-        .await?;
+        let _permit =
+            acquire_request_permit(&request.connector, self.get_max_concurrent_requests()).await;
+        let res =
+            transcript::execute_connector_processing_step(&state, connector_integration, &request)
+                .await?;
         Ok(res.response.unwrap())
     }
 
@@ -686,14 +762,11 @@ pub trait ConnectorActions: Connector {
         connector_integration
             .execute_pretasks(&mut request, &state)
             .await?;
-        let res = services::api::execute_connector_processing_step(
-            &state,
-            connector_integration,
-            &request,
-            payments::CallConnectorAction::Trigger,
-            None,
-        )
-        .await?;
+        let _permit =
+            acquire_request_permit(&request.connector, self.get_max_concurrent_requests()).await;
+        let res =
+            transcript::execute_connector_processing_step(&state, connector_integration, &request)
+                .await?;
         Ok(res.response.unwrap())
     }
 
@@ -728,14 +801,11 @@ pub trait ConnectorActions: Connector {
         connector_integration
             .execute_pretasks(&mut request, &state)
             .await?;
-        let res = services::api::execute_connector_processing_step(
-            &state,
-            connector_integration,
-            &request,
-            payments::CallConnectorAction::Trigger,
-            None,
-        )
-        .await?;
+        let _permit =
+            acquire_request_permit(&request.connector, self.get_max_concurrent_requests()).await;
+        let res =
+            transcript::execute_connector_processing_step(&state, connector_integration, &request)
+                .await?;
         Ok(res.response.unwrap())
     }
 
@@ -816,14 +886,11 @@ pub trait ConnectorActions: Connector {
         connector_integration
             .execute_pretasks(&mut request, &state)
             .await?;
-        let res = services::api::execute_connector_processing_step(
-            &state,
-            connector_integration,
-            &request,
-            payments::CallConnectorAction::Trigger,
-            None,
-        )
-        .await?;
+        let _permit =
+            acquire_request_permit(&request.connector, self.get_max_concurrent_requests()).await;
+        let res =
+            transcript::execute_connector_processing_step(&state, connector_integration, &request)
+                .await?;
         Ok(res.response.unwrap())
     }
 }
@@ -835,6 +902,7 @@ async fn call_connector<
 >(
     request: RouterData<T, Req, Resp>,
     integration: services::BoxedConnectorIntegration<'_, T, Req, Resp>,
+    max_concurrent_requests: usize,
 ) -> Result<RouterData<T, Req, Resp>, Report<ConnectorError>> {
     let conf = Settings::new().unwrap();
     let tx: oneshot::Sender<()> = oneshot::channel().0;
@@ -846,14 +914,23 @@ async fn call_connector<
         Box::new(services::MockApiClient),
     ))
     .await;
-    services::api::execute_connector_processing_step(
-        &state,
-        integration,
-        &request,
-        payments::CallConnectorAction::Trigger,
-        None,
-    )
-    .await
+    let _permit = acquire_request_permit(&request.connector, max_concurrent_requests).await;
+    transcript::execute_connector_processing_step(&state, integration, &request).await
+}
+
+/// Wait for a permit to make a request to the sandbox of the connector, to be held until the
+/// request has been made.
+async fn acquire_request_permit(
+    connector_name: &str,
+    max_concurrent_requests: usize,
+) -> OwnedSemaphorePermit {
+    let permits = CONNECTOR_REQUEST_PERMITS
+        .lock()
+        .unwrap()
+        .entry(connector_name.to_string())
+        .or_insert_with(|| Arc::new(Semaphore::new(max_concurrent_requests)))
+        .clone();
+    permits.acquire_owned().await.unwrap()
 }
 
 pub struct MockConfig {
@@ -937,9 +1014,16 @@ impl Default for PaymentAuthorizeType {
             customer_id: None,
             surcharge_details: None,
             request_incremental_authorization: false,
+            request_overcapture: false,
+            request_extended_authorization: false,
+            split_payments: None,
+            payment_channel: None,
+            l2_l3_data: None,
+            installment_tenure: None,
             metadata: None,
             authentication_data: None,
             customer_acceptance: None,
+            preferred_card_network: None,
         };
         Self(data)
     }
@@ -1014,6 +1098,7 @@ impl Default for PaymentRefundType {
             webhook_url: None,
             connector_metadata: None,
             reason: Some("Customer returned product".to_string()),
+            reason_code: None,
             connector_refund_id: None,
             browser_info: None,
         };
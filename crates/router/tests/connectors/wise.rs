@@ -39,8 +39,9 @@ impl utils::Connector for WiseTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .wise
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -28,8 +28,9 @@ impl utils::Connector for WorldlineTest {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            ConnectorAuthentication::new()
+            ConnectorAuthentication::get()
                 .worldline
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
@@ -107,9 +108,16 @@ impl WorldlineTest {
             customer_id: None,
             surcharge_details: None,
             request_incremental_authorization: false,
+            request_overcapture: false,
+            request_extended_authorization: false,
+            split_payments: None,
+            payment_channel: None,
+            l2_l3_data: None,
+            installment_tenure: None,
             metadata: None,
             authentication_data: None,
             customer_acceptance: None,
+            preferred_card_network: None,
         })
     }
 }
@@ -29,8 +29,9 @@ impl utils::Connector for Worldpay {
 
     fn get_auth_token(&self) -> types::ConnectorAuthType {
         utils::to_connector_auth_type(
-            connector_auth::ConnectorAuthentication::new()
+            connector_auth::ConnectorAuthentication::get()
                 .worldpay
+                .clone()
                 .expect("Missing connector authentication configuration")
                 .into(),
         )
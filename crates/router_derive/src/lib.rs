@@ -527,16 +527,22 @@ pub fn polymorphic_schema(input: proc_macro::TokenStream) -> proc_macro::TokenSt
 /// }
 /// ```
 ///
-/// This will call the `validate()` function for all the fields in the struct
+/// This will call the `validate()` function for all the fields in the struct, and report the
+/// errors of all the fields at once
 ///
 /// ```
 /// impl Connectors {
 ///      fn validate(&self) -> Result<(), ApplicationError> {
-///         self.stripe.validate()?;
-///         self.checkout.validate()?;
+///         let mut errors = ConfigValidationErrors::default();
+///         errors.check(self.stripe.validate());
+///         errors.check(self.checkout.validate());
+///         errors.into_result()
 ///      }
 /// }
 /// ```
+///
+/// `String` fields are checked to not be empty, and the ones with `url` in their names are
+/// additionally checked to be valid URLs.
 #[proc_macro_derive(ConfigValidate)]
 pub fn validate_config(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse_macro_input!(input as syn::DeriveInput);
@@ -27,19 +27,24 @@ pub fn validate_config(input: syn::DeriveInput) -> Result<proc_macro2::TokenStre
             // Do not call validate if it is an optional field
             if !is_optional_field {
                 let is_leaf_field = field_type_ident.eq("String");
-                let validate_expansion = if is_leaf_field {
-                quote::quote!(common_utils::fp_utils::when(
-                        self.#field_ident.is_empty(),
-                        || {
-                            Err(ApplicationError::InvalidConfigurationValueError(
-                                format!("{} must not be empty for {}", #field_ident_string, parent_field).into(),
-                            ))
+                let is_url_field = field_ident_string.contains("url");
+                let validate_expansion = if is_leaf_field && is_url_field {
+                    quote::quote!(
+                        if self.#field_ident.is_empty() {
+                            errors.push(format!("{} must not be empty for {}", #field_ident_string, parent_field));
+                        } else if url::Url::parse(&self.#field_ident).is_err() {
+                            errors.push(format!("{} must be a valid URL for {}", #field_ident_string, parent_field));
                         }
-                    )?;
-                )
+                    )
+                } else if is_leaf_field {
+                    quote::quote!(
+                        if self.#field_ident.is_empty() {
+                            errors.push(format!("{} must not be empty for {}", #field_ident_string, parent_field));
+                        }
+                    )
                 } else {
                     quote::quote!(
-                        self.#field_ident.validate(#field_ident_string)?;
+                        errors.check(self.#field_ident.validate(#field_ident_string));
                     )
                 };
                 Some(validate_expansion)
@@ -53,8 +58,9 @@ pub fn validate_config(input: syn::DeriveInput) -> Result<proc_macro2::TokenStre
     let expansion = quote::quote! {
         impl #struct_name {
             pub fn validate(&self, parent_field: &str) -> Result<(), ApplicationError> {
+                let mut errors = ConfigValidationErrors::default();
                 #(#function_expansions)*
-                Ok(())
+                errors.into_result()
             }
         }
     };
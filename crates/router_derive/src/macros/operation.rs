@@ -29,6 +29,8 @@ pub enum Derives {
     SessionData,
     IncrementalAuthorization,
     IncrementalAuthorizationData,
+    VerifyMicroDeposits,
+    VerifyMicroDepositsData,
 }
 
 impl Derives {
@@ -103,6 +105,12 @@ impl Conversion {
             Derives::IncrementalAuthorizationData => {
                 syn::Ident::new("PaymentsIncrementalAuthorizationData", Span::call_site())
             }
+            Derives::VerifyMicroDeposits => {
+                syn::Ident::new("PaymentsVerifyMicroDepositsRequest", Span::call_site())
+            }
+            Derives::VerifyMicroDepositsData => {
+                syn::Ident::new("PaymentsVerifyMicroDepositsData", Span::call_site())
+            }
         }
     }
 
@@ -423,6 +431,7 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
                     PaymentsSessionData,
                     CompleteAuthorizeData,
                     PaymentsIncrementalAuthorizationData,
+                    PaymentsVerifyMicroDepositsData,
 
                     api::{
                         PaymentsCaptureRequest,
@@ -434,7 +443,8 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
                         PaymentsStartRequest,
                         PaymentsSessionRequest,
                         VerifyRequest,
-                        PaymentsIncrementalAuthorizationRequest
+                        PaymentsIncrementalAuthorizationRequest,
+                        PaymentsVerifyMicroDepositsRequest
                     }
                 };
                 #trait_derive
@@ -75,6 +75,10 @@ pub enum Flow {
     MerchantConnectorsRetrieve,
     /// Merchant account list
     MerchantAccountList,
+    /// Merchant account key custody update flow.
+    MerchantKeyCustodyUpdate,
+    /// Merchant account feature entitlements update flow.
+    MerchantFeatureEntitlementsUpdate,
     /// Merchant Connectors update flow.
     MerchantConnectorsUpdate,
     /// Merchant Connectors delete flow.
@@ -192,6 +196,8 @@ pub enum Flow {
     RefundsList,
     // Retrieve forex flow.
     RetrieveForexFlow,
+    /// Localize forex amount flow.
+    LocalizeForexAmountFlow,
     /// Toggles recon service for a merchant.
     ReconMerchantUpdate,
     /// Recon token request flow.
@@ -268,10 +274,14 @@ pub enum Flow {
     RetrieveDisputeEvidence,
     /// Invalidate cache flow
     CacheInvalidate,
+    /// Payment Link Create flow
+    PaymentLinkCreate,
     /// Payment Link Retrieve flow
     PaymentLinkRetrieve,
     /// payment Link Initiate flow
     PaymentLinkInitiate,
+    /// Hosted checkout initiate flow
+    HostedCheckoutInitiate,
     /// Payment Link List flow
     PaymentLinkList,
     /// Payment Link Status
@@ -288,6 +298,8 @@ pub enum Flow {
     BusinessProfileList,
     /// Different verification flows
     Verification,
+    /// Register Apple Pay domains with the connectors of a business profile
+    ApplepayProfileDomainRegistration,
     /// Rust locker migration
     RustLockerMigration,
     /// Gsm Rule Creation flow
@@ -372,6 +384,10 @@ pub enum Flow {
     DeleteUserRole,
     /// Incremental Authorization flow
     PaymentsIncrementalAuthorization,
+    /// Micro-deposits verification flow
+    PaymentsVerifyMicroDeposits,
+    /// Payments manual retry flow
+    PaymentsRetry,
     /// Get action URL for connector onboarding
     GetActionUrl,
     /// Sync connector onboarding status
@@ -402,12 +418,48 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// List the schemas of the outgoing webhook payloads
+    WebhookSchemasList,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
     ToggleExtendedCardInfo,
+    /// Enable the webhook endpoint of a business profile disabled after delivery failures
+    WebhookEndpointEnable,
+    /// Retrieve the wallets a business profile has certificates for
+    WalletCertificatesRetrieve,
+    /// Set the certificates of the wallets of a business profile
+    WalletCertificatesUpdate,
+    /// Remove the certificates of a wallet of a business profile
+    WalletCertificatesDelete,
     /// Get the extended card info associated to a payment_id
     GetExtendedCardInfo,
+    /// Suggest the payment methods most likely to succeed for a payment
+    PaymentsSuggestedMethods,
+    /// Look up the Click to Pay profiles of a customer
+    ClickToPayIdentityLookup,
+    /// Enroll a card in the Click to Pay profile of a customer
+    ClickToPayEnrollment,
+    /// Calculate the tax on a payment for its shipping address
+    PaymentsTaxCalculation,
+    /// Replace the tags of a payment
+    PaymentsTagsUpdate,
+    /// Save a filter of the payments list of a merchant
+    SavedPaymentFilterSave,
+    /// List the saved filters of the payments list of a merchant
+    SavedPaymentFilterList,
+    /// Delete a saved filter of the payments list of a merchant
+    SavedPaymentFilterDelete,
+    /// Export the usage metered for merchants
+    UsageExport,
+    /// Migrate the payment methods and mandates of a merchant from one connector to another
+    ConnectorMigrationCreate,
+    /// Retrieve the status and outcomes of a connector migration
+    ConnectorMigrationRetrieve,
+    /// Export all the data held about a customer
+    CustomerDataExportCreate,
+    /// Retrieve the status and data of a customer data export
+    CustomerDataExportRetrieve,
 }
 
 ///
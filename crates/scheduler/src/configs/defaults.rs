@@ -7,6 +7,15 @@ impl Default for super::settings::SchedulerSettings {
             graceful_shutdown_interval: 60000,
             loop_interval: 5000,
             server: super::settings::Server::default(),
+            jitter: super::settings::JitterSettings::default(),
+        }
+    }
+}
+
+impl Default for super::settings::JitterSettings {
+    fn default() -> Self {
+        Self {
+            max_jitter_in_secs: 0,
         }
     }
 }
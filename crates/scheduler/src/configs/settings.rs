@@ -10,6 +10,16 @@ pub struct SchedulerSettings {
     pub loop_interval: u64,
     pub graceful_shutdown_interval: u64,
     pub server: Server,
+    pub jitter: JitterSettings,
+}
+
+/// Random delays added to the schedule times of the tasks scheduled in mass for the same time,
+/// such as for a time of the day, so that the tasks are not all picked up at once
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct JitterSettings {
+    /// Largest delay added to the schedule time of a task (in seconds)
+    pub max_jitter_in_secs: u32,
 }
 
 #[derive(Debug, Deserialize, Clone)]
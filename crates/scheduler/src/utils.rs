@@ -16,8 +16,11 @@ use super::{
     env::logger,
 };
 use crate::{
-    configs::settings::SchedulerSettings, consumer::types::ProcessTrackerBatch, errors,
-    flow::SchedulerFlow, metrics, SchedulerAppState, SchedulerInterface,
+    configs::settings::{JitterSettings, SchedulerSettings},
+    consumer::types::ProcessTrackerBatch,
+    errors,
+    flow::SchedulerFlow,
+    metrics, SchedulerAppState, SchedulerInterface,
 };
 
 pub async fn divide_and_append_tasks<T>(
@@ -354,6 +357,61 @@ pub fn get_outgoing_webhook_retry_schedule_time(
     }
 }
 
+/// Time in UTC at which a task scheduled for a time of the day in the timezone of the merchant
+/// runs on the date
+pub fn get_utc_schedule_time(
+    date: time::Date,
+    local_time: time::Time,
+    utc_offset: time::UtcOffset,
+) -> time::PrimitiveDateTime {
+    let schedule_time = time::PrimitiveDateTime::new(date, local_time)
+        .assume_offset(utc_offset)
+        .to_offset(time::UtcOffset::UTC);
+    time::PrimitiveDateTime::new(schedule_time.date(), schedule_time.time())
+}
+
+/// Delay the schedule time of a task by a random duration of up to the configured jitter, so that
+/// the tasks scheduled in mass for the same time are spread over the jitter
+pub fn add_jitter(
+    schedule_time: time::PrimitiveDateTime,
+    jitter: &JitterSettings,
+) -> time::PrimitiveDateTime {
+    use rand::distributions::{Distribution, Uniform};
+
+    if jitter.max_jitter_in_secs == 0 {
+        return schedule_time;
+    }
+    let jitter_in_secs =
+        Uniform::new_inclusive(0, jitter.max_jitter_in_secs).sample(&mut rand::thread_rng());
+    schedule_time.saturating_add(time::Duration::seconds(i64::from(jitter_in_secs)))
+}
+
+/// Parse the UTC offset of the timezone of a merchant, such as `+05:30` or `-08:00`
+pub fn parse_utc_offset(
+    utc_offset: &str,
+) -> CustomResult<time::UtcOffset, errors::ProcessTrackerError> {
+    let invalid_utc_offset = || {
+        report!(errors::ProcessTrackerError::ConfigurationError)
+            .attach_printable(format!("Invalid UTC offset `{utc_offset}`"))
+    };
+
+    let (sign, offset) = if let Some(offset) = utc_offset.strip_prefix('+') {
+        (1, offset)
+    } else if let Some(offset) = utc_offset.strip_prefix('-') {
+        (-1, offset)
+    } else {
+        return Err(invalid_utc_offset());
+    };
+    let (hours, minutes) = offset.split_once(':').ok_or_else(invalid_utc_offset)?;
+    if hours.len() != 2 || minutes.len() != 2 {
+        return Err(invalid_utc_offset());
+    }
+    let hours = hours.parse::<i8>().map_err(|_| invalid_utc_offset())?;
+    let minutes = minutes.parse::<i8>().map_err(|_| invalid_utc_offset())?;
+
+    time::UtcOffset::from_hms(sign * hours, sign * minutes, 0).map_err(|_| invalid_utc_offset())
+}
+
 /// Get the delay based on the retry count
 fn get_delay<'a>(
     retry_count: i32,
@@ -410,6 +468,7 @@ where
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::unwrap_used)]
     use super::*;
 
     #[test]
@@ -440,4 +499,55 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_get_utc_schedule_time() {
+        let date = time::Date::from_calendar_date(2024, time::Month::June, 1).unwrap();
+        let local_time = time::Time::from_hms(9, 0, 0).unwrap();
+
+        let schedule_time =
+            get_utc_schedule_time(date, local_time, parse_utc_offset("+05:30").unwrap());
+        assert_eq!(schedule_time.date(), date);
+        assert_eq!(
+            schedule_time.time(),
+            time::Time::from_hms(3, 30, 0).unwrap()
+        );
+
+        let schedule_time =
+            get_utc_schedule_time(date, local_time, parse_utc_offset("-20:00").unwrap());
+        assert_eq!(schedule_time.date(), date.next_day().unwrap());
+        assert_eq!(schedule_time.time(), time::Time::from_hms(5, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_parse_utc_offset() {
+        assert_eq!(
+            parse_utc_offset("-08:00").unwrap(),
+            time::UtcOffset::from_hms(-8, 0, 0).unwrap()
+        );
+        for utc_offset in ["05:30", "+5:30", "+05", "+26:00", "+05:60", ""] {
+            assert!(
+                parse_utc_offset(utc_offset).is_err(),
+                "`{utc_offset}` should not be parsed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_jitter() {
+        let schedule_time = common_utils::date_time::now();
+        let jitter = JitterSettings {
+            max_jitter_in_secs: 60,
+        };
+
+        for _ in 0..20 {
+            let jittered_schedule_time = add_jitter(schedule_time, &jitter);
+            assert!(jittered_schedule_time >= schedule_time);
+            assert!(jittered_schedule_time <= schedule_time + time::Duration::seconds(60));
+        }
+        assert_eq!(
+            add_jitter(schedule_time, &JitterSettings::default()),
+            schedule_time
+        );
+    }
 }
@@ -93,6 +93,10 @@ impl Into<DataStorageError> for &StorageError {
                 }
                 RedisError::JsonSerializationFailed => DataStorageError::SerializationFailed,
                 RedisError::JsonDeserializationFailed => DataStorageError::DeserializationFailed,
+                RedisError::MessagePackSerializationFailed => DataStorageError::SerializationFailed,
+                RedisError::MessagePackDeserializationFailed => {
+                    DataStorageError::DeserializationFailed
+                }
                 i => DataStorageError::RedisError(format!("{:?}", i)),
             },
         }
@@ -162,6 +166,12 @@ pub enum ApplicationError {
     #[error("Invalid configuration value provided: {0}")]
     InvalidConfigurationValueError(String),
 
+    #[error(
+        "Invalid configuration values provided:\n{}",
+        .0.iter().map(|error| format!("  - {error}")).collect::<Vec<_>>().join("\n")
+    )]
+    InvalidConfigurationValueErrors(Vec<String>),
+
     #[error("Metrics error: {0}")]
     MetricsError(MetricsError),
 
@@ -172,6 +182,34 @@ pub enum ApplicationError {
     ApiClientError(ApiClientError),
 }
 
+/// Collects the errors encountered when validating the configuration, so that all of them are
+/// reported at once instead of only the first one.
+#[derive(Debug, Default)]
+pub struct ConfigValidationErrors(Vec<String>);
+
+impl ConfigValidationErrors {
+    pub fn check(&mut self, result: ApplicationResult<()>) {
+        match result {
+            Ok(()) => {}
+            Err(ApplicationError::InvalidConfigurationValueErrors(errors)) => self.0.extend(errors),
+            Err(ApplicationError::InvalidConfigurationValueError(error)) => self.0.push(error),
+            Err(error) => self.0.push(error.to_string()),
+        }
+    }
+
+    pub fn push(&mut self, error: impl Into<String>) {
+        self.0.push(error.into());
+    }
+
+    pub fn into_result(self) -> ApplicationResult<()> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(ApplicationError::InvalidConfigurationValueErrors(self.0))
+        }
+    }
+}
+
 impl From<MetricsError> for ApplicationError {
     fn from(err: MetricsError) -> Self {
         Self::MetricsError(err)
@@ -203,6 +241,7 @@ impl ResponseError for ApplicationError {
             | Self::IoError(_)
             | Self::ConfigurationError(_)
             | Self::InvalidConfigurationValueError(_)
+            | Self::InvalidConfigurationValueErrors(_)
             | Self::ApiClientError(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -4,7 +4,7 @@ use diesel_models as store;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::errors::{StorageError, StorageResult};
 use masking::StrongSecret;
-use redis::{kv_store::RedisConnInterface, RedisStore};
+use redis::{kv_serialization::KvSerializationConfig, kv_store::RedisConnInterface, RedisStore};
 mod address;
 pub mod config;
 pub mod connection;
@@ -150,6 +150,7 @@ pub struct KVRouterStore<T: DatabaseStore> {
     drainer_stream_name: String,
     drainer_num_partitions: u8,
     ttl_for_kv: u32,
+    kv_serialization: KvSerializationConfig,
     pub request_id: Option<String>,
 }
 
@@ -159,14 +160,21 @@ where
     RouterStore<T>: DatabaseStore,
     T: DatabaseStore,
 {
-    type Config = (RouterStore<T>, String, u8, u32);
+    type Config = (RouterStore<T>, String, u8, u32, KvSerializationConfig);
     async fn new(config: Self::Config, _test_transaction: bool) -> StorageResult<Self> {
-        let (router_store, drainer_stream_name, drainer_num_partitions, ttl_for_kv) = config;
+        let (
+            router_store,
+            drainer_stream_name,
+            drainer_num_partitions,
+            ttl_for_kv,
+            kv_serialization,
+        ) = config;
         Ok(Self::from_store(
             router_store,
             drainer_stream_name,
             drainer_num_partitions,
             ttl_for_kv,
+            kv_serialization,
         ))
     }
     fn get_master_pool(&self) -> &PgPool {
@@ -191,6 +199,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         drainer_stream_name: String,
         drainer_num_partitions: u8,
         ttl_for_kv: u32,
+        kv_serialization: KvSerializationConfig,
     ) -> Self {
         let request_id = store.request_id.clone();
 
@@ -199,6 +208,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
             drainer_stream_name,
             drainer_num_partitions,
             ttl_for_kv,
+            kv_serialization,
             request_id,
         }
     }
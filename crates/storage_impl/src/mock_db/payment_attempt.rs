@@ -157,6 +157,11 @@ impl PaymentAttemptInterface for MockDb {
             mandate_data: payment_attempt.mandate_data,
             payment_method_billing_address_id: payment_attempt.payment_method_billing_address_id,
             fingerprint_id: payment_attempt.fingerprint_id,
+            connector_request_id: payment_attempt.connector_request_id,
+            extended_authorization_applied: payment_attempt.extended_authorization_applied,
+            capture_before: payment_attempt.capture_before,
+            amount_reversed: payment_attempt.amount_reversed,
+            overcaptured_amount: payment_attempt.overcaptured_amount,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
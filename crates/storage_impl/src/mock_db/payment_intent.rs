@@ -108,6 +108,15 @@ impl PaymentIntentInterface for MockDb {
             fingerprint_id: new.fingerprint_id,
             session_expiry: new.session_expiry,
             request_external_three_ds_authentication: new.request_external_three_ds_authentication,
+            request_overcapture: new.request_overcapture,
+            request_extended_authorization: new.request_extended_authorization,
+            order_tax_amount: new.order_tax_amount,
+            split_payments: new.split_payments,
+            payment_channel: new.payment_channel,
+            l2_l3_data: new.l2_l3_data,
+            tags: new.tags,
+            capture_adjustment_reason: new.capture_adjustment_reason,
+            installment_tenure: new.installment_tenure,
         };
         payment_intents.push(payment_intent.clone());
         Ok(payment_intent)
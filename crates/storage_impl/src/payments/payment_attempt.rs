@@ -410,6 +410,11 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                         .payment_method_billing_address_id
                         .clone(),
                     fingerprint_id: payment_attempt.fingerprint_id.clone(),
+                    connector_request_id: payment_attempt.connector_request_id.clone(),
+                    extended_authorization_applied: payment_attempt.extended_authorization_applied,
+                    capture_before: payment_attempt.capture_before,
+                    amount_reversed: payment_attempt.amount_reversed,
+                    overcaptured_amount: payment_attempt.overcaptured_amount,
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -489,8 +494,6 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                         .apply_changeset(this.clone().to_storage_model()),
                 );
                 // Check for database presence as well Maybe use a read replica here ?
-                let redis_value = serde_json::to_string(&updated_attempt)
-                    .change_context(errors::StorageError::KVError)?;
                 let field = format!("pa_{}", updated_attempt.attempt_id);
 
                 let redis_entry = kv::TypedSql {
@@ -565,7 +568,10 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
 
                 kv_wrapper::<(), _, _>(
                     self,
-                    KvOperation::Hset::<DieselPaymentAttempt>((&field, redis_value), redis_entry),
+                    KvOperation::Hset::<DieselPaymentAttempt>(
+                        (&field, &updated_attempt),
+                        redis_entry,
+                    ),
                     key,
                 )
                 .await
@@ -1164,6 +1170,11 @@ impl DataModelExt for PaymentAttempt {
             mandate_data: self.mandate_data.map(|d| d.to_storage_model()),
             payment_method_billing_address_id: self.payment_method_billing_address_id,
             fingerprint_id: self.fingerprint_id,
+            connector_request_id: self.connector_request_id,
+            extended_authorization_applied: self.extended_authorization_applied,
+            capture_before: self.capture_before,
+            amount_reversed: self.amount_reversed,
+            overcaptured_amount: self.overcaptured_amount,
         }
     }
 
@@ -1228,6 +1239,11 @@ impl DataModelExt for PaymentAttempt {
                 .map(MandateDetails::from_storage_model),
             payment_method_billing_address_id: storage_model.payment_method_billing_address_id,
             fingerprint_id: storage_model.fingerprint_id,
+            connector_request_id: storage_model.connector_request_id,
+            extended_authorization_applied: storage_model.extended_authorization_applied,
+            capture_before: storage_model.capture_before,
+            amount_reversed: storage_model.amount_reversed,
+            overcaptured_amount: storage_model.overcaptured_amount,
         }
     }
 }
@@ -1290,6 +1306,11 @@ impl DataModelExt for PaymentAttemptNew {
             mandate_data: self.mandate_data.map(|d| d.to_storage_model()),
             payment_method_billing_address_id: self.payment_method_billing_address_id,
             fingerprint_id: self.fingerprint_id,
+            connector_request_id: self.connector_request_id,
+            extended_authorization_applied: self.extended_authorization_applied,
+            capture_before: self.capture_before,
+            amount_reversed: self.amount_reversed,
+            overcaptured_amount: self.overcaptured_amount,
         }
     }
 
@@ -1352,6 +1373,11 @@ impl DataModelExt for PaymentAttemptNew {
                 .map(MandateDetails::from_storage_model),
             payment_method_billing_address_id: storage_model.payment_method_billing_address_id,
             fingerprint_id: storage_model.fingerprint_id,
+            connector_request_id: storage_model.connector_request_id,
+            extended_authorization_applied: storage_model.extended_authorization_applied,
+            capture_before: storage_model.capture_before,
+            amount_reversed: storage_model.amount_reversed,
+            overcaptured_amount: storage_model.overcaptured_amount,
         }
     }
 }
@@ -1520,12 +1546,16 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                overcaptured_amount,
                 updated_by,
                 authentication_data,
                 encoded_data,
                 unified_code,
                 unified_message,
                 payment_method_data,
+                connector_request_id,
+                extended_authorization_applied,
+                capture_before,
             } => DieselPaymentAttemptUpdate::ResponseUpdate {
                 status,
                 connector,
@@ -1540,12 +1570,16 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                overcaptured_amount,
                 updated_by,
                 authentication_data,
                 encoded_data,
                 unified_code,
                 unified_message,
                 payment_method_data,
+                connector_request_id,
+                extended_authorization_applied,
+                capture_before,
             },
             Self::UnresolvedResponseUpdate {
                 status,
@@ -1583,6 +1617,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                connector_request_id,
             } => DieselPaymentAttemptUpdate::ErrorUpdate {
                 connector,
                 status,
@@ -1595,6 +1630,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                connector_request_id,
             },
             Self::CaptureUpdate {
                 multiple_capture_count,
@@ -1636,10 +1672,23 @@ impl DataModelExt for PaymentAttemptUpdate {
             Self::AmountToCaptureUpdate {
                 status,
                 amount_capturable,
+                overcaptured_amount,
                 updated_by,
             } => DieselPaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
                 amount_capturable,
+                overcaptured_amount,
+                updated_by,
+            },
+            Self::PartialReversalUpdate {
+                status,
+                amount_reversed,
+                cancellation_reason,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::PartialReversalUpdate {
+                status,
+                amount_reversed,
+                cancellation_reason,
                 updated_by,
             },
             Self::ConnectorResponse {
@@ -1675,6 +1724,15 @@ impl DataModelExt for PaymentAttemptUpdate {
                 authentication_id,
                 updated_by,
             },
+            Self::TaxCalculationUpdate {
+                amount,
+                amount_capturable,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::TaxCalculationUpdate {
+                amount,
+                amount_capturable,
+                updated_by,
+            },
         }
     }
 
@@ -1839,12 +1897,16 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                overcaptured_amount,
                 updated_by,
                 authentication_data,
                 encoded_data,
                 unified_code,
                 unified_message,
                 payment_method_data,
+                connector_request_id,
+                extended_authorization_applied,
+                capture_before,
             } => Self::ResponseUpdate {
                 status,
                 connector,
@@ -1859,12 +1921,16 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                overcaptured_amount,
                 updated_by,
                 authentication_data,
                 encoded_data,
                 unified_code,
                 unified_message,
                 payment_method_data,
+                connector_request_id,
+                extended_authorization_applied,
+                capture_before,
             },
             DieselPaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -1902,6 +1968,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                connector_request_id,
             } => Self::ErrorUpdate {
                 connector,
                 status,
@@ -1914,6 +1981,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
                 payment_method_data,
+                connector_request_id,
             },
             DieselPaymentAttemptUpdate::CaptureUpdate {
                 amount_to_capture,
@@ -1955,10 +2023,23 @@ impl DataModelExt for PaymentAttemptUpdate {
             DieselPaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
                 amount_capturable,
+                overcaptured_amount,
                 updated_by,
             } => Self::AmountToCaptureUpdate {
                 status,
                 amount_capturable,
+                overcaptured_amount,
+                updated_by,
+            },
+            DieselPaymentAttemptUpdate::PartialReversalUpdate {
+                status,
+                amount_reversed,
+                cancellation_reason,
+                updated_by,
+            } => Self::PartialReversalUpdate {
+                status,
+                amount_reversed,
+                cancellation_reason,
                 updated_by,
             },
             DieselPaymentAttemptUpdate::ConnectorResponse {
@@ -1994,6 +2075,15 @@ impl DataModelExt for PaymentAttemptUpdate {
                 authentication_id,
                 updated_by,
             },
+            DieselPaymentAttemptUpdate::TaxCalculationUpdate {
+                amount,
+                amount_capturable,
+                updated_by,
+            } => Self::TaxCalculationUpdate {
+                amount,
+                amount_capturable,
+                updated_by,
+            },
         }
     }
 }
@@ -2,11 +2,13 @@
 use api_models::payments::AmountFilter;
 #[cfg(feature = "olap")]
 use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
+use common_utils::date_time;
 #[cfg(feature = "olap")]
 use common_utils::errors::ReportSwitchExt;
-use common_utils::{date_time, ext_traits::Encode};
 #[cfg(feature = "olap")]
-use diesel::{associations::HasTable, ExpressionMethods, JoinOnDsl, QueryDsl};
+use diesel::{
+    associations::HasTable, ExpressionMethods, JoinOnDsl, PgArrayExpressionMethods, QueryDsl,
+};
 use diesel_models::{
     enums::MerchantStorageScheme,
     kv,
@@ -115,6 +117,15 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     session_expiry: new.session_expiry,
                     request_external_three_ds_authentication: new
                         .request_external_three_ds_authentication,
+                    request_overcapture: new.request_overcapture,
+                    request_extended_authorization: new.request_extended_authorization,
+                    order_tax_amount: new.order_tax_amount,
+                    split_payments: new.split_payments.clone(),
+                    payment_channel: new.payment_channel,
+                    l2_l3_data: new.l2_l3_data.clone(),
+                    tags: new.tags.clone(),
+                    capture_adjustment_reason: new.capture_adjustment_reason,
+                    installment_tenure: new.installment_tenure,
                 };
                 let redis_entry = kv::TypedSql {
                     op: kv::DBOperation::Insert {
@@ -178,10 +189,6 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     .apply_changeset(origin_diesel_intent.clone());
                 // Check for database presence as well Maybe use a read replica here ?
 
-                let redis_value = diesel_intent
-                    .encode_to_string_of_json()
-                    .change_context(StorageError::SerializationFailed)?;
-
                 let redis_entry = kv::TypedSql {
                     op: kv::DBOperation::Update {
                         updatable: kv::Updateable::PaymentIntentUpdate(
@@ -195,7 +202,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
 
                 kv_wrapper::<(), _, _>(
                     self,
-                    KvOperation::<DieselPaymentIntent>::Hset((&field, redis_value), redis_entry),
+                    KvOperation::<DieselPaymentIntent>::Hset((&field, &diesel_intent), redis_entry),
                     key,
                 )
                 .await
@@ -454,6 +461,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(pi_dsl::profile_id.eq(profile_id.clone()));
                 }
+                if let Some(tags) = &params.tags {
+                    query = query.filter(pi_dsl::tags.contains(tags.clone()));
+                }
 
                 query = match (params.starting_at, &params.starting_after_id) {
                     (Some(starting_at), _) => query.filter(pi_dsl::created_at.ge(starting_at)),
@@ -582,6 +592,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(pi_dsl::profile_id.eq(profile_id.clone()));
                 }
+                if let Some(tags) = &params.tags {
+                    query = query.filter(pi_dsl::tags.contains(tags.clone()));
+                }
 
                 query = match (params.starting_at, &params.starting_after_id) {
                     (Some(starting_at), _) => query.filter(pi_dsl::created_at.ge(starting_at)),
@@ -737,6 +750,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(pi_dsl::profile_id.eq(profile_id.clone()));
                 }
+                if let Some(tags) = &params.tags {
+                    query = query.filter(pi_dsl::tags.contains(tags.clone()));
+                }
 
                 query = match params.starting_at {
                     Some(starting_at) => query.filter(pi_dsl::created_at.ge(starting_at)),
@@ -839,6 +855,15 @@ impl DataModelExt for PaymentIntentNew {
             fingerprint_id: self.fingerprint_id,
             session_expiry: self.session_expiry,
             request_external_three_ds_authentication: self.request_external_three_ds_authentication,
+            request_overcapture: self.request_overcapture,
+            request_extended_authorization: self.request_extended_authorization,
+            order_tax_amount: self.order_tax_amount,
+            split_payments: self.split_payments,
+            payment_channel: self.payment_channel,
+            l2_l3_data: self.l2_l3_data,
+            tags: self.tags,
+            capture_adjustment_reason: self.capture_adjustment_reason,
+            installment_tenure: self.installment_tenure,
         }
     }
 
@@ -886,6 +911,15 @@ impl DataModelExt for PaymentIntentNew {
             session_expiry: storage_model.session_expiry,
             request_external_three_ds_authentication: storage_model
                 .request_external_three_ds_authentication,
+            request_overcapture: storage_model.request_overcapture,
+            request_extended_authorization: storage_model.request_extended_authorization,
+            order_tax_amount: storage_model.order_tax_amount,
+            split_payments: storage_model.split_payments,
+            payment_channel: storage_model.payment_channel,
+            l2_l3_data: storage_model.l2_l3_data,
+            tags: storage_model.tags,
+            capture_adjustment_reason: storage_model.capture_adjustment_reason,
+            installment_tenure: storage_model.installment_tenure,
         }
     }
 }
@@ -937,6 +971,15 @@ impl DataModelExt for PaymentIntent {
             fingerprint_id: self.fingerprint_id,
             session_expiry: self.session_expiry,
             request_external_three_ds_authentication: self.request_external_three_ds_authentication,
+            request_overcapture: self.request_overcapture,
+            request_extended_authorization: self.request_extended_authorization,
+            order_tax_amount: self.order_tax_amount,
+            split_payments: self.split_payments,
+            payment_channel: self.payment_channel,
+            l2_l3_data: self.l2_l3_data,
+            tags: self.tags,
+            capture_adjustment_reason: self.capture_adjustment_reason,
+            installment_tenure: self.installment_tenure,
         }
     }
 
@@ -985,6 +1028,15 @@ impl DataModelExt for PaymentIntent {
             session_expiry: storage_model.session_expiry,
             request_external_three_ds_authentication: storage_model
                 .request_external_three_ds_authentication,
+            request_overcapture: storage_model.request_overcapture,
+            request_extended_authorization: storage_model.request_extended_authorization,
+            order_tax_amount: storage_model.order_tax_amount,
+            split_payments: storage_model.split_payments,
+            payment_channel: storage_model.payment_channel,
+            l2_l3_data: storage_model.l2_l3_data,
+            tags: storage_model.tags,
+            capture_adjustment_reason: storage_model.capture_adjustment_reason,
+            installment_tenure: storage_model.installment_tenure,
         }
     }
 }
@@ -1072,6 +1124,7 @@ impl DataModelExt for PaymentIntentUpdate {
                 fingerprint_id,
                 session_expiry,
                 request_external_three_ds_authentication,
+                installment_tenure,
             } => DieselPaymentIntentUpdate::Update {
                 amount,
                 currency,
@@ -1093,6 +1146,7 @@ impl DataModelExt for PaymentIntentUpdate {
                 fingerprint_id,
                 session_expiry,
                 request_external_three_ds_authentication,
+                installment_tenure,
             },
             Self::PaymentAttemptAndAttemptCountUpdate {
                 active_attempt_id,
@@ -1147,6 +1201,39 @@ impl DataModelExt for PaymentIntentUpdate {
             } => DieselPaymentIntentUpdate::AuthorizationCountUpdate {
                 authorization_count,
             },
+            Self::PaymentLinkUpdate {
+                payment_link_id,
+                updated_by,
+            } => DieselPaymentIntentUpdate::PaymentLinkUpdate {
+                payment_link_id,
+                updated_by,
+            },
+            Self::TaxCalculationUpdate {
+                amount,
+                order_tax_amount,
+                shipping_address_id,
+                updated_by,
+            } => DieselPaymentIntentUpdate::TaxCalculationUpdate {
+                amount,
+                order_tax_amount,
+                shipping_address_id,
+                updated_by,
+            },
+            Self::TagsUpdate { tags, updated_by } => {
+                DieselPaymentIntentUpdate::TagsUpdate { tags, updated_by }
+            }
+            Self::ExpiryUpdate { status, updated_by } => {
+                DieselPaymentIntentUpdate::ExpiryUpdate { status, updated_by }
+            }
+            Self::CaptureAdjustmentUpdate {
+                capture_adjustment_reason,
+                order_details,
+                updated_by,
+            } => DieselPaymentIntentUpdate::CaptureAdjustmentUpdate {
+                capture_adjustment_reason,
+                order_details,
+                updated_by,
+            },
         }
     }
 
@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 use api_models::enums::PayoutConnectors;
-use common_utils::{errors::CustomResult, ext_traits::Encode, fallback_reverse_lookup_not_found};
+use common_utils::{errors::CustomResult, fallback_reverse_lookup_not_found};
 use diesel_models::{
     enums::MerchantStorageScheme,
     kv,
@@ -9,7 +9,7 @@ use diesel_models::{
         PayoutAttempt as DieselPayoutAttempt, PayoutAttemptNew as DieselPayoutAttemptNew,
         PayoutAttemptUpdate as DieselPayoutAttemptUpdate,
     },
-    ReverseLookupNew,
+    ReverseLookup, ReverseLookupNew,
 };
 use error_stack::ResultExt;
 use hyperswitch_domain_models::{
@@ -158,9 +158,21 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
                     .apply_changeset(origin_diesel_payout.clone());
                 // Check for database presence as well Maybe use a read replica here ?
 
-                let redis_value = diesel_payout
-                    .encode_to_string_of_json()
-                    .change_context(errors::StorageError::SerializationFailed)?;
+                if !diesel_payout.connector_payout_id.is_empty()
+                    && diesel_payout
+                        .connector_payout_id
+                        .ne(&origin_diesel_payout.connector_payout_id)
+                {
+                    add_connector_payout_id_to_reverse_lookup(
+                        self,
+                        key_str.as_str(),
+                        this.merchant_id.as_str(),
+                        this.payout_attempt_id.as_str(),
+                        diesel_payout.connector_payout_id.as_str(),
+                        storage_scheme,
+                    )
+                    .await?;
+                }
 
                 let redis_entry = kv::TypedSql {
                     op: kv::DBOperation::Update {
@@ -175,7 +187,7 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
 
                 kv_wrapper::<(), _, _>(
                     self,
-                    KvOperation::<DieselPayoutAttempt>::Hset((&field, redis_value), redis_entry),
+                    KvOperation::<DieselPayoutAttempt>::Hset((&field, &diesel_payout), redis_entry),
                     key,
                 )
                 .await
@@ -246,6 +258,64 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn find_payout_attempt_by_merchant_id_connector_payout_id(
+        &self,
+        merchant_id: &str,
+        connector_payout_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<PayoutAttempt, errors::StorageError> {
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => {
+                self.router_store
+                    .find_payout_attempt_by_merchant_id_connector_payout_id(
+                        merchant_id,
+                        connector_payout_id,
+                        storage_scheme,
+                    )
+                    .await
+            }
+            MerchantStorageScheme::RedisKv => {
+                let lookup_id = format!("poa_conn_payout_{merchant_id}_{connector_payout_id}");
+                let lookup = fallback_reverse_lookup_not_found!(
+                    self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                        .await,
+                    self.router_store
+                        .find_payout_attempt_by_merchant_id_connector_payout_id(
+                            merchant_id,
+                            connector_payout_id,
+                            storage_scheme
+                        )
+                        .await
+                );
+                let key = PartitionKey::CombinationKey {
+                    combination: &lookup.pk_id,
+                };
+                Box::pin(utils::try_redis_get_else_try_database_get(
+                    async {
+                        kv_wrapper(
+                            self,
+                            KvOperation::<DieselPayoutAttempt>::HGet(&lookup.sk_id),
+                            key,
+                        )
+                        .await?
+                        .try_into_hget()
+                    },
+                    || async {
+                        self.router_store
+                            .find_payout_attempt_by_merchant_id_connector_payout_id(
+                                merchant_id,
+                                connector_payout_id,
+                                storage_scheme,
+                            )
+                            .await
+                    },
+                ))
+                .await
+            }
+        }
+    }
+
     #[instrument(skip_all)]
     async fn get_filters_for_payouts(
         &self,
@@ -320,6 +390,27 @@ impl<T: DatabaseStore> PayoutAttemptInterface for crate::RouterStore<T> {
         })
     }
 
+    #[instrument(skip_all)]
+    async fn find_payout_attempt_by_merchant_id_connector_payout_id(
+        &self,
+        merchant_id: &str,
+        connector_payout_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<PayoutAttempt, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayoutAttempt::find_by_merchant_id_connector_payout_id(
+            &conn,
+            merchant_id,
+            connector_payout_id,
+        )
+        .await
+        .map(PayoutAttempt::from_storage_model)
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
+
     #[instrument(skip_all)]
     async fn get_filters_for_payouts(
         &self,
@@ -507,3 +598,26 @@ impl DataModelExt for PayoutAttemptUpdate {
         todo!("Reverse map should no longer be needed")
     }
 }
+
+#[inline]
+#[instrument(skip_all)]
+async fn add_connector_payout_id_to_reverse_lookup<T: DatabaseStore>(
+    store: &KVRouterStore<T>,
+    key: &str,
+    merchant_id: &str,
+    updated_attempt_attempt_id: &str,
+    connector_payout_id: &str,
+    storage_scheme: MerchantStorageScheme,
+) -> CustomResult<ReverseLookup, errors::StorageError> {
+    let field = format!("poa_{}", updated_attempt_attempt_id);
+    let reverse_lookup_new = ReverseLookupNew {
+        lookup_id: format!("poa_conn_payout_{}_{}", merchant_id, connector_payout_id),
+        pk_id: key.to_owned(),
+        sk_id: field.clone(),
+        source: "payout_attempt".to_string(),
+        updated_by: storage_scheme.to_string(),
+    };
+    store
+        .insert_reverse_lookup(reverse_lookup_new, storage_scheme)
+        .await
+}
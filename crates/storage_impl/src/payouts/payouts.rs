@@ -1,6 +1,5 @@
 #[cfg(feature = "olap")]
 use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
-use common_utils::ext_traits::Encode;
 #[cfg(feature = "olap")]
 use diesel::{associations::HasTable, ExpressionMethods, JoinOnDsl, QueryDsl};
 #[cfg(feature = "olap")]
@@ -150,10 +149,6 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
                     .apply_changeset(origin_diesel_payout.clone());
                 // Check for database presence as well Maybe use a read replica here ?
 
-                let redis_value = diesel_payout
-                    .encode_to_string_of_json()
-                    .change_context(StorageError::SerializationFailed)?;
-
                 let redis_entry = kv::TypedSql {
                     op: kv::DBOperation::Update {
                         updatable: kv::Updateable::PayoutsUpdate(kv::PayoutsUpdateMems {
@@ -165,7 +160,7 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
 
                 kv_wrapper::<(), _, _>(
                     self,
-                    KvOperation::<DieselPayouts>::Hset((&field, redis_value), redis_entry),
+                    KvOperation::<DieselPayouts>::Hset((&field, &diesel_payout), redis_entry),
                     key,
                 )
                 .await
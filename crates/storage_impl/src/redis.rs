@@ -1,4 +1,5 @@
 pub mod cache;
+pub mod kv_serialization;
 pub mod kv_store;
 pub mod pub_sub;
 
@@ -0,0 +1,200 @@
+//! Serialization of the entities stored in the KV store.
+//!
+//! Entities are written either as JSON, or as MessagePack preceded by a two byte header holding a
+//! marker and the version of the encoding. The marker (`0xc1`) is never used by MessagePack and is
+//! neither a valid first byte of a JSON document nor of a UTF-8 string, so the format in which a
+//! value has been written can be determined when it is read.
+
+use common_utils::{errors::CustomResult, ext_traits::ByteSliceExt};
+use error_stack::{report, ResultExt};
+use redis_interface::errors::RedisError;
+
+/// Marker with which the values encoded as MessagePack start
+const BINARY_VALUE_MARKER: u8 = 0xc1;
+
+/// Version of the MessagePack encoding of the values, to be bumped whenever values written with
+/// the current encoding can no longer be read by the new one
+const MESSAGE_PACK_VERSION: u8 = 1;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KvSerializationFormat {
+    #[default]
+    Json,
+    /// MessagePack with the fields of the entities encoded by name, so that the entities with
+    /// flattened, skipped or defaulted fields are read back as they were written
+    MessagePack,
+}
+
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+#[serde(default)]
+pub struct KvSerializationConfig {
+    /// Format in which the entities are written to the KV store
+    pub format: KvSerializationFormat,
+    /// Whether the entities written in either format are read. This must be enabled while the
+    /// format is being switched, as the entities written in the previous format stay in the KV
+    /// store until they expire.
+    pub dual_read: bool,
+}
+
+impl Default for KvSerializationConfig {
+    fn default() -> Self {
+        Self {
+            format: KvSerializationFormat::Json,
+            dual_read: true,
+        }
+    }
+}
+
+impl KvSerializationConfig {
+    pub fn serialize<S>(&self, value: &S) -> CustomResult<Vec<u8>, RedisError>
+    where
+        S: serde::Serialize,
+    {
+        match self.format {
+            KvSerializationFormat::Json => {
+                serde_json::to_vec(value).change_context(RedisError::JsonSerializationFailed)
+            }
+            KvSerializationFormat::MessagePack => {
+                let mut serialized = vec![BINARY_VALUE_MARKER, MESSAGE_PACK_VERSION];
+                rmp_serde::encode::write_named(&mut serialized, value)
+                    .change_context(RedisError::MessagePackSerializationFailed)?;
+                Ok(serialized)
+            }
+        }
+    }
+
+    pub fn deserialize<T>(
+        &self,
+        value: &[u8],
+        type_name: &'static str,
+    ) -> CustomResult<T, RedisError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let value_format = match value {
+            [BINARY_VALUE_MARKER, ..] => KvSerializationFormat::MessagePack,
+            _ => KvSerializationFormat::Json,
+        };
+
+        if value_format != self.format && !self.dual_read {
+            let error = match self.format {
+                KvSerializationFormat::Json => RedisError::JsonDeserializationFailed,
+                KvSerializationFormat::MessagePack => RedisError::MessagePackDeserializationFailed,
+            };
+            return Err(report!(error).attach_printable(format!(
+                "{type_name} is stored as {value_format:?} while dual read is disabled"
+            )));
+        }
+
+        match value {
+            [BINARY_VALUE_MARKER, MESSAGE_PACK_VERSION, payload @ ..] => {
+                rmp_serde::from_slice(payload)
+                    .change_context(RedisError::MessagePackDeserializationFailed)
+                    .attach_printable_lazy(|| {
+                        format!("Unable to parse {type_name} from MessagePack")
+                    })
+            }
+            [BINARY_VALUE_MARKER, version, ..] => Err(report!(
+                RedisError::MessagePackDeserializationFailed
+            )
+            .attach_printable(format!(
+                "Unsupported version {version} of the MessagePack encoding of {type_name}"
+            ))),
+            _ => value
+                .parse_struct(type_name)
+                .change_context(RedisError::JsonDeserializationFailed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod kv_serialization_tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+    use super::*;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Entity {
+        id: String,
+        amount: i64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        metadata: Option<serde_json::Value>,
+    }
+
+    fn entity() -> Entity {
+        Entity {
+            id: "pay_1234".to_string(),
+            amount: 6540,
+            description: None,
+            metadata: Some(serde_json::json!({ "order": { "id": 1 } })),
+        }
+    }
+
+    fn config(format: KvSerializationFormat, dual_read: bool) -> KvSerializationConfig {
+        KvSerializationConfig { format, dual_read }
+    }
+
+    #[test]
+    fn round_trip_in_either_format() {
+        for format in [
+            KvSerializationFormat::Json,
+            KvSerializationFormat::MessagePack,
+        ] {
+            let config = config(format, false);
+            let serialized = config.serialize(&entity()).unwrap();
+            let deserialized: Entity = config.deserialize(&serialized, "Entity").unwrap();
+            assert_eq!(deserialized, entity());
+        }
+    }
+
+    #[test]
+    fn dual_read_reads_values_written_in_the_previous_format() {
+        let json = config(KvSerializationFormat::Json, true)
+            .serialize(&entity())
+            .unwrap();
+        let message_pack = config(KvSerializationFormat::MessagePack, true)
+            .serialize(&entity())
+            .unwrap();
+
+        let deserialized: Entity = config(KvSerializationFormat::MessagePack, true)
+            .deserialize(&json, "Entity")
+            .unwrap();
+        assert_eq!(deserialized, entity());
+        let deserialized: Entity = config(KvSerializationFormat::Json, true)
+            .deserialize(&message_pack, "Entity")
+            .unwrap();
+        assert_eq!(deserialized, entity());
+    }
+
+    #[test]
+    fn values_in_the_other_format_are_rejected_without_dual_read() {
+        let json = config(KvSerializationFormat::Json, false)
+            .serialize(&entity())
+            .unwrap();
+
+        let error = config(KvSerializationFormat::MessagePack, false)
+            .deserialize::<Entity>(&json, "Entity")
+            .unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &RedisError::MessagePackDeserializationFailed
+        );
+    }
+
+    #[test]
+    fn unknown_versions_are_rejected() {
+        let mut message_pack = config(KvSerializationFormat::MessagePack, true)
+            .serialize(&entity())
+            .unwrap();
+        message_pack[1] = MESSAGE_PACK_VERSION + 1;
+
+        let error = config(KvSerializationFormat::MessagePack, true)
+            .deserialize::<Entity>(&message_pack, "Entity")
+            .unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &RedisError::MessagePackDeserializationFailed
+        );
+    }
+}
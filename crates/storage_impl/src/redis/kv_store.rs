@@ -82,7 +82,7 @@ pub trait RedisConnInterface {
 
 /// An enum to represent what operation to do on
 pub enum KvOperation<'a, S: serde::Serialize + Debug> {
-    Hset((&'a str, String), TypedSql),
+    Hset((&'a str, &'a S), TypedSql),
     SetNx(&'a S, TypedSql),
     HSetNx(&'a str, &'a S, TypedSql),
     HGet(&'a str),
@@ -135,14 +135,17 @@ where
     let operation = op.to_string();
 
     let ttl = store.ttl_for_kv;
+    let serialization = store.kv_serialization;
 
     let result = async {
         match op {
-            KvOperation::Hset(value, sql) => {
+            KvOperation::Hset((field, value), sql) => {
                 logger::debug!(kv_operation= %operation, value = ?value);
 
+                let serialized = serialization.serialize(value)?;
+
                 redis_conn
-                    .set_hash_fields(&key, value, Some(ttl.into()))
+                    .set_hash_fields(&key, (field, serialized.as_slice()), Some(ttl.into()))
                     .await?;
 
                 store
@@ -153,16 +156,26 @@ where
             }
 
             KvOperation::HGet(field) => {
-                let result = redis_conn
-                    .get_hash_field_and_deserialize(&key, field, type_name)
-                    .await?;
+                let value = redis_conn.get_hash_field::<Vec<u8>>(&key, field).await?;
+
+                if value.is_empty() {
+                    return Err(report!(RedisError::NotFound));
+                }
+
+                let result = serialization.deserialize(&value, type_name)?;
                 Ok(KvResult::HGet(result))
             }
 
             KvOperation::Scan(pattern) => {
                 let result: Vec<T> = redis_conn
-                    .hscan_and_deserialize(&key, pattern, None)
+                    .hscan_raw(&key, pattern, None)
                     .await
+                    .map(|values| {
+                        values
+                            .iter()
+                            .filter_map(|value| serialization.deserialize(value, type_name).ok())
+                            .collect::<Vec<_>>()
+                    })
                     .and_then(|result| {
                         if result.is_empty() {
                             Err(report!(RedisError::NotFound))
@@ -178,8 +191,10 @@ where
 
                 value.check_for_constraints(&redis_conn).await?;
 
+                let serialized = serialization.serialize(value)?;
+
                 let result = redis_conn
-                    .serialize_and_set_hash_field_if_not_exist(&key, field, value, Some(ttl))
+                    .set_hash_field_if_not_exist(&key, field, serialized.as_slice(), Some(ttl))
                     .await?;
 
                 if matches!(result, redis_interface::HsetnxReply::KeySet) {
@@ -195,8 +210,14 @@ where
             KvOperation::SetNx(value, sql) => {
                 logger::debug!(kv_operation= %operation, value = ?value);
 
+                let serialized = serialization.serialize(value)?;
+
                 let result = redis_conn
-                    .serialize_and_set_key_if_not_exist(&key, value, Some(ttl.into()))
+                    .set_key_if_not_exists_with_expiry(
+                        &key,
+                        serialized.as_slice(),
+                        Some(ttl.into()),
+                    )
                     .await?;
 
                 value.check_for_constraints(&redis_conn).await?;
@@ -212,7 +233,13 @@ where
             }
 
             KvOperation::Get => {
-                let result = redis_conn.get_and_deserialize_key(&key, type_name).await?;
+                let value = redis_conn.get_key::<Vec<u8>>(&key).await?;
+
+                if value.is_empty() {
+                    return Err(report!(RedisError::NotFound));
+                }
+
+                let result = serialization.deserialize(&value, type_name)?;
                 Ok(KvResult::Get(result))
             }
         }
@@ -0,0 +1,22 @@
+use std::process::exit;
+
+use test_utils::capability_probe;
+
+#[tokio::main]
+async fn main() {
+    match capability_probe::probe().await {
+        Ok(report) => {
+            match serde_json::to_string_pretty(&report) {
+                Ok(report) => println!("{report}"),
+                Err(err) => eprintln!("Failed to serialize the report: {err}"),
+            }
+            if !report.is_account_usable() {
+                exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("Failed to probe the connector: {err}");
+            exit(1);
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use std::process::exit;
+
+use test_utils::certification;
+
+fn main() {
+    match certification::export_certification_package() {
+        Ok(package_dir) => {
+            println!("Certification package written to {}", package_dir.display());
+        }
+        Err(err) => {
+            eprintln!("Failed to export the certification package: {err}");
+            exit(1);
+        }
+    }
+}
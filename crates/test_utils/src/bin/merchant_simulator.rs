@@ -0,0 +1,22 @@
+use std::process::exit;
+
+use test_utils::merchant_simulator;
+
+#[tokio::main]
+async fn main() {
+    match merchant_simulator::simulate().await {
+        Ok(report) => {
+            match serde_json::to_string_pretty(&report) {
+                Ok(report) => println!("{report}"),
+                Err(err) => eprintln!("Failed to serialize the report: {err}"),
+            }
+            if !report.is_consistent() {
+                exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("Failed to run the simulation: {err}");
+            exit(1);
+        }
+    }
+}
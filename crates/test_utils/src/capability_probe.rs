@@ -0,0 +1,674 @@
+//! Probe of the flows the sandbox account of a connector supports, to onboard merchants.
+//!
+//! The probe creates a merchant with the connector credentials from the connector authentication
+//! file, makes payments through the connector covering each of the flows, and reports which of
+//! the flows actually work for the account.
+
+use std::{fs, io, path::PathBuf};
+
+use clap::Parser;
+use hyperswitch_client::API_KEY_HEADER;
+use masking::PeekInterface;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::connector_auth::{ConnectorAuthType, ConnectorAuthenticationMap};
+
+/// Amount of the payments made by the probe, half of which is captured by the partial capture
+const PROBE_AMOUNT: i64 = 1000;
+
+#[derive(Parser)]
+#[command(version, about = "Connector sandbox capability probe", long_about = None)]
+struct Args {
+    /// Admin API Key of the environment
+    #[arg(short, long)]
+    admin_api_key: String,
+    /// Base URL of the Hyperswitch environment
+    #[arg(short, long, default_value = "http://127.0.0.1:8080")]
+    base_url: String,
+    /// Name of the connector, whose credentials are read from the connector authentication file
+    #[arg(short, long)]
+    connector_name: String,
+    /// JSON file with the metadata of the connector account, for the connectors requiring it
+    #[arg(short = 'm', long)]
+    connector_metadata: Option<PathBuf>,
+    /// Card authorized without authentication by the sandbox of the connector
+    #[arg(long, default_value = "4242424242424242")]
+    card_number: String,
+    /// Card challenged with 3DS by the sandbox of the connector
+    #[arg(long, default_value = "4000000000003220")]
+    three_ds_card_number: String,
+    /// Secret of the webhooks of the connector, the webhooks are registered only if it is
+    /// provided
+    #[arg(short, long)]
+    webhook_secret: Option<String>,
+    /// File the capability report is written to
+    #[arg(short, long, default_value = "capability_report.json")]
+    output: PathBuf,
+    /// Keep the merchant created for the probe, instead of deleting it once done
+    #[arg(long)]
+    keep_merchant: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CapabilityStatus {
+    Supported,
+    Unsupported,
+    /// The flow could not be probed, since a flow it depends on is not supported or the probe
+    /// was not configured for it
+    Skipped,
+}
+
+/// Outcome of the probe of a flow
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct Capability {
+    pub status: CapabilityStatus,
+    /// Payment the flow was probed with
+    pub payment_id: Option<String>,
+    /// Status of the payment or refund at the end of the probe
+    pub observed_status: Option<String>,
+    pub detail: String,
+}
+
+impl Capability {
+    fn skipped(detail: impl Into<String>) -> Self {
+        Self {
+            status: CapabilityStatus::Skipped,
+            payment_id: None,
+            observed_status: None,
+            detail: detail.into(),
+        }
+    }
+
+    fn unsupported(detail: impl Into<String>) -> Self {
+        Self {
+            status: CapabilityStatus::Unsupported,
+            payment_id: None,
+            observed_status: None,
+            detail: detail.into(),
+        }
+    }
+
+    fn is_supported(&self) -> bool {
+        self.status == CapabilityStatus::Supported
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CapabilityReport {
+    pub connector: String,
+    pub probed_at: i64,
+    pub merchant_id: String,
+    pub merchant_connector_id: String,
+    /// Card payments authorized and captured automatically, without authentication
+    pub authorize: Capability,
+    pub three_ds: Capability,
+    pub manual_capture: Capability,
+    pub partial_capture: Capability,
+    pub refund: Capability,
+    pub webhooks: Capability,
+}
+
+impl CapabilityReport {
+    /// Whether the credentials of the connector work at all, the other flows are of no use
+    /// otherwise
+    pub fn is_account_usable(&self) -> bool {
+        self.authorize.is_supported()
+    }
+}
+
+/// Client of the Hyperswitch API, authenticated with the admin API key for the merchant set up,
+/// and with the API key of the merchant for the payments
+struct ProbeClient {
+    http: reqwest::Client,
+    base_url: String,
+}
+
+impl ProbeClient {
+    async fn send(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        api_key: &str,
+        body: Option<&Value>,
+    ) -> Result<Value, String> {
+        let mut request = self
+            .http
+            .request(method, format!("{}{path}", self.base_url))
+            .header(API_KEY_HEADER, api_key);
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        let response = request.send().await.map_err(|err| err.to_string())?;
+        let status = response.status();
+        let response_body = response.text().await.map_err(|err| err.to_string())?;
+
+        if !status.is_success() {
+            return Err(format!(
+                "API responded with status {status}: {response_body}"
+            ));
+        }
+        serde_json::from_str(&response_body).map_err(|err| err.to_string())
+    }
+
+    async fn post(&self, path: &str, api_key: &str, body: &Value) -> Result<Value, String> {
+        self.send(reqwest::Method::POST, path, api_key, Some(body))
+            .await
+    }
+}
+
+/// Account details of the connector account, in the format of the merchant connector account
+/// create request
+fn connector_account_details(auth_type: &ConnectorAuthType) -> Value {
+    match auth_type {
+        ConnectorAuthType::HeaderKey { api_key } => json!({
+            "auth_type": "HeaderKey",
+            "api_key": api_key.peek(),
+        }),
+        ConnectorAuthType::BodyKey { api_key, key1 } => json!({
+            "auth_type": "BodyKey",
+            "api_key": api_key.peek(),
+            "key1": key1.peek(),
+        }),
+        ConnectorAuthType::SignatureKey {
+            api_key,
+            key1,
+            api_secret,
+        } => json!({
+            "auth_type": "SignatureKey",
+            "api_key": api_key.peek(),
+            "key1": key1.peek(),
+            "api_secret": api_secret.peek(),
+        }),
+        ConnectorAuthType::MultiAuthKey {
+            api_key,
+            key1,
+            api_secret,
+            key2,
+        } => json!({
+            "auth_type": "MultiAuthKey",
+            "api_key": api_key.peek(),
+            "key1": key1.peek(),
+            "api_secret": api_secret.peek(),
+            "key2": key2.peek(),
+        }),
+        ConnectorAuthType::NoKey => json!({ "auth_type": "NoKey" }),
+    }
+}
+
+fn card_payment_request(
+    card_number: &str,
+    authentication_type: &str,
+    capture_method: &str,
+) -> Value {
+    json!({
+        "amount": PROBE_AMOUNT,
+        "currency": "USD",
+        "confirm": true,
+        "capture_method": capture_method,
+        "authentication_type": authentication_type,
+        "customer_id": "capability_probe",
+        "email": "guest@example.com",
+        "description": "Payment made by the capability probe",
+        "return_url": "https://hyperswitch.io",
+        "payment_method": "card",
+        "payment_method_type": "credit",
+        "payment_method_data": {
+            "card": {
+                "card_number": card_number,
+                "card_exp_month": "03",
+                "card_exp_year": "30",
+                "card_holder_name": "John Doe",
+                "card_cvc": "737"
+            }
+        },
+        "billing": {
+            "address": {
+                "line1": "1467",
+                "line2": "Harrison Street",
+                "city": "San Fransico",
+                "state": "California",
+                "zip": "94122",
+                "country": "US",
+                "first_name": "John",
+                "last_name": "Doe"
+            }
+        },
+        "browser_info": {
+            "user_agent": "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/125.0.0.0 Safari/537.36",
+            "accept_header": "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8",
+            "language": "en-US",
+            "color_depth": 24,
+            "screen_height": 1080,
+            "screen_width": 1920,
+            "time_zone": 0,
+            "java_enabled": true,
+            "java_script_enabled": true,
+            "ip_address": "127.0.0.1"
+        }
+    })
+}
+
+fn get_str<'a>(response: &'a Value, key: &str) -> Option<&'a str> {
+    response.get(key).and_then(Value::as_str)
+}
+
+/// The capability observed from the response to the payment or refund probing the flow, which is
+/// supported if the status of the response is one of the expected statuses
+fn check_response(
+    response: Result<Value, String>,
+    expected_statuses: &[&str],
+) -> (Capability, Option<Value>) {
+    let response = match response {
+        Ok(response) => response,
+        Err(err) => return (Capability::unsupported(err), None),
+    };
+    let observed_status = get_str(&response, "status").map(String::from);
+    let is_supported = observed_status
+        .as_deref()
+        .is_some_and(|status| expected_statuses.contains(&status));
+    let detail = if is_supported {
+        String::new()
+    } else {
+        match (
+            get_str(&response, "error_code"),
+            get_str(&response, "error_message"),
+        ) {
+            (None, None) => format!("expected the status to be one of {expected_statuses:?}"),
+            (code, message) => format!(
+                "connector responded with {}: {}",
+                code.unwrap_or("no error code"),
+                message.unwrap_or("no error message")
+            ),
+        }
+    };
+    let capability = Capability {
+        status: if is_supported {
+            CapabilityStatus::Supported
+        } else {
+            CapabilityStatus::Unsupported
+        },
+        payment_id: get_str(&response, "payment_id").map(String::from),
+        observed_status,
+        detail,
+    };
+
+    (capability, Some(response))
+}
+
+/// 3DS is supported if the payment with the card challenged by the sandbox waits for the customer
+/// to be redirected for authentication
+fn check_three_ds(response: Result<Value, String>) -> Capability {
+    let (mut capability, response) = check_response(response, &["requires_customer_action"]);
+    let next_action = response
+        .as_ref()
+        .and_then(|response| response.get("next_action"))
+        .and_then(|next_action| get_str(next_action, "type"));
+
+    if capability.is_supported() && next_action != Some("redirect_to_url") {
+        capability.status = CapabilityStatus::Unsupported;
+        capability.detail = format!(
+            "expected the customer to be redirected, got the next action {}",
+            next_action.unwrap_or("none")
+        );
+    } else if capability.observed_status.as_deref() == Some("succeeded") {
+        capability.detail = "the payment was authorized without authentication".to_string();
+    }
+
+    capability
+}
+
+/// Partial captures are supported if the payment is captured for the amount requested only
+fn check_partial_capture(response: Result<Value, String>, amount_to_capture: i64) -> Capability {
+    let (mut capability, response) =
+        check_response(response, &["partially_captured", "succeeded", "processing"]);
+    let amount_received = response
+        .as_ref()
+        .and_then(|response| response.get("amount_received"))
+        .and_then(Value::as_i64);
+
+    if capability.observed_status.as_deref() == Some("succeeded")
+        && amount_received != Some(amount_to_capture)
+    {
+        capability.status = CapabilityStatus::Unsupported;
+        capability.detail = format!(
+            "expected {amount_to_capture} to be captured, got {}",
+            amount_received.map_or_else(|| "none".to_string(), |amount| amount.to_string())
+        );
+    }
+
+    capability
+}
+
+async fn probe_payment(
+    client: &ProbeClient,
+    api_key: &str,
+    request: &Value,
+    expected_statuses: &[&str],
+) -> Capability {
+    check_response(
+        client.post("/payments", api_key, request).await,
+        expected_statuses,
+    )
+    .0
+}
+
+/// Set up a merchant with an account of the connector, returning the merchant id, the API key of
+/// the merchant and the merchant connector id
+async fn set_up_merchant(
+    client: &ProbeClient,
+    args: &Args,
+    auth_type: &ConnectorAuthType,
+) -> Result<(String, String, String), String> {
+    let merchant_id = format!(
+        "capability_probe_{}",
+        time::OffsetDateTime::now_utc().unix_timestamp()
+    );
+    client
+        .post(
+            "/accounts",
+            &args.admin_api_key,
+            &json!({
+                "merchant_id": merchant_id,
+                "merchant_name": "Capability probe",
+                "return_url": "https://hyperswitch.io",
+                "primary_business_details": [{ "country": "US", "business": "default" }]
+            }),
+        )
+        .await?;
+
+    let api_key = client
+        .post(
+            &format!("/api_keys/{merchant_id}"),
+            &args.admin_api_key,
+            &json!({ "name": "capability_probe", "expiration": "never" }),
+        )
+        .await?;
+    let api_key = get_str(&api_key, "api_key")
+        .ok_or("The API key response has no api_key")?
+        .to_string();
+
+    let metadata = args
+        .connector_metadata
+        .as_ref()
+        .map(fs::read_to_string)
+        .transpose()
+        .map_err(|err| err.to_string())?
+        .map(|metadata| serde_json::from_str::<Value>(&metadata))
+        .transpose()
+        .map_err(|err| err.to_string())?;
+    let card_payment_method_type = |payment_method_type: &str| {
+        json!({
+            "payment_method_type": payment_method_type,
+            "card_networks": ["Visa", "Mastercard"],
+            "minimum_amount": 1,
+            "maximum_amount": 68607706,
+            "recurring_enabled": true,
+            "installment_payment_enabled": true
+        })
+    };
+    let connector_account = client
+        .post(
+            &format!("/account/{merchant_id}/connectors"),
+            &args.admin_api_key,
+            &json!({
+                "connector_type": "payment_processor",
+                "connector_name": args.connector_name,
+                "connector_account_details": connector_account_details(auth_type),
+                "test_mode": true,
+                "disabled": false,
+                "business_country": "US",
+                "business_label": "default",
+                "payment_methods_enabled": [{
+                    "payment_method": "card",
+                    "payment_method_types": [
+                        card_payment_method_type("credit"),
+                        card_payment_method_type("debit")
+                    ]
+                }],
+                "metadata": metadata,
+                "connector_webhook_details": args
+                    .webhook_secret
+                    .as_ref()
+                    .map(|merchant_secret| json!({ "merchant_secret": merchant_secret }))
+            }),
+        )
+        .await?;
+    let merchant_connector_id = get_str(&connector_account, "merchant_connector_id")
+        .ok_or("The connector account response has no merchant_connector_id")?
+        .to_string();
+
+    Ok((merchant_id, api_key, merchant_connector_id))
+}
+
+/// Probe the flows with the connector account of the merchant
+async fn probe_flows(
+    client: &ProbeClient,
+    args: &Args,
+    api_key: &str,
+    webhook_endpoint: String,
+) -> [Capability; 6] {
+    let authorize = probe_payment(
+        client,
+        api_key,
+        &card_payment_request(&args.card_number, "no_three_ds", "automatic"),
+        &["succeeded", "processing"],
+    )
+    .await;
+    if !authorize.is_supported() {
+        let skipped = || Capability::skipped("the card payments are not authorized");
+        return [
+            authorize,
+            skipped(),
+            skipped(),
+            skipped(),
+            skipped(),
+            skipped(),
+        ];
+    }
+
+    let three_ds = check_three_ds(
+        client
+            .post(
+                "/payments",
+                api_key,
+                &card_payment_request(&args.three_ds_card_number, "three_ds", "automatic"),
+            )
+            .await,
+    );
+
+    let manual_capture = probe_payment(
+        client,
+        api_key,
+        &card_payment_request(&args.card_number, "no_three_ds", "manual"),
+        &["requires_capture"],
+    )
+    .await;
+    let partial_capture = match &manual_capture.payment_id {
+        Some(payment_id) if manual_capture.is_supported() => {
+            let amount_to_capture = PROBE_AMOUNT / 2;
+            let captured = client
+                .post(
+                    &format!("/payments/{payment_id}/capture"),
+                    api_key,
+                    &json!({ "amount_to_capture": amount_to_capture }),
+                )
+                .await;
+            check_partial_capture(captured, amount_to_capture)
+        }
+        _ => Capability::skipped("the payments are not captured manually"),
+    };
+
+    let refund = match &authorize.payment_id {
+        Some(payment_id) if authorize.observed_status.as_deref() == Some("succeeded") => {
+            check_response(
+                client
+                    .post(
+                        "/refunds",
+                        api_key,
+                        &json!({ "payment_id": payment_id, "reason": "Capability probe" }),
+                    )
+                    .await,
+                &["succeeded", "pending"],
+            )
+            .0
+        }
+        _ => Capability::skipped("the authorized payment has not succeeded"),
+    };
+
+    let webhooks = match args.webhook_secret {
+        Some(_) => Capability {
+            status: CapabilityStatus::Supported,
+            payment_id: None,
+            observed_status: None,
+            detail: format!(
+                "the webhooks are registered, configure {webhook_endpoint} as the webhook endpoint in the dashboard of the connector"
+            ),
+        },
+        None => Capability::skipped("no webhook secret was provided"),
+    };
+
+    [
+        authorize,
+        three_ds,
+        manual_capture,
+        partial_capture,
+        refund,
+        webhooks,
+    ]
+}
+
+/// Probe the sandbox of the connector with the arguments passed on the command line, writing the
+/// capability report to the output file
+pub async fn probe() -> io::Result<CapabilityReport> {
+    let args = Args::parse();
+    let invalid_input = |message: String| io::Error::new(io::ErrorKind::InvalidInput, message);
+
+    let auth_map = ConnectorAuthenticationMap::new();
+    let auth_type = auth_map.inner().get(&args.connector_name).ok_or_else(|| {
+        invalid_input(format!(
+            "No credentials of {} in the connector authentication file",
+            args.connector_name
+        ))
+    })?;
+    let client = ProbeClient {
+        http: reqwest::Client::new(),
+        base_url: args.base_url.trim_end_matches('/').to_string(),
+    };
+
+    let (merchant_id, api_key, merchant_connector_id) = set_up_merchant(&client, &args, auth_type)
+        .await
+        .map_err(|err| invalid_input(format!("Failed to set up the merchant: {err}")))?;
+    println!(
+        "Probing {} with the connector account {merchant_connector_id} of {merchant_id}",
+        args.connector_name
+    );
+
+    let webhook_endpoint = format!(
+        "{}/webhooks/{merchant_id}/{merchant_connector_id}",
+        client.base_url
+    );
+    let [authorize, three_ds, manual_capture, partial_capture, refund, webhooks] =
+        probe_flows(&client, &args, &api_key, webhook_endpoint).await;
+
+    if !args.keep_merchant {
+        if let Err(err) = client
+            .send(
+                reqwest::Method::DELETE,
+                &format!("/accounts/{merchant_id}"),
+                &args.admin_api_key,
+                None,
+            )
+            .await
+        {
+            eprintln!("Failed to delete the merchant {merchant_id}: {err}");
+        }
+    }
+
+    let report = CapabilityReport {
+        connector: args.connector_name,
+        probed_at: time::OffsetDateTime::now_utc().unix_timestamp(),
+        merchant_id,
+        merchant_connector_id,
+        authorize,
+        three_ds,
+        manual_capture,
+        partial_capture,
+        refund,
+        webhooks,
+    };
+    fs::write(&args.output, serde_json::to_string_pretty(&report)?)?;
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_the_status_of_the_response() {
+        let (capability, _) = check_response(
+            Ok(json!({ "payment_id": "pay_1", "status": "succeeded" })),
+            &["succeeded"],
+        );
+        assert_eq!(capability.status, CapabilityStatus::Supported);
+        assert_eq!(capability.payment_id.as_deref(), Some("pay_1"));
+
+        let (capability, _) = check_response(
+            Ok(json!({
+                "payment_id": "pay_2",
+                "status": "failed",
+                "error_code": "card_declined",
+                "error_message": "Your card was declined"
+            })),
+            &["succeeded"],
+        );
+        assert_eq!(capability.status, CapabilityStatus::Unsupported);
+        assert_eq!(
+            capability.detail,
+            "connector responded with card_declined: Your card was declined"
+        );
+
+        let (capability, response) = check_response(
+            Err("API responded with status 400 Bad Request".to_string()),
+            &["succeeded"],
+        );
+        assert_eq!(capability.status, CapabilityStatus::Unsupported);
+        assert!(response.is_none());
+    }
+
+    #[test]
+    fn checks_the_redirection_of_three_ds_payments() {
+        let challenged = check_three_ds(Ok(json!({
+            "payment_id": "pay_1",
+            "status": "requires_customer_action",
+            "next_action": { "type": "redirect_to_url", "redirect_to_url": "https://example.com" }
+        })));
+        assert_eq!(challenged.status, CapabilityStatus::Supported);
+
+        let frictionless =
+            check_three_ds(Ok(json!({ "payment_id": "pay_2", "status": "succeeded" })));
+        assert_eq!(frictionless.status, CapabilityStatus::Unsupported);
+        assert_eq!(
+            frictionless.detail,
+            "the payment was authorized without authentication"
+        );
+    }
+
+    #[test]
+    fn checks_the_amount_partially_captured() {
+        let partially_captured = check_partial_capture(
+            Ok(json!({ "status": "partially_captured", "amount_received": 500 })),
+            500,
+        );
+        assert_eq!(partially_captured.status, CapabilityStatus::Supported);
+
+        let fully_captured = check_partial_capture(
+            Ok(json!({ "status": "succeeded", "amount_received": 1000 })),
+            500,
+        );
+        assert_eq!(fully_captured.status, CapabilityStatus::Unsupported);
+    }
+}
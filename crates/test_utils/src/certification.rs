@@ -0,0 +1,528 @@
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::newman_runner::get_dir_path;
+
+#[derive(Parser)]
+#[command(version, about = "Connector certification evidence exporter", long_about = None)]
+struct Args {
+    /// Name of the connector
+    #[arg(short, long)]
+    connector_name: String,
+    /// Directory in which the certification package is written
+    #[arg(short, long, default_value = "certification")]
+    output_dir: String,
+    /// Connector configuration used to build the supported feature matrix
+    #[arg(long, default_value = "crates/connector_configs/toml/sandbox.toml")]
+    connector_config: String,
+    /// Optional newman JSON report (`--reporters json`) of a run against the connector,
+    /// used to attach the observed responses to the request samples
+    #[arg(short, long)]
+    newman_report: Option<String>,
+}
+
+/// Keys whose values are never exported as is, irrespective of where they appear in a payload
+const SENSITIVE_KEYS: [&str; 24] = [
+    "api_key",
+    "api_secret",
+    "key1",
+    "key2",
+    "merchant_secret",
+    "certificate",
+    "certificate_keys",
+    "client_secret",
+    "card_cvc",
+    "card_exp_month",
+    "card_exp_year",
+    "card_holder_name",
+    "bank_account_number",
+    "iban",
+    "cryptogram",
+    "email",
+    "first_name",
+    "last_name",
+    "line1",
+    "line2",
+    "line3",
+    "zip",
+    "phone",
+    "number",
+];
+
+/// Keys holding a PAN, of which only the last four digits are retained
+const CARD_NUMBER_KEYS: [&str; 2] = ["card_number", "network_token"];
+
+const REDACTED: &str = "*** redacted ***";
+
+#[derive(Debug, Serialize)]
+pub struct CertificationPackage {
+    pub connector: String,
+    pub generated_at: i64,
+    pub feature_matrix: FeatureMatrix,
+    pub test_transactions: Vec<TestTransaction>,
+    pub samples: Vec<Sample>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct FeatureMatrix {
+    /// Payment method types enabled for the connector, grouped by payment method
+    pub payment_methods: BTreeMap<String, Vec<String>>,
+    /// Flows exercised by the connector's test collection
+    pub flows: BTreeMap<&'static str, bool>,
+    pub webhooks: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TestTransaction {
+    pub category: String,
+    pub scenario: String,
+    pub steps: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Sample {
+    pub scenario: String,
+    pub step: String,
+    pub method: Option<String>,
+    pub url: Option<String>,
+    pub request: Option<Value>,
+    pub status_code: Option<u16>,
+    pub response: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DirMeta {
+    #[serde(rename = "childrenOrder", default)]
+    children_order: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NewmanReport {
+    run: NewmanRun,
+}
+
+#[derive(Debug, Deserialize)]
+struct NewmanRun {
+    #[serde(default)]
+    executions: Vec<NewmanExecution>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NewmanExecution {
+    item: NewmanItem,
+    response: Option<NewmanResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NewmanItem {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct NewmanResponse {
+    code: u16,
+    stream: Option<NewmanStream>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NewmanStream {
+    #[serde(default)]
+    data: Vec<u8>,
+}
+
+/// Maps a step of a flow testcase to the flow it certifies
+fn flow_for_step(step: &str) -> Option<&'static str> {
+    match step {
+        step if step.starts_with("Payments - Capture") => Some("capture"),
+        step if step.starts_with("Payments - Cancel") => Some("void"),
+        step if step.starts_with("Refunds - Create") => Some("refund"),
+        step if step.starts_with("Refunds - Retrieve") => Some("refund_sync"),
+        step if step.starts_with("Payments - Retrieve") => Some("payment_sync"),
+        step if step.starts_with("Recurring Payments") || step.contains("Mandates") => {
+            Some("mandates")
+        }
+        step if step.starts_with("Save card payments") => Some("save_card"),
+        step if step.starts_with("Incremental Authorization") => Some("incremental_authorization"),
+        step if step.starts_with("Payouts") => Some("payouts"),
+        _ => None,
+    }
+}
+
+/// Maps a flow testcase to the flow it certifies, based on the scenario name
+fn flow_for_scenario(scenario: &str) -> Option<&'static str> {
+    let scenario = scenario.to_lowercase();
+    if scenario.contains("3ds") {
+        Some("three_ds")
+    } else if scenario.contains("partial refund") {
+        Some("partial_refund")
+    } else if scenario.contains("manual_multiple") {
+        Some("multiple_partial_captures")
+    } else if scenario.contains("manual capture") {
+        Some("manual_capture")
+    } else {
+        None
+    }
+}
+
+/// Lists the children of a collection directory in the order newman would run them
+fn ordered_children(dir: &Path) -> io::Result<Vec<String>> {
+    let meta = fs::read_to_string(dir.join(".meta.json"))
+        .ok()
+        .and_then(|contents| serde_json::from_str::<DirMeta>(&contents).ok());
+
+    match meta {
+        Some(meta) if !meta.children_order.is_empty() => Ok(meta
+            .children_order
+            .into_iter()
+            .filter(|child| dir.join(child).is_dir())
+            .collect()),
+        _ => {
+            let mut children = fs::read_dir(dir)?
+                .filter_map(Result::ok)
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect::<Vec<_>>();
+            children.sort();
+            Ok(children)
+        }
+    }
+}
+
+/// Retains only the last four digits of a card number
+fn mask_card_number(card_number: &str) -> Option<String> {
+    let masked_length = card_number.len().checked_sub(4)?;
+    let last_four = card_number.get(masked_length..)?;
+    Some(format!("{}{last_four}", "*".repeat(masked_length)))
+}
+
+/// Redacts sensitive values in a JSON payload, in place
+pub fn mask_json(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if CARD_NUMBER_KEYS.contains(&key.as_str()) {
+                    let masked = value
+                        .as_str()
+                        .and_then(mask_card_number)
+                        .unwrap_or_else(|| REDACTED.to_string());
+                    *value = Value::String(masked);
+                } else if SENSITIVE_KEYS.contains(&key.as_str()) {
+                    if !value.is_null() {
+                        *value = Value::String(REDACTED.to_string());
+                    }
+                } else {
+                    mask_json(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(mask_json),
+        _ => {}
+    }
+}
+
+fn read_request_sample(step_dir: &Path) -> (Option<String>, Option<String>, Option<Value>) {
+    let request = fs::read_to_string(step_dir.join("request.json"))
+        .ok()
+        .and_then(|contents| serde_json::from_str::<Value>(&contents).ok());
+
+    let Some(request) = request else {
+        return (None, None, None);
+    };
+
+    let method = request
+        .get("method")
+        .and_then(Value::as_str)
+        .map(ToString::to_string);
+    let url = request
+        .pointer("/url/raw")
+        .and_then(Value::as_str)
+        .map(ToString::to_string);
+    let body = request
+        .pointer("/body/raw_json_formatted")
+        .cloned()
+        .map(|mut body| {
+            mask_json(&mut body);
+            body
+        });
+
+    (method, url, body)
+}
+
+/// Observed responses from a newman run, keyed by the step name in execution order
+fn load_newman_responses(path: &str) -> io::Result<HashMap<String, VecDeque<NewmanResponse>>> {
+    let report: NewmanReport = serde_json::from_str(&fs::read_to_string(path)?)?;
+    let mut responses: HashMap<String, VecDeque<NewmanResponse>> = HashMap::new();
+
+    for execution in report.run.executions {
+        if let Some(response) = execution.response {
+            responses
+                .entry(execution.item.name)
+                .or_default()
+                .push_back(response);
+        }
+    }
+
+    Ok(responses)
+}
+
+/// Builds the supported payment methods from the connector configuration
+fn load_payment_methods(
+    config_path: &str,
+    connector_name: &str,
+) -> io::Result<(BTreeMap<String, Vec<String>>, bool)> {
+    let config: toml::Value = toml::from_str(&fs::read_to_string(config_path)?)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+    let Some(connector_config) = config.get(connector_name).and_then(toml::Value::as_table) else {
+        eprintln!("Connector `{connector_name}` not found in {config_path}");
+        return Ok((BTreeMap::new(), false));
+    };
+
+    let payment_methods = connector_config
+        .iter()
+        .filter_map(|(payment_method, value)| {
+            let payment_method_types = value
+                .as_array()?
+                .iter()
+                .filter_map(|entry| entry.get("payment_method_type")?.as_str())
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            (!payment_method_types.is_empty())
+                .then(|| (payment_method.to_owned(), payment_method_types))
+        })
+        .collect();
+
+    let webhooks = connector_config.contains_key("connector_webhook_details");
+
+    Ok((payment_methods, webhooks))
+}
+
+pub fn build_package(
+    connector_name: &str,
+    connector_config: &str,
+    newman_report: Option<&str>,
+) -> io::Result<CertificationPackage> {
+    let collection_dir = PathBuf::from(get_dir_path(connector_name)).join("Flow Testcases");
+    let mut responses = newman_report
+        .map(load_newman_responses)
+        .transpose()?
+        .unwrap_or_default();
+
+    let (payment_methods, webhooks) = load_payment_methods(connector_config, connector_name)?;
+    let mut feature_matrix = FeatureMatrix {
+        payment_methods,
+        webhooks,
+        ..Default::default()
+    };
+    let mut test_transactions = Vec::new();
+    let mut samples = Vec::new();
+
+    for category in ordered_children(&collection_dir)? {
+        let category_dir = collection_dir.join(&category);
+
+        for scenario in ordered_children(&category_dir)? {
+            let scenario_dir = category_dir.join(&scenario);
+            let steps = ordered_children(&scenario_dir)?;
+
+            if let Some(flow) = flow_for_scenario(&scenario) {
+                feature_matrix.flows.insert(flow, true);
+            }
+
+            for step in &steps {
+                if let Some(flow) = flow_for_step(step) {
+                    feature_matrix.flows.insert(flow, true);
+                }
+
+                let (method, url, request) = read_request_sample(&scenario_dir.join(step));
+                let observed = responses.get_mut(step).and_then(VecDeque::pop_front);
+                let (status_code, response) = match observed {
+                    Some(observed) => {
+                        let response = observed
+                            .stream
+                            .and_then(|stream| serde_json::from_slice::<Value>(&stream.data).ok())
+                            .map(|mut response| {
+                                mask_json(&mut response);
+                                response
+                            });
+                        (Some(observed.code), response)
+                    }
+                    None => (None, None),
+                };
+
+                samples.push(Sample {
+                    scenario: scenario.clone(),
+                    step: step.clone(),
+                    method,
+                    url,
+                    request,
+                    status_code,
+                    response,
+                });
+            }
+
+            test_transactions.push(TestTransaction {
+                category: category.clone(),
+                scenario,
+                steps,
+            });
+        }
+    }
+
+    Ok(CertificationPackage {
+        connector: connector_name.to_owned(),
+        generated_at: time::OffsetDateTime::now_utc().unix_timestamp(),
+        feature_matrix,
+        test_transactions,
+        samples,
+    })
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    let contents = serde_json::to_string_pretty(value)?;
+    fs::write(path, contents)
+}
+
+/// Writes the certification package of a connector as separate files, one per evidence type
+pub fn write_package(package: &CertificationPackage, output_dir: &Path) -> io::Result<PathBuf> {
+    let package_dir = output_dir.join(&package.connector);
+    fs::create_dir_all(&package_dir)?;
+
+    write_json(
+        &package_dir.join("feature_matrix.json"),
+        &package.feature_matrix,
+    )?;
+    write_json(
+        &package_dir.join("test_transactions.json"),
+        &package.test_transactions,
+    )?;
+    write_json(&package_dir.join("samples.json"), &package.samples)?;
+    write_json(&package_dir.join("certification_package.json"), package)?;
+
+    Ok(package_dir)
+}
+
+pub fn export_certification_package() -> io::Result<PathBuf> {
+    let args = Args::parse();
+
+    let package = build_package(
+        &args.connector_name,
+        &args.connector_config,
+        args.newman_report.as_deref(),
+    )?;
+
+    write_package(&package, Path::new(&args.output_dir))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn masks_card_details_in_nested_payloads() {
+        let mut payload = json!({
+            "amount": 6540,
+            "payment_method_data": {
+                "card": {
+                    "card_number": "4242424242424242",
+                    "card_exp_month": "10",
+                    "card_exp_year": "2030",
+                    "card_holder_name": "John Doe",
+                    "card_cvc": "123",
+                    "card_network": "Visa"
+                }
+            }
+        });
+
+        mask_json(&mut payload);
+
+        assert_eq!(
+            payload,
+            json!({
+                "amount": 6540,
+                "payment_method_data": {
+                    "card": {
+                        "card_number": "************4242",
+                        "card_exp_month": REDACTED,
+                        "card_exp_year": REDACTED,
+                        "card_holder_name": REDACTED,
+                        "card_cvc": REDACTED,
+                        "card_network": "Visa"
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn masks_customer_details_in_arrays() {
+        let mut payload = json!({
+            "customers": [
+                {
+                    "email": "guest@example.com",
+                    "phone": { "number": "9123456789", "country_code": "+1" },
+                    "address": {
+                        "line1": "1467",
+                        "line2": "Harrison Street",
+                        "zip": "94122",
+                        "first_name": "John",
+                        "last_name": "Doe",
+                        "city": "San Fransico"
+                    }
+                },
+                { "email": "other@example.com", "name": null }
+            ]
+        });
+
+        mask_json(&mut payload);
+
+        assert_eq!(
+            payload,
+            json!({
+                "customers": [
+                    {
+                        "email": REDACTED,
+                        "phone": REDACTED,
+                        "address": {
+                            "line1": REDACTED,
+                            "line2": REDACTED,
+                            "zip": REDACTED,
+                            "first_name": REDACTED,
+                            "last_name": REDACTED,
+                            "city": "San Fransico"
+                        }
+                    },
+                    { "email": REDACTED, "name": null }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn masks_card_numbers_within_arrays() {
+        let mut payload = json!([
+            { "card_number": "5555555555554444" },
+            { "network_token": "4111111111111111", "card_cvc": null },
+            { "card_number": 4242 }
+        ]);
+
+        mask_json(&mut payload);
+
+        assert_eq!(
+            payload,
+            json!([
+                { "card_number": "************4444" },
+                { "network_token": "************1111", "card_cvc": null },
+                { "card_number": REDACTED }
+            ])
+        );
+    }
+}
@@ -1,8 +1,12 @@
 use std::{collections::HashMap, env};
 
 use masking::Secret;
+use once_cell::sync::OnceCell;
 use serde::{Deserialize, Serialize};
 
+/// Authentication of the connectors, read once and shared by the tests running in parallel
+static CONNECTOR_AUTHENTICATION: OnceCell<ConnectorAuthentication> = OnceCell::new();
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ConnectorAuthentication {
     pub aci: Option<BodyKey>,
@@ -14,6 +18,8 @@ pub struct ConnectorAuthentication {
     pub adyen_uk: Option<BodyKey>,
     #[cfg(feature = "payouts")]
     pub adyen_uk: Option<SignatureKey>,
+    pub affirm: Option<BodyKey>,
+    pub afterpay: Option<BodyKey>,
     pub airwallex: Option<BodyKey>,
     pub authorizedotnet: Option<BodyKey>,
     pub bambora: Option<BodyKey>,
@@ -49,6 +55,7 @@ pub struct ConnectorAuthentication {
     pub opennode: Option<HeaderKey>,
     pub payeezy: Option<SignatureKey>,
     pub payme: Option<BodyKey>,
+    pub payoneer: Option<SignatureKey>,
     pub paypal: Option<BodyKey>,
     pub payu: Option<BodyKey>,
     pub placetopay: Option<BodyKey>,
@@ -95,6 +102,15 @@ impl ConnectorAuthentication {
         )
         .expect("Failed to read connector authentication config file")
     }
+
+    /// Authentication of the connectors, read from the file the first time it is requested.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `CONNECTOR_AUTH_FILE_PATH` env is not set
+    pub fn get() -> &'static Self {
+        CONNECTOR_AUTHENTICATION.get_or_init(Self::new)
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
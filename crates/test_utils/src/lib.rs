@@ -1,2 +1,6 @@
+pub mod capability_probe;
+pub mod certification;
 pub mod connector_auth;
+pub mod lifecycle;
+pub mod merchant_simulator;
 pub mod newman_runner;
@@ -0,0 +1,274 @@
+//! Assertions of the invariants which hold across the lifecycle of a payment, for the tests of the
+//! connectors and of the payment flows.
+//!
+//! The assertions panic with a message describing the whole lifecycle being checked, so that the
+//! failing step can be told apart without rerunning the test.
+
+use std::fmt::Debug;
+
+use common_enums::{AttemptStatus, EventType, IntentStatus, RefundStatus};
+
+/// Stages of the lifecycle of a payment or a refund, in the order they are reached
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LifecycleStage {
+    Initiated,
+    Authenticating,
+    Authenticated,
+    Authorizing,
+    Authorized,
+    Capturing,
+    Succeeded,
+    Failed,
+}
+
+impl LifecycleStage {
+    /// Whether the lifecycle has ended, after which the status is not expected to change
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed)
+    }
+}
+
+/// Status of a payment or a refund, whose successive values are expected to only move the
+/// lifecycle forward
+pub trait LifecycleStatus: Copy + Debug + PartialEq {
+    /// The stage of the lifecycle the status belongs to, or `None` for the statuses which can be
+    /// reported at any stage, such as while the connector is processing a request
+    fn stage(&self) -> Option<LifecycleStage>;
+}
+
+impl LifecycleStatus for AttemptStatus {
+    fn stage(&self) -> Option<LifecycleStage> {
+        match self {
+            Self::Started
+            | Self::PaymentMethodAwaited
+            | Self::ConfirmationAwaited
+            | Self::DeviceDataCollectionPending => Some(LifecycleStage::Initiated),
+            Self::AuthenticationPending => Some(LifecycleStage::Authenticating),
+            Self::AuthenticationSuccessful => Some(LifecycleStage::Authenticated),
+            Self::Authorizing | Self::CodInitiated => Some(LifecycleStage::Authorizing),
+            Self::Authorized => Some(LifecycleStage::Authorized),
+            // A capture or a void which failed can be attempted again
+            Self::CaptureInitiated
+            | Self::CaptureFailed
+            | Self::VoidInitiated
+            | Self::VoidFailed
+            | Self::PartialChargedAndChargeable => Some(LifecycleStage::Capturing),
+            Self::Charged | Self::PartialCharged | Self::Voided | Self::AutoRefunded => {
+                Some(LifecycleStage::Succeeded)
+            }
+            Self::AuthenticationFailed
+            | Self::RouterDeclined
+            | Self::AuthorizationFailed
+            | Self::Failure => Some(LifecycleStage::Failed),
+            Self::Pending | Self::Unresolved => None,
+        }
+    }
+}
+
+/// The statuses of a payment are only expected to move its lifecycle forward within an attempt,
+/// manual retries of a failed payment start its lifecycle over
+impl LifecycleStatus for IntentStatus {
+    fn stage(&self) -> Option<LifecycleStage> {
+        match self {
+            Self::RequiresPaymentMethod | Self::RequiresConfirmation => {
+                Some(LifecycleStage::Initiated)
+            }
+            Self::RequiresCapture => Some(LifecycleStage::Authorized),
+            Self::PartiallyCapturedAndCapturable => Some(LifecycleStage::Capturing),
+            Self::Succeeded | Self::PartiallyCaptured | Self::Cancelled => {
+                Some(LifecycleStage::Succeeded)
+            }
+            Self::Failed | Self::Expired => Some(LifecycleStage::Failed),
+            // The customer or the merchant can be required to act both before and after the
+            // payment is authorized
+            Self::Processing | Self::RequiresCustomerAction | Self::RequiresMerchantAction => None,
+        }
+    }
+}
+
+impl LifecycleStatus for RefundStatus {
+    fn stage(&self) -> Option<LifecycleStage> {
+        match self {
+            Self::Success => Some(LifecycleStage::Succeeded),
+            Self::Failure | Self::TransactionFailure => Some(LifecycleStage::Failed),
+            Self::Pending | Self::PendingEnqueue | Self::ManualReview => None,
+        }
+    }
+}
+
+/// Check that the successive statuses of a payment or a refund never move its lifecycle back,
+/// and that the status does not change anymore once the lifecycle has ended, describing the
+/// first violation otherwise.
+pub fn check_status_progression<S: LifecycleStatus>(statuses: &[S]) -> Result<(), String> {
+    let mut current: Option<(S, LifecycleStage)> = None;
+
+    for (position, status) in statuses.iter().enumerate() {
+        if let Some((previous, previous_stage)) = current {
+            if previous_stage.is_terminal() && *status != previous {
+                return Err(format!(
+                    "Status `{status:?}` at position {position} follows the final status `{previous:?}`, in {statuses:?}"
+                ));
+            }
+        }
+
+        if let Some(stage) = status.stage() {
+            if let Some((previous, previous_stage)) = current {
+                if stage < previous_stage {
+                    return Err(format!(
+                        "Status `{status:?}` at position {position} moves the lifecycle back from `{previous:?}`, in {statuses:?}"
+                    ));
+                }
+            }
+            current = Some((*status, stage));
+        }
+    }
+
+    Ok(())
+}
+
+/// Assert that the successive statuses of a payment or a refund never move its lifecycle back,
+/// and that the status does not change anymore once the lifecycle has ended.
+#[track_caller]
+pub fn assert_status_progression<S: LifecycleStatus>(statuses: &[S]) {
+    if let Err(message) = check_status_progression(statuses) {
+        panic!("{message}");
+    }
+}
+
+/// Assert that no more than the authorized amount of a payment has been captured, and that no
+/// more than the captured amount has been refunded.
+#[track_caller]
+pub fn assert_amount_conservation(
+    authorized_amount: i64,
+    captured_amounts: &[i64],
+    refunded_amounts: &[i64],
+) {
+    assert!(
+        captured_amounts
+            .iter()
+            .chain(refunded_amounts)
+            .all(|amount| *amount > 0),
+        "Captured amounts {captured_amounts:?} and refunded amounts {refunded_amounts:?} must be positive"
+    );
+
+    let captured_amount: i64 = captured_amounts.iter().sum();
+    let refunded_amount: i64 = refunded_amounts.iter().sum();
+    assert!(
+        captured_amount <= authorized_amount,
+        "Captured amount {captured_amount} ({captured_amounts:?}) exceeds the authorized amount {authorized_amount}"
+    );
+    assert!(
+        refunded_amount <= captured_amount,
+        "Refunded amount {refunded_amount} ({refunded_amounts:?}) exceeds the captured amount {captured_amount}"
+    );
+}
+
+/// The event of the outgoing webhook sent when a payment moves to the status, if any
+pub fn expected_payment_event(status: IntentStatus) -> Option<EventType> {
+    match status {
+        IntentStatus::Succeeded => Some(EventType::PaymentSucceeded),
+        IntentStatus::Failed => Some(EventType::PaymentFailed),
+        IntentStatus::Processing => Some(EventType::PaymentProcessing),
+        IntentStatus::RequiresMerchantAction | IntentStatus::RequiresCustomerAction => {
+            Some(EventType::ActionRequired)
+        }
+        IntentStatus::Cancelled => Some(EventType::PaymentCancelled),
+        IntentStatus::Expired => Some(EventType::PaymentExpired),
+        IntentStatus::PartiallyCaptured | IntentStatus::PartiallyCapturedAndCapturable => {
+            Some(EventType::PaymentCaptured)
+        }
+        IntentStatus::RequiresCapture => Some(EventType::PaymentAuthorized),
+        IntentStatus::RequiresPaymentMethod | IntentStatus::RequiresConfirmation => None,
+    }
+}
+
+/// The event of the outgoing webhook sent when a refund moves to the status, if any
+pub fn expected_refund_event(status: RefundStatus) -> Option<EventType> {
+    match status {
+        RefundStatus::Success => Some(EventType::RefundSucceeded),
+        RefundStatus::Failure => Some(EventType::RefundFailed),
+        RefundStatus::ManualReview
+        | RefundStatus::Pending
+        | RefundStatus::PendingEnqueue
+        | RefundStatus::TransactionFailure => None,
+    }
+}
+
+/// Assert that the events of the outgoing webhooks of a payment match the statuses of the payment
+/// they were sent with, and that the statuses never move the lifecycle of the payment back.
+#[track_caller]
+pub fn assert_payment_events_consistent(events: &[(EventType, IntentStatus)]) {
+    for (event_type, status) in events {
+        assert_eq!(
+            Some(*event_type),
+            expected_payment_event(*status),
+            "Event `{event_type:?}` was sent for a payment with status `{status:?}`, in {events:?}"
+        );
+    }
+    assert_status_progression(&events.iter().map(|(_, status)| *status).collect::<Vec<_>>());
+}
+
+/// Assert that the events of the outgoing webhooks of a refund match the statuses of the refund
+/// they were sent with, and that the statuses never move the lifecycle of the refund back.
+#[track_caller]
+pub fn assert_refund_events_consistent(events: &[(EventType, RefundStatus)]) {
+    for (event_type, status) in events {
+        assert_eq!(
+            Some(*event_type),
+            expected_refund_event(*status),
+            "Event `{event_type:?}` was sent for a refund with status `{status:?}`, in {events:?}"
+        );
+    }
+    assert_status_progression(&events.iter().map(|(_, status)| *status).collect::<Vec<_>>());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_forward_status_progression() {
+        assert_status_progression(&[
+            AttemptStatus::Started,
+            AttemptStatus::AuthenticationPending,
+            AttemptStatus::Pending,
+            AttemptStatus::Authorized,
+            AttemptStatus::CaptureInitiated,
+            AttemptStatus::Pending,
+            AttemptStatus::Charged,
+            AttemptStatus::Charged,
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "moves the lifecycle back")]
+    fn rejects_backward_status_progression() {
+        assert_status_progression(&[AttemptStatus::Authorized, AttemptStatus::Authorizing]);
+    }
+
+    #[test]
+    #[should_panic(expected = "follows the final status")]
+    fn rejects_status_after_final_status() {
+        assert_status_progression(&[RefundStatus::Failure, RefundStatus::Pending]);
+    }
+
+    #[test]
+    fn accepts_conserved_amounts() {
+        assert_amount_conservation(1000, &[600, 400], &[100, 900]);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the captured amount")]
+    fn rejects_refunds_above_captured_amount() {
+        assert_amount_conservation(1000, &[500], &[300, 300]);
+    }
+
+    #[test]
+    #[should_panic(expected = "was sent for a payment with status")]
+    fn rejects_inconsistent_payment_events() {
+        assert_payment_events_consistent(&[
+            (EventType::PaymentAuthorized, IntentStatus::RequiresCapture),
+            (EventType::PaymentSucceeded, IntentStatus::PartiallyCaptured),
+        ]);
+    }
+}
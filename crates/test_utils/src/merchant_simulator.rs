@@ -0,0 +1,604 @@
+//! Simulator of a merchant backend, for soak testing releases.
+//!
+//! The simulator creates payments at a constant rate, receives the webhooks of the payments on a
+//! local HTTP server and verifies their signatures, and finally reports whether the payments,
+//! their webhooks and their final statuses are consistent with each other.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs, io,
+    path::PathBuf,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+use clap::Parser;
+use common_enums::{EventType, IntentStatus};
+use hyperswitch_client::{
+    api_models::{
+        payments::{PaymentRetrieveBody, PaymentsResponse},
+        webhooks::{OutgoingWebhook, OutgoingWebhookContent},
+    },
+    webhooks::{self, WebhookVerifier, SIGNATURE_HEADER},
+    HyperswitchClient, API_KEY_HEADER,
+};
+use rand::Rng;
+use serde::Serialize;
+use serde_json::{json, Value};
+use time::PrimitiveDateTime;
+use tokio::{task::JoinSet, time::MissedTickBehavior};
+
+use crate::lifecycle::{self, LifecycleStatus};
+
+/// Number of payments retrieved concurrently when collecting their final statuses
+const RETRIEVE_CONCURRENCY: usize = 16;
+
+#[derive(Parser)]
+#[command(version, about = "Merchant backend simulator for soak testing", long_about = None)]
+struct Args {
+    /// Base URL of the environment
+    #[arg(short, long, default_value = "http://127.0.0.1:8080")]
+    base_url: String,
+    /// API key of the merchant the payments are created for
+    #[arg(short = 'k', long)]
+    api_key: String,
+    /// `payment_response_hash_key` of the business profile, used to verify the webhooks
+    #[arg(short, long)]
+    webhook_secret: String,
+    /// Address the webhook server listens on, the webhook URL of the business profile must
+    /// point to it
+    #[arg(short, long, default_value = "127.0.0.1:8090")]
+    listen_address: String,
+    /// Number of payments created per second
+    #[arg(short, long, default_value_t = 1.0)]
+    rate: f64,
+    /// Time during which payments are created, in seconds
+    #[arg(short, long, default_value_t = 300)]
+    duration: u64,
+    /// Time to wait for the webhooks of the last payments once no more payments are created, in
+    /// seconds
+    #[arg(long, default_value_t = 60)]
+    settle_time: u64,
+    /// Interval between the progress reports, in seconds
+    #[arg(long, default_value_t = 30)]
+    report_interval: u64,
+    /// JSON file with the body of the payments create request, whose amount is randomized for
+    /// every payment. A confirmed card payment is created if not provided
+    #[arg(short, long)]
+    payment_request: Option<PathBuf>,
+    /// File the final report is written to, as JSON
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+/// Consistency metrics of a simulation, from the point of view of the merchant
+#[derive(Debug, Default, Serialize)]
+pub struct SimulationReport {
+    pub elapsed_secs: u64,
+    pub payments_created: usize,
+    pub payment_creation_failures: u64,
+    pub payment_creation_latency: LatencySummary,
+    pub webhooks_received: usize,
+    /// Webhooks whose signature could not be verified
+    pub webhooks_rejected: u64,
+    /// Webhooks received again after having been acknowledged
+    pub duplicate_webhooks: u64,
+    /// Webhooks of payments which were not created by the simulator
+    pub webhooks_for_unknown_payments: usize,
+    /// Time between the creation of a payment and the reception of its first webhook
+    pub webhook_delay: LatencySummary,
+    /// Payments whose final status is expected to be notified, for which no webhook carrying
+    /// that status has been received
+    pub payments_missing_webhook: Vec<String>,
+    pub inconsistencies: Vec<Inconsistency>,
+}
+
+impl SimulationReport {
+    pub fn is_consistent(&self) -> bool {
+        self.webhooks_rejected == 0
+            && self.duplicate_webhooks == 0
+            && self.payments_missing_webhook.is_empty()
+            && self.inconsistencies.is_empty()
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub struct LatencySummary {
+    pub p50_ms: u128,
+    pub p95_ms: u128,
+    pub p99_ms: u128,
+    pub max_ms: u128,
+}
+
+impl LatencySummary {
+    fn from_durations(mut durations: Vec<Duration>) -> Self {
+        durations.sort_unstable();
+        let percentile = |percentile: usize| {
+            durations
+                .get(durations.len().saturating_sub(1) * percentile / 100)
+                .map(Duration::as_millis)
+                .unwrap_or_default()
+        };
+
+        Self {
+            p50_ms: percentile(50),
+            p95_ms: percentile(95),
+            p99_ms: percentile(99),
+            max_ms: percentile(100),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct Inconsistency {
+    pub payment_id: String,
+    pub description: String,
+}
+
+struct CreatedPayment {
+    created_at: Instant,
+}
+
+#[derive(Clone, Debug)]
+struct ReceivedWebhook {
+    event_type: EventType,
+    status: IntentStatus,
+    sent_at: PrimitiveDateTime,
+    received_at: Instant,
+}
+
+#[derive(Default)]
+struct SimulatorState {
+    payments: HashMap<String, CreatedPayment>,
+    payment_creation_failures: u64,
+    payment_creation_latencies: Vec<Duration>,
+    /// Webhooks of the payments, which can be received before the response to the creation of
+    /// the payment
+    payment_webhooks: HashMap<String, Vec<ReceivedWebhook>>,
+    event_ids: HashSet<String>,
+    webhooks_received: usize,
+    webhooks_rejected: u64,
+    duplicate_webhooks: u64,
+}
+
+impl SimulatorState {
+    fn record_webhook(&mut self, webhook: OutgoingWebhook, received_at: Instant) {
+        self.webhooks_received += 1;
+        if !self.event_ids.insert(webhook.event_id) {
+            self.duplicate_webhooks += 1;
+            return;
+        }
+
+        // Only the webhooks of payments are checked, the other webhooks of the merchant are
+        // counted but otherwise ignored
+        if let OutgoingWebhookContent::PaymentDetails(payment) = webhook.content {
+            self.payment_webhooks
+                .entry(payment.payment_id.unwrap_or_default())
+                .or_default()
+                .push(ReceivedWebhook {
+                    event_type: webhook.event_type,
+                    status: payment.status,
+                    sent_at: webhook.timestamp,
+                    received_at,
+                });
+        }
+    }
+
+    /// Payments created by the simulator which have not been notified of a final status yet
+    fn payments_awaiting_webhook(&self) -> usize {
+        self.payments
+            .keys()
+            .filter(|payment_id| {
+                !self
+                    .payment_webhooks
+                    .get(*payment_id)
+                    .into_iter()
+                    .flatten()
+                    .any(|webhook| {
+                        webhook
+                            .status
+                            .stage()
+                            .is_some_and(lifecycle::LifecycleStage::is_terminal)
+                    })
+            })
+            .count()
+    }
+}
+
+#[derive(Clone)]
+struct Simulator {
+    state: Arc<Mutex<SimulatorState>>,
+    verifier: WebhookVerifier,
+}
+
+impl Simulator {
+    fn state(&self) -> MutexGuard<'_, SimulatorState> {
+        // The state stays consistent even if a task panicked while holding the lock, since every
+        // update is a single insertion or increment
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+async fn receive_webhook(
+    request: HttpRequest,
+    body: web::Bytes,
+    simulator: web::Data<Simulator>,
+) -> HttpResponse {
+    let received_at = Instant::now();
+    let signature = request
+        .headers()
+        .get(SIGNATURE_HEADER)
+        .and_then(|signature| signature.to_str().ok())
+        .unwrap_or_default();
+
+    match webhooks::construct_event(&simulator.verifier, signature, &body) {
+        Ok(webhook) => {
+            simulator.state().record_webhook(webhook, received_at);
+            HttpResponse::Ok().finish()
+        }
+        Err(err) => {
+            eprintln!("Rejected webhook: {err}");
+            simulator.state().webhooks_rejected += 1;
+            HttpResponse::BadRequest().finish()
+        }
+    }
+}
+
+/// Body of the payments create request used when no template is provided
+fn default_payment_request() -> Value {
+    json!({
+        "currency": "USD",
+        "confirm": true,
+        "capture_method": "automatic",
+        "authentication_type": "no_three_ds",
+        "customer_id": "merchant_simulator",
+        "description": "Payment created by the merchant simulator",
+        "payment_method": "card",
+        "payment_method_type": "credit",
+        "payment_method_data": {
+            "card": {
+                "card_number": "4242424242424242",
+                "card_exp_month": "10",
+                "card_exp_year": "35",
+                "card_holder_name": "joseph Doe",
+                "card_cvc": "123"
+            }
+        }
+    })
+}
+
+async fn create_payment(
+    http: &reqwest::Client,
+    base_url: &str,
+    api_key: &str,
+    body: &Value,
+) -> Result<PaymentsResponse, String> {
+    let response = http
+        .post(format!("{}/payments", base_url.trim_end_matches('/')))
+        .header(API_KEY_HEADER, api_key)
+        .json(body)
+        .send()
+        .await
+        .map_err(|err| err.to_string())?;
+    let status = response.status();
+    let response_body = response.text().await.map_err(|err| err.to_string())?;
+
+    if !status.is_success() {
+        return Err(format!(
+            "API responded with status {status}: {response_body}"
+        ));
+    }
+    serde_json::from_str(&response_body).map_err(|err| err.to_string())
+}
+
+/// Check the webhooks received for a payment against each other, and against the final status of
+/// the payment. Returns whether a webhook carrying the final status is missing, along with the
+/// inconsistencies found.
+fn check_payment(
+    payment_id: &str,
+    final_status: IntentStatus,
+    webhooks: &[ReceivedWebhook],
+) -> (bool, Vec<Inconsistency>) {
+    let inconsistency = |description: String| Inconsistency {
+        payment_id: payment_id.to_string(),
+        description,
+    };
+    let mut webhooks = webhooks.to_vec();
+    webhooks.sort_by_key(|webhook| webhook.sent_at);
+
+    let mut inconsistencies: Vec<_> = webhooks
+        .iter()
+        .filter(|webhook| {
+            lifecycle::expected_payment_event(webhook.status) != Some(webhook.event_type)
+        })
+        .map(|webhook| {
+            inconsistency(format!(
+                "Webhook `{:?}` was sent for the status `{:?}`",
+                webhook.event_type, webhook.status
+            ))
+        })
+        .collect();
+
+    let statuses: Vec<_> = webhooks.iter().map(|webhook| webhook.status).collect();
+    if let Err(description) = lifecycle::check_status_progression(&statuses) {
+        inconsistencies.push(inconsistency(description));
+    }
+
+    let is_missing_webhook = lifecycle::expected_payment_event(final_status).is_some()
+        && statuses.last() != Some(&final_status);
+
+    (is_missing_webhook, inconsistencies)
+}
+
+/// Run a simulation with the arguments passed on the command line
+pub async fn simulate() -> io::Result<SimulationReport> {
+    let args = Args::parse();
+    if args.rate.is_nan() || args.rate <= 0.0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "The rate must be positive",
+        ));
+    }
+
+    let payment_request = match &args.payment_request {
+        Some(path) => serde_json::from_str(&fs::read_to_string(path)?)?,
+        None => default_payment_request(),
+    };
+    let client = HyperswitchClient::new(&args.base_url, args.api_key.clone())
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+    let http = reqwest::Client::new();
+
+    let simulator = Simulator {
+        state: Arc::default(),
+        verifier: WebhookVerifier::new(&args.webhook_secret),
+    };
+    let server_data = web::Data::new(simulator.clone());
+    let server = HttpServer::new(move || {
+        App::new()
+            .app_data(server_data.clone())
+            .default_service(web::to(receive_webhook))
+    })
+    .workers(2)
+    .bind(&args.listen_address)?
+    .run();
+    let server_handle = server.handle();
+    tokio::spawn(server);
+    println!("Receiving webhooks on {}", args.listen_address);
+
+    let started_at = Instant::now();
+    let creation_deadline = started_at + Duration::from_secs(args.duration);
+    let report_interval = Duration::from_secs(args.report_interval);
+    let mut last_report = started_at;
+    let mut ticks = tokio::time::interval(Duration::from_secs_f64(1.0 / args.rate));
+    ticks.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let mut creations = JoinSet::new();
+
+    while Instant::now() < creation_deadline {
+        ticks.tick().await;
+
+        let mut body = payment_request.clone();
+        body["amount"] = json!(rand::thread_rng().gen_range(100..=10_000));
+        let (http, base_url, api_key, task_simulator) = (
+            http.clone(),
+            args.base_url.clone(),
+            args.api_key.clone(),
+            simulator.clone(),
+        );
+        // Payments are created concurrently, so that slow responses do not lower the rate
+        creations.spawn(async move {
+            let requested_at = Instant::now();
+            let result = create_payment(&http, &base_url, &api_key, &body).await;
+            let mut state = task_simulator.state();
+            match result.and_then(|payment| {
+                payment
+                    .payment_id
+                    .ok_or_else(|| "The response has no payment_id".to_string())
+            }) {
+                Ok(payment_id) => {
+                    state
+                        .payment_creation_latencies
+                        .push(requested_at.elapsed());
+                    state.payments.insert(
+                        payment_id,
+                        CreatedPayment {
+                            created_at: requested_at,
+                        },
+                    );
+                }
+                Err(err) => {
+                    eprintln!("Failed to create a payment: {err}");
+                    state.payment_creation_failures += 1;
+                }
+            }
+        });
+
+        if last_report.elapsed() >= report_interval {
+            last_report = Instant::now();
+            print_progress(started_at, &simulator.state());
+        }
+    }
+    while creations.join_next().await.is_some() {}
+
+    let settle_deadline = Instant::now() + Duration::from_secs(args.settle_time);
+    while Instant::now() < settle_deadline && simulator.state().payments_awaiting_webhook() > 0 {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    print_progress(started_at, &simulator.state());
+
+    let payment_ids: Vec<_> = simulator.state().payments.keys().cloned().collect();
+    let mut final_statuses = HashMap::new();
+    for payment_ids in payment_ids.chunks(RETRIEVE_CONCURRENCY) {
+        let mut retrievals = JoinSet::new();
+        for payment_id in payment_ids {
+            let (client, payment_id) = (client.clone(), payment_id.clone());
+            retrievals.spawn(async move {
+                let result = client
+                    .retrieve_payment(&payment_id, &PaymentRetrieveBody::default())
+                    .await;
+                (payment_id, result)
+            });
+        }
+        while let Some(Ok((payment_id, result))) = retrievals.join_next().await {
+            final_statuses.insert(payment_id, result.map(|payment| payment.status));
+        }
+    }
+    server_handle.stop(true).await;
+
+    let report = build_report(started_at.elapsed(), &simulator.state(), final_statuses);
+    if let Some(output) = &args.output {
+        fs::write(output, serde_json::to_string_pretty(&report)?)?;
+    }
+
+    Ok(report)
+}
+
+fn print_progress(started_at: Instant, state: &SimulatorState) {
+    println!(
+        "[{}s] payments created: {}, failed: {}, webhooks received: {}, rejected: {}, payments awaiting a final webhook: {}",
+        started_at.elapsed().as_secs(),
+        state.payments.len(),
+        state.payment_creation_failures,
+        state.webhooks_received,
+        state.webhooks_rejected,
+        state.payments_awaiting_webhook(),
+    );
+}
+
+fn build_report<E: std::fmt::Display>(
+    elapsed: Duration,
+    state: &SimulatorState,
+    final_statuses: HashMap<String, Result<IntentStatus, E>>,
+) -> SimulationReport {
+    let mut report = SimulationReport {
+        elapsed_secs: elapsed.as_secs(),
+        payments_created: state.payments.len(),
+        payment_creation_failures: state.payment_creation_failures,
+        payment_creation_latency: LatencySummary::from_durations(
+            state.payment_creation_latencies.clone(),
+        ),
+        webhooks_received: state.webhooks_received,
+        webhooks_rejected: state.webhooks_rejected,
+        duplicate_webhooks: state.duplicate_webhooks,
+        webhooks_for_unknown_payments: state
+            .payment_webhooks
+            .iter()
+            .filter(|(payment_id, _)| !state.payments.contains_key(*payment_id))
+            .map(|(_, webhooks)| webhooks.len())
+            .sum(),
+        ..Default::default()
+    };
+
+    let mut webhook_delays = Vec::new();
+    for (payment_id, payment) in &state.payments {
+        let webhooks = state
+            .payment_webhooks
+            .get(payment_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        if let Some(first_received_at) = webhooks.iter().map(|webhook| webhook.received_at).min() {
+            webhook_delays.push(first_received_at.saturating_duration_since(payment.created_at));
+        }
+
+        match final_statuses.get(payment_id) {
+            Some(Ok(final_status)) => {
+                let (is_missing_webhook, inconsistencies) =
+                    check_payment(payment_id, *final_status, webhooks);
+                if is_missing_webhook {
+                    report.payments_missing_webhook.push(payment_id.clone());
+                }
+                report.inconsistencies.extend(inconsistencies);
+            }
+            Some(Err(err)) => report.inconsistencies.push(Inconsistency {
+                payment_id: payment_id.clone(),
+                description: format!("Failed to retrieve the payment: {err}"),
+            }),
+            None => report.inconsistencies.push(Inconsistency {
+                payment_id: payment_id.clone(),
+                description: "The payment was not retrieved".to_string(),
+            }),
+        }
+    }
+    report.webhook_delay = LatencySummary::from_durations(webhook_delays);
+    report.payments_missing_webhook.sort();
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn webhook(
+        event_type: EventType,
+        status: IntentStatus,
+        sent_at: PrimitiveDateTime,
+    ) -> ReceivedWebhook {
+        ReceivedWebhook {
+            event_type,
+            status,
+            sent_at,
+            received_at: Instant::now(),
+        }
+    }
+
+    #[test]
+    fn accepts_webhooks_consistent_with_final_status() {
+        // Webhooks can be received in a different order than they were sent in
+        let webhooks = [
+            webhook(
+                EventType::PaymentSucceeded,
+                IntentStatus::Succeeded,
+                datetime!(2024-06-12 10:00:05),
+            ),
+            webhook(
+                EventType::PaymentProcessing,
+                IntentStatus::Processing,
+                datetime!(2024-06-12 10:00:00),
+            ),
+        ];
+
+        let (is_missing_webhook, inconsistencies) =
+            check_payment("pay_1", IntentStatus::Succeeded, &webhooks);
+        assert!(!is_missing_webhook);
+        assert_eq!(inconsistencies, []);
+    }
+
+    #[test]
+    fn reports_missing_and_inconsistent_webhooks() {
+        let webhooks = [webhook(
+            EventType::PaymentFailed,
+            IntentStatus::Processing,
+            datetime!(2024-06-12 10:00:00),
+        )];
+
+        let (is_missing_webhook, inconsistencies) =
+            check_payment("pay_1", IntentStatus::Succeeded, &webhooks);
+        assert!(is_missing_webhook);
+        assert_eq!(inconsistencies.len(), 1);
+
+        let (is_missing_webhook, _) = check_payment("pay_1", IntentStatus::Failed, &[]);
+        assert!(is_missing_webhook);
+    }
+
+    #[test]
+    fn summarizes_latencies() {
+        let summary =
+            LatencySummary::from_durations((1..=100).rev().map(Duration::from_millis).collect());
+        assert_eq!(
+            summary,
+            LatencySummary {
+                p50_ms: 50,
+                p95_ms: 95,
+                p99_ms: 99,
+                max_ms: 100,
+            }
+        );
+        assert_eq!(
+            LatencySummary::from_durations(Vec::new()),
+            LatencySummary::default()
+        );
+    }
+}
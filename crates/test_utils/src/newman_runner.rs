@@ -57,7 +57,7 @@ fn get_collection_path(name: impl AsRef<str>) -> String {
 // Generates the name of the collection directory for the specified connector.
 // Example: CONNECTOR_NAME="stripe" -> OUTPUT: postman/collection-dir/stripe
 #[inline]
-fn get_dir_path(name: impl AsRef<str>) -> String {
+pub fn get_dir_path(name: impl AsRef<str>) -> String {
     format!("postman/collection-dir/{}", name.as_ref())
 }
 
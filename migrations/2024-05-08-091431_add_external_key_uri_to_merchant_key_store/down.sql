@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_key_store DROP COLUMN IF EXISTS external_key_uri;
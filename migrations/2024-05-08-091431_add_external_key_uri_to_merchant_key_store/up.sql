@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE merchant_key_store ADD COLUMN IF NOT EXISTS external_key_uri VARCHAR(2048);
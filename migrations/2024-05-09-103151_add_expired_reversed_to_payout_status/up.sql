@@ -0,0 +1,2 @@
+ALTER TYPE "PayoutStatus" ADD VALUE IF NOT EXISTS 'expired';
+ALTER TYPE "PayoutStatus" ADD VALUE IF NOT EXISTS 'reversed';
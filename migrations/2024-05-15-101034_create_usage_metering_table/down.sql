@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS usage_metering_period_start_index;
+
+DROP TABLE IF EXISTS usage_metering;
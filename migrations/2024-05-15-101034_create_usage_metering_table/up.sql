@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS usage_metering (
+    merchant_id VARCHAR(64) NOT NULL,
+    metric VARCHAR(64) NOT NULL,
+    period_start TIMESTAMP NOT NULL,
+    count BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, metric, period_start)
+);
+
+CREATE INDEX IF NOT EXISTS usage_metering_period_start_index ON usage_metering (period_start);
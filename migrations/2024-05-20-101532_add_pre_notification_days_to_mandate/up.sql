@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE mandate ADD COLUMN IF NOT EXISTS pre_notification_days SMALLINT;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE gateway_status_map ADD COLUMN IF NOT EXISTS step_down_possible BOOLEAN NOT NULL DEFAULT FALSE;
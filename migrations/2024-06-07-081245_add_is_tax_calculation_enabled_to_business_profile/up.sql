@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS is_tax_calculation_enabled BOOLEAN NOT NULL DEFAULT FALSE;
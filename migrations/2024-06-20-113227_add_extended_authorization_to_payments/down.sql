@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_intent DROP COLUMN IF EXISTS request_extended_authorization;
+
+ALTER TABLE payment_attempt
+DROP COLUMN IF EXISTS extended_authorization_applied,
+DROP COLUMN IF EXISTS capture_before;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS request_extended_authorization BOOLEAN;
+
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS extended_authorization_applied BOOLEAN,
+ADD COLUMN IF NOT EXISTS capture_before TIMESTAMP;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TYPE "IntentStatus" ADD VALUE IF NOT EXISTS 'expired';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_expired';